@@ -12,7 +12,8 @@ CREATE TABLE connector_events_queue (
     `latency` UInt128,
     `method` LowCardinality(String),
     `dispute_id` Nullable(String),
-    `refund_id` Nullable(String)
+    `refund_id` Nullable(String),
+    `connector_request_id` Nullable(String)
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-outgoing-connector-events',
 kafka_group_name = 'hyper',
@@ -56,6 +57,7 @@ CREATE TABLE connector_events (
     `method` LowCardinality(String),
     `dispute_id` Nullable(String),
     `refund_id` Nullable(String),
+    `connector_request_id` Nullable(String),
     INDEX flowIndex flow TYPE bloom_filter GRANULARITY 1,
     INDEX connectorIndex connector_name TYPE bloom_filter GRANULARITY 1,
     INDEX statusIndex status_code TYPE bloom_filter GRANULARITY 1
@@ -86,6 +88,7 @@ CREATE TABLE connector_events_audit (
     `method` LowCardinality(String),
     `dispute_id` Nullable(String),
     `refund_id` Nullable(String),
+    `connector_request_id` Nullable(String),
     INDEX flowIndex flow TYPE bloom_filter GRANULARITY 1,
     INDEX connectorIndex connector_name TYPE bloom_filter GRANULARITY 1,
     INDEX statusIndex status_code TYPE bloom_filter GRANULARITY 1
@@ -109,7 +112,8 @@ CREATE MATERIALIZED VIEW connector_events_audit_mv TO connector_events_audit (
     `latency` UInt128,
     `method` LowCardinality(String),
     `refund_id` Nullable(String),
-    `dispute_id` Nullable(String)
+    `dispute_id` Nullable(String),
+    `connector_request_id` Nullable(String)
 ) AS
 SELECT
     merchant_id,
@@ -127,7 +131,8 @@ SELECT
     latency,
     method,
     refund_id,
-    dispute_id
+    dispute_id,
+    connector_request_id
 FROM
     connector_events_queue
 WHERE
@@ -150,7 +155,8 @@ CREATE MATERIALIZED VIEW connector_events_mv TO connector_events (
     `latency` UInt128,
     `method` LowCardinality(String),
     `refund_id` Nullable(String),
-    `dispute_id` Nullable(String)
+    `dispute_id` Nullable(String),
+    `connector_request_id` Nullable(String)
 ) AS
 SELECT
     merchant_id,
@@ -168,7 +174,8 @@ SELECT
     latency,
     method,
     refund_id,
-    dispute_id
+    dispute_id,
+    connector_request_id
 FROM
     connector_events_queue
 WHERE
@@ -43,6 +43,10 @@ CREATE TABLE payment_attempt_queue (
     `organization_id` String,
     `profile_id` String,
     `card_network` Nullable(String),
+    `connector_fee_amount` Nullable(UInt64),
+    `connector_fee_currency` LowCardinality(Nullable(String)),
+    `connector_request_id` Nullable(String),
+    `correlation_id` Nullable(String),
     `sign_flag` Int8
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-payment-attempt-events',
@@ -96,6 +100,10 @@ CREATE TABLE payment_attempts (
     `organization_id` String,
     `profile_id` String,
     `card_network` Nullable(String),
+    `connector_fee_amount` Nullable(UInt64),
+    `connector_fee_currency` LowCardinality(Nullable(String)),
+    `connector_request_id` Nullable(String),
+    `correlation_id` Nullable(String),
     `sign_flag` Int8,
     INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
     INDEX paymentMethodIndex payment_method TYPE bloom_filter GRANULARITY 1,
@@ -152,6 +160,10 @@ CREATE MATERIALIZED VIEW payment_attempt_mv TO payment_attempts (
     `organization_id` String,
     `profile_id` String,
     `card_network` Nullable(String),
+    `connector_fee_amount` Nullable(UInt64),
+    `connector_fee_currency` LowCardinality(Nullable(String)),
+    `connector_request_id` Nullable(String),
+    `correlation_id` Nullable(String),
     `sign_flag` Int8
 ) AS
 SELECT
@@ -200,6 +212,10 @@ SELECT
     organization_id,
     profile_id,
     card_network,
+    connector_fee_amount,
+    connector_fee_currency,
+    connector_request_id,
+    correlation_id,
     sign_flag
 FROM
     payment_attempt_queue
@@ -0,0 +1,75 @@
+CREATE TABLE payment_daily_summary (
+    `organization_id` LowCardinality(String),
+    `merchant_id` LowCardinality(String),
+    `profile_id` LowCardinality(String),
+    `status` LowCardinality(String),
+    `connector` LowCardinality(Nullable(String)),
+    `currency` LowCardinality(Nullable(String)),
+    `created_at` DateTime CODEC(T64, LZ4),
+    `payment_count` Int64,
+    `total_amount` Int64
+) ENGINE = SummingMergeTree((payment_count, total_amount)) PARTITION BY toStartOfMonth(created_at)
+ORDER BY
+    (created_at, merchant_id, profile_id, status, connector, currency) TTL created_at + toIntervalMonth(18) SETTINGS allow_nullable_key = 1,
+    index_granularity = 8192;
+
+-- Every update of an attempt is sent to the queue along with its previous version, which carries a
+-- sign_flag of -1, so that the sums of the signed counts and amounts are the totals of the
+-- attempts currently in a status
+CREATE MATERIALIZED VIEW payment_daily_summary_mv TO payment_daily_summary (
+    `organization_id` String,
+    `merchant_id` String,
+    `profile_id` String,
+    `status` String,
+    `connector` Nullable(String),
+    `currency` Nullable(String),
+    `created_at` DateTime,
+    `payment_count` Int64,
+    `total_amount` Int64
+) AS
+SELECT
+    organization_id,
+    merchant_id,
+    profile_id,
+    status,
+    connector,
+    currency,
+    toStartOfDay(created_at) AS created_at,
+    sum(sign_flag) AS payment_count,
+    sum(sign_flag * coalesce(amount, 0)) AS total_amount
+FROM
+    payment_attempt_queue
+WHERE
+    length(_error) = 0
+GROUP BY
+    organization_id,
+    merchant_id,
+    profile_id,
+    status,
+    connector,
+    currency,
+    created_at;
+
+-- Backfills the summary with the attempts received before the view was created
+INSERT INTO
+    payment_daily_summary
+SELECT
+    organization_id,
+    merchant_id,
+    profile_id,
+    status,
+    connector,
+    currency,
+    toStartOfDay(created_at) AS created_at,
+    sum(sign_flag) AS payment_count,
+    sum(sign_flag * coalesce(amount, 0)) AS total_amount
+FROM
+    payment_attempts
+GROUP BY
+    organization_id,
+    merchant_id,
+    profile_id,
+    status,
+    connector,
+    currency,
+    created_at;
@@ -0,0 +1,57 @@
+CREATE TABLE payment_intent_status_summary (
+    `organization_id` LowCardinality(String),
+    `merchant_id` LowCardinality(String),
+    `profile_id` LowCardinality(String),
+    `status` LowCardinality(String),
+    `created_at` DateTime CODEC(T64, LZ4),
+    `payment_count` Int64
+) ENGINE = SummingMergeTree(payment_count) PARTITION BY toStartOfDay(created_at)
+ORDER BY
+    (created_at, merchant_id, profile_id, status) TTL created_at + toIntervalMonth(18) SETTINGS index_granularity = 8192;
+
+-- Every update of an intent is sent to the queue along with its previous version, which carries a
+-- sign_flag of -1, so that the sum of the signs is the number of intents currently in a status
+CREATE MATERIALIZED VIEW payment_intent_status_summary_mv TO payment_intent_status_summary (
+    `organization_id` String,
+    `merchant_id` String,
+    `profile_id` String,
+    `status` String,
+    `created_at` DateTime,
+    `payment_count` Int64
+) AS
+SELECT
+    organization_id,
+    merchant_id,
+    coalesce(profile_id, '') AS profile_id,
+    status,
+    toStartOfMinute(created_at) AS created_at,
+    sum(sign_flag) AS payment_count
+FROM
+    payment_intents_queue
+WHERE
+    length(_error) = 0
+GROUP BY
+    organization_id,
+    merchant_id,
+    profile_id,
+    status,
+    created_at;
+
+-- Backfills the summary with the intents received before the view was created
+INSERT INTO
+    payment_intent_status_summary
+SELECT
+    organization_id,
+    merchant_id,
+    coalesce(profile_id, '') AS profile_id,
+    status,
+    toStartOfMinute(created_at) AS created_at,
+    sum(sign_flag) AS payment_count
+FROM
+    payment_intents
+GROUP BY
+    organization_id,
+    merchant_id,
+    profile_id,
+    status,
+    created_at;
@@ -20,6 +20,8 @@ CREATE TABLE payment_intents_queue
     `business_label` String,
     `attempt_count` UInt8,
     `profile_id` Nullable(String),
+    `customer_email` Nullable(String),
+    `customer_phone` Nullable(String),
     `modified_at` DateTime CODEC(T64, LZ4),
     `created_at` DateTime CODEC(T64, LZ4),
     `last_synced` Nullable(DateTime) CODEC(T64, LZ4),
@@ -53,6 +55,8 @@ CREATE TABLE payment_intents
     `business_label` String,
     `attempt_count` UInt8,
     `profile_id` Nullable(String),
+    `customer_email` Nullable(String),
+    `customer_phone` Nullable(String),
     `modified_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `created_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `last_synced` Nullable(DateTime) CODEC(T64, LZ4),
@@ -91,6 +95,8 @@ CREATE MATERIALIZED VIEW payment_intents_mv TO payment_intents
     `business_label` String,
     `attempt_count` UInt8,
     `profile_id` Nullable(String),
+    `customer_email` Nullable(String),
+    `customer_phone` Nullable(String),
     `modified_at` DateTime64(3),
     `created_at` DateTime64(3),
     `last_synced` Nullable(DateTime64(3)),
@@ -119,6 +125,8 @@ SELECT
     business_label,
     attempt_count,
     profile_id,
+    customer_email,
+    customer_phone,
     modified_at,
     created_at,
     last_synced,
@@ -0,0 +1,32 @@
+CREATE TABLE payment_throughput (
+    `merchant_id` LowCardinality(String),
+    `connector` LowCardinality(String),
+    `created_at` DateTime CODEC(T64, LZ4),
+    `attempts` AggregateFunction(uniqExact, String)
+) ENGINE = AggregatingMergeTree PARTITION BY toStartOfDay(created_at)
+ORDER BY
+    (created_at, merchant_id, connector) TTL created_at + toIntervalMonth(18) SETTINGS index_granularity = 8192;
+
+-- Every version of an attempt is sent to the queue, the attempts are counted once per second of
+-- creation by keeping their identifiers
+CREATE MATERIALIZED VIEW payment_throughput_mv TO payment_throughput (
+    `merchant_id` String,
+    `connector` String,
+    `created_at` DateTime,
+    `attempts` AggregateFunction(uniqExact, String)
+) AS
+SELECT
+    merchant_id,
+    assumeNotNull(connector) AS connector,
+    created_at,
+    uniqExactState(attempt_id) AS attempts
+FROM
+    payment_attempt_queue
+WHERE
+    length(_error) = 0
+    AND sign_flag = 1
+    AND isNotNull(connector)
+GROUP BY
+    merchant_id,
+    connector,
+    created_at;
@@ -29,6 +29,7 @@ use crate::{
     connector_events::events::ConnectorEventsResult,
     disputes::{filters::DisputeFilterRow, metrics::DisputeMetricRow},
     outgoing_webhook_event::events::OutgoingWebhookLogsResult,
+    payment_throughput::throughput::PaymentThroughputRow,
     sdk_events::events::SdkEventsResult,
     types::TableEngine,
 };
@@ -127,6 +128,10 @@ impl AnalyticsDataSource for ClickhouseClient {
             .change_context(QueryExecutionError::RowExtractionFailure)
     }
 
+    fn has_summaries() -> bool {
+        true
+    }
+
     fn get_table_engine(table: AnalyticsCollection) -> TableEngine {
         match table {
             AnalyticsCollection::Payment
@@ -145,7 +150,10 @@ impl AnalyticsDataSource for ClickhouseClient {
             | AnalyticsCollection::ConnectorEvents
             | AnalyticsCollection::ApiEventsAnalytics
             | AnalyticsCollection::OutgoingWebhookEvent
-            | AnalyticsCollection::ActivePaymentsAnalytics => TableEngine::BasicTree,
+            | AnalyticsCollection::ActivePaymentsAnalytics
+            | AnalyticsCollection::PaymentThroughput
+            | AnalyticsCollection::PaymentIntentStatusSummary
+            | AnalyticsCollection::PaymentDailySummary => TableEngine::BasicTree,
         }
     }
 }
@@ -184,6 +192,7 @@ impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
 }
 impl super::disputes::filters::DisputeFilterAnalytics for ClickhouseClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for ClickhouseClient {}
+impl super::payment_throughput::throughput::PaymentThroughputAnalytics for ClickhouseClient {}
 
 #[derive(Debug, serde::Serialize)]
 struct CkhQuery {
@@ -416,6 +425,16 @@ impl TryInto<ActivePaymentsMetricRow> for serde_json::Value {
     }
 }
 
+impl TryInto<PaymentThroughputRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PaymentThroughputRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PaymentThroughputRow in clickhouse results",
+        ))
+    }
+}
+
 impl ToSql<ClickhouseClient> for PrimitiveDateTime {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.assume_utc().unix_timestamp().to_string())
@@ -440,6 +459,9 @@ impl ToSql<ClickhouseClient> for AnalyticsCollection {
             Self::OutgoingWebhookEvent => Ok("outgoing_webhook_events_audit".to_string()),
             Self::Dispute => Ok("dispute".to_string()),
             Self::ActivePaymentsAnalytics => Ok("active_payments".to_string()),
+            Self::PaymentThroughput => Ok("payment_throughput".to_string()),
+            Self::PaymentIntentStatusSummary => Ok("payment_intent_status_summary".to_string()),
+            Self::PaymentDailySummary => Ok("payment_daily_summary".to_string()),
         }
     }
 }
@@ -13,6 +13,7 @@ pub mod metrics;
 pub mod opensearch;
 pub mod outgoing_webhook_event;
 pub mod payment_intents;
+pub mod payment_throughput;
 pub mod payments;
 mod query;
 pub mod refunds;
@@ -997,6 +998,8 @@ pub enum AnalyticsFlow {
     GetDisputeFilters,
     GetDisputeMetrics,
     GetSankey,
+    GetCheckoutFunnel,
+    GetPaymentThroughput,
 }
 
 impl FlowMetric for AnalyticsFlow {}
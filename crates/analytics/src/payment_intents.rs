@@ -3,6 +3,7 @@ mod core;
 pub mod filters;
 pub mod metrics;
 pub mod sankey;
+pub mod status_summary;
 pub mod types;
 pub use accumulator::{PaymentIntentMetricAccumulator, PaymentIntentMetricsAccumulator};
 
@@ -11,4 +12,4 @@ pub trait PaymentIntentAnalytics:
 {
 }
 
-pub use self::core::{get_filters, get_metrics, get_sankey};
+pub use self::core::{get_filters, get_metrics, get_sankey, get_status_counts};
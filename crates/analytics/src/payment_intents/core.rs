@@ -25,6 +25,7 @@ use super::{
     filters::{get_payment_intent_filter_for_dimension, PaymentIntentFilterRow},
     metrics::PaymentIntentMetricRow,
     sankey::{get_sankey_data, SessionizerRefundStatus},
+    status_summary::get_status_count_rows,
     PaymentIntentMetricsAccumulator,
 };
 use crate::{
@@ -46,6 +47,32 @@ pub enum TaskType {
     ),
 }
 
+/// Counts the payment intents created in the time range in every status, from the summary of the
+/// payment intent events
+#[instrument(skip_all)]
+pub async fn get_status_counts(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+) -> AnalyticsResult<Vec<(IntentStatus, i64)>> {
+    match pool {
+        AnalyticsProvider::Sqlx(_) => Err(AnalyticsError::NotImplemented(
+            "Payment status counts not implemented for sqlx",
+        ))?,
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool) => {
+            let status_count_rows = get_status_count_rows(ckh_pool, auth, time_range)
+                .await
+                .change_context(AnalyticsError::UnknownError)?;
+            Ok(status_count_rows
+                .into_iter()
+                .map(|row| (row.status.0, row.count))
+                .collect())
+        }
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn get_sankey(
     pool: &AnalyticsProvider,
@@ -0,0 +1,64 @@
+use common_enums::enums;
+use common_utils::{
+    errors::ParsingError,
+    types::{authentication::AuthInfo, TimeRange},
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use crate::{
+    clickhouse::ClickhouseClient,
+    query::{QueryBuilder, QueryFilter},
+    types::{AnalyticsCollection, DBEnumWrapper, MetricsError, MetricsResult},
+};
+
+#[derive(Debug, serde::Deserialize)]
+pub struct PaymentIntentStatusCountRow {
+    pub status: DBEnumWrapper<enums::IntentStatus>,
+    pub count: i64,
+}
+
+impl TryInto<PaymentIntentStatusCountRow> for serde_json::Value {
+    type Error = error_stack::Report<ParsingError>;
+
+    fn try_into(self) -> Result<PaymentIntentStatusCountRow, Self::Error> {
+        logger::debug!("Parsing PaymentIntentStatusCountRow from {:?}", self);
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PaymentIntentStatusCountRow in clickhouse results",
+        ))
+    }
+}
+
+/// Counts the payment intents in every status from the status summary, which is kept up to date
+/// from the payment intent events. The intents are summarized by the minute of their creation, so
+/// the time range is applied at the granularity of minutes.
+pub async fn get_status_count_rows(
+    clickhouse_client: &ClickhouseClient,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+) -> MetricsResult<Vec<PaymentIntentStatusCountRow>> {
+    let mut query_builder =
+        QueryBuilder::<ClickhouseClient>::new(AnalyticsCollection::PaymentIntentStatusSummary);
+    query_builder
+        .add_select_column("status")
+        .attach_printable("Error adding select clause")
+        .change_context(MetricsError::QueryBuildingError)?;
+    query_builder
+        .add_select_column("sum(payment_count) AS count")
+        .attach_printable("Error adding select clause")
+        .change_context(MetricsError::QueryBuildingError)?;
+    auth.set_filter_clause(&mut query_builder)
+        .change_context(MetricsError::QueryBuildingError)?;
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .change_context(MetricsError::QueryBuildingError)?;
+    query_builder
+        .add_group_by_clause("status")
+        .attach_printable("Error adding group by clause")
+        .change_context(MetricsError::QueryBuildingError)?;
+    query_builder
+        .execute_query::<PaymentIntentStatusCountRow, _>(clickhouse_client)
+        .await
+        .change_context(MetricsError::QueryBuildingError)?
+        .change_context(MetricsError::QueryExecutionFailure)
+}
@@ -0,0 +1,4 @@
+mod core;
+pub mod throughput;
+
+pub use self::core::get_payment_throughput;
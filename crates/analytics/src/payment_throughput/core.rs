@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use api_models::analytics::payment_throughput::{
+    GetPaymentThroughputRequest, PaymentThroughputBucket, PaymentThroughputResponse,
+    PaymentThroughputSeries, PaymentThroughputTrend,
+};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use super::throughput::{get_payment_throughput_rows, PaymentThroughputRow};
+use crate::{errors::AnalyticsResult, types::FiltersError, AnalyticsProvider};
+
+#[instrument(skip_all)]
+pub async fn get_payment_throughput(
+    pool: &AnalyticsProvider,
+    req: GetPaymentThroughputRequest,
+) -> AnalyticsResult<PaymentThroughputResponse> {
+    let rows = match pool {
+        AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
+            "Payment throughput not implemented for SQLX",
+        ))
+        .attach_printable("SQL Analytics is not implemented for Payment Throughput"),
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool) => {
+            get_payment_throughput_rows(&req, ckh_pool).await
+        }
+    }
+    .switch()?;
+
+    let mut rows_by_series: HashMap<_, Vec<PaymentThroughputRow>> = HashMap::new();
+    for row in rows {
+        rows_by_series
+            .entry((row.merchant_id.clone(), row.connector.clone()))
+            .or_default()
+            .push(row);
+    }
+
+    let bucket_size_in_secs = req.granularity.get_bucket_size_in_secs();
+    let mut series = rows_by_series
+        .into_iter()
+        .map(|((merchant_id, connector), rows)| {
+            let buckets = build_buckets(rows, bucket_size_in_secs);
+            let trend = build_trend(&buckets, req.forecast_buckets);
+            PaymentThroughputSeries {
+                merchant_id,
+                connector,
+                buckets,
+                trend,
+            }
+        })
+        .collect::<Vec<_>>();
+    series.sort_by(|a, b| {
+        a.merchant_id
+            .get_string_repr()
+            .cmp(b.merchant_id.get_string_repr())
+            .then_with(|| a.connector.cmp(&b.connector))
+    });
+
+    Ok(PaymentThroughputResponse {
+        granularity: req.granularity,
+        time_range: req.time_range,
+        series,
+    })
+}
+
+/// Orders the buckets of a series, adding empty buckets for the periods without transactions
+/// so that the growth is always measured against the preceding period
+fn build_buckets(
+    mut rows: Vec<PaymentThroughputRow>,
+    bucket_size_in_secs: u32,
+) -> Vec<PaymentThroughputBucket> {
+    rows.sort_by_key(|row| row.time_bucket);
+    let bucket_size = time::Duration::seconds(i64::from(bucket_size_in_secs));
+
+    let mut buckets: Vec<PaymentThroughputBucket> = Vec::with_capacity(rows.len());
+    for row in rows {
+        while let Some(next_time_bucket) = buckets
+            .last()
+            .map(|bucket| bucket.time_bucket + bucket_size)
+            .filter(|next_time_bucket| *next_time_bucket < row.time_bucket)
+        {
+            let previous_transactions = buckets.last().map(|bucket| bucket.total_transactions);
+            buckets.push(PaymentThroughputBucket {
+                time_bucket: next_time_bucket,
+                total_transactions: 0,
+                average_tps: 0.0,
+                p50_tps: 0,
+                p95_tps: 0,
+                p99_tps: 0,
+                peak_tps: 0,
+                growth_percentage: get_growth_percentage(previous_transactions, 0),
+            });
+        }
+
+        let previous_transactions = buckets.last().map(|bucket| bucket.total_transactions);
+        buckets.push(PaymentThroughputBucket {
+            time_bucket: row.time_bucket,
+            total_transactions: row.total_transactions,
+            average_tps: to_f64(row.total_transactions) / f64::from(bucket_size_in_secs),
+            p50_tps: row.p50_tps,
+            p95_tps: row.p95_tps,
+            p99_tps: row.p99_tps,
+            peak_tps: row.peak_tps,
+            growth_percentage: get_growth_percentage(previous_transactions, row.total_transactions),
+        });
+    }
+    buckets
+}
+
+/// Fits a line through the peak TPS of the buckets to project it over the forecast buckets
+fn build_trend(
+    buckets: &[PaymentThroughputBucket],
+    forecast_buckets: u32,
+) -> PaymentThroughputTrend {
+    let growth_percentages = buckets
+        .iter()
+        .filter_map(|bucket| bucket.growth_percentage)
+        .collect::<Vec<_>>();
+    let average_growth_percentage = u32::try_from(growth_percentages.len())
+        .ok()
+        .filter(|count| *count > 0)
+        .map(|count| growth_percentages.iter().sum::<f64>() / f64::from(count));
+
+    let points = (0u32..)
+        .zip(buckets)
+        .map(|(index, bucket)| (f64::from(index), to_f64(bucket.peak_tps)))
+        .collect::<Vec<_>>();
+    if points.is_empty() {
+        return PaymentThroughputTrend {
+            average_growth_percentage,
+            peak_tps_slope: 0.0,
+            projected_peak_tps: 0.0,
+        };
+    }
+
+    let count = f64::from(u32::try_from(points.len()).unwrap_or(u32::MAX));
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / count;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+    let covariance = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum::<f64>();
+    let variance = points
+        .iter()
+        .map(|(x, _)| (x - mean_x).powi(2))
+        .sum::<f64>();
+    let peak_tps_slope = if variance > 0.0 {
+        covariance / variance
+    } else {
+        0.0
+    };
+    let forecast_x = count - 1.0 + f64::from(forecast_buckets);
+    let projected_peak_tps = (mean_y + peak_tps_slope * (forecast_x - mean_x)).max(0.0);
+
+    PaymentThroughputTrend {
+        average_growth_percentage,
+        peak_tps_slope,
+        projected_peak_tps,
+    }
+}
+
+fn get_growth_percentage(previous: Option<u64>, current: u64) -> Option<f64> {
+    previous
+        .filter(|previous| *previous > 0)
+        .map(|previous| (to_f64(current) - to_f64(previous)) * 100.0 / to_f64(previous))
+}
+
+fn to_f64(value: u64) -> f64 {
+    f64::from(u32::try_from(value).unwrap_or(u32::MAX))
+}
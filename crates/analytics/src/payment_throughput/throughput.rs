@@ -0,0 +1,94 @@
+use api_models::analytics::{
+    payment_throughput::{GetPaymentThroughputRequest, ThroughputGranularity},
+    Granularity,
+};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, FiltersError, FiltersResult, LoadRow},
+};
+
+pub trait PaymentThroughputAnalytics: LoadRow<PaymentThroughputRow> {}
+
+/// Computes the TPS percentiles of every merchant and connector in each bucket, from the number
+/// of payment attempts created in every second
+pub async fn get_payment_throughput_rows<T>(
+    req: &GetPaymentThroughputRequest,
+    pool: &T,
+) -> FiltersResult<Vec<PaymentThroughputRow>>
+where
+    T: AnalyticsDataSource + PaymentThroughputAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> =
+        QueryBuilder::new(AnalyticsCollection::PaymentThroughput);
+
+    // The seconds are grouped again, as their partial counts may not have been merged yet
+    for column in ["merchant_id", "connector", "created_at"] {
+        query_builder.add_select_column(column).switch()?;
+        query_builder.add_group_by_clause(column).switch()?;
+    }
+    query_builder
+        .add_select_column("uniqExactMerge(attempts) AS transaction_count")
+        .switch()?;
+
+    req.time_range
+        .set_filter_clause(&mut query_builder)
+        .switch()?;
+    if !req.merchant_ids.is_empty() {
+        query_builder
+            .add_filter_in_range_clause("merchant_id", &req.merchant_ids)
+            .switch()?;
+    }
+    if !req.connectors.is_empty() {
+        query_builder
+            .add_filter_in_range_clause("connector", &req.connectors)
+            .switch()?;
+    }
+
+    let time_bucket = match req.granularity {
+        ThroughputGranularity::Hourly => "toStartOfHour(created_at) AS time_bucket",
+        ThroughputGranularity::Daily => "toStartOfDay(created_at) AS time_bucket",
+    };
+    for column in [
+        "merchant_id",
+        "connector",
+        time_bucket,
+        "sum(transaction_count) AS total_transactions",
+        "quantileExact(0.5)(transaction_count) AS p50_tps",
+        "quantileExact(0.95)(transaction_count) AS p95_tps",
+        "quantileExact(0.99)(transaction_count) AS p99_tps",
+        "max(transaction_count) AS peak_tps",
+    ] {
+        query_builder.add_outer_select_column(column).switch()?;
+    }
+    for column in ["merchant_id", "connector", "time_bucket"] {
+        query_builder.add_outer_group_by_clause(column).switch()?;
+    }
+
+    query_builder
+        .execute_query::<PaymentThroughputRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct PaymentThroughputRow {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub connector: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub time_bucket: PrimitiveDateTime,
+    pub total_transactions: u64,
+    pub p50_tps: u64,
+    pub p95_tps: u64,
+    pub p99_tps: u64,
+    pub peak_tps: u64,
+}
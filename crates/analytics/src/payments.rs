@@ -2,6 +2,7 @@ pub mod accumulator;
 mod core;
 pub mod distribution;
 pub mod filters;
+pub mod funnel;
 pub mod metrics;
 pub mod types;
 pub use accumulator::{
@@ -13,4 +14,4 @@ pub trait PaymentAnalytics:
 {
 }
 
-pub use self::core::{get_filters, get_metrics};
+pub use self::core::{get_checkout_funnel, get_filters, get_metrics};
@@ -6,12 +6,13 @@ use api_models::analytics::{
         MetricsBucketResponse, PaymentDimensions, PaymentDistributions, PaymentMetrics,
         PaymentMetricsBucketIdentifier,
     },
-    FilterValue, GetPaymentFiltersRequest, GetPaymentMetricRequest, PaymentFiltersResponse,
-    PaymentsAnalyticsMetadata, PaymentsMetricsResponse,
+    CheckoutFunnel, CheckoutFunnelResponse, FilterValue, GetPaymentFiltersRequest,
+    GetPaymentMetricRequest, PaymentFiltersResponse, PaymentsAnalyticsMetadata,
+    PaymentsMetricsResponse,
 };
 use bigdecimal::ToPrimitive;
 use common_enums::Currency;
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, types::TimeRange};
 use currency_conversion::{conversion::convert, types::ExchangeRates};
 use error_stack::ResultExt;
 use router_env::{
@@ -23,6 +24,7 @@ use router_env::{
 use super::{
     distribution::PaymentDistributionRow,
     filters::{get_payment_filter_for_dimension, PaymentFilterRow},
+    funnel::{get_checkout_funnel_data, CheckoutFunnelAccumulator},
     metrics::PaymentMetricRow,
     PaymentMetricsAccumulator,
 };
@@ -408,3 +410,46 @@ pub async fn get_filters(
     }
     Ok(res)
 }
+
+/// Computes the checkout funnel of the payments created in the time range, for every profile and
+/// payment method
+#[instrument(skip_all)]
+pub async fn get_checkout_funnel(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    req: TimeRange,
+) -> AnalyticsResult<CheckoutFunnelResponse> {
+    match pool {
+        AnalyticsProvider::Sqlx(_) => Err(AnalyticsError::NotImplemented(
+            "Checkout funnel not implemented for sqlx",
+        ))?,
+        AnalyticsProvider::Clickhouse(ckh_pool)
+        | AnalyticsProvider::CombinedCkh(_, ckh_pool)
+        | AnalyticsProvider::CombinedSqlx(_, ckh_pool) => {
+            let funnel_rows = get_checkout_funnel_data(ckh_pool, auth, &req)
+                .await
+                .change_context(AnalyticsError::UnknownError)?;
+            let mut funnels: HashMap<(Option<String>, Option<String>), CheckoutFunnelAccumulator> =
+                HashMap::new();
+            for row in funnel_rows {
+                funnels
+                    .entry((row.profile_id.clone(), row.payment_method.clone()))
+                    .or_default()
+                    .add_row(&row);
+            }
+
+            Ok(CheckoutFunnelResponse {
+                funnels: funnels
+                    .into_iter()
+                    .map(
+                        |((profile_id, payment_method), accumulator)| CheckoutFunnel {
+                            profile_id,
+                            payment_method,
+                            stages: accumulator.collect(),
+                        },
+                    )
+                    .collect(),
+            })
+        }
+    }
+}
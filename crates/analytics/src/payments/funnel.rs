@@ -0,0 +1,263 @@
+use api_models::analytics::{CheckoutFunnelStage, CheckoutFunnelStageCount};
+use bigdecimal::ToPrimitive;
+use common_enums::enums;
+use common_utils::{
+    errors::ParsingError,
+    types::{authentication::AuthInfo, TimeRange},
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use crate::{
+    clickhouse::ClickhouseClient,
+    query::{QueryBuilder, QueryFilter},
+    types::{AnalyticsCollection, MetricsError, MetricsResult},
+};
+
+/// Every status of payment attempts, to be mapped to the stage of the checkout funnel reached
+const ATTEMPT_STATUSES: [enums::AttemptStatus; 24] = [
+    enums::AttemptStatus::Started,
+    enums::AttemptStatus::AuthenticationFailed,
+    enums::AttemptStatus::RouterDeclined,
+    enums::AttemptStatus::AuthenticationPending,
+    enums::AttemptStatus::AuthenticationSuccessful,
+    enums::AttemptStatus::Authorized,
+    enums::AttemptStatus::AuthorizationFailed,
+    enums::AttemptStatus::Charged,
+    enums::AttemptStatus::Authorizing,
+    enums::AttemptStatus::CodInitiated,
+    enums::AttemptStatus::Voided,
+    enums::AttemptStatus::VoidInitiated,
+    enums::AttemptStatus::CaptureInitiated,
+    enums::AttemptStatus::CaptureFailed,
+    enums::AttemptStatus::VoidFailed,
+    enums::AttemptStatus::AutoRefunded,
+    enums::AttemptStatus::PartialCharged,
+    enums::AttemptStatus::PartialChargedAndChargeable,
+    enums::AttemptStatus::Unresolved,
+    enums::AttemptStatus::Pending,
+    enums::AttemptStatus::Failure,
+    enums::AttemptStatus::PaymentMethodAwaited,
+    enums::AttemptStatus::ConfirmationAwaited,
+    enums::AttemptStatus::DeviceDataCollectionPending,
+];
+
+/// The furthest stage of the checkout funnel reached by a confirmed attempt with the status.
+/// Attempts pass through authentication once sent to the connector, unless they are abandoned,
+/// declined by the router or fail on authentication.
+fn get_confirmed_attempt_stage(status: enums::AttemptStatus) -> CheckoutFunnelStage {
+    match status {
+        enums::AttemptStatus::Started
+        | enums::AttemptStatus::PaymentMethodAwaited
+        | enums::AttemptStatus::ConfirmationAwaited
+        | enums::AttemptStatus::DeviceDataCollectionPending
+        | enums::AttemptStatus::AuthenticationPending
+        | enums::AttemptStatus::AuthenticationFailed
+        | enums::AttemptStatus::RouterDeclined => CheckoutFunnelStage::Confirmed,
+        enums::AttemptStatus::Authorized
+        | enums::AttemptStatus::Charged
+        | enums::AttemptStatus::PartialCharged
+        | enums::AttemptStatus::PartialChargedAndChargeable
+        | enums::AttemptStatus::CaptureInitiated
+        | enums::AttemptStatus::CaptureFailed
+        | enums::AttemptStatus::AutoRefunded
+        | enums::AttemptStatus::VoidInitiated
+        | enums::AttemptStatus::VoidFailed
+        | enums::AttemptStatus::Voided => CheckoutFunnelStage::Authorized,
+        enums::AttemptStatus::AuthenticationSuccessful
+        | enums::AttemptStatus::AuthorizationFailed
+        | enums::AttemptStatus::Authorizing
+        | enums::AttemptStatus::CodInitiated
+        | enums::AttemptStatus::Unresolved
+        | enums::AttemptStatus::Pending
+        | enums::AttemptStatus::Failure => CheckoutFunnelStage::Authenticated,
+    }
+}
+
+/// Rank of the stage in the checkout funnel, the later stages having the higher ranks
+fn get_stage_rank(stage: CheckoutFunnelStage) -> u8 {
+    match stage {
+        CheckoutFunnelStage::Created => 0,
+        CheckoutFunnelStage::Confirmed => 1,
+        CheckoutFunnelStage::Authenticated => 2,
+        CheckoutFunnelStage::Authorized => 3,
+    }
+}
+
+/// Expression of the rank of the furthest stage of the checkout funnel reached by an attempt
+fn get_attempt_stage_rank_expression() -> String {
+    let get_statuses = |stage| {
+        ATTEMPT_STATUSES
+            .iter()
+            .filter(|status| get_confirmed_attempt_stage(**status) == stage)
+            .map(|status| format!("'{status}'"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+
+    format!(
+        "multiIf(NOT confirm, {}, status IN ({}), {}, status IN ({}), {}, {})",
+        get_stage_rank(CheckoutFunnelStage::Created),
+        get_statuses(CheckoutFunnelStage::Authorized),
+        get_stage_rank(CheckoutFunnelStage::Authorized),
+        get_statuses(CheckoutFunnelStage::Authenticated),
+        get_stage_rank(CheckoutFunnelStage::Authenticated),
+        get_stage_rank(CheckoutFunnelStage::Confirmed),
+    )
+}
+
+/// Number of payments of a profile and payment method whose furthest stage of the checkout funnel
+/// is the stage of the row
+#[derive(Debug, serde::Deserialize)]
+pub struct CheckoutFunnelRow {
+    pub profile_id: Option<String>,
+    pub payment_method: Option<String>,
+    pub stage: u8,
+    pub count: i64,
+}
+
+impl CheckoutFunnelRow {
+    fn get_reached_stage(&self) -> CheckoutFunnelStage {
+        [
+            CheckoutFunnelStage::Authorized,
+            CheckoutFunnelStage::Authenticated,
+            CheckoutFunnelStage::Confirmed,
+        ]
+        .into_iter()
+        .find(|stage| get_stage_rank(*stage) == self.stage)
+        .unwrap_or(CheckoutFunnelStage::Created)
+    }
+}
+
+/// Number of payments reaching each stage of the checkout funnel, for a profile and payment method
+#[derive(Debug, Default)]
+pub struct CheckoutFunnelAccumulator {
+    created: i64,
+    confirmed: i64,
+    authenticated: i64,
+    authorized: i64,
+}
+
+impl CheckoutFunnelAccumulator {
+    pub fn add_row(&mut self, row: &CheckoutFunnelRow) {
+        let reached_stage = row.get_reached_stage();
+        self.created += row.count;
+        if reached_stage == CheckoutFunnelStage::Created {
+            return;
+        }
+        self.confirmed += row.count;
+        if reached_stage == CheckoutFunnelStage::Confirmed {
+            return;
+        }
+        self.authenticated += row.count;
+        if reached_stage == CheckoutFunnelStage::Authenticated {
+            return;
+        }
+        self.authorized += row.count;
+    }
+
+    pub fn collect(self) -> Vec<CheckoutFunnelStageCount> {
+        let get_drop_off_rate = |previous: i64, current: i64| {
+            (previous > 0).then(|| {
+                (previous - current).to_f64().unwrap_or_default() / previous.to_f64().unwrap_or(1.0)
+            })
+        };
+
+        vec![
+            CheckoutFunnelStageCount {
+                stage: CheckoutFunnelStage::Created,
+                count: self.created,
+                drop_off_rate: None,
+            },
+            CheckoutFunnelStageCount {
+                stage: CheckoutFunnelStage::Confirmed,
+                count: self.confirmed,
+                drop_off_rate: get_drop_off_rate(self.created, self.confirmed),
+            },
+            CheckoutFunnelStageCount {
+                stage: CheckoutFunnelStage::Authenticated,
+                count: self.authenticated,
+                drop_off_rate: get_drop_off_rate(self.confirmed, self.authenticated),
+            },
+            CheckoutFunnelStageCount {
+                stage: CheckoutFunnelStage::Authorized,
+                count: self.authorized,
+                drop_off_rate: get_drop_off_rate(self.authenticated, self.authorized),
+            },
+        ]
+    }
+}
+
+impl TryInto<CheckoutFunnelRow> for serde_json::Value {
+    type Error = error_stack::Report<ParsingError>;
+
+    fn try_into(self) -> Result<CheckoutFunnelRow, Self::Error> {
+        logger::debug!("Parsing CheckoutFunnelRow from {:?}", self);
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse CheckoutFunnel in clickhouse results",
+        ))
+    }
+}
+
+/// Fetches the number of payments reaching each stage of the checkout funnel. The attempts are
+/// grouped by payment, so that retried payments are counted once, at the furthest stage reached by
+/// any of their attempts. The payment method of a payment is the one of any of its attempts, as
+/// attempts which are not confirmed yet have no payment method. Payments abandoned before a payment
+/// method was selected are counted without a payment method.
+pub async fn get_checkout_funnel_data(
+    clickhouse_client: &ClickhouseClient,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+) -> MetricsResult<Vec<CheckoutFunnelRow>> {
+    let mut query_builder = QueryBuilder::<ClickhouseClient>::new(AnalyticsCollection::Payment);
+    let stage_rank = format!("max({}) as stage", get_attempt_stage_rank_expression());
+
+    for column in [
+        "payment_id",
+        "profile_id",
+        "any(payment_method) as payment_method",
+        stage_rank.as_str(),
+    ] {
+        query_builder
+            .add_select_column(column)
+            .attach_printable("Error adding select clause")
+            .change_context(MetricsError::QueryBuildingError)?;
+    }
+
+    auth.set_filter_clause(&mut query_builder)
+        .change_context(MetricsError::QueryBuildingError)?;
+
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .change_context(MetricsError::QueryBuildingError)?;
+
+    for column in ["payment_id", "profile_id"] {
+        query_builder
+            .add_group_by_clause(column)
+            .attach_printable("Error adding group by clause")
+            .change_context(MetricsError::QueryBuildingError)?;
+    }
+
+    for column in ["profile_id", "payment_method", "stage", "count(*) as count"] {
+        query_builder
+            .add_outer_select_column(column)
+            .attach_printable("Error adding outer select clause")
+            .change_context(MetricsError::QueryBuildingError)?;
+    }
+
+    for column in ["profile_id", "payment_method", "stage"] {
+        query_builder
+            .add_outer_group_by_clause(column)
+            .attach_printable("Error adding outer group by clause")
+            .change_context(MetricsError::QueryBuildingError)?;
+    }
+
+    query_builder
+        .execute_query::<CheckoutFunnelRow, _>(clickhouse_client)
+        .await
+        .change_context(MetricsError::QueryBuildingError)?
+        .change_context(MetricsError::QueryExecutionFailure)?
+        .into_iter()
+        .map(Ok)
+        .collect()
+}
@@ -58,6 +58,77 @@ pub struct PaymentMetricRow {
 
 pub trait PaymentMetricAnalytics: LoadRow<PaymentMetricRow> {}
 
+/// Whether the metrics can be loaded from the payment daily summary instead of the payment
+/// attempts. The summary holds the totals of the attempts of a profile created on a day, by
+/// status, connector and currency, so it serves metrics requested for whole days by those
+/// dimensions only.
+fn is_served_by_daily_summary<T: AnalyticsDataSource>(
+    dimensions: &[PaymentDimensions],
+    filters: &PaymentFilters,
+    granularity: &Option<Granularity>,
+    time_range: &TimeRange,
+) -> bool {
+    let is_summary_dimension = |dimension: &PaymentDimensions| {
+        matches!(
+            dimension,
+            PaymentDimensions::PaymentStatus
+                | PaymentDimensions::Connector
+                | PaymentDimensions::Currency
+                | PaymentDimensions::ProfileId
+                | PaymentDimensions::MerchantId
+        )
+    };
+    let has_only_summary_filters = filters.auth_type.is_empty()
+        && filters.payment_method.is_empty()
+        && filters.payment_method_type.is_empty()
+        && filters.client_source.is_empty()
+        && filters.client_version.is_empty()
+        && filters.card_network.is_empty()
+        && filters.card_last_4.is_empty()
+        && filters.card_issuer.is_empty()
+        && filters.error_reason.is_empty();
+    let is_end_of_day = |date_time: PrimitiveDateTime| {
+        (date_time.hour(), date_time.minute(), date_time.second()) == (23, 59, 59)
+    };
+    let is_for_whole_days = time_range.start_time.time() == time::Time::MIDNIGHT
+        && time_range.end_time.map_or(true, is_end_of_day);
+
+    T::has_summaries()
+        && dimensions.iter().all(is_summary_dimension)
+        && has_only_summary_filters
+        && matches!(granularity, None | Some(Granularity::OneDay))
+        && is_for_whole_days
+}
+
+/// The collection the metrics are loaded from, along with the column holding the number of
+/// attempts of a row and the column holding the amount of the attempts of a row
+fn get_metrics_collection<T: AnalyticsDataSource>(
+    dimensions: &[PaymentDimensions],
+    filters: &PaymentFilters,
+    granularity: &Option<Granularity>,
+    time_range: &TimeRange,
+) -> (AnalyticsCollection, Aggregate<&'static str>, &'static str) {
+    if is_served_by_daily_summary::<T>(dimensions, filters, granularity, time_range) {
+        (
+            AnalyticsCollection::PaymentDailySummary,
+            Aggregate::Sum {
+                field: "payment_count",
+                alias: Some("count"),
+            },
+            "total_amount",
+        )
+    } else {
+        (
+            AnalyticsCollection::Payment,
+            Aggregate::Count {
+                field: None,
+                alias: Some("count"),
+            },
+            "amount",
+        )
+    }
+}
+
 #[async_trait::async_trait]
 pub trait PaymentMetric<T>
 where
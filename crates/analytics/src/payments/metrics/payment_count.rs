@@ -37,18 +37,15 @@ where
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<HashSet<(PaymentMetricsBucketIdentifier, PaymentMetricRow)>> {
-        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+        let (collection, count_aggregate, _) =
+            super::get_metrics_collection::<T>(dimensions, filters, granularity, time_range);
+        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(collection);
 
         for dim in dimensions.iter() {
             query_builder.add_select_column(dim).switch()?;
         }
 
-        query_builder
-            .add_select_column(Aggregate::Count {
-                field: None,
-                alias: Some("count"),
-            })
-            .switch()?;
+        query_builder.add_select_column(count_aggregate).switch()?;
         query_builder
             .add_select_column(Aggregate::Min {
                 field: "created_at",
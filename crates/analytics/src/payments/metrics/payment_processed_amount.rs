@@ -38,7 +38,9 @@ where
         time_range: &TimeRange,
         pool: &T,
     ) -> MetricsResult<HashSet<(PaymentMetricsBucketIdentifier, PaymentMetricRow)>> {
-        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+        let (collection, _, amount_field) =
+            super::get_metrics_collection::<T>(dimensions, filters, granularity, time_range);
+        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(collection);
 
         for dim in dimensions.iter() {
             query_builder.add_select_column(dim).switch()?;
@@ -46,7 +48,7 @@ where
 
         query_builder
             .add_select_column(Aggregate::Sum {
-                field: "amount",
+                field: amount_field,
                 alias: Some("total"),
             })
             .switch()?;
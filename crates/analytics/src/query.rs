@@ -410,6 +410,7 @@ where
     having: Option<Vec<(String, FilterTypes, String)>>,
     limit_by: Option<LimitByClause>,
     outer_select: Vec<String>,
+    outer_group_by: Vec<String>,
     top_n: Option<TopN>,
     table: AnalyticsCollection,
     distinct: bool,
@@ -557,6 +558,7 @@ where
             having: Default::default(),
             limit_by: Default::default(),
             outer_select: Default::default(),
+            outer_group_by: Default::default(),
             top_n: Default::default(),
             table,
             distinct: Default::default(),
@@ -803,6 +805,16 @@ where
         Ok(())
     }
 
+    pub fn add_outer_group_by_clause(&mut self, column: impl ToSql<T>) -> QueryResult<()> {
+        self.outer_group_by.push(
+            column
+                .to_sql(&self.table_engine)
+                .change_context(QueryBuildingError::SqlSerializeError)
+                .attach_printable("Error serializing outer group by field")?,
+        );
+        Ok(())
+    }
+
     pub fn get_filter_type_clause(&self) -> Option<String> {
         self.having.as_ref().map(|vec| {
             vec.iter()
@@ -883,6 +895,10 @@ where
                 format!("SELECT {} FROM (", &self.get_outer_select_clause()).as_str(),
             );
             query.push_str(") _");
+            if !self.outer_group_by.is_empty() {
+                query.push_str(" GROUP BY ");
+                query.push_str(&self.outer_group_by.join(", "));
+            }
         }
 
         if let Some(top_n) = &self.top_n {
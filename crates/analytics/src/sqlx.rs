@@ -924,6 +924,14 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
                 .attach_printable("ApiEvents table is not implemented for Sqlx"))?,
             Self::ActivePaymentsAnalytics => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("ActivePaymentsAnalytics table is not implemented for Sqlx"))?,
+            Self::PaymentThroughput => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("PaymentThroughput table is not implemented for Sqlx"))?,
+            Self::PaymentIntentStatusSummary => Err(error_stack::report!(
+                ParsingError::UnknownError
+            )
+            .attach_printable("PaymentIntentStatusSummary table is not implemented for Sqlx"))?,
+            Self::PaymentDailySummary => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("PaymentDailySummary table is not implemented for Sqlx"))?,
             Self::OutgoingWebhookEvent => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("OutgoingWebhookEvents table is not implemented for Sqlx"))?,
             Self::Dispute => Ok("dispute".to_string()),
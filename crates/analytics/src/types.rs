@@ -40,6 +40,9 @@ pub enum AnalyticsCollection {
     Dispute,
     ApiEventsAnalytics,
     ActivePaymentsAnalytics,
+    PaymentThroughput,
+    PaymentIntentStatusSummary,
+    PaymentDailySummary,
 }
 
 #[allow(dead_code)]
@@ -92,6 +95,12 @@ where
     fn get_table_engine(_table: AnalyticsCollection) -> TableEngine {
         TableEngine::BasicTree
     }
+
+    /// Whether the summaries maintained from the event stream, such as the payment daily summary,
+    /// are available in the data source
+    fn has_summaries() -> bool {
+        false
+    }
 }
 
 pub trait LoadRow<T>
@@ -108,6 +108,12 @@ pub struct MerchantAccountCreate {
     /// Default payment method collect link config
     #[schema(value_type = Option<BusinessCollectLinkConfig>)]
     pub pm_collect_link_config: Option<BusinessCollectLinkConfig>,
+
+    /// The region in which the data of the merchant account is kept, which must be the region
+    /// served by the deployment the merchant account is created on. Defaults to that region when
+    /// data residency is enabled, and can not be changed afterwards.
+    #[schema(max_length = 64, example = "eu")]
+    pub data_region: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -522,6 +528,10 @@ pub struct MerchantAccountResponse {
     /// Default payment method collect link config
     #[schema(value_type = Option<BusinessCollectLinkConfig>)]
     pub pm_collect_link_config: Option<BusinessCollectLinkConfig>,
+
+    /// The region in which the data of the merchant account is kept
+    #[schema(max_length = 64, example = "eu")]
+    pub data_region: Option<String>,
 }
 
 #[cfg(feature = "v2")]
@@ -636,6 +646,20 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// The format in which outgoing webhooks are posted to the webhook endpoint
+    #[schema(value_type = Option<OutgoingWebhookFormat>, example = "adyen")]
+    pub webhook_format: Option<api_enums::OutgoingWebhookFormat>,
+
+    /// The event types for which webhooks are posted to the webhook endpoint. Webhooks are posted
+    /// for all event types if not set
+    #[schema(value_type = Option<Vec<EventType>>, example = json!(["payment_succeeded", "refund_succeeded"]))]
+    pub enabled_events: Option<Vec<api_enums::EventType>>,
+
+    /// The version of the schema of the outgoing webhook payloads posted to the webhook endpoint.
+    /// The latest version is used if not set
+    #[schema(value_type = Option<OutgoingWebhookSchemaVersion>, example = "v1")]
+    pub schema_version: Option<api_enums::OutgoingWebhookSchemaVersion>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -648,6 +672,117 @@ pub struct MerchantAccountDeleteResponse {
     pub deleted: bool,
 }
 
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantAccountStatusUpdateRequest {
+    /// The status the merchant account transitions to. Closed accounts can not be reopened.
+    #[schema(value_type = MerchantAccountStatus, example = "suspended")]
+    pub status: api_enums::MerchantAccountStatus,
+    /// The reason for the transition
+    #[schema(max_length = 255, example = "Suspected fraudulent activity")]
+    pub reason: Option<String>,
+    /// Number of days after which a closed merchant account and its data are purged, defaults to
+    /// 90 days
+    #[schema(example = 90)]
+    pub purge_after_days: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MerchantAccountStatusUpdateRequestWithId {
+    pub merchant_id: id_type::MerchantId,
+    #[serde(flatten)]
+    pub request: MerchantAccountStatusUpdateRequest,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MerchantAccountStatusResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The current status of the merchant account
+    #[schema(value_type = MerchantAccountStatus, example = "active")]
+    pub status: api_enums::MerchantAccountStatus,
+    /// The reason for the last transition
+    pub reason: Option<String>,
+    /// The time at which the last transition happened
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub updated_at: Option<time::PrimitiveDateTime>,
+    /// The time at which a closed merchant account is purged
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub purge_scheduled_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantExternalVaultRequest {
+    /// The external tokenization service in which the card numbers of the merchant account are
+    /// kept, must be one of the configured providers. The card vault is used when not provided.
+    /// Cards saved while a provider is set are stored as aliases of the provider, and can only be
+    /// charged while the provider is set.
+    #[schema(max_length = 64, example = "vgs")]
+    pub provider: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MerchantExternalVaultRequestWithId {
+    pub merchant_id: id_type::MerchantId,
+    #[serde(flatten)]
+    pub request: MerchantExternalVaultRequest,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MerchantExternalVaultResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The external tokenization service in which the card numbers of the merchant account are
+    /// kept, the card vault is used when absent
+    #[schema(example = "vgs")]
+    pub provider: Option<String>,
+    /// The time at which the provider was last changed
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub updated_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantKeyDestroyRequest {
+    /// The reason for destroying the key, such as the closure of the merchant account or an
+    /// erasure request
+    #[schema(max_length = 255, example = "Erasure request of the merchant")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct MerchantKeyDestroyRequestWithId {
+    pub merchant_id: id_type::MerchantId,
+    #[serde(flatten)]
+    pub request: MerchantKeyDestroyRequest,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct MerchantKeyResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The status of the data encryption key of the merchant account
+    #[schema(value_type = MerchantKeyStatus, example = "active")]
+    pub status: api_enums::MerchantKeyStatus,
+    /// The time at which the key was created, absent once the key is destroyed
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<time::PrimitiveDateTime>,
+    /// The time at which the key was destroyed
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub destroyed_at: Option<time::PrimitiveDateTime>,
+    /// The reason for destroying the key
+    pub reason: Option<String>,
+}
+
 #[derive(Default, Debug, Deserialize, Serialize)]
 pub struct MerchantId {
     pub merchant_id: id_type::MerchantId,
@@ -1258,6 +1393,45 @@ impl MerchantConnectorResponse {
     }
 }
 
+/// The outcome of the latest verification of the credentials of a merchant connector account
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorCredentialStatus {
+    /// The connector accepted the credentials
+    Valid,
+    /// The connector rejected the credentials, the connector account is excluded from routing
+    Invalid,
+}
+
+/// A change in the health of the credentials of a merchant connector account, as found by the
+/// periodic verification of the credentials
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ConnectorCredentialHealthResponse {
+    /// Unique ID of the merchant connector account
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR", value_type = String)]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// Name of the Connector
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector_name: String,
+
+    /// The business profile of the connector account
+    #[schema(value_type = String, example = "pro_abcdefghijklmnopqrstuvwxyz")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The status of the credentials
+    #[schema(value_type = ConnectorCredentialStatus, example = "invalid")]
+    pub status: ConnectorCredentialStatus,
+
+    /// The error returned while verifying the credentials, if the credentials are invalid
+    pub error_message: Option<String>,
+
+    /// The time at which the credentials were verified
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub checked_at: time::PrimitiveDateTime,
+}
+
 #[cfg(feature = "v1")]
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
@@ -23,6 +23,7 @@ pub mod disputes;
 pub mod frm;
 pub mod outgoing_webhook_event;
 pub mod payment_intents;
+pub mod payment_throughput;
 pub mod payments;
 pub mod refunds;
 pub mod sdk_events;
@@ -461,3 +462,38 @@ pub struct SankeyResponse {
     pub merchant_awaited: i64,
     pub confirmation_awaited: i64,
 }
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutFunnelStage {
+    Created,
+    Confirmed,
+    Authenticated,
+    Authorized,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CheckoutFunnelStageCount {
+    pub stage: CheckoutFunnelStage,
+    /// Number of payments which reached the stage
+    pub count: i64,
+    /// Share of the payments of the previous stage which did not reach this stage
+    pub drop_off_rate: Option<f64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CheckoutFunnel {
+    pub profile_id: Option<String>,
+    /// The payment method of the payments, none for payments abandoned before a payment method was
+    /// selected
+    pub payment_method: Option<String>,
+    pub stages: Vec<CheckoutFunnelStageCount>,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct CheckoutFunnelResponse {
+    pub funnels: Vec<CheckoutFunnel>,
+}
@@ -0,0 +1,87 @@
+use common_utils::id_type::MerchantId;
+use time::PrimitiveDateTime;
+
+use super::TimeRange;
+
+#[derive(Clone, Copy, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThroughputGranularity {
+    #[default]
+    Hourly,
+    Daily,
+}
+
+impl ThroughputGranularity {
+    /// The length of a bucket, in seconds
+    pub fn get_bucket_size_in_secs(&self) -> u32 {
+        match self {
+            Self::Hourly => 3600,
+            Self::Daily => 86400,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPaymentThroughputRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub granularity: ThroughputGranularity,
+    /// Restricts the report to these merchants, all the merchants are included when empty
+    #[serde(default)]
+    pub merchant_ids: Vec<MerchantId>,
+    /// Restricts the report to these connectors, all the connectors are included when empty
+    #[serde(default)]
+    pub connectors: Vec<String>,
+    /// The number of buckets after the time range for which the peak TPS is projected
+    #[serde(default = "default_forecast_buckets")]
+    pub forecast_buckets: u32,
+}
+
+fn default_forecast_buckets() -> u32 {
+    1
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentThroughputResponse {
+    pub granularity: ThroughputGranularity,
+    pub time_range: TimeRange,
+    pub series: Vec<PaymentThroughputSeries>,
+}
+
+/// The throughput of a merchant on a connector
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentThroughputSeries {
+    pub merchant_id: MerchantId,
+    pub connector: String,
+    pub buckets: Vec<PaymentThroughputBucket>,
+    pub trend: PaymentThroughputTrend,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentThroughputBucket {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub time_bucket: PrimitiveDateTime,
+    pub total_transactions: u64,
+    /// Transactions per second over the whole bucket
+    pub average_tps: f64,
+    /// The percentiles and the peak of the transactions per second, over the seconds of the
+    /// bucket which had at least one transaction
+    pub p50_tps: u64,
+    pub p95_tps: u64,
+    pub p99_tps: u64,
+    pub peak_tps: u64,
+    /// Change of the total transactions from the previous bucket, in percent
+    pub growth_percentage: Option<f64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentThroughputTrend {
+    /// Average change of the total transactions between consecutive buckets, in percent
+    pub average_growth_percentage: Option<f64>,
+    /// Change of the peak TPS per bucket, fitted over the time range
+    pub peak_tps_slope: f64,
+    /// Peak TPS expected at the end of the forecast buckets, to be used when provisioning the
+    /// rate limits of the connector
+    pub projected_peak_tps: f64,
+}
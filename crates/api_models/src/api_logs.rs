@@ -0,0 +1,94 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// Summary of an API request made by a merchant. Request and response bodies are not recorded, so
+/// that the summary does not contain any personally identifiable information.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ApiLogResponse {
+    /// The identifier of the request, as returned in the `x-request-id` header
+    pub request_id: String,
+
+    /// The API flow the request was served by
+    #[schema(example = "PaymentsCreate")]
+    pub api_flow: String,
+
+    /// The HTTP method of the request
+    #[schema(example = "POST")]
+    pub http_method: String,
+
+    /// The path of the request, without the query parameters
+    #[schema(example = "/payments")]
+    pub url_path: String,
+
+    /// The HTTP status code of the response
+    #[schema(example = 200)]
+    pub status_code: i64,
+
+    /// Time taken to serve the request, in milliseconds
+    pub latency: u128,
+
+    /// The error code, if the request failed
+    #[schema(example = "IR_06")]
+    pub error_code: Option<String>,
+
+    /// The identifier of the API key used to authenticate the request
+    #[schema(value_type = Option<String>)]
+    pub api_key_id: Option<common_utils::id_type::ApiKeyId>,
+
+    /// The time at which the request was received
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ApiLogsListConstraints {
+    /// Only include requests whose path starts with this prefix
+    pub url_path: Option<String>,
+
+    /// Only include requests which were responded to with this HTTP status code
+    pub status_code: Option<i64>,
+
+    /// Only include requests which failed with this error code
+    pub error_code: Option<String>,
+
+    /// Only include requests authenticated with this API key
+    #[schema(value_type = Option<String>)]
+    pub api_key_id: Option<common_utils::id_type::ApiKeyId>,
+
+    /// Only include requests received after this time
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Only include requests received before this time
+    #[schema(value_type = Option<PrimitiveDateTime>)]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// The maximum number of requests to include, defaults to 100
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ApiLogsListResponse {
+    /// The number of requests included in the response
+    pub count: usize,
+
+    /// The requests, with the most recent request first
+    pub data: Vec<ApiLogResponse>,
+}
+
+impl ApiEventMetric for ApiLogsListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ApiLogsListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
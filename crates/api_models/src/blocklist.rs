@@ -9,6 +9,7 @@ pub enum BlocklistRequest {
     CardBin(String),
     Fingerprint(String),
     ExtendedCardBin(String),
+    Email(String),
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -59,6 +60,98 @@ fn default_list_limit() -> u16 {
     10
 }
 
+/// Request to synchronize the blocklist with the entries of an external risk system. The additions
+/// and removals are applied together or not at all, as a new version of the blocklist.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BlocklistSyncRequest {
+    /// The name of the external system the entries are synchronized from
+    #[schema(example = "risk_platform")]
+    pub source: String,
+    /// The version of the blocklist the changes were computed against. The synchronization is
+    /// rejected if the blocklist was synchronized since.
+    pub base_version: Option<u32>,
+    /// The version of the entries in the external system
+    pub external_version: Option<String>,
+    /// The entries to be added to the blocklist
+    #[serde(default)]
+    pub additions: Vec<BlocklistRequest>,
+    /// The entries to be removed from the blocklist
+    #[serde(default)]
+    pub removals: Vec<BlocklistRequest>,
+}
+
+/// Request to roll the blocklist back to a previous version, undoing the synchronizations made
+/// since
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BlocklistSyncRollbackRequest {
+    /// The version of the blocklist to be restored
+    pub version: u32,
+}
+
+/// Request to configure the source from which the blocklist is periodically pulled. The source is
+/// to respond with a document in the format of the blocklist synchronization request.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BlocklistSyncSourceRequest {
+    /// The HTTPS URL of the document to be pulled, such as a pre-signed URL of an object in S3
+    #[schema(example = "https://risk.example.com/blocklist.json")]
+    pub url: String,
+    /// Interval between two pulls of the document, in seconds
+    #[schema(example = 3600)]
+    pub pull_interval_in_secs: u32,
+    /// Whether the document is periodically pulled
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct BlocklistSyncSourceResponse {
+    /// The URL of the document pulled
+    pub url: String,
+    /// Interval between two pulls of the document, in seconds
+    pub pull_interval_in_secs: u32,
+    /// Whether the document is periodically pulled
+    pub enabled: bool,
+    /// The time at which the document was last pulled
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_pulled_at: Option<time::PrimitiveDateTime>,
+    /// The error encountered during the last pull of the document, if any
+    pub last_pull_error: Option<String>,
+}
+
+/// A synchronization of the blocklist
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct BlocklistSyncVersionResponse {
+    /// The version of the blocklist created by the synchronization
+    pub version: u32,
+    /// The name of the external system the entries were synchronized from
+    pub source: String,
+    /// The version of the entries in the external system
+    pub external_version: Option<String>,
+    /// The number of entries added to the blocklist
+    pub added_count: usize,
+    /// The number of entries removed from the blocklist
+    pub removed_count: usize,
+    /// The number of entries which were already in the requested state
+    pub unchanged_count: usize,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// The time at which the synchronization was rolled back, if it was
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub rolled_back_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct BlocklistSyncStatusResponse {
+    /// The current version of the blocklist
+    pub current_version: u32,
+    /// The retained synchronizations of the blocklist, the latest first
+    pub versions: Vec<BlocklistSyncVersionResponse>,
+    /// The source from which the blocklist is pulled, if configured
+    pub source: Option<BlocklistSyncSourceResponse>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct ToggleBlocklistQuery {
     #[schema(value_type = BlocklistDataKind)]
@@ -73,3 +166,9 @@ impl ApiEventMetric for GenerateFingerprintRequest {}
 impl ApiEventMetric for ToggleBlocklistQuery {}
 impl ApiEventMetric for GenerateFingerprintResponsePayload {}
 impl ApiEventMetric for Card {}
+impl ApiEventMetric for BlocklistSyncRequest {}
+impl ApiEventMetric for BlocklistSyncRollbackRequest {}
+impl ApiEventMetric for BlocklistSyncSourceRequest {}
+impl ApiEventMetric for BlocklistSyncSourceResponse {}
+impl ApiEventMetric for BlocklistSyncVersionResponse {}
+impl ApiEventMetric for BlocklistSyncStatusResponse {}
@@ -0,0 +1,66 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+    types::MinorUnit,
+};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::enums::Currency;
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize, IntoParams)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorBalanceQuery {
+    /// The account at the connector whose balance is to be retrieved, such as a Stripe connected
+    /// account, an Adyen balance account or a Rapyd wallet. Required for Adyen and Rapyd
+    pub account_id: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorBalanceRequest {
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    pub account_id: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ConnectorBalanceResponse {
+    /// The identifier of the merchant connector account
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+    /// The connector the balance was retrieved from
+    #[schema(example = "stripe")]
+    pub connector_name: String,
+    /// The account at the connector whose balance was retrieved
+    pub account_id: Option<String>,
+    /// The balances of the account, one per currency
+    pub balances: Vec<CurrencyBalance>,
+    /// The time at which the balance was retrieved from the connector. Balances are cached
+    /// briefly, so this may be slightly in the past
+    #[schema(value_type = String, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub fetched_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct CurrencyBalance {
+    /// The currency of the balance
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+    /// The amount which is available to be paid out, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub available: MinorUnit,
+    /// The amount which is not yet available, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 1200)]
+    pub pending: MinorUnit,
+}
+
+impl ApiEventMetric for ConnectorBalanceRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ConnectorBalanceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use utoipa::ToSchema;
+
+use crate::enums::{CardNetwork, Connector, CountryAlpha2, Currency, PaymentMethodType};
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorCapabilitiesRetrieveRequest {
+    pub connector: Connector,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ConnectorCapabilitiesResponse {
+    /// The connector
+    pub connector: Connector,
+    /// The window within which captured payments can be voided, absent if the connector doesn't
+    /// allow captured payments to be voided
+    pub void_window: Option<ConnectorVoidWindow>,
+    /// The timeout and retry policy applied to the requests sent to the connector
+    pub request_policy: ConnectorRequestPolicy,
+    /// The countries and currencies supported by the connector, per payment method type and card
+    /// network. Payment method types and card networks which are not listed are not restricted.
+    pub payment_method_restrictions: Vec<PaymentMethodRestriction>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentMethodRestriction {
+    /// The payment method type which is restricted, absent if the restriction is of a card network
+    #[schema(value_type = Option<PaymentMethodType>, example = "klarna")]
+    pub payment_method_type: Option<PaymentMethodType>,
+    /// The card network which is restricted, absent if the restriction is of a payment method type
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<CardNetwork>,
+    /// The countries of the customer supported by the connector, absent if all countries are
+    /// supported
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["DE", "AT"]))]
+    pub supported_countries: Option<Vec<CountryAlpha2>>,
+    /// The currencies supported by the connector, absent if all currencies are supported
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["EUR"]))]
+    pub supported_currencies: Option<Vec<Currency>>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConnectorVoidWindow {
+    /// Until the end of the day, in UTC, on which the payment was made
+    SameDay,
+    /// For the given duration after the payment was made
+    Duration {
+        /// The duration, in seconds
+        duration_in_secs: i64,
+    },
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ConnectorRequestPolicy {
+    /// Timeout of the requests sent to the connector, in seconds
+    pub timeout_secs: u64,
+    /// Retries of the requests rejected by the connector with HTTP 429, 502 or 503, absent if the
+    /// requests are not retried
+    pub retry: Option<ConnectorRetryPolicy>,
+    /// Policies of the flows which differ from the policy of the connector, by flow name
+    pub flows: HashMap<String, ConnectorFlowRequestPolicy>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ConnectorFlowRequestPolicy {
+    /// Timeout of the requests of the flow, in seconds
+    pub timeout_secs: u64,
+    /// Retries of the requests of the flow, absent if the requests are not retried
+    pub retry: Option<ConnectorRetryPolicy>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ConnectorRetryPolicy {
+    /// Maximum number of retries of a request
+    pub max_retries: u8,
+    /// Delay before the first retry, doubled for every subsequent retry
+    pub base_delay_in_millis: u64,
+    /// Upper bound of the delay between retries
+    pub max_delay_in_millis: u64,
+}
+
+impl ApiEventMetric for ConnectorCapabilitiesRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ConnectorCapabilitiesResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
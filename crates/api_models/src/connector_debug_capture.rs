@@ -0,0 +1,32 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorDebugCaptureListRequest {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ConnectorDebugCaptureListResponse {
+    /// The identifier of the payment
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+
+    /// The failed connector calls made for the payment, with the sensitive fields of the request
+    /// and response masked, the earliest call first
+    #[schema(value_type = Vec<Object>)]
+    pub data: Vec<serde_json::Value>,
+}
+
+impl ApiEventMetric for ConnectorDebugCaptureListRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ConnectorDebugCaptureListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -70,6 +70,7 @@ pub enum Connector {
     Cryptopay,
     Cybersource,
     Datatrans,
+    Declarative,
     Deutschebank,
     Digitalvirgo,
     Dlocal,
@@ -153,7 +154,7 @@ impl Connector {
     }
     #[cfg(feature = "payouts")]
     pub fn is_payout_quote_call_required(&self) -> bool {
-        matches!(self, Self::Wise)
+        matches!(self, Self::Wise | Self::Rapyd)
     }
     #[cfg(feature = "payouts")]
     pub fn supports_access_token_for_payout(&self, payout_method: Option<PayoutType>) -> bool {
@@ -178,10 +179,10 @@ impl Connector {
         )
     }
     pub fn supports_file_storage_module(&self) -> bool {
-        matches!(self, Self::Stripe | Self::Checkout)
+        matches!(self, Self::Stripe | Self::Checkout | Self::Rapyd)
     }
     pub fn requires_defend_dispute(&self) -> bool {
-        matches!(self, Self::Checkout)
+        matches!(self, Self::Checkout | Self::Rapyd)
     }
     pub fn is_separate_authentication_supported(&self) -> bool {
         match self {
@@ -245,7 +246,6 @@ impl Connector {
             | Self::Placetopay
             | Self::Powertranz
             | Self::Prophetpay
-            | Self::Rapyd
             | Self::Shift4
             | Self::Square
             | Self::Stax
@@ -267,10 +267,11 @@ impl Connector {
             | Self::Riskified
             | Self::Threedsecureio
             | Self::Datatrans
+            | Self::Declarative
             | Self::Netcetera
             | Self::Noon
             | Self::Stripe => false,
-            Self::Checkout | Self::Nmi | Self::Cybersource => true,
+            Self::Checkout | Self::Nmi | Self::Cybersource | Self::Rapyd => true,
         }
     }
     pub fn is_pre_processing_required_before_authorize(&self) -> bool {
@@ -0,0 +1,132 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+/// Maximum time a Rapyd payment can be left to be completed by the customer, 30 days
+const RAPYD_MAX_PAYMENT_EXPIRATION_SECONDS: u32 = 30 * 24 * 60 * 60;
+
+/// Connector specific options of a merchant connector account, which tune the requests sent to
+/// the connector without having to be passed in every payment
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(tag = "connector", content = "options", rename_all = "snake_case")]
+pub enum ConnectorOptions {
+    Adyen(AdyenConnectorOptions),
+    Rapyd(RapydConnectorOptions),
+}
+
+impl ConnectorOptions {
+    /// The connector the options are meant for
+    pub fn get_connector(&self) -> enums::Connector {
+        match self {
+            Self::Adyen(_) => enums::Connector::Adyen,
+            Self::Rapyd(_) => enums::Connector::Rapyd,
+        }
+    }
+
+    /// Validates the values of the options, beyond what is enforced by their types
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            Self::Adyen(_) => Ok(()),
+            Self::Rapyd(options) => options.validate(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AdyenConnectorOptions {
+    /// The shopper interaction sent for payments made while the customer is present. Payments
+    /// made while the customer is not present are always sent as continued authentication.
+    #[schema(value_type = Option<AdyenShopperInteraction>, example = "moto")]
+    pub shopper_interaction: Option<AdyenShopperInteraction>,
+}
+
+/// The sales channel through which the shopper gives their card details
+#[derive(Debug, Clone, Copy, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AdyenShopperInteraction {
+    /// Online transactions where the cardholder is present
+    Ecommerce,
+    /// Mail order and telephone order transactions
+    Moto,
+    /// Point of sale transactions where the cardholder is physically present
+    Pos,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RapydConnectorOptions {
+    /// Settings of the page the customer is redirected to by Rapyd to complete the payment
+    pub cashier_page: Option<RapydCashierPageOptions>,
+}
+
+impl RapydConnectorOptions {
+    fn validate(&self) -> Result<(), String> {
+        match self
+            .cashier_page
+            .as_ref()
+            .and_then(|cashier_page| cashier_page.payment_expiration_seconds)
+        {
+            Some(0) => Err("`payment_expiration_seconds` must be greater than 0".to_string()),
+            Some(seconds) if seconds > RAPYD_MAX_PAYMENT_EXPIRATION_SECONDS => Err(format!(
+                "`payment_expiration_seconds` must not be greater than {RAPYD_MAX_PAYMENT_EXPIRATION_SECONDS}"
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RapydCashierPageOptions {
+    /// The URL the customer is redirected to after completing the payment, instead of the return
+    /// URL of the payment
+    #[schema(value_type = Option<String>, example = "https://www.example.com/success")]
+    pub complete_payment_url: Option<url::Url>,
+
+    /// The URL the customer is redirected to when the payment fails, instead of the return URL of
+    /// the payment
+    #[schema(value_type = Option<String>, example = "https://www.example.com/failure")]
+    pub error_payment_url: Option<url::Url>,
+
+    /// The number of seconds the customer has to complete the payment, after which the payment
+    /// expires
+    #[schema(example = 3600, maximum = 2592000)]
+    pub payment_expiration_seconds: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ConnectorOptionsResponse {
+    /// The identifier for the Merchant Account
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The identifier for the Merchant Connector Account
+    #[schema(value_type = String)]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The options of the connector
+    pub connector_options: ConnectorOptions,
+
+    /// Time at which the options were last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for ConnectorOptions {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ConnectorOptionsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,104 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::enums::Connector;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorOutageStatus {
+    /// Payments are routed to the connector as configured
+    Healthy,
+    /// The connector is probably facing an outage, it is tried after all the other connectors
+    Degraded,
+}
+
+#[derive(Debug, Clone, Copy, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorOutageSource {
+    /// The outage was detected from the outcomes of the calls made to the connector
+    Automatic,
+    /// The connector was marked as degraded through the API
+    Manual,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ConnectorOutage {
+    /// How the outage was flagged
+    pub source: ConnectorOutageSource,
+    /// The host of the connector the outage is limited to. Outages without a region apply to all
+    /// the hosts of the connector
+    pub region: Option<String>,
+    /// Why the connector was marked as degraded
+    pub reason: String,
+    /// The time at which the connector was marked as degraded
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub flagged_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize, IntoParams)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorOutageQuery {
+    /// The host of the connector to retrieve the status for. Defaults to the host of the base URL
+    /// of the connector
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorOutageRetrieveRequest {
+    pub connector: Connector,
+    pub region: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorOutageUpdateRequest {
+    /// The status to set for the connector. Marking a connector as healthy also suppresses the
+    /// outage detection for the connector for the duration
+    pub status: ConnectorOutageStatus,
+    /// The host of the connector to override the status for. The status is overridden for all the
+    /// hosts of the connector if not provided
+    pub region: Option<String>,
+    /// Why the status of the connector is being overridden
+    pub reason: Option<String>,
+    /// Time for which the status is overridden, in seconds. Defaults to the degraded duration of
+    /// the outage detection
+    pub duration_in_secs: Option<i64>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorOutageUpdateWrapper {
+    pub connector: Connector,
+    pub request: ConnectorOutageUpdateRequest,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ConnectorOutageResponse {
+    /// The connector
+    pub connector: Connector,
+    /// The host of the connector the status was retrieved for
+    pub region: Option<String>,
+    /// The current status of the connector
+    pub status: ConnectorOutageStatus,
+    /// The details of the outage, if the connector is degraded
+    pub outage: Option<ConnectorOutage>,
+    /// Whether the outage detection is suppressed for the connector, as it was marked as healthy
+    pub detection_suppressed: bool,
+}
+
+impl ApiEventMetric for ConnectorOutageRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ConnectorOutageUpdateWrapper {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ConnectorOutageResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,162 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+    types::{MinorUnit, TimeRange},
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PaymentsExportRequest {
+    /// The time range within which the exported payments were last modified
+    #[serde(flatten)]
+    pub time_range: TimeRange,
+
+    /// The statuses of the exported payments, payments of all statuses are exported if not set
+    #[schema(value_type = Option<Vec<IntentStatus>>)]
+    pub status: Option<Vec<enums::IntentStatus>>,
+
+    /// The currencies of the exported payments, payments in all currencies are exported if not set
+    #[schema(value_type = Option<Vec<Currency>>)]
+    pub currency: Option<Vec<enums::Currency>>,
+
+    /// The cursor of the checkpoint of a previous export, the export resumes after it
+    pub cursor: Option<String>,
+
+    /// Maximum number of payments exported by the request
+    #[schema(example = 1000)]
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct RefundsExportRequest {
+    /// The time range within which the exported refunds were last modified
+    #[serde(flatten)]
+    pub time_range: TimeRange,
+
+    /// The statuses of the exported refunds, refunds of all statuses are exported if not set
+    #[schema(value_type = Option<Vec<RefundStatus>>)]
+    pub refund_status: Option<Vec<enums::RefundStatus>>,
+
+    /// The currencies of the exported refunds, refunds in all currencies are exported if not set
+    #[schema(value_type = Option<Vec<Currency>>)]
+    pub currency: Option<Vec<enums::Currency>>,
+
+    /// The cursor of the checkpoint of a previous export, the export resumes after it
+    pub cursor: Option<String>,
+
+    /// Maximum number of refunds exported by the request
+    #[schema(example = 1000)]
+    pub limit: Option<u32>,
+}
+
+/// A line of an export, exports are streamed as newline delimited JSON. The last line of every
+/// export is a checkpoint, from which the export can be resumed.
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+#[serde(tag = "object", rename_all = "snake_case")]
+pub enum DataExportLine {
+    Payment(PaymentExportRecord),
+    Refund(RefundExportRecord),
+    Checkpoint(DataExportCheckpoint),
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentExportRecord {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The identifier for the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The identifier for the profile of the payment
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// The status of the payment
+    #[schema(value_type = IntentStatus)]
+    pub status: enums::IntentStatus,
+    /// The amount of the payment, in the minor unit of the currency
+    #[schema(value_type = i64)]
+    pub amount: MinorUnit,
+    /// The currency of the payment
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<enums::Currency>,
+    /// The amount captured, in the minor unit of the currency
+    #[schema(value_type = Option<i64>)]
+    pub amount_captured: Option<MinorUnit>,
+    /// The identifier for the customer of the payment
+    #[schema(value_type = Option<String>)]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// The order reference of the merchant
+    pub merchant_order_reference_id: Option<String>,
+    /// The identifier for the active attempt of the payment
+    pub active_attempt_id: String,
+    /// The number of attempts made for the payment
+    pub attempt_count: i16,
+    /// The time at which the payment was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time at which the payment was last modified
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RefundExportRecord {
+    /// The identifier for the refund
+    pub refund_id: String,
+    /// The identifier for the payment of the refund
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The identifier for the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The identifier for the profile of the refund
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// The connector the refund was processed with
+    pub connector: String,
+    /// The status of the refund
+    #[schema(value_type = RefundStatus)]
+    pub refund_status: enums::RefundStatus,
+    /// The amount of the refund, in the minor unit of the currency
+    #[schema(value_type = i64)]
+    pub refund_amount: MinorUnit,
+    /// The amount of the payment of the refund, in the minor unit of the currency
+    #[schema(value_type = i64)]
+    pub total_amount: MinorUnit,
+    /// The currency of the refund
+    #[schema(value_type = Currency)]
+    pub currency: enums::Currency,
+    /// The reason of the refund
+    pub refund_reason: Option<String>,
+    /// The time at which the refund was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time at which the refund was last modified
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct DataExportCheckpoint {
+    /// The cursor to be passed to resume the export after the records exported so far
+    pub cursor: Option<String>,
+    /// The number of records exported by the request
+    pub exported_count: usize,
+    /// Whether there may be more records to be exported after the cursor
+    pub has_more: bool,
+}
+
+impl ApiEventMetric for PaymentsExportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for RefundsExportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
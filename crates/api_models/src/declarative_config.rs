@@ -0,0 +1,137 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use utoipa::ToSchema;
+
+/// Desired configuration of a merchant account, its profiles and their connectors
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DeclarativeConfigRequest {
+    /// The merchant account to be configured
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// Only computes the plan of changes without applying it
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Deletes the profiles and connectors of the merchant account which are not in the spec
+    #[serde(default)]
+    pub prune: bool,
+    /// Desired settings of the merchant account, in the shape of the merchant account update
+    /// request. Settings which are not specified are left as they are.
+    #[schema(value_type = Option<Object>)]
+    pub merchant_account: Option<serde_json::Value>,
+    /// Desired profiles of the merchant account
+    #[serde(default)]
+    pub profiles: Vec<ProfileSpec>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileSpec {
+    /// Name of the profile, which identifies it within the merchant account
+    pub profile_name: String,
+    /// Desired settings of the profile, in the shape of the profile create request
+    #[serde(default)]
+    #[schema(value_type = Object)]
+    pub settings: serde_json::Map<String, serde_json::Value>,
+    /// Desired connectors of the profile
+    #[serde(default)]
+    pub connectors: Vec<ConnectorSpec>,
+    /// Desired active routing algorithm of the profile for payments. The active algorithm is left
+    /// as it is if not specified
+    pub routing: Option<RoutingSpec>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingSpec {
+    /// Name of the routing algorithm
+    pub name: String,
+    /// Description of the routing algorithm
+    pub description: Option<String>,
+    /// The routing algorithm, in the shape of the algorithm of the routing config create request.
+    /// Connectors of the profile can be referred to by their connector label in place of their
+    /// `merchant_connector_id`
+    #[schema(value_type = Object)]
+    pub algorithm: serde_json::Value,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorSpec {
+    /// Label of the connector, which identifies it within the profile
+    pub connector_label: String,
+    /// Desired settings of the connector, in the shape of the connector create request
+    #[schema(value_type = Object)]
+    pub settings: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigResourceType {
+    MerchantAccount,
+    Profile,
+    Connector,
+    Routing,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigOperation {
+    Create,
+    Update,
+    Delete,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigActionStatus {
+    /// The action is part of the plan and was not applied
+    Planned,
+    /// The action was applied
+    Applied,
+    /// Applying the action failed, the actions after it were not applied
+    Failed,
+    /// The action was applied and then undone, as applying a later action failed
+    RolledBack,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct ConfigAction {
+    pub resource_type: ConfigResourceType,
+    /// Name of the resource, the profile name for profiles and routing algorithms and the profile
+    /// name followed by the connector label for connectors
+    pub resource_name: String,
+    /// Identifier of the resource, for existing resources and the ones created while applying
+    pub resource_id: Option<String>,
+    pub operation: ConfigOperation,
+    /// Settings which differ from the current ones, for updates
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub changed_fields: Vec<String>,
+    pub status: ConfigActionStatus,
+    /// Reason for which applying the action, or undoing it, failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct DeclarativeConfigResponse {
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    pub dry_run: bool,
+    /// Actions needed to reach the desired configuration, in the order they are applied
+    pub actions: Vec<ConfigAction>,
+}
+
+impl ApiEventMetric for DeclarativeConfigRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for DeclarativeConfigResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
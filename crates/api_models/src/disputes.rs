@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use common_utils::types::TimeRange;
+use common_utils::types::{MinorUnit, TimeRange};
 use masking::{Deserialize, Serialize};
 use serde::de::Error;
 use time::PrimitiveDateTime;
@@ -54,6 +54,28 @@ pub struct DisputeResponse {
     /// The `merchant_connector_id` of the connector / processor through which the dispute was processed
     #[schema(value_type = Option<String>)]
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    /// The funds withdrawn from the merchant for the dispute, present once the dispute is lost
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub financial_impact: Option<DisputeFinancialImpact>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
+pub struct DisputeFinancialImpact {
+    /// The disputed amount withdrawn from the merchant, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub debited_amount: MinorUnit,
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// The fee charged by the connector for the dispute, in the lowest denomination of its currency
+    #[schema(value_type = Option<i64>, example = 1500)]
+    pub fee_amount: Option<MinorUnit>,
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub fee_currency: Option<enums::Currency>,
+    /// The pending refunds of the payment which were reversed, as the customer was refunded
+    /// through the dispute
+    pub reversed_refund_ids: Vec<String>,
+    /// Whether refunds of the payment are blocked, which is the case once a dispute is lost
+    pub refunds_blocked: bool,
 }
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
@@ -86,7 +108,19 @@ pub struct DisputeResponsePaymentsRetrieve {
     pub created_at: PrimitiveDateTime,
 }
 
-#[derive(Debug, Serialize, Deserialize, strum::Display, Clone)]
+#[derive(
+    Debug,
+    Serialize,
+    Deserialize,
+    strum::Display,
+    strum::EnumString,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+)]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum EvidenceType {
@@ -229,6 +263,46 @@ pub struct DisputesAggregateResponse {
     pub status_with_count: HashMap<DisputeStatus, i64>,
 }
 
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct DisputeEvidenceEffectivenessResponse {
+    /// Outcomes of the disputes for which evidence was submitted, for each connector and reason code
+    pub outcomes: Vec<DisputeOutcomeSummary>,
+    /// Outcomes of the disputes for each category of evidence submitted, for each connector and reason code
+    pub evidence_effectiveness: Vec<EvidenceEffectiveness>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct DisputeOutcomeSummary {
+    /// Connector through which the disputes were received
+    pub connector: String,
+    /// Reason code of the disputes provided by the connector
+    pub connector_reason_code: Option<String>,
+    /// Number of disputes won
+    pub disputes_won: u32,
+    /// Number of disputes lost
+    pub disputes_lost: u32,
+    /// Fraction of the disputes won
+    pub win_rate: f64,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct EvidenceEffectiveness {
+    /// Connector through which the disputes were received
+    pub connector: String,
+    /// Reason code of the disputes provided by the connector
+    pub connector_reason_code: Option<String>,
+    /// Category of the evidence submitted
+    pub evidence_type: EvidenceType,
+    /// Number of disputes won with this category of evidence
+    pub disputes_won: u32,
+    /// Number of disputes lost with this category of evidence
+    pub disputes_lost: u32,
+    /// Fraction of the disputes won with this category of evidence
+    pub win_rate: f64,
+    /// Difference between the win rate with this category of evidence and the win rate of all the disputes for the connector and reason code
+    pub effectiveness_score: f64,
+}
+
 fn parse_comma_separated<'de, D, T>(v: D) -> Result<Option<Vec<T>>, D::Error>
 where
     D: serde::Deserializer<'de>,
@@ -50,6 +50,7 @@ pub enum PayoutConnectors {
     Ebanx,
     Payone,
     Paypal,
+    Rapyd,
     Stripe,
     Wise,
 }
@@ -64,6 +65,7 @@ impl From<PayoutConnectors> for RoutableConnectors {
             PayoutConnectors::Ebanx => Self::Ebanx,
             PayoutConnectors::Payone => Self::Payone,
             PayoutConnectors::Paypal => Self::Paypal,
+            PayoutConnectors::Rapyd => Self::Rapyd,
             PayoutConnectors::Stripe => Self::Stripe,
             PayoutConnectors::Wise => Self::Wise,
         }
@@ -80,6 +82,7 @@ impl From<PayoutConnectors> for Connector {
             PayoutConnectors::Ebanx => Self::Ebanx,
             PayoutConnectors::Payone => Self::Payone,
             PayoutConnectors::Paypal => Self::Paypal,
+            PayoutConnectors::Rapyd => Self::Rapyd,
             PayoutConnectors::Stripe => Self::Stripe,
             PayoutConnectors::Wise => Self::Wise,
         }
@@ -97,6 +100,7 @@ impl TryFrom<Connector> for PayoutConnectors {
             Connector::Ebanx => Ok(Self::Ebanx),
             Connector::Payone => Ok(Self::Payone),
             Connector::Paypal => Ok(Self::Paypal),
+            Connector::Rapyd => Ok(Self::Rapyd),
             Connector::Stripe => Ok(Self::Stripe),
             Connector::Wise => Ok(Self::Wise),
             _ => Err(format!("Invalid payout connector {}", value)),
@@ -95,6 +95,7 @@ pub enum ApiErrorResponse {
     ConnectorError(ApiError, #[serde(skip_serializing)] StatusCode),
     NotFound(ApiError),
     MethodNotAllowed(ApiError),
+    TooManyRequests(ApiError),
     BadRequest(ApiError),
     DomainError(ApiError),
 }
@@ -124,6 +125,7 @@ impl ApiErrorResponse {
             | Self::NotImplemented(i)
             | Self::NotFound(i)
             | Self::MethodNotAllowed(i)
+            | Self::TooManyRequests(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
             | Self::ConnectorError(i, _) => i,
@@ -142,6 +144,7 @@ impl ApiErrorResponse {
             | Self::NotImplemented(i)
             | Self::NotFound(i)
             | Self::MethodNotAllowed(i)
+            | Self::TooManyRequests(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
             | Self::ConnectorError(i, _) => i,
@@ -158,6 +161,7 @@ impl ApiErrorResponse {
             | Self::Unprocessable(_)
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
+            | Self::TooManyRequests(_)
             | Self::NotFound(_)
             | Self::BadRequest(_) => "invalid_request",
             Self::InternalServerError(_) => "api",
@@ -24,8 +24,14 @@ use crate::customers::CustomerListRequest;
 use crate::{
     admin::*,
     analytics::{
-        api_event::*, auth_events::*, connector_events::ConnectorEventsRequest,
-        outgoing_webhook_event::OutgoingWebhookLogsRequest, sdk_events::*, search::*, *,
+        api_event::*,
+        auth_events::*,
+        connector_events::ConnectorEventsRequest,
+        outgoing_webhook_event::OutgoingWebhookLogsRequest,
+        payment_throughput::{GetPaymentThroughputRequest, PaymentThroughputResponse},
+        sdk_events::*,
+        search::*,
+        *,
     },
     api_keys::*,
     cards_info::*,
@@ -72,6 +78,8 @@ impl_api_event_type!(
         MerchantConnectorId,
         MandateResponse,
         MandateRevokedResponse,
+        MandateDebitScheduleRequest,
+        MandateDebitResponse,
         RetrievePaymentLinkRequest,
         PaymentLinkListConstraints,
         MandateId,
@@ -86,6 +94,12 @@ impl_api_event_type!(
         ToggleAllKVRequest,
         ToggleAllKVResponse,
         MerchantAccountDeleteResponse,
+        MerchantAccountStatusUpdateRequestWithId,
+        MerchantAccountStatusResponse,
+        MerchantExternalVaultRequestWithId,
+        MerchantExternalVaultResponse,
+        MerchantKeyDestroyRequestWithId,
+        MerchantKeyResponse,
         MerchantAccountUpdate,
         CardInfoResponse,
         CreateApiKeyResponse,
@@ -123,6 +137,8 @@ impl_api_event_type!(
         SdkEventsRequest,
         ReportRequest,
         ConnectorEventsRequest,
+        GetPaymentThroughputRequest,
+        PaymentThroughputResponse,
         OutgoingWebhookLogsRequest,
         GetGlobalSearchRequest,
         GetSearchRequest,
@@ -132,6 +148,7 @@ impl_api_event_type!(
         DisputeFiltersResponse,
         GetDisputeMetricRequest,
         SankeyResponse,
+        CheckoutFunnelResponse,
         OrganizationResponse,
         OrganizationCreateRequest,
         OrganizationUpdateRequest,
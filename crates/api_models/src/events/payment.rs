@@ -5,33 +5,42 @@ use super::{
     PaymentStartRedirectionRequest, PaymentsConfirmIntentResponse, PaymentsCreateIntentRequest,
     PaymentsGetIntentRequest, PaymentsIntentResponse,
 };
+#[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+use crate::payment_methods::CustomerPaymentMethodsListResponse;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::payment_methods::NetworkTokenLifecycleEvent;
 #[cfg(all(
     any(feature = "v2", feature = "v1"),
     not(feature = "payment_methods_v2")
 ))]
-use crate::payment_methods::CustomerPaymentMethodsListResponse;
-#[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
-use crate::payment_methods::CustomerPaymentMethodsListResponse;
+use crate::payment_methods::{
+    CustomerPaymentMethodsListResponse, PaymentMethodCollectLinkSubmitRequest,
+};
 use crate::{
     payment_methods::{
         CustomerDefaultPaymentMethodResponse, DefaultPaymentMethod, ListCountriesCurrenciesRequest,
-        ListCountriesCurrenciesResponse, PaymentMethodCollectLinkRenderRequest,
-        PaymentMethodCollectLinkRequest, PaymentMethodCollectLinkResponse,
-        PaymentMethodDeleteResponse, PaymentMethodListRequest, PaymentMethodListResponse,
-        PaymentMethodResponse, PaymentMethodUpdate,
+        ListCountriesCurrenciesResponse, PaymentMethodAccountUpdateResponse,
+        PaymentMethodCollectLinkRenderRequest, PaymentMethodCollectLinkRequest,
+        PaymentMethodCollectLinkResponse, PaymentMethodDeleteResponse, PaymentMethodListRequest,
+        PaymentMethodListResponse, PaymentMethodResponse, PaymentMethodTokenResponse,
+        PaymentMethodUpdate,
     },
     payments::{
-        self, ExtendedCardInfoResponse, PaymentIdType, PaymentListConstraints,
+        self, ExtendedCardInfoResponse, ExternalPaymentsImportRequest,
+        ExternalPaymentsImportResponse, PaymentIdType, PaymentListConstraints,
         PaymentListFilterConstraints, PaymentListFilters, PaymentListFiltersV2,
-        PaymentListResponse, PaymentListResponseV2, PaymentsAggregateResponse,
-        PaymentsApproveRequest, PaymentsCancelRequest, PaymentsCaptureRequest,
-        PaymentsCompleteAuthorizeRequest, PaymentsDynamicTaxCalculationRequest,
-        PaymentsDynamicTaxCalculationResponse, PaymentsExternalAuthenticationRequest,
-        PaymentsExternalAuthenticationResponse, PaymentsIncrementalAuthorizationRequest,
-        PaymentsManualUpdateRequest, PaymentsManualUpdateResponse,
-        PaymentsPostSessionTokensRequest, PaymentsPostSessionTokensResponse, PaymentsRejectRequest,
-        PaymentsResponse, PaymentsRetrieveRequest, PaymentsSessionResponse, PaymentsStartRequest,
-        RedirectionResponse,
+        PaymentListResponse, PaymentListResponseV2, PaymentShipmentListResponse,
+        PaymentShipmentResponse, PaymentsAggregateResponse, PaymentsApproveRequest,
+        PaymentsCancelRequest, PaymentsCaptureRequest, PaymentsClientTokenRequest,
+        PaymentsClientTokenResponse, PaymentsCompleteAuthorizeRequest,
+        PaymentsDynamicTaxCalculationRequest, PaymentsDynamicTaxCalculationResponse,
+        PaymentsExternalAuthenticationRequest, PaymentsExternalAuthenticationResponse,
+        PaymentsGiftCardBalanceCheckRequest, PaymentsGiftCardBalanceCheckResponse,
+        PaymentsIncrementalAuthorizationRequest, PaymentsManualUpdateRequest,
+        PaymentsManualUpdateResponse, PaymentsPostSessionTokensRequest,
+        PaymentsPostSessionTokensResponse, PaymentsRejectRequest, PaymentsResponse,
+        PaymentsRetrieveRequest, PaymentsSessionResponse, PaymentsShipmentCaptureRequest,
+        PaymentsStartRequest, PurchaseResponse, PurchaseRetrieveRequest, RedirectionResponse,
     },
 };
 
@@ -65,6 +74,51 @@ impl ApiEventMetric for PaymentsCaptureRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsShipmentCaptureRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.to_owned(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentShipmentResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentShipmentListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsClientTokenRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsClientTokenResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for PaymentsCompleteAuthorizeRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -101,6 +155,24 @@ impl ApiEventMetric for PaymentsPostSessionTokensResponse {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsGiftCardBalanceCheckRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for PaymentsGiftCardBalanceCheckResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for PaymentsDynamicTaxCalculationResponse {}
 
@@ -195,6 +267,14 @@ impl ApiEventMetric for PaymentsResponse {
     }
 }
 
+impl ApiEventMetric for payments::PaymentsConfirmAsyncResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for PaymentMethodResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::PaymentMethod {
@@ -207,6 +287,22 @@ impl ApiEventMetric for PaymentMethodResponse {
 
 impl ApiEventMetric for PaymentMethodUpdate {}
 
+impl ApiEventMetric for PaymentMethodAccountUpdateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.payment_method.get_api_event_type()
+    }
+}
+
+impl ApiEventMetric for PaymentMethodTokenResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.payment_method_id.clone(),
+            payment_method: self.payment_method,
+            payment_method_type: self.payment_method_type,
+        })
+    }
+}
+
 impl ApiEventMetric for DefaultPaymentMethod {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::PaymentMethod {
@@ -274,6 +370,18 @@ impl ApiEventMetric for PaymentMethodCollectLinkRenderRequest {
     }
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+impl ApiEventMetric for PaymentMethodCollectLinkSubmitRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethodCollectLink {
+            link_id: self.pm_collect_link_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for PaymentMethodCollectLinkResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::PaymentMethodCollectLink {
@@ -282,6 +390,15 @@ impl ApiEventMetric for PaymentMethodCollectLinkResponse {
     }
 }
 
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for NetworkTokenLifecycleEvent {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for PaymentListFilterConstraints {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
@@ -322,6 +439,18 @@ impl ApiEventMetric for PaymentsAggregateResponse {
     }
 }
 
+impl ApiEventMetric for PurchaseRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for PurchaseResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
 impl ApiEventMetric for RedirectionResponse {}
 
 #[cfg(feature = "v1")]
@@ -366,6 +495,20 @@ impl ApiEventMetric for PaymentsManualUpdateResponse {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for ExternalPaymentsImportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for ExternalPaymentsImportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
 #[cfg(feature = "v1")]
 impl ApiEventMetric for PaymentsSessionResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
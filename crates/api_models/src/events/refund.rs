@@ -5,6 +5,10 @@ use crate::refunds::{
     RefundListResponse, RefundManualUpdateRequest, RefundRequest, RefundResponse,
     RefundUpdateRequest, RefundsRetrieveRequest,
 };
+#[cfg(feature = "payouts")]
+use crate::refunds::{
+    RefundTenderOverrideListResponse, RefundTenderOverrideRequest, RefundTenderOverrideResponse,
+};
 
 #[cfg(feature = "v1")]
 impl ApiEventMetric for RefundRequest {
@@ -69,6 +73,33 @@ impl ApiEventMetric for RefundManualUpdateRequest {
     }
 }
 
+#[cfg(all(feature = "v1", feature = "payouts"))]
+impl ApiEventMetric for RefundTenderOverrideRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(all(feature = "v1", feature = "payouts"))]
+impl ApiEventMetric for RefundTenderOverrideResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+#[cfg(all(feature = "v1", feature = "payouts"))]
+impl ApiEventMetric for RefundTenderOverrideListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for RefundListRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
@@ -108,3 +108,12 @@ impl ApiEventMetric for SuccessBasedRoutingUpdateConfigQuery {
         Some(ApiEventsType::Routing)
     }
 }
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for crate::routing::PaymentRoutingDecisionResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
@@ -15,12 +15,12 @@ use crate::user::{
     CreateUserAuthenticationMethodRequest, ForgotPasswordRequest, GetSsoAuthUrlRequest,
     GetUserAuthenticationMethodsRequest, GetUserDetailsResponse, GetUserRoleDetailsRequest,
     GetUserRoleDetailsResponseV2, InviteUserRequest, ReInviteUserRequest, RecoveryCodes,
-    ResetPasswordRequest, RotatePasswordRequest, SendVerifyEmailRequest, SignUpRequest,
-    SignUpWithMerchantIdRequest, SsoSignInRequest, SwitchMerchantRequest,
-    SwitchOrganizationRequest, SwitchProfileRequest, TokenResponse, TwoFactorAuthStatusResponse,
-    TwoFactorStatus, UpdateUserAccountDetailsRequest, UpdateUserAuthenticationMethodRequest,
-    UserFromEmailRequest, UserMerchantCreate, VerifyEmailRequest, VerifyRecoveryCodeRequest,
-    VerifyTotpRequest,
+    ResetPasswordRequest, RotatePasswordRequest, SamlAcsRequest, SendVerifyEmailRequest,
+    SignUpRequest, SignUpWithMerchantIdRequest, SkipTwoFactorAuthQueryParam, SsoSignInRequest,
+    SwitchMerchantRequest, SwitchOrganizationRequest, SwitchProfileRequest, TokenResponse,
+    TwoFactorAuthPolicy, TwoFactorAuthStatusResponse, TwoFactorStatus,
+    UpdateUserAccountDetailsRequest, UpdateUserAuthenticationMethodRequest, UserFromEmailRequest,
+    UserMerchantCreate, VerifyEmailRequest, VerifyRecoveryCodeRequest, VerifyTotpRequest,
 };
 
 #[cfg(feature = "recon")]
@@ -64,6 +64,8 @@ common_utils::impl_api_event_type!(
         TokenResponse,
         TwoFactorAuthStatusResponse,
         TwoFactorStatus,
+        TwoFactorAuthPolicy,
+        SkipTwoFactorAuthQueryParam,
         UserFromEmailRequest,
         BeginTotpResponse,
         VerifyRecoveryCodeRequest,
@@ -74,6 +76,7 @@ common_utils::impl_api_event_type!(
         UpdateUserAuthenticationMethodRequest,
         GetSsoAuthUrlRequest,
         SsoSignInRequest,
+        SamlAcsRequest,
         AuthSelectRequest
     )
 );
@@ -7,7 +7,7 @@ use crate::user_role::{
         UpdateRoleRequest,
     },
     AuthorizationInfoResponse, DeleteUserRoleRequest, ListUsersInEntityRequest,
-    UpdateUserRoleRequest,
+    RevokeInvitationRequest, UpdateUserRoleRequest,
 };
 
 common_utils::impl_api_event_type!(
@@ -17,6 +17,7 @@ common_utils::impl_api_event_type!(
         AuthorizationInfoResponse,
         UpdateUserRoleRequest,
         DeleteUserRoleRequest,
+        RevokeInvitationRequest,
         CreateRoleRequest,
         UpdateRoleRequest,
         ListRolesAtEntityLevelRequest,
@@ -0,0 +1,255 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use utoipa::ToSchema;
+
+use crate::{enums, routing::RoutableConnectorChoice};
+
+/// The arms an experiment splits its traffic between
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExperimentArm {
+    Control,
+    Treatment,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ExperimentStatus {
+    /// Eligible payments are being assigned to the arms of the experiment
+    Running,
+    /// No more payments are assigned, the results are kept
+    Stopped,
+    /// The winning arm has been promoted to the routing configuration of the profile
+    Promoted,
+}
+
+/// What the arms of an experiment differ in
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ExperimentVariants {
+    /// Payments of each arm are routed to a different connector first, the other connectors of
+    /// the routing order being kept as fallbacks
+    Connector {
+        control: RoutableConnectorChoice,
+        treatment: RoutableConnectorChoice,
+    },
+    /// Card payments of each arm are processed with a different authentication type
+    ThreeDsStrategy {
+        #[schema(value_type = AuthenticationType)]
+        control: enums::AuthenticationType,
+        #[schema(value_type = AuthenticationType)]
+        treatment: enums::AuthenticationType,
+    },
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExperimentCreateRequest {
+    /// The name of the experiment
+    #[schema(max_length = 64, example = "stripe_vs_adyen_eu_cards")]
+    pub name: String,
+
+    /// A description of the hypothesis tested by the experiment
+    pub description: Option<String>,
+
+    /// What the arms of the experiment differ in
+    pub variants: ExperimentVariants,
+
+    /// The share of the eligible payments enrolled in the experiment, in percent. Defaults to 100.
+    #[schema(minimum = 1, maximum = 100, example = 100)]
+    pub traffic_percentage: Option<u8>,
+
+    /// The share of the enrolled payments assigned to the treatment arm, in percent. Defaults to
+    /// 50.
+    #[schema(minimum = 1, maximum = 99, example = 50)]
+    pub treatment_percentage: Option<u8>,
+
+    /// Only payments made with these payment methods are eligible. All payment methods are
+    /// eligible when not set.
+    #[schema(value_type = Option<Vec<PaymentMethod>>)]
+    pub payment_methods: Option<Vec<enums::PaymentMethod>>,
+
+    /// Only payments in these currencies are eligible. All currencies are eligible when not set.
+    #[schema(value_type = Option<Vec<Currency>>)]
+    pub currencies: Option<Vec<enums::Currency>>,
+
+    /// The number of completed payments required in each arm before the results are tested for
+    /// significance. Defaults to 100.
+    #[schema(minimum = 1, example = 100)]
+    pub min_sample_size: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExperimentPromoteRequest {
+    /// The arm to promote. Defaults to the winning arm, the results must then be significant.
+    pub arm: Option<ExperimentArm>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ExperimentResponse {
+    /// The identifier of the experiment
+    #[schema(example = "exp_mbabizu24mvu3mela5njyhpit4")]
+    pub experiment_id: String,
+
+    /// The identifier of the business profile
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The name of the experiment
+    pub name: String,
+
+    /// A description of the hypothesis tested by the experiment
+    pub description: Option<String>,
+
+    /// The status of the experiment
+    pub status: ExperimentStatus,
+
+    /// What the arms of the experiment differ in
+    pub variants: ExperimentVariants,
+
+    /// The share of the eligible payments enrolled in the experiment, in percent
+    pub traffic_percentage: u8,
+
+    /// The share of the enrolled payments assigned to the treatment arm, in percent
+    pub treatment_percentage: u8,
+
+    /// Only payments made with these payment methods are eligible
+    #[schema(value_type = Option<Vec<PaymentMethod>>)]
+    pub payment_methods: Option<Vec<enums::PaymentMethod>>,
+
+    /// Only payments in these currencies are eligible
+    #[schema(value_type = Option<Vec<Currency>>)]
+    pub currencies: Option<Vec<enums::Currency>>,
+
+    /// The number of completed payments required in each arm before the results are tested for
+    /// significance
+    pub min_sample_size: u32,
+
+    /// The arm promoted to the routing configuration of the profile
+    pub promoted_arm: Option<ExperimentArm>,
+
+    /// The routing algorithm created when a connector experiment was promoted
+    #[schema(value_type = Option<String>)]
+    pub promoted_routing_algorithm_id: Option<id_type::RoutingId>,
+
+    /// The results of the experiment so far, present when a single experiment is retrieved
+    pub results: Option<ExperimentResults>,
+
+    /// The time at which the experiment was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// The time at which the experiment was stopped or promoted
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub ended_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ExperimentListResponse {
+    /// The experiments of the business profile, the most recent first
+    pub experiments: Vec<ExperimentResponse>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ExperimentArmMetrics {
+    /// The arm the metrics are of
+    pub arm: ExperimentArm,
+
+    /// The number of payment attempts assigned to the arm
+    pub assigned: u64,
+
+    /// The number of payment attempts of the arm which succeeded
+    pub succeeded: u64,
+
+    /// The number of payment attempts of the arm which failed
+    pub failed: u64,
+
+    /// The share of the completed payment attempts of the arm which succeeded, between 0 and 1
+    #[schema(example = 0.87)]
+    pub conversion_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ExperimentResults {
+    pub control: ExperimentArmMetrics,
+
+    pub treatment: ExperimentArmMetrics,
+
+    /// The conversion rate of the treatment arm minus the one of the control arm
+    #[schema(example = 0.021)]
+    pub uplift: Option<f64>,
+
+    /// The z-score of the two-proportion z-test comparing the conversion rates of the arms
+    pub z_score: Option<f64>,
+
+    /// The two-sided p-value of the two-proportion z-test, computed once both arms have reached
+    /// the minimum sample size
+    #[schema(example = 0.012)]
+    pub p_value: Option<f64>,
+
+    /// Whether the difference between the conversion rates is significant at the 95% confidence
+    /// level
+    pub is_significant: bool,
+
+    /// The arm with the higher conversion rate, present when the difference is significant
+    pub winning_arm: Option<ExperimentArm>,
+}
+
+/// The arm of an experiment a payment attempt was assigned to
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ExperimentAssignment {
+    /// The identifier of the experiment
+    pub experiment_id: String,
+
+    /// The arm the payment attempt was assigned to
+    pub arm: ExperimentArm,
+}
+
+impl ApiEventMetric for ExperimentCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for ExperimentPromoteRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for ExperimentResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
+
+impl ApiEventMetric for ExperimentListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Routing)
+    }
+}
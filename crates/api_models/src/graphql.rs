@@ -0,0 +1,40 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+/// A GraphQL request, as sent in the body of a `POST` request to the GraphQL endpoint
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphQLRequest {
+    /// The GraphQL document to be executed
+    pub query: String,
+
+    /// The name of the operation in the document to be executed, required only when the document
+    /// contains more than one operation
+    pub operation_name: Option<String>,
+
+    /// The values of the variables used by the operation
+    pub variables: Option<serde_json::Value>,
+}
+
+/// The result of executing a GraphQL request
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct GraphQLResponse {
+    /// The data resolved by the operation, `null` if the operation could not be executed at all
+    pub data: serde_json::Value,
+
+    /// The errors encountered while executing the operation, including the fields that were
+    /// resolved to `null` because they couldn't be read by the user
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<serde_json::Value>,
+}
+
+impl ApiEventMetric for GraphQLRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for GraphQLResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
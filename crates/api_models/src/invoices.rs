@@ -0,0 +1,204 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type, pii,
+    types::MinorUnit,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+#[derive(Debug, Clone, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceLineItem {
+    /// Description of the goods or services billed
+    #[schema(example = "Monthly subscription")]
+    pub description: String,
+
+    /// Number of units billed
+    #[schema(example = 2)]
+    pub quantity: u32,
+
+    /// Amount charged per unit, in the lowest denomination of the invoice currency
+    #[schema(value_type = i64, example = 1500)]
+    pub unit_amount: MinorUnit,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceCreateRequest {
+    /// The profile the invoice belongs to, defaults to the default profile of the merchant
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The customer being billed
+    #[schema(value_type = Option<String>, max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    /// The currency of the invoice, payments linked to it must be in the same currency
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+
+    /// The items billed by the invoice, the invoice amount is the sum of their amounts
+    pub line_items: Vec<InvoiceLineItem>,
+
+    /// The date by which the invoice is to be paid in full
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub due_date: PrimitiveDateTime,
+
+    /// A description of the invoice
+    #[schema(max_length = 255, example = "Invoice for September")]
+    pub description: Option<String>,
+
+    /// You can specify up to 50 keys, with key names up to 40 characters long and values up to
+    /// 500 characters long. Metadata is useful for storing additional, structured information on
+    /// an object.
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceLinkPaymentsRequest {
+    /// Identifiers of the payments to link to the invoice
+    #[schema(value_type = Option<Vec<String>>, example = json!(["pay_mbabizu24mvu3mela5njyhpit4"]))]
+    pub payment_ids: Option<Vec<id_type::PaymentId>>,
+
+    /// Identifiers of the payment links, whose payments are to be linked to the invoice
+    #[schema(example = json!(["plink_dVX0ZhgWWGBu9BvmmfvF"]))]
+    pub payment_link_ids: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct InvoiceId {
+    pub invoice_id: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InvoiceListConstraints {
+    /// Only list invoices with this status
+    #[schema(value_type = Option<InvoiceStatus>)]
+    pub status: Option<enums::InvoiceStatus>,
+
+    /// Number of invoices to list, defaults to 10 and is capped at 100
+    #[schema(example = 10)]
+    pub limit: Option<u32>,
+
+    /// Number of invoices to skip, the most recently created invoices are listed first
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct InvoiceResponse {
+    /// The identifier of the invoice
+    #[schema(example = "inv_dVX0ZhgWWGBu9BvmmfvF")]
+    pub invoice_id: String,
+
+    /// The identifier of the merchant the invoice belongs to
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The identifier of the profile the invoice belongs to
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The customer being billed
+    #[schema(value_type = Option<String>)]
+    pub customer_id: Option<id_type::CustomerId>,
+
+    /// The payment status of the invoice
+    #[schema(value_type = InvoiceStatus)]
+    pub status: enums::InvoiceStatus,
+
+    /// The currency of the invoice
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+
+    /// The total amount of the invoice
+    #[schema(value_type = i64, example = 3000)]
+    pub amount: MinorUnit,
+
+    /// The amount captured by the payments linked to the invoice
+    #[schema(value_type = i64, example = 1500)]
+    pub amount_paid: MinorUnit,
+
+    /// The amount still to be paid
+    #[schema(value_type = i64, example = 1500)]
+    pub amount_due: MinorUnit,
+
+    /// The items billed by the invoice
+    pub line_items: Vec<InvoiceLineItem>,
+
+    /// A description of the invoice
+    pub description: Option<String>,
+
+    /// Metadata attached to the invoice
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// Identifiers of the payments linked to the invoice
+    #[schema(value_type = Vec<String>)]
+    pub payment_ids: Vec<id_type::PaymentId>,
+
+    /// The date by which the invoice is to be paid in full
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub due_date: PrimitiveDateTime,
+
+    /// Time at which the invoice was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-01T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time at which the invoice was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-05T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct InvoiceListResponse {
+    /// The number of invoices included in the list
+    pub count: usize,
+
+    /// The list of invoices
+    pub data: Vec<InvoiceResponse>,
+}
+
+impl ApiEventMetric for InvoiceCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for InvoiceLinkPaymentsRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for InvoiceId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for InvoiceListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for InvoiceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for InvoiceListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
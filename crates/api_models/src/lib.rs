@@ -1,41 +1,62 @@
 pub mod admin;
 pub mod analytics;
 pub mod api_keys;
+pub mod api_logs;
 pub mod apple_pay_certificates_migration;
 pub mod blocklist;
 pub mod cards_info;
 pub mod conditional_configs;
+pub mod connector_balance;
+pub mod connector_capabilities;
+pub mod connector_debug_capture;
 pub mod connector_enums;
 pub mod connector_onboarding;
+pub mod connector_options;
+pub mod connector_outage;
 pub mod consts;
 pub mod currency;
 pub mod customers;
+pub mod data_export;
+pub mod declarative_config;
 pub mod disputes;
 pub mod enums;
 pub mod ephemeral_key;
 #[cfg(feature = "errors")]
 pub mod errors;
 pub mod events;
+pub mod experiments;
 pub mod files;
+pub mod graphql;
 pub mod gsm;
 pub mod health_check;
+pub mod invoices;
+pub mod localization;
 pub mod locker_migration;
+pub mod log_config;
 pub mod mandates;
+pub mod metadata_schema;
 pub mod organization;
+pub mod payment_descriptors;
 pub mod payment_methods;
 pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
+pub mod receipts;
 #[cfg(feature = "recon")]
 pub mod recon;
 pub mod refunds;
 pub mod routing;
+pub mod sandbox_data;
+pub mod simulation;
+pub mod support_bundle;
 pub mod surcharge_decision_configs;
+pub mod test_clocks;
 pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
+pub mod webhook_endpoints;
 pub mod webhook_events;
 pub mod webhooks;
@@ -0,0 +1,56 @@
+use std::collections::BTreeMap;
+
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use utoipa::ToSchema;
+
+/// Messages overriding the default customer facing content for a locale
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MessageBundle {
+    /// The locale of the messages, as a language tag
+    #[schema(example = "fr-BE")]
+    pub locale: String,
+
+    /// The messages keyed by the message they override
+    #[schema(value_type = Object, example = json!({
+        "payment_link.pay_now": "Payer",
+        "redirection.processing_payment": "Veuillez patienter..."
+    }))]
+    pub messages: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct MessageBundlesResponse {
+    /// The identifier for the Merchant Account
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The message bundles of the merchant
+    pub bundles: Vec<MessageBundle>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct MessageBundleDeleteRequest {
+    pub locale: String,
+}
+
+impl ApiEventMetric for MessageBundle {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for MessageBundlesResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for MessageBundleDeleteRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LogConfigUpdateRequest {
+    /// Log levels of modules to set, keyed by the module path, for example
+    /// `router::core::payments`. A `null` level removes the log level of the module.
+    #[schema(value_type = Option<HashMap<String, Option<LogLevel>>>)]
+    pub module_levels: Option<HashMap<String, Option<LogLevel>>>,
+
+    /// Log levels of the requests of merchants to set, keyed by the merchant ID. A `null` level
+    /// removes the log level of the merchant.
+    #[schema(value_type = Option<HashMap<String, Option<LogLevel>>>)]
+    pub merchant_levels: Option<HashMap<String, Option<LogLevel>>>,
+
+    /// Fraction of the info logs which are retained, between 0 and 1
+    #[schema(example = 0.1)]
+    pub info_sample_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct LogConfigResponse {
+    /// Log levels of modules, keyed by the module path
+    #[schema(value_type = HashMap<String, LogLevel>)]
+    pub module_levels: HashMap<String, LogLevel>,
+
+    /// Log levels of the requests of merchants, keyed by the merchant ID
+    #[schema(value_type = HashMap<String, LogLevel>)]
+    pub merchant_levels: HashMap<String, LogLevel>,
+
+    /// Fraction of the info logs which are retained
+    pub info_sample_rate: f64,
+}
+
+impl ApiEventMetric for LogConfigUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for LogConfigResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
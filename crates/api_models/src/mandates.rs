@@ -1,3 +1,4 @@
+use common_utils::{id_type, types::MinorUnit};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -114,6 +115,59 @@ pub struct MandateListConstraints {
     pub created_time_gte: Option<PrimitiveDateTime>,
 }
 
+/// Request to schedule a debit on a mandate. The customer is notified ahead of the debit, and a
+/// failed debit is retried within the configured window.
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MandateDebitScheduleRequest {
+    /// The amount to be debited, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the debit, which must be the currency of the mandate
+    #[schema(value_type = Currency, example = "INR")]
+    pub currency: api_enums::Currency,
+    /// The time at which the customer is debited
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub debit_at: PrimitiveDateTime,
+    /// A description of the debit, included in the pre-debit notification
+    #[schema(example = "Monthly subscription")]
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+pub struct MandateDebitResponse {
+    /// The identifier for the scheduled debit
+    pub mandate_debit_id: String,
+    /// The identifier for mandate
+    pub mandate_id: String,
+    /// The identifier for payment method
+    pub payment_method_id: String,
+    /// The identifier for the customer
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The amount to be debited, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the debit
+    #[schema(value_type = Currency, example = "INR")]
+    pub currency: api_enums::Currency,
+    /// A description of the debit
+    pub description: Option<String>,
+    /// The time at which the customer is debited
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub debit_at: PrimitiveDateTime,
+    /// The time at which the pre-debit notification is sent
+    #[schema(example = "2022-09-09T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub pre_debit_notification_at: PrimitiveDateTime,
+    /// The time until which a failed debit is retried
+    #[schema(example = "2022-09-12T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub retry_until: PrimitiveDateTime,
+}
+
 /// Details required for recurring payment
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
 #[serde(tag = "type", content = "data", rename_all = "snake_case")]
@@ -0,0 +1,61 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// Schema which the metadata of the merchant's payments must conform to
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMetadataSchemaRequest {
+    /// A JSON schema for the payment metadata. The `type`, `title`, `description`, `properties`,
+    /// `required`, `additionalProperties`, `items`, `enum`, `minLength`, `maxLength`, `pattern`,
+    /// `minimum`, `maximum`, `minItems` and `maxItems` keywords are supported.
+    #[schema(value_type = Object, example = json!({
+        "type": "object",
+        "properties": {
+            "order_id": { "type": "string", "maxLength": 64 },
+            "channel": { "type": "string", "enum": ["web", "app", "pos"] }
+        },
+        "required": ["order_id"]
+    }))]
+    pub schema: serde_json::Value,
+
+    /// Top level properties of the schema which payments can be filtered by. Searchable
+    /// properties must be strings, numbers, integers or booleans.
+    #[serde(default)]
+    #[schema(example = json!(["order_id", "channel"]))]
+    pub searchable_fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PaymentMetadataSchemaResponse {
+    /// The identifier for the Merchant Account
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The JSON schema for the payment metadata
+    #[schema(value_type = Object)]
+    pub schema: serde_json::Value,
+
+    /// Top level properties of the schema which payments can be filtered by
+    pub searchable_fields: Vec<String>,
+
+    /// The time at which the schema was last registered
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for PaymentMetadataSchemaRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PaymentMetadataSchemaResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
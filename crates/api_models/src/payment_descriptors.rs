@@ -0,0 +1,125 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use utoipa::ToSchema;
+
+use crate::enums;
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentDescriptorConfigRequest {
+    /// The merchant category code (MCC) sent to connectors accepting it on payments, a four digit
+    /// ISO 18245 code
+    #[schema(min_length = 4, max_length = 4, example = "5734")]
+    pub merchant_category_code: Option<String>,
+
+    /// The template of the soft descriptor shown on the statement of the customer, sent to Adyen
+    /// and Worldpay. Stripe is only sent the part after the `*` separator, as a suffix of the
+    /// descriptor of the Stripe account. The placeholders `{merchant}`, `{order_id}` and
+    /// `{payment_id}` are replaced with the values of the payment. The descriptor is limited to 22
+    /// characters, the part before the `*` separator to 12 characters.
+    #[schema(max_length = 22, example = "{merchant}*{order_id}")]
+    pub soft_descriptor_template: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentDescriptorConfigResponse {
+    /// The identifier of the business profile
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The merchant category code (MCC) sent to connectors accepting it on payments
+    #[schema(example = "5734")]
+    pub merchant_category_code: Option<String>,
+
+    /// The template of the soft descriptor shown on the statement of the customer
+    #[schema(example = "{merchant}*{order_id}")]
+    pub soft_descriptor_template: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SoftDescriptorPreviewRequest {
+    /// The connector the payments are processed through, one of the connectors soft descriptors are
+    /// sent to. Its descriptor rules are applied along with the card network rules.
+    #[schema(value_type = Connector, example = "adyen")]
+    pub connector: enums::Connector,
+
+    /// The template of the soft descriptor to preview. Defaults to the template configured for the
+    /// business profile.
+    #[schema(example = "{merchant}*{order_id}")]
+    pub soft_descriptor_template: Option<String>,
+
+    /// The order reference used for the `{order_id}` placeholder. Defaults to a sample value.
+    #[schema(example = "ORD-2024-000123")]
+    pub order_id: Option<String>,
+
+    /// The payment identifier used for the `{payment_id}` placeholder. Defaults to a sample value.
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SoftDescriptorPreviewResponse {
+    /// The identifier of the business profile
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The connector the preview applies to
+    #[schema(value_type = Connector, example = "adyen")]
+    pub connector: enums::Connector,
+
+    /// The template the descriptors are rendered from
+    #[schema(example = "{merchant}*{order_id}")]
+    pub soft_descriptor_template: String,
+
+    /// The descriptor before it is truncated to the length limits
+    #[schema(example = "ACME STORE*ORD-2024-000123")]
+    pub full_soft_descriptor: Option<String>,
+
+    /// The descriptor shown on the statement of the customer, per card network
+    pub previews: Vec<SoftDescriptorNetworkPreview>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SoftDescriptorNetworkPreview {
+    /// The card network whose rules are applied
+    #[schema(value_type = CardNetwork, example = "Visa")]
+    pub card_network: enums::CardNetwork,
+
+    /// The maximum length of the descriptor, the lowest of the card network and connector limits
+    #[schema(example = 22)]
+    pub max_length: usize,
+
+    /// The descriptor as shown on the statement of the customer
+    #[schema(example = "ACME STORE*ORD-2024-00")]
+    pub soft_descriptor: Option<String>,
+
+    /// Whether the descriptor was truncated to fit the length limits
+    pub is_truncated: bool,
+}
+
+impl ApiEventMetric for PaymentDescriptorConfigRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PaymentDescriptorConfigResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for SoftDescriptorPreviewRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for SoftDescriptorPreviewResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
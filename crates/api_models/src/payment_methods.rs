@@ -1271,6 +1271,18 @@ pub struct RequestPaymentMethodTypes {
     #[schema(example = 1313)]
     pub maximum_amount: Option<MinorUnit>,
 
+    /// Minimum and maximum amounts supported by the processor for specific currencies, which take
+    /// precedence over `minimum_amount` and `maximum_amount` for payments in these currencies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency_amount_limits: Option<Vec<CurrencyAmountLimits>>,
+
+    /// Whether the amount limits are enforced when confirming payments, in addition to filtering
+    /// the listed payment methods. Set for the payment methods configured on accounts created or
+    /// updated since the limits started being enforced, so that existing accounts are unaffected.
+    #[schema(read_only)]
+    #[serde(default)]
+    pub amount_limits_enforced: bool,
+
     /// Boolean to enable recurring payments / mandates. Default is true.
     #[schema(default = true, example = false)]
     pub recurring_enabled: bool,
@@ -1280,6 +1292,52 @@ pub struct RequestPaymentMethodTypes {
     pub installment_payment_enabled: bool,
 }
 
+impl RequestPaymentMethodTypes {
+    /// Returns the minimum and maximum amounts supported for the currency
+    pub fn get_amount_limits(
+        &self,
+        currency: Option<api_enums::Currency>,
+    ) -> (Option<MinorUnit>, Option<MinorUnit>) {
+        currency
+            .and_then(|currency| {
+                self.currency_amount_limits
+                    .as_ref()?
+                    .iter()
+                    .find(|limits| limits.currency == currency)
+            })
+            .map_or((self.minimum_amount, self.maximum_amount), |limits| {
+                (limits.minimum_amount, limits.maximum_amount)
+            })
+    }
+
+    /// Checks whether the amount is within the amount limits supported for the currency
+    pub fn is_amount_supported(
+        &self,
+        amount: MinorUnit,
+        currency: Option<api_enums::Currency>,
+    ) -> bool {
+        let (minimum_amount, maximum_amount) = self.get_amount_limits(currency);
+        minimum_amount.map_or(true, |minimum_amount| amount >= minimum_amount)
+            && maximum_amount.map_or(true, |maximum_amount| amount <= maximum_amount)
+    }
+}
+
+/// Minimum and maximum amounts supported by the processor for a currency
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq, Hash)]
+pub struct CurrencyAmountLimits {
+    /// The currency to which the amount limits apply
+    #[schema(value_type = Currency, example = "KWD")]
+    pub currency: api_enums::Currency,
+
+    /// Minimum amount supported for the currency, in the lowest denomination of the currency
+    #[schema(example = 100)]
+    pub minimum_amount: Option<MinorUnit>,
+
+    /// Maximum amount supported for the currency, in the lowest denomination of the currency
+    #[schema(example = 500000)]
+    pub maximum_amount: Option<MinorUnit>,
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -1884,6 +1942,154 @@ pub struct PaymentMethodCollectLinkResponse {
     /// List of payment methods shown on collect UI
     #[schema(value_type = Option<Vec<EnabledPaymentMethod>>, example = r#"[{"payment_method": "bank_transfer", "payment_method_types": ["ach", "bacs"]}]"#)]
     pub enabled_payment_methods: Option<Vec<link_utils::EnabledPaymentMethod>>,
+
+    /// Status of the collect link
+    #[schema(value_type = PaymentMethodCollectStatus, example = "initiated")]
+    pub status: link_utils::PaymentMethodCollectStatus,
+
+    /// The payment method saved through the link, once the customer has submitted its details
+    #[schema(example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub payment_method_id: Option<String>,
+}
+
+/// Details of a payment method whose network token or connector token has been suspended,
+/// resumed or deleted, or whose card has been updated by the account updater
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentMethodTokenResponse {
+    /// The unique identifier of the payment method.
+    #[schema(example = "pm_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub payment_method_id: String,
+
+    /// The unique identifier of the customer.
+    #[schema(value_type = String, example = "cus_92dnwed8s32bV9D8Snbiasd8v")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The type of payment method
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+
+    /// This is a sub-category of payment method.
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// Status of the payment method after the token lifecycle event
+    #[schema(value_type = PaymentMethodStatus, example = "suspended")]
+    pub status: api_enums::PaymentMethodStatus,
+
+    /// The last four digits of the card, for card payment methods
+    #[schema(example = "4242")]
+    pub card_last4: Option<String>,
+
+    /// The expiry month of the card, for card payment methods
+    #[schema(value_type = Option<String>, example = "10")]
+    pub card_exp_month: Option<masking::Secret<String>>,
+
+    /// The expiry year of the card, for card payment methods
+    #[schema(value_type = Option<String>, example = "2030")]
+    pub card_exp_year: Option<masking::Secret<String>>,
+}
+
+/// Outcome of refreshing the card of a payment method through the account updater of a connector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CardAccountUpdateStatus {
+    /// The card is unchanged
+    NoChange,
+    /// The card was re-issued with a new expiry
+    ExpiryUpdated,
+    /// The card was replaced with a new card number
+    CardNumberUpdated,
+    /// The card account was closed by the issuer
+    Closed,
+}
+
+/// The card of a payment method after it has been refreshed through the account updater of a
+/// connector
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentMethodAccountUpdateResponse {
+    /// The outcome of the account update
+    #[schema(value_type = CardAccountUpdateStatus, example = "expiry_updated")]
+    pub update_status: CardAccountUpdateStatus,
+
+    /// The connector account through which the card was refreshed
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The payment method after the account update
+    pub payment_method: PaymentMethodTokenResponse,
+}
+
+/// A lifecycle event of a network token, sent by the network tokenization service
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct NetworkTokenLifecycleEvent {
+    /// The reference of the card in the network tokenization service
+    pub card_reference: String,
+
+    /// The customer for whom the card was tokenized
+    pub customer_id: id_type::CustomerId,
+
+    /// The lifecycle event of the network token
+    pub event_type: NetworkTokenLifecycleEventType,
+
+    /// The expiry month of the network token, sent along with `token_updated` events
+    pub token_expiry_month: Option<masking::Secret<String>>,
+
+    /// The expiry year of the network token, sent along with `token_updated` events
+    pub token_expiry_year: Option<masking::Secret<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkTokenLifecycleEventType {
+    TokenSuspended,
+    TokenResumed,
+    TokenDeleted,
+    TokenUpdated,
+}
+
+/// Details of the payment method submitted by the customer through the hosted form of a payment
+/// method collect link. The payment method is saved for the customer of the link, without being
+/// charged.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodCollectLinkSubmitRequest {
+    /// The unique identifier for the collect link.
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String, example = "pm_collect_link_2bdacf398vwzq5n422S1")]
+    pub pm_collect_link_id: String,
+
+    /// The client secret of the collect link, passed on to the hosted form
+    #[schema(value_type = String)]
+    pub client_secret: masking::Secret<String>,
+
+    /// The type of payment method being saved
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// This is a sub-category of payment method.
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// Card Details
+    pub card: Option<CardDetail>,
+
+    /// Bank details
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = Option<Bank>)]
+    pub bank_transfer: Option<payouts::Bank>,
+
+    /// Wallet details
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = Option<Wallet>)]
+    pub wallet: Option<payouts::Wallet>,
+
+    /// The billing details of the payment method
+    #[schema(value_type = Option<Address>)]
+    pub billing: Option<payments::Address>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -1,5 +1,5 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeMap, HashMap, HashSet},
     fmt,
     num::NonZeroI64,
 };
@@ -621,6 +621,11 @@ pub struct PaymentsRequest {
     #[schema(value_type = Option<Vec<Connector>>, max_length = 255, example = json!(["stripe", "adyen"]))]
     pub connector: Option<Vec<api_enums::Connector>>,
 
+    /// Forces the attempt made by this confirm call to the given connector, bypassing the routing algorithm of the profile for this attempt only. The connector must still be eligible for the payment. This allows operators to work around connector incidents, including when manually retrying a payment.
+    #[schema(value_type = Option<RoutableConnectorChoice>, example = json!({"connector": "stripe", "merchant_connector_id": "mca_123"}))]
+    #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest)]
+    pub connector_override: Option<crate::routing::RoutableConnectorChoice>,
+
     #[schema(value_type = Option<CaptureMethod>, example = "automatic")]
     pub capture_method: Option<api_enums::CaptureMethod>,
 
@@ -641,6 +646,11 @@ pub struct PaymentsRequest {
     #[schema(default = false, example = true)]
     pub confirm: Option<bool>,
 
+    /// Whether to confirm the payment asynchronously. If set to `true` in the *Payments/Confirm API*, the request is validated and queued for processing, and a `202 Accepted` response is returned with the URL to poll for the status of the payment. The final status is also delivered through webhooks. Raw card details cannot be provided in this mode, the payment has to be confirmed with a saved payment method.
+    #[schema(default = false, example = false)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest)]
+    pub confirm_async: Option<bool>,
+
     /// Passing this object creates a new customer or attaches an existing customer to the payment
     pub customer: Option<CustomerDetails>,
 
@@ -1112,6 +1122,15 @@ pub struct BrowserInformation {
 
     /// User-agent of the browser
     pub user_agent: Option<String>,
+
+    /// Origin of the page from which the payment was confirmed
+    #[schema(example = "https://shop.example.com")]
+    pub origin: Option<String>,
+
+    /// Country of the ip address of the client, resolved by the server. Any value sent in the
+    /// request is ignored.
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub ip_country: Option<api_enums::CountryAlpha2>,
 }
 
 impl RequestSurchargeDetails {
@@ -1195,6 +1214,18 @@ pub struct PaymentAttemptResponse {
     pub client_source: Option<String>,
     /// Value passed in X-CLIENT-VERSION header during payments confirm request by the client
     pub client_version: Option<String>,
+    /// The processing fee charged by the connector for the attempt, in the lowest denomination of its currency, when reported by the connector
+    #[schema(value_type = Option<i64>, example = 205)]
+    pub connector_fee_amount: Option<MinorUnit>,
+    /// The currency of the processing fee charged by the connector
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub connector_fee_currency: Option<enums::Currency>,
+    /// The identifier assigned by the connector to its latest request for the attempt, to be quoted when raising tickets with the connector
+    #[schema(value_type = Option<String>, example = "req_KgHYvlMbOYmLbA")]
+    pub connector_request_id: Option<String>,
+    /// The identifier of the request which last called the connector for the attempt, as returned in the `x-request-id` header
+    #[schema(value_type = Option<String>, example = "0190c1d2-6a4e-7b3c-9f1d-2e8a5c4b7d10")]
+    pub correlation_id: Option<String>,
 }
 
 #[derive(
@@ -1228,6 +1259,12 @@ pub struct CaptureResponse {
     pub error_reason: Option<String>,
     /// Reference to the capture at connector side
     pub reference_id: Option<String>,
+    /// The currency in which the connector settles the capture, if it differs from the currency of the payment
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub settlement_currency: Option<enums::Currency>,
+    /// The capture amount in the settlement currency, in its lowest denomination
+    #[schema(value_type = Option<i64>, example = 6012)]
+    pub settlement_amount: Option<MinorUnit>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
@@ -3522,23 +3559,23 @@ where
         if let Some(payment_method_data) = payment_method_data_response.payment_method_data.as_ref()
         {
             match payment_method_data {
-                PaymentMethodDataResponse::Reward {} => serializer.serialize_str("reward"),
-                PaymentMethodDataResponse::BankDebit(_)
-                | PaymentMethodDataResponse::BankRedirect(_)
-                | PaymentMethodDataResponse::Card(_)
-                | PaymentMethodDataResponse::CardRedirect(_)
-                | PaymentMethodDataResponse::CardToken(_)
-                | PaymentMethodDataResponse::Crypto(_)
-                | PaymentMethodDataResponse::MandatePayment {}
-                | PaymentMethodDataResponse::GiftCard(_)
-                | PaymentMethodDataResponse::PayLater(_)
-                | PaymentMethodDataResponse::RealTimePayment(_)
-                | PaymentMethodDataResponse::MobilePayment(_)
-                | PaymentMethodDataResponse::Upi(_)
-                | PaymentMethodDataResponse::Wallet(_)
-                | PaymentMethodDataResponse::BankTransfer(_)
-                | PaymentMethodDataResponse::OpenBanking(_)
-                | PaymentMethodDataResponse::Voucher(_) => {
+                PaymentMethodDataResponse::Reward { .. } => serializer.serialize_str("reward"),
+                PaymentMethodDataResponse::BankDebit { .. }
+                | PaymentMethodDataResponse::BankRedirect { .. }
+                | PaymentMethodDataResponse::Card { .. }
+                | PaymentMethodDataResponse::CardRedirect { .. }
+                | PaymentMethodDataResponse::CardToken { .. }
+                | PaymentMethodDataResponse::Crypto { .. }
+                | PaymentMethodDataResponse::MandatePayment { .. }
+                | PaymentMethodDataResponse::GiftCard { .. }
+                | PaymentMethodDataResponse::PayLater { .. }
+                | PaymentMethodDataResponse::RealTimePayment { .. }
+                | PaymentMethodDataResponse::MobilePayment { .. }
+                | PaymentMethodDataResponse::Upi { .. }
+                | PaymentMethodDataResponse::Wallet { .. }
+                | PaymentMethodDataResponse::BankTransfer { .. }
+                | PaymentMethodDataResponse::OpenBanking { .. }
+                | PaymentMethodDataResponse::Voucher { .. } => {
                     payment_method_data_response.serialize(serializer)
                 }
             }
@@ -3551,9 +3588,111 @@ where
     }
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
-#[serde(rename_all = "snake_case")]
+/// The payment method data of a payment, the `type` field identifies the payment method and
+/// the details of the payment method are present under the key with the same name
+#[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum PaymentMethodDataResponse {
+    Card {
+        card: Box<CardResponse>,
+    },
+    BankTransfer {
+        bank_transfer: Box<BankTransferResponse>,
+    },
+    Wallet {
+        wallet: Box<WalletResponse>,
+    },
+    PayLater {
+        pay_later: Box<PaylaterResponse>,
+    },
+    BankRedirect {
+        bank_redirect: Box<BankRedirectResponse>,
+    },
+    Crypto {
+        crypto: Box<CryptoResponse>,
+    },
+    BankDebit {
+        bank_debit: Box<BankDebitResponse>,
+    },
+    MandatePayment {
+        mandate_payment: EmptyPaymentMethodDataResponse,
+    },
+    Reward {
+        reward: EmptyPaymentMethodDataResponse,
+    },
+    RealTimePayment {
+        real_time_payment: Box<RealTimePaymentDataResponse>,
+    },
+    Upi {
+        upi: Box<UpiResponse>,
+    },
+    Voucher {
+        voucher: Box<VoucherResponse>,
+    },
+    GiftCard {
+        gift_card: Box<GiftCardResponse>,
+    },
+    CardRedirect {
+        card_redirect: Box<CardRedirectResponse>,
+    },
+    CardToken {
+        card_token: Box<CardTokenResponse>,
+    },
+    OpenBanking {
+        open_banking: Box<OpenBankingResponse>,
+    },
+    MobilePayment {
+        mobile_payment: Box<MobilePaymentResponse>,
+    },
+}
+
+/// Details of payment methods that do not carry any additional data
+#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct EmptyPaymentMethodDataResponse {}
+
+/// Payment method data persisted before the `type` tag was introduced is identified only by the
+/// key of the payment method, so deserialization accepts both the tagged and the keyed shapes
+impl<'de> Deserialize<'de> for PaymentMethodDataResponse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut payment_method_data =
+            serde_json::Map::<String, serde_json::Value>::deserialize(deserializer)?;
+        let payment_method_type = match payment_method_data.remove("type") {
+            Some(serde_json::Value::String(payment_method_type)) => Some(payment_method_type),
+            Some(_) => return Err(de::Error::custom("`type` must be a string")),
+            None => None,
+        };
+
+        let keyed_payment_method_data = match payment_method_type {
+            Some(payment_method_type) => {
+                let details = payment_method_data
+                    .remove(&payment_method_type)
+                    .ok_or_else(|| de::Error::missing_field("payment method details"))?;
+                KeyedPaymentMethodDataResponse::deserialize(serde_json::Value::Object(
+                    serde_json::Map::from_iter([(payment_method_type, details)]),
+                ))
+                .map_err(de::Error::custom)?
+            }
+            None => payment_method_data
+                .into_iter()
+                .find_map(|entry| {
+                    KeyedPaymentMethodDataResponse::deserialize(serde_json::Value::Object(
+                        serde_json::Map::from_iter([entry]),
+                    ))
+                    .ok()
+                })
+                .ok_or_else(|| de::Error::custom("unknown payment method data"))?,
+        };
+
+        Ok(Self::from(keyed_payment_method_data))
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum KeyedPaymentMethodDataResponse {
     Card(Box<CardResponse>),
     BankTransfer(Box<BankTransferResponse>),
     Wallet(Box<WalletResponse>),
@@ -3561,8 +3700,8 @@ pub enum PaymentMethodDataResponse {
     BankRedirect(Box<BankRedirectResponse>),
     Crypto(Box<CryptoResponse>),
     BankDebit(Box<BankDebitResponse>),
-    MandatePayment {},
-    Reward {},
+    MandatePayment(EmptyPaymentMethodDataResponse),
+    Reward(EmptyPaymentMethodDataResponse),
     RealTimePayment(Box<RealTimePaymentDataResponse>),
     Upi(Box<UpiResponse>),
     Voucher(Box<VoucherResponse>),
@@ -3573,6 +3712,44 @@ pub enum PaymentMethodDataResponse {
     MobilePayment(Box<MobilePaymentResponse>),
 }
 
+impl From<KeyedPaymentMethodDataResponse> for PaymentMethodDataResponse {
+    fn from(payment_method_data: KeyedPaymentMethodDataResponse) -> Self {
+        match payment_method_data {
+            KeyedPaymentMethodDataResponse::Card(card) => Self::Card { card },
+            KeyedPaymentMethodDataResponse::BankTransfer(bank_transfer) => {
+                Self::BankTransfer { bank_transfer }
+            }
+            KeyedPaymentMethodDataResponse::Wallet(wallet) => Self::Wallet { wallet },
+            KeyedPaymentMethodDataResponse::PayLater(pay_later) => Self::PayLater { pay_later },
+            KeyedPaymentMethodDataResponse::BankRedirect(bank_redirect) => {
+                Self::BankRedirect { bank_redirect }
+            }
+            KeyedPaymentMethodDataResponse::Crypto(crypto) => Self::Crypto { crypto },
+            KeyedPaymentMethodDataResponse::BankDebit(bank_debit) => Self::BankDebit { bank_debit },
+            KeyedPaymentMethodDataResponse::MandatePayment(mandate_payment) => {
+                Self::MandatePayment { mandate_payment }
+            }
+            KeyedPaymentMethodDataResponse::Reward(reward) => Self::Reward { reward },
+            KeyedPaymentMethodDataResponse::RealTimePayment(real_time_payment) => {
+                Self::RealTimePayment { real_time_payment }
+            }
+            KeyedPaymentMethodDataResponse::Upi(upi) => Self::Upi { upi },
+            KeyedPaymentMethodDataResponse::Voucher(voucher) => Self::Voucher { voucher },
+            KeyedPaymentMethodDataResponse::GiftCard(gift_card) => Self::GiftCard { gift_card },
+            KeyedPaymentMethodDataResponse::CardRedirect(card_redirect) => {
+                Self::CardRedirect { card_redirect }
+            }
+            KeyedPaymentMethodDataResponse::CardToken(card_token) => Self::CardToken { card_token },
+            KeyedPaymentMethodDataResponse::OpenBanking(open_banking) => {
+                Self::OpenBanking { open_banking }
+            }
+            KeyedPaymentMethodDataResponse::MobilePayment(mobile_payment) => {
+                Self::MobilePayment { mobile_payment }
+            }
+        }
+    }
+}
+
 #[derive(Eq, PartialEq, Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct BankDebitResponse {
     #[serde(flatten)]
@@ -3895,6 +4072,77 @@ pub struct PaymentsCaptureRequest {
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
 }
 
+/// A line of the order of the payment fulfilled by a shipment
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ShipmentLineItem {
+    /// The `product_id` of the order line being shipped
+    #[schema(example = "shirt_blue_m")]
+    pub product_id: String,
+    /// The quantity of the product shipped
+    #[schema(example = 1)]
+    pub quantity: u16,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsShipmentCaptureRequest {
+    /// The unique identifier for the payment
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The reference of the shipment, unique across the shipments of the payment
+    #[schema(max_length = 64, example = "shipment_1")]
+    pub shipment_reference: String,
+    /// The amount to be captured for the shipment, which can not exceed the amount that is still
+    /// capturable on the payment
+    #[schema(value_type = i64, example = 2500)]
+    pub amount_to_capture: MinorUnit,
+    /// The lines of the order fulfilled by the shipment
+    pub line_items: Vec<ShipmentLineItem>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentShipmentResponse {
+    /// The unique identifier for the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    /// The reference of the shipment
+    #[schema(example = "shipment_1")]
+    pub shipment_reference: String,
+    /// The identifier of the capture made for the shipment
+    pub capture_id: String,
+    /// The status of the capture made for the shipment
+    #[schema(value_type = CaptureStatus, example = "charged")]
+    pub status: enums::CaptureStatus,
+    /// The amount captured for the shipment
+    #[schema(value_type = i64, example = 2500)]
+    pub amount: MinorUnit,
+    /// The currency of the amount captured for the shipment
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<enums::Currency>,
+    /// The lines of the order fulfilled by the shipment
+    pub line_items: Vec<ShipmentLineItem>,
+    /// If the capture failed, the error message returned by the connector
+    pub error_message: Option<String>,
+    /// The time at which the shipment was captured
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentShipmentListResponse {
+    /// The unique identifier for the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    /// The amount of the authorization that can still be captured
+    #[schema(value_type = i64, example = 4040)]
+    pub amount_capturable: MinorUnit,
+    /// The shipments of the payment, in the order in which they were captured
+    pub shipments: Vec<PaymentShipmentResponse>,
+}
+
 #[derive(Default, Clone, Debug, Eq, PartialEq, serde::Serialize)]
 pub struct UrlDetails {
     pub url: String,
@@ -4055,6 +4303,8 @@ pub struct BankTransferNextStepsData {
     pub bank_transfer_instructions: BankTransferInstructions,
     /// The details received by the receiver
     pub receiver: Option<ReceiverDetails>,
+    /// The instructions for performing the bank transfer, in the locale of the customer
+    pub instructions: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -4374,6 +4624,13 @@ pub struct PaymentsResponse {
     #[remove_in(PaymentsCreateResponseOpenApi)]
     pub unified_message: Option<String>,
 
+    /// Customer safe message describing the error, translated to the locale of the customer. Unlike `error_message`, this does not contain any details of the connector and can be shown on checkout pages
+    #[schema(
+        example = "Your payment was declined. Please try another payment method or contact your bank."
+    )]
+    #[remove_in(PaymentsCreateResponseOpenApi)]
+    pub display_message: Option<String>,
+
     /// Payment Experience for the current payment
     #[schema(value_type = Option<PaymentExperience>, example = "redirect_to_url")]
     pub payment_experience: Option<api_enums::PaymentExperience>,
@@ -4509,6 +4766,25 @@ pub struct PaymentsResponse {
 
     /// Connector Identifier for the payment method
     pub connector_mandate_id: Option<String>,
+
+    /// Signed urls to download the receipt of the payment, available once the payment succeeds
+    pub receipt: Option<crate::receipts::PaymentReceiptUrls>,
+
+    /// The processing fee charged by the connector for the payment, in the lowest denomination of its currency, when reported by the connector
+    #[schema(value_type = Option<i64>, example = 205)]
+    pub connector_fee_amount: Option<MinorUnit>,
+
+    /// The currency of the processing fee charged by the connector
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub connector_fee_currency: Option<enums::Currency>,
+
+    /// The identifier assigned by the connector to its latest request for the payment, to be quoted when raising tickets with the connector
+    #[schema(value_type = Option<String>, example = "req_KgHYvlMbOYmLbA")]
+    pub connector_request_id: Option<String>,
+
+    /// The identifier of the request which last called the connector for the payment, as returned in the `x-request-id` header
+    #[schema(value_type = Option<String>, example = "0190c1d2-6a4e-7b3c-9f1d-2e8a5c4b7d10")]
+    pub correlation_id: Option<String>,
 }
 
 // Serialize is implemented because, this will be serialized in the api events.
@@ -4826,6 +5102,11 @@ pub struct PaymentListConstraints {
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     #[serde(rename = "created.gte")]
     pub created_gte: Option<PrimitiveDateTime>,
+
+    /// Comma separated list of fields to be included in the response, nested fields are separated by a `.`
+    #[schema(example = "data.payment_id,data.status")]
+    #[serde(default, skip_serializing)]
+    pub fields: Option<String>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
@@ -4901,6 +5182,9 @@ pub struct PaymentListFilterConstraints {
     pub order: Order,
     /// The List of all the card networks to filter payments list
     pub card_network: Option<Vec<enums::CardNetwork>>,
+    /// The values of searchable metadata fields, as declared in the payment metadata schema, to
+    /// filter payments list
+    pub metadata: Option<serde_json::Map<String, serde_json::Value>>,
 }
 
 impl PaymentListFilterConstraints {
@@ -4952,6 +5236,79 @@ pub struct PaymentsAggregateResponse {
     pub status_with_count: HashMap<enums::IntentStatus, i64>,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PurchaseRetrieveRequest {
+    /// The order reference passed by the merchant while creating the payments of the purchase
+    pub merchant_order_reference_id: Option<String>,
+    /// Any one of the payments of the purchase, used when the order reference is not known
+    pub payment_id: Option<id_type::PaymentId>,
+}
+
+/// All the payments made for a single purchase of the customer, across the payments recreated
+/// for the purchase and the connectors they were routed to
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PurchaseResponse {
+    /// The order reference of the purchase, absent when its payments were not created with one
+    pub merchant_order_reference_id: Option<String>,
+    /// The status of the succeeded payment of the purchase if any, else of its latest payment
+    pub status: enums::IntentStatus,
+    /// The payment through which the purchase was completed
+    pub succeeded_payment_id: Option<id_type::PaymentId>,
+    /// The number of payments created for the purchase
+    pub total_payments: usize,
+    /// The number of attempts made across the payments of the purchase
+    pub total_attempts: usize,
+    /// The connectors the attempts of the purchase were routed to
+    pub connectors: Vec<String>,
+    /// The fingerprints of the payment methods used for the purchase
+    pub fingerprint_ids: Vec<String>,
+    /// The payments of the purchase, latest first
+    pub payments: Vec<PurchasePayment>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PurchasePayment {
+    pub payment_id: id_type::PaymentId,
+    /// How the payment was linked to the purchase
+    pub linked_by: PurchaseLinkType,
+    pub status: enums::IntentStatus,
+    pub amount: MinorUnit,
+    pub currency: Option<enums::Currency>,
+    pub customer_id: Option<id_type::CustomerId>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    /// The attempts of the payment, latest first
+    pub attempts: Vec<PurchaseAttempt>,
+    /// The disputes raised against the payment
+    pub disputes: Vec<disputes::DisputeResponsePaymentsRetrieve>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PurchaseLinkType {
+    /// The payment which was requested, created without an order reference
+    PaymentId,
+    /// The payment was created with the order reference of the purchase
+    OrderReference,
+    /// The payment was created without an order reference, but with a payment method used for the
+    /// purchase, for the same amount and around the same time
+    Fingerprint,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PurchaseAttempt {
+    pub attempt_id: String,
+    pub status: enums::AttemptStatus,
+    pub connector: Option<String>,
+    pub payment_method: Option<enums::PaymentMethod>,
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub fingerprint_id: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct AmountFilter {
     /// The start amount to filter list of transactions which are greater than or equal to the start amount
@@ -5127,74 +5484,92 @@ impl From<KlarnaSdkPaymentMethod> for PaylaterResponse {
 impl From<AdditionalPaymentData> for PaymentMethodDataResponse {
     fn from(payment_method_data: AdditionalPaymentData) -> Self {
         match payment_method_data {
-            AdditionalPaymentData::Card(card) => Self::Card(Box::new(CardResponse::from(*card))),
+            AdditionalPaymentData::Card(card) => Self::Card {
+                card: Box::new(CardResponse::from(*card)),
+            },
             AdditionalPaymentData::PayLater { klarna_sdk } => match klarna_sdk {
-                Some(sdk) => Self::PayLater(Box::new(PaylaterResponse::from(sdk))),
-                None => Self::PayLater(Box::new(PaylaterResponse { klarna_sdk: None })),
+                Some(sdk) => Self::PayLater {
+                    pay_later: Box::new(PaylaterResponse::from(sdk)),
+                },
+                None => Self::PayLater {
+                    pay_later: Box::new(PaylaterResponse { klarna_sdk: None }),
+                },
             },
             AdditionalPaymentData::Wallet {
                 apple_pay,
                 google_pay,
             } => match (apple_pay, google_pay) {
-                (Some(apple_pay_pm), _) => Self::Wallet(Box::new(WalletResponse {
-                    details: Some(WalletResponseData::ApplePay(Box::new(
-                        additional_info::WalletAdditionalDataForCard {
-                            last4: apple_pay_pm
-                                .display_name
-                                .clone()
-                                .chars()
-                                .rev()
-                                .take(4)
-                                .collect::<String>()
-                                .chars()
-                                .rev()
-                                .collect::<String>(),
-                            card_network: apple_pay_pm.network.clone(),
-                            card_type: apple_pay_pm.pm_type.clone(),
-                        },
-                    ))),
-                })),
-                (_, Some(google_pay_pm)) => Self::Wallet(Box::new(WalletResponse {
-                    details: Some(WalletResponseData::GooglePay(Box::new(google_pay_pm))),
-                })),
-                _ => Self::Wallet(Box::new(WalletResponse { details: None })),
+                (Some(apple_pay_pm), _) => Self::Wallet {
+                    wallet: Box::new(WalletResponse {
+                        details: Some(WalletResponseData::ApplePay(Box::new(
+                            additional_info::WalletAdditionalDataForCard {
+                                last4: apple_pay_pm
+                                    .display_name
+                                    .clone()
+                                    .chars()
+                                    .rev()
+                                    .take(4)
+                                    .collect::<String>()
+                                    .chars()
+                                    .rev()
+                                    .collect::<String>(),
+                                card_network: apple_pay_pm.network.clone(),
+                                card_type: apple_pay_pm.pm_type.clone(),
+                            },
+                        ))),
+                    }),
+                },
+                (_, Some(google_pay_pm)) => Self::Wallet {
+                    wallet: Box::new(WalletResponse {
+                        details: Some(WalletResponseData::GooglePay(Box::new(google_pay_pm))),
+                    }),
+                },
+                _ => Self::Wallet {
+                    wallet: Box::new(WalletResponse { details: None }),
+                },
+            },
+            AdditionalPaymentData::BankRedirect { bank_name, details } => Self::BankRedirect {
+                bank_redirect: Box::new(BankRedirectResponse { bank_name, details }),
+            },
+            AdditionalPaymentData::Crypto { details } => Self::Crypto {
+                crypto: Box::new(CryptoResponse { details }),
+            },
+            AdditionalPaymentData::BankDebit { details } => Self::BankDebit {
+                bank_debit: Box::new(BankDebitResponse { details }),
+            },
+            AdditionalPaymentData::MandatePayment {} => Self::MandatePayment {
+                mandate_payment: EmptyPaymentMethodDataResponse::default(),
+            },
+            AdditionalPaymentData::Reward {} => Self::Reward {
+                reward: EmptyPaymentMethodDataResponse::default(),
+            },
+            AdditionalPaymentData::RealTimePayment { details } => Self::RealTimePayment {
+                real_time_payment: Box::new(RealTimePaymentDataResponse { details }),
+            },
+            AdditionalPaymentData::Upi { details } => Self::Upi {
+                upi: Box::new(UpiResponse { details }),
+            },
+            AdditionalPaymentData::BankTransfer { details } => Self::BankTransfer {
+                bank_transfer: Box::new(BankTransferResponse { details }),
+            },
+            AdditionalPaymentData::Voucher { details } => Self::Voucher {
+                voucher: Box::new(VoucherResponse { details }),
+            },
+            AdditionalPaymentData::GiftCard { details } => Self::GiftCard {
+                gift_card: Box::new(GiftCardResponse { details }),
+            },
+            AdditionalPaymentData::CardRedirect { details } => Self::CardRedirect {
+                card_redirect: Box::new(CardRedirectResponse { details }),
+            },
+            AdditionalPaymentData::CardToken { details } => Self::CardToken {
+                card_token: Box::new(CardTokenResponse { details }),
+            },
+            AdditionalPaymentData::OpenBanking { details } => Self::OpenBanking {
+                open_banking: Box::new(OpenBankingResponse { details }),
+            },
+            AdditionalPaymentData::MobilePayment { details } => Self::MobilePayment {
+                mobile_payment: Box::new(MobilePaymentResponse { details }),
             },
-            AdditionalPaymentData::BankRedirect { bank_name, details } => {
-                Self::BankRedirect(Box::new(BankRedirectResponse { bank_name, details }))
-            }
-            AdditionalPaymentData::Crypto { details } => {
-                Self::Crypto(Box::new(CryptoResponse { details }))
-            }
-            AdditionalPaymentData::BankDebit { details } => {
-                Self::BankDebit(Box::new(BankDebitResponse { details }))
-            }
-            AdditionalPaymentData::MandatePayment {} => Self::MandatePayment {},
-            AdditionalPaymentData::Reward {} => Self::Reward {},
-            AdditionalPaymentData::RealTimePayment { details } => {
-                Self::RealTimePayment(Box::new(RealTimePaymentDataResponse { details }))
-            }
-            AdditionalPaymentData::Upi { details } => Self::Upi(Box::new(UpiResponse { details })),
-            AdditionalPaymentData::BankTransfer { details } => {
-                Self::BankTransfer(Box::new(BankTransferResponse { details }))
-            }
-            AdditionalPaymentData::Voucher { details } => {
-                Self::Voucher(Box::new(VoucherResponse { details }))
-            }
-            AdditionalPaymentData::GiftCard { details } => {
-                Self::GiftCard(Box::new(GiftCardResponse { details }))
-            }
-            AdditionalPaymentData::CardRedirect { details } => {
-                Self::CardRedirect(Box::new(CardRedirectResponse { details }))
-            }
-            AdditionalPaymentData::CardToken { details } => {
-                Self::CardToken(Box::new(CardTokenResponse { details }))
-            }
-            AdditionalPaymentData::OpenBanking { details } => {
-                Self::OpenBanking(Box::new(OpenBankingResponse { details }))
-            }
-            AdditionalPaymentData::MobilePayment { details } => {
-                Self::MobilePayment(Box::new(MobilePaymentResponse { details }))
-            }
         }
     }
 }
@@ -5363,6 +5738,82 @@ pub struct PaymentsPostSessionTokensResponse {
     pub status: api_enums::IntentStatus,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsGiftCardBalanceCheckRequest {
+    /// The unique identifier for the payment
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// It's a token used for client side verification.
+    #[schema(value_type = String)]
+    pub client_secret: Secret<String>,
+    /// The gift card or prepaid card whose balance is to be checked
+    pub gift_card: GiftCardData,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsGiftCardBalanceCheckResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// Balance remaining on the gift card
+    #[schema(value_type = i64, example = 6540)]
+    pub balance: MinorUnit,
+    /// The currency of the balance
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The amount of the payment that can be covered by the gift card
+    #[schema(value_type = i64, example = 6540)]
+    pub applicable_amount: MinorUnit,
+    /// The amount of the payment left to be paid with another payment method
+    #[schema(value_type = i64, example = 0)]
+    pub remaining_amount: MinorUnit,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema, Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ClientTokenScope {
+    /// The client token can be used to confirm the payment
+    Confirm,
+    /// The client token can be used to retrieve the payment
+    Retrieve,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsClientTokenRequest {
+    /// The unique identifier for the payment
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The operations the client token can be used for
+    #[schema(value_type = Vec<ClientTokenScope>, example = json!(["confirm"]))]
+    pub scopes: Vec<ClientTokenScope>,
+    /// The time in seconds after which the client token expires, defaults to the TTL configured
+    /// for client tokens
+    #[schema(example = 900)]
+    pub expires_in: Option<u32>,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsClientTokenResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The client token, which is passed as the `client_secret` of client side requests
+    #[schema(value_type = String, example = "pay_U42c409qyHwOkWo3vK60_secret_cst_el9ksDkiB8hi6j9N78yo")]
+    pub client_token: Secret<String>,
+    /// The operations the client token can be used for
+    pub scopes: Vec<ClientTokenScope>,
+    /// The time at which the client token expires
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:26:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub struct PaymentsDynamicTaxCalculationRequest {
     /// The unique identifier for the payment
@@ -5582,9 +6033,37 @@ pub struct ConnectorMetadata {
     pub apple_pay: Option<ApplepayConnectorMetadataRequest>,
     pub airwallex: Option<AirwallexData>,
     pub noon: Option<NoonData>,
+    /// Fields forwarded as is in the payment requests sent to Adyen
+    pub adyen: Option<ConnectorPassthroughData>,
+    /// Fields forwarded as is in the payment requests sent to Checkout
+    pub checkout: Option<ConnectorPassthroughData>,
 }
 
 impl ConnectorMetadata {
+    /// The fields to be forwarded as is to each of the connectors
+    pub fn get_all_passthrough_data(
+        &self,
+    ) -> impl Iterator<Item = (enums::Connector, &ConnectorPassthroughData)> {
+        [
+            (enums::Connector::Adyen, self.adyen.as_ref()),
+            (enums::Connector::Checkout, self.checkout.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(connector, passthrough_data)| {
+            passthrough_data.map(|passthrough_data| (connector, passthrough_data))
+        })
+    }
+
+    /// The fields to be forwarded as is to the given connector
+    pub fn get_passthrough_data(
+        &self,
+        connector: enums::Connector,
+    ) -> Option<&ConnectorPassthroughData> {
+        self.get_all_passthrough_data()
+            .find(|(passthrough_connector, _)| *passthrough_connector == connector)
+            .map(|(_, passthrough_data)| passthrough_data)
+    }
+
     pub fn from_value(
         value: pii::SecretSerdeValue,
     ) -> common_utils::errors::CustomResult<Self, common_utils::errors::ParsingError> {
@@ -5610,6 +6089,14 @@ impl ConnectorMetadata {
     }
 }
 
+/// Fields of a connector request which are not supported by Hyperswitch yet, keyed by their name in
+/// the request of the connector. Only the fields allowed by the connector can be passed, the
+/// others are rejected.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(transparent)]
+#[schema(value_type = Object, example = json!({ "industryUsage": "delayedCharge" }))]
+pub struct ConnectorPassthroughData(pub BTreeMap<String, pii::SecretSerdeValue>);
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct AirwallexData {
     /// payload required by airwallex
@@ -5757,6 +6244,8 @@ pub struct PazeSessionTokenResponse {
     pub email_address: Option<Email>,
 }
 
+/// The Google Pay session response, the variants are serialized without a tag and are told apart
+/// by the presence of `merchant_info`, which only the session response for non third party sdk has
 #[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum GpaySessionTokenResponse {
@@ -5940,6 +6429,7 @@ pub enum NextActionCall {
     CompleteAuthorize,
 }
 
+// This is left untagged as the session object is handed over to apple pay as is
 #[derive(Debug, Clone, Eq, PartialEq, serde::Serialize, ToSchema)]
 #[serde(untagged)]
 pub enum ApplePaySessionResponse {
@@ -6106,7 +6596,10 @@ pub struct PaymentsCancelRequest {
     /// The identifier for the payment
     #[serde(skip)]
     pub payment_id: id_type::PaymentId,
-    /// The reason for the payment cancel
+    /// The reason for the payment cancel. Either one of the normalized reasons `duplicate`,
+    /// `fraudulent`, `requested_by_customer` or `abandoned`, which are mapped to the reason codes
+    /// of the connector, or a free form reason.
+    #[schema(example = "requested_by_customer")]
     pub cancellation_reason: Option<String>,
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>, deprecated)]
@@ -6135,10 +6628,14 @@ pub struct PaymentsExternalAuthenticationRequest {
     pub client_secret: Secret<String>,
     /// SDK Information if request is from SDK
     pub sdk_information: Option<SdkInformation>,
-    /// Device Channel indicating whether request is coming from App or Browser
+    /// Device Channel indicating whether request is coming from App or Browser, or is initiated by the 3DS Requestor
     pub device_channel: DeviceChannel,
     /// Indicates if 3DS method data was successfully completed or not
     pub threeds_method_comp_ind: ThreeDsCompletionIndicator,
+    /// Indicates the type of the 3DS Requestor Initiated authentication. Required when the device channel is `3RI`
+    pub three_ri_indicator: Option<ThreeRiIndicator>,
+    /// The identifier of a prior authentication of the cardholder, such as the authentication of the customer initiated payment which set up the mandate. It is shared with the issuer for 3DS Requestor Initiated authentications
+    pub prior_authentication_id: Option<String>,
 }
 
 /// Indicates if 3DS method data was successfully completed or not
@@ -6164,6 +6661,20 @@ pub struct PaymentsManualUpdateRequest {
     pub connector_transaction_id: Option<String>,
 }
 
+/// Response returned when a payment confirmation is queued for asynchronous processing
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentsConfirmAsyncResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    /// The status of the payment when the confirmation was queued
+    #[schema(value_type = IntentStatus, example = "requires_confirmation")]
+    pub status: enums::IntentStatus,
+    /// The URL to poll for the status of the payment
+    #[schema(example = "https://sandbox.hyperswitch.io/payments/pay_mbabizu24mvu3mela5njyhpit4")]
+    pub poll_url: String,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub struct PaymentsManualUpdateResponse {
     /// The identifier for the payment
@@ -6185,6 +6696,85 @@ pub struct PaymentsManualUpdateResponse {
     pub connector_transaction_id: Option<String>,
 }
 
+/// Payments processed outside of hyperswitch, for example at a point of sale, to be imported as
+/// `external` records
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExternalPaymentsImportRequest {
+    /// The profile to which the payments are imported. The default profile of the merchant is
+    /// used if not passed.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// The payments to import, at most 100 in a request
+    pub payments: Vec<ExternalPaymentRecord>,
+}
+
+/// A payment captured outside of hyperswitch
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExternalPaymentRecord {
+    /// The reference of the payment in the external system. Payments are imported only once per
+    /// reference.
+    #[schema(max_length = 128, example = "pos_txn_0001")]
+    pub external_reference_id: String,
+    /// The captured amount, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the payment
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+    /// The time at which the payment was captured in the external system
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub captured_at: PrimitiveDateTime,
+    /// The payment method used for the payment
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<enums::PaymentMethod>,
+    /// The payment method type used for the payment
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+    /// The identifier of an existing customer who made the payment
+    #[schema(value_type = Option<String>, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// A description of the payment
+    #[schema(example = "Store #12, register 3")]
+    pub description: Option<String>,
+    /// Metadata of the payment, validated against the payment metadata schema of the merchant
+    #[schema(value_type = Option<Object>, example = r#"{ "store_id": "12" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// Outcome of importing an external payment
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExternalPaymentImportStatus {
+    /// The payment was imported
+    Imported,
+    /// A payment with the same external reference was already imported
+    Duplicate,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct ExternalPaymentImportResult {
+    /// The reference of the payment in the external system
+    pub external_reference_id: String,
+    /// The identifier of the payment recording the external payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    /// Whether the payment was imported by this request
+    pub status: ExternalPaymentImportStatus,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct ExternalPaymentsImportResponse {
+    /// Number of payments imported by this request
+    pub imported_count: usize,
+    /// Number of payments which were already imported
+    pub duplicate_count: usize,
+    /// The outcome for each payment, in the order of the request
+    pub payments: Vec<ExternalPaymentImportResult>,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub enum ThreeDsCompletionIndicator {
     /// 3DS method successfully completed
@@ -6198,13 +6788,33 @@ pub enum ThreeDsCompletionIndicator {
     NotAvailable,
 }
 
-/// Device Channel indicating whether request is coming from App or Browser
+/// Device Channel indicating whether request is coming from App or Browser, or is initiated by the 3DS Requestor without the cardholder
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema, Eq, PartialEq)]
 pub enum DeviceChannel {
     #[serde(rename = "APP")]
     App,
     #[serde(rename = "BRW")]
     Browser,
+    /// 3DS Requestor Initiated (3RI) authentication, performed without the cardholder for merchant initiated payments
+    #[serde(rename = "3RI")]
+    ThreeDsRequestorInitiated,
+}
+
+/// Indicates the type of the 3DS Requestor Initiated (3RI) authentication
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, Copy, ToSchema, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreeRiIndicator {
+    RecurringTransaction,
+    InstalmentTransaction,
+    AddCard,
+    MaintainCardInformation,
+    AccountVerification,
+    SplitOrDelayedShipment,
+    TopUp,
+    MailOrder,
+    TelephoneOrder,
+    WhitelistStatusCheck,
+    OtherPayment,
 }
 
 /// SDK Information if request is from SDK
@@ -6546,6 +7156,43 @@ pub struct PaymentLinkDetails {
     pub display_sdk_only: bool,
     pub locale: Option<String>,
     pub transaction_details: Option<Vec<admin::PaymentLinkTransactionDetails>>,
+    pub custom_messages: HashMap<String, String>,
+    /// Test cards of the connectors of the payment, listed on the page when it is previewed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview: Option<PaymentLinkPreviewDetails>,
+}
+
+/// Details rendered on the payment link page when previewed in the sandbox
+#[derive(Debug, serde::Serialize, Clone)]
+pub struct PaymentLinkPreviewDetails {
+    /// The scenarios which can be selected, for which test cards are listed
+    pub scenarios: Vec<PaymentLinkPreviewScenario>,
+    /// Test cards of the connectors enabled for the profile of the payment
+    pub test_cards: Vec<PaymentLinkTestCard>,
+}
+
+/// Outcome of a payment made with a test card
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentLinkPreviewScenario {
+    /// The payment succeeds without authentication
+    Success,
+    /// The payment is declined by the connector
+    Failure,
+    /// The payment succeeds after a 3DS challenge
+    ThreeDs,
+}
+
+#[derive(Debug, serde::Serialize, Clone)]
+pub struct PaymentLinkTestCard {
+    /// The connector which accepts the test card
+    pub connector: String,
+    /// The outcome of a payment made with the test card
+    pub scenario: PaymentLinkPreviewScenario,
+    pub card_number: String,
+    pub card_exp_month: String,
+    pub card_exp_year: String,
+    pub card_cvc: String,
 }
 
 #[derive(Debug, serde::Serialize, Clone)]
@@ -6574,6 +7221,7 @@ pub struct PaymentLinkStatusDetails {
     pub transaction_details: Option<Vec<admin::PaymentLinkTransactionDetails>>,
     pub unified_code: Option<String>,
     pub unified_message: Option<String>,
+    pub custom_messages: HashMap<String, String>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
@@ -6793,7 +7441,9 @@ mod payments_response_api_contract {
     #[test]
     fn test_reward_payment_response() {
         let payment_method_response_with_billing = PaymentMethodDataResponseWithBilling {
-            payment_method_data: Some(PaymentMethodDataResponse::Reward {}),
+            payment_method_data: Some(PaymentMethodDataResponse::Reward {
+                reward: EmptyPaymentMethodDataResponse::default(),
+            }),
             billing: None,
         };
 
@@ -6806,6 +7456,77 @@ mod payments_response_api_contract {
         let stringified_payments_response = payments_response.encode_to_string_of_json();
         assert_eq!(stringified_payments_response.unwrap(), expected_response);
     }
+
+    #[test]
+    fn test_payment_method_data_response_is_tagged() {
+        let payment_method_response_with_billing = PaymentMethodDataResponseWithBilling {
+            payment_method_data: Some(PaymentMethodDataResponse::MandatePayment {
+                mandate_payment: EmptyPaymentMethodDataResponse::default(),
+            }),
+            billing: None,
+        };
+
+        let payments_response = TestPaymentsResponse {
+            payment_method_data: Some(payment_method_response_with_billing),
+        };
+
+        let expected_response = r#"{"payment_method_data":{"type":"mandate_payment","mandate_payment":{},"billing":null}}"#;
+
+        let stringified_payments_response = payments_response.encode_to_string_of_json();
+        assert_eq!(stringified_payments_response.unwrap(), expected_response);
+    }
+
+    #[test]
+    fn test_tagged_payment_method_data_response_round_trip() {
+        let payment_method_data_with_billing = PaymentMethodDataResponseWithBilling {
+            payment_method_data: Some(PaymentMethodDataResponse::Card {
+                card: Box::new(CardResponse {
+                    last4: Some("4242".to_string()),
+                    card_type: None,
+                    card_network: Some(api_enums::CardNetwork::Visa),
+                    card_issuer: None,
+                    card_issuing_country: None,
+                    card_isin: Some("424242".to_string()),
+                    card_extended_bin: None,
+                    card_exp_month: Some(Secret::new("12".to_string())),
+                    card_exp_year: Some(Secret::new("2030".to_string())),
+                    card_holder_name: None,
+                    payment_checks: None,
+                    authentication_data: None,
+                }),
+            }),
+            billing: None,
+        };
+
+        let serialized_payment_method_data =
+            serde_json::to_value(&payment_method_data_with_billing).unwrap();
+        assert_eq!(serialized_payment_method_data["type"], "card");
+
+        let deserialized_payment_method_data: PaymentMethodDataResponseWithBilling =
+            serde_json::from_value(serialized_payment_method_data).unwrap();
+        assert_eq!(
+            deserialized_payment_method_data.payment_method_data,
+            payment_method_data_with_billing.payment_method_data
+        );
+    }
+
+    #[test]
+    fn test_untagged_payment_method_data_response_deserialization() {
+        let stored_payment_method_data = serde_json::json!({
+            "mandate_payment": {},
+            "billing": null
+        });
+
+        let payment_method_data_with_billing: PaymentMethodDataResponseWithBilling =
+            serde_json::from_value(stored_payment_method_data).unwrap();
+
+        assert_eq!(
+            payment_method_data_with_billing.payment_method_data,
+            Some(PaymentMethodDataResponse::MandatePayment {
+                mandate_payment: EmptyPaymentMethodDataResponse::default(),
+            })
+        );
+    }
 }
 
 /// Set of tests to extract billing details from payment method data
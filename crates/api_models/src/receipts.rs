@@ -0,0 +1,63 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// Format in which a payment receipt is rendered
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReceiptFormat {
+    Html,
+    Pdf,
+}
+
+/// Signed urls to download the receipt of a successful payment
+#[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentReceiptUrls {
+    /// Url to download the receipt as an HTML page
+    #[schema(
+        example = "https://sandbox.hyperswitch.io/receipts/merchant_1/pay_1/html?signature=..."
+    )]
+    pub html_url: String,
+
+    /// Url to download the receipt as a PDF document
+    #[schema(
+        example = "https://sandbox.hyperswitch.io/receipts/merchant_1/pay_1/pdf?signature=..."
+    )]
+    pub pdf_url: String,
+
+    /// Time after which the urls can no longer be used
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct ReceiptDownloadRequest {
+    pub merchant_id: id_type::MerchantId,
+    pub payment_id: id_type::PaymentId,
+    pub format: ReceiptFormat,
+}
+
+#[cfg(feature = "v1")]
+impl ApiEventMetric for ReceiptDownloadRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
@@ -153,6 +153,79 @@ pub struct RefundManualUpdateRequest {
     pub error_message: Option<String>,
 }
 
+/// Request to refund a payment to a bank account through a payout, instead of to the payment
+/// method the payment was made with
+#[cfg(feature = "payouts")]
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundTenderOverrideRequest {
+    /// The identifier of the payment to be refunded
+    #[serde(skip_deserializing)]
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// The amount to be refunded to the bank account, in the lowest denomination of the currency
+    /// of the payment. It is deducted from the amount of the payment that remains refundable.
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The reason for not refunding the payment to the payment method it was made with
+    #[schema(max_length = 255, example = "The card of the customer was closed")]
+    pub reason: String,
+    /// The details of the bank account to refund the payment to
+    #[schema(value_type = Bank)]
+    pub bank: crate::payouts::Bank,
+    /// The billing address of the holder of the bank account
+    #[schema(value_type = Option<Address>)]
+    pub billing: Option<crate::payments::Address>,
+    /// The payout connectors to be used for the refund, in the order of preference
+    #[schema(value_type = Option<Vec<PayoutConnectors>>, example = json!(["wise", "adyen"]))]
+    pub connector: Option<Vec<enums::PayoutConnectors>>,
+}
+
+/// A refund of a payment made to a bank account through a payout, instead of to the payment
+/// method the payment was made with
+#[cfg(feature = "payouts")]
+#[derive(Debug, ToSchema, Clone, Serialize)]
+pub struct RefundTenderOverrideResponse {
+    /// The identifier of the refund override
+    pub override_id: String,
+    /// The identifier of the refunded payment
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// The status of the refund, the amount of a pending refund is reserved until its payout is
+    /// created
+    #[schema(value_type = RefundTenderOverrideStatus)]
+    pub status: enums::RefundTenderOverrideStatus,
+    /// The identifier of the payout made to the bank account, once it has been created
+    pub payout_id: Option<String>,
+    /// The status of the payout made to the bank account, once it has been created
+    #[schema(value_type = Option<PayoutStatus>)]
+    pub payout_status: Option<enums::PayoutStatus>,
+    /// The amount refunded to the bank account
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the refunded amount
+    #[schema(value_type = Currency)]
+    pub currency: enums::Currency,
+    /// The reason for not refunding the payment to the payment method it was made with
+    pub reason: String,
+    /// The identifier of the user who made the refund
+    pub created_by: String,
+    /// The time at which the refund was made
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The refunds of a payment made to bank accounts through payouts
+#[cfg(feature = "payouts")]
+#[derive(Debug, ToSchema, Clone, Serialize)]
+pub struct RefundTenderOverrideListResponse {
+    /// The identifier of the refunded payment
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// The refunds of the payment made to bank accounts
+    pub overrides: Vec<RefundTenderOverrideResponse>,
+}
+
 /// To indicate whether to refund needs to be instant or scheduled
 #[derive(
     Default, Debug, Clone, Copy, ToSchema, Deserialize, Serialize, Eq, PartialEq, strum::Display,
@@ -206,6 +279,16 @@ pub struct RefundResponse {
     /// Charge specific fields for controlling the revert of funds from either platform or connected account
     #[schema(value_type = Option<ChargeRefunds>)]
     pub charges: Option<ChargeRefunds>,
+    /// The currency in which the connector settles the refund, if it differs from the currency of the payment
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub settlement_currency: Option<enums::Currency>,
+    /// The refund amount in the settlement currency, in its lowest denomination
+    #[schema(value_type = Option<i64>, example = 6012)]
+    pub settlement_amount: Option<MinorUnit>,
+    /// The time by which the pending refund is expected to be settled, estimated from the time the connector took to settle the recent refunds
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expected_settlement_at: Option<PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
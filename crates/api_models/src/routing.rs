@@ -280,6 +280,64 @@ pub enum RoutingAlgorithmKind {
     Dynamic,
 }
 
+/// Explains how the connector for a payment attempt was chosen
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingDecisionTrace {
+    /// The routing algorithm active on the profile, if any
+    #[schema(value_type = Option<String>)]
+    pub algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// The kind of the routing algorithm, not present when the profile default fallback was used
+    pub algorithm_kind: Option<RoutingAlgorithmKind>,
+    /// The name of the rule which matched the payment, for advanced routing algorithms
+    pub matched_rule: Option<String>,
+    /// The connector the attempt was forced to by the confirm request, bypassing the routing
+    /// algorithm of the profile
+    pub connector_override: Option<RoutableConnectorChoice>,
+    /// The connectors chosen by the routing algorithm, in order of preference
+    pub routed_connectors: Vec<RoutableConnectorChoice>,
+    /// The connectors among the routed connectors which passed the eligibility analysis
+    pub eligible_connectors: Vec<RoutableConnectorChoice>,
+    /// The connectors of the profile default fallback, appended after the eligible connectors
+    pub fallback_connectors: Vec<RoutableConnectorChoice>,
+    /// Whether the connectors were reordered by success based dynamic routing
+    pub dynamic_routing_applied: bool,
+    /// The experiments the payment attempt was enrolled in, with the arm it was assigned to
+    #[serde(default)]
+    pub experiments: Vec<crate::experiments::ExperimentAssignment>,
+    /// The connectors which were moved to the end of the list as they were degraded due to a
+    /// probable outage
+    #[serde(default)]
+    pub degraded_connectors: Vec<RoutableConnectorChoice>,
+    /// Whether the payment was routed to the synthetic connector of the simulation mode
+    #[serde(default)]
+    pub simulation_mode_applied: bool,
+    /// The final list of connectors considered for the attempt, in order of preference
+    pub final_connectors: Vec<RoutableConnectorChoice>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentAttemptRoutingDecision {
+    /// The identifier for the payment attempt
+    pub attempt_id: String,
+    /// The connector the payment attempt was routed to
+    pub connector: Option<String>,
+    /// Explains how the connector was chosen, not present if the connector was not chosen by
+    /// the routing algorithm of the profile
+    pub routing_decision: Option<RoutingDecisionTrace>,
+    /// The time at which the payment attempt was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentRoutingDecisionResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// The routing decisions of the attempts of the payment, latest first
+    pub attempts: Vec<PaymentAttemptRoutingDecision>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct RoutingPayloadWrapper {
     pub updated_config: Vec<RoutableConnectorChoice>,
@@ -680,3 +738,61 @@ impl CurrentBlockThreshold {
         }
     }
 }
+
+/// The reason for which the router took an orchestration decision on a payment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OrchestrationDecisionReason {
+    /// The connector requested the payment to be retried without 3DS exemptions
+    StepUp,
+    /// The connector declined the payment with an error that is configured to be retried
+    AutoRetry,
+    /// The connector is degraded and the payment was routed to the next eligible connector
+    ConnectorDegraded,
+}
+
+/// An orchestration decision taken by the router while processing a payment, such as a retry on
+/// another connector or routing around a degraded connector
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PaymentOrchestrationEventResponse {
+    /// The identifier of the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: common_utils::id_type::PaymentId,
+
+    /// The identifier of the merchant
+    #[schema(value_type = String, example = "merchant_1668273825")]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The business profile of the payment
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The payment attempt on which the decision was taken
+    pub attempt_id: Option<String>,
+
+    /// The connector the payment was routed to before the decision
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+
+    /// The connector the payment is routed to after the decision
+    #[schema(example = "adyen")]
+    pub next_connector: Option<String>,
+
+    /// The payment attempt created by the router for the retry
+    pub next_attempt_id: Option<String>,
+
+    /// The reason for which the decision was taken
+    #[schema(value_type = OrchestrationDecisionReason, example = "auto_retry")]
+    pub reason: OrchestrationDecisionReason,
+
+    /// The error code returned by the connector that led to the decision
+    pub error_code: Option<String>,
+
+    /// The error message returned by the connector that led to the decision
+    pub error_message: Option<String>,
+
+    /// The time at which the decision was taken
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
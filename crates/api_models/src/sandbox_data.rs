@@ -0,0 +1,107 @@
+use common_enums::{SandboxDataResetStatus, SandboxDataResource};
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SandboxDataResetResponse {
+    /// The identifier of the reset
+    #[schema(example = "reset_tDgCEt0cfQCBoCn0SbD2")]
+    pub reset_id: String,
+
+    /// The status of the reset
+    #[schema(value_type = SandboxDataResetStatus, example = "in_progress")]
+    pub status: SandboxDataResetStatus,
+
+    /// The resource being deleted, while the reset is in progress
+    #[schema(value_type = Option<SandboxDataResource>, example = "payments")]
+    pub current_resource: Option<SandboxDataResource>,
+
+    /// Number of records deleted so far, for each of the resources
+    pub progress: Vec<SandboxDataResetProgress>,
+
+    /// The reason the reset failed, if it did
+    pub error_message: Option<String>,
+
+    /// The time the reset was requested at
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time the reset completed or failed at
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:13:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SandboxDataResetProgress {
+    /// The resource deleted by the reset
+    #[schema(value_type = SandboxDataResource, example = "customers")]
+    pub resource: SandboxDataResource,
+
+    /// Number of records of the resource deleted so far
+    #[schema(example = 120)]
+    pub deleted_records: u64,
+}
+
+impl ApiEventMetric for SandboxDataResetResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DemoConnectorsRequest {
+    /// The profile to which the demo connectors are attached, the default profile of the merchant
+    /// account is used if not provided
+    #[schema(value_type = Option<String>, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct DemoConnectorsResponse {
+    /// Whether the merchant account has opted into the demo connectors
+    pub opted_in: bool,
+
+    /// The connector accounts created for the merchant account with the credentials of the demo
+    /// connectors
+    pub connectors: Vec<DemoConnectorAccount>,
+
+    /// Number of payments the merchant account can make through the demo connectors in a day
+    #[schema(example = 100)]
+    pub daily_payment_quota: u64,
+
+    /// Number of payments the merchant account has made through the demo connectors today
+    #[schema(example = 12)]
+    pub payments_today: u64,
+
+    /// The time the merchant account opted into the demo connectors at
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub opted_in_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct DemoConnectorAccount {
+    /// Name of the connector
+    #[schema(example = "stripe")]
+    pub connector_name: String,
+
+    /// The connector account created for the merchant account
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+}
+
+impl ApiEventMetric for DemoConnectorsRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for DemoConnectorsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,169 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    types::MinorUnit,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::Currency;
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SimulationModeUpdateRequest {
+    /// Whether the payments of the merchant are routed to the synthetic connector
+    pub enabled: bool,
+
+    /// The merchant connector account of a dummy connector, which the payments of the merchant are
+    /// routed to. Required when enabling the simulation mode
+    #[schema(value_type = Option<String>, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct SimulationModeResponse {
+    /// Whether the payments of the merchant are routed to the synthetic connector
+    pub enabled: bool,
+
+    /// The merchant connector account which the payments of the merchant are routed to
+    #[schema(value_type = Option<String>, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+
+    /// The name of the synthetic connector
+    #[schema(example = "phonypay")]
+    pub connector_name: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LoadTestStage {
+    /// Duration of the stage, in seconds
+    #[schema(example = 60)]
+    pub duration_in_secs: u32,
+
+    /// Number of payments created every second during the stage
+    #[schema(example = 50)]
+    pub requests_per_second: u32,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum LoadTestTrafficShape {
+    /// Creates payments at a fixed rate in each stage, one stage after another
+    Stages {
+        /// The stages of the load test
+        stages: Vec<LoadTestStage>,
+    },
+
+    /// Replays the shape of the payments created by the merchant, as recorded in the API logs of
+    /// the merchant
+    Recorded {
+        /// Only replay the payments created after this time
+        #[schema(value_type = Option<PrimitiveDateTime>)]
+        #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+        created_after: Option<PrimitiveDateTime>,
+
+        /// Only replay the payments created before this time
+        #[schema(value_type = Option<PrimitiveDateTime>)]
+        #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+        created_before: Option<PrimitiveDateTime>,
+
+        /// The factor by which the recorded rate of payments is multiplied, defaults to 1
+        #[schema(example = 10)]
+        rate_multiplier: Option<u32>,
+    },
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LoadTestCreateRequest {
+    /// The shape of the traffic generated by the load test
+    pub shape: LoadTestTrafficShape,
+
+    /// The amount of each payment created by the load test, in the lowest denomination of the
+    /// currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The currency of each payment created by the load test
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: Currency,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LoadTestStatus {
+    /// Payments are being created by the load test
+    Running,
+    /// All the payments of the load test have been created
+    Completed,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct LoadTestId {
+    pub load_test_id: String,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct LoadTestResponse {
+    /// The identifier of the load test
+    #[schema(example = "load_test_tDgCEt0cfQCBoCn0SbD2")]
+    pub load_test_id: String,
+
+    /// The status of the load test
+    pub status: LoadTestStatus,
+
+    /// The stages the traffic of the load test was generated in
+    pub stages: Vec<LoadTestStage>,
+
+    /// The number of payments the load test creates in total
+    pub planned_requests: u64,
+
+    /// The number of payment requests sent so far
+    pub sent_requests: u64,
+
+    /// The number of payment requests which were responded to successfully
+    pub succeeded_requests: u64,
+
+    /// The number of payment requests which failed
+    pub failed_requests: u64,
+
+    /// The time at which the load test was started
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the load test completed
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:12:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+impl ApiEventMetric for SimulationModeUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for SimulationModeResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for LoadTestCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for LoadTestId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for LoadTestResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
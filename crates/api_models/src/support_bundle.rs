@@ -0,0 +1,180 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::{enums, webhook_events::EventListItemResponse};
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SupportBundleRequest {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+}
+
+/// Diagnostic data of a payment assembled for escalations. Customer and payment method details,
+/// as well as the bodies of the connector calls and webhooks, are left out or masked.
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct SupportBundle {
+    /// The identifier of the payment
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+
+    /// The identifier of the merchant account
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// Time at which the bundle was generated
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub generated_at: PrimitiveDateTime,
+
+    /// The payment and its attempts, refunds and disputes, the earliest first
+    pub timeline: Vec<SupportBundleTimelineEntry>,
+
+    /// The routing outcome of every attempt of the payment
+    pub routing_trace: Vec<SupportBundleRoutingTrace>,
+
+    /// The calls made to the connectors for the payment, with sensitive fields masked
+    #[schema(value_type = Vec<Object>)]
+    pub connector_events: Vec<serde_json::Value>,
+
+    /// The failed connector calls captured for the payment, with sensitive fields masked
+    #[schema(value_type = Vec<Object>)]
+    pub failed_connector_calls: Vec<serde_json::Value>,
+
+    /// Every delivery attempt of the webhooks sent for the payment
+    pub webhook_deliveries: Vec<SupportBundleWebhookDelivery>,
+
+    /// The scheduler tasks of the payment and of its webhooks
+    pub scheduler_tasks: Vec<SupportBundleSchedulerTask>,
+
+    /// The sections of the bundle which could not be collected, with the reason
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SupportBundleObject {
+    Payment,
+    PaymentAttempt,
+    Refund,
+    Dispute,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SupportBundleTimelineEntry {
+    /// The type of the object
+    pub object: SupportBundleObject,
+
+    /// The identifier of the object
+    pub object_id: String,
+
+    /// The current status of the object
+    pub status: String,
+
+    /// The connector processing the object
+    pub connector: Option<String>,
+
+    /// The identifier of the object at the connector
+    pub connector_reference_id: Option<String>,
+
+    /// The identifier assigned by the connector to its latest request for the object
+    pub connector_request_id: Option<String>,
+
+    /// The identifier of the request which last called the connector for the object
+    pub correlation_id: Option<String>,
+
+    /// The amount of the object, in the lowest denomination of its currency
+    #[schema(value_type = Option<i64>)]
+    pub amount: Option<common_utils::types::MinorUnit>,
+
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<enums::Currency>,
+
+    pub error_code: Option<String>,
+
+    pub error_message: Option<String>,
+
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SupportBundleRoutingTrace {
+    /// The identifier of the attempt
+    pub attempt_id: String,
+
+    /// The connector the attempt was routed to
+    pub connector: Option<String>,
+
+    /// The merchant connector account the attempt was routed to
+    #[schema(value_type = Option<String>)]
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+
+    /// The routing algorithm passed in the payment request
+    #[schema(value_type = Option<Object>)]
+    pub straight_through_algorithm: Option<serde_json::Value>,
+
+    /// The decision taken by the routing engine for the attempt
+    #[schema(value_type = Option<Object>)]
+    pub routing_decision: Option<serde_json::Value>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct SupportBundleWebhookDelivery {
+    #[serde(flatten)]
+    pub event_information: EventListItemResponse,
+
+    /// The type of the delivery attempt
+    #[schema(value_type = Option<WebhookDeliveryAttempt>)]
+    pub delivery_attempt: Option<enums::WebhookDeliveryAttempt>,
+
+    /// The HTTP status code returned by the merchant's endpoint
+    pub status_code: Option<u16>,
+
+    /// The error which occurred while delivering the webhook
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct SupportBundleSchedulerTask {
+    /// The identifier of the task
+    pub id: String,
+
+    /// The name of the task
+    pub name: Option<String>,
+
+    /// The workflow executing the task
+    pub runner: Option<String>,
+
+    /// The status of the task
+    pub status: String,
+
+    /// The outcome of the task, as recorded by its workflow
+    pub business_status: String,
+
+    /// The number of times the task has been retried
+    pub retry_count: i32,
+
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for SupportBundleRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for SupportBundle {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
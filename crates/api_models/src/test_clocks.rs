@@ -0,0 +1,95 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TestClockCreateRequest {
+    /// A name to identify the test clock
+    #[schema(example = "Subscription renewal")]
+    pub name: Option<String>,
+
+    /// The time the test clock starts at, defaults to the current time
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub frozen_time: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TestClockAttachRequest {
+    /// Identifiers of the payments, mandates or other resources, whose scheduled tasks are to be
+    /// driven by the test clock
+    #[schema(example = json!(["pay_mbabizu24mvu3mela5njyhpit4"]))]
+    pub resource_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TestClockAdvanceRequest {
+    /// The time to advance the test clock to, which must be later than its current time
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-11T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub frozen_time: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct TestClockId {
+    pub clock_id: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct TestClockResponse {
+    /// The identifier of the test clock
+    #[schema(example = "clock_tDgCEt0cfQCBoCn0SbD2")]
+    pub clock_id: String,
+
+    /// The name of the test clock
+    pub name: Option<String>,
+
+    /// The time the test clock was started at
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+
+    /// The current time of the test clock
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-11T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub frozen_time: PrimitiveDateTime,
+
+    /// Identifiers of the resources attached to the test clock
+    pub resource_ids: Vec<String>,
+
+    /// Number of scheduled tasks which became due and were triggered by the last advance
+    pub triggered_tasks: usize,
+}
+
+impl ApiEventMetric for TestClockCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for TestClockAttachRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for TestClockAdvanceRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for TestClockId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for TestClockResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
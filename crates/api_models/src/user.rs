@@ -69,6 +69,11 @@ pub struct InviteUserRequest {
     pub email: pii::Email,
     pub name: Secret<String>,
     pub role_id: String,
+    /// Merchant account the user is invited to, defaults to the merchant account of the inviter
+    pub merchant_id: Option<id_type::MerchantId>,
+    /// Profile the user is invited to, defaults to the profile of the inviter when inviting to the
+    /// same merchant account
+    pub profile_id: Option<id_type::ProfileId>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -84,6 +89,8 @@ pub struct InviteMultipleUserResponse {
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
 pub struct ReInviteUserRequest {
     pub email: pii::Email,
+    pub merchant_id: Option<id_type::MerchantId>,
+    pub profile_id: Option<id_type::ProfileId>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
@@ -182,6 +189,12 @@ pub struct UpdateUserAccountDetailsRequest {
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct SkipTwoFactorAuthQueryParam {
     pub skip_two_factor_auth: Option<bool>,
+    pub remember_device: Option<bool>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct TwoFactorAuthPolicy {
+    pub force_two_factor_auth: bool,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -253,6 +266,9 @@ pub enum AuthConfig {
         private_config: OpenIdConnectPrivateConfig,
         public_config: OpenIdConnectPublicConfig,
     },
+    Saml {
+        public_config: SamlPublicConfig,
+    },
     MagicLink,
     Password,
 }
@@ -268,6 +284,54 @@ pub struct OpenIdConnectPrivateConfig {
 #[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
 pub struct OpenIdConnectPublicConfig {
     pub name: OpenIdProvider,
+    /// Provisioning of the users signing in for the first time, such users are rejected if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jit_provisioning: Option<SsoJitProvisioningConfig>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct SamlPublicConfig {
+    /// Entity id of the identity provider, the issuer of the responses and the assertions
+    pub idp_entity_id: String,
+    /// Single sign on url of the identity provider, the authentication requests are sent to it
+    pub idp_sso_url: String,
+    /// PEM encoded certificate the identity provider signs the responses or assertions with
+    pub idp_certificate: String,
+    /// Entity id of the dashboard at the identity provider, the audience of the assertions
+    pub sp_entity_id: String,
+    /// Attribute holding the email of the user, the name id of the subject is used if not set
+    #[serde(default)]
+    pub email_attribute: Option<String>,
+    /// Attribute holding the groups of the user, `groups` is used if not set
+    #[serde(default)]
+    pub groups_attribute: Option<String>,
+    /// Whether the users can sign in from the identity provider without an authentication request
+    #[serde(default)]
+    pub allow_idp_initiated_sign_in: bool,
+    /// Provisioning of the users signing in for the first time, such users are rejected if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jit_provisioning: Option<SsoJitProvisioningConfig>,
+}
+
+/// Just in time provisioning of the users signing in with an OpenID Connect or SAML identity
+/// provider.
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct SsoJitProvisioningConfig {
+    /// Merchant the provisioned users are added to when their role is a merchant level role
+    pub merchant_id: id_type::MerchantId,
+    /// Roles of the provisioned users based on their groups at the identity provider, the first
+    /// mapping matching any of the groups of the user is used
+    #[serde(default)]
+    pub group_role_mappings: Vec<SsoGroupRoleMapping>,
+    /// Role of the provisioned users not matching any of the mappings, such users are rejected
+    /// if not set
+    pub default_role_id: Option<String>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct SsoGroupRoleMapping {
+    pub group: String,
+    pub role_id: String,
 }
 
 #[derive(
@@ -326,6 +390,8 @@ pub struct AuthMethodDetails {
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct GetSsoAuthUrlRequest {
     pub id: String,
+    /// Issuer of the identity provider, sent when the sign in is initiated by the identity provider
+    pub iss: Option<String>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -334,6 +400,15 @@ pub struct SsoSignInRequest {
     pub code: Secret<String>,
 }
 
+/// Response of the identity provider posted to the assertion consumer service of the dashboard
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct SamlAcsRequest {
+    #[serde(rename = "SAMLResponse")]
+    pub saml_response: Secret<String>,
+    #[serde(rename = "RelayState")]
+    pub relay_state: Option<Secret<String>>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 pub struct AuthIdQueryParam {
     pub auth_id: Option<String>,
@@ -1,5 +1,5 @@
 use common_enums::{ParentGroup, PermissionGroup};
-use common_utils::pii;
+use common_utils::{id_type, pii};
 use masking::Secret;
 
 pub mod role;
@@ -45,10 +45,27 @@ pub struct DeleteUserRoleRequest {
     pub email: pii::Email,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct RevokeInvitationRequest {
+    pub email: pii::Email,
+    pub merchant_id: Option<id_type::MerchantId>,
+    pub profile_id: Option<id_type::ProfileId>,
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct ListUsersInEntityResponse {
     pub email: pii::Email,
     pub roles: Vec<role::MinimalRoleInfo>,
+    /// Active if the user has accepted any of the roles, only pending invitations otherwise
+    pub status: UserStatus,
+    /// Expiry of the earliest pending invitation of the user
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        with = "common_utils::custom_serde::iso8601::option"
+    )]
+    pub invitation_expires_at: Option<time::PrimitiveDateTime>,
+    /// Permission groups granted by the accepted roles of the user
+    pub permission_groups: Vec<PermissionGroup>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
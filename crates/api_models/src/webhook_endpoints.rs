@@ -0,0 +1,231 @@
+use common_enums::EventType;
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use masking::Secret;
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointCreateRequest {
+    /// The URL to which the outgoing webhooks are posted
+    #[schema(value_type = String, example = "https://merchant.example.com/webhooks/finance")]
+    pub url: url::Url,
+
+    /// A description of the webhook endpoint
+    #[schema(max_length = 255, example = "Refund webhooks for the finance team")]
+    pub description: Option<String>,
+
+    /// The event types posted to the endpoint. All event types are posted if not set.
+    #[schema(value_type = Option<Vec<EventType>>)]
+    pub enabled_events: Option<Vec<EventType>>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointUpdateRequest {
+    /// The URL to which the outgoing webhooks are posted
+    #[schema(value_type = Option<String>, example = "https://merchant.example.com/webhooks/finance")]
+    pub url: Option<url::Url>,
+
+    /// A description of the webhook endpoint
+    #[schema(max_length = 255, example = "Refund webhooks for the finance team")]
+    pub description: Option<String>,
+
+    /// The event types posted to the endpoint
+    #[schema(value_type = Option<Vec<EventType>>)]
+    pub enabled_events: Option<Vec<EventType>>,
+
+    /// Whether outgoing webhooks are posted to the endpoint
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct WebhookEndpointResponse {
+    /// The identifier of the webhook endpoint
+    #[schema(example = "whe_5RwYd3YVFC4vyhtVyHJy")]
+    pub endpoint_id: String,
+
+    /// The identifier of the business profile of the webhook endpoint
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The URL to which the outgoing webhooks are posted
+    pub url: String,
+
+    /// A description of the webhook endpoint
+    pub description: Option<String>,
+
+    /// The event types posted to the endpoint. All event types are posted if not set.
+    #[schema(value_type = Option<Vec<EventType>>)]
+    pub enabled_events: Option<Vec<EventType>>,
+
+    /// Whether outgoing webhooks are posted to the endpoint
+    pub is_active: bool,
+
+    /// The secret with which the outgoing webhooks posted to the endpoint are signed
+    #[schema(value_type = String)]
+    pub signing_secret: Secret<String>,
+
+    /// The deliveries of outgoing webhooks to the endpoint
+    pub delivery_stats: WebhookEndpointDeliveryStats,
+
+    /// The time at which the webhook endpoint was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the webhook endpoint was last modified
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, ToSchema)]
+pub struct WebhookEndpointDeliveryStats {
+    /// The number of outgoing webhooks delivered to the endpoint
+    pub succeeded: u64,
+
+    /// The number of outgoing webhooks which could not be delivered to the endpoint
+    pub failed: u64,
+
+    /// The number of outgoing webhooks which could not be delivered to the endpoint since the last
+    /// one which was delivered
+    pub consecutive_failures: u64,
+
+    /// The time at which an outgoing webhook was last delivered to the endpoint
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_delivered_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct WebhookEndpointMetricsResponse {
+    /// The identifier of the webhook endpoint
+    #[schema(example = "whe_5RwYd3YVFC4vyhtVyHJy")]
+    pub endpoint_id: String,
+
+    /// The number of recorded delivery attempts from which the success rate and latency are
+    /// computed
+    pub delivery_attempts: usize,
+
+    /// The percentage of the recorded delivery attempts which were successful
+    #[schema(example = 99.5)]
+    pub success_rate: Option<f64>,
+
+    /// The 95th percentile of the time taken by the endpoint to respond to the recorded delivery
+    /// attempts, in milliseconds
+    pub p95_latency_in_ms: Option<u64>,
+
+    /// The number of outgoing webhooks which could not be delivered to the endpoint since the last
+    /// one which was delivered
+    pub consecutive_failures: u64,
+
+    /// The time at which an outgoing webhook was last delivered to the endpoint
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_delivered_at: Option<PrimitiveDateTime>,
+}
+
+/// An attempt to deliver an outgoing webhook to a webhook endpoint
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct WebhookEndpointDeliveryAttempt {
+    /// The identifier of the event of the outgoing webhook
+    #[schema(example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: String,
+
+    /// The type of the event of the outgoing webhook
+    pub event_type: EventType,
+
+    /// Whether the outgoing webhook was delivered to the endpoint
+    pub is_delivered: bool,
+
+    /// The HTTP status code with which the endpoint responded
+    #[schema(example = 200)]
+    pub status_code: Option<u16>,
+
+    /// The body of the response of the endpoint, truncated to the configured length
+    pub response_body: Option<String>,
+
+    /// The reason the outgoing webhook could not be sent to the endpoint, if no response was
+    /// received
+    pub error_message: Option<String>,
+
+    /// The time taken by the endpoint to respond, in milliseconds
+    pub latency_in_ms: u64,
+
+    /// The time at which the delivery was attempted
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointDeliveryAttemptsListConstraints {
+    /// The maximum number of delivery attempts to include in the response
+    pub limit: Option<u16>,
+
+    /// The number of most recent delivery attempts to skip
+    pub offset: Option<u16>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct WebhookEndpointDeliveryAttemptsListResponse {
+    /// The number of delivery attempts included in the response
+    pub count: usize,
+
+    /// The number of recorded delivery attempts of the webhook endpoint
+    pub total_count: usize,
+
+    /// The delivery attempts, with the most recent attempt first
+    pub data: Vec<WebhookEndpointDeliveryAttempt>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct WebhookEndpointListResponse {
+    /// The number of webhook endpoints of the business profile
+    pub count: usize,
+
+    /// The webhook endpoints of the business profile
+    pub data: Vec<WebhookEndpointResponse>,
+}
+
+impl ApiEventMetric for WebhookEndpointCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointMetricsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointDeliveryAttemptsListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointDeliveryAttemptsListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
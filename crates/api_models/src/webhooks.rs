@@ -5,7 +5,10 @@ use utoipa::ToSchema;
 
 #[cfg(feature = "payouts")]
 use crate::payouts;
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+use crate::{
+    admin, disputes, enums as api_enums, invoices, mandates, payment_methods, payments, refunds,
+    routing,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -39,6 +42,15 @@ pub enum IncomingWebhookEvent {
     DisputeLost,
     MandateActive,
     MandateRevoked,
+    /// The reusable wallet token issued by the connector has been updated, such as when the
+    /// underlying card was renewed
+    WalletTokenUpdated,
+    /// The reusable wallet token issued by the connector can no longer be used
+    WalletTokenExpired,
+    /// The reusable wallet token issued by the connector has been temporarily suspended
+    WalletTokenSuspended,
+    /// The suspended wallet token issued by the connector can be used again
+    WalletTokenResumed,
     EndpointVerification,
     ExternalAuthenticationARes,
     FrmApproved,
@@ -69,6 +81,7 @@ pub enum WebhookFlow {
     ReturnResponse,
     BankTransfer,
     Mandate,
+    WalletToken,
     ExternalAuthentication,
     FraudCheck,
 }
@@ -99,6 +112,10 @@ pub enum WebhookResponseTracker {
         mandate_id: String,
         status: common_enums::MandateStatus,
     },
+    PaymentMethod {
+        payment_method_id: String,
+        status: common_enums::ConnectorMandateStatus,
+    },
     NoEffect,
 }
 
@@ -108,7 +125,7 @@ impl WebhookResponseTracker {
             Self::Payment { payment_id, .. }
             | Self::Refund { payment_id, .. }
             | Self::Dispute { payment_id, .. } => Some(payment_id.to_owned()),
-            Self::NoEffect | Self::Mandate { .. } => None,
+            Self::NoEffect | Self::Mandate { .. } | Self::PaymentMethod { .. } => None,
             #[cfg(feature = "payouts")]
             Self::Payout { .. } => None,
         }
@@ -136,6 +153,10 @@ impl From<IncomingWebhookEvent> for WebhookFlow {
             IncomingWebhookEvent::MandateActive | IncomingWebhookEvent::MandateRevoked => {
                 Self::Mandate
             }
+            IncomingWebhookEvent::WalletTokenUpdated
+            | IncomingWebhookEvent::WalletTokenExpired
+            | IncomingWebhookEvent::WalletTokenSuspended
+            | IncomingWebhookEvent::WalletTokenResumed => Self::WalletToken,
             IncomingWebhookEvent::DisputeOpened
             | IncomingWebhookEvent::DisputeAccepted
             | IncomingWebhookEvent::DisputeExpired
@@ -217,6 +238,10 @@ pub struct OutgoingWebhook {
     #[schema(value_type = EventType)]
     pub event_type: api_enums::EventType,
 
+    /// The version of the schema of this payload
+    #[schema(value_type = OutgoingWebhookSchemaVersion)]
+    pub schema_version: api_enums::OutgoingWebhookSchemaVersion,
+
     /// This is specific to the flow, for ex: it will be `PaymentsResponse` for payments flow
     pub content: OutgoingWebhookContent,
 
@@ -236,9 +261,32 @@ pub enum OutgoingWebhookContent {
     DisputeDetails(Box<disputes::DisputeResponse>),
     #[schema(value_type = MandateResponse, title = "MandateResponse")]
     MandateDetails(Box<mandates::MandateResponse>),
+    #[schema(value_type = MandateDebitResponse, title = "MandateDebitResponse")]
+    MandateDebitDetails(Box<mandates::MandateDebitResponse>),
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = InvoiceResponse, title = "InvoiceResponse")]
+    InvoiceDetails(Box<invoices::InvoiceResponse>),
+    #[schema(
+        value_type = PaymentMethodCollectLinkResponse,
+        title = "PaymentMethodCollectLinkResponse"
+    )]
+    PaymentMethodCollectLinkDetails(Box<payment_methods::PaymentMethodCollectLinkResponse>),
+    #[schema(value_type = PaymentMethodTokenResponse, title = "PaymentMethodTokenResponse")]
+    PaymentMethodTokenDetails(Box<payment_methods::PaymentMethodTokenResponse>),
+    #[schema(
+        value_type = PaymentOrchestrationEventResponse,
+        title = "PaymentOrchestrationEventResponse"
+    )]
+    OrchestrationDetails(Box<routing::PaymentOrchestrationEventResponse>),
+    #[schema(
+        value_type = ConnectorCredentialHealthResponse,
+        title = "ConnectorCredentialHealthResponse"
+    )]
+    ConnectorCredentialHealthDetails(Box<admin::ConnectorCredentialHealthResponse>),
+    #[schema(value_type = PaymentShipmentResponse, title = "PaymentShipmentResponse")]
+    ShipmentDetails(Box<payments::PaymentShipmentResponse>),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -67,6 +67,7 @@ pub enum RoutableConnectors {
     Cryptopay,
     Cybersource,
     Datatrans,
+    Declarative,
     Deutschebank,
     Digitalvirgo,
     Dlocal,
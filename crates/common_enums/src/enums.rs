@@ -321,6 +321,7 @@ pub enum BlocklistDataKind {
     PaymentMethod,
     CardBin,
     ExtendedCardBin,
+    Email,
 }
 
 /// Default value if not passed is set to 'automatic' which results in Auth and Capture in one single API request. Pass 'manual' or 'manual_multiple' in case you want do a separate Auth and Capture by first authorizing and placing a hold on your customer's funds so that you can use the Payments/Capture endpoint later to capture the authorized amount. Pass 'manual' if you want to only capture the amount later once or 'manual_multiple' if you want to capture the funds multiple times later. Both 'manual' and 'manual_multiple' are only supported by a specific list of processors
@@ -1158,6 +1159,11 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    Invoices,
+    PaymentMethods,
+    Orchestration,
+    MerchantConnectors,
+    Shipments,
 }
 
 #[derive(
@@ -1196,6 +1202,8 @@ pub enum EventType {
     DisputeLost,
     MandateActive,
     MandateRevoked,
+    /// Sent ahead of a scheduled debit on a mandate, so that the customer can be notified of it
+    MandatePreDebitNotification,
     PayoutSuccess,
     PayoutFailed,
     PayoutInitiated,
@@ -1203,6 +1211,35 @@ pub enum EventType {
     PayoutCancelled,
     PayoutExpired,
     PayoutReversed,
+    InvoicePaid,
+    InvoicePartiallyPaid,
+    InvoiceOverdue,
+    /// Sent ahead of and after the due date of an invoice that is not fully paid
+    InvoiceReminder,
+    /// The customer saved a payment method through a payment method collect link
+    PaymentMethodCollected,
+    /// The token of a payment method was suspended by the card network or the connector
+    PaymentMethodTokenSuspended,
+    /// The token of a suspended payment method was resumed
+    PaymentMethodTokenResumed,
+    /// The token of a payment method was deleted by the card network or the connector
+    PaymentMethodTokenDeleted,
+    /// The card of a payment method was updated by the account updater of the connector
+    PaymentMethodUpdated,
+    /// The router retried a failed payment attempt, on the same or another connector
+    PaymentRetryTriggered,
+    /// The router routed the payment away from a degraded connector
+    PaymentConnectorSwitched,
+    /// The router stopped sending the traffic of the merchant to a degraded connector
+    ConnectorCircuitBreakerOpened,
+    /// The credentials of a merchant connector account were rejected by the connector
+    ConnectorCredentialsInvalid,
+    /// The credentials of a merchant connector account were accepted again by the connector
+    ConnectorCredentialsRestored,
+    /// The amount of a shipment of the order was captured
+    ShipmentCaptured,
+    /// The capture of the amount of a shipment of the order failed
+    ShipmentCaptureFailed,
 }
 
 #[derive(
@@ -1226,6 +1263,79 @@ pub enum WebhookDeliveryAttempt {
     ManualRetry,
 }
 
+/// The payload format in which outgoing webhooks are delivered to a webhook endpoint
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OutgoingWebhookFormat {
+    /// The native outgoing webhook payload
+    #[default]
+    Hyperswitch,
+    /// Adyen's notification item format, for merchants migrating off Adyen
+    Adyen,
+}
+
+/// The version of the schema of the native outgoing webhook payload. Profiles pin a version so
+/// that changes to the payload do not break existing webhook endpoints.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OutgoingWebhookSchemaVersion {
+    #[default]
+    V1,
+}
+
+/// Normalized reason for cancelling a payment, which is mapped to the reason codes of the
+/// connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CancellationReason {
+    /// The payment was created more than once
+    Duplicate,
+    /// The payment is suspected to be fraudulent
+    Fraudulent,
+    /// The customer asked for the payment to be cancelled
+    RequestedByCustomer,
+    /// The customer didn't complete the purchase
+    Abandoned,
+}
+
 // TODO: This decision about using KV mode or not,
 // should be taken at a top level rather than pushing it down to individual functions via an enum.
 #[derive(
@@ -1298,6 +1408,22 @@ pub enum IntentStatus {
 }
 
 impl IntentStatus {
+    /// Indicates whether the payment has reached a state from which no further status
+    /// transitions are expected. A partially captured payment can still be captured or refunded.
+    pub fn is_in_terminal_state(self) -> bool {
+        match self {
+            Self::Succeeded | Self::Failed | Self::Cancelled => true,
+            Self::PartiallyCaptured
+            | Self::Processing
+            | Self::RequiresCustomerAction
+            | Self::RequiresMerchantAction
+            | Self::RequiresPaymentMethod
+            | Self::RequiresConfirmation
+            | Self::RequiresCapture
+            | Self::PartiallyCapturedAndCapturable => false,
+        }
+    }
+
     /// Indicates whether the syncing with the connector should be allowed or not
     pub fn should_force_sync_with_connector(&self) -> bool {
         match self {
@@ -1403,6 +1529,9 @@ pub enum PaymentMethodStatus {
     Processing,
     /// Indicates that the payment method is awaiting some data before changing state to active
     AwaitingData,
+    /// Indicates that the token of the payment method has been suspended by the card network or
+    /// the connector, and hence cannot be used for payments until it is resumed.
+    Suspended,
 }
 
 impl From<AttemptStatus> for PaymentMethodStatus {
@@ -2216,6 +2345,7 @@ pub enum FileUploadProvider {
     Router,
     Stripe,
     Checkout,
+    Rapyd,
 }
 
 #[derive(
@@ -3105,6 +3235,7 @@ pub enum TokenPurpose {
 #[serde(rename_all = "snake_case")]
 pub enum UserAuthType {
     OpenIdConnect,
+    Saml,
     MagicLink,
     #[default]
     Password,
@@ -3334,3 +3465,197 @@ pub enum ConnectorMandateStatus {
     /// Indicates that the connector mandate  is not active and hence cannot be used for payments.
     Inactive,
 }
+
+/// Payment status of an invoice
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum InvoiceStatus {
+    /// No amount has been paid towards the invoice yet
+    #[default]
+    Open,
+    /// Part of the invoice amount has been paid
+    PartiallyPaid,
+    /// The invoice amount has been paid in full
+    Paid,
+    /// The due date has passed without the invoice being paid in full
+    Overdue,
+}
+
+/// Kind of the movement of funds recorded in the ledger of a merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum LedgerEntryType {
+    /// The disputed amount withdrawn from the merchant once the dispute was lost
+    ChargebackDebit,
+    /// The fee charged by the connector for the lost dispute
+    ChargebackFee,
+    /// A pending refund which was reversed, as the customer was refunded through the chargeback
+    RefundReversal,
+}
+
+/// Status of a refund of a payment made to a bank account through a payout
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundTenderOverrideStatus {
+    /// The refund has been recorded and its payout is being created, the amount is reserved
+    Pending,
+    /// The payout of the refund has been created
+    PayoutCreated,
+    /// The payout of the refund could not be created, no amount was refunded
+    Failed,
+}
+
+/// Lifecycle status of a merchant account
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MerchantAccountStatus {
+    /// The merchant account can be used without restrictions
+    #[default]
+    Active,
+    /// New payments and payouts are rejected, while existing payments can still be captured,
+    /// refunded and disputed
+    Suspended,
+    /// The merchant account is read-only and is purged once its retention period is over
+    Closed,
+}
+
+/// Lifecycle status of the data encryption key of a merchant account
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MerchantKeyStatus {
+    /// The key is used to encrypt and decrypt the data of the merchant account
+    #[default]
+    Active,
+    /// The key is deleted, the data encrypted with it can no longer be decrypted
+    Destroyed,
+}
+
+/// Status of a job resetting the data of a sandbox merchant account
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SandboxDataResetStatus {
+    /// The reset has been scheduled and is yet to start
+    #[default]
+    Pending,
+    /// The data of the merchant account is being deleted
+    InProgress,
+    /// All the data of the merchant account has been deleted
+    Completed,
+    /// The reset stopped on an error, part of the data may have been deleted
+    Failed,
+}
+
+/// Data deleted by a reset of a sandbox merchant account, listed in the order it is deleted
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SandboxDataResource {
+    LedgerEntries,
+    RefundTenderOverrides,
+    Refunds,
+    Disputes,
+    PaymentAttempts,
+    Payments,
+    Mandates,
+    PaymentMethods,
+    Addresses,
+    Customers,
+}
@@ -162,3 +162,6 @@ pub const PAYOUT_FLOW_STR: &str = "payout_flow";
 /// The number of bytes allocated for the hashed connector transaction ID.
 /// Total number of characters equals CONNECTOR_TRANSACTION_ID_HASH_BYTES times 2.
 pub const CONNECTOR_TRANSACTION_ID_HASH_BYTES: usize = 25;
+
+/// Version assigned to a payment intent or attempt when it is created, incremented on every update
+pub const INITIAL_ROW_VERSION: i32 = 1;
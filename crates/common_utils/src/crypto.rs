@@ -8,6 +8,7 @@ use ring::{
     aead::{self, BoundKey, OpeningKey, SealingKey, UnboundKey},
     hmac,
 };
+use subtle::ConstantTimeEq;
 
 use crate::{
     errors::{self, CustomResult},
@@ -271,7 +272,7 @@ impl VerifySignature for Blake3 {
     ) -> CustomResult<bool, errors::CryptoError> {
         let key = blake3::derive_key(&self.0, secret);
         let output = blake3::keyed_hash(&key, msg);
-        Ok(output.as_bytes() == signature)
+        Ok(constant_time_eq(output.as_bytes(), signature))
     }
 }
 
@@ -367,7 +368,7 @@ impl VerifySignature for Sha512 {
                 .change_context(errors::CryptoError::SignatureVerificationFailed)?,
         );
         let hashed_digest_into_bytes = hashed_digest.into_bytes();
-        Ok(hashed_digest_into_bytes == signature)
+        Ok(constant_time_eq(&hashed_digest_into_bytes, signature))
     }
 }
 /// MD5 hash function
@@ -391,7 +392,7 @@ impl VerifySignature for Md5 {
         let hashed_digest = Self
             .generate_digest(msg)
             .change_context(errors::CryptoError::SignatureVerificationFailed)?;
-        Ok(hashed_digest == signature)
+        Ok(constant_time_eq(&hashed_digest, signature))
     }
 }
 
@@ -413,10 +414,26 @@ impl VerifySignature for Sha256 {
             .generate_digest(msg)
             .change_context(errors::CryptoError::SignatureVerificationFailed)?;
         let hashed_digest_into_bytes = hashed_digest.as_slice();
-        Ok(hashed_digest_into_bytes == signature)
+        Ok(constant_time_eq(hashed_digest_into_bytes, signature))
     }
 }
 
+/// Compare two byte slices in constant time, to be used when comparing signatures or other secrets
+/// so that the time taken does not reveal the length of the matching prefix
+#[inline]
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    bool::from(a.ct_eq(b))
+}
+
+/// Check whether the UNIX timestamp (in seconds) included in a signed message is within the given
+/// tolerance of the current time, so that captured messages cannot be replayed indefinitely
+pub fn is_timestamp_within_tolerance(timestamp: i64, tolerance_in_secs: i64) -> bool {
+    crate::date_time::now_unix_timestamp()
+        .saturating_sub(timestamp)
+        .saturating_abs()
+        <= tolerance_in_secs
+}
+
 /// Generate a random string using a cryptographically secure pseudo-random number generator
 /// (CSPRNG). Typically used for generating (readable) keys and passwords.
 #[inline]
@@ -554,7 +571,25 @@ pub type EncryptableEmail = Encryptable<Secret<String, pii::EmailStrategy>>;
 mod crypto_tests {
     #![allow(clippy::expect_used)]
     use super::{DecodeMessage, EncodeMessage, SignMessage, VerifySignature};
-    use crate::crypto::GenerateDigest;
+    use crate::crypto::{constant_time_eq, is_timestamp_within_tolerance, GenerateDigest};
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"signature", b"signature"));
+        assert!(!constant_time_eq(b"signature", b"signaturf"));
+        assert!(!constant_time_eq(b"signature", b"sig"));
+        assert!(!constant_time_eq(b"", b"signature"));
+    }
+
+    #[test]
+    fn test_is_timestamp_within_tolerance() {
+        let now = crate::date_time::now_unix_timestamp();
+        assert!(is_timestamp_within_tolerance(now, 300));
+        assert!(is_timestamp_within_tolerance(now - 299, 300));
+        assert!(!is_timestamp_within_tolerance(now - 600, 300));
+        assert!(!is_timestamp_within_tolerance(now + 600, 300));
+        assert!(!is_timestamp_within_tolerance(i64::MIN, 300));
+    }
 
     #[test]
     fn test_hmac_sha256_sign_message() {
@@ -53,6 +53,14 @@ pub enum ParsingError {
     /// Failed to convert the given integer because of integer overflow error
     #[error("Integer Overflow error")]
     IntegerOverflow,
+    /// The amount cannot be represented with the number of decimal places accepted
+    #[error("Amount {amount} cannot be represented with {max_decimal_places} decimal places")]
+    AmountPrecisionLoss {
+        /// The amount in minor unit
+        amount: i64,
+        /// The maximum number of decimal places accepted
+        max_decimal_places: u8,
+    },
 }
 
 /// Validation errors.
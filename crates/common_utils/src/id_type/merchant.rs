@@ -204,6 +204,14 @@ impl MerchantId {
         format!("should_call_gsm_{}", self.get_string_repr())
     }
 
+    /// Get the key of the flag that enables the orchestration webhooks of the merchant
+    pub fn get_should_send_orchestration_webhooks_key(&self) -> String {
+        format!(
+            "should_send_orchestration_webhooks_{}",
+            self.get_string_repr()
+        )
+    }
+
     /// get_max_auto_single_connector_payout_retries_enabled_
     pub fn get_max_auto_single_connector_payout_retries_enabled(
         &self,
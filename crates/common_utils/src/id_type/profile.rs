@@ -15,6 +15,18 @@ crate::impl_serializable_secret_id_type!(ProfileId);
 crate::impl_queryable_id_type!(ProfileId);
 crate::impl_to_sql_from_sql_id_type!(ProfileId);
 
+/// All the keys that can be formed from profile id
+impl ProfileId {
+    /// get the key of the config deciding whether voids past the void window of the connector are
+    /// converted into refunds
+    pub fn get_void_to_refund_conversion_enabled_key(&self) -> String {
+        format!(
+            "void_to_refund_conversion_enabled_{}",
+            self.get_string_repr()
+        )
+    }
+}
+
 impl crate::events::ApiEventMetric for ProfileId {
     fn get_api_event_type(&self) -> Option<crate::events::ApiEventsType> {
         Some(crate::events::ApiEventsType::BusinessProfile {
@@ -38,6 +38,7 @@ pub struct Request {
     pub certificate: Option<Secret<String>>,
     pub certificate_key: Option<Secret<String>>,
     pub body: Option<RequestContent>,
+    pub proxy: Option<RequestProxy>,
 }
 
 impl std::fmt::Debug for RequestContent {
@@ -81,6 +82,7 @@ impl Request {
             certificate: None,
             certificate_key: None,
             body: None,
+            proxy: None,
         }
     }
 
@@ -103,6 +105,19 @@ impl Request {
     pub fn add_certificate_key(&mut self, certificate_key: Option<Secret<String>>) {
         self.certificate = certificate_key;
     }
+
+    pub fn add_proxy(&mut self, proxy: Option<RequestProxy>) {
+        self.proxy = proxy;
+    }
+}
+
+/// Forward proxy through which a request is sent in place of the configured outbound proxy
+#[derive(Clone, Debug)]
+pub struct RequestProxy {
+    pub url: Secret<String>,
+    /// Base64 encoded certificate of the authority signing the certificates presented by the
+    /// proxy, for proxies which inspect the requests
+    pub ca_certificate: Option<Secret<String>>,
 }
 
 #[derive(Debug)]
@@ -113,6 +128,7 @@ pub struct RequestBuilder {
     pub certificate: Option<Secret<String>>,
     pub certificate_key: Option<Secret<String>>,
     pub body: Option<RequestContent>,
+    pub proxy: Option<RequestProxy>,
 }
 
 impl RequestBuilder {
@@ -124,6 +140,7 @@ impl RequestBuilder {
             certificate: None,
             certificate_key: None,
             body: None,
+            proxy: None,
         }
     }
 
@@ -180,6 +197,7 @@ impl RequestBuilder {
             certificate: self.certificate,
             certificate_key: self.certificate_key,
             body: self.body,
+            proxy: self.proxy,
         }
     }
 }
@@ -354,6 +354,81 @@ impl AmountConvertor for MinorUnitForConnector {
     }
 }
 
+/// Rounding of amounts which have more decimal places than accepted by the connector
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum AmountRounding {
+    /// Rounds to the nearest accepted amount, halves are rounded away from zero
+    #[default]
+    HalfUp,
+    /// Rounds towards zero
+    Down,
+    /// Fails the conversion instead of changing the amount
+    Reject,
+}
+
+/// Connector required amount type, for connectors which accept minor units but fewer decimal
+/// places than the currency has. For example, connectors requiring the last digit of amounts in
+/// three decimal currencies to be zero accept at most two decimal places.
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq)]
+pub struct MinorUnitForConnectorWithPrecision {
+    /// Maximum number of decimal places accepted by the connector
+    pub max_decimal_places: u8,
+    /// Rounding of amounts with more decimal places than accepted
+    pub rounding: AmountRounding,
+}
+
+impl AmountConvertor for MinorUnitForConnectorWithPrecision {
+    type Output = MinorUnit;
+    fn convert(
+        &self,
+        amount: MinorUnit,
+        currency: enums::Currency,
+    ) -> Result<Self::Output, error_stack::Report<ParsingError>> {
+        amount.round_to_decimal_places(currency, self.max_decimal_places, self.rounding)
+    }
+    fn convert_back(
+        &self,
+        amount: MinorUnit,
+        _currency: enums::Currency,
+    ) -> Result<MinorUnit, error_stack::Report<ParsingError>> {
+        Ok(amount)
+    }
+}
+
+/// Connector required amount type, for connectors which accept the major denomination as a string
+/// but fewer decimal places than the currency has
+#[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq)]
+pub struct StringMajorUnitForConnectorWithPrecision {
+    /// Maximum number of decimal places accepted by the connector
+    pub max_decimal_places: u8,
+    /// Rounding of amounts with more decimal places than accepted
+    pub rounding: AmountRounding,
+}
+
+impl AmountConvertor for StringMajorUnitForConnectorWithPrecision {
+    type Output = StringMajorUnit;
+    fn convert(
+        &self,
+        amount: MinorUnit,
+        currency: enums::Currency,
+    ) -> Result<Self::Output, error_stack::Report<ParsingError>> {
+        let rounded_amount =
+            amount.round_to_decimal_places(currency, self.max_decimal_places, self.rounding)?;
+        let amount_decimal = rounded_amount
+            .to_major_unit_as_decimal(currency)
+            .round_dp(u32::from(self.max_decimal_places));
+        Ok(StringMajorUnit::new(amount_decimal.to_string()))
+    }
+
+    fn convert_back(
+        &self,
+        amount: StringMajorUnit,
+        currency: enums::Currency,
+    ) -> Result<MinorUnit, error_stack::Report<ParsingError>> {
+        amount.to_minor_unit_as_i64(currency)
+    }
+}
+
 /// This Unit struct represents MinorUnit in which core amount works
 #[derive(
     Default,
@@ -395,14 +470,9 @@ impl MinorUnit {
         self,
         currency: enums::Currency,
     ) -> Result<StringMajorUnit, error_stack::Report<ParsingError>> {
-        let amount_f64 = self.to_major_unit_as_f64(currency)?;
-        let amount_string = if currency.is_zero_decimal_currency() {
-            amount_f64.0.to_string()
-        } else if currency.is_three_decimal_currency() {
-            format!("{:.3}", amount_f64.0)
-        } else {
-            format!("{:.2}", amount_f64.0)
-        };
+        // The scale of the decimal is retained when formatting, so that the string has exactly
+        // as many decimal places as the currency
+        let amount_string = self.to_major_unit_as_decimal(currency).to_string();
         Ok(StringMajorUnit::new(amount_string))
     }
 
@@ -411,22 +481,59 @@ impl MinorUnit {
         self,
         currency: enums::Currency,
     ) -> Result<FloatMajorUnit, error_stack::Report<ParsingError>> {
-        let amount_decimal =
-            Decimal::from_i64(self.0).ok_or(ParsingError::I64ToDecimalConversionFailure)?;
-
-        let amount = if currency.is_zero_decimal_currency() {
-            amount_decimal
-        } else if currency.is_three_decimal_currency() {
-            amount_decimal / Decimal::from(1000)
-        } else {
-            amount_decimal / Decimal::from(100)
-        };
-        let amount_f64 = amount
+        let amount_f64 = self
+            .to_major_unit_as_decimal(currency)
             .to_f64()
             .ok_or(ParsingError::FloatToDecimalConversionFailure)?;
         Ok(FloatMajorUnit::new(amount_f64))
     }
 
+    /// Convert the amount to its major denomination based on the number of decimal places of the
+    /// currency
+    fn to_major_unit_as_decimal(self, currency: enums::Currency) -> Decimal {
+        Decimal::new(
+            self.0,
+            u32::from(currency.number_of_digits_after_decimal_point()),
+        )
+    }
+
+    /// Rounds the amount to the number of decimal places accepted by the connector, if the
+    /// currency has more decimal places than accepted
+    pub fn round_to_decimal_places(
+        self,
+        currency: enums::Currency,
+        max_decimal_places: u8,
+        rounding: AmountRounding,
+    ) -> Result<Self, error_stack::Report<ParsingError>> {
+        let excess_decimal_places = currency
+            .number_of_digits_after_decimal_point()
+            .saturating_sub(max_decimal_places);
+        if excess_decimal_places == 0 {
+            return Ok(self);
+        }
+
+        let factor = 10_i64.pow(u32::from(excess_decimal_places));
+        let remainder = self.0 % factor;
+        if remainder == 0 {
+            return Ok(self);
+        }
+
+        let truncated_amount = self.0 - remainder;
+        let rounded_amount = match rounding {
+            AmountRounding::Reject => Err(ParsingError::AmountPrecisionLoss {
+                amount: self.0,
+                max_decimal_places,
+            })?,
+            AmountRounding::Down => truncated_amount,
+            // Halves are rounded away from zero
+            AmountRounding::HalfUp if remainder.abs() * 2 >= factor => truncated_amount
+                .checked_add(factor * remainder.signum())
+                .ok_or(ParsingError::IntegerOverflow)?,
+            AmountRounding::HalfUp => truncated_amount,
+        };
+        Ok(Self(rounded_amount))
+    }
+
     ///Convert minor unit to string minor unit
     fn to_minor_unit_as_string(self) -> Result<StringMinorUnit, error_stack::Report<ParsingError>> {
         Ok(StringMinorUnit::new(self.0.to_string()))
@@ -549,21 +656,26 @@ impl FloatMajorUnit {
         let amount_decimal =
             Decimal::from_f64(self.0).ok_or(ParsingError::FloatToDecimalConversionFailure)?;
 
-        let amount = if currency.is_zero_decimal_currency() {
-            amount_decimal
-        } else if currency.is_three_decimal_currency() {
-            amount_decimal * Decimal::from(1000)
-        } else {
-            amount_decimal * Decimal::from(100)
-        };
-
-        let amount_i64 = amount
-            .to_i64()
-            .ok_or(ParsingError::DecimalToI64ConversionFailure)?;
-        Ok(MinorUnit::new(amount_i64))
+        convert_major_unit_decimal_to_minor_unit(amount_decimal, currency)
     }
 }
 
+/// Converts an amount in the major denomination of the currency to its minor unit, based on the
+/// number of decimal places of the currency
+fn convert_major_unit_decimal_to_minor_unit(
+    amount: Decimal,
+    currency: enums::Currency,
+) -> Result<MinorUnit, error_stack::Report<ParsingError>> {
+    let minor_unit_multiplier =
+        Decimal::from(10_i64.pow(u32::from(currency.number_of_digits_after_decimal_point())));
+    let amount_i64 = amount
+        .checked_mul(minor_unit_multiplier)
+        .ok_or(ParsingError::IntegerOverflow)?
+        .to_i64()
+        .ok_or(ParsingError::DecimalToI64ConversionFailure)?;
+    Ok(MinorUnit::new(amount_i64))
+}
+
 /// Connector specific types to send
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq, Eq)]
 pub struct StringMajorUnit(String);
@@ -585,17 +697,7 @@ impl StringMajorUnit {
             }
         })?;
 
-        let amount = if currency.is_zero_decimal_currency() {
-            amount_decimal
-        } else if currency.is_three_decimal_currency() {
-            amount_decimal * Decimal::from(1000)
-        } else {
-            amount_decimal * Decimal::from(100)
-        };
-        let amount_i64 = amount
-            .to_i64()
-            .ok_or(ParsingError::DecimalToI64ConversionFailure)?;
-        Ok(MinorUnit::new(amount_i64))
+        convert_major_unit_decimal_to_minor_unit(amount_decimal, currency)
     }
     /// forms a new StringMajorUnit default unit i.e zero
     pub fn zero() -> Self {
@@ -1024,6 +1126,166 @@ mod amount_conversion_tests {
         assert_eq!(converted_back_amount, request_amount);
     }
 
+    /// Amounts in minor unit which every amount convertor must convert back without any change
+    const CONFORMANCE_AMOUNTS: [i64; 8] = [0, 1, 9, 10, 105, 1000, 123456, 999999999];
+
+    /// Currencies covering every number of decimal places
+    const CONFORMANCE_CURRENCIES: [enums::Currency; 6] = [
+        enums::Currency::USD,
+        enums::Currency::EUR,
+        enums::Currency::BHD,
+        enums::Currency::KWD,
+        enums::Currency::JPY,
+        enums::Currency::KRW,
+    ];
+
+    /// Conformance checks each amount convertor used by connectors must pass, the amount
+    /// converted for the connector must be converted back to the same amount in minor unit
+    fn assert_amount_convertor_conformance<T: AmountConvertor>(convertor: &T) {
+        for currency in CONFORMANCE_CURRENCIES {
+            for amount in CONFORMANCE_AMOUNTS.map(MinorUnit::new) {
+                let converted_amount = convertor.convert(amount, currency).unwrap();
+                let converted_back_amount =
+                    convertor.convert_back(converted_amount, currency).unwrap();
+                assert_eq!(
+                    converted_back_amount, amount,
+                    "{amount} {currency} was not converted back to the same amount"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn amount_convertors_conformance() {
+        assert_amount_convertor_conformance(&StringMinorUnitForConnector);
+        assert_amount_convertor_conformance(&StringMajorUnitForCore);
+        assert_amount_convertor_conformance(&StringMajorUnitForConnector);
+        assert_amount_convertor_conformance(&FloatMajorUnitForConnector);
+        assert_amount_convertor_conformance(&MinorUnitForConnector);
+        assert_amount_convertor_conformance(&MinorUnitForConnectorWithPrecision {
+            max_decimal_places: 3,
+            rounding: AmountRounding::Reject,
+        });
+        assert_amount_convertor_conformance(&StringMajorUnitForConnectorWithPrecision {
+            max_decimal_places: 3,
+            rounding: AmountRounding::Reject,
+        });
+    }
+
+    #[test]
+    fn amount_conversion_of_three_decimal_currencies() {
+        let request_amount = MinorUnit::new(1005);
+
+        for currency in [enums::Currency::BHD, enums::Currency::KWD] {
+            assert_eq!(
+                StringMajorUnitForConnector
+                    .convert(request_amount, currency)
+                    .unwrap()
+                    .0,
+                "1.005"
+            );
+            assert_eq!(
+                FloatMajorUnitForConnector
+                    .convert(request_amount, currency)
+                    .unwrap()
+                    .0,
+                1.005
+            );
+            assert_eq!(
+                StringMajorUnitForConnector
+                    .convert_back(StringMajorUnit::new("1.005".to_string()), currency)
+                    .unwrap(),
+                request_amount
+            );
+            assert_eq!(
+                FloatMajorUnitForConnector
+                    .convert_back(FloatMajorUnit::new(1.005), currency)
+                    .unwrap(),
+                request_amount
+            );
+        }
+
+        assert_eq!(
+            StringMajorUnitForConnector
+                .convert(MinorUnit::new(1000), THREE_DECIMAL_CURRENCY)
+                .unwrap()
+                .0,
+            "1.000"
+        );
+        assert_eq!(
+            StringMajorUnitForConnector
+                .convert(MinorUnit::new(-1005), THREE_DECIMAL_CURRENCY)
+                .unwrap()
+                .0,
+            "-1.005"
+        );
+    }
+
+    #[test]
+    fn amount_conversion_with_connector_precision() {
+        let round_half_up = MinorUnitForConnectorWithPrecision {
+            max_decimal_places: 2,
+            rounding: AmountRounding::HalfUp,
+        };
+        let round_down = MinorUnitForConnectorWithPrecision {
+            max_decimal_places: 2,
+            rounding: AmountRounding::Down,
+        };
+        let reject = MinorUnitForConnectorWithPrecision {
+            max_decimal_places: 2,
+            rounding: AmountRounding::Reject,
+        };
+
+        // Three decimal currencies are rounded to a multiple of ten minor units
+        for (amount, rounded_half_up, rounded_down) in [
+            (1004, 1000, 1000),
+            (1005, 1010, 1000),
+            (-1005, -1010, -1000),
+        ] {
+            let amount = MinorUnit::new(amount);
+            assert_eq!(
+                round_half_up
+                    .convert(amount, THREE_DECIMAL_CURRENCY)
+                    .unwrap(),
+                MinorUnit::new(rounded_half_up)
+            );
+            assert_eq!(
+                round_down.convert(amount, THREE_DECIMAL_CURRENCY).unwrap(),
+                MinorUnit::new(rounded_down)
+            );
+            assert!(reject.convert(amount, THREE_DECIMAL_CURRENCY).is_err());
+        }
+        assert_eq!(
+            reject
+                .convert(MinorUnit::new(1010), THREE_DECIMAL_CURRENCY)
+                .unwrap(),
+            MinorUnit::new(1010)
+        );
+
+        // Currencies with fewer decimal places than accepted are not changed
+        for currency in [TWO_DECIMAL_CURRENCY, ZERO_DECIMAL_CURRENCY] {
+            assert_eq!(
+                reject.convert(MinorUnit::new(1005), currency).unwrap(),
+                MinorUnit::new(1005)
+            );
+        }
+
+        let string_major_unit = StringMajorUnitForConnectorWithPrecision {
+            max_decimal_places: 2,
+            rounding: AmountRounding::HalfUp,
+        };
+        let converted_amount = string_major_unit
+            .convert(MinorUnit::new(1005), THREE_DECIMAL_CURRENCY)
+            .unwrap();
+        assert_eq!(converted_amount.0, "1.01");
+        assert_eq!(
+            string_major_unit
+                .convert_back(converted_amount, THREE_DECIMAL_CURRENCY)
+                .unwrap(),
+            MinorUnit::new(1010)
+        );
+    }
+
     #[test]
     fn amount_conversion_to_string_minor_unit() {
         let request_amount = MinorUnit::new(999999999);
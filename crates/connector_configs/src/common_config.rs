@@ -102,6 +102,8 @@ pub struct ApiModelMetaData {
     pub source_balance_account: Option<String>,
     pub brand_id: Option<String>,
     pub destination_account_number: Option<String>,
+    pub sender_company_name: Option<String>,
+    pub sender_country: Option<String>,
 }
 
 #[serde_with::skip_serializing_none]
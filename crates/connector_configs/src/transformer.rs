@@ -23,6 +23,8 @@ impl DashboardRequestPayload {
             card_networks: Some(card_provider),
             minimum_amount: Some(MinorUnit::zero()),
             maximum_amount: Some(MinorUnit::new(68607706)),
+            currency_amount_limits: None,
+            amount_limits_enforced: false,
             recurring_enabled: true,
             installment_payment_enabled: false,
             accepted_currencies: None,
@@ -82,6 +84,8 @@ impl DashboardRequestPayload {
                 card_networks: None,
                 minimum_amount: Some(MinorUnit::zero()),
                 maximum_amount: Some(MinorUnit::new(68607706)),
+                currency_amount_limits: None,
+                amount_limits_enforced: false,
                 recurring_enabled: true,
                 installment_payment_enabled: false,
                 accepted_currencies: method_type.accepted_currencies,
@@ -121,6 +125,8 @@ impl DashboardRequestPayload {
                                         card_networks: Some(vec![method.payment_method_type]),
                                         minimum_amount: Some(MinorUnit::zero()),
                                         maximum_amount: Some(MinorUnit::new(68607706)),
+                                        currency_amount_limits: None,
+                                        amount_limits_enforced: false,
                                         recurring_enabled: true,
                                         installment_payment_enabled: false,
                                         accepted_currencies: method.accepted_currencies,
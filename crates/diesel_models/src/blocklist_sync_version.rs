@@ -0,0 +1,53 @@
+use diesel::{AsExpression, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::{blocklist::BlocklistNew, schema::blocklist_sync_version};
+
+/// Entries added to or removed from the blocklist by a synchronization
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct BlocklistSyncEntries(pub Vec<BlocklistNew>);
+
+common_utils::impl_to_sql_from_sql_json!(BlocklistSyncEntries);
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = blocklist_sync_version)]
+pub struct BlocklistSyncVersionNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub version: i32,
+    pub source: String,
+    pub external_version: Option<String>,
+    pub added_entries: BlocklistSyncEntries,
+    pub removed_entries: BlocklistSyncEntries,
+    pub unchanged_count: i32,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Deserialize, Serialize,
+)]
+#[diesel(table_name = blocklist_sync_version, primary_key(merchant_id, version), check_for_backend(diesel::pg::Pg))]
+pub struct BlocklistSyncVersion {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub version: i32,
+    pub source: String,
+    pub external_version: Option<String>,
+    pub added_entries: BlocklistSyncEntries,
+    pub removed_entries: BlocklistSyncEntries,
+    pub unchanged_count: i32,
+    pub created_at: time::PrimitiveDateTime,
+    pub rolled_back_at: Option<time::PrimitiveDateTime>,
+}
+
+/// The additions and removals of a synchronization of the blocklist of a merchant, to be applied
+/// as the given version
+#[derive(Clone, Debug)]
+pub struct BlocklistSync {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub version: i32,
+    pub source: String,
+    pub external_version: Option<String>,
+    pub additions: Vec<BlocklistNew>,
+    pub removals: Vec<String>,
+    pub created_at: time::PrimitiveDateTime,
+}
@@ -520,6 +520,9 @@ pub struct WebhookDetails {
     pub payment_created_enabled: Option<bool>,
     pub payment_succeeded_enabled: Option<bool>,
     pub payment_failed_enabled: Option<bool>,
+    pub webhook_format: Option<common_enums::OutgoingWebhookFormat>,
+    pub enabled_events: Option<Vec<common_enums::EventType>>,
+    pub schema_version: Option<common_enums::OutgoingWebhookSchemaVersion>,
 }
 
 common_utils::impl_to_sql_from_sql_json!(WebhookDetails);
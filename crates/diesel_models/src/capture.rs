@@ -31,6 +31,9 @@ pub struct Capture {
     // reference to the capture at connector side
     pub connector_response_reference_id: Option<String>,
     pub connector_capture_data: Option<String>,
+    // amount of the capture in the currency settled by the connector
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub settlement_amount: Option<MinorUnit>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize)]
@@ -56,6 +59,8 @@ pub struct CaptureNew {
     pub capture_sequence: i16,
     pub connector_response_reference_id: Option<String>,
     pub connector_capture_data: Option<String>,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub settlement_amount: Option<MinorUnit>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -65,6 +70,8 @@ pub enum CaptureUpdate {
         connector_capture_id: Option<ConnectorTransactionId>,
         connector_response_reference_id: Option<String>,
         connector_capture_data: Option<String>,
+        settlement_currency: Option<storage_enums::Currency>,
+        settlement_amount: Option<MinorUnit>,
     },
     ErrorUpdate {
         status: storage_enums::CaptureStatus,
@@ -85,6 +92,8 @@ pub struct CaptureUpdateInternal {
     pub connector_capture_id: Option<ConnectorTransactionId>,
     pub connector_response_reference_id: Option<String>,
     pub connector_capture_data: Option<String>,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub settlement_amount: Option<MinorUnit>,
 }
 
 impl CaptureUpdate {
@@ -98,6 +107,8 @@ impl CaptureUpdate {
             connector_capture_id,
             connector_response_reference_id,
             connector_capture_data,
+            settlement_currency,
+            settlement_amount,
         } = self.into();
         Capture {
             status: status.unwrap_or(source.status),
@@ -109,6 +120,8 @@ impl CaptureUpdate {
             connector_response_reference_id: connector_response_reference_id
                 .or(source.connector_response_reference_id),
             connector_capture_data: connector_capture_data.or(source.connector_capture_data),
+            settlement_currency: settlement_currency.or(source.settlement_currency),
+            settlement_amount: settlement_amount.or(source.settlement_amount),
             ..source
         }
     }
@@ -123,12 +136,16 @@ impl From<CaptureUpdate> for CaptureUpdateInternal {
                 connector_capture_id: connector_transaction_id,
                 connector_response_reference_id,
                 connector_capture_data,
+                settlement_currency,
+                settlement_amount,
             } => Self {
                 status: Some(status),
                 connector_capture_id: connector_transaction_id,
                 modified_at: now,
                 connector_response_reference_id,
                 connector_capture_data,
+                settlement_currency,
+                settlement_amount,
                 ..Self::default()
             },
             CaptureUpdate::ErrorUpdate {
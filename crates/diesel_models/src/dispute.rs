@@ -61,6 +61,8 @@ pub struct Dispute {
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub dispute_amount: i64,
     pub organization_id: common_utils::id_type::OrganizationId,
+    pub submitted_evidence_types: Option<Vec<String>>,
+    pub evidence_submitted_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug)]
@@ -81,6 +83,11 @@ pub enum DisputeUpdate {
     EvidenceUpdate {
         evidence: Secret<serde_json::Value>,
     },
+    EvidenceSubmittedUpdate {
+        dispute_status: storage_enums::DisputeStatus,
+        connector_status: Option<String>,
+        submitted_evidence_types: Vec<String>,
+    },
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -95,6 +102,8 @@ pub struct DisputeUpdateInternal {
     connector_updated_at: Option<PrimitiveDateTime>,
     modified_at: PrimitiveDateTime,
     evidence: Option<Secret<serde_json::Value>>,
+    submitted_evidence_types: Option<Vec<String>>,
+    evidence_submitted_at: Option<PrimitiveDateTime>,
 }
 
 impl From<DisputeUpdate> for DisputeUpdateInternal {
@@ -118,6 +127,8 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 connector_updated_at,
                 modified_at: common_utils::date_time::now(),
                 evidence: None,
+                submitted_evidence_types: None,
+                evidence_submitted_at: None,
             },
             DisputeUpdate::StatusUpdate {
                 dispute_status,
@@ -132,6 +143,8 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 challenge_required_by: None,
                 connector_updated_at: None,
                 evidence: None,
+                submitted_evidence_types: None,
+                evidence_submitted_at: None,
             },
             DisputeUpdate::EvidenceUpdate { evidence } => Self {
                 evidence: Some(evidence),
@@ -143,6 +156,25 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 challenge_required_by: None,
                 connector_updated_at: None,
                 modified_at: common_utils::date_time::now(),
+                submitted_evidence_types: None,
+                evidence_submitted_at: None,
+            },
+            DisputeUpdate::EvidenceSubmittedUpdate {
+                dispute_status,
+                connector_status,
+                submitted_evidence_types,
+            } => Self {
+                dispute_status: Some(dispute_status),
+                connector_status,
+                submitted_evidence_types: Some(submitted_evidence_types),
+                evidence_submitted_at: Some(common_utils::date_time::now()),
+                modified_at: common_utils::date_time::now(),
+                dispute_stage: None,
+                connector_reason: None,
+                connector_reason_code: None,
+                challenge_required_by: None,
+                connector_updated_at: None,
+                evidence: None,
             },
         }
     }
@@ -72,7 +72,14 @@ pub enum EventObjectType {
     RefundDetails,
     DisputeDetails,
     MandateDetails,
+    MandateDebitDetails,
     PayoutDetails,
+    InvoiceDetails,
+    PaymentMethodCollectLinkDetails,
+    PaymentMethodTokenDetails,
+    OrchestrationDetails,
+    ConnectorCredentialHealthDetails,
+    ShipmentDetails,
 }
 
 #[derive(
@@ -8,6 +8,8 @@ pub enum DatabaseError {
     UniqueViolation,
     #[error("No fields were provided to be updated")]
     NoFieldsToUpdate,
+    #[error("The record was modified by a concurrent update")]
+    VersionConflict,
     #[error("An error occurred when generating typed SQL query")]
     QueryGenerationFailed,
     // InsertFailed,
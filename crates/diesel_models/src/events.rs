@@ -26,6 +26,7 @@ pub struct EventNew {
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -57,6 +58,7 @@ pub struct Event {
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, AsExpression, diesel::FromSqlRow)]
@@ -81,6 +83,18 @@ pub enum EventMetadata {
         payment_method_id: String,
         mandate_id: String,
     },
+    Invoice {
+        invoice_id: String,
+    },
+    PaymentMethodCollectLink {
+        pm_collect_link_id: String,
+    },
+    PaymentMethod {
+        payment_method_id: String,
+    },
+    MerchantConnector {
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
@@ -149,6 +149,18 @@ pub struct PaymentMethodCollectLinkData {
     #[serde(flatten)]
     pub ui_config: GenericLinkUiConfig,
     pub enabled_payment_methods: Option<Vec<EnabledPaymentMethod>>,
+    /// The payment method saved through the link
+    pub payment_method_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum PaymentMethodCollectLinkUpdate {
+    StatusUpdate {
+        link_status: PaymentMethodCollectStatus,
+    },
+    SubmitUpdate {
+        link_data: serde_json::Value,
+    },
 }
 
 #[derive(Clone, Debug, Identifiable, Queryable, Serialize, Deserialize)]
@@ -180,6 +192,7 @@ pub enum PayoutLinkUpdate {
 #[diesel(table_name = generic_link)]
 pub struct GenericLinkUpdateInternal {
     pub link_status: Option<GenericLinkStatus>,
+    pub link_data: Option<serde_json::Value>,
 }
 
 impl From<PayoutLinkUpdate> for GenericLinkUpdateInternal {
@@ -187,6 +200,24 @@ impl From<PayoutLinkUpdate> for GenericLinkUpdateInternal {
         match generic_link_update {
             PayoutLinkUpdate::StatusUpdate { link_status } => Self {
                 link_status: Some(GenericLinkStatus::PayoutLink(link_status)),
+                link_data: None,
+            },
+        }
+    }
+}
+
+impl From<PaymentMethodCollectLinkUpdate> for GenericLinkUpdateInternal {
+    fn from(generic_link_update: PaymentMethodCollectLinkUpdate) -> Self {
+        match generic_link_update {
+            PaymentMethodCollectLinkUpdate::StatusUpdate { link_status } => Self {
+                link_status: Some(GenericLinkStatus::PaymentMethodCollect(link_status)),
+                link_data: None,
+            },
+            PaymentMethodCollectLinkUpdate::SubmitUpdate { link_data } => Self {
+                link_status: Some(GenericLinkStatus::PaymentMethodCollect(
+                    PaymentMethodCollectStatus::Submitted,
+                )),
+                link_data: Some(link_data),
             },
         }
     }
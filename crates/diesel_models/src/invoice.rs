@@ -0,0 +1,125 @@
+use common_utils::{id_type, pii, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::invoice};
+
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = invoice, primary_key(invoice_id), check_for_backend(diesel::pg::Pg))]
+pub struct Invoice {
+    pub invoice_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub status: storage_enums::InvoiceStatus,
+    pub currency: storage_enums::Currency,
+    pub amount: MinorUnit,
+    pub amount_paid: MinorUnit,
+    pub line_items: serde_json::Value,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    #[diesel(deserialize_as = super::DieselArray<String>)]
+    pub payment_ids: Vec<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub due_date: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = invoice)]
+pub struct InvoiceNew {
+    pub invoice_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub status: storage_enums::InvoiceStatus,
+    pub currency: storage_enums::Currency,
+    pub amount: MinorUnit,
+    pub amount_paid: MinorUnit,
+    pub line_items: serde_json::Value,
+    pub description: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub payment_ids: Vec<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub due_date: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+// Tracking data by process_tracker
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct InvoiceReminderTrackingData {
+    pub invoice_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub due_date: PrimitiveDateTime,
+    // Times at which the invoice is checked, the task is rescheduled to the next of them after
+    // every run.
+    pub reminder_times: Vec<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum InvoiceUpdate {
+    PaymentsUpdate {
+        payment_ids: Vec<String>,
+    },
+    StatusUpdate {
+        status: storage_enums::InvoiceStatus,
+        amount_paid: MinorUnit,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = invoice)]
+pub struct InvoiceUpdateInternal {
+    status: Option<storage_enums::InvoiceStatus>,
+    amount_paid: Option<MinorUnit>,
+    payment_ids: Option<Vec<String>>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<InvoiceUpdate> for InvoiceUpdateInternal {
+    fn from(invoice_update: InvoiceUpdate) -> Self {
+        match invoice_update {
+            InvoiceUpdate::PaymentsUpdate { payment_ids } => Self {
+                payment_ids: Some(payment_ids),
+                status: None,
+                amount_paid: None,
+                modified_at: common_utils::date_time::now(),
+            },
+            InvoiceUpdate::StatusUpdate {
+                status,
+                amount_paid,
+            } => Self {
+                status: Some(status),
+                amount_paid: Some(amount_paid),
+                payment_ids: None,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+impl InvoiceUpdateInternal {
+    pub fn apply_changeset(self, source: Invoice) -> Invoice {
+        let Self {
+            status,
+            amount_paid,
+            payment_ids,
+            modified_at,
+        } = self;
+
+        Invoice {
+            status: status.unwrap_or(source.status),
+            amount_paid: amount_paid.unwrap_or(source.amount_paid),
+            payment_ids: payment_ids.unwrap_or(source.payment_ids),
+            modified_at,
+            ..source
+        }
+    }
+}
@@ -19,6 +19,12 @@ use crate::{
     PaymentMethodUpdateInternal, PgPooledConn,
 };
 
+/// Version of the records cached in redis before `row_version` was introduced, the same as the
+/// version the migration gave the existing rows of the database
+pub(crate) fn default_row_version() -> i32 {
+    common_utils::consts::INITIAL_ROW_VERSION
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "db_op", content = "data")]
 pub enum DBOperation {
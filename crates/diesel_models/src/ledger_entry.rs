@@ -0,0 +1,69 @@
+use common_utils::{id_type, types::MinorUnit};
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    refund::{Refund, RefundUpdate},
+    schema::ledger_entry,
+};
+
+/// Movement of funds of a merchant. The amount is credited to the merchant, and is negative for
+/// the amounts debited from the merchant.
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = ledger_entry, primary_key(entry_id), check_for_backend(diesel::pg::Pg))]
+pub struct LedgerEntry {
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub payment_id: id_type::PaymentId,
+    pub dispute_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub entry_type: storage_enums::LedgerEntryType,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = ledger_entry)]
+pub struct LedgerEntryNew {
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub payment_id: id_type::PaymentId,
+    pub dispute_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub entry_type: storage_enums::LedgerEntryType,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl From<LedgerEntryNew> for LedgerEntry {
+    fn from(ledger_entry_new: LedgerEntryNew) -> Self {
+        Self {
+            entry_id: ledger_entry_new.entry_id,
+            merchant_id: ledger_entry_new.merchant_id,
+            profile_id: ledger_entry_new.profile_id,
+            payment_id: ledger_entry_new.payment_id,
+            dispute_id: ledger_entry_new.dispute_id,
+            refund_id: ledger_entry_new.refund_id,
+            entry_type: ledger_entry_new.entry_type,
+            amount: ledger_entry_new.amount,
+            currency: ledger_entry_new.currency,
+            created_at: ledger_entry_new.created_at,
+        }
+    }
+}
+
+/// A refund to be reversed, along with the ledger entry recording its reversal
+#[derive(Clone, Debug)]
+pub struct RefundReversal {
+    pub refund: Refund,
+    pub refund_update: RefundUpdate,
+    pub ledger_entry: LedgerEntryNew,
+}
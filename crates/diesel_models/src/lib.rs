@@ -10,6 +10,7 @@ pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
+pub mod blocklist_sync_version;
 pub mod customers;
 pub mod dispute;
 pub mod enums;
@@ -21,8 +22,10 @@ pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod invoice;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod ledger_entry;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -33,14 +36,18 @@ pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_order_reference;
+pub mod payment_shipment;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
 pub mod query;
 pub mod refund;
+pub mod refund_tender_override;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod routing_experiment_assignment;
 pub mod types;
 pub mod unified_translations;
 
@@ -53,6 +60,7 @@ pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use diesel_impl::{DieselArray, OptionalDieselArray};
 
@@ -60,10 +68,10 @@ pub type StorageResult<T> = error_stack::Result<T, errors::DatabaseError>;
 pub type PgPooledConn = async_bb8_diesel::Connection<diesel::PgConnection>;
 pub use self::{
     address::*, api_keys::*, cards_info::*, configs::*, customers::*, dispute::*, ephemeral_key::*,
-    events::*, file::*, generic_link::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, payment_attempt::*, payment_intent::*, payment_method::*,
-    payout_attempt::*, payouts::*, process_tracker::*, refund::*, reverse_lookup::*,
-    user_authentication_method::*,
+    events::*, file::*, generic_link::*, invoice::*, locker_mock_up::*, mandate::*,
+    merchant_account::*, merchant_connector_account::*, payment_attempt::*, payment_intent::*,
+    payment_method::*, payout_attempt::*, payouts::*, process_tracker::*, refund::*,
+    reverse_lookup::*, user_authentication_method::*,
 };
 
 /// The types and implementations provided by this module are required for the schema generated by
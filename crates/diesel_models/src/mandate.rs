@@ -237,3 +237,23 @@ impl From<&MandateNew> for Mandate {
         }
     }
 }
+
+// Tracking data by process_tracker
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct MandateDebitTrackingData {
+    pub mandate_debit_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub mandate_id: String,
+    pub payment_method_id: String,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub amount: common_utils::types::MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub description: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub debit_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub pre_debit_notification_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub retry_until: PrimitiveDateTime,
+}
@@ -172,6 +172,20 @@ pub struct PaymentAttempt {
     pub order_tax_amount: Option<MinorUnit>,
     pub connector_transaction_data: Option<String>,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    /// Incremented on every update, used to detect concurrent modifications of the record.
+    /// Records cached in redis before this field was introduced deserialize with the initial
+    /// version, the version the migration gave the rows of the database.
+    #[serde(default = "crate::kv::default_row_version")]
+    pub row_version: i32,
+    pub routing_decision: Option<serde_json::Value>,
+    /// The processing fee reported by the connector for the attempt
+    pub connector_fee_amount: Option<MinorUnit>,
+    pub connector_fee_currency: Option<storage_enums::Currency>,
+    /// Identifier assigned by the connector to its latest request for the attempt
+    pub connector_request_id: Option<String>,
+    /// Identifier of the request to the application which last called the connector for the
+    /// attempt
+    pub correlation_id: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -351,6 +365,7 @@ pub struct PaymentAttemptNew {
     pub shipping_cost: Option<MinorUnit>,
     pub order_tax_amount: Option<MinorUnit>,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub routing_decision: Option<serde_json::Value>,
 }
 
 #[cfg(feature = "v1")]
@@ -379,6 +394,7 @@ pub enum PaymentAttemptUpdate {
         payment_token: Option<String>,
         connector: Option<String>,
         straight_through_algorithm: Option<serde_json::Value>,
+        routing_decision: Option<serde_json::Value>,
         amount_capturable: Option<MinorUnit>,
         surcharge_amount: Option<MinorUnit>,
         tax_amount: Option<MinorUnit>,
@@ -404,6 +420,7 @@ pub enum PaymentAttemptUpdate {
         payment_experience: Option<storage_enums::PaymentExperience>,
         business_sub_label: Option<String>,
         straight_through_algorithm: Option<serde_json::Value>,
+        routing_decision: Option<serde_json::Value>,
         error_code: Option<Option<String>>,
         error_message: Option<Option<String>>,
         amount_capturable: Option<MinorUnit>,
@@ -471,6 +488,10 @@ pub enum PaymentAttemptUpdate {
         payment_method_data: Option<serde_json::Value>,
         charge_id: Option<String>,
         connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+        connector_fee_amount: Option<MinorUnit>,
+        connector_fee_currency: Option<storage_enums::Currency>,
+        connector_request_id: Option<String>,
+        correlation_id: Option<String>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -500,6 +521,8 @@ pub enum PaymentAttemptUpdate {
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
         authentication_type: Option<storage_enums::AuthenticationType>,
+        connector_request_id: Option<String>,
+        correlation_id: Option<String>,
     },
     CaptureUpdate {
         amount_to_capture: Option<MinorUnit>,
@@ -848,6 +871,11 @@ pub struct PaymentAttemptUpdateInternal {
     pub order_tax_amount: Option<MinorUnit>,
     pub connector_transaction_data: Option<String>,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub routing_decision: Option<serde_json::Value>,
+    pub connector_fee_amount: Option<MinorUnit>,
+    pub connector_fee_currency: Option<storage_enums::Currency>,
+    pub connector_request_id: Option<String>,
+    pub correlation_id: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -1031,6 +1059,11 @@ impl PaymentAttemptUpdate {
             order_tax_amount,
             connector_transaction_data,
             connector_mandate_detail,
+            routing_decision,
+            connector_fee_amount,
+            connector_fee_currency,
+            connector_request_id,
+            correlation_id,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -1089,6 +1122,12 @@ impl PaymentAttemptUpdate {
             connector_transaction_data: connector_transaction_data
                 .or(source.connector_transaction_data),
             connector_mandate_detail: connector_mandate_detail.or(source.connector_mandate_detail),
+            row_version: source.row_version.saturating_add(1),
+            routing_decision: routing_decision.or(source.routing_decision),
+            connector_fee_amount: connector_fee_amount.or(source.connector_fee_amount),
+            connector_fee_currency: connector_fee_currency.or(source.connector_fee_currency),
+            connector_request_id: connector_request_id.or(source.connector_request_id),
+            correlation_id: correlation_id.or(source.correlation_id),
             ..source
         }
     }
@@ -2141,6 +2180,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                routing_decision: None,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -2197,6 +2241,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                routing_decision: None,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -2213,6 +2262,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_experience,
                 business_sub_label,
                 straight_through_algorithm,
+                routing_decision,
                 error_code,
                 error_message,
                 amount_capturable,
@@ -2284,6 +2334,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount,
                 connector_transaction_data: None,
                 connector_mandate_detail,
+                routing_decision,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -2341,6 +2396,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                routing_decision: None,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -2399,6 +2459,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                routing_decision: None,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
@@ -2457,6 +2522,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                routing_decision: None,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::ConnectorMandateDetailUpdate {
                 connector_mandate_detail,
@@ -2513,6 +2583,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail,
+                routing_decision: None,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -2569,6 +2644,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                routing_decision: None,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -2592,6 +2672,10 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_data,
                 charge_id,
                 connector_mandate_detail,
+                connector_fee_amount,
+                connector_fee_currency,
+                connector_request_id,
+                correlation_id,
             } => {
                 let (connector_transaction_id, connector_transaction_data) =
                     connector_transaction_id
@@ -2650,6 +2734,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail,
+                    routing_decision: None,
+                    connector_fee_amount,
+                    connector_fee_currency,
+                    connector_request_id,
+                    correlation_id,
                 }
             }
             PaymentAttemptUpdate::ErrorUpdate {
@@ -2665,6 +2754,8 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_request_id,
+                correlation_id,
             } => {
                 let (connector_transaction_id, connector_transaction_data) =
                     connector_transaction_id
@@ -2723,6 +2814,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    routing_decision: None,
+                    connector_fee_amount: None,
+                    connector_fee_currency: None,
+                    connector_request_id,
+                    correlation_id,
                 }
             }
             PaymentAttemptUpdate::StatusUpdate { status, updated_by } => Self {
@@ -2777,11 +2873,17 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                routing_decision: None,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
                 connector,
                 straight_through_algorithm,
+                routing_decision,
                 amount_capturable,
                 surcharge_amount,
                 tax_amount,
@@ -2839,6 +2941,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                routing_decision,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -2908,6 +3015,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    routing_decision: None,
+                    connector_fee_amount: None,
+                    connector_fee_currency: None,
+                    connector_request_id: None,
+                    correlation_id: None,
                 }
             }
             PaymentAttemptUpdate::PreprocessingUpdate {
@@ -2976,6 +3088,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    routing_decision: None,
+                    connector_fee_amount: None,
+                    connector_fee_currency: None,
+                    connector_request_id: None,
+                    correlation_id: None,
                 }
             }
             PaymentAttemptUpdate::CaptureUpdate {
@@ -3034,6 +3151,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                routing_decision: None,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -3091,6 +3213,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                routing_decision: None,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -3157,6 +3284,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    routing_decision: None,
+                    connector_fee_amount: None,
+                    connector_fee_currency: None,
+                    connector_request_id: None,
+                    correlation_id: None,
                 }
             }
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
@@ -3214,6 +3346,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                routing_decision: None,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -3273,6 +3410,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                routing_decision: None,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -3341,6 +3483,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     shipping_cost: None,
                     order_tax_amount: None,
                     connector_mandate_detail: None,
+                    routing_decision: None,
+                    connector_fee_amount: None,
+                    connector_fee_currency: None,
+                    connector_request_id: None,
+                    correlation_id: None,
                 }
             }
             PaymentAttemptUpdate::PostSessionTokensUpdate {
@@ -3398,6 +3545,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount: None,
                 connector_transaction_data: None,
                 connector_mandate_detail: None,
+                routing_decision: None,
+                connector_fee_amount: None,
+                connector_fee_currency: None,
+                connector_request_id: None,
+                correlation_id: None,
             },
         }
     }
@@ -136,6 +136,11 @@ pub struct PaymentIntent {
     pub organization_id: common_utils::id_type::OrganizationId,
     pub tax_details: Option<TaxDetails>,
     pub skip_external_tax_calculation: Option<bool>,
+    /// Incremented on every update, used to detect concurrent modifications of the record.
+    /// Records cached in redis before this field was introduced deserialize with the initial
+    /// version, the version the migration gave the rows of the database.
+    #[serde(default = "crate::kv::default_row_version")]
+    pub row_version: i32,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq)]
@@ -727,6 +732,7 @@ impl PaymentIntentUpdate {
             is_payment_processor_token_flow: is_payment_processor_token_flow
                 .or(source.is_payment_processor_token_flow),
             tax_details: tax_details.or(source.tax_details),
+            row_version: source.row_version.saturating_add(1),
             ..source
         }
     }
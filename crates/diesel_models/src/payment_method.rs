@@ -854,6 +854,17 @@ pub struct PaymentsMandateReferenceRecord {
     pub connector_mandate_request_reference_id: Option<String>,
 }
 
+impl PaymentsMandateReferenceRecord {
+    /// Whether the connector mandate can be used for merchant initiated payments. Mandates
+    /// recorded before their status was tracked are considered usable.
+    pub fn is_usable(&self) -> bool {
+        !matches!(
+            self.connector_mandate_status,
+            Some(common_enums::ConnectorMandateStatus::Inactive)
+        )
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, diesel::AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Jsonb)]
 pub struct PaymentsMandateReference(
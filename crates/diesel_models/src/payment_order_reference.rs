@@ -0,0 +1,46 @@
+use common_utils::id_type;
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::payment_order_reference;
+
+/// Order reference supplied by the merchant, reserved for a payment of a business profile which
+/// requires the order references of its payments to be unique
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(
+    table_name = payment_order_reference,
+    primary_key(profile_id, merchant_order_reference_id),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct PaymentOrderReference {
+    pub profile_id: id_type::ProfileId,
+    pub merchant_order_reference_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub payment_id: id_type::PaymentId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_order_reference)]
+pub struct PaymentOrderReferenceNew {
+    pub profile_id: id_type::ProfileId,
+    pub merchant_order_reference_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub payment_id: id_type::PaymentId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl From<PaymentOrderReferenceNew> for PaymentOrderReference {
+    fn from(payment_order_reference_new: PaymentOrderReferenceNew) -> Self {
+        Self {
+            profile_id: payment_order_reference_new.profile_id,
+            merchant_order_reference_id: payment_order_reference_new.merchant_order_reference_id,
+            merchant_id: payment_order_reference_new.merchant_id,
+            payment_id: payment_order_reference_new.payment_id,
+            created_at: payment_order_reference_new.created_at,
+        }
+    }
+}
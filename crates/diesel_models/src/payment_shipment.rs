@@ -0,0 +1,38 @@
+use common_utils::{id_type, types::MinorUnit};
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::schema::payment_shipment;
+
+/// A shipment of the order of a payment, along with the capture made for its amount. The status
+/// of a shipment is that of its capture.
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(
+    table_name = payment_shipment,
+    primary_key(payment_id, shipment_reference),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct PaymentShipment {
+    pub payment_id: id_type::PaymentId,
+    pub shipment_reference: String,
+    pub merchant_id: id_type::MerchantId,
+    pub capture_id: String,
+    pub amount: MinorUnit,
+    pub line_items: serde_json::Value,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_shipment)]
+pub struct PaymentShipmentNew {
+    pub payment_id: id_type::PaymentId,
+    pub shipment_reference: String,
+    pub merchant_id: id_type::MerchantId,
+    pub capture_id: String,
+    pub amount: MinorUnit,
+    pub line_items: serde_json::Value,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
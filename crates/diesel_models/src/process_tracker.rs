@@ -210,6 +210,14 @@ pub enum ProcessTrackerRunner {
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
+    PaymentsConfirmWorkflow,
+    MerchantAccountPurgeWorkflow,
+    InvoiceReminderWorkflow,
+    MandateDebitWorkflow,
+    SandboxDataResetWorkflow,
+    ConnectorCredentialHealthWorkflow,
+    BlocklistSyncWorkflow,
+    AccountUpdaterWorkflow,
 }
 
 #[cfg(test)]
@@ -10,15 +10,20 @@ pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
+pub mod blocklist_sync_version;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_export;
 pub mod dispute;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod generics;
+pub mod graphql;
 pub mod gsm;
+pub mod invoice;
+pub mod ledger_entry;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -29,15 +34,22 @@ pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_order_reference;
+pub mod payment_shipment;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
+pub mod refund_tender_override;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod routing_experiment_assignment;
+#[cfg(feature = "v1")]
+pub mod sandbox_data;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
@@ -0,0 +1,192 @@
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
+use diesel::{
+    associations::HasTable, result::Error as DieselError, BoolExpressionMethods, ExpressionMethods,
+    QueryDsl,
+};
+use error_stack::{report, ResultExt};
+
+use super::generics;
+use crate::{
+    blocklist::{Blocklist, BlocklistNew},
+    blocklist_sync_version::{
+        BlocklistSync, BlocklistSyncEntries, BlocklistSyncVersion, BlocklistSyncVersionNew,
+    },
+    errors,
+    schema::{blocklist, blocklist_sync_version::dsl},
+    PgPooledConn, StorageResult,
+};
+
+fn to_new_entries(entries: Vec<Blocklist>) -> BlocklistSyncEntries {
+    BlocklistSyncEntries(
+        entries
+            .into_iter()
+            .map(|entry| BlocklistNew {
+                merchant_id: entry.merchant_id,
+                fingerprint_id: entry.fingerprint_id,
+                data_kind: entry.data_kind,
+                metadata: entry.metadata,
+                created_at: entry.created_at,
+            })
+            .collect(),
+    )
+}
+
+fn to_database_error(error: DieselError) -> error_stack::Report<errors::DatabaseError> {
+    match error {
+        DieselError::DatabaseError(diesel::result::DatabaseErrorKind::UniqueViolation, _) => {
+            report!(error).change_context(errors::DatabaseError::UniqueViolation)
+        }
+        _ => report!(error).change_context(errors::DatabaseError::Others),
+    }
+}
+
+/// Removes the entries with the given fingerprints from the blocklist of the merchant, returning
+/// the removed entries
+async fn remove_entries(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    fingerprint_ids: Vec<String>,
+) -> Result<Vec<Blocklist>, DieselError> {
+    if fingerprint_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    diesel::delete(
+        blocklist::table.filter(
+            blocklist::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(blocklist::fingerprint_id.eq_any(fingerprint_ids)),
+        ),
+    )
+    .get_results_async(conn)
+    .await
+}
+
+/// Adds the entries to the blocklist, returning the entries which were not in the blocklist
+/// already
+async fn add_entries(
+    conn: &PgPooledConn,
+    entries: Vec<BlocklistNew>,
+) -> Result<Vec<Blocklist>, DieselError> {
+    if entries.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    diesel::insert_into(blocklist::table)
+        .values(entries)
+        .on_conflict_do_nothing()
+        .get_results_async(conn)
+        .await
+}
+
+impl BlocklistSyncVersion {
+    /// Removes and adds the blocklist entries of the synchronization and records it as a new
+    /// version, in a single transaction. Entries which are already absent from or present in the
+    /// blocklist are left untouched. Only the latest `max_retained_versions` versions are kept.
+    pub async fn apply(
+        conn: &PgPooledConn,
+        sync: BlocklistSync,
+        max_retained_versions: i32,
+    ) -> StorageResult<Self> {
+        conn.transaction_async(|conn| {
+            Box::pin(async move {
+                let requested_count = sync.additions.len() + sync.removals.len();
+                let removed = remove_entries(&conn, &sync.merchant_id, sync.removals).await?;
+                let added = add_entries(&conn, sync.additions).await?;
+                let unchanged_count = requested_count
+                    .saturating_sub(added.len())
+                    .saturating_sub(removed.len());
+
+                let version = diesel::insert_into(dsl::blocklist_sync_version)
+                    .values(BlocklistSyncVersionNew {
+                        merchant_id: sync.merchant_id.clone(),
+                        version: sync.version,
+                        source: sync.source,
+                        external_version: sync.external_version,
+                        added_entries: to_new_entries(added),
+                        removed_entries: to_new_entries(removed),
+                        unchanged_count: i32::try_from(unchanged_count).unwrap_or(i32::MAX),
+                        created_at: sync.created_at,
+                    })
+                    .get_result_async::<Self>(&conn)
+                    .await?;
+
+                diesel::delete(
+                    dsl::blocklist_sync_version.filter(
+                        dsl::merchant_id
+                            .eq(sync.merchant_id)
+                            .and(dsl::version.le(sync.version - max_retained_versions)),
+                    ),
+                )
+                .execute_async(&conn)
+                .await?;
+
+                Ok::<_, DieselError>(version)
+            })
+        })
+        .await
+        .map_err(to_database_error)
+        .attach_printable("Error while applying a blocklist synchronization")
+    }
+
+    /// Undoes the given versions, the latest first, and marks them as rolled back, in a single
+    /// transaction
+    pub async fn rollback(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        mut versions: Vec<Self>,
+        rolled_back_at: time::PrimitiveDateTime,
+    ) -> StorageResult<()> {
+        let merchant_id = merchant_id.to_owned();
+        versions.sort_by_key(|version| std::cmp::Reverse(version.version));
+
+        conn.transaction_async(|conn| {
+            Box::pin(async move {
+                for version in versions {
+                    remove_entries(
+                        &conn,
+                        &merchant_id,
+                        version
+                            .added_entries
+                            .0
+                            .into_iter()
+                            .map(|entry| entry.fingerprint_id)
+                            .collect(),
+                    )
+                    .await?;
+                    add_entries(&conn, version.removed_entries.0).await?;
+
+                    diesel::update(
+                        dsl::blocklist_sync_version.filter(
+                            dsl::merchant_id
+                                .eq(merchant_id.clone())
+                                .and(dsl::version.eq(version.version)),
+                        ),
+                    )
+                    .set(dsl::rolled_back_at.eq(Some(rolled_back_at)))
+                    .execute_async(&conn)
+                    .await?;
+                }
+
+                Ok::<_, DieselError>(())
+            })
+        })
+        .await
+        .map_err(to_database_error)
+        .attach_printable("Error while rolling back blocklist synchronizations")
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(dsl::version.desc()),
+        )
+        .await
+    }
+}
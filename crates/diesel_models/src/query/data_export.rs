@@ -0,0 +1,127 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{debug_query, BoolExpressionMethods, ExpressionMethods, QueryDsl};
+use error_stack::ResultExt;
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums, errors, refund::Refund, schema::refund, PgPooledConn, StorageResult,
+};
+#[cfg(feature = "v1")]
+use crate::{payment_intent::PaymentIntent, schema::payment_intent};
+
+/// Position in the export of the records of a merchant. Records are exported in the order of the
+/// time they were last modified, records modified at the same time in the order of their
+/// identifiers, so that records modified after being exported are exported again.
+#[derive(Clone, Debug)]
+pub struct ExportCursor {
+    pub modified_at: PrimitiveDateTime,
+    pub id: String,
+}
+
+/// Filters common to the exports of all the resources
+#[derive(Clone, Debug)]
+pub struct ExportConstraints {
+    pub profile_ids: Option<Vec<common_utils::id_type::ProfileId>>,
+    /// Only the records modified within the time range are exported
+    pub start_time: PrimitiveDateTime,
+    pub end_time: Option<PrimitiveDateTime>,
+    pub currency: Option<Vec<storage_enums::Currency>>,
+    /// Only the records after the cursor are exported
+    pub cursor: Option<ExportCursor>,
+    pub limit: i64,
+}
+
+/// Finds the next batch of the payments of the merchant to be exported
+#[cfg(feature = "v1")]
+pub async fn find_payment_intents_batch(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    constraints: &ExportConstraints,
+    status: Option<&[storage_enums::IntentStatus]>,
+) -> StorageResult<Vec<PaymentIntent>> {
+    let mut query = payment_intent::table
+        .filter(payment_intent::merchant_id.eq(merchant_id.to_owned()))
+        .filter(payment_intent::modified_at.ge(constraints.start_time))
+        .order((
+            payment_intent::modified_at.asc(),
+            payment_intent::payment_id.asc(),
+        ))
+        .limit(constraints.limit)
+        .into_boxed();
+
+    if let Some(end_time) = constraints.end_time {
+        query = query.filter(payment_intent::modified_at.lt(end_time));
+    }
+    if let Some(profile_ids) = &constraints.profile_ids {
+        query = query.filter(payment_intent::profile_id.eq_any(profile_ids.clone()));
+    }
+    if let Some(currency) = &constraints.currency {
+        query = query.filter(payment_intent::currency.eq_any(currency.clone()));
+    }
+    if let Some(status) = status {
+        query = query.filter(payment_intent::status.eq_any(status.to_vec()));
+    }
+    if let Some(cursor) = &constraints.cursor {
+        query = query.filter(
+            payment_intent::modified_at
+                .gt(cursor.modified_at)
+                .or(payment_intent::modified_at
+                    .eq(cursor.modified_at)
+                    .and(payment_intent::payment_id.gt(cursor.id.clone()))),
+        );
+    }
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding the payments to be exported")
+}
+
+/// Finds the next batch of the refunds of the merchant to be exported
+pub async fn find_refunds_batch(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    constraints: &ExportConstraints,
+    refund_status: Option<&[storage_enums::RefundStatus]>,
+) -> StorageResult<Vec<Refund>> {
+    let mut query = refund::table
+        .filter(refund::merchant_id.eq(merchant_id.to_owned()))
+        .filter(refund::modified_at.ge(constraints.start_time))
+        .order((refund::modified_at.asc(), refund::refund_id.asc()))
+        .limit(constraints.limit)
+        .into_boxed();
+
+    if let Some(end_time) = constraints.end_time {
+        query = query.filter(refund::modified_at.lt(end_time));
+    }
+    if let Some(profile_ids) = &constraints.profile_ids {
+        query = query.filter(refund::profile_id.eq_any(profile_ids.clone()));
+    }
+    if let Some(currency) = &constraints.currency {
+        query = query.filter(refund::currency.eq_any(currency.clone()));
+    }
+    if let Some(refund_status) = refund_status {
+        query = query.filter(refund::refund_status.eq_any(refund_status.to_vec()));
+    }
+    if let Some(cursor) = &constraints.cursor {
+        query = query.filter(
+            refund::modified_at
+                .gt(cursor.modified_at)
+                .or(refund::modified_at
+                    .eq(cursor.modified_at)
+                    .and(refund::refund_id.gt(cursor.id.clone()))),
+        );
+    }
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding the refunds to be exported")
+}
@@ -7,7 +7,7 @@ use crate::{
     errors as db_errors,
     generic_link::{
         GenericLink, GenericLinkData, GenericLinkNew, GenericLinkState, GenericLinkUpdateInternal,
-        PaymentMethodCollectLink, PayoutLink, PayoutLinkUpdate,
+        PaymentMethodCollectLink, PaymentMethodCollectLinkUpdate, PayoutLink, PayoutLinkUpdate,
     },
     schema::generic_link::dsl,
     PgPooledConn, StorageResult,
@@ -98,6 +98,35 @@ impl GenericLink {
     }
 }
 
+impl PaymentMethodCollectLink {
+    pub async fn update_pm_collect_link(
+        self,
+        conn: &PgPooledConn,
+        pm_collect_link_update: PaymentMethodCollectLinkUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<GenericLink as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::link_id.eq(self.link_id.to_owned()),
+            GenericLinkUpdateInternal::from(pm_collect_link_update),
+        )
+        .await
+        .and_then(|mut pm_collect_links: Vec<GenericLink>| {
+            pm_collect_links
+                .pop()
+                .ok_or(error_stack::report!(db_errors::DatabaseError::NotFound))
+        })
+        .and_then(|res| {
+            Self::try_from(res)
+                .change_context(db_errors::DatabaseError::Others)
+                .attach_printable("failed to parse payment method collect link data from DB")
+        })
+        .or_else(|error| match error.current_context() {
+            db_errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+            _ => Err(error),
+        })
+    }
+}
+
 impl PayoutLink {
     pub async fn update_payout_link(
         self,
@@ -0,0 +1,160 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::id_type;
+use diesel::{debug_query, ExpressionMethods, QueryDsl};
+use error_stack::ResultExt;
+use router_env::logger;
+
+#[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+use crate::{customers::Customer, schema::customers};
+use crate::{
+    dispute::Dispute,
+    errors,
+    refund::Refund,
+    schema::{dispute, refund},
+    PgPooledConn, StorageResult,
+};
+#[cfg(feature = "v1")]
+use crate::{
+    payment_attempt::PaymentAttempt,
+    payment_intent::PaymentIntent,
+    schema::{payment_attempt, payment_intent},
+};
+
+/// Finds the payments of the merchant with any of the payment ids
+#[cfg(feature = "v1")]
+pub async fn find_payment_intents_by_payment_ids(
+    conn: &PgPooledConn,
+    merchant_id: &id_type::MerchantId,
+    payment_ids: &[id_type::PaymentId],
+) -> StorageResult<Vec<PaymentIntent>> {
+    let query = payment_intent::table
+        .filter(payment_intent::merchant_id.eq(merchant_id.to_owned()))
+        .filter(payment_intent::payment_id.eq_any(payment_ids.to_vec()));
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding payments by payment ids")
+}
+
+/// Finds the attempts of the payments of the merchant with any of the payment ids
+#[cfg(feature = "v1")]
+pub async fn find_payment_attempts_by_payment_ids(
+    conn: &PgPooledConn,
+    merchant_id: &id_type::MerchantId,
+    payment_ids: &[id_type::PaymentId],
+) -> StorageResult<Vec<PaymentAttempt>> {
+    let query = payment_attempt::table
+        .filter(payment_attempt::merchant_id.eq(merchant_id.to_owned()))
+        .filter(payment_attempt::payment_id.eq_any(payment_ids.to_vec()))
+        .order(payment_attempt::created_at.asc());
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding payment attempts by payment ids")
+}
+
+/// Finds the refunds of the payments of the merchant with any of the payment ids
+pub async fn find_refunds_by_payment_ids(
+    conn: &PgPooledConn,
+    merchant_id: &id_type::MerchantId,
+    payment_ids: &[id_type::PaymentId],
+) -> StorageResult<Vec<Refund>> {
+    let query = refund::table
+        .filter(refund::merchant_id.eq(merchant_id.to_owned()))
+        .filter(refund::payment_id.eq_any(payment_ids.to_vec()))
+        .order(refund::created_at.asc());
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding refunds by payment ids")
+}
+
+/// Finds the refunds of the merchant with any of the refund ids
+pub async fn find_refunds_by_refund_ids(
+    conn: &PgPooledConn,
+    merchant_id: &id_type::MerchantId,
+    refund_ids: &[String],
+) -> StorageResult<Vec<Refund>> {
+    let query = refund::table
+        .filter(refund::merchant_id.eq(merchant_id.to_owned()))
+        .filter(refund::refund_id.eq_any(refund_ids.to_vec()));
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding refunds by refund ids")
+}
+
+/// Finds the disputes of the payments of the merchant with any of the payment ids
+pub async fn find_disputes_by_payment_ids(
+    conn: &PgPooledConn,
+    merchant_id: &id_type::MerchantId,
+    payment_ids: &[id_type::PaymentId],
+) -> StorageResult<Vec<Dispute>> {
+    let query = dispute::table
+        .filter(dispute::merchant_id.eq(merchant_id.to_owned()))
+        .filter(dispute::payment_id.eq_any(payment_ids.to_vec()))
+        .order(dispute::created_at.asc());
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding disputes by payment ids")
+}
+
+/// Finds the disputes of the merchant with any of the dispute ids
+pub async fn find_disputes_by_dispute_ids(
+    conn: &PgPooledConn,
+    merchant_id: &id_type::MerchantId,
+    dispute_ids: &[String],
+) -> StorageResult<Vec<Dispute>> {
+    let query = dispute::table
+        .filter(dispute::merchant_id.eq(merchant_id.to_owned()))
+        .filter(dispute::dispute_id.eq_any(dispute_ids.to_vec()));
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding disputes by dispute ids")
+}
+
+/// Finds the customers of the merchant with any of the customer ids
+#[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+pub async fn find_customers_by_customer_ids(
+    conn: &PgPooledConn,
+    merchant_id: &id_type::MerchantId,
+    customer_ids: &[id_type::CustomerId],
+) -> StorageResult<Vec<Customer>> {
+    let query = customers::table
+        .filter(customers::merchant_id.eq(merchant_id.to_owned()))
+        .filter(customers::customer_id.eq_any(customer_ids.to_vec()));
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding customers by customer ids")
+}
@@ -0,0 +1,120 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, result::Error as DieselError,
+    BoolExpressionMethods, ExpressionMethods, PgArrayExpressionMethods, QueryDsl, Table,
+};
+use error_stack::{report, ResultExt};
+
+use super::generics;
+use crate::{
+    enums::InvoiceStatus,
+    errors,
+    invoice::{Invoice, InvoiceNew, InvoiceUpdate, InvoiceUpdateInternal},
+    schema::invoice::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl InvoiceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<Invoice> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl Invoice {
+    pub async fn find_by_merchant_id_invoice_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::invoice_id.eq(invoice_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_ids.contains(vec![payment_id.get_string_repr().to_owned()])),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<InvoiceStatus>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(status) = status {
+            query = query.filter(dsl::status.eq(status));
+        }
+
+        query = query.limit(limit);
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        match generics::db_metrics::track_database_call::<Self, _, _>(
+            query.get_results_async(conn),
+            generics::db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        {
+            Ok(value) => Ok(value),
+            Err(err) => match err {
+                DieselError::NotFound => {
+                    Err(report!(err)).change_context(errors::DatabaseError::NotFound)
+                }
+                _ => Err(report!(err)).change_context(errors::DatabaseError::Others),
+            },
+        }
+    }
+
+    pub async fn update(self, conn: &PgPooledConn, invoice: InvoiceUpdate) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::invoice_id.eq(self.invoice_id.to_owned()),
+            InvoiceUpdateInternal::from(invoice),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use async_bb8_diesel::{AsyncConnection, AsyncRunQueryDsl};
+use diesel::{
+    associations::HasTable, result::Error as DieselError, BoolExpressionMethods, ExpressionMethods,
+    OptionalExtension, QueryDsl, Table,
+};
+use error_stack::{report, ResultExt};
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    ledger_entry::{LedgerEntry, LedgerEntryNew, RefundReversal},
+    refund::{Refund, RefundUpdateInternal},
+    schema::{ledger_entry::dsl, refund},
+    PgPooledConn, StorageResult,
+};
+
+impl LedgerEntryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<LedgerEntry> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl LedgerEntry {
+    pub async fn find_by_merchant_id_dispute_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::dispute_id.eq(dispute_id.to_owned())),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
+    /// Records the ledger entries and reverses the refunds in a single transaction. A refund is
+    /// reversed, and its reversal recorded, only if it is still pending and was not sent to the
+    /// connector. Returns the recorded ledger entries and the reversed refunds.
+    pub async fn insert_with_refund_reversals(
+        conn: &PgPooledConn,
+        mut ledger_entries: Vec<LedgerEntryNew>,
+        refund_reversals: Vec<RefundReversal>,
+    ) -> StorageResult<(Vec<Self>, Vec<Refund>)> {
+        conn.transaction_async(|conn| {
+            Box::pin(async move {
+                let mut reversed_refunds = Vec::with_capacity(refund_reversals.len());
+                for refund_reversal in refund_reversals {
+                    let reversed_refund = diesel::update(
+                        refund::table.filter(
+                            refund::merchant_id
+                                .eq(refund_reversal.refund.merchant_id)
+                                .and(refund::refund_id.eq(refund_reversal.refund.refund_id))
+                                .and(refund::refund_status.eq(storage_enums::RefundStatus::Pending))
+                                .and(refund::sent_to_gateway.eq(false)),
+                        ),
+                    )
+                    .set(RefundUpdateInternal::from(refund_reversal.refund_update))
+                    .get_result_async::<Refund>(&conn)
+                    .await
+                    .optional()?;
+
+                    if let Some(reversed_refund) = reversed_refund {
+                        ledger_entries.push(refund_reversal.ledger_entry);
+                        reversed_refunds.push(reversed_refund);
+                    }
+                }
+
+                let recorded_ledger_entries = diesel::insert_into(dsl::ledger_entry)
+                    .values(ledger_entries)
+                    .get_results_async::<Self>(&conn)
+                    .await?;
+
+                Ok::<_, DieselError>((recorded_ledger_entries, reversed_refunds))
+            })
+        })
+        .await
+        .map_err(|error| match error {
+            DieselError::DatabaseError(diesel::result::DatabaseErrorKind::UniqueViolation, _) => {
+                report!(error).change_context(errors::DatabaseError::UniqueViolation)
+            }
+            _ => report!(error).change_context(errors::DatabaseError::Others),
+        })
+        .attach_printable("Error while recording ledger entries with refund reversals")
+    }
+}
@@ -45,7 +45,10 @@ impl PaymentAttempt {
             dsl::attempt_id
                 .eq(self.attempt_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            PaymentAttemptUpdateInternal::from(payment_attempt).populate_derived_fields(&self),
+            (
+                PaymentAttemptUpdateInternal::from(payment_attempt).populate_derived_fields(&self),
+                dsl::row_version.eq(dsl::row_version + 1),
+            ),
         )
         .await
         {
@@ -57,6 +60,30 @@ impl PaymentAttempt {
         }
     }
 
+    /// Updates the payment attempt only if the stored record still has the `row_version` that
+    /// was read, returns `VersionConflict` if the record was modified by a concurrent update
+    #[cfg(feature = "v1")]
+    pub async fn update_with_attempt_id_if_row_version_matches(
+        self,
+        conn: &PgPooledConn,
+        payment_attempt: PaymentAttemptUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::attempt_id
+                .eq(self.attempt_id.to_owned())
+                .and(dsl::merchant_id.eq(self.merchant_id.to_owned()))
+                .and(dsl::row_version.eq(self.row_version)),
+            (
+                PaymentAttemptUpdateInternal::from(payment_attempt).populate_derived_fields(&self),
+                dsl::row_version.eq(dsl::row_version + 1),
+            ),
+        )
+        .await?
+        .pop()
+        .ok_or(report!(DatabaseError::VersionConflict))
+    }
+
     #[cfg(feature = "v2")]
     pub async fn update_with_attempt_id(
         self,
@@ -58,7 +58,10 @@ impl PaymentIntent {
             dsl::payment_id
                 .eq(self.payment_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            payment_intent::PaymentIntentUpdateInternal::from(payment_intent),
+            (
+                payment_intent::PaymentIntentUpdateInternal::from(payment_intent),
+                dsl::row_version.eq(dsl::row_version + 1),
+            ),
         )
         .await
         {
@@ -72,6 +75,30 @@ impl PaymentIntent {
         }
     }
 
+    /// Updates the payment intent only if the stored record still has the `row_version` that
+    /// was read, returns `VersionConflict` if the record was modified by a concurrent update
+    #[cfg(feature = "v1")]
+    pub async fn update_if_row_version_matches(
+        self,
+        conn: &PgPooledConn,
+        payment_intent: payment_intent::PaymentIntentUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::payment_id
+                .eq(self.payment_id.to_owned())
+                .and(dsl::merchant_id.eq(self.merchant_id.to_owned()))
+                .and(dsl::row_version.eq(self.row_version)),
+            (
+                payment_intent::PaymentIntentUpdateInternal::from(payment_intent),
+                dsl::row_version.eq(dsl::row_version + 1),
+            ),
+        )
+        .await?
+        .pop()
+        .ok_or(error_stack::report!(errors::DatabaseError::VersionConflict))
+    }
+
     #[cfg(feature = "v2")]
     pub async fn find_by_merchant_reference_id_merchant_id(
         conn: &PgPooledConn,
@@ -13,7 +13,7 @@ use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
 ))]
-use diesel::{debug_query, pg::Pg, QueryDsl};
+use diesel::{debug_query, pg::Pg, PgJsonbExpressionMethods, QueryDsl};
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -136,6 +136,37 @@ impl PaymentMethod {
         todo!()
     }
 
+    /// Finds the payment methods holding the given connector mandate id, which is also the id of
+    /// the reusable wallet tokens issued by connectors, for the merchant connector account
+    pub async fn find_by_merchant_id_connector_mandate_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+        connector_mandate_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        let connector_mandate_reference = serde_json::json!({
+            merchant_connector_id.get_string_repr(): {
+                "connector_mandate_id": connector_mandate_id,
+            }
+        });
+
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector_mandate_details.contains(connector_mandate_reference)),
+            None,
+            None,
+            None,
+        )
+        .await
+    }
+
     pub async fn get_count_by_customer_id_merchant_id_status(
         conn: &PgPooledConn,
         customer_id: &common_utils::id_type::CustomerId,
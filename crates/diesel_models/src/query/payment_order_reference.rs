@@ -0,0 +1,44 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    payment_order_reference::{PaymentOrderReference, PaymentOrderReferenceNew},
+    schema::payment_order_reference::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentOrderReferenceNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentOrderReference> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentOrderReference {
+    pub async fn find_by_profile_id_merchant_order_reference_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_order_reference_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::merchant_order_reference_id.eq(merchant_order_reference_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn delete_by_profile_id_merchant_order_reference_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_order_reference_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::merchant_order_reference_id.eq(merchant_order_reference_id.to_owned())),
+        )
+        .await
+    }
+}
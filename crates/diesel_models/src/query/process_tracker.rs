@@ -1,5 +1,10 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
-use router_env::{instrument, tracing};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, BoolExpressionMethods, ExpressionMethods, QueryDsl, Table,
+    TextExpressionMethods,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
 use time::PrimitiveDateTime;
 
 use super::generics;
@@ -12,6 +17,11 @@ use crate::{
     PgPooledConn, StorageResult,
 };
 
+diesel::define_sql_function! {
+    /// Reverses the characters of the string
+    fn reverse(string: diesel::sql_types::Text) -> diesel::sql_types::Text;
+}
+
 impl ProcessTrackerNew {
     #[instrument(skip(conn))]
     pub async fn insert_process(self, conn: &PgPooledConn) -> StorageResult<ProcessTracker> {
@@ -89,6 +99,48 @@ impl ProcessTracker {
         .await
     }
 
+    /// Finds a page of the processes whose identifiers end with the suffix, in the order of their
+    /// identifiers. The suffix is matched as a prefix of the reversed identifier, which is indexed.
+    #[instrument(skip(conn))]
+    pub async fn find_processes_by_id_suffix_time_status(
+        conn: &PgPooledConn,
+        id_suffix: &str,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: enums::ProcessTrackerStatus,
+        after_id: Option<String>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let reversed_suffix = id_suffix
+            .chars()
+            .rev()
+            .flat_map(|character| match character {
+                '\\' | '%' | '_' => vec!['\\', character],
+                character => vec![character],
+            })
+            .collect::<String>();
+
+        let mut query = dsl::process_tracker
+            .filter(reverse(dsl::id).like(format!("{reversed_suffix}%")))
+            .filter(dsl::schedule_time.between(time_lower_limit, time_upper_limit))
+            .filter(dsl::status.eq(status))
+            .order(dsl::id.asc())
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(after_id) = after_id {
+            query = query.filter(dsl::id.gt(after_id));
+        }
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while finding processes by identifier suffix")
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_processes_to_clean(
         conn: &PgPooledConn,
@@ -0,0 +1,57 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    refund_tender_override::{
+        RefundTenderOverride, RefundTenderOverrideNew, RefundTenderOverrideUpdate,
+        RefundTenderOverrideUpdateInternal,
+    },
+    schema::refund_tender_override::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl RefundTenderOverrideNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<RefundTenderOverride> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl RefundTenderOverride {
+    pub async fn update_by_merchant_id_override_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        override_id: &str,
+        update: RefundTenderOverrideUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::override_id.eq(override_id.to_owned())),
+            RefundTenderOverrideUpdateInternal::from(update),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+}
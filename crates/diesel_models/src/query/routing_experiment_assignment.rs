@@ -0,0 +1,83 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{BoolExpressionMethods, ExpressionMethods, QueryDsl};
+use error_stack::{report, ResultExt};
+
+use crate::{
+    errors,
+    routing_experiment_assignment::{
+        RoutingExperimentArmCount, RoutingExperimentAssignment, RoutingExperimentAssignmentNew,
+    },
+    schema::routing_experiment_assignment::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl RoutingExperimentAssignmentNew {
+    /// Inserts the assignment unless the payment was assigned to the experiment already, returning
+    /// whether it was inserted
+    pub async fn insert_if_not_exists(self, conn: &PgPooledConn) -> StorageResult<bool> {
+        diesel::insert_into(dsl::routing_experiment_assignment)
+            .values(self)
+            .on_conflict_do_nothing()
+            .execute_async(conn)
+            .await
+            .map(|inserted_rows| inserted_rows > 0)
+            .map_err(|error| report!(error).change_context(errors::DatabaseError::Others))
+            .attach_printable("Error while inserting a routing experiment assignment")
+    }
+}
+
+impl RoutingExperimentAssignment {
+    /// Records whether the payment assigned to the experiment succeeded. A success replaces an
+    /// earlier failure, as a payment failing on its first attempt may succeed on a retry, while a
+    /// failure never replaces an earlier outcome.
+    pub async fn update_outcome(
+        conn: &PgPooledConn,
+        experiment_id: &str,
+        payment_id: &common_utils::id_type::PaymentId,
+        succeeded: bool,
+        completed_at: time::PrimitiveDateTime,
+    ) -> StorageResult<()> {
+        let assignment = dsl::routing_experiment_assignment.filter(
+            dsl::experiment_id
+                .eq(experiment_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+        );
+        let result = if succeeded {
+            diesel::update(assignment.filter(dsl::succeeded.is_null().or(dsl::succeeded.eq(false))))
+                .set((
+                    dsl::succeeded.eq(Some(true)),
+                    dsl::completed_at.eq(Some(completed_at)),
+                ))
+                .execute_async(conn)
+                .await
+        } else {
+            diesel::update(assignment.filter(dsl::succeeded.is_null()))
+                .set((
+                    dsl::succeeded.eq(Some(false)),
+                    dsl::completed_at.eq(Some(completed_at)),
+                ))
+                .execute_async(conn)
+                .await
+        };
+
+        result
+            .map(|_| ())
+            .map_err(|error| report!(error).change_context(errors::DatabaseError::Others))
+            .attach_printable("Error while updating the outcome of a routing experiment assignment")
+    }
+
+    /// Counts the payments assigned to each arm of the experiment, by whether they succeeded
+    pub async fn count_by_experiment_id(
+        conn: &PgPooledConn,
+        experiment_id: &str,
+    ) -> StorageResult<Vec<RoutingExperimentArmCount>> {
+        dsl::routing_experiment_assignment
+            .filter(dsl::experiment_id.eq(experiment_id.to_owned()))
+            .group_by((dsl::arm, dsl::succeeded))
+            .select((dsl::arm, dsl::succeeded, diesel::dsl::count_star()))
+            .load_async::<RoutingExperimentArmCount>(conn)
+            .await
+            .map_err(|error| report!(error).change_context(errors::DatabaseError::Others))
+            .attach_printable("Error while counting the routing experiment assignments")
+    }
+}
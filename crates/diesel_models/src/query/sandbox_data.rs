@@ -0,0 +1,141 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_enums::SandboxDataResource;
+use diesel::{debug_query, ExpressionMethods, QueryDsl, Queryable};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use crate::{
+    enums as storage_enums, errors,
+    schema::{
+        address, customers, dispute, ledger_entry, mandate, payment_attempt, payment_intent,
+        payment_methods, refund, refund_tender_override,
+    },
+    PgPooledConn, StorageResult,
+};
+
+/// References of a payment method to the data held for it in the locker
+#[derive(Clone, Debug, Queryable)]
+pub struct PaymentMethodLockerReference {
+    pub payment_method_id: String,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub payment_method: Option<storage_enums::PaymentMethod>,
+    pub locker_id: Option<String>,
+    pub network_token_requestor_reference_id: Option<String>,
+    pub network_token_locker_id: Option<String>,
+}
+
+/// Deletes up to `limit` records of the merchant from the table, selecting the records to be
+/// deleted by the given key column
+macro_rules! delete_merchant_records {
+    ($conn:expr, $merchant_id:expr, $limit:expr, $table:ident, $key:ident) => {{
+        let batch = $table::table
+            .select($table::$key)
+            .filter($table::merchant_id.eq($merchant_id.to_owned()))
+            .limit($limit);
+        let query = diesel::delete($table::table)
+            .filter($table::merchant_id.eq($merchant_id.to_owned()))
+            .filter($table::$key.eq_any(batch));
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query.execute_async($conn).await
+    }};
+}
+
+/// Deletes a batch of the records of a resource of the merchant, returning the number of records
+/// deleted. The merchant has no more records of the resource once fewer than `limit` records are
+/// deleted.
+pub async fn delete_batch(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    resource: SandboxDataResource,
+    limit: i64,
+) -> StorageResult<usize> {
+    match resource {
+        SandboxDataResource::LedgerEntries => {
+            delete_merchant_records!(conn, merchant_id, limit, ledger_entry, entry_id)
+        }
+        SandboxDataResource::RefundTenderOverrides => {
+            delete_merchant_records!(
+                conn,
+                merchant_id,
+                limit,
+                refund_tender_override,
+                override_id
+            )
+        }
+        SandboxDataResource::Refunds => {
+            delete_merchant_records!(conn, merchant_id, limit, refund, refund_id)
+        }
+        SandboxDataResource::Disputes => {
+            delete_merchant_records!(conn, merchant_id, limit, dispute, dispute_id)
+        }
+        SandboxDataResource::PaymentAttempts => {
+            delete_merchant_records!(conn, merchant_id, limit, payment_attempt, attempt_id)
+        }
+        SandboxDataResource::Payments => {
+            delete_merchant_records!(conn, merchant_id, limit, payment_intent, payment_id)
+        }
+        SandboxDataResource::Mandates => {
+            delete_merchant_records!(conn, merchant_id, limit, mandate, mandate_id)
+        }
+        SandboxDataResource::PaymentMethods => {
+            delete_merchant_records!(conn, merchant_id, limit, payment_methods, payment_method_id)
+        }
+        SandboxDataResource::Addresses => {
+            delete_merchant_records!(conn, merchant_id, limit, address, address_id)
+        }
+        SandboxDataResource::Customers => {
+            delete_merchant_records!(conn, merchant_id, limit, customers, customer_id)
+        }
+    }
+    .change_context(errors::DatabaseError::Others)
+    .attach_printable_lazy(|| format!("Error while deleting the {resource} of the merchant"))
+}
+
+/// Finds the locker references of up to `limit` payment methods of the merchant
+pub async fn find_payment_method_locker_references(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    limit: i64,
+) -> StorageResult<Vec<PaymentMethodLockerReference>> {
+    let query = payment_methods::table
+        .select((
+            payment_methods::payment_method_id,
+            payment_methods::customer_id,
+            payment_methods::payment_method,
+            payment_methods::locker_id,
+            payment_methods::network_token_requestor_reference_id,
+            payment_methods::network_token_locker_id,
+        ))
+        .filter(payment_methods::merchant_id.eq(merchant_id.to_owned()))
+        .order(payment_methods::payment_method_id)
+        .limit(limit);
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding the payment methods of the merchant")
+}
+
+/// Deletes the given payment methods of the merchant, returning the number of records deleted
+pub async fn delete_payment_methods(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    payment_method_ids: Vec<String>,
+) -> StorageResult<usize> {
+    let query = diesel::delete(payment_methods::table)
+        .filter(payment_methods::merchant_id.eq(merchant_id.to_owned()))
+        .filter(payment_methods::payment_method_id.eq_any(payment_method_ids));
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .execute_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while deleting the payment methods of the merchant")
+}
@@ -0,0 +1,81 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    schema::webhook_endpoint::dsl,
+    webhook_endpoint::{
+        WebhookEndpoint, WebhookEndpointNew, WebhookEndpointUpdate, WebhookEndpointUpdateInternal,
+    },
+    PgPooledConn, StorageResult,
+};
+
+impl WebhookEndpointNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<WebhookEndpoint> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl WebhookEndpoint {
+    pub async fn find_by_profile_id_endpoint_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::endpoint_id.eq(endpoint_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_profile_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::profile_id.eq(profile_id.to_owned()),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_profile_id_endpoint_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+        webhook_endpoint_update: WebhookEndpointUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::endpoint_id.eq(endpoint_id.to_owned())),
+            WebhookEndpointUpdateInternal::from(webhook_endpoint_update),
+        )
+        .await
+    }
+
+    pub async fn delete_by_profile_id_endpoint_id(
+        conn: &PgPooledConn,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::profile_id
+                .eq(profile_id.to_owned())
+                .and(dsl::endpoint_id.eq(endpoint_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -53,6 +53,12 @@ pub struct Refund {
     pub organization_id: common_utils::id_type::OrganizationId,
     pub connector_refund_data: Option<String>,
     pub connector_transaction_data: Option<String>,
+    // amount of the refund in the currency settled by the connector
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub settlement_amount: Option<MinorUnit>,
+    // time by which the pending refund is expected to be settled by the connector
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expected_settlement_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(
@@ -110,6 +116,9 @@ pub enum RefundUpdate {
         refund_arn: String,
         updated_by: String,
         connector_refund_data: Option<String>,
+        settlement_currency: Option<storage_enums::Currency>,
+        settlement_amount: Option<MinorUnit>,
+        expected_settlement_at: Option<PrimitiveDateTime>,
     },
     MetadataAndReasonUpdate {
         metadata: Option<pii::SecretSerdeValue>,
@@ -153,6 +162,9 @@ pub struct RefundUpdateInternal {
     updated_by: String,
     modified_at: PrimitiveDateTime,
     connector_refund_data: Option<String>,
+    settlement_currency: Option<storage_enums::Currency>,
+    settlement_amount: Option<MinorUnit>,
+    expected_settlement_at: Option<PrimitiveDateTime>,
 }
 
 impl RefundUpdateInternal {
@@ -169,6 +181,11 @@ impl RefundUpdateInternal {
             updated_by: self.updated_by,
             modified_at: self.modified_at,
             connector_refund_data: self.connector_refund_data,
+            settlement_currency: self.settlement_currency.or(source.settlement_currency),
+            settlement_amount: self.settlement_amount.or(source.settlement_amount),
+            expected_settlement_at: self
+                .expected_settlement_at
+                .or(source.expected_settlement_at),
             ..source
         }
     }
@@ -185,6 +202,9 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_arn,
                 updated_by,
                 connector_refund_data,
+                settlement_currency,
+                settlement_amount,
+                expected_settlement_at,
             } => Self {
                 connector_refund_id: Some(connector_refund_id),
                 refund_status: Some(refund_status),
@@ -193,6 +213,9 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_arn: Some(refund_arn),
                 updated_by,
                 connector_refund_data,
+                settlement_currency,
+                settlement_amount,
+                expected_settlement_at,
                 metadata: None,
                 refund_reason: None,
                 refund_error_code: None,
@@ -214,6 +237,9 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
                 connector_refund_data: None,
+                settlement_currency: None,
+                settlement_amount: None,
+                expected_settlement_at: None,
             },
             RefundUpdate::StatusUpdate {
                 connector_refund_id,
@@ -233,6 +259,9 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_reason: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                settlement_currency: None,
+                settlement_amount: None,
+                expected_settlement_at: None,
             },
             RefundUpdate::ErrorUpdate {
                 refund_status,
@@ -253,6 +282,9 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 metadata: None,
                 refund_reason: None,
                 modified_at: common_utils::date_time::now(),
+                settlement_currency: None,
+                settlement_amount: None,
+                expected_settlement_at: None,
             },
             RefundUpdate::ManualUpdate {
                 refund_status,
@@ -271,6 +303,9 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_reason: None,
                 modified_at: common_utils::date_time::now(),
                 connector_refund_data: None,
+                settlement_currency: None,
+                settlement_amount: None,
+                expected_settlement_at: None,
             },
         }
     }
@@ -290,6 +325,9 @@ impl RefundUpdate {
             updated_by,
             modified_at: _,
             connector_refund_data,
+            settlement_currency,
+            settlement_amount,
+            expected_settlement_at,
         } = self.into();
         Refund {
             connector_refund_id: connector_refund_id.or(source.connector_refund_id),
@@ -303,6 +341,9 @@ impl RefundUpdate {
             updated_by,
             modified_at: common_utils::date_time::now(),
             connector_refund_data: connector_refund_data.or(source.connector_refund_data),
+            settlement_currency: settlement_currency.or(source.settlement_currency),
+            settlement_amount: settlement_amount.or(source.settlement_amount),
+            expected_settlement_at: expected_settlement_at.or(source.expected_settlement_at),
             ..source
         }
     }
@@ -0,0 +1,76 @@
+use common_utils::{id_type, types::MinorUnit};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::refund_tender_override};
+
+/// A refund of a payment made to a bank account through a payout, instead of to the payment
+/// method the payment was made with. The refund is recorded before its payout is created, and is
+/// never removed so that the refunds of a payment form its audit trail.
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = refund_tender_override, primary_key(override_id), check_for_backend(diesel::pg::Pg))]
+pub struct RefundTenderOverride {
+    pub override_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub payment_id: id_type::PaymentId,
+    pub payout_id: Option<String>,
+    pub status: storage_enums::RefundTenderOverrideStatus,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub reason: String,
+    pub created_by: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = refund_tender_override)]
+pub struct RefundTenderOverrideNew {
+    pub override_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub payment_id: id_type::PaymentId,
+    pub payout_id: Option<String>,
+    pub status: storage_enums::RefundTenderOverrideStatus,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub reason: String,
+    pub created_by: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug)]
+pub enum RefundTenderOverrideUpdate {
+    PayoutCreated { payout_id: String },
+    Failed,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = refund_tender_override)]
+pub struct RefundTenderOverrideUpdateInternal {
+    pub payout_id: Option<String>,
+    pub status: storage_enums::RefundTenderOverrideStatus,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<RefundTenderOverrideUpdate> for RefundTenderOverrideUpdateInternal {
+    fn from(update: RefundTenderOverrideUpdate) -> Self {
+        match update {
+            RefundTenderOverrideUpdate::PayoutCreated { payout_id } => Self {
+                payout_id: Some(payout_id),
+                status: storage_enums::RefundTenderOverrideStatus::PayoutCreated,
+                modified_at: common_utils::date_time::now(),
+            },
+            RefundTenderOverrideUpdate::Failed => Self {
+                payout_id: None,
+                status: storage_enums::RefundTenderOverrideStatus::Failed,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
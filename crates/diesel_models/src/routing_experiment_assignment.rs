@@ -0,0 +1,38 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+
+use crate::schema::routing_experiment_assignment;
+
+#[derive(Clone, Debug, Eq, PartialEq, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = routing_experiment_assignment)]
+pub struct RoutingExperimentAssignmentNew {
+    pub experiment_id: String,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub arm: String,
+    pub assigned_at: time::PrimitiveDateTime,
+}
+
+/// Assignment of a payment to an arm of a routing experiment. Whether the payment succeeded is
+/// unknown until the payment reaches a successful or failed status.
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Deserialize, Serialize,
+)]
+#[diesel(table_name = routing_experiment_assignment, primary_key(experiment_id, payment_id), check_for_backend(diesel::pg::Pg))]
+pub struct RoutingExperimentAssignment {
+    pub experiment_id: String,
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub arm: String,
+    pub succeeded: Option<bool>,
+    pub assigned_at: time::PrimitiveDateTime,
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+/// Number of the payments assigned to an arm of an experiment, by whether they succeeded
+#[derive(Clone, Debug, Eq, PartialEq, Queryable)]
+pub struct RoutingExperimentArmCount {
+    pub arm: String,
+    pub succeeded: Option<bool>,
+    pub count: i64,
+}
@@ -166,6 +166,26 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    blocklist_sync_version (merchant_id, version) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        version -> Int4,
+        #[max_length = 255]
+        source -> Varchar,
+        #[max_length = 255]
+        external_version -> Nullable<Varchar>,
+        added_entries -> Jsonb,
+        removed_entries -> Jsonb,
+        unchanged_count -> Int4,
+        created_at -> Timestamp,
+        rolled_back_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -250,6 +270,8 @@ diesel::table! {
         connector_response_reference_id -> Nullable<Varchar>,
         #[max_length = 512]
         connector_capture_data -> Nullable<Varchar>,
+        settlement_currency -> Nullable<Currency>,
+        settlement_amount -> Nullable<Int8>,
     }
 }
 
@@ -384,6 +406,8 @@ diesel::table! {
         dispute_amount -> Int8,
         #[max_length = 32]
         organization_id -> Varchar,
+        submitted_evidence_types -> Nullable<Array<Nullable<Text>>>,
+        evidence_submitted_at -> Nullable<Timestamp>,
     }
 }
 
@@ -406,7 +430,7 @@ diesel::table! {
         #[max_length = 64]
         business_profile_id -> Nullable<Varchar>,
         primary_object_created_at -> Nullable<Timestamp>,
-        #[max_length = 64]
+        #[max_length = 128]
         idempotent_event_id -> Nullable<Varchar>,
         #[max_length = 64]
         initial_attempt_id -> Nullable<Varchar>,
@@ -414,6 +438,8 @@ diesel::table! {
         response -> Nullable<Bytea>,
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
+        #[max_length = 64]
+        webhook_endpoint_id -> Nullable<Varchar>,
     }
 }
 
@@ -557,6 +583,60 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    invoice (invoice_id) {
+        #[max_length = 64]
+        invoice_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        currency -> Currency,
+        amount -> Int8,
+        amount_paid -> Int8,
+        line_items -> Jsonb,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        payment_ids -> Array<Nullable<Text>>,
+        due_date -> Timestamp,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    ledger_entry (entry_id) {
+        #[max_length = 64]
+        entry_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        dispute_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        entry_type -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -680,6 +760,8 @@ diesel::table! {
         payment_link_config -> Nullable<Jsonb>,
         pm_collect_link_config -> Nullable<Jsonb>,
         version -> ApiVersion,
+        #[max_length = 64]
+        data_region -> Nullable<Varchar>,
     }
 }
 
@@ -851,6 +933,14 @@ diesel::table! {
         #[max_length = 512]
         connector_transaction_data -> Nullable<Varchar>,
         connector_mandate_detail -> Nullable<Jsonb>,
+        row_version -> Int4,
+        routing_decision -> Nullable<Jsonb>,
+        connector_fee_amount -> Nullable<Int8>,
+        connector_fee_currency -> Nullable<Currency>,
+        #[max_length = 255]
+        connector_request_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        correlation_id -> Nullable<Varchar>,
     }
 }
 
@@ -931,6 +1021,7 @@ diesel::table! {
         organization_id -> Varchar,
         tax_details -> Nullable<Jsonb>,
         skip_external_tax_calculation -> Nullable<Bool>,
+        row_version -> Int4,
     }
 }
 
@@ -1025,6 +1116,42 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_order_reference (profile_id, merchant_order_reference_id) {
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 255]
+        merchant_order_reference_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_shipment (payment_id, shipment_reference) {
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        shipment_reference -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        capture_id -> Varchar,
+        amount -> Int8,
+        line_items -> Jsonb,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1192,6 +1319,35 @@ diesel::table! {
         connector_refund_data -> Nullable<Varchar>,
         #[max_length = 512]
         connector_transaction_data -> Nullable<Varchar>,
+        settlement_currency -> Nullable<Currency>,
+        settlement_amount -> Nullable<Int8>,
+        expected_settlement_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    refund_tender_override (override_id) {
+        #[max_length = 64]
+        override_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        payout_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 255]
+        reason -> Varchar,
+        #[max_length = 64]
+        created_by -> Varchar,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -1262,6 +1418,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    routing_experiment_assignment (experiment_id, payment_id) {
+        #[max_length = 64]
+        experiment_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 32]
+        arm -> Varchar,
+        succeeded -> Nullable<Bool>,
+        assigned_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1391,6 +1566,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_endpoint (endpoint_id) {
+        #[max_length = 64]
+        endpoint_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        url -> Text,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        signing_secret -> Bytea,
+        enabled_events -> Nullable<Jsonb>,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
@@ -1398,6 +1595,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     blocklist,
     blocklist_fingerprint,
     blocklist_lookup,
+    blocklist_sync_version,
     business_profile,
     captures,
     cards_info,
@@ -1411,6 +1609,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     gateway_status_map,
     generic_link,
     incremental_authorization,
+    invoice,
+    ledger_entry,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -1421,17 +1621,22 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_intent,
     payment_link,
     payment_methods,
+    payment_order_reference,
+    payment_shipment,
     payout_attempt,
     payouts,
     process_tracker,
     refund,
+    refund_tender_override,
     reverse_lookup,
     roles,
     routing_algorithm,
+    routing_experiment_assignment,
     themes,
     unified_translations,
     user_authentication_methods,
     user_key_store,
     user_roles,
     users,
+    webhook_endpoint,
 );
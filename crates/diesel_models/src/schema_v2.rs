@@ -168,6 +168,26 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    blocklist_sync_version (merchant_id, version) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        version -> Int4,
+        #[max_length = 255]
+        source -> Varchar,
+        #[max_length = 255]
+        external_version -> Nullable<Varchar>,
+        added_entries -> Jsonb,
+        removed_entries -> Jsonb,
+        unchanged_count -> Int4,
+        created_at -> Timestamp,
+        rolled_back_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -258,6 +278,8 @@ diesel::table! {
         connector_response_reference_id -> Nullable<Varchar>,
         #[max_length = 512]
         connector_capture_data -> Nullable<Varchar>,
+        settlement_currency -> Nullable<Currency>,
+        settlement_amount -> Nullable<Int8>,
     }
 }
 
@@ -396,6 +418,8 @@ diesel::table! {
         dispute_amount -> Int8,
         #[max_length = 32]
         organization_id -> Varchar,
+        submitted_evidence_types -> Nullable<Array<Nullable<Text>>>,
+        evidence_submitted_at -> Nullable<Timestamp>,
     }
 }
 
@@ -418,7 +442,7 @@ diesel::table! {
         #[max_length = 64]
         business_profile_id -> Nullable<Varchar>,
         primary_object_created_at -> Nullable<Timestamp>,
-        #[max_length = 64]
+        #[max_length = 128]
         idempotent_event_id -> Nullable<Varchar>,
         #[max_length = 64]
         initial_attempt_id -> Nullable<Varchar>,
@@ -426,6 +450,8 @@ diesel::table! {
         response -> Nullable<Bytea>,
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
         metadata -> Nullable<Jsonb>,
+        #[max_length = 64]
+        webhook_endpoint_id -> Nullable<Varchar>,
     }
 }
 
@@ -569,6 +595,60 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    invoice (invoice_id) {
+        #[max_length = 64]
+        invoice_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        currency -> Currency,
+        amount -> Int8,
+        amount_paid -> Int8,
+        line_items -> Jsonb,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        metadata -> Nullable<Jsonb>,
+        payment_ids -> Array<Nullable<Text>>,
+        due_date -> Timestamp,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    ledger_entry (entry_id) {
+        #[max_length = 64]
+        entry_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        dispute_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        entry_type -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -969,6 +1049,42 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_order_reference (profile_id, merchant_order_reference_id) {
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 255]
+        merchant_order_reference_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_shipment (payment_id, shipment_reference) {
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        shipment_reference -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        capture_id -> Varchar,
+        amount -> Int8,
+        line_items -> Jsonb,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1137,6 +1253,35 @@ diesel::table! {
         connector_refund_data -> Nullable<Varchar>,
         #[max_length = 512]
         connector_transaction_data -> Nullable<Varchar>,
+        settlement_currency -> Nullable<Currency>,
+        settlement_amount -> Nullable<Int8>,
+        expected_settlement_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    refund_tender_override (override_id) {
+        #[max_length = 64]
+        override_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        payout_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 255]
+        reason -> Varchar,
+        #[max_length = 64]
+        created_by -> Varchar,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -1208,6 +1353,25 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    routing_experiment_assignment (experiment_id, payment_id) {
+        #[max_length = 64]
+        experiment_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 32]
+        arm -> Varchar,
+        succeeded -> Nullable<Bool>,
+        assigned_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1338,6 +1502,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    webhook_endpoint (endpoint_id) {
+        #[max_length = 64]
+        endpoint_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        url -> Text,
+        #[max_length = 255]
+        description -> Nullable<Varchar>,
+        signing_secret -> Bytea,
+        enabled_events -> Nullable<Jsonb>,
+        is_active -> Bool,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
@@ -1345,6 +1531,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     blocklist,
     blocklist_fingerprint,
     blocklist_lookup,
+    blocklist_sync_version,
     business_profile,
     captures,
     cards_info,
@@ -1358,6 +1545,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     gateway_status_map,
     generic_link,
     incremental_authorization,
+    invoice,
+    ledger_entry,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -1368,17 +1557,22 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_intent,
     payment_link,
     payment_methods,
+    payment_order_reference,
+    payment_shipment,
     payout_attempt,
     payouts,
     process_tracker,
     refund,
+    refund_tender_override,
     reverse_lookup,
     roles,
     routing_algorithm,
+    routing_experiment_assignment,
     themes,
     unified_translations,
     user_authentication_methods,
     user_key_store,
     user_roles,
     users,
+    webhook_endpoint,
 );
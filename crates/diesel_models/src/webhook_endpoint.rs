@@ -0,0 +1,142 @@
+use common_utils::{encryption::Encryption, id_type};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::webhook_endpoint};
+
+/// Endpoint of a business profile to which outgoing webhooks are posted, in addition to the
+/// webhook url of the profile
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable, Serialize, Deserialize)]
+#[diesel(table_name = webhook_endpoint, primary_key(endpoint_id), check_for_backend(diesel::pg::Pg))]
+pub struct WebhookEndpoint {
+    pub endpoint_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub url: String,
+    pub description: Option<String>,
+    pub signing_secret: Encryption,
+    // event types posted to the endpoint, all event types if not set
+    pub enabled_events: Option<WebhookEndpointEnabledEvents>,
+    pub is_active: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointNew {
+    pub endpoint_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: id_type::ProfileId,
+    pub url: String,
+    pub description: Option<String>,
+    pub signing_secret: Encryption,
+    pub enabled_events: Option<WebhookEndpointEnabledEvents>,
+    pub is_active: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<WebhookEndpointNew> for WebhookEndpoint {
+    fn from(webhook_endpoint_new: WebhookEndpointNew) -> Self {
+        Self {
+            endpoint_id: webhook_endpoint_new.endpoint_id,
+            merchant_id: webhook_endpoint_new.merchant_id,
+            profile_id: webhook_endpoint_new.profile_id,
+            url: webhook_endpoint_new.url,
+            description: webhook_endpoint_new.description,
+            signing_secret: webhook_endpoint_new.signing_secret,
+            enabled_events: webhook_endpoint_new.enabled_events,
+            is_active: webhook_endpoint_new.is_active,
+            created_at: webhook_endpoint_new.created_at,
+            modified_at: webhook_endpoint_new.modified_at,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, diesel::AsExpression, diesel::FromSqlRow)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+#[serde(transparent)]
+pub struct WebhookEndpointEnabledEvents(pub Vec<storage_enums::EventType>);
+
+common_utils::impl_to_sql_from_sql_json!(WebhookEndpointEnabledEvents);
+
+#[derive(Debug)]
+pub enum WebhookEndpointUpdate {
+    Update {
+        url: Option<String>,
+        description: Option<String>,
+        enabled_events: Option<WebhookEndpointEnabledEvents>,
+        is_active: Option<bool>,
+    },
+    SigningSecretUpdate {
+        signing_secret: Encryption,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = webhook_endpoint)]
+pub struct WebhookEndpointUpdateInternal {
+    url: Option<String>,
+    description: Option<String>,
+    signing_secret: Option<Encryption>,
+    enabled_events: Option<WebhookEndpointEnabledEvents>,
+    is_active: Option<bool>,
+    modified_at: PrimitiveDateTime,
+}
+
+impl From<WebhookEndpointUpdate> for WebhookEndpointUpdateInternal {
+    fn from(webhook_endpoint_update: WebhookEndpointUpdate) -> Self {
+        match webhook_endpoint_update {
+            WebhookEndpointUpdate::Update {
+                url,
+                description,
+                enabled_events,
+                is_active,
+            } => Self {
+                url,
+                description,
+                signing_secret: None,
+                enabled_events,
+                is_active,
+                modified_at: common_utils::date_time::now(),
+            },
+            WebhookEndpointUpdate::SigningSecretUpdate { signing_secret } => Self {
+                url: None,
+                description: None,
+                signing_secret: Some(signing_secret),
+                enabled_events: None,
+                is_active: None,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+impl WebhookEndpointUpdateInternal {
+    pub fn apply_changeset(self, source: WebhookEndpoint) -> WebhookEndpoint {
+        let Self {
+            url,
+            description,
+            signing_secret,
+            enabled_events,
+            is_active,
+            modified_at,
+        } = self;
+
+        WebhookEndpoint {
+            url: url.unwrap_or(source.url),
+            description: description.or(source.description),
+            signing_secret: signing_secret.unwrap_or(source.signing_secret),
+            enabled_events: enabled_events.or(source.enabled_events),
+            is_active: is_active.unwrap_or(source.is_active),
+            modified_at,
+            ..source
+        }
+    }
+}
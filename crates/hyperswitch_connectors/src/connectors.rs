@@ -6,6 +6,7 @@ pub mod bitpay;
 pub mod cashtocode;
 pub mod coinbase;
 pub mod cryptopay;
+pub mod declarative;
 pub mod deutschebank;
 pub mod digitalvirgo;
 pub mod dlocal;
@@ -42,11 +43,11 @@ pub mod zsl;
 pub use self::{
     airwallex::Airwallex, amazonpay::Amazonpay, bambora::Bambora, billwerk::Billwerk,
     bitpay::Bitpay, cashtocode::Cashtocode, coinbase::Coinbase, cryptopay::Cryptopay,
-    deutschebank::Deutschebank, digitalvirgo::Digitalvirgo, dlocal::Dlocal, elavon::Elavon,
-    fiserv::Fiserv, fiservemea::Fiservemea, fiuu::Fiuu, forte::Forte, globepay::Globepay,
-    helcim::Helcim, jpmorgan::Jpmorgan, mollie::Mollie, multisafepay::Multisafepay,
-    nexinets::Nexinets, nexixpay::Nexixpay, nomupay::Nomupay, novalnet::Novalnet, payeezy::Payeezy,
-    payu::Payu, powertranz::Powertranz, razorpay::Razorpay, shift4::Shift4, square::Square,
-    stax::Stax, taxjar::Taxjar, thunes::Thunes, tsys::Tsys, volt::Volt, worldline::Worldline,
-    worldpay::Worldpay, zen::Zen, zsl::Zsl,
+    declarative::Declarative, deutschebank::Deutschebank, digitalvirgo::Digitalvirgo,
+    dlocal::Dlocal, elavon::Elavon, fiserv::Fiserv, fiservemea::Fiservemea, fiuu::Fiuu,
+    forte::Forte, globepay::Globepay, helcim::Helcim, jpmorgan::Jpmorgan, mollie::Mollie,
+    multisafepay::Multisafepay, nexinets::Nexinets, nexixpay::Nexixpay, nomupay::Nomupay,
+    novalnet::Novalnet, payeezy::Payeezy, payu::Payu, powertranz::Powertranz, razorpay::Razorpay,
+    shift4::Shift4, square::Square, stax::Stax, taxjar::Taxjar, thunes::Thunes, tsys::Tsys,
+    volt::Volt, worldline::Worldline, worldpay::Worldpay, zen::Zen, zsl::Zsl,
 };
@@ -994,6 +994,22 @@ impl IncomingWebhook for Airwallex {
         Ok(format!("{}{}", timestamp, String::from_utf8_lossy(request.body)).into_bytes())
     }
 
+    fn get_webhook_timestamp(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<i64>, errors::ConnectorError> {
+        // Airwallex sends the timestamp in milliseconds
+        let timestamp_in_millis: i64 = request
+            .headers
+            .get("x-timestamp")
+            .and_then(|header_value| header_value.to_str().ok())
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)?
+            .parse()
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
+            .attach_printable("Could not parse the webhook timestamp")?;
+        Ok(Some(timestamp_in_millis / 1000))
+    }
+
     fn get_webhook_object_reference_id(
         &self,
         request: &IncomingWebhookRequestDetails<'_>,
@@ -381,7 +381,10 @@ impl webhooks::IncomingWebhook for Cashtocode {
         let signature_auth = String::from_utf8(signature.to_vec())
             .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
             .attach_printable("Could not convert secret to UTF-8")?;
-        Ok(signature_auth == secret_auth)
+        Ok(common_utils::crypto::constant_time_eq(
+            signature_auth.as_bytes(),
+            secret_auth.as_bytes(),
+        ))
     }
 
     fn get_webhook_object_reference_id(
@@ -0,0 +1,915 @@
+pub mod transformers;
+
+use std::collections::BTreeMap;
+
+use common_enums::enums;
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::BytesExt,
+    request::{Request, RequestBuilder, RequestContent},
+    types::{AmountConvertor, StringMajorUnit, StringMajorUnitForConnector},
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::{
+    router_data::{AccessToken, ConnectorAuthType, ErrorResponse, RouterData},
+    router_flow_types::{
+        access_token_auth::AccessTokenAuth,
+        payments::{Authorize, Capture, PSync, PaymentMethodToken, Session, SetupMandate, Void},
+        refunds::{Execute, RSync},
+    },
+    router_request_types::{
+        AccessTokenRequestData, PaymentMethodTokenizationData, PaymentsAuthorizeData,
+        PaymentsCancelData, PaymentsCaptureData, PaymentsSessionData, PaymentsSyncData,
+        RefundsData, SetupMandateRequestData,
+    },
+    router_response_types::{PaymentsResponseData, RefundsResponseData},
+    types::{
+        PaymentsAuthorizeRouterData, PaymentsCancelRouterData, PaymentsCaptureRouterData,
+        PaymentsSyncRouterData, RefundSyncRouterData, RefundsRouterData,
+    },
+};
+use hyperswitch_interfaces::{
+    api::{self, ConnectorCommon, ConnectorCommonExt, ConnectorIntegration, ConnectorValidation},
+    configs::{
+        Connectors, DeclarativeConnectorSpec, DeclarativeConnectors, DeclarativeErrorMapping,
+        DeclarativeFlowSpec,
+    },
+    consts::{NO_ERROR_CODE, NO_ERROR_MESSAGE},
+    errors,
+    events::connector_api_logs::ConnectorEvent,
+    types::{self, Response},
+    webhooks,
+};
+use masking::Mask;
+use once_cell::sync::OnceCell;
+use transformers as declarative;
+
+use crate::{
+    constants::headers,
+    utils::{self, PaymentsAuthorizeRequestData},
+};
+
+/// The specifications of the declarative connectors of the settings, by identifier. The
+/// specifications are registered once at startup, as the responses of the connectors are mapped
+/// without access to the settings.
+static DECLARATIVE_CONNECTOR_SPECS: OnceCell<BTreeMap<String, DeclarativeConnectorSpec>> =
+    OnceCell::new();
+
+/// Connector whose requests and responses are mapped from a declarative specification, serving
+/// connectors with simple REST APIs without transformer code of their own. The specification is
+/// selected per connector account, by the identifier in the `declarative_connector` field of the
+/// metadata of the account.
+#[derive(Clone)]
+pub struct Declarative {
+    amount_converter: &'static (dyn AmountConvertor<Output = StringMajorUnit> + Sync),
+}
+
+impl Declarative {
+    pub fn new() -> &'static Self {
+        &Self {
+            amount_converter: &StringMajorUnitForConnector,
+        }
+    }
+
+    /// Registers the declarative connectors of the settings. Specifications registered earlier
+    /// in the process are kept.
+    pub fn register(connectors: &DeclarativeConnectors) {
+        let specs = connectors
+            .iter()
+            .map(|(declarative_connector, spec)| (declarative_connector.clone(), spec.clone()))
+            .collect::<BTreeMap<_, _>>();
+
+        let declarative_connectors = specs.keys().cloned().collect::<Vec<_>>();
+        if DECLARATIVE_CONNECTOR_SPECS.set(specs).is_ok() {
+            router_env::logger::info!(
+                ?declarative_connectors,
+                "Registered the declarative connectors"
+            );
+        }
+    }
+
+    /// The specification of the declarative connector with the given identifier, failing for
+    /// identifiers which are not configured
+    pub fn get_spec(
+        declarative_connector: &str,
+    ) -> CustomResult<&'static DeclarativeConnectorSpec, errors::ConnectorError> {
+        DECLARATIVE_CONNECTOR_SPECS
+            .get()
+            .and_then(|specs| specs.get(declarative_connector))
+            .ok_or_else(|| {
+                report!(errors::ConnectorError::InvalidConnectorConfig {
+                    config: "metadata.declarative_connector",
+                })
+            })
+            .attach_printable_lazy(|| {
+                format!("No declarative connector {declarative_connector} is configured")
+            })
+    }
+
+    /// The specification of the declarative connector the connector account is served by
+    fn get_account_spec<Flow, Request, Response>(
+        req: &RouterData<Flow, Request, Response>,
+    ) -> CustomResult<&'static DeclarativeConnectorSpec, errors::ConnectorError> {
+        let metadata =
+            declarative::DeclarativeConnectorMetadata::try_from(&req.connector_meta_data)?;
+        Self::get_spec(&metadata.declarative_connector)
+    }
+
+    fn get_flow_spec<'a, S>(
+        &self,
+        flow_spec: Option<&'a DeclarativeFlowSpec<S>>,
+        flow: &str,
+    ) -> CustomResult<&'a DeclarativeFlowSpec<S>, errors::ConnectorError> {
+        flow_spec.ok_or_else(|| {
+            report!(errors::ConnectorError::FlowNotSupported {
+                flow: flow.to_string(),
+                connector: self.id().to_string(),
+            })
+        })
+    }
+
+    fn get_flow_url<S>(
+        spec: &DeclarativeConnectorSpec,
+        flow_spec: &DeclarativeFlowSpec<S>,
+        variables: &declarative::DeclarativeVariables,
+    ) -> String {
+        format!(
+            "{}{}",
+            spec.base_url,
+            declarative::render_string(&flow_spec.path, variables)
+        )
+    }
+
+    fn get_flow_request_body<S>(
+        &self,
+        flow_spec: &DeclarativeFlowSpec<S>,
+        variables: &declarative::DeclarativeVariables,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let template = flow_spec.request_template.as_ref().ok_or_else(|| {
+            report!(errors::ConnectorError::RequestEncodingFailedWithReason(
+                "The flow has no request template".to_string()
+            ))
+        })?;
+        Ok(RequestContent::Json(Box::new(
+            declarative::get_request_body(template, variables),
+        )))
+    }
+
+    fn build_flow_request<S>(
+        &self,
+        flow_spec: &DeclarativeFlowSpec<S>,
+        url: String,
+        headers: Vec<(String, masking::Maskable<String>)>,
+        body: Option<RequestContent>,
+    ) -> Request {
+        let request_builder = RequestBuilder::new()
+            .method(flow_spec.method)
+            .url(&url)
+            .attach_default_headers()
+            .headers(headers);
+        match body {
+            Some(body) => request_builder.set_body(body),
+            None => request_builder,
+        }
+        .build()
+    }
+
+    fn parse_response<S>(
+        &self,
+        flow_spec: &DeclarativeFlowSpec<S>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: &Response,
+    ) -> CustomResult<(serde_json::Value, declarative::DeclarativeResponse), errors::ConnectorError>
+    {
+        let response: serde_json::Value = res
+            .response
+            .parse_struct("Declarative Response")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        let mapped_response =
+            declarative::DeclarativeResponse::try_from_value(flow_spec, &response)?;
+        event_builder.map(|i| i.set_response_body(&mapped_response));
+        router_env::logger::info!(connector_response=?mapped_response);
+        Ok((response, mapped_response))
+    }
+
+    fn get_authorize_variables(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+    ) -> CustomResult<declarative::DeclarativeVariables, errors::ConnectorError> {
+        let amount = utils::convert_amount(
+            self.amount_converter,
+            req.request.minor_amount,
+            req.request.currency,
+        )?;
+        declarative::get_authorize_variables(req, amount)
+    }
+
+    fn get_capture_variables(
+        &self,
+        req: &PaymentsCaptureRouterData,
+    ) -> CustomResult<declarative::DeclarativeVariables, errors::ConnectorError> {
+        let amount = utils::convert_amount(
+            self.amount_converter,
+            req.request.minor_amount_to_capture,
+            req.request.currency,
+        )?;
+        declarative::get_capture_variables(req, amount)
+    }
+
+    fn get_refund_variables<F>(
+        &self,
+        req: &RefundsRouterData<F>,
+    ) -> CustomResult<declarative::DeclarativeVariables, errors::ConnectorError> {
+        let amount = utils::convert_amount(
+            self.amount_converter,
+            req.request.minor_refund_amount,
+            req.request.currency,
+        )?;
+        declarative::get_refund_variables(req, amount)
+    }
+}
+
+impl api::Payment for Declarative {}
+impl api::PaymentSession for Declarative {}
+impl api::ConnectorAccessToken for Declarative {}
+impl api::MandateSetup for Declarative {}
+impl api::PaymentAuthorize for Declarative {}
+impl api::PaymentSync for Declarative {}
+impl api::PaymentCapture for Declarative {}
+impl api::PaymentVoid for Declarative {}
+impl api::Refund for Declarative {}
+impl api::RefundExecute for Declarative {}
+impl api::RefundSync for Declarative {}
+impl api::PaymentToken for Declarative {}
+
+impl ConnectorIntegration<PaymentMethodToken, PaymentMethodTokenizationData, PaymentsResponseData>
+    for Declarative
+{
+    // Not Implemented (R)
+}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Declarative
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &RouterData<Flow, Request, Response>,
+        _connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let variables = declarative::get_auth_variables(&req.connector_auth_type)?;
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            self.get_content_type().to_string().into(),
+        )];
+        header.extend(spec.headers.iter().map(|(name, template)| {
+            (
+                name.clone(),
+                declarative::render_string(template, &variables).into_masked(),
+            )
+        }));
+        Ok(header)
+    }
+}
+
+impl ConnectorCommon for Declarative {
+    fn id(&self) -> &'static str {
+        "declarative"
+    }
+
+    fn get_currency_unit(&self) -> api::CurrencyUnit {
+        api::CurrencyUnit::Minor
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, _connectors: &'a Connectors) -> &'a str {
+        // The base url is that of the specification of the connector account
+        ""
+    }
+
+    fn get_auth_header(
+        &self,
+        _auth_type: &ConnectorAuthType,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        // The headers are rendered from the specification of the connector account
+        Ok(Vec::new())
+    }
+
+    fn build_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        let response: serde_json::Value = match res
+            .response
+            .parse_struct("Declarative ErrorResponse")
+        {
+            Ok(response) => response,
+            Err(_) => return utils::handle_json_response_deserialization_failure(res, self.id()),
+        };
+        // Error responses are mapped without the connector account, the error mappings of the
+        // declarative connectors are tried in turn until one of them finds an error code or message
+        let error_response = DECLARATIVE_CONNECTOR_SPECS
+            .get()
+            .into_iter()
+            .flat_map(BTreeMap::values)
+            .map(|spec| {
+                declarative::get_error_response(&spec.error, &response, res.status_code, None, None)
+            })
+            .find(|error_response| {
+                error_response.code != NO_ERROR_CODE || error_response.message != NO_ERROR_MESSAGE
+            })
+            .unwrap_or_else(|| {
+                declarative::get_error_response(
+                    &DeclarativeErrorMapping::default(),
+                    &response,
+                    res.status_code,
+                    None,
+                    None,
+                )
+            });
+
+        event_builder.map(|i| i.set_response_body(&error_response));
+        router_env::logger::info!(connector_response=?error_response);
+
+        Ok(error_response)
+    }
+}
+
+impl ConnectorValidation for Declarative {
+    fn validate_capture_method(
+        &self,
+        capture_method: Option<enums::CaptureMethod>,
+        _pmt: Option<enums::PaymentMethodType>,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        let capture_method = capture_method.unwrap_or_default();
+        match capture_method {
+            // Manual captures are validated against the specification of the connector account
+            // when the payment is authorized
+            enums::CaptureMethod::Automatic | enums::CaptureMethod::Manual => Ok(()),
+            enums::CaptureMethod::ManualMultiple | enums::CaptureMethod::Scheduled => Err(
+                utils::construct_not_supported_error_report(capture_method, self.id()),
+            ),
+        }
+    }
+}
+
+impl ConnectorIntegration<Session, PaymentsSessionData, PaymentsResponseData> for Declarative {}
+
+impl ConnectorIntegration<AccessTokenAuth, AccessTokenRequestData, AccessToken> for Declarative {}
+
+impl ConnectorIntegration<SetupMandate, SetupMandateRequestData, PaymentsResponseData>
+    for Declarative
+{
+}
+
+impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData> for Declarative {
+    fn get_headers(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.authorize.as_ref(), "authorize")?;
+        Ok(Self::get_flow_url(
+            spec,
+            flow_spec,
+            &self.get_authorize_variables(req)?,
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.authorize.as_ref(), "authorize")?;
+        self.get_flow_request_body(flow_spec, &self.get_authorize_variables(req)?)
+    }
+
+    fn build_request(
+        &self,
+        req: &PaymentsAuthorizeRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.authorize.as_ref(), "authorize")?;
+        if !req.request.is_auto_capture()? && spec.capture.is_none() {
+            return Err(utils::construct_not_supported_error_report(
+                enums::CaptureMethod::Manual,
+                self.id(),
+            ));
+        }
+        let body = flow_spec
+            .request_template
+            .as_ref()
+            .map(|_| types::PaymentsAuthorizeType::get_request_body(self, req, connectors))
+            .transpose()?;
+        Ok(Some(self.build_flow_request(
+            flow_spec,
+            types::PaymentsAuthorizeType::get_url(self, req, connectors)?,
+            types::PaymentsAuthorizeType::get_headers(self, req, connectors)?,
+            body,
+        )))
+    }
+
+    fn handle_response(
+        &self,
+        data: &PaymentsAuthorizeRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsAuthorizeRouterData, errors::ConnectorError> {
+        let spec = Self::get_account_spec(data)?;
+        let flow_spec = self.get_flow_spec(spec.authorize.as_ref(), "authorize")?;
+        let (response, mapped_response) = self.parse_response(flow_spec, event_builder, &res)?;
+        declarative::get_payments_router_data(
+            flow_spec,
+            &spec.error,
+            &response,
+            mapped_response,
+            data,
+            None,
+            res.status_code,
+        )
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData> for Declarative {
+    fn get_headers(
+        &self,
+        req: &PaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsSyncRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.psync.as_ref(), "psync")?;
+        Ok(Self::get_flow_url(
+            spec,
+            flow_spec,
+            &declarative::get_psync_variables(req)?,
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsSyncRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.psync.as_ref(), "psync")?;
+        self.get_flow_request_body(flow_spec, &declarative::get_psync_variables(req)?)
+    }
+
+    fn build_request(
+        &self,
+        req: &PaymentsSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.psync.as_ref(), "psync")?;
+        let body = flow_spec
+            .request_template
+            .as_ref()
+            .map(|_| types::PaymentsSyncType::get_request_body(self, req, connectors))
+            .transpose()?;
+        Ok(Some(self.build_flow_request(
+            flow_spec,
+            types::PaymentsSyncType::get_url(self, req, connectors)?,
+            types::PaymentsSyncType::get_headers(self, req, connectors)?,
+            body,
+        )))
+    }
+
+    fn handle_response(
+        &self,
+        data: &PaymentsSyncRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsSyncRouterData, errors::ConnectorError> {
+        let spec = Self::get_account_spec(data)?;
+        let flow_spec = self.get_flow_spec(spec.psync.as_ref(), "psync")?;
+        let (response, mapped_response) = self.parse_response(flow_spec, event_builder, &res)?;
+        declarative::get_payments_router_data(
+            flow_spec,
+            &spec.error,
+            &response,
+            mapped_response,
+            data,
+            data.request
+                .connector_transaction_id
+                .get_connector_transaction_id()
+                .ok(),
+            res.status_code,
+        )
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<Capture, PaymentsCaptureData, PaymentsResponseData> for Declarative {
+    fn get_headers(
+        &self,
+        req: &PaymentsCaptureRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsCaptureRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.capture.as_ref(), "capture")?;
+        Ok(Self::get_flow_url(
+            spec,
+            flow_spec,
+            &self.get_capture_variables(req)?,
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsCaptureRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.capture.as_ref(), "capture")?;
+        self.get_flow_request_body(flow_spec, &self.get_capture_variables(req)?)
+    }
+
+    fn build_request(
+        &self,
+        req: &PaymentsCaptureRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.capture.as_ref(), "capture")?;
+        let body = flow_spec
+            .request_template
+            .as_ref()
+            .map(|_| types::PaymentsCaptureType::get_request_body(self, req, connectors))
+            .transpose()?;
+        Ok(Some(self.build_flow_request(
+            flow_spec,
+            types::PaymentsCaptureType::get_url(self, req, connectors)?,
+            types::PaymentsCaptureType::get_headers(self, req, connectors)?,
+            body,
+        )))
+    }
+
+    fn handle_response(
+        &self,
+        data: &PaymentsCaptureRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsCaptureRouterData, errors::ConnectorError> {
+        let spec = Self::get_account_spec(data)?;
+        let flow_spec = self.get_flow_spec(spec.capture.as_ref(), "capture")?;
+        let (response, mapped_response) = self.parse_response(flow_spec, event_builder, &res)?;
+        declarative::get_payments_router_data(
+            flow_spec,
+            &spec.error,
+            &response,
+            mapped_response,
+            data,
+            Some(data.request.connector_transaction_id.clone()),
+            res.status_code,
+        )
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<Void, PaymentsCancelData, PaymentsResponseData> for Declarative {
+    fn get_headers(
+        &self,
+        req: &PaymentsCancelRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &PaymentsCancelRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.void.as_ref(), "void")?;
+        Ok(Self::get_flow_url(
+            spec,
+            flow_spec,
+            &declarative::get_void_variables(req)?,
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &PaymentsCancelRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.void.as_ref(), "void")?;
+        self.get_flow_request_body(flow_spec, &declarative::get_void_variables(req)?)
+    }
+
+    fn build_request(
+        &self,
+        req: &PaymentsCancelRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.void.as_ref(), "void")?;
+        let body = flow_spec
+            .request_template
+            .as_ref()
+            .map(|_| types::PaymentsVoidType::get_request_body(self, req, connectors))
+            .transpose()?;
+        Ok(Some(self.build_flow_request(
+            flow_spec,
+            types::PaymentsVoidType::get_url(self, req, connectors)?,
+            types::PaymentsVoidType::get_headers(self, req, connectors)?,
+            body,
+        )))
+    }
+
+    fn handle_response(
+        &self,
+        data: &PaymentsCancelRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<PaymentsCancelRouterData, errors::ConnectorError> {
+        let spec = Self::get_account_spec(data)?;
+        let flow_spec = self.get_flow_spec(spec.void.as_ref(), "void")?;
+        let (response, mapped_response) = self.parse_response(flow_spec, event_builder, &res)?;
+        declarative::get_payments_router_data(
+            flow_spec,
+            &spec.error,
+            &response,
+            mapped_response,
+            data,
+            Some(data.request.connector_transaction_id.clone()),
+            res.status_code,
+        )
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<Execute, RefundsData, RefundsResponseData> for Declarative {
+    fn get_headers(
+        &self,
+        req: &RefundsRouterData<Execute>,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &RefundsRouterData<Execute>,
+        _connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.refund.as_ref(), "refund")?;
+        Ok(Self::get_flow_url(
+            spec,
+            flow_spec,
+            &self.get_refund_variables(req)?,
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &RefundsRouterData<Execute>,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.refund.as_ref(), "refund")?;
+        self.get_flow_request_body(flow_spec, &self.get_refund_variables(req)?)
+    }
+
+    fn build_request(
+        &self,
+        req: &RefundsRouterData<Execute>,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.refund.as_ref(), "refund")?;
+        let body = flow_spec
+            .request_template
+            .as_ref()
+            .map(|_| types::RefundExecuteType::get_request_body(self, req, connectors))
+            .transpose()?;
+        Ok(Some(self.build_flow_request(
+            flow_spec,
+            types::RefundExecuteType::get_url(self, req, connectors)?,
+            types::RefundExecuteType::get_headers(self, req, connectors)?,
+            body,
+        )))
+    }
+
+    fn handle_response(
+        &self,
+        data: &RefundsRouterData<Execute>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<RefundsRouterData<Execute>, errors::ConnectorError> {
+        let spec = Self::get_account_spec(data)?;
+        let flow_spec = self.get_flow_spec(spec.refund.as_ref(), "refund")?;
+        let (response, mapped_response) = self.parse_response(flow_spec, event_builder, &res)?;
+        declarative::get_refunds_router_data(
+            flow_spec,
+            &spec.error,
+            &response,
+            mapped_response,
+            data,
+            res.status_code,
+        )
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl ConnectorIntegration<RSync, RefundsData, RefundsResponseData> for Declarative {
+    fn get_headers(
+        &self,
+        req: &RefundSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &RefundSyncRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.refund_sync.as_ref(), "refund_sync")?;
+        Ok(Self::get_flow_url(
+            spec,
+            flow_spec,
+            &self.get_refund_variables(req)?,
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &RefundSyncRouterData,
+        _connectors: &Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.refund_sync.as_ref(), "refund_sync")?;
+        self.get_flow_request_body(flow_spec, &self.get_refund_variables(req)?)
+    }
+
+    fn build_request(
+        &self,
+        req: &RefundSyncRouterData,
+        connectors: &Connectors,
+    ) -> CustomResult<Option<Request>, errors::ConnectorError> {
+        let spec = Self::get_account_spec(req)?;
+        let flow_spec = self.get_flow_spec(spec.refund_sync.as_ref(), "refund_sync")?;
+        let body = flow_spec
+            .request_template
+            .as_ref()
+            .map(|_| types::RefundSyncType::get_request_body(self, req, connectors))
+            .transpose()?;
+        Ok(Some(self.build_flow_request(
+            flow_spec,
+            types::RefundSyncType::get_url(self, req, connectors)?,
+            types::RefundSyncType::get_headers(self, req, connectors)?,
+            body,
+        )))
+    }
+
+    fn handle_response(
+        &self,
+        data: &RefundSyncRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: Response,
+    ) -> CustomResult<RefundSyncRouterData, errors::ConnectorError> {
+        let spec = Self::get_account_spec(data)?;
+        let flow_spec = self.get_flow_spec(spec.refund_sync.as_ref(), "refund_sync")?;
+        let (response, mapped_response) = self.parse_response(flow_spec, event_builder, &res)?;
+        declarative::get_refunds_router_data(
+            flow_spec,
+            &spec.error,
+            &response,
+            mapped_response,
+            data,
+            res.status_code,
+        )
+    }
+
+    fn get_error_response(
+        &self,
+        res: Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+#[async_trait::async_trait]
+impl webhooks::IncomingWebhook for Declarative {
+    fn get_webhook_object_reference_id(
+        &self,
+        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
+        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+    }
+
+    fn get_webhook_event_type(
+        &self,
+        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<api_models::webhooks::IncomingWebhookEvent, errors::ConnectorError> {
+        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+    }
+
+    fn get_webhook_resource_object(
+        &self,
+        _request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
+        Err(report!(errors::ConnectorError::WebhooksNotImplemented))
+    }
+}
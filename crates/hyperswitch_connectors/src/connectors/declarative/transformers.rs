@@ -0,0 +1,488 @@
+use std::collections::HashMap;
+
+use common_enums::{enums, AttemptStatus};
+use common_utils::{pii, request::Method, types::StringMajorUnit};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::{
+    payment_method_data::PaymentMethodData,
+    router_data::{ConnectorAuthType, ErrorResponse, RouterData},
+    router_request_types::ResponseId,
+    router_response_types::{PaymentsResponseData, RedirectForm, RefundsResponseData},
+    types::{
+        PaymentsAuthorizeRouterData, PaymentsCancelRouterData, PaymentsCaptureRouterData,
+        PaymentsSyncRouterData, RefundsRouterData,
+    },
+};
+use hyperswitch_interfaces::{
+    configs::{DeclarativeErrorMapping, DeclarativeFlowSpec},
+    consts::{NO_ERROR_CODE, NO_ERROR_MESSAGE},
+    errors,
+};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::utils::{self, CardData, PaymentsAuthorizeRequestData};
+
+type Error = error_stack::Report<errors::ConnectorError>;
+
+/// Metadata of a connector account of the declarative connector
+#[derive(Debug, Deserialize)]
+pub struct DeclarativeConnectorMetadata {
+    /// Identifier of the declarative connector of the settings serving the account
+    pub declarative_connector: String,
+}
+
+impl TryFrom<&Option<pii::SecretSerdeValue>> for DeclarativeConnectorMetadata {
+    type Error = Error;
+    fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
+        utils::to_connector_meta_from_secret::<Self>(meta_data.clone()).change_context(
+            errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata.declarative_connector",
+            },
+        )
+    }
+}
+
+/// Values the templates of a flow can refer to, by placeholder name
+pub type DeclarativeVariables = HashMap<&'static str, Value>;
+
+fn insert_string(variables: &mut DeclarativeVariables, name: &'static str, value: String) {
+    variables.insert(name, Value::String(value));
+}
+
+fn insert_amount(
+    variables: &mut DeclarativeVariables,
+    amount: common_utils::types::MinorUnit,
+    amount_decimal: StringMajorUnit,
+    currency: enums::Currency,
+) {
+    variables.insert("amount", Value::from(amount.get_amount_as_i64()));
+    insert_string(
+        variables,
+        "amount_decimal",
+        amount_decimal.get_amount_as_string(),
+    );
+    insert_string(variables, "currency", currency.to_string());
+}
+
+/// The credentials of the connector account, as variables of the templates
+pub fn get_auth_variables(auth_type: &ConnectorAuthType) -> Result<DeclarativeVariables, Error> {
+    let credentials = match auth_type {
+        ConnectorAuthType::HeaderKey { api_key } => vec![("api_key", api_key)],
+        ConnectorAuthType::BodyKey { api_key, key1 } => {
+            vec![("api_key", api_key), ("key1", key1)]
+        }
+        ConnectorAuthType::SignatureKey {
+            api_key,
+            key1,
+            api_secret,
+        } => vec![
+            ("api_key", api_key),
+            ("key1", key1),
+            ("api_secret", api_secret),
+        ],
+        ConnectorAuthType::MultiAuthKey {
+            api_key,
+            key1,
+            api_secret,
+            key2,
+        } => vec![
+            ("api_key", api_key),
+            ("key1", key1),
+            ("api_secret", api_secret),
+            ("key2", key2),
+        ],
+        _ => return Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+    };
+
+    Ok(credentials
+        .into_iter()
+        .map(|(name, value)| (name, Value::String(value.peek().clone())))
+        .collect())
+}
+
+pub fn get_authorize_variables(
+    item: &PaymentsAuthorizeRouterData,
+    amount_decimal: StringMajorUnit,
+) -> Result<DeclarativeVariables, Error> {
+    let mut variables = get_auth_variables(&item.connector_auth_type)?;
+    insert_string(
+        &mut variables,
+        "reference",
+        item.connector_request_reference_id.clone(),
+    );
+    insert_amount(
+        &mut variables,
+        item.request.minor_amount,
+        amount_decimal,
+        item.request.currency,
+    );
+    variables.insert("auto_capture", Value::Bool(item.request.is_auto_capture()?));
+    if let Some(return_url) = item.request.router_return_url.clone() {
+        insert_string(&mut variables, "return_url", return_url);
+    }
+    if let Some(webhook_url) = item.request.webhook_url.clone() {
+        insert_string(&mut variables, "webhook_url", webhook_url);
+    }
+    if let Some(email) = item.request.email.clone() {
+        insert_string(&mut variables, "email", email.expose().expose());
+    }
+    if let Some(description) = item.description.clone() {
+        insert_string(&mut variables, "description", description);
+    }
+
+    match &item.request.payment_method_data {
+        PaymentMethodData::Card(card) => {
+            insert_string(
+                &mut variables,
+                "card_number",
+                card.card_number.get_card_no(),
+            );
+            insert_string(
+                &mut variables,
+                "card_exp_month",
+                card.card_exp_month.clone().expose(),
+            );
+            insert_string(
+                &mut variables,
+                "card_exp_year",
+                card.get_expiry_year_4_digit().expose(),
+            );
+            insert_string(&mut variables, "card_cvc", card.card_cvc.clone().expose());
+            if let Some(card_holder_name) = card.card_holder_name.clone() {
+                insert_string(
+                    &mut variables,
+                    "card_holder_name",
+                    card_holder_name.expose(),
+                );
+            }
+        }
+        _ => Err(errors::ConnectorError::NotImplemented(
+            utils::get_unimplemented_payment_method_error_message("declarative connectors"),
+        ))?,
+    }
+
+    Ok(variables)
+}
+
+pub fn get_psync_variables(item: &PaymentsSyncRouterData) -> Result<DeclarativeVariables, Error> {
+    let mut variables = get_auth_variables(&item.connector_auth_type)?;
+    let connector_transaction_id = item
+        .request
+        .connector_transaction_id
+        .get_connector_transaction_id()
+        .change_context(errors::ConnectorError::MissingConnectorTransactionID)?;
+    insert_string(
+        &mut variables,
+        "connector_transaction_id",
+        connector_transaction_id,
+    );
+    insert_string(
+        &mut variables,
+        "reference",
+        item.connector_request_reference_id.clone(),
+    );
+    Ok(variables)
+}
+
+pub fn get_capture_variables(
+    item: &PaymentsCaptureRouterData,
+    amount_decimal: StringMajorUnit,
+) -> Result<DeclarativeVariables, Error> {
+    let mut variables = get_auth_variables(&item.connector_auth_type)?;
+    insert_string(
+        &mut variables,
+        "connector_transaction_id",
+        item.request.connector_transaction_id.clone(),
+    );
+    insert_amount(
+        &mut variables,
+        item.request.minor_amount_to_capture,
+        amount_decimal,
+        item.request.currency,
+    );
+    Ok(variables)
+}
+
+pub fn get_void_variables(item: &PaymentsCancelRouterData) -> Result<DeclarativeVariables, Error> {
+    let mut variables = get_auth_variables(&item.connector_auth_type)?;
+    insert_string(
+        &mut variables,
+        "connector_transaction_id",
+        item.request.connector_transaction_id.clone(),
+    );
+    if let Some(cancellation_reason) = item.request.cancellation_reason.clone() {
+        insert_string(&mut variables, "reason", cancellation_reason);
+    }
+    Ok(variables)
+}
+
+pub fn get_refund_variables<F>(
+    item: &RefundsRouterData<F>,
+    amount_decimal: StringMajorUnit,
+) -> Result<DeclarativeVariables, Error> {
+    let mut variables = get_auth_variables(&item.connector_auth_type)?;
+    insert_string(&mut variables, "refund_id", item.request.refund_id.clone());
+    insert_string(
+        &mut variables,
+        "connector_transaction_id",
+        item.request.connector_transaction_id.clone(),
+    );
+    if let Some(connector_refund_id) = item.request.connector_refund_id.clone() {
+        insert_string(&mut variables, "connector_refund_id", connector_refund_id);
+    }
+    insert_amount(
+        &mut variables,
+        item.request.minor_refund_amount,
+        amount_decimal,
+        item.request.currency,
+    );
+    if let Some(reason) = item.request.reason.clone() {
+        insert_string(&mut variables, "reason", reason);
+    }
+    Ok(variables)
+}
+
+/// The name of the variable a string consisting of a single placeholder refers to
+fn get_placeholder_name(template: &str) -> Option<&str> {
+    let name = template.strip_prefix("{{")?.strip_suffix("}}")?.trim();
+    (!name.contains("{{") && !name.contains("}}")).then_some(name)
+}
+
+fn value_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(value) => Some(value.to_string()),
+        Value::Bool(value) => Some(value.to_string()),
+        Value::Null | Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+/// Replaces the placeholders of the template with the values of the variables, placeholders of
+/// absent variables are replaced with empty strings
+pub fn render_string(template: &str, variables: &DeclarativeVariables) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut remaining = template;
+    while let Some((prefix, rest)) = remaining.split_once("{{") {
+        rendered.push_str(prefix);
+        let Some((name, rest)) = rest.split_once("}}") else {
+            rendered.push_str("{{");
+            remaining = rest;
+            continue;
+        };
+        if let Some(value) = variables.get(name.trim()).and_then(value_to_string) {
+            rendered.push_str(&value);
+        }
+        remaining = rest;
+    }
+    rendered.push_str(remaining);
+    rendered
+}
+
+/// Renders the JSON template of a request body. A string consisting of a single placeholder is
+/// replaced by the value of the variable, or null if the variable is absent.
+pub fn render_template(template: &Value, variables: &DeclarativeVariables) -> Value {
+    match template {
+        Value::String(template) => match get_placeholder_name(template) {
+            Some(name) => variables.get(name).cloned().unwrap_or(Value::Null),
+            None => Value::String(render_string(template, variables)),
+        },
+        Value::Array(items) => Value::Array(
+            items
+                .iter()
+                .map(|item| render_template(item, variables))
+                .collect(),
+        ),
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, field)| (key.clone(), render_template(field, variables)))
+                .collect(),
+        ),
+        Value::Null | Value::Bool(_) | Value::Number(_) => template.clone(),
+    }
+}
+
+/// Selects the value at a JSON path of the form `$.data.items[0].id`
+pub fn select_path<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let path = path.strip_prefix('$').unwrap_or(path);
+    path.split('.')
+        .filter(|segment| !segment.is_empty())
+        .try_fold(value, |value, segment| {
+            let mut parts = segment.split('[');
+            let key = parts.next().unwrap_or_default();
+            let value = if key.is_empty() {
+                value
+            } else {
+                value.get(key)?
+            };
+            parts.try_fold(value, |value, index| {
+                value.get(index.strip_suffix(']')?.parse::<usize>().ok()?)
+            })
+        })
+}
+
+fn select_string(value: &Value, path: Option<&String>) -> Option<String> {
+    path.and_then(|path| select_path(value, path))
+        .and_then(value_to_string)
+}
+
+/// Fields of a connector response, as mapped by the specification of the flow
+#[derive(Debug, Clone, Serialize)]
+pub struct DeclarativeResponse {
+    pub status: String,
+    pub id: Option<String>,
+    pub redirect_url: Option<String>,
+}
+
+impl DeclarativeResponse {
+    pub fn try_from_value<S>(
+        flow_spec: &DeclarativeFlowSpec<S>,
+        response: &Value,
+    ) -> Result<Self, Error> {
+        let status = select_path(response, &flow_spec.status_path)
+            .and_then(value_to_string)
+            .ok_or(errors::ConnectorError::ResponseHandlingFailed)
+            .attach_printable_lazy(|| {
+                format!("No status at {} in the response", flow_spec.status_path)
+            })?;
+
+        Ok(Self {
+            status,
+            id: select_string(response, flow_spec.id_path.as_ref()),
+            redirect_url: select_string(response, flow_spec.redirect_url_path.as_ref()),
+        })
+    }
+
+    fn get_status<S: Copy>(&self, flow_spec: &DeclarativeFlowSpec<S>) -> Result<S, Error> {
+        flow_spec
+            .status_map
+            .get(&self.status)
+            .copied()
+            .ok_or(errors::ConnectorError::ResponseHandlingFailed)
+            .attach_printable_lazy(|| format!("Status {} is not mapped", self.status))
+    }
+}
+
+/// The error reported in a response, through the error mapping of the specification
+pub fn get_error_response(
+    error_mapping: &DeclarativeErrorMapping,
+    response: &Value,
+    status_code: u16,
+    attempt_status: Option<AttemptStatus>,
+    connector_transaction_id: Option<String>,
+) -> ErrorResponse {
+    ErrorResponse {
+        status_code,
+        code: select_string(response, error_mapping.code_path.as_ref())
+            .unwrap_or(NO_ERROR_CODE.to_string()),
+        message: select_string(response, error_mapping.message_path.as_ref())
+            .unwrap_or(NO_ERROR_MESSAGE.to_string()),
+        reason: select_string(response, error_mapping.reason_path.as_ref()),
+        attempt_status,
+        connector_transaction_id,
+    }
+}
+
+/// Updates the payment with the mapped response. The identifier of the request is kept if the
+/// response carries none.
+pub fn get_payments_router_data<F, T>(
+    flow_spec: &DeclarativeFlowSpec<AttemptStatus>,
+    error_mapping: &DeclarativeErrorMapping,
+    response: &Value,
+    mapped_response: DeclarativeResponse,
+    data: &RouterData<F, T, PaymentsResponseData>,
+    connector_transaction_id: Option<String>,
+    http_code: u16,
+) -> Result<RouterData<F, T, PaymentsResponseData>, Error>
+where
+    F: Clone,
+    T: Clone,
+{
+    let status = mapped_response.get_status(flow_spec)?;
+    let connector_transaction_id = mapped_response
+        .id
+        .or(connector_transaction_id)
+        .ok_or(errors::ConnectorError::MissingConnectorTransactionID)?;
+
+    let response = if utils::is_payment_failure(status) {
+        Err(get_error_response(
+            error_mapping,
+            response,
+            http_code,
+            Some(status),
+            Some(connector_transaction_id),
+        ))
+    } else {
+        let redirection_data = mapped_response
+            .redirect_url
+            .map(|redirect_url| url::Url::parse(&redirect_url))
+            .transpose()
+            .change_context(errors::ConnectorError::ResponseHandlingFailed)
+            .attach_printable("Invalid redirect url in the response")?
+            .map(|redirect_url| RedirectForm::from((redirect_url, Method::Get)));
+        Ok(PaymentsResponseData::TransactionResponse {
+            resource_id: ResponseId::ConnectorTransactionId(connector_transaction_id.clone()),
+            redirection_data: Box::new(redirection_data),
+            mandate_reference: Box::new(None),
+            connector_metadata: None,
+            network_txn_id: None,
+            connector_response_reference_id: Some(connector_transaction_id),
+            incremental_authorization_allowed: None,
+            charge_id: None,
+        })
+    };
+
+    Ok(RouterData {
+        status,
+        response,
+        ..data.clone()
+    })
+}
+
+/// Updates the refund with the mapped response. The refund identifier of the request is kept if
+/// the response carries none.
+pub fn get_refunds_router_data<F>(
+    flow_spec: &DeclarativeFlowSpec<enums::RefundStatus>,
+    error_mapping: &DeclarativeErrorMapping,
+    response: &Value,
+    mapped_response: DeclarativeResponse,
+    data: &RefundsRouterData<F>,
+    http_code: u16,
+) -> Result<RefundsRouterData<F>, Error>
+where
+    F: Clone,
+{
+    let refund_status = mapped_response.get_status(flow_spec)?;
+    let connector_refund_id = mapped_response
+        .id
+        .or_else(|| data.request.connector_refund_id.clone())
+        .ok_or(errors::ConnectorError::MissingConnectorRefundID)?;
+
+    let response = if utils::is_refund_failure(refund_status) {
+        Err(get_error_response(
+            error_mapping,
+            response,
+            http_code,
+            None,
+            Some(connector_refund_id),
+        ))
+    } else {
+        Ok(RefundsResponseData {
+            connector_refund_id,
+            refund_status,
+        })
+    };
+
+    Ok(RouterData {
+        response,
+        ..data.clone()
+    })
+}
+
+/// The body of a request, masked as a whole in the logs since the template can place sensitive
+/// values anywhere
+pub fn get_request_body(template: &Value, variables: &DeclarativeVariables) -> Secret<Value> {
+    Secret::new(render_template(template, variables))
+}
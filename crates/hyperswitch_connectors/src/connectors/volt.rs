@@ -660,6 +660,17 @@ impl webhooks::IncomingWebhook for Volt {
         .into_bytes())
     }
 
+    fn get_webhook_timestamp(
+        &self,
+        request: &webhooks::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<i64>, errors::ConnectorError> {
+        utils::get_header_key_value(webhook_headers::X_VOLT_TIMED, request.headers)?
+            .parse()
+            .map(Some)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
+            .attach_printable("Could not parse the webhook timestamp")
+    }
+
     fn get_webhook_object_reference_id(
         &self,
         request: &webhooks::IncomingWebhookRequestDetails<'_>,
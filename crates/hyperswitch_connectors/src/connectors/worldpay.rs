@@ -1143,7 +1143,10 @@ impl IncomingWebhook for Worldpay {
         let signed_message = hmac::sign(&signing_key, &message);
         let computed_signature = hex::encode(signed_message.as_ref());
 
-        Ok(computed_signature.as_bytes() == hex::encode(signature).as_bytes())
+        Ok(crypto::constant_time_eq(
+            computed_signature.as_bytes(),
+            hex::encode(signature).as_bytes(),
+        ))
     }
 
     fn get_webhook_object_reference_id(
@@ -241,6 +241,12 @@ trait WorldpayPaymentsRequestData {
     fn get_settlement_info(&self, _amount: i64) -> Option<AutoSettlement> {
         None
     }
+    fn get_statement_descriptor(&self) -> Option<String> {
+        None
+    }
+    fn get_merchant_category_code(&self) -> Option<String> {
+        None
+    }
 }
 
 impl WorldpayPaymentsRequestData
@@ -372,6 +378,14 @@ impl WorldpayPaymentsRequestData
             _ => None,
         }
     }
+
+    fn get_statement_descriptor(&self) -> Option<String> {
+        self.request.statement_descriptor.clone()
+    }
+
+    fn get_merchant_category_code(&self) -> Option<String> {
+        self.request.merchant_category_code.clone()
+    }
 }
 
 // Dangling helper function to create ThreeDS request
@@ -500,7 +514,10 @@ impl<T: WorldpayPaymentsRequestData> TryFrom<(&WorldpayRouterData<&T>, &Secret<S
                     item.router_data.get_mandate_id(),
                 )?,
                 narrative: InstructionNarrative {
-                    line1: merchant_name.expose(),
+                    line1: item
+                        .router_data
+                        .get_statement_descriptor()
+                        .unwrap_or_else(|| merchant_name.expose()),
                 },
                 value: PaymentValue {
                     amount: item.amount,
@@ -513,6 +530,7 @@ impl<T: WorldpayPaymentsRequestData> TryFrom<(&WorldpayRouterData<&T>, &Secret<S
             },
             merchant: Merchant {
                 entity: entity_id.clone(),
+                mcc: item.router_data.get_merchant_category_code(),
                 ..Default::default()
             },
             transaction_reference: item.router_data.get_connector_request_reference_id(),
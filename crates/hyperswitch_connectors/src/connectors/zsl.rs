@@ -38,7 +38,7 @@ use hyperswitch_interfaces::{
     types::{self, Response},
     webhooks::{IncomingWebhook, IncomingWebhookRequestDetails},
 };
-use masking::{ExposeInterface, Secret};
+use masking::{ExposeInterface, PeekInterface, Secret};
 use transformers::{self as zsl, get_status};
 
 use crate::{
@@ -436,7 +436,10 @@ impl IncomingWebhook for Zsl {
             },
         )?;
 
-        Ok(signature.eq(&webhook_response.signature))
+        Ok(common_utils::crypto::constant_time_eq(
+            signature.peek().as_bytes(),
+            webhook_response.signature.peek().as_bytes(),
+        ))
     }
 
     fn get_webhook_api_response(
@@ -12,7 +12,7 @@ use hyperswitch_domain_models::{
     types,
 };
 use hyperswitch_interfaces::{api, consts::NO_ERROR_CODE, errors};
-use masking::{ExposeInterface, Secret};
+use masking::{ExposeInterface, PeekInterface, Secret};
 use ring::digest;
 use serde::{Deserialize, Serialize};
 
@@ -312,7 +312,10 @@ impl<F, T> TryFrom<ResponseRouterData<F, ZslPaymentsResponse, T, PaymentsRespons
                 },
             )?;
 
-            if calculated_signature.clone().eq(&item.response.signature) {
+            if common_utils::crypto::constant_time_eq(
+                calculated_signature.peek().as_bytes(),
+                item.response.signature.peek().as_bytes(),
+            ) {
                 let decoded_redirect_url_bytes: Vec<u8> = base64::engine::general_purpose::STANDARD
                     .decode(item.response.txn_url.clone())
                     .change_context(errors::ConnectorError::RequestEncodingFailed)?;
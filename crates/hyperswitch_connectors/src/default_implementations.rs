@@ -24,26 +24,30 @@ use hyperswitch_domain_models::{
 };
 use hyperswitch_domain_models::{
     router_flow_types::{
+        account_balance::AccountBalance,
+        account_update::AccountUpdate,
         dispute::{Accept, Defend, Evidence},
         files::{Retrieve, Upload},
         mandate_revoke::MandateRevoke,
         payments::{
-            Approve, AuthorizeSessionToken, CalculateTax, CompleteAuthorize,
+            Approve, AuthorizeSessionToken, Balance, CalculateTax, CompleteAuthorize,
             CreateConnectorCustomer, IncrementalAuthorization, PostProcessing, PostSessionTokens,
             PreProcessing, Reject, SdkSessionUpdate,
         },
         webhooks::VerifyWebhookSource,
     },
     router_request_types::{
-        AcceptDisputeRequestData, AuthorizeSessionTokenData, CompleteAuthorizeData,
-        ConnectorCustomerData, DefendDisputeRequestData, MandateRevokeRequestData,
+        AcceptDisputeRequestData, AccountBalanceRequestData, AccountUpdateRequestData,
+        AuthorizeSessionTokenData, CompleteAuthorizeData, ConnectorCustomerData,
+        DefendDisputeRequestData, GiftCardBalanceCheckRequestData, MandateRevokeRequestData,
         PaymentsApproveData, PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
         PaymentsPostSessionTokensData, PaymentsPreProcessingData, PaymentsRejectData,
         PaymentsTaxCalculationData, RetrieveFileRequestData, SdkPaymentsSessionUpdateData,
         SubmitEvidenceRequestData, UploadFileRequestData, VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
+        AcceptDisputeResponse, AccountBalanceResponseData, AccountUpdateResponseData,
+        DefendDisputeResponse, GiftCardBalanceCheckResponseData, MandateRevokeResponseData,
         PaymentsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
         TaxCalculationResponseData, UploadFileResponse, VerifyWebhookSourceResponseData,
     },
@@ -65,8 +69,8 @@ use hyperswitch_interfaces::{
         files::{FileUpload, RetrieveFile, UploadFile},
         payments::{
             ConnectorCustomer, PaymentApprove, PaymentAuthorizeSessionToken,
-            PaymentIncrementalAuthorization, PaymentPostSessionTokens, PaymentReject,
-            PaymentSessionUpdate, PaymentsCompleteAuthorize, PaymentsPostProcessing,
+            PaymentGiftCardBalanceCheck, PaymentIncrementalAuthorization, PaymentPostSessionTokens,
+            PaymentReject, PaymentSessionUpdate, PaymentsCompleteAuthorize, PaymentsPostProcessing,
             PaymentsPreProcessing, TaxCalculation,
         },
         ConnectorIntegration, ConnectorMandateRevoke, ConnectorRedirectResponse,
@@ -97,6 +101,7 @@ default_imp_for_authorize_session_token!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -153,6 +158,7 @@ default_imp_for_calculate_tax!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -239,6 +245,7 @@ default_imp_for_session_update!(
     connectors::Powertranz,
     connectors::Thunes,
     connectors::Tsys,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Volt
 );
@@ -294,6 +301,65 @@ default_imp_for_post_session_tokens!(
     connectors::Powertranz,
     connectors::Thunes,
     connectors::Tsys,
+    connectors::Declarative,
+    connectors::Deutschebank,
+    connectors::Volt,
+    connectors::Zen,
+    connectors::Zsl
+);
+
+macro_rules! default_imp_for_gift_card_balance_check {
+    ($($path:ident::$connector:ident),*) => {
+        $( impl PaymentGiftCardBalanceCheck for $path::$connector {}
+            impl
+            ConnectorIntegration<
+                Balance,
+                GiftCardBalanceCheckRequestData,
+                GiftCardBalanceCheckResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_gift_card_balance_check!(
+    connectors::Airwallex,
+    connectors::Amazonpay,
+    connectors::Bambora,
+    connectors::Bitpay,
+    connectors::Billwerk,
+    connectors::Cashtocode,
+    connectors::Coinbase,
+    connectors::Cryptopay,
+    connectors::Digitalvirgo,
+    connectors::Dlocal,
+    connectors::Elavon,
+    connectors::Square,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Forte,
+    connectors::Helcim,
+    connectors::Jpmorgan,
+    connectors::Razorpay,
+    connectors::Shift4,
+    connectors::Stax,
+    connectors::Taxjar,
+    connectors::Mollie,
+    connectors::Multisafepay,
+    connectors::Nomupay,
+    connectors::Novalnet,
+    connectors::Nexinets,
+    connectors::Nexixpay,
+    connectors::Payeezy,
+    connectors::Payu,
+    connectors::Fiuu,
+    connectors::Globepay,
+    connectors::Worldline,
+    connectors::Worldpay,
+    connectors::Powertranz,
+    connectors::Thunes,
+    connectors::Tsys,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Volt,
     connectors::Zen,
@@ -323,6 +389,7 @@ default_imp_for_complete_authorize!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Dlocal,
     connectors::Elavon,
     connectors::Fiserv,
@@ -374,6 +441,7 @@ default_imp_for_incremental_authorization!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -432,6 +500,7 @@ default_imp_for_create_customer!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -489,6 +558,7 @@ default_imp_for_connector_redirect_response!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Dlocal,
     connectors::Elavon,
@@ -541,6 +611,7 @@ default_imp_for_pre_processing_steps!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -597,6 +668,7 @@ default_imp_for_post_processing_steps!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -655,6 +727,7 @@ default_imp_for_approve!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -713,6 +786,7 @@ default_imp_for_reject!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -771,6 +845,125 @@ default_imp_for_webhook_source_verification!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
+    connectors::Deutschebank,
+    connectors::Digitalvirgo,
+    connectors::Dlocal,
+    connectors::Elavon,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Fiuu,
+    connectors::Forte,
+    connectors::Globepay,
+    connectors::Helcim,
+    connectors::Jpmorgan,
+    connectors::Nomupay,
+    connectors::Novalnet,
+    connectors::Nexinets,
+    connectors::Nexixpay,
+    connectors::Payeezy,
+    connectors::Payu,
+    connectors::Powertranz,
+    connectors::Mollie,
+    connectors::Multisafepay,
+    connectors::Razorpay,
+    connectors::Shift4,
+    connectors::Stax,
+    connectors::Square,
+    connectors::Taxjar,
+    connectors::Thunes,
+    connectors::Tsys,
+    connectors::Worldline,
+    connectors::Worldpay,
+    connectors::Volt,
+    connectors::Zen,
+    connectors::Zsl
+);
+
+macro_rules! default_imp_for_account_balance {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::ConnectorAccountBalance for $path::$connector {}
+            impl
+            ConnectorIntegration<
+            AccountBalance,
+            AccountBalanceRequestData,
+            AccountBalanceResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_account_balance!(
+    connectors::Airwallex,
+    connectors::Amazonpay,
+    connectors::Bambora,
+    connectors::Billwerk,
+    connectors::Bitpay,
+    connectors::Cashtocode,
+    connectors::Coinbase,
+    connectors::Cryptopay,
+    connectors::Declarative,
+    connectors::Deutschebank,
+    connectors::Digitalvirgo,
+    connectors::Dlocal,
+    connectors::Elavon,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Fiuu,
+    connectors::Forte,
+    connectors::Globepay,
+    connectors::Helcim,
+    connectors::Jpmorgan,
+    connectors::Nomupay,
+    connectors::Novalnet,
+    connectors::Nexinets,
+    connectors::Nexixpay,
+    connectors::Payeezy,
+    connectors::Payu,
+    connectors::Powertranz,
+    connectors::Mollie,
+    connectors::Multisafepay,
+    connectors::Razorpay,
+    connectors::Shift4,
+    connectors::Stax,
+    connectors::Square,
+    connectors::Taxjar,
+    connectors::Thunes,
+    connectors::Tsys,
+    connectors::Worldline,
+    connectors::Worldpay,
+    connectors::Volt,
+    connectors::Zen,
+    connectors::Zsl
+);
+
+macro_rules! default_imp_for_account_update {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::ConnectorAccountUpdate for $path::$connector {}
+            impl
+            ConnectorIntegration<
+            AccountUpdate,
+            AccountUpdateRequestData,
+            AccountUpdateResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_account_update!(
+    connectors::Airwallex,
+    connectors::Amazonpay,
+    connectors::Bambora,
+    connectors::Billwerk,
+    connectors::Bitpay,
+    connectors::Cashtocode,
+    connectors::Coinbase,
+    connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -830,6 +1023,7 @@ default_imp_for_accept_dispute!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -888,6 +1082,7 @@ default_imp_for_submit_evidence!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -946,6 +1141,7 @@ default_imp_for_defend_dispute!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1013,6 +1209,7 @@ default_imp_for_file_upload!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1064,6 +1261,7 @@ default_imp_for_payouts!(
     connectors::Cashtocode,
     connectors::Cryptopay,
     connectors::Coinbase,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1123,6 +1321,7 @@ default_imp_for_payouts_create!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1183,6 +1382,7 @@ default_imp_for_payouts_retrieve!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1243,6 +1443,7 @@ default_imp_for_payouts_eligibility!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1303,6 +1504,7 @@ default_imp_for_payouts_fulfill!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1363,6 +1565,7 @@ default_imp_for_payouts_cancel!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1423,6 +1626,7 @@ default_imp_for_payouts_quote!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1483,6 +1687,7 @@ default_imp_for_payouts_recipient!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1543,6 +1748,7 @@ default_imp_for_payouts_recipient_account!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1603,6 +1809,7 @@ default_imp_for_frm_sale!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1663,6 +1870,7 @@ default_imp_for_frm_checkout!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1723,6 +1931,7 @@ default_imp_for_frm_transaction!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1783,6 +1992,7 @@ default_imp_for_frm_fulfillment!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1843,6 +2053,7 @@ default_imp_for_frm_record_return!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1900,6 +2111,7 @@ default_imp_for_revoking_mandates!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -5,25 +5,28 @@ use hyperswitch_domain_models::{
             DisputesFlowData, MandateRevokeFlowData, PaymentFlowData, RefundFlowData,
             WebhookSourceVerifyData,
         },
-        AccessTokenFlowData, FilesFlowData,
+        AccessTokenFlowData, AccountBalanceFlowData, AccountUpdateFlowData, FilesFlowData,
     },
     router_flow_types::{
+        account_balance::AccountBalance,
+        account_update::AccountUpdate,
         dispute::{Accept, Defend, Evidence},
         files::{Retrieve, Upload},
         mandate_revoke::MandateRevoke,
         payments::{
-            Approve, Authorize, AuthorizeSessionToken, CalculateTax, Capture, CompleteAuthorize,
-            CreateConnectorCustomer, IncrementalAuthorization, PSync, PaymentMethodToken,
-            PostProcessing, PostSessionTokens, PreProcessing, Reject, SdkSessionUpdate, Session,
-            SetupMandate, Void,
+            Approve, Authorize, AuthorizeSessionToken, Balance, CalculateTax, Capture,
+            CompleteAuthorize, CreateConnectorCustomer, IncrementalAuthorization, PSync,
+            PaymentMethodToken, PostProcessing, PostSessionTokens, PreProcessing, Reject,
+            SdkSessionUpdate, Session, SetupMandate, Void,
         },
         refunds::{Execute, RSync},
         webhooks::VerifyWebhookSource,
         AccessTokenAuth,
     },
     router_request_types::{
-        AcceptDisputeRequestData, AccessTokenRequestData, AuthorizeSessionTokenData,
-        CompleteAuthorizeData, ConnectorCustomerData, DefendDisputeRequestData,
+        AcceptDisputeRequestData, AccessTokenRequestData, AccountBalanceRequestData,
+        AccountUpdateRequestData, AuthorizeSessionTokenData, CompleteAuthorizeData,
+        ConnectorCustomerData, DefendDisputeRequestData, GiftCardBalanceCheckRequestData,
         MandateRevokeRequestData, PaymentMethodTokenizationData, PaymentsApproveData,
         PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
         PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
@@ -33,7 +36,8 @@ use hyperswitch_domain_models::{
         SubmitEvidenceRequestData, UploadFileRequestData, VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
+        AcceptDisputeResponse, AccountBalanceResponseData, AccountUpdateResponseData,
+        DefendDisputeResponse, GiftCardBalanceCheckResponseData, MandateRevokeResponseData,
         PaymentsResponseData, RefundsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
         TaxCalculationResponseData, UploadFileResponse, VerifyWebhookSourceResponseData,
     },
@@ -74,13 +78,15 @@ use hyperswitch_interfaces::{
         files_v2::{FileUploadV2, RetrieveFileV2, UploadFileV2},
         payments_v2::{
             ConnectorCustomerV2, MandateSetupV2, PaymentApproveV2, PaymentAuthorizeSessionTokenV2,
-            PaymentAuthorizeV2, PaymentCaptureV2, PaymentIncrementalAuthorizationV2,
-            PaymentPostSessionTokensV2, PaymentRejectV2, PaymentSessionUpdateV2, PaymentSessionV2,
-            PaymentSyncV2, PaymentTokenV2, PaymentV2, PaymentVoidV2, PaymentsCompleteAuthorizeV2,
-            PaymentsPostProcessingV2, PaymentsPreProcessingV2, TaxCalculationV2,
+            PaymentAuthorizeV2, PaymentCaptureV2, PaymentGiftCardBalanceCheckV2,
+            PaymentIncrementalAuthorizationV2, PaymentPostSessionTokensV2, PaymentRejectV2,
+            PaymentSessionUpdateV2, PaymentSessionV2, PaymentSyncV2, PaymentTokenV2, PaymentV2,
+            PaymentVoidV2, PaymentsCompleteAuthorizeV2, PaymentsPostProcessingV2,
+            PaymentsPreProcessingV2, TaxCalculationV2,
         },
         refunds_v2::{RefundExecuteV2, RefundSyncV2, RefundV2},
-        ConnectorAccessTokenV2, ConnectorMandateRevokeV2, ConnectorVerifyWebhookSourceV2,
+        ConnectorAccessTokenV2, ConnectorAccountBalanceV2, ConnectorAccountUpdateV2,
+        ConnectorMandateRevokeV2, ConnectorVerifyWebhookSourceV2,
     },
     connector_integration_v2::ConnectorIntegrationV2,
 };
@@ -109,6 +115,7 @@ macro_rules! default_imp_for_new_connector_integration_payment {
             impl TaxCalculationV2 for $path::$connector{}
             impl PaymentSessionUpdateV2 for $path::$connector{}
             impl PaymentPostSessionTokensV2 for $path::$connector{}
+            impl PaymentGiftCardBalanceCheckV2 for $path::$connector{}
             impl
             ConnectorIntegrationV2<Authorize,PaymentFlowData, PaymentsAuthorizeData, PaymentsResponseData>
             for $path::$connector{}
@@ -200,6 +207,13 @@ macro_rules! default_imp_for_new_connector_integration_payment {
             PaymentsPostSessionTokensData,
             PaymentsResponseData,
             > for $path::$connector{}
+        impl
+            ConnectorIntegrationV2<
+            Balance,
+            PaymentFlowData,
+            GiftCardBalanceCheckRequestData,
+            GiftCardBalanceCheckResponseData,
+            > for $path::$connector{}
     )*
     };
 }
@@ -213,6 +227,7 @@ default_imp_for_new_connector_integration_payment!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -272,6 +287,7 @@ default_imp_for_new_connector_integration_refund!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -326,6 +342,7 @@ default_imp_for_new_connector_integration_connector_access_token!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -386,6 +403,7 @@ default_imp_for_new_connector_integration_accept_dispute!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -445,6 +463,7 @@ default_imp_for_new_connector_integration_submit_evidence!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -504,6 +523,7 @@ default_imp_for_new_connector_integration_defend_dispute!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -573,6 +593,7 @@ default_imp_for_new_connector_integration_file_upload!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -634,6 +655,7 @@ default_imp_for_new_connector_integration_payouts_create!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -695,6 +717,7 @@ default_imp_for_new_connector_integration_payouts_eligibility!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -756,6 +779,7 @@ default_imp_for_new_connector_integration_payouts_fulfill!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -817,6 +841,7 @@ default_imp_for_new_connector_integration_payouts_cancel!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -878,6 +903,7 @@ default_imp_for_new_connector_integration_payouts_quote!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -939,6 +965,7 @@ default_imp_for_new_connector_integration_payouts_recipient!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1000,6 +1027,7 @@ default_imp_for_new_connector_integration_payouts_sync!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1061,6 +1089,7 @@ default_imp_for_new_connector_integration_payouts_recipient_account!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1120,6 +1149,127 @@ default_imp_for_new_connector_integration_webhook_source_verification!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
+    connectors::Deutschebank,
+    connectors::Digitalvirgo,
+    connectors::Dlocal,
+    connectors::Elavon,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Fiuu,
+    connectors::Forte,
+    connectors::Globepay,
+    connectors::Helcim,
+    connectors::Jpmorgan,
+    connectors::Nomupay,
+    connectors::Novalnet,
+    connectors::Nexinets,
+    connectors::Nexixpay,
+    connectors::Payeezy,
+    connectors::Payu,
+    connectors::Powertranz,
+    connectors::Mollie,
+    connectors::Multisafepay,
+    connectors::Razorpay,
+    connectors::Shift4,
+    connectors::Stax,
+    connectors::Square,
+    connectors::Taxjar,
+    connectors::Thunes,
+    connectors::Tsys,
+    connectors::Worldline,
+    connectors::Volt,
+    connectors::Worldpay,
+    connectors::Zen,
+    connectors::Zsl
+);
+
+macro_rules! default_imp_for_new_connector_integration_account_balance {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl ConnectorAccountBalanceV2 for $path::$connector {}
+            impl
+            ConnectorIntegrationV2<
+            AccountBalance,
+            AccountBalanceFlowData,
+            AccountBalanceRequestData,
+            AccountBalanceResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_new_connector_integration_account_balance!(
+    connectors::Airwallex,
+    connectors::Amazonpay,
+    connectors::Bambora,
+    connectors::Billwerk,
+    connectors::Bitpay,
+    connectors::Cashtocode,
+    connectors::Coinbase,
+    connectors::Cryptopay,
+    connectors::Declarative,
+    connectors::Deutschebank,
+    connectors::Digitalvirgo,
+    connectors::Dlocal,
+    connectors::Elavon,
+    connectors::Fiserv,
+    connectors::Fiservemea,
+    connectors::Fiuu,
+    connectors::Forte,
+    connectors::Globepay,
+    connectors::Helcim,
+    connectors::Jpmorgan,
+    connectors::Nomupay,
+    connectors::Novalnet,
+    connectors::Nexinets,
+    connectors::Nexixpay,
+    connectors::Payeezy,
+    connectors::Payu,
+    connectors::Powertranz,
+    connectors::Mollie,
+    connectors::Multisafepay,
+    connectors::Razorpay,
+    connectors::Shift4,
+    connectors::Stax,
+    connectors::Square,
+    connectors::Taxjar,
+    connectors::Thunes,
+    connectors::Tsys,
+    connectors::Worldline,
+    connectors::Volt,
+    connectors::Worldpay,
+    connectors::Zen,
+    connectors::Zsl
+);
+
+macro_rules! default_imp_for_new_connector_integration_account_update {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl ConnectorAccountUpdateV2 for $path::$connector {}
+            impl
+            ConnectorIntegrationV2<
+            AccountUpdate,
+            AccountUpdateFlowData,
+            AccountUpdateRequestData,
+            AccountUpdateResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_new_connector_integration_account_update!(
+    connectors::Airwallex,
+    connectors::Amazonpay,
+    connectors::Bambora,
+    connectors::Billwerk,
+    connectors::Bitpay,
+    connectors::Cashtocode,
+    connectors::Coinbase,
+    connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1181,6 +1331,7 @@ default_imp_for_new_connector_integration_frm_sale!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1242,6 +1393,7 @@ default_imp_for_new_connector_integration_frm_checkout!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1303,6 +1455,7 @@ default_imp_for_new_connector_integration_frm_transaction!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1364,6 +1517,7 @@ default_imp_for_new_connector_integration_frm_fulfillment!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1425,6 +1579,7 @@ default_imp_for_new_connector_integration_frm_record_return!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
@@ -1483,6 +1638,7 @@ default_imp_for_new_connector_integration_revoking_mandates!(
     connectors::Cashtocode,
     connectors::Coinbase,
     connectors::Cryptopay,
+    connectors::Declarative,
     connectors::Deutschebank,
     connectors::Digitalvirgo,
     connectors::Dlocal,
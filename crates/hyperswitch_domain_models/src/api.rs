@@ -7,15 +7,40 @@ use common_utils::{
 
 use super::payment_method_data::PaymentMethodData;
 
+/// A file sent as the body of the response as its chunks are produced, instead of being buffered
+pub struct FileStream {
+    pub content_type: mime::Mime,
+    pub body: futures::stream::BoxStream<'static, Result<actix_web::web::Bytes, std::io::Error>>,
+}
+
+impl std::fmt::Debug for FileStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileStream")
+            .field("content_type", &self.content_type)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Streams are only equal to themselves, as their contents can't be compared
+impl PartialEq for FileStream {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Eq for FileStream {}
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum ApplicationResponse<R> {
     Json(R),
+    JsonAccepted(R),
     StatusOk,
     TextPlain(String),
     JsonForRedirection(api_models::payments::RedirectionResponse),
     Form(Box<RedirectionFormData>),
     PaymentLinkForm(Box<PaymentLinkAction>),
     FileData((Vec<u8>, mime::Mime)),
+    FileStream(FileStream),
     JsonWithHeaders((R, Vec<(String, masking::Maskable<String>)>)),
     GenericLinkForm(Box<GenericLinks>),
 }
@@ -23,7 +48,7 @@ pub enum ApplicationResponse<R> {
 impl<T: ApiEventMetric> ApiEventMetric for ApplicationResponse<T> {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         match self {
-            Self::Json(r) => r.get_api_event_type(),
+            Self::Json(r) | Self::JsonAccepted(r) => r.get_api_event_type(),
             Self::JsonWithHeaders((r, _)) => r.get_api_event_type(),
             _ => None,
         }
@@ -38,6 +63,17 @@ pub struct RedirectionFormData {
     pub payment_method_data: Option<PaymentMethodData>,
     pub amount: String,
     pub currency: String,
+    pub branding: Option<RedirectionFormBranding>,
+}
+
+/// Branding of the profile applied to the interstitial page rendered for a redirection
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct RedirectionFormBranding {
+    pub theme: Option<String>,
+    pub logo: Option<String>,
+    pub seller_name: Option<String>,
+    /// Message shown while redirecting, translated with the message bundles of the merchant
+    pub processing_message: Option<String>,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -39,3 +39,14 @@ pub enum StorageError {
     #[error("RedisError: {0:?}")]
     RedisError(String),
 }
+
+impl StorageError {
+    pub fn is_db_version_conflict(&self) -> bool {
+        match self {
+            Self::DatabaseError(err) => {
+                matches!(err.current_context(), DatabaseError::VersionConflict)
+            }
+            _ => false,
+        }
+    }
+}
@@ -274,6 +274,19 @@ pub enum ApiErrorResponse {
     LinkConfigurationError { message: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_41", message = "Payout validation failed")]
     PayoutFailed { data: Option<serde_json::Value> },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_42", message = "The amount is not supported by {connector} for {payment_method_type}")]
+    AmountNotSupported {
+        connector: String,
+        payment_method_type: String,
+        minimum_amount: Option<common_utils::types::MinorUnit>,
+        maximum_amount: Option<common_utils::types::MinorUnit>,
+    },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_43", message = "The {limit} processing limit of the profile is exceeded")]
+    ProcessingLimitExceeded { limit: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_44", message = "Too many export requests, retry after a minute")]
+    DataExportRateLimitExceeded,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_45", message = "Too many balance checks for this payment, retry later")]
+    GiftCardBalanceCheckRateLimitExceeded,
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
@@ -287,6 +300,8 @@ pub enum ApiErrorResponse {
     WebhookUnprocessableEntity,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_06", message = "Merchant Secret set my merchant for webhook source verification is invalid")]
     WebhookInvalidMerchantSecret,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "WE_07", message = "Webhook was rejected as a replay of a previously received webhook")]
+    WebhookReplayDetected,
     #[error(error_type = ErrorType::ServerNotAvailable, code = "IE", message = "{reason} as data mismatched for {field_names}")]
     IntegrityCheckFailed {
         reason: String,
@@ -627,6 +642,25 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::PayoutFailed { data } => {
                 AER::BadRequest(ApiError::new("IR", 41, "Payout failed while processing with connector.", Some(Extra { data: data.clone(), ..Default::default()})))
             },
+            Self::AmountNotSupported { connector, payment_method_type, minimum_amount, maximum_amount } => {
+                AER::BadRequest(ApiError::new("IR", 42, format!("The amount is not supported by {connector} for {payment_method_type}"), Some(Extra {
+                    data: Some(serde_json::json!({
+                        "minimum_amount": minimum_amount,
+                        "maximum_amount": maximum_amount,
+                    })),
+                    connector: Some(connector.clone()),
+                    ..Default::default()
+                })))
+            },
+            Self::ProcessingLimitExceeded { limit } => {
+                AER::BadRequest(ApiError::new("IR", 43, format!("The {limit} processing limit of the profile is exceeded"), None))
+            },
+            Self::DataExportRateLimitExceeded => {
+                AER::TooManyRequests(ApiError::new("IR", 44, "Too many export requests, retry after a minute", None))
+            },
+            Self::GiftCardBalanceCheckRateLimitExceeded => {
+                AER::TooManyRequests(ApiError::new("IR", 45, "Too many balance checks for this payment, retry later", None))
+            },
 
             Self::WebhookAuthenticationFailed => {
                 AER::Unauthorized(ApiError::new("WE", 1, "Webhook authentication failed", None))
@@ -646,6 +680,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::WebhookInvalidMerchantSecret => {
                 AER::BadRequest(ApiError::new("WE", 6, "Merchant Secret set for webhook source verification is invalid", None))
             }
+            Self::WebhookReplayDetected => {
+                AER::Unauthorized(ApiError::new("WE", 7, "Webhook was rejected as a replay of a previously received webhook", None))
+            }
             Self::IntegrityCheckFailed {
                 reason,
                 field_names,
@@ -47,6 +47,7 @@ pub struct MerchantAccount {
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
     pub version: common_enums::ApiVersion,
+    pub data_region: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -81,6 +82,7 @@ pub struct MerchantAccountSetter {
     pub payment_link_config: Option<serde_json::Value>,
     pub pm_collect_link_config: Option<serde_json::Value>,
     pub version: common_enums::ApiVersion,
+    pub data_region: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -115,6 +117,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             payment_link_config: item.payment_link_config,
             pm_collect_link_config: item.pm_collect_link_config,
             version: item.version,
+            data_region: item.data_region,
         }
     }
 }
@@ -615,6 +618,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             payment_link_config: self.payment_link_config,
             pm_collect_link_config: self.pm_collect_link_config,
             version: self.version,
+            data_region: self.data_region,
         };
 
         Ok(diesel_models::MerchantAccount::from(setter))
@@ -692,6 +696,7 @@ impl super::behaviour::Conversion for MerchantAccount {
                 payment_link_config: item.payment_link_config,
                 pm_collect_link_config: item.pm_collect_link_config,
                 version: item.version,
+                data_region: item.data_region,
             })
         }
         .await
@@ -730,6 +735,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             payment_link_config: self.payment_link_config,
             pm_collect_link_config: self.pm_collect_link_config,
             version: crate::consts::API_VERSION,
+            data_region: self.data_region,
         })
     }
 }
@@ -479,6 +479,16 @@ pub struct PaymentAttempt {
     pub profile_id: id_type::ProfileId,
     pub organization_id: id_type::OrganizationId,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub row_version: i32,
+    pub routing_decision: Option<serde_json::Value>,
+    /// The processing fee reported by the connector for the attempt
+    pub connector_fee_amount: Option<MinorUnit>,
+    pub connector_fee_currency: Option<storage_enums::Currency>,
+    /// Identifier assigned by the connector to its latest request for the attempt
+    pub connector_request_id: Option<String>,
+    /// Identifier of the request to the application which last called the connector for the
+    /// attempt
+    pub correlation_id: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -724,6 +734,7 @@ pub struct PaymentAttemptNew {
     pub profile_id: id_type::ProfileId,
     pub organization_id: id_type::OrganizationId,
     pub connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+    pub routing_decision: Option<serde_json::Value>,
 }
 
 #[cfg(feature = "v1")]
@@ -750,6 +761,7 @@ pub enum PaymentAttemptUpdate {
         payment_token: Option<String>,
         connector: Option<String>,
         straight_through_algorithm: Option<serde_json::Value>,
+        routing_decision: Option<serde_json::Value>,
         amount_capturable: Option<MinorUnit>,
         surcharge_amount: Option<MinorUnit>,
         tax_amount: Option<MinorUnit>,
@@ -775,6 +787,7 @@ pub enum PaymentAttemptUpdate {
         payment_experience: Option<storage_enums::PaymentExperience>,
         business_sub_label: Option<String>,
         straight_through_algorithm: Option<serde_json::Value>,
+        routing_decision: Option<serde_json::Value>,
         error_code: Option<Option<String>>,
         error_message: Option<Option<String>>,
         amount_capturable: Option<MinorUnit>,
@@ -838,6 +851,10 @@ pub enum PaymentAttemptUpdate {
         payment_method_data: Option<serde_json::Value>,
         charge_id: Option<String>,
         connector_mandate_detail: Option<ConnectorMandateReferenceId>,
+        connector_fee_amount: Option<MinorUnit>,
+        connector_fee_currency: Option<storage_enums::Currency>,
+        connector_request_id: Option<String>,
+        correlation_id: Option<String>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -867,6 +884,8 @@ pub enum PaymentAttemptUpdate {
         connector_transaction_id: Option<String>,
         payment_method_data: Option<serde_json::Value>,
         authentication_type: Option<storage_enums::AuthenticationType>,
+        connector_request_id: Option<String>,
+        correlation_id: Option<String>,
     },
     CaptureUpdate {
         amount_to_capture: Option<MinorUnit>,
@@ -965,6 +984,7 @@ impl PaymentAttemptUpdate {
                 payment_token,
                 connector,
                 straight_through_algorithm,
+                routing_decision,
                 amount_capturable,
                 updated_by,
                 surcharge_amount,
@@ -974,6 +994,7 @@ impl PaymentAttemptUpdate {
                 payment_token,
                 connector,
                 straight_through_algorithm,
+                routing_decision,
                 amount_capturable,
                 surcharge_amount,
                 tax_amount,
@@ -1027,6 +1048,7 @@ impl PaymentAttemptUpdate {
                 payment_experience,
                 business_sub_label,
                 straight_through_algorithm,
+                routing_decision,
                 error_code,
                 error_message,
                 amount_capturable,
@@ -1057,6 +1079,7 @@ impl PaymentAttemptUpdate {
                 payment_experience,
                 business_sub_label,
                 straight_through_algorithm,
+                routing_decision,
                 error_code,
                 error_message,
                 amount_capturable,
@@ -1108,6 +1131,10 @@ impl PaymentAttemptUpdate {
                 payment_method_data,
                 charge_id,
                 connector_mandate_detail,
+                connector_fee_amount,
+                connector_fee_currency,
+                connector_request_id,
+                correlation_id,
             } => DieselPaymentAttemptUpdate::ResponseUpdate {
                 status,
                 connector,
@@ -1130,6 +1157,10 @@ impl PaymentAttemptUpdate {
                 payment_method_data,
                 charge_id,
                 connector_mandate_detail,
+                connector_fee_amount,
+                connector_fee_currency,
+                connector_request_id,
+                correlation_id,
             },
             Self::UnresolvedResponseUpdate {
                 status,
@@ -1168,6 +1199,8 @@ impl PaymentAttemptUpdate {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_request_id,
+                correlation_id,
             } => DieselPaymentAttemptUpdate::ErrorUpdate {
                 connector,
                 status,
@@ -1181,6 +1214,8 @@ impl PaymentAttemptUpdate {
                 connector_transaction_id,
                 payment_method_data,
                 authentication_type,
+                connector_request_id,
+                correlation_id,
             },
             Self::CaptureUpdate {
                 multiple_capture_count,
@@ -1425,6 +1460,12 @@ impl behaviour::Conversion for PaymentAttempt {
             order_tax_amount: self.net_amount.get_order_tax_amount(),
             shipping_cost: self.net_amount.get_shipping_cost(),
             connector_mandate_detail: self.connector_mandate_detail,
+            row_version: self.row_version,
+            routing_decision: self.routing_decision,
+            connector_fee_amount: self.connector_fee_amount,
+            connector_fee_currency: self.connector_fee_currency,
+            connector_request_id: self.connector_request_id,
+            correlation_id: self.correlation_id,
         })
     }
 
@@ -1506,6 +1547,12 @@ impl behaviour::Conversion for PaymentAttempt {
                 profile_id: storage_model.profile_id,
                 organization_id: storage_model.organization_id,
                 connector_mandate_detail: storage_model.connector_mandate_detail,
+                row_version: storage_model.row_version,
+                routing_decision: storage_model.routing_decision,
+                connector_fee_amount: storage_model.connector_fee_amount,
+                connector_fee_currency: storage_model.connector_fee_currency,
+                connector_request_id: storage_model.connector_request_id,
+                correlation_id: storage_model.correlation_id,
             })
         }
         .await
@@ -1588,6 +1635,7 @@ impl behaviour::Conversion for PaymentAttempt {
             order_tax_amount: self.net_amount.get_order_tax_amount(),
             shipping_cost: self.net_amount.get_shipping_cost(),
             connector_mandate_detail: self.connector_mandate_detail,
+            routing_decision: self.routing_decision,
         })
     }
 }
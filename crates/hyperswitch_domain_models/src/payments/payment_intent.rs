@@ -1002,6 +1002,11 @@ pub struct PaymentIntentListParams {
     pub limit: Option<u32>,
     pub order: api_models::payments::Order,
     pub card_network: Option<Vec<storage_enums::CardNetwork>>,
+    /// Values of metadata fields which the metadata of the payment must contain
+    pub metadata: Option<serde_json::Value>,
+    pub merchant_order_reference_id: Option<String>,
+    /// Fingerprints of the payment methods, any of which the payment must have been made with
+    pub fingerprint_id: Option<Vec<String>>,
 }
 
 impl From<api_models::payments::PaymentListConstraints> for PaymentIntentFetchConstraints {
@@ -1016,6 +1021,7 @@ impl From<api_models::payments::PaymentListConstraints> for PaymentIntentFetchCo
             created_gt,
             created_lte,
             created_gte,
+            fields: _,
         } = value;
         Self::List(Box::new(PaymentIntentListParams {
             offset: 0,
@@ -1036,6 +1042,9 @@ impl From<api_models::payments::PaymentListConstraints> for PaymentIntentFetchCo
             limit: Some(std::cmp::min(limit, PAYMENTS_LIST_MAX_LIMIT_V1)),
             order: Default::default(),
             card_network: None,
+            metadata: None,
+            merchant_order_reference_id: None,
+            fingerprint_id: None,
         }))
     }
 }
@@ -1061,6 +1070,9 @@ impl From<common_utils::types::TimeRange> for PaymentIntentFetchConstraints {
             limit: None,
             order: Default::default(),
             card_network: None,
+            metadata: None,
+            merchant_order_reference_id: None,
+            fingerprint_id: None,
         }))
     }
 }
@@ -1084,6 +1096,7 @@ impl From<api_models::payments::PaymentListFilterConstraints> for PaymentIntentF
             merchant_connector_id,
             order,
             card_network,
+            metadata,
         } = value;
         if let Some(payment_intent_id) = payment_id {
             Self::Single { payment_intent_id }
@@ -1107,6 +1120,9 @@ impl From<api_models::payments::PaymentListFilterConstraints> for PaymentIntentF
                 limit: Some(std::cmp::min(limit, PAYMENTS_LIST_MAX_LIMIT_V2)),
                 order,
                 card_network,
+                metadata: metadata.map(serde_json::Value::Object),
+                merchant_order_reference_id: None,
+                fingerprint_id: None,
             }))
         }
     }
@@ -1551,6 +1567,7 @@ impl behaviour::Conversion for PaymentIntent {
             shipping_cost: self.shipping_cost,
             tax_details: self.tax_details,
             skip_external_tax_calculation: self.skip_external_tax_calculation,
+            row_version: self.row_version,
         })
     }
 
@@ -1638,6 +1655,7 @@ impl behaviour::Conversion for PaymentIntent {
                 is_payment_processor_token_flow: storage_model.is_payment_processor_token_flow,
                 organization_id: storage_model.organization_id,
                 skip_external_tax_calculation: storage_model.skip_external_tax_calculation,
+                row_version: storage_model.row_version,
             })
         }
         .await
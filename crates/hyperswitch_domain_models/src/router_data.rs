@@ -63,6 +63,9 @@ pub struct RouterData<Flow, Request, Response> {
 
     pub test_mode: Option<bool>,
     pub connector_http_status_code: Option<u16>,
+    /// Identifier assigned by the connector to its latest request, as returned in the response
+    /// headers of the connector
+    pub connector_request_id: Option<String>,
     pub external_latency: Option<u128>,
     /// Contains apple pay flow type simplified or manual
     pub apple_pay_flow: Option<payment_method_data::ApplePayFlow>,
@@ -81,6 +84,9 @@ pub struct RouterData<Flow, Request, Response> {
 
     pub integrity_check: Result<(), IntegrityCheckError>,
 
+    /// Connector specific options set on the merchant connector account
+    pub connector_options: Option<api_models::connector_options::ConnectorOptions>,
+
     pub additional_merchant_data: Option<api_models::admin::AdditionalMerchantData>,
 
     pub header_payload: Option<payments::HeaderPayload>,
@@ -324,6 +330,8 @@ pub struct PaymentMethodBalance {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ConnectorResponseData {
     pub additional_payment_method_data: Option<AdditionalPaymentMethodConnectorResponse>,
+    pub settlement_details: Option<SettlementDetails>,
+    pub connector_fee: Option<ConnectorFee>,
 }
 
 impl ConnectorResponseData {
@@ -332,10 +340,43 @@ impl ConnectorResponseData {
     ) -> Self {
         Self {
             additional_payment_method_data: Some(additional_payment_method_data),
+            settlement_details: None,
+            connector_fee: None,
+        }
+    }
+
+    pub fn with_settlement_details(settlement_details: SettlementDetails) -> Self {
+        Self {
+            additional_payment_method_data: None,
+            settlement_details: Some(settlement_details),
+            connector_fee: None,
+        }
+    }
+
+    pub fn with_connector_fee(connector_fee: ConnectorFee) -> Self {
+        Self {
+            additional_payment_method_data: None,
+            settlement_details: None,
+            connector_fee: Some(connector_fee),
         }
     }
 }
 
+/// The amount of a capture or refund in the currency the connector settles the merchant in, for
+/// connectors which settle in a currency other than the presentment currency
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SettlementDetails {
+    pub settlement_currency: common_enums::Currency,
+    pub settlement_amount: MinorUnit,
+}
+
+/// The processing fee charged by the connector for a payment, as reported in its responses
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorFee {
+    pub amount: MinorUnit,
+    pub currency: common_enums::Currency,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum AdditionalPaymentMethodConnectorResponse {
     Card {
@@ -7,8 +7,9 @@ pub use flow_common_types::FrmFlowData;
 #[cfg(feature = "payouts")]
 pub use flow_common_types::PayoutFlowData;
 pub use flow_common_types::{
-    AccessTokenFlowData, DisputesFlowData, ExternalAuthenticationFlowData, FilesFlowData,
-    MandateRevokeFlowData, PaymentFlowData, RefundFlowData, WebhookSourceVerifyData,
+    AccessTokenFlowData, AccountBalanceFlowData, AccountUpdateFlowData, DisputesFlowData,
+    ExternalAuthenticationFlowData, FilesFlowData, MandateRevokeFlowData, PaymentFlowData,
+    RefundFlowData, WebhookSourceVerifyData,
 };
 
 use crate::router_data::{ConnectorAuthType, ErrorResponse};
@@ -140,6 +140,16 @@ pub struct WebhookSourceVerifyData {
 #[derive(Debug, Clone)]
 pub struct AccessTokenFlowData {}
 
+#[derive(Debug, Clone)]
+pub struct AccountBalanceFlowData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountUpdateFlowData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
 #[derive(Debug, Clone)]
 pub struct FilesFlowData {
     pub merchant_id: common_utils::id_type::MerchantId,
@@ -1,4 +1,6 @@
 pub mod access_token_auth;
+pub mod account_balance;
+pub mod account_update;
 pub mod dispute;
 pub mod files;
 pub mod fraud_check;
@@ -9,6 +11,8 @@ pub mod refunds;
 pub mod webhooks;
 
 pub use access_token_auth::*;
+pub use account_balance::*;
+pub use account_update::*;
 pub use dispute::*;
 pub use files::*;
 pub use fraud_check::*;
@@ -73,6 +73,11 @@ pub struct PaymentsAuthorizeData {
     pub integrity_object: Option<AuthoriseIntegrityObject>,
     pub shipping_cost: Option<MinorUnit>,
     pub additional_payment_method_data: Option<AdditionalPaymentData>,
+    /// Fields passed by the merchant in the connector metadata, to be forwarded as is in the
+    /// request sent to the connector
+    pub connector_passthrough_data: Option<api_models::payments::ConnectorPassthroughData>,
+    /// Merchant category code (MCC) configured for the profile of the payment
+    pub merchant_category_code: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -463,6 +468,8 @@ pub struct PaymentsCancelData {
     pub currency: Option<storage_enums::Currency>,
     pub connector_transaction_id: String,
     pub cancellation_reason: Option<String>,
+    /// The cancellation reason, when it is one of the normalized reasons
+    pub cancellation_reason_code: Option<storage_enums::CancellationReason>,
     pub connector_meta: Option<serde_json::Value>,
     pub browser_info: Option<BrowserInformation>,
     pub metadata: Option<serde_json::Value>,
@@ -496,6 +503,8 @@ pub struct BrowserInformation {
     pub ip_address: Option<std::net::IpAddr>,
     pub accept_header: Option<String>,
     pub user_agent: Option<String>,
+    pub origin: Option<String>,
+    pub ip_country: Option<common_enums::CountryAlpha2>,
 }
 
 #[derive(Debug, Clone, Default, Serialize)]
@@ -811,6 +820,30 @@ pub struct VerifyWebhookSourceRequestData {
     pub merchant_secret: api_models::webhooks::ConnectorWebhookSecrets,
 }
 
+#[derive(Debug, Clone)]
+pub struct AccountBalanceRequestData {
+    /// Account of the connector whose balance is retrieved, for connectors which hold balances in
+    /// multiple accounts
+    pub account_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountUpdateRequestData {
+    /// Identifier of the card stored with the connector, such as the connector mandate id
+    pub connector_payment_method_id: String,
+    /// Details of the card as currently stored, for telling apart the cards updated by the issuer
+    pub card_last4: Option<String>,
+    pub card_exp_month: Option<Secret<String>>,
+    pub card_exp_year: Option<Secret<String>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct GiftCardBalanceCheckRequestData {
+    pub payment_method_data: PaymentMethodData,
+    pub currency: storage_enums::Currency,
+    pub minor_amount: MinorUnit,
+}
+
 #[derive(Debug, Clone)]
 pub struct MandateRevokeRequestData {
     pub mandate_id: String,
@@ -91,6 +91,8 @@ pub struct ConnectorAuthenticationRequestData {
     pub threeds_method_comp_ind: api_models::payments::ThreeDsCompletionIndicator,
     pub three_ds_requestor_url: String,
     pub webhook_url: String,
+    pub three_ri_indicator: Option<api_models::payments::ThreeRiIndicator>,
+    pub prior_authentication_data: Option<PriorAuthenticationData>,
 }
 
 #[derive(Clone, serde::Deserialize, Debug, serde::Serialize, PartialEq, Eq)]
@@ -142,6 +144,15 @@ impl TryFrom<&diesel_models::authentication::Authentication> for PreAuthenticati
     }
 }
 
+/// Details of a prior authentication of the cardholder, shared with the issuer for 3DS Requestor
+/// Initiated authentications
+#[derive(Clone, Debug)]
+pub struct PriorAuthenticationData {
+    pub acs_trans_id: String,
+    pub authentication_type: Option<common_enums::DecoupledAuthenticationType>,
+    pub authenticated_at: time::PrimitiveDateTime,
+}
+
 #[derive(Clone, Default, Debug, Serialize, Deserialize)]
 pub struct ThreeDsMethodData {
     pub three_ds_method_data_submission: bool,
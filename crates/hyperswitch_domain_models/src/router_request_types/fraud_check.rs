@@ -27,6 +27,24 @@ pub struct FraudCheckCheckoutData {
     pub payment_method_data: Option<api_models::payments::AdditionalPaymentData>,
     pub email: Option<Email>,
     pub gateway: Option<String>,
+    pub risk_signals: Option<PaymentRiskSignals>,
+}
+
+/// Risk signals derived from the context of a payment, available to routing rules and fraud checks
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaymentRiskSignals {
+    /// Country which issued the card, as resolved from its BIN
+    pub bin_country: Option<common_enums::CountryAlpha2>,
+    /// Country of the ip address of the customer
+    pub ip_country: Option<common_enums::CountryAlpha2>,
+    /// Whether the card was issued in a country other than the one of the ip address of the
+    /// customer, unknown if either country is unknown
+    pub is_bin_ip_country_mismatch: Option<bool>,
+    /// Whether the payment is made during the night in the local time of the customer, unknown if
+    /// the time zone of the customer is unknown
+    pub is_night_time: Option<bool>,
+    /// Number of disputes raised on the previous payments of the customer
+    pub customer_chargeback_count: Option<i64>,
 }
 
 #[derive(Debug, Clone)]
@@ -5,7 +5,10 @@ use std::collections::HashMap;
 use common_utils::{request::Method, types as common_types, types::MinorUnit};
 pub use disputes::{AcceptDisputeResponse, DefendDisputeResponse, SubmitEvidenceResponse};
 
-use crate::router_request_types::{authentication::AuthNFlowType, ResponseId};
+use crate::{
+    router_data::SettlementDetails,
+    router_request_types::{authentication::AuthNFlowType, ResponseId},
+};
 #[derive(Debug, Clone)]
 pub struct RefundsResponseData {
     pub connector_refund_id: String,
@@ -93,6 +96,7 @@ pub enum CaptureSyncResponse {
         status: common_enums::AttemptStatus,
         connector_response_reference_id: Option<String>,
         amount: Option<MinorUnit>,
+        settlement_details: Option<SettlementDetails>,
     },
     Error {
         code: String,
@@ -109,6 +113,14 @@ impl CaptureSyncResponse {
             Self::Success { amount, .. } | Self::Error { amount, .. } => *amount,
         }
     }
+    pub fn get_settlement_details(&self) -> Option<SettlementDetails> {
+        match self {
+            Self::Success {
+                settlement_details, ..
+            } => settlement_details.clone(),
+            Self::Error { .. } => None,
+        }
+    }
     pub fn get_connector_response_reference_id(&self) -> Option<String> {
         match self {
             Self::Success {
@@ -378,6 +390,47 @@ pub enum VerifyWebhookStatus {
     SourceNotVerified,
 }
 
+#[derive(Debug, Clone)]
+pub struct AccountBalanceResponseData {
+    pub balances: Vec<CurrencyBalance>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountUpdateResponseData {
+    pub status: AccountUpdateStatus,
+    /// Number of the replacement card, for connectors sharing it
+    pub card_number: Option<cards::CardNumber>,
+    pub card_last4: Option<String>,
+    pub card_exp_month: Option<masking::Secret<String>>,
+    pub card_exp_year: Option<masking::Secret<String>>,
+}
+
+/// Outcome of the account updater of the card issuer for a stored card
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountUpdateStatus {
+    NoChange,
+    ExpiryUpdated,
+    CardNumberUpdated,
+    /// The card account was closed by the issuer, the card can no longer be charged
+    Closed,
+}
+
+#[derive(Debug, Clone)]
+pub struct GiftCardBalanceCheckResponseData {
+    /// Balance remaining on the gift card
+    pub balance: MinorUnit,
+    pub currency: common_enums::Currency,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrencyBalance {
+    pub currency: common_enums::Currency,
+    /// Amount which can be paid out or used
+    pub available: MinorUnit,
+    /// Amount which is not yet available, for example because it is yet to be settled
+    pub pending: MinorUnit,
+}
+
 #[derive(Debug, Clone)]
 pub struct MandateRevokeResponseData {
     pub mandate_status: common_enums::MandateStatus,
@@ -26,13 +26,21 @@ use hyperswitch_domain_models::{
     payment_method_data::PaymentMethodData,
     router_data::{AccessToken, ConnectorAuthType, ErrorResponse, RouterData},
     router_data_v2::{
-        flow_common_types::WebhookSourceVerifyData, AccessTokenFlowData, MandateRevokeFlowData,
+        flow_common_types::WebhookSourceVerifyData, AccessTokenFlowData, AccountBalanceFlowData,
+        AccountUpdateFlowData, MandateRevokeFlowData,
+    },
+    router_flow_types::{
+        mandate_revoke::MandateRevoke, AccessTokenAuth, AccountBalance, AccountUpdate,
+        VerifyWebhookSource,
     },
-    router_flow_types::{mandate_revoke::MandateRevoke, AccessTokenAuth, VerifyWebhookSource},
     router_request_types::{
-        AccessTokenRequestData, MandateRevokeRequestData, VerifyWebhookSourceRequestData,
+        AccessTokenRequestData, AccountBalanceRequestData, AccountUpdateRequestData,
+        MandateRevokeRequestData, VerifyWebhookSourceRequestData,
+    },
+    router_response_types::{
+        AccountBalanceResponseData, AccountUpdateResponseData, MandateRevokeResponseData,
+        VerifyWebhookSourceResponseData,
     },
-    router_response_types::{MandateRevokeResponseData, VerifyWebhookSourceResponseData},
 };
 use masking::Maskable;
 use router_env::metrics::add_attributes;
@@ -128,6 +136,16 @@ pub trait ConnectorIntegration<T, Req, Resp>:
         Ok(None)
     }
 
+    /// Header carrying the idempotency key of the request, for connectors which deduplicate
+    /// requests with the same key. The key must be stable for the flow of the payment attempt, so
+    /// that the connector does not process the request twice when it is retried
+    fn get_idempotency_header(
+        &self,
+        _req: &RouterData<T, Req, Resp>,
+    ) -> CustomResult<Option<(String, Maskable<String>)>, errors::ConnectorError> {
+        Ok(None)
+    }
+
     /// fn handle_response
     fn handle_response(
         &self,
@@ -251,6 +269,12 @@ pub trait ConnectorCommon {
         "application/json"
     }
 
+    /// Headers of the connector's responses carrying the identifier assigned by the connector to
+    /// the request, checked in order
+    fn get_request_id_headers(&self) -> &'static [&'static str] {
+        &["request-id", "x-request-id"]
+    }
+
     // FIXME write doc - think about this
     // fn headers(&self) -> Vec<(&str, &str)>;
 
@@ -338,6 +362,40 @@ pub trait ConnectorVerifyWebhookSourceV2:
 {
 }
 
+/// trait ConnectorAccountBalance
+pub trait ConnectorAccountBalance:
+    ConnectorIntegration<AccountBalance, AccountBalanceRequestData, AccountBalanceResponseData>
+{
+}
+
+/// trait ConnectorAccountBalanceV2
+pub trait ConnectorAccountBalanceV2:
+    ConnectorIntegrationV2<
+    AccountBalance,
+    AccountBalanceFlowData,
+    AccountBalanceRequestData,
+    AccountBalanceResponseData,
+>
+{
+}
+
+/// trait ConnectorAccountUpdate
+pub trait ConnectorAccountUpdate:
+    ConnectorIntegration<AccountUpdate, AccountUpdateRequestData, AccountUpdateResponseData>
+{
+}
+
+/// trait ConnectorAccountUpdateV2
+pub trait ConnectorAccountUpdateV2:
+    ConnectorIntegrationV2<
+    AccountUpdate,
+    AccountUpdateFlowData,
+    AccountUpdateRequestData,
+    AccountUpdateResponseData,
+>
+{
+}
+
 /// trait ConnectorValidation
 pub trait ConnectorValidation: ConnectorCommon {
     /// fn validate_capture_method
@@ -398,6 +456,32 @@ pub trait ConnectorValidation: ConnectorCommon {
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         false
     }
+
+    /// The window within which a captured payment can be voided, before the connector settles it.
+    /// `None` when captured payments can't be voided at all.
+    fn get_void_window(&self) -> Option<VoidWindow> {
+        None
+    }
+}
+
+/// Window within which a captured payment can be voided, after which the connector settles the
+/// payment and it can only be refunded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VoidWindow {
+    /// Until the end of the day, in UTC, on which the payment was made
+    SameDay,
+    /// For the given duration after the payment was made
+    Duration(time::Duration),
+}
+
+impl VoidWindow {
+    /// Whether a payment made at `made_at` can still be voided at `now`
+    pub fn is_open(&self, made_at: time::PrimitiveDateTime, now: time::PrimitiveDateTime) -> bool {
+        match self {
+            Self::SameDay => made_at.date() == now.date(),
+            Self::Duration(duration) => now <= made_at.saturating_add(*duration),
+        }
+    }
 }
 
 /// trait ConnectorRedirectResponse
@@ -1,5 +1,8 @@
 //! Configs interface
-use common_enums::ApplicationError;
+use std::{collections::HashMap, time::Duration};
+
+use common_enums::{ApplicationError, AttemptStatus, RefundStatus};
+use common_utils::request::Method;
 use masking::Secret;
 use router_derive;
 use serde::Deserialize;
@@ -94,6 +97,10 @@ pub struct Connectors {
     pub worldpay: ConnectorParams,
     pub zen: ConnectorParams,
     pub zsl: ConnectorParams,
+    /// Request timeouts and retry policies of the connectors, by connector and flow
+    pub request_policies: ConnectorRequestPolicies,
+    /// Connectors integrated through a declarative specification instead of transformer code
+    pub declarative: DeclarativeConnectors,
 }
 
 /// struct ConnectorParams
@@ -182,3 +189,308 @@ pub struct ConnectorParamsWithThreeUrls {
     /// third base url
     pub third_base_url: String,
 }
+
+/// Request timeouts and retry policies of the connectors, by connector name
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ConnectorRequestPolicies(HashMap<String, ConnectorRequestPolicy>);
+
+impl ConnectorRequestPolicies {
+    /// The policy configured for the connector, if any
+    pub fn get_connector_policy(&self, connector: &str) -> Option<&ConnectorRequestPolicy> {
+        self.0.get(connector)
+    }
+
+    /// The policy applied to the requests of the flow sent to the connector. The values configured
+    /// for the flow take precedence over the values configured for the connector.
+    pub fn get_flow_policy(&self, connector: &str, flow: &str) -> ConnectorFlowRequestPolicy {
+        self.get_connector_policy(connector)
+            .map(|connector_policy| connector_policy.get_flow_policy(flow))
+            .unwrap_or_default()
+    }
+
+    /// Validates that the timeouts and retry delays of the policies are usable
+    pub fn validate(&self, parent_field: &str) -> Result<(), ApplicationError> {
+        self.0.iter().try_for_each(|(connector, connector_policy)| {
+            let field = format!("{parent_field}.{connector}");
+            connector_policy.get_default_policy().validate(&field)?;
+            connector_policy
+                .flows
+                .iter()
+                .try_for_each(|(flow, flow_policy)| {
+                    flow_policy.validate(&format!("{field}.flows.{flow}"))
+                })
+        })
+    }
+}
+
+/// Request timeout and retry policy of a connector
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorRequestPolicy {
+    /// Timeout of the requests sent to the connector, in seconds
+    pub timeout_secs: Option<u64>,
+    /// Retries of the requests rejected by the connector with a retryable status code
+    pub retry: Option<ConnectorRetryPolicy>,
+    /// Policies of specific flows, by flow name such as `authorize` or `complete_authorize`,
+    /// overriding the values configured for the connector
+    pub flows: HashMap<String, ConnectorFlowRequestPolicy>,
+}
+
+impl ConnectorRequestPolicy {
+    /// The policy applied to the requests of the flows with no policy of their own
+    pub fn get_default_policy(&self) -> ConnectorFlowRequestPolicy {
+        ConnectorFlowRequestPolicy {
+            timeout_secs: self.timeout_secs,
+            retry: self.retry.clone(),
+        }
+    }
+
+    /// The policy applied to the requests of the flow, the flow name is matched ignoring case and
+    /// underscores so that both `complete_authorize` and `CompleteAuthorize` can be used
+    pub fn get_flow_policy(&self, flow: &str) -> ConnectorFlowRequestPolicy {
+        let normalize = |name: &str| name.replace('_', "").to_lowercase();
+        let flow = normalize(flow);
+        let flow_policy = self
+            .flows
+            .iter()
+            .find_map(|(name, flow_policy)| (normalize(name) == flow).then_some(flow_policy));
+
+        match flow_policy {
+            Some(flow_policy) => ConnectorFlowRequestPolicy {
+                timeout_secs: flow_policy.timeout_secs.or(self.timeout_secs),
+                retry: flow_policy.retry.clone().or_else(|| self.retry.clone()),
+            },
+            None => self.get_default_policy(),
+        }
+    }
+}
+
+/// Request timeout and retry policy of a flow of a connector
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorFlowRequestPolicy {
+    /// Timeout of the requests of the flow, in seconds
+    pub timeout_secs: Option<u64>,
+    /// Retries of the requests of the flow, a policy with no retries disables the retries
+    /// configured for the connector
+    pub retry: Option<ConnectorRetryPolicy>,
+}
+
+impl ConnectorFlowRequestPolicy {
+    fn validate(&self, parent_field: &str) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.timeout_secs == Some(0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "timeout_secs must be greater than 0 for {parent_field}"
+            )))
+        })?;
+        self.retry
+            .as_ref()
+            .map_or(Ok(()), |retry| retry.validate(parent_field))
+    }
+}
+
+/// Retries of the requests rejected by a connector with HTTP 429, 502 or 503, with exponential
+/// backoff
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ConnectorRetryPolicy {
+    /// Maximum number of retries of a request, retries are disabled if zero
+    pub max_retries: u8,
+    /// Delay before the first retry, doubled for every subsequent retry
+    pub base_delay_in_millis: u64,
+    /// Upper bound of the delay between retries
+    pub max_delay_in_millis: u64,
+}
+
+impl ConnectorRetryPolicy {
+    /// Delay before the retry following the given number of retries
+    pub fn get_delay(&self, retries: u8) -> Duration {
+        Duration::from_millis(
+            self.base_delay_in_millis
+                .saturating_mul(2_u64.saturating_pow(u32::from(retries)))
+                .min(self.max_delay_in_millis),
+        )
+    }
+
+    fn validate(&self, parent_field: &str) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.max_delay_in_millis < self.base_delay_in_millis, || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "retry.max_delay_in_millis must not be less than retry.base_delay_in_millis for {parent_field}"
+            )))
+        })
+    }
+}
+
+/// Specifications of the connectors integrated declaratively, by identifier. The identifiers are
+/// a namespace of their own, distinct from the names of the connectors: a connector account of the
+/// `declarative` connector refers to its specification through the `declarative_connector` field
+/// of its metadata.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct DeclarativeConnectors(HashMap<String, DeclarativeConnectorSpec>);
+
+impl DeclarativeConnectors {
+    /// The specification configured with the identifier, if any
+    pub fn get_spec(&self, declarative_connector: &str) -> Option<&DeclarativeConnectorSpec> {
+        self.0.get(declarative_connector)
+    }
+
+    /// The specifications of all the declarative connectors
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &DeclarativeConnectorSpec)> {
+        self.0.iter()
+    }
+
+    /// Validates that the identifiers consist of lowercase letters, digits and underscores and
+    /// that the flows of the specifications are usable
+    pub fn validate(&self, parent_field: &str) -> Result<(), ApplicationError> {
+        self.0.iter().try_for_each(|(declarative_connector, spec)| {
+            let field = format!("{parent_field}.{declarative_connector}");
+            common_utils::fp_utils::when(
+                declarative_connector.is_empty()
+                    || !declarative_connector.chars().all(|character| {
+                        character.is_ascii_lowercase()
+                            || character.is_ascii_digit()
+                            || character == '_'
+                    }),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "{field} must consist of lowercase letters, digits and underscores"
+                    )))
+                },
+            )?;
+            spec.validate(&field)
+        })
+    }
+}
+
+/// Declarative integration of a connector with a simple REST API. The requests of every flow are
+/// rendered from a template and the responses are mapped through JSON paths, such as
+/// `$.data.status` or `$.links[0].href`.
+///
+/// Templates refer to the values of the payment through `{{variable}}` placeholders, such as
+/// `{{amount}}`, `{{currency}}`, `{{card_number}}`, `{{connector_transaction_id}}` or the
+/// credentials of the connector account, `{{api_key}}`, `{{key1}}`, `{{api_secret}}` and
+/// `{{key2}}`. A string consisting of a single placeholder is replaced by the value of the
+/// variable as is, so that numbers remain numbers.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct DeclarativeConnectorSpec {
+    /// Base url the paths of the flows are appended to
+    pub base_url: String,
+    /// Headers sent with every request, the values are templates
+    pub headers: HashMap<String, String>,
+    /// Fields of the error responses of the connector
+    pub error: DeclarativeErrorMapping,
+    /// Authorization of payments
+    pub authorize: Option<DeclarativeFlowSpec<AttemptStatus>>,
+    /// Retrieval of the status of payments
+    pub psync: Option<DeclarativeFlowSpec<AttemptStatus>>,
+    /// Capture of authorized payments
+    pub capture: Option<DeclarativeFlowSpec<AttemptStatus>>,
+    /// Cancellation of authorized payments
+    pub void: Option<DeclarativeFlowSpec<AttemptStatus>>,
+    /// Refund of payments
+    pub refund: Option<DeclarativeFlowSpec<RefundStatus>>,
+    /// Retrieval of the status of refunds
+    pub refund_sync: Option<DeclarativeFlowSpec<RefundStatus>>,
+}
+
+impl DeclarativeConnectorSpec {
+    fn validate(&self, parent_field: &str) -> Result<(), ApplicationError> {
+        common_utils::fp_utils::when(self.base_url.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "base_url must not be empty for {parent_field}"
+            )))
+        })?;
+        common_utils::fp_utils::when(self.authorize.is_none(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(format!(
+                "authorize must be specified for {parent_field}"
+            )))
+        })?;
+
+        let validate_flow = |flow: &str, flow_spec: Option<Result<(), String>>| {
+            flow_spec.map_or(Ok(()), |result| {
+                result.map_err(|field| {
+                    ApplicationError::InvalidConfigurationValueError(format!(
+                        "{field} must not be empty for {parent_field}.{flow}"
+                    ))
+                })
+            })
+        };
+        validate_flow(
+            "authorize",
+            self.authorize.as_ref().map(DeclarativeFlowSpec::validate),
+        )?;
+        validate_flow(
+            "psync",
+            self.psync.as_ref().map(DeclarativeFlowSpec::validate),
+        )?;
+        validate_flow(
+            "capture",
+            self.capture.as_ref().map(DeclarativeFlowSpec::validate),
+        )?;
+        validate_flow(
+            "void",
+            self.void.as_ref().map(DeclarativeFlowSpec::validate),
+        )?;
+        validate_flow(
+            "refund",
+            self.refund.as_ref().map(DeclarativeFlowSpec::validate),
+        )?;
+        validate_flow(
+            "refund_sync",
+            self.refund_sync.as_ref().map(DeclarativeFlowSpec::validate),
+        )
+    }
+}
+
+/// JSON paths of the fields of the error responses of a declarative connector
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct DeclarativeErrorMapping {
+    /// Path of the error code
+    pub code_path: Option<String>,
+    /// Path of the error message
+    pub message_path: Option<String>,
+    /// Path of the detailed reason of the error
+    pub reason_path: Option<String>,
+}
+
+/// Request template and response mapping of a flow of a declarative connector
+#[derive(Debug, Deserialize, Clone)]
+pub struct DeclarativeFlowSpec<S> {
+    /// HTTP method of the request
+    pub method: Method,
+    /// Path of the request relative to the base url, a template
+    pub path: String,
+    /// JSON body of the request, no body is sent if absent
+    #[serde(default)]
+    pub request_template: Option<serde_json::Value>,
+    /// Path of the status of the resource in the response
+    pub status_path: String,
+    /// Statuses of the resource by the status reported by the connector
+    pub status_map: HashMap<String, S>,
+    /// Path of the identifier of the resource in the response, the identifier of the request is
+    /// kept if absent
+    #[serde(default)]
+    pub id_path: Option<String>,
+    /// Path of the url the customer is redirected to, for payments requiring customer action
+    #[serde(default)]
+    pub redirect_url_path: Option<String>,
+}
+
+impl<S> DeclarativeFlowSpec<S> {
+    /// Validates that the flow can be mapped, reporting the first empty field
+    fn validate(&self) -> Result<(), String> {
+        if self.path.is_empty() {
+            Err("path".to_string())
+        } else if self.status_path.is_empty() {
+            Err("status_path".to_string())
+        } else if self.status_map.is_empty() {
+            Err("status_map".to_string())
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -107,6 +107,15 @@ pub trait ConnectorIntegrationV2<Flow, ResourceCommonData, Req, Resp>:
         ))
     }
 
+    /// header carrying the idempotency key of the request, for connectors which deduplicate
+    /// requests with the same key
+    fn get_idempotency_header(
+        &self,
+        _req: &RouterDataV2<Flow, ResourceCommonData, Req, Resp>,
+    ) -> CustomResult<Option<(String, Maskable<String>)>, errors::ConnectorError> {
+        Ok(None)
+    }
+
     /// accepts the raw api response and decodes it
     fn handle_response_v2(
         &self,
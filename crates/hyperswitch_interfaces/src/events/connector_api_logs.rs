@@ -25,6 +25,7 @@ pub struct ConnectorEvent {
     refund_id: Option<String>,
     dispute_id: Option<String>,
     status_code: u16,
+    connector_request_id: Option<String>,
 }
 
 impl ConnectorEvent {
@@ -66,6 +67,7 @@ impl ConnectorEvent {
             refund_id,
             dispute_id,
             status_code,
+            connector_request_id: None,
         }
     }
 
@@ -89,6 +91,11 @@ impl ConnectorEvent {
         }
     }
 
+    /// fn set_connector_request_id
+    pub fn set_connector_request_id(&mut self, connector_request_id: Option<String>) {
+        self.connector_request_id = connector_request_id;
+    }
+
     /// fn set_error
     pub fn set_error(&mut self, error: serde_json::Value) {
         self.error = Some(error.to_string());
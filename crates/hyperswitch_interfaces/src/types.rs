@@ -3,6 +3,8 @@ use hyperswitch_domain_models::{
     router_data::AccessToken,
     router_flow_types::{
         access_token_auth::AccessTokenAuth,
+        account_balance::AccountBalance,
+        account_update::AccountUpdate,
         dispute::{Accept, Defend, Evidence},
         files::{Retrieve, Upload},
         mandate_revoke::MandateRevoke,
@@ -16,8 +18,9 @@ use hyperswitch_domain_models::{
         webhooks::VerifyWebhookSource,
     },
     router_request_types::{
-        AcceptDisputeRequestData, AccessTokenRequestData, AuthorizeSessionTokenData,
-        CompleteAuthorizeData, ConnectorCustomerData, DefendDisputeRequestData,
+        AcceptDisputeRequestData, AccessTokenRequestData, AccountBalanceRequestData,
+        AccountUpdateRequestData, AuthorizeSessionTokenData, CompleteAuthorizeData,
+        ConnectorCustomerData, DefendDisputeRequestData, GiftCardBalanceCheckRequestData,
         MandateRevokeRequestData, PaymentMethodTokenizationData, PaymentsAuthorizeData,
         PaymentsCancelData, PaymentsCaptureData, PaymentsIncrementalAuthorizationData,
         PaymentsPostProcessingData, PaymentsPostSessionTokensData, PaymentsPreProcessingData,
@@ -26,7 +29,8 @@ use hyperswitch_domain_models::{
         SubmitEvidenceRequestData, UploadFileRequestData, VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        AcceptDisputeResponse, DefendDisputeResponse, MandateRevokeResponseData,
+        AcceptDisputeResponse, AccountBalanceResponseData, AccountUpdateResponseData,
+        DefendDisputeResponse, GiftCardBalanceCheckResponseData, MandateRevokeResponseData,
         PaymentsResponseData, RefundsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
         TaxCalculationResponseData, UploadFileResponse, VerifyWebhookSourceResponseData,
     },
@@ -92,9 +96,12 @@ pub type PaymentsPreAuthorizeType = dyn ConnectorIntegration<
 /// Type alias for `ConnectorIntegration<InitPayment, PaymentsAuthorizeData, PaymentsResponseData>`
 pub type PaymentsInitType =
     dyn ConnectorIntegration<InitPayment, PaymentsAuthorizeData, PaymentsResponseData>;
-/// Type alias for `ConnectorIntegration<Balance, PaymentsAuthorizeData, PaymentsResponseData`
-pub type PaymentsBalanceType =
-    dyn ConnectorIntegration<Balance, PaymentsAuthorizeData, PaymentsResponseData>;
+/// Type alias for `ConnectorIntegration<Balance, GiftCardBalanceCheckRequestData, GiftCardBalanceCheckResponseData>`
+pub type PaymentsBalanceType = dyn ConnectorIntegration<
+    Balance,
+    GiftCardBalanceCheckRequestData,
+    GiftCardBalanceCheckResponseData,
+>;
 /// Type alias for `PaymentsSyncType = dyn ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData>`
 pub type PaymentsSyncType = dyn ConnectorIntegration<PSync, PaymentsSyncData, PaymentsResponseData>;
 /// Type alias for `ConnectorIntegration<Capture, PaymentsCaptureData, PaymentsResponseData>`
@@ -170,6 +177,14 @@ pub type VerifyWebhookSourceType = dyn ConnectorIntegration<
     VerifyWebhookSourceResponseData,
 >;
 
+/// Type alias for `ConnectorIntegration<AccountBalance, AccountBalanceRequestData, AccountBalanceResponseData>`
+pub type AccountBalanceType =
+    dyn ConnectorIntegration<AccountBalance, AccountBalanceRequestData, AccountBalanceResponseData>;
+
+/// Type alias for `ConnectorIntegration<AccountUpdate, AccountUpdateRequestData, AccountUpdateResponseData>`
+pub type AccountUpdateType =
+    dyn ConnectorIntegration<AccountUpdate, AccountUpdateRequestData, AccountUpdateResponseData>;
+
 /// Type alias for `ConnectorIntegration<Evidence, SubmitEvidenceRequestData, SubmitEvidenceResponse>`
 pub type SubmitEvidenceType =
     dyn ConnectorIntegration<Evidence, SubmitEvidenceRequestData, SubmitEvidenceResponse>;
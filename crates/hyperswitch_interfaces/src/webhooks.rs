@@ -237,4 +237,26 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
     > {
         Ok(None)
     }
+
+    /// fn get_webhook_timestamp
+    ///
+    /// Returns the UNIX timestamp (in seconds) at which the webhook was signed, for connectors
+    /// whose signature scheme includes a timestamp. Used to reject replays of old webhooks.
+    fn get_webhook_timestamp(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<i64>, errors::ConnectorError> {
+        Ok(None)
+    }
+
+    /// fn get_webhook_nonce
+    ///
+    /// Returns the nonce (or salt) included in the signature of the webhook, for connectors whose
+    /// signature scheme includes one. Webhooks reusing a previously seen nonce are rejected.
+    fn get_webhook_nonce(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        Ok(None)
+    }
 }
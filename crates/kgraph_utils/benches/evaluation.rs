@@ -41,6 +41,8 @@ fn build_test_data(
                 accepted_countries: None,
                 minimum_amount: Some(MinorUnit::new(10)),
                 maximum_amount: Some(MinorUnit::new(1000)),
+                currency_amount_limits: None,
+                amount_limits_enforced: false,
                 recurring_enabled: true,
                 installment_payment_enabled: true,
             });
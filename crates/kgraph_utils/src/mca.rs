@@ -736,6 +736,8 @@ mod tests {
                         accepted_countries: None,
                         minimum_amount: Some(MinorUnit::new(10)),
                         maximum_amount: Some(MinorUnit::new(1000)),
+                        currency_amount_limits: None,
+                        amount_limits_enforced: false,
                         recurring_enabled: true,
                         installment_payment_enabled: true,
                     },
@@ -752,6 +754,8 @@ mod tests {
                         accepted_countries: None,
                         minimum_amount: Some(MinorUnit::new(10)),
                         maximum_amount: Some(MinorUnit::new(1000)),
+                        currency_amount_limits: None,
+                        amount_limits_enforced: false,
                         recurring_enabled: true,
                         installment_payment_enabled: true,
                     },
@@ -796,6 +800,8 @@ mod tests {
                         accepted_countries: None,
                         minimum_amount: Some(MinorUnit::new(10)),
                         maximum_amount: Some(MinorUnit::new(1000)),
+                        currency_amount_limits: None,
+                        amount_limits_enforced: false,
                         recurring_enabled: true,
                         installment_payment_enabled: true,
                     },
@@ -812,6 +818,8 @@ mod tests {
                         accepted_countries: None,
                         minimum_amount: Some(MinorUnit::new(10)),
                         maximum_amount: Some(MinorUnit::new(1000)),
+                        currency_amount_limits: None,
+                        amount_limits_enforced: false,
                         recurring_enabled: true,
                         installment_payment_enabled: true,
                     },
@@ -0,0 +1,104 @@
+use utoipa::openapi::{
+    schema::{Discriminator, Schema},
+    OpenApi, Ref, RefOr,
+};
+
+/// Completes the discriminators of internally tagged enums.
+///
+/// The variants of such enums are generated inline and the discriminator only carries the name
+/// of the tag property. The variants are moved into their own components and referenced from the
+/// discriminator mapping, so that generated clients can deserialize them into typed models
+/// instead of trying every variant in turn.
+pub(crate) struct DiscriminatedUnionsAddon;
+
+impl utoipa::Modify for DiscriminatedUnionsAddon {
+    fn modify(&self, openapi: &mut OpenApi) {
+        let Some(components) = openapi.components.as_mut() else {
+            return;
+        };
+
+        let discriminated_unions = components
+            .schemas
+            .iter()
+            .filter_map(|(schema_name, schema)| match schema {
+                RefOr::T(Schema::OneOf(one_of)) => one_of
+                    .discriminator
+                    .as_ref()
+                    .filter(|discriminator| discriminator.mapping.is_empty())
+                    .map(|discriminator| {
+                        (schema_name.clone(), discriminator.property_name.clone())
+                    }),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        for (schema_name, property_name) in discriminated_unions {
+            let Some(RefOr::T(Schema::OneOf(mut one_of))) = components.schemas.remove(&schema_name)
+            else {
+                continue;
+            };
+
+            let tag_values = one_of
+                .items
+                .iter()
+                .map(|variant| get_tag_value(variant, &property_name))
+                .collect::<Option<Vec<_>>>();
+
+            // Leave the schema untouched if any of the variants can't be told apart
+            if let Some(tag_values) = tag_values {
+                let mut discriminator = Discriminator::new(property_name);
+                let variants = std::mem::take(&mut one_of.items);
+
+                for (tag_value, variant) in tag_values.into_iter().zip(variants) {
+                    let variant_schema_name =
+                        format!("{schema_name}{}", to_pascal_case(&tag_value));
+                    let variant_ref = Ref::from_schema_name(&variant_schema_name);
+
+                    discriminator
+                        .mapping
+                        .insert(tag_value, variant_ref.ref_location.clone());
+                    components.schemas.insert(variant_schema_name, variant);
+                    one_of.items.push(RefOr::Ref(variant_ref));
+                }
+
+                one_of.discriminator = Some(discriminator);
+            }
+
+            components
+                .schemas
+                .insert(schema_name, RefOr::T(Schema::OneOf(one_of)));
+        }
+    }
+}
+
+/// Finds the value of the tag property of an enum variant, which is present either on the
+/// variant object itself or on one of the schemas it is composed of
+fn get_tag_value(variant: &RefOr<Schema>, property_name: &str) -> Option<String> {
+    match variant {
+        RefOr::T(Schema::Object(object)) => match object.properties.get(property_name) {
+            Some(RefOr::T(Schema::Object(property))) => match property.enum_values.as_deref() {
+                Some([value]) => value.as_str().map(ToString::to_string),
+                _ => None,
+            },
+            _ => None,
+        },
+        RefOr::T(Schema::AllOf(all_of)) => all_of
+            .items
+            .iter()
+            .find_map(|item| get_tag_value(item, property_name)),
+        _ => None,
+    }
+}
+
+fn to_pascal_case(value: &str) -> String {
+    value
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
@@ -1,3 +1,5 @@
+#[cfg(any(feature = "v1", feature = "v2"))]
+mod discriminated_unions;
 #[cfg(feature = "v1")]
 mod openapi;
 #[cfg(feature = "v2")]
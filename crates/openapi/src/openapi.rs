@@ -1,4 +1,4 @@
-use crate::routes;
+use crate::{discriminated_unions::DiscriminatedUnionsAddon, routes};
 
 #[derive(utoipa::OpenApi)]
 #[openapi(
@@ -75,9 +75,12 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_confirm,
         routes::payments::payments_retrieve,
         routes::payments::payments_capture,
+        routes::payments::payments_shipment_capture,
+        routes::payments::payments_shipment_list,
         routes::payments::payments_connector_session,
         routes::payments::payments_cancel,
         routes::payments::payments_list,
+        routes::payments::import_external_payments,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
         routes::payments::payments_external_authentication,
@@ -118,6 +121,7 @@ Never share your secret api keys. Keep them guarded and secure.
         // Routes for mandates
         routes::mandates::get_mandate,
         routes::mandates::revoke_mandate,
+        routes::mandates::schedule_mandate_debit,
         routes::mandates::customers_mandates_list,
 
         //Routes for customers
@@ -136,6 +140,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payment_method::payment_method_retrieve_api,
         routes::payment_method::payment_method_update_api,
         routes::payment_method::payment_method_delete_api,
+        routes::payment_method::retrieve_pm_collect_link_api,
 
         // Routes for Profile
         routes::profile::profile_create,
@@ -166,6 +171,10 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::blocklist::list_blocked_payment_methods,
         routes::blocklist::add_entry_to_blocklist,
         routes::blocklist::toggle_blocklist_guard,
+        routes::blocklist::sync_blocklist,
+        routes::blocklist::retrieve_blocklist_sync_status,
+        routes::blocklist::rollback_blocklist_sync,
+        routes::blocklist::update_blocklist_sync_source,
 
         // Routes for payouts
         routes::payouts::payouts_create,
@@ -198,6 +207,7 @@ Never share your secret api keys. Keep them guarded and secure.
         common_utils::types::TimeRange,
         common_utils::link_utils::GenericLinkUiConfig,
         common_utils::link_utils::EnabledPaymentMethod,
+        common_utils::link_utils::PaymentMethodCollectStatus,
         common_utils::payout_method_utils::AdditionalPayoutMethodData,
         common_utils::payout_method_utils::CardAdditionalData,
         common_utils::payout_method_utils::BankAdditionalData,
@@ -221,6 +231,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantAccountDeleteResponse,
         api_models::admin::MerchantConnectorDeleteResponse,
         api_models::admin::MerchantConnectorResponse,
+        api_models::admin::ConnectorCredentialStatus,
+        api_models::admin::ConnectorCredentialHealthResponse,
         api_models::admin::MerchantConnectorListResponse,
         api_models::admin::AuthenticationConnectorDetails,
         api_models::admin::ExtendedCardInfoConfig,
@@ -243,6 +255,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::CardDetail,
         api_models::payment_methods::CardDetailUpdate,
         api_models::payment_methods::RequestPaymentMethodTypes,
+        api_models::payment_methods::CurrencyAmountLimits,
         api_models::poll::PollResponse,
         api_models::poll::PollStatus,
         api_models::customers::CustomerResponse,
@@ -304,6 +317,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::PaymentLinkTransactionDetails,
         api_models::admin::TransactionDetailsUiConfiguration,
         api_models::disputes::DisputeResponse,
+        api_models::disputes::DisputeFinancialImpact,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
@@ -354,12 +368,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentProcessingDetails,
         api_models::payments::PaymentMethodDataResponseWithBilling,
         api_models::payments::PaymentMethodDataResponse,
+        api_models::payments::EmptyPaymentMethodDataResponse,
         api_models::payments::CardResponse,
         api_models::payments::PaylaterResponse,
         api_models::payments::KlarnaSdkPaymentMethodResponse,
         api_models::payments::SwishQrData,
         api_models::payments::AirwallexData,
         api_models::payments::NoonData,
+        api_models::payments::ConnectorPassthroughData,
         api_models::payments::OrderDetails,
         api_models::payments::OrderDetailsWithAmount,
         api_models::payments::NextActionType,
@@ -383,10 +399,54 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsUpdateRequest,
         api_models::payments::PaymentsConfirmRequest,
         api_models::payments::PaymentsResponse,
+        api_models::receipts::PaymentReceiptUrls,
+        api_models::receipts::ReceiptFormat,
+        api_models::connector_balance::ConnectorBalanceResponse,
+        api_models::connector_balance::CurrencyBalance,
+        api_models::invoices::InvoiceLineItem,
+        api_models::invoices::InvoiceCreateRequest,
+        api_models::invoices::InvoiceLinkPaymentsRequest,
+        api_models::invoices::InvoiceListConstraints,
+        api_models::invoices::InvoiceResponse,
+        api_models::invoices::InvoiceListResponse,
+        api_models::webhook_endpoints::WebhookEndpointCreateRequest,
+        api_models::webhook_endpoints::WebhookEndpointUpdateRequest,
+        api_models::webhook_endpoints::WebhookEndpointResponse,
+        api_models::webhook_endpoints::WebhookEndpointDeliveryStats,
+        api_models::webhook_endpoints::WebhookEndpointListResponse,
+        api_models::payment_descriptors::PaymentDescriptorConfigRequest,
+        api_models::payment_descriptors::PaymentDescriptorConfigResponse,
+        api_models::payment_descriptors::SoftDescriptorPreviewRequest,
+        api_models::payment_descriptors::SoftDescriptorPreviewResponse,
+        api_models::payment_descriptors::SoftDescriptorNetworkPreview,
+        api_models::experiments::ExperimentArm,
+        api_models::experiments::ExperimentStatus,
+        api_models::experiments::ExperimentVariants,
+        api_models::experiments::ExperimentCreateRequest,
+        api_models::experiments::ExperimentPromoteRequest,
+        api_models::experiments::ExperimentResponse,
+        api_models::experiments::ExperimentListResponse,
+        api_models::experiments::ExperimentArmMetrics,
+        api_models::experiments::ExperimentResults,
+        api_models::experiments::ExperimentAssignment,
+        api_models::webhook_endpoints::WebhookEndpointMetricsResponse,
+        api_models::webhook_endpoints::WebhookEndpointDeliveryAttempt,
+        api_models::webhook_endpoints::WebhookEndpointDeliveryAttemptsListConstraints,
+        api_models::webhook_endpoints::WebhookEndpointDeliveryAttemptsListResponse,
+        api_models::payments::PaymentsConfirmAsyncResponse,
+        api_models::payments::ExternalPaymentsImportRequest,
+        api_models::payments::ExternalPaymentRecord,
+        api_models::payments::ExternalPaymentImportStatus,
+        api_models::payments::ExternalPaymentImportResult,
+        api_models::payments::ExternalPaymentsImportResponse,
         api_models::payments::PaymentsCreateResponseOpenApi,
         api_models::payments::PaymentRetrieveBody,
         api_models::payments::PaymentsRetrieveRequest,
         api_models::payments::PaymentsCaptureRequest,
+        api_models::payments::PaymentsShipmentCaptureRequest,
+        api_models::payments::ShipmentLineItem,
+        api_models::payments::PaymentShipmentResponse,
+        api_models::payments::PaymentShipmentListResponse,
         api_models::payments::PaymentsSessionRequest,
         api_models::payments::PaymentsSessionResponse,
         api_models::payments::PazeWalletData,
@@ -474,6 +534,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::SdkInformation,
         api_models::payments::DeviceChannel,
         api_models::payments::ThreeDsCompletionIndicator,
+        api_models::payments::ThreeRiIndicator,
         api_models::payments::MifinityData,
         api_models::enums::TransactionStatus,
         api_models::payments::BrowserInformation,
@@ -491,12 +552,18 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::SurchargePercentage,
         api_models::payment_methods::PaymentMethodCollectLinkRequest,
         api_models::payment_methods::PaymentMethodCollectLinkResponse,
+        api_models::payment_methods::PaymentMethodTokenResponse,
+        api_models::payment_methods::PaymentMethodAccountUpdateResponse,
+        api_models::payment_methods::CardAccountUpdateStatus,
+        api_models::payment_methods::PaymentMethodCollectLinkSubmitRequest,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
         api_models::payments::AmountFilter,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
+        api_models::mandates::MandateDebitScheduleRequest,
+        api_models::mandates::MandateDebitResponse,
         api_models::mandates::RecurringDetails,
         api_models::mandates::NetworkTransactionIdAndCardDetails,
         api_models::mandates::ProcessorPaymentToken,
@@ -542,6 +609,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhooks::OutgoingWebhook,
         api_models::webhooks::OutgoingWebhookContent,
         api_models::enums::EventClass,
+        api_models::enums::InvoiceStatus,
         api_models::enums::EventType,
         api_models::enums::DecoupledAuthenticationType,
         api_models::enums::AuthenticationStatus,
@@ -568,6 +636,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::RoutingDictionaryRecord,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
+        api_models::routing::OrchestrationDecisionReason,
+        api_models::routing::PaymentOrchestrationEventResponse,
         api_models::routing::SuccessBasedRoutingFeatures,
         api_models::routing::LinkedRoutingConfigRetrieveResponse,
         api_models::routing::RoutingRetrieveResponse,
@@ -597,12 +667,20 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::blocklist::BlocklistResponse,
         api_models::blocklist::ToggleBlocklistResponse,
         api_models::blocklist::ListBlocklistQuery,
+        api_models::blocklist::BlocklistSyncRequest,
+        api_models::blocklist::BlocklistSyncRollbackRequest,
+        api_models::blocklist::BlocklistSyncSourceRequest,
+        api_models::blocklist::BlocklistSyncSourceResponse,
+        api_models::blocklist::BlocklistSyncVersionResponse,
+        api_models::blocklist::BlocklistSyncStatusResponse,
         api_models::enums::BlocklistDataKind,
         api_models::webhook_events::EventListItemResponse,
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::enums::WebhookDeliveryAttempt,
+        api_models::enums::OutgoingWebhookFormat,
+        api_models::enums::OutgoingWebhookSchemaVersion,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
         api_models::payments::PaymentChargeRequest,
@@ -648,7 +726,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsPostSessionTokensRequest,
         api_models::payments::PaymentsPostSessionTokensResponse,
     )),
-    modifiers(&SecurityAddon)
+    modifiers(&SecurityAddon, &DiscriminatedUnionsAddon)
 )]
 // Bypass clippy lint for not being constructed
 #[allow(dead_code)]
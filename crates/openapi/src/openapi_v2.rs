@@ -1,4 +1,4 @@
-use crate::routes;
+use crate::{discriminated_unions::DiscriminatedUnionsAddon, routes};
 
 #[derive(utoipa::OpenApi)]
 #[openapi(
@@ -161,6 +161,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantAccountDeleteResponse,
         api_models::admin::MerchantConnectorDeleteResponse,
         api_models::admin::MerchantConnectorResponse,
+        api_models::admin::ConnectorCredentialStatus,
+        api_models::admin::ConnectorCredentialHealthResponse,
         api_models::admin::MerchantConnectorListResponse,
         api_models::admin::AuthenticationConnectorDetails,
         api_models::admin::ExtendedCardInfoConfig,
@@ -183,6 +185,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::CardDetail,
         api_models::payment_methods::CardDetailUpdate,
         api_models::payment_methods::RequestPaymentMethodTypes,
+        api_models::payment_methods::CurrencyAmountLimits,
         api_models::poll::PollResponse,
         api_models::poll::PollStatus,
         api_models::customers::CustomerResponse,
@@ -246,6 +249,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::PaymentLinkTransactionDetails,
         api_models::admin::TransactionDetailsUiConfiguration,
         api_models::disputes::DisputeResponse,
+        api_models::disputes::DisputeFinancialImpact,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
@@ -296,12 +300,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentProcessingDetails,
         api_models::payments::PaymentMethodDataResponseWithBilling,
         api_models::payments::PaymentMethodDataResponse,
+        api_models::payments::EmptyPaymentMethodDataResponse,
         api_models::payments::CardResponse,
         api_models::payments::PaylaterResponse,
         api_models::payments::KlarnaSdkPaymentMethodResponse,
         api_models::payments::SwishQrData,
         api_models::payments::AirwallexData,
         api_models::payments::NoonData,
+        api_models::payments::ConnectorPassthroughData,
         api_models::payments::OrderDetails,
         api_models::payments::OrderDetailsWithAmount,
         api_models::payments::NextActionType,
@@ -321,10 +327,20 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::CardToken,
         api_models::payments::CustomerAcceptance,
         api_models::payments::PaymentsResponse,
+        api_models::receipts::PaymentReceiptUrls,
+        api_models::receipts::ReceiptFormat,
+        api_models::invoices::InvoiceLineItem,
+        api_models::invoices::InvoiceCreateRequest,
+        api_models::invoices::InvoiceLinkPaymentsRequest,
+        api_models::invoices::InvoiceListConstraints,
+        api_models::invoices::InvoiceResponse,
+        api_models::invoices::InvoiceListResponse,
         api_models::payments::PaymentsCreateResponseOpenApi,
         api_models::payments::PaymentRetrieveBody,
         api_models::payments::PaymentsRetrieveRequest,
         api_models::payments::PaymentsCaptureRequest,
+        api_models::payments::ShipmentLineItem,
+        api_models::payments::PaymentShipmentResponse,
         api_models::payments::PaymentsSessionRequest,
         api_models::payments::PaymentsSessionResponse,
         api_models::payments::PaymentsCreateIntentRequest,
@@ -415,6 +431,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::SdkInformation,
         api_models::payments::DeviceChannel,
         api_models::payments::ThreeDsCompletionIndicator,
+        api_models::payments::ThreeRiIndicator,
         api_models::payments::MifinityData,
         api_models::enums::TransactionStatus,
         api_models::payments::PaymentCreatePaymentLinkConfig,
@@ -434,6 +451,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::SurchargePercentage,
         api_models::payment_methods::PaymentMethodCollectLinkRequest,
         api_models::payment_methods::PaymentMethodCollectLinkResponse,
+        api_models::payment_methods::PaymentMethodTokenResponse,
+        api_models::payment_methods::PaymentMethodAccountUpdateResponse,
+        api_models::payment_methods::CardAccountUpdateStatus,
         api_models::payments::PaymentsRetrieveResponse,
         api_models::refunds::RefundListRequest,
         api_models::refunds::RefundListResponse,
@@ -441,6 +461,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
+        api_models::mandates::MandateDebitScheduleRequest,
+        api_models::mandates::MandateDebitResponse,
         api_models::mandates::RecurringDetails,
         api_models::mandates::ProcessorPaymentToken,
         api_models::ephemeral_key::EphemeralKeyCreateResponse,
@@ -489,6 +511,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhooks::OutgoingWebhook,
         api_models::webhooks::OutgoingWebhookContent,
         api_models::enums::EventClass,
+        api_models::enums::InvoiceStatus,
         api_models::enums::EventType,
         api_models::enums::DecoupledAuthenticationType,
         api_models::enums::AuthenticationStatus,
@@ -515,6 +538,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::RoutingDictionaryRecord,
         api_models::routing::RoutingKind,
         api_models::routing::RoutableConnectorChoice,
+        api_models::routing::OrchestrationDecisionReason,
+        api_models::routing::PaymentOrchestrationEventResponse,
         api_models::routing::LinkedRoutingConfigRetrieveResponse,
         api_models::routing::RoutingRetrieveResponse,
         api_models::routing::ProfileDefaultRoutingConfig,
@@ -548,6 +573,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::enums::WebhookDeliveryAttempt,
+        api_models::enums::OutgoingWebhookFormat,
+        api_models::enums::OutgoingWebhookSchemaVersion,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
         api_models::payments::PaymentChargeRequest,
@@ -595,7 +622,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::ConfirmIntentAmountDetailsResponse,
         routes::payments::ForceSync,
     )),
-    modifiers(&SecurityAddon)
+    modifiers(&SecurityAddon, &DiscriminatedUnionsAddon)
 )]
 // Bypass clippy lint for not being constructed
 #[allow(dead_code)]
@@ -57,3 +57,58 @@ pub async fn remove_entry_from_blocklist() {}
     security(("api_key" = []))
 )]
 pub async fn list_blocked_payment_methods() {}
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/sync",
+    request_body = BlocklistSyncRequest,
+    responses(
+        (status = 200, description = "Blocklist synchronized", body = BlocklistSyncVersionResponse),
+        (status = 400, description = "Invalid Data"),
+        (status = 412, description = "Blocklist synchronized since the base version")
+    ),
+    tag = "Blocklist",
+    operation_id = "Synchronize the blocklist",
+    security(("api_key" = []))
+)]
+pub async fn sync_blocklist() {}
+
+#[utoipa::path(
+    get,
+    path = "/blocklist/sync",
+    responses(
+        (status = 200, description = "Blocklist synchronization status", body = BlocklistSyncStatusResponse)
+    ),
+    tag = "Blocklist",
+    operation_id = "Retrieve the synchronization status of the blocklist",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_blocklist_sync_status() {}
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/sync/rollback",
+    request_body = BlocklistSyncRollbackRequest,
+    responses(
+        (status = 200, description = "Blocklist rolled back", body = BlocklistSyncStatusResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Roll the blocklist back to a previous version",
+    security(("api_key" = []))
+)]
+pub async fn rollback_blocklist_sync() {}
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/sync/source",
+    request_body = BlocklistSyncSourceRequest,
+    responses(
+        (status = 200, description = "Blocklist source configured", body = BlocklistSyncSourceResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Configure the source of the blocklist",
+    security(("api_key" = []))
+)]
+pub async fn update_blocklist_sync_source() {}
@@ -36,6 +36,28 @@ pub async fn get_mandate() {}
 )]
 pub async fn revoke_mandate() {}
 
+/// Mandates - Schedule Debit
+///
+/// Schedules a debit on a mandate. The customer is notified of the debit ahead of it, and the
+/// debit is retried within the configured window if it fails.
+#[utoipa::path(
+    post,
+    path = "/mandates/{mandate_id}/debits",
+    params(
+        ("mandate_id" = String, Path, description = "The identifier for a mandate")
+    ),
+    request_body = MandateDebitScheduleRequest,
+    responses(
+        (status = 200, description = "The debit was scheduled successfully", body = MandateDebitResponse),
+        (status = 400, description = "The debit is not allowed on the mandate"),
+        (status = 404, description = "Mandate does not exist in our records")
+    ),
+    tag = "Mandates",
+    operation_id = "Schedule a Mandate Debit",
+    security(("api_key" = []))
+)]
+pub async fn schedule_mandate_debit() {}
+
 /// Mandates - List Mandates
 #[utoipa::path(
     get,
@@ -172,6 +172,26 @@ pub async fn payment_method_update_api() {}
 )]
 pub async fn payment_method_delete_api() {}
 
+/// Payment Method - Retrieve Collect Link
+///
+/// Retrieves a payment method collect link, along with the payment method saved through it once
+/// the customer has submitted their details.
+#[utoipa::path(
+    get,
+    path = "/payment_methods/collect/{collect_id}",
+    params (
+        ("collect_id" = String, Path, description = "The unique identifier for the payment method collect link"),
+    ),
+    responses(
+        (status = 200, description = "Payment method collect link retrieved", body = PaymentMethodCollectLinkResponse),
+        (status = 404, description = "Payment method collect link does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Retrieve a Payment method collect link",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_pm_collect_link_api() {}
+
 /// Payment Method - Set Default Payment Method for Customer
 ///
 /// Set the Payment Method as Default for the Customer.
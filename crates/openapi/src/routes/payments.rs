@@ -339,6 +339,7 @@ pub fn payments_update() {}
     ),
     responses(
         (status = 200, description = "Payment confirmed", body = PaymentsCreateResponseOpenApi),
+        (status = 202, description = "Payment confirmation queued for asynchronous processing", body = PaymentsConfirmAsyncResponse),
         (status = 400, description = "Missing mandatory fields")
     ),
     tag = "Payments",
@@ -381,6 +382,60 @@ pub fn payments_confirm() {}
 )]
 pub fn payments_capture() {}
 
+#[cfg(feature = "v1")]
+/// Payments - Capture a shipment
+///
+/// To capture the amount of a shipment of the order, for payments authorized with the `manual_multiple` capture method. The shipped quantities are validated against the order details of the payment.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/shipments",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body (
+        content = PaymentsShipmentCaptureRequest,
+        examples(
+            (
+                "Capture the amount of a shipment" = (
+                    value = json!({
+                        "shipment_reference": "shipment_1",
+                        "amount_to_capture": 2500,
+                        "line_items": [{"product_id": "shirt_blue_m", "quantity": 1}]
+                    })
+                )
+            ),
+        )
+    ),
+    responses(
+        (status = 200, description = "Shipment captured", body = PaymentShipmentResponse),
+        (status = 400, description = "The shipment exceeds the remaining authorization or order lines")
+    ),
+    tag = "Payments",
+    operation_id = "Capture a Shipment of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_shipment_capture() {}
+
+#[cfg(feature = "v1")]
+/// Payments - List shipments
+///
+/// To list the shipments captured on a payment
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/shipments",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Shipments of the payment", body = PaymentShipmentListResponse),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "List the Shipments of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_shipment_list() {}
+
 #[cfg(feature = "v1")]
 /// Payments - Session token
 ///
@@ -481,6 +536,23 @@ pub fn payments_cancel() {}
 )]
 pub fn payments_list() {}
 
+/// Payments - Import External Payments
+///
+/// To import payments processed outside of hyperswitch, for example at a point of sale. The imported payments are listed along with other payments, with `external` as their connector.
+#[utoipa::path(
+    post,
+    path = "/payments/external/import",
+    request_body = ExternalPaymentsImportRequest,
+    responses(
+        (status = 200, description = "External payments imported", body = ExternalPaymentsImportResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payments",
+    operation_id = "Import External Payments",
+    security(("api_key" = []))
+)]
+pub fn import_external_payments() {}
+
 /// Profile level Payments - List
 ///
 /// To list the payments
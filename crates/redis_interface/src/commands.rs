@@ -15,7 +15,10 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, ListInterface, SetsInterface, StreamsInterface},
+    interfaces::{
+        HashesInterface, KeysInterface, ListInterface, LuaInterface, SetsInterface,
+        StreamsInterface,
+    },
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
@@ -31,6 +34,12 @@ use crate::{
     types::{DelReply, HsetnxReply, MsetnxReply, RedisEntryId, SaddReply, SetnxReply},
 };
 
+const INCREMENT_KEY_BY_WITH_EXPIRY_SCRIPT: &str = r#"
+local value = redis.call("INCRBY", KEYS[1], ARGV[1])
+redis.call("EXPIRE", KEYS[1], ARGV[2])
+return value
+"#;
+
 impl super::RedisConnectionPool {
     pub fn add_prefix(&self, key: &str) -> String {
         if self.key_prefix.is_empty() {
@@ -202,6 +211,18 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::JsonDeserializationFailed)
     }
 
+    /// Fetches the value of the key and deletes the key atomically
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_and_delete_key<V>(&self, key: &str) -> CustomResult<V, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        self.pool
+            .getdel(self.add_prefix(key))
+            .await
+            .change_context(errors::RedisError::GetFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn delete_key(&self, key: &str) -> CustomResult<DelReply, errors::RedisError> {
         self.pool
@@ -413,6 +434,32 @@ impl super::RedisConnectionPool {
         Ok(values_after_increment)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key_with_expiry(
+        &self,
+        key: &str,
+        seconds: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        self.increment_key_by_with_expiry(key, 1, seconds).await
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_key_by_with_expiry(
+        &self,
+        key: &str,
+        increment: i64,
+        seconds: i64,
+    ) -> CustomResult<i64, errors::RedisError> {
+        // The expiry is set by the same script, so that the key never outlives it
+        self.evaluate_redis_script(
+            INCREMENT_KEY_BY_WITH_EXPIRY_SCRIPT,
+            vec![key.to_string()],
+            vec![increment.to_string(), seconds.to_string()],
+        )
+        .await
+        .change_context(errors::RedisError::IncrementKeyFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn hscan(
         &self,
@@ -763,6 +810,46 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::PopListElementsFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn trim_list(
+        &self,
+        key: &str,
+        start: i64,
+        stop: i64,
+    ) -> CustomResult<(), errors::RedisError> {
+        self.pool
+            .ltrim(self.add_prefix(key), start, stop)
+            .await
+            .change_context(errors::RedisError::TrimListFailed)
+    }
+
+    //                                              Lua scripts
+
+    /// Evaluates the Lua script atomically, the keys are prefixed before being passed to the
+    /// script
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn evaluate_redis_script<V, T>(
+        &self,
+        lua_script: &'static str,
+        keys: Vec<String>,
+        values: V,
+    ) -> CustomResult<T, errors::RedisError>
+    where
+        V: TryInto<MultipleValues> + Debug + Send + Sync,
+        V::Error: Into<fred::error::RedisError> + Send + Sync,
+        T: FromRedis + Send,
+    {
+        let keys = keys
+            .iter()
+            .map(|key| self.add_prefix(key))
+            .collect::<Vec<_>>();
+
+        self.pool
+            .eval(lua_script, keys, values)
+            .await
+            .change_context(errors::RedisError::ScriptExecutionFailed)
+    }
+
     //                                              Consumer Group API
 
     #[instrument(level = "DEBUG", skip(self))]
@@ -76,6 +76,12 @@ pub enum RedisError {
     GetListLengthFailed,
     #[error("Failed to pop list elements in Redis")]
     PopListElementsFailed,
+    #[error("Failed to trim list in Redis")]
+    TrimListFailed,
+    #[error("Failed to execute the Lua script in Redis")]
+    ScriptExecutionFailed,
     #[error("Failed to increment hash field in Redis")]
     IncrementHashFieldFailed,
+    #[error("Failed to increment key in Redis")]
+    IncrementKeyFailed,
 }
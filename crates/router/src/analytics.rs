@@ -155,6 +155,14 @@ pub mod routes {
                             web::resource("metrics/sankey")
                                 .route(web::post().to(get_merchant_sankey)),
                         )
+                        .service(
+                            web::resource("metrics/checkout_funnel")
+                                .route(web::post().to(get_merchant_checkout_funnel)),
+                        )
+                        .service(
+                            web::resource("capacity/payment_throughput")
+                                .route(web::post().to(get_payment_throughput)),
+                        )
                         .service(
                             web::scope("/merchant")
                                 .service(
@@ -207,6 +215,10 @@ pub mod routes {
                                 .service(
                                     web::resource("metrics/sankey")
                                         .route(web::post().to(get_merchant_sankey)),
+                                )
+                                .service(
+                                    web::resource("metrics/checkout_funnel")
+                                        .route(web::post().to(get_merchant_checkout_funnel)),
                                 ),
                         )
                         .service(
@@ -253,6 +265,10 @@ pub mod routes {
                                 .service(
                                     web::resource("metrics/sankey")
                                         .route(web::post().to(get_org_sankey)),
+                                )
+                                .service(
+                                    web::resource("metrics/checkout_funnel")
+                                        .route(web::post().to(get_org_checkout_funnel)),
                                 ),
                         )
                         .service(
@@ -315,6 +331,10 @@ pub mod routes {
                                 .service(
                                     web::resource("metrics/sankey")
                                         .route(web::post().to(get_profile_sankey)),
+                                )
+                                .service(
+                                    web::resource("metrics/checkout_funnel")
+                                        .route(web::post().to(get_profile_checkout_funnel)),
                                 ),
                         ),
                 )
@@ -2376,4 +2396,127 @@ pub mod routes {
         ))
         .await
     }
+
+    pub async fn get_merchant_checkout_funnel(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<TimeRange>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetCheckoutFunnel;
+        let payload = json_payload.into_inner();
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::payments::get_checkout_funnel(&state.pool, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_org_checkout_funnel(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<TimeRange>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetCheckoutFunnel;
+        let payload = json_payload.into_inner();
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let auth: AuthInfo = AuthInfo::OrgLevel {
+                    org_id: org_id.clone(),
+                };
+                analytics::payments::get_checkout_funnel(&state.pool, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::OrganizationAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_profile_checkout_funnel(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<TimeRange>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetCheckoutFunnel;
+        let payload = json_payload.into_inner();
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state: crate::routes::SessionState, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+                analytics::payments::get_checkout_funnel(&state.pool, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// Reports the TPS percentiles and growth trends of the merchants on each connector, for the
+    /// capacity planning of the platform and of the connector rate limits
+    pub async fn get_payment_throughput(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<
+            api_models::analytics::payment_throughput::GetPaymentThroughputRequest,
+        >,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentThroughput;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, _, req, _| async move {
+                analytics::payment_throughput::get_payment_throughput(&state.pool, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::AdminApiAuth,
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
 }
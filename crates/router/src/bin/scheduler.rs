@@ -40,6 +40,7 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
             services::proxy_bypass_urls(
                 conf.key_manager.get_inner(),
                 &conf.locker,
+                &conf.data_residency,
                 &conf.proxy.bypass_proxy_urls,
             ),
         )
@@ -329,6 +330,52 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PaymentMethodStatusUpdateWorkflow => Ok(Box::new(
                     workflows::payment_method_status_update::PaymentMethodStatusUpdateWorkflow,
                 )),
+                storage::ProcessTrackerRunner::PaymentsConfirmWorkflow => Ok(Box::new(
+                    workflows::payment_confirm::PaymentsConfirmWorkflow,
+                )),
+                storage::ProcessTrackerRunner::MerchantAccountPurgeWorkflow => Ok(Box::new(
+                    workflows::merchant_account_purge::MerchantAccountPurgeWorkflow,
+                )),
+                storage::ProcessTrackerRunner::InvoiceReminderWorkflow => Ok(Box::new(
+                    workflows::invoice_reminder::InvoiceReminderWorkflow,
+                )),
+                storage::ProcessTrackerRunner::MandateDebitWorkflow => {
+                    Ok(Box::new(workflows::mandate_debit::MandateDebitWorkflow))
+                }
+                storage::ProcessTrackerRunner::SandboxDataResetWorkflow => Ok(Box::new(
+                    workflows::sandbox_data_reset::SandboxDataResetWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ConnectorCredentialHealthWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::connector_credential_health::ConnectorCredentialHealthWorkflow,
+                        ))
+                    }
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run connector credential health workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::BlocklistSyncWorkflow => {
+                    Ok(Box::new(workflows::blocklist_sync::BlocklistSyncWorkflow))
+                }
+                storage::ProcessTrackerRunner::AccountUpdaterWorkflow => {
+                    #[cfg(not(feature = "payment_methods_v2"))]
+                    {
+                        Ok(Box::new(workflows::account_updater::AccountUpdaterWorkflow))
+                    }
+                    #[cfg(feature = "payment_methods_v2")]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run account updater workflow when payment_methods_v2 feature is enabled",
+                            )
+                    }
+                }
             }
         };
 
@@ -398,6 +445,11 @@ async fn start_scheduler(
         |state, tenant| {
             Arc::new(state.clone())
                 .get_session_state(tenant, || ProcessTrackerError::TenantNotFound.into())
+                .map(|mut session_state| {
+                    // Workflows run by the scheduler are never part of the live traffic
+                    session_state.request_lane = api::request_lane::RequestLane::Batch;
+                    session_state
+                })
         },
     )
     .await
@@ -1,2 +1,3 @@
+pub mod adyen;
 pub mod stripe;
 pub mod wrap;
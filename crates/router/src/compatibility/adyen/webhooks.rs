@@ -0,0 +1,359 @@
+use api_models::webhooks::{self as api};
+use base64::Engine;
+use common_utils::{consts::BASE64_ENGINE, crypto::SignMessage, ext_traits::Encode};
+use error_stack::ResultExt;
+use serde::Serialize;
+
+use crate::{
+    core::{
+        errors,
+        webhooks::types::{OutgoingWebhookPayloadWithSignature, OutgoingWebhookType},
+    },
+    services::request::Maskable,
+};
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenOutgoingWebhook {
+    live: String,
+    notification_items: Vec<AdyenNotificationItem>,
+}
+
+#[derive(Clone, Serialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+pub struct AdyenNotificationItem {
+    notification_request_item: AdyenNotificationRequestItem,
+}
+
+#[derive(Clone, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenNotificationRequestItem {
+    additional_data: AdyenNotificationAdditionalData,
+    amount: AdyenNotificationAmount,
+    event_code: &'static str,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    event_date: time::PrimitiveDateTime,
+    merchant_account_code: String,
+    merchant_reference: String,
+    original_reference: Option<String>,
+    payment_method: Option<String>,
+    psp_reference: String,
+    reason: Option<String>,
+    success: String,
+}
+
+#[derive(Clone, Default, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenNotificationAdditionalData {
+    hmac_signature: Option<String>,
+}
+
+#[derive(Clone, Default, Serialize, Debug)]
+pub struct AdyenNotificationAmount {
+    currency: String,
+    value: i64,
+}
+
+impl AdyenNotificationRequestItem {
+    fn get_signature_message(&self) -> String {
+        format!(
+            "{}:{}:{}:{}:{}:{}:{}:{}",
+            self.psp_reference,
+            self.original_reference.clone().unwrap_or_default(),
+            self.merchant_account_code,
+            self.merchant_reference,
+            self.amount.value,
+            self.amount.currency,
+            self.event_code,
+            self.success
+        )
+    }
+}
+
+impl OutgoingWebhookType for AdyenOutgoingWebhook {
+    fn get_outgoing_webhooks_signature(
+        &self,
+        payment_response_hash_key: Option<impl AsRef<[u8]>>,
+    ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
+        let payment_response_hash_key = payment_response_hash_key
+            .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
+            .attach_printable("For adyen compatibility payment_response_hash_key is mandatory")?;
+
+        // Adyen HMAC keys are hex encoded, such keys are decoded the same way adyen's libraries
+        // do so that existing verification code keeps working
+        let signing_key = hex::decode(payment_response_hash_key.as_ref())
+            .unwrap_or_else(|_| payment_response_hash_key.as_ref().to_vec());
+
+        let notification_items = self
+            .notification_items
+            .iter()
+            .cloned()
+            .map(|mut item| {
+                let signature = common_utils::crypto::HmacSha256::sign_message(
+                    &common_utils::crypto::HmacSha256,
+                    &signing_key,
+                    item.notification_request_item
+                        .get_signature_message()
+                        .as_bytes(),
+                )
+                .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+                .attach_printable("Failed to sign the message")?;
+                item.notification_request_item
+                    .additional_data
+                    .hmac_signature = Some(BASE64_ENGINE.encode(signature));
+                Ok(item)
+            })
+            .collect::<errors::CustomResult<Vec<_>, errors::WebhooksFlowError>>()?;
+
+        let webhook_signature_payload = Self {
+            live: self.live.clone(),
+            notification_items,
+        }
+        .encode_to_string_of_json()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+        .attach_printable("failed encoding outgoing webhook payload")?;
+
+        // Adyen carries the signature in the notification item itself rather than in a header
+        Ok(OutgoingWebhookPayloadWithSignature {
+            payload: webhook_signature_payload.into(),
+            signature: None,
+        })
+    }
+
+    fn add_webhook_header(_header: &mut Vec<(String, Maskable<String>)>, _signature: String) {}
+}
+
+/// Returns the adyen event code and the value of the `success` flag for an event
+fn get_adyen_event_code(event_type: api_models::enums::EventType) -> (&'static str, bool) {
+    match event_type {
+        api_models::enums::EventType::PaymentSucceeded
+        | api_models::enums::EventType::PaymentAuthorized => ("AUTHORISATION", true),
+        api_models::enums::EventType::PaymentFailed => ("AUTHORISATION", false),
+        api_models::enums::EventType::PaymentProcessing
+        | api_models::enums::EventType::ActionRequired => ("PENDING", true),
+        api_models::enums::EventType::PaymentCancelled => ("CANCELLATION", true),
+        api_models::enums::EventType::PaymentCaptured => ("CAPTURE", true),
+        api_models::enums::EventType::RefundSucceeded => ("REFUND", true),
+        api_models::enums::EventType::RefundFailed => ("REFUND", false),
+        api_models::enums::EventType::DisputeOpened => ("NOTIFICATION_OF_CHARGEBACK", true),
+        api_models::enums::EventType::DisputeChallenged => ("INFORMATION_SUPPLIED", true),
+        api_models::enums::EventType::DisputeExpired
+        | api_models::enums::EventType::DisputeAccepted
+        | api_models::enums::EventType::DisputeLost => ("CHARGEBACK", true),
+        api_models::enums::EventType::DisputeCancelled
+        | api_models::enums::EventType::DisputeWon => ("CHARGEBACK_REVERSED", true),
+        api_models::enums::EventType::MandateActive => ("RECURRING_CONTRACT", true),
+        api_models::enums::EventType::PayoutSuccess => ("PAYOUT_THIRDPARTY", true),
+        api_models::enums::EventType::PayoutFailed => ("PAYOUT_THIRDPARTY", false),
+        api_models::enums::EventType::PayoutCancelled => ("PAYOUT_DECLINE", true),
+        api_models::enums::EventType::PayoutExpired => ("PAYOUT_EXPIRE", true),
+        api_models::enums::EventType::PayoutReversed => ("PAIDOUT_REVERSED", true),
+
+        // the below are not really adyen compatible because adyen doesn't provide this
+        api_models::enums::EventType::MandateRevoked => ("RECURRING_CONTRACT", false),
+        api_models::enums::EventType::MandatePreDebitNotification => ("PREARRANGED_DEBIT", true),
+        api_models::enums::EventType::PayoutInitiated
+        | api_models::enums::EventType::PayoutProcessing => ("PENDING", true),
+        api_models::enums::EventType::InvoicePaid => ("INVOICE_PAID", true),
+        api_models::enums::EventType::InvoicePartiallyPaid => ("INVOICE_PARTIALLY_PAID", true),
+        api_models::enums::EventType::InvoiceOverdue => ("INVOICE_OVERDUE", true),
+        api_models::enums::EventType::InvoiceReminder => ("INVOICE_REMINDER", true),
+        api_models::enums::EventType::PaymentMethodCollected => ("RECURRING_CONTRACT", true),
+        api_models::enums::EventType::PaymentMethodTokenSuspended => {
+            ("RECURRING_TOKEN_SUSPENDED", true)
+        }
+        api_models::enums::EventType::PaymentMethodTokenResumed => {
+            ("RECURRING_TOKEN_RESUMED", true)
+        }
+        api_models::enums::EventType::PaymentMethodTokenDeleted => {
+            ("RECURRING_TOKEN_DISABLED", true)
+        }
+        api_models::enums::EventType::PaymentMethodUpdated => ("RECURRING_CONTRACT_UPDATED", true),
+        api_models::enums::EventType::PaymentRetryTriggered => ("RETRY_TRIGGERED", true),
+        api_models::enums::EventType::PaymentConnectorSwitched => ("CONNECTOR_SWITCHED", true),
+        api_models::enums::EventType::ConnectorCircuitBreakerOpened => {
+            ("CONNECTOR_CIRCUIT_BREAKER_OPENED", true)
+        }
+        api_models::enums::EventType::ConnectorCredentialsInvalid => {
+            ("CONNECTOR_CREDENTIALS_INVALID", false)
+        }
+        api_models::enums::EventType::ConnectorCredentialsRestored => {
+            ("CONNECTOR_CREDENTIALS_RESTORED", true)
+        }
+        api_models::enums::EventType::ShipmentCaptured => ("CAPTURE", true),
+        api_models::enums::EventType::ShipmentCaptureFailed => ("CAPTURE_FAILED", false),
+    }
+}
+
+impl From<api::OutgoingWebhook> for AdyenOutgoingWebhook {
+    fn from(value: api::OutgoingWebhook) -> Self {
+        let (event_code, success) = get_adyen_event_code(value.event_type);
+        let merchant_account_code = value.merchant_id.get_string_repr().to_owned();
+
+        let (psp_reference, original_reference, merchant_reference, amount, payment_method, reason) =
+            match value.content {
+                api::OutgoingWebhookContent::PaymentDetails(payment) => {
+                    let payment_id = payment.payment_id.get_string_repr().to_owned();
+                    (
+                        payment_id.clone(),
+                        None,
+                        payment.merchant_order_reference_id.unwrap_or(payment_id),
+                        AdyenNotificationAmount {
+                            currency: payment.currency,
+                            value: payment.amount.get_amount_as_i64(),
+                        },
+                        payment
+                            .payment_method_type
+                            .map(|payment_method_type| payment_method_type.to_string()),
+                        payment.error_message,
+                    )
+                }
+                api::OutgoingWebhookContent::RefundDetails(refund) => (
+                    refund.refund_id.clone(),
+                    Some(refund.payment_id.get_string_repr().to_owned()),
+                    refund.refund_id,
+                    AdyenNotificationAmount {
+                        currency: refund.currency,
+                        value: refund.amount.get_amount_as_i64(),
+                    },
+                    None,
+                    refund.error_message.or(refund.reason),
+                ),
+                api::OutgoingWebhookContent::DisputeDetails(dispute) => (
+                    dispute.dispute_id.clone(),
+                    Some(dispute.payment_id.get_string_repr().to_owned()),
+                    dispute.dispute_id,
+                    AdyenNotificationAmount {
+                        currency: dispute.currency,
+                        value: dispute.amount.parse().unwrap_or_default(),
+                    },
+                    None,
+                    dispute.connector_reason,
+                ),
+                api::OutgoingWebhookContent::MandateDetails(mandate) => (
+                    mandate.mandate_id.clone(),
+                    None,
+                    mandate.mandate_id,
+                    AdyenNotificationAmount::default(),
+                    Some(mandate.payment_method),
+                    None,
+                ),
+                api::OutgoingWebhookContent::MandateDebitDetails(mandate_debit) => (
+                    mandate_debit.mandate_debit_id.clone(),
+                    None,
+                    mandate_debit.mandate_id,
+                    AdyenNotificationAmount {
+                        currency: mandate_debit.currency.to_string(),
+                        value: mandate_debit.amount.get_amount_as_i64(),
+                    },
+                    None,
+                    mandate_debit.description,
+                ),
+                #[cfg(feature = "payouts")]
+                api::OutgoingWebhookContent::PayoutDetails(payout) => (
+                    payout.payout_id.clone(),
+                    None,
+                    payout.payout_id,
+                    AdyenNotificationAmount {
+                        currency: payout.currency.to_string(),
+                        value: payout.amount.get_amount_as_i64(),
+                    },
+                    payout
+                        .payout_type
+                        .map(|payout_type| payout_type.to_string()),
+                    payout.error_message,
+                ),
+                api::OutgoingWebhookContent::InvoiceDetails(invoice) => (
+                    invoice.invoice_id.clone(),
+                    None,
+                    invoice.invoice_id,
+                    AdyenNotificationAmount {
+                        currency: invoice.currency.to_string(),
+                        value: invoice.amount.get_amount_as_i64(),
+                    },
+                    None,
+                    None,
+                ),
+                api::OutgoingWebhookContent::PaymentMethodCollectLinkDetails(pm_collect_link) => (
+                    pm_collect_link
+                        .payment_method_id
+                        .unwrap_or(pm_collect_link.pm_collect_link_id.clone()),
+                    None,
+                    pm_collect_link.pm_collect_link_id,
+                    AdyenNotificationAmount::default(),
+                    None,
+                    None,
+                ),
+                api::OutgoingWebhookContent::PaymentMethodTokenDetails(payment_method) => (
+                    payment_method.payment_method_id.clone(),
+                    None,
+                    payment_method.payment_method_id,
+                    AdyenNotificationAmount::default(),
+                    payment_method
+                        .payment_method_type
+                        .map(|pmt| pmt.to_string()),
+                    None,
+                ),
+                api::OutgoingWebhookContent::OrchestrationDetails(orchestration) => {
+                    let payment_id = orchestration.payment_id.get_string_repr().to_owned();
+                    (
+                        payment_id.clone(),
+                        orchestration.attempt_id,
+                        payment_id,
+                        AdyenNotificationAmount::default(),
+                        None,
+                        orchestration.error_message,
+                    )
+                }
+                api::OutgoingWebhookContent::ConnectorCredentialHealthDetails(
+                    credential_health,
+                ) => {
+                    let merchant_connector_id = credential_health
+                        .merchant_connector_id
+                        .get_string_repr()
+                        .to_owned();
+                    (
+                        merchant_connector_id.clone(),
+                        None,
+                        merchant_connector_id,
+                        AdyenNotificationAmount::default(),
+                        None,
+                        credential_health.error_message,
+                    )
+                }
+                api::OutgoingWebhookContent::ShipmentDetails(shipment) => (
+                    shipment.capture_id,
+                    Some(shipment.payment_id.get_string_repr().to_owned()),
+                    shipment.shipment_reference,
+                    AdyenNotificationAmount {
+                        currency: shipment
+                            .currency
+                            .map(|currency| currency.to_string())
+                            .unwrap_or_default(),
+                        value: shipment.amount.get_amount_as_i64(),
+                    },
+                    None,
+                    shipment.error_message,
+                ),
+            };
+
+        Self {
+            live: false.to_string(),
+            notification_items: vec![AdyenNotificationItem {
+                notification_request_item: AdyenNotificationRequestItem {
+                    additional_data: AdyenNotificationAdditionalData::default(),
+                    amount,
+                    event_code,
+                    event_date: value.timestamp,
+                    merchant_account_code,
+                    merchant_reference,
+                    original_reference,
+                    payment_method,
+                    psp_reference,
+                    reason,
+                    success: success.to_string(),
+                },
+            }],
+        }
+    }
+}
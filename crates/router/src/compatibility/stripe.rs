@@ -1,6 +1,7 @@
 pub mod app;
 pub mod customers;
 pub mod payment_intents;
+pub mod payment_methods;
 pub mod refunds;
 pub mod setup_intents;
 pub mod webhooks;
@@ -25,6 +26,6 @@ impl StripeApis {
             .service(app::Refunds::server(state.clone()))
             .service(app::Customers::server(state.clone()))
             .service(app::Webhooks::server(state.clone()))
-            .service(app::Mandates::server(state))
+            .service(app::PaymentMethods::server(state))
     }
 }
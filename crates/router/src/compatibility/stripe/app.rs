@@ -2,6 +2,12 @@ use actix_web::{web, Scope};
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use super::customers::*;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2"),
+    not(feature = "payment_methods_v2")
+))]
+use super::payment_methods::*;
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use super::{payment_intents::*, setup_intents::*};
 use super::{refunds::*, webhooks::*};
@@ -59,6 +65,9 @@ impl SetupIntents {
             .service(
                 web::resource("/{setup_id}/confirm").route(web::post().to(setup_intents_confirm)),
             )
+            .service(
+                web::resource("/{setup_id}/cancel").route(web::post().to(setup_intents_cancel)),
+            )
     }
 }
 
@@ -119,12 +128,21 @@ impl Webhooks {
     }
 }
 
-pub struct Mandates;
+pub struct PaymentMethods;
 
-impl Mandates {
+impl PaymentMethods {
     pub fn server(config: routes::AppState) -> Scope {
-        web::scope("/payment_methods")
-            .app_data(web::Data::new(config))
-            .service(web::resource("/{id}/detach").route(web::post().to(mandates::revoke_mandate)))
+        let mut route = web::scope("/payment_methods").app_data(web::Data::new(config));
+        #[cfg(all(
+            any(feature = "v1", feature = "v2"),
+            not(feature = "customer_v2"),
+            not(feature = "payment_methods_v2")
+        ))]
+        {
+            route = route
+                .service(web::resource("").route(web::post().to(payment_method_create)))
+                .service(web::resource("/{id}").route(web::get().to(payment_method_retrieve)));
+        }
+        route.service(web::resource("/{id}/detach").route(web::post().to(mandates::revoke_mandate)))
     }
 }
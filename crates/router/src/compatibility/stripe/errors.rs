@@ -268,6 +268,17 @@ pub enum StripeErrorCode {
     ExtendedCardInfoNotFound,
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "not_configured", message = "{message}")]
     LinkConfigurationError { message: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "amount_not_supported", message = "The amount is not supported by {connector} for {payment_method_type}")]
+    AmountNotSupported {
+        connector: String,
+        payment_method_type: String,
+    },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "processing_limit_exceeded", message = "The {limit} processing limit of the profile is exceeded")]
+    ProcessingLimitExceeded { limit: String },
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many export requests, retry after a minute")]
+    DataExportRateLimitExceeded,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many balance checks for this payment, retry later")]
+    GiftCardBalanceCheckRateLimitExceeded,
     #[error(error_type = StripeErrorType::ConnectorError, code = "CE", message = "{reason} as data mismatched for {field_names}")]
     IntegrityCheckFailed {
         reason: String,
@@ -644,9 +655,8 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             | errors::ApiErrorResponse::WebhookProcessingFailure
             | errors::ApiErrorResponse::WebhookAuthenticationFailed
             | errors::ApiErrorResponse::WebhookUnprocessableEntity
-            | errors::ApiErrorResponse::WebhookInvalidMerchantSecret => {
-                Self::WebhookProcessingError
-            }
+            | errors::ApiErrorResponse::WebhookInvalidMerchantSecret
+            | errors::ApiErrorResponse::WebhookReplayDetected => Self::WebhookProcessingError,
             errors::ApiErrorResponse::IncorrectPaymentMethodConfiguration => {
                 Self::PaymentMethodUnactivated
             }
@@ -663,6 +673,23 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::LinkConfigurationError { message } => {
                 Self::LinkConfigurationError { message }
             }
+            errors::ApiErrorResponse::AmountNotSupported {
+                connector,
+                payment_method_type,
+                ..
+            } => Self::AmountNotSupported {
+                connector,
+                payment_method_type,
+            },
+            errors::ApiErrorResponse::ProcessingLimitExceeded { limit } => {
+                Self::ProcessingLimitExceeded { limit }
+            }
+            errors::ApiErrorResponse::DataExportRateLimitExceeded => {
+                Self::DataExportRateLimitExceeded
+            }
+            errors::ApiErrorResponse::GiftCardBalanceCheckRateLimitExceeded => {
+                Self::GiftCardBalanceCheckRateLimitExceeded
+            }
             errors::ApiErrorResponse::IntegrityCheckFailed {
                 reason,
                 field_names,
@@ -750,7 +777,9 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::CurrencyConversionFailed
             | Self::PaymentMethodDeleteFailed
             | Self::ExtendedCardInfoNotFound
-            | Self::LinkConfigurationError { .. } => StatusCode::BAD_REQUEST,
+            | Self::LinkConfigurationError { .. }
+            | Self::AmountNotSupported { .. }
+            | Self::ProcessingLimitExceeded { .. } => StatusCode::BAD_REQUEST,
             Self::RefundFailed
             | Self::PayoutFailed
             | Self::PaymentLinkNotFound
@@ -769,6 +798,9 @@ impl actix_web::ResponseError for StripeErrorCode {
                 StatusCode::from_u16(*code).unwrap_or(StatusCode::OK)
             }
             Self::LockTimeout => StatusCode::LOCKED,
+            Self::DataExportRateLimitExceeded | Self::GiftCardBalanceCheckRateLimitExceeded => {
+                StatusCode::TOO_MANY_REQUESTS
+            }
         }
     }
 
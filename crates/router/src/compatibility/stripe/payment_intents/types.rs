@@ -647,6 +647,7 @@ impl TryFrom<StripePaymentListConstraints> for payments::PaymentListConstraints
             created_gt: from_timestamp_to_datetime(item.created_gt)?,
             created_lte: from_timestamp_to_datetime(item.created_lte)?,
             created_gte: from_timestamp_to_datetime(item.created_gte)?,
+            fields: None,
         })
     }
 }
@@ -0,0 +1,162 @@
+use api_models::{payment_methods as api_types, payments};
+use common_utils::{id_type, pii};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    compatibility::stripe::payment_intents::types::StripeBillingDetails,
+    types::api::enums as api_enums,
+};
+
+#[derive(Debug, Default, Serialize, PartialEq, Eq, Deserialize, Clone)]
+#[serde(rename_all = "snake_case")]
+pub enum StripePaymentMethodType {
+    #[default]
+    Card,
+}
+
+impl From<StripePaymentMethodType> for api_enums::PaymentMethod {
+    fn from(item: StripePaymentMethodType) -> Self {
+        match item {
+            StripePaymentMethodType::Card => Self::Card,
+        }
+    }
+}
+
+#[derive(Default, PartialEq, Eq, Deserialize, Clone, Debug)]
+pub struct StripePaymentMethodCard {
+    pub number: cards::CardNumber,
+    pub exp_month: masking::Secret<String>,
+    pub exp_year: masking::Secret<String>,
+    // Accepted for request compatibility, card security codes are never stored
+    pub cvc: Option<masking::Secret<String>>,
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+impl From<StripePaymentMethodCard> for api_types::CardDetail {
+    fn from(card: StripePaymentMethodCard) -> Self {
+        Self {
+            card_number: card.number,
+            card_exp_month: card.exp_month,
+            card_exp_year: card.exp_year,
+            card_holder_name: None,
+            nick_name: None,
+            card_issuing_country: None,
+            card_network: None,
+            card_issuer: None,
+            card_type: None,
+        }
+    }
+}
+
+#[derive(Default, PartialEq, Eq, Deserialize, Clone, Debug)]
+pub struct StripePaymentMethodCreateRequest {
+    #[serde(rename = "type")]
+    pub payment_method_type: StripePaymentMethodType,
+    pub card: Option<StripePaymentMethodCard>,
+    pub billing_details: Option<StripeBillingDetails>,
+    pub customer: Option<id_type::CustomerId>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+impl From<StripePaymentMethodCreateRequest> for api_types::PaymentMethodCreate {
+    fn from(item: StripePaymentMethodCreateRequest) -> Self {
+        let card_holder_name = item
+            .billing_details
+            .as_ref()
+            .and_then(|billing| billing.name.clone())
+            .map(masking::Secret::new);
+        Self {
+            payment_method: Some(item.payment_method_type.into()),
+            payment_method_type: None,
+            payment_method_issuer: None,
+            payment_method_issuer_code: None,
+            card: item.card.map(|card| api_types::CardDetail {
+                card_holder_name,
+                ..card.into()
+            }),
+            metadata: item.metadata,
+            customer_id: item.customer,
+            card_network: None,
+            #[cfg(feature = "payouts")]
+            bank_transfer: None,
+            #[cfg(feature = "payouts")]
+            wallet: None,
+            client_secret: None,
+            payment_method_data: None,
+            billing: item.billing_details.map(payments::Address::from),
+            connector_mandate_details: None,
+            network_transaction_id: None,
+        }
+    }
+}
+
+#[derive(Default, Serialize, PartialEq, Eq)]
+pub struct StripePaymentMethodCardResponse {
+    pub brand: Option<String>,
+    pub country: Option<String>,
+    pub last4: Option<String>,
+    pub exp_month: Option<masking::Secret<String>>,
+    pub exp_year: Option<masking::Secret<String>>,
+    pub fingerprint: Option<masking::Secret<String>>,
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+impl From<api_types::CardDetailFromLocker> for StripePaymentMethodCardResponse {
+    fn from(item: api_types::CardDetailFromLocker) -> Self {
+        Self {
+            brand: item
+                .card_network
+                .map(|network| network.to_string().to_lowercase())
+                .or(item.scheme.map(|scheme| scheme.to_lowercase())),
+            country: item.issuer_country,
+            last4: item.last4_digits,
+            exp_month: item.expiry_month,
+            exp_year: item.expiry_year,
+            fingerprint: item.card_fingerprint,
+        }
+    }
+}
+
+#[derive(Default, Serialize, PartialEq, Eq)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub object: &'static str,
+    #[serde(rename = "type")]
+    pub payment_method_type: Option<api_enums::PaymentMethod>,
+    pub card: Option<StripePaymentMethodCardResponse>,
+    pub customer: Option<id_type::CustomerId>,
+    pub created: Option<i64>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub livemode: bool,
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+impl From<api_types::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(item: api_types::PaymentMethodResponse) -> Self {
+        Self {
+            id: item.payment_method_id,
+            object: "payment_method",
+            payment_method_type: item.payment_method,
+            card: item.card.map(From::from),
+            customer: item.customer_id,
+            created: item
+                .created
+                .map(|created| created.assume_utc().unix_timestamp()),
+            metadata: item.metadata,
+            livemode: false,
+        }
+    }
+}
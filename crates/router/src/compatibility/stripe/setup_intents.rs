@@ -308,3 +308,74 @@ pub async fn setup_intents_confirm(
     ))
     .await
 }
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCancel))]
+pub async fn setup_intents_cancel(
+    state: web::Data<routes::AppState>,
+    qs_config: web::Data<serde_qs::Config>,
+    req: HttpRequest,
+    form_payload: web::Bytes,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> HttpResponse {
+    let setup_id = path.into_inner();
+    let stripe_payload: types::StripePaymentCancelRequest = match qs_config
+        .deserialize_bytes(&form_payload)
+    {
+        Ok(p) => p,
+        Err(err) => {
+            return api::log_and_return_error_response(report!(errors::StripeErrorCode::from(err)))
+        }
+    };
+
+    let mut payload: payment_types::PaymentsCancelRequest = stripe_payload.into();
+    payload.payment_id = setup_id;
+
+    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
+
+    let flow = Flow::PaymentsCancel;
+
+    Box::pin(wrap::compatibility_api_wrap::<
+        _,
+        _,
+        _,
+        _,
+        _,
+        types::StripeSetupIntentResponse,
+        errors::StripeErrorCode,
+        _,
+    >(
+        flow,
+        state.into_inner(),
+        &req,
+        payload,
+        |state, auth, req, req_state| {
+            payments::payments_core::<
+                api_types::Void,
+                api_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::Void>,
+            >(
+                state,
+                req_state,
+                auth.merchant_account,
+                None,
+                auth.key_store,
+                payments::PaymentCancel,
+                req,
+                auth_flow,
+                payments::CallConnectorAction::Trigger,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+            )
+        },
+        &*auth_type,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
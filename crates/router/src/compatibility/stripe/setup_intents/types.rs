@@ -42,7 +42,7 @@ impl From<StripeBillingDetails> for payments::Address {
     }
 }
 
-#[derive(Default, Serialize, PartialEq, Eq, Deserialize, Clone)]
+#[derive(Debug, Default, Serialize, PartialEq, Eq, Deserialize, Clone)]
 pub struct StripeCard {
     pub number: cards::CardNumber,
     pub exp_month: pii::Secret<String>,
@@ -298,7 +298,7 @@ impl TryFrom<StripeSetupIntentRequest> for payments::PaymentsRequest {
     }
 }
 
-#[derive(Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum StripeSetupStatus {
     Succeeded,
@@ -355,13 +355,13 @@ impl From<StripePaymentCancelRequest> for payments::PaymentsCancelRequest {
         }
     }
 }
-#[derive(Default, Eq, PartialEq, Serialize)]
+#[derive(Debug, Default, Eq, PartialEq, Serialize)]
 pub struct RedirectUrl {
     pub return_url: Option<String>,
     pub url: Option<String>,
 }
 
-#[derive(Eq, PartialEq, serde::Serialize)]
+#[derive(Debug, Eq, PartialEq, serde::Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum StripeNextAction {
     RedirectToUrl {
@@ -457,7 +457,7 @@ pub(crate) fn into_stripe_next_action(
     })
 }
 
-#[derive(Default, Eq, PartialEq, Serialize)]
+#[derive(Debug, Default, Eq, PartialEq, Serialize)]
 pub struct StripeSetupIntentResponse {
     pub id: id_type::PaymentId,
     pub object: String,
@@ -475,7 +475,7 @@ pub struct StripeSetupIntentResponse {
     pub connector_transaction_id: Option<String>,
 }
 
-#[derive(Default, Eq, PartialEq, Serialize)]
+#[derive(Debug, Default, Eq, PartialEq, Serialize)]
 pub struct LastPaymentError {
     charge: Option<String>,
     code: Option<String>,
@@ -487,7 +487,7 @@ pub struct LastPaymentError {
     error_type: String,
 }
 
-#[derive(Default, Eq, PartialEq, Serialize)]
+#[derive(Debug, Default, Eq, PartialEq, Serialize)]
 pub struct StripePaymentMethod {
     #[serde(rename = "id")]
     payment_method_id: String,
@@ -576,6 +576,7 @@ impl TryFrom<StripePaymentListConstraints> for payments::PaymentListConstraints
             created_gt: from_timestamp_to_datetime(item.created_gt)?,
             created_lte: from_timestamp_to_datetime(item.created_lte)?,
             created_gte: from_timestamp_to_datetime(item.created_gte)?,
+            fields: None,
         })
     }
 }
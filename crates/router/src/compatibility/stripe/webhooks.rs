@@ -13,6 +13,7 @@ use serde::Serialize;
 
 use super::{
     payment_intents::types::StripePaymentIntentResponse, refunds::types::StripeRefundResponse,
+    setup_intents::types::StripeSetupIntentResponse,
 };
 use crate::{
     core::{
@@ -31,7 +32,19 @@ pub struct StripeOutgoingWebhook {
     object: &'static str,
     data: StripeWebhookObject,
     created: u64,
-    // api_version: "2019-11-05", // not used
+    api_version: &'static str,
+    livemode: bool,
+    pending_webhooks: u32,
+    request: StripeWebhookRequest,
+}
+
+/// Stripe API version whose event payload shape is emulated by the compatibility layer
+const STRIPE_COMPATIBLE_API_VERSION: &str = "2019-11-05";
+
+#[derive(Serialize, Debug, Default)]
+pub struct StripeWebhookRequest {
+    id: Option<String>,
+    idempotency_key: Option<String>,
 }
 
 impl OutgoingWebhookType for StripeOutgoingWebhook {
@@ -82,11 +95,19 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
 #[serde(tag = "type", content = "object", rename_all = "snake_case")]
 pub enum StripeWebhookObject {
     PaymentIntent(Box<StripePaymentIntentResponse>),
+    SetupIntent(Box<StripeSetupIntentResponse>),
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
     Mandate(StripeMandateResponse),
+    MandateDebit(Box<api_models::mandates::MandateDebitResponse>),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    Invoice(Box<api_models::invoices::InvoiceResponse>),
+    PaymentMethodCollectLink(Box<api_models::payment_methods::PaymentMethodCollectLinkResponse>),
+    PaymentMethod(Box<api_models::payment_methods::PaymentMethodTokenResponse>),
+    Orchestration(Box<api_models::routing::PaymentOrchestrationEventResponse>),
+    ConnectorCredentialHealth(Box<api_models::admin::ConnectorCredentialHealthResponse>),
+    Shipment(Box<api_models::payments::PaymentShipmentResponse>),
 }
 
 #[derive(Serialize, Debug)]
@@ -282,6 +303,9 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeLost => "dispute.lost",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        // stripe notifies the customers of the upcoming debits on indian e-mandates through the
+        // upcoming invoice
+        api_models::enums::EventType::MandatePreDebitNotification => "invoice.upcoming",
 
         // as per this doc https://stripe.com/docs/api/events/types#event_types-payment_intent.amount_capturable_updated
         api_models::enums::EventType::PaymentAuthorized => {
@@ -296,16 +320,67 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::InvoicePaid => "invoice.paid",
+        api_models::enums::EventType::InvoicePartiallyPaid => "invoice.partially_paid",
+        api_models::enums::EventType::InvoiceOverdue => "invoice.overdue",
+        api_models::enums::EventType::InvoiceReminder => "invoice.upcoming",
+        api_models::enums::EventType::PaymentMethodCollected => "payment_method.attached",
+        api_models::enums::EventType::PaymentMethodTokenSuspended
+        | api_models::enums::EventType::PaymentMethodTokenResumed
+        | api_models::enums::EventType::PaymentMethodUpdated => "payment_method.updated",
+        api_models::enums::EventType::PaymentMethodTokenDeleted => "payment_method.detached",
+        // stripe does not expose its orchestration decisions, these are hyperswitch specific
+        api_models::enums::EventType::PaymentRetryTriggered => "payment_intent.retry_triggered",
+        api_models::enums::EventType::PaymentConnectorSwitched => {
+            "payment_intent.connector_switched"
+        }
+        api_models::enums::EventType::ConnectorCircuitBreakerOpened => {
+            "connector.circuit_breaker_opened"
+        }
+        api_models::enums::EventType::ConnectorCredentialsInvalid => {
+            "connector.credentials_invalid"
+        }
+        api_models::enums::EventType::ConnectorCredentialsRestored => {
+            "connector.credentials_restored"
+        }
+        api_models::enums::EventType::ShipmentCaptured => "charge.captured",
+        api_models::enums::EventType::ShipmentCaptureFailed => "charge.failed",
+    }
+}
+
+/// Zero amount payments are surfaced as setup intents, so their events use the
+/// `setup_intent.*` names wherever stripe has an equivalent event.
+fn get_stripe_setup_intent_event_type(
+    event_type: api_models::enums::EventType,
+) -> Option<&'static str> {
+    match event_type {
+        api_models::enums::EventType::PaymentSucceeded => Some("setup_intent.succeeded"),
+        api_models::enums::EventType::PaymentFailed => Some("setup_intent.setup_failed"),
+        api_models::enums::EventType::PaymentCancelled => Some("setup_intent.canceled"),
+        api_models::enums::EventType::ActionRequired => Some("setup_intent.requires_action"),
+        _ => None,
     }
 }
 
 impl From<api::OutgoingWebhook> for StripeOutgoingWebhook {
     fn from(value: api::OutgoingWebhook) -> Self {
+        let data = StripeWebhookObject::from(value.content);
+        let stype = match data {
+            StripeWebhookObject::SetupIntent(_) => {
+                get_stripe_setup_intent_event_type(value.event_type)
+                    .unwrap_or_else(|| get_stripe_event_type(value.event_type))
+            }
+            _ => get_stripe_event_type(value.event_type),
+        };
         Self {
             id: value.event_id,
-            stype: get_stripe_event_type(value.event_type),
-            data: StripeWebhookObject::from(value.content),
+            stype,
+            data,
             object: "event",
+            api_version: STRIPE_COMPATIBLE_API_VERSION,
+            livemode: false,
+            pending_webhooks: 1,
+            request: StripeWebhookRequest::default(),
             // put this conversion it into a function
             created: u64::try_from(value.timestamp.assume_utc().unix_timestamp()).unwrap_or_else(
                 |error| {
@@ -326,6 +401,11 @@ impl From<api::OutgoingWebhook> for StripeOutgoingWebhook {
 impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
     fn from(value: api::OutgoingWebhookContent) -> Self {
         match value {
+            api::OutgoingWebhookContent::PaymentDetails(payment)
+                if payment.amount == common_utils::types::MinorUnit::zero() =>
+            {
+                Self::SetupIntent(Box::new((*payment).into()))
+            }
             api::OutgoingWebhookContent::PaymentDetails(payment) => {
                 Self::PaymentIntent(Box::new((*payment).into()))
             }
@@ -336,8 +416,25 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::MandateDetails(mandate) => {
                 Self::Mandate((*mandate).into())
             }
+            api::OutgoingWebhookContent::MandateDebitDetails(mandate_debit) => {
+                Self::MandateDebit(mandate_debit)
+            }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            api::OutgoingWebhookContent::InvoiceDetails(invoice) => Self::Invoice(invoice),
+            api::OutgoingWebhookContent::PaymentMethodCollectLinkDetails(pm_collect_link) => {
+                Self::PaymentMethodCollectLink(pm_collect_link)
+            }
+            api::OutgoingWebhookContent::PaymentMethodTokenDetails(payment_method) => {
+                Self::PaymentMethod(payment_method)
+            }
+            api::OutgoingWebhookContent::OrchestrationDetails(orchestration) => {
+                Self::Orchestration(orchestration)
+            }
+            api::OutgoingWebhookContent::ConnectorCredentialHealthDetails(credential_health) => {
+                Self::ConnectorCredentialHealth(credential_health)
+            }
+            api::OutgoingWebhookContent::ShipmentDetails(shipment) => Self::Shipment(shipment),
         }
     }
 }
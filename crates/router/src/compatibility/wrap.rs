@@ -6,7 +6,7 @@ use router_env::{instrument, tracing, Tag};
 use serde::Serialize;
 
 use crate::{
-    core::{api_locking, errors},
+    core::{api_locking, errors, localization},
     events::api_logs::ApiEventMetric,
     routes::{
         app::{AppStateInfo, ReqState},
@@ -108,11 +108,36 @@ where
                 ),
             }
         }
+        Ok(api::ApplicationResponse::JsonAccepted(response)) => {
+            let response = S::try_from(response);
+            match response {
+                Ok(response) => match serde_json::to_string(&response) {
+                    Ok(res) => api::http_response_json_accepted(res),
+                    Err(_) => api::http_response_err(
+                        r#"{
+                                "error": {
+                                    "message": "Error serializing response from connector"
+                                }
+                            }"#,
+                    ),
+                },
+                Err(_) => api::http_response_err(
+                    r#"{
+                        "error": {
+                            "message": "Error converting juspay response to stripe response"
+                        }
+                    }"#,
+                ),
+            }
+        }
         Ok(api::ApplicationResponse::StatusOk) => api::http_response_ok(),
         Ok(api::ApplicationResponse::TextPlain(text)) => api::http_response_plaintext(text),
         Ok(api::ApplicationResponse::FileData((file_data, content_type))) => {
             api::http_response_file_data(file_data, content_type)
         }
+        Ok(api::ApplicationResponse::FileStream(file_stream)) => {
+            api::http_response_file_stream(file_stream)
+        }
         Ok(api::ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => api::http_redirect_response(res, response),
@@ -127,12 +152,20 @@ where
         }
         Ok(api::ApplicationResponse::Form(redirection_data)) => {
             let config = state.conf();
+            let localizer = localization::Localizer::new(
+                request
+                    .headers()
+                    .get(crate::headers::ACCEPT_LANGUAGE)
+                    .and_then(|locale| locale.to_str().ok()),
+            );
             api::build_redirection_form(
                 &redirection_data.redirect_form,
                 redirection_data.payment_method_data,
                 redirection_data.amount,
                 redirection_data.currency,
+                redirection_data.branding,
                 config,
+                &localizer,
             )
             .respond_to(request)
             .map_into_boxed_body()
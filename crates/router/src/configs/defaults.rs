@@ -109,6 +109,162 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::RefundSettlementEstimation {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_samples: 200,
+            min_samples: 20,
+            percentile: 80,
+        }
+    }
+}
+
+impl Default for super::settings::ApiLogsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_entries_per_merchant: 1000,
+            // 7 days
+            retention_in_secs: 7 * 24 * 60 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::WebhookEndpointAnalyticsConfig {
+    fn default() -> Self {
+        Self {
+            max_delivery_attempts: 500,
+            max_response_body_length: 1024,
+        }
+    }
+}
+
+impl Default for super::settings::DataExportConfig {
+    fn default() -> Self {
+        Self {
+            max_records_per_request: 10000,
+            batch_size: 1000,
+            requests_per_minute: 10,
+        }
+    }
+}
+
+impl Default for super::settings::GiftCardBalanceCheckConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_payment: 5,
+            window_in_secs: 900,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorOutageDetectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_in_secs: 60,
+            minimum_calls: 20,
+            error_rate_threshold_percentage: 50,
+            timeout_threshold: 10,
+            // 10 minutes
+            degraded_duration_in_secs: 10 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorCredentialHealthConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            // 6 hours
+            check_interval_in_secs: 6 * 60 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::AccountUpdaterConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            batch_size: 100,
+            // 1 day
+            interval_in_secs: 24 * 60 * 60,
+            expiry_window_in_days: 30,
+        }
+    }
+}
+
+impl Default for super::settings::BlocklistSyncConfig {
+    fn default() -> Self {
+        Self {
+            max_entries_per_sync: 10000,
+            max_retained_versions: 10,
+            // 5 minutes
+            min_pull_interval_in_secs: 5 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::RiskEnrichmentConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            night_start_hour: 0,
+            night_end_hour: 6,
+            trust_cdn_country_headers: false,
+        }
+    }
+}
+
+impl Default for super::settings::WebhookReplayProtectionSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            // 5 minutes
+            timestamp_tolerance_in_secs: 5 * 60,
+            // 10 minutes, so that nonces outlive the timestamp tolerance in either direction
+            nonce_expiry_in_secs: 10 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::ClientTokenConfig {
+    fn default() -> Self {
+        Self {
+            // 15 minutes
+            default_ttl_in_secs: 15 * 60,
+            // 1 hour
+            max_ttl_in_secs: 60 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::SimulationConfig {
+    fn default() -> Self {
+        Self {
+            max_load_test_requests: 100_000,
+            max_requests_per_second: 500,
+            request_timeout_in_secs: 30,
+        }
+    }
+}
+
+impl Default for super::settings::TrafficMirroring {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target_base_url: String::new(),
+            api_key: String::new().into(),
+            flows: HashSet::new(),
+            sampling_percentage: 1,
+            placeholders: HashMap::new(),
+            request_timeout_in_secs: 30,
+            accept_mirrored_requests: false,
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
@@ -128,6 +284,49 @@ impl Default for super::settings::DrainerSettings {
     }
 }
 
+impl Default for super::settings::RequestLanes {
+    fn default() -> Self {
+        Self {
+            live_max_concurrent_connector_calls: None,
+            batch_max_concurrent_connector_calls: 50,
+        }
+    }
+}
+
+impl Default for super::settings::Receipts {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            // 7 * 24 * 60 * 60 seconds
+            download_url_expiry_in_secs: 604800,
+            // 24 * 60 * 60 seconds
+            cache_ttl_in_secs: 86400,
+        }
+    }
+}
+
+impl Default for super::settings::Invoices {
+    fn default() -> Self {
+        Self {
+            reminder_days_before_due: vec![3, 1],
+            overdue_reminder_days: vec![1, 7],
+        }
+    }
+}
+
+impl Default for super::settings::EMandate {
+    fn default() -> Self {
+        Self {
+            connector_list: HashSet::new(),
+            pre_debit_notification_hours: 24,
+            debit_retry_window_hours: 72,
+            debit_retry_interval_hours: 24,
+            // 15,000 INR
+            max_debit_amount: common_utils::types::MinorUnit::new(1_500_000),
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::KvConfig {
     fn default() -> Self {
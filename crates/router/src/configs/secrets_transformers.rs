@@ -78,6 +78,34 @@ impl SecretsHandler for settings::ConnectorOnboarding {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for settings::ExternalVault {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let external_vault = value.get_inner();
+
+        let mut providers = std::collections::HashMap::new();
+        for (name, provider) in external_vault.providers.iter() {
+            let (api_key, proxy_url) = tokio::try_join!(
+                secret_management_client.get_secret(provider.api_key.clone()),
+                secret_management_client.get_secret(provider.proxy_url.clone()),
+            )?;
+            providers.insert(
+                name.clone(),
+                settings::ExternalVaultProvider {
+                    api_key,
+                    proxy_url,
+                    ..provider.clone()
+                },
+            );
+        }
+
+        Ok(value.transition_state(|_| Self { providers }))
+    }
+}
+
 #[async_trait::async_trait]
 impl SecretsHandler for settings::ForexApi {
     async fn convert_to_raw_secret(
@@ -381,6 +409,14 @@ pub(crate) async fn fetch_raw_secrets(
             .await
             .expect("Failed to decrypt forex api configs");
 
+    #[allow(clippy::expect_used)]
+    let external_vault = settings::ExternalVault::convert_to_raw_secret(
+        conf.external_vault,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt external vault configs");
+
     #[allow(clippy::expect_used)]
     let jwekey = settings::Jwekey::convert_to_raw_secret(conf.jwekey, secret_management_client)
         .await
@@ -484,6 +520,18 @@ pub(crate) async fn fetch_raw_secrets(
         connectors: conf.connectors,
         forex_api,
         refund: conf.refund,
+        refund_settlement_estimation: conf.refund_settlement_estimation,
+        api_logs: conf.api_logs,
+        webhook_endpoint_analytics: conf.webhook_endpoint_analytics,
+        data_export: conf.data_export,
+        gift_card_balance_check: conf.gift_card_balance_check,
+        connector_outage_detection: conf.connector_outage_detection,
+        connector_credential_health: conf.connector_credential_health,
+        account_updater: conf.account_updater,
+        blocklist_sync: conf.blocklist_sync,
+        risk_enrichment: conf.risk_enrichment,
+        simulation: conf.simulation,
+        client_token: conf.client_token,
         eph_key: conf.eph_key,
         scheduler: conf.scheduler,
         jwekey,
@@ -515,6 +563,15 @@ pub(crate) async fn fetch_raw_secrets(
         multiple_api_version_supported_connectors: conf.multiple_api_version_supported_connectors,
         applepay_merchant_configs,
         lock_settings: conf.lock_settings,
+        request_lanes: conf.request_lanes,
+        receipts: conf.receipts,
+        invoices: conf.invoices,
+        chargeback_fees: conf.chargeback_fees,
+        e_mandate: conf.e_mandate,
+        external_vault,
+        demo_connectors: conf.demo_connectors,
+        traffic_mirroring: conf.traffic_mirroring,
+        data_residency: conf.data_residency,
         temp_locker_enable_config: conf.temp_locker_enable_config,
         generic_link: conf.generic_link,
         payment_link: conf.payment_link,
@@ -65,6 +65,18 @@ pub struct Settings<S: SecretState> {
     pub connectors: Connectors,
     pub forex_api: SecretStateContainer<ForexApi, S>,
     pub refund: Refund,
+    pub refund_settlement_estimation: RefundSettlementEstimation,
+    pub api_logs: ApiLogsConfig,
+    pub webhook_endpoint_analytics: WebhookEndpointAnalyticsConfig,
+    pub data_export: DataExportConfig,
+    pub gift_card_balance_check: GiftCardBalanceCheckConfig,
+    pub connector_outage_detection: ConnectorOutageDetectionConfig,
+    pub connector_credential_health: ConnectorCredentialHealthConfig,
+    pub account_updater: AccountUpdaterConfig,
+    pub blocklist_sync: BlocklistSyncConfig,
+    pub risk_enrichment: RiskEnrichmentConfig,
+    pub simulation: SimulationConfig,
+    pub client_token: ClientTokenConfig,
     pub eph_key: EphemeralConfig,
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
@@ -100,6 +112,15 @@ pub struct Settings<S: SecretState> {
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
+    pub request_lanes: RequestLanes,
+    pub receipts: Receipts,
+    pub invoices: Invoices,
+    pub chargeback_fees: ChargebackFees,
+    pub e_mandate: EMandate,
+    pub external_vault: SecretStateContainer<ExternalVault, S>,
+    pub demo_connectors: DemoConnectors,
+    pub traffic_mirroring: TrafficMirroring,
+    pub data_residency: DataResidency,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub generic_link: GenericLink,
     pub payment_link: PaymentLink,
@@ -557,6 +578,8 @@ pub struct UserSettings {
     pub totp_issuer_name: String,
     pub base_url: String,
     pub force_two_factor_auth: bool,
+    pub remembered_device_expiry_in_secs: i64,
+    pub invitation_expiry_in_secs: i64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -588,6 +611,150 @@ pub struct Refund {
     pub max_age: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RefundSettlementEstimation {
+    /// Whether the settlement times of refunds are tracked to estimate when pending refunds settle
+    pub enabled: bool,
+    /// Number of the most recent settlement times tracked for each connector
+    pub max_samples: usize,
+    /// Number of settlement times required for a connector before its refunds are estimated
+    pub min_samples: usize,
+    /// Percentile of the tracked settlement times used as the estimate
+    pub percentile: u8,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ApiLogsConfig {
+    /// Whether summaries of the API requests made by merchants are recorded
+    pub enabled: bool,
+    /// Maximum number of requests recorded for each merchant, older requests are discarded
+    pub max_entries_per_merchant: usize,
+    /// Time for which the requests are retained, in seconds
+    pub retention_in_secs: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct WebhookEndpointAnalyticsConfig {
+    /// Maximum number of delivery attempts recorded for each webhook endpoint, older attempts are
+    /// discarded
+    pub max_delivery_attempts: usize,
+    /// Maximum number of characters of the response body recorded for each delivery attempt
+    pub max_response_body_length: usize,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct DataExportConfig {
+    /// Maximum number of records exported by a single export request
+    pub max_records_per_request: u32,
+    /// Number of records fetched from the database at a time while exporting
+    pub batch_size: u32,
+    /// Maximum number of export requests a merchant can make in a minute
+    pub requests_per_minute: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct GiftCardBalanceCheckConfig {
+    /// Maximum number of balance checks allowed for a single payment within the window
+    pub requests_per_payment: u32,
+    /// Duration of the rate limiting window, in seconds
+    pub window_in_secs: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorOutageDetectionConfig {
+    /// Whether the outcomes of connector calls are tracked to detect connector outages
+    pub enabled: bool,
+    /// Duration of the window over which the outcomes of connector calls are aggregated, in seconds.
+    /// The outcomes are aggregated per host of the connector on each instance of the application
+    pub window_in_secs: i64,
+    /// Minimum number of connector calls in a window before the error rate is considered
+    pub minimum_calls: usize,
+    /// Percentage of connector calls in a window which must fail for an outage to be flagged
+    pub error_rate_threshold_percentage: u8,
+    /// Number of timed out connector calls in a window for which an outage is flagged
+    pub timeout_threshold: usize,
+    /// Time for which a connector is marked as degraded once an outage is detected, in seconds
+    pub degraded_duration_in_secs: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorCredentialHealthConfig {
+    /// Whether the credentials of the merchant connector accounts are periodically verified
+    pub enabled: bool,
+    /// Interval between two verifications of the credentials of a connector account, in seconds
+    pub check_interval_in_secs: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AccountUpdaterConfig {
+    /// Whether the cards of the saved payment methods are periodically refreshed through the
+    /// account updater of the connectors
+    pub enabled: bool,
+    /// Number of customers whose cards are refreshed by a single run of the account updater
+    pub batch_size: u16,
+    /// Interval between two passes of the account updater over the customers of a merchant, in
+    /// seconds
+    pub interval_in_secs: i64,
+    /// Cards expiring within this number of days are refreshed by the account updater
+    pub expiry_window_in_days: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct BlocklistSyncConfig {
+    /// Maximum number of entries added or removed by a single synchronization of the blocklist
+    pub max_entries_per_sync: usize,
+    /// Number of synchronizations of the blocklist of a merchant which are retained, and can be
+    /// rolled back
+    pub max_retained_versions: usize,
+    /// Minimum interval between two pulls of the blocklist from the source of a merchant, in
+    /// seconds
+    pub min_pull_interval_in_secs: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RiskEnrichmentConfig {
+    /// Whether the risk signals of payments are derived before fraud checks and routing
+    pub enabled: bool,
+    /// Local hour of the customer at which the night starts
+    pub night_start_hour: u8,
+    /// Local hour of the customer at which the night ends
+    pub night_end_hour: u8,
+    /// Whether the country of the client is taken from the `CF-IPCountry` and
+    /// `CloudFront-Viewer-Country` headers, which can only be trusted when the server is behind
+    /// a CDN setting them
+    pub trust_cdn_country_headers: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ClientTokenConfig {
+    /// Time in seconds after which client tokens expire, when not specified while creating them
+    pub default_ttl_in_secs: u32,
+    /// Maximum time in seconds for which client tokens can be valid
+    pub max_ttl_in_secs: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SimulationConfig {
+    /// Maximum number of payments a single load test can create
+    pub max_load_test_requests: u64,
+    /// Maximum number of payments a load test can create every second
+    pub max_requests_per_second: u32,
+    /// Timeout for each payment request sent by a load test, in seconds
+    pub request_timeout_in_secs: u64,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct EphemeralConfig {
@@ -678,6 +845,18 @@ pub struct DrainerSettings {
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
+    pub replay_protection: WebhookReplayProtectionSettings,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookReplayProtectionSettings {
+    pub enabled: bool,
+    /// Maximum allowed difference between the timestamp signed by the connector and the current
+    /// time, in seconds
+    pub timestamp_tolerance_in_secs: i64,
+    /// Duration for which the nonces of received webhooks are remembered, in seconds
+    pub nonce_expiry_in_secs: i64,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -853,6 +1032,16 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
         self.lock_settings.validate()?;
+        self.request_lanes.validate()?;
+        self.receipts.validate()?;
+        self.invoices.validate()?;
+        self.e_mandate.validate()?;
+        self.external_vault.get_inner().validate()?;
+        self.demo_connectors.validate()?;
+        self.traffic_mirroring.validate()?;
+        self.data_residency.validate()?;
+        self.refund_settlement_estimation.validate()?;
+        self.risk_enrichment.validate()?;
         self.events.validate()?;
 
         #[cfg(feature = "olap")]
@@ -906,6 +1095,165 @@ pub struct Payouts {
     pub required_fields: PayoutRequiredFields,
 }
 
+/// Limits on the connector calls in progress for each class of traffic
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RequestLanes {
+    /// Maximum number of connector calls in progress for live traffic, unbounded if not set
+    pub live_max_concurrent_connector_calls: Option<usize>,
+    /// Maximum number of connector calls in progress for batch traffic
+    pub batch_max_concurrent_connector_calls: usize,
+}
+
+/// Rendering and download of the receipts of successful payments
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct Receipts {
+    /// Whether receipt download urls are exposed in payment responses and webhooks
+    pub enabled: bool,
+    /// Validity of the signed receipt download urls in seconds
+    pub download_url_expiry_in_secs: i64,
+    /// Time for which a rendered receipt is served from the file storage before it is rendered
+    /// again, in seconds
+    pub cache_ttl_in_secs: i64,
+}
+
+/// Reminders sent for invoices which are not paid in full
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct Invoices {
+    /// Days before the due date at which reminder webhooks are sent
+    pub reminder_days_before_due: Vec<u8>,
+    /// Days after the due date at which reminder webhooks are sent for overdue invoices
+    pub overdue_reminder_days: Vec<u8>,
+}
+
+/// Fees charged by the connectors for the disputes lost by the merchants, by connector name
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct ChargebackFees(HashMap<String, ChargebackFee>);
+
+impl ChargebackFees {
+    pub fn get_fee(&self, connector: &str) -> Option<&ChargebackFee> {
+        self.0.get(connector)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ChargebackFee {
+    /// The fee in the lowest denomination of the currency
+    pub amount: common_utils::types::MinorUnit,
+    pub currency: enums::Currency,
+}
+
+/// Compliance rules for the debits scheduled on e-mandates, as required for recurring payments in
+/// India
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct EMandate {
+    /// Connectors which support debits on e-mandates
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<enums::Connector>,
+    /// Hours before a debit at which the customer is notified of it
+    pub pre_debit_notification_hours: u16,
+    /// Hours after the scheduled time of a debit within which it is retried if it fails
+    pub debit_retry_window_hours: u16,
+    /// Hours between the retries of a failed debit
+    pub debit_retry_interval_hours: u16,
+    /// Maximum amount of a debit, in the lowest denomination of INR, above which an additional
+    /// factor of authentication is required from the customer
+    pub max_debit_amount: common_utils::types::MinorUnit,
+}
+
+/// External tokenization services which merchant accounts can use in place of the card vault
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ExternalVault {
+    pub providers: HashMap<String, ExternalVaultProvider>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct ExternalVaultProvider {
+    /// Endpoint exchanging a card number for a format preserving alias
+    pub tokenize_url: String,
+    /// Value of the authorization header sent to the tokenize endpoint
+    pub api_key: Secret<String>,
+    /// Forward proxy replacing the aliases in the connector requests with the card numbers, the
+    /// credentials of the proxy are part of the url
+    pub proxy_url: Secret<String>,
+    /// Base64 encoded certificate of the authority signing the certificates presented by the
+    /// forward proxy
+    pub proxy_ca_certificate: Option<Secret<String>>,
+}
+
+/// Replay of sanitized copies of selected requests on a staging deployment, to validate upgrades
+/// against the shapes of real traffic
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct TrafficMirroring {
+    pub enabled: bool,
+    /// Base url of the staging deployment the requests are mirrored to
+    pub target_base_url: String,
+    /// Api key of the merchant account on the staging deployment the mirrored requests are made
+    /// on, the merchant account is expected to be in simulation mode
+    pub api_key: Secret<String>,
+    /// Flows whose requests are mirrored
+    pub flows: HashSet<String>,
+    /// Percentage of the requests of the selected flows which are mirrored
+    pub sampling_percentage: u8,
+    /// Values replacing the masked fields of the mirrored requests, keyed by the name of the field
+    pub placeholders: HashMap<String, String>,
+    /// Timeout of the mirrored requests in seconds
+    pub request_timeout_in_secs: u64,
+    /// Whether mirrored requests received by this deployment are accepted, for merchant accounts
+    /// in simulation mode only
+    pub accept_mirrored_requests: bool,
+}
+
+/// Platform accounts of connectors which sandbox merchant accounts can use for test payments
+/// before configuring their own connector accounts
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct DemoConnectors {
+    pub enabled: bool,
+    /// Number of payments a merchant account can make through the demo connectors in a day
+    pub daily_payment_quota: u64,
+    /// The demo connectors, keyed by the name of the connector
+    pub connectors: HashMap<String, DemoConnector>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct DemoConnector {
+    /// Credentials of the platform account of the connector
+    pub connector_account_details: hyperswitch_domain_models::router_data::ConnectorAuthType,
+    /// Payment methods enabled on the connector accounts created for the merchant accounts
+    #[serde(default)]
+    pub payment_methods_enabled: Vec<api_models::admin::PaymentMethodsEnabled>,
+}
+
+/// Regions in which the data of merchant accounts is kept. Every region is served by its own
+/// deployment, whose database holds the data of the merchant accounts of the region.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct DataResidency {
+    pub enabled: bool,
+    /// The region served by this deployment, merchant accounts created on this deployment and
+    /// merchant accounts without a region belong to it
+    pub current_region: String,
+    pub regions: HashMap<String, DataRegion>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct DataRegion {
+    /// Base url of the deployment serving the region
+    pub base_url: String,
+    /// Hosts of the card vault of the region
+    pub locker_host: String,
+    pub locker_host_rs: String,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct LockSettings {
     pub redis_lock_expiry_seconds: u32,
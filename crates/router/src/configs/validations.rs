@@ -181,6 +181,216 @@ impl super::settings::LockSettings {
     }
 }
 
+impl super::settings::RequestLanes {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.live_max_concurrent_connector_calls == Some(0), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "live_max_concurrent_connector_calls must not be 0".into(),
+            ))
+        })?;
+
+        when(self.batch_max_concurrent_connector_calls == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "batch_max_concurrent_connector_calls must not be 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::Receipts {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.download_url_expiry_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "receipt download_url_expiry_in_secs must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.cache_ttl_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "receipt cache_ttl_in_secs must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::Invoices {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.reminder_days_before_due.contains(&0) || self.overdue_reminder_days.contains(&0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "invoice reminder days must be greater than 0".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::EMandate {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.pre_debit_notification_hours < 24, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "pre-debit notifications must be sent at least 24 hours before the debit".into(),
+            ))
+        })?;
+
+        when(self.debit_retry_interval_hours == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "debit retry interval must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ExternalVault {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.providers.iter().try_for_each(|(name, provider)| {
+            when(
+                provider.tokenize_url.is_default_or_empty()
+                    || provider.api_key.is_default_or_empty()
+                    || provider.proxy_url.is_default_or_empty(),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "tokenize url, api key and proxy url of external vault provider {name} must not be empty"
+                    )))
+                },
+            )
+        })
+    }
+}
+
+impl super::settings::DemoConnectors {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use std::str::FromStr;
+
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(self.daily_payment_quota == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "daily payment quota of the demo connectors must not be 0".into(),
+            ))
+        })?;
+
+        self.connectors.keys().try_for_each(|name| {
+            api_models::enums::Connector::from_str(name)
+                .map(|_| ())
+                .map_err(|_| {
+                    ApplicationError::InvalidConfigurationValueError(format!(
+                        "demo connector {name} is not a supported connector"
+                    ))
+                })
+        })
+    }
+}
+
+impl super::settings::DataResidency {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(!self.regions.contains_key(&self.current_region), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "data residency current region must be one of the configured regions".into(),
+            ))
+        })?;
+
+        self.regions.iter().try_for_each(|(name, region)| {
+            when(
+                region.base_url.is_default_or_empty()
+                    || region.locker_host.is_default_or_empty()
+                    || region.locker_host_rs.is_default_or_empty(),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "base url and locker hosts of data region {name} must not be empty"
+                    )))
+                },
+            )
+        })
+    }
+}
+
+impl super::settings::TrafficMirroring {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(
+            self.target_base_url.is_default_or_empty() || self.api_key.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "target base url and api key of traffic mirroring must not be empty".into(),
+                ))
+            },
+        )?;
+
+        when(self.sampling_percentage > 100, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "sampling percentage of traffic mirroring must not exceed 100".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RefundSettlementEstimation {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(
+            self.min_samples == 0 || self.min_samples > self.max_samples,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "minimum samples of refund settlement estimation must be positive and must not exceed the maximum samples".into(),
+                ))
+            },
+        )?;
+
+        when(self.percentile == 0 || self.percentile > 100, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "percentile of refund settlement estimation must be between 1 and 100".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RiskEnrichmentConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.night_start_hour > 23 || self.night_end_hour > 23,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "night hours of risk enrichment must be between 0 and 23".into(),
+                ))
+            },
+        )
+    }
+}
+
 impl super::settings::GenericLinkEnvConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
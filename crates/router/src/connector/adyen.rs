@@ -18,7 +18,9 @@ use crate::connector::utils::PayoutsData;
 use crate::{
     capture_method_not_supported,
     configs::settings,
-    connector::utils::{convert_amount, PaymentMethodDataType},
+    connector::utils::{
+        construct_idempotency_key, convert_amount, PassthroughRequest, PaymentMethodDataType,
+    },
     consts,
     core::errors::{self, CustomResult},
     events::connector_api_logs::ConnectorEvent,
@@ -72,6 +74,10 @@ impl ConnectorCommon for Adyen {
         connectors.adyen.base_url.as_ref()
     }
 
+    fn get_request_id_headers(&self) -> &'static [&'static str] {
+        &["pspreference"]
+    }
+
     fn build_error_response(
         &self,
         res: types::Response,
@@ -535,6 +541,23 @@ impl
         let connector_req = adyen::AdyenCaptureRequest::try_from(&connector_router_data)?;
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
+    fn get_idempotency_header(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+    ) -> CustomResult<Option<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let reference_id = req
+            .request
+            .multiple_capture_data
+            .as_ref()
+            .map_or(req.attempt_id.as_str(), |capture_data| {
+                capture_data.capture_reference.as_str()
+            });
+        Ok(Some((
+            headers::IDEMPOTENCY_KEY.to_string(),
+            construct_idempotency_key::<api::Capture>(reference_id).into(),
+        )))
+    }
+
     fn build_request(
         &self,
         req: &types::PaymentsCaptureRouterData,
@@ -816,10 +839,24 @@ impl
             req.request.currency,
         )?;
         let connector_router_data = adyen::AdyenRouterData::try_from((amount, req))?;
-        let connector_req = adyen::AdyenPaymentRequest::try_from(&connector_router_data)?;
+        let connector_req = PassthroughRequest::new(
+            adyen::AdyenPaymentRequest::try_from(&connector_router_data)?,
+            api_models::enums::Connector::Adyen,
+            req.request.connector_passthrough_data.as_ref(),
+        );
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
+    fn get_idempotency_header(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+    ) -> CustomResult<Option<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(Some((
+            headers::IDEMPOTENCY_KEY.to_string(),
+            construct_idempotency_key::<api::Authorize>(&req.attempt_id).into(),
+        )))
+    }
+
     fn build_request(
         &self,
         req: &types::PaymentsAuthorizeRouterData,
@@ -1024,6 +1061,105 @@ impl
     }
 }
 
+impl api::PaymentGiftCardBalanceCheck for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::Balance,
+        types::GiftCardBalanceCheckRequestData,
+        types::GiftCardBalanceCheckResponseData,
+    > for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::PaymentsBalanceRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PaymentsBalanceType::get_content_type(self)
+                .to_string()
+                .into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PaymentsBalanceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        let endpoint = build_env_specific_endpoint(
+            self.base_url(connectors),
+            req.test_mode,
+            &req.connector_meta_data,
+        )?;
+        Ok(format!(
+            "{}{}/paymentMethods/balance",
+            endpoint, ADYEN_API_VERSION
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PaymentsBalanceRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = adyen::AdyenBalanceRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PaymentsBalanceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Post)
+                .url(&types::PaymentsBalanceType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::PaymentsBalanceType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(types::PaymentsBalanceType::get_request_body(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PaymentsBalanceRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PaymentsBalanceRouterData, errors::ConnectorError> {
+        let response: adyen::AdyenBalanceResponse = res
+            .response
+            .parse_struct("AdyenBalanceResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
 impl
     services::ConnectorIntegration<
         api::Void,
@@ -1074,6 +1210,16 @@ impl
 
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
+    fn get_idempotency_header(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+    ) -> CustomResult<Option<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(Some((
+            headers::IDEMPOTENCY_KEY.to_string(),
+            construct_idempotency_key::<api::Void>(&req.attempt_id).into(),
+        )))
+    }
+
     fn build_request(
         &self,
         req: &types::PaymentsCancelRouterData,
@@ -1591,6 +1737,99 @@ impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::P
     }
 }
 
+impl api::ConnectorAccountBalance for Adyen {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccountBalance,
+        types::AccountBalanceRequestData,
+        types::AccountBalanceResponseData,
+    > for Adyen
+{
+    fn get_headers(
+        &self,
+        req: &types::AccountBalanceRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::AccountBalanceType::get_content_type(self)
+                .to_string()
+                .into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::AccountBalanceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        // Balances are held by balance accounts of the Adyen balance platform
+        let balance_account_id =
+            req.request
+                .account_id
+                .clone()
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "account_id",
+                })?;
+        Ok(format!(
+            "{}bcl/v2/balanceAccounts/{}",
+            connectors.adyenplatform.base_url, balance_account_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::AccountBalanceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::AccountBalanceType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::AccountBalanceType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::AccountBalanceRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::AccountBalanceRouterData, errors::ConnectorError> {
+        let response: adyen::AdyenBalanceAccountResponse = res
+            .response
+            .parse_struct("AdyenBalanceAccountResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
 impl api::Refund for Adyen {}
 impl api::RefundExecute for Adyen {}
 impl api::RefundSync for Adyen {}
@@ -1648,6 +1887,16 @@ impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::Ref
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
+    fn get_idempotency_header(
+        &self,
+        req: &types::RefundsRouterData<api::Execute>,
+    ) -> CustomResult<Option<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(Some((
+            headers::IDEMPOTENCY_KEY.to_string(),
+            construct_idempotency_key::<api::Execute>(&req.request.refund_id).into(),
+        )))
+    }
+
     fn build_request(
         &self,
         req: &types::RefundsRouterData<api::Execute>,
@@ -1805,7 +2054,10 @@ impl api::IncomingWebhook for Adyen {
         let signing_key = hmac::Key::new(hmac::HMAC_SHA256, &raw_key);
         let signed_messaged = hmac::sign(&signing_key, &message);
         let payload_sign = consts::BASE64_ENGINE.encode(signed_messaged.as_ref());
-        Ok(payload_sign.as_bytes().eq(&signature))
+        Ok(crypto::constant_time_eq(
+            payload_sign.as_bytes(),
+            &signature,
+        ))
     }
 
     fn get_webhook_object_reference_id(
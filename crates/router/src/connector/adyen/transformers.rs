@@ -1,6 +1,6 @@
 #[cfg(feature = "payouts")]
 use api_models::payouts::PayoutMethodData;
-use api_models::{enums, payments, webhooks};
+use api_models::{connector_options, enums, payments, webhooks};
 use cards::CardNumber;
 use common_utils::{errors::ParsingError, ext_traits::Encode, id_type, pii, types::MinorUnit};
 use error_stack::{report, ResultExt};
@@ -136,6 +136,21 @@ pub struct LineItem {
     quantity: Option<u16>,
 }
 
+/// Fields of the payment request which merchants can pass in `connector_metadata.adyen`, none of
+/// them are populated by [`AdyenPaymentRequest`]
+pub const PASSTHROUGH_FIELDS: &[&str] = &[
+    "accountInfo",
+    "applicationInfo",
+    "dateOfBirth",
+    "deviceFingerprint",
+    "fundingSource",
+    "industryUsage",
+    "merchantRiskIndicator",
+    "riskData",
+    "splits",
+    "store",
+];
+
 #[serde_with::skip_serializing_none]
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -159,6 +174,7 @@ pub struct AdyenPaymentRequest<'a> {
     shopper_locale: Option<String>,
     shopper_email: Option<Email>,
     shopper_statement: Option<String>,
+    mcc: Option<String>,
     social_security_number: Option<Secret<String>>,
     telephone_number: Option<Secret<String>>,
     billing_address: Option<Address>,
@@ -1612,35 +1628,55 @@ impl<'a> TryFrom<&AdyenRouterData<&types::PaymentsAuthorizeRouterData>>
     }
 }
 
-impl<'a> TryFrom<&types::PaymentsPreProcessingRouterData> for AdyenBalanceRequest<'a> {
-    type Error = Error;
-    fn try_from(item: &types::PaymentsPreProcessingRouterData) -> Result<Self, Self::Error> {
-        let payment_method = match &item.request.payment_method_data {
-            Some(domain::PaymentMethodData::GiftCard(gift_card_data)) => {
-                match gift_card_data.as_ref() {
-                    domain::GiftCardData::Givex(gift_card_data) => {
-                        let balance_pm = BalancePmData {
-                            payment_type: GiftCardBrand::Givex,
-                            number: gift_card_data.number.clone(),
-                            cvc: gift_card_data.cvc.clone(),
-                        };
-                        Ok(AdyenPaymentMethod::PaymentMethodBalance(Box::new(
-                            balance_pm,
-                        )))
-                    }
-                    domain::GiftCardData::PaySafeCard {} => {
-                        Err(errors::ConnectorError::FlowNotSupported {
-                            flow: "Balance".to_string(),
-                            connector: "adyen".to_string(),
-                        })
+fn get_balance_payment_method<'a>(
+    payment_method_data: Option<&domain::PaymentMethodData>,
+) -> Result<AdyenPaymentMethod<'a>, Error> {
+    match payment_method_data {
+        Some(domain::PaymentMethodData::GiftCard(gift_card_data)) => {
+            match gift_card_data.as_ref() {
+                domain::GiftCardData::Givex(gift_card_data) => {
+                    let balance_pm = BalancePmData {
+                        payment_type: GiftCardBrand::Givex,
+                        number: gift_card_data.number.clone(),
+                        cvc: gift_card_data.cvc.clone(),
+                    };
+                    Ok(AdyenPaymentMethod::PaymentMethodBalance(Box::new(
+                        balance_pm,
+                    )))
+                }
+                domain::GiftCardData::PaySafeCard {} => {
+                    Err(errors::ConnectorError::FlowNotSupported {
+                        flow: "Balance".to_string(),
+                        connector: "adyen".to_string(),
                     }
+                    .into())
                 }
             }
-            _ => Err(errors::ConnectorError::FlowNotSupported {
-                flow: "Balance".to_string(),
-                connector: "adyen".to_string(),
-            }),
-        }?;
+        }
+        _ => Err(errors::ConnectorError::FlowNotSupported {
+            flow: "Balance".to_string(),
+            connector: "adyen".to_string(),
+        }
+        .into()),
+    }
+}
+
+impl<'a> TryFrom<&types::PaymentsPreProcessingRouterData> for AdyenBalanceRequest<'a> {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsPreProcessingRouterData) -> Result<Self, Self::Error> {
+        let payment_method = get_balance_payment_method(item.request.payment_method_data.as_ref())?;
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            payment_method,
+            merchant_account: auth_type.merchant_account,
+        })
+    }
+}
+
+impl<'a> TryFrom<&types::PaymentsBalanceRouterData> for AdyenBalanceRequest<'a> {
+    type Error = Error;
+    fn try_from(item: &types::PaymentsBalanceRouterData) -> Result<Self, Self::Error> {
+        let payment_method = get_balance_payment_method(Some(&item.request.payment_method_data))?;
         let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
         Ok(Self {
             payment_method,
@@ -1651,12 +1687,26 @@ impl<'a> TryFrom<&types::PaymentsPreProcessingRouterData> for AdyenBalanceReques
 
 impl From<&types::PaymentsAuthorizeRouterData> for AdyenShopperInteraction {
     fn from(item: &types::PaymentsAuthorizeRouterData) -> Self {
-        match item.request.off_session {
-            Some(true) => Self::ContinuedAuthentication,
+        match (item.request.off_session, &item.connector_options) {
+            (Some(true), _) => Self::ContinuedAuthentication,
+            (_, Some(connector_options::ConnectorOptions::Adyen(options))) => options
+                .shopper_interaction
+                .map(Self::from)
+                .unwrap_or_default(),
             _ => Self::Ecommerce,
         }
     }
 }
+
+impl From<connector_options::AdyenShopperInteraction> for AdyenShopperInteraction {
+    fn from(shopper_interaction: connector_options::AdyenShopperInteraction) -> Self {
+        match shopper_interaction {
+            connector_options::AdyenShopperInteraction::Ecommerce => Self::Ecommerce,
+            connector_options::AdyenShopperInteraction::Moto => Self::Moto,
+            connector_options::AdyenShopperInteraction::Pos => Self::Pos,
+        }
+    }
+}
 type RecurringDetails = (Option<AdyenRecurringModel>, Option<bool>, Option<String>);
 
 fn get_recurring_processing_model(
@@ -2705,6 +2755,7 @@ impl<'a>
             store_payment_method,
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            mcc: item.router_data.request.merchant_category_code.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
@@ -2769,6 +2820,7 @@ impl<'a>
             store_payment_method,
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            mcc: item.router_data.request.merchant_category_code.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
@@ -2825,6 +2877,7 @@ impl<'a>
             store_payment_method,
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            mcc: item.router_data.request.merchant_category_code.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
@@ -2885,6 +2938,7 @@ impl<'a>
             store_payment_method: None,
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            mcc: item.router_data.request.merchant_category_code.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
@@ -2937,6 +2991,7 @@ impl<'a>
             store_payment_method: None,
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            mcc: item.router_data.request.merchant_category_code.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
@@ -2989,6 +3044,7 @@ impl<'a>
             channel: None,
             social_security_number: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            mcc: item.router_data.request.merchant_category_code.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
@@ -3053,6 +3109,7 @@ impl<'a>
             store_payment_method,
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            mcc: item.router_data.request.merchant_category_code.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
@@ -3170,6 +3227,7 @@ impl<'a>
             store_payment_method,
             channel,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            mcc: item.router_data.request.merchant_category_code.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
@@ -3246,6 +3304,7 @@ impl<'a>
             store_payment_method,
             channel: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            mcc: item.router_data.request.merchant_category_code.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
@@ -3306,6 +3365,7 @@ impl<'a>
             channel: None,
             social_security_number: None,
             shopper_statement: item.router_data.request.statement_descriptor.clone(),
+            mcc: item.router_data.request.merchant_category_code.clone(),
             shopper_ip: item.router_data.request.get_ip_address_as_optional(),
             metadata: item.router_data.request.metadata.clone().map(Into::into),
             merchant_order_reference: item.router_data.request.merchant_order_reference_id.clone(),
@@ -3389,6 +3449,23 @@ impl<F>
     }
 }
 
+impl TryFrom<types::PaymentsBalanceResponseRouterData<AdyenBalanceResponse>>
+    for types::PaymentsBalanceRouterData
+{
+    type Error = Error;
+    fn try_from(
+        item: types::PaymentsBalanceResponseRouterData<AdyenBalanceResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::GiftCardBalanceCheckResponseData {
+                balance: item.response.balance.value,
+                currency: item.response.balance.currency,
+            }),
+            ..item.data
+        })
+    }
+}
+
 pub fn get_adyen_response(
     response: Response,
     is_capture_manual: bool,
@@ -4186,6 +4263,61 @@ impl<F> TryFrom<types::RefundsResponseRouterData<F, AdyenRefundResponse>>
     }
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenBalanceAccountResponse {
+    pub id: String,
+    #[serde(default)]
+    pub balances: Vec<AdyenBalance>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdyenBalance {
+    pub currency: storage_enums::Currency,
+    pub available: MinorUnit,
+    pub pending: MinorUnit,
+    pub balance: MinorUnit,
+    pub reserved: Option<MinorUnit>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            AdyenBalanceAccountResponse,
+            T,
+            types::AccountBalanceResponseData,
+        >,
+    > for types::RouterData<F, T, types::AccountBalanceResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            AdyenBalanceAccountResponse,
+            T,
+            types::AccountBalanceResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let available = item
+            .response
+            .balances
+            .iter()
+            .map(|balance| (balance.currency, balance.available));
+        let pending = item
+            .response
+            .balances
+            .iter()
+            .map(|balance| (balance.currency, balance.pending));
+        let balances = utils::get_currency_balances(available, pending);
+        Ok(Self {
+            response: Ok(types::AccountBalanceResponseData { balances }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ErrorResponse {
@@ -376,7 +376,10 @@ impl api::IncomingWebhook for Adyenplatform {
         let signing_key = hmac::Key::new(hmac::HMAC_SHA256, &raw_key);
         let signed_messaged = hmac::sign(&signing_key, &message);
         let payload_sign = consts::BASE64_ENGINE.encode(signed_messaged.as_ref());
-        Ok(payload_sign.as_bytes().eq(&signature))
+        Ok(crypto::constant_time_eq(
+            payload_sign.as_bytes(),
+            &signature,
+        ))
     }
 
     fn get_webhook_object_reference_id(
@@ -89,6 +89,11 @@ impl ConnectorValidation for Authorizedotnet {
             std::collections::HashSet::from([crate::connector::utils::PaymentMethodDataType::Card]);
         connector_utils::is_mandate_supported(pm_data, pm_type, mandate_supported_pmd, self.id())
     }
+
+    fn get_void_window(&self) -> Option<services::VoidWindow> {
+        // Captured transactions are settled in the daily batch, until which they can be voided
+        Some(services::VoidWindow::SameDay)
+    }
 }
 
 impl api::Payment for Authorizedotnet {}
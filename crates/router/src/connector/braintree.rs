@@ -932,7 +932,10 @@ impl api::IncomingWebhook for Braintree {
         );
         let signed_messaged = hmac::sign(&signing_key, &message);
         let payload_sign: String = hex::encode(signed_messaged);
-        Ok(payload_sign.as_bytes().eq(&signature))
+        Ok(crypto::constant_time_eq(
+            payload_sign.as_bytes(),
+            &signature,
+        ))
     }
 
     fn get_webhook_object_reference_id(
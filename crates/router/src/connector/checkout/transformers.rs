@@ -225,6 +225,21 @@ pub struct ReturnUrl {
     pub failure_url: Option<String>,
 }
 
+/// Fields of the payment request which merchants can pass in `connector_metadata.checkout`, none
+/// of them are populated by [`PaymentsRequest`]
+pub const PASSTHROUGH_FIELDS: &[&str] = &[
+    "billing_descriptor",
+    "customer",
+    "description",
+    "items",
+    "payment_type",
+    "processing",
+    "recipient",
+    "risk",
+    "sender",
+    "shipping",
+];
+
 #[derive(Debug, Serialize)]
 pub struct PaymentsRequest {
     pub source: PaymentSource,
@@ -238,6 +253,14 @@ pub struct PaymentsRequest {
     pub capture: bool,
     pub reference: String,
     pub metadata: Option<Secret<serde_json::Value>>,
+    pub authorization_type: Option<CheckoutAuthorizationType>,
+}
+
+/// Estimated authorizations can be incremented after they are authorized
+#[derive(Debug, Serialize)]
+pub enum CheckoutAuthorizationType {
+    Estimated,
+    Final,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -418,13 +441,13 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
                 .request
                 .router_return_url
                 .as_ref()
-                .map(|return_url| format!("{return_url}?status=success")),
+                .map(|return_url| utils::append_query_param(return_url, "status", "success")),
             failure_url: item
                 .router_data
                 .request
                 .router_return_url
                 .as_ref()
-                .map(|return_url| format!("{return_url}?status=failure")),
+                .map(|return_url| utils::append_query_param(return_url, "status", "failure")),
         };
 
         let capture = matches!(
@@ -432,6 +455,10 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
             Some(enums::CaptureMethod::Automatic)
         );
 
+        let authorization_type = (!capture
+            && item.router_data.request.request_incremental_authorization)
+            .then_some(CheckoutAuthorizationType::Estimated);
+
         let connector_auth = &item.router_data.connector_auth_type;
         let auth_type: CheckoutAuthType = connector_auth.try_into()?;
         let processing_channel_id = auth_type.processing_channel_id;
@@ -446,6 +473,7 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
             capture,
             reference: item.router_data.connector_request_reference_id.clone(),
             metadata,
+            authorization_type,
         })
     }
 }
@@ -688,6 +716,10 @@ impl TryFrom<types::PaymentsResponseRouterData<PaymentsResponse>>
         } else {
             None
         };
+        let incremental_authorization_allowed = Some(
+            item.data.request.request_incremental_authorization
+                && status == enums::AttemptStatus::Authorized,
+        );
         let payments_response_data = types::PaymentsResponseData::TransactionResponse {
             resource_id: types::ResponseId::ConnectorTransactionId(item.response.id.clone()),
             redirection_data: Box::new(redirection_data),
@@ -697,7 +729,7 @@ impl TryFrom<types::PaymentsResponseRouterData<PaymentsResponse>>
             connector_response_reference_id: Some(
                 item.response.reference.unwrap_or(item.response.id),
             ),
-            incremental_authorization_allowed: None,
+            incremental_authorization_allowed,
             charge_id: None,
         };
         Ok(Self {
@@ -937,6 +969,68 @@ impl TryFrom<types::PaymentsCaptureResponseRouterData<PaymentCaptureResponse>>
     }
 }
 
+#[derive(Debug, Serialize)]
+pub struct CheckoutIncrementalAuthorizationRequest {
+    pub amount: MinorUnit,
+}
+
+impl TryFrom<&CheckoutRouterData<&types::PaymentsIncrementalAuthorizationRouterData>>
+    for CheckoutIncrementalAuthorizationRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: &CheckoutRouterData<&types::PaymentsIncrementalAuthorizationRouterData>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.amount.to_owned(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CheckoutIncrementalAuthorizationResponse {
+    pub action_id: String,
+    pub approved: Option<bool>,
+    pub response_code: Option<String>,
+    pub response_summary: Option<String>,
+}
+
+impl
+    TryFrom<
+        types::PaymentsIncrementalAuthorizationResponseRouterData<
+            CheckoutIncrementalAuthorizationResponse,
+        >,
+    > for types::PaymentsIncrementalAuthorizationRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PaymentsIncrementalAuthorizationResponseRouterData<
+            CheckoutIncrementalAuthorizationResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let (status, error_code, error_message) = if item.response.approved == Some(true) {
+            (common_enums::AuthorizationStatus::Success, None, None)
+        } else {
+            (
+                common_enums::AuthorizationStatus::Failure,
+                item.response.response_code,
+                item.response.response_summary,
+            )
+        };
+        Ok(Self {
+            response: Ok(
+                types::PaymentsResponseData::IncrementalAuthorizationResponse {
+                    status,
+                    error_code,
+                    error_message,
+                    connector_authorization_id: Some(item.response.action_id),
+                },
+            ),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RefundRequest {
     amount: Option<MinorUnit>,
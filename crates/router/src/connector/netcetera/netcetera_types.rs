@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use common_utils::pii::Email;
+use error_stack::ResultExt;
 use masking::ExposeInterface;
 use serde::{Deserialize, Serialize};
 use unidecode::unidecode;
@@ -8,7 +9,7 @@ use unidecode::unidecode;
 use crate::{
     connector::utils::{AddressDetailsData, PhoneDetailsData},
     errors,
-    types::api::MessageCategory,
+    types::{self, api::MessageCategory},
 };
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -43,6 +44,66 @@ impl From<api_models::payments::DeviceChannel> for NetceteraDeviceChannel {
         match value {
             api_models::payments::DeviceChannel::App => Self::AppBased,
             api_models::payments::DeviceChannel::Browser => Self::Browser,
+            api_models::payments::DeviceChannel::ThreeDsRequestorInitiated => {
+                Self::ThreeDsRequestorInitiated
+            }
+        }
+    }
+}
+
+/// Indicates the type of 3RI request. This field is required when deviceChannel = 03 (3RI).
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub enum ThreeRIIndicator {
+    #[serde(rename = "01")]
+    RecurringTransaction,
+    #[serde(rename = "02")]
+    InstalmentTransaction,
+    #[serde(rename = "03")]
+    AddCard,
+    #[serde(rename = "04")]
+    MaintainCardInformation,
+    #[serde(rename = "05")]
+    AccountVerification,
+    #[serde(rename = "06")]
+    SplitOrDelayedShipment,
+    #[serde(rename = "07")]
+    TopUp,
+    #[serde(rename = "08")]
+    MailOrder,
+    #[serde(rename = "09")]
+    TelephoneOrder,
+    #[serde(rename = "10")]
+    WhitelistStatusCheck,
+    #[serde(rename = "11")]
+    OtherPayment,
+}
+
+impl From<api_models::payments::ThreeRiIndicator> for ThreeRIIndicator {
+    fn from(value: api_models::payments::ThreeRiIndicator) -> Self {
+        match value {
+            api_models::payments::ThreeRiIndicator::RecurringTransaction => {
+                Self::RecurringTransaction
+            }
+            api_models::payments::ThreeRiIndicator::InstalmentTransaction => {
+                Self::InstalmentTransaction
+            }
+            api_models::payments::ThreeRiIndicator::AddCard => Self::AddCard,
+            api_models::payments::ThreeRiIndicator::MaintainCardInformation => {
+                Self::MaintainCardInformation
+            }
+            api_models::payments::ThreeRiIndicator::AccountVerification => {
+                Self::AccountVerification
+            }
+            api_models::payments::ThreeRiIndicator::SplitOrDelayedShipment => {
+                Self::SplitOrDelayedShipment
+            }
+            api_models::payments::ThreeRiIndicator::TopUp => Self::TopUp,
+            api_models::payments::ThreeRiIndicator::MailOrder => Self::MailOrder,
+            api_models::payments::ThreeRiIndicator::TelephoneOrder => Self::TelephoneOrder,
+            api_models::payments::ThreeRiIndicator::WhitelistStatusCheck => {
+                Self::WhitelistStatusCheck
+            }
+            api_models::payments::ThreeRiIndicator::OtherPayment => Self::OtherPayment,
         }
     }
 }
@@ -291,6 +352,35 @@ pub struct ThreeDSRequestorPriorTransactionAuthenticationInformation {
     pub three_ds_req_prior_auth_data: String,
 }
 
+impl TryFrom<types::authentication::PriorAuthenticationData>
+    for ThreeDSRequestorPriorTransactionAuthenticationInformation
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        value: types::authentication::PriorAuthenticationData,
+    ) -> Result<Self, Self::Error> {
+        let three_ds_req_prior_auth_method = match value.authentication_type {
+            Some(common_enums::DecoupledAuthenticationType::Challenge) => "02",
+            Some(common_enums::DecoupledAuthenticationType::Frictionless) | None => "01",
+        };
+        Ok(Self {
+            three_ds_req_prior_ref: value.acs_trans_id,
+            three_ds_req_prior_auth_method: three_ds_req_prior_auth_method.to_string(),
+            three_ds_req_prior_auth_timestamp: common_utils::date_time::format_date(
+                value.authenticated_at,
+                common_utils::date_time::DateFormat::YYYYMMDDHHmm,
+            )
+            .change_context(
+                errors::ConnectorError::RequestEncodingFailedWithReason(
+                    "Failed to format Date".to_string(),
+                ),
+            )?,
+            // Not defined in detail by the specification
+            three_ds_req_prior_auth_data: String::new(),
+        })
+    }
+}
+
 /// Enum indicating whether the 3DS Requestor requests the ACS to utilize Decoupled Authentication.
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum ThreeDSRequestorDecoupledRequestIndicator {
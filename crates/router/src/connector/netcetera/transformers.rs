@@ -437,7 +437,7 @@ pub struct NetceteraAuthenticationRequest {
     pub challenge_message_extension: Option<Vec<netcetera_types::MessageExtensionAttribute>>,
     pub browser_information: Option<netcetera_types::Browser>,
     #[serde(rename = "threeRIInd")]
-    pub three_ri_ind: Option<String>,
+    pub three_ri_ind: Option<netcetera_types::ThreeRIIndicator>,
     pub sdk_information: Option<netcetera_types::Sdk>,
     pub device: Option<String>,
     pub multi_transaction: Option<String>,
@@ -461,7 +461,14 @@ impl TryFrom<&NetceteraRouterData<&types::authentication::ConnectorAuthenticatio
                 netcetera_types::ThreeDSRequestorAuthenticationIndicator::Payment,
             three_ds_requestor_authentication_info: None,
             three_ds_requestor_challenge_ind: None,
-            three_ds_requestor_prior_authentication_info: None,
+            three_ds_requestor_prior_authentication_info: request
+                .prior_authentication_data
+                .map(|prior_authentication_data| {
+                    prior_authentication_data
+                        .try_into()
+                        .map(netcetera_types::SingleOrListElement::new_single)
+                })
+                .transpose()?,
             three_ds_requestor_dec_req_ind: None,
             three_ds_requestor_dec_max_time: None,
             app_ip: None,
@@ -541,13 +548,15 @@ impl TryFrom<&NetceteraRouterData<&types::authentication::ConnectorAuthenticatio
             api_models::payments::DeviceChannel::Browser => {
                 request.browser_details.map(netcetera_types::Browser::from)
             }
-            api_models::payments::DeviceChannel::App => None,
+            api_models::payments::DeviceChannel::App
+            | api_models::payments::DeviceChannel::ThreeDsRequestorInitiated => None,
         };
         let sdk_information = match request.device_channel {
             api_models::payments::DeviceChannel::App => {
                 request.sdk_information.map(netcetera_types::Sdk::from)
             }
-            api_models::payments::DeviceChannel::Browser => None,
+            api_models::payments::DeviceChannel::Browser
+            | api_models::payments::DeviceChannel::ThreeDsRequestorInitiated => None,
         };
         let device_render_options = match request.device_channel {
             api_models::payments::DeviceChannel::App => {
@@ -563,7 +572,8 @@ impl TryFrom<&NetceteraRouterData<&types::authentication::ConnectorAuthenticatio
                     ],
                 })
             }
-            api_models::payments::DeviceChannel::Browser => None,
+            api_models::payments::DeviceChannel::Browser
+            | api_models::payments::DeviceChannel::ThreeDsRequestorInitiated => None,
         };
         Ok(Self {
             preferred_protocol_version: Some(pre_authn_data.message_version),
@@ -592,7 +602,9 @@ impl TryFrom<&NetceteraRouterData<&types::authentication::ConnectorAuthenticatio
             message_extension: None,
             challenge_message_extension: None,
             browser_information,
-            three_ri_ind: None,
+            three_ri_ind: request
+                .three_ri_indicator
+                .map(netcetera_types::ThreeRIIndicator::from),
             sdk_information,
             device: None,
             multi_transaction: None,
@@ -132,6 +132,11 @@ impl ConnectorValidation for Nmi {
         // in case we dont have transaction id, we can make psync using attempt id
         Ok(())
     }
+
+    fn get_void_window(&self) -> Option<services::VoidWindow> {
+        // Sales can only be voided until they are settled at the end of the day
+        Some(services::VoidWindow::SameDay)
+    }
 }
 
 impl
@@ -15,6 +15,8 @@ use ring::hmac;
 use transformers as rapyd;
 
 use super::utils as connector_utils;
+#[cfg(feature = "payouts")]
+use super::utils::{PayoutsData, RouterData};
 use crate::{
     configs::settings,
     connector::utils::convert_amount,
@@ -143,6 +145,16 @@ impl ConnectorValidation for Rapyd {
             ),
         }
     }
+
+    fn validate_mandate_payment(
+        &self,
+        pm_type: Option<enums::PaymentMethodType>,
+        pm_data: types::domain::payments::PaymentMethodData,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        let mandate_supported_pmd =
+            std::collections::HashSet::from([connector_utils::PaymentMethodDataType::Card]);
+        connector_utils::is_mandate_supported(pm_data, pm_type, mandate_supported_pmd, self.id())
+    }
 }
 
 impl api::ConnectorAccessToken for Rapyd {}
@@ -217,6 +229,16 @@ impl
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
+    fn get_idempotency_header(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+    ) -> CustomResult<Option<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(Some((
+            "idempotency".to_string(),
+            connector_utils::construct_idempotency_key::<api::Authorize>(&req.attempt_id).into(),
+        )))
+    }
+
     fn build_request(
         &self,
         req: &types::RouterData<
@@ -288,6 +310,111 @@ impl
 
 impl api::Payment for Rapyd {}
 
+impl api::ConnectorCustomer for Rapyd {}
+impl
+    services::ConnectorIntegration<
+        api::CreateConnectorCustomer,
+        types::ConnectorCustomerData,
+        types::PaymentsResponseData,
+    > for Rapyd
+{
+    fn get_headers(
+        &self,
+        _req: &types::ConnectorCustomerRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::ConnectorCustomerType::get_content_type(self)
+                .to_string()
+                .into(),
+        )])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::ConnectorCustomerRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/v1/customers", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::ConnectorCustomerRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = rapyd::RapydCustomerRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::ConnectorCustomerRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let timestamp = date_time::now_unix_timestamp();
+        let salt = Alphanumeric.sample_string(&mut rand::thread_rng(), 12);
+
+        let auth: rapyd::RapydAuthType = rapyd::RapydAuthType::try_from(&req.connector_auth_type)?;
+        let body = types::ConnectorCustomerType::get_request_body(self, req, connectors)?;
+        let req_body = body.get_inner_value().expose();
+        let signature =
+            self.generate_signature(&auth, "post", "/v1/customers", &req_body, &timestamp, &salt)?;
+        let headers = vec![
+            ("access_key".to_string(), auth.access_key.into_masked()),
+            ("salt".to_string(), salt.into_masked()),
+            ("timestamp".to_string(), timestamp.to_string().into()),
+            ("signature".to_string(), signature.into_masked()),
+        ];
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::ConnectorCustomerType::get_url(
+                self, req, connectors,
+            )?)
+            .attach_default_headers()
+            .headers(types::ConnectorCustomerType::get_headers(
+                self, req, connectors,
+            )?)
+            .headers(headers)
+            .set_body(body)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::ConnectorCustomerRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::ConnectorCustomerRouterData, errors::ConnectorError> {
+        let response: rapyd::RapydCustomerResponse = res
+            .response
+            .parse_struct("Rapyd CustomerResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
 impl api::MandateSetup for Rapyd {}
 impl
     services::ConnectorIntegration<
@@ -296,19 +423,96 @@ impl
         types::PaymentsResponseData,
     > for Rapyd
 {
-    fn build_request(
+    fn get_headers(
         &self,
-        _req: &types::RouterData<
-            api::SetupMandate,
-            types::SetupMandateRequestData,
-            types::PaymentsResponseData,
-        >,
+        _req: &types::SetupMandateRouterData,
         _connectors: &settings::Connectors,
-    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
-        Err(
-            errors::ConnectorError::NotImplemented("Setup Mandate flow for Rapyd".to_string())
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::SetupMandateType::get_content_type(self)
+                .to_string()
                 .into(),
-        )
+        )])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::SetupMandateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/v1/payments", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::SetupMandateRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = rapyd::RapydPaymentsRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::SetupMandateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let timestamp = date_time::now_unix_timestamp();
+        let salt = Alphanumeric.sample_string(&mut rand::thread_rng(), 12);
+
+        let auth: rapyd::RapydAuthType = rapyd::RapydAuthType::try_from(&req.connector_auth_type)?;
+        let body = types::SetupMandateType::get_request_body(self, req, connectors)?;
+        let req_body = body.get_inner_value().expose();
+        let signature =
+            self.generate_signature(&auth, "post", "/v1/payments", &req_body, &timestamp, &salt)?;
+        let headers = vec![
+            ("access_key".to_string(), auth.access_key.into_masked()),
+            ("salt".to_string(), salt.into_masked()),
+            ("timestamp".to_string(), timestamp.to_string().into()),
+            ("signature".to_string(), signature.into_masked()),
+        ];
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::SetupMandateType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::SetupMandateType::get_headers(self, req, connectors)?)
+            .headers(headers)
+            .set_body(body)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::SetupMandateRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::SetupMandateRouterData, errors::ConnectorError> {
+        let response: rapyd::RapydPaymentsResponse = res
+            .response
+            .parse_struct("Rapyd PaymentResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
     }
 }
 
@@ -350,6 +554,16 @@ impl
         ))
     }
 
+    fn get_idempotency_header(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+    ) -> CustomResult<Option<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(Some((
+            "idempotency".to_string(),
+            connector_utils::construct_idempotency_key::<api::Void>(&req.attempt_id).into(),
+        )))
+    }
+
     fn build_request(
         &self,
         req: &types::PaymentsCancelRouterData,
@@ -547,6 +761,23 @@ impl
         Ok(RequestContent::Json(Box::new(connector_req)))
     }
 
+    fn get_idempotency_header(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+    ) -> CustomResult<Option<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let reference_id = req
+            .request
+            .multiple_capture_data
+            .as_ref()
+            .map_or(req.attempt_id.as_str(), |capture_data| {
+                capture_data.capture_reference.as_str()
+            });
+        Ok(Some((
+            "idempotency".to_string(),
+            connector_utils::construct_idempotency_key::<api::Capture>(reference_id).into(),
+        )))
+    }
+
     fn build_request(
         &self,
         req: &types::PaymentsCaptureRouterData,
@@ -640,67 +871,56 @@ impl
     //TODO: implement sessions flow
 }
 
-impl api::Refund for Rapyd {}
-impl api::RefundExecute for Rapyd {}
-impl api::RefundSync for Rapyd {}
+impl api::ConnectorAccountBalance for Rapyd {}
 
-impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
-    for Rapyd
+impl
+    services::ConnectorIntegration<
+        api::AccountBalance,
+        types::AccountBalanceRequestData,
+        types::AccountBalanceResponseData,
+    > for Rapyd
 {
     fn get_headers(
         &self,
-        _req: &types::RefundsRouterData<api::Execute>,
+        _req: &types::AccountBalanceRouterData,
         _connectors: &settings::Connectors,
     ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
         Ok(vec![(
             headers::CONTENT_TYPE.to_string(),
-            types::RefundExecuteType::get_content_type(self)
+            types::AccountBalanceType::get_content_type(self)
                 .to_string()
                 .into(),
         )])
     }
 
     fn get_content_type(&self) -> &'static str {
-        ConnectorCommon::common_get_content_type(self)
+        self.common_get_content_type()
     }
 
     fn get_url(
         &self,
-        _req: &types::RefundsRouterData<api::Execute>,
+        req: &types::AccountBalanceRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
-        Ok(format!("{}/v1/refunds", self.base_url(connectors)))
-    }
-
-    fn get_request_body(
-        &self,
-        req: &types::RefundsRouterData<api::Execute>,
-        _connectors: &settings::Connectors,
-    ) -> CustomResult<RequestContent, errors::ConnectorError> {
-        let amount = convert_amount(
-            self.amount_converter,
-            req.request.minor_refund_amount,
-            req.request.currency,
-        )?;
-        let connector_router_data = rapyd::RapydRouterData::from((amount, req));
-        let connector_req = rapyd::RapydRefundRequest::try_from(&connector_router_data)?;
-
-        Ok(RequestContent::Json(Box::new(connector_req)))
+        Ok(format!(
+            "{}{}",
+            self.base_url(connectors),
+            get_wallet_accounts_path(req)?
+        ))
     }
 
     fn build_request(
         &self,
-        req: &types::RefundsRouterData<api::Execute>,
+        req: &types::AccountBalanceRouterData,
         connectors: &settings::Connectors,
     ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
         let timestamp = date_time::now_unix_timestamp();
         let salt = Alphanumeric.sample_string(&mut rand::thread_rng(), 12);
 
-        let body = types::RefundExecuteType::get_request_body(self, req, connectors)?;
-        let req_body = body.get_inner_value().expose();
         let auth: rapyd::RapydAuthType = rapyd::RapydAuthType::try_from(&req.connector_auth_type)?;
-        let signature =
-            self.generate_signature(&auth, "post", "/v1/refunds", &req_body, &timestamp, &salt)?;
+        let url_path = get_wallet_accounts_path(req)?;
+        let signature = self.generate_signature(&auth, "get", &url_path, "", &timestamp, &salt)?;
+
         let headers = vec![
             ("access_key".to_string(), auth.access_key.into_masked()),
             ("salt".to_string(), salt.into_masked()),
@@ -708,37 +928,17 @@ impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::Ref
             ("signature".to_string(), signature.into_masked()),
         ];
         let request = services::RequestBuilder::new()
-            .method(services::Method::Post)
-            .url(&types::RefundExecuteType::get_url(self, req, connectors)?)
+            .method(services::Method::Get)
+            .url(&types::AccountBalanceType::get_url(self, req, connectors)?)
             .attach_default_headers()
-            .headers(headers)
-            .set_body(types::RefundExecuteType::get_request_body(
+            .headers(types::AccountBalanceType::get_headers(
                 self, req, connectors,
             )?)
+            .headers(headers)
             .build();
         Ok(Some(request))
     }
 
-    fn handle_response(
-        &self,
-        data: &types::RefundsRouterData<api::Execute>,
-        event_builder: Option<&mut ConnectorEvent>,
-        res: types::Response,
-    ) -> CustomResult<types::RefundsRouterData<api::Execute>, errors::ConnectorError> {
-        let response: rapyd::RefundResponse = res
-            .response
-            .parse_struct("rapyd RefundResponse")
-            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
-        event_builder.map(|i| i.set_response_body(&response));
-        router_env::logger::info!(connector_response=?response);
-        types::RouterData::try_from(types::ResponseRouterData {
-            response,
-            data: data.clone(),
-            http_code: res.status_code,
-        })
-        .change_context(errors::ConnectorError::ResponseHandlingFailed)
-    }
-
     fn get_error_response(
         &self,
         res: types::Response,
@@ -746,21 +946,16 @@ impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::Ref
     ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
         self.build_error_response(res, event_builder)
     }
-}
 
-impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData>
-    for Rapyd
-{
-    // default implementation of build_request method will be executed
     fn handle_response(
         &self,
-        data: &types::RefundSyncRouterData,
+        data: &types::AccountBalanceRouterData,
         event_builder: Option<&mut ConnectorEvent>,
         res: types::Response,
-    ) -> CustomResult<types::RefundSyncRouterData, errors::ConnectorError> {
-        let response: rapyd::RefundResponse = res
+    ) -> CustomResult<types::AccountBalanceRouterData, errors::ConnectorError> {
+        let response: rapyd::RapydWalletAccountsResponse = res
             .response
-            .parse_struct("rapyd RefundResponse")
+            .parse_struct("Rapyd WalletAccountsResponse")
             .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
         event_builder.map(|i| i.set_response_body(&response));
         router_env::logger::info!(connector_response=?response);
@@ -773,6 +968,1029 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
     }
 }
 
+fn get_wallet_accounts_path(
+    req: &types::AccountBalanceRouterData,
+) -> CustomResult<String, errors::ConnectorError> {
+    let ewallet_id =
+        req.request
+            .account_id
+            .clone()
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "account_id",
+            })?;
+    Ok(format!("/v1/user/{ewallet_id}/accounts"))
+}
+
+impl api::Refund for Rapyd {}
+impl api::RefundExecute for Rapyd {}
+impl api::RefundSync for Rapyd {}
+
+impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::RefundsResponseData>
+    for Rapyd
+{
+    fn get_headers(
+        &self,
+        _req: &types::RefundsRouterData<api::Execute>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::RefundExecuteType::get_content_type(self)
+                .to_string()
+                .into(),
+        )])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        ConnectorCommon::common_get_content_type(self)
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::RefundsRouterData<api::Execute>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/v1/refunds", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::RefundsRouterData<api::Execute>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let amount = convert_amount(
+            self.amount_converter,
+            req.request.minor_refund_amount,
+            req.request.currency,
+        )?;
+        let connector_router_data = rapyd::RapydRouterData::from((amount, req));
+        let connector_req = rapyd::RapydRefundRequest::try_from(&connector_router_data)?;
+
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn get_idempotency_header(
+        &self,
+        req: &types::RefundsRouterData<api::Execute>,
+    ) -> CustomResult<Option<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(Some((
+            "idempotency".to_string(),
+            connector_utils::construct_idempotency_key::<api::Execute>(&req.request.refund_id)
+                .into(),
+        )))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::RefundsRouterData<api::Execute>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let timestamp = date_time::now_unix_timestamp();
+        let salt = Alphanumeric.sample_string(&mut rand::thread_rng(), 12);
+
+        let body = types::RefundExecuteType::get_request_body(self, req, connectors)?;
+        let req_body = body.get_inner_value().expose();
+        let auth: rapyd::RapydAuthType = rapyd::RapydAuthType::try_from(&req.connector_auth_type)?;
+        let signature =
+            self.generate_signature(&auth, "post", "/v1/refunds", &req_body, &timestamp, &salt)?;
+        let headers = vec![
+            ("access_key".to_string(), auth.access_key.into_masked()),
+            ("salt".to_string(), salt.into_masked()),
+            ("timestamp".to_string(), timestamp.to_string().into()),
+            ("signature".to_string(), signature.into_masked()),
+        ];
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::RefundExecuteType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(headers)
+            .set_body(types::RefundExecuteType::get_request_body(
+                self, req, connectors,
+            )?)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::RefundsRouterData<api::Execute>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::RefundsRouterData<api::Execute>, errors::ConnectorError> {
+        let response: rapyd::RefundResponse = res
+            .response
+            .parse_struct("rapyd RefundResponse")
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponseData>
+    for Rapyd
+{
+    // default implementation of build_request method will be executed
+    fn handle_response(
+        &self,
+        data: &types::RefundSyncRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::RefundSyncRouterData, errors::ConnectorError> {
+        let response: rapyd::RefundResponse = res
+            .response
+            .parse_struct("rapyd RefundResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+}
+
+impl api::Dispute for Rapyd {}
+impl api::AcceptDispute for Rapyd {}
+impl api::SubmitEvidence for Rapyd {}
+impl api::DefendDispute for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Accept,
+        types::AcceptDisputeRequestData,
+        types::AcceptDisputeResponse,
+    > for Rapyd
+{
+    fn get_headers(
+        &self,
+        _req: &types::AcceptDisputeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::AcceptDisputeType::get_content_type(self)
+                .to_string()
+                .into(),
+        )])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}/v1/disputes/{}/accept",
+            self.base_url(connectors),
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::AcceptDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let timestamp = date_time::now_unix_timestamp();
+        let salt = Alphanumeric.sample_string(&mut rand::thread_rng(), 12);
+
+        let auth: rapyd::RapydAuthType = rapyd::RapydAuthType::try_from(&req.connector_auth_type)?;
+        let url_path = format!("/v1/disputes/{}/accept", req.request.connector_dispute_id);
+        let signature = self.generate_signature(&auth, "post", &url_path, "", &timestamp, &salt)?;
+        let headers = vec![
+            ("access_key".to_string(), auth.access_key.into_masked()),
+            ("salt".to_string(), salt.into_masked()),
+            ("timestamp".to_string(), timestamp.to_string().into()),
+            ("signature".to_string(), signature.into_masked()),
+        ];
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::AcceptDisputeType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::AcceptDisputeType::get_headers(
+                self, req, connectors,
+            )?)
+            .headers(headers)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::AcceptDisputeRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::AcceptDisputeRouterData, errors::ConnectorError> {
+        let response: rapyd::RapydDisputeResponse = res
+            .response
+            .parse_struct("Rapyd DisputeResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        Ok(types::AcceptDisputeRouterData {
+            response: Ok(types::AcceptDisputeResponse {
+                dispute_status: api::enums::DisputeStatus::DisputeAccepted,
+                connector_status: response.data.map(|data| data.status.to_string()),
+            }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl api::UploadFile for Rapyd {}
+
+impl api::RetrieveFile for Rapyd {}
+
+impl
+    services::ConnectorIntegration<
+        api::Retrieve,
+        types::RetrieveFileRequestData,
+        types::RetrieveFileResponse,
+    > for Rapyd
+{
+}
+
+#[async_trait::async_trait]
+impl api::FileUpload for Rapyd {
+    fn validate_file_upload(
+        &self,
+        purpose: api::FilePurpose,
+        file_size: i32,
+        file_type: mime::Mime,
+    ) -> CustomResult<(), errors::ConnectorError> {
+        match purpose {
+            api::FilePurpose::DisputeEvidence => {
+                let supported_file_types =
+                    ["image/jpeg", "image/jpg", "image/png", "application/pdf"];
+                // 5 Megabytes (MB)
+                if file_size > 5000000 {
+                    Err(errors::ConnectorError::FileValidationFailed {
+                        reason: "file_size exceeded the max file size of 5MB".to_owned(),
+                    })?
+                }
+                if !supported_file_types.contains(&file_type.to_string().as_str()) {
+                    Err(errors::ConnectorError::FileValidationFailed {
+                        reason: "file_type does not match JPEG, JPG, PNG, or PDF format".to_owned(),
+                    })?
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Upload,
+        types::UploadFileRequestData,
+        types::UploadFileResponse,
+    > for Rapyd
+{
+    fn get_headers(
+        &self,
+        _req: &types::UploadFileRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::UploadFileType::get_content_type(self)
+                .to_string()
+                .into(),
+        )])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/v1/files", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::UploadFileRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = rapyd::RapydFileUploadRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::UploadFileRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let timestamp = date_time::now_unix_timestamp();
+        let salt = Alphanumeric.sample_string(&mut rand::thread_rng(), 12);
+
+        let auth: rapyd::RapydAuthType = rapyd::RapydAuthType::try_from(&req.connector_auth_type)?;
+        let body = types::UploadFileType::get_request_body(self, req, connectors)?;
+        let req_body = body.get_inner_value().expose();
+        let signature =
+            self.generate_signature(&auth, "post", "/v1/files", &req_body, &timestamp, &salt)?;
+        let headers = vec![
+            ("access_key".to_string(), auth.access_key.into_masked()),
+            ("salt".to_string(), salt.into_masked()),
+            ("timestamp".to_string(), timestamp.to_string().into()),
+            ("signature".to_string(), signature.into_masked()),
+        ];
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::UploadFileType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::UploadFileType::get_headers(self, req, connectors)?)
+            .headers(headers)
+            .set_body(body)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::UploadFileRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::UploadFileRouterData, errors::ConnectorError> {
+        let response: rapyd::RapydFileUploadResponse = res
+            .response
+            .parse_struct("Rapyd FileUploadResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        let file = response
+            .data
+            .ok_or(errors::ConnectorError::ResponseHandlingFailed)?;
+        Ok(types::UploadFileRouterData {
+            response: Ok(types::UploadFileResponse {
+                provider_file_id: file.id,
+            }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+/// Attaches the evidence to the dispute, the evidence is submitted for review by the defend
+/// dispute flow
+impl
+    services::ConnectorIntegration<
+        api::Evidence,
+        types::SubmitEvidenceRequestData,
+        types::SubmitEvidenceResponse,
+    > for Rapyd
+{
+    fn get_headers(
+        &self,
+        _req: &types::SubmitEvidenceRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::SubmitEvidenceType::get_content_type(self)
+                .to_string()
+                .into(),
+        )])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}/v1/disputes/{}/evidence",
+            self.base_url(connectors),
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let connector_req = rapyd::RapydDisputeEvidenceRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::SubmitEvidenceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let timestamp = date_time::now_unix_timestamp();
+        let salt = Alphanumeric.sample_string(&mut rand::thread_rng(), 12);
+
+        let auth: rapyd::RapydAuthType = rapyd::RapydAuthType::try_from(&req.connector_auth_type)?;
+        let body = types::SubmitEvidenceType::get_request_body(self, req, connectors)?;
+        let req_body = body.get_inner_value().expose();
+        let url_path = format!("/v1/disputes/{}/evidence", req.request.connector_dispute_id);
+        let signature =
+            self.generate_signature(&auth, "post", &url_path, &req_body, &timestamp, &salt)?;
+        let headers = vec![
+            ("access_key".to_string(), auth.access_key.into_masked()),
+            ("salt".to_string(), salt.into_masked()),
+            ("timestamp".to_string(), timestamp.to_string().into()),
+            ("signature".to_string(), signature.into_masked()),
+        ];
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::SubmitEvidenceType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::SubmitEvidenceType::get_headers(
+                self, req, connectors,
+            )?)
+            .headers(headers)
+            .set_body(body)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::SubmitEvidenceRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::SubmitEvidenceRouterData, errors::ConnectorError> {
+        let response: rapyd::RapydDisputeResponse = res
+            .response
+            .parse_struct("Rapyd DisputeResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        Ok(types::SubmitEvidenceRouterData {
+            response: Ok(types::SubmitEvidenceResponse {
+                dispute_status: api_models::enums::DisputeStatus::DisputeChallenged,
+                connector_status: response.data.map(|data| data.status.to_string()),
+            }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl
+    services::ConnectorIntegration<
+        api::Defend,
+        types::DefendDisputeRequestData,
+        types::DefendDisputeResponse,
+    > for Rapyd
+{
+    fn get_headers(
+        &self,
+        _req: &types::DefendDisputeRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::DefendDisputeType::get_content_type(self)
+                .to_string()
+                .into(),
+        )])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::DefendDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}/v1/disputes/{}/evidence/submit",
+            self.base_url(connectors),
+            req.request.connector_dispute_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::DefendDisputeRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let timestamp = date_time::now_unix_timestamp();
+        let salt = Alphanumeric.sample_string(&mut rand::thread_rng(), 12);
+
+        let auth: rapyd::RapydAuthType = rapyd::RapydAuthType::try_from(&req.connector_auth_type)?;
+        let url_path = format!(
+            "/v1/disputes/{}/evidence/submit",
+            req.request.connector_dispute_id
+        );
+        let signature = self.generate_signature(&auth, "post", &url_path, "", &timestamp, &salt)?;
+        let headers = vec![
+            ("access_key".to_string(), auth.access_key.into_masked()),
+            ("salt".to_string(), salt.into_masked()),
+            ("timestamp".to_string(), timestamp.to_string().into()),
+            ("signature".to_string(), signature.into_masked()),
+        ];
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::DefendDisputeType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::DefendDisputeType::get_headers(
+                self, req, connectors,
+            )?)
+            .headers(headers)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::DefendDisputeRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::DefendDisputeRouterData, errors::ConnectorError> {
+        let response: rapyd::RapydDisputeResponse = res
+            .response
+            .parse_struct("Rapyd DisputeResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        let (dispute_status, connector_status) = match response.data {
+            Some(data) => (
+                api::enums::DisputeStatus::from(data.status.clone()),
+                Some(data.status.to_string()),
+            ),
+            None => (api::enums::DisputeStatus::DisputeChallenged, None),
+        };
+        Ok(types::DefendDisputeRouterData {
+            response: Ok(types::DefendDisputeResponse {
+                dispute_status,
+                connector_status,
+            }),
+            ..data.clone()
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl api::Payouts for Rapyd {}
+#[cfg(feature = "payouts")]
+impl api::PayoutCancel for Rapyd {}
+#[cfg(feature = "payouts")]
+impl api::PayoutCreate for Rapyd {}
+#[cfg(feature = "payouts")]
+impl api::PayoutEligibility for Rapyd {}
+#[cfg(feature = "payouts")]
+impl api::PayoutFulfill for Rapyd {}
+#[cfg(feature = "payouts")]
+impl api::PayoutQuote for Rapyd {}
+#[cfg(feature = "payouts")]
+impl api::PayoutRecipient for Rapyd {}
+
+#[cfg(feature = "payouts")]
+impl services::ConnectorIntegration<api::PoQuote, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+    fn get_headers(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoQuote>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PayoutQuoteType::get_content_type(self)
+                .to_string()
+                .into(),
+        )])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoQuote>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}/v1/payouts", self.base_url(connectors)))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &types::PayoutsRouterData<api::PoQuote>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        let amount = convert_amount(
+            self.amount_converter,
+            req.request.minor_amount,
+            req.request.destination_currency,
+        )?;
+        let connector_router_data = rapyd::RapydRouterData::from((amount, req));
+        let connector_req = rapyd::RapydPayoutCreateRequest::try_from(&connector_router_data)?;
+        Ok(RequestContent::Json(Box::new(connector_req)))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoQuote>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let timestamp = date_time::now_unix_timestamp();
+        let salt = Alphanumeric.sample_string(&mut rand::thread_rng(), 12);
+
+        let auth: rapyd::RapydAuthType = rapyd::RapydAuthType::try_from(&req.connector_auth_type)?;
+        let body = types::PayoutQuoteType::get_request_body(self, req, connectors)?;
+        let req_body = body.get_inner_value().expose();
+        let signature =
+            self.generate_signature(&auth, "post", "/v1/payouts", &req_body, &timestamp, &salt)?;
+        let headers = vec![
+            ("access_key".to_string(), auth.access_key.into_masked()),
+            ("salt".to_string(), salt.into_masked()),
+            ("timestamp".to_string(), timestamp.to_string().into()),
+            ("signature".to_string(), signature.into_masked()),
+        ];
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::PayoutQuoteType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::PayoutQuoteType::get_headers(self, req, connectors)?)
+            .headers(headers)
+            .set_body(body)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoQuote>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoQuote>, errors::ConnectorError> {
+        let response: rapyd::RapydPayoutResponse = res
+            .response
+            .parse_struct("Rapyd PayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response: rapyd::RapydPayoutQuoteResponse(response),
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+/// The payout held by Rapyd in the quote flow is looked up and recorded as the created payout
+#[cfg(feature = "payouts")]
+impl services::ConnectorIntegration<api::PoCreate, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+    fn get_headers(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoCreate>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PayoutCreateType::get_content_type(self)
+                .to_string()
+                .into(),
+        )])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}/v1/payouts/{}",
+            self.base_url(connectors),
+            req.get_quote_id()?
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCreate>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let timestamp = date_time::now_unix_timestamp();
+        let salt = Alphanumeric.sample_string(&mut rand::thread_rng(), 12);
+
+        let auth: rapyd::RapydAuthType = rapyd::RapydAuthType::try_from(&req.connector_auth_type)?;
+        let url_path = format!("/v1/payouts/{}", req.get_quote_id()?);
+        let signature = self.generate_signature(&auth, "get", &url_path, "", &timestamp, &salt)?;
+        let headers = vec![
+            ("access_key".to_string(), auth.access_key.into_masked()),
+            ("salt".to_string(), salt.into_masked()),
+            ("timestamp".to_string(), timestamp.to_string().into()),
+            ("signature".to_string(), signature.into_masked()),
+        ];
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&types::PayoutCreateType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::PayoutCreateType::get_headers(self, req, connectors)?)
+            .headers(headers)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoCreate>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCreate>, errors::ConnectorError> {
+        let response: rapyd::RapydPayoutResponse = res
+            .response
+            .parse_struct("Rapyd PayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+/// Confirms the payout held by Rapyd, releasing it at the quoted exchange rate
+#[cfg(feature = "payouts")]
+impl services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+    fn get_headers(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoFulfill>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PayoutFulfillType::get_content_type(self)
+                .to_string()
+                .into(),
+        )])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}/v1/payouts/confirm/{}",
+            self.base_url(connectors),
+            req.request.get_transfer_id()?
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoFulfill>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let timestamp = date_time::now_unix_timestamp();
+        let salt = Alphanumeric.sample_string(&mut rand::thread_rng(), 12);
+
+        let auth: rapyd::RapydAuthType = rapyd::RapydAuthType::try_from(&req.connector_auth_type)?;
+        let url_path = format!("/v1/payouts/confirm/{}", req.request.get_transfer_id()?);
+        let signature = self.generate_signature(&auth, "post", &url_path, "", &timestamp, &salt)?;
+        let headers = vec![
+            ("access_key".to_string(), auth.access_key.into_masked()),
+            ("salt".to_string(), salt.into_masked()),
+            ("timestamp".to_string(), timestamp.to_string().into()),
+            ("signature".to_string(), signature.into_masked()),
+        ];
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&types::PayoutFulfillType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::PayoutFulfillType::get_headers(
+                self, req, connectors,
+            )?)
+            .headers(headers)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoFulfill>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoFulfill>, errors::ConnectorError> {
+        let response: rapyd::RapydPayoutResponse = res
+            .response
+            .parse_struct("Rapyd PayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl services::ConnectorIntegration<api::PoCancel, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+    fn get_headers(
+        &self,
+        _req: &types::PayoutsRouterData<api::PoCancel>,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::PayoutCancelType::get_content_type(self)
+                .to_string()
+                .into(),
+        )])
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}/v1/payouts/{}",
+            self.base_url(connectors),
+            req.request.get_transfer_id()?
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::PayoutsRouterData<api::PoCancel>,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        let timestamp = date_time::now_unix_timestamp();
+        let salt = Alphanumeric.sample_string(&mut rand::thread_rng(), 12);
+
+        let auth: rapyd::RapydAuthType = rapyd::RapydAuthType::try_from(&req.connector_auth_type)?;
+        let url_path = format!("/v1/payouts/{}", req.request.get_transfer_id()?);
+        let signature =
+            self.generate_signature(&auth, "delete", &url_path, "", &timestamp, &salt)?;
+        let headers = vec![
+            ("access_key".to_string(), auth.access_key.into_masked()),
+            ("salt".to_string(), salt.into_masked()),
+            ("timestamp".to_string(), timestamp.to_string().into()),
+            ("signature".to_string(), signature.into_masked()),
+        ];
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Delete)
+            .url(&types::PayoutCancelType::get_url(self, req, connectors)?)
+            .attach_default_headers()
+            .headers(types::PayoutCancelType::get_headers(self, req, connectors)?)
+            .headers(headers)
+            .build();
+        Ok(Some(request))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::PayoutsRouterData<api::PoCancel>,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::PayoutsRouterData<api::PoCancel>, errors::ConnectorError> {
+        let response: rapyd::RapydPayoutResponse = res
+            .response
+            .parse_struct("Rapyd PayoutResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl
+    services::ConnectorIntegration<
+        api::PoEligibility,
+        types::PayoutsData,
+        types::PayoutsResponseData,
+    > for Rapyd
+{
+}
+
+#[cfg(feature = "payouts")]
+impl
+    services::ConnectorIntegration<api::PoRecipient, types::PayoutsData, types::PayoutsResponseData>
+    for Rapyd
+{
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Rapyd {
     fn get_webhook_source_verification_algorithm(
@@ -828,6 +2046,25 @@ impl api::IncomingWebhook for Rapyd {
         Ok(to_sign.into_bytes())
     }
 
+    fn get_webhook_timestamp(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<i64>, errors::ConnectorError> {
+        connector_utils::get_header_key_value("timestamp", request.headers)?
+            .parse()
+            .map(Some)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
+            .attach_printable("Could not parse the webhook timestamp")
+    }
+
+    fn get_webhook_nonce(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        let salt = connector_utils::get_header_key_value("salt", request.headers)?;
+        Ok(Some(salt.to_string()))
+    }
+
     async fn verify_webhook_source(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
@@ -865,7 +2102,7 @@ impl api::IncomingWebhook for Rapyd {
         let key = hmac::Key::new(hmac::HMAC_SHA256, secret_key.peek().as_bytes());
         let tag = hmac::sign(&key, &message);
         let hmac_sign = hex::encode(tag);
-        Ok(hmac_sign.as_bytes().eq(&signature))
+        Ok(crypto::constant_time_eq(hmac_sign.as_bytes(), &signature))
     }
 
     fn get_webhook_object_reference_id(
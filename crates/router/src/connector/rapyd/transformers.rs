@@ -1,17 +1,26 @@
-use common_utils::types::MinorUnit;
+use api_models::connector_options;
+#[cfg(feature = "payouts")]
+use api_models::payouts::{Bank, PayoutMethodData};
+use base64::Engine;
+use common_utils::types::{FloatMajorUnit, FloatMajorUnitForConnector, MinorUnit};
 use error_stack::ResultExt;
+use hyperswitch_domain_models::router_request_types::AuthenticationData;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use url::Url;
 
+#[cfg(feature = "payouts")]
+use crate::connector::utils::{AddressDetailsData, PayoutsData};
 use crate::{
-    connector::utils::{PaymentsAuthorizeRequestData, RouterData},
+    connector::utils::{
+        self as connector_utils, convert_back_amount_to_minor_units, get_currency_balances,
+        CardData, CardIssuer, PaymentsAuthorizeRequestData, RouterData,
+    },
     consts,
     core::errors,
-    pii::Secret,
+    pii::{Email, Secret},
     services,
     types::{self, api, domain, storage::enums, transformers::ForeignFrom},
-    utils::OptionExt,
 };
 
 #[derive(Debug, Serialize)]
@@ -33,19 +42,70 @@ impl<T> From<(MinorUnit, T)> for RapydRouterData<T> {
 pub struct RapydPaymentsRequest {
     pub amount: MinorUnit,
     pub currency: enums::Currency,
-    pub payment_method: PaymentMethod,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<RapydPaymentMethodData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initiation_type: Option<RapydInitiationType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub save_payment_method: Option<bool>,
     pub payment_method_options: Option<PaymentMethodOptions>,
     pub merchant_reference_id: Option<String>,
     pub capture: Option<bool>,
     pub description: Option<String>,
     pub complete_payment_url: Option<String>,
     pub error_payment_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RapydInitiationType {
+    CustomerPresent,
+    Recurring,
+}
+
+/// The payment method to be charged, either its details or the id of a payment method saved for
+/// the customer
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum RapydPaymentMethodData {
+    PaymentMethod(PaymentMethod),
+    SavedPaymentMethod(String),
 }
 
 #[derive(Default, Debug, Serialize)]
 pub struct PaymentMethodOptions {
     #[serde(rename = "3d_required")]
     pub three_ds: bool,
+    #[serde(flatten)]
+    pub external_three_ds: Option<RapydExternalThreeDs>,
+}
+
+/// Result of the 3DS authentication performed by an external 3DS server, the card is then
+/// authorized without Rapyd authenticating the customer again
+#[derive(Debug, Serialize)]
+pub struct RapydExternalThreeDs {
+    #[serde(rename = "3d_version")]
+    pub version: String,
+    pub cavv: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eci: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ds_trans_id: Option<String>,
+}
+
+impl From<&AuthenticationData> for RapydExternalThreeDs {
+    fn from(authentication_data: &AuthenticationData) -> Self {
+        Self {
+            version: authentication_data.message_version.to_string(),
+            cavv: Secret::new(authentication_data.cavv.clone()),
+            eci: authentication_data.eci.clone(),
+            ds_trans_id: authentication_data.ds_trans_id.clone(),
+        }
+    }
 }
 #[derive(Default, Debug, Serialize)]
 pub struct PaymentMethod {
@@ -86,19 +146,81 @@ pub struct RapydWallet {
     token: Option<Secret<String>>,
 }
 
+/// Rapyd identifies card payment methods by the country and the network of the card, such as
+/// `us_visa_card`
+fn get_card_payment_method_type(
+    ccard: &domain::Card,
+    country: Option<enums::CountryAlpha2>,
+) -> Result<String, error_stack::Report<errors::ConnectorError>> {
+    let country = country.ok_or(errors::ConnectorError::MissingRequiredField {
+        field_name: "billing.address.country",
+    })?;
+    let network = match ccard.get_card_issuer()? {
+        CardIssuer::Visa => "visa",
+        CardIssuer::Master => "mastercard",
+        CardIssuer::Maestro => "maestro",
+        CardIssuer::AmericanExpress => "amex",
+        CardIssuer::Discover => "discover",
+        CardIssuer::DinersClub | CardIssuer::CarteBlanche => "diners",
+        CardIssuer::JCB => "jcb",
+    };
+    Ok(format!(
+        "{}_{network}_card",
+        country.to_string().to_lowercase()
+    ))
+}
+
+fn get_card_payment_method(
+    ccard: &domain::Card,
+    card_holder_name: Option<Secret<String>>,
+    country: Option<enums::CountryAlpha2>,
+) -> Result<PaymentMethod, error_stack::Report<errors::ConnectorError>> {
+    Ok(PaymentMethod {
+        pm_type: get_card_payment_method_type(ccard, country)?,
+        fields: Some(PaymentFields {
+            number: ccard.card_number.to_owned(),
+            expiration_month: ccard.card_exp_month.to_owned(),
+            expiration_year: ccard.card_exp_year.to_owned(),
+            name: card_holder_name.unwrap_or(Secret::new("".to_string())),
+            cvv: ccard.card_cvc.to_owned(),
+        }),
+        address: None,
+        digital_wallet: None,
+    })
+}
+
 impl TryFrom<&RapydRouterData<&types::PaymentsAuthorizeRouterData>> for RapydPaymentsRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
         item: &RapydRouterData<&types::PaymentsAuthorizeRouterData>,
     ) -> Result<Self, Self::Error> {
+        // Recurring payments charge the card saved for the customer when the mandate was set up,
+        // without the customer being present
+        let mandate_payment_method = item.router_data.request.connector_mandate_id();
+        let customer = mandate_payment_method
+            .as_ref()
+            .map(|_| item.router_data.get_connector_customer_id())
+            .transpose()?
+            .map(Secret::new);
         let (capture, payment_method_options) = match item.router_data.payment_method {
             diesel_models::enums::PaymentMethod::Card => {
-                let three_ds_enabled = matches!(
-                    item.router_data.auth_type,
-                    enums::AuthenticationType::ThreeDs
-                );
+                let external_three_ds = item
+                    .router_data
+                    .request
+                    .authentication_data
+                    .as_ref()
+                    .filter(|_| mandate_payment_method.is_none())
+                    .map(RapydExternalThreeDs::from);
+                // The customer is already authenticated when the external 3DS data is passed on
+                let three_ds_enabled = mandate_payment_method.is_none()
+                    && external_three_ds.is_none()
+                    && matches!(
+                        item.router_data.auth_type,
+                        enums::AuthenticationType::ThreeDs
+                    );
                 let payment_method_options = PaymentMethodOptions {
                     three_ds: three_ds_enabled,
+                    external_three_ds,
                 };
                 (
                     Some(matches!(
@@ -111,24 +233,16 @@ impl TryFrom<&RapydRouterData<&types::PaymentsAuthorizeRouterData>> for RapydPay
             _ => (None, None),
         };
         let payment_method = match item.router_data.request.payment_method_data {
-            domain::PaymentMethodData::Card(ref ccard) => {
-                Some(PaymentMethod {
-                    pm_type: "in_amex_card".to_owned(), //[#369] Map payment method type based on country
-                    fields: Some(PaymentFields {
-                        number: ccard.card_number.to_owned(),
-                        expiration_month: ccard.card_exp_month.to_owned(),
-                        expiration_year: ccard.card_exp_year.to_owned(),
-                        name: item
-                            .router_data
-                            .get_optional_billing_full_name()
-                            .to_owned()
-                            .unwrap_or(Secret::new("".to_string())),
-                        cvv: ccard.card_cvc.to_owned(),
-                    }),
-                    address: None,
-                    digital_wallet: None,
-                })
-            }
+            _ if mandate_payment_method.is_some() => mandate_payment_method
+                .clone()
+                .map(RapydPaymentMethodData::SavedPaymentMethod),
+            domain::PaymentMethodData::Card(ref ccard) => Some(
+                RapydPaymentMethodData::PaymentMethod(get_card_payment_method(
+                    ccard,
+                    item.router_data.get_optional_billing_full_name(),
+                    item.router_data.get_optional_billing_country(),
+                )?),
+            ),
             domain::PaymentMethodData::Wallet(ref wallet_data) => {
                 let digital_wallet = match wallet_data {
                     domain::WalletData::GooglePay(data) => Some(RapydWallet {
@@ -141,30 +255,161 @@ impl TryFrom<&RapydRouterData<&types::PaymentsAuthorizeRouterData>> for RapydPay
                     }),
                     _ => None,
                 };
-                Some(PaymentMethod {
+                Some(RapydPaymentMethodData::PaymentMethod(PaymentMethod {
                     pm_type: "by_visa_card".to_string(), //[#369]
                     fields: None,
                     address: None,
                     digital_wallet,
-                })
+                }))
             }
-            _ => None,
-        }
-        .get_required_value("payment_method not implemented")
-        .change_context(errors::ConnectorError::NotImplemented(
-            "payment_method".to_owned(),
-        ))?;
+            _ => Err(errors::ConnectorError::NotImplemented(
+                "payment_method".to_owned(),
+            ))?,
+        };
+        let initiation_type = mandate_payment_method
+            .is_some()
+            .then_some(RapydInitiationType::Recurring);
         let return_url = item.router_data.request.get_return_url()?;
+        let cashier_page = match &item.router_data.connector_options {
+            Some(connector_options::ConnectorOptions::Rapyd(options)) => {
+                options.cashier_page.clone().unwrap_or_default()
+            }
+            _ => connector_options::RapydCashierPageOptions::default(),
+        };
         Ok(Self {
             amount: item.amount,
             currency: item.router_data.request.currency,
             payment_method,
+            customer,
+            initiation_type,
+            save_payment_method: None,
             capture,
             payment_method_options,
             merchant_reference_id: Some(item.router_data.connector_request_reference_id.clone()),
             description: None,
-            error_payment_url: Some(return_url.clone()),
-            complete_payment_url: Some(return_url),
+            error_payment_url: Some(
+                cashier_page
+                    .error_payment_url
+                    .map(|url| url.to_string())
+                    .unwrap_or_else(|| return_url.clone()),
+            ),
+            complete_payment_url: Some(
+                cashier_page
+                    .complete_payment_url
+                    .map(|url| url.to_string())
+                    .unwrap_or(return_url),
+            ),
+            expiration: cashier_page
+                .payment_expiration_seconds
+                .map(|seconds| common_utils::date_time::now_unix_timestamp() + i64::from(seconds)),
+        })
+    }
+}
+
+/// Verifies the card with a zero amount payment, saving it for the customer so that recurring
+/// payments can be charged to it
+impl TryFrom<&types::SetupMandateRouterData> for RapydPaymentsRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::SetupMandateRouterData) -> Result<Self, Self::Error> {
+        let payment_method = match item.request.payment_method_data {
+            domain::PaymentMethodData::Card(ref ccard) => get_card_payment_method(
+                ccard,
+                item.get_optional_billing_full_name(),
+                item.get_optional_billing_country(),
+            )?,
+            _ => Err(errors::ConnectorError::NotImplemented(
+                connector_utils::get_unimplemented_payment_method_error_message("rapyd"),
+            ))?,
+        };
+        let payment_method_options = PaymentMethodOptions {
+            three_ds: matches!(item.auth_type, enums::AuthenticationType::ThreeDs),
+            external_three_ds: None,
+        };
+        let return_url = item.request.router_return_url.clone().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "return_url",
+            },
+        )?;
+        Ok(Self {
+            amount: MinorUnit::zero(),
+            currency: item.request.currency,
+            payment_method: Some(RapydPaymentMethodData::PaymentMethod(payment_method)),
+            customer: Some(Secret::new(item.get_connector_customer_id()?)),
+            initiation_type: Some(RapydInitiationType::CustomerPresent),
+            save_payment_method: Some(true),
+            payment_method_options: Some(payment_method_options),
+            merchant_reference_id: Some(item.connector_request_reference_id.clone()),
+            capture: None,
+            description: None,
+            complete_payment_url: Some(return_url.clone()),
+            error_payment_url: Some(return_url),
+            expiration: None,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RapydCustomerRequest {
+    pub name: Secret<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<Email>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl TryFrom<&types::ConnectorCustomerRouterData> for RapydCustomerRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ConnectorCustomerRouterData) -> Result<Self, Self::Error> {
+        let name = item
+            .request
+            .name
+            .clone()
+            .or_else(|| item.get_optional_billing_full_name())
+            .unwrap_or(Secret::new("".to_string()));
+        Ok(Self {
+            name,
+            email: item.request.email.clone(),
+            description: item.request.description.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RapydCustomerResponse {
+    pub status: Status,
+    pub data: Option<RapydCustomerData>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RapydCustomerData {
+    pub id: String,
+}
+
+impl<F, T>
+    TryFrom<types::ResponseRouterData<F, RapydCustomerResponse, T, types::PaymentsResponseData>>
+    for types::RouterData<F, T, types::PaymentsResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<F, RapydCustomerResponse, T, types::PaymentsResponseData>,
+    ) -> Result<Self, Self::Error> {
+        let response = match item.response.data {
+            Some(data) => Ok(types::PaymentsResponseData::ConnectorCustomerResponse {
+                connector_customer_id: data.id,
+            }),
+            None => Err(types::ErrorResponse {
+                code: item.response.status.error_code,
+                status_code: item.http_code,
+                message: item.response.status.status.unwrap_or_default(),
+                reason: item.response.status.message,
+                attempt_status: None,
+                connector_transaction_id: None,
+            }),
+        };
+
+        Ok(Self {
+            response,
+            ..item.data
         })
     }
 }
@@ -240,6 +485,69 @@ pub struct RapydPaymentsResponse {
     pub data: Option<ResponseData>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RapydWalletAccountsResponse {
+    pub status: Status,
+    pub data: Option<Vec<RapydWalletAccount>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RapydWalletAccount {
+    pub currency: enums::Currency,
+    pub balance: FloatMajorUnit,
+    pub on_hold_balance: FloatMajorUnit,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            RapydWalletAccountsResponse,
+            T,
+            types::AccountBalanceResponseData,
+        >,
+    > for types::RouterData<F, T, types::AccountBalanceResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            RapydWalletAccountsResponse,
+            T,
+            types::AccountBalanceResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let accounts = item.response.data.unwrap_or_default();
+        let mut available = Vec::with_capacity(accounts.len());
+        let mut pending = Vec::with_capacity(accounts.len());
+        // Rapyd reports wallet balances in major units, funds on hold are not yet available
+        for account in accounts {
+            available.push((
+                account.currency,
+                convert_back_amount_to_minor_units(
+                    &FloatMajorUnitForConnector,
+                    account.balance,
+                    account.currency,
+                )?,
+            ));
+            pending.push((
+                account.currency,
+                convert_back_amount_to_minor_units(
+                    &FloatMajorUnitForConnector,
+                    account.on_hold_balance,
+                    account.currency,
+                )?,
+            ));
+        }
+        Ok(Self {
+            response: Ok(types::AccountBalanceResponseData {
+                balances: get_currency_balances(available, pending),
+            }),
+            ..item.data
+        })
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Status {
     pub error_code: String,
@@ -278,6 +586,8 @@ pub struct ResponseData {
     pub paid: Option<bool>,
     pub failure_code: Option<String>,
     pub failure_message: Option<String>,
+    pub payment_method: Option<String>,
+    pub customer_token: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -469,6 +779,19 @@ impl<F, T>
                         let redirection_data = redirection_url
                             .map(|url| services::RedirectForm::from((url, services::Method::Get)));
 
+                        // Cards charged for a customer are saved for it, recurring payments
+                        // being charged to the saved card of the customer
+                        let mandate_reference = data
+                            .customer_token
+                            .as_ref()
+                            .and(data.payment_method.clone())
+                            .map(|payment_method| types::MandateReference {
+                                connector_mandate_id: Some(payment_method),
+                                payment_method_id: None,
+                                mandate_metadata: None,
+                                connector_mandate_request_reference_id: None,
+                            });
+
                         (
                             attempt_status,
                             Ok(types::PaymentsResponseData::TransactionResponse {
@@ -476,7 +799,7 @@ impl<F, T>
                                     data.id.to_owned(),
                                 ), //transaction_id is also the field but this id is used to initiate a refund
                                 redirection_data: Box::new(redirection_data),
-                                mandate_reference: Box::new(None),
+                                mandate_reference: Box::new(mandate_reference),
                                 connector_metadata: None,
                                 network_txn_id: None,
                                 connector_response_reference_id: data
@@ -562,6 +885,181 @@ impl From<RapydWebhookDisputeStatus> for api_models::webhooks::IncomingWebhookEv
     }
 }
 
+impl From<RapydWebhookDisputeStatus> for api_models::enums::DisputeStatus {
+    fn from(value: RapydWebhookDisputeStatus) -> Self {
+        match value {
+            RapydWebhookDisputeStatus::Active | RapydWebhookDisputeStatus::Unknown => {
+                Self::DisputeOpened
+            }
+            RapydWebhookDisputeStatus::Review => Self::DisputeChallenged,
+            RapydWebhookDisputeStatus::Lose => Self::DisputeLost,
+            RapydWebhookDisputeStatus::Win => Self::DisputeWon,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RapydDisputeResponse {
+    pub status: Status,
+    pub data: Option<RapydDisputeResponseData>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RapydDisputeResponseData {
+    pub id: String,
+    pub status: RapydWebhookDisputeStatus,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RapydFileUploadRequest {
+    pub file_name: String,
+    pub content_type: String,
+    /// Base64 encoded content of the file
+    pub file: Secret<String>,
+}
+
+impl TryFrom<&types::UploadFileRouterData> for RapydFileUploadRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::UploadFileRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            file_name: item.request.file_key.clone(),
+            content_type: item.request.file_type.to_string(),
+            file: Secret::new(consts::BASE64_ENGINE.encode(&item.request.file)),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RapydFileUploadResponse {
+    pub status: Status,
+    pub data: Option<RapydFileUploadResponseData>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RapydFileUploadResponseData {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RapydEvidenceDocumentType {
+    CancellationPolicy,
+    CustomerCommunication,
+    CustomerSignature,
+    Invoice,
+    Receipt,
+    RecurringTransactionAgreement,
+    RefundPolicy,
+    ServiceDocumentation,
+    ShippingDocumentation,
+    Other,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RapydEvidenceDocument {
+    #[serde(rename = "type")]
+    pub document_type: RapydEvidenceDocumentType,
+    pub file: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RapydDisputeEvidenceRequest {
+    pub documents: Vec<RapydEvidenceDocument>,
+    pub product_description: Option<String>,
+    pub customer_name: Option<String>,
+    pub customer_email_address: Option<String>,
+    pub customer_purchase_ip: Option<String>,
+    pub billing_address: Option<String>,
+    pub shipping_address: Option<String>,
+    pub shipping_carrier: Option<String>,
+    pub shipping_date: Option<String>,
+    pub shipping_tracking_number: Option<String>,
+    pub service_date: Option<String>,
+    pub cancellation_policy_disclosure: Option<String>,
+    pub cancellation_rebuttal: Option<String>,
+    pub refund_policy_disclosure: Option<String>,
+    pub refund_refusal_explanation: Option<String>,
+    pub access_activity_log: Option<String>,
+    pub additional_information: Option<String>,
+}
+
+impl TryFrom<&types::SubmitEvidenceRouterData> for RapydDisputeEvidenceRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::SubmitEvidenceRouterData) -> Result<Self, Self::Error> {
+        let request = item.request.clone();
+        let documents = [
+            (
+                RapydEvidenceDocumentType::CancellationPolicy,
+                request.cancellation_policy_provider_file_id,
+            ),
+            (
+                RapydEvidenceDocumentType::CustomerCommunication,
+                request.customer_communication_provider_file_id,
+            ),
+            (
+                RapydEvidenceDocumentType::CustomerSignature,
+                request.customer_signature_provider_file_id,
+            ),
+            (
+                RapydEvidenceDocumentType::Invoice,
+                request.invoice_showing_distinct_transactions_provider_file_id,
+            ),
+            (
+                RapydEvidenceDocumentType::Receipt,
+                request.receipt_provider_file_id,
+            ),
+            (
+                RapydEvidenceDocumentType::RecurringTransactionAgreement,
+                request.recurring_transaction_agreement_provider_file_id,
+            ),
+            (
+                RapydEvidenceDocumentType::RefundPolicy,
+                request.refund_policy_provider_file_id,
+            ),
+            (
+                RapydEvidenceDocumentType::ServiceDocumentation,
+                request.service_documentation_provider_file_id,
+            ),
+            (
+                RapydEvidenceDocumentType::ShippingDocumentation,
+                request.shipping_documentation_provider_file_id,
+            ),
+            (
+                RapydEvidenceDocumentType::Other,
+                request.uncategorized_file_provider_file_id,
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(document_type, file)| {
+            file.map(|file| RapydEvidenceDocument {
+                document_type,
+                file,
+            })
+        })
+        .collect();
+
+        Ok(Self {
+            documents,
+            product_description: request.product_description,
+            customer_name: request.customer_name,
+            customer_email_address: request.customer_email_address,
+            customer_purchase_ip: request.customer_purchase_ip,
+            billing_address: request.billing_address,
+            shipping_address: request.shipping_address,
+            shipping_carrier: request.shipping_carrier,
+            shipping_date: request.shipping_date,
+            shipping_tracking_number: request.shipping_tracking_number,
+            service_date: request.service_date,
+            cancellation_policy_disclosure: request.cancellation_policy_disclosure,
+            cancellation_rebuttal: request.cancellation_rebuttal,
+            refund_policy_disclosure: request.refund_policy_disclosure,
+            refund_refusal_explanation: request.refund_refusal_explanation,
+            access_activity_log: request.access_activity_log,
+            additional_information: request.uncategorized_text,
+        })
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum WebhookData {
@@ -599,3 +1097,397 @@ impl From<RefundResponseData> for RefundResponse {
         }
     }
 }
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Deserialize)]
+pub struct RapydPayoutMetadata {
+    pub sender_country: enums::CountryAlpha2,
+    pub sender_company_name: Secret<String>,
+}
+
+#[cfg(feature = "payouts")]
+impl TryFrom<&Option<Secret<serde_json::Value>>> for RapydPayoutMetadata {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(meta_data: &Option<Secret<serde_json::Value>>) -> Result<Self, Self::Error> {
+        connector_utils::to_connector_meta_from_secret::<Self>(meta_data.clone())
+            .change_context(errors::ConnectorError::InvalidConnectorConfig { config: "metadata" })
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+pub struct RapydPayoutCreateRequest {
+    payout_method_type: String,
+    payout_amount: MinorUnit,
+    payout_currency: enums::Currency,
+    sender_currency: enums::Currency,
+    sender_country: enums::CountryAlpha2,
+    sender_entity_type: RapydPayoutEntityType,
+    sender: RapydPayoutSender,
+    beneficiary_country: enums::CountryAlpha2,
+    beneficiary_entity_type: RapydPayoutEntityType,
+    beneficiary: RapydPayoutBeneficiary,
+    merchant_reference_id: String,
+    description: Option<String>,
+    confirm_automatically: bool,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RapydPayoutEntityType {
+    Individual,
+    Company,
+}
+
+#[cfg(feature = "payouts")]
+impl From<enums::PayoutEntityType> for RapydPayoutEntityType {
+    fn from(entity_type: enums::PayoutEntityType) -> Self {
+        match entity_type {
+            enums::PayoutEntityType::Individual
+            | enums::PayoutEntityType::Personal
+            | enums::PayoutEntityType::NaturalPerson => Self::Individual,
+            enums::PayoutEntityType::Company
+            | enums::PayoutEntityType::NonProfit
+            | enums::PayoutEntityType::PublicSector
+            | enums::PayoutEntityType::Business => Self::Company,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+pub struct RapydPayoutSender {
+    company_name: Secret<String>,
+    country: enums::CountryAlpha2,
+    currency: enums::Currency,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Default, Serialize)]
+pub struct RapydPayoutBeneficiary {
+    first_name: Option<Secret<String>>,
+    last_name: Option<Secret<String>>,
+    email: Option<Email>,
+    country: Option<enums::CountryAlpha2>,
+    currency: Option<enums::Currency>,
+    address: Option<Secret<String>>,
+    city: Option<String>,
+    postcode: Option<Secret<String>>,
+    bank_name: Option<String>,
+    account_number: Option<Secret<String>>,
+    aba: Option<Secret<String>>,
+    sort_code: Option<Secret<String>>,
+    iban: Option<Secret<String>>,
+    bic_swift: Option<Secret<String>>,
+}
+
+#[cfg(feature = "payouts")]
+fn get_payout_method_type(
+    bank: &Bank,
+    beneficiary_country: enums::CountryAlpha2,
+) -> Result<String, errors::ConnectorError> {
+    // Rapyd payout method types are scoped by the country of the beneficiary bank
+    match bank {
+        Bank::Ach(_) => Ok("us_ach_bank".to_string()),
+        Bank::Bacs(_) => Ok("gb_general_bank".to_string()),
+        Bank::Sepa(_) => Ok(format!(
+            "{}_sepa_bank",
+            beneficiary_country.to_string().to_lowercase()
+        )),
+        Bank::Pix(_) => Err(errors::ConnectorError::NotImplemented(
+            connector_utils::get_unimplemented_payment_method_error_message("Rapyd"),
+        )),
+    }
+}
+
+#[cfg(feature = "payouts")]
+fn get_payout_beneficiary(
+    bank: Bank,
+    billing_address: &api_models::payments::AddressDetails,
+    email: Option<Email>,
+    currency: enums::Currency,
+) -> Result<RapydPayoutBeneficiary, error_stack::Report<errors::ConnectorError>> {
+    let beneficiary = RapydPayoutBeneficiary {
+        first_name: Some(billing_address.get_first_name()?.to_owned()),
+        last_name: billing_address.last_name.clone(),
+        email,
+        country: Some(*billing_address.get_country()?),
+        currency: Some(currency),
+        address: billing_address.line1.clone(),
+        city: billing_address.city.clone(),
+        postcode: billing_address.zip.clone(),
+        ..RapydPayoutBeneficiary::default()
+    };
+    match bank {
+        Bank::Ach(ach) => Ok(RapydPayoutBeneficiary {
+            bank_name: ach.bank_name,
+            account_number: Some(ach.bank_account_number),
+            aba: Some(ach.bank_routing_number),
+            ..beneficiary
+        }),
+        Bank::Bacs(bacs) => Ok(RapydPayoutBeneficiary {
+            bank_name: bacs.bank_name,
+            account_number: Some(bacs.bank_account_number),
+            sort_code: Some(bacs.bank_sort_code),
+            ..beneficiary
+        }),
+        Bank::Sepa(sepa) => Ok(RapydPayoutBeneficiary {
+            bank_name: sepa.bank_name,
+            iban: Some(sepa.iban),
+            bic_swift: sepa.bic,
+            ..beneficiary
+        }),
+        Bank::Pix(_) => Err(errors::ConnectorError::NotImplemented(
+            connector_utils::get_unimplemented_payment_method_error_message("Rapyd"),
+        ))?,
+    }
+}
+
+// The payout is created without being confirmed, Rapyd holds it with the quoted exchange rate
+// until it is confirmed in the fulfill flow
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<&RapydRouterData<&types::PayoutsRouterData<F>>> for RapydPayoutCreateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &RapydRouterData<&types::PayoutsRouterData<F>>) -> Result<Self, Self::Error> {
+        let request = &item.router_data.request;
+        let metadata = RapydPayoutMetadata::try_from(&item.router_data.connector_meta_data)?;
+        match item.router_data.get_payout_method_data()? {
+            PayoutMethodData::Bank(bank) => {
+                let billing_address = item.router_data.get_billing_address()?;
+                let customer_details = request.get_customer_details()?;
+                let beneficiary_country = *billing_address.get_country()?;
+                let payout_method_type = get_payout_method_type(&bank, beneficiary_country)?;
+                let beneficiary = get_payout_beneficiary(
+                    bank,
+                    billing_address,
+                    customer_details.email,
+                    request.destination_currency,
+                )?;
+
+                Ok(Self {
+                    payout_method_type,
+                    payout_amount: item.amount,
+                    payout_currency: request.destination_currency,
+                    sender_currency: request.source_currency,
+                    sender_country: metadata.sender_country,
+                    sender_entity_type: RapydPayoutEntityType::Company,
+                    sender: RapydPayoutSender {
+                        company_name: metadata.sender_company_name,
+                        country: metadata.sender_country,
+                        currency: request.source_currency,
+                    },
+                    beneficiary_country,
+                    beneficiary_entity_type: RapydPayoutEntityType::from(request.entity_type),
+                    beneficiary,
+                    merchant_reference_id: item.router_data.connector_request_reference_id.clone(),
+                    description: item.router_data.description.clone(),
+                    confirm_automatically: false,
+                })
+            }
+            PayoutMethodData::Card(_) | PayoutMethodData::Wallet(_) => {
+                Err(errors::ConnectorError::NotImplemented(
+                    connector_utils::get_unimplemented_payment_method_error_message("Rapyd"),
+                ))?
+            }
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RapydPayoutResponse {
+    pub status: Status,
+    pub data: Option<RapydPayoutResponseData>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RapydPayoutResponseData {
+    pub id: String,
+    pub status: RapydPayoutStatus,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum RapydPayoutStatus {
+    Created,
+    Confirmation,
+    Completed,
+    Canceled,
+    Error,
+    Expired,
+    Returned,
+}
+
+#[cfg(feature = "payouts")]
+impl From<RapydPayoutStatus> for enums::PayoutStatus {
+    fn from(status: RapydPayoutStatus) -> Self {
+        match status {
+            RapydPayoutStatus::Confirmation => Self::RequiresFulfillment,
+            RapydPayoutStatus::Created => Self::Pending,
+            RapydPayoutStatus::Completed => Self::Success,
+            RapydPayoutStatus::Canceled => Self::Cancelled,
+            RapydPayoutStatus::Error => Self::Failed,
+            RapydPayoutStatus::Expired => Self::Expired,
+            RapydPayoutStatus::Returned => Self::Reversed,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, RapydPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, RapydPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        let response = match item.response.data {
+            Some(data) => Ok(types::PayoutsResponseData {
+                status: Some(enums::PayoutStatus::from(data.status)),
+                connector_payout_id: Some(data.id),
+                payout_eligible: None,
+                should_add_next_step_to_process_tracker: false,
+                error_code: None,
+                error_message: None,
+            }),
+            None => Err(types::ErrorResponse {
+                code: item.response.status.error_code,
+                status_code: item.http_code,
+                message: item.response.status.status.unwrap_or_default(),
+                reason: item.response.status.message,
+                attempt_status: None,
+                connector_transaction_id: None,
+            }),
+        };
+        Ok(Self {
+            response,
+            ..item.data
+        })
+    }
+}
+
+/// Response of the quote flow, the held payout serves as the quote and is only recorded once the
+/// payout is created
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RapydPayoutQuoteResponse(pub RapydPayoutResponse);
+
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, RapydPayoutQuoteResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, RapydPayoutQuoteResponse>,
+    ) -> Result<Self, Self::Error> {
+        let router_data = types::PayoutsRouterData::try_from(types::PayoutsResponseRouterData {
+            response: item.response.0,
+            data: item.data,
+            http_code: item.http_code,
+        })?;
+        let response = router_data
+            .response
+            .map(|response| types::PayoutsResponseData {
+                status: Some(enums::PayoutStatus::RequiresCreation),
+                ..response
+            });
+        Ok(Self {
+            response,
+            ..router_data
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_rapyd_external_three_ds {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::types::SemanticVersion;
+    use hyperswitch_domain_models::router_request_types::AuthenticationData;
+    use serde_json::json;
+
+    use crate::connector::rapyd::transformers::{
+        PaymentMethodOptions, RapydExternalThreeDs, RapydPaymentsRequest,
+    };
+
+    fn get_authentication_data(eci: Option<&str>, ds_trans_id: Option<&str>) -> AuthenticationData {
+        AuthenticationData {
+            eci: eci.map(str::to_string),
+            cavv: "AAABBEg0VhI0VniQEjRWAAAAAAA=".to_string(),
+            threeds_server_transaction_id: "3ds_server_transaction_id".to_string(),
+            message_version: SemanticVersion::new(2, 2, 0),
+            ds_trans_id: ds_trans_id.map(str::to_string),
+        }
+    }
+
+    fn get_serialized_payment_method_options(
+        payment_method_options: PaymentMethodOptions,
+    ) -> serde_json::Value {
+        let request = RapydPaymentsRequest {
+            payment_method_options: Some(payment_method_options),
+            ..Default::default()
+        };
+        serde_json::to_value(request)
+            .unwrap()
+            .get("payment_method_options")
+            .cloned()
+            .unwrap()
+    }
+
+    #[test]
+    fn should_pass_external_authentication_data() {
+        let authentication_data = get_authentication_data(Some("05"), Some("ds_trans_id"));
+        let payment_method_options = PaymentMethodOptions {
+            three_ds: false,
+            external_three_ds: Some(RapydExternalThreeDs::from(&authentication_data)),
+        };
+
+        assert_eq!(
+            get_serialized_payment_method_options(payment_method_options),
+            json!({
+                "3d_required": false,
+                "3d_version": "2.2.0",
+                "cavv": "AAABBEg0VhI0VniQEjRWAAAAAAA=",
+                "eci": "05",
+                "ds_trans_id": "ds_trans_id",
+            })
+        );
+    }
+
+    #[test]
+    fn should_omit_absent_external_authentication_fields() {
+        let authentication_data = get_authentication_data(None, None);
+        let payment_method_options = PaymentMethodOptions {
+            three_ds: false,
+            external_three_ds: Some(RapydExternalThreeDs::from(&authentication_data)),
+        };
+
+        assert_eq!(
+            get_serialized_payment_method_options(payment_method_options),
+            json!({
+                "3d_required": false,
+                "3d_version": "2.2.0",
+                "cavv": "AAABBEg0VhI0VniQEjRWAAAAAAA=",
+            })
+        );
+    }
+
+    #[test]
+    fn should_request_three_ds_without_external_authentication_data() {
+        let payment_method_options = PaymentMethodOptions {
+            three_ds: true,
+            external_three_ds: None,
+        };
+
+        assert_eq!(
+            get_serialized_payment_method_options(payment_method_options),
+            json!({ "3d_required": true })
+        );
+    }
+
+    #[test]
+    fn should_support_separate_authentication() {
+        assert!(api_models::enums::Connector::Rapyd.is_separate_authentication_supported());
+    }
+}
@@ -371,6 +371,8 @@ pub struct SignifydPaymentsCheckoutRequest {
     order_id: String,
     purchase: Purchase,
     coverage_requests: Option<CoverageRequests>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
 }
 
 impl TryFrom<&frm_types::FrmCheckoutRouterData> for SignifydPaymentsCheckoutRequest {
@@ -445,11 +447,33 @@ impl TryFrom<&frm_types::FrmCheckoutRouterData> for SignifydPaymentsCheckoutRequ
                 .phone
                 .and_then(|phone_data| phone_data.number),
         };
+        // Risk signals derived for the payment are shared with Signifyd as tags of the order
+        let tags = item.request.risk_signals.as_ref().and_then(|risk_signals| {
+            let tags = [
+                risk_signals
+                    .is_bin_ip_country_mismatch
+                    .filter(|is_mismatch| *is_mismatch)
+                    .map(|_| "bin_ip_country_mismatch".to_string()),
+                risk_signals
+                    .is_night_time
+                    .filter(|is_night_time| *is_night_time)
+                    .map(|_| "night_time".to_string()),
+                risk_signals
+                    .customer_chargeback_count
+                    .filter(|count| *count > 0)
+                    .map(|count| format!("customer_chargebacks:{count}")),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+            (!tags.is_empty()).then_some(tags)
+        });
         Ok(Self {
             checkout_id: item.payment_id.clone(),
             order_id: item.attempt_id.clone(),
             purchase,
             coverage_requests: metadata.coverage_request,
+            tags,
         })
     }
 }
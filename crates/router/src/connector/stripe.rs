@@ -648,6 +648,23 @@ impl
         Ok(RequestContent::FormUrlEncoded(Box::new(connector_req)))
     }
 
+    fn get_idempotency_header(
+        &self,
+        req: &types::PaymentsCaptureRouterData,
+    ) -> CustomResult<Option<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let reference_id = req
+            .request
+            .multiple_capture_data
+            .as_ref()
+            .map_or(req.attempt_id.as_str(), |capture_data| {
+                capture_data.capture_reference.as_str()
+            });
+        Ok(Some((
+            headers::IDEMPOTENCY_KEY.to_string(),
+            connector_utils::construct_idempotency_key::<api::Capture>(reference_id).into(),
+        )))
+    }
+
     fn build_request(
         &self,
         req: &types::PaymentsCaptureRouterData,
@@ -794,7 +811,9 @@ impl
                 self.base_url(connectors),
                 "v1/payment_intents",
                 x,
-                "?expand[0]=latest_charge" //updated payment_id(if present) reside inside latest_charge field
+                // updated payment_id(if present) reside inside latest_charge field, its balance
+                // transaction carries the processing fee
+                "?expand[0]=latest_charge&expand[1]=latest_charge.balance_transaction"
             )),
             x => x.change_context(errors::ConnectorError::MissingConnectorTransactionID),
         }
@@ -1002,6 +1021,16 @@ impl
         }
     }
 
+    fn get_idempotency_header(
+        &self,
+        req: &types::PaymentsAuthorizeRouterData,
+    ) -> CustomResult<Option<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(Some((
+            headers::IDEMPOTENCY_KEY.to_string(),
+            connector_utils::construct_idempotency_key::<api::Authorize>(&req.attempt_id).into(),
+        )))
+    }
+
     fn build_request(
         &self,
         req: &types::PaymentsAuthorizeRouterData,
@@ -1206,6 +1235,16 @@ impl
         Ok(RequestContent::FormUrlEncoded(Box::new(connector_req)))
     }
 
+    fn get_idempotency_header(
+        &self,
+        req: &types::PaymentsCancelRouterData,
+    ) -> CustomResult<Option<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(Some((
+            headers::IDEMPOTENCY_KEY.to_string(),
+            connector_utils::construct_idempotency_key::<api::Void>(&req.attempt_id).into(),
+        )))
+    }
+
     fn build_request(
         &self,
         req: &types::PaymentsCancelRouterData,
@@ -1444,6 +1483,185 @@ impl
     }
 }
 
+impl api::ConnectorAccountBalance for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccountBalance,
+        types::AccountBalanceRequestData,
+        types::AccountBalanceResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::AccountBalanceRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::AccountBalanceType::get_content_type(self)
+                .to_string()
+                .into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        // The balance of a connected account is retrieved on behalf of that account
+        if let Some(account_id) = req.request.account_id.clone() {
+            header.push((
+                headers::STRIPE_COMPATIBLE_CONNECT_ACCOUNT.to_string(),
+                account_id.into_masked(),
+            ));
+        }
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &types::AccountBalanceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}{}", self.base_url(connectors), "v1/balance"))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::AccountBalanceRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::AccountBalanceType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::AccountBalanceType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::AccountBalanceRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::AccountBalanceRouterData, errors::ConnectorError> {
+        let response: stripe::StripeBalanceResponse = res
+            .response
+            .parse_struct("StripeBalanceResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
+impl api::ConnectorAccountUpdate for Stripe {}
+
+impl
+    services::ConnectorIntegration<
+        api::AccountUpdate,
+        types::AccountUpdateRequestData,
+        types::AccountUpdateResponseData,
+    > for Stripe
+{
+    fn get_headers(
+        &self,
+        req: &types::AccountUpdateRouterData,
+        _connectors: &settings::Connectors,
+    ) -> CustomResult<Vec<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            headers::CONTENT_TYPE.to_string(),
+            types::AccountUpdateType::get_content_type(self)
+                .to_string()
+                .into(),
+        )];
+        let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut api_key);
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &types::AccountUpdateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}v1/payment_methods/{}",
+            self.base_url(connectors),
+            req.request.connector_payment_method_id
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &types::AccountUpdateRouterData,
+        connectors: &settings::Connectors,
+    ) -> CustomResult<Option<services::Request>, errors::ConnectorError> {
+        Ok(Some(
+            services::RequestBuilder::new()
+                .method(services::Method::Get)
+                .url(&types::AccountUpdateType::get_url(self, req, connectors)?)
+                .attach_default_headers()
+                .headers(types::AccountUpdateType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &types::AccountUpdateRouterData,
+        event_builder: Option<&mut ConnectorEvent>,
+        res: types::Response,
+    ) -> CustomResult<types::AccountUpdateRouterData, errors::ConnectorError> {
+        let response: stripe::StripePaymentMethodRetrieveResponse = res
+            .response
+            .parse_struct("StripePaymentMethodRetrieveResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+
+        event_builder.map(|i| i.set_response_body(&response));
+        router_env::logger::info!(connector_response=?response);
+
+        types::RouterData::try_from(types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: types::Response,
+        event_builder: Option<&mut ConnectorEvent>,
+    ) -> CustomResult<types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res, event_builder)
+    }
+}
+
 impl api::Refund for Stripe {}
 impl api::RefundExecute for Stripe {}
 impl api::RefundSync for Stripe {}
@@ -1516,6 +1734,17 @@ impl services::ConnectorIntegration<api::Execute, types::RefundsData, types::Ref
         Ok(request_body)
     }
 
+    fn get_idempotency_header(
+        &self,
+        req: &types::RefundsRouterData<api::Execute>,
+    ) -> CustomResult<Option<(String, request::Maskable<String>)>, errors::ConnectorError> {
+        Ok(Some((
+            headers::IDEMPOTENCY_KEY.to_string(),
+            connector_utils::construct_idempotency_key::<api::Execute>(&req.request.refund_id)
+                .into(),
+        )))
+    }
+
     fn build_request(
         &self,
         req: &types::RefundsRouterData<api::Execute>,
@@ -1645,7 +1874,11 @@ impl services::ConnectorIntegration<api::RSync, types::RefundsData, types::Refun
         connectors: &settings::Connectors,
     ) -> CustomResult<String, errors::ConnectorError> {
         let id = req.request.get_connector_refund_id()?;
-        Ok(format!("{}v1/refunds/{}", self.base_url(connectors), id))
+        Ok(format!(
+            "{}v1/refunds/{}?expand[0]=balance_transaction",
+            self.base_url(connectors),
+            id
+        ))
     }
 
     fn build_request(
@@ -2165,6 +2398,17 @@ fn get_signature_elements_from_header(
     Ok(security_header_kvs)
 }
 
+fn is_wallet_token_webhook(
+    request: &api::IncomingWebhookRequestDetails<'_>,
+) -> CustomResult<bool, errors::ConnectorError> {
+    let details: stripe::WebhookPaymentMethodEvent = request
+        .body
+        .parse_struct("WebhookPaymentMethodEvent")
+        .change_context(errors::ConnectorError::WebhookEventTypeNotFound)?;
+
+    Ok(details.event_data.event_object.is_wallet_token())
+}
+
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Stripe {
     fn get_webhook_source_verification_algorithm(
@@ -2208,10 +2452,46 @@ impl api::IncomingWebhook for Stripe {
         .into_bytes())
     }
 
+    fn get_webhook_timestamp(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<i64>, errors::ConnectorError> {
+        let mut security_header_kvs = get_signature_elements_from_header(request.headers)?;
+
+        let timestamp = security_header_kvs
+            .remove("t")
+            .ok_or(errors::ConnectorError::WebhookSignatureNotFound)?;
+
+        String::from_utf8_lossy(&timestamp)
+            .parse()
+            .map(Some)
+            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
+            .attach_printable("Could not parse the webhook timestamp")
+    }
+
     fn get_webhook_object_reference_id(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<api_models::webhooks::ObjectReferenceId, errors::ConnectorError> {
+        let event_type_details: stripe::WebhookEventTypeBody = request
+            .body
+            .parse_struct("WebhookEventTypeBody")
+            .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+        // The id of the payment method is the reusable token used for recurring wallet payments
+        if event_type_details.event_type.is_payment_method_event() {
+            let details: stripe::WebhookPaymentMethodEvent = request
+                .body
+                .parse_struct("WebhookPaymentMethodEvent")
+                .change_context(errors::ConnectorError::WebhookReferenceIdNotFound)?;
+
+            return Ok(api_models::webhooks::ObjectReferenceId::MandateId(
+                api_models::webhooks::MandateIdType::ConnectorMandateId(
+                    details.event_data.event_object.id,
+                ),
+            ));
+        }
+
         let details: stripe::WebhookEvent = request
             .body
             .parse_struct("WebhookEvent")
@@ -2388,6 +2668,21 @@ impl api::IncomingWebhook for Stripe {
             stripe::WebhookEventType::ChargeDisputeFundsReinstated => {
                 api::IncomingWebhookEvent::DisputeWon
             }
+            // Only the tokens of wallets are stored as connector mandates
+            stripe::WebhookEventType::PaymentMethodAutomaticallyUpdated => {
+                if is_wallet_token_webhook(request)? {
+                    api::IncomingWebhookEvent::WalletTokenUpdated
+                } else {
+                    api::IncomingWebhookEvent::EventNotSupported
+                }
+            }
+            stripe::WebhookEventType::PaymentMethodDetached => {
+                if is_wallet_token_webhook(request)? {
+                    api::IncomingWebhookEvent::WalletTokenExpired
+                } else {
+                    api::IncomingWebhookEvent::EventNotSupported
+                }
+            }
             stripe::WebhookEventType::Unknown
             | stripe::WebhookEventType::ChargeCaptured
             | stripe::WebhookEventType::ChargeExpired
@@ -2407,6 +2702,20 @@ impl api::IncomingWebhook for Stripe {
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
     ) -> CustomResult<Box<dyn masking::ErasedMaskSerialize>, errors::ConnectorError> {
+        let event_type_details: stripe::WebhookEventTypeBody = request
+            .body
+            .parse_struct("WebhookEventTypeBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+        if event_type_details.event_type.is_payment_method_event() {
+            let details: stripe::WebhookPaymentMethodEvent = request
+                .body
+                .parse_struct("WebhookPaymentMethodEvent")
+                .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+            return Ok(Box::new(details.event_data.event_object));
+        }
+
         let details: stripe::WebhookEvent = request
             .body
             .parse_struct("WebhookEvent")
@@ -1,4 +1,4 @@
-use std::{collections::HashMap, ops::Deref};
+use std::{collections::HashMap, ops::Deref, str::FromStr};
 
 use api_models::{self, enums as api_enums};
 use common_utils::{
@@ -117,12 +117,15 @@ pub struct StripeMandateRequest {
     mandate_type: StripeMandateType,
 }
 
-#[derive(Debug, Eq, PartialEq, Serialize)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ExpandableObjects {
     LatestCharge,
     Customer,
     LatestAttempt,
+    BalanceTransaction,
+    #[serde(rename = "latest_charge.balance_transaction")]
+    LatestChargeBalanceTransaction,
 }
 
 #[derive(Debug, Eq, PartialEq, Serialize)]
@@ -1960,7 +1963,9 @@ impl TryFrom<(&types::PaymentsAuthorizeRouterData, MinorUnit)> for PaymentIntent
             off_session: item.request.off_session,
             setup_future_usage: item.request.setup_future_usage,
             payment_method_types,
-            expand: Some(ExpandableObjects::LatestCharge),
+            // expanding the balance transaction expands the latest charge as well, the balance
+            // transaction carries the processing fee of payments which are captured right away
+            expand: Some(ExpandableObjects::LatestChargeBalanceTransaction),
             browser_info,
             charges,
         })
@@ -2219,6 +2224,40 @@ pub enum StripeChargeEnum {
 pub struct StripeCharge {
     pub id: String,
     pub payment_method_details: Option<StripePaymentMethodDetailsResponse>,
+    pub balance_transaction: Option<StripeChargeBalanceTransactionObject>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum StripeChargeBalanceTransactionObject {
+    Expanded(StripeChargeBalanceTransaction),
+    Id(String),
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct StripeChargeBalanceTransaction {
+    pub fee: MinorUnit,
+    pub currency: String,
+}
+
+impl StripeCharge {
+    /// The processing fee of the charge, available once the charge is captured and the balance
+    /// transaction is expanded. The fee is in the currency of the Stripe balance.
+    fn get_connector_fee(&self) -> Option<types::ConnectorFee> {
+        let Some(StripeChargeBalanceTransactionObject::Expanded(balance_transaction)) =
+            self.balance_transaction.as_ref()
+        else {
+            return None;
+        };
+        let currency =
+            enums::Currency::from_str(balance_transaction.currency.to_uppercase().as_str())
+                .map_err(|error| router_env::logger::warn!(?error, "Unknown fee currency"))
+                .ok()?;
+        Some(types::ConnectorFee {
+            amount: balance_transaction.fee,
+            currency,
+        })
+    }
 }
 
 #[derive(Deserialize, Clone, Debug, PartialEq, Eq, Serialize)]
@@ -2398,19 +2437,33 @@ pub struct SetupIntentResponse {
     pub last_setup_error: Option<ErrorDetails>,
 }
 
-fn extract_payment_method_connector_response_from_latest_charge(
+fn extract_connector_response_from_latest_charge(
     stripe_charge_enum: &StripeChargeEnum,
 ) -> Option<types::ConnectorResponseData> {
-    if let StripeChargeEnum::ChargeObject(charge_object) = stripe_charge_enum {
-        charge_object
-            .payment_method_details
-            .as_ref()
-            .and_then(StripePaymentMethodDetailsResponse::get_additional_payment_method_data)
-    } else {
-        None
+    let StripeChargeEnum::ChargeObject(charge_object) = stripe_charge_enum else {
+        return None;
+    };
+    let additional_payment_method_data = charge_object
+        .payment_method_details
+        .as_ref()
+        .and_then(StripePaymentMethodDetailsResponse::get_additional_payment_method_data)
+        .map(types::AdditionalPaymentMethodConnectorResponse::from);
+    let connector_fee = charge_object.get_connector_fee();
+
+    match (additional_payment_method_data, connector_fee) {
+        (None, None) => None,
+        (Some(additional_payment_method_data), connector_fee) => {
+            Some(types::ConnectorResponseData {
+                connector_fee,
+                ..types::ConnectorResponseData::with_additional_payment_method_data(
+                    additional_payment_method_data,
+                )
+            })
+        }
+        (None, Some(connector_fee)) => Some(types::ConnectorResponseData::with_connector_fee(
+            connector_fee,
+        )),
     }
-    .map(types::AdditionalPaymentMethodConnectorResponse::from)
-    .map(types::ConnectorResponseData::with_additional_payment_method_data)
 }
 
 fn extract_payment_method_connector_response_from_latest_attempt(
@@ -2508,7 +2561,7 @@ impl<F, T>
             .response
             .latest_charge
             .as_ref()
-            .and_then(extract_payment_method_connector_response_from_latest_charge);
+            .and_then(extract_connector_response_from_latest_charge);
 
         Ok(Self {
             status,
@@ -2672,7 +2725,7 @@ impl<F, T>
             .response
             .latest_charge
             .as_ref()
-            .and_then(extract_payment_method_connector_response_from_latest_charge);
+            .and_then(extract_connector_response_from_latest_charge);
 
         let response = if connector_util::is_payment_failure(status) {
             types::PaymentsResponseData::foreign_try_from((
@@ -2975,6 +3028,8 @@ pub struct RefundRequest {
     pub payment_intent: String,
     #[serde(flatten)]
     pub meta_data: StripeMetadata,
+    #[serde(rename = "expand[0]")]
+    pub expand: Option<ExpandableObjects>,
 }
 
 impl<F> TryFrom<(&types::RefundsRouterData<F>, MinorUnit)> for RefundRequest {
@@ -2990,6 +3045,8 @@ impl<F> TryFrom<(&types::RefundsRouterData<F>, MinorUnit)> for RefundRequest {
                 order_id: Some(item.request.refund_id.clone()),
                 is_refund_id_as_reference: Some("true".to_string()),
             },
+            // the balance transaction carries the refunded amount in the settlement currency
+            expand: Some(ExpandableObjects::BalanceTransaction),
         })
     }
 }
@@ -3002,6 +3059,8 @@ pub struct ChargeRefundRequest {
     pub amount: Option<MinorUnit>, //amount in cents, hence passed as integer
     #[serde(flatten)]
     pub meta_data: StripeMetadata,
+    #[serde(rename = "expand[0]")]
+    pub expand: Option<ExpandableObjects>,
 }
 
 impl<F> TryFrom<&types::RefundsRouterData<F>> for ChargeRefundRequest {
@@ -3032,6 +3091,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for ChargeRefundRequest {
                         order_id: Some(item.request.refund_id.clone()),
                         is_refund_id_as_reference: Some("true".to_string()),
                     },
+                    expand: Some(ExpandableObjects::BalanceTransaction),
                 })
             }
         }
@@ -3071,6 +3131,48 @@ pub struct RefundResponse {
     pub payment_intent: String,
     pub status: RefundStatus,
     pub failure_reason: Option<String>,
+    pub balance_transaction: Option<StripeBalanceTransactionObject>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum StripeBalanceTransactionObject {
+    Expanded(StripeBalanceTransaction),
+    Id(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StripeBalanceTransaction {
+    pub amount: MinorUnit,
+    pub currency: String,
+    pub exchange_rate: Option<f64>,
+}
+
+impl RefundResponse {
+    /// The refunded amount in the currency of the Stripe balance, when it is settled in a currency
+    /// other than the currency of the refund
+    fn get_settlement_details(&self) -> Option<types::SettlementDetails> {
+        let Some(StripeBalanceTransactionObject::Expanded(balance_transaction)) =
+            self.balance_transaction.as_ref()
+        else {
+            return None;
+        };
+        if balance_transaction
+            .currency
+            .eq_ignore_ascii_case(&self.currency)
+        {
+            return None;
+        }
+        let settlement_currency =
+            enums::Currency::from_str(balance_transaction.currency.to_uppercase().as_str())
+                .map_err(|error| router_env::logger::warn!(?error, "Unknown settlement currency"))
+                .ok()?;
+        Some(types::SettlementDetails {
+            settlement_currency,
+            // Stripe reports the balance transaction of a refund as a debit
+            settlement_amount: MinorUnit::new(balance_transaction.amount.get_amount_as_i64().abs()),
+        })
+    }
 }
 
 impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
@@ -3080,6 +3182,10 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
     fn try_from(
         item: types::RefundsResponseRouterData<api::Execute, RefundResponse>,
     ) -> Result<Self, Self::Error> {
+        let connector_response = item
+            .response
+            .get_settlement_details()
+            .map(types::ConnectorResponseData::with_settlement_details);
         let refund_status = enums::RefundStatus::from(item.response.status);
         let response = if connector_util::is_refund_failure(refund_status) {
             Err(types::ErrorResponse {
@@ -3103,6 +3209,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
 
         Ok(Self {
             response,
+            connector_response,
             ..item.data
         })
     }
@@ -3115,6 +3222,10 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
     fn try_from(
         item: types::RefundsResponseRouterData<api::RSync, RefundResponse>,
     ) -> Result<Self, Self::Error> {
+        let connector_response = item
+            .response
+            .get_settlement_details()
+            .map(types::ConnectorResponseData::with_settlement_details);
         let refund_status = enums::RefundStatus::from(item.response.status);
         let response = if connector_util::is_refund_failure(refund_status) {
             Err(types::ErrorResponse {
@@ -3138,6 +3249,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
 
         Ok(Self {
             response,
+            connector_response,
             ..item.data
         })
     }
@@ -3217,14 +3329,38 @@ pub struct StripeRedirectResponse {
 
 #[derive(Debug, Serialize)]
 pub struct CancelRequest {
-    cancellation_reason: Option<String>,
+    cancellation_reason: Option<StripeCancellationReason>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeCancellationReason {
+    Duplicate,
+    Fraudulent,
+    RequestedByCustomer,
+    Abandoned,
+}
+
+impl From<storage_enums::CancellationReason> for StripeCancellationReason {
+    fn from(reason: storage_enums::CancellationReason) -> Self {
+        match reason {
+            storage_enums::CancellationReason::Duplicate => Self::Duplicate,
+            storage_enums::CancellationReason::Fraudulent => Self::Fraudulent,
+            storage_enums::CancellationReason::RequestedByCustomer => Self::RequestedByCustomer,
+            storage_enums::CancellationReason::Abandoned => Self::Abandoned,
+        }
+    }
 }
 
 impl TryFrom<&types::PaymentsCancelRouterData> for CancelRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsCancelRouterData) -> Result<Self, Self::Error> {
+        // Stripe only accepts its own set of reasons, free form reasons are not sent
         Ok(Self {
-            cancellation_reason: item.request.cancellation_reason.clone(),
+            cancellation_reason: item
+                .request
+                .cancellation_reason_code
+                .map(StripeCancellationReason::from),
         })
     }
 }
@@ -3296,6 +3432,8 @@ pub struct StripeMandateOptions {
 pub struct CaptureRequest {
     /// If amount_to_capture is None stripe captures the amount in the payment intent.
     amount_to_capture: Option<MinorUnit>,
+    #[serde(rename = "expand[0]")]
+    expand: Option<ExpandableObjects>,
 }
 
 impl TryFrom<MinorUnit> for CaptureRequest {
@@ -3303,6 +3441,8 @@ impl TryFrom<MinorUnit> for CaptureRequest {
     fn try_from(capture_amount: MinorUnit) -> Result<Self, Self::Error> {
         Ok(Self {
             amount_to_capture: Some(capture_amount),
+            // the balance transaction of the captured charge carries the processing fee
+            expand: Some(ExpandableObjects::LatestChargeBalanceTransaction),
         })
     }
 }
@@ -3709,6 +3849,72 @@ pub enum WebhookEventType {
     SourceTransactionCreated,
     #[serde(rename = "payment_intent.partially_funded")]
     PaymentIntentPartiallyFunded,
+    #[serde(rename = "payment_method.automatically_updated")]
+    PaymentMethodAutomaticallyUpdated,
+    #[serde(rename = "payment_method.detached")]
+    PaymentMethodDetached,
+    #[serde(other)]
+    Unknown,
+}
+
+impl WebhookEventType {
+    /// Whether the event is about a payment method rather than a payment, refund or dispute
+    pub fn is_payment_method_event(&self) -> bool {
+        matches!(
+            self,
+            Self::PaymentMethodAutomaticallyUpdated | Self::PaymentMethodDetached
+        )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WebhookPaymentMethodEvent {
+    #[serde(rename = "data")]
+    pub event_data: WebhookPaymentMethodEventData,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WebhookPaymentMethodEventData {
+    #[serde(rename = "object")]
+    pub event_object: WebhookPaymentMethodObject,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookPaymentMethodObject {
+    pub id: String,
+    pub card: Option<WebhookPaymentMethodCard>,
+}
+
+impl WebhookPaymentMethodObject {
+    /// Whether the payment method is a reusable token issued for a wallet, such as the merchant
+    /// tokens of recurring Apple Pay payments
+    pub fn is_wallet_token(&self) -> bool {
+        matches!(
+            self.card
+                .as_ref()
+                .and_then(|card| card.wallet.as_ref())
+                .map(|wallet| &wallet.wallet_type),
+            Some(StripeCardWalletType::ApplePay | StripeCardWalletType::GooglePay)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookPaymentMethodCard {
+    pub wallet: Option<StripeCardWallet>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StripeCardWallet {
+    #[serde(rename = "type")]
+    pub wallet_type: StripeCardWalletType,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeCardWalletType {
+    ApplePay,
+    GooglePay,
     #[serde(other)]
     Unknown,
 }
@@ -4106,6 +4312,140 @@ impl ForeignTryFrom<(&Option<ErrorDetails>, u16, String)> for types::PaymentsRes
     }
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StripeBalanceResponse {
+    pub available: Vec<StripeBalanceAmount>,
+    pub pending: Vec<StripeBalanceAmount>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StripeBalanceAmount {
+    pub amount: MinorUnit,
+    pub currency: String,
+}
+
+impl TryFrom<StripeBalanceAmount> for (enums::Currency, MinorUnit) {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: StripeBalanceAmount) -> Result<Self, Self::Error> {
+        let currency = enums::Currency::from_str(item.currency.to_uppercase().as_str())
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok((currency, item.amount))
+    }
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<F, StripeBalanceResponse, T, types::AccountBalanceResponseData>,
+    > for types::RouterData<F, T, types::AccountBalanceResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            StripeBalanceResponse,
+            T,
+            types::AccountBalanceResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let available = item
+            .response
+            .available
+            .into_iter()
+            .map(TryFrom::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        let pending = item
+            .response
+            .pending
+            .into_iter()
+            .map(TryFrom::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            response: Ok(types::AccountBalanceResponseData {
+                balances: connector_util::get_currency_balances(available, pending),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StripePaymentMethodRetrieveResponse {
+    pub id: String,
+    pub card: Option<StripePaymentMethodCard>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct StripePaymentMethodCard {
+    pub exp_month: u8,
+    pub exp_year: u16,
+    pub last4: String,
+}
+
+/// Stored expiry years may be in either the two or the four digit format
+fn is_same_expiry(
+    card_exp_month: Option<&Secret<String>>,
+    card_exp_year: Option<&Secret<String>>,
+    card: &StripePaymentMethodCard,
+) -> bool {
+    let exp_month = card_exp_month.and_then(|month| month.peek().parse::<u8>().ok());
+    let exp_year = card_exp_year
+        .and_then(|year| year.peek().parse::<u16>().ok())
+        .map(|year| if year < 100 { year + 2000 } else { year });
+    exp_month == Some(card.exp_month) && exp_year == Some(card.exp_year)
+}
+
+impl<F>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            StripePaymentMethodRetrieveResponse,
+            types::AccountUpdateRequestData,
+            types::AccountUpdateResponseData,
+        >,
+    > for types::RouterData<F, types::AccountUpdateRequestData, types::AccountUpdateResponseData>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            StripePaymentMethodRetrieveResponse,
+            types::AccountUpdateRequestData,
+            types::AccountUpdateResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let card = item
+            .response
+            .card
+            .ok_or(errors::ConnectorError::MissingRequiredField { field_name: "card" })?;
+        let request = &item.data.request;
+
+        // Stripe keeps the cards of payment methods up to date through the card account
+        // updater, but never shares the updated card number
+        let status = if request.card_last4.as_deref() != Some(card.last4.as_str()) {
+            types::AccountUpdateStatus::CardNumberUpdated
+        } else if !is_same_expiry(
+            request.card_exp_month.as_ref(),
+            request.card_exp_year.as_ref(),
+            &card,
+        ) {
+            types::AccountUpdateStatus::ExpiryUpdated
+        } else {
+            types::AccountUpdateStatus::NoChange
+        };
+
+        Ok(Self {
+            response: Ok(types::AccountUpdateResponseData {
+                status,
+                card_number: None,
+                card_last4: Some(card.last4),
+                card_exp_month: Some(Secret::new(format!("{:02}", card.exp_month))),
+                card_exp_year: Some(Secret::new(card.exp_year.to_string())),
+            }),
+            ..item.data
+        })
+    }
+}
+
 pub(super) fn transform_headers_for_connect_platform(
     charge_type: api::enums::PaymentChargeType,
     transfer_account_id: String,
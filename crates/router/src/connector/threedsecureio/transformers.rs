@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use api_models::payments::{DeviceChannel, ThreeDsCompletionIndicator};
+use api_models::payments::{DeviceChannel, ThreeDsCompletionIndicator, ThreeRiIndicator};
 use base64::Engine;
 use common_utils::date_time;
 use error_stack::ResultExt;
@@ -301,7 +301,7 @@ impl TryFrom<&ThreedsecureioRouterData<&types::authentication::ConnectorAuthenti
                     field_name: "sdk_information",
                 },
             )?),
-            DeviceChannel::Browser => None,
+            DeviceChannel::Browser | DeviceChannel::ThreeDsRequestorInitiated => None,
         };
         let (acquirer_bin, acquirer_merchant_id) = pre_authentication_data
             .acquirer_bin
@@ -361,8 +361,25 @@ impl TryFrom<&ThreedsecureioRouterData<&types::authentication::ConnectorAuthenti
             device_channel: match item.router_data.request.device_channel.clone() {
                 DeviceChannel::App => "01",
                 DeviceChannel::Browser => "02",
+                DeviceChannel::ThreeDsRequestorInitiated => "03",
             }
             .to_string(),
+            three_ri_ind: request.three_ri_indicator.map(|three_ri_indicator| {
+                match three_ri_indicator {
+                    ThreeRiIndicator::RecurringTransaction => "01",
+                    ThreeRiIndicator::InstalmentTransaction => "02",
+                    ThreeRiIndicator::AddCard => "03",
+                    ThreeRiIndicator::MaintainCardInformation => "04",
+                    ThreeRiIndicator::AccountVerification => "05",
+                    ThreeRiIndicator::SplitOrDelayedShipment => "06",
+                    ThreeRiIndicator::TopUp => "07",
+                    ThreeRiIndicator::MailOrder => "08",
+                    ThreeRiIndicator::TelephoneOrder => "09",
+                    ThreeRiIndicator::WhitelistStatusCheck => "10",
+                    ThreeRiIndicator::OtherPayment => "11",
+                }
+                .to_string()
+            }),
             message_category: match item.router_data.request.message_category.clone() {
                 MessageCategory::Payment => "01",
                 MessageCategory::NonPayment => "02",
@@ -438,7 +455,7 @@ impl TryFrom<&ThreedsecureioRouterData<&types::authentication::ConnectorAuthenti
                     // UI types that the device supports for displaying specific challenge user interfaces within the SDK, 01 for Text
                     sdk_ui_type: vec!["01".to_string()],
                 }),
-                DeviceChannel::Browser => None,
+                DeviceChannel::Browser | DeviceChannel::ThreeDsRequestorInitiated => None,
             },
             cardholder_name: card_holder_name,
             email: request.email.clone(),
@@ -537,6 +554,8 @@ pub struct ThreedsecureioAuthenticationRequest {
     pub three_dsrequestor_authentication_ind: String,
     pub cardholder_name: Option<Secret<String>>,
     pub device_channel: String,
+    #[serde(rename = "threeRIInd")]
+    pub three_ri_ind: Option<String>,
     pub browser_javascript_enabled: Option<bool>,
     pub browser_accept_header: Option<String>,
     pub browser_ip: Option<Secret<String, common_utils::pii::IpAddress>>,
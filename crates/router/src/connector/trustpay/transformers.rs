@@ -368,7 +368,7 @@ fn get_bank_redirection_request_data(
                 debtor: get_debtor_info(item, pm, params)?,
             },
             callback_urls: CallbackURLs {
-                success: format!("{return_url}?status=SuccessOk"),
+                success: utils::append_query_param(&return_url, "status", "SuccessOk"),
                 cancel: return_url.clone(),
                 error: return_url,
             },
@@ -398,6 +398,8 @@ impl TryFrom<&TrustpayRouterData<&types::PaymentsAuthorizeRouterData>> for Trust
             accept_header: Some(browser_info.accept_header.unwrap_or("*".to_string())),
             user_agent: browser_info.user_agent,
             ip_address: browser_info.ip_address,
+            origin: browser_info.origin,
+            ip_country: browser_info.ip_country,
         };
         let params = get_mandatory_fields(item.router_data)?;
         let amount = item.amount.to_owned();
@@ -2350,6 +2350,11 @@ pub trait MultipleCaptureSyncResponse {
         None
     }
     fn get_amount_captured(&self) -> Result<Option<MinorUnit>, error_stack::Report<ParsingError>>;
+    /// The captured amount in the settlement currency, for connectors which settle in a currency
+    /// other than the presentment currency
+    fn get_settlement_details(&self) -> Option<types::SettlementDetails> {
+        None
+    }
 }
 
 pub fn construct_captures_response_hashmap<T>(
@@ -2375,6 +2380,7 @@ where
                         .attach_printable(
                             "failed to convert back captured response amount to minor unit",
                         )?,
+                    settlement_details: capture_sync_response.get_settlement_details(),
                 },
             );
         }
@@ -2388,6 +2394,23 @@ pub fn is_manual_capture(capture_method: Option<enums::CaptureMethod>) -> bool {
         || capture_method == Some(enums::CaptureMethod::ManualMultiple)
 }
 
+/// Idempotency key of the connector request of the flow, for the payment attempt, capture or
+/// refund referred to by `reference_id`
+pub fn construct_idempotency_key<F>(reference_id: &str) -> String {
+    let flow = std::any::type_name::<F>()
+        .split("::")
+        .last()
+        .unwrap_or_default();
+    format!("{reference_id}_{flow}")
+}
+
+/// Appends a query parameter to a url, which may already carry query parameters such as the
+/// signature of the callback urls of the router
+pub fn append_query_param(url: &str, param: &str, value: &str) -> String {
+    let separator = if url.contains('?') { '&' } else { '?' };
+    format!("{url}{separator}{param}={value}")
+}
+
 pub fn generate_random_bytes(length: usize) -> Vec<u8> {
     // returns random bytes of length n
     let mut rng = rand::thread_rng();
@@ -2613,6 +2636,47 @@ pub fn get_card_details(
     }
 }
 
+#[cfg(test)]
+mod currency_balances_tests {
+    use super::*;
+
+    #[test]
+    fn test_get_currency_balances() {
+        let balances = get_currency_balances(
+            vec![
+                (enums::Currency::USD, MinorUnit::new(1000)),
+                (enums::Currency::EUR, MinorUnit::new(500)),
+                (enums::Currency::USD, MinorUnit::new(200)),
+            ],
+            vec![
+                (enums::Currency::USD, MinorUnit::new(300)),
+                (enums::Currency::GBP, MinorUnit::new(50)),
+            ],
+        );
+
+        assert_eq!(
+            balances,
+            vec![
+                types::CurrencyBalance {
+                    currency: enums::Currency::EUR,
+                    available: MinorUnit::new(500),
+                    pending: MinorUnit::new(0),
+                },
+                types::CurrencyBalance {
+                    currency: enums::Currency::GBP,
+                    available: MinorUnit::new(0),
+                    pending: MinorUnit::new(50),
+                },
+                types::CurrencyBalance {
+                    currency: enums::Currency::USD,
+                    available: MinorUnit::new(1200),
+                    pending: MinorUnit::new(300),
+                },
+            ]
+        );
+    }
+}
+
 #[cfg(test)]
 mod error_code_error_message_tests {
     #![allow(clippy::unwrap_used)]
@@ -3058,6 +3122,35 @@ pub fn convert_back_amount_to_minor_units<T>(
         .change_context(errors::ConnectorError::AmountConversionFailed)
 }
 
+/// Combines the available and pending amounts of a connector account, which connectors report
+/// separately, into a balance per currency
+pub fn get_currency_balances(
+    available: impl IntoIterator<Item = (enums::Currency, MinorUnit)>,
+    pending: impl IntoIterator<Item = (enums::Currency, MinorUnit)>,
+) -> Vec<types::CurrencyBalance> {
+    let mut balances: HashMap<enums::Currency, types::CurrencyBalance> = HashMap::new();
+    let empty_balance = |currency| types::CurrencyBalance {
+        currency,
+        available: MinorUnit::new(0),
+        pending: MinorUnit::new(0),
+    };
+    for (currency, amount) in available {
+        let balance = balances
+            .entry(currency)
+            .or_insert_with(|| empty_balance(currency));
+        balance.available = balance.available + amount;
+    }
+    for (currency, amount) in pending {
+        let balance = balances
+            .entry(currency)
+            .or_insert_with(|| empty_balance(currency));
+        balance.pending = balance.pending + amount;
+    }
+    let mut balances: Vec<_> = balances.into_values().collect();
+    balances.sort_by_key(|balance| balance.currency.to_string());
+    balances
+}
+
 pub fn get_authorise_integrity_object<T>(
     amount_convertor: &dyn AmountConvertor<Output = T>,
     amount: T,
@@ -3143,3 +3236,54 @@ impl NetworkTokenData for domain::NetworkTokenData {
         Secret::new(year)
     }
 }
+
+/// Fields of `connector_metadata.{connector}` which the connector allows to be forwarded as is in
+/// its requests. The allowlists are defined along with the request of each connector, and must not
+/// contain any field which is already populated by its transformer.
+pub fn get_passthrough_fields_allowlist(
+    connector: api::enums::Connector,
+) -> &'static [&'static str] {
+    match connector {
+        api::enums::Connector::Adyen => crate::connector::adyen::transformers::PASSTHROUGH_FIELDS,
+        api::enums::Connector::Checkout => {
+            crate::connector::checkout::transformers::PASSTHROUGH_FIELDS
+        }
+        _ => &[],
+    }
+}
+
+/// Request of a connector along with the fields passed through by the merchant for the connector
+#[derive(Debug, serde::Serialize)]
+pub struct PassthroughRequest<T> {
+    #[serde(flatten)]
+    request: T,
+    #[serde(flatten)]
+    passthrough_fields: HashMap<String, pii::SecretSerdeValue>,
+}
+
+impl<T> PassthroughRequest<T> {
+    /// Adds the passthrough fields to the request, the fields which are not in the allowlist of
+    /// the connector are dropped
+    pub fn new(
+        request: T,
+        connector: api::enums::Connector,
+        passthrough_data: Option<&payments::ConnectorPassthroughData>,
+    ) -> Self {
+        let allowlist = get_passthrough_fields_allowlist(connector);
+        let passthrough_fields = passthrough_data
+            .map(|passthrough_data| {
+                passthrough_data
+                    .0
+                    .iter()
+                    .filter(|(field, _)| allowlist.contains(&field.as_str()))
+                    .map(|(field, value)| (field.clone(), value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Self {
+            request,
+            passthrough_fields,
+        }
+    }
+}
@@ -139,6 +139,15 @@ pub const MAX_ALLOWED_AMOUNT: i64 = 999999999;
 pub const DEFAULT_UNIFIED_ERROR_CODE: &str = "UE_9000";
 pub const DEFAULT_UNIFIED_ERROR_MESSAGE: &str = "Something went wrong";
 
+// Connector recorded on the payment attempts of payments processed outside of hyperswitch
+pub const EXTERNAL_PAYMENTS_CONNECTOR: &str = "external";
+
+// Maximum number of external payments that can be imported in a request
+pub const MAX_EXTERNAL_PAYMENTS_IMPORT_BATCH_SIZE: usize = 100;
+
+// Maximum length of the reference of an external payment
+pub const MAX_EXTERNAL_REFERENCE_ID_LENGTH: usize = 128;
+
 // Recon's feature tag
 pub const RECON_FEATURE_TAG: &str = "RECONCILIATION AND SETTLEMENT";
 
@@ -31,6 +31,11 @@ pub const REDIS_TOTP_ATTEMPTS_PREFIX: &str = "TOTP_ATTEMPTS_";
 pub const REDIS_RECOVERY_CODE_ATTEMPTS_PREFIX: &str = "RC_ATTEMPTS_";
 pub const REDIS_TOTP_ATTEMPTS_TTL_IN_SECS: i64 = 5 * 60; // 5 mins
 pub const REDIS_RECOVERY_CODE_ATTEMPTS_TTL_IN_SECS: i64 = 10 * 60; // 10 mins
+pub const REDIS_REMEMBERED_DEVICE_PREFIX: &str = "2FA_DEVICE_";
+
+/// Config key prefix of the two factor authentication policy of an organization
+pub const TWO_FACTOR_AUTH_POLICY_CONFIG_PREFIX: &str = "two_factor_auth_policy_";
+pub const REMEMBERED_DEVICE_COOKIE_NAME: &str = "remembered_device";
 
 pub const REDIS_SSO_PREFIX: &str = "SSO_";
 pub const REDIS_SSO_TTL: i64 = 5 * 60; // 5 minutes
@@ -1,6 +1,7 @@
 pub mod admin;
 pub mod api_keys;
 pub mod api_locking;
+pub mod api_logs;
 #[cfg(feature = "v1")]
 pub mod apple_pay_certificates_migration;
 pub mod authentication;
@@ -10,23 +11,52 @@ pub mod cache;
 pub mod cards_info;
 pub mod conditional_config;
 pub mod configs;
+#[cfg(feature = "v1")]
+pub mod connector_balance;
+pub mod connector_capabilities;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod connector_credential_health;
+pub mod connector_debug_capture;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
+#[cfg(feature = "v1")]
+pub mod connector_options;
+pub mod connector_outage;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
+#[cfg(feature = "v1")]
+pub mod data_export;
+pub mod data_residency;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod declarative_config;
+#[cfg(feature = "v1")]
+pub mod demo_connectors;
 pub mod disputes;
 pub mod encryption;
 pub mod errors;
+#[cfg(feature = "v1")]
+pub mod experiments;
 pub mod files;
 #[cfg(feature = "frm")]
 pub mod fraud_check;
+#[cfg(all(feature = "graphql", feature = "v1", not(feature = "customer_v2")))]
+pub mod graphql;
 pub mod gsm;
 pub mod health_check;
 #[cfg(feature = "v1")]
+pub mod invoices;
+pub mod localization;
+#[cfg(feature = "v1")]
 pub mod locker_migration;
+pub mod log_config;
 pub mod mandate;
+pub mod merchant_account_lifecycle;
+pub mod merchant_key_lifecycle;
+pub mod metadata_schema;
 pub mod metrics;
+#[cfg(feature = "v1")]
+pub mod payment_descriptors;
 pub mod payment_link;
 pub mod payment_methods;
 pub mod payments;
@@ -36,12 +66,23 @@ pub mod payout_link;
 pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
+#[cfg(feature = "v1")]
+pub mod receipts;
 #[cfg(feature = "recon")]
 pub mod recon;
 #[cfg(feature = "v1")]
 pub mod refunds;
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod sandbox_data;
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub mod simulation;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod support_bundle;
 pub mod surcharge_decision_config;
+#[cfg(feature = "v1")]
+pub mod test_clocks;
+pub mod traffic_mirroring;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
@@ -52,4 +93,6 @@ pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(feature = "v1")]
+pub mod webhook_endpoints;
+#[cfg(feature = "v1")]
 pub mod webhooks;
@@ -28,6 +28,7 @@ use crate::types::transformers::ForeignFrom;
 use crate::{
     consts,
     core::{
+        connector_capabilities,
         encryption::transfer_encryption_key,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
         payment_methods::{cards, transformers},
@@ -332,6 +333,11 @@ impl MerchantAccountCreateBridge for api::MerchantAccountCreate {
         )
         .await?;
 
+        let data_region = super::data_residency::get_region_for_new_merchant_account(
+            &state.conf.data_residency,
+            self.data_region,
+        )?;
+
         let organization = CreateOrValidateOrganization::new(self.organization_id)
             .create_or_validate(db)
             .await?;
@@ -403,6 +409,7 @@ impl MerchantAccountCreateBridge for api::MerchantAccountCreate {
                     payment_link_config: None,
                     pm_collect_link_config,
                     version: hyperswitch_domain_models::consts::API_VERSION,
+                    data_region,
                 },
             )
         }
@@ -1331,6 +1338,13 @@ impl<'a> ConnectorAuthTypeAndMetadataValidation<'a> {
                 datatrans::transformers::DatatransAuthType::try_from(self.auth_type)?;
                 Ok(())
             }
+            api_enums::Connector::Declarative => {
+                let metadata = declarative::transformers::DeclarativeConnectorMetadata::try_from(
+                    self.connector_meta_data,
+                )?;
+                Declarative::get_spec(&metadata.declarative_connector)?;
+                Ok(())
+            }
             api_enums::Connector::Deutschebank => {
                 deutschebank::transformers::DeutschebankAuthType::try_from(self.auth_type)?;
                 Ok(())
@@ -1701,12 +1715,35 @@ struct PaymentMethodsEnabled<'a> {
     payment_methods_enabled: &'a Option<Vec<api_models::admin::PaymentMethodsEnabled>>,
 }
 
+/// Marks the amount limits of the payment methods being configured as enforced at confirmation.
+/// Payment methods configured before the limits were enforced remain unmarked.
+fn enforce_amount_limits(
+    payment_methods_enabled: api_models::admin::PaymentMethodsEnabled,
+) -> api_models::admin::PaymentMethodsEnabled {
+    api_models::admin::PaymentMethodsEnabled {
+        payment_method_types: payment_methods_enabled.payment_method_types.map(
+            |payment_method_types| {
+                payment_method_types
+                    .into_iter()
+                    .map(|payment_method_type| {
+                        api_models::payment_methods::RequestPaymentMethodTypes {
+                            amount_limits_enforced: true,
+                            ..payment_method_type
+                        }
+                    })
+                    .collect()
+            },
+        ),
+        ..payment_methods_enabled
+    }
+}
+
 impl<'a> PaymentMethodsEnabled<'a> {
     fn get_payment_methods_enabled(&self) -> RouterResult<Option<Vec<pii::SecretSerdeValue>>> {
         let mut vec = Vec::new();
         let payment_methods_enabled = match self.payment_methods_enabled.clone() {
             Some(val) => {
-                for pm in val.into_iter() {
+                for pm in val.into_iter().map(enforce_amount_limits) {
                     let pm_value = pm
                         .encode_to_value()
                         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -2181,8 +2218,9 @@ impl MerchantConnectorAccountUpdateBridge for api_models::admin::MerchantConnect
     ) -> RouterResult<domain::MerchantConnectorAccountUpdate> {
         let payment_methods_enabled = self.payment_methods_enabled.map(|pm_enabled| {
             pm_enabled
-                .iter()
-                .flat_map(Encode::encode_to_value)
+                .into_iter()
+                .map(enforce_amount_limits)
+                .flat_map(|pm| pm.encode_to_value())
                 .map(Secret::new)
                 .collect::<Vec<pii::SecretSerdeValue>>()
         });
@@ -2728,6 +2766,12 @@ pub async fn create_connector(
 
     connector_metadata.validate_apple_pay_certificates_in_mca_metadata()?;
 
+    connector_capabilities::validate_payment_methods_enabled(
+        &state.conf.pm_filters,
+        &req.connector_name.to_string(),
+        req.payment_methods_enabled.as_deref(),
+    )?;
+
     #[cfg(feature = "v1")]
     helpers::validate_business_details(
         req.business_country,
@@ -2835,6 +2879,20 @@ pub async fn create_connector(
         ]),
     );
 
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    if let Err(error) = crate::core::connector_credential_health::schedule_credential_health_check(
+        &state,
+        merchant_id,
+        &mca.get_id(),
+    )
+    .await
+    {
+        crate::logger::error!(
+            ?error,
+            "Failed to schedule the connector credential health check"
+        );
+    }
+
     let mca_response = mca.foreign_try_into()?;
     Ok(service_api::ApplicationResponse::Json(mca_response))
 }
@@ -3067,6 +3125,12 @@ pub async fn update_connector(
         .await?;
     core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
 
+    connector_capabilities::validate_payment_methods_enabled(
+        &state.conf.pm_filters,
+        &mca.connector_name,
+        req.payment_methods_enabled.as_deref(),
+    )?;
+
     let payment_connector = req
         .clone()
         .create_domain_model_from_request(
@@ -3081,6 +3145,7 @@ pub async fn update_connector(
     // Profile id should always be present
     let profile_id = mca.profile_id.clone();
 
+    let are_credentials_updated = req.connector_account_details.is_some();
     let request_connector_label = req.connector_label;
 
     let updated_mca = db
@@ -3104,6 +3169,30 @@ pub async fn update_connector(
             )
         })?;
 
+    // The connector account is no longer excluded from routing once its credentials are updated,
+    // the new credentials are verified by the next credential health check
+    if are_credentials_updated {
+        crate::core::payments::connector_health::mark_connector_healthy(
+            &state,
+            merchant_connector_id,
+        )
+        .await;
+        #[cfg(all(feature = "v1", feature = "olap"))]
+        if let Err(error) =
+            crate::core::connector_credential_health::schedule_credential_health_check(
+                &state,
+                merchant_id,
+                merchant_connector_id,
+            )
+            .await
+        {
+            crate::logger::error!(
+                ?error,
+                "Failed to schedule the connector credential health check"
+            );
+        }
+    }
+
     let response = updated_mca.foreign_try_into()?;
 
     Ok(service_api::ApplicationResponse::Json(response))
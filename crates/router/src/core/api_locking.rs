@@ -10,6 +10,38 @@ use crate::routes::{app::SessionStateInfo, lock_utils};
 
 pub const API_LOCK_PREFIX: &str = "API_LOCK";
 
+pub const API_LOCK_FENCE_SUFFIX: &str = "FENCE";
+
+/// Acquires the lock if it is free, issuing the next fencing token of the lock. The token is part
+/// of the lock value, and no token is issued when the lock is busy. Returns 0 if the lock is busy.
+const ACQUIRE_FENCED_LOCK_SCRIPT: &str = r#"
+if redis.call("EXISTS", KEYS[1]) == 1 then
+    return 0
+end
+local fencing_token = redis.call("INCR", KEYS[2])
+redis.call("EXPIRE", KEYS[2], ARGV[3])
+redis.call("SET", KEYS[1], fencing_token .. "_" .. ARGV[1], "EX", ARGV[2])
+return fencing_token
+"#;
+
+/// Renews the expiry of the lock only when it is still held with the fencing token of the request.
+/// Returns 0 if the lock expired and is free or held with a newer fencing token.
+const RENEW_FENCED_LOCK_SCRIPT: &str = r#"
+if redis.call("GET", KEYS[1]) == ARGV[1] then
+    return redis.call("EXPIRE", KEYS[1], ARGV[2])
+end
+return 0
+"#;
+
+/// Deletes the lock only when it is held by the request
+const RELEASE_FENCED_LOCK_SCRIPT: &str = r#"
+local lock_value = redis.call("GET", KEYS[1])
+if lock_value and string.sub(lock_value, -string.len(ARGV[1])) == ARGV[1] then
+    return redis.call("DEL", KEYS[1])
+end
+return 0
+"#;
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum LockStatus {
     // status when the lock is acquired by the caller
@@ -22,6 +54,9 @@ pub enum LockStatus {
 pub enum LockAction {
     // Sleep until the lock is acquired
     Hold { input: LockingInput },
+    // Acquire the lock with a fencing token, if it is held wait for the request holding it to
+    // complete so that its outcome can be replayed
+    Fence { input: LockingInput },
     // Queue it but return response as 2xx, could be used for webhooks
     QueueWithOk,
     // Return Error
@@ -47,15 +82,92 @@ impl LockingInput {
             self.unique_locking_key
         )
     }
+
+    fn get_redis_fence_key(&self, merchant_id: common_utils::id_type::MerchantId) -> String {
+        format!(
+            "{}_{}",
+            self.get_redis_locking_key(merchant_id),
+            API_LOCK_FENCE_SUFFIX
+        )
+    }
+}
+
+/// Outcome of acquiring a fenced lock
+#[derive(Clone, Debug)]
+pub enum FencedLockStatus {
+    /// The lock was acquired by the request
+    Acquired(FencedLock),
+    /// The lock was held by a duplicate request which has completed since, the outcome of the
+    /// duplicate request is to be replayed instead of processing the request again
+    Replay,
+}
+
+/// A lock acquired with a fencing token
+#[derive(Clone, Debug)]
+pub struct FencedLock {
+    redis_locking_key: String,
+    lock_value: String,
+}
+
+impl FencedLock {
+    /// Checks that the lock is still held with the fencing token of the request before its writes
+    /// are applied, and renews the expiry of the lock in the same step so that the writes which
+    /// follow are applied while the request holds the lock. The lock may have expired while the
+    /// request was in progress and been acquired by another request with a newer fencing token.
+    pub async fn validate<A>(&self, state: &A) -> RouterResult<()>
+    where
+        A: SessionStateInfo,
+    {
+        let redis_lock_expiry_seconds = state.conf().lock_settings.redis_lock_expiry_seconds;
+        let renewed = state
+            .store()
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)?
+            .evaluate_redis_script::<_, i64>(
+                RENEW_FENCED_LOCK_SCRIPT,
+                vec![self.redis_locking_key.clone()],
+                vec![
+                    self.lock_value.clone(),
+                    redis_lock_expiry_seconds.to_string(),
+                ],
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to renew the fenced lock")?;
+
+        if renewed > 0 {
+            Ok(())
+        } else {
+            Err(report!(errors::ApiErrorResponse::ResourceBusy))
+                .attach_printable("The lock expired and is held with a newer fencing token")
+        }
+    }
+}
+
+/// Validates the fenced lock of the request, if the request holds one, before the request writes
+/// the outcome of a connector call
+pub async fn validate_fenced_lock<A>(
+    state: &A,
+    fenced_lock_status: &Option<FencedLockStatus>,
+) -> RouterResult<()>
+where
+    A: SessionStateInfo,
+{
+    match fenced_lock_status {
+        Some(FencedLockStatus::Acquired(fenced_lock)) => fenced_lock.validate(state).await,
+        Some(FencedLockStatus::Replay) | None => Ok(()),
+    }
 }
 
 impl LockAction {
+    /// Acquires the lock of the action. The status of a fenced lock is returned, so that a
+    /// duplicate of a request holding the lock can replay its outcome.
     #[instrument(skip_all)]
     pub async fn perform_locking_action<A>(
         self,
         state: &A,
         merchant_id: common_utils::id_type::MerchantId,
-    ) -> RouterResult<()>
+    ) -> RouterResult<Option<FencedLockStatus>>
     where
         A: SessionStateInfo,
     {
@@ -90,7 +202,7 @@ impl LockAction {
                             logger::info!("Lock acquired for locking input {:?}", input);
                             tracing::Span::current()
                                 .record("redis_lock_acquired", redis_locking_key);
-                            return Ok(());
+                            return Ok(None);
                         }
                         Ok(redis::SetnxReply::KeyNotSet) => {
                             logger::info!(
@@ -111,7 +223,78 @@ impl LockAction {
 
                 Err(report!(errors::ApiErrorResponse::ResourceBusy))
             }
-            Self::QueueWithOk | Self::Drop | Self::NotApplicable => Ok(()),
+            Self::Fence { input } => {
+                let redis_conn = state
+                    .store()
+                    .get_redis_conn()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+                let redis_locking_key = input.get_redis_locking_key(merchant_id.clone());
+                let redis_fence_key = input.get_redis_fence_key(merchant_id);
+                let lock_settings = &state.conf().lock_settings;
+                let redis_lock_expiry_seconds = i64::from(lock_settings.redis_lock_expiry_seconds);
+                // The request id identifies the holder of the lock on release, a lock without one
+                // could be released by any other request
+                let request_id = state
+                    .get_request_id()
+                    .ok_or(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("The request id is required to acquire a fenced lock")?;
+
+                // The fence counter outlives the lock, so that the fencing tokens of the lock keep
+                // increasing across expiries of the lock
+                let fencing_token = redis_conn
+                    .evaluate_redis_script::<_, i64>(
+                        ACQUIRE_FENCED_LOCK_SCRIPT,
+                        vec![redis_locking_key.clone(), redis_fence_key],
+                        vec![
+                            request_id.clone(),
+                            redis_lock_expiry_seconds.to_string(),
+                            redis_lock_expiry_seconds.saturating_mul(2).to_string(),
+                        ],
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to acquire the fenced lock")?;
+
+                if fencing_token > 0 {
+                    logger::info!(
+                        "Lock acquired with fencing token {} for locking input {:?}",
+                        fencing_token,
+                        input
+                    );
+                    tracing::Span::current()
+                        .record("redis_lock_acquired", redis_locking_key.clone());
+                    return Ok(Some(FencedLockStatus::Acquired(FencedLock {
+                        redis_locking_key,
+                        lock_value: format!("{fencing_token}_{request_id}"),
+                    })));
+                }
+
+                logger::info!(
+                    "Lock busy by other request, waiting for it to complete for locking input {:?}",
+                    input
+                );
+                let lock_retries = input
+                    .override_lock_retries
+                    .unwrap_or(lock_settings.lock_retries);
+                for _retry in 0..lock_retries {
+                    actix_time::sleep(tokio::time::Duration::from_millis(u64::from(
+                        lock_settings.delay_between_retries_in_milliseconds,
+                    )))
+                    .await;
+
+                    let lock_value = redis_conn
+                        .get_key::<Option<String>>(&redis_locking_key)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+                    if lock_value.is_none() {
+                        return Ok(Some(FencedLockStatus::Replay));
+                    }
+                }
+
+                Err(report!(errors::ApiErrorResponse::ResourceBusy))
+            }
+            Self::QueueWithOk | Self::Drop | Self::NotApplicable => Ok(None),
         }
     }
 
@@ -165,6 +348,41 @@ impl LockAction {
                     }
                 }
             }
+            Self::Fence { input } => {
+                let redis_conn = state
+                    .store()
+                    .get_redis_conn()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+                let redis_locking_key = input.get_redis_locking_key(merchant_id);
+                let request_id = state
+                    .get_request_id()
+                    .ok_or(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("The request id is required to release a fenced lock")?;
+
+                // The lock is only deleted if it is still held by the request, it may have expired
+                // while the request was in progress and been acquired by another request
+                let deleted_keys = redis_conn
+                    .evaluate_redis_script::<_, i64>(
+                        RELEASE_FENCED_LOCK_SCRIPT,
+                        vec![redis_locking_key.clone()],
+                        vec![format!("_{request_id}")],
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to release the fenced lock")?;
+
+                if deleted_keys > 0 {
+                    logger::info!("Lock freed for locking input {:?}", input);
+                    tracing::Span::current().record("redis_lock_released", redis_locking_key);
+                } else {
+                    logger::warn!(
+                        "Lock was not held by the request on release for locking input {:?}",
+                        input
+                    );
+                }
+                Ok(())
+            }
             Self::QueueWithOk | Self::Drop | Self::NotApplicable => Ok(()),
         }
     }
@@ -0,0 +1,146 @@
+use api_models::api_logs as api_log_types;
+use common_utils::ext_traits::{Encode, StringExt};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::domain,
+};
+
+const API_LOGS_PREFIX: &str = "API_LOGS";
+const DEFAULT_API_LOGS_LIST_LIMIT: u16 = 100;
+
+fn get_api_logs_key(merchant_id: &common_utils::id_type::MerchantId) -> String {
+    format!("{API_LOGS_PREFIX}_{}", merchant_id.get_string_repr())
+}
+
+/// Records the summary of an API request made by the merchant, discarding the oldest requests once
+/// the maximum number of requests recorded for the merchant is exceeded
+#[instrument(skip_all)]
+pub async fn record_api_log(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    api_log: api_log_types::ApiLogResponse,
+) {
+    if let Err(error) = append_api_log(state, merchant_id, api_log).await {
+        logger::error!(?error, "Failed to record the api log");
+    }
+}
+
+async fn append_api_log(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    api_log: api_log_types::ApiLogResponse,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = get_api_logs_key(merchant_id);
+    let api_log = api_log
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the api log")?;
+
+    redis_conn
+        .append_elements_to_list(&key, api_log)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let max_entries = state.conf.api_logs.max_entries_per_merchant;
+    let entries = redis_conn
+        .get_list_length(&key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    if entries > max_entries {
+        redis_conn
+            .lpop_list_elements(&key, Some(entries - max_entries))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    }
+
+    redis_conn
+        .set_expiry(&key, state.conf.api_logs.retention_in_secs)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+/// Finds the retained API requests of the merchant which satisfy the constraints, with the most
+/// recent request first
+pub async fn find_api_logs(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    constraints: api_log_types::ApiLogsListConstraints,
+) -> RouterResult<Vec<api_log_types::ApiLogResponse>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let api_logs = redis_conn
+        .get_list_elements(&get_api_logs_key(merchant_id), 0, -1)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the api logs")?;
+
+    let retained_after = common_utils::date_time::now()
+        - time::Duration::seconds(state.conf.api_logs.retention_in_secs);
+    let limit = usize::from(constraints.limit.unwrap_or(DEFAULT_API_LOGS_LIST_LIMIT));
+
+    let data = api_logs
+        .into_iter()
+        .rev()
+        .filter_map(|api_log| {
+            api_log
+                .parse_struct::<api_log_types::ApiLogResponse>("ApiLogResponse")
+                .map_err(|error| logger::error!(?error, "Failed to parse the api log"))
+                .ok()
+        })
+        .filter(|api_log| {
+            api_log.created_at >= retained_after
+                && constraints
+                    .url_path
+                    .as_ref()
+                    .map_or(true, |url_path| api_log.url_path.starts_with(url_path))
+                && constraints
+                    .status_code
+                    .map_or(true, |status_code| api_log.status_code == status_code)
+                && constraints.error_code.as_ref().map_or(true, |error_code| {
+                    api_log.error_code.as_ref() == Some(error_code)
+                })
+                && constraints.api_key_id.as_ref().map_or(true, |api_key_id| {
+                    api_log.api_key_id.as_ref() == Some(api_key_id)
+                })
+                && constraints
+                    .created_after
+                    .map_or(true, |created_after| api_log.created_at >= created_after)
+                && constraints
+                    .created_before
+                    .map_or(true, |created_before| api_log.created_at <= created_before)
+        })
+        .take(limit)
+        .collect();
+
+    Ok(data)
+}
+
+#[instrument(skip_all)]
+pub async fn list_api_logs(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: api_log_types::ApiLogsListConstraints,
+) -> RouterResponse<api_log_types::ApiLogsListResponse> {
+    let data = find_api_logs(&state, merchant_account.get_id(), constraints).await?;
+
+    Ok(ApplicationResponse::Json(
+        api_log_types::ApiLogsListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
@@ -39,6 +39,8 @@ pub async fn perform_authentication(
     email: Option<common_utils::pii::Email>,
     webhook_url: String,
     three_ds_requestor_url: String,
+    three_ri_indicator: Option<payments::ThreeRiIndicator>,
+    prior_authentication_data: Option<types::PriorAuthenticationData>,
 ) -> CustomResult<api::authentication::AuthenticationResponse, ApiErrorResponse> {
     let router_data = transformers::construct_authentication_router_data(
         merchant_id,
@@ -60,6 +62,8 @@ pub async fn perform_authentication(
         email,
         webhook_url,
         three_ds_requestor_url,
+        three_ri_indicator,
+        prior_authentication_data,
     )?;
     let response = Box::pin(utils::do_auth_connector_call(
         state,
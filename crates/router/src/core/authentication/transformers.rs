@@ -43,6 +43,8 @@ pub fn construct_authentication_router_data(
     email: Option<common_utils::pii::Email>,
     webhook_url: String,
     three_ds_requestor_url: String,
+    three_ri_indicator: Option<payments::ThreeRiIndicator>,
+    prior_authentication_data: Option<super::types::PriorAuthenticationData>,
 ) -> RouterResult<types::authentication::ConnectorAuthenticationRouterData> {
     let router_request = types::authentication::ConnectorAuthenticationRequestData {
         payment_method_data,
@@ -62,6 +64,8 @@ pub fn construct_authentication_router_data(
         three_ds_requestor_url,
         threeds_method_comp_ind,
         webhook_url,
+        three_ri_indicator,
+        prior_authentication_data,
     };
     construct_router_data(
         authentication_connector,
@@ -174,6 +178,7 @@ pub fn construct_router_data<F: Clone, Req, Res>(
         quote_id: None,
         test_mode,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -182,6 +187,7 @@ pub fn construct_router_data<F: Clone, Req, Res>(
         payment_method_status: None,
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -1,6 +1,7 @@
 use error_stack::{Report, ResultExt};
 pub use hyperswitch_domain_models::router_request_types::authentication::{
-    AcquirerDetails, ExternalThreeDSConnectorMetadata, PreAuthenticationData, ThreeDsMethodData,
+    AcquirerDetails, ExternalThreeDSConnectorMetadata, PreAuthenticationData,
+    PriorAuthenticationData, ThreeDsMethodData,
 };
 
 use crate::{
@@ -33,3 +34,26 @@ impl ForeignTryFrom<&storage::Authentication> for PreAuthenticationData {
         })
     }
 }
+
+impl ForeignTryFrom<&storage::Authentication> for PriorAuthenticationData {
+    type Error = Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(authentication: &storage::Authentication) -> Result<Self, Self::Error> {
+        let error_message = errors::ApiErrorResponse::PreconditionFailed {
+            message: "Prior authentication must be completed successfully".to_string(),
+        };
+        if authentication.authentication_status != common_enums::AuthenticationStatus::Success {
+            return Err(error_message.into());
+        }
+        let acs_trans_id = authentication
+            .acs_trans_id
+            .clone()
+            .get_required_value("acs_trans_id")
+            .change_context(error_message)?;
+        Ok(Self {
+            acs_trans_id,
+            authentication_type: authentication.authentication_type,
+            authenticated_at: authentication.modified_at,
+        })
+    }
+}
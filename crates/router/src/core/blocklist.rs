@@ -1,3 +1,4 @@
+pub mod sync;
 pub mod transformers;
 pub mod utils;
 
@@ -0,0 +1,696 @@
+use std::collections::HashSet;
+
+use api_models::blocklist as api_blocklist;
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::utils::get_blocklist_fingerprint;
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{domain, storage, transformers::ForeignFrom},
+    utils,
+};
+
+const BLOCKLIST_SYNC_NAME: &str = "BLOCKLIST_SYNC";
+const BLOCKLIST_SYNC_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::BlocklistSyncWorkflow;
+const BLOCKLIST_SYNC_TAG: &str = "BLOCKLIST_SYNC";
+const BLOCKLIST_SYNC_LOCK_EXPIRY_IN_SECS: i64 = 300;
+const BLOCKLIST_SOURCE_TIMEOUT_SECS: u64 = 30;
+const BLOCKLIST_SYNC_SOURCE_MAX_LENGTH: usize = 255;
+
+/// Deletes the lock only when it is still held by the caller
+const RELEASE_SYNC_LOCK_SCRIPT: &str = r#"
+if redis.call("GET", KEYS[1]) == ARGV[1] then
+    return redis.call("DEL", KEYS[1])
+end
+return 0
+"#;
+
+/// Tracking data of the task periodically pulling the blocklist of a merchant from its source
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct BlocklistSyncTrackingData {
+    pub merchant_id: id_type::MerchantId,
+}
+
+impl ForeignFrom<&storage::BlocklistSyncVersion> for api_blocklist::BlocklistSyncVersionResponse {
+    fn foreign_from(version: &storage::BlocklistSyncVersion) -> Self {
+        Self {
+            version: u32::try_from(version.version).unwrap_or_default(),
+            source: version.source.clone(),
+            external_version: version.external_version.clone(),
+            added_count: version.added_entries.0.len(),
+            removed_count: version.removed_entries.0.len(),
+            unchanged_count: usize::try_from(version.unchanged_count).unwrap_or_default(),
+            created_at: version.created_at,
+            rolled_back_at: version.rolled_back_at,
+        }
+    }
+}
+
+/// The retained synchronizations of the blocklist of a merchant, the latest first
+struct BlocklistSyncHistory {
+    current_version: i32,
+    latest_version: i32,
+    versions: Vec<storage::BlocklistSyncVersion>,
+}
+
+impl BlocklistSyncHistory {
+    fn new(versions: Vec<storage::BlocklistSyncVersion>) -> Self {
+        let latest_version = versions
+            .iter()
+            .map(|version| version.version)
+            .max()
+            .unwrap_or_default();
+        // Versions are rolled back from the latest one, so the current version is the latest one
+        // which was not rolled back, or the one preceding the oldest retained version
+        let current_version = versions
+            .iter()
+            .filter(|version| version.rolled_back_at.is_none())
+            .map(|version| version.version)
+            .max()
+            .or_else(|| versions.iter().map(|version| version.version - 1).min())
+            .unwrap_or_default();
+
+        Self {
+            current_version,
+            latest_version,
+            versions,
+        }
+    }
+}
+
+async fn get_sync_history(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<BlocklistSyncHistory> {
+    db.list_blocklist_sync_versions_by_merchant_id(merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the blocklist synchronizations")
+        .map(BlocklistSyncHistory::new)
+}
+
+/// The source from which the blocklist of a merchant is periodically pulled
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+struct BlocklistSyncSource {
+    url: String,
+    pull_interval_in_secs: u32,
+    enabled: bool,
+    last_external_version: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    last_pulled_at: Option<time::PrimitiveDateTime>,
+    last_pull_error: Option<String>,
+}
+
+impl From<&BlocklistSyncSource> for api_blocklist::BlocklistSyncSourceResponse {
+    fn from(source: &BlocklistSyncSource) -> Self {
+        Self {
+            url: source.url.clone(),
+            pull_interval_in_secs: source.pull_interval_in_secs,
+            enabled: source.enabled,
+            last_pulled_at: source.last_pulled_at,
+            last_pull_error: source.last_pull_error.clone(),
+        }
+    }
+}
+
+fn get_source_config_key(merchant_id: &id_type::MerchantId) -> String {
+    format!("blocklist_sync_source_{}", merchant_id.get_string_repr())
+}
+
+fn get_lock_key(merchant_id: &id_type::MerchantId) -> String {
+    format!("blocklist_sync_lock_{}", merchant_id.get_string_repr())
+}
+
+fn get_process_tracker_id(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{BLOCKLIST_SYNC_RUNNER}_{BLOCKLIST_SYNC_NAME}_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+async fn get_config<T: serde::de::DeserializeOwned>(
+    db: &dyn StorageInterface,
+    key: &str,
+    type_name: &'static str,
+) -> RouterResult<Option<T>> {
+    match db.find_config_by_key_from_db(key).await {
+        Ok(config) => config
+            .config
+            .parse_struct(type_name)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to parse the {type_name}"))
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to fetch the {type_name}"))),
+    }
+}
+
+async fn store_config<T: serde::Serialize>(
+    db: &dyn StorageInterface,
+    key: String,
+    value: &T,
+) -> RouterResult<()> {
+    let config = value
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the blocklist synchronization config")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the blocklist synchronization config")
+}
+
+/// Runs the given operation while holding the blocklist synchronization lock of the merchant, so
+/// that synchronizations and rollbacks of a blocklist never interleave
+async fn with_sync_lock<T, F>(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    operation: F,
+) -> RouterResult<T>
+where
+    F: std::future::Future<Output = RouterResult<T>>,
+{
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let lock_key = get_lock_key(merchant_id);
+    let lock_token = utils::generate_id(consts::ID_LENGTH, "lock");
+
+    let reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &lock_key,
+            lock_token.clone(),
+            Some(BLOCKLIST_SYNC_LOCK_EXPIRY_IN_SECS),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to acquire the blocklist synchronization lock")?;
+    if reply != redis_interface::SetnxReply::KeySet {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "the blocklist is already being synchronized".to_string(),
+        }));
+    }
+
+    let result = operation.await;
+
+    // The lock may have expired and been acquired by another synchronization in the meantime
+    match redis_conn
+        .evaluate_redis_script::<_, i64>(RELEASE_SYNC_LOCK_SCRIPT, vec![lock_key], vec![lock_token])
+        .await
+    {
+        Ok(0) => logger::warn!("The blocklist synchronization lock expired before being released"),
+        Ok(_) => {}
+        Err(error) => logger::error!(
+            ?error,
+            "Failed to release the blocklist synchronization lock"
+        ),
+    }
+
+    result
+}
+
+fn get_fingerprints(
+    entries: &[api_blocklist::BlocklistRequest],
+) -> RouterResult<Vec<(String, common_enums::BlocklistDataKind)>> {
+    entries.iter().map(get_blocklist_fingerprint).collect()
+}
+
+/// Applies the additions and removals of the synchronization request as a new version of the
+/// blocklist. Either all of the changes are applied or none of them.
+#[instrument(skip_all)]
+pub async fn apply_blocklist_sync(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    request: api_blocklist::BlocklistSyncRequest,
+) -> RouterResult<api_blocklist::BlocklistSyncVersionResponse> {
+    let config = &state.conf.blocklist_sync;
+    let entries_count = request.additions.len() + request.removals.len();
+    utils::when(entries_count == 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "no entries to be added to or removed from the blocklist".to_string(),
+        })
+    })?;
+    utils::when(entries_count > config.max_entries_per_sync, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "a synchronization can add or remove at most {} entries",
+                config.max_entries_per_sync
+            ),
+        })
+    })?;
+
+    utils::when(
+        request.source.len() > BLOCKLIST_SYNC_SOURCE_MAX_LENGTH
+            || request
+                .external_version
+                .as_ref()
+                .is_some_and(|external_version| {
+                    external_version.len() > BLOCKLIST_SYNC_SOURCE_MAX_LENGTH
+                }),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "the source and the external version can be at most {BLOCKLIST_SYNC_SOURCE_MAX_LENGTH} characters long"
+                ),
+            })
+        },
+    )?;
+
+    // Every entry is validated before the blocklist is changed
+    let additions = get_fingerprints(&request.additions)?;
+    let removals = get_fingerprints(&request.removals)?;
+    let removed_fingerprints = removals
+        .iter()
+        .map(|(fingerprint_id, _)| fingerprint_id.as_str())
+        .collect::<HashSet<_>>();
+    utils::when(
+        additions
+            .iter()
+            .any(|(fingerprint_id, _)| removed_fingerprints.contains(fingerprint_id.as_str())),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "an entry cannot be both added to and removed from the blocklist"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    with_sync_lock(state, merchant_id, async {
+        let db = state.store.as_ref();
+        let history = get_sync_history(db, merchant_id).await?;
+
+        if let Some(base_version) = request.base_version {
+            utils::when(
+                i32::try_from(base_version).ok() != Some(history.current_version),
+                || {
+                    Err(errors::ApiErrorResponse::PreconditionFailed {
+                        message: format!(
+                            "the blocklist is at version {}, not at the base version {base_version}",
+                            history.current_version
+                        ),
+                    })
+                },
+            )?;
+        }
+
+        let version = history.latest_version + 1;
+        let metadata = serde_json::json!({
+            "source": request.source,
+            "sync_version": version,
+        });
+        let created_at = date_time::now();
+        let blocklist_sync = storage::BlocklistSync {
+            merchant_id: merchant_id.clone(),
+            version,
+            source: request.source,
+            external_version: request.external_version,
+            additions: additions
+                .into_iter()
+                .map(|(fingerprint_id, data_kind)| storage::BlocklistNew {
+                    merchant_id: merchant_id.clone(),
+                    fingerprint_id,
+                    data_kind,
+                    metadata: Some(metadata.clone()),
+                    created_at,
+                })
+                .collect(),
+            removals: removals
+                .into_iter()
+                .map(|(fingerprint_id, _)| fingerprint_id)
+                .collect(),
+            created_at,
+        };
+        let max_retained_versions =
+            i32::try_from(config.max_retained_versions).unwrap_or(i32::MAX);
+
+        // The changes and the version are stored in a single transaction, and a concurrent
+        // synchronization creating the same version fails as a whole
+        db.apply_blocklist_sync(blocklist_sync, max_retained_versions)
+            .await
+            .map_err(|error| {
+                if error.current_context().is_db_unique_violation() {
+                    error.change_context(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "the blocklist was synchronized concurrently".to_string(),
+                    })
+                } else {
+                    error
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to apply the blocklist synchronization")
+                }
+            })
+            .map(|sync_version| {
+                api_blocklist::BlocklistSyncVersionResponse::foreign_from(&sync_version)
+            })
+    })
+    .await
+}
+
+/// Rolls the blocklist back to the given version, undoing the synchronizations made since, the
+/// latest first
+#[instrument(skip_all)]
+pub async fn rollback_blocklist_sync(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    request: api_blocklist::BlocklistSyncRollbackRequest,
+) -> RouterResult<()> {
+    let target_version = i32::try_from(request.version).change_context(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("version {} does not exist", request.version),
+        },
+    )?;
+
+    with_sync_lock(state, merchant_id, async {
+        let db = state.store.as_ref();
+        let history = get_sync_history(db, merchant_id).await?;
+
+        utils::when(target_version >= history.current_version, || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "the version to be restored must precede the current version {}",
+                    history.current_version
+                ),
+            })
+        })?;
+        // The synchronizations made since the version must all be retained to be undone
+        let oldest_retained_version = history
+            .versions
+            .iter()
+            .map(|version| version.version)
+            .min()
+            .unwrap_or(history.latest_version + 1);
+        utils::when(target_version + 1 < oldest_retained_version, || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("version {} is no longer retained", request.version),
+            })
+        })?;
+
+        let versions_to_undo = history
+            .versions
+            .into_iter()
+            .filter(|sync_version| {
+                sync_version.version > target_version && sync_version.rolled_back_at.is_none()
+            })
+            .collect();
+        db.rollback_blocklist_sync_versions(merchant_id, versions_to_undo, date_time::now())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to roll back the blocklist synchronizations")
+    })
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn get_blocklist_sync_status(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<api_blocklist::BlocklistSyncStatusResponse> {
+    let db = state.store.as_ref();
+    let history = get_sync_history(db, merchant_id).await?;
+    let source: Option<BlocklistSyncSource> = get_config(
+        db,
+        &get_source_config_key(merchant_id),
+        "BlocklistSyncSource",
+    )
+    .await?;
+
+    Ok(api_blocklist::BlocklistSyncStatusResponse {
+        current_version: u32::try_from(history.current_version).unwrap_or_default(),
+        versions: history
+            .versions
+            .iter()
+            .map(api_blocklist::BlocklistSyncVersionResponse::foreign_from)
+            .collect(),
+        source: source.as_ref().map(Into::into),
+    })
+}
+
+/// Configures the source from which the blocklist is periodically pulled, scheduling the pulls if
+/// the source is enabled
+#[instrument(skip_all)]
+pub async fn configure_blocklist_sync_source(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    request: api_blocklist::BlocklistSyncSourceRequest,
+) -> RouterResult<api_blocklist::BlocklistSyncSourceResponse> {
+    let config = &state.conf.blocklist_sync;
+    let url = url::Url::parse(&request.url).change_context(
+        errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "url".to_string(),
+            expected_format: "a valid URL".to_string(),
+        },
+    )?;
+    utils::when(url.scheme() != "https", || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "the source of the blocklist must be served over https".to_string(),
+        })
+    })?;
+    utils::when(
+        request.pull_interval_in_secs < config.min_pull_interval_in_secs,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "the pull interval must be at least {} seconds",
+                    config.min_pull_interval_in_secs
+                ),
+            })
+        },
+    )?;
+
+    let db = state.store.as_ref();
+    let source_key = get_source_config_key(merchant_id);
+    let existing_source: Option<BlocklistSyncSource> =
+        get_config(db, &source_key, "BlocklistSyncSource").await?;
+    // The state of the previous pulls is kept as long as the same document is pulled
+    let source = match existing_source {
+        Some(existing_source) if existing_source.url == request.url => BlocklistSyncSource {
+            pull_interval_in_secs: request.pull_interval_in_secs,
+            enabled: request.enabled,
+            ..existing_source
+        },
+        _ => BlocklistSyncSource {
+            url: request.url,
+            pull_interval_in_secs: request.pull_interval_in_secs,
+            enabled: request.enabled,
+            last_external_version: None,
+            last_pulled_at: None,
+            last_pull_error: None,
+        },
+    };
+    store_config(db, source_key, &source).await?;
+
+    if source.enabled {
+        schedule_blocklist_pull(state, merchant_id).await?;
+    }
+
+    Ok((&source).into())
+}
+
+/// Schedules the blocklist of the merchant to be pulled right away, rescheduling the task if it
+/// already exists
+async fn schedule_blocklist_pull(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let process_tracker_id = get_process_tracker_id(merchant_id);
+    let schedule_time = date_time::now();
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the blocklist synchronization task")?;
+    if let Some(process) = existing_process {
+        return db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule the blocklist synchronization task");
+    }
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        BLOCKLIST_SYNC_NAME,
+        BLOCKLIST_SYNC_RUNNER,
+        [BLOCKLIST_SYNC_TAG],
+        BlocklistSyncTrackingData {
+            merchant_id: merchant_id.clone(),
+        },
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the blocklist synchronization task")?;
+
+    match db.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to schedule the blocklist synchronization task")),
+    }
+}
+
+async fn fetch_blocklist_document(
+    state: &SessionState,
+    url: &str,
+) -> RouterResult<api_blocklist::BlocklistSyncRequest> {
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Get)
+        .url(url)
+        .attach_default_headers()
+        .build();
+    let response = state
+        .api_client
+        .send_request(state, request, Some(BLOCKLIST_SOURCE_TIMEOUT_SECS), false)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reach the source of the blocklist")?;
+
+    let status = response.status();
+    utils::when(!status.is_success(), || {
+        Err(
+            report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+                "The source of the blocklist responded with {status}"
+            )),
+        )
+    })?;
+
+    response
+        .text()
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to read the blocklist document")?
+        .parse_struct("BlocklistSyncRequest")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the blocklist document")
+}
+
+/// Pulls the blocklist document of the merchant from its source, applying it unless its external
+/// version was applied already. Returns the time at which the document is to be pulled next, if it
+/// is to be pulled again.
+#[instrument(skip_all)]
+pub async fn pull_blocklist_from_source(
+    state: &SessionState,
+    tracking_data: &BlocklistSyncTrackingData,
+) -> RouterResult<Option<time::PrimitiveDateTime>> {
+    let db = state.store.as_ref();
+    let merchant_id = &tracking_data.merchant_id;
+    let source_key = get_source_config_key(merchant_id);
+    let Some(mut source) =
+        get_config::<BlocklistSyncSource>(db, &source_key, "BlocklistSyncSource").await?
+    else {
+        return Ok(None);
+    };
+    if !source.enabled {
+        return Ok(None);
+    }
+
+    let result = async {
+        let document = fetch_blocklist_document(state, &source.url).await?;
+        if document.external_version.is_some()
+            && document.external_version == source.last_external_version
+        {
+            return Ok(source.last_external_version.clone());
+        }
+        let external_version = document.external_version.clone();
+        apply_blocklist_sync(state, merchant_id, document).await?;
+        Ok::<_, error_stack::Report<errors::ApiErrorResponse>>(external_version)
+    }
+    .await;
+
+    source.last_pulled_at = Some(date_time::now());
+    match result {
+        Ok(external_version) => {
+            source.last_external_version = external_version;
+            source.last_pull_error = None;
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to pull the blocklist from its source");
+            source.last_pull_error = Some(match error.current_context() {
+                errors::ApiErrorResponse::InvalidRequestData { message }
+                | errors::ApiErrorResponse::PreconditionFailed { message } => message.clone(),
+                errors::ApiErrorResponse::InvalidDataFormat {
+                    field_name,
+                    expected_format,
+                } => format!("{field_name} is expected to be {expected_format}"),
+                _ => "failed to fetch or apply the blocklist document".to_string(),
+            });
+        }
+    }
+    store_config(db, source_key, &source).await?;
+
+    Ok(Some(
+        date_time::now() + time::Duration::seconds(i64::from(source.pull_interval_in_secs)),
+    ))
+}
+
+pub async fn sync_blocklist(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: api_blocklist::BlocklistSyncRequest,
+) -> RouterResponse<api_blocklist::BlocklistSyncVersionResponse> {
+    apply_blocklist_sync(&state, merchant_account.get_id(), request)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+pub async fn rollback_blocklist(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: api_blocklist::BlocklistSyncRollbackRequest,
+) -> RouterResponse<api_blocklist::BlocklistSyncStatusResponse> {
+    rollback_blocklist_sync(&state, merchant_account.get_id(), request).await?;
+    get_blocklist_sync_status(&state, merchant_account.get_id())
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+pub async fn retrieve_blocklist_sync_status(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<api_blocklist::BlocklistSyncStatusResponse> {
+    get_blocklist_sync_status(&state, merchant_account.get_id())
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+pub async fn update_blocklist_sync_source(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: api_blocklist::BlocklistSyncSourceRequest,
+) -> RouterResponse<api_blocklist::BlocklistSyncSourceResponse> {
+    configure_blocklist_sync_source(&state, merchant_account.get_id(), request)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
@@ -1,9 +1,12 @@
 use api_models::blocklist as api_blocklist;
 use common_enums::MerchantDecision;
-use common_utils::errors::CustomResult;
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    errors::CustomResult,
+};
 use diesel_models::configs;
 use error_stack::ResultExt;
-use masking::StrongSecret;
+use masking::{PeekInterface, StrongSecret};
 
 use super::{errors, transformers::generate_fingerprint, SessionState};
 use crate::{
@@ -31,6 +34,17 @@ pub async fn delete_entry_from_blocklist(
             delete_card_bin_blocklist_entry(state, &xbin, merchant_id).await?
         }
 
+        api_blocklist::DeleteFromBlocklistRequest::Email(email) => state
+            .store
+            .delete_blocklist_entry_by_merchant_id_fingerprint_id(
+                merchant_id,
+                &get_email_fingerprint(&email)?,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "no blocklist record for the given email was found".to_string(),
+            })?,
+
         api_blocklist::DeleteFromBlocklistRequest::Fingerprint(fingerprint_id) => state
             .store
             .delete_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, &fingerprint_id)
@@ -131,6 +145,51 @@ fn validate_extended_card_bin(bin: &str) -> RouterResult<()> {
     }
 }
 
+/// Emails are blocked by the digest of their normalized form, so that the blocklist holds no email
+/// addresses
+pub fn get_email_fingerprint(email: &str) -> RouterResult<String> {
+    let email = email.trim().to_lowercase();
+    common_utils::validation::validate_email(&email).change_context(
+        errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: "an email address".to_string(),
+        },
+    )?;
+    crypto::Sha256
+        .generate_digest(email.as_bytes())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to generate the email fingerprint")
+        .map(hex::encode)
+}
+
+/// Validates the data to be blocked, returning the fingerprint by which it is blocked along with
+/// its kind
+pub fn get_blocklist_fingerprint(
+    data: &api_blocklist::BlocklistRequest,
+) -> RouterResult<(String, common_enums::BlocklistDataKind)> {
+    match data {
+        api_blocklist::BlocklistRequest::CardBin(bin) => {
+            validate_card_bin(bin)?;
+            Ok((bin.clone(), common_enums::BlocklistDataKind::CardBin))
+        }
+        api_blocklist::BlocklistRequest::ExtendedCardBin(bin) => {
+            validate_extended_card_bin(bin)?;
+            Ok((
+                bin.clone(),
+                common_enums::BlocklistDataKind::ExtendedCardBin,
+            ))
+        }
+        api_blocklist::BlocklistRequest::Fingerprint(fingerprint_id) => Ok((
+            fingerprint_id.clone(),
+            common_enums::BlocklistDataKind::PaymentMethod,
+        )),
+        api_blocklist::BlocklistRequest::Email(email) => Ok((
+            get_email_fingerprint(email)?,
+            common_enums::BlocklistDataKind::Email,
+        )),
+    }
+}
+
 pub async fn insert_entry_into_blocklist(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -159,6 +218,16 @@ pub async fn insert_entry_into_blocklist(
             .await?
         }
 
+        api_blocklist::AddToBlocklistRequest::Email(email) => {
+            duplicate_check_insert_bin(
+                &get_email_fingerprint(email)?,
+                state,
+                merchant_id,
+                common_enums::BlocklistDataKind::Email,
+            )
+            .await?
+        }
+
         api_blocklist::AddToBlocklistRequest::Fingerprint(fingerprint_id) => {
             let blocklist_entry_result = state
                 .store
@@ -247,8 +316,12 @@ async fn duplicate_check_insert_bin(
 
     match blocklist_entry_result {
         Ok(_) => {
+            let message = match data_kind {
+                common_enums::BlocklistDataKind::Email => "provided email is already blocked",
+                _ => "provided bin is already blocked",
+            };
             return Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "provided bin is already blocked".to_string(),
+                message: message.to_string(),
             }
             .into());
         }
@@ -348,6 +421,13 @@ where
                 _ => None,
             });
 
+    // Hashed email to check whether or not this payment should be blocked.
+    let email_fingerprint = payment_data.email.as_ref().and_then(|email| {
+        get_email_fingerprint(email.peek())
+            .map_err(|error| logger::error!(?error))
+            .ok()
+    });
+
     //validating the payment method.
     let mut blocklist_futures = Vec::new();
     if let Some(card_number_fingerprint) = card_number_fingerprint.as_ref() {
@@ -373,6 +453,12 @@ where
         ));
     }
 
+    if let Some(email_fingerprint) = email_fingerprint.as_ref() {
+        blocklist_futures.push(
+            db.find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, email_fingerprint),
+        );
+    }
+
     let blocklist_lookups = futures::future::join_all(blocklist_futures).await;
 
     let mut should_payment_be_blocked = false;
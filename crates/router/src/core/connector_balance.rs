@@ -0,0 +1,251 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use api_models::connector_balance as balance_types;
+use common_utils::{date_time, ext_traits::ValueExt, id_type};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        payments::{self, helpers},
+    },
+    routes::SessionState,
+    services::{self, ApplicationResponse},
+    types::{self, api, domain, PaymentAddress},
+};
+
+const CONNECTOR_BALANCE_PREFIX: &str = "CONNECTOR_BALANCE";
+
+/// Time for which the balance retrieved from a connector is served from the cache, in seconds
+const CONNECTOR_BALANCE_CACHE_TTL_IN_SECS: i64 = 60;
+
+const IRRELEVANT_ATTEMPT_ID_IN_ACCOUNT_BALANCE_FLOW: &str =
+    "irrelevant_attempt_id_in_account_balance_flow";
+
+const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_ACCOUNT_BALANCE_FLOW: &str =
+    "irrelevant_connector_request_reference_id_in_account_balance_flow";
+
+const BALANCE_SUPPORTED_CONNECTORS: [api_models::enums::Connector; 3] = [
+    api_models::enums::Connector::Adyen,
+    api_models::enums::Connector::Rapyd,
+    api_models::enums::Connector::Stripe,
+];
+
+fn get_connector_balance_key(
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    account_id: Option<&str>,
+) -> String {
+    format!(
+        "{CONNECTOR_BALANCE_PREFIX}_{}_{}_{}",
+        merchant_id.get_string_repr(),
+        merchant_connector_id.get_string_repr(),
+        account_id.unwrap_or_default()
+    )
+}
+
+fn construct_account_balance_router_data(
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_account: domain::MerchantConnectorAccount,
+    account_id: Option<String>,
+) -> errors::RouterResult<types::AccountBalanceRouterData> {
+    let auth_type: types::ConnectorAuthType =
+        helpers::MerchantConnectorAccountType::DbVal(Box::new(merchant_connector_account.clone()))
+            .get_connector_account_details()
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_id.clone(),
+        connector: merchant_connector_account.connector_name.clone(),
+        customer_id: None,
+        payment_id: id_type::PaymentId::get_irrelevant_id("account_balance_flow")
+            .get_string_repr()
+            .to_owned(),
+        attempt_id: IRRELEVANT_ATTEMPT_ID_IN_ACCOUNT_BALANCE_FLOW.to_string(),
+        status: diesel_models::enums::AttemptStatus::default(),
+        payment_method: diesel_models::enums::PaymentMethod::default(),
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        address: PaymentAddress::default(),
+        auth_type: diesel_models::enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.metadata.clone(),
+        connector_wallets_details: None,
+        amount_captured: None,
+        minor_amount_captured: None,
+        request: types::AccountBalanceRequestData { account_id },
+        response: Err(types::ErrorResponse::default()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        connector_customer: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        connector_request_reference_id:
+            IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_ACCOUNT_BALANCE_FLOW.to_string(),
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        test_mode: merchant_connector_account.test_mode,
+        payment_method_balance: None,
+        payment_method_status: None,
+        connector_api_version: None,
+        connector_http_status_code: None,
+        connector_request_id: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        refund_id: None,
+        dispute_id: None,
+        connector_response: None,
+        integrity_check: Ok(()),
+        connector_options: None,
+        additional_merchant_data: None,
+        header_payload: None,
+        connector_mandate_request_reference_id: None,
+    })
+}
+
+/// Whether the balance of the account can be retrieved from the connector
+pub fn is_balance_supported(connector_name: &str) -> bool {
+    api_models::enums::Connector::from_str(connector_name)
+        .map(|connector| BALANCE_SUPPORTED_CONNECTORS.contains(&connector))
+        .unwrap_or(false)
+}
+
+/// Retrieves the balances of the account from the connector, returning the error of the connector
+/// if it rejects the request
+pub async fn call_connector_balance_api(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_account: domain::MerchantConnectorAccount,
+    account_id: Option<String>,
+) -> errors::RouterResult<Result<types::AccountBalanceResponseData, types::ErrorResponse>> {
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &merchant_connector_account.connector_name,
+        api::GetToken::Connector,
+        Some(merchant_connector_account.get_id()),
+    )?;
+    let connector_integration: services::BoxedAccountBalanceConnectorIntegrationInterface<
+        api::AccountBalance,
+        types::AccountBalanceRequestData,
+        types::AccountBalanceResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data =
+        construct_account_balance_router_data(merchant_id, merchant_connector_account, account_id)?;
+
+    Ok(services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .attach_printable("Failed while calling the account balance connector api")?
+    .response)
+}
+
+/// Retrieves the available and pending balances of the account at the connector, serving a
+/// recently retrieved balance from the cache
+#[instrument(skip_all)]
+pub async fn retrieve_connector_balance(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: balance_types::ConnectorBalanceRequest,
+) -> RouterResponse<balance_types::ConnectorBalanceResponse> {
+    let merchant_id = merchant_account.get_id();
+    let key = get_connector_balance_key(
+        merchant_id,
+        &request.merchant_connector_id,
+        request.account_id.as_deref(),
+    );
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+        .ok();
+
+    if let Some(redis_conn) = redis_conn.as_ref() {
+        if let Ok(balance) = redis_conn
+            .get_and_deserialize_key::<balance_types::ConnectorBalanceResponse>(
+                &key,
+                "ConnectorBalanceResponse",
+            )
+            .await
+        {
+            return Ok(ApplicationResponse::Json(balance));
+        }
+    }
+
+    let merchant_connector_account = state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &(&state).into(),
+            merchant_id,
+            &request.merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: request.merchant_connector_id.get_string_repr().to_string(),
+        })?;
+
+    let connector_name = merchant_connector_account.connector_name.clone();
+    if !is_balance_supported(&connector_name) {
+        return Err(errors::ApiErrorResponse::FlowNotSupported {
+            flow: "Account balance".to_string(),
+            connector: connector_name,
+        }
+        .into());
+    }
+
+    let response = call_connector_balance_api(
+        &state,
+        merchant_id,
+        merchant_connector_account,
+        request.account_id.clone(),
+    )
+    .await?
+    .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+        code: err.code,
+        message: err.message,
+        connector: connector_name.clone(),
+        status_code: err.status_code,
+        reason: err.reason,
+    })?;
+
+    let balance = balance_types::ConnectorBalanceResponse {
+        merchant_connector_id: request.merchant_connector_id,
+        connector_name,
+        account_id: request.account_id,
+        balances: response
+            .balances
+            .into_iter()
+            .map(|balance| balance_types::CurrencyBalance {
+                currency: balance.currency,
+                available: balance.available,
+                pending: balance.pending,
+            })
+            .collect(),
+        fetched_at: date_time::now(),
+    };
+
+    if let Some(redis_conn) = redis_conn {
+        redis_conn
+            .serialize_and_set_key_with_expiry(&key, &balance, CONNECTOR_BALANCE_CACHE_TTL_IN_SECS)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to cache the connector balance"))
+            .ok();
+    }
+
+    Ok(ApplicationResponse::Json(balance))
+}
@@ -0,0 +1,265 @@
+use std::{collections::HashSet, fmt::Display, hash::Hash};
+
+use api_models::{admin as admin_types, connector_capabilities as capabilities_types};
+use hyperswitch_interfaces::configs;
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings,
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::SessionState,
+    services::{ApplicationResponse, VoidWindow},
+    types::api,
+};
+
+/// The filters of the payment method types and card networks of the connector, the default
+/// filters applying to the connectors which have none configured, as in the routing of payments
+fn get_payment_method_filters<'a>(
+    pm_filters: &'a settings::ConnectorFilters,
+    connector: &str,
+) -> Option<&'a settings::PaymentMethodFilters> {
+    pm_filters
+        .0
+        .get(connector)
+        .or_else(|| pm_filters.0.get("default"))
+}
+
+fn to_sorted_vec<T: Copy + Display>(values: &HashSet<T>) -> Vec<T> {
+    let mut values = values.iter().copied().collect::<Vec<_>>();
+    values.sort_by_cached_key(ToString::to_string);
+    values
+}
+
+fn get_payment_method_restrictions(
+    pm_filters: &settings::ConnectorFilters,
+    connector: &str,
+) -> Vec<capabilities_types::PaymentMethodRestriction> {
+    let mut restrictions = get_payment_method_filters(pm_filters, connector)
+        .map(|payment_method_filters| {
+            payment_method_filters
+                .0
+                .iter()
+                .filter(|(_, filter)| filter.country.is_some() || filter.currency.is_some())
+                .map(|(filter_key, filter)| {
+                    let (payment_method_type, card_network) = match filter_key {
+                        settings::PaymentMethodFilterKey::PaymentMethodType(
+                            payment_method_type,
+                        ) => (Some(*payment_method_type), None),
+                        settings::PaymentMethodFilterKey::CardNetwork(card_network) => {
+                            (None, Some(card_network.clone()))
+                        }
+                    };
+                    capabilities_types::PaymentMethodRestriction {
+                        payment_method_type,
+                        card_network,
+                        supported_countries: filter.country.as_ref().map(to_sorted_vec),
+                        supported_currencies: filter.currency.as_ref().map(to_sorted_vec),
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    restrictions.sort_by_cached_key(|restriction| {
+        (
+            restriction
+                .payment_method_type
+                .as_ref()
+                .map(ToString::to_string),
+            restriction.card_network.as_ref().map(ToString::to_string),
+        )
+    });
+    restrictions
+}
+
+fn get_unsupported_values<T: Eq + Hash + Display>(
+    enabled_values: &[T],
+    supported_values: Option<&HashSet<T>>,
+) -> Option<String> {
+    let supported_values = supported_values?;
+    let unsupported_values = enabled_values
+        .iter()
+        .filter(|value| !supported_values.contains(value))
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    (!unsupported_values.is_empty()).then(|| unsupported_values.join(", "))
+}
+
+fn validate_payment_method_filter(
+    connector: &str,
+    filter_name: String,
+    filter: &settings::CurrencyCountryFlowFilter,
+    payment_method_type: &api_models::payment_methods::RequestPaymentMethodTypes,
+) -> RouterResult<()> {
+    if let Some(admin_types::AcceptedCountries::EnableOnly(countries)) =
+        &payment_method_type.accepted_countries
+    {
+        if let Some(unsupported_countries) =
+            get_unsupported_values(countries, filter.country.as_ref())
+        {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The connector `{connector}` does not support the countries {unsupported_countries} for {filter_name}, remove them from `accepted_countries`. The supported countries are listed in the capabilities of the connector"
+                ),
+            }
+            .into());
+        }
+    }
+    if let Some(admin_types::AcceptedCurrencies::EnableOnly(currencies)) =
+        &payment_method_type.accepted_currencies
+    {
+        if let Some(unsupported_currencies) =
+            get_unsupported_values(currencies, filter.currency.as_ref())
+        {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The connector `{connector}` does not support the currencies {unsupported_currencies} for {filter_name}, remove them from `accepted_currencies`. The supported currencies are listed in the capabilities of the connector"
+                ),
+            }
+            .into());
+        }
+    }
+    Ok(())
+}
+
+/// Validates the countries and currencies which the merchant connector account enables for its
+/// payment method types against the ones supported by the connector. Payments in unsupported
+/// combinations would never be routed to the connector, so they are rejected upfront.
+pub fn validate_payment_methods_enabled(
+    pm_filters: &settings::ConnectorFilters,
+    connector: &str,
+    payment_methods_enabled: Option<&[admin_types::PaymentMethodsEnabled]>,
+) -> RouterResult<()> {
+    let Some(payment_method_filters) = get_payment_method_filters(pm_filters, connector) else {
+        return Ok(());
+    };
+    let payment_method_types = payment_methods_enabled
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|payment_method| payment_method.payment_method_types.as_ref())
+        .flatten();
+
+    for payment_method_type in payment_method_types {
+        let type_filter_key = settings::PaymentMethodFilterKey::PaymentMethodType(
+            payment_method_type.payment_method_type,
+        );
+        if let Some(filter) = payment_method_filters.0.get(&type_filter_key) {
+            validate_payment_method_filter(
+                connector,
+                format!(
+                    "the payment method type `{}`",
+                    payment_method_type.payment_method_type
+                ),
+                filter,
+                payment_method_type,
+            )?;
+        }
+
+        for card_network in payment_method_type.card_networks.iter().flatten() {
+            let network_filter_key =
+                settings::PaymentMethodFilterKey::CardNetwork(card_network.clone());
+            if let Some(filter) = payment_method_filters.0.get(&network_filter_key) {
+                validate_payment_method_filter(
+                    connector,
+                    format!("the card network `{card_network}`"),
+                    filter,
+                    payment_method_type,
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn to_retry_policy_response(
+    retry_policy: Option<configs::ConnectorRetryPolicy>,
+) -> Option<capabilities_types::ConnectorRetryPolicy> {
+    retry_policy
+        .filter(|retry_policy| retry_policy.max_retries > 0)
+        .map(|retry_policy| capabilities_types::ConnectorRetryPolicy {
+            max_retries: retry_policy.max_retries,
+            base_delay_in_millis: retry_policy.base_delay_in_millis,
+            max_delay_in_millis: retry_policy.max_delay_in_millis,
+        })
+}
+
+fn to_flow_policy_response(
+    flow_policy: configs::ConnectorFlowRequestPolicy,
+) -> capabilities_types::ConnectorFlowRequestPolicy {
+    capabilities_types::ConnectorFlowRequestPolicy {
+        timeout_secs: flow_policy.timeout_secs.unwrap_or(consts::REQUEST_TIME_OUT),
+        retry: to_retry_policy_response(flow_policy.retry),
+    }
+}
+
+/// The request policy of the connector, with the flow specific policies merged with the policy of
+/// the connector so that the effective values are reported
+fn get_request_policy(
+    request_policies: &configs::ConnectorRequestPolicies,
+    connector: &str,
+) -> capabilities_types::ConnectorRequestPolicy {
+    let connector_policy = request_policies.get_connector_policy(connector);
+    let default_policy = to_flow_policy_response(
+        connector_policy
+            .map(|connector_policy| connector_policy.get_default_policy())
+            .unwrap_or_default(),
+    );
+    let flows = connector_policy
+        .map(|connector_policy| {
+            connector_policy
+                .flows
+                .keys()
+                .map(|flow| {
+                    (
+                        flow.clone(),
+                        to_flow_policy_response(connector_policy.get_flow_policy(flow)),
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    capabilities_types::ConnectorRequestPolicy {
+        timeout_secs: default_policy.timeout_secs,
+        retry: default_policy.retry,
+        flows,
+    }
+}
+
+#[instrument(skip_all, fields(connector = %request.connector))]
+pub async fn retrieve_connector_capabilities(
+    state: SessionState,
+    request: capabilities_types::ConnectorCapabilitiesRetrieveRequest,
+) -> RouterResponse<capabilities_types::ConnectorCapabilitiesResponse> {
+    let connector_name = request.connector.to_string();
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name,
+        api::GetToken::Connector,
+        None,
+    )?;
+    let void_window = connector
+        .connector
+        .get_void_window()
+        .map(|void_window| match void_window {
+            VoidWindow::SameDay => capabilities_types::ConnectorVoidWindow::SameDay,
+            VoidWindow::Duration(duration) => capabilities_types::ConnectorVoidWindow::Duration {
+                duration_in_secs: duration.whole_seconds(),
+            },
+        });
+
+    Ok(ApplicationResponse::Json(
+        capabilities_types::ConnectorCapabilitiesResponse {
+            connector: request.connector,
+            void_window,
+            request_policy: get_request_policy(
+                &state.conf.connectors.request_policies,
+                &connector_name,
+            ),
+            payment_method_restrictions: get_payment_method_restrictions(
+                &state.conf.pm_filters,
+                &connector_name,
+            ),
+        },
+    ))
+}
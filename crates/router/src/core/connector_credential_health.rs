@@ -0,0 +1,296 @@
+use api_models::{admin as admin_api, webhooks};
+use common_utils::{date_time, id_type};
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        connector_balance,
+        errors::{self, RouterResult},
+        payments::connector_health,
+        webhooks as webhooks_core,
+    },
+    routes::SessionState,
+    types::{domain, storage},
+};
+
+const CONNECTOR_CREDENTIAL_HEALTH_NAME: &str = "CONNECTOR_CREDENTIAL_HEALTH";
+const CONNECTOR_CREDENTIAL_HEALTH_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::ConnectorCredentialHealthWorkflow;
+const CONNECTOR_CREDENTIAL_HEALTH_TAG: &str = "CONNECTOR_CREDENTIAL_HEALTH";
+
+/// Tracking data of the task periodically verifying the credentials of a merchant connector
+/// account
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorCredentialHealthTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+}
+
+/// Outcome of a single verification of the credentials of a connector account
+enum CredentialCheckOutcome {
+    Valid,
+    Invalid { error_message: String },
+}
+
+/// Schedules the periodic verification of the credentials of the merchant connector account, if
+/// it is not scheduled already. The verification is scheduled even while the health check is
+/// disabled, so that the connector accounts are verified once it is enabled.
+#[instrument(skip_all)]
+pub async fn schedule_credential_health_check(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<()> {
+    let process_tracker_id = format!(
+        "{CONNECTOR_CREDENTIAL_HEALTH_RUNNER}_{CONNECTOR_CREDENTIAL_HEALTH_NAME}_{}",
+        merchant_connector_id.get_string_repr()
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        CONNECTOR_CREDENTIAL_HEALTH_NAME,
+        CONNECTOR_CREDENTIAL_HEALTH_RUNNER,
+        [CONNECTOR_CREDENTIAL_HEALTH_TAG],
+        ConnectorCredentialHealthTrackingData {
+            merchant_id: merchant_id.clone(),
+            merchant_connector_id: merchant_connector_id.clone(),
+        },
+        get_next_check_time(state),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the connector credential health task")?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        // The task of the connector account is already scheduled
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to schedule the connector credential health task")),
+    }
+}
+
+/// The time at which the credentials are to be verified next
+pub fn get_next_check_time(state: &SessionState) -> time::PrimitiveDateTime {
+    let config = &state.conf.connector_credential_health;
+    date_time::now() + time::Duration::seconds(config.check_interval_in_secs)
+}
+
+/// Verifies the credentials of the merchant connector account, excluding the connector account
+/// from routing while its credentials are rejected by the connector. The credentials are verified
+/// by retrieving the balance of the account, which doesn't create any transaction on the
+/// connector. The merchant is notified whenever the credentials become invalid or valid again.
+/// Returns whether the credentials are to be verified again, which is not the case once the
+/// connector account no longer exists or when the balance cannot be retrieved from the connector.
+#[instrument(skip_all)]
+pub async fn perform_credential_health_check(
+    state: &SessionState,
+    tracking_data: &ConnectorCredentialHealthTrackingData,
+) -> RouterResult<bool> {
+    let config = &state.conf.connector_credential_health;
+    if !config.enabled {
+        return Ok(true);
+    }
+
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = match db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+    {
+        Ok(key_store) => key_store,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(false),
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the merchant key store"))
+        }
+    };
+
+    let merchant_connector_account = match db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &tracking_data.merchant_connector_id,
+            &key_store,
+        )
+        .await
+    {
+        Ok(merchant_connector_account) => merchant_connector_account,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(false),
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the merchant connector account"))
+        }
+    };
+
+    // Disabled connector accounts are not used for routing, their credentials are verified again
+    // once they are enabled
+    if merchant_connector_account.disabled.unwrap_or(false) {
+        return Ok(true);
+    }
+
+    if !connector_balance::is_balance_supported(&merchant_connector_account.connector_name) {
+        logger::info!(
+            connector = %merchant_connector_account.connector_name,
+            "Connector does not support the verification of credentials"
+        );
+        return Ok(false);
+    }
+
+    let outcome = match connector_balance::call_connector_balance_api(
+        state,
+        &tracking_data.merchant_id,
+        merchant_connector_account.clone(),
+        None,
+    )
+    .await?
+    {
+        Ok(_) => CredentialCheckOutcome::Valid,
+        // Only an authentication failure means that the credentials are invalid, other errors
+        // don't change the health of the credentials
+        Err(error_response) if matches!(error_response.status_code, 401 | 403) => {
+            CredentialCheckOutcome::Invalid {
+                error_message: error_response.reason.unwrap_or(error_response.message),
+            }
+        }
+        Err(error_response) => {
+            return Err(errors::ApiErrorResponse::InternalServerError.into()).attach_printable(
+                format!(
+                    "Failed to verify the connector credentials, connector responded with status code {}",
+                    error_response.status_code
+                ),
+            );
+        }
+    };
+
+    let merchant_connector_id = merchant_connector_account.get_id();
+    let was_unhealthy =
+        connector_health::is_connector_unhealthy(state, merchant_connector_id.get_string_repr())
+            .await;
+
+    let (status, error_message, event_type) = match outcome {
+        CredentialCheckOutcome::Valid => {
+            if !was_unhealthy {
+                return Ok(true);
+            }
+            connector_health::mark_connector_healthy(state, &merchant_connector_id).await;
+            (
+                admin_api::ConnectorCredentialStatus::Valid,
+                None,
+                storage_enums::EventType::ConnectorCredentialsRestored,
+            )
+        }
+        CredentialCheckOutcome::Invalid { error_message } => {
+            // The mark outlives the interval between two verifications, so that the connector
+            // account stays excluded until its credentials are verified again
+            connector_health::mark_connector_unhealthy(
+                state,
+                &merchant_connector_id,
+                &error_message,
+                config.check_interval_in_secs * 2,
+            )
+            .await;
+            if was_unhealthy {
+                return Ok(true);
+            }
+            (
+                admin_api::ConnectorCredentialStatus::Invalid,
+                Some(error_message),
+                storage_enums::EventType::ConnectorCredentialsInvalid,
+            )
+        }
+    };
+
+    let checked_at = date_time::now();
+    trigger_credential_health_webhook(
+        state,
+        &tracking_data.merchant_id,
+        &key_store,
+        event_type,
+        admin_api::ConnectorCredentialHealthResponse {
+            merchant_connector_id,
+            connector_name: merchant_connector_account.connector_name.clone(),
+            profile_id: merchant_connector_account.profile_id.clone(),
+            status,
+            error_message,
+            checked_at,
+        },
+    )
+    .await;
+
+    Ok(true)
+}
+
+async fn trigger_credential_health_webhook(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    event_type: storage_enums::EventType,
+    credential_health_response: admin_api::ConnectorCredentialHealthResponse,
+) {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let result = async {
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, key_store)
+            .await
+            .change_context(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+        let business_profile = db
+            .find_business_profile_by_profile_id(
+                key_manager_state,
+                key_store,
+                &credential_health_response.profile_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::ProfileNotFound {
+                id: credential_health_response
+                    .profile_id
+                    .get_string_repr()
+                    .to_owned(),
+            })?;
+
+        // Every change in the health of the credentials is a separate event
+        let primary_object_id = format!(
+            "{}_{}",
+            credential_health_response
+                .merchant_connector_id
+                .get_string_repr(),
+            credential_health_response
+                .checked_at
+                .assume_utc()
+                .unix_timestamp()
+        );
+
+        Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_account,
+            business_profile,
+            key_store,
+            event_type,
+            storage_enums::EventClass::MerchantConnectors,
+            primary_object_id,
+            storage_enums::EventObjectType::ConnectorCredentialHealthDetails,
+            webhooks::OutgoingWebhookContent::ConnectorCredentialHealthDetails(Box::new(
+                credential_health_response,
+            )),
+            None,
+        ))
+        .await
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            %event_type,
+            "Failed to trigger the connector credential health webhook"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use api_models::connector_debug_capture as debug_capture_types;
+use common_utils::{ext_traits::StringExt, id_type};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, CustomResult, RouterResponse, RouterResult},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::ConnectorDebugCaptureConfig,
+};
+
+const CONNECTOR_DEBUG_CAPTURE_PREFIX: &str = "CONNECTOR_DEBUG_CAPTURE";
+/// The maximum number of failed connector calls retained per payment, the oldest calls are
+/// discarded first
+const MAX_CONNECTOR_DEBUG_CAPTURES_PER_PAYMENT: i64 = 20;
+
+fn get_connector_debug_capture_key(merchant_id: &id_type::MerchantId, payment_id: &str) -> String {
+    format!(
+        "{CONNECTOR_DEBUG_CAPTURE_PREFIX}_{}_{payment_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+#[cfg(feature = "v1")]
+async fn find_profile_id(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    attempt_id: &str,
+) -> Option<id_type::ProfileId> {
+    let key_manager_state = &state.into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to find the merchant key store"))
+        .ok()?;
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to find the merchant account"))
+        .ok()?;
+
+    state
+        .store
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            attempt_id,
+            merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to find the payment attempt"))
+        .ok()
+        .map(|payment_attempt| payment_attempt.profile_id)
+}
+
+// Connector calls are not captured for v2 payments yet
+#[cfg(not(feature = "v1"))]
+async fn find_profile_id(
+    _state: &SessionState,
+    _merchant_id: &id_type::MerchantId,
+    _attempt_id: &str,
+) -> Option<id_type::ProfileId> {
+    None
+}
+
+async fn find_connector_debug_capture_config(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> Option<ConnectorDebugCaptureConfig> {
+    state
+        .store
+        .find_config_by_key(&ConnectorDebugCaptureConfig::get_config_key(profile_id))
+        .await
+        .ok()?
+        .config
+        .parse_struct("ConnectorDebugCaptureConfig")
+        .map_err(|error| {
+            logger::error!(?error, "Failed to parse the connector debug capture config")
+        })
+        .ok()
+}
+
+async fn append_connector_call(
+    state: &SessionState,
+    key: &str,
+    config: &ConnectorDebugCaptureConfig,
+    connector_call: serde_json::Value,
+) -> CustomResult<(), redis_interface::errors::RedisError> {
+    let redis_conn = state.store.get_redis_conn()?;
+
+    redis_conn
+        .append_elements_to_list(key, connector_call.to_string())
+        .await?;
+    redis_conn
+        .trim_list(key, -MAX_CONNECTOR_DEBUG_CAPTURES_PER_PAYMENT, -1)
+        .await?;
+    redis_conn
+        .set_expiry(key, i64::from(config.retention_in_days) * 24 * 60 * 60)
+        .await
+}
+
+/// Retains the redacted request and response of a failed connector call, if the connector debug
+/// capture is enabled for the profile of the payment. Only the most recent calls of each payment
+/// are retained.
+#[instrument(skip_all)]
+pub async fn capture_failed_connector_call(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    payment_id: String,
+    attempt_id: String,
+    connector_call: serde_json::Value,
+) {
+    let Some(profile_id) = find_profile_id(&state, &merchant_id, &attempt_id).await else {
+        return;
+    };
+    let Some(config) = find_connector_debug_capture_config(&state, &profile_id).await else {
+        return;
+    };
+
+    let key = get_connector_debug_capture_key(&merchant_id, &payment_id);
+    let result = append_connector_call(&state, &key, &config, connector_call).await;
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to capture the failed connector call");
+    }
+}
+
+/// Fetches the failed connector calls captured for the payment, the earliest call first
+pub async fn find_connector_debug_captures(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<Vec<serde_json::Value>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    Ok(redis_conn
+        .get_list_elements(
+            &get_connector_debug_capture_key(merchant_id, payment_id.get_string_repr()),
+            0,
+            -1,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the captured connector calls")?
+        .into_iter()
+        .filter_map(|connector_call| {
+            serde_json::from_str(&connector_call)
+                .map_err(|error| logger::error!(?error, "Failed to parse the connector call"))
+                .ok()
+        })
+        .collect())
+}
+
+#[instrument(skip_all)]
+pub async fn list_connector_debug_captures(
+    state: SessionState,
+    request: debug_capture_types::ConnectorDebugCaptureListRequest,
+) -> RouterResponse<debug_capture_types::ConnectorDebugCaptureListResponse> {
+    let data =
+        find_connector_debug_captures(&state, &request.merchant_id, &request.payment_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        debug_capture_types::ConnectorDebugCaptureListResponse {
+            payment_id: request.payment_id,
+            data,
+        },
+    ))
+}
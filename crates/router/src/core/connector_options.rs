@@ -0,0 +1,223 @@
+use api_models::connector_options as options_types;
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+/// Options of a merchant connector account, stored in the configs table
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct ConnectorOptionsConfig {
+    connector_options: options_types::ConnectorOptions,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    modified_at: time::PrimitiveDateTime,
+}
+
+impl ConnectorOptionsConfig {
+    fn get_config_key(merchant_connector_id: &id_type::MerchantConnectorAccountId) -> String {
+        format!(
+            "connector_options_{}",
+            merchant_connector_id.get_string_repr()
+        )
+    }
+
+    fn to_response(
+        self,
+        merchant_id: id_type::MerchantId,
+        merchant_connector_id: id_type::MerchantConnectorAccountId,
+    ) -> options_types::ConnectorOptionsResponse {
+        options_types::ConnectorOptionsResponse {
+            merchant_id,
+            merchant_connector_id,
+            connector_options: self.connector_options,
+            modified_at: self.modified_at,
+        }
+    }
+}
+
+async fn find_connector_options_config(
+    db: &dyn StorageInterface,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<Option<ConnectorOptionsConfig>> {
+    // Most connector accounts have no options, the absence of options is cached as well so that
+    // constructing the connector requests doesn't hit the database every time
+    db.find_config_by_key_unwrap_or(
+        &ConnectorOptionsConfig::get_config_key(merchant_connector_id),
+        Some("null".to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the connector options")?
+    .config
+    .parse_struct("ConnectorOptionsConfig")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse the connector options")
+}
+
+/// Fetches the options of a merchant connector account, to be passed on to the connector
+pub async fn find_connector_options(
+    db: &dyn StorageInterface,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<Option<options_types::ConnectorOptions>> {
+    Ok(find_connector_options_config(db, merchant_connector_id)
+        .await?
+        .map(|config| config.connector_options))
+}
+
+async fn find_merchant_connector_account(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    let store = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_connector_account = store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            merchant_id,
+            merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &merchant_connector_account)?;
+
+    Ok(merchant_connector_account)
+}
+
+#[instrument(skip_all)]
+pub async fn update_connector_options(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+    request: options_types::ConnectorOptions,
+) -> RouterResponse<options_types::ConnectorOptionsResponse> {
+    let merchant_connector_account =
+        find_merchant_connector_account(&state, &merchant_id, profile_id, &merchant_connector_id)
+            .await?;
+
+    let connector = request.get_connector();
+    if merchant_connector_account.connector_name != connector.to_string() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Options for {connector} can't be set on a {} connector account",
+                merchant_connector_account.connector_name
+            ),
+        }));
+    }
+    request.validate().map_err(|message| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid connector options, {message}"),
+        })
+    })?;
+
+    let db = state.store.as_ref();
+    let connector_options = ConnectorOptionsConfig {
+        connector_options: request,
+        modified_at: date_time::now(),
+    };
+    let key = ConnectorOptionsConfig::get_config_key(&merchant_connector_id);
+    let config = connector_options
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the connector options")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the connector options")?;
+
+    logger::info!(
+        merchant_connector_id = %merchant_connector_id.get_string_repr(),
+        "Connector options updated"
+    );
+
+    Ok(ApplicationResponse::Json(
+        connector_options.to_response(merchant_id, merchant_connector_id),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_connector_options(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<options_types::ConnectorOptionsResponse> {
+    find_merchant_connector_account(&state, &merchant_id, profile_id, &merchant_connector_id)
+        .await?;
+
+    let connector_options =
+        find_connector_options_config(state.store.as_ref(), &merchant_connector_id)
+            .await?
+            .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "No options set for the connector account".to_string(),
+            })?;
+
+    Ok(ApplicationResponse::Json(
+        connector_options.to_response(merchant_id, merchant_connector_id),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_connector_options(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<()> {
+    find_merchant_connector_account(&state, &merchant_id, profile_id, &merchant_connector_id)
+        .await?;
+
+    state
+        .store
+        .delete_config_by_key(&ConnectorOptionsConfig::get_config_key(
+            &merchant_connector_id,
+        ))
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No options set for the connector account".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
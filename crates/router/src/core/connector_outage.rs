@@ -0,0 +1,403 @@
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+
+use api_models::{connector_outage as outage_types, enums};
+use common_utils::ext_traits::Encode;
+use error_stack::{report, ResultExt};
+use once_cell::sync::Lazy;
+use redis_interface::{errors::RedisError, RedisConnectionPool, SetnxReply};
+use router_env::{instrument, logger, metrics::add_attributes, tracing, Instrument};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments::connector_health,
+    },
+    routes::{metrics, SessionState},
+    services::ApplicationResponse,
+};
+
+/// The outcomes of the calls made to each connector and host in the current window. The outcomes
+/// are aggregated on each instance of the application, so that recording them does not require a
+/// call to redis; only the outages detected are shared with the other instances.
+static CONNECTOR_CALL_STATS: Lazy<Mutex<HashMap<(String, String), ConnectorCallStats>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// The outcome of a call made to a connector, as relevant for detecting outages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectorCallOutcome {
+    /// The connector responded, including with client errors
+    Responded,
+    /// The connector responded with a server error, or could not be reached
+    Failed,
+    /// The call to the connector timed out
+    TimedOut,
+}
+
+#[derive(Debug, Default)]
+struct ConnectorCallStats {
+    window: i64,
+    total: usize,
+    failed: usize,
+    timed_out: usize,
+    is_outage_flagged: bool,
+}
+
+/// Records the outcome of a call made to the connector at the host, and flags an outage of the
+/// connector at the host if the error rate or the number of timeouts in the current window exceed
+/// the configured thresholds
+pub fn record_connector_call_outcome(
+    state: &SessionState,
+    connector: &str,
+    region: &str,
+    outcome: ConnectorCallOutcome,
+) {
+    let config = &state.conf.connector_outage_detection;
+    let window_in_secs = config.window_in_secs.max(1);
+    let window = common_utils::date_time::now_unix_timestamp() / window_in_secs;
+
+    let reason = {
+        let mut call_stats = CONNECTOR_CALL_STATS
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let stats = call_stats
+            .entry((connector.to_string(), region.to_string()))
+            .or_default();
+        if stats.window != window {
+            *stats = ConnectorCallStats {
+                window,
+                ..Default::default()
+            };
+        }
+
+        stats.total += 1;
+        match outcome {
+            ConnectorCallOutcome::Responded => {}
+            ConnectorCallOutcome::Failed => stats.failed += 1,
+            ConnectorCallOutcome::TimedOut => {
+                stats.failed += 1;
+                stats.timed_out += 1;
+            }
+        }
+
+        // The outage is flagged at most once per window, so that redis is only called when the
+        // thresholds are first exceeded
+        let reason = if outcome == ConnectorCallOutcome::Responded || stats.is_outage_flagged {
+            None
+        } else if stats.timed_out >= config.timeout_threshold {
+            Some(format!(
+                "{} calls to the connector timed out within {window_in_secs} seconds",
+                stats.timed_out
+            ))
+        } else if stats.total >= config.minimum_calls
+            && stats.failed.saturating_mul(100)
+                >= stats
+                    .total
+                    .saturating_mul(usize::from(config.error_rate_threshold_percentage))
+        {
+            Some(format!(
+                "{} of {} calls to the connector failed within {window_in_secs} seconds",
+                stats.failed, stats.total
+            ))
+        } else {
+            None
+        };
+        stats.is_outage_flagged |= reason.is_some();
+        reason
+    };
+
+    if let Some(reason) = reason {
+        tokio::spawn(
+            flag_connector_outage(
+                state.clone(),
+                connector.to_string(),
+                region.to_string(),
+                reason,
+            )
+            .in_current_span(),
+        );
+    }
+}
+
+#[instrument(skip_all, fields(connector = %connector, region = %region))]
+async fn flag_connector_outage(
+    state: SessionState,
+    connector: String,
+    region: String,
+    reason: String,
+) {
+    if let Err(error) = mark_connector_degraded(&state, &connector, &region, reason).await {
+        logger::error!(?error, "Failed to flag the connector outage");
+    }
+}
+
+async fn is_outage_detection_suppressed(
+    redis_conn: &RedisConnectionPool,
+    connector: &str,
+    region: Option<&str>,
+) -> RouterResult<bool> {
+    let mut suppression_keys = vec![connector_health::get_connector_outage_suppression_key(
+        connector, None,
+    )];
+    suppression_keys.extend(region.map(|region| {
+        connector_health::get_connector_outage_suppression_key(connector, Some(region))
+    }));
+
+    for suppression_key in suppression_keys {
+        if redis_conn
+            .exists::<()>(&suppression_key)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to check the connector outage suppression")?
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+async fn mark_connector_degraded(
+    state: &SessionState,
+    connector: &str,
+    region: &str,
+    reason: String,
+) -> RouterResult<()> {
+    let config = &state.conf.connector_outage_detection;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    if is_outage_detection_suppressed(&redis_conn, connector, Some(region)).await? {
+        logger::info!(reason, "Connector outage detection is suppressed, skipping");
+        return Ok(());
+    }
+
+    let outage = outage_types::ConnectorOutage {
+        source: outage_types::ConnectorOutageSource::Automatic,
+        region: Some(region.to_string()),
+        reason,
+        flagged_at: common_utils::date_time::now(),
+    };
+    let encoded_outage = outage
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the connector outage")?;
+
+    // The outage is only flagged if the connector is not already degraded at the host, so that a
+    // single alert is raised across the instances for an outage
+    let reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &connector_health::get_connector_outage_key(connector, Some(region)),
+            encoded_outage,
+            Some(config.degraded_duration_in_secs),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to mark the connector as degraded")?;
+
+    if reply == SetnxReply::KeySet {
+        metrics::CONNECTOR_OUTAGE_DETECTED.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([
+                ("connector", connector.to_string()),
+                ("region", region.to_string()),
+            ]),
+        );
+        logger::error!(
+            connector,
+            region,
+            reason = outage.reason,
+            degraded_duration_in_secs = config.degraded_duration_in_secs,
+            "Probable connector outage detected, marked the connector as degraded"
+        );
+    }
+
+    Ok(())
+}
+
+async fn get_connector_outage(
+    redis_conn: &RedisConnectionPool,
+    connector: &str,
+    region: Option<&str>,
+) -> RouterResult<Option<outage_types::ConnectorOutage>> {
+    match redis_conn
+        .get_and_deserialize_key::<outage_types::ConnectorOutage>(
+            &connector_health::get_connector_outage_key(connector, region),
+            "ConnectorOutage",
+        )
+        .await
+    {
+        Ok(outage) => Ok(Some(outage)),
+        Err(error) if error.current_context() == &RedisError::NotFound => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the connector outage")),
+    }
+}
+
+async fn get_connector_outage_status(
+    state: &SessionState,
+    connector: enums::Connector,
+    region: Option<String>,
+) -> RouterResult<outage_types::ConnectorOutageResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let connector_name = connector.to_string();
+
+    let outage = match get_connector_outage(&redis_conn, &connector_name, None).await? {
+        Some(outage) => Some(outage),
+        None => match region.as_deref() {
+            Some(region) => {
+                get_connector_outage(&redis_conn, &connector_name, Some(region)).await?
+            }
+            None => None,
+        },
+    };
+    let detection_suppressed =
+        is_outage_detection_suppressed(&redis_conn, &connector_name, region.as_deref()).await?;
+
+    Ok(outage_types::ConnectorOutageResponse {
+        connector,
+        region,
+        status: if outage.is_some() {
+            outage_types::ConnectorOutageStatus::Degraded
+        } else {
+            outage_types::ConnectorOutageStatus::Healthy
+        },
+        outage,
+        detection_suppressed,
+    })
+}
+
+pub async fn retrieve_connector_outage(
+    state: SessionState,
+    request: outage_types::ConnectorOutageRetrieveRequest,
+) -> RouterResponse<outage_types::ConnectorOutageResponse> {
+    let region = request
+        .region
+        .or_else(|| connector_health::get_connector_region(&state, &request.connector.to_string()));
+    get_connector_outage_status(&state, request.connector, region)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Overrides the status of the connector at the host, or across all its hosts. Marking the
+/// connector as healthy clears any outage and suppresses the outage detection for the connector
+/// for the duration.
+pub async fn update_connector_outage(
+    state: SessionState,
+    wrapper: outage_types::ConnectorOutageUpdateWrapper,
+) -> RouterResponse<outage_types::ConnectorOutageResponse> {
+    let outage_types::ConnectorOutageUpdateWrapper { connector, request } = wrapper;
+    let duration_in_secs = request.duration_in_secs.unwrap_or(
+        state
+            .conf
+            .connector_outage_detection
+            .degraded_duration_in_secs,
+    );
+
+    if duration_in_secs <= 0 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`duration_in_secs` must be greater than 0".to_string(),
+        }));
+    }
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let connector_name = connector.to_string();
+    let region = request.region.as_deref();
+
+    match request.status {
+        outage_types::ConnectorOutageStatus::Degraded => {
+            let outage = outage_types::ConnectorOutage {
+                source: outage_types::ConnectorOutageSource::Manual,
+                region: request.region.clone(),
+                reason: request
+                    .reason
+                    .clone()
+                    .unwrap_or_else(|| "Marked as degraded manually".to_string()),
+                flagged_at: common_utils::date_time::now(),
+            };
+            redis_conn
+                .serialize_and_set_key_with_expiry(
+                    &connector_health::get_connector_outage_key(&connector_name, region),
+                    outage,
+                    duration_in_secs,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to mark the connector as degraded")?;
+            redis_conn
+                .delete_key(&connector_health::get_connector_outage_suppression_key(
+                    &connector_name,
+                    region,
+                ))
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to clear the connector outage suppression")?;
+        }
+        outage_types::ConnectorOutageStatus::Healthy => {
+            // Marking the connector as healthy across all its hosts also clears the outage
+            // detected at the host its calls are routed to
+            let mut outage_keys = vec![connector_health::get_connector_outage_key(
+                &connector_name,
+                region,
+            )];
+            if region.is_none() {
+                outage_keys.extend(
+                    connector_health::get_connector_region(&state, &connector_name).map(|region| {
+                        connector_health::get_connector_outage_key(&connector_name, Some(&region))
+                    }),
+                );
+            }
+            for outage_key in outage_keys {
+                redis_conn
+                    .delete_key(&outage_key)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to clear the connector outage")?;
+            }
+            redis_conn
+                .set_key_with_expiry(
+                    &connector_health::get_connector_outage_suppression_key(
+                        &connector_name,
+                        region,
+                    ),
+                    request
+                        .reason
+                        .clone()
+                        .unwrap_or_else(|| "Marked as healthy manually".to_string()),
+                    duration_in_secs,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to suppress the connector outage detection")?;
+        }
+    }
+
+    logger::info!(
+        connector = connector_name,
+        region,
+        status = ?request.status,
+        duration_in_secs,
+        "Overrode the connector outage status"
+    );
+
+    let region = request
+        .region
+        .or_else(|| connector_health::get_connector_region(&state, &connector_name));
+    get_connector_outage_status(&state, connector, region)
+        .await
+        .map(ApplicationResponse::Json)
+}
@@ -0,0 +1,348 @@
+use std::future::Future;
+
+use actix_web::web::Bytes;
+use api_models::data_export::{
+    DataExportCheckpoint, DataExportLine, PaymentExportRecord, PaymentsExportRequest,
+    RefundExportRecord, RefundsExportRequest,
+};
+use base64::Engine;
+use common_utils::{consts, date_time, id_type, types::TimeRange};
+use diesel_models::query::data_export::{ExportConstraints, ExportCursor};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, CustomResult, RouterResponse, RouterResult},
+    routes::SessionState,
+    services::{ApplicationResponse, FileStream},
+    types::{domain, storage, storage::enums},
+};
+
+const DATA_EXPORT_REQUESTS_PREFIX: &str = "DATA_EXPORT_REQUESTS";
+const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Limits the number of export requests of the merchant in a minute. Failures in tracking the
+/// requests never fail the export.
+async fn check_rate_limit(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection for rate limiting");
+            return Ok(());
+        }
+    };
+    let key = format!(
+        "{}_{}_{}",
+        DATA_EXPORT_REQUESTS_PREFIX,
+        merchant_id.get_string_repr(),
+        date_time::now_unix_timestamp() / 60
+    );
+
+    match redis_conn.increment_key_with_expiry(&key, 60).await {
+        Ok(requests) if requests > i64::from(state.conf.data_export.requests_per_minute) => Err(
+            report!(errors::ApiErrorResponse::DataExportRateLimitExceeded),
+        ),
+        Ok(_) => Ok(()),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to track the export requests of the merchant"
+            );
+            Ok(())
+        }
+    }
+}
+
+fn encode_cursor(cursor: &ExportCursor) -> String {
+    consts::BASE64_ENGINE_URL_SAFE.encode(format!(
+        "{}|{}",
+        cursor.modified_at.assume_utc().unix_timestamp_nanos(),
+        cursor.id
+    ))
+}
+
+fn decode_cursor(cursor: &str) -> RouterResult<ExportCursor> {
+    let invalid_cursor = || errors::ApiErrorResponse::InvalidRequestData {
+        message: "Invalid export cursor".to_string(),
+    };
+    let decoded = consts::BASE64_ENGINE_URL_SAFE
+        .decode(cursor)
+        .ok()
+        .and_then(|decoded| String::from_utf8(decoded).ok())
+        .ok_or_else(invalid_cursor)?;
+    let (modified_at, id) = decoded.split_once('|').ok_or_else(invalid_cursor)?;
+    let modified_at = modified_at
+        .parse::<i128>()
+        .ok()
+        .and_then(|modified_at| time::OffsetDateTime::from_unix_timestamp_nanos(modified_at).ok())
+        .ok_or_else(invalid_cursor)?;
+
+    Ok(ExportCursor {
+        modified_at: time::PrimitiveDateTime::new(modified_at.date(), modified_at.time()),
+        id: id.to_string(),
+    })
+}
+
+fn get_export_constraints(
+    profile_id_list: Option<Vec<id_type::ProfileId>>,
+    time_range: TimeRange,
+    currency: Option<Vec<enums::Currency>>,
+    cursor: Option<&str>,
+) -> RouterResult<ExportConstraints> {
+    Ok(ExportConstraints {
+        profile_ids: profile_id_list,
+        start_time: time_range.start_time,
+        end_time: time_range.end_time,
+        currency,
+        cursor: cursor.map(decode_cursor).transpose()?,
+        limit: 0,
+    })
+}
+
+fn write_export_line(body: &mut Vec<u8>, line: &DataExportLine) -> Result<(), std::io::Error> {
+    serde_json::to_writer(&mut *body, line)?;
+    body.push(b'\n');
+    Ok(())
+}
+
+/// Progress of an export, from one batch of records to the next
+struct ExportProgress {
+    constraints: ExportConstraints,
+    batch_size: u32,
+    max_records: u32,
+    exported_count: u32,
+    has_more: bool,
+    is_finished: bool,
+}
+
+impl ExportProgress {
+    /// Fetches the next batch of records and renders it as export lines. Once `max_records`
+    /// records are exported or no records are left, the checkpoint holding the cursor of the last
+    /// exported record is rendered and the export is finished.
+    async fn next_chunk<T, F, Fut>(
+        &mut self,
+        fetch_batch: &F,
+        to_export_line: fn(T) -> (ExportCursor, DataExportLine),
+    ) -> Result<Bytes, std::io::Error>
+    where
+        F: Fn(ExportConstraints) -> Fut,
+        Fut: Future<Output = CustomResult<Vec<T>, errors::StorageError>>,
+    {
+        let mut chunk = Vec::new();
+
+        if !self.has_more || self.exported_count >= self.max_records {
+            self.is_finished = true;
+            write_export_line(
+                &mut chunk,
+                &DataExportLine::Checkpoint(DataExportCheckpoint {
+                    cursor: self.constraints.cursor.as_ref().map(encode_cursor),
+                    exported_count: usize::try_from(self.exported_count).unwrap_or(usize::MAX),
+                    has_more: self.has_more,
+                }),
+            )?;
+            return Ok(Bytes::from(chunk));
+        }
+
+        let requested = self
+            .batch_size
+            .min(self.max_records.saturating_sub(self.exported_count));
+        self.constraints.limit = i64::from(requested);
+        let batch = fetch_batch(self.constraints.clone())
+            .await
+            .map_err(|error| {
+                // The response has already started, the export ends without a checkpoint
+                logger::error!(?error, "Failed to fetch the records to be exported");
+                self.is_finished = true;
+                std::io::Error::other("Failed to fetch the records to be exported")
+            })?;
+        let batch_len = u32::try_from(batch.len()).unwrap_or(u32::MAX);
+
+        for record in batch {
+            let (cursor, line) = to_export_line(record);
+            write_export_line(&mut chunk, &line)?;
+            self.constraints.cursor = Some(cursor);
+        }
+        self.exported_count = self.exported_count.saturating_add(batch_len);
+        self.has_more = batch_len >= requested;
+
+        Ok(Bytes::from(chunk))
+    }
+}
+
+/// Exports up to `max_records` records after the cursor of the constraints, streaming them in
+/// batches as they are fetched. The export ends with a checkpoint holding the cursor of the last
+/// exported record.
+fn export_in_batches<T, F, Fut>(
+    state: &SessionState,
+    constraints: ExportConstraints,
+    max_records: u32,
+    fetch_batch: F,
+    to_export_line: fn(T) -> (ExportCursor, DataExportLine),
+) -> RouterResponse<serde_json::Value>
+where
+    T: Send + 'static,
+    F: Fn(ExportConstraints) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = CustomResult<Vec<T>, errors::StorageError>> + Send,
+{
+    let progress = ExportProgress {
+        constraints,
+        batch_size: state.conf.data_export.batch_size.max(1),
+        max_records,
+        exported_count: 0,
+        has_more: true,
+        is_finished: false,
+    };
+    let body = futures::stream::unfold(
+        (progress, fetch_batch),
+        move |(mut progress, fetch_batch)| async move {
+            if progress.is_finished {
+                return None;
+            }
+            let chunk = progress.next_chunk(&fetch_batch, to_export_line).await;
+            Some((chunk, (progress, fetch_batch)))
+        },
+    );
+
+    let content_type = NDJSON_CONTENT_TYPE
+        .parse()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    Ok(ApplicationResponse::FileStream(FileStream {
+        content_type,
+        body: Box::pin(body),
+    }))
+}
+
+fn get_max_records(state: &SessionState, limit: Option<u32>) -> u32 {
+    let max_records_per_request = state.conf.data_export.max_records_per_request;
+    limit.map_or(max_records_per_request, |limit| {
+        limit.min(max_records_per_request)
+    })
+}
+
+fn to_payment_export_line(
+    payment_intent: diesel_models::PaymentIntent,
+) -> (ExportCursor, DataExportLine) {
+    let cursor = ExportCursor {
+        modified_at: payment_intent.modified_at,
+        id: payment_intent.payment_id.get_string_repr().to_owned(),
+    };
+    let record = PaymentExportRecord {
+        payment_id: payment_intent.payment_id,
+        merchant_id: payment_intent.merchant_id,
+        profile_id: payment_intent.profile_id,
+        status: payment_intent.status,
+        amount: payment_intent.amount,
+        currency: payment_intent.currency,
+        amount_captured: payment_intent.amount_captured,
+        customer_id: payment_intent.customer_id,
+        merchant_order_reference_id: payment_intent.merchant_order_reference_id,
+        active_attempt_id: payment_intent.active_attempt_id,
+        attempt_count: payment_intent.attempt_count,
+        created_at: payment_intent.created_at,
+        modified_at: payment_intent.modified_at,
+    };
+    (cursor, DataExportLine::Payment(record))
+}
+
+fn to_refund_export_line(refund: storage::Refund) -> (ExportCursor, DataExportLine) {
+    let cursor = ExportCursor {
+        modified_at: refund.modified_at,
+        id: refund.refund_id.clone(),
+    };
+    let record = RefundExportRecord {
+        refund_id: refund.refund_id,
+        payment_id: refund.payment_id,
+        merchant_id: refund.merchant_id,
+        profile_id: refund.profile_id,
+        connector: refund.connector,
+        refund_status: refund.refund_status,
+        refund_amount: refund.refund_amount,
+        total_amount: refund.total_amount,
+        currency: refund.currency,
+        refund_reason: refund.refund_reason,
+        created_at: refund.created_at,
+        modified_at: refund.modified_at,
+    };
+    (cursor, DataExportLine::Refund(record))
+}
+
+/// Exports the payments of the merchant matching the filters as newline delimited JSON, in the
+/// order of their last modification. The export can be resumed from the cursor of its checkpoint.
+#[instrument(skip_all)]
+pub async fn export_payments(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id_list: Option<Vec<id_type::ProfileId>>,
+    request: PaymentsExportRequest,
+) -> RouterResponse<serde_json::Value> {
+    let merchant_id = merchant_account.get_id();
+    check_rate_limit(&state, merchant_id).await?;
+
+    let constraints = get_export_constraints(
+        profile_id_list,
+        request.time_range,
+        request.currency,
+        request.cursor.as_deref(),
+    )?;
+    let status = request.status;
+    let merchant_id = merchant_id.clone();
+    let db = state.store.clone();
+
+    export_in_batches(
+        &state,
+        constraints,
+        get_max_records(&state, request.limit),
+        move |constraints| {
+            let status = status.clone();
+            let merchant_id = merchant_id.clone();
+            let db = db.clone();
+            async move {
+                db.find_payment_intents_export_batch(&merchant_id, &constraints, status.as_deref())
+                    .await
+            }
+        },
+        to_payment_export_line,
+    )
+}
+
+/// Exports the refunds of the merchant matching the filters as newline delimited JSON, in the
+/// order of their last modification. The export can be resumed from the cursor of its checkpoint.
+#[instrument(skip_all)]
+pub async fn export_refunds(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id_list: Option<Vec<id_type::ProfileId>>,
+    request: RefundsExportRequest,
+) -> RouterResponse<serde_json::Value> {
+    let merchant_id = merchant_account.get_id();
+    check_rate_limit(&state, merchant_id).await?;
+
+    let constraints = get_export_constraints(
+        profile_id_list,
+        request.time_range,
+        request.currency,
+        request.cursor.as_deref(),
+    )?;
+    let refund_status = request.refund_status;
+    let merchant_id = merchant_id.clone();
+    let db = state.store.clone();
+
+    export_in_batches(
+        &state,
+        constraints,
+        get_max_records(&state, request.limit),
+        move |constraints| {
+            let refund_status = refund_status.clone();
+            let merchant_id = merchant_id.clone();
+            let db = db.clone();
+            async move {
+                db.find_refunds_export_batch(&merchant_id, &constraints, refund_status.as_deref())
+                    .await
+            }
+        },
+        to_refund_export_line,
+    )
+}
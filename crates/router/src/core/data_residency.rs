@@ -0,0 +1,227 @@
+use std::borrow::Cow;
+
+use common_utils::{fp_utils, id_type};
+use error_stack::{report, ResultExt};
+
+use crate::{
+    configs::settings,
+    core::errors::{self, RouterResult, StorageErrorExt},
+    routes::SessionState,
+};
+
+/// The region holding the data of a merchant account, merchant accounts without a region predate
+/// data residency and belong to the region served by this deployment
+fn get_region<'a>(
+    data_region: Option<&'a str>,
+    data_residency: &'a settings::DataResidency,
+) -> &'a str {
+    data_region.unwrap_or(data_residency.current_region.as_str())
+}
+
+/// The region a new merchant account is tagged with. The data of the merchant account is kept in
+/// the database of the deployment it is created on, so merchant accounts can only be created on
+/// the deployment serving their region.
+pub fn get_region_for_new_merchant_account(
+    data_residency: &settings::DataResidency,
+    requested_region: Option<String>,
+) -> RouterResult<Option<String>> {
+    if !data_residency.enabled {
+        return match requested_region {
+            Some(_) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Data residency is not enabled".to_string(),
+            })),
+            None => Ok(None),
+        };
+    }
+
+    let region = requested_region.unwrap_or_else(|| data_residency.current_region.clone());
+    let region_config = data_residency.regions.get(&region).ok_or_else(|| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`{region}` is not a configured data region"),
+        })
+    })?;
+    fp_utils::when(region != data_residency.current_region, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Merchant accounts of the {region} region must be created through {}",
+                region_config.base_url
+            ),
+        }))
+    })?;
+
+    Ok(Some(region))
+}
+
+async fn get_merchant_data_region(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<String>> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    #[cfg(feature = "v1")]
+    let data_region = merchant_account.data_region;
+    // Merchant accounts of the v2 api are not tagged with a region
+    #[cfg(feature = "v2")]
+    let data_region = {
+        let _ = merchant_account;
+        None
+    };
+
+    Ok(data_region)
+}
+
+/// Rejects requests for merchant accounts whose data is kept in another region, so that the data
+/// of a merchant account is never read or written outside its region.
+pub async fn validate_request_for_data_region(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    let data_residency = &state.conf.data_residency;
+    if !data_residency.enabled {
+        return Ok(());
+    }
+
+    let data_region = get_merchant_data_region(state, merchant_id).await?;
+    validate_data_region(data_region.as_deref(), data_residency)
+}
+
+fn validate_data_region(
+    data_region: Option<&str>,
+    data_residency: &settings::DataResidency,
+) -> RouterResult<()> {
+    let region = get_region(data_region, data_residency);
+    fp_utils::when(region != data_residency.current_region, || {
+        let base_url = data_residency
+            .regions
+            .get(region)
+            .map(|region_config| region_config.base_url.as_str())
+            .unwrap_or_default();
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The data of the merchant account is kept in the {region} region and must be \
+                 accessed through {base_url}"
+            ),
+        }))
+    })
+}
+
+/// The card vault settings of the region holding the data of the merchant account
+pub async fn get_locker_for_merchant<'a>(
+    state: &'a SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Cow<'a, settings::Locker>> {
+    let data_residency = &state.conf.data_residency;
+    if !data_residency.enabled {
+        return Ok(Cow::Borrowed(&state.conf.locker));
+    }
+
+    let data_region = get_merchant_data_region(state, merchant_id).await?;
+    get_regional_locker(&state.conf.locker, data_region.as_deref(), data_residency).map(Cow::Owned)
+}
+
+fn get_regional_locker(
+    locker: &settings::Locker,
+    data_region: Option<&str>,
+    data_residency: &settings::DataResidency,
+) -> RouterResult<settings::Locker> {
+    let region = get_region(data_region, data_residency);
+    let region_config = data_residency
+        .regions
+        .get(region)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Data region {region} is not configured"))?;
+
+    Ok(settings::Locker {
+        host: region_config.locker_host.clone(),
+        host_rs: region_config.locker_host_rs.clone(),
+        ..locker.clone()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn data_residency() -> settings::DataResidency {
+        let region = |name: &str| settings::DataRegion {
+            base_url: format!("https://{name}.example.com"),
+            locker_host: format!("https://locker.{name}.example.com"),
+            locker_host_rs: format!("https://locker-rs.{name}.example.com"),
+        };
+        settings::DataResidency {
+            enabled: true,
+            current_region: "us".to_string(),
+            regions: [("us", region("us")), ("eu", region("eu"))]
+                .into_iter()
+                .map(|(name, region)| (name.to_string(), region))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_region_for_new_merchant_account() {
+        let data_residency = data_residency();
+        assert_eq!(
+            get_region_for_new_merchant_account(&data_residency, None).unwrap(),
+            Some("us".to_string())
+        );
+        assert_eq!(
+            get_region_for_new_merchant_account(&data_residency, Some("us".to_string())).unwrap(),
+            Some("us".to_string())
+        );
+        assert!(
+            get_region_for_new_merchant_account(&data_residency, Some("eu".to_string())).is_err()
+        );
+        assert!(
+            get_region_for_new_merchant_account(&data_residency, Some("in".to_string())).is_err()
+        );
+
+        let disabled = settings::DataResidency {
+            enabled: false,
+            ..data_residency
+        };
+        assert_eq!(
+            get_region_for_new_merchant_account(&disabled, None).unwrap(),
+            None
+        );
+        assert!(get_region_for_new_merchant_account(&disabled, Some("us".to_string())).is_err());
+    }
+
+    #[test]
+    fn test_cross_region_requests_are_rejected() {
+        let data_residency = data_residency();
+        assert!(validate_data_region(None, &data_residency).is_ok());
+        assert!(validate_data_region(Some("us"), &data_residency).is_ok());
+        assert!(validate_data_region(Some("eu"), &data_residency).is_err());
+    }
+
+    #[test]
+    fn test_locker_of_the_merchant_region() {
+        let data_residency = data_residency();
+        let locker = settings::Locker::default();
+
+        let regional_locker = get_regional_locker(&locker, Some("eu"), &data_residency).unwrap();
+        assert_eq!(regional_locker.host, "https://locker.eu.example.com");
+        assert_eq!(regional_locker.host_rs, "https://locker-rs.eu.example.com");
+
+        let regional_locker = get_regional_locker(&locker, None, &data_residency).unwrap();
+        assert_eq!(regional_locker.host, "https://locker.us.example.com");
+
+        assert!(get_regional_locker(&locker, Some("in"), &data_residency).is_err());
+    }
+}
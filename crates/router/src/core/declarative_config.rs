@@ -0,0 +1,1115 @@
+use std::collections::{HashMap, HashSet};
+
+use api_models::{
+    admin as admin_api, declarative_config as config_api, enums, routing as routing_api,
+};
+use common_utils::{ext_traits::ValueExt, id_type};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use serde_json::{Map, Value};
+
+use crate::{
+    core::{
+        admin,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        routing,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, transformers::ForeignTryFrom},
+};
+
+/// Connector setting which is read back masked, so it is compared against the stored credentials
+const CONNECTOR_ACCOUNT_DETAILS: &str = "connector_account_details";
+/// Field of the connectors in routing algorithms which can hold the connector label instead
+const MERCHANT_CONNECTOR_ID: &str = "merchant_connector_id";
+const DEFAULT_ROUTING_DESCRIPTION: &str = "Applied through the declarative configuration";
+
+/// Change to be made to reach the desired configuration, already parsed into the request of the
+/// corresponding API
+enum PlannedChange {
+    UpdateMerchantAccount(Box<admin_api::MerchantAccountUpdate>),
+    CreateProfile(Box<admin_api::ProfileCreate>),
+    UpdateProfile {
+        profile_id: id_type::ProfileId,
+        request: Box<admin_api::ProfileUpdate>,
+    },
+    DeleteProfile(id_type::ProfileId),
+    CreateConnector {
+        profile_name: String,
+        request: Box<admin_api::MerchantConnectorCreate>,
+    },
+    UpdateConnector {
+        merchant_connector_id: id_type::MerchantConnectorAccountId,
+        request: Box<admin_api::MerchantConnectorUpdate>,
+    },
+    DeleteConnector(id_type::MerchantConnectorAccountId),
+    ActivateRouting {
+        profile_name: String,
+        routing: Box<config_api::RoutingSpec>,
+        previous_algorithm_id: Option<id_type::RoutingId>,
+    },
+}
+
+/// Change undoing an applied change, made when applying a later change fails so that either all
+/// or none of the changes remain applied
+enum Rollback {
+    UpdateMerchantAccount(Box<admin_api::MerchantAccountUpdate>),
+    DeleteProfile(id_type::ProfileId),
+    UpdateProfile {
+        profile_id: id_type::ProfileId,
+        request: Box<admin_api::ProfileUpdate>,
+    },
+    RestoreProfile(Box<domain::Profile>),
+    DeleteConnector(id_type::MerchantConnectorAccountId),
+    UpdateConnector {
+        merchant_connector_id: id_type::MerchantConnectorAccountId,
+        request: Box<admin_api::MerchantConnectorUpdate>,
+    },
+    RestoreConnector(Box<domain::MerchantConnectorAccount>),
+    LinkRouting(id_type::RoutingId),
+    UnlinkRouting(id_type::ProfileId),
+}
+
+/// Identifiers of the profiles and connectors by their names, including the ones created while
+/// applying
+#[derive(Default)]
+struct ResourceIds {
+    profiles: HashMap<String, id_type::ProfileId>,
+    connectors: HashMap<(String, String), id_type::MerchantConnectorAccountId>,
+}
+
+struct PlannedAction {
+    action: config_api::ConfigAction,
+    change: PlannedChange,
+    rollback: Option<Rollback>,
+}
+
+impl PlannedAction {
+    fn new(
+        resource_type: config_api::ConfigResourceType,
+        resource_name: String,
+        resource_id: Option<String>,
+        operation: config_api::ConfigOperation,
+        changed_fields: Vec<String>,
+        change: PlannedChange,
+    ) -> Self {
+        Self {
+            action: config_api::ConfigAction {
+                resource_type,
+                resource_name,
+                resource_id,
+                operation,
+                changed_fields,
+                status: config_api::ConfigActionStatus::Planned,
+                error: None,
+            },
+            change,
+            rollback: None,
+        }
+    }
+
+    fn with_rollback(mut self, rollback: Option<Rollback>) -> Self {
+        self.rollback = rollback;
+        self
+    }
+}
+
+/// Computes the changes needed for the merchant account to match the desired configuration and
+/// applies them unless it is a dry run. All the settings are validated before any change is
+/// applied, the changes are then applied in order. Applying stops at the first failure, in which
+/// case the changes already applied are undone in the reverse order.
+#[instrument(skip_all)]
+pub async fn apply_declarative_config(
+    state: SessionState,
+    request: config_api::DeclarativeConfigRequest,
+) -> RouterResponse<config_api::DeclarativeConfigResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &request.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &request.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let profiles = db
+        .list_profile_by_merchant_id(key_manager_state, &key_store, &request.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the profiles of the merchant account")?;
+    let connectors = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            &request.merchant_id,
+            true,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the connectors of the merchant account")?;
+
+    let mut resource_ids = get_resource_ids(&profiles, &connectors);
+
+    let mut planned_actions = plan_merchant_account_changes(&request, &merchant_account)?;
+    let (profile_and_connector_actions, delete_actions) =
+        plan_profile_and_connector_changes(&request, &profiles, &connectors)?;
+    planned_actions.extend(profile_and_connector_actions);
+    planned_actions.extend(
+        plan_routing_changes(
+            &state,
+            &request,
+            &merchant_account,
+            &profiles,
+            &resource_ids,
+        )
+        .await?,
+    );
+    planned_actions.extend(delete_actions);
+
+    if !request.dry_run {
+        let mut applied_count = 0;
+        let mut is_failed = false;
+        for planned_action in planned_actions.iter_mut() {
+            match apply_change(
+                &state,
+                &merchant_account,
+                &key_store,
+                &mut resource_ids,
+                &planned_action.change,
+            )
+            .await
+            {
+                Ok((resource_id, rollback)) => {
+                    planned_action.action.resource_id = Some(resource_id);
+                    planned_action.action.status = config_api::ConfigActionStatus::Applied;
+                    if rollback.is_some() {
+                        planned_action.rollback = rollback;
+                    }
+                    applied_count += 1;
+                }
+                Err(error) => {
+                    logger::error!(declarative_config_error=?error);
+                    planned_action.action.status = config_api::ConfigActionStatus::Failed;
+                    planned_action.action.error = Some(error.current_context().to_string());
+                    is_failed = true;
+                    break;
+                }
+            }
+        }
+
+        if is_failed {
+            if let Some(applied_actions) = planned_actions.get_mut(..applied_count) {
+                rollback_changes(&state, &merchant_account, &key_store, applied_actions).await;
+            }
+        }
+    }
+
+    Ok(ApplicationResponse::Json(
+        config_api::DeclarativeConfigResponse {
+            merchant_id: request.merchant_id,
+            dry_run: request.dry_run,
+            actions: planned_actions
+                .into_iter()
+                .map(|planned_action| planned_action.action)
+                .collect(),
+        },
+    ))
+}
+
+fn plan_merchant_account_changes(
+    request: &config_api::DeclarativeConfigRequest,
+    merchant_account: &domain::MerchantAccount,
+) -> RouterResult<Vec<PlannedAction>> {
+    let Some(desired) = request.merchant_account.as_ref() else {
+        return Ok(Vec::new());
+    };
+    let desired = desired
+        .as_object()
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`merchant_account` must be an object".to_string(),
+        })?;
+
+    let current = to_json(admin_api::MerchantAccountResponse::foreign_try_from(
+        merchant_account.clone(),
+    ))?;
+    let changed_fields = get_changed_fields(desired, &current);
+    if changed_fields.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let merchant_id = Value::String(request.merchant_id.get_string_repr().to_owned());
+    let mut update = select_fields(desired, &changed_fields);
+    update.insert("merchant_id".to_string(), merchant_id.clone());
+    let update = parse_settings::<admin_api::MerchantAccountUpdate>(update, "merchant account")?;
+
+    let mut rollback = select_current_fields(&current, &changed_fields);
+    rollback.insert("merchant_id".to_string(), merchant_id);
+    let rollback = get_rollback_request::<admin_api::MerchantAccountUpdate>(rollback)
+        .map(|request| Rollback::UpdateMerchantAccount(Box::new(request)));
+
+    Ok(vec![PlannedAction::new(
+        config_api::ConfigResourceType::MerchantAccount,
+        request.merchant_id.get_string_repr().to_owned(),
+        Some(request.merchant_id.get_string_repr().to_owned()),
+        config_api::ConfigOperation::Update,
+        changed_fields,
+        PlannedChange::UpdateMerchantAccount(Box::new(update)),
+    )
+    .with_rollback(rollback)])
+}
+
+/// Plans the creates and updates of the profiles and connectors, and separately their deletes
+/// which are applied last
+fn plan_profile_and_connector_changes(
+    request: &config_api::DeclarativeConfigRequest,
+    profiles: &[domain::Profile],
+    connectors: &[domain::MerchantConnectorAccount],
+) -> RouterResult<(Vec<PlannedAction>, Vec<PlannedAction>)> {
+    let profiles_by_name = profiles
+        .iter()
+        .map(|profile| (profile.profile_name.as_str(), profile))
+        .collect::<HashMap<_, _>>();
+    let connectors_by_lineage = connectors
+        .iter()
+        .filter_map(|connector| {
+            connector
+                .connector_label
+                .as_deref()
+                .map(|label| ((connector.profile_id.clone(), label), connector))
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut profile_actions = Vec::new();
+    let mut connector_actions = Vec::new();
+    let mut specified_profile_names = HashSet::new();
+    let mut specified_connector_ids = HashSet::new();
+
+    for profile_spec in request.profiles.iter() {
+        if !specified_profile_names.insert(profile_spec.profile_name.as_str()) {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Profile `{}` is specified twice", profile_spec.profile_name),
+            }));
+        }
+        if profile_spec.settings.contains_key("profile_name") {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`profile_name` cannot be part of the profile settings".to_string(),
+            }));
+        }
+
+        let existing_profile = profiles_by_name.get(profile_spec.profile_name.as_str());
+        match existing_profile {
+            Some(profile) => {
+                let current = to_json(admin_api::ProfileResponse::foreign_try_from(
+                    (*profile).clone(),
+                ))?;
+                let changed_fields = get_changed_fields(&profile_spec.settings, &current);
+                if !changed_fields.is_empty() {
+                    let update = parse_settings::<admin_api::ProfileUpdate>(
+                        select_fields(&profile_spec.settings, &changed_fields),
+                        &format!("profile `{}`", profile_spec.profile_name),
+                    )?;
+                    let rollback = get_rollback_request::<admin_api::ProfileUpdate>(
+                        select_current_fields(&current, &changed_fields),
+                    )
+                    .map(|request| Rollback::UpdateProfile {
+                        profile_id: profile.get_id().to_owned(),
+                        request: Box::new(request),
+                    });
+                    profile_actions.push(
+                        PlannedAction::new(
+                            config_api::ConfigResourceType::Profile,
+                            profile_spec.profile_name.clone(),
+                            Some(profile.get_id().get_string_repr().to_owned()),
+                            config_api::ConfigOperation::Update,
+                            changed_fields,
+                            PlannedChange::UpdateProfile {
+                                profile_id: profile.get_id().to_owned(),
+                                request: Box::new(update),
+                            },
+                        )
+                        .with_rollback(rollback),
+                    );
+                }
+            }
+            None => {
+                let mut settings = profile_spec.settings.clone();
+                settings.insert(
+                    "profile_name".to_string(),
+                    Value::String(profile_spec.profile_name.clone()),
+                );
+                let create = parse_settings::<admin_api::ProfileCreate>(
+                    settings,
+                    &format!("profile `{}`", profile_spec.profile_name),
+                )?;
+                profile_actions.push(PlannedAction::new(
+                    config_api::ConfigResourceType::Profile,
+                    profile_spec.profile_name.clone(),
+                    None,
+                    config_api::ConfigOperation::Create,
+                    Vec::new(),
+                    PlannedChange::CreateProfile(Box::new(create)),
+                ));
+            }
+        }
+
+        let mut specified_labels = HashSet::new();
+        for connector_spec in profile_spec.connectors.iter() {
+            let resource_name = format!(
+                "{}/{}",
+                profile_spec.profile_name, connector_spec.connector_label
+            );
+            if !specified_labels.insert(connector_spec.connector_label.as_str()) {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("Connector `{resource_name}` is specified twice"),
+                }));
+            }
+            if ["connector_label", "profile_id", "merchant_connector_id"]
+                .iter()
+                .any(|field| connector_spec.settings.contains_key(*field))
+            {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Connector `{resource_name}` settings cannot contain the connector label or any identifier"
+                    ),
+                }));
+            }
+
+            let existing_connector = existing_profile.and_then(|profile| {
+                connectors_by_lineage.get(&(
+                    profile.get_id().to_owned(),
+                    connector_spec.connector_label.as_str(),
+                ))
+            });
+            match existing_connector {
+                Some(connector) => {
+                    specified_connector_ids.insert(connector.get_id());
+                    let mut current =
+                        to_json(admin_api::MerchantConnectorResponse::foreign_try_from(
+                            (*connector).clone(),
+                        ))?;
+                    if let Some(current) = current.as_object_mut() {
+                        current.insert(
+                            CONNECTOR_ACCOUNT_DETAILS.to_string(),
+                            connector
+                                .connector_account_details
+                                .get_inner()
+                                .peek()
+                                .clone(),
+                        );
+                    }
+
+                    let changed_fields = get_changed_fields(&connector_spec.settings, &current);
+                    if !changed_fields.is_empty() {
+                        let identifiers = [
+                            (
+                                "connector_type".to_string(),
+                                current.get("connector_type").cloned().unwrap_or_default(),
+                            ),
+                            (
+                                "merchant_id".to_string(),
+                                Value::String(request.merchant_id.get_string_repr().to_owned()),
+                            ),
+                        ];
+                        let mut update = select_fields(&connector_spec.settings, &changed_fields);
+                        update.extend(identifiers.clone());
+                        let update = parse_settings::<admin_api::MerchantConnectorUpdate>(
+                            update,
+                            &format!("connector `{resource_name}`"),
+                        )?;
+                        let mut rollback = select_current_fields(&current, &changed_fields);
+                        rollback.extend(identifiers);
+                        let rollback =
+                            get_rollback_request::<admin_api::MerchantConnectorUpdate>(rollback)
+                                .map(|request| Rollback::UpdateConnector {
+                                    merchant_connector_id: connector.get_id(),
+                                    request: Box::new(request),
+                                });
+                        connector_actions.push(
+                            PlannedAction::new(
+                                config_api::ConfigResourceType::Connector,
+                                resource_name,
+                                Some(connector.get_id().get_string_repr().to_owned()),
+                                config_api::ConfigOperation::Update,
+                                changed_fields,
+                                PlannedChange::UpdateConnector {
+                                    merchant_connector_id: connector.get_id(),
+                                    request: Box::new(update),
+                                },
+                            )
+                            .with_rollback(rollback),
+                        );
+                    }
+                }
+                None => {
+                    let mut settings = connector_spec.settings.clone();
+                    settings.insert(
+                        "connector_label".to_string(),
+                        Value::String(connector_spec.connector_label.clone()),
+                    );
+                    let create = parse_settings::<admin_api::MerchantConnectorCreate>(
+                        settings,
+                        &format!("connector `{resource_name}`"),
+                    )?;
+                    connector_actions.push(PlannedAction::new(
+                        config_api::ConfigResourceType::Connector,
+                        resource_name,
+                        None,
+                        config_api::ConfigOperation::Create,
+                        Vec::new(),
+                        PlannedChange::CreateConnector {
+                            profile_name: profile_spec.profile_name.clone(),
+                            request: Box::new(create),
+                        },
+                    ));
+                }
+            }
+        }
+    }
+
+    let mut delete_actions = Vec::new();
+    if request.prune {
+        let profile_names_by_id = profiles
+            .iter()
+            .map(|profile| (profile.get_id().to_owned(), profile.profile_name.as_str()))
+            .collect::<HashMap<_, _>>();
+
+        // Connectors are deleted before the profiles they belong to
+        delete_actions.extend(
+            connectors
+                .iter()
+                .filter(|connector| !specified_connector_ids.contains(&connector.get_id()))
+                .map(|connector| {
+                    PlannedAction::new(
+                        config_api::ConfigResourceType::Connector,
+                        format!(
+                            "{}/{}",
+                            profile_names_by_id
+                                .get(&connector.profile_id)
+                                .copied()
+                                .unwrap_or(connector.profile_id.get_string_repr()),
+                            connector
+                                .connector_label
+                                .as_deref()
+                                .unwrap_or(connector.connector_name.as_str())
+                        ),
+                        Some(connector.get_id().get_string_repr().to_owned()),
+                        config_api::ConfigOperation::Delete,
+                        Vec::new(),
+                        PlannedChange::DeleteConnector(connector.get_id()),
+                    )
+                    .with_rollback(Some(Rollback::RestoreConnector(Box::new(
+                        connector.clone(),
+                    ))))
+                }),
+        );
+        delete_actions.extend(
+            profiles
+                .iter()
+                .filter(|profile| !specified_profile_names.contains(profile.profile_name.as_str()))
+                .map(|profile| {
+                    PlannedAction::new(
+                        config_api::ConfigResourceType::Profile,
+                        profile.profile_name.clone(),
+                        Some(profile.get_id().get_string_repr().to_owned()),
+                        config_api::ConfigOperation::Delete,
+                        Vec::new(),
+                        PlannedChange::DeleteProfile(profile.get_id().to_owned()),
+                    )
+                    .with_rollback(Some(Rollback::RestoreProfile(Box::new(profile.clone()))))
+                }),
+        );
+    }
+
+    profile_actions.extend(connector_actions);
+    Ok((profile_actions, delete_actions))
+}
+
+/// Plans the activation of the routing algorithms of the profiles whose active algorithm differs
+/// from the desired one. The desired algorithm is created and activated as a new algorithm.
+async fn plan_routing_changes(
+    state: &SessionState,
+    request: &config_api::DeclarativeConfigRequest,
+    merchant_account: &domain::MerchantAccount,
+    profiles: &[domain::Profile],
+    resource_ids: &ResourceIds,
+) -> RouterResult<Vec<PlannedAction>> {
+    let mut routing_actions = Vec::new();
+
+    for profile_spec in request.profiles.iter() {
+        let Some(routing) = profile_spec.routing.as_ref() else {
+            continue;
+        };
+        let algorithm = parse_routing_algorithm(
+            routing,
+            &profile_spec.profile_name,
+            &resource_ids.connectors,
+        )?;
+
+        let existing_profile = profiles
+            .iter()
+            .find(|profile| profile.profile_name == profile_spec.profile_name);
+        let previous_algorithm_id = existing_profile
+            .and_then(|profile| profile.routing_algorithm.clone())
+            .map(|routing_algorithm| {
+                routing_algorithm
+                    .parse_value::<routing_api::RoutingAlgorithmRef>("RoutingAlgorithmRef")
+            })
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the routing algorithm of the profile")?
+            .and_then(|routing_algorithm| routing_algorithm.algorithm_id);
+
+        if let Some(previous_algorithm_id) = previous_algorithm_id.as_ref() {
+            let active_algorithm = state
+                .store
+                .find_routing_algorithm_by_algorithm_id_merchant_id(
+                    previous_algorithm_id,
+                    merchant_account.get_id(),
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the active routing algorithm of the profile")?;
+            if active_algorithm.name == routing.name
+                && active_algorithm.algorithm_data == serde_json::json!(algorithm)
+            {
+                continue;
+            }
+        }
+
+        let rollback = match (previous_algorithm_id.as_ref(), existing_profile) {
+            (Some(previous_algorithm_id), _) => {
+                Some(Rollback::LinkRouting(previous_algorithm_id.clone()))
+            }
+            (None, Some(profile)) => Some(Rollback::UnlinkRouting(profile.get_id().to_owned())),
+            // The routing algorithm of a profile being created is undone along with the profile
+            (None, None) => None,
+        };
+        routing_actions.push(
+            PlannedAction::new(
+                config_api::ConfigResourceType::Routing,
+                profile_spec.profile_name.clone(),
+                previous_algorithm_id
+                    .as_ref()
+                    .map(|algorithm_id| algorithm_id.get_string_repr().to_owned()),
+                if previous_algorithm_id.is_some() {
+                    config_api::ConfigOperation::Update
+                } else {
+                    config_api::ConfigOperation::Create
+                },
+                Vec::new(),
+                PlannedChange::ActivateRouting {
+                    profile_name: profile_spec.profile_name.clone(),
+                    routing: Box::new(routing.clone()),
+                    previous_algorithm_id,
+                },
+            )
+            .with_rollback(rollback),
+        );
+    }
+
+    Ok(routing_actions)
+}
+
+fn get_resource_ids(
+    profiles: &[domain::Profile],
+    connectors: &[domain::MerchantConnectorAccount],
+) -> ResourceIds {
+    let profile_names_by_id = profiles
+        .iter()
+        .map(|profile| (profile.get_id().to_owned(), profile.profile_name.clone()))
+        .collect::<HashMap<_, _>>();
+
+    ResourceIds {
+        connectors: connectors
+            .iter()
+            .filter_map(|connector| {
+                let profile_name = profile_names_by_id.get(&connector.profile_id)?;
+                let connector_label = connector.connector_label.clone()?;
+                Some(((profile_name.clone(), connector_label), connector.get_id()))
+            })
+            .collect(),
+        profiles: profile_names_by_id
+            .into_iter()
+            .map(|(profile_id, profile_name)| (profile_name, profile_id))
+            .collect(),
+    }
+}
+
+/// Parses the routing algorithm of the profile, with the connector labels it refers to replaced
+/// by the identifiers of the connectors which are known
+fn parse_routing_algorithm(
+    routing: &config_api::RoutingSpec,
+    profile_name: &str,
+    connector_ids: &HashMap<(String, String), id_type::MerchantConnectorAccountId>,
+) -> RouterResult<routing_api::RoutingAlgorithm> {
+    let mut algorithm = routing.algorithm.clone();
+    resolve_connector_labels(&mut algorithm, &|label| {
+        connector_ids
+            .get(&(profile_name.to_string(), label.to_string()))
+            .map(|merchant_connector_id| merchant_connector_id.get_string_repr().to_owned())
+    });
+
+    serde_json::from_value(algorithm).map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid routing algorithm for profile `{profile_name}`: {error}"),
+        })
+    })
+}
+
+fn resolve_connector_labels(value: &mut Value, get_connector_id: &dyn Fn(&str) -> Option<String>) {
+    match value {
+        Value::Object(object) => {
+            for (field, value) in object.iter_mut() {
+                match value {
+                    Value::String(label) if field == MERCHANT_CONNECTOR_ID => {
+                        if let Some(merchant_connector_id) = get_connector_id(label) {
+                            *label = merchant_connector_id;
+                        }
+                    }
+                    _ => resolve_connector_labels(value, get_connector_id),
+                }
+            }
+        }
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| resolve_connector_labels(value, get_connector_id)),
+        _ => {}
+    }
+}
+
+/// Applies the change, returning the identifier of the resource along with the change undoing
+/// it, for changes whose undoing is only known once applied
+async fn apply_change(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    resource_ids: &mut ResourceIds,
+    change: &PlannedChange,
+) -> RouterResult<(String, Option<Rollback>)> {
+    let merchant_id = merchant_account.get_id();
+    match change {
+        PlannedChange::UpdateMerchantAccount(request) => {
+            get_json_response(
+                admin::merchant_account_update(
+                    state.clone(),
+                    merchant_id,
+                    None,
+                    request.as_ref().clone(),
+                )
+                .await?,
+            )?;
+            Ok((merchant_id.get_string_repr().to_owned(), None))
+        }
+        PlannedChange::CreateProfile(request) => {
+            let profile = get_json_response(
+                admin::create_profile(
+                    state.clone(),
+                    request.as_ref().clone(),
+                    merchant_account.clone(),
+                    key_store.clone(),
+                )
+                .await?,
+            )?;
+            resource_ids
+                .profiles
+                .insert(profile.profile_name, profile.profile_id.clone());
+            Ok((
+                profile.profile_id.get_string_repr().to_owned(),
+                Some(Rollback::DeleteProfile(profile.profile_id)),
+            ))
+        }
+        PlannedChange::UpdateProfile {
+            profile_id,
+            request,
+        } => {
+            get_json_response(
+                admin::update_profile(
+                    state.clone(),
+                    profile_id,
+                    key_store.clone(),
+                    request.as_ref().clone(),
+                )
+                .await?,
+            )?;
+            Ok((profile_id.get_string_repr().to_owned(), None))
+        }
+        PlannedChange::DeleteProfile(profile_id) => {
+            get_json_response(
+                admin::delete_profile(state.clone(), profile_id.clone(), merchant_id).await?,
+            )?;
+            Ok((profile_id.get_string_repr().to_owned(), None))
+        }
+        PlannedChange::CreateConnector {
+            profile_name,
+            request,
+        } => {
+            let mut request = request.as_ref().clone();
+            request.profile_id = Some(get_profile_id(resource_ids, profile_name)?);
+            let connector_label = request.connector_label.clone();
+            let connector = get_json_response(
+                admin::create_connector(
+                    state.clone(),
+                    request,
+                    merchant_account.clone(),
+                    None,
+                    key_store.clone(),
+                )
+                .await?,
+            )?;
+            if let Some(connector_label) = connector_label {
+                resource_ids.connectors.insert(
+                    (profile_name.clone(), connector_label),
+                    connector.merchant_connector_id.clone(),
+                );
+            }
+            Ok((
+                connector.merchant_connector_id.get_string_repr().to_owned(),
+                Some(Rollback::DeleteConnector(connector.merchant_connector_id)),
+            ))
+        }
+        PlannedChange::UpdateConnector {
+            merchant_connector_id,
+            request,
+        } => {
+            get_json_response(
+                admin::update_connector(
+                    state.clone(),
+                    merchant_id,
+                    None,
+                    merchant_connector_id,
+                    request.as_ref().clone(),
+                )
+                .await?,
+            )?;
+            Ok((merchant_connector_id.get_string_repr().to_owned(), None))
+        }
+        PlannedChange::DeleteConnector(merchant_connector_id) => {
+            get_json_response(
+                admin::delete_connector(
+                    state.clone(),
+                    merchant_id.to_owned(),
+                    merchant_connector_id.clone(),
+                )
+                .await?,
+            )?;
+            Ok((merchant_connector_id.get_string_repr().to_owned(), None))
+        }
+        PlannedChange::ActivateRouting {
+            profile_name,
+            routing: routing_spec,
+            previous_algorithm_id,
+        } => {
+            let profile_id = get_profile_id(resource_ids, profile_name)?;
+            // The connectors created while applying are only known now
+            let algorithm =
+                parse_routing_algorithm(routing_spec, profile_name, &resource_ids.connectors)?;
+            let record = get_json_response(
+                routing::create_routing_algorithm_under_profile(
+                    state.clone(),
+                    merchant_account.clone(),
+                    key_store.clone(),
+                    None,
+                    routing_api::RoutingConfigRequest {
+                        name: Some(routing_spec.name.clone()),
+                        description: Some(
+                            routing_spec
+                                .description
+                                .clone()
+                                .unwrap_or_else(|| DEFAULT_ROUTING_DESCRIPTION.to_string()),
+                        ),
+                        algorithm: Some(algorithm),
+                        profile_id: Some(profile_id.clone()),
+                    },
+                    &enums::TransactionType::Payment,
+                )
+                .await?,
+            )?;
+            get_json_response(
+                routing::link_routing_config(
+                    state.clone(),
+                    merchant_account.clone(),
+                    key_store.clone(),
+                    None,
+                    record.id.clone(),
+                    &enums::TransactionType::Payment,
+                )
+                .await?,
+            )?;
+            Ok((
+                record.id.get_string_repr().to_owned(),
+                previous_algorithm_id
+                    .is_none()
+                    .then_some(Rollback::UnlinkRouting(profile_id)),
+            ))
+        }
+    }
+}
+
+/// Undoes the applied changes in the reverse order they were applied in. The changes which could
+/// not be undone are reported along with the reason.
+async fn rollback_changes(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    applied_actions: &mut [PlannedAction],
+) {
+    for planned_action in applied_actions.iter_mut().rev() {
+        let Some(rollback) = planned_action.rollback.take() else {
+            planned_action.action.error = Some("The change cannot be undone".to_string());
+            continue;
+        };
+        match apply_rollback(state, merchant_account, key_store, rollback).await {
+            Ok(()) => {
+                planned_action.action.status = config_api::ConfigActionStatus::RolledBack;
+            }
+            Err(error) => {
+                logger::error!(declarative_config_rollback_error=?error);
+                planned_action.action.error = Some(format!(
+                    "Failed to undo the change: {}",
+                    error.current_context()
+                ));
+            }
+        }
+    }
+}
+
+async fn apply_rollback(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    rollback: Rollback,
+) -> RouterResult<()> {
+    let merchant_id = merchant_account.get_id();
+    let key_manager_state = &state.into();
+    match rollback {
+        Rollback::UpdateMerchantAccount(request) => {
+            admin::merchant_account_update(state.clone(), merchant_id, None, *request).await?;
+        }
+        Rollback::DeleteProfile(profile_id) => {
+            admin::delete_profile(state.clone(), profile_id, merchant_id).await?;
+        }
+        Rollback::UpdateProfile {
+            profile_id,
+            request,
+        } => {
+            admin::update_profile(state.clone(), &profile_id, key_store.clone(), *request).await?;
+        }
+        Rollback::RestoreProfile(profile) => {
+            state
+                .store
+                .insert_business_profile(key_manager_state, key_store, *profile)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to restore the deleted profile")?;
+        }
+        Rollback::DeleteConnector(merchant_connector_id) => {
+            admin::delete_connector(state.clone(), merchant_id.to_owned(), merchant_connector_id)
+                .await?;
+        }
+        Rollback::UpdateConnector {
+            merchant_connector_id,
+            request,
+        } => {
+            admin::update_connector(
+                state.clone(),
+                merchant_id,
+                None,
+                &merchant_connector_id,
+                *request,
+            )
+            .await?;
+        }
+        Rollback::RestoreConnector(connector) => {
+            state
+                .store
+                .insert_merchant_connector_account(key_manager_state, *connector, key_store)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to restore the deleted connector")?;
+        }
+        Rollback::LinkRouting(algorithm_id) => {
+            routing::link_routing_config(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                None,
+                algorithm_id,
+                &enums::TransactionType::Payment,
+            )
+            .await?;
+        }
+        Rollback::UnlinkRouting(profile_id) => {
+            routing::unlink_routing_config(
+                state.clone(),
+                merchant_account.clone(),
+                key_store.clone(),
+                routing_api::RoutingConfigRequest {
+                    name: None,
+                    description: None,
+                    algorithm: None,
+                    profile_id: Some(profile_id),
+                },
+                None,
+                &enums::TransactionType::Payment,
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+fn get_profile_id(
+    resource_ids: &ResourceIds,
+    profile_name: &str,
+) -> RouterResult<id_type::ProfileId> {
+    resource_ids
+        .profiles
+        .get(profile_name)
+        .cloned()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Profile was not created")
+}
+
+fn get_json_response<T>(response: ApplicationResponse<T>) -> RouterResult<T> {
+    match response {
+        ApplicationResponse::Json(response) => Ok(response),
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response while applying the declarative config"),
+    }
+}
+
+fn to_json<T: serde::Serialize, E: error_stack::Context>(
+    response: Result<T, error_stack::Report<E>>,
+) -> RouterResult<Value> {
+    let response = response
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct the current configuration")?;
+    serde_json::to_value(response)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the current configuration")
+}
+
+fn parse_settings<T: serde::de::DeserializeOwned>(
+    settings: Map<String, Value>,
+    resource: &str,
+) -> RouterResult<T> {
+    serde_json::from_value(Value::Object(settings)).map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid settings for {resource}: {error}"),
+        })
+    })
+}
+
+/// Returns the current values of the fields, to undo the update of the fields
+fn select_current_fields(current: &Value, fields: &[String]) -> Map<String, Value> {
+    fields
+        .iter()
+        .map(|field| {
+            (
+                field.clone(),
+                current.get(field.as_str()).cloned().unwrap_or_default(),
+            )
+        })
+        .collect()
+}
+
+/// Parses the request undoing an update. Updates whose previous settings cannot be expressed as
+/// an update request are applied without the ability to undo them.
+fn get_rollback_request<T: serde::de::DeserializeOwned>(settings: Map<String, Value>) -> Option<T> {
+    serde_json::from_value(Value::Object(settings))
+        .map_err(|error| logger::warn!(?error, "The update cannot be undone"))
+        .ok()
+}
+
+fn select_fields(settings: &Map<String, Value>, fields: &[String]) -> Map<String, Value> {
+    settings
+        .iter()
+        .filter(|(field, _)| fields.contains(field))
+        .map(|(field, value)| (field.clone(), value.clone()))
+        .collect()
+}
+
+/// Returns the desired settings which differ from the current ones. Nested objects only need to
+/// contain the desired values, the other values they currently have are not considered changes.
+fn get_changed_fields(desired: &Map<String, Value>, current: &Value) -> Vec<String> {
+    desired
+        .iter()
+        .filter(|(field, value)| {
+            !is_subset(value, current.get(field.as_str()).unwrap_or(&Value::Null))
+        })
+        .map(|(field, _)| field.clone())
+        .collect()
+}
+
+fn is_subset(desired: &Value, current: &Value) -> bool {
+    match (desired, current) {
+        (Value::Object(desired), Value::Object(current)) => desired
+            .iter()
+            .all(|(key, value)| is_subset(value, current.get(key).unwrap_or(&Value::Null))),
+        _ => desired == current,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_get_changed_fields() {
+        let current = json!({
+            "return_url": "https://example.com",
+            "webhook_details": { "webhook_url": "https://example.com/hook", "webhook_version": "1" },
+            "metadata": null
+        });
+        let desired = json!({
+            "return_url": "https://example.com",
+            "webhook_details": { "webhook_url": "https://example.com/hook" },
+            "metadata": null,
+            "payment_response_hash_key": "key"
+        });
+        assert_eq!(
+            get_changed_fields(desired.as_object().unwrap(), &current),
+            vec!["payment_response_hash_key".to_string()]
+        );
+
+        let desired = json!({ "webhook_details": { "webhook_url": "https://example.com/new" } });
+        assert_eq!(
+            get_changed_fields(desired.as_object().unwrap(), &current),
+            vec!["webhook_details".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_resolve_connector_labels() {
+        let mut algorithm = json!({
+            "type": "priority",
+            "data": [
+                { "connector": "stripe", "merchant_connector_id": "stripe_primary" },
+                { "connector": "adyen", "merchant_connector_id": "mca_existing" }
+            ]
+        });
+        resolve_connector_labels(&mut algorithm, &|label| {
+            (label == "stripe_primary").then(|| "mca_stripe".to_string())
+        });
+        assert_eq!(
+            algorithm,
+            json!({
+                "type": "priority",
+                "data": [
+                    { "connector": "stripe", "merchant_connector_id": "mca_stripe" },
+                    { "connector": "adyen", "merchant_connector_id": "mca_existing" }
+                ]
+            })
+        );
+    }
+}
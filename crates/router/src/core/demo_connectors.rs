@@ -0,0 +1,358 @@
+use api_models::{admin as admin_api, enums, sandbox_data as sandbox_data_types};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt},
+    fp_utils, id_type,
+};
+use error_stack::{report, ResultExt};
+use masking::Secret;
+use router_env::{instrument, logger, tracing, Env};
+
+use crate::{
+    core::{
+        admin,
+        errors::{self, RouterResponse, RouterResult},
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{api, domain, storage},
+};
+
+const DEMO_CONNECTOR_PAYMENTS_PREFIX: &str = "DEMO_CONNECTOR_PAYMENTS";
+/// Counters of a day are kept for slightly longer than the day itself
+const DEMO_CONNECTOR_PAYMENTS_TTL_IN_SECS: i64 = 2 * 24 * 60 * 60;
+
+/// Demo connector accounts created for a merchant account, stored in the configs table
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct MerchantDemoConnectorsRecord {
+    pub connectors: Vec<sandbox_data_types::DemoConnectorAccount>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub opted_in_at: Option<time::PrimitiveDateTime>,
+}
+
+impl MerchantDemoConnectorsRecord {
+    fn is_demo_connector_account(
+        &self,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> bool {
+        self.connectors
+            .iter()
+            .any(|connector| connector.merchant_connector_id == *merchant_connector_id)
+    }
+}
+
+fn get_demo_connectors_config_key(merchant_id: &id_type::MerchantId) -> String {
+    format!("merchant_demo_connectors_{}", merchant_id.get_string_repr())
+}
+
+fn get_demo_connector_payments_key(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{DEMO_CONNECTOR_PAYMENTS_PREFIX}_{}_{}",
+        merchant_id.get_string_repr(),
+        date_time::now().date()
+    )
+}
+
+fn validate_sandbox_environment() -> RouterResult<()> {
+    match router_env::which() {
+        Env::Production => Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Demo connectors are only available in the sandbox environment".to_string(),
+        })),
+        Env::Development | Env::Sandbox => Ok(()),
+    }
+}
+
+/// Fetches the demo connector accounts of the merchant account. The accounts are served from the
+/// in-memory config cache on the payments path.
+async fn get_merchant_demo_connectors(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<MerchantDemoConnectorsRecord> {
+    let default_record = MerchantDemoConnectorsRecord::default()
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the default merchant demo connectors")?;
+
+    db.find_config_by_key_unwrap_or(
+        &get_demo_connectors_config_key(merchant_id),
+        Some(default_record),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the merchant demo connectors")?
+    .config
+    .parse_struct("MerchantDemoConnectorsRecord")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse the merchant demo connectors")
+}
+
+async fn store_merchant_demo_connectors(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    record: &MerchantDemoConnectorsRecord,
+) -> RouterResult<()> {
+    let key = get_demo_connectors_config_key(merchant_id);
+    let config = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the merchant demo connectors")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the merchant demo connectors")
+}
+
+async fn get_payments_today(state: &SessionState, merchant_id: &id_type::MerchantId) -> u64 {
+    let payments = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .get_key::<Option<i64>>(&get_demo_connector_payments_key(merchant_id))
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to fetch the demo connector payments"))
+            .ok()
+            .flatten(),
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection for demo connectors");
+            None
+        }
+    };
+
+    payments
+        .and_then(|payments| u64::try_from(payments).ok())
+        .unwrap_or_default()
+}
+
+async fn to_demo_connectors_response(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    record: MerchantDemoConnectorsRecord,
+) -> sandbox_data_types::DemoConnectorsResponse {
+    sandbox_data_types::DemoConnectorsResponse {
+        opted_in: !record.connectors.is_empty(),
+        connectors: record.connectors,
+        daily_payment_quota: state.conf.demo_connectors.daily_payment_quota,
+        payments_today: get_payments_today(state, merchant_id).await,
+        opted_in_at: record.opted_in_at,
+    }
+}
+
+fn build_connector_create_request(
+    connector_name: enums::Connector,
+    demo_connector: &crate::configs::settings::DemoConnector,
+    profile_id: Option<id_type::ProfileId>,
+) -> RouterResult<admin_api::MerchantConnectorCreate> {
+    let connector_account_details = demo_connector
+        .connector_account_details
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the credentials of the demo connector")?;
+
+    Ok(admin_api::MerchantConnectorCreate {
+        connector_type: enums::ConnectorType::PaymentProcessor,
+        connector_name,
+        connector_label: Some(format!("{connector_name}_demo")),
+        profile_id,
+        connector_account_details: Some(Secret::new(connector_account_details)),
+        payment_methods_enabled: Some(demo_connector.payment_methods_enabled.clone()),
+        connector_webhook_details: None,
+        metadata: None,
+        test_mode: Some(true),
+        disabled: None,
+        frm_configs: None,
+        business_country: None,
+        business_label: None,
+        business_sub_label: None,
+        merchant_connector_id: None,
+        pm_auth_config: None,
+        status: None,
+        additional_merchant_data: None,
+        connector_wallets_details: None,
+    })
+}
+
+/// Opts the merchant account into the demo connectors, creating a connector account with the
+/// credentials of each of the demo connectors. Opting in again creates the accounts of the demo
+/// connectors added since.
+#[instrument(skip_all)]
+pub async fn opt_into_demo_connectors(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: sandbox_data_types::DemoConnectorsRequest,
+) -> RouterResponse<sandbox_data_types::DemoConnectorsResponse> {
+    validate_sandbox_environment()?;
+    let demo_connectors = &state.conf.demo_connectors;
+    fp_utils::when(
+        !demo_connectors.enabled || demo_connectors.connectors.is_empty(),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Demo connectors are not enabled".to_string(),
+            }))
+        },
+    )?;
+
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let mut record = get_merchant_demo_connectors(db, merchant_id).await?;
+
+    let mut connector_names = demo_connectors.connectors.keys().collect::<Vec<_>>();
+    connector_names.sort();
+    for connector_name in connector_names {
+        if record
+            .connectors
+            .iter()
+            .any(|connector| connector.connector_name == *connector_name)
+        {
+            continue;
+        }
+        let Some(demo_connector) = demo_connectors.connectors.get(connector_name) else {
+            continue;
+        };
+        let connector = connector_name
+            .parse::<enums::Connector>()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!("Demo connector {connector_name} is not a supported connector")
+            })?;
+
+        let connector_create_request =
+            build_connector_create_request(connector, demo_connector, request.profile_id.clone())?;
+        let merchant_connector_account = match admin::create_connector(
+            state.clone(),
+            connector_create_request,
+            merchant_account.clone(),
+            None,
+            key_store.clone(),
+        )
+        .await?
+        {
+            ApplicationResponse::Json(merchant_connector_account) => merchant_connector_account,
+            _ => {
+                return Err(report!(errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable("Unexpected response while creating the demo connector")
+            }
+        };
+
+        // The record is stored after every connector, so that opting in again after a failure
+        // does not create the same connector twice
+        record
+            .connectors
+            .push(sandbox_data_types::DemoConnectorAccount {
+                connector_name: connector_name.clone(),
+                merchant_connector_id: merchant_connector_account.merchant_connector_id,
+            });
+        record.opted_in_at = record.opted_in_at.or(Some(date_time::now()));
+        store_merchant_demo_connectors(db, merchant_id, &record).await?;
+
+        logger::info!(
+            merchant_id = %merchant_id.get_string_repr(),
+            connector = %connector_name,
+            "Created the demo connector account of the merchant account"
+        );
+    }
+
+    Ok(ApplicationResponse::Json(
+        to_demo_connectors_response(&state, merchant_id, record).await,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_demo_connectors(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<sandbox_data_types::DemoConnectorsResponse> {
+    validate_sandbox_environment()?;
+    let merchant_id = merchant_account.get_id();
+    let record = get_merchant_demo_connectors(state.store.as_ref(), merchant_id).await?;
+
+    Ok(ApplicationResponse::Json(
+        to_demo_connectors_response(&state, merchant_id, record).await,
+    ))
+}
+
+/// Enforces the daily quota of payments through the demo connectors on a payment being confirmed.
+/// Payments routed to connector accounts of the merchant account's own are not counted. Failures
+/// in tracking the usage never fail the payment.
+#[instrument(skip_all)]
+pub async fn check_and_record_demo_connector_usage(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector_call_type: &api::ConnectorCallType,
+) -> RouterResult<()> {
+    let demo_connectors = &state.conf.demo_connectors;
+    if !demo_connectors.enabled {
+        return Ok(());
+    }
+
+    let merchant_connector_id = match connector_call_type {
+        api::ConnectorCallType::PreDetermined(connector) => {
+            connector.merchant_connector_id.as_ref()
+        }
+        api::ConnectorCallType::Retryable(connectors) => connectors
+            .first()
+            .and_then(|connector| connector.merchant_connector_id.as_ref()),
+        api::ConnectorCallType::SessionMultiple(_) => None,
+    };
+    let Some(merchant_connector_id) = merchant_connector_id else {
+        return Ok(());
+    };
+
+    let record = get_merchant_demo_connectors(state.store.as_ref(), merchant_id).await?;
+    if !record.is_demo_connector_account(merchant_connector_id) {
+        return Ok(());
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection for demo connectors");
+            return Ok(());
+        }
+    };
+
+    let key = get_demo_connector_payments_key(merchant_id);
+    let payments = match redis_conn
+        .increment_key_with_expiry(&key, DEMO_CONNECTOR_PAYMENTS_TTL_IN_SECS)
+        .await
+    {
+        Ok(payments) => payments,
+        Err(error) => {
+            logger::error!(?error, "Failed to record the demo connector payment");
+            return Ok(());
+        }
+    };
+
+    let quota = i64::try_from(demo_connectors.daily_payment_quota).unwrap_or(i64::MAX);
+    if payments > quota {
+        // Rejected payments do not use up the quota
+        if let Err(error) = redis_conn
+            .increment_key_by_with_expiry(&key, -1, DEMO_CONNECTOR_PAYMENTS_TTL_IN_SECS)
+            .await
+        {
+            logger::error!(?error, "Failed to revert the demo connector payment");
+        }
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The daily quota of {quota} payments through the demo connectors is exhausted, \
+                 configure a connector account of your own to continue"
+            ),
+        }));
+    }
+
+    Ok(())
+}
@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
 
 use api_models::{
     admin::MerchantConnectorInfo, disputes as dispute_models, files as files_api_models,
@@ -7,6 +10,8 @@ use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
 use router_env::{instrument, tracing};
 use strum::IntoEnumIterator;
+#[cfg(feature = "v1")]
+pub mod chargebacks;
 pub mod transformers;
 
 use super::{
@@ -42,7 +47,14 @@ pub async fn retrieve_dispute(
             dispute_id: req.dispute_id,
         })?;
     core_utils::validate_profile_id_from_auth_layer(profile_id, &dispute)?;
-    let dispute_response = api_models::disputes::DisputeResponse::foreign_from(dispute);
+    #[cfg(feature = "v1")]
+    let financial_impact = chargebacks::get_dispute_financial_impact(&state, &dispute).await?;
+    #[cfg(feature = "v2")]
+    let financial_impact = None;
+    let dispute_response = api_models::disputes::DisputeResponse {
+        financial_impact,
+        ..api_models::disputes::DisputeResponse::foreign_from(dispute)
+    };
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
 
@@ -287,6 +299,10 @@ pub async fn submit_evidence(
             })
         },
     )?;
+    let submitted_evidence_types = transformers::get_submitted_evidence_types(&req)
+        .into_iter()
+        .map(|evidence_type| evidence_type.to_string())
+        .collect();
     let submit_evidence_request_data = transformers::get_evidence_request_data(
         &state,
         &merchant_account,
@@ -405,9 +421,10 @@ pub async fn submit_evidence(
             submit_evidence_response.connector_status,
         )
     };
-    let update_dispute = diesel_models::dispute::DisputeUpdate::StatusUpdate {
+    let update_dispute = diesel_models::dispute::DisputeUpdate::EvidenceSubmittedUpdate {
         dispute_status,
         connector_status,
+        submitted_evidence_types,
     };
     let updated_dispute = db
         .update_dispute(dispute.clone(), update_dispute)
@@ -594,3 +611,132 @@ pub async fn get_aggregates_for_disputes(
         },
     ))
 }
+
+#[derive(Clone, Copy, Default)]
+struct DisputeOutcomeCount {
+    won: u32,
+    lost: u32,
+}
+
+impl DisputeOutcomeCount {
+    fn add(&mut self, dispute_status: storage_enums::DisputeStatus) {
+        match dispute_status {
+            storage_enums::DisputeStatus::DisputeWon => self.won = self.won.saturating_add(1),
+            storage_enums::DisputeStatus::DisputeLost => self.lost = self.lost.saturating_add(1),
+            _ => {}
+        }
+    }
+
+    fn win_rate(&self) -> f64 {
+        let total = self.won.saturating_add(self.lost);
+        if total == 0 {
+            0.0
+        } else {
+            f64::from(self.won) / f64::from(total)
+        }
+    }
+}
+
+/// Computes the win rates of the resolved disputes for which evidence was submitted, for each
+/// connector and reason code, and for each category of evidence submitted. The effectiveness of a
+/// category of evidence is its win rate relative to the win rate of all the disputes for the
+/// connector and reason code.
+#[instrument(skip(state))]
+pub async fn get_evidence_effectiveness_for_disputes(
+    state: SessionState,
+    merchant: domain::MerchantAccount,
+    profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+    time_range: common_utils::types::TimeRange,
+) -> RouterResponse<dispute_models::DisputeEvidenceEffectivenessResponse> {
+    let dispute_list_constraints = hyperswitch_domain_models::disputes::DisputeListConstraints {
+        dispute_id: None,
+        payment_id: None,
+        limit: None,
+        offset: None,
+        profile_id: profile_id_list,
+        dispute_status: Some(vec![
+            storage_enums::DisputeStatus::DisputeWon,
+            storage_enums::DisputeStatus::DisputeLost,
+        ]),
+        dispute_stage: None,
+        reason: None,
+        connector: None,
+        merchant_connector_id: None,
+        currency: None,
+        time_range: Some(time_range),
+    };
+    let disputes = state
+        .store
+        .find_disputes_by_constraints(merchant.get_id(), &dispute_list_constraints)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve resolved disputes")?;
+
+    let mut outcomes: BTreeMap<(String, Option<String>), DisputeOutcomeCount> = BTreeMap::new();
+    let mut evidence_outcomes: BTreeMap<
+        (String, Option<String>, dispute_models::EvidenceType),
+        DisputeOutcomeCount,
+    > = BTreeMap::new();
+    for dispute in disputes {
+        let Some(submitted_evidence_types) = dispute.submitted_evidence_types else {
+            continue;
+        };
+        for evidence_type in submitted_evidence_types
+            .iter()
+            .filter_map(|evidence_type| dispute_models::EvidenceType::from_str(evidence_type).ok())
+        {
+            evidence_outcomes
+                .entry((
+                    dispute.connector.clone(),
+                    dispute.connector_reason_code.clone(),
+                    evidence_type,
+                ))
+                .or_default()
+                .add(dispute.dispute_status);
+        }
+        outcomes
+            .entry((dispute.connector, dispute.connector_reason_code))
+            .or_default()
+            .add(dispute.dispute_status);
+    }
+
+    let evidence_effectiveness = evidence_outcomes
+        .into_iter()
+        .map(
+            |((connector, connector_reason_code, evidence_type), count)| {
+                let overall_win_rate = outcomes
+                    .get(&(connector.clone(), connector_reason_code.clone()))
+                    .map(DisputeOutcomeCount::win_rate)
+                    .unwrap_or_default();
+                dispute_models::EvidenceEffectiveness {
+                    connector,
+                    connector_reason_code,
+                    evidence_type,
+                    disputes_won: count.won,
+                    disputes_lost: count.lost,
+                    win_rate: count.win_rate(),
+                    effectiveness_score: count.win_rate() - overall_win_rate,
+                }
+            },
+        )
+        .collect();
+    let outcomes = outcomes
+        .into_iter()
+        .map(
+            |((connector, connector_reason_code), count)| dispute_models::DisputeOutcomeSummary {
+                connector,
+                connector_reason_code,
+                disputes_won: count.won,
+                disputes_lost: count.lost,
+                win_rate: count.win_rate(),
+            },
+        )
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::DisputeEvidenceEffectivenessResponse {
+            outcomes,
+            evidence_effectiveness,
+        },
+    ))
+}
@@ -0,0 +1,263 @@
+use std::str::FromStr;
+
+use api_models::disputes as dispute_models;
+use common_utils::types::MinorUnit;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult},
+        webhooks,
+    },
+    routes::SessionState,
+    types::{
+        api, domain,
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+    utils::generate_id,
+};
+
+const REFUND_REVERSAL_ERROR_CODE: &str = "CHARGEBACK_LOST";
+const REFUND_REVERSAL_ERROR_MESSAGE: &str = "Refund reversed as the payment was charged back";
+
+/// Builds the financial impact of a lost dispute from its ledger entries. Returns `None` if the
+/// debit of the dispute has not been recorded yet.
+fn get_financial_impact_from_ledger_entries(
+    ledger_entries: &[storage::LedgerEntry],
+) -> Option<dispute_models::DisputeFinancialImpact> {
+    let debit = ledger_entries
+        .iter()
+        .find(|entry| entry.entry_type == enums::LedgerEntryType::ChargebackDebit)?;
+    let fee = ledger_entries
+        .iter()
+        .find(|entry| entry.entry_type == enums::LedgerEntryType::ChargebackFee);
+
+    Some(dispute_models::DisputeFinancialImpact {
+        debited_amount: MinorUnit::new(0) - debit.amount,
+        currency: debit.currency,
+        fee_amount: fee.map(|fee| MinorUnit::new(0) - fee.amount),
+        fee_currency: fee.map(|fee| fee.currency),
+        reversed_refund_ids: ledger_entries
+            .iter()
+            .filter(|entry| entry.entry_type == enums::LedgerEntryType::RefundReversal)
+            .filter_map(|entry| entry.refund_id.clone())
+            .collect(),
+        refunds_blocked: true,
+    })
+}
+
+fn get_ledger_entry(
+    dispute: &storage::Dispute,
+    refund_id: Option<String>,
+    entry_type: enums::LedgerEntryType,
+    amount: MinorUnit,
+    currency: enums::Currency,
+) -> storage::LedgerEntryNew {
+    storage::LedgerEntryNew {
+        entry_id: generate_id(consts::ID_LENGTH, "le"),
+        merchant_id: dispute.merchant_id.clone(),
+        profile_id: dispute.profile_id.clone(),
+        payment_id: dispute.payment_id.clone(),
+        dispute_id: Some(dispute.dispute_id.clone()),
+        refund_id,
+        entry_type,
+        amount,
+        currency,
+        created_at: common_utils::date_time::now(),
+    }
+}
+
+fn get_refund_reversal_update(
+    storage_scheme: enums::MerchantStorageScheme,
+) -> storage::RefundUpdate {
+    storage::RefundUpdate::ErrorUpdate {
+        refund_status: Some(enums::RefundStatus::Failure),
+        refund_error_message: Some(REFUND_REVERSAL_ERROR_MESSAGE.to_string()),
+        refund_error_code: Some(REFUND_REVERSAL_ERROR_CODE.to_string()),
+        updated_by: storage_scheme.to_string(),
+        connector_refund_id: None,
+        connector_refund_data: None,
+    }
+}
+
+/// Retrieves the financial impact recorded for a lost dispute
+#[instrument(skip_all)]
+pub async fn get_dispute_financial_impact(
+    state: &SessionState,
+    dispute: &storage::Dispute,
+) -> RouterResult<Option<dispute_models::DisputeFinancialImpact>> {
+    if dispute.dispute_status != enums::DisputeStatus::DisputeLost {
+        return Ok(None);
+    }
+    let ledger_entries = state
+        .store
+        .find_ledger_entries_by_merchant_id_dispute_id(&dispute.merchant_id, &dispute.dispute_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the ledger entries of the dispute")?;
+    Ok(get_financial_impact_from_ledger_entries(&ledger_entries))
+}
+
+/// Records the debit and the fee of a lost dispute in the ledger, and reverses the refunds of the
+/// payment which are yet to be sent to the connector, up to the disputed amount. Since a dispute
+/// may be marked as lost more than once, the ledger entries of the dispute are recorded only once.
+#[instrument(skip_all)]
+pub async fn record_lost_dispute(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    dispute: &storage::Dispute,
+) -> RouterResult<dispute_models::DisputeFinancialImpact> {
+    let db = &*state.store;
+    let ledger_entries = db
+        .find_ledger_entries_by_merchant_id_dispute_id(&dispute.merchant_id, &dispute.dispute_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the ledger entries of the dispute")?;
+    if let Some(financial_impact) = get_financial_impact_from_ledger_entries(&ledger_entries) {
+        return Ok(financial_impact);
+    }
+
+    let currency = enums::Currency::from_str(&dispute.currency)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Invalid dispute currency: {}", dispute.currency))?;
+    let disputed_amount = MinorUnit::new(dispute.dispute_amount);
+
+    let mut new_ledger_entries = vec![get_ledger_entry(
+        dispute,
+        None,
+        enums::LedgerEntryType::ChargebackDebit,
+        MinorUnit::new(0) - disputed_amount,
+        currency,
+    )];
+    if let Some(fee) = state.conf.chargeback_fees.get_fee(&dispute.connector) {
+        new_ledger_entries.push(get_ledger_entry(
+            dispute,
+            None,
+            enums::LedgerEntryType::ChargebackFee,
+            MinorUnit::new(0) - fee.amount,
+            fee.currency,
+        ));
+    }
+
+    let mut pending_refunds = db
+        .find_refund_by_payment_id_merchant_id(
+            &dispute.payment_id,
+            &dispute.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds of the disputed payment")?
+        .into_iter()
+        .filter(|refund| {
+            refund.refund_status == enums::RefundStatus::Pending && !refund.sent_to_gateway
+        })
+        .collect::<Vec<_>>();
+    pending_refunds.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+
+    let mut reversed_amount = MinorUnit::new(0);
+    let mut refund_reversals = Vec::new();
+    for refund in pending_refunds {
+        if reversed_amount + refund.refund_amount > disputed_amount {
+            continue;
+        }
+        reversed_amount = reversed_amount + refund.refund_amount;
+        refund_reversals.push(storage::RefundReversal {
+            ledger_entry: get_ledger_entry(
+                dispute,
+                Some(refund.refund_id.clone()),
+                enums::LedgerEntryType::RefundReversal,
+                refund.refund_amount,
+                refund.currency,
+            ),
+            refund_update: get_refund_reversal_update(merchant_account.storage_scheme),
+            refund,
+        });
+    }
+
+    // The refunds are reversed and the ledger entries recorded in a single transaction. The
+    // ledger entries of a dispute are unique, so that a dispute concurrently marked as lost is
+    // recorded only once.
+    let (recorded_ledger_entries, reversed_refunds) = match db
+        .insert_ledger_entries_with_refund_reversals(new_ledger_entries, refund_reversals)
+        .await
+    {
+        Ok(recorded) => recorded,
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::info!("The lost dispute was recorded concurrently");
+            return get_dispute_financial_impact(state, dispute)
+                .await?
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Debit of the lost dispute was not recorded");
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to record the ledger entries of the dispute"))
+        }
+    };
+
+    // The refunds of merchants with key-value storage are also cached, which has to reflect their
+    // reversal
+    let reversed_refunds =
+        if merchant_account.storage_scheme == enums::MerchantStorageScheme::RedisKv {
+            let mut cached_refunds = Vec::with_capacity(reversed_refunds.len());
+            for reversed_refund in reversed_refunds {
+                let refund_id = reversed_refund.refund_id.clone();
+                cached_refunds.push(
+                    db.update_refund(
+                        reversed_refund,
+                        get_refund_reversal_update(merchant_account.storage_scheme),
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable_lazy(|| {
+                        format!("Failed to update the reversed refund: {refund_id}")
+                    })?,
+                );
+            }
+            cached_refunds
+        } else {
+            reversed_refunds
+        };
+
+    for reversed_refund in reversed_refunds {
+        let event_type: Option<enums::EventType> = reversed_refund.refund_status.foreign_into();
+        if let Some(outgoing_event_type) = event_type {
+            let refund_id = reversed_refund.refund_id.clone();
+            let created_at = reversed_refund.created_at;
+            let refund_response: api_models::refunds::RefundResponse =
+                reversed_refund.foreign_into();
+            Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+                state.clone(),
+                merchant_account.clone(),
+                business_profile.clone(),
+                key_store,
+                outgoing_event_type,
+                enums::EventClass::Refunds,
+                refund_id.clone(),
+                enums::EventObjectType::RefundDetails,
+                api::OutgoingWebhookContent::RefundDetails(Box::new(refund_response)),
+                Some(created_at),
+            ))
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to trigger the webhook of the reversed refund: {refund_id}"
+                );
+            })
+            .ok();
+        }
+    }
+
+    get_financial_impact_from_ledger_entries(&recorded_ledger_entries)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Debit of the lost dispute was not recorded")
+}
@@ -159,6 +159,50 @@ pub async fn get_evidence_request_data(
     })
 }
 
+pub fn get_submitted_evidence_types(
+    evidence_request: &api_models::disputes::SubmitEvidenceRequest,
+) -> Vec<EvidenceType> {
+    [
+        (
+            &evidence_request.cancellation_policy,
+            EvidenceType::CancellationPolicy,
+        ),
+        (
+            &evidence_request.customer_communication,
+            EvidenceType::CustomerCommunication,
+        ),
+        (
+            &evidence_request.customer_signature,
+            EvidenceType::CustomerSignature,
+        ),
+        (&evidence_request.receipt, EvidenceType::Receipt),
+        (&evidence_request.refund_policy, EvidenceType::RefundPolicy),
+        (
+            &evidence_request.service_documentation,
+            EvidenceType::ServiceDocumentation,
+        ),
+        (
+            &evidence_request.shipping_documentation,
+            EvidenceType::ShippingDocumentation,
+        ),
+        (
+            &evidence_request.invoice_showing_distinct_transactions,
+            EvidenceType::InvoiceShowingDistinctTransactions,
+        ),
+        (
+            &evidence_request.recurring_transaction_agreement,
+            EvidenceType::RecurringTransactionAgreement,
+        ),
+        (
+            &evidence_request.uncategorized_file,
+            EvidenceType::UncategorizedFile,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(file_id, evidence_type)| file_id.as_ref().map(|_| evidence_type))
+    .collect()
+}
+
 pub fn update_dispute_evidence(
     dispute_evidence: DisputeEvidence,
     evidence_type: api::EvidenceType,
@@ -57,6 +57,7 @@ impl<T> StorageErrorExt<T, errors::ApiErrorResponse>
                 hyperswitch_domain_models::errors::StorageError::CustomerRedacted => {
                     errors::ApiErrorResponse::CustomerRedacted
                 }
+                error if error.is_db_version_conflict() => errors::ApiErrorResponse::ResourceBusy,
                 _ => errors::ApiErrorResponse::InternalServerError,
             };
             err.change_context(new_err)
@@ -0,0 +1,1009 @@
+use api_models::{
+    conditional_configs as conditional_config_types, enums as api_enums,
+    experiments as experiment_types,
+    routing::{self as routing_types, RoutableConnectorChoice},
+};
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    date_time,
+    ext_traits::{Encode, StringExt},
+    generate_id, id_type,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        conditional_config,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        routing,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage, storage::enums as storage_enums},
+};
+
+const EXPERIMENT_NAME_MAX_LENGTH: usize = 64;
+const DEFAULT_TRAFFIC_PERCENTAGE: u8 = 100;
+const DEFAULT_TREATMENT_PERCENTAGE: u8 = 50;
+const DEFAULT_MIN_SAMPLE_SIZE: u32 = 100;
+/// Significance level of the two-proportion z-test the arms are compared with
+const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+/// An experiment of a business profile, the experiments of a profile are stored together in the
+/// configs table
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct RoutingExperiment {
+    experiment_id: String,
+    name: String,
+    description: Option<String>,
+    status: experiment_types::ExperimentStatus,
+    variants: experiment_types::ExperimentVariants,
+    traffic_percentage: u8,
+    treatment_percentage: u8,
+    payment_methods: Option<Vec<api_enums::PaymentMethod>>,
+    currencies: Option<Vec<api_enums::Currency>>,
+    min_sample_size: u32,
+    promoted_arm: Option<experiment_types::ExperimentArm>,
+    promoted_routing_algorithm_id: Option<id_type::RoutingId>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: time::PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    ended_at: Option<time::PrimitiveDateTime>,
+}
+
+impl RoutingExperiment {
+    fn is_eligible(
+        &self,
+        payment_method: Option<api_enums::PaymentMethod>,
+        currency: Option<api_enums::Currency>,
+    ) -> bool {
+        let is_payment_method_eligible = self.payment_methods.as_ref().map_or(true, |methods| {
+            payment_method.is_some_and(|payment_method| methods.contains(&payment_method))
+        });
+        let is_currency_eligible = self.currencies.as_ref().map_or(true, |currencies| {
+            currency.is_some_and(|currency| currencies.contains(&currency))
+        });
+        is_payment_method_eligible && is_currency_eligible
+    }
+
+    /// Assigns the payment to an arm of the experiment, or none when the payment falls outside
+    /// the enrolled share of the traffic. The assignment only depends on the payment, so that all
+    /// the attempts of a payment are assigned to the same arm.
+    fn assign_arm(
+        &self,
+        payment_id: &id_type::PaymentId,
+    ) -> Option<experiment_types::ExperimentArm> {
+        let is_enrolled =
+            get_bucket(&self.experiment_id, "traffic", payment_id) < self.traffic_percentage;
+        is_enrolled.then(|| {
+            if get_bucket(&self.experiment_id, "arm", payment_id) < self.treatment_percentage {
+                experiment_types::ExperimentArm::Treatment
+            } else {
+                experiment_types::ExperimentArm::Control
+            }
+        })
+    }
+
+    fn to_response(
+        self,
+        profile_id: id_type::ProfileId,
+        results: Option<experiment_types::ExperimentResults>,
+    ) -> experiment_types::ExperimentResponse {
+        experiment_types::ExperimentResponse {
+            experiment_id: self.experiment_id,
+            profile_id,
+            name: self.name,
+            description: self.description,
+            status: self.status,
+            variants: self.variants,
+            traffic_percentage: self.traffic_percentage,
+            treatment_percentage: self.treatment_percentage,
+            payment_methods: self.payment_methods,
+            currencies: self.currencies,
+            min_sample_size: self.min_sample_size,
+            promoted_arm: self.promoted_arm,
+            promoted_routing_algorithm_id: self.promoted_routing_algorithm_id,
+            results,
+            created_at: self.created_at,
+            ended_at: self.ended_at,
+        }
+    }
+}
+
+fn get_config_key(profile_id: &id_type::ProfileId) -> String {
+    format!("routing_experiments_{}", profile_id.get_string_repr())
+}
+
+/// A bucket between 0 and 99 derived from the payment, salted so that the enrollment and the arm
+/// of a payment are independent of each other
+fn get_bucket(experiment_id: &str, salt: &str, payment_id: &id_type::PaymentId) -> u8 {
+    let message = format!("{experiment_id}:{salt}:{}", payment_id.get_string_repr());
+    let hash = crypto::Sha256
+        .generate_digest(message.as_bytes())
+        .map(|digest| {
+            digest
+                .iter()
+                .take(8)
+                .fold(0u64, |hash, byte| (hash << 8) | u64::from(*byte))
+        })
+        .unwrap_or_default();
+    u8::try_from(hash % 100).unwrap_or_default()
+}
+
+fn is_same_connector(
+    experiment_connector: &RoutableConnectorChoice,
+    connector: &RoutableConnectorChoice,
+) -> bool {
+    experiment_connector.connector == connector.connector
+        && experiment_connector.merchant_connector_id.as_ref().map_or(
+            true,
+            |merchant_connector_id| {
+                connector.merchant_connector_id.as_ref() == Some(merchant_connector_id)
+            },
+        )
+}
+
+async fn find_experiments(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Vec<RoutingExperiment>> {
+    // Most profiles have no experiments, the absence of experiments is cached as well so that
+    // routing doesn't hit the database for every payment
+    db.find_config_by_key_unwrap_or(&get_config_key(profile_id), Some("[]".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the experiments of the profile")?
+        .config
+        .parse_struct("Vec<RoutingExperiment>")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the experiments of the profile")
+}
+
+async fn store_experiments(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    experiments: &[RoutingExperiment],
+) -> RouterResult<()> {
+    let key = get_config_key(profile_id);
+    let config = experiments
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the experiments of the profile")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the experiments of the profile")
+}
+
+/// The outcome of applying the running experiments of the profile to a payment attempt
+#[derive(Debug, Default)]
+pub struct ExperimentDecision {
+    /// The connectors reordered for the arm of the connector experiment, if any
+    pub connectors: Vec<RoutableConnectorChoice>,
+    /// The authentication type of the arm of the 3DS strategy experiment, if any
+    pub authentication_type: Option<storage_enums::AuthenticationType>,
+    pub assignments: Vec<experiment_types::ExperimentAssignment>,
+}
+
+/// Assigns the payment attempt to an arm of the running experiments it is eligible for. A
+/// connector experiment only applies when both of its connectors were routed to, the connector of
+/// the arm is then tried first. A 3DS strategy experiment only applies to card payments for which
+/// 3DS was not already required by the request or the decision manager, so that an experiment never
+/// waives a required 3DS. Mandate payments are left out of connector experiments, as they may be
+/// tied to a connector. A payment is only counted once per experiment, however many times it is
+/// routed.
+#[instrument(skip_all)]
+pub async fn apply_experiments(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    payment_attempt: &storage::PaymentAttempt,
+    connectors: Vec<RoutableConnectorChoice>,
+    is_mandate_payment: bool,
+) -> ExperimentDecision {
+    let mut decision = ExperimentDecision {
+        connectors,
+        ..Default::default()
+    };
+    let experiments = match find_experiments(&*state.store, profile_id).await {
+        Ok(experiments) => experiments,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the experiments, skipping them");
+            return decision;
+        }
+    };
+
+    for experiment in experiments.iter().filter(|experiment| {
+        experiment.status == experiment_types::ExperimentStatus::Running
+            && experiment.is_eligible(payment_attempt.payment_method, payment_attempt.currency)
+    }) {
+        let arm = match &experiment.variants {
+            experiment_types::ExperimentVariants::Connector { control, treatment } => {
+                let is_routed = |experiment_connector: &RoutableConnectorChoice| {
+                    decision
+                        .connectors
+                        .iter()
+                        .any(|connector| is_same_connector(experiment_connector, connector))
+                };
+                if is_mandate_payment || !is_routed(control) || !is_routed(treatment) {
+                    continue;
+                }
+                let Some(arm) = experiment.assign_arm(&payment_attempt.payment_id) else {
+                    continue;
+                };
+                let arm_connector = match arm {
+                    experiment_types::ExperimentArm::Control => control,
+                    experiment_types::ExperimentArm::Treatment => treatment,
+                };
+                let (mut reordered_connectors, other_connectors): (Vec<_>, Vec<_>) = decision
+                    .connectors
+                    .into_iter()
+                    .partition(|connector| is_same_connector(arm_connector, connector));
+                reordered_connectors.extend(other_connectors);
+                decision.connectors = reordered_connectors;
+                arm
+            }
+            experiment_types::ExperimentVariants::ThreeDsStrategy { control, treatment } => {
+                if payment_attempt.payment_method != Some(api_enums::PaymentMethod::Card)
+                    || payment_attempt.authentication_type
+                        == Some(storage_enums::AuthenticationType::ThreeDs)
+                {
+                    continue;
+                }
+                let Some(arm) = experiment.assign_arm(&payment_attempt.payment_id) else {
+                    continue;
+                };
+                decision.authentication_type = Some(match arm {
+                    experiment_types::ExperimentArm::Control => *control,
+                    experiment_types::ExperimentArm::Treatment => *treatment,
+                });
+                arm
+            }
+        };
+
+        if let Err(error) = state
+            .store
+            .insert_routing_experiment_assignment_if_not_exists(
+                storage::RoutingExperimentAssignmentNew {
+                    experiment_id: experiment.experiment_id.clone(),
+                    payment_id: payment_attempt.payment_id.clone(),
+                    profile_id: profile_id.clone(),
+                    arm: arm.to_string(),
+                    assigned_at: date_time::now(),
+                },
+            )
+            .await
+        {
+            logger::error!(?error, "Failed to record the experiment assignment");
+        }
+        decision
+            .assignments
+            .push(experiment_types::ExperimentAssignment {
+                experiment_id: experiment.experiment_id.clone(),
+                arm,
+            });
+    }
+
+    decision
+}
+
+/// Records whether the payment succeeded in the assignments of the payment to the experiments,
+/// once the payment attempt has reached a successful or failed status
+#[instrument(skip_all)]
+pub async fn record_experiment_outcome(
+    state: SessionState,
+    payment_attempt: storage::PaymentAttempt,
+) {
+    let succeeded = match payment_attempt.status {
+        storage_enums::AttemptStatus::Authorized
+        | storage_enums::AttemptStatus::Charged
+        | storage_enums::AttemptStatus::PartialCharged
+        | storage_enums::AttemptStatus::PartialChargedAndChargeable
+        | storage_enums::AttemptStatus::CaptureInitiated => true,
+        storage_enums::AttemptStatus::AuthenticationFailed
+        | storage_enums::AttemptStatus::AuthorizationFailed
+        | storage_enums::AttemptStatus::RouterDeclined
+        | storage_enums::AttemptStatus::Failure => false,
+        _ => return,
+    };
+    let assignments = payment_attempt
+        .routing_decision
+        .clone()
+        .and_then(|routing_decision| {
+            serde_json::from_value::<routing_types::RoutingDecisionTrace>(routing_decision).ok()
+        })
+        .map(|routing_decision| routing_decision.experiments)
+        .unwrap_or_default();
+    for assignment in assignments {
+        if let Err(error) = state
+            .store
+            .update_routing_experiment_assignment_outcome(
+                &assignment.experiment_id,
+                &payment_attempt.payment_id,
+                succeeded,
+                date_time::now(),
+            )
+            .await
+        {
+            logger::error!(?error, "Failed to record the experiment outcome");
+        }
+    }
+}
+
+fn get_arm_metrics(
+    counts: &[storage::RoutingExperimentArmCount],
+    arm: experiment_types::ExperimentArm,
+) -> experiment_types::ExperimentArmMetrics {
+    let arm_name = arm.to_string();
+    let get_count = |is_counted: fn(Option<bool>) -> bool| {
+        counts
+            .iter()
+            .filter(|count| count.arm == arm_name && is_counted(count.succeeded))
+            .filter_map(|count| u64::try_from(count.count).ok())
+            .sum::<u64>()
+    };
+    let succeeded = get_count(|succeeded| succeeded == Some(true));
+    let failed = get_count(|succeeded| succeeded == Some(false));
+
+    experiment_types::ExperimentArmMetrics {
+        arm,
+        assigned: get_count(|_| true),
+        succeeded,
+        failed,
+        conversion_rate: get_conversion_rate(succeeded, failed),
+    }
+}
+
+fn to_f64(count: u64) -> f64 {
+    f64::from(u32::try_from(count).unwrap_or(u32::MAX))
+}
+
+fn get_conversion_rate(succeeded: u64, failed: u64) -> Option<f64> {
+    let completed = succeeded.saturating_add(failed);
+    (completed > 0).then(|| to_f64(succeeded) / to_f64(completed))
+}
+
+/// Complementary error function, with a fractional error below 1.2e-7 (Numerical Recipes)
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let polynomial = [
+        -1.265_512_23,
+        1.000_023_68,
+        0.374_091_96,
+        0.096_784_18,
+        -0.186_288_06,
+        0.278_868_07,
+        -1.135_203_98,
+        1.488_515_87,
+        -0.822_152_23,
+        0.170_872_77,
+    ]
+    .iter()
+    .rev()
+    .fold(0.0, |sum, coefficient| coefficient + t * sum);
+    let result = t * (-z * z + polynomial).exp();
+    if x >= 0.0 {
+        result
+    } else {
+        2.0 - result
+    }
+}
+
+/// Compares the conversion rates of the arms with a two-proportion z-test. The test is only run
+/// once both arms have reached the minimum sample size, to avoid concluding on early noise.
+fn get_experiment_results(
+    control: experiment_types::ExperimentArmMetrics,
+    treatment: experiment_types::ExperimentArmMetrics,
+    min_sample_size: u32,
+) -> experiment_types::ExperimentResults {
+    let control_completed = control.succeeded.saturating_add(control.failed);
+    let treatment_completed = treatment.succeeded.saturating_add(treatment.failed);
+    let uplift = control
+        .conversion_rate
+        .zip(treatment.conversion_rate)
+        .map(|(control_rate, treatment_rate)| treatment_rate - control_rate);
+
+    let has_min_sample_size = control_completed >= u64::from(min_sample_size)
+        && treatment_completed >= u64::from(min_sample_size);
+    let z_score = uplift.filter(|_| has_min_sample_size).and_then(|uplift| {
+        let pooled_rate = get_conversion_rate(
+            control.succeeded.saturating_add(treatment.succeeded),
+            control.failed.saturating_add(treatment.failed),
+        )?;
+        let standard_error = (pooled_rate
+            * (1.0 - pooled_rate)
+            * (1.0 / to_f64(control_completed) + 1.0 / to_f64(treatment_completed)))
+        .sqrt();
+        (standard_error > 0.0).then(|| uplift / standard_error)
+    });
+    let p_value = z_score.map(|z_score| erfc(z_score.abs() / std::f64::consts::SQRT_2));
+    let is_significant = p_value.is_some_and(|p_value| p_value < SIGNIFICANCE_LEVEL);
+    let winning_arm = z_score.filter(|_| is_significant).map(|z_score| {
+        if z_score > 0.0 {
+            experiment_types::ExperimentArm::Treatment
+        } else {
+            experiment_types::ExperimentArm::Control
+        }
+    });
+
+    experiment_types::ExperimentResults {
+        control,
+        treatment,
+        uplift,
+        z_score,
+        p_value,
+        is_significant,
+        winning_arm,
+    }
+}
+
+async fn get_results(
+    state: &SessionState,
+    experiment: &RoutingExperiment,
+) -> RouterResult<experiment_types::ExperimentResults> {
+    let counts = state
+        .store
+        .count_routing_experiment_assignments(&experiment.experiment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the assignments of the experiment")?;
+
+    Ok(get_experiment_results(
+        get_arm_metrics(&counts, experiment_types::ExperimentArm::Control),
+        get_arm_metrics(&counts, experiment_types::ExperimentArm::Treatment),
+        experiment.min_sample_size,
+    ))
+}
+
+async fn validate_business_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<domain::Profile> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+}
+
+fn invalid_request_data(
+    message: impl Into<String>,
+) -> error_stack::Report<errors::ApiErrorResponse> {
+    report!(errors::ApiErrorResponse::InvalidRequestData {
+        message: message.into(),
+    })
+}
+
+/// Resolves the connector of an arm to an enabled connector account of the profile, so that the
+/// experiment keeps targeting the same account when more accounts of the connector are added
+fn resolve_experiment_connector(
+    merchant_connector_accounts: &[domain::MerchantConnectorAccount],
+    profile_id: &id_type::ProfileId,
+    connector: RoutableConnectorChoice,
+) -> RouterResult<RoutableConnectorChoice> {
+    let merchant_connector_account = merchant_connector_accounts
+        .iter()
+        .find(|merchant_connector_account| {
+            &merchant_connector_account.profile_id == profile_id
+                && merchant_connector_account.connector_name == connector.connector.to_string()
+                && connector
+                    .merchant_connector_id
+                    .as_ref()
+                    .map_or(true, |merchant_connector_id| {
+                        &merchant_connector_account.merchant_connector_id == merchant_connector_id
+                    })
+        })
+        .ok_or_else(|| {
+            invalid_request_data(format!(
+                "No enabled connector account of {} found in the profile",
+                connector.connector
+            ))
+        })?;
+
+    Ok(RoutableConnectorChoice {
+        merchant_connector_id: Some(merchant_connector_account.merchant_connector_id.clone()),
+        ..connector
+    })
+}
+
+async fn validate_variants(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    variants: experiment_types::ExperimentVariants,
+) -> RouterResult<experiment_types::ExperimentVariants> {
+    match variants {
+        experiment_types::ExperimentVariants::Connector { control, treatment } => {
+            let merchant_connector_accounts = state
+                .store
+                .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+                    &state.into(),
+                    merchant_account.get_id(),
+                    false,
+                    key_store,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the connector accounts of the merchant")?;
+            let control =
+                resolve_experiment_connector(&merchant_connector_accounts, profile_id, control)?;
+            let treatment =
+                resolve_experiment_connector(&merchant_connector_accounts, profile_id, treatment)?;
+            if control == treatment {
+                return Err(invalid_request_data(
+                    "The arms of the experiment must target different connector accounts",
+                ));
+            }
+            Ok(experiment_types::ExperimentVariants::Connector { control, treatment })
+        }
+        experiment_types::ExperimentVariants::ThreeDsStrategy { control, treatment } => {
+            if control == treatment {
+                return Err(invalid_request_data(
+                    "The arms of the experiment must use different authentication types",
+                ));
+            }
+            Ok(experiment_types::ExperimentVariants::ThreeDsStrategy { control, treatment })
+        }
+    }
+}
+
+fn is_same_kind(
+    variants: &experiment_types::ExperimentVariants,
+    other: &experiment_types::ExperimentVariants,
+) -> bool {
+    std::mem::discriminant(variants) == std::mem::discriminant(other)
+}
+
+#[instrument(skip_all)]
+pub async fn create_experiment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: experiment_types::ExperimentCreateRequest,
+) -> RouterResponse<experiment_types::ExperimentResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    let name = request.name.trim().to_owned();
+    if name.is_empty() || name.len() > EXPERIMENT_NAME_MAX_LENGTH {
+        return Err(invalid_request_data(format!(
+            "name must be between 1 and {EXPERIMENT_NAME_MAX_LENGTH} characters"
+        )));
+    }
+    let traffic_percentage = request
+        .traffic_percentage
+        .unwrap_or(DEFAULT_TRAFFIC_PERCENTAGE);
+    if !(1..=100).contains(&traffic_percentage) {
+        return Err(invalid_request_data(
+            "traffic_percentage must be between 1 and 100",
+        ));
+    }
+    let treatment_percentage = request
+        .treatment_percentage
+        .unwrap_or(DEFAULT_TREATMENT_PERCENTAGE);
+    if !(1..=99).contains(&treatment_percentage) {
+        return Err(invalid_request_data(
+            "treatment_percentage must be between 1 and 99",
+        ));
+    }
+    let min_sample_size = request.min_sample_size.unwrap_or(DEFAULT_MIN_SAMPLE_SIZE);
+    if min_sample_size == 0 {
+        return Err(invalid_request_data("min_sample_size must be at least 1"));
+    }
+
+    let db = state.store.as_ref();
+    let mut experiments = find_experiments(db, &profile_id).await?;
+    // Two experiments of the same kind would compete for the same payments and skew each other
+    if experiments.iter().any(|experiment| {
+        experiment.status == experiment_types::ExperimentStatus::Running
+            && is_same_kind(&experiment.variants, &request.variants)
+    }) {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "An experiment of the same kind is already running on the profile".to_string(),
+        }));
+    }
+    let variants = validate_variants(
+        &state,
+        &merchant_account,
+        &key_store,
+        &profile_id,
+        request.variants,
+    )
+    .await?;
+
+    let experiment = RoutingExperiment {
+        experiment_id: generate_id(consts::ID_LENGTH, "exp"),
+        name,
+        description: request.description,
+        status: experiment_types::ExperimentStatus::Running,
+        variants,
+        traffic_percentage,
+        treatment_percentage,
+        payment_methods: request.payment_methods,
+        currencies: request.currencies,
+        min_sample_size,
+        promoted_arm: None,
+        promoted_routing_algorithm_id: None,
+        created_at: date_time::now(),
+        ended_at: None,
+    };
+    experiments.push(experiment.clone());
+    store_experiments(db, &profile_id, &experiments).await?;
+
+    logger::info!(
+        experiment_id = %experiment.experiment_id,
+        profile_id = %profile_id.get_string_repr(),
+        "Experiment created"
+    );
+
+    Ok(ApplicationResponse::Json(
+        experiment.to_response(profile_id, None),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_experiments(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<experiment_types::ExperimentListResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    let experiments = find_experiments(state.store.as_ref(), &profile_id)
+        .await?
+        .into_iter()
+        .rev()
+        .map(|experiment| experiment.to_response(profile_id.clone(), None))
+        .collect();
+
+    Ok(ApplicationResponse::Json(
+        experiment_types::ExperimentListResponse { experiments },
+    ))
+}
+
+fn find_experiment<'a>(
+    experiments: &'a mut [RoutingExperiment],
+    experiment_id: &str,
+) -> RouterResult<&'a mut RoutingExperiment> {
+    experiments
+        .iter_mut()
+        .find(|experiment| experiment.experiment_id == experiment_id)
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "Experiment not found".to_string(),
+            })
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_experiment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    experiment_id: String,
+) -> RouterResponse<experiment_types::ExperimentResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    let mut experiments = find_experiments(state.store.as_ref(), &profile_id).await?;
+    let experiment = find_experiment(&mut experiments, &experiment_id)?.clone();
+    let results = get_results(&state, &experiment).await?;
+
+    Ok(ApplicationResponse::Json(
+        experiment.to_response(profile_id, Some(results)),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn stop_experiment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    experiment_id: String,
+) -> RouterResponse<experiment_types::ExperimentResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    let db = state.store.as_ref();
+    let mut experiments = find_experiments(db, &profile_id).await?;
+    let experiment = find_experiment(&mut experiments, &experiment_id)?;
+    if experiment.status != experiment_types::ExperimentStatus::Running {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("The experiment is already {}", experiment.status),
+        }));
+    }
+    experiment.status = experiment_types::ExperimentStatus::Stopped;
+    experiment.ended_at = Some(date_time::now());
+    let experiment = experiment.clone();
+    store_experiments(db, &profile_id, &experiments).await?;
+    let results = get_results(&state, &experiment).await?;
+
+    Ok(ApplicationResponse::Json(
+        experiment.to_response(profile_id, Some(results)),
+    ))
+}
+
+fn get_json_response<T>(response: ApplicationResponse<T>) -> RouterResult<T> {
+    match response {
+        ApplicationResponse::Json(response) => Ok(response),
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response while promoting the experiment"),
+    }
+}
+
+/// Creates a priority routing algorithm trying the connector of the promoted arm first, with the
+/// connector of the other arm as fallback, and activates it on the profile
+async fn promote_connector_arm(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    experiment: &RoutingExperiment,
+    connectors: Vec<RoutableConnectorChoice>,
+) -> RouterResult<id_type::RoutingId> {
+    let routing_algorithm = get_json_response(
+        routing::create_routing_algorithm_under_profile(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            Some(profile_id.clone()),
+            routing_types::RoutingConfigRequest {
+                name: Some(format!("experiment_{}", experiment.name)),
+                description: Some(format!(
+                    "Promoted from the experiment {}",
+                    experiment.experiment_id
+                )),
+                algorithm: Some(routing_types::RoutingAlgorithm::Priority(connectors)),
+                profile_id: Some(profile_id.clone()),
+            },
+            &api_enums::TransactionType::Payment,
+        )
+        .await?,
+    )?;
+    routing::link_routing_config(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        Some(profile_id.clone()),
+        routing_algorithm.id.clone(),
+        &api_enums::TransactionType::Payment,
+    )
+    .await?;
+
+    Ok(routing_algorithm.id)
+}
+
+/// Makes the authentication type of the promoted arm the default of the decision manager of the
+/// merchant, the rules already configured are kept
+async fn promote_three_ds_arm(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    authentication_type: api_enums::AuthenticationType,
+) -> RouterResult<()> {
+    let existing_record = state
+        .store
+        .find_config_by_key(&merchant_account.get_id().get_payment_config_routing_id())
+        .await
+        .ok()
+        .and_then(|config| {
+            config
+                .config
+                .parse_struct::<conditional_config_types::DecisionManagerRecord>(
+                    "DecisionManagerRecord",
+                )
+                .ok()
+        });
+    let default_selection = conditional_config_types::ConditionalConfigs {
+        override_3ds: Some(match authentication_type {
+            api_enums::AuthenticationType::ThreeDs => {
+                conditional_config_types::AuthenticationType::ThreeDs
+            }
+            api_enums::AuthenticationType::NoThreeDs => {
+                conditional_config_types::AuthenticationType::NoThreeDs
+            }
+        }),
+    };
+    let (name, program) = match existing_record {
+        Some(record) => (
+            record.name,
+            routing_types::ast::Program {
+                default_selection,
+                ..record.program
+            },
+        ),
+        None => (
+            "experiment_default_3ds".to_string(),
+            routing_types::ast::Program {
+                default_selection,
+                rules: Vec::new(),
+                metadata: Default::default(),
+            },
+        ),
+    };
+
+    conditional_config::upsert_conditional_config(
+        state.clone(),
+        key_store.clone(),
+        merchant_account.clone(),
+        conditional_config_types::DecisionManager::DecisionManagerv1(
+            conditional_config_types::DecisionManagerRequest {
+                name: Some(name),
+                program: Some(program),
+            },
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Promotes an arm of the experiment to the routing configuration of the profile, ending the
+/// experiment. Without an arm in the request the winning arm is promoted, which requires the
+/// results to be significant.
+#[instrument(skip_all)]
+pub async fn promote_experiment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    experiment_id: String,
+    request: experiment_types::ExperimentPromoteRequest,
+) -> RouterResponse<experiment_types::ExperimentResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    let db = state.store.as_ref();
+    let mut experiments = find_experiments(db, &profile_id).await?;
+    let experiment = find_experiment(&mut experiments, &experiment_id)?.clone();
+    if experiment.status == experiment_types::ExperimentStatus::Promoted {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The experiment is already promoted".to_string(),
+        }));
+    }
+    let results = get_results(&state, &experiment).await?;
+    let arm = request.arm.or(results.winning_arm).ok_or_else(|| {
+        report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The results of the experiment aren't significant yet, the arm to promote must be given".to_string(),
+        })
+    })?;
+
+    let promoted_routing_algorithm_id = match &experiment.variants {
+        experiment_types::ExperimentVariants::Connector { control, treatment } => {
+            let connectors = match arm {
+                experiment_types::ExperimentArm::Control => {
+                    vec![control.clone(), treatment.clone()]
+                }
+                experiment_types::ExperimentArm::Treatment => {
+                    vec![treatment.clone(), control.clone()]
+                }
+            };
+            Some(
+                promote_connector_arm(
+                    &state,
+                    &merchant_account,
+                    &key_store,
+                    &profile_id,
+                    &experiment,
+                    connectors,
+                )
+                .await?,
+            )
+        }
+        experiment_types::ExperimentVariants::ThreeDsStrategy { control, treatment } => {
+            let authentication_type = match arm {
+                experiment_types::ExperimentArm::Control => *control,
+                experiment_types::ExperimentArm::Treatment => *treatment,
+            };
+            promote_three_ds_arm(&state, &merchant_account, &key_store, authentication_type)
+                .await?;
+            None
+        }
+    };
+
+    let stored_experiment = find_experiment(&mut experiments, &experiment_id)?;
+    stored_experiment.status = experiment_types::ExperimentStatus::Promoted;
+    stored_experiment.promoted_arm = Some(arm);
+    stored_experiment.promoted_routing_algorithm_id = promoted_routing_algorithm_id;
+    stored_experiment.ended_at = stored_experiment.ended_at.or(Some(date_time::now()));
+    let experiment = stored_experiment.clone();
+    store_experiments(db, &profile_id, &experiments).await?;
+
+    logger::info!(
+        experiment_id = %experiment.experiment_id,
+        %arm,
+        "Experiment promoted"
+    );
+
+    Ok(ApplicationResponse::Json(
+        experiment.to_response(profile_id, Some(results)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arm_metrics(
+        arm: experiment_types::ExperimentArm,
+        succeeded: u64,
+        failed: u64,
+    ) -> experiment_types::ExperimentArmMetrics {
+        experiment_types::ExperimentArmMetrics {
+            arm,
+            assigned: succeeded + failed,
+            succeeded,
+            failed,
+            conversion_rate: get_conversion_rate(succeeded, failed),
+        }
+    }
+
+    #[test]
+    fn test_erfc() {
+        assert!((erfc(0.0) - 1.0).abs() < 1e-6);
+        assert!((erfc(1.959_964 / std::f64::consts::SQRT_2) - 0.05).abs() < 1e-6);
+        assert!((erfc(-1.0) - 1.842_700_79).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_significant_results() {
+        let results = get_experiment_results(
+            arm_metrics(experiment_types::ExperimentArm::Control, 800, 200),
+            arm_metrics(experiment_types::ExperimentArm::Treatment, 860, 140),
+            100,
+        );
+        assert!(results.is_significant);
+        assert_eq!(
+            results.winning_arm,
+            Some(experiment_types::ExperimentArm::Treatment)
+        );
+    }
+
+    #[test]
+    fn test_results_below_min_sample_size() {
+        let results = get_experiment_results(
+            arm_metrics(experiment_types::ExperimentArm::Control, 10, 40),
+            arm_metrics(experiment_types::ExperimentArm::Treatment, 45, 5),
+            100,
+        );
+        assert!(results.p_value.is_none());
+        assert!(!results.is_significant);
+        assert!(results.winning_arm.is_none());
+    }
+
+    #[test]
+    fn test_bucket_is_stable() {
+        let payment_id = id_type::PaymentId::generate_test_payment_id_for_sample_data();
+        let bucket = get_bucket("exp_test", "arm", &payment_id);
+        assert!(bucket < 100);
+        assert_eq!(bucket, get_bucket("exp_test", "arm", &payment_id));
+    }
+}
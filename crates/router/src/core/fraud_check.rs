@@ -457,6 +457,7 @@ where
         connector_details: frm_connector_details.clone(),
         order_details,
         frm_metadata: payment_data.get_payment_intent().frm_metadata.clone(),
+        risk_signals: payment_data.get_risk_signals().cloned(),
     };
 
     let fraud_check_operation: operation::BoxedFraudCheckOperation<F, D> =
@@ -127,6 +127,7 @@ impl ConstructFlowSpecificData<frm_api::Checkout, FraudCheckCheckoutData, FraudC
                         field_name: "customer.customer_data.email",
                     })?,
                 gateway: self.payment_attempt.connector.clone(),
+                risk_signals: self.risk_signals.clone(),
             }, // self.order_details
             response: Ok(FraudCheckResponseData::TransactionResponse {
                 resource_id: ResponseId::ConnectorTransactionId("".to_string()),
@@ -150,6 +151,7 @@ impl ConstructFlowSpecificData<frm_api::Checkout, FraudCheckCheckoutData, FraudC
             quote_id: None,
             payment_method_balance: None,
             connector_http_status_code: None,
+            connector_request_id: None,
             external_latency: None,
             connector_api_version: None,
             apple_pay_flow: None,
@@ -158,6 +160,7 @@ impl ConstructFlowSpecificData<frm_api::Checkout, FraudCheckCheckoutData, FraudC
             dispute_id: None,
             connector_response: None,
             integrity_check: Ok(()),
+            connector_options: None,
             additional_merchant_data: None,
             header_payload,
             connector_mandate_request_reference_id: None,
@@ -118,6 +118,7 @@ pub async fn construct_fulfillment_router_data<'a>(
         test_mode,
         connector_api_version: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -125,6 +126,7 @@ pub async fn construct_fulfillment_router_data<'a>(
         dispute_id: None,
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -118,6 +118,7 @@ impl ConstructFlowSpecificData<RecordReturn, FraudCheckRecordReturnData, FraudCh
             quote_id: None,
             payment_method_balance: None,
             connector_http_status_code: None,
+            connector_request_id: None,
             external_latency: None,
             connector_api_version: None,
             apple_pay_flow: None,
@@ -126,6 +127,7 @@ impl ConstructFlowSpecificData<RecordReturn, FraudCheckRecordReturnData, FraudCh
             dispute_id: None,
             connector_response: None,
             integrity_check: Ok(()),
+            connector_options: None,
             additional_merchant_data: None,
             header_payload,
             connector_mandate_request_reference_id: None,
@@ -126,6 +126,7 @@ impl ConstructFlowSpecificData<frm_api::Sale, FraudCheckSaleData, FraudCheckResp
             quote_id: None,
             payment_method_balance: None,
             connector_http_status_code: None,
+            connector_request_id: None,
             external_latency: None,
             connector_api_version: None,
             apple_pay_flow: None,
@@ -134,6 +135,7 @@ impl ConstructFlowSpecificData<frm_api::Sale, FraudCheckSaleData, FraudCheckResp
             dispute_id: None,
             connector_response: None,
             integrity_check: Ok(()),
+            connector_options: None,
             additional_merchant_data: None,
             header_payload,
             connector_mandate_request_reference_id: None,
@@ -134,6 +134,7 @@ impl
             quote_id: None,
             payment_method_balance: None,
             connector_http_status_code: None,
+            connector_request_id: None,
             external_latency: None,
             connector_api_version: None,
             payment_method_status: None,
@@ -143,6 +144,7 @@ impl
             dispute_id: None,
             connector_response: None,
             integrity_check: Ok(()),
+            connector_options: None,
             additional_merchant_data: None,
             header_payload,
             connector_mandate_request_reference_id: None,
@@ -151,6 +151,7 @@ impl GetTracker<PaymentToFrmData> for FraudCheckPost {
                     order_details: payment_data.order_details,
                     refund: None,
                     frm_metadata: payment_data.frm_metadata,
+                    risk_signals: payment_data.risk_signals,
                 };
                 Ok(Some(frm_data))
             }
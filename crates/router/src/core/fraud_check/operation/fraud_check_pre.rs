@@ -142,6 +142,7 @@ impl GetTracker<PaymentToFrmData> for FraudCheckPre {
                     order_details: payment_data.order_details,
                     refund: None,
                     frm_metadata: payment_data.frm_metadata,
+                    risk_signals: payment_data.risk_signals,
                 };
                 Ok(Some(frm_data))
             }
@@ -247,6 +248,7 @@ where
                 payment_method_data: router_data.request.payment_method_data,
                 email: router_data.request.email,
                 gateway: router_data.request.gateway,
+                risk_signals: router_data.request.risk_signals,
             }),
             response: FrmResponse::Checkout(router_data.response),
         })
@@ -9,7 +9,8 @@ use common_utils::pii::SecretSerdeValue;
 use hyperswitch_domain_models::payments::{payment_attempt::PaymentAttempt, PaymentIntent};
 pub use hyperswitch_domain_models::{
     router_request_types::fraud_check::{
-        Address, Destination, FrmFulfillmentRequest, FulfillmentStatus, Fulfillments, Product,
+        Address, Destination, FrmFulfillmentRequest, FulfillmentStatus, Fulfillments,
+        PaymentRiskSignals, Product,
     },
     types::OrderDetailsWithAmount,
 };
@@ -60,6 +61,7 @@ pub struct FrmData {
     pub order_details: Option<Vec<OrderDetailsWithAmount>>,
     pub refund: Option<RefundResponse>,
     pub frm_metadata: Option<SecretSerdeValue>,
+    pub risk_signals: Option<PaymentRiskSignals>,
 }
 
 #[derive(Debug)]
@@ -84,6 +86,7 @@ pub struct PaymentToFrmData {
     pub connector_details: ConnectorDetailsCore,
     pub order_details: Option<Vec<OrderDetailsWithAmount>>,
     pub frm_metadata: Option<SecretSerdeValue>,
+    pub risk_signals: Option<PaymentRiskSignals>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
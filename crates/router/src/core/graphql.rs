@@ -0,0 +1,149 @@
+pub mod loaders;
+pub mod types;
+
+use actix_web::http::header::HeaderMap;
+use api_models::graphql as graphql_types;
+use async_graphql::{dataloader::DataLoader, EmptyMutation, EmptySubscription, Schema};
+use once_cell::sync::Lazy;
+use router_env::{instrument, tracing};
+
+use self::{loaders::StoreLoader, types::Query};
+use crate::{
+    core::errors::{RouterResponse, RouterResult},
+    routes::SessionState,
+    services::{
+        authentication::{self as auth, AuthToken},
+        authorization::{self, permissions::Permission, roles::RoleInfo},
+        ApplicationResponse,
+    },
+    types::domain,
+};
+
+/// Maximum depth of the selection sets of a query, deep enough for a payment, its refunds and the
+/// payment those refunds belong to
+const MAX_QUERY_DEPTH: usize = 8;
+
+/// Maximum number of fields that can be resolved by a single query
+const MAX_QUERY_COMPLEXITY: usize = 512;
+
+pub type GraphQLSchema = Schema<Query, EmptyMutation, EmptySubscription>;
+
+/// The schema is the same for all merchants, the merchant and the permissions of the user are
+/// attached to each request instead
+static SCHEMA: Lazy<GraphQLSchema> = Lazy::new(|| {
+    Schema::build(Query, EmptyMutation, EmptySubscription)
+        .limit_depth(MAX_QUERY_DEPTH)
+        .limit_complexity(MAX_QUERY_COMPLEXITY)
+        .finish()
+});
+
+/// The resources which can be read through the GraphQL endpoint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resource {
+    Payment,
+    Refund,
+    Dispute,
+    Customer,
+}
+
+/// The resources the authenticated user is allowed to read.
+///
+/// API keys have access to all the resources of the merchant, whereas the access of dashboard
+/// users is limited by the permissions of their role.
+#[derive(Debug, Clone, Copy)]
+pub struct ReadPermissions {
+    payments: bool,
+    refunds: bool,
+    disputes: bool,
+    customers: bool,
+}
+
+impl ReadPermissions {
+    fn all() -> Self {
+        Self {
+            payments: true,
+            refunds: true,
+            disputes: true,
+            customers: true,
+        }
+    }
+
+    fn from_role_info(role_info: &RoleInfo) -> Self {
+        Self {
+            payments: role_info.check_permission_exists(&Permission::ProfilePaymentRead),
+            refunds: role_info.check_permission_exists(&Permission::ProfileRefundRead),
+            disputes: role_info.check_permission_exists(&Permission::ProfileDisputeRead),
+            customers: role_info.check_permission_exists(&Permission::MerchantCustomerRead),
+        }
+    }
+
+    pub fn can_read(&self, resource: Resource) -> bool {
+        match resource {
+            Resource::Payment => self.payments,
+            Resource::Refund => self.refunds,
+            Resource::Dispute => self.disputes,
+            Resource::Customer => self.customers,
+        }
+    }
+}
+
+/// Data attached to each GraphQL request, available to all the resolvers of the request
+pub struct GraphQLContext {
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+    pub permissions: ReadPermissions,
+}
+
+async fn get_read_permissions(
+    state: &SessionState,
+    headers: &HeaderMap,
+) -> RouterResult<ReadPermissions> {
+    if auth::is_jwt_auth(headers) {
+        let payload = auth::parse_jwt_payload::<_, AuthToken>(headers, state).await?;
+        let role_info = authorization::get_role_info(state, &payload).await?;
+        Ok(ReadPermissions::from_role_info(&role_info))
+    } else {
+        Ok(ReadPermissions::all())
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn execute_query(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    request_headers: &HeaderMap,
+    req: graphql_types::GraphQLRequest,
+) -> RouterResponse<graphql_types::GraphQLResponse> {
+    let permissions = get_read_permissions(&state, request_headers).await?;
+
+    let mut request = async_graphql::Request::new(req.query);
+    if let Some(operation_name) = req.operation_name {
+        request = request.operation_name(operation_name);
+    }
+    if let Some(variables) = req.variables {
+        request = request.variables(async_graphql::Variables::from_json(variables));
+    }
+
+    // Lookups of the same kind made while resolving a query are batched and deduplicated by the
+    // loader, which only lives as long as the request, so that nothing is cached across requests
+    let loader = DataLoader::new(
+        StoreLoader::new(state, merchant_account, key_store),
+        tokio::spawn,
+    );
+    let request = request.data(loader).data(GraphQLContext {
+        profile_id,
+        permissions,
+    });
+
+    let response = SCHEMA.execute(request).await;
+
+    Ok(ApplicationResponse::Json(graphql_types::GraphQLResponse {
+        data: response.data.into_json().unwrap_or_default(),
+        errors: response
+            .errors
+            .into_iter()
+            .filter_map(|error| serde_json::to_value(error).ok())
+            .collect(),
+    }))
+}
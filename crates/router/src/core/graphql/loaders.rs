@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+
+use async_graphql::dataloader::Loader;
+use common_utils::id_type;
+use error_stack::ResultExt;
+use futures::future;
+use router_env::logger;
+use storage_impl::DataModelExt;
+
+use crate::{
+    core::errors,
+    routes::SessionState,
+    types::{
+        domain::{self, behaviour::Conversion},
+        storage,
+    },
+};
+
+/// Payment intent of a payment
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PaymentIntentKey(pub id_type::PaymentId);
+
+/// All the attempts of a payment
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PaymentAttemptsKey(pub id_type::PaymentId);
+
+/// All the refunds of a payment
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PaymentRefundsKey(pub id_type::PaymentId);
+
+/// All the disputes of a payment
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PaymentDisputesKey(pub id_type::PaymentId);
+
+/// Refund by its refund id
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RefundKey(pub String);
+
+/// Dispute by its dispute id
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DisputeKey(pub String);
+
+/// Customer by its customer id
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CustomerKey(pub id_type::CustomerId);
+
+/// Loads the resources of the authenticated merchant from the store.
+///
+/// The keys requested while resolving the fields of a query are collected and deduplicated by the
+/// data loader, and the resources of a batch are fetched with a single query, so that listing the
+/// refunds of a hundred payments doesn't result in a hundred round trips, nor in fetching the same
+/// payment twice when two refunds belong to it. The resources are read from the database, those
+/// of merchants on the key value storage scheme are visible once they have been drained to it.
+pub struct StoreLoader {
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+}
+
+impl StoreLoader {
+    pub fn new(
+        state: SessionState,
+        merchant_account: domain::MerchantAccount,
+        key_store: domain::MerchantKeyStore,
+    ) -> Self {
+        Self {
+            state,
+            merchant_account,
+            key_store,
+        }
+    }
+}
+
+/// Fails the batch without exposing the cause of the failure in the response
+fn internal_error<E: std::fmt::Debug>(error: E) -> async_graphql::Error {
+    logger::error!(?error, "Failed to load resources for GraphQL query");
+    async_graphql::Error::new("Something went wrong")
+}
+
+/// Groups the resources by the key they were requested with, every requested key resolving to a
+/// possibly empty list
+fn group_by<K, V>(keys: &[K], values: Vec<V>, key_of: impl Fn(&V) -> K) -> HashMap<K, Vec<V>>
+where
+    K: Clone + Eq + std::hash::Hash,
+{
+    let mut grouped: HashMap<K, Vec<V>> =
+        keys.iter().cloned().map(|key| (key, Vec::new())).collect();
+    for value in values {
+        grouped.entry(key_of(&value)).or_default().push(value);
+    }
+    grouped
+}
+
+impl Loader<PaymentIntentKey> for StoreLoader {
+    type Value = storage::PaymentIntent;
+    type Error = async_graphql::Error;
+
+    async fn load(
+        &self,
+        keys: &[PaymentIntentKey],
+    ) -> Result<HashMap<PaymentIntentKey, Self::Value>, Self::Error> {
+        let key_manager_state = &(&self.state).into();
+        let payment_ids = keys
+            .iter()
+            .map(|PaymentIntentKey(payment_id)| payment_id.clone())
+            .collect::<Vec<_>>();
+        let payment_intents = self
+            .state
+            .store
+            .find_payment_intents_by_payment_ids(self.merchant_account.get_id(), &payment_ids)
+            .await
+            .map_err(internal_error)?;
+
+        future::try_join_all(payment_intents.into_iter().map(|payment_intent| {
+            storage::PaymentIntent::convert_back(
+                key_manager_state,
+                payment_intent,
+                self.key_store.key.get_inner(),
+                self.key_store.merchant_id.clone().into(),
+            )
+        }))
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+        .map_err(internal_error)
+        .map(|payment_intents| {
+            payment_intents
+                .into_iter()
+                .map(|payment_intent| {
+                    (
+                        PaymentIntentKey(payment_intent.payment_id.clone()),
+                        payment_intent,
+                    )
+                })
+                .collect()
+        })
+    }
+}
+
+impl Loader<PaymentAttemptsKey> for StoreLoader {
+    type Value = Vec<storage::PaymentAttempt>;
+    type Error = async_graphql::Error;
+
+    async fn load(
+        &self,
+        keys: &[PaymentAttemptsKey],
+    ) -> Result<HashMap<PaymentAttemptsKey, Self::Value>, Self::Error> {
+        let payment_ids = keys
+            .iter()
+            .map(|PaymentAttemptsKey(payment_id)| payment_id.clone())
+            .collect::<Vec<_>>();
+        let payment_attempts = self
+            .state
+            .store
+            .find_payment_attempts_by_payment_ids(self.merchant_account.get_id(), &payment_ids)
+            .await
+            .map_err(internal_error)?
+            .into_iter()
+            .map(storage::PaymentAttempt::from_storage_model)
+            .collect();
+
+        Ok(group_by(keys, payment_attempts, |payment_attempt| {
+            PaymentAttemptsKey(payment_attempt.payment_id.clone())
+        }))
+    }
+}
+
+impl Loader<PaymentRefundsKey> for StoreLoader {
+    type Value = Vec<storage::Refund>;
+    type Error = async_graphql::Error;
+
+    async fn load(
+        &self,
+        keys: &[PaymentRefundsKey],
+    ) -> Result<HashMap<PaymentRefundsKey, Self::Value>, Self::Error> {
+        let payment_ids = keys
+            .iter()
+            .map(|PaymentRefundsKey(payment_id)| payment_id.clone())
+            .collect::<Vec<_>>();
+        let refunds = self
+            .state
+            .store
+            .find_refunds_by_payment_ids(self.merchant_account.get_id(), &payment_ids)
+            .await
+            .map_err(internal_error)?;
+
+        Ok(group_by(keys, refunds, |refund| {
+            PaymentRefundsKey(refund.payment_id.clone())
+        }))
+    }
+}
+
+impl Loader<PaymentDisputesKey> for StoreLoader {
+    type Value = Vec<storage::Dispute>;
+    type Error = async_graphql::Error;
+
+    async fn load(
+        &self,
+        keys: &[PaymentDisputesKey],
+    ) -> Result<HashMap<PaymentDisputesKey, Self::Value>, Self::Error> {
+        let payment_ids = keys
+            .iter()
+            .map(|PaymentDisputesKey(payment_id)| payment_id.clone())
+            .collect::<Vec<_>>();
+        let disputes = self
+            .state
+            .store
+            .find_disputes_by_payment_ids(self.merchant_account.get_id(), &payment_ids)
+            .await
+            .map_err(internal_error)?;
+
+        Ok(group_by(keys, disputes, |dispute| {
+            PaymentDisputesKey(dispute.payment_id.clone())
+        }))
+    }
+}
+
+impl Loader<RefundKey> for StoreLoader {
+    type Value = storage::Refund;
+    type Error = async_graphql::Error;
+
+    async fn load(
+        &self,
+        keys: &[RefundKey],
+    ) -> Result<HashMap<RefundKey, Self::Value>, Self::Error> {
+        let refund_ids = keys
+            .iter()
+            .map(|RefundKey(refund_id)| refund_id.clone())
+            .collect::<Vec<_>>();
+        let refunds = self
+            .state
+            .store
+            .find_refunds_by_refund_ids(self.merchant_account.get_id(), &refund_ids)
+            .await
+            .map_err(internal_error)?;
+
+        Ok(refunds
+            .into_iter()
+            .map(|refund| (RefundKey(refund.refund_id.clone()), refund))
+            .collect())
+    }
+}
+
+impl Loader<DisputeKey> for StoreLoader {
+    type Value = storage::Dispute;
+    type Error = async_graphql::Error;
+
+    async fn load(
+        &self,
+        keys: &[DisputeKey],
+    ) -> Result<HashMap<DisputeKey, Self::Value>, Self::Error> {
+        let dispute_ids = keys
+            .iter()
+            .map(|DisputeKey(dispute_id)| dispute_id.clone())
+            .collect::<Vec<_>>();
+        let disputes = self
+            .state
+            .store
+            .find_disputes_by_dispute_ids(self.merchant_account.get_id(), &dispute_ids)
+            .await
+            .map_err(internal_error)?;
+
+        Ok(disputes
+            .into_iter()
+            .map(|dispute| (DisputeKey(dispute.dispute_id.clone()), dispute))
+            .collect())
+    }
+}
+
+impl Loader<CustomerKey> for StoreLoader {
+    type Value = domain::Customer;
+    type Error = async_graphql::Error;
+
+    async fn load(
+        &self,
+        keys: &[CustomerKey],
+    ) -> Result<HashMap<CustomerKey, Self::Value>, Self::Error> {
+        let key_manager_state = &(&self.state).into();
+        let customer_ids = keys
+            .iter()
+            .map(|CustomerKey(customer_id)| customer_id.clone())
+            .collect::<Vec<_>>();
+        let customers = self
+            .state
+            .store
+            .find_customers_by_customer_ids(self.merchant_account.get_id(), &customer_ids)
+            .await
+            .map_err(internal_error)?;
+
+        future::try_join_all(customers.into_iter().map(|customer| {
+            domain::Customer::convert_back(
+                key_manager_state,
+                customer,
+                self.key_store.key.get_inner(),
+                self.key_store.merchant_id.clone().into(),
+            )
+        }))
+        .await
+        .change_context(errors::StorageError::DecryptionError)
+        .map_err(internal_error)
+        .map(|customers| {
+            customers
+                .into_iter()
+                .map(|customer| (CustomerKey(customer.customer_id.clone()), customer))
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,408 @@
+use std::borrow::Cow;
+
+use async_graphql::{dataloader::DataLoader, Context, Guard, Object, Result};
+use common_utils::id_type;
+use time::{format_description::well_known::Iso8601, PrimitiveDateTime};
+
+use super::{
+    loaders::{
+        CustomerKey, DisputeKey, PaymentAttemptsKey, PaymentDisputesKey, PaymentIntentKey,
+        PaymentRefundsKey, RefundKey, StoreLoader,
+    },
+    GraphQLContext, Resource,
+};
+use crate::{
+    core::utils::{self as core_utils, GetProfileId},
+    types::{domain, storage},
+};
+
+/// Maximum number of payments that can be fetched by their identifiers in a single query
+const MAX_PAYMENT_IDS: usize = 100;
+
+/// Rejects the field if the user is not allowed to read the resource it resolves to. The rest of
+/// the query is still resolved, with the rejected field set to `null` and an error reported.
+struct ReadGuard(Resource);
+
+impl Guard for ReadGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        let context = ctx.data::<GraphQLContext>()?;
+        context
+            .permissions
+            .can_read(self.0)
+            .then_some(())
+            .ok_or_else(|| {
+                async_graphql::Error::new(format!(
+                    "Access forbidden, the user is not allowed to read {:?} data",
+                    self.0
+                ))
+            })
+    }
+}
+
+fn get_loader<'a>(ctx: &Context<'a>) -> Result<&'a DataLoader<StoreLoader>> {
+    ctx.data::<DataLoader<StoreLoader>>()
+}
+
+/// Resources of other profiles are hidden from users whose access is limited to a profile
+fn is_visible<T: GetProfileId + std::fmt::Debug>(ctx: &Context<'_>, object: &T) -> Result<bool> {
+    let context = ctx.data::<GraphQLContext>()?;
+    Ok(core_utils::validate_profile_id_from_auth_layer(context.profile_id.clone(), object).is_ok())
+}
+
+fn filter_visible<T: GetProfileId + std::fmt::Debug>(
+    ctx: &Context<'_>,
+    objects: Vec<T>,
+) -> Result<Vec<T>> {
+    let context = ctx.data::<GraphQLContext>()?;
+    Ok(objects
+        .into_iter()
+        .filter(|object| {
+            core_utils::validate_profile_id_from_auth_layer(context.profile_id.clone(), object)
+                .is_ok()
+        })
+        .collect())
+}
+
+fn format_timestamp(timestamp: PrimitiveDateTime) -> Result<String> {
+    Ok(timestamp.assume_utc().format(&Iso8601::DEFAULT)?)
+}
+
+async fn load_payment(
+    ctx: &Context<'_>,
+    payment_id: id_type::PaymentId,
+) -> Result<Option<Payment>> {
+    let payment_intent = get_loader(ctx)?
+        .load_one(PaymentIntentKey(payment_id))
+        .await?;
+    match payment_intent {
+        Some(payment_intent) if is_visible(ctx, &payment_intent)? => {
+            Ok(Some(Payment(payment_intent)))
+        }
+        _ => Ok(None),
+    }
+}
+
+pub struct Query;
+
+#[Object]
+impl Query {
+    /// A payment of the merchant
+    #[graphql(guard = "ReadGuard(Resource::Payment)")]
+    async fn payment(&self, ctx: &Context<'_>, payment_id: String) -> Result<Option<Payment>> {
+        let payment_id = id_type::PaymentId::wrap(payment_id)?;
+        load_payment(ctx, payment_id).await
+    }
+
+    /// The payments of the merchant with the given identifiers, payments which don't exist are
+    /// left out
+    #[graphql(guard = "ReadGuard(Resource::Payment)")]
+    async fn payments(&self, ctx: &Context<'_>, payment_ids: Vec<String>) -> Result<Vec<Payment>> {
+        if payment_ids.len() > MAX_PAYMENT_IDS {
+            return Err(async_graphql::Error::new(format!(
+                "At most {MAX_PAYMENT_IDS} payments can be fetched at once"
+            )));
+        }
+
+        let payment_ids = payment_ids
+            .into_iter()
+            .map(|payment_id| id_type::PaymentId::wrap(payment_id).map(PaymentIntentKey))
+            .collect::<Result<Vec<_>, _>>()?;
+        let payment_intents = get_loader(ctx)?.load_many(payment_ids.clone()).await?;
+
+        // Return the payments in the order they were asked for
+        let payment_intents = payment_ids
+            .iter()
+            .filter_map(|payment_id| payment_intents.get(payment_id).cloned())
+            .collect();
+        Ok(filter_visible(ctx, payment_intents)?
+            .into_iter()
+            .map(Payment)
+            .collect())
+    }
+
+    /// A refund of the merchant
+    #[graphql(guard = "ReadGuard(Resource::Refund)")]
+    async fn refund(&self, ctx: &Context<'_>, refund_id: String) -> Result<Option<Refund>> {
+        let refund = get_loader(ctx)?.load_one(RefundKey(refund_id)).await?;
+        match refund {
+            Some(refund) if is_visible(ctx, &refund)? => Ok(Some(Refund(refund))),
+            _ => Ok(None),
+        }
+    }
+
+    /// A dispute of the merchant
+    #[graphql(guard = "ReadGuard(Resource::Dispute)")]
+    async fn dispute(&self, ctx: &Context<'_>, dispute_id: String) -> Result<Option<Dispute>> {
+        let dispute = get_loader(ctx)?.load_one(DisputeKey(dispute_id)).await?;
+        match dispute {
+            Some(dispute) if is_visible(ctx, &dispute)? => Ok(Some(Dispute(dispute))),
+            _ => Ok(None),
+        }
+    }
+
+    /// A customer of the merchant
+    #[graphql(guard = "ReadGuard(Resource::Customer)")]
+    async fn customer(&self, ctx: &Context<'_>, customer_id: String) -> Result<Option<Customer>> {
+        let customer_id = id_type::CustomerId::try_from(Cow::from(customer_id))?;
+        let customer = get_loader(ctx)?.load_one(CustomerKey(customer_id)).await?;
+        Ok(customer.map(Customer))
+    }
+}
+
+pub struct Payment(storage::PaymentIntent);
+
+#[Object]
+impl Payment {
+    async fn payment_id(&self) -> &str {
+        self.0.payment_id.get_string_repr()
+    }
+
+    async fn status(&self) -> String {
+        self.0.status.to_string()
+    }
+
+    /// Amount of the payment in the lowest denomination of the currency
+    async fn amount(&self) -> i64 {
+        self.0.amount.get_amount_as_i64()
+    }
+
+    async fn amount_captured(&self) -> Option<i64> {
+        self.0
+            .amount_captured
+            .map(|amount_captured| amount_captured.get_amount_as_i64())
+    }
+
+    async fn currency(&self) -> Option<String> {
+        self.0.currency.map(|currency| currency.to_string())
+    }
+
+    async fn description(&self) -> Option<&str> {
+        self.0.description.as_deref()
+    }
+
+    async fn profile_id(&self) -> Option<&str> {
+        self.0
+            .profile_id
+            .as_ref()
+            .map(|profile_id| profile_id.get_string_repr())
+    }
+
+    async fn created_at(&self) -> Result<String> {
+        format_timestamp(self.0.created_at)
+    }
+
+    async fn modified_at(&self) -> Result<String> {
+        format_timestamp(self.0.modified_at)
+    }
+
+    /// All the attempts made to complete the payment
+    async fn attempts(&self, ctx: &Context<'_>) -> Result<Vec<PaymentAttempt>> {
+        let payment_attempts = get_loader(ctx)?
+            .load_one(PaymentAttemptsKey(self.0.payment_id.clone()))
+            .await?
+            .unwrap_or_default();
+        Ok(payment_attempts.into_iter().map(PaymentAttempt).collect())
+    }
+
+    #[graphql(guard = "ReadGuard(Resource::Refund)")]
+    async fn refunds(&self, ctx: &Context<'_>) -> Result<Vec<Refund>> {
+        let refunds = get_loader(ctx)?
+            .load_one(PaymentRefundsKey(self.0.payment_id.clone()))
+            .await?
+            .unwrap_or_default();
+        Ok(filter_visible(ctx, refunds)?
+            .into_iter()
+            .map(Refund)
+            .collect())
+    }
+
+    #[graphql(guard = "ReadGuard(Resource::Dispute)")]
+    async fn disputes(&self, ctx: &Context<'_>) -> Result<Vec<Dispute>> {
+        let disputes = get_loader(ctx)?
+            .load_one(PaymentDisputesKey(self.0.payment_id.clone()))
+            .await?
+            .unwrap_or_default();
+        Ok(filter_visible(ctx, disputes)?
+            .into_iter()
+            .map(Dispute)
+            .collect())
+    }
+
+    #[graphql(guard = "ReadGuard(Resource::Customer)")]
+    async fn customer(&self, ctx: &Context<'_>) -> Result<Option<Customer>> {
+        let Some(customer_id) = self.0.customer_id.clone() else {
+            return Ok(None);
+        };
+        let customer = get_loader(ctx)?.load_one(CustomerKey(customer_id)).await?;
+        Ok(customer.map(Customer))
+    }
+}
+
+pub struct PaymentAttempt(storage::PaymentAttempt);
+
+#[Object]
+impl PaymentAttempt {
+    async fn attempt_id(&self) -> &str {
+        &self.0.attempt_id
+    }
+
+    async fn status(&self) -> String {
+        self.0.status.to_string()
+    }
+
+    /// Amount of the attempt in the lowest denomination of the currency
+    async fn amount(&self) -> i64 {
+        self.0.net_amount.get_total_amount().get_amount_as_i64()
+    }
+
+    async fn currency(&self) -> Option<String> {
+        self.0.currency.map(|currency| currency.to_string())
+    }
+
+    async fn connector(&self) -> Option<&str> {
+        self.0.connector.as_deref()
+    }
+
+    async fn payment_method(&self) -> Option<String> {
+        self.0
+            .payment_method
+            .map(|payment_method| payment_method.to_string())
+    }
+
+    async fn payment_method_type(&self) -> Option<String> {
+        self.0
+            .payment_method_type
+            .map(|payment_method_type| payment_method_type.to_string())
+    }
+
+    async fn error_code(&self) -> Option<&str> {
+        self.0.error_code.as_deref()
+    }
+
+    async fn error_message(&self) -> Option<&str> {
+        self.0.error_message.as_deref()
+    }
+
+    async fn created_at(&self) -> Result<String> {
+        format_timestamp(self.0.created_at)
+    }
+}
+
+pub struct Refund(storage::Refund);
+
+#[Object]
+impl Refund {
+    async fn refund_id(&self) -> &str {
+        &self.0.refund_id
+    }
+
+    async fn payment_id(&self) -> &str {
+        self.0.payment_id.get_string_repr()
+    }
+
+    async fn status(&self) -> String {
+        self.0.refund_status.to_string()
+    }
+
+    /// Amount of the refund in the lowest denomination of the currency
+    async fn amount(&self) -> i64 {
+        self.0.refund_amount.get_amount_as_i64()
+    }
+
+    async fn currency(&self) -> String {
+        self.0.currency.to_string()
+    }
+
+    async fn reason(&self) -> Option<&str> {
+        self.0.refund_reason.as_deref()
+    }
+
+    async fn connector(&self) -> &str {
+        &self.0.connector
+    }
+
+    async fn created_at(&self) -> Result<String> {
+        format_timestamp(self.0.created_at)
+    }
+
+    /// The payment which was refunded
+    #[graphql(guard = "ReadGuard(Resource::Payment)")]
+    async fn payment(&self, ctx: &Context<'_>) -> Result<Option<Payment>> {
+        load_payment(ctx, self.0.payment_id.clone()).await
+    }
+}
+
+pub struct Dispute(storage::Dispute);
+
+#[Object]
+impl Dispute {
+    async fn dispute_id(&self) -> &str {
+        &self.0.dispute_id
+    }
+
+    async fn payment_id(&self) -> &str {
+        self.0.payment_id.get_string_repr()
+    }
+
+    async fn stage(&self) -> String {
+        self.0.dispute_stage.to_string()
+    }
+
+    async fn status(&self) -> String {
+        self.0.dispute_status.to_string()
+    }
+
+    /// Disputed amount in the lowest denomination of the currency
+    async fn amount(&self) -> i64 {
+        self.0.dispute_amount
+    }
+
+    async fn currency(&self) -> &str {
+        &self.0.currency
+    }
+
+    async fn connector(&self) -> &str {
+        &self.0.connector
+    }
+
+    async fn connector_reason(&self) -> Option<&str> {
+        self.0.connector_reason.as_deref()
+    }
+
+    async fn challenge_required_by(&self) -> Result<Option<String>> {
+        self.0
+            .challenge_required_by
+            .map(format_timestamp)
+            .transpose()
+    }
+
+    async fn created_at(&self) -> Result<String> {
+        format_timestamp(self.0.created_at)
+    }
+
+    /// The payment which was disputed
+    #[graphql(guard = "ReadGuard(Resource::Payment)")]
+    async fn payment(&self, ctx: &Context<'_>) -> Result<Option<Payment>> {
+        load_payment(ctx, self.0.payment_id.clone()).await
+    }
+}
+
+pub struct Customer(domain::Customer);
+
+#[Object]
+impl Customer {
+    async fn customer_id(&self) -> &str {
+        self.0.customer_id.get_string_repr()
+    }
+
+    async fn description(&self) -> Option<&str> {
+        self.0
+            .description
+            .as_ref()
+            .map(|description| description.get_string_repr())
+    }
+
+    async fn created_at(&self) -> Result<String> {
+        format_timestamp(self.0.created_at)
+    }
+}
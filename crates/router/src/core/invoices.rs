@@ -0,0 +1,665 @@
+use std::collections::HashSet;
+
+use api_models::{invoices as invoice_types, webhooks};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    generate_id, id_type,
+    types::MinorUnit,
+};
+use diesel_models::invoice::InvoiceReminderTrackingData;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        webhooks as webhooks_core,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const INVOICE_REMINDER_NAME: &str = "INVOICE_REMINDER";
+const INVOICE_REMINDER_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::InvoiceReminderWorkflow;
+const INVOICE_REMINDER_TAG: &str = "INVOICE";
+
+const DEFAULT_INVOICE_LIST_LIMIT: u32 = 10;
+const MAX_INVOICE_LIST_LIMIT: u32 = 100;
+
+#[instrument(skip_all)]
+pub async fn create_invoice(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: invoice_types::InvoiceCreateRequest,
+) -> RouterResponse<invoice_types::InvoiceResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    let now = common_utils::date_time::now();
+
+    let amount = get_invoice_amount(&request.line_items)?;
+    if request.due_date <= now {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`due_date` must be in the future".to_string(),
+        }));
+    }
+
+    let profile_id = request
+        .profile_id
+        .or(merchant_account.default_profile.clone())
+        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })?;
+    db.find_business_profile_by_merchant_id_profile_id(
+        &(&state).into(),
+        &key_store,
+        merchant_id,
+        &profile_id,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    let line_items = request
+        .line_items
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the invoice line items")?;
+
+    let invoice = db
+        .insert_invoice(storage::InvoiceNew {
+            invoice_id: generate_id(consts::ID_LENGTH, "inv"),
+            merchant_id: merchant_id.to_owned(),
+            profile_id,
+            customer_id: request.customer_id,
+            status: storage_enums::InvoiceStatus::Open,
+            currency: request.currency,
+            amount,
+            amount_paid: MinorUnit::zero(),
+            line_items,
+            description: request.description,
+            metadata: request.metadata,
+            payment_ids: Vec::new(),
+            due_date: request.due_date,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the invoice")?;
+
+    add_invoice_reminder_task(&state, &invoice, now).await?;
+
+    Ok(ApplicationResponse::Json(to_invoice_response(invoice)?))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_invoice(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: invoice_types::InvoiceId,
+) -> RouterResponse<invoice_types::InvoiceResponse> {
+    let invoice = find_invoice(&state, merchant_account.get_id(), &request.invoice_id).await?;
+    let invoice = refresh_invoice_status(&state, &merchant_account, &key_store, invoice).await?;
+
+    Ok(ApplicationResponse::Json(to_invoice_response(invoice)?))
+}
+
+#[instrument(skip_all)]
+pub async fn list_invoices(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    constraints: invoice_types::InvoiceListConstraints,
+) -> RouterResponse<invoice_types::InvoiceListResponse> {
+    let limit = constraints
+        .limit
+        .unwrap_or(DEFAULT_INVOICE_LIST_LIMIT)
+        .clamp(1, MAX_INVOICE_LIST_LIMIT);
+
+    let invoices = state
+        .store
+        .list_invoices_by_merchant_id(
+            merchant_account.get_id(),
+            constraints.status,
+            i64::from(limit),
+            constraints.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the invoices")?;
+
+    let data = invoices
+        .into_iter()
+        .map(to_invoice_response)
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(ApplicationResponse::Json(
+        invoice_types::InvoiceListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+/// Links payments to an invoice, either directly or through the payments of payment links. The
+/// payments must belong to the profile and be in the currency of the invoice.
+#[instrument(skip_all)]
+pub async fn link_payments(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    invoice_id: String,
+    request: invoice_types::InvoiceLinkPaymentsRequest,
+) -> RouterResponse<invoice_types::InvoiceResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    let invoice = find_invoice(&state, merchant_id, &invoice_id).await?;
+
+    if invoice.status == storage_enums::InvoiceStatus::Paid {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Payments cannot be linked to an invoice which is already paid".to_string(),
+        }));
+    }
+
+    let mut payment_ids = request.payment_ids.unwrap_or_default();
+    for payment_link_id in request.payment_link_ids.unwrap_or_default() {
+        let payment_link = db
+            .find_payment_link_by_payment_link_id(&payment_link_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
+        if payment_link.merchant_id != *merchant_id {
+            return Err(report!(errors::ApiErrorResponse::PaymentLinkNotFound));
+        }
+        payment_ids.push(payment_link.payment_id);
+    }
+
+    if payment_ids.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "At least one of `payment_ids` or `payment_link_ids` must be provided"
+                .to_string(),
+        }));
+    }
+
+    for payment_id in &payment_ids {
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                &(&state).into(),
+                payment_id,
+                merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        if payment_intent.currency != Some(invoice.currency) {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Payment {} is not in the currency of the invoice",
+                    payment_id.get_string_repr()
+                ),
+            }));
+        }
+        if payment_intent
+            .profile_id
+            .as_ref()
+            .is_some_and(|profile_id| *profile_id != invoice.profile_id)
+        {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Payment {} does not belong to the profile of the invoice",
+                    payment_id.get_string_repr()
+                ),
+            }));
+        }
+    }
+
+    let mut linked_payment_ids = invoice.payment_ids.clone();
+    let mut seen_payment_ids = linked_payment_ids.iter().cloned().collect::<HashSet<_>>();
+    for payment_id in payment_ids {
+        let payment_id = payment_id.get_string_repr().to_owned();
+        if seen_payment_ids.insert(payment_id.clone()) {
+            linked_payment_ids.push(payment_id);
+        }
+    }
+
+    let invoice = db
+        .update_invoice(
+            invoice,
+            storage::InvoiceUpdate::PaymentsUpdate {
+                payment_ids: linked_payment_ids,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to link the payments to the invoice")?;
+    let invoice = refresh_invoice_status(&state, &merchant_account, &key_store, invoice).await?;
+
+    Ok(ApplicationResponse::Json(to_invoice_response(invoice)?))
+}
+
+/// Recomputes the amount paid towards an invoice from its linked payments, and updates its status.
+/// An outgoing webhook is sent whenever the status changes.
+#[instrument(skip_all)]
+pub async fn refresh_invoice_status(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    invoice: storage::Invoice,
+) -> RouterResult<storage::Invoice> {
+    let amount_paid = get_amount_paid(state, merchant_account, key_store, &invoice).await?;
+    let status = get_invoice_status(
+        invoice.amount,
+        amount_paid,
+        invoice.due_date,
+        common_utils::date_time::now(),
+    );
+    if status == invoice.status && amount_paid == invoice.amount_paid {
+        return Ok(invoice);
+    }
+
+    let previous_status = invoice.status;
+    let invoice = state
+        .store
+        .update_invoice(
+            invoice,
+            storage::InvoiceUpdate::StatusUpdate {
+                status,
+                amount_paid,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the invoice status")?;
+
+    if status != previous_status {
+        logger::info!(
+            invoice_id = %invoice.invoice_id,
+            %previous_status,
+            %status,
+            "Invoice status updated"
+        );
+        if let Some(event_type) = Option::<storage_enums::EventType>::foreign_from(status) {
+            send_invoice_webhook(state, merchant_account, key_store, &invoice, event_type)
+                .await
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to send the invoice status webhook")
+                })
+                .ok();
+        }
+    }
+
+    Ok(invoice)
+}
+
+/// Refreshes the status of the invoices a payment is linked to, once the payment is captured
+#[instrument(skip_all)]
+pub async fn refresh_invoices_of_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<()> {
+    let invoices = state
+        .store
+        .find_invoices_by_merchant_id_payment_id(merchant_account.get_id(), payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the invoices of the payment")?;
+
+    for invoice in invoices {
+        refresh_invoice_status(state, merchant_account, key_store, invoice).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn send_invoice_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    invoice: &storage::Invoice,
+    event_type: storage_enums::EventType,
+) -> RouterResult<()> {
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, &invoice.profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: invoice.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        event_type,
+        storage_enums::EventClass::Invoices,
+        invoice.invoice_id.clone(),
+        storage_enums::EventObjectType::InvoiceDetails,
+        webhooks::OutgoingWebhookContent::InvoiceDetails(Box::new(to_invoice_response(
+            invoice.clone(),
+        )?)),
+        Some(invoice.created_at),
+    ))
+    .await
+}
+
+pub async fn find_invoice(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    invoice_id: &str,
+) -> RouterResult<storage::Invoice> {
+    state
+        .store
+        .find_invoice_by_merchant_id_invoice_id(merchant_id, invoice_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Invoice with the given id '{invoice_id}' does not exist"),
+        })
+}
+
+pub fn to_invoice_response(
+    invoice: storage::Invoice,
+) -> RouterResult<invoice_types::InvoiceResponse> {
+    let line_items = invoice
+        .line_items
+        .parse_value("InvoiceLineItems")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the invoice line items")?;
+    let payment_ids = invoice
+        .payment_ids
+        .into_iter()
+        .map(|payment_id| id_type::PaymentId::try_from(std::borrow::Cow::Owned(payment_id)))
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the payment ids linked to the invoice")?;
+
+    Ok(invoice_types::InvoiceResponse {
+        invoice_id: invoice.invoice_id,
+        merchant_id: invoice.merchant_id,
+        profile_id: invoice.profile_id,
+        customer_id: invoice.customer_id,
+        status: invoice.status,
+        currency: invoice.currency,
+        amount: invoice.amount,
+        amount_paid: invoice.amount_paid,
+        amount_due: MinorUnit::new(
+            invoice
+                .amount
+                .get_amount_as_i64()
+                .saturating_sub(invoice.amount_paid.get_amount_as_i64())
+                .max(0),
+        ),
+        line_items,
+        description: invoice.description,
+        metadata: invoice.metadata,
+        payment_ids,
+        due_date: invoice.due_date,
+        created_at: invoice.created_at,
+        modified_at: invoice.modified_at,
+    })
+}
+
+fn get_invoice_amount(line_items: &[invoice_types::InvoiceLineItem]) -> RouterResult<MinorUnit> {
+    if line_items.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "An invoice must have at least one line item".to_string(),
+        }));
+    }
+
+    let amount = line_items.iter().try_fold(0_i64, |total, line_item| {
+        if line_item.quantity == 0 || line_item.unit_amount.get_amount_as_i64() <= 0 {
+            return None;
+        }
+        line_item
+            .unit_amount
+            .get_amount_as_i64()
+            .checked_mul(i64::from(line_item.quantity))
+            .and_then(|line_item_amount| total.checked_add(line_item_amount))
+    });
+
+    amount
+        .map(MinorUnit::new)
+        .ok_or(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Line items must have a positive quantity and unit amount, and the invoice \
+                      amount must not overflow"
+                .to_string(),
+        }))
+}
+
+/// Sums the amount captured by the linked payments
+async fn get_amount_paid(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    invoice: &storage::Invoice,
+) -> RouterResult<MinorUnit> {
+    let mut amount_paid = MinorUnit::zero();
+    for payment_id in &invoice.payment_ids {
+        let payment_id = id_type::PaymentId::try_from(std::borrow::Cow::Owned(payment_id.clone()))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the payment id linked to the invoice")?;
+        let payment_intent = state
+            .store
+            .find_payment_intent_by_payment_id_merchant_id(
+                &state.into(),
+                &payment_id,
+                merchant_account.get_id(),
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        amount_paid = amount_paid
+            + match payment_intent.status {
+                storage_enums::IntentStatus::Succeeded => payment_intent
+                    .amount_captured
+                    .unwrap_or(payment_intent.amount),
+                storage_enums::IntentStatus::PartiallyCaptured
+                | storage_enums::IntentStatus::PartiallyCapturedAndCapturable => {
+                    payment_intent.amount_captured.unwrap_or_default()
+                }
+                storage_enums::IntentStatus::Failed
+                | storage_enums::IntentStatus::Cancelled
+                | storage_enums::IntentStatus::Processing
+                | storage_enums::IntentStatus::RequiresCustomerAction
+                | storage_enums::IntentStatus::RequiresMerchantAction
+                | storage_enums::IntentStatus::RequiresPaymentMethod
+                | storage_enums::IntentStatus::RequiresConfirmation
+                | storage_enums::IntentStatus::RequiresCapture => MinorUnit::zero(),
+            };
+    }
+
+    Ok(amount_paid)
+}
+
+fn get_invoice_status(
+    amount: MinorUnit,
+    amount_paid: MinorUnit,
+    due_date: PrimitiveDateTime,
+    now: PrimitiveDateTime,
+) -> storage_enums::InvoiceStatus {
+    if amount_paid >= amount {
+        storage_enums::InvoiceStatus::Paid
+    } else if now > due_date {
+        storage_enums::InvoiceStatus::Overdue
+    } else if amount_paid > MinorUnit::zero() {
+        storage_enums::InvoiceStatus::PartiallyPaid
+    } else {
+        storage_enums::InvoiceStatus::Open
+    }
+}
+
+/// Times at which an invoice is checked by the reminder task: the configured days before the due
+/// date, the due date itself, when the invoice becomes overdue, and the configured days after it.
+pub fn get_reminder_times(
+    config: &settings::Invoices,
+    due_date: PrimitiveDateTime,
+    now: PrimitiveDateTime,
+) -> Vec<PrimitiveDateTime> {
+    let mut reminder_times = config
+        .reminder_days_before_due
+        .iter()
+        .map(|days| due_date - time::Duration::days(i64::from(*days)))
+        .chain(std::iter::once(due_date))
+        .chain(
+            config
+                .overdue_reminder_days
+                .iter()
+                .map(|days| due_date + time::Duration::days(i64::from(*days))),
+        )
+        .filter(|reminder_time| *reminder_time > now)
+        .collect::<Vec<_>>();
+    reminder_times.sort();
+    reminder_times.dedup();
+    reminder_times
+}
+
+async fn add_invoice_reminder_task(
+    state: &SessionState,
+    invoice: &storage::Invoice,
+    now: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let reminder_times = get_reminder_times(&state.conf.invoices, invoice.due_date, now);
+    let Some(schedule_time) = reminder_times.first().copied() else {
+        return Ok(());
+    };
+
+    let process_tracker_id = format!(
+        "{INVOICE_REMINDER_RUNNER}_{INVOICE_REMINDER_NAME}_{}_{}",
+        invoice.invoice_id,
+        invoice.merchant_id.get_string_repr()
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        INVOICE_REMINDER_NAME,
+        INVOICE_REMINDER_RUNNER,
+        [INVOICE_REMINDER_TAG],
+        InvoiceReminderTrackingData {
+            invoice_id: invoice.invoice_id.clone(),
+            merchant_id: invoice.merchant_id.clone(),
+            due_date: invoice.due_date,
+            reminder_times,
+        },
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the invoice reminder task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule the invoice reminders")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_get_invoice_amount() {
+        let line_items = vec![
+            invoice_types::InvoiceLineItem {
+                description: "Monthly subscription".to_string(),
+                quantity: 2,
+                unit_amount: MinorUnit::new(1500),
+            },
+            invoice_types::InvoiceLineItem {
+                description: "Setup fee".to_string(),
+                quantity: 1,
+                unit_amount: MinorUnit::new(500),
+            },
+        ];
+        assert_eq!(
+            get_invoice_amount(&line_items).unwrap(),
+            MinorUnit::new(3500)
+        );
+
+        assert!(get_invoice_amount(&[]).is_err());
+        assert!(get_invoice_amount(&[invoice_types::InvoiceLineItem {
+            description: "Free sample".to_string(),
+            quantity: 0,
+            unit_amount: MinorUnit::new(500),
+        }])
+        .is_err());
+        assert!(get_invoice_amount(&[invoice_types::InvoiceLineItem {
+            description: "Overflow".to_string(),
+            quantity: 2,
+            unit_amount: MinorUnit::new(i64::MAX),
+        }])
+        .is_err());
+    }
+
+    #[test]
+    fn test_get_invoice_status() {
+        let due_date = datetime!(2024-11-30 00:00);
+        let before_due = datetime!(2024-11-20 00:00);
+        let after_due = datetime!(2024-12-01 00:00);
+        let amount = MinorUnit::new(3000);
+
+        assert_eq!(
+            get_invoice_status(amount, MinorUnit::zero(), due_date, before_due),
+            storage_enums::InvoiceStatus::Open
+        );
+        assert_eq!(
+            get_invoice_status(amount, MinorUnit::new(1000), due_date, before_due),
+            storage_enums::InvoiceStatus::PartiallyPaid
+        );
+        assert_eq!(
+            get_invoice_status(amount, MinorUnit::new(1000), due_date, after_due),
+            storage_enums::InvoiceStatus::Overdue
+        );
+        assert_eq!(
+            get_invoice_status(amount, MinorUnit::new(3000), due_date, after_due),
+            storage_enums::InvoiceStatus::Paid
+        );
+    }
+
+    #[test]
+    fn test_get_reminder_times() {
+        let config = settings::Invoices {
+            reminder_days_before_due: vec![1, 3, 1],
+            overdue_reminder_days: vec![7],
+        };
+        let due_date = datetime!(2024-11-30 00:00);
+
+        assert_eq!(
+            get_reminder_times(&config, due_date, datetime!(2024-11-20 00:00)),
+            vec![
+                datetime!(2024-11-27 00:00),
+                datetime!(2024-11-29 00:00),
+                due_date,
+                datetime!(2024-12-07 00:00),
+            ]
+        );
+        assert_eq!(
+            get_reminder_times(&config, due_date, datetime!(2024-11-28 00:00)),
+            vec![
+                datetime!(2024-11-29 00:00),
+                due_date,
+                datetime!(2024-12-07 00:00),
+            ]
+        );
+    }
+}
@@ -0,0 +1,555 @@
+use std::collections::{BTreeMap, HashMap};
+
+use api_models::{localization as localization_types, payments::BankTransferInstructions};
+use common_utils::{
+    consts::DEFAULT_LOCALE,
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use rust_i18n::t;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+const PAYMENT_LINK_MESSAGE_PREFIX: &str = "payment_link.";
+
+/// Messages shown on payment link pages, translated on the client side
+const PAYMENT_LINK_MESSAGE_KEYS: [&str; 29] = [
+    "expires_on",
+    "ref_id",
+    "requested_by",
+    "pay_now",
+    "your_cart",
+    "quantity",
+    "show_less",
+    "show_more",
+    "miscellaneous_charges",
+    "miscellaneous_charges_detail",
+    "payment_taking_longer",
+    "payment_link_expired",
+    "payment_received",
+    "payment_link_expired_message",
+    "paid_successfully",
+    "payment_pending",
+    "payment_failed",
+    "payment_cancelled",
+    "payment_under_review",
+    "payment_success",
+    "partial_payment_captured",
+    "something_went_wrong",
+    "redirecting",
+    "redirecting_in",
+    "seconds",
+    "unexpected_error",
+    "not_allowed",
+    "error_code",
+    "error_message",
+];
+
+/// Messages rendered by the router, translated using the locale files
+const ROUTER_MESSAGE_KEYS: [&str; 23] = [
+    "redirection.processing_payment",
+    "bank_transfer.instructions.ach",
+    "bank_transfer.instructions.bacs",
+    "bank_transfer.instructions.doku",
+    "bank_transfer.instructions.multibanco",
+    "bank_transfer.instructions.sepa",
+    "receipt.title",
+    "receipt.payment_id",
+    "receipt.date",
+    "receipt.payment_method",
+    "receipt.amount",
+    "receipt.shipping",
+    "receipt.tax",
+    "receipt.surcharge",
+    "receipt.total",
+    "receipt.thank_you",
+    "error_display.card_declined",
+    "error_display.insufficient_funds",
+    "error_display.expired_card",
+    "error_display.incorrect_card_details",
+    "error_display.authentication_failed",
+    "error_display.try_again_later",
+    "error_display.generic",
+];
+
+/// Keywords in the error messages of the connectors, and the customer safe messages shown for
+/// them. The first match wins, so the more specific keywords are listed first.
+const ERROR_DISPLAY_MESSAGE_KEYWORDS: [(&str, &str); 18] = [
+    ("insufficient", "error_display.insufficient_funds"),
+    ("not sufficient", "error_display.insufficient_funds"),
+    ("expired card", "error_display.expired_card"),
+    ("card expired", "error_display.expired_card"),
+    ("card has expired", "error_display.expired_card"),
+    ("expiry", "error_display.incorrect_card_details"),
+    ("expiration", "error_display.incorrect_card_details"),
+    ("cvc", "error_display.incorrect_card_details"),
+    ("cvv", "error_display.incorrect_card_details"),
+    ("security code", "error_display.incorrect_card_details"),
+    ("invalid card", "error_display.incorrect_card_details"),
+    ("card number", "error_display.incorrect_card_details"),
+    ("authenticat", "error_display.authentication_failed"),
+    ("3ds", "error_display.authentication_failed"),
+    ("timeout", "error_display.try_again_later"),
+    ("timed out", "error_display.try_again_later"),
+    ("declin", "error_display.card_declined"),
+    ("do not honor", "error_display.card_declined"),
+];
+
+const MAX_MESSAGE_LENGTH: usize = 1024;
+
+/// Message bundles of a merchant keyed by locale, stored in the configs table
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct MessageBundlesConfig {
+    pub bundles: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl MessageBundlesConfig {
+    pub fn get_config_key(merchant_id: &id_type::MerchantId) -> String {
+        format!("message_bundles_{}", merchant_id.get_string_repr())
+    }
+}
+
+/// Translates customer facing messages to a locale, preferring the messages of the merchant
+#[derive(Debug, Clone)]
+pub struct Localizer {
+    locale: String,
+    merchant_messages: BTreeMap<String, String>,
+}
+
+impl Localizer {
+    pub fn new(locale: Option<&str>) -> Self {
+        Self {
+            locale: normalize_locale(locale),
+            merchant_messages: BTreeMap::new(),
+        }
+    }
+
+    pub fn get_locale(&self) -> &str {
+        &self.locale
+    }
+
+    pub fn translate(&self, key: &str) -> String {
+        self.merchant_messages
+            .get(key)
+            .cloned()
+            .unwrap_or_else(|| t!(key, locale = self.locale.as_str()).to_string())
+    }
+
+    /// Messages of the merchant for payment link pages, keyed as expected by the page scripts
+    pub fn get_payment_link_messages(&self) -> HashMap<String, String> {
+        self.merchant_messages
+            .iter()
+            .filter_map(|(key, message)| {
+                key.strip_prefix(PAYMENT_LINK_MESSAGE_PREFIX)
+                    .map(|key| (to_camel_case(key), message.clone()))
+            })
+            .collect()
+    }
+
+    pub fn get_bank_transfer_instructions(
+        &self,
+        instructions: &BankTransferInstructions,
+    ) -> String {
+        let key = match instructions {
+            BankTransferInstructions::AchCreditTransfer(_) => "bank_transfer.instructions.ach",
+            BankTransferInstructions::BacsBankInstructions(_) => "bank_transfer.instructions.bacs",
+            BankTransferInstructions::DokuBankTransferInstructions(_) => {
+                "bank_transfer.instructions.doku"
+            }
+            BankTransferInstructions::Multibanco(_) => "bank_transfer.instructions.multibanco",
+            BankTransferInstructions::SepaBankInstructions(_) => "bank_transfer.instructions.sepa",
+        };
+        self.translate(key)
+    }
+
+    /// Customer safe message for an error of the connector. The raw messages of the connectors
+    /// are only used to pick the message and are never shown to the customer.
+    pub fn get_error_display_message(
+        &self,
+        error_code: Option<&str>,
+        error_message: Option<&str>,
+    ) -> String {
+        self.translate(get_error_display_message_key(error_code, error_message))
+    }
+}
+
+fn get_error_display_message_key(
+    error_code: Option<&str>,
+    error_message: Option<&str>,
+) -> &'static str {
+    if error_code == Some(consts::REQUEST_TIMEOUT_ERROR_CODE) {
+        return "error_display.try_again_later";
+    }
+
+    let error_message = error_message.unwrap_or_default().to_lowercase();
+    ERROR_DISPLAY_MESSAGE_KEYWORDS
+        .iter()
+        .find(|(keyword, _)| error_message.contains(keyword))
+        .map_or("error_display.generic", |(_, key)| key)
+}
+
+/// Picks the preferred locale out of an `Accept-Language` header value
+pub fn normalize_locale(locale: Option<&str>) -> String {
+    locale
+        .and_then(|locale| locale.split(',').next())
+        .and_then(|locale| locale.split(';').next())
+        .map(|locale| locale.trim().replace('_', "-"))
+        .filter(|locale| !locale.is_empty() && locale != "*")
+        .unwrap_or_else(|| DEFAULT_LOCALE.to_string())
+}
+
+/// Picks the locale to render content in out of an `Accept-Language` header value. The languages
+/// are considered in the order of their quality values, and the first one which has translations,
+/// either for its exact locale or for its language, is picked.
+pub fn get_preferred_locale(accept_language: Option<&str>) -> String {
+    let available_locales = rust_i18n::available_locales!();
+    let find_available_locale = |locale: &str| {
+        available_locales
+            .iter()
+            .find(|available_locale| available_locale.eq_ignore_ascii_case(locale))
+            .map(|available_locale| available_locale.to_string())
+    };
+
+    let mut languages = accept_language
+        .unwrap_or_default()
+        .split(',')
+        .filter_map(|language| {
+            let mut parts = language.split(';');
+            let locale = parts.next()?.trim().replace('_', "-");
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |quality| quality.trim().parse::<f32>().ok())?;
+            (!locale.is_empty() && locale != "*" && quality > 0.0).then_some((locale, quality))
+        })
+        .collect::<Vec<_>>();
+    // The sort is stable, so languages with the same quality keep the order of the header
+    languages.sort_by(|(_, quality), (_, other_quality)| other_quality.total_cmp(quality));
+
+    languages
+        .iter()
+        .find_map(|(locale, _)| {
+            find_available_locale(locale)
+                .or_else(|| locale.split('-').next().and_then(find_available_locale))
+        })
+        .unwrap_or_else(|| DEFAULT_LOCALE.to_string())
+}
+
+fn to_camel_case(key: &str) -> String {
+    let mut parts = key.split('_');
+    let first = parts.next().unwrap_or_default().to_string();
+    parts.fold(first, |mut camel_case, part| {
+        let mut chars = part.chars();
+        if let Some(first_char) = chars.next() {
+            camel_case.extend(first_char.to_uppercase());
+            camel_case.push_str(chars.as_str());
+        }
+        camel_case
+    })
+}
+
+fn is_supported_message_key(key: &str) -> bool {
+    ROUTER_MESSAGE_KEYS.contains(&key)
+        || key
+            .strip_prefix(PAYMENT_LINK_MESSAGE_PREFIX)
+            .is_some_and(|key| PAYMENT_LINK_MESSAGE_KEYS.contains(&key))
+}
+
+async fn find_message_bundles(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<MessageBundlesConfig> {
+    match db
+        .find_config_by_key(&MessageBundlesConfig::get_config_key(merchant_id))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("MessageBundlesConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the message bundles"),
+        Err(error) if error.current_context().is_db_not_found() => {
+            Ok(MessageBundlesConfig::default())
+        }
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the message bundles")),
+    }
+}
+
+/// Builds the localizer for the locale of the customer. The message bundle of the merchant for
+/// the locale is used, falling back to the bundle for its language. Failures in fetching the
+/// bundles fall back to the default messages.
+#[instrument(skip_all)]
+pub async fn get_localizer(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    locale: Option<&str>,
+) -> Localizer {
+    let mut localizer = Localizer::new(locale);
+    let mut bundles = match find_message_bundles(state.store.as_ref(), merchant_id).await {
+        Ok(message_bundles) => message_bundles.bundles,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch the message bundles of the merchant"
+            );
+            return localizer;
+        }
+    };
+
+    let language = localizer
+        .locale
+        .split('-')
+        .next()
+        .unwrap_or_default()
+        .to_string();
+    let language_messages = bundles.remove(&language).unwrap_or_default();
+    localizer.merchant_messages = bundles.remove(&localizer.locale).unwrap_or_default();
+    for (key, message) in language_messages {
+        localizer.merchant_messages.entry(key).or_insert(message);
+    }
+
+    localizer
+}
+
+async fn store_message_bundles(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    message_bundles: &MessageBundlesConfig,
+) -> RouterResult<()> {
+    let key = MessageBundlesConfig::get_config_key(merchant_id);
+    let config = message_bundles
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the message bundles")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the message bundles")
+}
+
+fn to_bundles_response(
+    merchant_id: id_type::MerchantId,
+    message_bundles: MessageBundlesConfig,
+) -> localization_types::MessageBundlesResponse {
+    localization_types::MessageBundlesResponse {
+        merchant_id,
+        bundles: message_bundles
+            .bundles
+            .into_iter()
+            .map(|(locale, messages)| localization_types::MessageBundle { locale, messages })
+            .collect(),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn list_message_bundles(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<localization_types::MessageBundlesResponse> {
+    let merchant_id = merchant_account.get_id();
+    let message_bundles = find_message_bundles(state.store.as_ref(), merchant_id).await?;
+
+    Ok(ApplicationResponse::Json(to_bundles_response(
+        merchant_id.clone(),
+        message_bundles,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_message_bundle(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: localization_types::MessageBundle,
+) -> RouterResponse<localization_types::MessageBundlesResponse> {
+    let locale = normalize_locale(Some(&request.locale));
+    if locale != request.locale.trim().replace('_', "-") {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`locale` must be a single language tag".to_string(),
+        }));
+    }
+    if request.messages.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`messages` must not be empty".to_string(),
+        }));
+    }
+    if let Some(key) = request
+        .messages
+        .keys()
+        .find(|key| !is_supported_message_key(key))
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`{key}` is not a customizable message"),
+        }));
+    }
+    if let Some(key) = request
+        .messages
+        .iter()
+        .find_map(|(key, message)| (message.len() > MAX_MESSAGE_LENGTH).then_some(key))
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Message `{key}` must not be longer than {MAX_MESSAGE_LENGTH} characters"
+            ),
+        }));
+    }
+
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let mut message_bundles = find_message_bundles(db, merchant_id).await?;
+    message_bundles
+        .bundles
+        .insert(locale.clone(), request.messages);
+    store_message_bundles(db, merchant_id, &message_bundles).await?;
+
+    logger::info!(
+        merchant_id = %merchant_id.get_string_repr(),
+        %locale,
+        "Message bundle updated"
+    );
+
+    Ok(ApplicationResponse::Json(to_bundles_response(
+        merchant_id.clone(),
+        message_bundles,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_message_bundle(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: localization_types::MessageBundleDeleteRequest,
+) -> RouterResponse<localization_types::MessageBundlesResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let mut message_bundles = find_message_bundles(db, merchant_id).await?;
+    message_bundles
+        .bundles
+        .remove(&normalize_locale(Some(&request.locale)))
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Message bundle for locale {} not found", request.locale),
+        })?;
+
+    if message_bundles.bundles.is_empty() {
+        db.delete_config_by_key(&MessageBundlesConfig::get_config_key(merchant_id))
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the message bundles")?;
+    } else {
+        store_message_bundles(db, merchant_id, &message_bundles).await?;
+    }
+
+    Ok(ApplicationResponse::Json(to_bundles_response(
+        merchant_id.clone(),
+        message_bundles,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_normalize_locale() {
+        assert_eq!(normalize_locale(Some("fr-BE,fr;q=0.9,en;q=0.8")), "fr-BE");
+        assert_eq!(normalize_locale(Some("en_GB")), "en-GB");
+        assert_eq!(normalize_locale(Some("de;q=0.5")), "de");
+        assert_eq!(normalize_locale(Some("*")), DEFAULT_LOCALE);
+        assert_eq!(normalize_locale(None), DEFAULT_LOCALE);
+    }
+
+    #[test]
+    fn test_get_preferred_locale() {
+        assert_eq!(
+            get_preferred_locale(Some("fr-BE,fr;q=0.9,en;q=0.8")),
+            "fr-BE"
+        );
+        assert_eq!(get_preferred_locale(Some("en;q=0.5, de")), "de");
+        assert_eq!(get_preferred_locale(Some("de-AT")), "de");
+        assert_eq!(get_preferred_locale(Some("xx-YY, en_gb;q=0.7")), "en-GB");
+        assert_eq!(
+            get_preferred_locale(Some("fr;q=0, es;q=invalid")),
+            DEFAULT_LOCALE
+        );
+        assert_eq!(get_preferred_locale(None), DEFAULT_LOCALE);
+    }
+
+    #[test]
+    fn test_payment_link_messages() {
+        let localizer = Localizer {
+            locale: "fr".to_string(),
+            merchant_messages: BTreeMap::from([
+                ("payment_link.pay_now".to_string(), "Payer".to_string()),
+                (
+                    "redirection.processing_payment".to_string(),
+                    "Veuillez patienter".to_string(),
+                ),
+            ]),
+        };
+
+        assert_eq!(
+            localizer.get_payment_link_messages(),
+            HashMap::from([("payNow".to_string(), "Payer".to_string())])
+        );
+        assert_eq!(
+            localizer.translate("redirection.processing_payment"),
+            "Veuillez patienter"
+        );
+        assert!(is_supported_message_key(
+            "payment_link.miscellaneous_charges_detail"
+        ));
+        assert!(!is_supported_message_key("payment_link.unknown"));
+    }
+
+    #[test]
+    fn test_error_display_message_key() {
+        assert_eq!(
+            get_error_display_message_key(Some("51"), Some("Insufficient Funds")),
+            "error_display.insufficient_funds"
+        );
+        assert_eq!(
+            get_error_display_message_key(None, Some("Invalid CVC2 value")),
+            "error_display.incorrect_card_details"
+        );
+        assert_eq!(
+            get_error_display_message_key(None, Some("Transaction Declined")),
+            "error_display.card_declined"
+        );
+        assert_eq!(
+            get_error_display_message_key(
+                Some(consts::REQUEST_TIMEOUT_ERROR_CODE),
+                Some("Connector did not respond in specified time")
+            ),
+            "error_display.try_again_later"
+        );
+        assert_eq!(
+            get_error_display_message_key(Some("E001"), Some("Merchant id not found")),
+            "error_display.generic"
+        );
+    }
+}
@@ -0,0 +1,110 @@
+use api_models::log_config as log_config_types;
+use error_stack::{report, ResultExt};
+use router_env::{
+    logger::{self, config::Level, DynamicLogConfig},
+    tracing,
+};
+use storage_impl::redis::pub_sub::PubSubInterface;
+
+use crate::{
+    core::errors::{self, RouterResponse},
+    routes::SessionState,
+    services::ApplicationResponse,
+};
+
+fn to_level(log_level: log_config_types::LogLevel) -> Level {
+    match log_level {
+        log_config_types::LogLevel::Error => tracing::Level::ERROR,
+        log_config_types::LogLevel::Warn => tracing::Level::WARN,
+        log_config_types::LogLevel::Info => tracing::Level::INFO,
+        log_config_types::LogLevel::Debug => tracing::Level::DEBUG,
+        log_config_types::LogLevel::Trace => tracing::Level::TRACE,
+    }
+    .into()
+}
+
+fn to_log_level(level: Level) -> log_config_types::LogLevel {
+    match level.into_level() {
+        tracing::Level::ERROR => log_config_types::LogLevel::Error,
+        tracing::Level::WARN => log_config_types::LogLevel::Warn,
+        tracing::Level::INFO => log_config_types::LogLevel::Info,
+        tracing::Level::DEBUG => log_config_types::LogLevel::Debug,
+        _ => log_config_types::LogLevel::Trace,
+    }
+}
+
+fn to_response(config: DynamicLogConfig) -> log_config_types::LogConfigResponse {
+    log_config_types::LogConfigResponse {
+        module_levels: config
+            .module_levels
+            .into_iter()
+            .map(|(module, level)| (module, to_log_level(level)))
+            .collect(),
+        merchant_levels: config
+            .merchant_levels
+            .into_iter()
+            .map(|(merchant_id, level)| (merchant_id, to_log_level(level)))
+            .collect(),
+        info_sample_rate: config.info_sample_rate,
+    }
+}
+
+pub async fn retrieve_log_config(
+    _state: SessionState,
+) -> RouterResponse<log_config_types::LogConfigResponse> {
+    Ok(ApplicationResponse::Json(to_response(
+        DynamicLogConfig::get(),
+    )))
+}
+
+/// Updates the log levels and sampling of all the instances. The log levels and sampling are not
+/// persisted, and are reset when an instance restarts.
+pub async fn update_log_config(
+    state: SessionState,
+    request: log_config_types::LogConfigUpdateRequest,
+) -> RouterResponse<log_config_types::LogConfigResponse> {
+    let mut config = DynamicLogConfig::get();
+
+    for (module, log_level) in request.module_levels.unwrap_or_default() {
+        match log_level {
+            Some(log_level) => config.module_levels.insert(module, to_level(log_level)),
+            None => config.module_levels.remove(&module),
+        };
+    }
+
+    for (merchant_id, log_level) in request.merchant_levels.unwrap_or_default() {
+        match log_level {
+            Some(log_level) => config
+                .merchant_levels
+                .insert(merchant_id, to_level(log_level)),
+            None => config.merchant_levels.remove(&merchant_id),
+        };
+    }
+
+    if let Some(info_sample_rate) = request.info_sample_rate {
+        if !(0.0..=1.0).contains(&info_sample_rate) {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`info_sample_rate` must be between 0 and 1".to_string(),
+            }));
+        }
+        config.info_sample_rate = info_sample_rate;
+    }
+
+    // The config is applied to this instance when the message is received on the channel as well,
+    // it is applied here so that the response reflects it regardless of the delivery
+    DynamicLogConfig::set(config.clone());
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .publish_dynamic_log_config(&config)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to publish the dynamic log config")?;
+
+    logger::info!(?config, "Published the dynamic log config");
+
+    Ok(ApplicationResponse::Json(to_response(config)))
+}
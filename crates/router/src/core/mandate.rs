@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+pub mod debits;
 pub mod helpers;
 pub mod utils;
 use api_models::payments;
@@ -0,0 +1,440 @@
+use std::str::FromStr;
+
+use api_models::{enums as api_enums, mandates as mandate_types, webhooks};
+use common_utils::{ext_traits::ValueExt, generate_id, id_type};
+use diesel_models::mandate::MandateDebitTrackingData;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::HeaderPayload;
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+use time::PrimitiveDateTime;
+
+use super::helpers;
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, helpers as payment_helpers},
+        webhooks as webhooks_core,
+    },
+    routes::SessionState,
+    services::{self, ApplicationResponse},
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+    },
+    utils,
+};
+
+const MANDATE_DEBIT_NAME: &str = "MANDATE_DEBIT";
+const MANDATE_DEBIT_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::MandateDebitWorkflow;
+const MANDATE_DEBIT_TAG: &str = "MANDATE";
+
+/// Schedules a debit on an e-mandate. The customer is notified of the debit through the
+/// pre-debit notification webhook ahead of it, after which the recurring payment is created by the
+/// mandate debit task.
+#[instrument(skip_all)]
+pub async fn schedule_mandate_debit(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    mandate_id: String,
+    req: mandate_types::MandateDebitScheduleRequest,
+) -> RouterResponse<mandate_types::MandateDebitResponse> {
+    let mandate = state
+        .store
+        .find_mandate_by_merchant_id_mandate_id(
+            merchant_account.get_id(),
+            &mandate_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+
+    let now = common_utils::date_time::now();
+    validate_mandate_debit(&state.conf.e_mandate, &mandate, &req, now)?;
+    let (pre_debit_notification_at, retry_until) =
+        get_debit_window(&state.conf.e_mandate, mandate.end_date, req.debit_at);
+
+    let profile_id =
+        helpers::get_profile_id_for_mandate(&state, &merchant_account, &key_store, mandate.clone())
+            .await?;
+
+    let tracking_data = MandateDebitTrackingData {
+        mandate_debit_id: generate_id(consts::ID_LENGTH, "mdb"),
+        merchant_id: mandate.merchant_id,
+        profile_id,
+        mandate_id: mandate.mandate_id,
+        payment_method_id: mandate.payment_method_id,
+        customer_id: mandate.customer_id,
+        amount: req.amount,
+        currency: req.currency,
+        description: req.description,
+        debit_at: req.debit_at,
+        pre_debit_notification_at,
+        retry_until,
+    };
+
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        MANDATE_DEBIT_RUNNER,
+        MANDATE_DEBIT_NAME,
+        &tracking_data.mandate_debit_id,
+        &tracking_data.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        MANDATE_DEBIT_NAME,
+        MANDATE_DEBIT_RUNNER,
+        [MANDATE_DEBIT_TAG],
+        tracking_data.clone(),
+        pre_debit_notification_at,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the mandate debit task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule the mandate debit")?;
+
+    Ok(ApplicationResponse::Json(to_mandate_debit_response(
+        tracking_data,
+    )))
+}
+
+/// Validates a debit against the mandate and the e-mandate rules: the mandate must be active on a
+/// connector supporting e-mandates, the amount must be within the cap of the mandate and the
+/// maximum debit amount, and there must be enough time left to notify the customer.
+pub fn validate_mandate_debit(
+    config: &settings::EMandate,
+    mandate: &storage::Mandate,
+    req: &mandate_types::MandateDebitScheduleRequest,
+    now: PrimitiveDateTime,
+) -> RouterResult<()> {
+    utils::when(
+        mandate.mandate_status != storage_enums::MandateStatus::Active,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "mandate is not active".into()
+            }))
+        },
+    )?;
+
+    let is_e_mandate_connector = api_enums::Connector::from_str(&mandate.connector)
+        .map(|connector| config.connector_list.contains(&connector))
+        .unwrap_or(false);
+    utils::when(!is_e_mandate_connector, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "debits cannot be scheduled on mandates of {}",
+                mandate.connector
+            )
+        }))
+    })?;
+
+    utils::when(req.currency != api_enums::Currency::INR, || {
+        Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: "debits can be scheduled only in INR".into()
+        }))
+    })?;
+
+    utils::when(req.amount.get_amount_as_i64() <= 0, || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than 0".into()
+        }))
+    })?;
+
+    utils::when(req.amount > config.max_debit_amount, || {
+        Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: "amount exceeds the maximum debit amount without additional authentication"
+                .into()
+        }))
+    })?;
+
+    payment_helpers::verify_mandate_details(req.amount, req.currency, mandate.clone())?;
+
+    let pre_debit_notification_hours = i64::from(config.pre_debit_notification_hours);
+    utils::when(
+        req.debit_at - time::Duration::hours(pre_debit_notification_hours) < now,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "debit_at must be at least {pre_debit_notification_hours} hours from now"
+                )
+            }))
+        },
+    )?;
+
+    let is_mandate_valid_at_debit = mandate
+        .start_date
+        .map_or(true, |start_date| start_date <= req.debit_at)
+        && mandate
+            .end_date
+            .map_or(true, |end_date| req.debit_at <= end_date);
+    utils::when(!is_mandate_valid_at_debit, || {
+        Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: "mandate is not valid at the time of the debit".into()
+        }))
+    })
+}
+
+/// Time at which the customer is notified of a debit, and the time until which the debit is
+/// retried, which does not extend beyond the end date of the mandate
+pub fn get_debit_window(
+    config: &settings::EMandate,
+    mandate_end_date: Option<PrimitiveDateTime>,
+    debit_at: PrimitiveDateTime,
+) -> (PrimitiveDateTime, PrimitiveDateTime) {
+    let pre_debit_notification_at =
+        debit_at - time::Duration::hours(i64::from(config.pre_debit_notification_hours));
+    let retry_until = debit_at + time::Duration::hours(i64::from(config.debit_retry_window_hours));
+    (
+        pre_debit_notification_at,
+        mandate_end_date.map_or(retry_until, |end_date| retry_until.min(end_date)),
+    )
+}
+
+/// Time of the next attempt of a failed debit, if it is within the retry window of the debit
+pub fn get_next_retry_time(
+    config: &settings::EMandate,
+    tracking_data: &MandateDebitTrackingData,
+    now: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    let next_retry_time = now + time::Duration::hours(i64::from(config.debit_retry_interval_hours));
+    (next_retry_time <= tracking_data.retry_until).then_some(next_retry_time)
+}
+
+pub async fn find_mandate_debit(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    mandate_debit_id: &str,
+) -> RouterResult<MandateDebitTrackingData> {
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        MANDATE_DEBIT_RUNNER,
+        MANDATE_DEBIT_NAME,
+        mandate_debit_id,
+        merchant_id,
+    );
+    state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mandate debit task")?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Mandate debit with the given id '{mandate_debit_id}' does not exist"),
+        })?
+        .tracking_data
+        .parse_value("MandateDebitTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+pub async fn send_pre_debit_notification(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &MandateDebitTrackingData,
+) -> RouterResult<()> {
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, &tracking_data.profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        storage_enums::EventType::MandatePreDebitNotification,
+        storage_enums::EventClass::Mandates,
+        tracking_data.mandate_debit_id.clone(),
+        storage_enums::EventObjectType::MandateDebitDetails,
+        webhooks::OutgoingWebhookContent::MandateDebitDetails(Box::new(to_mandate_debit_response(
+            tracking_data.clone(),
+        ))),
+        None,
+    ))
+    .await
+}
+
+/// Creates the recurring payment for an attempt of a debit, and returns the status of the payment.
+/// Every attempt uses its own payment, identified by the debit and the attempt number, so that an
+/// attempt which has already reached the connector is not repeated if the task is run again.
+#[instrument(skip_all)]
+pub async fn execute_mandate_debit(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    tracking_data: &MandateDebitTrackingData,
+    attempt: i32,
+) -> RouterResult<storage_enums::IntentStatus> {
+    let payment_id =
+        id_type::PaymentId::wrap(format!("{}_{attempt}", tracking_data.mandate_debit_id))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct the payment id of the mandate debit")?;
+
+    match state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_intent) => {
+            logger::info!(
+                payment_id = ?payment_id,
+                "Payment of the mandate debit attempt already exists"
+            );
+            return Ok(payment_intent.status);
+        }
+        Err(error) if error.current_context().is_db_not_found() => {}
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payment of the mandate debit"))
+        }
+    }
+
+    let payment_request = api::PaymentsRequest {
+        payment_id: Some(api::PaymentIdType::PaymentIntentId(payment_id)),
+        merchant_id: Some(tracking_data.merchant_id.clone()),
+        amount: Some(tracking_data.amount.into()),
+        currency: Some(tracking_data.currency),
+        customer_id: Some(tracking_data.customer_id.clone()),
+        description: tracking_data.description.clone(),
+        confirm: Some(true),
+        off_session: Some(true),
+        recurring_details: Some(mandate_types::RecurringDetails::MandateId(
+            tracking_data.mandate_id.clone(),
+        )),
+        profile_id: Some(tracking_data.profile_id.clone()),
+        ..Default::default()
+    };
+
+    let response = Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account,
+        Some(tracking_data.profile_id.clone()),
+        key_store,
+        payments::PaymentCreate,
+        payment_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await?;
+
+    match response {
+        ApplicationResponse::Json(payments_response)
+        | ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            Ok(payments_response.status)
+        }
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response from the payment of the mandate debit"),
+    }
+}
+
+pub fn to_mandate_debit_response(
+    tracking_data: MandateDebitTrackingData,
+) -> mandate_types::MandateDebitResponse {
+    mandate_types::MandateDebitResponse {
+        mandate_debit_id: tracking_data.mandate_debit_id,
+        mandate_id: tracking_data.mandate_id,
+        payment_method_id: tracking_data.payment_method_id,
+        customer_id: tracking_data.customer_id,
+        amount: tracking_data.amount,
+        currency: tracking_data.currency,
+        description: tracking_data.description,
+        debit_at: tracking_data.debit_at,
+        pre_debit_notification_at: tracking_data.pre_debit_notification_at,
+        retry_until: tracking_data.retry_until,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashSet;
+
+    use common_utils::types::MinorUnit;
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_config() -> settings::EMandate {
+        settings::EMandate {
+            connector_list: HashSet::from([api_enums::Connector::Stripe]),
+            pre_debit_notification_hours: 24,
+            debit_retry_window_hours: 72,
+            debit_retry_interval_hours: 24,
+            max_debit_amount: MinorUnit::new(1_500_000),
+        }
+    }
+
+    #[test]
+    fn test_get_debit_window() {
+        let config = get_config();
+        let debit_at = datetime!(2024-12-10 10:00);
+
+        assert_eq!(
+            get_debit_window(&config, None, debit_at),
+            (datetime!(2024-12-09 10:00), datetime!(2024-12-13 10:00))
+        );
+        assert_eq!(
+            get_debit_window(&config, Some(datetime!(2024-12-11 00:00)), debit_at),
+            (datetime!(2024-12-09 10:00), datetime!(2024-12-11 00:00))
+        );
+    }
+
+    #[test]
+    fn test_get_next_retry_time() {
+        let config = get_config();
+        let tracking_data = MandateDebitTrackingData {
+            mandate_debit_id: "mdb_123".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            profile_id: id_type::ProfileId::try_from(std::borrow::Cow::from("pro_123")).unwrap(),
+            mandate_id: "man_123".to_string(),
+            payment_method_id: "pm_123".to_string(),
+            customer_id: id_type::CustomerId::default(),
+            amount: MinorUnit::new(100_000),
+            currency: api_enums::Currency::INR,
+            description: None,
+            debit_at: datetime!(2024-12-10 10:00),
+            pre_debit_notification_at: datetime!(2024-12-09 10:00),
+            retry_until: datetime!(2024-12-13 10:00),
+        };
+
+        assert_eq!(
+            get_next_retry_time(&config, &tracking_data, datetime!(2024-12-10 10:00)),
+            Some(datetime!(2024-12-11 10:00))
+        );
+        assert_eq!(
+            get_next_retry_time(&config, &tracking_data, datetime!(2024-12-12 10:00)),
+            Some(datetime!(2024-12-13 10:00))
+        );
+        assert_eq!(
+            get_next_retry_time(&config, &tracking_data, datetime!(2024-12-12 10:01)),
+            None
+        );
+    }
+}
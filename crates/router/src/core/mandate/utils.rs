@@ -67,6 +67,7 @@ pub async fn construct_mandate_revoke_router_data(
             IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_MANDATE_REVOKE_FLOW.to_string(),
         test_mode: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -78,6 +79,7 @@ pub async fn construct_mandate_revoke_router_data(
         dispute_id: None,
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -0,0 +1,359 @@
+use actix_web::http::Method;
+use api_models::{admin as admin_api, enums::MerchantAccountStatus};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt},
+    fp_utils, id_type,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing, Flow};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, SessionState},
+    services::ApplicationResponse,
+    types::storage,
+};
+
+/// Number of days after which a closed merchant account is purged, unless specified otherwise
+const DEFAULT_PURGE_AFTER_DAYS: u32 = 90;
+
+const MERCHANT_ACCOUNT_PURGE_NAME: &str = "MERCHANT_ACCOUNT_PURGE";
+const MERCHANT_ACCOUNT_PURGE_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::MerchantAccountPurgeWorkflow;
+const MERCHANT_ACCOUNT_PURGE_TAG: &str = "MERCHANT_ACCOUNT";
+
+/// Flows which start new payments or payouts, these are rejected for suspended merchant accounts
+const FLOWS_REJECTED_FOR_SUSPENDED_ACCOUNTS: [Flow; 8] = [
+    Flow::PaymentsCreate,
+    Flow::PaymentsConfirm,
+    Flow::PaymentsStart,
+    Flow::PaymentsSessionToken,
+    Flow::PaymentsIncrementalAuthorization,
+    Flow::PayoutsCreate,
+    Flow::PayoutsConfirm,
+    Flow::PayoutsFulfill,
+];
+
+/// Flows which only read data but are served over POST, these are allowed for closed merchant
+/// accounts
+const READ_FLOWS_ALLOWED_FOR_CLOSED_ACCOUNTS: [Flow; 11] = [
+    Flow::PaymentsRetrieve,
+    Flow::PaymentsList,
+    Flow::PaymentsFilters,
+    Flow::PaymentsAggregate,
+    Flow::RefundsRetrieve,
+    Flow::RefundsList,
+    Flow::RefundsFilters,
+    Flow::RefundsAggregate,
+    Flow::PayoutsList,
+    Flow::PayoutsFilter,
+    Flow::DisputesList,
+];
+
+/// Lifecycle status of a merchant account, stored in the configs table
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct MerchantAccountStatusRecord {
+    pub status: MerchantAccountStatus,
+    pub reason: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub updated_at: Option<time::PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub purge_scheduled_at: Option<time::PrimitiveDateTime>,
+}
+
+fn get_status_config_key(merchant_id: &id_type::MerchantId) -> String {
+    format!("merchant_account_status_{}", merchant_id.get_string_repr())
+}
+
+/// Fetches the status of the merchant account, merchant accounts without a stored status are
+/// active. The status is served from the in-memory config cache on the request path.
+pub async fn get_merchant_account_status(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<MerchantAccountStatusRecord> {
+    let default_record = MerchantAccountStatusRecord::default()
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the default merchant account status")?;
+
+    db.find_config_by_key_unwrap_or(&get_status_config_key(merchant_id), Some(default_record))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant account status")?
+        .config
+        .parse_struct("MerchantAccountStatusRecord")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the merchant account status")
+}
+
+async fn store_merchant_account_status(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    record: &MerchantAccountStatusRecord,
+) -> RouterResult<()> {
+    let key = get_status_config_key(merchant_id);
+    let config = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the merchant account status")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the merchant account status")
+}
+
+/// Rejects the request if the status of the merchant account does not permit the flow. Suspended
+/// merchant accounts can not start new payments or payouts, closed merchant accounts are
+/// read-only.
+pub async fn validate_request_for_merchant_account_status(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    flow: &str,
+    method: &Method,
+) -> RouterResult<()> {
+    let record = get_merchant_account_status(state.store.as_ref(), merchant_id).await?;
+    let is_one_of = |flows: &[Flow]| flows.iter().any(|allowed| allowed.to_string() == flow);
+
+    match record.status {
+        MerchantAccountStatus::Active => Ok(()),
+        MerchantAccountStatus::Suspended => {
+            fp_utils::when(is_one_of(&FLOWS_REJECTED_FOR_SUSPENDED_ACCOUNTS), || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message:
+                        "The merchant account is suspended and can not create payments or payouts"
+                            .to_string(),
+                }))
+            })
+        }
+        MerchantAccountStatus::Closed => fp_utils::when(
+            !matches!(*method, Method::GET | Method::HEAD)
+                && !is_one_of(&READ_FLOWS_ALLOWED_FOR_CLOSED_ACCOUNTS),
+            || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "The merchant account is closed and can only be read".to_string(),
+                }))
+            },
+        ),
+    }
+}
+
+fn to_status_response(
+    merchant_id: id_type::MerchantId,
+    record: MerchantAccountStatusRecord,
+) -> admin_api::MerchantAccountStatusResponse {
+    admin_api::MerchantAccountStatusResponse {
+        merchant_id,
+        status: record.status,
+        reason: record.reason,
+        updated_at: record.updated_at,
+        purge_scheduled_at: record.purge_scheduled_at,
+    }
+}
+
+pub(crate) async fn find_merchant_account(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    db.find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .map(|_| ())
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_merchant_account_status(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<admin_api::MerchantAccountStatusResponse> {
+    find_merchant_account(&state, &merchant_id).await?;
+    let record = get_merchant_account_status(state.store.as_ref(), &merchant_id).await?;
+
+    Ok(ApplicationResponse::Json(to_status_response(
+        merchant_id,
+        record,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn update_merchant_account_status(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_id: id_type::MerchantId,
+    request: admin_api::MerchantAccountStatusUpdateRequest,
+) -> RouterResponse<admin_api::MerchantAccountStatusResponse> {
+    find_merchant_account(&state, &merchant_id).await?;
+    let db = state.store.as_ref();
+    let current_record = get_merchant_account_status(db, &merchant_id).await?;
+
+    fp_utils::when(
+        current_record.status == MerchantAccountStatus::Closed,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The merchant account is closed, its status can not be changed"
+                    .to_string(),
+            }))
+        },
+    )?;
+    fp_utils::when(current_record.status == request.status, || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("The merchant account is already {}", request.status),
+        }))
+    })?;
+    fp_utils::when(
+        request.status != MerchantAccountStatus::Closed && request.purge_after_days.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message:
+                    "`purge_after_days` can only be provided when closing the merchant account"
+                        .to_string(),
+            }))
+        },
+    )?;
+
+    let now = date_time::now();
+    let purge_scheduled_at = (request.status == MerchantAccountStatus::Closed).then(|| {
+        now.saturating_add(time::Duration::days(i64::from(
+            request.purge_after_days.unwrap_or(DEFAULT_PURGE_AFTER_DAYS),
+        )))
+    });
+    let record = MerchantAccountStatusRecord {
+        status: request.status,
+        reason: request.reason,
+        updated_at: Some(now),
+        purge_scheduled_at,
+    };
+
+    if let Some(schedule_time) = purge_scheduled_at {
+        add_merchant_account_purge_task(db, &merchant_id, schedule_time).await?;
+    }
+    store_merchant_account_status(db, &merchant_id, &record).await?;
+
+    logger::info!(
+        merchant_id = %merchant_id.get_string_repr(),
+        previous_status = %current_record.status,
+        status = %record.status,
+        "Merchant account status updated"
+    );
+    req_state
+        .event_context
+        .event(AuditEvent::new(
+            AuditEventType::MerchantAccountStatusUpdate {
+                merchant_id: merchant_id.clone(),
+                previous_status: current_record.status,
+                status: record.status,
+                reason: record.reason.clone(),
+            },
+        ))
+        .emit();
+
+    Ok(ApplicationResponse::Json(to_status_response(
+        merchant_id,
+        record,
+    )))
+}
+
+async fn add_merchant_account_purge_task(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    schedule_time: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let process_tracker_id = format!(
+        "{MERCHANT_ACCOUNT_PURGE_RUNNER}_{MERCHANT_ACCOUNT_PURGE_NAME}_{}",
+        merchant_id.get_string_repr()
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        MERCHANT_ACCOUNT_PURGE_NAME,
+        MERCHANT_ACCOUNT_PURGE_RUNNER,
+        [MERCHANT_ACCOUNT_PURGE_TAG],
+        admin_api::MerchantId {
+            merchant_id: merchant_id.clone(),
+        },
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the merchant account purge task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule the merchant account purge")?;
+
+    Ok(())
+}
+
+/// Deletes the status of a purged merchant account
+pub async fn delete_merchant_account_status(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    match db
+        .delete_config_by_key(&get_status_config_key(merchant_id))
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => Ok(()),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the merchant account status")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_status_record_round_trip() {
+        let now = date_time::now();
+        let record = MerchantAccountStatusRecord {
+            status: MerchantAccountStatus::Closed,
+            reason: Some("Closed on request".to_string()),
+            updated_at: Some(now),
+            purge_scheduled_at: Some(now),
+        };
+        let parsed: MerchantAccountStatusRecord = record
+            .encode_to_string_of_json()
+            .unwrap()
+            .parse_struct("MerchantAccountStatusRecord")
+            .unwrap();
+        assert_eq!(parsed.status, MerchantAccountStatus::Closed);
+        assert!(parsed.purge_scheduled_at.is_some());
+
+        let parsed: MerchantAccountStatusRecord = r#"{"status":"suspended","reason":null}"#
+            .to_string()
+            .parse_struct("MerchantAccountStatusRecord")
+            .unwrap();
+        assert_eq!(parsed.status, MerchantAccountStatus::Suspended);
+        assert!(parsed.updated_at.is_none());
+    }
+}
@@ -0,0 +1,231 @@
+use api_models::{
+    admin as admin_api,
+    enums::{MerchantAccountStatus, MerchantKeyStatus},
+};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt},
+    fp_utils, id_type,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        merchant_account_lifecycle,
+    },
+    db::StorageInterface,
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, SessionState},
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+/// Record of the destruction of the data encryption key of a merchant account, stored in the
+/// configs table as the key itself no longer exists
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MerchantKeyDestructionRecord {
+    pub reason: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub destroyed_at: time::PrimitiveDateTime,
+}
+
+fn get_destruction_config_key(merchant_id: &id_type::MerchantId) -> String {
+    format!("merchant_key_destruction_{}", merchant_id.get_string_repr())
+}
+
+/// Fetches the record of the destruction of the key of the merchant account, if the key was
+/// destroyed
+pub async fn get_key_destruction_record(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<MerchantKeyDestructionRecord>> {
+    match db
+        .find_config_by_key_from_db(&get_destruction_config_key(merchant_id))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("MerchantKeyDestructionRecord")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the merchant key destruction record")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the merchant key destruction record")),
+    }
+}
+
+/// Deletes the record of the destruction of the key of a purged merchant account
+pub async fn delete_key_destruction_record(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    match db
+        .delete_config_by_key(&get_destruction_config_key(merchant_id))
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_not_found() => Ok(()),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the merchant key destruction record")),
+    }
+}
+
+async fn find_merchant_key_store(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<domain::MerchantKeyStore>> {
+    let db = state.store.as_ref();
+    match db
+        .get_merchant_key_store_by_merchant_id(
+            &state.into(),
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+    {
+        Ok(key_store) => Ok(Some(key_store)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the merchant key store")),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_merchant_key(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<admin_api::MerchantKeyResponse> {
+    let key_store = find_merchant_key_store(&state, &merchant_id).await?;
+    let destruction_record = get_key_destruction_record(state.store.as_ref(), &merchant_id).await?;
+
+    let response = match (key_store, destruction_record) {
+        (Some(key_store), _) => admin_api::MerchantKeyResponse {
+            merchant_id,
+            status: MerchantKeyStatus::Active,
+            created_at: Some(key_store.created_at),
+            destroyed_at: None,
+            reason: None,
+        },
+        (None, Some(record)) => admin_api::MerchantKeyResponse {
+            merchant_id,
+            status: MerchantKeyStatus::Destroyed,
+            created_at: None,
+            destroyed_at: Some(record.destroyed_at),
+            reason: record.reason,
+        },
+        (None, None) => Err(report!(errors::ApiErrorResponse::MerchantAccountNotFound))?,
+    };
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+/// Destroys the data encryption key of a closed merchant account. The encrypted data of the
+/// merchant account, such as the personal data of its customers, can no longer be decrypted once
+/// the key is deleted.
+#[instrument(skip_all)]
+pub async fn destroy_merchant_key(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_id: id_type::MerchantId,
+    request: admin_api::MerchantKeyDestroyRequest,
+) -> RouterResponse<admin_api::MerchantKeyResponse> {
+    let db = state.store.as_ref();
+    if find_merchant_key_store(&state, &merchant_id)
+        .await?
+        .is_none()
+    {
+        return match get_key_destruction_record(db, &merchant_id).await? {
+            Some(_) => Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The key of the merchant account is already destroyed".to_string(),
+            })),
+            None => Err(report!(errors::ApiErrorResponse::MerchantAccountNotFound)),
+        };
+    }
+
+    // Data encrypted through the key manager is encrypted with keys held by the key manager, which
+    // are not deleted along with the key store
+    fp_utils::when(state.conf.key_manager.get_inner().enabled, || {
+        Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Keys can not be destroyed while the key manager is enabled".to_string(),
+        }))
+    })?;
+    let account_status =
+        merchant_account_lifecycle::get_merchant_account_status(db, &merchant_id).await?;
+    fp_utils::when(
+        account_status.status != MerchantAccountStatus::Closed,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The key can only be destroyed once the merchant account is closed"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    // The record is stored before the key is deleted, so that a failed deletion can be retried
+    let record = MerchantKeyDestructionRecord {
+        reason: request.reason,
+        destroyed_at: date_time::now(),
+    };
+    store_key_destruction_record(db, &merchant_id, &record).await?;
+    db.delete_merchant_key_store_by_merchant_id(&merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete the merchant key store")?;
+
+    logger::info!(
+        merchant_id = %merchant_id.get_string_repr(),
+        "Merchant key destroyed"
+    );
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::MerchantKeyDestroyed {
+            merchant_id: merchant_id.clone(),
+            reason: record.reason.clone(),
+        }))
+        .emit();
+
+    Ok(ApplicationResponse::Json(admin_api::MerchantKeyResponse {
+        merchant_id,
+        status: MerchantKeyStatus::Destroyed,
+        created_at: None,
+        destroyed_at: Some(record.destroyed_at),
+        reason: record.reason,
+    }))
+}
+
+async fn store_key_destruction_record(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    record: &MerchantKeyDestructionRecord,
+) -> RouterResult<()> {
+    let key = get_destruction_config_key(merchant_id);
+    let config = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the merchant key destruction record")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the merchant key destruction record")
+}
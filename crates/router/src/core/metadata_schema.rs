@@ -0,0 +1,483 @@
+use api_models::metadata_schema as schema_types;
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use serde_json::Value;
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+const SUPPORTED_TYPES: [&str; 7] = [
+    "object", "array", "string", "number", "integer", "boolean", "null",
+];
+
+const SEARCHABLE_TYPES: [&str; 4] = ["string", "number", "integer", "boolean"];
+
+/// Payment metadata schema of a merchant, stored in the configs table
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PaymentMetadataSchemaConfig {
+    pub schema: Value,
+    pub searchable_fields: Vec<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: time::PrimitiveDateTime,
+}
+
+impl PaymentMetadataSchemaConfig {
+    pub fn get_config_key(merchant_id: &id_type::MerchantId) -> String {
+        format!("payment_metadata_schema_{}", merchant_id.get_string_repr())
+    }
+}
+
+/// Fetches the payment metadata schema registered by the merchant, if any
+pub async fn find_payment_metadata_schema(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<PaymentMetadataSchemaConfig>> {
+    let config_key = PaymentMetadataSchemaConfig::get_config_key(merchant_id);
+    match db.find_config_by_key(&config_key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("PaymentMetadataSchemaConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the payment metadata schema")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment metadata schema")),
+    }
+}
+
+/// Validates the metadata of a payment against the schema registered by the merchant. Metadata
+/// of merchants without a schema is not validated.
+#[instrument(skip_all)]
+pub async fn validate_payment_metadata(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    metadata: Option<&common_utils::pii::SecretSerdeValue>,
+) -> RouterResult<()> {
+    let Some(metadata_schema) =
+        find_payment_metadata_schema(state.store.as_ref(), merchant_id).await?
+    else {
+        return Ok(());
+    };
+
+    let metadata = metadata
+        .map(|metadata| metadata.peek())
+        .unwrap_or(&Value::Null);
+    validate_value(&metadata_schema.schema, metadata, "metadata").map_err(|message| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Metadata does not conform to the payment metadata schema, {message}"),
+        })
+    })
+}
+
+/// Ensures that payments are only filtered by the searchable fields of the payment metadata schema
+pub async fn validate_metadata_filter(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    metadata_filter: &serde_json::Map<String, Value>,
+) -> RouterResult<()> {
+    let searchable_fields = find_payment_metadata_schema(state.store.as_ref(), merchant_id)
+        .await?
+        .map(|metadata_schema| metadata_schema.searchable_fields)
+        .unwrap_or_default();
+
+    match metadata_filter
+        .keys()
+        .find(|field| !searchable_fields.contains(field))
+    {
+        Some(field) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("`{field}` is not a searchable field of the payment metadata schema"),
+        })),
+        None => Ok(()),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_metadata_schema(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<schema_types::PaymentMetadataSchemaResponse> {
+    let merchant_id = merchant_account.get_id();
+    let metadata_schema = find_payment_metadata_schema(state.store.as_ref(), merchant_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payment metadata schema not registered".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(to_schema_response(
+        merchant_id.clone(),
+        metadata_schema,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_payment_metadata_schema(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: schema_types::PaymentMetadataSchemaRequest,
+) -> RouterResponse<schema_types::PaymentMetadataSchemaResponse> {
+    validate_schema(&request.schema, "schema").map_err(|message| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid payment metadata schema, {message}"),
+        })
+    })?;
+    validate_searchable_fields(&request.schema, &request.searchable_fields)
+        .map_err(|message| report!(errors::ApiErrorResponse::InvalidRequestData { message }))?;
+
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let metadata_schema = PaymentMetadataSchemaConfig {
+        schema: request.schema,
+        searchable_fields: request.searchable_fields,
+        updated_at: date_time::now(),
+    };
+    let key = PaymentMetadataSchemaConfig::get_config_key(merchant_id);
+    let config = metadata_schema
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the payment metadata schema")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the payment metadata schema")?;
+
+    logger::info!(
+        merchant_id = %merchant_id.get_string_repr(),
+        "Payment metadata schema registered"
+    );
+
+    Ok(ApplicationResponse::Json(to_schema_response(
+        merchant_id.clone(),
+        metadata_schema,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_payment_metadata_schema(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<()> {
+    state
+        .store
+        .delete_config_by_key(&PaymentMetadataSchemaConfig::get_config_key(
+            merchant_account.get_id(),
+        ))
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payment metadata schema not registered".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+fn to_schema_response(
+    merchant_id: id_type::MerchantId,
+    metadata_schema: PaymentMetadataSchemaConfig,
+) -> schema_types::PaymentMetadataSchemaResponse {
+    schema_types::PaymentMetadataSchemaResponse {
+        merchant_id,
+        schema: metadata_schema.schema,
+        searchable_fields: metadata_schema.searchable_fields,
+        updated_at: metadata_schema.updated_at,
+    }
+}
+
+fn get_types(schema: &serde_json::Map<String, Value>) -> Vec<&str> {
+    match schema.get("type") {
+        Some(Value::String(schema_type)) => vec![schema_type.as_str()],
+        Some(Value::Array(schema_types)) => schema_types.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn is_non_negative_integer(value: &Value) -> bool {
+    value.as_u64().is_some()
+}
+
+/// Ensures that the schema only uses the supported subset of JSON schema, so that merchants are
+/// not misled into relying on keywords which are not enforced
+fn validate_schema(schema: &Value, path: &str) -> Result<(), String> {
+    let schema = schema
+        .as_object()
+        .ok_or_else(|| format!("`{path}` must be an object"))?;
+
+    for (keyword, value) in schema {
+        let is_valid = match keyword.as_str() {
+            "type" => match value {
+                Value::String(schema_type) => SUPPORTED_TYPES.contains(&schema_type.as_str()),
+                Value::Array(schema_types) => {
+                    !schema_types.is_empty()
+                        && schema_types.iter().all(|schema_type| {
+                            schema_type
+                                .as_str()
+                                .is_some_and(|schema_type| SUPPORTED_TYPES.contains(&schema_type))
+                        })
+                }
+                _ => false,
+            },
+            "title" | "description" | "pattern" => value.is_string(),
+            "properties" => match value.as_object() {
+                Some(properties) => {
+                    for (property, property_schema) in properties {
+                        validate_schema(property_schema, &format!("{path}.properties.{property}"))?;
+                    }
+                    true
+                }
+                None => false,
+            },
+            "required" => value
+                .as_array()
+                .is_some_and(|required| required.iter().all(Value::is_string)),
+            "additionalProperties" => value.is_boolean(),
+            "items" => {
+                validate_schema(value, &format!("{path}.items"))?;
+                true
+            }
+            "enum" => value.is_array(),
+            "minLength" | "maxLength" | "minItems" | "maxItems" => is_non_negative_integer(value),
+            "minimum" | "maximum" => value.is_number(),
+            _ => return Err(format!("`{path}.{keyword}` is not a supported keyword")),
+        };
+
+        if !is_valid {
+            return Err(format!("`{path}.{keyword}` is invalid"));
+        }
+    }
+
+    if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+        regex::Regex::new(pattern)
+            .map_err(|_| format!("`{path}.pattern` is not a valid regular expression"))?;
+    }
+
+    Ok(())
+}
+
+fn validate_searchable_fields(schema: &Value, searchable_fields: &[String]) -> Result<(), String> {
+    let properties = schema.get("properties").and_then(Value::as_object);
+
+    for field in searchable_fields {
+        let is_searchable = properties
+            .and_then(|properties| properties.get(field))
+            .and_then(Value::as_object)
+            .map(get_types)
+            .is_some_and(|types| {
+                !types.is_empty()
+                    && types
+                        .iter()
+                        .all(|schema_type| SEARCHABLE_TYPES.contains(schema_type))
+            });
+
+        if !is_searchable {
+            return Err(format!(
+                "Searchable field `{field}` must be a top level property of a scalar type"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn matches_type(value: &Value, schema_type: &str) -> bool {
+    match schema_type {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "boolean" => value.is_boolean(),
+        "null" => value.is_null(),
+        _ => false,
+    }
+}
+
+fn get_length_bound(schema: &serde_json::Map<String, Value>, keyword: &str) -> Option<usize> {
+    schema
+        .get(keyword)
+        .and_then(Value::as_u64)
+        .map(|bound| usize::try_from(bound).unwrap_or(usize::MAX))
+}
+
+/// Validates the value against a schema which was validated with `validate_schema`
+fn validate_value(schema: &Value, value: &Value, path: &str) -> Result<(), String> {
+    let Some(schema) = schema.as_object() else {
+        return Ok(());
+    };
+
+    let types = get_types(schema);
+    if !types.is_empty()
+        && !types
+            .iter()
+            .any(|schema_type| matches_type(value, schema_type))
+    {
+        return Err(format!("`{path}` must be of type {}", types.join(" or ")));
+    }
+
+    if let Some(allowed_values) = schema.get("enum").and_then(Value::as_array) {
+        if !allowed_values.contains(value) {
+            return Err(format!("`{path}` must be one of the allowed values"));
+        }
+    }
+
+    match value {
+        Value::String(string) => {
+            let length = string.chars().count();
+            if get_length_bound(schema, "minLength").is_some_and(|min_length| length < min_length) {
+                return Err(format!("`{path}` is too short"));
+            }
+            if get_length_bound(schema, "maxLength").is_some_and(|max_length| length > max_length) {
+                return Err(format!("`{path}` is too long"));
+            }
+            if let Some(pattern) = schema.get("pattern").and_then(Value::as_str) {
+                let is_match = regex::Regex::new(pattern)
+                    .map(|regex| regex.is_match(string))
+                    .unwrap_or(true);
+                if !is_match {
+                    return Err(format!("`{path}` does not match the pattern `{pattern}`"));
+                }
+            }
+        }
+        Value::Number(number) => {
+            let number = number.as_f64().unwrap_or_default();
+            if schema
+                .get("minimum")
+                .and_then(Value::as_f64)
+                .is_some_and(|minimum| number < minimum)
+            {
+                return Err(format!("`{path}` is less than the minimum"));
+            }
+            if schema
+                .get("maximum")
+                .and_then(Value::as_f64)
+                .is_some_and(|maximum| number > maximum)
+            {
+                return Err(format!("`{path}` is greater than the maximum"));
+            }
+        }
+        Value::Array(items) => {
+            if get_length_bound(schema, "minItems").is_some_and(|min_items| items.len() < min_items)
+            {
+                return Err(format!("`{path}` has too few items"));
+            }
+            if get_length_bound(schema, "maxItems").is_some_and(|max_items| items.len() > max_items)
+            {
+                return Err(format!("`{path}` has too many items"));
+            }
+            if let Some(items_schema) = schema.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    validate_value(items_schema, item, &format!("{path}[{index}]"))?;
+                }
+            }
+        }
+        Value::Object(object) => {
+            let properties = schema.get("properties").and_then(Value::as_object);
+
+            for required in schema
+                .get("required")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+            {
+                if !object.contains_key(required) {
+                    return Err(format!("`{path}.{required}` is required"));
+                }
+            }
+
+            for (key, property_value) in object {
+                match properties.and_then(|properties| properties.get(key)) {
+                    Some(property_schema) => {
+                        validate_value(property_schema, property_value, &format!("{path}.{key}"))?
+                    }
+                    None if schema.get("additionalProperties") == Some(&Value::Bool(false)) => {
+                        return Err(format!("`{path}.{key}` is not an allowed property"));
+                    }
+                    None => (),
+                }
+            }
+        }
+        Value::Bool(_) | Value::Null => (),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use serde_json::json;
+
+    use super::*;
+
+    fn get_schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "order_id": { "type": "string", "maxLength": 8, "pattern": "^ord_" },
+                "channel": { "type": "string", "enum": ["web", "app"] },
+                "items": {
+                    "type": "array",
+                    "maxItems": 2,
+                    "items": { "type": "integer", "minimum": 1 }
+                }
+            },
+            "required": ["order_id"],
+            "additionalProperties": false
+        })
+    }
+
+    #[test]
+    fn test_validate_schema() {
+        assert!(validate_schema(&get_schema(), "schema").is_ok());
+        assert!(validate_schema(&json!({ "type": "object", "oneOf": [] }), "schema").is_err());
+        assert!(validate_schema(&json!({ "type": "date" }), "schema").is_err());
+        assert!(validate_schema(&json!({ "pattern": "(" }), "schema").is_err());
+        assert!(validate_searchable_fields(&get_schema(), &["channel".to_string()]).is_ok());
+        assert!(validate_searchable_fields(&get_schema(), &["items".to_string()]).is_err());
+        assert!(validate_searchable_fields(&get_schema(), &["unknown".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_validate_value() {
+        let schema = get_schema();
+        let validate = |value: Value| validate_value(&schema, &value, "metadata");
+
+        assert!(
+            validate(json!({ "order_id": "ord_1", "channel": "web", "items": [1, 2] })).is_ok()
+        );
+        assert!(validate(json!({ "channel": "web" })).is_err());
+        assert!(validate(json!({ "order_id": "order_1" })).is_err());
+        assert!(validate(json!({ "order_id": "ord_123456" })).is_err());
+        assert!(validate(json!({ "order_id": "ord_1", "channel": "pos" })).is_err());
+        assert!(validate(json!({ "order_id": "ord_1", "items": [0] })).is_err());
+        assert!(validate(json!({ "order_id": "ord_1", "items": [1, 2, 3] })).is_err());
+        assert!(validate(json!({ "order_id": "ord_1", "note": "gift" })).is_err());
+        assert!(validate(Value::Null).is_err());
+    }
+}
@@ -0,0 +1,547 @@
+use api_models::{enums as api_enums, payment_descriptors as descriptor_types};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage, PaymentDescriptorConfig},
+};
+
+/// Maximum length of a soft descriptor accepted by the card networks
+const SOFT_DESCRIPTOR_MAX_LENGTH: usize = 22;
+/// Maximum length of the part of a soft descriptor before the separator, which identifies the
+/// merchant
+const SOFT_DESCRIPTOR_PREFIX_MAX_LENGTH: usize = 12;
+const SOFT_DESCRIPTOR_SEPARATOR: char = '*';
+
+const MERCHANT_PLACEHOLDER: &str = "{merchant}";
+const ORDER_ID_PLACEHOLDER: &str = "{order_id}";
+const PAYMENT_ID_PLACEHOLDER: &str = "{payment_id}";
+const SOFT_DESCRIPTOR_PLACEHOLDERS: [&str; 3] = [
+    MERCHANT_PLACEHOLDER,
+    ORDER_ID_PLACEHOLDER,
+    PAYMENT_ID_PLACEHOLDER,
+];
+
+const MERCHANT_CATEGORY_CODE_LENGTH: usize = 4;
+
+/// Maximum length of the soft descriptor shown on the statement of the customer, per card network
+const CARD_NETWORK_SOFT_DESCRIPTOR_MAX_LENGTHS: [(api_enums::CardNetwork, usize); 4] = [
+    (api_enums::CardNetwork::Visa, 25),
+    (api_enums::CardNetwork::Mastercard, 22),
+    (api_enums::CardNetwork::AmericanExpress, 20),
+    (api_enums::CardNetwork::Discover, 22),
+];
+
+/// Payment identifier used in previews when the merchant does not provide one
+const SAMPLE_PAYMENT_ID: &str = "pay_mbabizu24mvu3mela5njyhpit4";
+
+/// Soft descriptor forwarded to a connector, in the field of the request the connector accepts it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SoftDescriptor {
+    /// A full descriptor, replacing the descriptor of the account of the merchant at the connector
+    StatementDescriptor(String),
+    /// The part of the descriptor after the separator, appended by the connector to the descriptor
+    /// prefix of the account of the merchant
+    StatementDescriptorSuffix(String),
+}
+
+impl SoftDescriptor {
+    fn get_value(&self) -> &str {
+        match self {
+            Self::StatementDescriptor(value) | Self::StatementDescriptorSuffix(value) => value,
+        }
+    }
+}
+
+/// Payment descriptors resolved for a payment
+#[derive(Debug, Clone, Default)]
+pub struct PaymentDescriptors {
+    pub merchant_category_code: Option<String>,
+    pub soft_descriptor: Option<SoftDescriptor>,
+}
+
+/// How soft descriptors are forwarded to a connector
+#[derive(Debug, Clone, Copy)]
+struct ConnectorSoftDescriptorRules {
+    /// Whether the connector accepts a full descriptor, or only a suffix
+    accepts_full_descriptor: bool,
+    max_length: usize,
+}
+
+/// Characters accepted by the card networks in soft descriptors
+fn is_descriptor_character(character: char) -> bool {
+    character.is_ascii_alphanumeric()
+        || matches!(character, ' ' | '.' | ',' | '-' | SOFT_DESCRIPTOR_SEPARATOR)
+}
+
+fn get_static_text(template: &str) -> String {
+    SOFT_DESCRIPTOR_PLACEHOLDERS
+        .iter()
+        .fold(template.to_owned(), |text, placeholder| {
+            text.replace(placeholder, "")
+        })
+}
+
+fn invalid_request_data(
+    message: impl Into<String>,
+) -> error_stack::Report<errors::ApiErrorResponse> {
+    report!(errors::ApiErrorResponse::InvalidRequestData {
+        message: message.into(),
+    })
+}
+
+fn validate_merchant_category_code(merchant_category_code: &str) -> RouterResult<()> {
+    if merchant_category_code.len() != MERCHANT_CATEGORY_CODE_LENGTH
+        || !merchant_category_code
+            .chars()
+            .all(|character| character.is_ascii_digit())
+    {
+        return Err(invalid_request_data(
+            "merchant_category_code must be a four digit code",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates the template against the length and character rules of the card networks. Values of
+/// the placeholders are truncated to fit the rules when the descriptor is rendered.
+fn validate_soft_descriptor_template(template: &str) -> RouterResult<()> {
+    let static_text = get_static_text(template);
+
+    if static_text.contains(['{', '}']) {
+        return Err(invalid_request_data(
+            "soft_descriptor_template contains an unknown placeholder, the supported placeholders are {merchant}, {order_id} and {payment_id}",
+        ));
+    }
+    if let Some(character) = static_text
+        .chars()
+        .find(|character| !is_descriptor_character(*character))
+    {
+        return Err(invalid_request_data(format!(
+            "soft_descriptor_template contains the character '{character}', which is not accepted by the card networks"
+        )));
+    }
+    if template.trim().is_empty() {
+        return Err(invalid_request_data(
+            "soft_descriptor_template must not be empty",
+        ));
+    }
+    if template.matches(SOFT_DESCRIPTOR_SEPARATOR).count() > 1 {
+        return Err(invalid_request_data(format!(
+            "soft_descriptor_template must contain at most one '{SOFT_DESCRIPTOR_SEPARATOR}' separator"
+        )));
+    }
+    if static_text.len() > SOFT_DESCRIPTOR_MAX_LENGTH {
+        return Err(invalid_request_data(format!(
+            "soft_descriptor_template must not exceed {SOFT_DESCRIPTOR_MAX_LENGTH} characters, excluding placeholders"
+        )));
+    }
+    if let Some((prefix, _)) = template.split_once(SOFT_DESCRIPTOR_SEPARATOR) {
+        if get_static_text(prefix).len() > SOFT_DESCRIPTOR_PREFIX_MAX_LENGTH {
+            return Err(invalid_request_data(format!(
+                "the part of soft_descriptor_template before the '{SOFT_DESCRIPTOR_SEPARATOR}' separator must not exceed {SOFT_DESCRIPTOR_PREFIX_MAX_LENGTH} characters, excluding placeholders"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Rules of the connectors the soft descriptor of the profile is forwarded to. Connectors which
+/// are not listed do not accept descriptor overrides, or reject the characters of the rendered
+/// descriptor, and are not sent the soft descriptor of the profile.
+fn get_connector_soft_descriptor_rules(
+    connector: api_enums::Connector,
+) -> Option<ConnectorSoftDescriptorRules> {
+    match connector {
+        api_enums::Connector::Adyen => Some(ConnectorSoftDescriptorRules {
+            accepts_full_descriptor: true,
+            max_length: 22,
+        }),
+        api_enums::Connector::Worldpay => Some(ConnectorSoftDescriptorRules {
+            accepts_full_descriptor: true,
+            max_length: 24,
+        }),
+        // Stripe rejects full descriptors on card payments and the separator in descriptors, the
+        // descriptor prefix is configured on the Stripe account instead
+        api_enums::Connector::Stripe => Some(ConnectorSoftDescriptorRules {
+            accepts_full_descriptor: false,
+            max_length: 22,
+        }),
+        _ => None,
+    }
+}
+
+/// Maximum length of the soft descriptor forwarded to the connector, the lowest of the limits of
+/// the connector and the card network. The lowest limit of the card networks is applied when the
+/// card network of the payment is not known.
+fn get_soft_descriptor_max_length(
+    rules: ConnectorSoftDescriptorRules,
+    card_network: Option<&api_enums::CardNetwork>,
+) -> usize {
+    let network_max_length = CARD_NETWORK_SOFT_DESCRIPTOR_MAX_LENGTHS
+        .iter()
+        .filter(|(network, _)| card_network.map_or(true, |card_network| network == card_network))
+        .map(|(_, network_max_length)| *network_max_length)
+        .min()
+        .unwrap_or(SOFT_DESCRIPTOR_MAX_LENGTH);
+
+    network_max_length.min(rules.max_length)
+}
+
+/// Renders the soft descriptor forwarded to the connector, truncated to the given maximum length.
+/// Connectors accepting only a suffix are sent the part of the template after the separator.
+fn render_connector_soft_descriptor(
+    template: &str,
+    merchant_name: &str,
+    order_id: &str,
+    payment_id: &str,
+    rules: ConnectorSoftDescriptorRules,
+    max_length: Option<usize>,
+) -> Option<SoftDescriptor> {
+    if rules.accepts_full_descriptor {
+        render_soft_descriptor(template, merchant_name, order_id, payment_id, max_length)
+            .map(SoftDescriptor::StatementDescriptor)
+    } else {
+        let (_, suffix) = template.split_once(SOFT_DESCRIPTOR_SEPARATOR)?;
+        render_soft_descriptor(suffix, merchant_name, order_id, payment_id, max_length)
+            .map(SoftDescriptor::StatementDescriptorSuffix)
+    }
+}
+
+/// Renders the soft descriptor of a payment from the template, dropping the characters which are
+/// not accepted by the card networks. When a maximum length is given, the descriptor is truncated
+/// to it and the merchant part of the descriptor to the network prefix limit.
+fn render_soft_descriptor(
+    template: &str,
+    merchant_name: &str,
+    order_id: &str,
+    payment_id: &str,
+    max_length: Option<usize>,
+) -> Option<String> {
+    let sanitize = |value: &str| {
+        value
+            .chars()
+            .filter(|character| {
+                is_descriptor_character(*character) && *character != SOFT_DESCRIPTOR_SEPARATOR
+            })
+            .collect::<String>()
+    };
+    let (merchant_name, order_id, payment_id) = (
+        sanitize(merchant_name),
+        sanitize(order_id),
+        sanitize(payment_id),
+    );
+    let render = |text: &str| {
+        text.replace(MERCHANT_PLACEHOLDER, &merchant_name)
+            .replace(ORDER_ID_PLACEHOLDER, &order_id)
+            .replace(PAYMENT_ID_PLACEHOLDER, &payment_id)
+    };
+    let truncate = |text: &str, max_length: Option<usize>| {
+        text.chars()
+            .take(max_length.unwrap_or(usize::MAX))
+            .collect::<String>()
+    };
+
+    let soft_descriptor = match template.split_once(SOFT_DESCRIPTOR_SEPARATOR) {
+        Some((prefix, suffix)) => format!(
+            "{}{SOFT_DESCRIPTOR_SEPARATOR}{}",
+            truncate(
+                render(prefix).trim(),
+                max_length.map(|_| SOFT_DESCRIPTOR_PREFIX_MAX_LENGTH)
+            )
+            .trim_end(),
+            render(suffix).trim()
+        ),
+        None => render(template),
+    };
+    let soft_descriptor = truncate(soft_descriptor.trim(), max_length)
+        .trim_end()
+        .to_owned();
+
+    (!soft_descriptor.is_empty()).then_some(soft_descriptor)
+}
+
+async fn find_payment_descriptor_config(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> Option<PaymentDescriptorConfig> {
+    let config_key = PaymentDescriptorConfig::get_config_key(profile_id);
+    state
+        .store
+        .find_config_by_key(&config_key)
+        .await
+        .ok()?
+        .config
+        .parse_struct::<PaymentDescriptorConfig>("PaymentDescriptorConfig")
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to parse the payment descriptors of the profile"
+            )
+        })
+        .ok()
+}
+
+fn get_merchant_name(merchant_account: &domain::MerchantAccount) -> String {
+    merchant_account
+        .merchant_name
+        .as_ref()
+        .map(|merchant_name| merchant_name.get_inner().peek().to_owned())
+        .unwrap_or_default()
+}
+
+/// Resolves the payment descriptors of the payment from the configuration of its profile, the soft
+/// descriptor is rendered for the connector the payment is processed through and the card network
+/// of the payment
+#[instrument(skip_all)]
+pub async fn get_payment_descriptors(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: &storage::PaymentIntent,
+    connector: Option<api_enums::Connector>,
+    card_network: Option<&api_enums::CardNetwork>,
+) -> PaymentDescriptors {
+    let config = match payment_intent.profile_id.as_ref() {
+        Some(profile_id) => find_payment_descriptor_config(state, profile_id).await,
+        None => None,
+    };
+    let Some(config) = config else {
+        return PaymentDescriptors::default();
+    };
+
+    let rules = connector.and_then(get_connector_soft_descriptor_rules);
+    let soft_descriptor = config
+        .soft_descriptor_template
+        .as_deref()
+        .zip(rules)
+        .and_then(|(template, rules)| {
+            let merchant_name = get_merchant_name(merchant_account);
+            let payment_id = payment_intent.payment_id.get_string_repr();
+            let order_id = payment_intent
+                .merchant_order_reference_id
+                .as_deref()
+                .unwrap_or(payment_id);
+
+            render_connector_soft_descriptor(
+                template,
+                &merchant_name,
+                order_id,
+                payment_id,
+                rules,
+                Some(get_soft_descriptor_max_length(rules, card_network)),
+            )
+        });
+
+    PaymentDescriptors {
+        merchant_category_code: config.merchant_category_code,
+        soft_descriptor,
+    }
+}
+
+async fn validate_business_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<domain::Profile> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+}
+
+fn get_payment_descriptor_config_response(
+    profile_id: id_type::ProfileId,
+    config: PaymentDescriptorConfig,
+) -> descriptor_types::PaymentDescriptorConfigResponse {
+    descriptor_types::PaymentDescriptorConfigResponse {
+        profile_id,
+        merchant_category_code: config.merchant_category_code,
+        soft_descriptor_template: config.soft_descriptor_template,
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_descriptor_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<descriptor_types::PaymentDescriptorConfigResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    let config_key = PaymentDescriptorConfig::get_config_key(&profile_id);
+    let config = match state.store.find_config_by_key_from_db(&config_key).await {
+        Ok(config) => config
+            .config
+            .parse_struct::<PaymentDescriptorConfig>("PaymentDescriptorConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the payment descriptors of the profile")?,
+        Err(error) if error.current_context().is_db_not_found() => {
+            PaymentDescriptorConfig::default()
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payment descriptors of the profile"))
+        }
+    };
+
+    Ok(ApplicationResponse::Json(
+        get_payment_descriptor_config_response(profile_id, config),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_payment_descriptor_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: descriptor_types::PaymentDescriptorConfigRequest,
+) -> RouterResponse<descriptor_types::PaymentDescriptorConfigResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    request
+        .merchant_category_code
+        .as_deref()
+        .map(validate_merchant_category_code)
+        .transpose()?;
+    request
+        .soft_descriptor_template
+        .as_deref()
+        .map(validate_soft_descriptor_template)
+        .transpose()?;
+
+    let config = PaymentDescriptorConfig {
+        merchant_category_code: request.merchant_category_code,
+        soft_descriptor_template: request.soft_descriptor_template,
+    };
+    let config_key = PaymentDescriptorConfig::get_config_key(&profile_id);
+    let encoded_config = config
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the payment descriptors of the profile")?;
+
+    match state.store.find_config_by_key_from_db(&config_key).await {
+        Ok(_) => state
+            .store
+            .update_config_by_key(
+                &config_key,
+                storage::ConfigUpdate::Update {
+                    config: Some(encoded_config),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => state
+            .store
+            .insert_config(storage::ConfigNew {
+                key: config_key,
+                config: encoded_config,
+            })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the payment descriptors of the profile")?;
+
+    Ok(ApplicationResponse::Json(
+        get_payment_descriptor_config_response(profile_id, config),
+    ))
+}
+
+/// Renders the soft descriptor as it is forwarded to the connector for each card network, with the
+/// length limits of the network and the connector applied
+#[instrument(skip_all)]
+pub async fn preview_soft_descriptor(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: descriptor_types::SoftDescriptorPreviewRequest,
+) -> RouterResponse<descriptor_types::SoftDescriptorPreviewResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    let soft_descriptor_template = match request.soft_descriptor_template {
+        Some(template) => template,
+        None => find_payment_descriptor_config(&state, &profile_id)
+            .await
+            .and_then(|config| config.soft_descriptor_template)
+            .ok_or_else(|| {
+                invalid_request_data(
+                    "soft_descriptor_template is not configured for the business profile",
+                )
+            })?,
+    };
+    validate_soft_descriptor_template(&soft_descriptor_template)?;
+    let rules = get_connector_soft_descriptor_rules(request.connector).ok_or_else(|| {
+        invalid_request_data(format!(
+            "soft descriptors are not forwarded to {}",
+            request.connector
+        ))
+    })?;
+
+    let merchant_name = get_merchant_name(&merchant_account);
+    let payment_id = request
+        .payment_id
+        .unwrap_or_else(|| SAMPLE_PAYMENT_ID.to_owned());
+    // Payments without an order reference fall back to the payment identifier
+    let order_id = request.order_id.unwrap_or_else(|| payment_id.clone());
+    let render = |max_length| {
+        render_connector_soft_descriptor(
+            &soft_descriptor_template,
+            &merchant_name,
+            &order_id,
+            &payment_id,
+            rules,
+            max_length,
+        )
+        .map(|soft_descriptor| soft_descriptor.get_value().to_owned())
+    };
+
+    let full_soft_descriptor = render(None);
+    let previews = CARD_NETWORK_SOFT_DESCRIPTOR_MAX_LENGTHS
+        .into_iter()
+        .map(|(card_network, _)| {
+            let max_length = get_soft_descriptor_max_length(rules, Some(&card_network));
+            let soft_descriptor = render(Some(max_length));
+
+            descriptor_types::SoftDescriptorNetworkPreview {
+                card_network,
+                max_length,
+                is_truncated: soft_descriptor != full_soft_descriptor,
+                soft_descriptor,
+            }
+        })
+        .collect();
+
+    Ok(ApplicationResponse::Json(
+        descriptor_types::SoftDescriptorPreviewResponse {
+            profile_id,
+            connector: request.connector,
+            soft_descriptor_template,
+            full_soft_descriptor,
+            previews,
+        },
+    ))
+}
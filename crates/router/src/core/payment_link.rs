@@ -1,3 +1,4 @@
+pub mod preview;
 pub mod validator;
 use actix_web::http::header;
 use api_models::{
@@ -22,6 +23,7 @@ use time::PrimitiveDateTime;
 
 use super::{
     errors::{self, RouterResult, StorageErrorExt},
+    localization,
     payments::helpers,
 };
 use crate::{
@@ -71,6 +73,7 @@ pub async fn form_payment_link_data(
     merchant_id: common_utils::id_type::MerchantId,
     payment_id: common_utils::id_type::PaymentId,
     locale: Option<String>,
+    query_string: &str,
 ) -> RouterResult<(PaymentLink, PaymentLinkData, PaymentLinkConfig)> {
     todo!()
 }
@@ -83,6 +86,7 @@ pub async fn form_payment_link_data(
     merchant_id: common_utils::id_type::MerchantId,
     payment_id: common_utils::id_type::PaymentId,
     locale: Option<String>,
+    query_string: &str,
 ) -> RouterResult<(PaymentLink, PaymentLinkData, PaymentLinkConfig)> {
     let db = &*state.store;
     let key_manager_state = &state.into();
@@ -103,6 +107,8 @@ pub async fn form_payment_link_data(
         .get_required_value("payment_link_id")
         .change_context(errors::ApiErrorResponse::PaymentLinkNotFound)?;
 
+    let localizer = localization::get_localizer(state, &merchant_id, locale.as_deref()).await;
+
     let merchant_name_from_merchant_account = merchant_account
         .merchant_name
         .clone()
@@ -144,6 +150,15 @@ pub async fn form_payment_link_data(
             id: profile_id.get_string_repr().to_owned(),
         })?;
 
+    // Links signed at creation must be opened with their signature intact
+    if helpers::is_url_signed(&payment_link.link_to_pay) {
+        let url_signing_key = business_profile
+            .payment_response_hash_key
+            .as_deref()
+            .get_required_value("payment_response_hash_key")?;
+        helpers::verify_url_signature(query_string, payment_id.get_string_repr(), url_signing_key)?;
+    }
+
     let return_url = if let Some(payment_create_return_url) = payment_intent.return_url.clone() {
         payment_create_return_url
     } else {
@@ -240,6 +255,7 @@ pub async fn form_payment_link_data(
             transaction_details: payment_link_config.transaction_details.clone(),
             unified_code: payment_attempt.unified_code,
             unified_message: payment_attempt.unified_message,
+            custom_messages: localizer.get_payment_link_messages(),
         };
 
         return Ok((
@@ -267,6 +283,8 @@ pub async fn form_payment_link_data(
         display_sdk_only: payment_link_config.display_sdk_only,
         locale,
         transaction_details: payment_link_config.transaction_details.clone(),
+        custom_messages: localizer.get_payment_link_messages(),
+        preview: None,
     };
 
     Ok((
@@ -283,6 +301,7 @@ pub async fn initiate_secure_payment_link_flow(
     merchant_id: common_utils::id_type::MerchantId,
     payment_id: common_utils::id_type::PaymentId,
     request_headers: &header::HeaderMap,
+    query_string: &str,
 ) -> RouterResponse<services::PaymentLinkFormData> {
     let locale = get_header_value_by_key(ACCEPT_LANGUAGE.into(), request_headers)?
         .map(|val| val.to_string());
@@ -293,6 +312,7 @@ pub async fn initiate_secure_payment_link_flow(
         merchant_id,
         payment_id,
         locale,
+        query_string,
     )
     .await?;
 
@@ -382,6 +402,7 @@ pub async fn initiate_payment_link_flow(
     merchant_id: common_utils::id_type::MerchantId,
     payment_id: common_utils::id_type::PaymentId,
     request_headers: &header::HeaderMap,
+    query_string: &str,
 ) -> RouterResponse<services::PaymentLinkFormData> {
     let locale = get_header_value_by_key(ACCEPT_LANGUAGE.into(), request_headers)?
         .map(|val| val.to_string());
@@ -392,10 +413,65 @@ pub async fn initiate_payment_link_flow(
         merchant_id,
         payment_id,
         locale,
+        query_string,
     )
     .await?;
 
-    let css_script = get_color_scheme_css(&payment_link_config);
+    render_payment_link(&state, payment_details, &payment_link_config)
+}
+
+/// Renders the payment link along with the test cards of the connectors of the payment and the
+/// scenarios which can be demonstrated with them, for merchants to try out their checkout in the
+/// sandbox
+pub async fn initiate_payment_link_preview_flow(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    merchant_id: common_utils::id_type::MerchantId,
+    payment_id: common_utils::id_type::PaymentId,
+    request_headers: &header::HeaderMap,
+    query_string: &str,
+) -> RouterResponse<services::PaymentLinkFormData> {
+    preview::validate_preview_environment()?;
+
+    let locale = get_header_value_by_key(ACCEPT_LANGUAGE.into(), request_headers)?
+        .map(|val| val.to_string());
+    let (payment_link, payment_details, payment_link_config) = form_payment_link_data(
+        &state,
+        merchant_account,
+        key_store.clone(),
+        merchant_id.clone(),
+        payment_id,
+        locale,
+        query_string,
+    )
+    .await?;
+
+    let payment_details = match payment_details {
+        PaymentLinkData::PaymentLinkDetails(mut payment_link_details) => {
+            payment_link_details.preview = Some(
+                preview::get_preview_details(
+                    &state,
+                    &merchant_id,
+                    payment_link.profile_id.as_ref(),
+                    &key_store,
+                )
+                .await?,
+            );
+            PaymentLinkData::PaymentLinkDetails(payment_link_details)
+        }
+        status_details @ PaymentLinkData::PaymentLinkStatusDetails(_) => status_details,
+    };
+
+    render_payment_link(&state, payment_details, &payment_link_config)
+}
+
+fn render_payment_link(
+    state: &SessionState,
+    payment_details: PaymentLinkData,
+    payment_link_config: &PaymentLinkConfig,
+) -> RouterResponse<services::PaymentLinkFormData> {
+    let css_script = get_color_scheme_css(payment_link_config);
     let js_script = get_js_script(&payment_details)?;
 
     match payment_details {
@@ -801,6 +877,8 @@ pub async fn get_payment_link_status(
         .await
         .or(Some(unified_message));
 
+    let localizer = localization::get_localizer(&state, &merchant_id, locale.as_deref()).await;
+
     let payment_details = api_models::payments::PaymentLinkStatusDetails {
         amount,
         currency,
@@ -818,6 +896,7 @@ pub async fn get_payment_link_status(
         transaction_details: payment_link_config.transaction_details,
         unified_code: Some(unified_code),
         unified_message: unified_translated_message,
+        custom_messages: localizer.get_payment_link_messages(),
     };
     let js_script = get_js_script(&PaymentLinkData::PaymentLinkStatusDetails(Box::new(
         payment_details,
@@ -553,5 +553,7 @@ const locales = {
 
   function getTranslations(locale_str) {
     var locale = locale_str || 'en'; // defaults if locale is not present in payment details.
-    return locales[locale] || locales['en']; // defaults if locale is not implemented in locales.
+    var translations = locales[locale] || locales['en']; // defaults if locale is not implemented in locales.
+    var customMessages = (window.__PAYMENT_DETAILS && window.__PAYMENT_DETAILS.custom_messages) || {};
+    return Object.assign({}, translations, customMessages); // messages configured by the merchant take precedence.
   }
\ No newline at end of file
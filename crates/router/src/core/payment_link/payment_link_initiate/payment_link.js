@@ -244,6 +244,11 @@ function boot() {
   }
 
 
+  // Render test cards when the payment link is previewed
+  if (paymentDetails.preview) {
+    renderPreviewPanel(paymentDetails.preview);
+  }
+
   // Deal w loaders
   show("#sdk-spinner");
   hide("#page-spinner");
@@ -984,3 +989,96 @@ function renderSDKHeader(paymentDetails) {
     sdkHeaderNode.append(sdkHeaderItemNode);
   }
 }
+
+/**
+ * Trigger - on boot, when the payment link is previewed
+ * Uses
+ *  - Render the panel listing the test cards of the connectors of the payment
+ *  - Render the selector of the scenario for which test cards are listed
+ * @param {PaymentLinkPreviewDetails} previewDetails
+ **/
+function renderPreviewPanel(previewDetails) {
+  var scenarioLabels = {
+    success: "Successful payment",
+    failure: "Declined payment",
+    three_ds: "3DS challenge",
+  };
+
+  var previewNode = document.createElement("div");
+  previewNode.id = "payment-link-preview";
+
+  var previewHeaderNode = document.createElement("div");
+  previewHeaderNode.className = "payment-link-preview-header";
+  previewHeaderNode.innerText = "Preview mode - use a test card to try out the checkout";
+  previewNode.append(previewHeaderNode);
+
+  if (previewDetails.test_cards.length === 0) {
+    var emptyNode = document.createElement("div");
+    emptyNode.className = "payment-link-preview-empty";
+    emptyNode.innerText = "No test cards are available for the connectors of this payment";
+    previewNode.append(emptyNode);
+    document.body.prepend(previewNode);
+    return;
+  }
+
+  var scenarioSelectNode = document.createElement("select");
+  scenarioSelectNode.className = "payment-link-preview-scenario";
+  previewDetails.scenarios.forEach(function (scenario) {
+    var optionNode = document.createElement("option");
+    optionNode.value = scenario;
+    optionNode.innerText = scenarioLabels[scenario] || scenario;
+    scenarioSelectNode.append(optionNode);
+  });
+  previewNode.append(scenarioSelectNode);
+
+  var testCardsNode = document.createElement("div");
+  testCardsNode.className = "payment-link-preview-cards";
+  previewNode.append(testCardsNode);
+
+  function renderTestCards(scenario) {
+    testCardsNode.innerHTML = "";
+    previewDetails.test_cards
+      .filter(function (testCard) {
+        return testCard.scenario === scenario;
+      })
+      .forEach(function (testCard) {
+        var testCardNode = document.createElement("div");
+        testCardNode.className = "payment-link-preview-card";
+        var connectorNode = document.createElement("div");
+        connectorNode.className = "payment-link-preview-card-connector";
+        connectorNode.innerText = testCard.connector;
+        var cardNumberNode = document.createElement("div");
+        cardNumberNode.className = "payment-link-preview-card-number";
+        cardNumberNode.innerText = testCard.card_number;
+        var cardDetailsNode = document.createElement("div");
+        cardDetailsNode.className = "payment-link-preview-card-details";
+        cardDetailsNode.innerText =
+          testCard.card_exp_month +
+          "/" +
+          testCard.card_exp_year +
+          " · CVC " +
+          testCard.card_cvc;
+        var copyButtonNode = document.createElement("button");
+        copyButtonNode.className = "payment-link-preview-card-copy";
+        copyButtonNode.innerText = "Copy";
+        copyButtonNode.onclick = function () {
+          if (navigator.clipboard) {
+            navigator.clipboard.writeText(testCard.card_number);
+            copyButtonNode.innerText = "Copied";
+          }
+        };
+        testCardNode.append(connectorNode);
+        testCardNode.append(cardNumberNode);
+        testCardNode.append(cardDetailsNode);
+        testCardNode.append(copyButtonNode);
+        testCardsNode.append(testCardNode);
+      });
+  }
+
+  scenarioSelectNode.onchange = function () {
+    renderTestCards(scenarioSelectNode.value);
+  };
+  renderTestCards(previewDetails.scenarios[0]);
+
+  document.body.prepend(previewNode);
+}
@@ -0,0 +1,145 @@
+use api_models::payments::{
+    PaymentLinkPreviewDetails, PaymentLinkPreviewScenario as Scenario, PaymentLinkTestCard,
+};
+use error_stack::{report, ResultExt};
+use router_env::Env;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::{domain, storage::enums},
+};
+
+/// Expiry and CVC accepted along with the test cards by the sandboxes of the connectors
+const TEST_CARD_EXP_MONTH: &str = "03";
+const TEST_CARD_EXP_YEAR: &str = "2030";
+const TEST_CARD_CVC: &str = "737";
+
+/// Test cards of the connector sandboxes, by connector and scenario
+const TEST_CARDS: &[(&str, Scenario, &str)] = &[
+    ("adyen", Scenario::Success, "4111111111111111"),
+    ("adyen", Scenario::ThreeDs, "4917610000000000"),
+    ("bankofamerica", Scenario::Success, "4242424242424242"),
+    ("bankofamerica", Scenario::ThreeDs, "4000000000001091"),
+    ("bluesnap", Scenario::Success, "4242424242424242"),
+    ("bluesnap", Scenario::ThreeDs, "4000000000001091"),
+    ("cybersource", Scenario::Success, "4242424242424242"),
+    ("cybersource", Scenario::ThreeDs, "4000000000001091"),
+    ("nexixpay", Scenario::Success, "4012000033330026"),
+    ("nexixpay", Scenario::ThreeDs, "4349940199004549"),
+    ("paypal", Scenario::Success, "4012000033330026"),
+    ("paypal", Scenario::ThreeDs, "4868719460707704"),
+    ("stripe", Scenario::Success, "4242424242424242"),
+    ("stripe", Scenario::Failure, "4000000000000002"),
+    ("stripe", Scenario::ThreeDs, "4000002500003155"),
+    ("trustpay", Scenario::Success, "4200000000000000"),
+    ("trustpay", Scenario::ThreeDs, "4200000000000067"),
+    ("wellsfargo", Scenario::Success, "4242424242424242"),
+    ("wellsfargo", Scenario::ThreeDs, "4000000000001091"),
+    ("worldpay", Scenario::Success, "4242424242424242"),
+    ("worldpay", Scenario::ThreeDs, "4000000000001091"),
+];
+
+/// Test cards of the dummy connectors, which are accepted by all of them
+const DUMMY_CONNECTOR_TEST_CARDS: &[(Scenario, &str)] = &[
+    (Scenario::Success, "4242424242424242"),
+    (Scenario::Failure, "4000000000000002"),
+    (Scenario::ThreeDs, "4000003800000446"),
+];
+
+const DUMMY_CONNECTORS: &[&str] = &[
+    "phonypay",
+    "fauxpay",
+    "pretendpay",
+    "stripe_test",
+    "adyen_test",
+    "checkout_test",
+    "paypal_test",
+];
+
+/// Payment links are previewed with test cards, which are of no use outside the sandbox
+pub fn validate_preview_environment() -> RouterResult<()> {
+    match router_env::which() {
+        Env::Production => Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Payment link previews are only available in the sandbox environment"
+                .to_string(),
+        })),
+        Env::Development | Env::Sandbox => Ok(()),
+    }
+}
+
+fn get_connector_test_cards(connector: &str) -> Vec<PaymentLinkTestCard> {
+    let to_test_card = |scenario: Scenario, card_number: &str| PaymentLinkTestCard {
+        connector: connector.to_string(),
+        scenario,
+        card_number: card_number.to_string(),
+        card_exp_month: TEST_CARD_EXP_MONTH.to_string(),
+        card_exp_year: TEST_CARD_EXP_YEAR.to_string(),
+        card_cvc: TEST_CARD_CVC.to_string(),
+    };
+
+    if DUMMY_CONNECTORS.contains(&connector) {
+        return DUMMY_CONNECTOR_TEST_CARDS
+            .iter()
+            .map(|(scenario, card_number)| to_test_card(*scenario, card_number))
+            .collect();
+    }
+
+    TEST_CARDS
+        .iter()
+        .filter(|(test_card_connector, _, _)| *test_card_connector == connector)
+        .map(|(_, scenario, card_number)| to_test_card(*scenario, card_number))
+        .collect()
+}
+
+/// Lists the test cards of the payment processors enabled for the profile, along with the
+/// scenarios which can be demonstrated with them
+pub async fn get_preview_details(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: Option<&common_utils::id_type::ProfileId>,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<PaymentLinkPreviewDetails> {
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the connector accounts of the merchant")?;
+
+    let mut connectors = merchant_connector_accounts
+        .into_iter()
+        .filter(|merchant_connector_account| {
+            merchant_connector_account.connector_type == enums::ConnectorType::PaymentProcessor
+                && profile_id.map_or(true, |profile_id| {
+                    merchant_connector_account.profile_id == *profile_id
+                })
+        })
+        .map(|merchant_connector_account| merchant_connector_account.connector_name)
+        .collect::<Vec<_>>();
+    connectors.sort();
+    connectors.dedup();
+
+    let test_cards = connectors
+        .iter()
+        .flat_map(|connector| get_connector_test_cards(connector))
+        .collect::<Vec<_>>();
+    let scenarios = [Scenario::Success, Scenario::Failure, Scenario::ThreeDs]
+        .into_iter()
+        .filter(|scenario| {
+            test_cards
+                .iter()
+                .any(|test_card| test_card.scenario == *scenario)
+        })
+        .collect();
+
+    Ok(PaymentLinkPreviewDetails {
+        scenarios,
+        test_cards,
+    })
+}
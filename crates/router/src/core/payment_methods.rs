@@ -1,7 +1,18 @@
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub mod account_updater;
 pub mod cards;
+pub mod external_vault;
 pub mod migration;
 pub mod network_tokenization;
 pub mod surcharge_decision_configs;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub mod token_lifecycle;
 pub mod transformers;
 pub mod utils;
 mod validator;
@@ -48,11 +59,16 @@ use super::{
     errors::{RouterResponse, StorageErrorExt},
     pm_auth,
 };
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+use crate::core::webhooks as webhooks_core;
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 use crate::{
     configs::settings,
     core::{payment_methods::transformers as pm_transforms, utils as core_utils},
-    headers, logger,
+    headers,
     routes::payment_methods as pm_routes,
     services::encryption,
     types::{
@@ -68,6 +84,7 @@ use crate::{
         errors::{self, RouterResult},
         payments::helpers as payment_helpers,
     },
+    logger,
     routes::{app::StorageInterface, SessionState},
     services,
     types::{
@@ -197,14 +214,23 @@ pub async fn initiate_pm_collect_link(
         req.return_url.clone(),
     )
     .await?;
+
+    // Return response
+    Ok(services::ApplicationResponse::Json(
+        to_pm_collect_link_response(pm_collect_link)?,
+    ))
+}
+
+pub fn to_pm_collect_link_response(
+    pm_collect_link: PaymentMethodCollectLink,
+) -> RouterResult<payment_methods::PaymentMethodCollectLinkResponse> {
     let customer_id = id_type::CustomerId::try_from(Cow::from(pm_collect_link.primary_reference))
         .change_context(errors::ApiErrorResponse::InvalidDataValue {
         field_name: "customer_id",
     })?;
 
-    // Return response
     let url = pm_collect_link.url.peek();
-    let response = payment_methods::PaymentMethodCollectLinkResponse {
+    Ok(payment_methods::PaymentMethodCollectLinkResponse {
         pm_collect_link_id: pm_collect_link.link_id,
         customer_id,
         expiry: pm_collect_link.expiry,
@@ -217,8 +243,209 @@ pub async fn initiate_pm_collect_link(
         return_url: pm_collect_link.return_url,
         ui_config: pm_collect_link.link_data.ui_config,
         enabled_payment_methods: pm_collect_link.link_data.enabled_payment_methods,
+        status: pm_collect_link.link_status,
+        payment_method_id: pm_collect_link.link_data.payment_method_id,
+    })
+}
+
+/// Fetches a payment method collect link created by the merchant
+pub async fn find_pm_collect_link(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    pm_collect_link_id: &str,
+) -> RouterResult<PaymentMethodCollectLink> {
+    let not_found_error = errors::ApiErrorResponse::GenericNotFoundError {
+        message: "payment method collect link not found".to_string(),
     };
-    Ok(services::ApplicationResponse::Json(response))
+    let pm_collect_link = state
+        .store
+        .find_pm_collect_link_by_link_id(pm_collect_link_id)
+        .await
+        .to_not_found_response(not_found_error.clone())?;
+
+    // Links of other merchants are reported as missing
+    if &pm_collect_link.merchant_id != merchant_id {
+        return Err(report!(not_found_error));
+    }
+
+    Ok(pm_collect_link)
+}
+
+pub async fn retrieve_pm_collect_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    pm_collect_link_id: String,
+) -> RouterResponse<payment_methods::PaymentMethodCollectLinkResponse> {
+    let pm_collect_link =
+        find_pm_collect_link(&state, merchant_account.get_id(), &pm_collect_link_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        to_pm_collect_link_response(pm_collect_link)?,
+    ))
+}
+
+/// Saves the payment method submitted through the hosted form of a collect link for the customer
+/// of the link, and notifies the merchant of the completion of the link
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all)]
+pub async fn submit_pm_collect_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payment_methods::PaymentMethodCollectLinkSubmitRequest,
+) -> RouterResponse<payment_methods::PaymentMethodCollectLinkResponse> {
+    let db: &dyn StorageInterface = &*state.store;
+    let pm_collect_link =
+        find_pm_collect_link(&state, merchant_account.get_id(), &req.pm_collect_link_id).await?;
+
+    if pm_collect_link.link_data.client_secret.peek() != req.client_secret.peek() {
+        return Err(report!(errors::ApiErrorResponse::ClientSecretInvalid));
+    }
+    match pm_collect_link.link_status {
+        common_utils::link_utils::PaymentMethodCollectStatus::Initiated => {}
+        common_utils::link_utils::PaymentMethodCollectStatus::Invalidated => {
+            return Err(report!(errors::ApiErrorResponse::ClientSecretExpired));
+        }
+        common_utils::link_utils::PaymentMethodCollectStatus::Submitted => {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "payment method details were already submitted for this link".to_string(),
+            }));
+        }
+    }
+    if common_utils::date_time::now() > pm_collect_link.expiry {
+        db.update_pm_collect_link(
+            pm_collect_link,
+            storage::PaymentMethodCollectLinkUpdate::StatusUpdate {
+                link_status: common_utils::link_utils::PaymentMethodCollectStatus::Invalidated,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to invalidate the expired payment method collect link")?;
+        return Err(report!(errors::ApiErrorResponse::ClientSecretExpired));
+    }
+
+    let is_payment_method_enabled = pm_collect_link
+        .link_data
+        .enabled_payment_methods
+        .as_ref()
+        .map_or(true, |enabled_payment_methods| {
+            enabled_payment_methods.iter().any(|enabled| {
+                enabled.payment_method == req.payment_method
+                    && req.payment_method_type.map_or(true, |payment_method_type| {
+                        enabled.payment_method_types.contains(&payment_method_type)
+                    })
+            })
+        });
+    if !is_payment_method_enabled {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "payment method {} is not enabled for this link",
+                req.payment_method
+            ),
+        }));
+    }
+
+    let payment_method_create = payment_methods::PaymentMethodCreate {
+        payment_method: Some(req.payment_method),
+        payment_method_type: req.payment_method_type,
+        payment_method_issuer: None,
+        payment_method_issuer_code: None,
+        card: req.card,
+        metadata: None,
+        customer_id: Some(pm_collect_link.link_data.customer_id.clone()),
+        card_network: None,
+        #[cfg(feature = "payouts")]
+        bank_transfer: req.bank_transfer,
+        #[cfg(feature = "payouts")]
+        wallet: req.wallet,
+        client_secret: None,
+        payment_method_data: None,
+        billing: req.billing,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+    let payment_method = match Box::pin(cards::add_payment_method(
+        &state,
+        payment_method_create,
+        &merchant_account,
+        &key_store,
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(payment_method) => payment_method,
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from saving the payment method")?,
+    };
+
+    let mut link_data = pm_collect_link.link_data.clone();
+    link_data.payment_method_id = Some(payment_method.payment_method_id);
+    let link_data = link_data
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert PaymentMethodCollectLinkData to Value")?;
+    let pm_collect_link = db
+        .update_pm_collect_link(
+            pm_collect_link,
+            storage::PaymentMethodCollectLinkUpdate::SubmitUpdate { link_data },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the payment method collect link")?;
+
+    // The payment method is saved by now, failing to notify the merchant shouldn't fail the request
+    send_pm_collect_link_webhook(&state, &merchant_account, &key_store, &pm_collect_link)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to send payment method collect webhook"))
+        .ok();
+
+    Ok(services::ApplicationResponse::Json(
+        to_pm_collect_link_response(pm_collect_link)?,
+    ))
+}
+
+/// Payment method collect links aren't tied to a profile, the webhook is sent through the default
+/// profile of the merchant
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+async fn send_pm_collect_link_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    pm_collect_link: &PaymentMethodCollectLink,
+) -> RouterResult<()> {
+    let Some(profile_id) = merchant_account.default_profile.as_ref() else {
+        logger::info!("No default profile set, skipping the payment method collect webhook");
+        return Ok(());
+    };
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        storage_enums::EventType::PaymentMethodCollected,
+        storage_enums::EventClass::PaymentMethods,
+        pm_collect_link.link_id.clone(),
+        storage_enums::EventObjectType::PaymentMethodCollectLinkDetails,
+        api_models::webhooks::OutgoingWebhookContent::PaymentMethodCollectLinkDetails(Box::new(
+            to_pm_collect_link_response(pm_collect_link.clone())?,
+        )),
+        Some(pm_collect_link.created_at),
+    ))
+    .await
 }
 
 pub async fn create_pm_collect_db_entry(
@@ -0,0 +1,715 @@
+use std::{marker::PhantomData, str::FromStr};
+
+use api_models::payment_methods::{
+    CardAccountUpdateStatus, CardDetailsPaymentMethod, PaymentMethodAccountUpdateResponse,
+    PaymentMethodsData,
+};
+use common_enums::PaymentMethodStatus;
+use common_utils::{date_time, ext_traits::ValueExt, id_type};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::{cards, token_lifecycle},
+        payments::{self, helpers},
+    },
+    db::customers::CustomerListConstraints,
+    routes::SessionState,
+    services::{self, ApplicationResponse},
+    types::{self, api, domain, storage, storage::enums as storage_enums, PaymentAddress},
+};
+
+const ACCOUNT_UPDATER_NAME: &str = "ACCOUNT_UPDATER";
+const ACCOUNT_UPDATER_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::AccountUpdaterWorkflow;
+const ACCOUNT_UPDATER_TAG: &str = "ACCOUNT_UPDATER";
+
+const IRRELEVANT_ATTEMPT_ID_IN_ACCOUNT_UPDATE_FLOW: &str =
+    "irrelevant_attempt_id_in_account_update_flow";
+
+const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_ACCOUNT_UPDATE_FLOW: &str =
+    "irrelevant_connector_request_reference_id_in_account_update_flow";
+
+const ACCOUNT_UPDATER_SUPPORTED_CONNECTORS: [api_models::enums::Connector; 1] =
+    [api_models::enums::Connector::Stripe];
+
+/// Tracking data of the task periodically refreshing the saved cards of a merchant
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct AccountUpdaterTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    /// Number of customers of the merchant already covered by the current pass
+    pub offset: u32,
+}
+
+/// Outcome of a single run of the account updater over a batch of customers
+pub enum AccountUpdaterRunOutcome {
+    /// Customers of the merchant are left to be covered by the current pass
+    NextBatch { offset: u32 },
+    /// All customers of the merchant are covered, the next pass starts after the interval
+    PassCompleted,
+    /// The account updater is disabled, or the merchant no longer exists
+    Stopped,
+}
+
+/// Schedules the periodic refresh of the saved cards of the merchant, if it is not scheduled
+/// already
+#[instrument(skip_all)]
+pub async fn schedule_account_updater(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<()> {
+    if !state.conf.account_updater.enabled {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "account updater is not enabled".to_string(),
+        }));
+    }
+
+    let merchant_id = merchant_account.get_id();
+    let process_tracker_id = format!(
+        "{ACCOUNT_UPDATER_RUNNER}_{ACCOUNT_UPDATER_NAME}_{}",
+        merchant_id.get_string_repr()
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        ACCOUNT_UPDATER_NAME,
+        ACCOUNT_UPDATER_RUNNER,
+        [ACCOUNT_UPDATER_TAG],
+        AccountUpdaterTrackingData {
+            merchant_id: merchant_id.clone(),
+            offset: 0,
+        },
+        date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the account updater task")?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(ApplicationResponse::StatusOk),
+        // The task of the merchant is already scheduled
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            Ok(ApplicationResponse::StatusOk)
+        }
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to schedule the account updater task")),
+    }
+}
+
+/// The time at which the next pass of the account updater starts
+pub fn get_next_pass_time(state: &SessionState) -> time::PrimitiveDateTime {
+    date_time::now() + time::Duration::seconds(state.conf.account_updater.interval_in_secs)
+}
+
+fn get_card_details(payment_method: &domain::PaymentMethod) -> Option<CardDetailsPaymentMethod> {
+    payment_method
+        .payment_method_data
+        .clone()
+        .map(|data| data.into_inner().expose())
+        .and_then(|value| serde_json::from_value::<PaymentMethodsData>(value).ok())
+        .and_then(|data| match data {
+            PaymentMethodsData::Card(card) => Some(card),
+            PaymentMethodsData::BankDetails(_) => None,
+        })
+}
+
+/// Whether the card expires before the end of the window, cards which have already expired
+/// included. Stored expiry years may be in either the two or the four digit format.
+fn is_card_expiring(card: &CardDetailsPaymentMethod, window_end: time::PrimitiveDateTime) -> bool {
+    let exp_month = card
+        .expiry_month
+        .as_ref()
+        .and_then(|month| month.peek().parse::<u8>().ok());
+    let exp_year = card
+        .expiry_year
+        .as_ref()
+        .and_then(|year| year.peek().parse::<i32>().ok())
+        .map(|year| if year < 100 { year + 2000 } else { year });
+
+    match (exp_year, exp_month) {
+        (Some(exp_year), Some(exp_month)) => {
+            (exp_year, exp_month) <= (window_end.year(), u8::from(window_end.month()))
+        }
+        _ => false,
+    }
+}
+
+/// Finds a connector supporting account updates which holds a mandate for the payment method,
+/// along with the identifier of the payment method at the connector
+async fn find_account_updater_connector(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &domain::PaymentMethod,
+) -> RouterResult<Option<(domain::MerchantConnectorAccount, String)>> {
+    let Some(mandate_reference) = payment_method
+        .connector_mandate_details
+        .clone()
+        .map(|value| {
+            value.parse_value::<diesel_models::PaymentsMandateReference>("PaymentsMandateReference")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the connector mandate details")?
+    else {
+        return Ok(None);
+    };
+
+    for (merchant_connector_id, mandate_record) in mandate_reference.iter() {
+        let merchant_connector_account = match state
+            .store
+            .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                &state.into(),
+                merchant_id,
+                merchant_connector_id,
+                key_store,
+            )
+            .await
+        {
+            Ok(merchant_connector_account) => merchant_connector_account,
+            Err(error) if error.current_context().is_db_not_found() => continue,
+            Err(error) => {
+                return Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the merchant connector account"))
+            }
+        };
+        let is_account_update_supported =
+            api_models::enums::Connector::from_str(&merchant_connector_account.connector_name)
+                .map(|connector| ACCOUNT_UPDATER_SUPPORTED_CONNECTORS.contains(&connector))
+                .unwrap_or(false);
+        if is_account_update_supported && !merchant_connector_account.disabled.unwrap_or(false) {
+            return Ok(Some((
+                merchant_connector_account,
+                mandate_record.connector_mandate_id.clone(),
+            )));
+        }
+    }
+
+    Ok(None)
+}
+
+fn construct_account_update_router_data(
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: domain::MerchantConnectorAccount,
+    payment_method: &domain::PaymentMethod,
+    request: types::AccountUpdateRequestData,
+) -> RouterResult<types::AccountUpdateRouterData> {
+    let auth_type: types::ConnectorAuthType =
+        helpers::MerchantConnectorAccountType::DbVal(Box::new(merchant_connector_account.clone()))
+            .get_connector_account_details()
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: merchant_account.get_id().clone(),
+        connector: merchant_connector_account.connector_name.clone(),
+        customer_id: Some(payment_method.customer_id.clone()),
+        payment_id: id_type::PaymentId::get_irrelevant_id("account_update_flow")
+            .get_string_repr()
+            .to_owned(),
+        attempt_id: IRRELEVANT_ATTEMPT_ID_IN_ACCOUNT_UPDATE_FLOW.to_string(),
+        status: diesel_models::enums::AttemptStatus::default(),
+        payment_method: diesel_models::enums::PaymentMethod::Card,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        address: PaymentAddress::default(),
+        auth_type: diesel_models::enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.metadata.clone(),
+        connector_wallets_details: None,
+        amount_captured: None,
+        minor_amount_captured: None,
+        request,
+        response: Err(types::ErrorResponse::default()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        connector_customer: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        connector_request_reference_id:
+            IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_ACCOUNT_UPDATE_FLOW.to_string(),
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        test_mode: merchant_connector_account.test_mode,
+        payment_method_balance: None,
+        payment_method_status: None,
+        connector_api_version: None,
+        connector_http_status_code: None,
+        connector_request_id: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        refund_id: None,
+        dispute_id: None,
+        connector_response: None,
+        integrity_check: Ok(()),
+        connector_options: None,
+        additional_merchant_data: None,
+        header_payload: None,
+        connector_mandate_request_reference_id: None,
+    })
+}
+
+/// Adds the card re-issued by the issuer to the locker, returning the locker id of the new card.
+/// The card being replaced is left in the locker, so that the payment method keeps a usable card
+/// until it has been pointed at the new one.
+async fn add_updated_card_to_locker(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_method: &domain::PaymentMethod,
+    card: &CardDetailsPaymentMethod,
+    response: &types::AccountUpdateResponseData,
+) -> RouterResult<String> {
+    let card_reference = payment_method
+        .locker_id
+        .as_ref()
+        .unwrap_or(&payment_method.payment_method_id);
+    let card_from_locker = cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        card_reference,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error getting card from locker")?;
+
+    let updated_card = api::CardDetail {
+        card_number: response
+            .card_number
+            .clone()
+            .unwrap_or(card_from_locker.card_number),
+        card_exp_month: response
+            .card_exp_month
+            .clone()
+            .unwrap_or(card_from_locker.card_exp_month),
+        card_exp_year: response
+            .card_exp_year
+            .clone()
+            .unwrap_or(card_from_locker.card_exp_year),
+        card_holder_name: card_from_locker.name_on_card,
+        nick_name: card_from_locker.nick_name.map(Secret::new),
+        card_issuing_country: card.issuer_country.clone(),
+        card_network: card.card_network.clone(),
+        card_issuer: card.card_issuer.clone(),
+        card_type: card.card_type.clone(),
+    };
+    let payment_method_create = api::PaymentMethodCreate {
+        payment_method: payment_method.payment_method,
+        payment_method_type: payment_method.payment_method_type,
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: payment_method.payment_method_issuer_code,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card: Some(updated_card.clone()),
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        metadata: None,
+        customer_id: Some(payment_method.customer_id.clone()),
+        client_secret: payment_method.client_secret.clone(),
+        payment_method_data: None,
+        card_network: None,
+        billing: None,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+
+    let (locker_response, _) = Box::pin(cards::add_card_to_locker(
+        state,
+        payment_method_create,
+        &updated_card,
+        &payment_method.customer_id,
+        merchant_account,
+        None,
+    ))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add the updated card to the locker")?;
+
+    Ok(locker_response.payment_method_id)
+}
+
+/// Applies the card re-issued by the issuer to the payment method. Connectors which don't share
+/// the new card number keep the card up to date on their side, in which case the card in the
+/// locker is left as is, along with the details of the card, which describe the card in the
+/// locker.
+async fn update_payment_method_card(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: domain::PaymentMethod,
+    mut card: CardDetailsPaymentMethod,
+    response: &types::AccountUpdateResponseData,
+) -> RouterResult<domain::PaymentMethod> {
+    if response.status == types::AccountUpdateStatus::CardNumberUpdated
+        && card.saved_to_locker
+        && response.card_number.is_none()
+    {
+        logger::info!(
+            payment_method_id = %payment_method.get_id(),
+            "Card number updated by the connector without the new card number, the card in the locker is left as is"
+        );
+        return Ok(payment_method);
+    }
+
+    let is_card_in_locker = match response.status {
+        types::AccountUpdateStatus::ExpiryUpdated
+        | types::AccountUpdateStatus::CardNumberUpdated => card.saved_to_locker,
+        types::AccountUpdateStatus::NoChange | types::AccountUpdateStatus::Closed => false,
+    };
+    let replaced_card_reference = payment_method
+        .locker_id
+        .clone()
+        .unwrap_or_else(|| payment_method.payment_method_id.clone());
+    let locker_id = if is_card_in_locker {
+        Some(
+            add_updated_card_to_locker(state, merchant_account, &payment_method, &card, response)
+                .await?,
+        )
+    } else {
+        None
+    };
+
+    card.last4_digits = response.card_last4.clone().or(card.last4_digits);
+    card.expiry_month = response.card_exp_month.clone().or(card.expiry_month);
+    card.expiry_year = response.card_exp_year.clone().or(card.expiry_year);
+    let payment_method_data =
+        cards::create_encrypted_data(&state.into(), key_store, PaymentMethodsData::Card(card))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to encrypt payment method data")?;
+
+    let payment_method_update = match locker_id {
+        Some(locker_id) => storage::PaymentMethodUpdate::AdditionalDataUpdate {
+            payment_method_data: Some(payment_method_data.into()),
+            status: None,
+            locker_id: Some(locker_id),
+            network_token_requestor_reference_id: None,
+            payment_method: None,
+            payment_method_issuer: None,
+            payment_method_type: None,
+            network_token_locker_id: None,
+            network_token_payment_method_data: None,
+        },
+        None => storage::PaymentMethodUpdate::PaymentMethodDataUpdate {
+            payment_method_data: Some(payment_method_data.into()),
+        },
+    };
+    let payment_method = state
+        .store
+        .update_payment_method(
+            &state.into(),
+            key_store,
+            payment_method,
+            payment_method_update,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the card of the payment method")?;
+
+    // The replaced card is only deleted once the payment method points at the new card
+    if is_card_in_locker {
+        cards::delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            &replaced_card_reference,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(?error, "Failed to delete the replaced card from the locker")
+        })
+        .ok();
+    }
+
+    Ok(payment_method)
+}
+
+/// Refreshes the card of a saved payment method through the account updater of a connector
+/// holding a mandate for it. The merchant is notified of updated cards through a
+/// `payment_method_updated` webhook, and of closed card accounts through a
+/// `payment_method_token_deleted` webhook. Returns `None` when no connector supporting account
+/// updates holds a mandate for the payment method.
+#[instrument(skip_all)]
+pub async fn refresh_payment_method_card(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: domain::PaymentMethod,
+) -> RouterResult<Option<PaymentMethodAccountUpdateResponse>> {
+    let card = get_card_details(&payment_method)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to obtain the card details of the payment method")?;
+    let Some((merchant_connector_account, connector_payment_method_id)) =
+        find_account_updater_connector(
+            state,
+            merchant_account.get_id(),
+            key_store,
+            &payment_method,
+        )
+        .await?
+    else {
+        return Ok(None);
+    };
+
+    let merchant_connector_id = merchant_connector_account.get_id();
+    let connector_name = merchant_connector_account.connector_name.clone();
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name,
+        api::GetToken::Connector,
+        Some(merchant_connector_id.clone()),
+    )?;
+    let connector_integration: services::BoxedAccountUpdateConnectorIntegrationInterface<
+        api::AccountUpdate,
+        types::AccountUpdateRequestData,
+        types::AccountUpdateResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data = construct_account_update_router_data(
+        merchant_account,
+        merchant_connector_account,
+        &payment_method,
+        types::AccountUpdateRequestData {
+            connector_payment_method_id,
+            card_last4: card.last4_digits.clone(),
+            card_exp_month: card.expiry_month.clone(),
+            card_exp_year: card.expiry_year.clone(),
+        },
+    )?;
+
+    let response = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .attach_printable("Failed while calling the account update connector api")?
+    .response
+    .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+        code: err.code,
+        message: err.message,
+        connector: connector_name,
+        status_code: err.status_code,
+        reason: err.reason,
+    })?;
+
+    let update_status = match response.status {
+        types::AccountUpdateStatus::NoChange => CardAccountUpdateStatus::NoChange,
+        types::AccountUpdateStatus::ExpiryUpdated => CardAccountUpdateStatus::ExpiryUpdated,
+        types::AccountUpdateStatus::CardNumberUpdated => CardAccountUpdateStatus::CardNumberUpdated,
+        types::AccountUpdateStatus::Closed => CardAccountUpdateStatus::Closed,
+    };
+    let payment_method = match response.status {
+        types::AccountUpdateStatus::NoChange => payment_method,
+        types::AccountUpdateStatus::ExpiryUpdated
+        | types::AccountUpdateStatus::CardNumberUpdated => {
+            let payment_method = update_payment_method_card(
+                state,
+                merchant_account,
+                key_store,
+                payment_method,
+                card,
+                &response,
+            )
+            .await?;
+            logger::info!(
+                payment_method_id = %payment_method.get_id(),
+                status = ?response.status,
+                "Card of the payment method updated by the account updater"
+            );
+            token_lifecycle::send_payment_method_webhook(
+                state,
+                merchant_account,
+                key_store,
+                &payment_method,
+                storage_enums::EventType::PaymentMethodUpdated,
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to send payment method updated webhook")
+            })
+            .ok();
+            payment_method
+        }
+        types::AccountUpdateStatus::Closed => {
+            token_lifecycle::update_payment_method_token_status(
+                state,
+                merchant_account,
+                key_store,
+                payment_method,
+                PaymentMethodStatus::Inactive,
+            )
+            .await?
+        }
+    };
+
+    Ok(Some(PaymentMethodAccountUpdateResponse {
+        update_status,
+        merchant_connector_id,
+        payment_method: token_lifecycle::to_payment_method_token_response(&payment_method),
+    }))
+}
+
+/// Refreshes the card of a saved payment method on demand, ahead of the periodic refresh
+#[instrument(skip_all)]
+pub async fn update_payment_method_account(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_method_id: String,
+) -> RouterResponse<PaymentMethodAccountUpdateResponse> {
+    let payment_method = state
+        .store
+        .find_payment_method(
+            &(&state).into(),
+            &key_store,
+            &payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    if &payment_method.merchant_id != merchant_account.get_id() {
+        return Err(report!(errors::ApiErrorResponse::PaymentMethodNotFound));
+    }
+
+    if payment_method.payment_method != Some(storage_enums::PaymentMethod::Card) {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "account updates are only supported for cards".to_string(),
+        }));
+    }
+    if payment_method.status != PaymentMethodStatus::Active {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "account updates are only supported for active payment methods".to_string(),
+        }));
+    }
+
+    let response =
+        refresh_payment_method_card(&state, &merchant_account, &key_store, payment_method)
+            .await?
+            .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+                message: "no connector supporting account updates holds a mandate for the card"
+                    .to_string(),
+            })?;
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+/// Refreshes the cards expiring soon of a batch of customers of the merchant. Failures to refresh
+/// a card are logged and do not hold the batch back, the card is retried with the next pass.
+#[instrument(skip_all)]
+pub async fn run_account_updater(
+    state: &SessionState,
+    tracking_data: &AccountUpdaterTrackingData,
+) -> RouterResult<AccountUpdaterRunOutcome> {
+    let config = &state.conf.account_updater;
+    if !config.enabled {
+        return Ok(AccountUpdaterRunOutcome::Stopped);
+    }
+
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = match db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+    {
+        Ok(key_store) => key_store,
+        Err(error) if error.current_context().is_db_not_found() => {
+            return Ok(AccountUpdaterRunOutcome::Stopped)
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the merchant key store"))
+        }
+    };
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let customers = db
+        .list_customers_by_merchant_id(
+            key_manager_state,
+            &tracking_data.merchant_id,
+            &key_store,
+            CustomerListConstraints {
+                limit: config.batch_size,
+                offset: Some(tracking_data.offset),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the customers of the merchant")?;
+
+    let window_end = date_time::now() + time::Duration::days(config.expiry_window_in_days);
+    for customer in customers.iter() {
+        let payment_methods = db
+            .find_payment_method_by_customer_id_merchant_id_list(
+                key_manager_state,
+                &key_store,
+                &customer.customer_id,
+                &tracking_data.merchant_id,
+                None,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment methods of the customer")?;
+
+        for payment_method in payment_methods {
+            let is_expiring_card = payment_method.status == PaymentMethodStatus::Active
+                && payment_method.payment_method == Some(storage_enums::PaymentMethod::Card)
+                && get_card_details(&payment_method)
+                    .is_some_and(|card| is_card_expiring(&card, window_end));
+            if !is_expiring_card {
+                continue;
+            }
+
+            let payment_method_id = payment_method.get_id().to_owned();
+            match refresh_payment_method_card(state, &merchant_account, &key_store, payment_method)
+                .await
+            {
+                Ok(Some(response)) => logger::info!(
+                    %payment_method_id,
+                    update_status = ?response.update_status,
+                    "Card of the payment method refreshed by the account updater"
+                ),
+                Ok(None) => {}
+                Err(error) => logger::error!(
+                    ?error,
+                    %payment_method_id,
+                    "Failed to refresh the card of the payment method"
+                ),
+            }
+        }
+    }
+
+    let customers_count = u32::try_from(customers.len())
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    if customers_count < u32::from(config.batch_size) {
+        Ok(AccountUpdaterRunOutcome::PassCompleted)
+    } else {
+        Ok(AccountUpdaterRunOutcome::NextBatch {
+            offset: tracking_data.offset.saturating_add(customers_count),
+        })
+    }
+}
@@ -75,12 +75,13 @@ use crate::{
         settings,
     },
     core::{
+        data_residency,
         errors::{self, StorageErrorExt},
         payment_methods::{
-            migration, network_tokenization, transformers as payment_methods, vault,
+            external_vault, migration, network_tokenization, transformers as payment_methods, vault,
         },
         payments::{
-            helpers,
+            connector_health, helpers,
             routing::{self, SessionFlowRoutingInput},
         },
         utils as core_utils,
@@ -2397,13 +2398,15 @@ pub async fn get_payment_method_from_hs_locker<'a>(
     payment_method_reference: &'a str,
     locker_choice: Option<api_enums::LockerChoice>,
 ) -> errors::CustomResult<Secret<String>, errors::VaultError> {
-    let locker = &state.conf.locker;
+    let locker = data_residency::get_locker_for_merchant(state, merchant_id)
+        .await
+        .change_context(errors::VaultError::FetchPaymentMethodFailed)?;
     let jwekey = state.conf.jwekey.get_inner();
 
     let payment_method_data = if !locker.mock_locker {
         let request = payment_methods::mk_get_card_request_hs(
             jwekey,
-            locker,
+            &locker,
             customer_id,
             merchant_id,
             payment_method_reference,
@@ -2453,13 +2456,29 @@ pub async fn add_card_to_hs_locker(
     customer_id: &id_type::CustomerId,
     locker_choice: api_enums::LockerChoice,
 ) -> errors::CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
-    let locker = &state.conf.locker;
+    let locker = data_residency::get_locker_for_merchant(state, payload.get_merchant_id())
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?;
     let jwekey = state.conf.jwekey.get_inner();
     let db = &*state.store;
-    let stored_card_response = if !locker.mock_locker {
+    let stored_card_response = if let Some(payload) =
+        external_vault::tokenize_store_locker_request(state, payload).await?
+    {
+        // Cards of merchant accounts using an external vault are kept there in place of the card
+        // vault, the record of the card only holds the alias of the card number
+        mock_call_to_locker_hs(
+            db,
+            &external_vault::generate_card_reference(),
+            &payload,
+            None,
+            None,
+            Some(customer_id),
+        )
+        .await?
+    } else if !locker.mock_locker {
         let request = payment_methods::mk_add_locker_request_hs(
             jwekey,
-            locker,
+            &locker,
             payload,
             locker_choice,
             state.tenant.tenant_id.clone(),
@@ -2629,13 +2648,15 @@ pub async fn get_card_from_hs_locker<'a>(
     card_reference: &'a str,
     locker_choice: api_enums::LockerChoice,
 ) -> errors::CustomResult<Card, errors::VaultError> {
-    let locker = &state.conf.locker;
+    let locker = data_residency::get_locker_for_merchant(state, merchant_id)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)?;
     let jwekey = &state.conf.jwekey.get_inner();
 
-    if !locker.mock_locker {
+    if !locker.mock_locker && !external_vault::is_external_vault_card_reference(card_reference) {
         let request = payment_methods::mk_get_card_request_hs(
             jwekey,
-            locker,
+            &locker,
             customer_id,
             merchant_id,
             card_reference,
@@ -2677,12 +2698,20 @@ pub async fn delete_card_from_hs_locker<'a>(
     merchant_id: &id_type::MerchantId,
     card_reference: &'a str,
 ) -> errors::CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
-    let locker = &state.conf.locker;
+    let locker = data_residency::get_locker_for_merchant(state, merchant_id)
+        .await
+        .change_context(errors::VaultError::DeleteCardFailed)?;
     let jwekey = &state.conf.jwekey.get_inner();
 
+    if external_vault::is_external_vault_card_reference(card_reference) {
+        return mock_delete_card_hs(&*state.store, card_reference)
+            .await
+            .change_context(errors::VaultError::DeleteCardFailed);
+    }
+
     let request = payment_methods::mk_delete_card_request_hs(
         jwekey,
-        locker,
+        &locker,
         customer_id,
         merchant_id,
         card_reference,
@@ -3242,6 +3271,34 @@ pub async fn list_payment_methods(
         response
     );
 
+    // Filter out payment methods offered by connectors which are currently unhealthy, as payments
+    // with them are not expected to succeed
+    let merchant_connector_ids = response
+        .iter()
+        .map(|intermediate| intermediate.merchant_connector_id.clone())
+        .collect::<HashSet<_>>();
+    let unhealthy_merchant_connector_ids =
+        futures::future::join_all(merchant_connector_ids.into_iter().map(
+            |merchant_connector_id| async {
+                connector_health::is_connector_unhealthy(&state, &merchant_connector_id)
+                    .await
+                    .then_some(merchant_connector_id)
+            },
+        ))
+        .await
+        .into_iter()
+        .flatten()
+        .collect::<HashSet<_>>();
+    if !unhealthy_merchant_connector_ids.is_empty() {
+        logger::info!(
+            ?unhealthy_merchant_connector_ids,
+            "Filtering out payment methods of unhealthy connectors"
+        );
+        response.retain(|intermediate| {
+            !unhealthy_merchant_connector_ids.contains(&intermediate.merchant_connector_id)
+        });
+    }
+
     // Filter out wallet payment method from mca if customer has already saved it
     customer
         .as_ref()
@@ -3517,6 +3574,7 @@ pub async fn list_payment_methods(
             payment_token: None,
             connector: None,
             straight_through_algorithm: Some(encoded),
+            routing_decision: None,
             amount_capturable: None,
             updated_by: merchant_account.storage_scheme.to_string(),
             merchant_connector_id: None,
@@ -4280,7 +4338,11 @@ pub async fn filter_payment_methods(
                         &payment_method_type_info,
                         req.installment_payment_enabled,
                     )
-                    && filter_amount_based(&payment_method_type_info, req.amount)
+                    && filter_amount_based(
+                        &payment_method_type_info,
+                        req.amount,
+                        payment_intent.and_then(|intent| intent.currency),
+                    )
                 {
                     let payment_method_object = payment_method_type_info.clone();
 
@@ -4447,14 +4509,11 @@ pub async fn filter_payment_methods(
 fn filter_amount_based(
     payment_method: &RequestPaymentMethodTypes,
     amount: Option<MinorUnit>,
+    currency: Option<api_enums::Currency>,
 ) -> bool {
-    let min_check = amount
-        .and_then(|amt| payment_method.minimum_amount.map(|min_amt| amt >= min_amt))
-        .unwrap_or(true);
-    let max_check = amount
-        .and_then(|amt| payment_method.maximum_amount.map(|max_amt| amt <= max_amt))
-        .unwrap_or(true);
-    (min_check && max_check) || amount == Some(MinorUnit::zero())
+    amount.map_or(true, |amount| {
+        payment_method.is_amount_supported(amount, currency) || amount == MinorUnit::zero()
+    })
 }
 
 fn filter_installment_based(
@@ -0,0 +1,325 @@
+use api_models::admin as admin_api;
+use cards::CardNumber;
+use common_utils::{
+    date_time,
+    errors::CustomResult,
+    ext_traits::{BytesExt, Encode, StringExt},
+    fp_utils, generate_id, id_type,
+    request::{RequestContent, RequestProxy},
+};
+use error_stack::{report, ResultExt};
+use masking::{Mask, PeekInterface};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use storage_impl::redis::cache::{self, CacheKind, CONFIG_CACHE};
+
+use super::transformers as payment_methods;
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        merchant_account_lifecycle::find_merchant_account,
+    },
+    db::StorageInterface,
+    headers,
+    routes::SessionState,
+    services::{self, ApplicationResponse},
+    types::storage,
+};
+
+/// External vault provider of a merchant account, stored in the configs table
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MerchantExternalVaultRecord {
+    pub provider: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub updated_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Serialize)]
+struct ExternalVaultTokenizeRequest {
+    #[serde(rename = "type")]
+    token_type: &'static str,
+    data: CardNumber,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExternalVaultTokenizeResponse {
+    /// Format preserving alias of the card number, the connectors receive it in place of the card
+    /// number and the forward proxy of the provider exchanges it for the card number
+    token: CardNumber,
+}
+
+/// Prefix of the references of the cards kept in an external vault instead of the card vault
+const EXTERNAL_VAULT_CARD_REFERENCE_PREFIX: &str = "ext_card";
+
+fn get_external_vault_config_key(merchant_id: &id_type::MerchantId) -> String {
+    format!("merchant_external_vault_{}", merchant_id.get_string_repr())
+}
+
+/// Key of the parsed external vault of the merchant account in the config cache
+fn get_external_vault_cache_key(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "merchant_external_vault_parsed_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Whether the card reference is that of a card kept in an external vault, whose record is not
+/// stored by the card vault
+pub fn is_external_vault_card_reference(card_reference: &str) -> bool {
+    card_reference.starts_with(EXTERNAL_VAULT_CARD_REFERENCE_PREFIX)
+}
+
+/// Fetches the external vault provider of the merchant account
+pub async fn get_merchant_external_vault(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<MerchantExternalVaultRecord> {
+    let find_external_vault_from_db = || async {
+        match db
+            .find_config_by_key_from_db(&get_external_vault_config_key(merchant_id))
+            .await
+        {
+            Ok(config) => config
+                .config
+                .parse_struct("MerchantExternalVaultRecord")
+                .change_context(errors::StorageError::DeserializationFailed)
+                .attach_printable("Failed to parse the merchant external vault"),
+            // Merchant accounts without the config use the card vault
+            Err(error) if error.current_context().is_db_not_found() => {
+                Ok(MerchantExternalVaultRecord::default())
+            }
+            Err(error) => Err(error),
+        }
+    };
+
+    // The external vault is looked up on every connector call of the merchant account, so the
+    // parsed record is cached and redacted whenever it is updated
+    cache::get_or_populate_in_memory(
+        db.get_cache_store().as_ref(),
+        &get_external_vault_cache_key(merchant_id),
+        find_external_vault_from_db,
+        &CONFIG_CACHE,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the merchant external vault")
+}
+
+async fn store_merchant_external_vault(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    record: &MerchantExternalVaultRecord,
+) -> RouterResult<()> {
+    let key = get_external_vault_config_key(merchant_id);
+    let config = record
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the merchant external vault")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                storage::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the merchant external vault")?;
+
+    cache::publish_into_redact_channel(
+        db.get_cache_store().as_ref(),
+        [CacheKind::Config(
+            get_external_vault_cache_key(merchant_id).into(),
+        )],
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to redact the cached merchant external vault")?;
+    Ok(())
+}
+
+/// The external vault provider of the merchant account, if the merchant account does not use the
+/// card vault
+pub async fn get_vault_provider_for_merchant<'a>(
+    state: &'a SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<&'a settings::ExternalVaultProvider>> {
+    let providers = &state.conf.external_vault.get_inner().providers;
+    if providers.is_empty() {
+        return Ok(None);
+    }
+
+    get_merchant_external_vault(state.store.as_ref(), merchant_id)
+        .await?
+        .provider
+        .map(|provider| {
+            providers
+                .get(&provider)
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!("External vault provider {provider} is not configured")
+                })
+        })
+        .transpose()
+}
+
+/// The forward proxy through which the connector requests of the merchant account are sent, so
+/// that the aliases in the requests are exchanged for the card numbers
+pub async fn get_request_proxy_for_merchant(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<RequestProxy>> {
+    Ok(get_vault_provider_for_merchant(state, merchant_id)
+        .await?
+        .map(|provider| RequestProxy {
+            url: provider.proxy_url.clone(),
+            ca_certificate: provider.proxy_ca_certificate.clone(),
+        }))
+}
+
+#[instrument(skip_all)]
+async fn tokenize_card_number(
+    state: &SessionState,
+    provider: &settings::ExternalVaultProvider,
+    card_number: &CardNumber,
+) -> CustomResult<CardNumber, errors::VaultError> {
+    let payload = ExternalVaultTokenizeRequest {
+        token_type: "card_number",
+        data: card_number.clone(),
+    };
+    let mut request = services::Request::new(services::Method::Post, &provider.tokenize_url);
+    request.add_header(headers::CONTENT_TYPE, "application/json".into());
+    request.add_header(
+        headers::AUTHORIZATION,
+        provider.api_key.peek().clone().into_masked(),
+    );
+    request.add_default_headers();
+    request.set_body(RequestContent::Json(Box::new(payload)));
+
+    let response = services::call_connector_api(state, request, "tokenize_in_external_vault")
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to call the external vault")?
+        .map_err(|error_response| {
+            report!(errors::VaultError::UnexpectedResponseError(
+                error_response.response
+            ))
+        })
+        .attach_printable("The external vault failed to tokenize the card number")?;
+
+    response
+        .response
+        .parse_struct::<ExternalVaultTokenizeResponse>("ExternalVaultTokenizeResponse")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)
+        .attach_printable("The external vault did not return a format preserving alias")
+        .map(|response| response.token)
+}
+
+/// Replaces the card number of the request with an alias from the external vault of the merchant
+/// account. Returns `None` for merchant accounts using the card vault and for requests which are
+/// not of cards.
+pub async fn tokenize_store_locker_request(
+    state: &SessionState,
+    payload: &payment_methods::StoreLockerReq,
+) -> CustomResult<Option<payment_methods::StoreLockerReq>, errors::VaultError> {
+    let store_card_req = match payload {
+        payment_methods::StoreLockerReq::LockerCard(store_card_req) => store_card_req,
+        payment_methods::StoreLockerReq::LockerGeneric(_) => return Ok(None),
+    };
+    let Some(provider) = get_vault_provider_for_merchant(state, &store_card_req.merchant_id)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?
+    else {
+        return Ok(None);
+    };
+
+    let card_number =
+        tokenize_card_number(state, provider, &store_card_req.card.card_number).await?;
+    let mut store_card_req = store_card_req.clone();
+    store_card_req.card.card_number = card_number;
+    Ok(Some(payment_methods::StoreLockerReq::LockerCard(
+        store_card_req,
+    )))
+}
+
+/// Reference of a card kept in an external vault
+pub fn generate_card_reference() -> String {
+    generate_id(consts::ID_LENGTH, EXTERNAL_VAULT_CARD_REFERENCE_PREFIX)
+}
+
+fn to_external_vault_response(
+    merchant_id: id_type::MerchantId,
+    record: MerchantExternalVaultRecord,
+) -> admin_api::MerchantExternalVaultResponse {
+    admin_api::MerchantExternalVaultResponse {
+        merchant_id,
+        provider: record.provider,
+        updated_at: record.updated_at,
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_merchant_external_vault(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<admin_api::MerchantExternalVaultResponse> {
+    find_merchant_account(&state, &merchant_id).await?;
+    let record = get_merchant_external_vault(state.store.as_ref(), &merchant_id).await?;
+
+    Ok(ApplicationResponse::Json(to_external_vault_response(
+        merchant_id,
+        record,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn update_merchant_external_vault(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    request: admin_api::MerchantExternalVaultRequest,
+) -> RouterResponse<admin_api::MerchantExternalVaultResponse> {
+    if let Some(provider) = request.provider.as_ref() {
+        fp_utils::when(
+            !state
+                .conf
+                .external_vault
+                .get_inner()
+                .providers
+                .contains_key(provider),
+            || {
+                Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("`{provider}` is not a configured external vault provider"),
+                }))
+            },
+        )?;
+    }
+
+    find_merchant_account(&state, &merchant_id).await?;
+    let record = MerchantExternalVaultRecord {
+        provider: request.provider,
+        updated_at: Some(date_time::now()),
+    };
+    store_merchant_external_vault(state.store.as_ref(), &merchant_id, &record).await?;
+
+    logger::info!(
+        merchant_id = %merchant_id.get_string_repr(),
+        provider = ?record.provider,
+        "Updated the external vault provider of the merchant account"
+    );
+
+    Ok(ApplicationResponse::Json(to_external_vault_response(
+        merchant_id,
+        record,
+    )))
+}
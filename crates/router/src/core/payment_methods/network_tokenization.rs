@@ -15,6 +15,11 @@ use masking::{ExposeInterface, Mask, PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 
 use super::transformers::DeleteCardResp;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+use crate::core::errors::StorageErrorExt;
 use crate::{
     core::{errors, payment_methods, payments::helpers},
     headers, logger,
@@ -697,3 +702,176 @@ pub async fn delete_network_token_from_tokenization_service(
             .attach_printable("Delete Token at Token service failed")
     }
 }
+
+/// Provisions a network token for a card which was saved without one, so that payments with the
+/// saved card can be routed to the connectors accepting network tokens
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub async fn provision_network_token(
+    state: routes::SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_method_id: String,
+) -> errors::RouterResponse<api_models::payment_methods::PaymentMethodTokenResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let payment_method = db
+        .find_payment_method(
+            key_manager_state,
+            &key_store,
+            &payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    if &payment_method.merchant_id != merchant_account.get_id() {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::PaymentMethodNotFound
+        ));
+    }
+
+    if payment_method.payment_method != Some(api_enums::PaymentMethod::Card) {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::PreconditionFailed {
+                message: "network tokens can only be provisioned for cards".to_string(),
+            }
+        ));
+    }
+    if payment_method
+        .network_token_requestor_reference_id
+        .is_some()
+    {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::PreconditionFailed {
+                message: "a network token is already provisioned for the payment method"
+                    .to_string(),
+            }
+        ));
+    }
+
+    let card = match helpers::fetch_card_details_from_locker(
+        &state,
+        &payment_method.customer_id,
+        merchant_account.get_id(),
+        payment_method
+            .locker_id
+            .as_ref()
+            .unwrap_or(&payment_method.payment_method_id),
+        None,
+    )
+    .await?
+    {
+        domain::PaymentMethodData::Card(card) => domain::Card {
+            card_network: card.card_network.or_else(|| {
+                payment_method
+                    .scheme
+                    .as_deref()
+                    .and_then(|scheme| scheme.parse::<api_enums::CardNetwork>().ok())
+            }),
+            ..card
+        },
+        _ => {
+            return Err(error_stack::report!(
+                errors::ApiErrorResponse::InternalServerError
+            ))
+            .attach_printable("Card details not found in the locker")
+        }
+    };
+    if !card.card_network.as_ref().is_some_and(|card_network| {
+        state
+            .conf
+            .network_tokenization_supported_card_networks
+            .card_networks
+            .contains(card_network)
+    }) {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::NotSupported {
+                message: "Network tokenization for the card network of the payment method"
+                    .to_string(),
+            }
+        ));
+    }
+
+    let payment_method_create_request = api::PaymentMethodCreate {
+        payment_method: payment_method.payment_method,
+        payment_method_type: payment_method.payment_method_type,
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: payment_method.payment_method_issuer_code,
+        card: None,
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        metadata: None,
+        customer_id: Some(payment_method.customer_id.clone()),
+        card_network: card
+            .card_network
+            .as_ref()
+            .map(|card_network| card_network.to_string()),
+        client_secret: None,
+        payment_method_data: None,
+        billing: None,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+    let (Some(network_token_resp), _, Some(network_token_requestor_ref_id)) = Box::pin(
+        crate::core::payments::tokenization::save_network_token_in_locker(
+            &state,
+            &merchant_account,
+            &card,
+            payment_method_create_request,
+        ),
+    )
+    .await?
+    else {
+        return Err(error_stack::report!(
+            errors::ApiErrorResponse::InternalServerError
+        ))
+        .attach_printable("Failed to provision a network token for the card");
+    };
+
+    let network_token_payment_method_data = match network_token_resp.card {
+        Some(token_details) => Some(
+            payment_methods::cards::create_encrypted_data(
+                key_manager_state,
+                &key_store,
+                PaymentMethodsData::Card(
+                    api_models::payment_methods::CardDetailsPaymentMethod::from(token_details),
+                ),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unable to encrypt network token data")?,
+        ),
+        None => None,
+    };
+
+    let payment_method = db
+        .update_payment_method(
+            key_manager_state,
+            &key_store,
+            payment_method,
+            crate::types::storage::PaymentMethodUpdate::AdditionalDataUpdate {
+                payment_method_data: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                payment_method_type: None,
+                payment_method_issuer: None,
+                network_token_requestor_reference_id: Some(network_token_requestor_ref_id),
+                network_token_locker_id: Some(network_token_resp.payment_method_id),
+                network_token_payment_method_data: network_token_payment_method_data
+                    .map(Into::into),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the network token of the payment method")?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_methods::token_lifecycle::to_payment_method_token_response(&payment_method),
+    ))
+}
@@ -0,0 +1,288 @@
+use api_models::payment_methods::{
+    NetworkTokenLifecycleEvent, NetworkTokenLifecycleEventType, PaymentMethodTokenResponse,
+    PaymentMethodsData,
+};
+use common_enums::PaymentMethodStatus;
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    logger,
+    routes::SessionState,
+    services,
+    types::{domain, storage, storage::enums as storage_enums, transformers::ForeignFrom},
+    utils::OptionExt,
+};
+
+/// Updates the status of the payment methods holding a network token on a lifecycle event sent by
+/// the network tokenization service. Suspended tokens are excluded from the saved payment methods
+/// of the customer until they are resumed, updated tokens have their expiry refreshed.
+#[instrument(skip_all)]
+pub async fn handle_network_token_lifecycle_event(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: NetworkTokenLifecycleEvent,
+) -> RouterResponse<()> {
+    let db: &dyn StorageInterface = &*state.store;
+    let payment_methods = db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            &(&state).into(),
+            &key_store,
+            &req.customer_id,
+            merchant_account.get_id(),
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment methods of the customer")?
+        .into_iter()
+        .filter(|payment_method| {
+            payment_method
+                .network_token_requestor_reference_id
+                .as_deref()
+                == Some(req.card_reference.as_str())
+        })
+        .collect::<Vec<_>>();
+    if payment_methods.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "no payment method holds the network token of this card reference".to_string(),
+        }));
+    }
+
+    let status = match req.event_type {
+        NetworkTokenLifecycleEventType::TokenSuspended => PaymentMethodStatus::Suspended,
+        NetworkTokenLifecycleEventType::TokenResumed => PaymentMethodStatus::Active,
+        NetworkTokenLifecycleEventType::TokenDeleted => PaymentMethodStatus::Inactive,
+        NetworkTokenLifecycleEventType::TokenUpdated => {
+            let token_expiry_month = req
+                .token_expiry_month
+                .get_required_value("token_expiry_month")?;
+            let token_expiry_year = req
+                .token_expiry_year
+                .get_required_value("token_expiry_year")?;
+            for payment_method in payment_methods {
+                update_network_token_expiry(
+                    &state,
+                    &merchant_account,
+                    &key_store,
+                    payment_method,
+                    token_expiry_month.clone(),
+                    token_expiry_year.clone(),
+                )
+                .await?;
+            }
+            return Ok(services::ApplicationResponse::StatusOk);
+        }
+    };
+    for payment_method in payment_methods {
+        // Payment methods which are yet to be activated or are already deactivated aren't revived
+        if !matches!(
+            payment_method.status,
+            PaymentMethodStatus::Active | PaymentMethodStatus::Suspended
+        ) {
+            logger::info!(
+                payment_method_id = %payment_method.get_id(),
+                current_status = %payment_method.status,
+                "Skipping the network token lifecycle event of the payment method"
+            );
+            continue;
+        }
+        update_payment_method_token_status(
+            &state,
+            &merchant_account,
+            &key_store,
+            payment_method,
+            status,
+        )
+        .await?;
+    }
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Updates the expiry of the network token held by a payment method, after the token has been
+/// re-issued by the card network. The status of the payment method is left untouched.
+async fn update_network_token_expiry(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: domain::PaymentMethod,
+    token_expiry_month: Secret<String>,
+    token_expiry_year: Secret<String>,
+) -> RouterResult<()> {
+    let Some(PaymentMethodsData::Card(mut token_details)) = payment_method
+        .network_token_payment_method_data
+        .clone()
+        .map(|data| data.into_inner().expose())
+        .and_then(|value| serde_json::from_value::<PaymentMethodsData>(value).ok())
+    else {
+        logger::info!(
+            payment_method_id = %payment_method.get_id(),
+            "No network token details found, skipping the network token expiry update"
+        );
+        return Ok(());
+    };
+    token_details.expiry_month = Some(token_expiry_month);
+    token_details.expiry_year = Some(token_expiry_year);
+
+    let network_token_payment_method_data = cards::create_encrypted_data(
+        &state.into(),
+        key_store,
+        PaymentMethodsData::Card(token_details),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to encrypt network token data")?;
+
+    let payment_method = state
+        .store
+        .update_payment_method(
+            &state.into(),
+            key_store,
+            payment_method,
+            storage::PaymentMethodUpdate::AdditionalDataUpdate {
+                payment_method_data: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                payment_method_type: None,
+                payment_method_issuer: None,
+                network_token_requestor_reference_id: None,
+                network_token_locker_id: None,
+                network_token_payment_method_data: Some(network_token_payment_method_data.into()),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the network token expiry of the payment method")?;
+    logger::info!(
+        payment_method_id = %payment_method.get_id(),
+        "Network token expiry of the payment method updated"
+    );
+
+    Ok(())
+}
+
+/// Updates the status of a payment method after a lifecycle event of its network token or
+/// connector token, and notifies the merchant when the status has changed
+pub async fn update_payment_method_token_status(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: domain::PaymentMethod,
+    status: PaymentMethodStatus,
+) -> RouterResult<domain::PaymentMethod> {
+    if payment_method.status == status {
+        return Ok(payment_method);
+    }
+
+    let payment_method = state
+        .store
+        .update_payment_method(
+            &state.into(),
+            key_store,
+            payment_method,
+            storage::PaymentMethodUpdate::StatusUpdate {
+                status: Some(status),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the status of the payment method")?;
+    logger::info!(
+        payment_method_id = %payment_method.get_id(),
+        %status,
+        "Token status of the payment method updated"
+    );
+
+    if let Some(event_type) = Option::<storage_enums::EventType>::foreign_from(status) {
+        send_payment_method_webhook(
+            state,
+            merchant_account,
+            key_store,
+            &payment_method,
+            event_type,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to send payment method token webhook"))
+        .ok();
+    }
+
+    Ok(payment_method)
+}
+
+/// Payment methods aren't tied to a profile, the webhook is sent through the default profile of
+/// the merchant
+pub async fn send_payment_method_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: &domain::PaymentMethod,
+    event_type: storage_enums::EventType,
+) -> RouterResult<()> {
+    let Some(profile_id) = merchant_account.default_profile.as_ref() else {
+        logger::info!("No default profile set, skipping the payment method token webhook");
+        return Ok(());
+    };
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        event_type,
+        storage_enums::EventClass::PaymentMethods,
+        payment_method.get_id().to_owned(),
+        storage_enums::EventObjectType::PaymentMethodTokenDetails,
+        api_models::webhooks::OutgoingWebhookContent::PaymentMethodTokenDetails(Box::new(
+            to_payment_method_token_response(payment_method),
+        )),
+        None,
+    ))
+    .await
+}
+
+pub fn to_payment_method_token_response(
+    payment_method: &domain::PaymentMethod,
+) -> PaymentMethodTokenResponse {
+    let card_details = payment_method
+        .payment_method_data
+        .clone()
+        .map(|data| data.into_inner().expose())
+        .and_then(|value| serde_json::from_value::<PaymentMethodsData>(value).ok())
+        .and_then(|data| match data {
+            PaymentMethodsData::Card(card) => Some(card),
+            PaymentMethodsData::BankDetails(_) => None,
+        });
+    PaymentMethodTokenResponse {
+        payment_method_id: payment_method.get_id().to_owned(),
+        customer_id: payment_method.customer_id.clone(),
+        payment_method: payment_method.payment_method,
+        payment_method_type: payment_method.payment_method_type,
+        status: payment_method.status,
+        card_last4: card_details
+            .as_ref()
+            .and_then(|card| card.last4_digits.clone()),
+        card_exp_month: card_details
+            .as_ref()
+            .and_then(|card| card.expiry_month.clone()),
+        card_exp_year: card_details.and_then(|card| card.expiry_year),
+    }
+}
@@ -27,7 +27,7 @@ use crate::{
     utils::OptionExt,
 };
 
-#[derive(Debug, Serialize)]
+#[derive(Clone, Debug, Serialize)]
 #[serde(untagged)]
 pub enum StoreLockerReq {
     LockerCard(StoreCardReq),
@@ -41,9 +41,16 @@ impl StoreLockerReq {
             Self::LockerGeneric(_) => (),
         }
     }
+
+    pub fn get_merchant_id(&self) -> &id_type::MerchantId {
+        match self {
+            Self::LockerCard(c) => &c.merchant_id,
+            Self::LockerGeneric(g) => &g.merchant_id,
+        }
+    }
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct StoreCardReq {
     pub merchant_id: id_type::MerchantId,
     pub merchant_customer_id: id_type::CustomerId,
@@ -53,7 +60,7 @@ pub struct StoreCardReq {
     pub ttl: i64,
 }
 
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct StoreGenericReq {
     pub merchant_id: id_type::MerchantId,
     pub merchant_customer_id: id_type::CustomerId,
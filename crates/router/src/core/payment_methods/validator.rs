@@ -151,5 +151,6 @@ pub async fn validate_request_and_initiate_payment_method_collect_link(
         session_expiry,
         ui_config: pm_collect_link_config,
         enabled_payment_methods: Some(enabled_payment_methods),
+        payment_method_id: None,
     })
 }
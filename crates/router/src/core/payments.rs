@@ -1,16 +1,41 @@
 pub mod access_token;
+#[cfg(feature = "v1")]
+pub mod challenge_page;
+#[cfg(feature = "v1")]
+pub mod client_token;
 pub mod conditional_configs;
+pub mod connector_health;
 pub mod connector_integration_v2_impls;
 pub mod customers;
+#[cfg(feature = "v1")]
+pub mod external_payments;
 pub mod flows;
+#[cfg(feature = "v1")]
+pub mod gift_card_balance;
 pub mod helpers;
 pub mod operations;
+#[cfg(feature = "v1")]
+pub mod orchestration_webhooks;
+#[cfg(feature = "v1")]
+pub mod order_reference;
+#[cfg(feature = "v1")]
+pub mod processing_limits;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod purchases;
+#[cfg(feature = "v1")]
+pub mod rate_limiter;
 #[cfg(feature = "retry")]
 pub mod retry;
+#[cfg(feature = "v1")]
+pub mod risk_enrichment;
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod shipments;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
+#[cfg(feature = "v1")]
+pub mod void_window;
 #[cfg(feature = "olap")]
 use std::collections::HashMap;
 use std::{
@@ -46,7 +71,7 @@ pub use hyperswitch_domain_models::{
     payment_address::PaymentAddress,
     payments::HeaderPayload,
     router_data::{PaymentMethodToken, RouterData},
-    router_request_types::CustomerDetails,
+    router_request_types::{fraud_check::PaymentRiskSignals, CustomerDetails},
 };
 use masking::{ExposeInterface, PeekInterface, Secret};
 use redis_interface::errors::RedisError;
@@ -75,15 +100,23 @@ use super::{
 };
 #[cfg(feature = "frm")]
 use crate::core::fraud_check as frm_core;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::core::metadata_schema;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::core::routing::helpers as routing_helpers;
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+use crate::core::simulation;
+#[cfg(feature = "v1")]
+use crate::core::{demo_connectors, experiments};
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::types::api::convert_connector_data_to_routable_connectors;
 use crate::{
     configs::settings::{ApplePayPreDecryptFlow, PaymentMethodTypeTokenFilter},
     connector::utils::missing_field_err,
     core::{
+        api_locking,
         errors::{self, CustomResult, RouterResponse, RouterResult},
+        localization,
         payment_methods::{cards, network_tokenization},
         payouts,
         routing::{self as core_routing},
@@ -320,6 +353,12 @@ where
         .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
         .attach_printable("Failed while fetching/creating customer")?;
 
+    // The risk signals are only used by the fraud checks and the routing of confirmed payments
+    if state.conf.risk_enrichment.enabled && is_operation_confirm(&operation) {
+        let risk_signals = risk_enrichment::get_payment_risk_signals(state, &payment_data).await;
+        payment_data.set_risk_signals(risk_signals);
+    }
+
     let authentication_type =
         call_decision_manager(state, &merchant_account, &business_profile, &payment_data).await?;
 
@@ -413,6 +452,23 @@ where
             )
             .await?;
 
+        if is_operation_confirm(&operation) {
+            let payment_attempt = payment_data.get_payment_attempt();
+            processing_limits::check_processing_limits(
+                state,
+                business_profile.get_id(),
+                payment_attempt.net_amount.get_total_amount(),
+                payment_attempt.currency,
+            )
+            .await?;
+            demo_connectors::check_and_record_demo_connector_usage(
+                state,
+                merchant_account.get_id(),
+                &connector_details,
+            )
+            .await?;
+        }
+
         if should_continue_transaction {
             #[cfg(feature = "frm")]
             match (
@@ -485,6 +541,10 @@ where
                     //add connector http status code metrics
                     add_connector_http_status_code_metrics(connector_http_status_code);
 
+                    // The lock may have expired while waiting on the connector, the response is
+                    // only written while the request still holds the fencing token of the lock
+                    api_locking::validate_fenced_lock(state, &req_state.fenced_lock_status).await?;
+
                     operation
                         .to_post_update_tracker()?
                         .save_pm_and_mandate(
@@ -622,6 +682,10 @@ where
                     //add connector http status code metrics
                     add_connector_http_status_code_metrics(connector_http_status_code);
 
+                    // The lock may have expired while waiting on the connector, the response is
+                    // only written while the request still holds the fencing token of the lock
+                    api_locking::validate_fenced_lock(state, &req_state.fenced_lock_status).await?;
+
                     operation
                         .to_post_update_tracker()?
                         .save_pm_and_mandate(
@@ -1089,6 +1153,7 @@ where
         payment_data.get_address(),
         payment_data.get_recurring_details(),
         payment_data.get_currency(),
+        payment_data.get_risk_signals(),
     );
     let algorithm_ref: api::routing::RoutingAlgorithmRef = merchant_account
         .routing_algorithm
@@ -1353,10 +1418,10 @@ where
     let (payment_data, _req, customer, connector_http_status_code, external_latency) =
         payments_operation_core::<_, _, _, _, _>(
             &state,
-            req_state,
+            req_state.clone(),
             merchant_account,
             profile_id,
-            key_store,
+            key_store.clone(),
             operation.clone(),
             req,
             call_connector_action,
@@ -1366,7 +1431,40 @@ where
         )
         .await?;
 
-    Res::generate_response(
+    if helpers::check_if_operation_confirm(&operation)
+        && is_awaiting_completion(payment_data.get_payment_intent().status)
+    {
+        add_pending_completion_task(&*state.store, payment_data.get_payment_attempt())
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to add pending completion task"))
+            .ok();
+    }
+
+    let payment_attempt = payment_data.get_payment_attempt();
+    let localizer = if payment_attempt.payment_method
+        == Some(storage_enums::PaymentMethod::BankTransfer)
+        || payment_attempt.error_code.is_some()
+        || payment_attempt.error_message.is_some()
+    {
+        Some(
+            localization::get_localizer(
+                &state,
+                &payment_data.get_payment_intent().merchant_id,
+                header_payload.locale.as_deref(),
+            )
+            .await,
+        )
+    } else {
+        None
+    };
+    let challenge_page_payment = is_start_pay(&operation).then(|| {
+        (
+            payment_data.get_payment_intent().clone(),
+            payment_data.get_payment_attempt().clone(),
+        )
+    });
+
+    let response = Res::generate_response(
         payment_data,
         customer,
         auth_flow,
@@ -1376,7 +1474,52 @@ where
         connector_http_status_code,
         external_latency,
         header_payload.x_hs_latency,
-    )
+    )?;
+
+    let response = match (challenge_page_payment, response) {
+        (
+            Some((payment_intent, payment_attempt)),
+            services::ApplicationResponse::Form(redirection_data),
+        ) => services::ApplicationResponse::Form(
+            challenge_page::serve_challenge_page(
+                &state,
+                &req_state,
+                &key_store,
+                payment_intent,
+                payment_attempt,
+                header_payload.locale.as_deref(),
+                redirection_data,
+            )
+            .await,
+        ),
+        (_, response) => response,
+    };
+
+    let response = match (localizer, response) {
+        (Some(localizer), services::ApplicationResponse::Json(response)) => {
+            services::ApplicationResponse::Json(Res::localize(response, &localizer))
+        }
+        (Some(localizer), services::ApplicationResponse::JsonWithHeaders((response, headers))) => {
+            services::ApplicationResponse::JsonWithHeaders((
+                Res::localize(response, &localizer),
+                headers,
+            ))
+        }
+        (_, response) => response,
+    };
+
+    Ok(match response {
+        services::ApplicationResponse::Json(response) => {
+            services::ApplicationResponse::Json(Res::attach_receipt_urls(response, &state))
+        }
+        services::ApplicationResponse::JsonWithHeaders((response, headers)) => {
+            services::ApplicationResponse::JsonWithHeaders((
+                Res::attach_receipt_urls(response, &state),
+                headers,
+            ))
+        }
+        response => response,
+    })
 }
 
 #[cfg(feature = "v1")]
@@ -1615,13 +1758,22 @@ pub trait PaymentRedirectFlow: Sync {
         );
         let connector = req.connector.clone().get_required_value("connector")?;
 
-        let query_params = req.param.clone().get_required_value("param")?;
-
         let resource_id = api::PaymentIdTypeExt::get_payment_intent_id(&req.resource_id)
             .change_context(errors::ApiErrorResponse::MissingRequiredField {
                 field_name: "payment_id",
             })?;
 
+        let (query_params, is_callback_verified) = helpers::verify_callback_url_signature(
+            &state,
+            &req.param.clone().get_required_value("param")?,
+            merchant_account.get_id(),
+            &resource_id,
+        )?;
+        let req = PaymentsRedirectResponseData {
+            param: Some(query_params.clone()),
+            ..req
+        };
+
         // This connector data is ephemeral, the call payment flow will get new connector data
         // with merchant account details, so the connector_id can be safely set to None here
         let connector_data = api::ConnectorData::get_connector_by_name(
@@ -1641,6 +1793,35 @@ pub trait PaymentRedirectFlow: Sync {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to decide the response flow")?;
 
+        // Anyone can call the callback urls of the router, the parameters of a callback which did
+        // not come through a url issued for the payment are never trusted to update the payment
+        let flow_type = match self.get_payment_action() {
+            _ if is_callback_verified => flow_type,
+            // Reached from the authentication flow of the router, not from a connector
+            services::PaymentAction::PaymentAuthenticateCompleteAuthorize => flow_type,
+            services::PaymentAction::CompleteAuthorize => {
+                return Err(report!(errors::ApiErrorResponse::GenericUnauthorized {
+                    message: "Signature of the callback url is invalid".to_string(),
+                }));
+            }
+            services::PaymentAction::PSync => {
+                logger::warn!(
+                    "Unverified redirect callback, syncing the payment with the connector"
+                );
+                CallConnectorAction::Trigger
+            }
+        };
+
+        #[cfg(feature = "v1")]
+        challenge_page::track_acs_callback(
+            &state,
+            &req_state,
+            merchant_account.get_id(),
+            &resource_id,
+            &connector,
+        )
+        .await;
+
         let payment_flow_response = self
             .call_payment_flow(
                 &state,
@@ -2135,6 +2316,7 @@ impl PaymentRedirectFlow for PaymentAuthenticateCompleteAuthorize {
                 payment_method_data: None,
                 amount: payments_response.amount.to_string(),
                 currency: payments_response.currency.clone(),
+                branding: None,
             },
         )))
     }
@@ -2206,6 +2388,18 @@ where
         .populate_payment_data(state, payment_data, merchant_account)
         .await?;
 
+    if helpers::check_if_operation_confirm(operation) {
+        let payment_attempt = payment_data.get_payment_attempt();
+        helpers::validate_amount_limits_for_payment_method_type(
+            &merchant_connector_account,
+            &connector.connector_name.to_string(),
+            payment_attempt.payment_method,
+            payment_attempt.payment_method_type,
+            payment_attempt.net_amount.get_total_amount(),
+            payment_attempt.currency,
+        )?;
+    }
+
     let (pd, tokenization_action) = get_connector_tokenization_action_when_confirm_true(
         state,
         operation,
@@ -2376,7 +2570,23 @@ where
         // This is added because few connector integrations do not update the status,
         // and rely on previous status set in router_data
         router_data.status = payment_data.get_payment_attempt().status;
-        router_data
+
+        let rate_limiter = match merchant_connector_account.get_mca_id() {
+            Some(merchant_connector_id) => {
+                rate_limiter::ConnectorRateLimiter::find(
+                    state,
+                    &connector.connector_name.to_string(),
+                    &merchant_connector_id,
+                )
+                .await
+            }
+            None => None,
+        };
+        if let Some(rate_limiter) = rate_limiter.as_ref() {
+            rate_limiter.wait_for_capacity(state).await;
+        }
+
+        let router_data = router_data
             .decide_flows(
                 state,
                 &connector,
@@ -2385,7 +2595,15 @@ where
                 business_profile,
                 header_payload.clone(),
             )
-            .await
+            .await?;
+
+        if let Some(rate_limiter) = rate_limiter.as_ref() {
+            if router_data.connector_http_status_code == Some(429) {
+                rate_limiter.record_rate_limit_exceeded(state).await;
+            }
+        }
+
+        Ok(router_data)
     } else {
         Ok(router_data)
     }?;
@@ -3912,6 +4130,7 @@ where
     pub poll_config: Option<router_types::PollConfig>,
     pub tax_data: Option<TaxData>,
     pub session_id: Option<String>,
+    pub risk_signals: Option<PaymentRiskSignals>,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -4039,10 +4258,12 @@ where
                     | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
             ) && payment_data.get_force_sync().unwrap_or(false)
         }
+        // Captured payments reach here only when they are within the void window of the connector
         "PaymentCancel" => matches!(
             payment_data.get_payment_intent().status,
             storage_enums::IntentStatus::RequiresCapture
                 | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+                | storage_enums::IntentStatus::Succeeded
         ),
         "PaymentCapture" => {
             matches!(
@@ -4165,6 +4386,14 @@ pub async fn apply_filters_on_payments(
         async {
             let limit = &constraints.limit;
             helpers::validate_payment_list_request_for_joins(*limit)?;
+            if let Some(metadata_filter) = &constraints.metadata {
+                metadata_schema::validate_metadata_filter(
+                    &state,
+                    merchant.get_id(),
+                    metadata_filter,
+                )
+                .await?;
+            }
             let db: &dyn StorageInterface = state.store.as_ref();
             let pi_fetch_constraints = (constraints.clone(), profile_id_list.clone()).try_into()?;
             let list: Vec<(storage::PaymentIntent, storage::PaymentAttempt)> = db
@@ -4356,11 +4585,40 @@ pub async fn get_aggregates_for_payments(
     profile_id_list: Option<Vec<id_type::ProfileId>>,
     time_range: common_utils::types::TimeRange,
 ) -> RouterResponse<api::PaymentsAggregateResponse> {
-    let db = state.store.as_ref();
-    let intent_status_with_count = db
-        .get_intent_status_with_count(merchant.get_id(), profile_id_list, &time_range)
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let auth = match profile_id_list.clone() {
+        Some(profile_ids) => common_utils::types::authentication::AuthInfo::ProfileLevel {
+            org_id: merchant.organization_id.clone(),
+            merchant_id: merchant.get_id().clone(),
+            profile_ids,
+        },
+        None => common_utils::types::authentication::AuthInfo::MerchantLevel {
+            org_id: merchant.organization_id.clone(),
+            merchant_ids: vec![merchant.get_id().clone()],
+        },
+    };
+
+    // The counts are read from the summary of the payment intent events, the payment intents are
+    // only scanned when the summary is not available
+    let intent_status_with_count = match analytics::payment_intents::get_status_counts(
+        &state.pool,
+        &auth,
+        &time_range,
+    )
+    .await
+    {
+        Ok(intent_status_with_count) => intent_status_with_count,
+        Err(error) => {
+            logger::warn!(
+                ?error,
+                "Failed to get the payment status counts from analytics"
+            );
+            state
+                .store
+                .get_intent_status_with_count(merchant.get_id(), profile_id_list, &time_range)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?
+        }
+    };
 
     let mut status_map: HashMap<enums::IntentStatus, i64> =
         intent_status_with_count.into_iter().collect();
@@ -4410,6 +4668,77 @@ pub async fn add_process_sync_task(
     Ok(())
 }
 
+/// Whether the payment is waiting for the customer or the connector to complete it
+#[cfg(feature = "v1")]
+fn is_awaiting_completion(status: storage_enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        storage_enums::IntentStatus::RequiresCustomerAction
+            | storage_enums::IntentStatus::Processing
+    )
+}
+
+/// Schedules the payment sync task of the payment, if the merchant has enabled the reconciliation
+/// of payments awaiting completion. A sync task already scheduled for the attempt is left as is.
+#[cfg(feature = "v1")]
+pub async fn add_pending_completion_task(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+) -> CustomResult<(), errors::StorageError> {
+    let config = get_pending_completion_config(db, &payment_attempt.merchant_id).await;
+    if !config.enabled {
+        return Ok(());
+    }
+
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+        "PAYMENTS_SYNC",
+        payment_attempt.get_id(),
+        &payment_attempt.merchant_id,
+    );
+    if db
+        .as_scheduler()
+        .find_process_by_id(&process_tracker_id)
+        .await?
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    add_process_sync_task(
+        db,
+        payment_attempt,
+        common_utils::date_time::now() + time::Duration::seconds(config.threshold_in_secs),
+    )
+    .await
+}
+
+#[cfg(feature = "v1")]
+async fn get_pending_completion_config(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> router_types::PendingCompletionConfig {
+    let config_key = router_types::PendingCompletionConfig::get_config_key(merchant_id);
+
+    db.find_config_by_key(&config_key)
+        .await
+        .ok()
+        .and_then(|config| {
+            config
+                .config
+                .parse_struct("PendingCompletionConfig")
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to parse the pending completion config for {}",
+                        config_key
+                    )
+                })
+                .ok()
+        })
+        .unwrap_or_default()
+}
+
 #[cfg(feature = "v2")]
 pub async fn reset_process_sync_task(
     db: &dyn StorageInterface,
@@ -4443,6 +4772,190 @@ pub async fn reset_process_sync_task(
     Ok(())
 }
 
+/// Tracking data of the task confirming a payment asynchronously. Only the references to the
+/// payment and to its saved payment method are stored, the rest of the confirm request is applied
+/// to the payment before the task is queued.
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct PaymentsConfirmTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub payment_id: id_type::PaymentId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub payment_token: Option<String>,
+    pub mandate_id: Option<String>,
+    pub recurring_details: Option<RecurringDetails>,
+}
+
+#[cfg(feature = "v1")]
+impl From<PaymentsConfirmTrackingData> for api::PaymentsRequest {
+    fn from(tracking_data: PaymentsConfirmTrackingData) -> Self {
+        Self {
+            payment_id: Some(api::PaymentIdType::PaymentIntentId(
+                tracking_data.payment_id,
+            )),
+            merchant_id: Some(tracking_data.merchant_id),
+            confirm: Some(true),
+            payment_token: tracking_data.payment_token,
+            mandate_id: tracking_data.mandate_id,
+            recurring_details: tracking_data.recurring_details,
+            ..Default::default()
+        }
+    }
+}
+
+/// Validates the confirm request, applies it to the payment and queues the confirmation to be
+/// processed by the scheduler, so that the connector call does not happen in the request-response
+/// cycle. The status of the payment can be polled with the returned URL, and is also delivered
+/// through webhooks.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn payments_confirm_async(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    mut req: api::PaymentsRequest,
+) -> RouterResponse<payments_api::PaymentsConfirmAsyncResponse> {
+    // Card data cannot be applied to the payment ahead of the confirmation, only saved payment
+    // methods can be referred to by the queued confirmation
+    let has_card_details = req.payment_method_data.is_some()
+        || matches!(
+            req.recurring_details,
+            Some(RecurringDetails::NetworkTransactionIdAndCardDetails(_))
+        );
+    utils::when(has_card_details, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`payment_method_data` and card details in `recurring_details` cannot be \
+                provided when `confirm_async` is true, use a saved payment method instead"
+                .to_string(),
+        })
+    })?;
+
+    let payment_id = api::PaymentIdTypeExt::get_payment_intent_id(
+        req.payment_id.as_ref().get_required_value("payment_id")?,
+    )
+    .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    helpers::validate_payment_status_against_not_allowed_statuses(
+        &payment_intent.status,
+        &[
+            storage_enums::IntentStatus::Cancelled,
+            storage_enums::IntentStatus::Succeeded,
+            storage_enums::IntentStatus::Processing,
+            storage_enums::IntentStatus::RequiresCapture,
+            storage_enums::IntentStatus::RequiresMerchantAction,
+            storage_enums::IntentStatus::RequiresCustomerAction,
+        ],
+        "confirm",
+    )?;
+
+    req.merchant_id = Some(merchant_account.get_id().to_owned());
+    req.confirm = Some(false);
+    req.confirm_async = None;
+    let tracking_data = PaymentsConfirmTrackingData {
+        merchant_id: merchant_account.get_id().to_owned(),
+        payment_id: payment_intent.payment_id.clone(),
+        profile_id: profile_id.clone(),
+        payment_token: req.payment_token.clone(),
+        mandate_id: req.mandate_id.clone(),
+        recurring_details: req.recurring_details.clone(),
+    };
+
+    // The fields of the confirm request are stored on the payment through an update, from where
+    // they are picked up by the queued confirmation
+    Box::pin(payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account,
+        profile_id,
+        key_store,
+        PaymentUpdate,
+        req,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    ))
+    .await?;
+
+    match add_payment_confirm_task(&*state.store, &payment_intent, tracking_data).await {
+        Ok(()) => {}
+        // The confirmation of this attempt is already queued
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::info!(
+                payment_id = ?payment_intent.payment_id,
+                "Payment confirmation is already queued"
+            );
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while adding payment confirm task to process tracker")
+        }
+    }
+
+    Ok(services::ApplicationResponse::JsonAccepted(
+        payments_api::PaymentsConfirmAsyncResponse {
+            poll_url: format!(
+                "{}/payments/{}",
+                state.base_url,
+                payment_intent.payment_id.get_string_repr()
+            ),
+            payment_id: payment_intent.payment_id,
+            status: payment_intent.status,
+        },
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn add_payment_confirm_task(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    tracking_data: PaymentsConfirmTrackingData,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PaymentsConfirmWorkflow;
+    let task = "PAYMENTS_CONFIRM";
+    let tag = ["CONFIRM", "PAYMENT"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        payment_intent.active_attempt.get_id().as_str(),
+        &payment_intent.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
 #[cfg(feature = "v1")]
 pub fn update_straight_through_routing<F, D>(
     payment_data: &mut D,
@@ -4512,6 +5025,22 @@ where
                     key_store,
                     payment_data,
                     Some(straight_through),
+                    None,
+                    eligible_connectors,
+                    mandate_type,
+                )
+                .await?
+            }
+
+            api::ConnectorChoice::Override(connector_override) => {
+                connector_selection(
+                    state,
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    payment_data,
+                    None,
+                    Some(connector_override),
                     eligible_connectors,
                     mandate_type,
                 )
@@ -4526,6 +5055,7 @@ where
                     key_store,
                     payment_data,
                     None,
+                    None,
                     eligible_connectors,
                     mandate_type,
                 )
@@ -4642,6 +5172,21 @@ where
                 )
                 .await?
             }
+            api::ConnectorChoice::Override(connector_override) => {
+                let straight_through =
+                    api::routing::StraightThroughAlgorithm::Single(Box::new(connector_override))
+                        .encode_to_value()
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to serialize the connector override")?;
+                get_eligible_connector_for_nti(
+                    state,
+                    key_store,
+                    payment_data,
+                    core_routing::StraightThroughAlgorithmTypeSingle(straight_through),
+                    business_profile,
+                )
+                .await?
+            }
             api::ConnectorChoice::SessionMultiple(_) => {
                 Err(errors::ApiErrorResponse::InternalServerError).attach_printable(
                     "Invalid routing rule configured for nti and card details based mit flow",
@@ -4676,6 +5221,7 @@ pub async fn connector_selection<F, D>(
     key_store: &domain::MerchantKeyStore,
     payment_data: &mut D,
     request_straight_through: Option<serde_json::Value>,
+    connector_override: Option<api::routing::RoutableConnectorChoice>,
     eligible_connectors: Option<Vec<enums::RoutableConnectors>>,
     mandate_type: Option<api::MandateTransactionType>,
 ) -> RouterResult<ConnectorCallType>
@@ -4720,6 +5266,7 @@ where
         key_store,
         payment_data,
         request_straight_through,
+        connector_override,
         &mut routing_data,
         eligible_connectors,
         mandate_type,
@@ -4749,6 +5296,7 @@ pub async fn decide_connector<F, D>(
     key_store: &domain::MerchantKeyStore,
     payment_data: &mut D,
     request_straight_through: Option<api::routing::StraightThroughAlgorithm>,
+    connector_override: Option<api::routing::RoutableConnectorChoice>,
     routing_data: &mut storage::RoutingData,
     eligible_connectors: Option<Vec<enums::RoutableConnectors>>,
     mandate_type: Option<api::MandateTransactionType>,
@@ -4769,6 +5317,7 @@ pub async fn decide_connector<F, D>(
     key_store: &domain::MerchantKeyStore,
     payment_data: &mut D,
     request_straight_through: Option<api::routing::StraightThroughAlgorithm>,
+    connector_override: Option<api::routing::RoutableConnectorChoice>,
     routing_data: &mut storage::RoutingData,
     eligible_connectors: Option<Vec<enums::RoutableConnectors>>,
     mandate_type: Option<api::MandateTransactionType>,
@@ -4817,6 +5366,21 @@ where
         return Ok(ConnectorCallType::PreDetermined(connector_data));
     }
 
+    if let Some(connector_override) = connector_override {
+        return route_connector_override_for_payments(
+            &state,
+            merchant_account,
+            business_profile,
+            key_store,
+            payment_data,
+            connector_override,
+            routing_data,
+            eligible_connectors,
+            mandate_type,
+        )
+        .await;
+    }
+
     if let Some((pre_routing_results, storage_pm_type)) =
         routing_data.routing_info.pre_routing_results.as_ref().zip(
             payment_data
@@ -4927,6 +5491,7 @@ where
                 payment_data.get_address(),
                 payment_data.get_recurring_details(),
                 payment_data.get_currency(),
+                payment_data.get_risk_signals(),
             );
 
             connectors = routing::perform_eligibility_analysis_with_fallback(
@@ -4936,6 +5501,7 @@ where
                 &TransactionData::Payment(transaction_data),
                 eligible_connectors,
                 business_profile,
+                None,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -4985,6 +5551,7 @@ where
                 payment_data.get_address(),
                 payment_data.get_recurring_details(),
                 payment_data.get_currency(),
+                payment_data.get_risk_signals(),
             );
 
             connectors = routing::perform_eligibility_analysis_with_fallback(
@@ -4994,6 +5561,7 @@ where
                 &TransactionData::Payment(transaction_data),
                 eligible_connectors,
                 business_profile,
+                None,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -5035,6 +5603,7 @@ where
         new_pd.get_address(),
         new_pd.get_recurring_details(),
         new_pd.get_currency(),
+        new_pd.get_risk_signals(),
     );
 
     route_connector_v1_for_payments(
@@ -5303,7 +5872,10 @@ where
         } else if connector_mandate_details
             .clone()
             .map(|connector_mandate_details| {
-                connector_mandate_details.contains_key(merchant_connector_id)
+                // Expired wallet tokens can't be used for merchant initiated payments
+                connector_mandate_details
+                    .get(merchant_connector_id)
+                    .is_some_and(|mandate_reference_record| mandate_reference_record.is_usable())
             })
             .unwrap_or(false)
         {
@@ -5664,12 +6236,15 @@ where
         algorithm_ref.algorithm_id
     };
 
+    let mut routing_decision = api::routing::RoutingDecisionTrace::default();
+
     let connectors = routing::perform_static_routing_v1(
         state,
         merchant_account.get_id(),
         routing_algorithm_id.as_ref(),
         business_profile,
         &TransactionData::Payment(transaction_data.clone()),
+        Some(&mut routing_decision),
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
@@ -5681,6 +6256,7 @@ where
         &TransactionData::Payment(transaction_data),
         eligible_connectors,
         business_profile,
+        Some(&mut routing_decision),
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -5721,20 +6297,185 @@ where
                         .and_then(|card_isin| card_isin.as_str())
                         .map(|card_isin| card_isin.to_string()),
                 );
-            routing::perform_success_based_routing(
+            match routing::perform_success_based_routing(
                 state,
                 connectors.clone(),
                 business_profile,
                 success_based_routing_config_params_interpolator,
             )
             .await
-            .map_err(|e| logger::error!(success_rate_routing_error=?e))
-            .unwrap_or(connectors)
+            {
+                Ok(connectors) => {
+                    routing_decision.dynamic_routing_applied = true;
+                    connectors
+                }
+                Err(error) => {
+                    logger::error!(success_rate_routing_error=?error);
+                    connectors
+                }
+            }
         } else {
             connectors
         }
     };
 
+    let experiment_decision = experiments::apply_experiments(
+        state,
+        business_profile.get_id(),
+        payment_data.get_payment_attempt(),
+        connectors,
+        mandate_type.is_some(),
+    )
+    .await;
+    if let Some(authentication_type) = experiment_decision.authentication_type {
+        payment_data.set_authentication_type_in_attempt(Some(authentication_type));
+    }
+    routing_decision.experiments = experiment_decision.assignments;
+    let connectors = experiment_decision.connectors;
+
+    let connectors = connector_health::exclude_unhealthy_connectors(state, connectors).await;
+    let preferred_connector = connectors.first().cloned();
+    let (connectors, degraded_connectors) =
+        connector_health::deprioritize_degraded_connectors(state, connectors).await;
+    orchestration_webhooks::trigger_degraded_connector_webhooks(
+        state,
+        merchant_account,
+        business_profile,
+        key_store,
+        payment_data.get_payment_intent(),
+        payment_data.get_payment_attempt(),
+        preferred_connector.as_ref(),
+        connectors.first(),
+        &degraded_connectors,
+    )
+    .await;
+    routing_decision.degraded_connectors = degraded_connectors;
+
+    // Merchants in the simulation mode have all their payments routed to the synthetic connector
+    #[cfg(feature = "dummy_connector")]
+    let connectors =
+        match simulation::get_simulation_connector(state, merchant_account.get_id()).await {
+            Some(connector) => {
+                routing_decision.simulation_mode_applied = true;
+                vec![connector]
+            }
+            None => connectors,
+        };
+
+    routing_decision.final_connectors = connectors.clone();
+    match routing_decision.encode_to_value() {
+        Ok(routing_decision) => {
+            payment_data.set_routing_decision_in_payment_attempt(routing_decision)
+        }
+        Err(error) => logger::error!(?error, "Failed to serialize the routing decision"),
+    }
+
+    let connector_data = connectors
+        .into_iter()
+        .map(|conn| {
+            api::ConnectorData::get_connector_by_name(
+                &state.conf.connectors,
+                &conn.connector.to_string(),
+                api::GetToken::Connector,
+                conn.merchant_connector_id,
+            )
+        })
+        .collect::<CustomResult<Vec<_>, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid connector name received")?;
+
+    decide_multiplex_connector_for_normal_or_recurring_payment(
+        state,
+        payment_data,
+        routing_data,
+        connector_data,
+        mandate_type,
+        business_profile.is_connector_agnostic_mit_enabled,
+        business_profile.is_network_tokenization_enabled,
+    )
+    .await
+}
+
+/// Routes the attempt to the connector it was forced to by the confirm request. The connector is
+/// still subject to the eligibility analysis, but the profile default fallback is not considered,
+/// so that the attempt is never routed to a connector other than the one chosen.
+#[cfg(feature = "v1")]
+#[allow(clippy::too_many_arguments)]
+async fn route_connector_override_for_payments<F, D>(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    payment_data: &mut D,
+    connector_override: api::routing::RoutableConnectorChoice,
+    routing_data: &mut storage::RoutingData,
+    eligible_connectors: Option<Vec<enums::RoutableConnectors>>,
+    mandate_type: Option<api::MandateTransactionType>,
+) -> RouterResult<ConnectorCallType>
+where
+    F: Send + Clone,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F> + Send + Sync + Clone,
+{
+    let new_pd = payment_data.clone();
+    let transaction_data = core_routing::PaymentsDslInput::new(
+        new_pd.get_setup_mandate(),
+        new_pd.get_payment_attempt(),
+        new_pd.get_payment_intent(),
+        new_pd.get_payment_method_data(),
+        new_pd.get_address(),
+        new_pd.get_recurring_details(),
+        new_pd.get_currency(),
+        new_pd.get_risk_signals(),
+    );
+
+    let connectors = routing::perform_eligibility_analysis(
+        state,
+        key_store,
+        vec![connector_override.clone()],
+        &TransactionData::Payment(transaction_data),
+        eligible_connectors.as_ref(),
+        business_profile.get_id(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("failed eligibility analysis of the connector override")?;
+
+    if connectors.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The connector '{}' is not eligible for this payment",
+                connector_override.connector
+            ),
+        }));
+    }
+
+    let mut routing_decision = api::routing::RoutingDecisionTrace {
+        connector_override: Some(connector_override),
+        eligible_connectors: connectors.clone(),
+        ..Default::default()
+    };
+
+    // Merchants in the simulation mode have all their payments routed to the synthetic connector
+    #[cfg(feature = "dummy_connector")]
+    let connectors =
+        match simulation::get_simulation_connector(state, merchant_account.get_id()).await {
+            Some(connector) => {
+                routing_decision.simulation_mode_applied = true;
+                vec![connector]
+            }
+            None => connectors,
+        };
+    #[cfg(not(feature = "dummy_connector"))]
+    let _ = merchant_account;
+
+    routing_decision.final_connectors = connectors.clone();
+    match routing_decision.encode_to_value() {
+        Ok(routing_decision) => {
+            payment_data.set_routing_decision_in_payment_attempt(routing_decision)
+        }
+        Err(error) => logger::error!(?error, "Failed to serialize the routing decision"),
+    }
+
     let connector_data = connectors
         .into_iter()
         .map(|conn| {
@@ -5806,6 +6547,7 @@ pub async fn route_connector_v1_for_payouts(
         routing_algorithm_id.as_ref(),
         business_profile,
         &TransactionData::Payout(transaction_data),
+        None,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
@@ -5816,6 +6558,7 @@ pub async fn route_connector_v1_for_payouts(
         &TransactionData::Payout(transaction_data),
         eligible_connectors,
         business_profile,
+        None,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -5903,6 +6646,38 @@ pub async fn payment_external_authentication(
         &[storage_enums::IntentStatus::RequiresCustomerAction],
         "authenticate",
     )?;
+    let three_ri_indicator = if req.device_channel
+        == api_models::payments::DeviceChannel::ThreeDsRequestorInitiated
+    {
+        if payment_intent.off_session != Some(true) {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "3DS Requestor Initiated authentication is only allowed for off-session payments".to_owned(),
+            })?
+        }
+        Some(
+            req.three_ri_indicator
+                .get_required_value("three_ri_indicator")?,
+        )
+    } else {
+        None
+    };
+    let prior_authentication_data = match req.prior_authentication_id {
+        Some(prior_authentication_id) => {
+            let prior_authentication = db
+                .find_authentication_by_merchant_id_authentication_id(
+                    merchant_id,
+                    prior_authentication_id.clone(),
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "No authentication found for prior_authentication_id {prior_authentication_id}"
+                    ),
+                })?;
+            Some((&prior_authentication).foreign_try_into()?)
+        }
+        None => None,
+    };
 
     let optional_customer = match &payment_intent.customer_id {
         Some(customer_id) => Some(
@@ -6054,6 +6829,8 @@ pub async fn payment_external_authentication(
         optional_customer.and_then(|customer| customer.email.map(pii::Email::from)),
         webhook_url,
         authentication_details.three_ds_requestor_url.clone(),
+        three_ri_indicator,
+        prior_authentication_data,
     ))
     .await?;
     Ok(services::ApplicationResponse::Json(
@@ -6130,6 +6907,7 @@ pub async fn payment_start_redirection(
             payment_method_data: None,
             amount: payment_attempt.amount_details.net_amount.to_string(),
             currency: payment_intent.amount_details.currency.to_string(),
+            branding: None,
         },
     )))
 }
@@ -6355,6 +7133,7 @@ pub trait OperationSessionGetters<F> {
     fn get_mandate_connector(&self) -> Option<&MandateConnectorDetails>;
     fn get_force_sync(&self) -> Option<bool>;
     fn get_capture_method(&self) -> Option<enums::CaptureMethod>;
+    fn get_risk_signals(&self) -> Option<&PaymentRiskSignals>;
 
     #[cfg(feature = "v2")]
     fn get_optional_payment_attempt(&self) -> Option<&storage::PaymentAttempt>;
@@ -6377,6 +7156,8 @@ pub trait OperationSessionSetters<F> {
     );
     #[cfg(feature = "v1")]
     fn set_capture_method_in_attempt(&mut self, capture_method: enums::CaptureMethod);
+    #[cfg(feature = "v1")]
+    fn set_risk_signals(&mut self, risk_signals: PaymentRiskSignals);
     fn set_frm_message(&mut self, frm_message: FraudCheck);
     fn set_payment_intent_status(&mut self, status: storage_enums::IntentStatus);
     fn set_authentication_type_in_attempt(
@@ -6399,6 +7180,8 @@ pub trait OperationSessionSetters<F> {
         &mut self,
         straight_through_algorithm: serde_json::Value,
     );
+    #[cfg(feature = "v1")]
+    fn set_routing_decision_in_payment_attempt(&mut self, routing_decision: serde_json::Value);
     fn set_connector_in_payment_attempt(&mut self, connector: Option<String>);
 }
 
@@ -6536,6 +7319,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentData<F> {
         Some(self.payment_intent.capture_method)
     }
 
+    fn get_risk_signals(&self) -> Option<&PaymentRiskSignals> {
+        self.risk_signals.as_ref()
+    }
+
     #[cfg(feature = "v2")]
     fn get_optional_payment_attempt(&self) -> Option<&storage::PaymentAttempt> {
         todo!();
@@ -6593,6 +7380,11 @@ impl<F: Clone> OperationSessionSetters<F> for PaymentData<F> {
         self.payment_attempt.capture_method = Some(capture_method);
     }
 
+    #[cfg(feature = "v1")]
+    fn set_risk_signals(&mut self, risk_signals: PaymentRiskSignals) {
+        self.risk_signals = Some(risk_signals);
+    }
+
     fn set_frm_message(&mut self, frm_message: FraudCheck) {
         self.frm_message = Some(frm_message);
     }
@@ -6644,6 +7436,11 @@ impl<F: Clone> OperationSessionSetters<F> for PaymentData<F> {
         self.payment_attempt.straight_through_algorithm = Some(straight_through_algorithm);
     }
 
+    #[cfg(feature = "v1")]
+    fn set_routing_decision_in_payment_attempt(&mut self, routing_decision: serde_json::Value) {
+        self.payment_attempt.routing_decision = Some(routing_decision);
+    }
+
     fn set_connector_in_payment_attempt(&mut self, connector: Option<String>) {
         self.payment_attempt.connector = connector;
     }
@@ -6772,6 +7569,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentIntentData<F> {
         todo!()
     }
 
+    fn get_risk_signals(&self) -> Option<&PaymentRiskSignals> {
+        None
+    }
+
     #[cfg(feature = "v2")]
     fn get_optional_payment_attempt(&self) -> Option<&storage::PaymentAttempt> {
         todo!();
@@ -6986,6 +7787,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentConfirmData<F> {
         todo!()
     }
 
+    fn get_risk_signals(&self) -> Option<&PaymentRiskSignals> {
+        None
+    }
+
     #[cfg(feature = "v2")]
     fn get_optional_payment_attempt(&self) -> Option<&storage::PaymentAttempt> {
         todo!();
@@ -7202,6 +8007,10 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentStatusData<F> {
         todo!()
     }
 
+    fn get_risk_signals(&self) -> Option<&PaymentRiskSignals> {
+        None
+    }
+
     #[cfg(feature = "v2")]
     fn get_optional_payment_attempt(&self) -> Option<&storage::PaymentAttempt> {
         self.payment_attempt.as_ref()
@@ -0,0 +1,196 @@
+use common_utils::{date_time, id_type};
+use router_env::{instrument, logger, tracing};
+
+use super::PaymentEvent;
+use crate::{
+    core::localization,
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{domain, storage, storage::enums as storage_enums},
+};
+
+const CHALLENGE_PAGE_SERVED_PREFIX: &str = "CHALLENGE_PAGE_SERVED";
+
+/// Time for which a served challenge page is awaiting the callback of the ACS, in seconds.
+/// Callbacks arriving later are not attributed to the challenge.
+const CHALLENGE_PAGE_SERVED_TTL_IN_SECS: i64 = 3600;
+
+fn get_challenge_page_served_key(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> String {
+    format!(
+        "{CHALLENGE_PAGE_SERVED_PREFIX}_{}_{}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+/// Whether the theme is a hex color or a named color, the theme is interpolated in the style
+/// attributes of the interstitial page
+fn is_valid_color(theme: &str) -> bool {
+    match theme.strip_prefix('#') {
+        Some(hex) => {
+            matches!(hex.len(), 3 | 4 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+        }
+        None => !theme.is_empty() && theme.chars().all(|c| c.is_ascii_alphabetic()),
+    }
+}
+
+/// Builds the branding of the interstitial page out of the payment link configuration of the
+/// profile, falling back to the default page on failures
+async fn get_redirection_form_branding(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    locale: Option<&str>,
+) -> services::RedirectionFormBranding {
+    let localizer = localization::get_localizer(state, &payment_intent.merchant_id, locale).await;
+    let processing_message = Some(localizer.translate("redirection.processing_payment"));
+
+    let Some(profile_id) = payment_intent.profile_id.as_ref() else {
+        return services::RedirectionFormBranding {
+            processing_message,
+            ..Default::default()
+        };
+    };
+    let payment_link_config = match state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+        .await
+    {
+        Ok(business_profile) => business_profile
+            .payment_link_config
+            .and_then(|config| config.default_config),
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the business profile for branding");
+            None
+        }
+    };
+
+    services::RedirectionFormBranding {
+        theme: payment_link_config
+            .as_ref()
+            .and_then(|config| config.theme.clone())
+            .filter(|theme| {
+                let is_valid_color = is_valid_color(theme);
+                if !is_valid_color {
+                    logger::warn!(%theme, "Ignoring the invalid theme of the payment link config");
+                }
+                is_valid_color
+            }),
+        logo: payment_link_config
+            .as_ref()
+            .and_then(|config| config.logo.clone()),
+        seller_name: payment_link_config.and_then(|config| config.seller_name),
+        processing_message,
+    }
+}
+
+/// Applies the branding of the profile to the interstitial page of a 3DS challenge, and tracks
+/// the page being served so that customers dropping off on the challenge can be told apart
+#[instrument(skip_all)]
+pub async fn serve_challenge_page(
+    state: &SessionState,
+    req_state: &ReqState,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: storage::PaymentIntent,
+    payment_attempt: storage::PaymentAttempt,
+    locale: Option<&str>,
+    mut redirection_data: Box<services::RedirectionFormData>,
+) -> Box<services::RedirectionFormData> {
+    redirection_data.branding =
+        Some(get_redirection_form_branding(state, key_store, &payment_intent, locale).await);
+
+    if payment_attempt.authentication_type != Some(storage_enums::AuthenticationType::ThreeDs) {
+        return redirection_data;
+    }
+
+    if let Ok(redis_conn) = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+    {
+        redis_conn
+            .set_key_with_expiry(
+                &get_challenge_page_served_key(
+                    &payment_intent.merchant_id,
+                    &payment_intent.payment_id,
+                ),
+                date_time::now_unix_timestamp(),
+                CHALLENGE_PAGE_SERVED_TTL_IN_SECS,
+            )
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to track the served challenge page"))
+            .ok();
+    }
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::ChallengePageServed))
+        .with(PaymentEvent {
+            payment_intent,
+            payment_attempt,
+        })
+        .emit();
+
+    redirection_data
+}
+
+/// Tracks the callback of the ACS for a challenge page served by the router. Callbacks of
+/// redirections which did not go through a served challenge page are not tracked.
+#[instrument(skip_all)]
+pub async fn track_acs_callback(
+    state: &SessionState,
+    req_state: &ReqState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    connector: &str,
+) {
+    let Ok(redis_conn) = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+    else {
+        return;
+    };
+    let key = get_challenge_page_served_key(merchant_id, payment_id);
+
+    // Fetched and deleted at once, so that concurrent callbacks are tracked only once
+    let served_at = match redis_conn.get_and_delete_key::<Option<i64>>(&key).await {
+        Ok(Some(served_at)) => served_at,
+        Ok(None) => return,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the served challenge page");
+            return;
+        }
+    };
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::AcsCallbackReceived {
+            merchant_id: merchant_id.clone(),
+            payment_id: payment_id.clone(),
+            connector: connector.to_string(),
+            challenge_duration_in_secs: date_time::now_unix_timestamp().saturating_sub(served_at),
+        }))
+        .emit();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_valid_color;
+
+    #[test]
+    fn test_is_valid_color() {
+        assert!(is_valid_color("#fff"));
+        assert!(is_valid_color("#1A2b3C"));
+        assert!(is_valid_color("#1a2b3c80"));
+        assert!(is_valid_color("navy"));
+        assert!(!is_valid_color("#12345"));
+        assert!(!is_valid_color("#ggg"));
+        assert!(!is_valid_color(""));
+        assert!(!is_valid_color("red; background: url(https://example.com)"));
+    }
+}
@@ -0,0 +1,333 @@
+use api_models::payments::{
+    ClientTokenScope, PaymentsClientTokenRequest, PaymentsClientTokenResponse,
+};
+use common_utils::{date_time, fp_utils, id_type};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::PaymentIntent;
+use masking::Secret;
+use redis_interface::{errors::RedisError, RedisConnectionPool};
+use router_env::{instrument, tracing};
+
+use super::helpers;
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::domain,
+};
+
+/// Marker following the payment id in client tokens. Client tokens keep the shape of client
+/// secrets, so that the payment id can be derived from either in the same way.
+const CLIENT_TOKEN_MARKER: &str = "_secret_cst";
+
+/// Moves the client token generation of a payment past both its current value and the current
+/// time of redis in milliseconds. Generations keep increasing after the key expires, as a rotation
+/// never issues a generation older than its own time, while tokens issued before any rotation are
+/// of the generation 0.
+const ROTATE_GENERATION_SCRIPT: &str = r#"
+local time = redis.call("TIME")
+local rotated_at = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+local generation = math.max(tonumber(redis.call("GET", KEYS[1]) or "0") + 1, rotated_at)
+redis.call("SET", KEYS[1], generation, "EX", ARGV[1])
+return generation
+"#;
+
+/// Client token as stored in redis, keyed by the token itself
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct StoredClientToken {
+    merchant_id: id_type::MerchantId,
+    payment_id: id_type::PaymentId,
+    scopes: Vec<ClientTokenScope>,
+    /// Generation of the client tokens of the payment at the time of issuing, tokens of an older
+    /// generation than the current one were revoked by a rotation
+    generation: i64,
+}
+
+fn get_client_token_key(client_token: &str) -> String {
+    format!("client_token_{client_token}")
+}
+
+fn get_generation_key(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> String {
+    format!(
+        "client_token_generation_{}_{}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+/// Key of the config enforcing client tokens for the merchant. The client secrets of the payments
+/// of the merchant are then rejected, only client tokens are accepted.
+fn get_client_tokens_enforced_key(merchant_id: &id_type::MerchantId) -> String {
+    format!("client_tokens_enforced_{}", merchant_id.get_string_repr())
+}
+
+async fn are_client_tokens_enforced(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<bool> {
+    state
+        .store
+        .find_config_by_key_unwrap_or(
+            &get_client_tokens_enforced_key(merchant_id),
+            Some("false".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the client tokens enforcement config")
+        .map(|config| config.config == "true")
+}
+
+/// Returns whether the client secret passed in a request is a client token
+pub fn is_client_token(client_secret: &str) -> bool {
+    client_secret.contains(&format!("{CLIENT_TOKEN_MARKER}_"))
+}
+
+fn get_redis_conn(state: &SessionState) -> RouterResult<std::sync::Arc<RedisConnectionPool>> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")
+}
+
+async fn get_current_generation(
+    redis_conn: &RedisConnectionPool,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<i64> {
+    redis_conn
+        .get_key::<Option<i64>>(&get_generation_key(merchant_id, payment_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the client token generation")
+        .map(Option::unwrap_or_default)
+}
+
+async fn find_payment_intent(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<PaymentIntent> {
+    state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+}
+
+#[instrument(skip_all)]
+pub async fn create_client_token(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentsClientTokenRequest,
+) -> RouterResponse<PaymentsClientTokenResponse> {
+    let payment_intent =
+        find_payment_intent(&state, &merchant_account, &key_store, &req.payment_id).await?;
+    let redis_conn = get_redis_conn(&state)?;
+    let generation = get_current_generation(
+        &redis_conn,
+        &payment_intent.merchant_id,
+        &payment_intent.payment_id,
+    )
+    .await?;
+
+    issue_client_token(&state, &redis_conn, &payment_intent, req, generation)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+/// Revokes all the client tokens issued for the payment so far and issues a new one
+#[instrument(skip_all)]
+pub async fn rotate_client_tokens(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentsClientTokenRequest,
+) -> RouterResponse<PaymentsClientTokenResponse> {
+    let payment_intent =
+        find_payment_intent(&state, &merchant_account, &key_store, &req.payment_id).await?;
+    let redis_conn = get_redis_conn(&state)?;
+
+    // The generation only needs to outlive the tokens issued before the rotation, the next
+    // rotation after it expired still issues a newer generation than all the earlier ones
+    let generation = redis_conn
+        .evaluate_redis_script::<_, i64>(
+            ROTATE_GENERATION_SCRIPT,
+            vec![get_generation_key(
+                &payment_intent.merchant_id,
+                &payment_intent.payment_id,
+            )],
+            vec![state.conf.client_token.max_ttl_in_secs.to_string()],
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to increment the client token generation")?;
+
+    issue_client_token(&state, &redis_conn, &payment_intent, req, generation)
+        .await
+        .map(ApplicationResponse::Json)
+}
+
+async fn issue_client_token(
+    state: &SessionState,
+    redis_conn: &RedisConnectionPool,
+    payment_intent: &PaymentIntent,
+    req: PaymentsClientTokenRequest,
+    generation: i64,
+) -> RouterResult<PaymentsClientTokenResponse> {
+    let config = &state.conf.client_token;
+
+    fp_utils::when(req.scopes.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "At least one scope must be provided for the client token".to_string(),
+        })
+    })?;
+
+    let expires_in = req.expires_in.unwrap_or(config.default_ttl_in_secs);
+    fp_utils::when(
+        expires_in == 0 || expires_in > config.max_ttl_in_secs,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`expires_in` must be between 1 and {} seconds",
+                    config.max_ttl_in_secs
+                ),
+            })
+        },
+    )?;
+
+    let client_token = common_utils::generate_id_with_default_len(&format!(
+        "{}{CLIENT_TOKEN_MARKER}",
+        payment_intent.payment_id.get_string_repr()
+    ));
+
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_client_token_key(&client_token),
+            StoredClientToken {
+                merchant_id: payment_intent.merchant_id.clone(),
+                payment_id: payment_intent.payment_id.clone(),
+                scopes: req.scopes.clone(),
+                generation,
+            },
+            i64::from(expires_in),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the client token")?;
+
+    Ok(PaymentsClientTokenResponse {
+        payment_id: payment_intent.payment_id.clone(),
+        client_token: Secret::new(client_token),
+        scopes: req.scopes,
+        expires_at: date_time::now().saturating_add(time::Duration::seconds(i64::from(expires_in))),
+    })
+}
+
+/// Authenticates the client secret passed in a request, which can either be the client secret of
+/// the payment or a client token granting the required scope. The client secret of the payment is
+/// rejected when client tokens are enforced for the merchant.
+pub async fn authenticate_client_secret_or_token(
+    state: &SessionState,
+    request_client_secret: Option<&String>,
+    payment_intent: &PaymentIntent,
+    required_scope: ClientTokenScope,
+) -> RouterResult<()> {
+    match request_client_secret {
+        Some(client_token) if is_client_token(client_token) => {
+            authenticate_client_token(state, client_token, payment_intent, required_scope).await
+        }
+        Some(_) if are_client_tokens_enforced(state, &payment_intent.merchant_id).await? => {
+            Err(report!(errors::ApiErrorResponse::ClientSecretInvalid))
+                .attach_printable("Client secrets are rejected as client tokens are enforced")
+        }
+        _ => Ok(helpers::authenticate_client_secret(
+            request_client_secret,
+            payment_intent,
+        )?),
+    }
+}
+
+async fn authenticate_client_token(
+    state: &SessionState,
+    client_token: &str,
+    payment_intent: &PaymentIntent,
+    required_scope: ClientTokenScope,
+) -> RouterResult<()> {
+    let redis_conn = get_redis_conn(state)?;
+
+    let stored_token = match redis_conn
+        .get_and_deserialize_key::<StoredClientToken>(
+            &get_client_token_key(client_token),
+            "StoredClientToken",
+        )
+        .await
+    {
+        Ok(stored_token) => stored_token,
+        Err(error) if matches!(error.current_context(), RedisError::NotFound) => {
+            return Err(report!(errors::ApiErrorResponse::ClientSecretExpired))
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the client token"))
+        }
+    };
+
+    fp_utils::when(
+        stored_token.merchant_id != payment_intent.merchant_id
+            || stored_token.payment_id != payment_intent.payment_id,
+        || Err(report!(errors::ApiErrorResponse::ClientSecretInvalid)),
+    )?;
+
+    fp_utils::when(!stored_token.scopes.contains(&required_scope), || {
+        Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: format!("{required_scope} scope of the client token"),
+        }))
+    })?;
+
+    let current_generation = get_current_generation(
+        &redis_conn,
+        &payment_intent.merchant_id,
+        &payment_intent.payment_id,
+    )
+    .await?;
+    fp_utils::when(stored_token.generation < current_generation, || {
+        Err(report!(errors::ApiErrorResponse::ClientSecretExpired))
+            .attach_printable("Client token was revoked by a rotation")
+    })?;
+
+    // Client tokens never outlive the session of the payment
+    let session_expiry = payment_intent.session_expiry.unwrap_or(
+        payment_intent
+            .created_at
+            .saturating_add(time::Duration::seconds(consts::DEFAULT_SESSION_EXPIRY)),
+    );
+    fp_utils::when(date_time::now() > session_expiry, || {
+        Err(report!(errors::ApiErrorResponse::ClientSecretExpired))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_client_token() {
+        assert!(is_client_token("pay_abc_secret_cst_Xy12"));
+        assert!(!is_client_token("pay_abc_secret_Xy12"));
+        assert!(!is_client_token("pay_abc_secret_cstXy12"));
+    }
+}
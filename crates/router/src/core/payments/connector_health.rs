@@ -0,0 +1,228 @@
+use api_models::routing as api_routing;
+use common_utils::id_type;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    routes::SessionState,
+    types::api::{self, ConnectorCommon},
+};
+
+pub const CONNECTOR_HEALTH_PREFIX: &str = "CONNECTOR_UNHEALTHY";
+pub const CONNECTOR_OUTAGE_PREFIX: &str = "CONNECTOR_OUTAGE";
+pub const CONNECTOR_OUTAGE_SUPPRESSION_PREFIX: &str = "CONNECTOR_OUTAGE_SUPPRESSED";
+
+fn get_connector_health_key(merchant_connector_id: &str) -> String {
+    format!("{CONNECTOR_HEALTH_PREFIX}_{merchant_connector_id}")
+}
+
+/// Returns the key of the outage of the connector at the host, or across all the hosts of the
+/// connector if no host is provided
+pub fn get_connector_outage_key(connector: &str, region: Option<&str>) -> String {
+    match region {
+        Some(region) => format!("{CONNECTOR_OUTAGE_PREFIX}_{connector}_{region}"),
+        None => format!("{CONNECTOR_OUTAGE_PREFIX}_{connector}"),
+    }
+}
+
+/// Returns the key of the suppression of the outage detection of the connector at the host, or
+/// across all the hosts of the connector if no host is provided
+pub fn get_connector_outage_suppression_key(connector: &str, region: Option<&str>) -> String {
+    match region {
+        Some(region) => format!("{CONNECTOR_OUTAGE_SUPPRESSION_PREFIX}_{connector}_{region}"),
+        None => format!("{CONNECTOR_OUTAGE_SUPPRESSION_PREFIX}_{connector}"),
+    }
+}
+
+/// Returns the host which the calls to the connector are made to, as configured in its base URL
+pub fn get_connector_region(state: &SessionState, connector: &str) -> Option<String> {
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector,
+        api::GetToken::Connector,
+        None,
+    )
+    .map_err(|error| logger::warn!(?error, "Failed to get the connector to find its host"))
+    .ok()?;
+
+    url::Url::parse(connector_data.connector.base_url(&state.conf.connectors))
+        .ok()?
+        .host_str()
+        .map(ToString::to_string)
+}
+
+/// Marks the merchant connector account as unhealthy for the provided duration, during which
+/// payment methods served only by it are not offered to customers
+#[instrument(skip_all)]
+pub async fn mark_connector_unhealthy(
+    state: &SessionState,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    reason: &str,
+    duration_in_secs: i64,
+) {
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .set_key_with_expiry(
+                    &get_connector_health_key(merchant_connector_id.get_string_repr()),
+                    reason.to_string(),
+                    duration_in_secs,
+                )
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    match result {
+        Ok(()) => logger::warn!(
+            merchant_connector_id = %merchant_connector_id.get_string_repr(),
+            reason,
+            duration_in_secs,
+            "Marked the connector as unhealthy"
+        ),
+        Err(error) => logger::error!(?error, "Failed to mark the connector as unhealthy"),
+    }
+}
+
+/// Clears the unhealthy mark of the merchant connector account, if any
+#[instrument(skip_all)]
+pub async fn mark_connector_healthy(
+    state: &SessionState,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) {
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .delete_key(&get_connector_health_key(
+                    merchant_connector_id.get_string_repr(),
+                ))
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to mark the connector as healthy");
+    }
+}
+
+/// Checks whether the merchant connector account is currently marked as unhealthy. Connectors are
+/// considered healthy if their health cannot be determined.
+#[instrument(skip_all)]
+pub async fn is_connector_unhealthy(state: &SessionState, merchant_connector_id: &str) -> bool {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for connector health"
+            );
+            return false;
+        }
+    };
+
+    redis_conn
+        .exists::<Vec<u8>>(&get_connector_health_key(merchant_connector_id))
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to check the connector health"))
+        .unwrap_or(false)
+}
+
+/// Removes the connectors whose merchant connector accounts are currently marked as unhealthy from
+/// the routing order. The connectors are left untouched if all of them are unhealthy, in which case
+/// the payment is attempted with them regardless.
+pub async fn exclude_unhealthy_connectors(
+    state: &SessionState,
+    connectors: Vec<api_routing::RoutableConnectorChoice>,
+) -> Vec<api_routing::RoutableConnectorChoice> {
+    let mut healthy_connectors = Vec::with_capacity(connectors.len());
+    for connector in &connectors {
+        let is_unhealthy = match connector.merchant_connector_id.as_ref() {
+            Some(merchant_connector_id) => {
+                is_connector_unhealthy(state, merchant_connector_id.get_string_repr()).await
+            }
+            None => false,
+        };
+        if !is_unhealthy {
+            healthy_connectors.push(connector.clone());
+        }
+    }
+
+    if healthy_connectors.is_empty() || healthy_connectors.len() == connectors.len() {
+        return connectors;
+    }
+
+    logger::info!(
+        excluded_connectors = connectors.len() - healthy_connectors.len(),
+        "Excluded the unhealthy connectors from the routing order"
+    );
+    healthy_connectors
+}
+
+/// Checks whether the connector is currently marked as degraded, either at the host or across all
+/// its hosts. Connectors are considered healthy if their status cannot be determined.
+#[instrument(skip_all)]
+pub async fn is_connector_degraded(
+    state: &SessionState,
+    connector: &str,
+    region: Option<&str>,
+) -> bool {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for connector outages"
+            );
+            return false;
+        }
+    };
+
+    let mut outage_keys = vec![get_connector_outage_key(connector, None)];
+    outage_keys.extend(region.map(|region| get_connector_outage_key(connector, Some(region))));
+
+    for outage_key in outage_keys {
+        let is_degraded = redis_conn
+            .exists::<()>(&outage_key)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to check the connector outage"))
+            .unwrap_or(false);
+        if is_degraded {
+            return true;
+        }
+    }
+    false
+}
+
+/// Moves the connectors which are currently degraded at the host their calls are made to to the
+/// end of the list, preserving the relative order of the connectors otherwise. Returns the
+/// reordered list along with the degraded connectors.
+pub async fn deprioritize_degraded_connectors(
+    state: &SessionState,
+    connectors: Vec<api_routing::RoutableConnectorChoice>,
+) -> (
+    Vec<api_routing::RoutableConnectorChoice>,
+    Vec<api_routing::RoutableConnectorChoice>,
+) {
+    let mut available_connectors = Vec::with_capacity(connectors.len());
+    let mut degraded_connectors = Vec::new();
+
+    for connector in connectors {
+        let connector_name = connector.connector.to_string();
+        let region = get_connector_region(state, &connector_name);
+        if is_connector_degraded(state, &connector_name, region.as_deref()).await {
+            degraded_connectors.push(connector);
+        } else {
+            available_connectors.push(connector);
+        }
+    }
+
+    if !degraded_connectors.is_empty() {
+        logger::info!(
+            ?degraded_connectors,
+            "Moved the degraded connectors to the end of the routing order"
+        );
+    }
+
+    available_connectors.extend(degraded_connectors.iter().cloned());
+    (available_connectors, degraded_connectors)
+}
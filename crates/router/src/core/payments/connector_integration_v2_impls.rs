@@ -48,6 +48,8 @@ mod dummy_connector_default_impl {
 
     impl<const T: u8> api::PaymentPostSessionTokensV2 for connector::DummyConnector<T> {}
 
+    impl<const T: u8> api::PaymentGiftCardBalanceCheckV2 for connector::DummyConnector<T> {}
+
     impl<const T: u8>
         services::ConnectorIntegrationV2<
             api::Authorize,
@@ -214,6 +216,15 @@ mod dummy_connector_default_impl {
         > for connector::DummyConnector<T>
     {
     }
+    impl<const T: u8>
+        services::ConnectorIntegrationV2<
+            api::Balance,
+            types::PaymentFlowData,
+            types::GiftCardBalanceCheckRequestData,
+            types::GiftCardBalanceCheckResponseData,
+        > for connector::DummyConnector<T>
+    {
+    }
 
     impl<const T: u8>
         services::ConnectorIntegrationV2<
@@ -441,6 +452,30 @@ mod dummy_connector_default_impl {
     {
     }
 
+    impl<const T: u8> api::ConnectorAccountBalanceV2 for connector::DummyConnector<T> {}
+
+    impl<const T: u8>
+        services::ConnectorIntegrationV2<
+            api::AccountBalance,
+            types::AccountBalanceFlowData,
+            types::AccountBalanceRequestData,
+            types::AccountBalanceResponseData,
+        > for connector::DummyConnector<T>
+    {
+    }
+
+    impl<const T: u8> api::ConnectorAccountUpdateV2 for connector::DummyConnector<T> {}
+
+    impl<const T: u8>
+        services::ConnectorIntegrationV2<
+            api::AccountUpdate,
+            types::AccountUpdateFlowData,
+            types::AccountUpdateRequestData,
+            types::AccountUpdateResponseData,
+        > for connector::DummyConnector<T>
+    {
+    }
+
     impl<const T: u8> api::FraudCheckV2 for connector::DummyConnector<T> {}
 
     #[cfg(feature = "frm")]
@@ -593,6 +628,7 @@ macro_rules! default_imp_for_new_connector_integration_payment {
             impl api::TaxCalculationV2 for $path::$connector{}
             impl api::PaymentSessionUpdateV2 for $path::$connector{}
             impl api::PaymentPostSessionTokensV2 for $path::$connector{}
+            impl api::PaymentGiftCardBalanceCheckV2 for $path::$connector{}
             impl
             services::ConnectorIntegrationV2<api::Authorize,types::PaymentFlowData, types::PaymentsAuthorizeData, types::PaymentsResponseData>
             for $path::$connector{}
@@ -685,6 +721,13 @@ macro_rules! default_imp_for_new_connector_integration_payment {
                 types::PaymentsPostSessionTokensData,
                 types::PaymentsResponseData,
                 > for $path::$connector{}
+
+            impl services::ConnectorIntegrationV2<
+            api::Balance,
+            types::PaymentFlowData,
+                types::GiftCardBalanceCheckRequestData,
+                types::GiftCardBalanceCheckResponseData,
+                > for $path::$connector{}
     )*
     };
 }
@@ -1127,6 +1170,7 @@ default_imp_for_new_connector_integration_payouts!(
     connector::Coinbase,
     connector::Cybersource,
     connector::Datatrans,
+    connector::Declarative,
     connector::Deutschebank,
     connector::Digitalvirgo,
     connector::Dlocal,
@@ -1745,6 +1789,126 @@ default_imp_for_new_connector_integration_webhook_source_verification!(
     connector::Plaid
 );
 
+macro_rules! default_imp_for_new_connector_integration_account_balance {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::ConnectorAccountBalanceV2 for $path::$connector {}
+            impl
+            services::ConnectorIntegrationV2<
+            api::AccountBalance,
+            types::AccountBalanceFlowData,
+            types::AccountBalanceRequestData,
+            types::AccountBalanceResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_new_connector_integration_account_balance!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Adyenplatform,
+    connector::Authorizedotnet,
+    connector::Bamboraapac,
+    connector::Bankofamerica,
+    connector::Bluesnap,
+    connector::Boku,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Ebanx,
+    connector::Globalpay,
+    connector::Gocardless,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Netcetera,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Paybox,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Placetopay,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Stripe,
+    connector::Trustpay,
+    connector::Threedsecureio,
+    connector::Wellsfargo,
+    connector::Wise,
+    connector::Plaid
+);
+
+macro_rules! default_imp_for_new_connector_integration_account_update {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::ConnectorAccountUpdateV2 for $path::$connector {}
+            impl
+            services::ConnectorIntegrationV2<
+            api::AccountUpdate,
+            types::AccountUpdateFlowData,
+            types::AccountUpdateRequestData,
+            types::AccountUpdateResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+default_imp_for_new_connector_integration_account_update!(
+    connector::Aci,
+    connector::Adyen,
+    connector::Adyenplatform,
+    connector::Authorizedotnet,
+    connector::Bamboraapac,
+    connector::Bankofamerica,
+    connector::Bluesnap,
+    connector::Boku,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Ebanx,
+    connector::Globalpay,
+    connector::Gocardless,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Netcetera,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Paybox,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Placetopay,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Stripe,
+    connector::Trustpay,
+    connector::Threedsecureio,
+    connector::Wellsfargo,
+    connector::Wise,
+    connector::Plaid
+);
+
 macro_rules! default_imp_for_new_connector_integration_frm {
     ($($path:ident::$connector:ident),*) => {
         $(
@@ -1774,6 +1938,7 @@ default_imp_for_new_connector_integration_frm!(
     connector::Coinbase,
     connector::Cybersource,
     connector::Datatrans,
+    connector::Declarative,
     connector::Deutschebank,
     connector::Digitalvirgo,
     connector::Dlocal,
@@ -2269,6 +2434,7 @@ default_imp_for_new_connector_integration_connector_authentication!(
     connector::Coinbase,
     connector::Cybersource,
     connector::Datatrans,
+    connector::Declarative,
     connector::Deutschebank,
     connector::Digitalvirgo,
     connector::Dlocal,
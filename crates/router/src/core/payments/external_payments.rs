@@ -0,0 +1,411 @@
+use std::collections::HashMap;
+
+use api_models::payments::{
+    ExternalPaymentImportResult, ExternalPaymentImportStatus, ExternalPaymentRecord,
+    ExternalPaymentsImportRequest, ExternalPaymentsImportResponse,
+};
+use common_utils::{
+    date_time, id_type,
+    types::{ConnectorTransactionId, MinorUnit},
+};
+use diesel_models::{enums as storage_enums, user::sample_data::PaymentAttemptBatchNew};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::{errors::StorageError, payments::PaymentIntent};
+use masking::ExposeInterface;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        metadata_schema, utils as core_utils,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::domain,
+};
+
+/// Records payments processed outside of hyperswitch as succeeded payments, with an attempt on the
+/// `external` connector. Payments whose external reference was already imported are reported as
+/// duplicates instead of being imported again.
+#[instrument(skip_all)]
+pub async fn import_external_payments(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: ExternalPaymentsImportRequest,
+) -> RouterResponse<ExternalPaymentsImportResponse> {
+    validate_import_request(&req, date_time::now())?;
+
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_account.get_id();
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        key_manager_state,
+        &key_store,
+        None,
+        None,
+        &merchant_account,
+        req.profile_id.as_ref(),
+        db,
+        true,
+    )
+    .await?;
+
+    let mut results = Vec::with_capacity(req.payments.len());
+    let mut payment_intents = Vec::new();
+    let mut payment_attempts = Vec::new();
+    // Payments imported by this request, so that references repeated in the request are imported
+    // only once
+    let mut imported_payments: HashMap<String, id_type::PaymentId> = HashMap::new();
+
+    for record in req.payments {
+        let existing_payment_id = match imported_payments.get(&record.external_reference_id) {
+            Some(payment_id) => Some(payment_id.clone()),
+            None => {
+                find_imported_payment_id(&state, merchant_id, &record.external_reference_id).await?
+            }
+        };
+
+        if let Some(payment_id) = existing_payment_id {
+            results.push(ExternalPaymentImportResult {
+                external_reference_id: record.external_reference_id,
+                payment_id,
+                status: ExternalPaymentImportStatus::Duplicate,
+            });
+            continue;
+        }
+
+        metadata_schema::validate_payment_metadata(&state, merchant_id, record.metadata.as_ref())
+            .await?;
+
+        if let Some(customer_id) = &record.customer_id {
+            db.find_customer_by_customer_id_merchant_id(
+                key_manager_state,
+                customer_id,
+                merchant_id,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+        }
+
+        let payment_id = id_type::PaymentId::default();
+        let external_reference_id = record.external_reference_id.clone();
+        let (payment_intent, payment_attempt) =
+            construct_external_payment(&merchant_account, &profile_id, payment_id.clone(), record);
+
+        imported_payments.insert(external_reference_id.clone(), payment_id.clone());
+        payment_intents.push(payment_intent);
+        payment_attempts.push(payment_attempt);
+        results.push(ExternalPaymentImportResult {
+            external_reference_id,
+            payment_id,
+            status: ExternalPaymentImportStatus::Imported,
+        });
+    }
+
+    if !payment_intents.is_empty() {
+        db.insert_payment_intents_batch_for_sample_data(
+            key_manager_state,
+            payment_intents,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the payment intents of external payments")?;
+
+        db.insert_payment_attempts_batch_for_sample_data(payment_attempts)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the payment attempts of external payments")?;
+    }
+
+    Ok(ApplicationResponse::Json(ExternalPaymentsImportResponse {
+        imported_count: imported_payments.len(),
+        duplicate_count: results.len() - imported_payments.len(),
+        payments: results,
+    }))
+}
+
+/// Whether the payment attempt records a payment processed outside of hyperswitch
+pub fn is_external_payment_attempt(connector: Option<&str>) -> bool {
+    connector == Some(consts::EXTERNAL_PAYMENTS_CONNECTOR)
+}
+
+fn validate_import_request(
+    req: &ExternalPaymentsImportRequest,
+    now: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    if req.payments.is_empty()
+        || req.payments.len() > consts::MAX_EXTERNAL_PAYMENTS_IMPORT_BATCH_SIZE
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "payments must contain between 1 and {} payments",
+                consts::MAX_EXTERNAL_PAYMENTS_IMPORT_BATCH_SIZE
+            ),
+        }));
+    }
+
+    for record in &req.payments {
+        if record.external_reference_id.is_empty()
+            || record.external_reference_id.len() > consts::MAX_EXTERNAL_REFERENCE_ID_LENGTH
+        {
+            return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "external_reference_id",
+            }));
+        }
+        if record.amount <= MinorUnit::new(0)
+            || record.amount > MinorUnit::new(consts::MAX_ALLOWED_AMOUNT)
+        {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "amount of external payment {} must be between 1 and {}",
+                    record.external_reference_id,
+                    consts::MAX_ALLOWED_AMOUNT
+                ),
+            }));
+        }
+        if record.captured_at > now {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "captured_at of external payment {} is in the future",
+                    record.external_reference_id
+                ),
+            }));
+        }
+    }
+
+    Ok(())
+}
+
+/// Finds the payment to which the external reference was imported earlier, if any
+async fn find_imported_payment_id(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    external_reference_id: &str,
+) -> RouterResult<Option<id_type::PaymentId>> {
+    // External payments are inserted directly in the database, so lookups skip the kv store
+    match state
+        .store
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            merchant_id,
+            external_reference_id,
+            storage_enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+    {
+        Ok(payment_attempt) => Ok(is_external_payment_attempt(
+            payment_attempt.connector.as_deref(),
+        )
+        .then_some(payment_attempt.payment_id)),
+        Err(error) if matches!(error.current_context(), StorageError::ValueNotFound(_)) => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find the payment attempt of an external payment"),
+    }
+}
+
+fn construct_external_payment(
+    merchant_account: &domain::MerchantAccount,
+    profile_id: &id_type::ProfileId,
+    payment_id: id_type::PaymentId,
+    record: ExternalPaymentRecord,
+) -> (PaymentIntent, PaymentAttemptBatchNew) {
+    let attempt_id = payment_id.get_attempt_id(1);
+    let now = date_time::now();
+    let updated_by = merchant_account.storage_scheme.to_string();
+    let organization_id = merchant_account.organization_id.clone();
+
+    let payment_intent = PaymentIntent {
+        payment_id: payment_id.clone(),
+        merchant_id: merchant_account.get_id().clone(),
+        status: storage_enums::IntentStatus::Succeeded,
+        amount: record.amount,
+        currency: Some(record.currency),
+        amount_captured: Some(record.amount),
+        customer_id: record.customer_id,
+        description: record.description,
+        return_url: None,
+        metadata: record.metadata.map(ExposeInterface::expose),
+        connector_id: None,
+        shipping_address_id: None,
+        billing_address_id: None,
+        statement_descriptor_name: None,
+        statement_descriptor_suffix: None,
+        // The payment is attributed to the time at which it was captured
+        created_at: record.captured_at,
+        modified_at: now,
+        last_synced: None,
+        setup_future_usage: None,
+        off_session: None,
+        client_secret: None,
+        active_attempt: hyperswitch_domain_models::RemoteStorageObject::ForeignID(
+            attempt_id.clone(),
+        ),
+        business_country: None,
+        business_label: None,
+        order_details: None,
+        allowed_payment_method_types: None,
+        connector_metadata: None,
+        feature_metadata: None,
+        attempt_count: 1,
+        profile_id: Some(profile_id.clone()),
+        merchant_decision: None,
+        payment_link_id: None,
+        payment_confirm_source: None,
+        updated_by: updated_by.clone(),
+        surcharge_applicable: None,
+        request_incremental_authorization: None,
+        incremental_authorization_allowed: None,
+        authorization_count: None,
+        fingerprint_id: None,
+        session_expiry: None,
+        request_external_three_ds_authentication: None,
+        charges: None,
+        frm_metadata: None,
+        customer_details: None,
+        billing_details: None,
+        merchant_order_reference_id: None,
+        shipping_details: None,
+        is_payment_processor_token_flow: None,
+        organization_id: organization_id.clone(),
+        shipping_cost: None,
+        tax_details: None,
+        skip_external_tax_calculation: None,
+        row_version: common_utils::consts::INITIAL_ROW_VERSION,
+    };
+
+    let (connector_transaction_id, connector_transaction_data) =
+        ConnectorTransactionId::form_id_and_data(record.external_reference_id.clone());
+    let payment_attempt = PaymentAttemptBatchNew {
+        attempt_id,
+        payment_id,
+        merchant_id: merchant_account.get_id().clone(),
+        status: storage_enums::AttemptStatus::Charged,
+        amount: record.amount,
+        currency: Some(record.currency),
+        save_to_locker: None,
+        connector: Some(consts::EXTERNAL_PAYMENTS_CONNECTOR.to_string()),
+        error_message: None,
+        offer_amount: None,
+        surcharge_amount: None,
+        tax_amount: None,
+        payment_method_id: None,
+        payment_method: record.payment_method,
+        capture_method: None,
+        capture_on: None,
+        confirm: true,
+        authentication_type: None,
+        created_at: record.captured_at,
+        modified_at: now,
+        last_synced: None,
+        cancellation_reason: None,
+        amount_to_capture: Some(record.amount),
+        mandate_id: None,
+        browser_info: None,
+        payment_token: None,
+        error_code: None,
+        connector_metadata: None,
+        payment_experience: None,
+        payment_method_type: record.payment_method_type,
+        payment_method_data: None,
+        business_sub_label: None,
+        straight_through_algorithm: None,
+        preprocessing_step_id: None,
+        mandate_details: None,
+        error_reason: None,
+        connector_response_reference_id: Some(record.external_reference_id),
+        connector_transaction_id: Some(connector_transaction_id),
+        multiple_capture_count: None,
+        amount_capturable: MinorUnit::new(0),
+        updated_by,
+        merchant_connector_id: None,
+        authentication_data: None,
+        encoded_data: None,
+        unified_code: None,
+        unified_message: None,
+        net_amount: Some(record.amount),
+        external_three_ds_authentication_attempted: None,
+        authentication_connector: None,
+        authentication_id: None,
+        mandate_data: None,
+        payment_method_billing_address_id: None,
+        fingerprint_id: None,
+        charge_id: None,
+        client_source: None,
+        client_version: None,
+        customer_acceptance: None,
+        profile_id: profile_id.clone(),
+        organization_id,
+        shipping_cost: None,
+        order_tax_amount: None,
+        connector_transaction_data,
+        connector_mandate_detail: None,
+    };
+
+    (payment_intent, payment_attempt)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn record(external_reference_id: &str, amount: i64) -> ExternalPaymentRecord {
+        ExternalPaymentRecord {
+            external_reference_id: external_reference_id.to_string(),
+            amount: MinorUnit::new(amount),
+            currency: common_enums::Currency::USD,
+            captured_at: datetime!(2024-11-01 10:00:00),
+            payment_method: Some(common_enums::PaymentMethod::Card),
+            payment_method_type: None,
+            customer_id: None,
+            description: None,
+            metadata: None,
+        }
+    }
+
+    fn request(payments: Vec<ExternalPaymentRecord>) -> ExternalPaymentsImportRequest {
+        ExternalPaymentsImportRequest {
+            profile_id: None,
+            payments,
+        }
+    }
+
+    #[test]
+    fn test_validate_import_request() {
+        let now = datetime!(2024-11-02 00:00:00);
+
+        assert!(validate_import_request(&request(vec![record("pos_1", 100)]), now).is_ok());
+        assert!(validate_import_request(&request(vec![]), now).is_err());
+        assert!(validate_import_request(&request(vec![record("", 100)]), now).is_err());
+        assert!(validate_import_request(&request(vec![record("pos_1", 0)]), now).is_err());
+        assert!(validate_import_request(
+            &request(vec![record("pos_1", consts::MAX_ALLOWED_AMOUNT + 1)]),
+            now
+        )
+        .is_err());
+
+        let mut future_record = record("pos_1", 100);
+        future_record.captured_at = datetime!(2024-11-03 00:00:00);
+        assert!(validate_import_request(&request(vec![future_record]), now).is_err());
+
+        let too_many = (0..=consts::MAX_EXTERNAL_PAYMENTS_IMPORT_BATCH_SIZE)
+            .map(|i| record(&format!("pos_{i}"), 100))
+            .collect();
+        assert!(validate_import_request(&request(too_many), now).is_err());
+    }
+
+    #[test]
+    fn test_is_external_payment_attempt() {
+        assert!(is_external_payment_attempt(Some("external")));
+        assert!(!is_external_payment_attempt(Some("stripe")));
+        assert!(!is_external_payment_attempt(None));
+    }
+}
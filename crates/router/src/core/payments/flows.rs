@@ -306,6 +306,144 @@ default_imp_for_webhook_source_verification!(
     connector::Wise
 );
 
+macro_rules! default_imp_for_account_balance {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::ConnectorAccountBalance for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::AccountBalance,
+            types::AccountBalanceRequestData,
+            types::AccountBalanceResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::ConnectorAccountBalance for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::AccountBalance,
+        types::AccountBalanceRequestData,
+        types::AccountBalanceResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+default_imp_for_account_balance!(
+    connector::Adyenplatform,
+    connector::Aci,
+    connector::Authorizedotnet,
+    connector::Bamboraapac,
+    connector::Bankofamerica,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Boku,
+    connector::Checkout,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Ebanx,
+    connector::Globalpay,
+    connector::Gocardless,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Netcetera,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Paybox,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Placetopay,
+    connector::Plaid,
+    connector::Prophetpay,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Wellsfargo,
+    connector::Wellsfargopayout,
+    connector::Wise
+);
+
+macro_rules! default_imp_for_account_update {
+    ($($path:ident::$connector:ident),*) => {
+        $(
+            impl api::ConnectorAccountUpdate for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+            api::AccountUpdate,
+            types::AccountUpdateRequestData,
+            types::AccountUpdateResponseData,
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::ConnectorAccountUpdate for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::AccountUpdate,
+        types::AccountUpdateRequestData,
+        types::AccountUpdateResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+default_imp_for_account_update!(
+    connector::Adyenplatform,
+    connector::Aci,
+    connector::Adyen,
+    connector::Authorizedotnet,
+    connector::Bamboraapac,
+    connector::Bankofamerica,
+    connector::Bluesnap,
+    connector::Braintree,
+    connector::Boku,
+    connector::Checkout,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Ebanx,
+    connector::Globalpay,
+    connector::Gocardless,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Netcetera,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Paybox,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Placetopay,
+    connector::Plaid,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Wellsfargo,
+    connector::Wellsfargopayout,
+    connector::Wise
+);
+
 macro_rules! default_imp_for_create_customer {
     ($($path:ident::$connector:ident),*) => {
         $(
@@ -366,7 +504,6 @@ default_imp_for_create_customer!(
     connector::Placetopay,
     connector::Plaid,
     connector::Prophetpay,
-    connector::Rapyd,
     connector::Riskified,
     connector::Signifyd,
     connector::Threedsecureio,
@@ -469,6 +606,7 @@ default_imp_for_connector_request_id!(
     connector::Cryptopay,
     connector::Cybersource,
     connector::Datatrans,
+    connector::Declarative,
     connector::Deutschebank,
     connector::Digitalvirgo,
     connector::Dlocal,
@@ -592,7 +730,6 @@ default_imp_for_accept_dispute!(
     connector::Placetopay,
     connector::Plaid,
     connector::Prophetpay,
-    connector::Rapyd,
     connector::Riskified,
     connector::Signifyd,
     connector::Stripe,
@@ -683,7 +820,6 @@ default_imp_for_file_upload!(
     connector::Placetopay,
     connector::Plaid,
     connector::Prophetpay,
-    connector::Rapyd,
     connector::Riskified,
     connector::Signifyd,
     connector::Threedsecureio,
@@ -752,7 +888,6 @@ default_imp_for_submit_evidence!(
     connector::Placetopay,
     connector::Plaid,
     connector::Prophetpay,
-    connector::Rapyd,
     connector::Riskified,
     connector::Signifyd,
     connector::Threedsecureio,
@@ -821,7 +956,6 @@ default_imp_for_defend_dispute!(
     connector::Placetopay,
     connector::Plaid,
     connector::Prophetpay,
-    connector::Rapyd,
     connector::Riskified,
     connector::Signifyd,
     connector::Stripe,
@@ -1006,7 +1140,6 @@ default_imp_for_payouts!(
     connector::Placetopay,
     connector::Plaid,
     connector::Prophetpay,
-    connector::Rapyd,
     connector::Riskified,
     connector::Signifyd,
     connector::Threedsecureio,
@@ -1074,7 +1207,6 @@ default_imp_for_payouts_create!(
     connector::Placetopay,
     connector::Plaid,
     connector::Prophetpay,
-    connector::Rapyd,
     connector::Riskified,
     connector::Signifyd,
     connector::Threedsecureio,
@@ -1218,7 +1350,6 @@ default_imp_for_payouts_eligibility!(
     connector::Placetopay,
     connector::Plaid,
     connector::Prophetpay,
-    connector::Rapyd,
     connector::Riskified,
     connector::Signifyd,
     connector::Stripe,
@@ -1284,7 +1415,6 @@ default_imp_for_payouts_fulfill!(
     connector::Placetopay,
     connector::Plaid,
     connector::Prophetpay,
-    connector::Rapyd,
     connector::Riskified,
     connector::Signifyd,
     connector::Threedsecureio,
@@ -1353,7 +1483,6 @@ default_imp_for_payouts_cancel!(
     connector::Placetopay,
     connector::Plaid,
     connector::Prophetpay,
-    connector::Rapyd,
     connector::Riskified,
     connector::Signifyd,
     connector::Threedsecureio,
@@ -1423,7 +1552,6 @@ default_imp_for_payouts_quote!(
     connector::Placetopay,
     connector::Plaid,
     connector::Prophetpay,
-    connector::Rapyd,
     connector::Riskified,
     connector::Signifyd,
     connector::Stripe,
@@ -1494,7 +1622,6 @@ default_imp_for_payouts_recipient!(
     connector::Placetopay,
     connector::Plaid,
     connector::Prophetpay,
-    connector::Rapyd,
     connector::Riskified,
     connector::Signifyd,
     connector::Threedsecureio,
@@ -1754,6 +1881,7 @@ default_imp_for_fraud_check!(
     connector::Cybersource,
     connector::Coinbase,
     connector::Datatrans,
+    connector::Declarative,
     connector::Deutschebank,
     connector::Digitalvirgo,
     connector::Dlocal,
@@ -2212,7 +2340,6 @@ default_imp_for_incremental_authorization!(
     connector::Bluesnap,
     connector::Boku,
     connector::Braintree,
-    connector::Checkout,
     connector::Datatrans,
     connector::Ebanx,
     connector::Globalpay,
@@ -2418,6 +2545,7 @@ default_imp_for_connector_authentication!(
     connector::Coinbase,
     connector::Cybersource,
     connector::Datatrans,
+    connector::Declarative,
     connector::Deutschebank,
     connector::Digitalvirgo,
     connector::Dlocal,
@@ -2753,3 +2881,72 @@ default_imp_for_post_session_tokens!(
     connector::Wellsfargopayout,
     connector::Wise
 );
+
+macro_rules! default_imp_for_gift_card_balance_check {
+    ($($path:ident::$connector:ident),*) => {
+        $( impl api::PaymentGiftCardBalanceCheck for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+                api::Balance,
+                types::GiftCardBalanceCheckRequestData,
+                types::GiftCardBalanceCheckResponseData
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PaymentGiftCardBalanceCheck for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::Balance,
+        types::GiftCardBalanceCheckRequestData,
+        types::GiftCardBalanceCheckResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+
+default_imp_for_gift_card_balance_check!(
+    connector::Aci,
+    connector::Adyenplatform,
+    connector::Authorizedotnet,
+    connector::Bamboraapac,
+    connector::Bankofamerica,
+    connector::Bluesnap,
+    connector::Boku,
+    connector::Braintree,
+    connector::Checkout,
+    connector::Cybersource,
+    connector::Datatrans,
+    connector::Ebanx,
+    connector::Globalpay,
+    connector::Gocardless,
+    connector::Gpayments,
+    connector::Iatapay,
+    connector::Itaubank,
+    connector::Klarna,
+    connector::Mifinity,
+    connector::Netcetera,
+    connector::Nmi,
+    connector::Noon,
+    connector::Nuvei,
+    connector::Opayo,
+    connector::Opennode,
+    connector::Paybox,
+    connector::Payme,
+    connector::Payone,
+    connector::Paypal,
+    connector::Placetopay,
+    connector::Plaid,
+    connector::Prophetpay,
+    connector::Rapyd,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Stripe,
+    connector::Threedsecureio,
+    connector::Trustpay,
+    connector::Wellsfargo,
+    connector::Wellsfargopayout,
+    connector::Wise
+);
@@ -118,7 +118,7 @@ impl
             types::PaymentsResponseData,
         >,
     > {
-        Box::pin(transformers::construct_payment_router_data::<
+        let mut router_data = Box::pin(transformers::construct_payment_router_data::<
             api::Authorize,
             types::PaymentsAuthorizeData,
         >(
@@ -132,7 +132,48 @@ impl
             merchant_recipient_data,
             header_payload,
         ))
-        .await
+        .await?;
+
+        // The statement descriptor provided with the payment takes precedence over the soft
+        // descriptor configured for the profile
+        let payment_descriptors = crate::core::payment_descriptors::get_payment_descriptors(
+            state,
+            merchant_account,
+            &self.payment_intent,
+            router_data
+                .connector
+                .parse::<api_models::enums::Connector>()
+                .ok(),
+            match &router_data.request.payment_method_data {
+                domain::PaymentMethodData::Card(card) => card.card_network.as_ref(),
+                _ => None,
+            },
+        )
+        .await;
+        router_data.request.merchant_category_code = payment_descriptors.merchant_category_code;
+        match payment_descriptors.soft_descriptor {
+            Some(crate::core::payment_descriptors::SoftDescriptor::StatementDescriptor(
+                soft_descriptor,
+            )) => {
+                router_data.request.statement_descriptor = router_data
+                    .request
+                    .statement_descriptor
+                    .take()
+                    .or(Some(soft_descriptor));
+            }
+            Some(crate::core::payment_descriptors::SoftDescriptor::StatementDescriptorSuffix(
+                soft_descriptor,
+            )) => {
+                router_data.request.statement_descriptor_suffix = router_data
+                    .request
+                    .statement_descriptor_suffix
+                    .take()
+                    .or(Some(soft_descriptor));
+            }
+            None => (),
+        }
+
+        Ok(router_data)
     }
 
     async fn get_merchant_recipient_data<'a>(
@@ -173,7 +214,7 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
         connector: &api::ConnectorData,
         call_connector_action: payments::CallConnectorAction,
         connector_request: Option<services::Request>,
-        _business_profile: &domain::Profile,
+        business_profile: &domain::Profile,
         _header_payload: hyperswitch_domain_models::payments::HeaderPayload,
     ) -> RouterResult<Self> {
         let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
@@ -185,15 +226,19 @@ impl Feature<api::Authorize, types::PaymentsAuthorizeData> for types::PaymentsAu
         if self.should_proceed_with_authorize() {
             self.decide_authentication_type();
             logger::debug!(auth_type=?self.auth_type);
-            let mut new_router_data = services::execute_connector_processing_step(
-                state,
-                connector_integration,
-                &self,
-                call_connector_action,
-                connector_request,
-            )
-            .await
-            .to_payment_failed_response()?;
+            let time_budget_config =
+                helpers::get_connector_time_budget_config(state, business_profile.get_id()).await;
+            let mut new_router_data =
+                helpers::execute_connector_processing_step_within_time_budget(
+                    state,
+                    connector_integration,
+                    &self,
+                    call_connector_action,
+                    connector_request,
+                    time_budget_config.authorize_in_millis,
+                )
+                .await
+                .to_payment_failed_response()?;
 
             // Initiating Integrity check
             let integrity_result = helpers::check_integrity_based_on_flow(
@@ -82,7 +82,7 @@ impl Feature<api::Capture, types::PaymentsCaptureData>
         connector: &api::ConnectorData,
         call_connector_action: payments::CallConnectorAction,
         connector_request: Option<services::Request>,
-        _business_profile: &domain::Profile,
+        business_profile: &domain::Profile,
         _header_payload: hyperswitch_domain_models::payments::HeaderPayload,
     ) -> RouterResult<Self> {
         let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
@@ -91,12 +91,28 @@ impl Feature<api::Capture, types::PaymentsCaptureData>
             types::PaymentsResponseData,
         > = connector.connector.get_connector_integration();
 
-        let mut new_router_data = services::execute_connector_processing_step(
+        let retry_config = helpers::get_connector_request_retry_config(
+            state,
+            &connector.connector_name.to_string(),
+        )
+        .await;
+        // Captures are only retried if the connector deduplicates them through idempotency keys
+        let retry_config = if retry_config.retry_capture {
+            retry_config
+        } else {
+            types::ConnectorRequestRetryConfig::default()
+        };
+        let time_budget_config =
+            helpers::get_connector_time_budget_config(state, business_profile.get_id()).await;
+
+        let mut new_router_data = helpers::execute_connector_processing_step_with_retries(
             state,
             connector_integration,
             &self,
             call_connector_action,
             connector_request,
+            &retry_config,
+            time_budget_config.capture_in_millis,
         )
         .await
         .to_payment_failed_response()?;
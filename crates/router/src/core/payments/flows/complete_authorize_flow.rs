@@ -102,7 +102,7 @@ impl Feature<api::CompleteAuthorize, types::CompleteAuthorizeData>
         connector: &api::ConnectorData,
         call_connector_action: payments::CallConnectorAction,
         connector_request: Option<services::Request>,
-        _business_profile: &domain::Profile,
+        business_profile: &domain::Profile,
         _header_payload: hyperswitch_domain_models::payments::HeaderPayload,
     ) -> RouterResult<Self> {
         let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
@@ -111,12 +111,15 @@ impl Feature<api::CompleteAuthorize, types::CompleteAuthorizeData>
             types::PaymentsResponseData,
         > = connector.connector.get_connector_integration();
 
-        let resp = services::execute_connector_processing_step(
+        let time_budget_config =
+            helpers::get_connector_time_budget_config(state, business_profile.get_id()).await;
+        let resp = helpers::execute_connector_processing_step_within_time_budget(
             state,
             connector_integration,
             &self,
             call_connector_action,
             connector_request,
+            time_budget_config.complete_authorize_in_millis,
         )
         .await
         .to_payment_failed_response()?;
@@ -115,7 +115,7 @@ impl Feature<api::PSync, types::PaymentsSyncData>
         connector: &api::ConnectorData,
         call_connector_action: payments::CallConnectorAction,
         connector_request: Option<services::Request>,
-        _business_profile: &domain::Profile,
+        business_profile: &domain::Profile,
         _header_payload: hyperswitch_domain_models::payments::HeaderPayload,
     ) -> RouterResult<Self> {
         let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
@@ -154,12 +154,22 @@ impl Feature<api::PSync, types::PaymentsSyncData>
             (types::SyncRequestType::MultipleCaptureSync(_), Err(err)) => Err(err),
             _ => {
                 // for bulk sync of captures, above logic needs to be handled at connector end
-                let mut new_router_data = services::execute_connector_processing_step(
+                let retry_config = helpers::get_connector_request_retry_config(
+                    state,
+                    &connector.connector_name.to_string(),
+                )
+                .await;
+                let time_budget_config =
+                    helpers::get_connector_time_budget_config(state, business_profile.get_id())
+                        .await;
+                let mut new_router_data = helpers::execute_connector_processing_step_with_retries(
                     state,
                     connector_integration,
                     &self,
                     call_connector_action,
                     connector_request,
+                    &retry_config,
+                    time_budget_config.psync_in_millis,
                 )
                 .await
                 .to_payment_failed_response()?;
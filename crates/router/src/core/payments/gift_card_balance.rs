@@ -0,0 +1,281 @@
+use std::marker::PhantomData;
+
+use api_models::payments::{
+    ClientTokenScope, PaymentsGiftCardBalanceCheckRequest, PaymentsGiftCardBalanceCheckResponse,
+};
+use common_utils::{
+    ext_traits::{OptionExt, ValueExt},
+    id_type,
+    types::MinorUnit,
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::PaymentIntent;
+use masking::ExposeInterface;
+use router_env::{instrument, tracing};
+
+use super::{client_token, helpers};
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments,
+    },
+    routes::SessionState,
+    services::{self, ApplicationResponse},
+    types::{self, api, domain, storage::enums as storage_enums, PaymentAddress},
+};
+
+const GIFT_CARD_BALANCE_CHECK_PREFIX: &str = "GIFT_CARD_BALANCE_CHECK";
+
+const GIFT_CARD_BALANCE_CHECK_SUPPORTED_CONNECTORS: [api_models::enums::Connector; 1] =
+    [api_models::enums::Connector::Adyen];
+
+/// Limits the number of balance checks that can be made for a payment, so that the endpoint
+/// cannot be used to enumerate gift card numbers. Unlike other rate limits, this fails closed.
+async fn check_rate_limit(
+    state: &SessionState,
+    payment_intent: &PaymentIntent,
+) -> RouterResult<()> {
+    let config = &state.conf.gift_card_balance_check;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection for rate limiting")?;
+    let key = format!(
+        "{GIFT_CARD_BALANCE_CHECK_PREFIX}_{}_{}",
+        payment_intent.merchant_id.get_string_repr(),
+        payment_intent.payment_id.get_string_repr()
+    );
+
+    let requests = redis_conn
+        .increment_key_with_expiry(&key, config.window_in_secs)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to track the balance checks of the payment")?;
+
+    if requests > i64::from(config.requests_per_payment) {
+        return Err(report!(
+            errors::ApiErrorResponse::GiftCardBalanceCheckRateLimitExceeded
+        ));
+    }
+    Ok(())
+}
+
+async fn find_merchant_connector_account(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    for connector in GIFT_CARD_BALANCE_CHECK_SUPPORTED_CONNECTORS {
+        let merchant_connector_account = state
+            .store
+            .find_merchant_connector_account_by_profile_id_connector_name(
+                &state.into(),
+                profile_id,
+                &connector.to_string(),
+                key_store,
+            )
+            .await;
+        match merchant_connector_account {
+            Ok(merchant_connector_account)
+                if !merchant_connector_account.disabled.unwrap_or(false) =>
+            {
+                return Ok(merchant_connector_account)
+            }
+            Ok(_) => {}
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => {
+                return Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the merchant connector account"))
+            }
+        }
+    }
+
+    Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+        message: "No connector supporting gift card balance checks is configured for the profile"
+            .to_string(),
+    }))
+}
+
+fn construct_gift_card_balance_router_data(
+    payment_intent: &PaymentIntent,
+    merchant_connector_account: domain::MerchantConnectorAccount,
+    payment_method_data: domain::PaymentMethodData,
+    currency: storage_enums::Currency,
+) -> RouterResult<types::PaymentsBalanceRouterData> {
+    let auth_type: types::ConnectorAuthType =
+        helpers::MerchantConnectorAccountType::DbVal(Box::new(merchant_connector_account.clone()))
+            .get_connector_account_details()
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let attempt_id = payment_intent.active_attempt.get_id();
+
+    Ok(types::RouterData {
+        flow: PhantomData,
+        merchant_id: payment_intent.merchant_id.clone(),
+        connector: merchant_connector_account.connector_name.clone(),
+        customer_id: payment_intent.customer_id.clone(),
+        payment_id: payment_intent.payment_id.get_string_repr().to_owned(),
+        attempt_id: attempt_id.clone(),
+        status: diesel_models::enums::AttemptStatus::default(),
+        payment_method: diesel_models::enums::PaymentMethod::GiftCard,
+        connector_auth_type: auth_type,
+        description: None,
+        return_url: None,
+        address: PaymentAddress::default(),
+        auth_type: diesel_models::enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.metadata.clone(),
+        connector_wallets_details: None,
+        amount_captured: None,
+        minor_amount_captured: None,
+        request: types::GiftCardBalanceCheckRequestData {
+            payment_method_data,
+            currency,
+            minor_amount: payment_intent.amount,
+        },
+        response: Err(types::ErrorResponse::default()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        connector_customer: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        connector_request_reference_id: attempt_id,
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        test_mode: merchant_connector_account.test_mode,
+        payment_method_balance: None,
+        payment_method_status: None,
+        connector_api_version: None,
+        connector_http_status_code: None,
+        connector_request_id: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        refund_id: None,
+        dispute_id: None,
+        connector_response: None,
+        integrity_check: Ok(()),
+        connector_options: None,
+        additional_merchant_data: None,
+        header_payload: None,
+        connector_mandate_request_reference_id: None,
+    })
+}
+
+/// Checks the balance remaining on a gift card before the payment is confirmed, so that the
+/// client can plan splitting the payment across the gift card and another payment method
+#[instrument(skip_all)]
+pub async fn check_gift_card_balance(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentsGiftCardBalanceCheckRequest,
+) -> RouterResponse<PaymentsGiftCardBalanceCheckResponse> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &req.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    client_token::authenticate_client_secret_or_token(
+        &state,
+        Some(&req.client_secret.expose()),
+        &payment_intent,
+        ClientTokenScope::Confirm,
+    )
+    .await?;
+
+    helpers::validate_payment_status_against_allowed_statuses(
+        &payment_intent.status,
+        &[
+            storage_enums::IntentStatus::RequiresPaymentMethod,
+            storage_enums::IntentStatus::RequiresConfirmation,
+        ],
+        "check gift card balance for",
+    )?;
+
+    check_rate_limit(&state, &payment_intent).await?;
+
+    let currency = payment_intent
+        .currency
+        .get_required_value("currency")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let profile_id = payment_intent
+        .profile_id
+        .as_ref()
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let merchant_connector_account =
+        find_merchant_connector_account(&state, &key_store, profile_id).await?;
+
+    let connector_name = merchant_connector_account.connector_name.clone();
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name,
+        api::GetToken::Connector,
+        Some(merchant_connector_account.get_id()),
+    )?;
+    let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+        api::Balance,
+        types::GiftCardBalanceCheckRequestData,
+        types::GiftCardBalanceCheckResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let payment_method_data = domain::PaymentMethodData::from(
+        api_models::payments::PaymentMethodData::GiftCard(Box::new(req.gift_card)),
+    );
+    let router_data = construct_gift_card_balance_router_data(
+        &payment_intent,
+        merchant_connector_account,
+        payment_method_data,
+        currency,
+    )?;
+
+    let response = services::execute_connector_processing_step(
+        &state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        None,
+    )
+    .await
+    .attach_printable("Failed while calling the gift card balance connector api")?
+    .response
+    .map_err(|err| errors::ApiErrorResponse::ExternalConnectorError {
+        code: err.code,
+        message: err.message,
+        connector: connector_name,
+        status_code: err.status_code,
+        reason: err.reason,
+    })?;
+
+    // A balance held in a currency other than that of the payment cannot be applied to it
+    let applicable_amount = if response.currency != currency {
+        MinorUnit::zero()
+    } else if response.balance < payment_intent.amount {
+        response.balance
+    } else {
+        payment_intent.amount
+    };
+
+    Ok(ApplicationResponse::Json(
+        PaymentsGiftCardBalanceCheckResponse {
+            payment_id: payment_intent.payment_id,
+            balance: response.balance,
+            currency: response.currency,
+            applicable_amount,
+            remaining_amount: payment_intent.amount - applicable_amount,
+        },
+    ))
+}
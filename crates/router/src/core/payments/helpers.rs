@@ -43,6 +43,7 @@ use openssl::{
     symm::{decrypt_aead, Cipher},
 };
 use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use tracing_futures::Instrument;
 use uuid::Uuid;
 use x509_parser::parse_x509_certificate;
 
@@ -2304,6 +2305,70 @@ pub async fn make_pm_data<'a, F: Clone, R, D>(
     todo!()
 }
 
+/// Ensures that the CVC is provided for customer initiated payments with a saved card, when the
+/// amount is at or above the threshold of the profile's CVC re-collection policy
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub async fn validate_saved_card_cvc_policy<F: Clone>(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payment_data: &PaymentData<F>,
+    token_data: &storage::PaymentTokenData,
+    card_token_data: Option<&domain::CardToken>,
+) -> RouterResult<()> {
+    let is_saved_card = matches!(
+        token_data,
+        storage::PaymentTokenData::PermanentCard(_) | storage::PaymentTokenData::Permanent(_)
+    );
+    let is_merchant_initiated =
+        payment_data.mandate_id.is_some() || payment_data.payment_intent.off_session == Some(true);
+    let is_cvc_provided = card_token_data
+        .and_then(|token_data| token_data.card_cvc.as_ref())
+        .is_some_and(|cvc| !cvc.peek().is_empty());
+
+    if !is_saved_card || is_merchant_initiated || is_cvc_provided {
+        return Ok(());
+    }
+
+    let config_key =
+        crate::types::SavedCardCvcPolicyConfig::get_config_key(business_profile.get_id());
+    let cvc_policy = state
+        .store
+        .find_config_by_key(&config_key)
+        .await
+        .ok()
+        .and_then(|config| {
+            config
+                .config
+                .parse_struct::<crate::types::SavedCardCvcPolicyConfig>("SavedCardCvcPolicyConfig")
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to parse the saved card cvc policy for {}",
+                        config_key
+                    )
+                })
+                .ok()
+        });
+
+    match cvc_policy {
+        Some(cvc_policy)
+            if cvc_policy
+                .is_cvc_required(payment_data.payment_attempt.net_amount.get_total_amount()) =>
+        {
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "card_cvc",
+            })
+            .attach_printable(
+                "CVC is required by the profile for saved card payments of this amount",
+            )
+        }
+        _ => Ok(()),
+    }
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -2366,6 +2431,15 @@ pub async fn make_pm_data<'a, F: Clone, R, D>(
     // TODO: Handle case where payment method and token both are present in request properly.
     let (payment_method, pm_id) = match (&request, payment_data.token_data.as_ref()) {
         (_, Some(hyperswitch_token)) => {
+            validate_saved_card_cvc_policy(
+                state,
+                business_profile,
+                payment_data,
+                hyperswitch_token,
+                card_token_data.as_ref(),
+            )
+            .await?;
+
             let pm_data = Box::pin(payment_methods::retrieve_payment_method_with_token(
                 state,
                 merchant_key_store,
@@ -2585,6 +2659,35 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+/// Validates a capture of a payment captured in multiple parts against the captures made so far.
+/// The amounts of the captures which are charged, pending at the connector or in flight cannot be
+/// captured again.
+#[instrument(skip_all)]
+pub(crate) fn validate_amount_to_capture_against_captures(
+    authorized_amount: MinorUnit,
+    previous_captures: &[storage::Capture],
+    amount_to_capture: MinorUnit,
+) -> RouterResult<()> {
+    let blocked_amount = previous_captures
+        .iter()
+        .filter(|capture| capture.status != storage_enums::CaptureStatus::Failed)
+        .fold(MinorUnit::zero(), |blocked_amount, capture| {
+            blocked_amount + capture.amount
+        });
+    let remaining_amount = authorized_amount - blocked_amount;
+
+    utils::when(
+        amount_to_capture <= MinorUnit::zero() || amount_to_capture > remaining_amount,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "amount_to_capture should be greater than 0 and at most the remaining capturable amount {remaining_amount}"
+                )
+            }))
+        },
+    )
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
@@ -3105,6 +3208,146 @@ pub fn hmac_sha512_sorted_query_params(
     Ok(hex::encode(signature))
 }
 
+/// Query parameter carrying the signature of the callback urls of the router, to which connectors
+/// redirect the customer
+const CALLBACK_URL_SIGNATURE_PARAM: &str = "hs_signature";
+
+fn get_callback_url_signature(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<String> {
+    let hash_key = state.conf.api_keys.get_inner().get_hash_key()?;
+    let signature = crypto::HmacSha512::sign_message(
+        &crypto::HmacSha512,
+        hash_key.peek(),
+        format!(
+            "callback_url:{}:{}",
+            merchant_id.get_string_repr(),
+            payment_id.get_string_repr()
+        )
+        .as_bytes(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to sign the callback url")?;
+
+    Ok(hex::encode(signature))
+}
+
+/// Signs a callback url of the router handed to a connector, so that the callbacks of the payment
+/// can be verified to come from a url issued for it by `verify_callback_url_signature`
+pub fn sign_callback_url(
+    state: &SessionState,
+    url: String,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<String> {
+    let signature = get_callback_url_signature(state, merchant_id, payment_id)?;
+    Ok(format!("{url}?{CALLBACK_URL_SIGNATURE_PARAM}={signature}"))
+}
+
+/// Verifies the signature added by `sign_callback_url` to the query string of a callback, and
+/// removes it from the query string so that connectors never see it. Returns the remaining query
+/// string along with whether the signature is valid.
+pub fn verify_callback_url_signature(
+    state: &SessionState,
+    query_string: &str,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<(String, bool)> {
+    let expected_signature = get_callback_url_signature(state, merchant_id, payment_id)?;
+
+    let mut is_signature_valid = false;
+    // The raw parameters are kept as is, connectors may verify their own signatures over them
+    let query_string = query_string
+        .split('&')
+        .filter(|param| match param.split_once('=') {
+            Some((CALLBACK_URL_SIGNATURE_PARAM, signature)) => {
+                is_signature_valid |=
+                    crypto::constant_time_eq(signature.as_bytes(), expected_signature.as_bytes());
+                false
+            }
+            _ => true,
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+
+    Ok((query_string, is_signature_valid))
+}
+
+/// Query parameter carrying the signature of a url signed by `sign_url`
+const URL_SIGNATURE_PARAM: &str = "signature";
+/// Query parameter carrying the algorithm of the signature of a url signed by `sign_url`
+const URL_SIGNATURE_ALGORITHM_PARAM: &str = "signature_algorithm";
+
+/// Signs the query parameters of a browser-bound url along with the identifier of the resource it
+/// refers to, using the merchant-scoped `key`, so that tampering with the url can be detected by
+/// `verify_url_signature`
+pub fn sign_url(url: &str, resource_id: &str, key: &str) -> RouterResult<String> {
+    let mut url = url::Url::parse(url)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to parse the url")?;
+
+    let signature = hmac_sha512_sorted_query_params(
+        &mut url
+            .query_pairs()
+            .chain(std::iter::once((
+                Cow::Borrowed("resource_id"),
+                Cow::Borrowed(resource_id),
+            )))
+            .collect::<Vec<_>>(),
+        key,
+    )?;
+
+    url.query_pairs_mut()
+        .append_pair(URL_SIGNATURE_PARAM, &signature)
+        .append_pair(URL_SIGNATURE_ALGORITHM_PARAM, "HMAC-SHA512");
+
+    Ok(url.to_string())
+}
+
+/// Checks whether the url carries a signature added by `sign_url`
+pub fn is_url_signed(url: &str) -> bool {
+    url::Url::parse(url)
+        .map(|url| url.query_pairs().any(|(key, _)| key == URL_SIGNATURE_PARAM))
+        .unwrap_or(false)
+}
+
+/// Verifies the signature added by `sign_url` against the query string of an incoming request
+pub fn verify_url_signature(query_string: &str, resource_id: &str, key: &str) -> RouterResult<()> {
+    let query_pairs = url::form_urlencoded::parse(query_string.as_bytes());
+
+    let signature = query_pairs
+        .clone()
+        .find(|(param, _)| param == URL_SIGNATURE_PARAM)
+        .map(|(_, value)| value)
+        .ok_or(errors::ApiErrorResponse::GenericUnauthorized {
+            message: "Signature of the url is missing".to_string(),
+        })?;
+
+    let expected_signature = hmac_sha512_sorted_query_params(
+        &mut query_pairs
+            .filter(|(param, _)| {
+                param != URL_SIGNATURE_PARAM && param != URL_SIGNATURE_ALGORITHM_PARAM
+            })
+            .chain(std::iter::once((
+                Cow::Borrowed("resource_id"),
+                Cow::Borrowed(resource_id),
+            )))
+            .collect::<Vec<_>>(),
+        key,
+    )?;
+
+    utils::when(
+        !crypto::constant_time_eq(expected_signature.as_bytes(), signature.as_bytes()),
+        || {
+            Err(report!(errors::ApiErrorResponse::GenericUnauthorized {
+                message: "Signature of the url is invalid".to_string(),
+            }))
+        },
+    )
+}
+
 pub fn check_if_operation_confirm<Op: std::fmt::Debug>(operations: Op) -> bool {
     format!("{operations:?}") == "PaymentConfirm"
 }
@@ -3468,6 +3711,7 @@ mod tests {
             shipping_cost: None,
             tax_details: None,
             skip_external_tax_calculation: None,
+            row_version: common_utils::consts::INITIAL_ROW_VERSION,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
@@ -3537,6 +3781,7 @@ mod tests {
             shipping_cost: None,
             tax_details: None,
             skip_external_tax_calculation: None,
+            row_version: common_utils::consts::INITIAL_ROW_VERSION,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
@@ -3604,10 +3849,54 @@ mod tests {
             shipping_cost: None,
             tax_details: None,
             skip_external_tax_calculation: None,
+            row_version: common_utils::consts::INITIAL_ROW_VERSION,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
     }
+
+    #[test]
+    fn test_sign_and_verify_url() {
+        let signed_url = sign_url(
+            "https://example.com/payment_link/merchant/pay_123?locale=en",
+            "pay_123",
+            "secret_key",
+        )
+        .unwrap();
+        assert!(is_url_signed(&signed_url));
+
+        let query_string = url::Url::parse(&signed_url)
+            .unwrap()
+            .query()
+            .unwrap()
+            .to_owned();
+        assert!(verify_url_signature(&query_string, "pay_123", "secret_key").is_ok());
+        assert!(verify_url_signature(&query_string, "pay_456", "secret_key").is_err());
+        assert!(verify_url_signature(&query_string, "pay_123", "other_key").is_err());
+
+        let tampered_query_string = query_string.replace("locale=en", "locale=fr");
+        assert!(verify_url_signature(&tampered_query_string, "pay_123", "secret_key").is_err());
+        assert!(verify_url_signature("locale=en", "pay_123", "secret_key").is_err());
+    }
+
+    #[test]
+    fn test_validate_connector_passthrough_data() {
+        let connector_metadata = |adyen: serde_json::Value| {
+            api_models::payments::ConnectorMetadata::from_value(
+                serde_json::json!({ "adyen": adyen }).into(),
+            )
+            .unwrap()
+        };
+
+        assert!(validate_connector_passthrough_data(&connector_metadata(
+            serde_json::json!({ "industryUsage": "delayedCharge", "store": "store_1" })
+        ))
+        .is_ok());
+        assert!(validate_connector_passthrough_data(&connector_metadata(
+            serde_json::json!({ "industryUsage": "delayedCharge", "reference": "order_1" })
+        ))
+        .is_err());
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -3718,6 +4007,72 @@ impl MerchantConnectorAccountType {
             Self::CacheVal(_) => None,
         }
     }
+
+    pub fn get_payment_methods_enabled(&self) -> Option<&Vec<pii::SecretSerdeValue>> {
+        match self {
+            Self::DbVal(db_val) => db_val.payment_methods_enabled.as_ref(),
+            Self::CacheVal(_) => None,
+        }
+    }
+}
+
+/// Validates that the amount is within the amount limits configured for the payment method type
+/// on the merchant connector account
+pub fn validate_amount_limits_for_payment_method_type(
+    merchant_connector_account: &MerchantConnectorAccountType,
+    connector_name: &str,
+    payment_method: Option<enums::PaymentMethod>,
+    payment_method_type: Option<enums::PaymentMethodType>,
+    amount: MinorUnit,
+    currency: Option<enums::Currency>,
+) -> RouterResult<()> {
+    let (Some(payment_method), Some(payment_method_type)) = (payment_method, payment_method_type)
+    else {
+        return Ok(());
+    };
+
+    // Zero amount payments are used for setting up mandates, which are not restricted by limits
+    if amount == MinorUnit::zero() {
+        return Ok(());
+    }
+
+    let payment_method_type_info = merchant_connector_account
+        .get_payment_methods_enabled()
+        .into_iter()
+        .flatten()
+        .filter_map(|payment_methods_enabled| {
+            payment_methods_enabled
+                .peek()
+                .clone()
+                .parse_value::<api_models::admin::PaymentMethodsEnabled>("PaymentMethodsEnabled")
+                .ok()
+        })
+        .filter(|payment_methods_enabled| payment_methods_enabled.payment_method == payment_method)
+        .flat_map(|payment_methods_enabled| {
+            payment_methods_enabled
+                .payment_method_types
+                .unwrap_or_default()
+        })
+        .find(|payment_method_type_info| {
+            payment_method_type_info.payment_method_type == payment_method_type
+        });
+
+    match payment_method_type_info {
+        Some(payment_method_type_info)
+            if payment_method_type_info.amount_limits_enforced
+                && !payment_method_type_info.is_amount_supported(amount, currency) =>
+        {
+            let (minimum_amount, maximum_amount) =
+                payment_method_type_info.get_amount_limits(currency);
+            Err(report!(errors::ApiErrorResponse::AmountNotSupported {
+                connector: connector_name.to_string(),
+                payment_method_type: payment_method_type.to_string(),
+                minimum_amount,
+                maximum_amount,
+            }))
+        }
+        _ => Ok(()),
+    }
 }
 
 /// Query for merchant connector account either by business label or profile id
@@ -3915,6 +4270,7 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         test_mode: router_data.test_mode,
         connector_api_version: router_data.connector_api_version,
         connector_http_status_code: router_data.connector_http_status_code,
+        connector_request_id: router_data.connector_request_id,
         external_latency: router_data.external_latency,
         apple_pay_flow: router_data.apple_pay_flow,
         frm_metadata: router_data.frm_metadata,
@@ -3923,6 +4279,7 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         connector_response: router_data.connector_response,
         integrity_check: Ok(()),
         connector_wallets_details: router_data.connector_wallets_details,
+        connector_options: router_data.connector_options,
         additional_merchant_data: router_data.additional_merchant_data,
         header_payload: router_data.header_payload,
         connector_mandate_request_reference_id: router_data.connector_mandate_request_reference_id,
@@ -4138,6 +4495,7 @@ impl AttemptType {
             organization_id: old_payment_attempt.organization_id,
             profile_id: old_payment_attempt.profile_id,
             connector_mandate_detail: None,
+            routing_decision: None,
         }
     }
 
@@ -4320,6 +4678,72 @@ mod test {
             super::get_payment_id_from_client_secret(client_secret3).unwrap()
         );
     }
+
+    fn get_capture(
+        capture_sequence: i16,
+        amount: i64,
+        status: super::storage_enums::CaptureStatus,
+    ) -> super::storage::Capture {
+        let now = common_utils::date_time::now();
+        super::storage::Capture {
+            capture_id: format!("pay_attempt_{capture_sequence}"),
+            payment_id: common_utils::id_type::PaymentId::default(),
+            merchant_id: common_utils::id_type::MerchantId::default(),
+            status,
+            amount: super::MinorUnit::new(amount),
+            currency: None,
+            connector: "checkout".to_string(),
+            error_message: None,
+            error_code: None,
+            error_reason: None,
+            tax_amount: None,
+            created_at: now,
+            modified_at: now,
+            authorized_attempt_id: "pay_attempt".to_string(),
+            connector_capture_id: None,
+            capture_sequence,
+            connector_response_reference_id: None,
+            connector_capture_data: None,
+            settlement_currency: None,
+            settlement_amount: None,
+        }
+    }
+
+    #[test]
+    fn test_amount_to_capture_within_remaining_amount() {
+        let previous_captures = vec![
+            get_capture(1, 300, super::storage_enums::CaptureStatus::Charged),
+            get_capture(2, 200, super::storage_enums::CaptureStatus::Failed),
+        ];
+
+        assert!(super::validate_amount_to_capture_against_captures(
+            super::MinorUnit::new(1000),
+            &previous_captures,
+            super::MinorUnit::new(700),
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_amount_to_capture_exceeding_remaining_amount() {
+        let previous_captures = vec![
+            get_capture(1, 300, super::storage_enums::CaptureStatus::Charged),
+            get_capture(2, 200, super::storage_enums::CaptureStatus::Pending),
+        ];
+
+        assert!(super::validate_amount_to_capture_against_captures(
+            super::MinorUnit::new(1000),
+            &previous_captures,
+            super::MinorUnit::new(600),
+        )
+        .is_err());
+        assert!(super::validate_amount_to_capture_against_captures(
+            super::MinorUnit::new(1000),
+            &previous_captures,
+            super::MinorUnit::zero(),
+        )
+        .is_err());
+    }
 }
 
 #[instrument(skip_all)]
@@ -4795,6 +5219,21 @@ pub fn validate_customer_access(
     Ok(())
 }
 
+#[cfg(feature = "v1")]
+/// The connector of a payment can only be overridden by the merchant, a client holding the
+/// client secret would otherwise be able to bypass the routing configured by the merchant
+pub fn validate_connector_override_access(
+    auth_flow: services::AuthFlow,
+    request: &api::PaymentsRequest,
+) -> Result<(), errors::ApiErrorResponse> {
+    if auth_flow == services::AuthFlow::Client && request.connector_override.is_some() {
+        Err(errors::ApiErrorResponse::GenericUnauthorized {
+            message: "connector_override cannot be set with a client secret".to_string(),
+        })?;
+    }
+    Ok(())
+}
+
 pub fn is_apple_pay_simplified_flow(
     connector_metadata: Option<pii::SecretSerdeValue>,
     connector_name: Option<&String>,
@@ -5536,6 +5975,28 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+/// Validates that the fields to be forwarded as is to the connectors are allowed by them
+pub fn validate_connector_passthrough_data(
+    connector_metadata: &api_models::payments::ConnectorMetadata,
+) -> Result<(), errors::ApiErrorResponse> {
+    for (connector_name, passthrough_data) in connector_metadata.get_all_passthrough_data() {
+        let allowlist = connector::utils::get_passthrough_fields_allowlist(connector_name);
+        if let Some(field) = passthrough_data
+            .0
+            .keys()
+            .find(|field| !allowlist.contains(&field.as_str()))
+        {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`{field}` can't be passed through to {connector_name}, the allowed fields are: {}",
+                    allowlist.join(", ")
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
 pub fn get_recipient_id_for_open_banking(
     merchant_data: &AdditionalMerchantData,
 ) -> Result<Option<String>, errors::ApiErrorResponse> {
@@ -6116,3 +6577,569 @@ pub fn validate_platform_fees_for_marketplace(
         }
     }
 }
+
+pub async fn get_connector_time_budget_config(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> crate::types::ConnectorTimeBudgetConfig {
+    let config_key = crate::types::ConnectorTimeBudgetConfig::get_config_key(profile_id);
+
+    state
+        .store
+        .find_config_by_key(&config_key)
+        .await
+        .ok()
+        .and_then(|config| {
+            config
+                .config
+                .parse_struct("ConnectorTimeBudgetConfig")
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to parse the connector time budget config for {}",
+                        config_key
+                    )
+                })
+                .ok()
+        })
+        .unwrap_or_default()
+}
+
+/// Executes the connector call, and responds with a pending status if the connector does not
+/// respond within the time budget.
+///
+/// The connector call is not cancelled when the time budget is exceeded, it is completed in the
+/// background and the payment is synced with the connector once the call completes, so that the
+/// final status of the payment is not left to webhooks alone.
+#[instrument(skip_all)]
+pub async fn execute_connector_processing_step_within_time_budget<F, Req>(
+    state: &SessionState,
+    connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+        F,
+        Req,
+        PaymentsResponseData,
+    >,
+    router_data: &RouterData<F, Req, PaymentsResponseData>,
+    call_connector_action: payments::CallConnectorAction,
+    connector_request: Option<services::Request>,
+    time_budget_in_millis: Option<u64>,
+) -> CustomResult<RouterData<F, Req, PaymentsResponseData>, errors::ConnectorError>
+where
+    F: Clone + std::fmt::Debug + Send + Sync + 'static,
+    Req: Clone + std::fmt::Debug + Send + Sync + 'static,
+{
+    let time_budget = match (time_budget_in_millis, &call_connector_action) {
+        (Some(time_budget_in_millis), payments::CallConnectorAction::Trigger) => {
+            std::time::Duration::from_millis(time_budget_in_millis)
+        }
+        _ => {
+            return services::execute_connector_processing_step(
+                state,
+                connector_integration,
+                router_data,
+                call_connector_action,
+                connector_request,
+            )
+            .await
+        }
+    };
+
+    let m_state = state.clone();
+    let m_router_data = router_data.clone();
+    let mut connector_call = tokio::spawn(
+        async move {
+            services::execute_connector_processing_step(
+                &m_state,
+                connector_integration,
+                &m_router_data,
+                call_connector_action,
+                connector_request,
+            )
+            .await
+        }
+        .in_current_span(),
+    );
+
+    match tokio::time::timeout(time_budget, &mut connector_call).await {
+        Ok(result) => result
+            .change_context(errors::ConnectorError::ProcessingStepFailed(None))
+            .attach_printable("Connector call task failed")?,
+        Err(_) => {
+            logger::warn!(
+                connector = %router_data.connector,
+                time_budget_in_millis = time_budget.as_millis(),
+                "Connector did not respond within the time budget, responding with a pending status"
+            );
+
+            let payment_id = router_data.payment_id.clone();
+            let m_state = state.clone();
+            let m_router_data = router_data.clone();
+            // Dropping the handle detaches the task, the outcome of the call is applied to the
+            // payment through payment sync once the call completes
+            tokio::spawn(
+                async move {
+                    let late_response = match connector_call.await {
+                        Ok(Ok(response)) => {
+                            logger::info!(
+                                %payment_id,
+                                status = ?response.status,
+                                "Connector call completed after exceeding the time budget"
+                            );
+                            LateConnectorResponse::from_response(&response.response)
+                        }
+                        Ok(Err(error)) => {
+                            logger::error!(
+                                %payment_id,
+                                ?error,
+                                "Connector call failed after exceeding the time budget"
+                            );
+                            LateConnectorResponse::default()
+                        }
+                        Err(error) => {
+                            logger::error!(
+                                %payment_id,
+                                ?error,
+                                "Connector call task failed after exceeding the time budget"
+                            );
+                            LateConnectorResponse::default()
+                        }
+                    };
+
+                    // The outcome of the call is unknown if the call failed, the payment is
+                    // synced with the connector irrespective of the outcome
+                    if let Err(error) =
+                        sync_payment_after_time_budget(&m_state, &m_router_data, late_response)
+                            .await
+                    {
+                        logger::error!(
+                            %payment_id,
+                            ?error,
+                            "Failed to sync the payment after exceeding the time budget"
+                        );
+                    }
+                }
+                .in_current_span(),
+            );
+
+            let mut pending_router_data = router_data.clone();
+            pending_router_data.status = enums::AttemptStatus::Pending;
+            pending_router_data.response = Ok(PaymentsResponseData::TransactionResponse {
+                resource_id: crate::types::ResponseId::NoResponseId,
+                redirection_data: Box::new(None),
+                mandate_reference: Box::new(None),
+                connector_metadata: None,
+                network_txn_id: None,
+                connector_response_reference_id: None,
+                incremental_authorization_allowed: None,
+                charge_id: None,
+            });
+            Ok(pending_router_data)
+        }
+    }
+}
+
+/// The part of a connector response, received after exceeding the time budget, which cannot be
+/// recovered through payment sync
+#[derive(Debug, Default, PartialEq)]
+struct LateConnectorResponse {
+    connector_transaction_id: Option<String>,
+    redirection_data: Option<services::RedirectForm>,
+}
+
+impl LateConnectorResponse {
+    fn from_response(response: &Result<PaymentsResponseData, ErrorResponse>) -> Self {
+        match response {
+            Ok(PaymentsResponseData::TransactionResponse {
+                resource_id,
+                redirection_data,
+                ..
+            }) => Self {
+                connector_transaction_id: resource_id.get_connector_transaction_id().ok(),
+                redirection_data: (**redirection_data).clone(),
+            },
+            // The final status of the payment is applied through payment sync, which requires the
+            // connector transaction id for most connectors
+            Err(error) => Self {
+                connector_transaction_id: error.connector_transaction_id.clone(),
+                redirection_data: None,
+            },
+            Ok(_) => Self::default(),
+        }
+    }
+}
+
+/// Retains the outcome of a connector call which completed after exceeding the time budget.
+///
+/// The connector transaction id and the redirection data are stored on the payment attempt, the
+/// payment requires customer action if the connector responded with redirection data, and a
+/// payment sync is scheduled right away irrespective of the outcome of the call, so that the final
+/// status is applied to the payment through the payment sync flow.
+#[cfg(feature = "v1")]
+async fn sync_payment_after_time_budget<F, Req>(
+    state: &SessionState,
+    router_data: &RouterData<F, Req, PaymentsResponseData>,
+    late_response: LateConnectorResponse,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &router_data.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(
+            key_manager_state,
+            &router_data.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let storage_scheme = merchant_account.storage_scheme;
+    let mut payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &router_data.attempt_id,
+            &router_data.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let connector_transaction_id = late_response
+        .connector_transaction_id
+        .filter(|_| payment_attempt.connector_transaction_id.is_none());
+    let authentication_data = late_response
+        .redirection_data
+        .as_ref()
+        .map(Encode::encode_to_value)
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the redirection data")?;
+    // The payment may have progressed through webhooks in the meantime, the redirection is only
+    // applied to payments still pending on the outcome of the call
+    let authentication_data =
+        authentication_data.filter(|_| payment_attempt.status == enums::AttemptStatus::Pending);
+    let requires_customer_action = authentication_data.is_some();
+
+    if connector_transaction_id.is_some() || authentication_data.is_some() {
+        payment_attempt = db
+            .update_payment_attempt_with_attempt_id(
+                payment_attempt,
+                storage::PaymentAttemptUpdate::ConnectorResponse {
+                    authentication_data,
+                    encoded_data: None,
+                    connector_transaction_id,
+                    connector: None,
+                    charge_id: None,
+                    updated_by: storage_scheme.to_string(),
+                },
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    }
+
+    if requires_customer_action {
+        payment_attempt = db
+            .update_payment_attempt_with_attempt_id(
+                payment_attempt,
+                storage::PaymentAttemptUpdate::StatusUpdate {
+                    status: enums::AttemptStatus::AuthenticationPending,
+                    updated_by: storage_scheme.to_string(),
+                },
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id(
+                key_manager_state,
+                &payment_attempt.payment_id,
+                &payment_attempt.merchant_id,
+                &key_store,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        db.update_payment_intent(
+            key_manager_state,
+            payment_intent,
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::RequiresCustomerAction,
+                incremental_authorization_allowed: None,
+                updated_by: storage_scheme.to_string(),
+            },
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    }
+
+    // The sync task may already be scheduled if payment sync is configured for the connector, in
+    // which case the scheduled task applies the outcome
+    if let Err(error) =
+        payments::add_process_sync_task(db, &payment_attempt, common_utils::date_time::now()).await
+    {
+        logger::warn!(?error, "Failed to schedule the payment sync");
+    }
+
+    Ok(())
+}
+
+// Payment sync is not scheduled for v2 payments yet
+#[cfg(not(feature = "v1"))]
+async fn sync_payment_after_time_budget<F, Req>(
+    _state: &SessionState,
+    _router_data: &RouterData<F, Req, PaymentsResponseData>,
+    _late_response: LateConnectorResponse,
+) -> RouterResult<()> {
+    Ok(())
+}
+
+pub async fn get_connector_request_retry_config(
+    state: &SessionState,
+    connector_name: &str,
+) -> crate::types::ConnectorRequestRetryConfig {
+    let config_key = crate::types::ConnectorRequestRetryConfig::get_config_key(connector_name);
+
+    state
+        .store
+        .find_config_by_key(&config_key)
+        .await
+        .ok()
+        .and_then(|config| {
+            config
+                .config
+                .parse_struct("ConnectorRequestRetryConfig")
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to parse the connector request retry config for {}",
+                        config_key
+                    )
+                })
+                .ok()
+        })
+        .unwrap_or_default()
+}
+
+/// Executes an idempotent connector call, retrying it with exponential backoff and jitter if the
+/// connector rejects it with a retryable status code.
+///
+/// The request is rebuilt for every retry, and no retry is made if it cannot be completed within
+/// the time budget of the flow, the retries are only bounded by their number if the profile has no
+/// time budget for the flow. The retries are left to the request policy of the flow if one is
+/// configured, so that the requests are not retried by both.
+#[instrument(skip_all)]
+pub async fn execute_connector_processing_step_with_retries<F, Req>(
+    state: &SessionState,
+    connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+        F,
+        Req,
+        PaymentsResponseData,
+    >,
+    router_data: &RouterData<F, Req, PaymentsResponseData>,
+    call_connector_action: payments::CallConnectorAction,
+    connector_request: Option<services::Request>,
+    retry_config: &crate::types::ConnectorRequestRetryConfig,
+    time_budget_in_millis: Option<u64>,
+) -> CustomResult<RouterData<F, Req, PaymentsResponseData>, errors::ConnectorError>
+where
+    F: Clone + std::fmt::Debug + Send + Sync + 'static,
+    Req: Clone + std::fmt::Debug + Send + Sync + 'static,
+{
+    let is_retried_by_request_policy =
+        services::get_connector_flow_request_policy::<F>(state, &router_data.connector)
+            .retry
+            .is_some_and(|retry_policy| retry_policy.max_retries > 0);
+    if retry_config.max_retries == 0
+        || is_retried_by_request_policy
+        || !matches!(
+            call_connector_action,
+            payments::CallConnectorAction::Trigger
+        )
+        || !services::is_connector_request_retryable(&connector_integration, router_data)
+    {
+        return services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            router_data,
+            call_connector_action,
+            connector_request,
+        )
+        .await;
+    }
+
+    let start_time = std::time::Instant::now();
+    let time_budget = time_budget_in_millis.map(std::time::Duration::from_millis);
+    let mut connector_request = connector_request;
+    let mut retries = 0;
+
+    loop {
+        let response = services::execute_connector_processing_step(
+            state,
+            connector_integration.clone_box(),
+            router_data,
+            call_connector_action.clone(),
+            connector_request.take(),
+        )
+        .await?;
+
+        let is_retryable = response.response.is_err()
+            && response
+                .connector_http_status_code
+                .is_some_and(|status_code| {
+                    services::RETRYABLE_CONNECTOR_STATUS_CODES.contains(&status_code)
+                });
+        if !is_retryable || retries >= retry_config.max_retries {
+            return Ok(response);
+        }
+
+        let delay = get_connector_request_retry_delay(retry_config, retries);
+        if time_budget
+            .is_some_and(|time_budget| start_time.elapsed().saturating_add(delay) >= time_budget)
+        {
+            logger::warn!(
+                connector = %router_data.connector,
+                retries,
+                "Connector request retry would exceed the time budget, not retrying"
+            );
+            return Ok(response);
+        }
+
+        retries += 1;
+        logger::info!(
+            connector = %router_data.connector,
+            status_code = ?response.connector_http_status_code,
+            retries,
+            delay_in_millis = delay.as_millis(),
+            "Retrying the connector request"
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Delay before the retry, doubled for every retry and bounded by the maximum delay. The delay is
+/// randomized between half and the full value to avoid retries of concurrent requests aligning.
+fn get_connector_request_retry_delay(
+    retry_config: &crate::types::ConnectorRequestRetryConfig,
+    retries: u8,
+) -> std::time::Duration {
+    use rand::Rng;
+
+    let delay_in_millis = retry_config
+        .base_delay_in_millis
+        .saturating_mul(2_u64.saturating_pow(u32::from(retries)))
+        .min(retry_config.max_delay_in_millis);
+    let jittered_delay_in_millis =
+        rand::thread_rng().gen_range(delay_in_millis / 2..=delay_in_millis);
+
+    std::time::Duration::from_millis(jittered_delay_in_millis)
+}
+
+#[cfg(test)]
+mod connector_call_tests {
+    use super::*;
+
+    fn get_transaction_response(
+        resource_id: crate::types::ResponseId,
+        redirection_data: Option<services::RedirectForm>,
+    ) -> Result<PaymentsResponseData, ErrorResponse> {
+        Ok(PaymentsResponseData::TransactionResponse {
+            resource_id,
+            redirection_data: Box::new(redirection_data),
+            mandate_reference: Box::new(None),
+            connector_metadata: None,
+            network_txn_id: None,
+            connector_response_reference_id: None,
+            incremental_authorization_allowed: None,
+            charge_id: None,
+        })
+    }
+
+    #[test]
+    fn test_late_connector_success_retains_transaction_id_and_redirection() {
+        let redirection_data = services::RedirectForm::Form {
+            endpoint: "https://acs.example.com/challenge".to_string(),
+            method: common_utils::request::Method::Post,
+            form_fields: std::collections::HashMap::from([(
+                "creq".to_string(),
+                "eyJ0aHJlZURTU2VydmVyVHJhbnNJRCI6IjEyMyJ9".to_string(),
+            )]),
+        };
+        let response = get_transaction_response(
+            crate::types::ResponseId::ConnectorTransactionId("txn_123".to_string()),
+            Some(redirection_data.clone()),
+        );
+
+        assert_eq!(
+            LateConnectorResponse::from_response(&response),
+            LateConnectorResponse {
+                connector_transaction_id: Some("txn_123".to_string()),
+                redirection_data: Some(redirection_data),
+            }
+        );
+    }
+
+    #[test]
+    fn test_late_connector_success_without_transaction_id() {
+        let response = get_transaction_response(crate::types::ResponseId::NoResponseId, None);
+
+        assert_eq!(
+            LateConnectorResponse::from_response(&response),
+            LateConnectorResponse::default()
+        );
+    }
+
+    #[test]
+    fn test_connector_request_retry_delay() {
+        let retry_config = crate::types::ConnectorRequestRetryConfig {
+            max_retries: 5,
+            base_delay_in_millis: 100,
+            max_delay_in_millis: 1000,
+            retry_capture: false,
+        };
+        let get_delay_in_millis =
+            |retries| get_connector_request_retry_delay(&retry_config, retries).as_millis();
+
+        for _ in 0..100 {
+            // The delay is doubled for every retry and jittered between half and the full value
+            assert!((50..=100).contains(&get_delay_in_millis(0)));
+            assert!((100..=200).contains(&get_delay_in_millis(1)));
+            assert!((400..=800).contains(&get_delay_in_millis(3)));
+            // The delay is bounded by the maximum delay
+            assert!((500..=1000).contains(&get_delay_in_millis(4)));
+            assert!((500..=1000).contains(&get_delay_in_millis(u8::MAX)));
+        }
+
+        let retry_config = crate::types::ConnectorRequestRetryConfig {
+            base_delay_in_millis: 0,
+            ..retry_config
+        };
+        assert_eq!(
+            get_connector_request_retry_delay(&retry_config, 3),
+            std::time::Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_late_connector_error_retains_transaction_id() {
+        let response = Err(ErrorResponse {
+            connector_transaction_id: Some("txn_123".to_string()),
+            ..ErrorResponse::default()
+        });
+
+        assert_eq!(
+            LateConnectorResponse::from_response(&response),
+            LateConnectorResponse {
+                connector_transaction_id: Some("txn_123".to_string()),
+                redirection_data: None,
+            }
+        );
+    }
+}
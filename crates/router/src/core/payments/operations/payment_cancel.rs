@@ -3,7 +3,7 @@ use std::marker::PhantomData;
 use api_models::enums::FrmSuggestion;
 use async_trait::async_trait;
 use common_utils::ext_traits::AsyncExt;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use router_derive;
 use router_env::{instrument, tracing};
 
@@ -11,7 +11,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::{helpers, operations, PaymentData},
+        payments::{helpers, operations, void_window, PaymentData},
     },
     events::audit_events::{AuditEvent, AuditEventType},
     routes::{app::ReqState, SessionState},
@@ -67,18 +67,6 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
-        helpers::validate_payment_status_against_not_allowed_statuses(
-            &payment_intent.status,
-            &[
-                enums::IntentStatus::Failed,
-                enums::IntentStatus::Succeeded,
-                enums::IntentStatus::Cancelled,
-                enums::IntentStatus::Processing,
-                enums::IntentStatus::RequiresMerchantAction,
-            ],
-            "cancel",
-        )?;
-
         let mut payment_attempt = db
             .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
                 &payment_intent.payment_id,
@@ -89,6 +77,36 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             .await
             .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+        // Captured payments can only be voided through the connectors which allow it, until the
+        // void window of the connector passes and the payment gets settled
+        let void_window = match payment_intent.status {
+            enums::IntentStatus::Succeeded => {
+                void_window::get_connector_void_window(state, &payment_attempt)?
+            }
+            _ => None,
+        };
+        match void_window {
+            Some(void_window) => {
+                if !void_window::is_void_window_open(state, void_window, &payment_attempt).await {
+                    return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                        message: "Void window of the connector has passed, refund the payment"
+                            .to_string(),
+                    }));
+                }
+            }
+            None => helpers::validate_payment_status_against_not_allowed_statuses(
+                &payment_intent.status,
+                &[
+                    enums::IntentStatus::Failed,
+                    enums::IntentStatus::Succeeded,
+                    enums::IntentStatus::Cancelled,
+                    enums::IntentStatus::Processing,
+                    enums::IntentStatus::RequiresMerchantAction,
+                ],
+                "cancel",
+            )?,
+        }
+
         let shipping_address = helpers::get_address_by_id(
             state,
             payment_intent.shipping_address_id.clone(),
@@ -203,6 +221,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCancelRequest>
             poll_config: None,
             tax_data: None,
             session_id: None,
+            risk_signals: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -236,17 +255,19 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsCancelRequest> for
         F: 'b + Send,
     {
         let cancellation_reason = payment_data.payment_attempt.cancellation_reason.clone();
-        let (intent_status_update, attempt_status_update) =
-            if payment_data.payment_intent.status != enums::IntentStatus::RequiresCapture {
-                let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
-                    status: enums::IntentStatus::Cancelled,
-                    updated_by: storage_scheme.to_string(),
-                    incremental_authorization_allowed: None,
-                };
-                (Some(payment_intent_update), enums::AttemptStatus::Voided)
-            } else {
-                (None, enums::AttemptStatus::VoidInitiated)
+        let (intent_status_update, attempt_status_update) = if !matches!(
+            payment_data.payment_intent.status,
+            enums::IntentStatus::RequiresCapture | enums::IntentStatus::Succeeded
+        ) {
+            let payment_intent_update = storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::Cancelled,
+                updated_by: storage_scheme.to_string(),
+                incremental_authorization_allowed: None,
             };
+            (Some(payment_intent_update), enums::AttemptStatus::Voided)
+        } else {
+            (None, enums::AttemptStatus::VoidInitiated)
+        };
 
         if let Some(payment_intent_update) = intent_status_update {
             payment_data.payment_intent = state
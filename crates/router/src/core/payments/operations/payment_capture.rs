@@ -124,6 +124,20 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+            helpers::validate_amount_to_capture_against_captures(
+                payment_attempt.get_total_amount(),
+                &previous_captures,
+                amount_to_capture,
+            )?;
+
+            // The sequence of the new capture follows the captures made so far, so that it stays
+            // consistent with the captures table even if a previous capture count was not persisted
+            payment_attempt.multiple_capture_count = Some(
+                i16::try_from(previous_captures.len())
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Error while converting from usize to i16")?,
+            );
+
             let capture = db
                 .insert_capture(
                     payment_attempt
@@ -254,6 +268,7 @@ impl<F: Send + Clone> GetTracker<F, payments::PaymentData<F>, api::PaymentsCaptu
             poll_config: None,
             tax_data: None,
             session_id: None,
+            risk_signals: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
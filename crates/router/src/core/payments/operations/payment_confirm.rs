@@ -30,10 +30,11 @@ use crate::{
 };
 use crate::{
     core::{
-        authentication,
+        api_locking, authentication,
         blocklist::utils as blocklist_utils,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
+        metadata_schema,
         payments::{
             self, helpers, operations, populate_surcharge_details, CustomerDetails, PaymentAddress,
             PaymentData,
@@ -108,6 +109,8 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
 
         helpers::validate_customer_access(&payment_intent, auth_flow, request)?;
 
+        helpers::validate_connector_override_access(auth_flow, request)?;
+
         if [
             Some(common_enums::PaymentSource::Webhook),
             Some(common_enums::PaymentSource::ExternalAuthenticator),
@@ -140,7 +143,13 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             )?;
         }
 
-        helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
+        payments::client_token::authenticate_client_secret_or_token(
+            state,
+            request.client_secret.as_ref(),
+            &payment_intent,
+            api_models::payments::ClientTokenScope::Confirm,
+        )
+        .await?;
 
         let customer_details = helpers::get_customer_details_from_request(request);
 
@@ -444,8 +453,29 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error converting feature_metadata to Value")?
             .or(payment_intent.feature_metadata);
+        if request.metadata.is_some() {
+            metadata_schema::validate_payment_metadata(
+                state,
+                &payment_intent.merchant_id,
+                request.metadata.as_ref(),
+            )
+            .await?;
+        }
         payment_intent.metadata = request.metadata.clone().or(payment_intent.metadata);
         payment_intent.frm_metadata = request.frm_metadata.clone().or(payment_intent.frm_metadata);
+        payments::order_reference::reserve_changed_order_reference(
+            state,
+            merchant_id,
+            payment_intent.profile_id.as_ref(),
+            &payment_intent.payment_id,
+            payment_intent.merchant_order_reference_id.as_deref(),
+            request.merchant_order_reference_id.as_deref(),
+        )
+        .await?;
+        payment_intent.merchant_order_reference_id = request
+            .merchant_order_reference_id
+            .clone()
+            .or(payment_intent.merchant_order_reference_id);
         payment_intent.request_incremental_authorization = request
             .request_incremental_authorization
             .map(|request_incremental_authorization| {
@@ -805,6 +835,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             poll_config: None,
             tax_data: None,
             session_id: None,
+            risk_signals: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -916,6 +947,11 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest, PaymentData<F>> for Paymen
         _payment_intent: &storage::PaymentIntent,
         _key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<api::ConnectorChoice, errors::ApiErrorResponse> {
+        // The connector override applies to the attempt made by this confirm call alone
+        if let Some(connector_override) = request.connector_override.clone() {
+            return Ok(api::ConnectorChoice::Override(connector_override));
+        }
+
         // Use a new connector in the confirm call or use the same one which was passed when
         // creating the payment or if none is passed then use the routing algorithm
         helpers::get_connector_default(state, request.routing.clone()).await
@@ -1150,6 +1186,10 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
     where
         F: 'b + Send,
     {
+        // The lock may have expired and been acquired by another request while this one was
+        // waiting on the connector, writes are only allowed while the fencing token is still held
+        api_locking::validate_fenced_lock(state, &req_state.fenced_lock_status).await?;
+
         let payment_method = payment_data.payment_attempt.payment_method;
         let browser_info = payment_data.payment_attempt.browser_info.clone();
         let frm_message = payment_data.frm_message.clone();
@@ -1221,6 +1261,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             .payment_attempt
             .straight_through_algorithm
             .clone();
+        let routing_decision = payment_data.payment_attempt.routing_decision.clone();
         let payment_token = payment_data.token.clone();
         let payment_method_type = payment_data.payment_attempt.payment_method_type;
         let profile_id = payment_data
@@ -1351,6 +1392,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         payment_experience,
                         business_sub_label: m_business_sub_label,
                         straight_through_algorithm: m_straight_through_algorithm,
+                        routing_decision,
                         error_code: m_error_code,
                         error_message: m_error_message,
                         amount_capturable: Some(authorized_amount),
@@ -1421,6 +1463,10 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
         let m_order_details = order_details.clone();
         let m_metadata = metadata.clone();
         let m_frm_metadata = frm_metadata.clone();
+        let m_merchant_order_reference_id = payment_data
+            .payment_intent
+            .merchant_order_reference_id
+            .clone();
         let m_db = state.clone().store;
         let m_storage_scheme = storage_scheme.to_string();
         let session_expiry = m_payment_data_payment_intent.session_expiry;
@@ -1457,7 +1503,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                         request_external_three_ds_authentication: None,
                         frm_metadata: m_frm_metadata,
                         customer_details,
-                        merchant_order_reference_id: None,
+                        merchant_order_reference_id: m_merchant_order_reference_id,
                         billing_details,
                         shipping_details,
                         is_payment_processor_token_flow,
@@ -1545,6 +1591,9 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest, PaymentData<F>> f
         if let Some(amount) = request.amount {
             helpers::validate_max_amount(amount)?;
         }
+        if let Some(connector_metadata) = &request.connector_metadata {
+            helpers::validate_connector_passthrough_data(connector_metadata)?;
+        }
 
         let request_merchant_id = request.merchant_id.as_ref();
         helpers::validate_merchant_id(merchant_account.get_id(), request_merchant_id)
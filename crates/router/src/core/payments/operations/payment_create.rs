@@ -36,7 +36,7 @@ use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
-        payment_link,
+        metadata_schema, payment_link,
         payment_methods::cards::create_encrypted_data,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
         utils as core_utils,
@@ -99,6 +99,9 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             merchant_account,
         )?;
 
+        metadata_schema::validate_payment_metadata(state, merchant_id, request.metadata.as_ref())
+            .await?;
+
         // If profile id is not passed, get it from the business_country and business_label
         #[cfg(feature = "v1")]
         let profile_id = core_utils::get_profile_id_from_business_details(
@@ -281,6 +284,10 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
                     domain_name,
                     session_expiry,
                     header_payload.locale.clone(),
+                    business_profile
+                        .enable_payment_response_hash
+                        .then_some(business_profile.payment_response_hash_key.as_deref())
+                        .flatten(),
                 )
                 .await?
             }
@@ -611,6 +618,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             poll_config: None,
             tax_data: None,
             session_id: None,
+            risk_signals: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -853,6 +861,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
             .payment_attempt
             .straight_through_algorithm
             .clone();
+        let routing_decision = payment_data.payment_attempt.routing_decision.clone();
         let authorized_amount = payment_data.payment_attempt.get_total_amount();
         let merchant_connector_id = payment_data.payment_attempt.merchant_connector_id.clone();
 
@@ -873,6 +882,7 @@ impl<F: Clone> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for Paymen
                     payment_token,
                     connector,
                     straight_through_algorithm,
+                    routing_decision,
                     amount_capturable: match payment_data.confirm.unwrap_or(true) {
                         true => Some(authorized_amount),
                         false => None,
@@ -953,6 +963,9 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest, PaymentData<F>> f
         if let Some(session_expiry) = &request.session_expiry {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
+        if let Some(connector_metadata) = &request.connector_metadata {
+            helpers::validate_connector_passthrough_data(connector_metadata)?;
+        }
 
         if let Some(payment_link) = &request.payment_link {
             if *payment_link {
@@ -1272,6 +1285,7 @@ impl PaymentCreate {
                 organization_id: organization_id.clone(),
                 profile_id,
                 connector_mandate_detail: None,
+                routing_decision: None,
             },
             additional_pm_data,
 
@@ -1479,6 +1493,7 @@ impl PaymentCreate {
             shipping_cost: request.shipping_cost,
             tax_details: None,
             skip_external_tax_calculation,
+            row_version: common_utils::consts::INITIAL_ROW_VERSION,
         })
     }
 
@@ -1530,6 +1545,7 @@ async fn create_payment_link(
     domain_name: String,
     session_expiry: PrimitiveDateTime,
     locale: Option<String>,
+    url_signing_key: Option<&str>,
 ) -> RouterResult<Option<api_models::payments::PaymentLinkResponse>> {
     let created_at @ last_modified_at = Some(common_utils::date_time::now());
     let payment_link_id = utils::generate_id(consts::ID_LENGTH, "plink");
@@ -1552,6 +1568,18 @@ async fn create_payment_link(
         )
     });
 
+    // Links of merchants opting into response hashing are signed, so that they are verified when
+    // opened
+    let (open_payment_link, secure_link) = match url_signing_key {
+        Some(key) => (
+            helpers::sign_url(&open_payment_link, payment_id.get_string_repr(), key)?,
+            secure_link
+                .map(|link| helpers::sign_url(&link, payment_id.get_string_repr(), key))
+                .transpose()?,
+        ),
+        None => (open_payment_link, secure_link),
+    };
+
     let payment_link_config_encoded_value = payment_link_config.encode_to_value().change_context(
         errors::ApiErrorResponse::InvalidDataValue {
             field_name: "payment_link_config",
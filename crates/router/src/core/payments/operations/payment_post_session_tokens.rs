@@ -165,6 +165,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsPostSessionToke
             poll_config: None,
             tax_data: None,
             session_id: None,
+            risk_signals: None,
         };
         let get_trackers_response = operations::GetTrackerResponse {
             operation: Box::new(self),
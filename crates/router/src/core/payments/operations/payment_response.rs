@@ -22,6 +22,11 @@ use tracing_futures::Instrument;
 use super::{Operation, OperationSessionSetters, PostUpdateTracker};
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::core::routing::helpers as routing_helpers;
+#[cfg(feature = "v1")]
+use crate::core::{
+    experiments,
+    payments::{processing_limits, void_window},
+};
 use crate::{
     connector::utils::PaymentResponseRouterData,
     consts,
@@ -1258,6 +1263,13 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     >,
     #[cfg(all(feature = "v1", feature = "dynamic_routing"))] business_profile: &domain::Profile,
 ) -> RouterResult<PaymentData<F>> {
+    // References for raising tickets with the connector, and for tracing the call in the logs
+    let connector_request_id = router_data.connector_request_id.clone();
+    let correlation_id = state
+        .request_id
+        .as_ref()
+        .map(|request_id| request_id.as_hyphenated().to_string());
+
     // Update additional payment data with the payment method response that we received from connector
     // This is for details like whether 3ds was upgraded and which version of 3ds was used
     // also some connectors might send card network details in the response, which is captured and stored
@@ -1428,6 +1440,8 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             connector_transaction_id: err.connector_transaction_id,
                             payment_method_data: additional_payment_method_data,
                             authentication_type: auth_update,
+                            connector_request_id,
+                            correlation_id,
                         }),
                     )
                 }
@@ -1465,6 +1479,8 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                             connector_transaction_id,
                             payment_method_data: None,
                             authentication_type: auth_update,
+                            connector_request_id,
+                            correlation_id,
                         }),
                     )
                 }
@@ -1665,6 +1681,9 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 payment_data.payment_attempt.connector.clone(),
                                 payment_data.payment_attempt.merchant_id.clone(),
                             );
+                            let connector_fee = router_data.connector_response.as_ref().and_then(
+                                |connector_response| connector_response.connector_fee.clone(),
+                            );
                             let (capture_updates, payment_attempt_update) = match payment_data
                                 .multiple_capture_data
                             {
@@ -1679,6 +1698,12 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                                 (Some(txn_id), txn_data)
                                             }
                                         };
+                                    let settlement_details = router_data
+                                        .connector_response
+                                        .as_ref()
+                                        .and_then(|connector_response| {
+                                            connector_response.settlement_details.clone()
+                                        });
                                     let capture_update = storage::CaptureUpdate::ResponseUpdate {
                                         status: enums::CaptureStatus::foreign_try_from(
                                             router_data.status,
@@ -1686,6 +1711,11 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                         connector_capture_id: connector_capture_id.clone(),
                                         connector_response_reference_id,
                                         connector_capture_data: connector_capture_data.clone(),
+                                        settlement_currency: settlement_details
+                                            .as_ref()
+                                            .map(|details| details.settlement_currency),
+                                        settlement_amount: settlement_details
+                                            .map(|details| details.settlement_amount),
                                     };
                                     let capture_update_list = vec![(
                                         multiple_capture_data.get_latest_capture().clone(),
@@ -1731,6 +1761,13 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                             .payment_attempt
                                             .connector_mandate_detail
                                             .clone(),
+                                        connector_fee_amount: connector_fee
+                                            .as_ref()
+                                            .map(|fee| fee.amount),
+                                        connector_fee_currency: connector_fee
+                                            .map(|fee| fee.currency),
+                                        connector_request_id,
+                                        correlation_id,
                                     }),
                                 ),
                             };
@@ -2019,6 +2056,51 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         }
     }
 
+    if payment_attempt.routing_decision.is_some() {
+        tokio::spawn(
+            experiments::record_experiment_outcome(state.clone(), payment_attempt.clone())
+                .in_current_span(),
+        );
+    }
+
+    // The volume of the processing limits only counts the payments which succeeded, once
+    let is_successful = |status| {
+        matches!(
+            status,
+            common_enums::IntentStatus::Succeeded
+                | common_enums::IntentStatus::RequiresCapture
+                | common_enums::IntentStatus::PartiallyCaptured
+                | common_enums::IntentStatus::PartiallyCapturedAndCapturable
+        )
+    };
+    if !is_successful(payment_data.payment_intent.status) && is_successful(payment_intent.status) {
+        tokio::spawn(
+            processing_limits::record_processing_volume(
+                state.clone(),
+                payment_attempt.profile_id.clone(),
+                payment_attempt.net_amount.get_total_amount(),
+                payment_attempt.currency,
+            )
+            .in_current_span(),
+        );
+    }
+
+    // The void window of captured payments is measured from their capture
+    let is_captured = |status| {
+        matches!(
+            status,
+            enums::AttemptStatus::Charged
+                | enums::AttemptStatus::PartialCharged
+                | enums::AttemptStatus::PartialChargedAndChargeable
+        )
+    };
+    if !is_captured(payment_data.payment_attempt.status) && is_captured(payment_attempt.status) {
+        tokio::spawn(
+            void_window::record_capture_time(state.clone(), payment_attempt.clone())
+                .in_current_span(),
+        );
+    }
+
     payment_data.payment_intent = payment_intent;
     payment_data.payment_attempt = payment_attempt;
     router_data.payment_method_status.and_then(|status| {
@@ -2144,7 +2226,9 @@ async fn update_payment_method_status_and_ntid<F: Clone>(
                 None
             };
 
+        // Suspended tokens are resumed only by their lifecycle events
         let pm_update = if payment_method.status != common_enums::PaymentMethodStatus::Active
+            && payment_method.status != common_enums::PaymentMethodStatus::Suspended
             && payment_method.status != attempt_status.into()
         {
             let updated_pm_status = common_enums::PaymentMethodStatus::from(attempt_status);
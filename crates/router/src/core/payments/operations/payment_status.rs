@@ -12,8 +12,8 @@ use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         payments::{
-            helpers, operations, types as payment_types, CustomerDetails, PaymentAddress,
-            PaymentData,
+            client_token, helpers, operations, types as payment_types, CustomerDetails,
+            PaymentAddress, PaymentData,
         },
     },
     routes::{app::ReqState, SessionState},
@@ -261,7 +261,13 @@ async fn get_tracker_for_sync<
     )
     .await?;
 
-    helpers::authenticate_client_secret(request.client_secret.as_ref(), &payment_intent)?;
+    client_token::authenticate_client_secret_or_token(
+        state,
+        request.client_secret.as_ref(),
+        &payment_intent,
+        api_models::payments::ClientTokenScope::Retrieve,
+    )
+    .await?;
 
     let payment_id = payment_attempt.payment_id.clone();
 
@@ -506,6 +512,7 @@ async fn get_tracker_for_sync<
         poll_config: None,
         tax_data: None,
         session_id: None,
+        risk_signals: None,
     };
 
     let get_trackers_response = operations::GetTrackerResponse {
@@ -21,6 +21,7 @@ use crate::{
     core::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
+        metadata_schema,
         payment_methods::cards::create_encrypted_data,
         payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
         utils as core_utils,
@@ -269,6 +270,14 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Error converting feature_metadata to Value")?
             .or(payment_intent.feature_metadata);
+        if request.metadata.is_some() {
+            metadata_schema::validate_payment_metadata(
+                state,
+                &payment_intent.merchant_id,
+                request.metadata.as_ref(),
+            )
+            .await?;
+        }
         payment_intent.metadata = request.metadata.clone().or(payment_intent.metadata);
         payment_intent.frm_metadata = request.frm_metadata.clone().or(payment_intent.frm_metadata);
         Self::populate_payment_intent_with_request(&mut payment_intent, request);
@@ -388,6 +397,15 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             .request_external_three_ds_authentication
             .or(payment_intent.request_external_three_ds_authentication);
 
+        payments::order_reference::reserve_changed_order_reference(
+            state,
+            merchant_id,
+            payment_intent.profile_id.as_ref(),
+            &payment_intent.payment_id,
+            payment_intent.merchant_order_reference_id.as_deref(),
+            request.merchant_order_reference_id.as_deref(),
+        )
+        .await?;
         payment_intent.merchant_order_reference_id = request
             .merchant_order_reference_id
             .clone()
@@ -487,6 +505,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             poll_config: None,
             tax_data: None,
             session_id: None,
+            risk_signals: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -967,6 +986,9 @@ impl<F: Send + Clone> ValidateRequest<F, api::PaymentsRequest, PaymentData<F>> f
         if let Some(session_expiry) = &request.session_expiry {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
+        if let Some(connector_metadata) = &request.connector_metadata {
+            helpers::validate_connector_passthrough_data(connector_metadata)?;
+        }
         let payment_id = request
             .payment_id
             .clone()
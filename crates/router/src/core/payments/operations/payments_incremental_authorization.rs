@@ -171,6 +171,7 @@ impl<F: Send + Clone>
             poll_config: None,
             tax_data: None,
             session_id: None,
+            risk_signals: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -178,6 +178,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsDynamicTaxCalcu
             poll_config: None,
             tax_data: Some(tax_data),
             session_id: request.session_id.clone(),
+            risk_signals: None,
         };
         let get_trackers_response = operations::GetTrackerResponse {
             operation: Box::new(self),
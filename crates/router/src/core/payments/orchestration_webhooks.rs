@@ -0,0 +1,292 @@
+use api_models::{routing as api_routing, webhooks};
+use common_utils::{date_time, id_type};
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use redis_interface::SetnxReply;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        webhooks as webhooks_core,
+    },
+    routes::SessionState,
+    types::{self, domain, storage},
+};
+
+const CIRCUIT_BREAKER_NOTIFIED_PREFIX: &str = "ORCHESTRATION_CIRCUIT_BREAKER_NOTIFIED";
+
+fn get_circuit_breaker_notified_key(merchant_id: &id_type::MerchantId, connector: &str) -> String {
+    format!(
+        "{CIRCUIT_BREAKER_NOTIFIED_PREFIX}_{}_{connector}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn is_same_connector(
+    connector: &api_routing::RoutableConnectorChoice,
+    other: &api_routing::RoutableConnectorChoice,
+) -> bool {
+    connector.connector == other.connector
+        && connector.merchant_connector_id == other.merchant_connector_id
+}
+
+/// The payment attempt on which an orchestration decision is taken, captured before the decision
+/// replaces the attempt of the payment
+#[derive(Debug, Clone)]
+pub struct OrchestrationDecisionContext {
+    pub attempt_id: String,
+    pub connector: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl OrchestrationDecisionContext {
+    pub fn new<F, FData>(
+        payment_attempt: &storage::PaymentAttempt,
+        router_data: &types::RouterData<F, FData, types::PaymentsResponseData>,
+    ) -> Self {
+        let error = router_data.response.as_ref().err();
+        Self {
+            attempt_id: payment_attempt.attempt_id.clone(),
+            connector: payment_attempt.connector.clone(),
+            error_code: error.map(|error| error.code.clone()),
+            error_message: error.map(|error| error.message.clone()),
+        }
+    }
+}
+
+/// Orchestration webhooks are opt-in, as they are sent in addition to the payment status
+/// webhooks
+async fn is_orchestration_webhooks_enabled(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> bool {
+    let config = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_should_send_orchestration_webhooks_key(),
+            Some("false".to_string()),
+        )
+        .await;
+    match config {
+        Ok(conf) => conf.config == "true",
+        Err(error) => {
+            logger::error!(?error);
+            false
+        }
+    }
+}
+
+fn to_orchestration_event_response(
+    payment_intent: &storage::PaymentIntent,
+    reason: api_routing::OrchestrationDecisionReason,
+) -> api_routing::PaymentOrchestrationEventResponse {
+    api_routing::PaymentOrchestrationEventResponse {
+        payment_id: payment_intent.payment_id.clone(),
+        merchant_id: payment_intent.merchant_id.clone(),
+        profile_id: payment_intent.profile_id.clone(),
+        attempt_id: None,
+        connector: None,
+        next_connector: None,
+        next_attempt_id: None,
+        reason,
+        error_code: None,
+        error_message: None,
+        created_at: date_time::now(),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn trigger_orchestration_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    event_type: storage_enums::EventType,
+    primary_object_id: String,
+    orchestration_response: api_routing::PaymentOrchestrationEventResponse,
+) {
+    let result = Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile.clone(),
+        key_store,
+        event_type,
+        storage_enums::EventClass::Orchestration,
+        primary_object_id,
+        storage_enums::EventObjectType::OrchestrationDetails,
+        webhooks::OutgoingWebhookContent::OrchestrationDetails(Box::new(orchestration_response)),
+        None,
+    ))
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, %event_type, "Failed to trigger the orchestration webhook");
+    }
+}
+
+/// Notifies the merchant that a failed payment attempt was retried, the new attempt of the payment
+/// being `next_attempt`
+#[instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
+pub async fn trigger_payment_retry_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    previous_attempt: OrchestrationDecisionContext,
+    next_attempt: &storage::PaymentAttempt,
+    reason: api_routing::OrchestrationDecisionReason,
+) {
+    if !is_orchestration_webhooks_enabled(state, merchant_account.get_id()).await {
+        return;
+    }
+
+    let orchestration_response = api_routing::PaymentOrchestrationEventResponse {
+        attempt_id: Some(previous_attempt.attempt_id),
+        connector: previous_attempt.connector,
+        next_connector: next_attempt.connector.clone(),
+        next_attempt_id: Some(next_attempt.attempt_id.clone()),
+        error_code: previous_attempt.error_code,
+        error_message: previous_attempt.error_message,
+        ..to_orchestration_event_response(payment_intent, reason)
+    };
+
+    trigger_orchestration_webhook(
+        state,
+        merchant_account,
+        business_profile,
+        key_store,
+        storage_enums::EventType::PaymentRetryTriggered,
+        next_attempt.attempt_id.clone(),
+        orchestration_response,
+    )
+    .await;
+}
+
+async fn should_notify_circuit_breaker_opened(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector: &str,
+) -> RouterResult<bool> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    // The key expires along with the outage of the connector, so that the merchant is notified
+    // once per outage
+    let reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &get_circuit_breaker_notified_key(merchant_id, connector),
+            date_time::now_unix_timestamp(),
+            Some(
+                state
+                    .conf
+                    .connector_outage_detection
+                    .degraded_duration_in_secs,
+            ),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the circuit breaker notification")?;
+
+    Ok(reply == SetnxReply::KeySet)
+}
+
+/// Notifies the merchant of the degraded connectors which the payment was routed around. The
+/// circuit breaker of a connector is notified once per outage, and the switch of the connector
+/// whenever the preferred connector of the payment is degraded.
+#[instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
+pub async fn trigger_degraded_connector_webhooks(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::Profile,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    preferred_connector: Option<&api_routing::RoutableConnectorChoice>,
+    routed_connector: Option<&api_routing::RoutableConnectorChoice>,
+    degraded_connectors: &[api_routing::RoutableConnectorChoice],
+) {
+    if degraded_connectors.is_empty()
+        || !is_orchestration_webhooks_enabled(state, merchant_account.get_id()).await
+    {
+        return;
+    }
+
+    let next_connector = routed_connector.map(|connector| connector.connector.to_string());
+
+    for degraded_connector in degraded_connectors {
+        let connector = degraded_connector.connector.to_string();
+        match should_notify_circuit_breaker_opened(state, merchant_account.get_id(), &connector)
+            .await
+        {
+            Ok(true) => {
+                let orchestration_response = api_routing::PaymentOrchestrationEventResponse {
+                    attempt_id: Some(payment_attempt.attempt_id.clone()),
+                    connector: Some(connector.clone()),
+                    next_connector: next_connector.clone(),
+                    ..to_orchestration_event_response(
+                        payment_intent,
+                        api_routing::OrchestrationDecisionReason::ConnectorDegraded,
+                    )
+                };
+                trigger_orchestration_webhook(
+                    state,
+                    merchant_account,
+                    business_profile,
+                    key_store,
+                    storage_enums::EventType::ConnectorCircuitBreakerOpened,
+                    format!("{}_{connector}", payment_attempt.attempt_id),
+                    orchestration_response,
+                )
+                .await;
+            }
+            Ok(false) => {}
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    connector,
+                    "Failed to check the circuit breaker notification"
+                );
+            }
+        }
+    }
+
+    let Some(preferred_connector) = preferred_connector else {
+        return;
+    };
+    let is_switched = degraded_connectors
+        .iter()
+        .any(|connector| is_same_connector(connector, preferred_connector))
+        && routed_connector
+            .is_some_and(|connector| !is_same_connector(connector, preferred_connector));
+    if !is_switched {
+        return;
+    }
+
+    let orchestration_response = api_routing::PaymentOrchestrationEventResponse {
+        attempt_id: Some(payment_attempt.attempt_id.clone()),
+        connector: Some(preferred_connector.connector.to_string()),
+        next_connector,
+        ..to_orchestration_event_response(
+            payment_intent,
+            api_routing::OrchestrationDecisionReason::ConnectorDegraded,
+        )
+    };
+    trigger_orchestration_webhook(
+        state,
+        merchant_account,
+        business_profile,
+        key_store,
+        storage_enums::EventType::PaymentConnectorSwitched,
+        payment_attempt.attempt_id.clone(),
+        orchestration_response,
+    )
+    .await;
+}
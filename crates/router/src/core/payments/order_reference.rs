@@ -0,0 +1,419 @@
+use api_models::payments::PaymentsRequest;
+use common_utils::{ext_traits::StringExt, id_type};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    types::{domain, storage, OrderReferenceDuplicateAction, OrderReferenceUniquenessConfig},
+};
+
+/// Outcome of reserving the merchant order reference of a payment being created
+#[derive(Debug)]
+pub enum OrderReferenceReservation {
+    /// The order reference of the payment is not required to be unique
+    NotRequired,
+    /// The order reference is reserved for the payment being created
+    Reserved {
+        profile_id: id_type::ProfileId,
+        merchant_order_reference_id: String,
+    },
+    /// The order reference is already used by this payment, which is to be returned instead
+    Existing(id_type::PaymentId),
+}
+
+async fn find_order_reference_uniqueness_config(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> Option<OrderReferenceUniquenessConfig> {
+    state
+        .store
+        .find_config_by_key(&OrderReferenceUniquenessConfig::get_config_key(profile_id))
+        .await
+        .ok()?
+        .config
+        .parse_struct::<OrderReferenceUniquenessConfig>("OrderReferenceUniquenessConfig")
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to parse the order reference uniqueness config"
+            )
+        })
+        .ok()
+}
+
+/// Reserves the merchant order reference of the payment being created, if the profile enforces the
+/// uniqueness of order references. The reservation relies on the unique key of the order references
+/// of a profile, so that concurrent payments with the same order reference cannot both succeed.
+#[instrument(skip_all)]
+pub async fn reserve_order_reference(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: Option<&id_type::ProfileId>,
+    request: &PaymentsRequest,
+) -> RouterResult<OrderReferenceReservation> {
+    let Some(merchant_order_reference_id) = request.merchant_order_reference_id.clone() else {
+        return Ok(OrderReferenceReservation::NotRequired);
+    };
+    let payment_id = request
+        .payment_id
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "payment_id",
+        })?
+        .get_payment_intent_id()
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        &state.into(),
+        key_store,
+        request.business_country,
+        request.business_label.as_ref(),
+        merchant_account,
+        request.profile_id.as_ref().or(profile_id),
+        &*state.store,
+        false,
+    )
+    .await?;
+    let Some(config) = find_order_reference_uniqueness_config(state, &profile_id).await else {
+        return Ok(OrderReferenceReservation::NotRequired);
+    };
+
+    let payment_order_reference = storage::PaymentOrderReferenceNew {
+        profile_id: profile_id.clone(),
+        merchant_order_reference_id: merchant_order_reference_id.clone(),
+        merchant_id: merchant_account.get_id().clone(),
+        payment_id: payment_id.clone(),
+        created_at: common_utils::date_time::now(),
+    };
+    match state
+        .store
+        .insert_payment_order_reference(payment_order_reference)
+        .await
+    {
+        Ok(_) => Ok(OrderReferenceReservation::Reserved {
+            profile_id,
+            merchant_order_reference_id,
+        }),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            let existing = state
+                .store
+                .find_payment_order_reference_by_profile_id_merchant_order_reference_id(
+                    &profile_id,
+                    &merchant_order_reference_id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the payment using the order reference")?;
+
+            get_reservation_of_used_order_reference(
+                config.on_duplicate,
+                &merchant_order_reference_id,
+                &payment_id,
+                existing.payment_id,
+            )
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reserve the order reference of the payment"),
+    }
+}
+
+/// Outcome of creating a payment with an order reference already used by the existing payment
+fn get_reservation_of_used_order_reference(
+    on_duplicate: OrderReferenceDuplicateAction,
+    merchant_order_reference_id: &str,
+    payment_id: &id_type::PaymentId,
+    existing_payment_id: id_type::PaymentId,
+) -> RouterResult<OrderReferenceReservation> {
+    // A retry of the same payment is left to the duplicate checks of the payment itself
+    if existing_payment_id == *payment_id {
+        return Ok(OrderReferenceReservation::NotRequired);
+    }
+    match on_duplicate {
+        OrderReferenceDuplicateAction::Reject => Err(get_order_reference_used_error(
+            merchant_order_reference_id,
+            &existing_payment_id,
+        )),
+        OrderReferenceDuplicateAction::ReturnExisting => {
+            Ok(OrderReferenceReservation::Existing(existing_payment_id))
+        }
+    }
+}
+
+fn get_order_reference_used_error(
+    merchant_order_reference_id: &str,
+    existing_payment_id: &id_type::PaymentId,
+) -> error_stack::Report<errors::ApiErrorResponse> {
+    report!(errors::ApiErrorResponse::GenericDuplicateError {
+        message: format!(
+            "The order reference '{}' is already used by the payment '{}'",
+            merchant_order_reference_id,
+            existing_payment_id.get_string_repr()
+        ),
+    })
+}
+
+/// The order reference a payment is changed to by an update or confirm request, if it differs from
+/// the current order reference of the payment
+fn get_changed_order_reference<'a>(
+    current_order_reference_id: Option<&str>,
+    requested_order_reference_id: Option<&'a str>,
+) -> Option<&'a str> {
+    requested_order_reference_id.filter(|requested| current_order_reference_id != Some(*requested))
+}
+
+/// Reserves the order reference a payment is changed to by an update or confirm request, if the
+/// profile enforces the uniqueness of order references, and releases the previous order reference
+/// of the payment. An order reference used by another payment is always rejected, since the
+/// request is for a specific payment and cannot be answered with the other one.
+#[instrument(skip_all)]
+pub async fn reserve_changed_order_reference(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<&id_type::ProfileId>,
+    payment_id: &id_type::PaymentId,
+    current_order_reference_id: Option<&str>,
+    requested_order_reference_id: Option<&str>,
+) -> RouterResult<()> {
+    let Some(merchant_order_reference_id) =
+        get_changed_order_reference(current_order_reference_id, requested_order_reference_id)
+    else {
+        return Ok(());
+    };
+    let Some(profile_id) = profile_id else {
+        return Ok(());
+    };
+    if find_order_reference_uniqueness_config(state, profile_id)
+        .await
+        .is_none()
+    {
+        return Ok(());
+    }
+
+    let payment_order_reference = storage::PaymentOrderReferenceNew {
+        profile_id: profile_id.clone(),
+        merchant_order_reference_id: merchant_order_reference_id.to_string(),
+        merchant_id: merchant_id.clone(),
+        payment_id: payment_id.clone(),
+        created_at: common_utils::date_time::now(),
+    };
+    match state
+        .store
+        .insert_payment_order_reference(payment_order_reference)
+        .await
+    {
+        Ok(_) => (),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            let existing = state
+                .store
+                .find_payment_order_reference_by_profile_id_merchant_order_reference_id(
+                    profile_id,
+                    merchant_order_reference_id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find the payment using the order reference")?;
+            // Reserved by an earlier request for this payment which did not complete
+            if existing.payment_id != *payment_id {
+                return Err(get_order_reference_used_error(
+                    merchant_order_reference_id,
+                    &existing.payment_id,
+                ));
+            }
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reserve the order reference of the payment")
+        }
+    }
+
+    if let Some(previous_order_reference_id) = current_order_reference_id {
+        release_previous_order_reference(
+            state,
+            profile_id,
+            payment_id,
+            previous_order_reference_id,
+        )
+        .await;
+    }
+    Ok(())
+}
+
+/// Releases the previous order reference of a payment, unless it is reserved by another payment,
+/// which is possible for payments created before the profile enforced uniqueness
+async fn release_previous_order_reference(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    payment_id: &id_type::PaymentId,
+    previous_order_reference_id: &str,
+) {
+    match state
+        .store
+        .find_payment_order_reference_by_profile_id_merchant_order_reference_id(
+            profile_id,
+            previous_order_reference_id,
+        )
+        .await
+    {
+        Ok(previous) if previous.payment_id == *payment_id => (),
+        Ok(_) => return,
+        Err(error) if error.current_context().is_db_not_found() => return,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to find the previous order reference of the payment"
+            );
+            return;
+        }
+    }
+
+    if let Err(error) = state
+        .store
+        .delete_payment_order_reference_by_profile_id_merchant_order_reference_id(
+            profile_id,
+            previous_order_reference_id,
+        )
+        .await
+    {
+        logger::error!(
+            ?error,
+            "Failed to release the previous order reference of the payment"
+        );
+    }
+}
+
+/// Releases the order reference reserved for a payment which could not be created, so that the
+/// merchant can retry with the same order reference. The reservation is kept if the payment was
+/// created despite the failure.
+#[instrument(skip_all)]
+pub async fn release_order_reference(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+    reservation: &OrderReferenceReservation,
+) {
+    let OrderReferenceReservation::Reserved {
+        profile_id,
+        merchant_order_reference_id,
+    } = reservation
+    else {
+        return;
+    };
+
+    match state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(_) => return,
+        Err(error) if error.current_context().is_db_not_found() => (),
+        Err(error) => {
+            logger::error!(?error, "Failed to find the payment of the order reference");
+            return;
+        }
+    }
+
+    if let Err(error) = state
+        .store
+        .delete_payment_order_reference_by_profile_id_merchant_order_reference_id(
+            profile_id,
+            merchant_order_reference_id,
+        )
+        .await
+    {
+        logger::error!(
+            ?error,
+            "Failed to release the order reference of the payment"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn get_payment_id(payment_id: &'static str) -> id_type::PaymentId {
+        id_type::PaymentId::try_from(Cow::Borrowed(payment_id)).unwrap()
+    }
+
+    #[test]
+    fn test_order_reference_used_by_another_payment() {
+        let payment_id = get_payment_id("pay_new");
+        let existing_payment_id = get_payment_id("pay_existing");
+
+        assert!(get_reservation_of_used_order_reference(
+            OrderReferenceDuplicateAction::Reject,
+            "order_1",
+            &payment_id,
+            existing_payment_id.clone(),
+        )
+        .is_err());
+        assert!(matches!(
+            get_reservation_of_used_order_reference(
+                OrderReferenceDuplicateAction::ReturnExisting,
+                "order_1",
+                &payment_id,
+                existing_payment_id.clone(),
+            )
+            .unwrap(),
+            OrderReferenceReservation::Existing(returned_payment_id)
+                if returned_payment_id == existing_payment_id
+        ));
+    }
+
+    #[test]
+    fn test_order_reference_used_by_the_same_payment() {
+        let payment_id = get_payment_id("pay_new");
+
+        for on_duplicate in [
+            OrderReferenceDuplicateAction::Reject,
+            OrderReferenceDuplicateAction::ReturnExisting,
+        ] {
+            assert!(matches!(
+                get_reservation_of_used_order_reference(
+                    on_duplicate,
+                    "order_1",
+                    &payment_id,
+                    payment_id.clone(),
+                )
+                .unwrap(),
+                OrderReferenceReservation::NotRequired
+            ));
+        }
+    }
+
+    #[test]
+    fn test_changed_order_reference() {
+        assert_eq!(
+            get_changed_order_reference(Some("order_1"), Some("order_2")),
+            Some("order_2")
+        );
+        assert_eq!(
+            get_changed_order_reference(None, Some("order_2")),
+            Some("order_2")
+        );
+        assert_eq!(
+            get_changed_order_reference(Some("order_1"), Some("order_1")),
+            None
+        );
+        assert_eq!(get_changed_order_reference(Some("order_1"), None), None);
+        assert_eq!(get_changed_order_reference(None, None), None);
+    }
+}
@@ -0,0 +1,321 @@
+use common_utils::{ext_traits::StringExt, id_type, types::MinorUnit};
+use error_stack::report;
+use redis_interface::{RedisConnectionPool, SetnxReply};
+use router_env::{instrument, logger, metrics::add_attributes, tracing};
+use rust_decimal::prelude::ToPrimitive;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::{metrics, SessionState},
+    types::{storage::enums, ProcessingLimitsConfig},
+    utils::currency,
+};
+
+const PROCESSING_VOLUME_PREFIX: &str = "PROCESSING_VOLUME";
+
+/// Period over which the volume of a profile is limited
+#[derive(Debug, Clone, Copy)]
+enum VolumePeriod {
+    Daily,
+    Monthly,
+}
+
+impl VolumePeriod {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Daily => "daily",
+            Self::Monthly => "monthly",
+        }
+    }
+
+    /// Identifier of the current period, counters of past periods expire on their own
+    fn get_current_period_id(self) -> String {
+        let today = common_utils::date_time::now().date();
+        match self {
+            Self::Daily => today.to_string(),
+            Self::Monthly => format!("{}-{:02}", today.year(), u8::from(today.month())),
+        }
+    }
+
+    /// Time for which the counter of a period is kept, slightly longer than the period itself
+    fn get_counter_ttl_in_secs(self) -> i64 {
+        match self {
+            Self::Daily => 2 * 24 * 60 * 60,
+            Self::Monthly => 32 * 24 * 60 * 60,
+        }
+    }
+}
+
+/// Volume counter of a profile for the current period
+struct VolumeCounter {
+    period: VolumePeriod,
+    limit: MinorUnit,
+    key: String,
+}
+
+impl VolumeCounter {
+    fn new(period: VolumePeriod, limit: MinorUnit, profile_id: &id_type::ProfileId) -> Self {
+        Self {
+            period,
+            limit,
+            key: format!(
+                "{}_{}_{}_{}",
+                PROCESSING_VOLUME_PREFIX,
+                profile_id.get_string_repr(),
+                period.as_str(),
+                period.get_current_period_id()
+            ),
+        }
+    }
+
+    fn get_alert_key(&self) -> String {
+        format!("{}_ALERT", self.key)
+    }
+}
+
+async fn find_processing_limits_config(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> Option<ProcessingLimitsConfig> {
+    state
+        .store
+        .find_config_by_key(&ProcessingLimitsConfig::get_config_key(profile_id))
+        .await
+        .ok()?
+        .config
+        .parse_struct::<ProcessingLimitsConfig>("ProcessingLimitsConfig")
+        .map_err(|error| logger::error!(?error, "Failed to parse the processing limits config"))
+        .ok()
+}
+
+/// Converts the amount to the minor unit of the reference currency of the processing limits
+async fn convert_to_reference_currency(
+    state: &SessionState,
+    amount: MinorUnit,
+    currency: enums::Currency,
+    reference_currency: enums::Currency,
+) -> Option<MinorUnit> {
+    if currency == reference_currency {
+        return Some(amount);
+    }
+
+    let forex_api = state.conf.forex_api.get_inner();
+    let rates = currency::get_forex_rates(
+        state,
+        forex_api.call_delay,
+        forex_api.local_fetch_retry_delay,
+        forex_api.local_fetch_retry_count,
+    )
+    .await
+    .map_err(|error| logger::error!(?error, "Failed to fetch the forex rates"))
+    .ok()?;
+
+    // The converted amount is in the major unit of the reference currency
+    let converted_amount = currency_conversion::conversion::convert(
+        &rates.data,
+        currency,
+        reference_currency,
+        amount.get_amount_as_i64(),
+    )
+    .map_err(|error| logger::error!(?error, "Failed to convert the amount"))
+    .ok()?;
+    let minor_unit_factor = rust_decimal::Decimal::from(10_i64.saturating_pow(u32::from(
+        reference_currency.number_of_digits_after_decimal_point(),
+    )));
+
+    (converted_amount * minor_unit_factor)
+        .round()
+        .to_i64()
+        .map(MinorUnit::new)
+}
+
+fn get_volume_counters(
+    config: &ProcessingLimitsConfig,
+    profile_id: &id_type::ProfileId,
+) -> Vec<VolumeCounter> {
+    [
+        config
+            .daily_volume_limit
+            .map(|limit| VolumeCounter::new(VolumePeriod::Daily, limit, profile_id)),
+        config
+            .monthly_volume_limit
+            .map(|limit| VolumeCounter::new(VolumePeriod::Monthly, limit, profile_id)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Enforces the processing limits of the profile on a payment being confirmed. The volume of the
+/// current day and month only counts the payments which succeeded, the payment is rejected if its
+/// amount would take the volume over a limit.
+///
+/// Failures in converting the amount or reading the volume never fail the payment.
+#[instrument(skip_all)]
+pub async fn check_processing_limits(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    amount: MinorUnit,
+    currency: Option<enums::Currency>,
+) -> RouterResult<()> {
+    let Some(config) = find_processing_limits_config(state, profile_id).await else {
+        return Ok(());
+    };
+    let Some(currency) = currency else {
+        return Ok(());
+    };
+    let Some(amount) =
+        convert_to_reference_currency(state, amount, currency, config.reference_currency).await
+    else {
+        logger::warn!(
+            %currency,
+            reference_currency = %config.reference_currency,
+            "Unable to convert the amount to the reference currency, skipping the processing limits"
+        );
+        return Ok(());
+    };
+
+    if config
+        .max_transaction_amount
+        .is_some_and(|max_transaction_amount| amount > max_transaction_amount)
+    {
+        record_limit_exceeded(profile_id, "per_transaction");
+        return Err(report!(errors::ApiErrorResponse::ProcessingLimitExceeded {
+            limit: "per transaction".to_string(),
+        }));
+    }
+
+    let counters = get_volume_counters(&config, profile_id);
+    if counters.is_empty() {
+        return Ok(());
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for processing limits"
+            );
+            return Ok(());
+        }
+    };
+
+    for counter in counters.iter() {
+        let volume = match redis_conn.get_key::<Option<i64>>(&counter.key).await {
+            Ok(volume) => volume.unwrap_or_default(),
+            Err(error) => {
+                logger::error!(?error, "Failed to fetch the processing volume");
+                continue;
+            }
+        };
+
+        if volume.saturating_add(amount.get_amount_as_i64()) > counter.limit.get_amount_as_i64() {
+            record_limit_exceeded(profile_id, counter.period.as_str());
+            return Err(report!(errors::ApiErrorResponse::ProcessingLimitExceeded {
+                limit: format!("{} volume", counter.period.as_str()),
+            }));
+        }
+    }
+
+    Ok(())
+}
+
+/// Records the amount of a payment which succeeded in the volume of the current day and month of
+/// the profile, raising an alert when a volume approaches its limit
+#[instrument(skip_all)]
+pub async fn record_processing_volume(
+    state: SessionState,
+    profile_id: id_type::ProfileId,
+    amount: MinorUnit,
+    currency: Option<enums::Currency>,
+) {
+    let Some(config) = find_processing_limits_config(&state, &profile_id).await else {
+        return;
+    };
+    let counters = get_volume_counters(&config, &profile_id);
+    let Some(currency) = currency.filter(|_| !counters.is_empty()) else {
+        return;
+    };
+    let Some(amount) =
+        convert_to_reference_currency(&state, amount, currency, config.reference_currency).await
+    else {
+        return;
+    };
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to get redis connection for processing limits"
+            );
+            return;
+        }
+    };
+
+    for counter in counters.iter() {
+        match redis_conn
+            .increment_key_by_with_expiry(
+                &counter.key,
+                amount.get_amount_as_i64(),
+                counter.period.get_counter_ttl_in_secs(),
+            )
+            .await
+        {
+            Ok(volume) if volume >= config.get_alert_threshold(counter.limit) => {
+                raise_limit_alert(&redis_conn, &profile_id, counter, volume).await;
+            }
+            Ok(_) => (),
+            Err(error) => logger::error!(?error, "Failed to record the processing volume"),
+        }
+    }
+}
+
+fn record_limit_exceeded(profile_id: &id_type::ProfileId, limit: &'static str) {
+    metrics::PROCESSING_LIMIT_EXCEEDED.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([
+            ("profile_id", profile_id.get_string_repr().to_owned()),
+            ("limit", limit.to_string()),
+        ]),
+    );
+}
+
+/// Raises an alert once per period when the volume approaches the limit
+async fn raise_limit_alert(
+    redis_conn: &RedisConnectionPool,
+    profile_id: &id_type::ProfileId,
+    counter: &VolumeCounter,
+    volume: i64,
+) {
+    match redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &counter.get_alert_key(),
+            "true",
+            Some(counter.period.get_counter_ttl_in_secs()),
+        )
+        .await
+    {
+        Ok(SetnxReply::KeySet) => {
+            metrics::PROCESSING_LIMIT_ALERT.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([
+                    ("profile_id", profile_id.get_string_repr().to_owned()),
+                    ("limit", counter.period.as_str().to_string()),
+                ]),
+            );
+            logger::warn!(
+                profile_id = %profile_id.get_string_repr(),
+                period = counter.period.as_str(),
+                volume,
+                limit = counter.limit.get_amount_as_i64(),
+                "Processing volume of the profile is approaching its limit"
+            );
+        }
+        Ok(SetnxReply::KeyNotSet) => (),
+        Err(error) => logger::error!(?error, "Failed to record the processing limit alert"),
+    }
+}
@@ -0,0 +1,315 @@
+use std::collections::BTreeSet;
+
+use api_models::payments as payment_types;
+use common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1;
+use error_stack::{report, ResultExt};
+use futures::future::try_join_all;
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+/// Payments created without an order reference are linked to a purchase by the fingerprints of
+/// their payment methods only when created within this duration of the payments of the purchase
+const FINGERPRINT_LINK_WINDOW: time::Duration = time::Duration::hours(24);
+
+fn get_purchase_constraints(
+    merchant_order_reference_id: Option<String>,
+    fingerprint_id: Option<Vec<String>>,
+    time_range: Option<(PrimitiveDateTime, PrimitiveDateTime)>,
+) -> PaymentIntentFetchConstraints {
+    PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+        offset: 0,
+        starting_at: time_range.map(|(starting_at, _)| starting_at),
+        ending_at: time_range.map(|(_, ending_at)| ending_at),
+        amount_filter: None,
+        connector: None,
+        currency: None,
+        status: None,
+        payment_method: None,
+        payment_method_type: None,
+        authentication_type: None,
+        merchant_connector_id: None,
+        profile_id: None,
+        customer_id: None,
+        starting_after_id: None,
+        ending_before_id: None,
+        limit: Some(PAYMENTS_LIST_MAX_LIMIT_V1),
+        order: Default::default(),
+        card_network: None,
+        metadata: None,
+        merchant_order_reference_id,
+        fingerprint_id,
+    }))
+}
+
+async fn find_payment_intents(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    constraints: &PaymentIntentFetchConstraints,
+) -> RouterResult<Vec<storage::PaymentIntent>> {
+    state
+        .store
+        .filter_payment_intent_by_constraints(
+            &state.into(),
+            merchant_account.get_id(),
+            constraints,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payments of the purchase")
+}
+
+async fn get_purchase_payment(
+    state: &SessionState,
+    payment_intent: storage::PaymentIntent,
+    linked_by: payment_types::PurchaseLinkType,
+) -> RouterResult<payment_types::PurchasePayment> {
+    let db = state.store.as_ref();
+    let mut payment_attempts = db
+        .find_attempts_by_merchant_id_payment_id(
+            &payment_intent.merchant_id,
+            &payment_intent.payment_id,
+            // since OLAP doesn't have KV. Force to get the data from PSQL.
+            enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the attempts of the payment")?;
+    payment_attempts.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    let disputes = db
+        .find_disputes_by_merchant_id_payment_id(
+            &payment_intent.merchant_id,
+            &payment_intent.payment_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the disputes of the payment")?;
+
+    Ok(payment_types::PurchasePayment {
+        payment_id: payment_intent.payment_id,
+        linked_by,
+        status: payment_intent.status,
+        amount: payment_intent.amount,
+        currency: payment_intent.currency,
+        customer_id: payment_intent.customer_id,
+        created: payment_intent.created_at,
+        attempts: payment_attempts
+            .into_iter()
+            .map(|payment_attempt| payment_types::PurchaseAttempt {
+                attempt_id: payment_attempt.attempt_id,
+                status: payment_attempt.status,
+                connector: payment_attempt.connector,
+                payment_method: payment_attempt.payment_method,
+                payment_method_type: payment_attempt.payment_method_type,
+                error_code: payment_attempt.error_code,
+                error_message: payment_attempt
+                    .error_reason
+                    .or(payment_attempt.error_message),
+                fingerprint_id: payment_attempt.fingerprint_id,
+                created: payment_attempt.created_at,
+            })
+            .collect(),
+        disputes: disputes
+            .into_iter()
+            .map(ForeignFrom::foreign_from)
+            .collect(),
+    })
+}
+
+/// Finds the payments created without an order reference, for the same amount and around the
+/// same time as the payments of the purchase, using any of the payment methods of the purchase
+async fn find_payment_intents_linked_by_fingerprint(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intents: &[storage::PaymentIntent],
+    fingerprint_ids: &BTreeSet<String>,
+) -> RouterResult<Vec<storage::PaymentIntent>> {
+    let created_at = payment_intents
+        .iter()
+        .map(|payment_intent| payment_intent.created_at);
+    let (Some(first_created_at), Some(last_created_at)) =
+        (created_at.clone().min(), created_at.max())
+    else {
+        return Ok(Vec::new());
+    };
+    if fingerprint_ids.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let constraints = get_purchase_constraints(
+        None,
+        Some(fingerprint_ids.iter().cloned().collect()),
+        Some((
+            first_created_at.saturating_sub(FINGERPRINT_LINK_WINDOW),
+            last_created_at.saturating_add(FINGERPRINT_LINK_WINDOW),
+        )),
+    );
+
+    Ok(
+        find_payment_intents(state, merchant_account, key_store, &constraints)
+            .await?
+            .into_iter()
+            .filter(|linked_payment_intent| {
+                linked_payment_intent.merchant_order_reference_id.is_none()
+                    && payment_intents.iter().all(|payment_intent| {
+                        payment_intent.payment_id != linked_payment_intent.payment_id
+                    })
+                    && payment_intents.iter().any(|payment_intent| {
+                        payment_intent.amount == linked_payment_intent.amount
+                            && payment_intent.currency == linked_payment_intent.currency
+                    })
+            })
+            .collect(),
+    )
+}
+
+/// Lists all the payments of a purchase along with their attempts and disputes. The payments are
+/// linked by the order reference passed by the merchant, and payments recreated without the
+/// order reference are linked by the fingerprints of the payment methods used for the purchase.
+#[instrument(skip_all)]
+pub async fn retrieve_purchase(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: payment_types::PurchaseRetrieveRequest,
+) -> RouterResponse<payment_types::PurchaseResponse> {
+    let (merchant_order_reference_id, requested_payment_intent) =
+        match (request.merchant_order_reference_id, request.payment_id) {
+            (Some(merchant_order_reference_id), None) => (Some(merchant_order_reference_id), None),
+            (None, Some(payment_id)) => {
+                let payment_intent = state
+                    .store
+                    .find_payment_intent_by_payment_id_merchant_id(
+                        &(&state).into(),
+                        &payment_id,
+                        merchant_account.get_id(),
+                        &key_store,
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+                (
+                    payment_intent.merchant_order_reference_id.clone(),
+                    Some(payment_intent),
+                )
+            }
+            _ => {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Either `merchant_order_reference_id` or `payment_id` must be passed"
+                        .to_string(),
+                }))
+            }
+        };
+
+    let (payment_intents, linked_by) = match &merchant_order_reference_id {
+        Some(merchant_order_reference_id) => (
+            find_payment_intents(
+                &state,
+                &merchant_account,
+                &key_store,
+                &get_purchase_constraints(Some(merchant_order_reference_id.clone()), None, None),
+            )
+            .await?,
+            payment_types::PurchaseLinkType::OrderReference,
+        ),
+        None => (
+            requested_payment_intent.into_iter().collect(),
+            payment_types::PurchaseLinkType::PaymentId,
+        ),
+    };
+    if payment_intents.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::PaymentNotFound));
+    }
+
+    let mut payments = try_join_all(
+        payment_intents
+            .iter()
+            .cloned()
+            .map(|payment_intent| get_purchase_payment(&state, payment_intent, linked_by)),
+    )
+    .await?;
+
+    let mut fingerprint_ids = payment_intents
+        .iter()
+        .filter_map(|payment_intent| payment_intent.fingerprint_id.clone())
+        .chain(payments.iter().flat_map(|payment| {
+            payment
+                .attempts
+                .iter()
+                .filter_map(|attempt| attempt.fingerprint_id.clone())
+        }))
+        .collect::<BTreeSet<_>>();
+
+    let linked_payment_intents = find_payment_intents_linked_by_fingerprint(
+        &state,
+        &merchant_account,
+        &key_store,
+        &payment_intents,
+        &fingerprint_ids,
+    )
+    .await?;
+    let linked_payments = try_join_all(linked_payment_intents.into_iter().map(|payment_intent| {
+        get_purchase_payment(
+            &state,
+            payment_intent,
+            payment_types::PurchaseLinkType::Fingerprint,
+        )
+    }))
+    .await?;
+    fingerprint_ids.extend(linked_payments.iter().flat_map(|payment| {
+        payment
+            .attempts
+            .iter()
+            .filter_map(|attempt| attempt.fingerprint_id.clone())
+    }));
+    payments.extend(linked_payments);
+    payments.sort_by(|a, b| b.created.cmp(&a.created));
+
+    let succeeded_payment = payments
+        .iter()
+        .find(|payment| payment.status == enums::IntentStatus::Succeeded);
+    let status = succeeded_payment
+        .or(payments.first())
+        .map(|payment| payment.status)
+        .ok_or(errors::ApiErrorResponse::PaymentNotFound)?;
+    let connectors = payments
+        .iter()
+        .flat_map(|payment| {
+            payment
+                .attempts
+                .iter()
+                .filter_map(|attempt| attempt.connector.clone())
+        })
+        .collect::<BTreeSet<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        payment_types::PurchaseResponse {
+            merchant_order_reference_id,
+            status,
+            succeeded_payment_id: succeeded_payment.map(|payment| payment.payment_id.clone()),
+            total_payments: payments.len(),
+            total_attempts: payments.iter().map(|payment| payment.attempts.len()).sum(),
+            connectors: connectors.into_iter().collect(),
+            fingerprint_ids: fingerprint_ids.into_iter().collect(),
+            payments,
+        },
+    ))
+}
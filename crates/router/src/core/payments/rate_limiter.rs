@@ -0,0 +1,395 @@
+use std::time::Duration;
+
+use common_utils::{ext_traits::StringExt, id_type};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    routes::SessionState, services::api::request_lane::RequestLane, types::ConnectorRateLimitConfig,
+};
+
+pub const CONNECTOR_RATE_LIMIT_PREFIX: &str = "CONNECTOR_RATE_LIMIT";
+
+/// Reserves the next slot of the bucket and returns it along with the current time of redis, both
+/// in microseconds. The bucket stores the time at which it would be full again.
+const RESERVE_SLOT_SCRIPT: &str = r#"
+redis.replicate_commands()
+local time = redis.call("TIME")
+local now = tonumber(time[1]) * 1000000 + tonumber(time[2])
+local slot = math.max(tonumber(redis.call("GET", KEYS[1]) or now), now)
+local next_slot = slot + tonumber(ARGV[1])
+local expiry_in_millis = math.ceil((next_slot - now) / 1000) + 1000
+redis.call("SET", KEYS[1], string.format("%.0f", next_slot), "PX", expiry_in_millis)
+return { string.format("%.0f", slot), string.format("%.0f", now) }
+"#;
+
+/// Pushes the next slot of the bucket back by the given number of microseconds from now, unless
+/// it is already further away
+const DEFER_SLOTS_SCRIPT: &str = r#"
+redis.replicate_commands()
+local time = redis.call("TIME")
+local now = tonumber(time[1]) * 1000000 + tonumber(time[2])
+local deferred_slot = now + tonumber(ARGV[1])
+if tonumber(redis.call("GET", KEYS[1]) or 0) < deferred_slot then
+    local expiry_in_millis = math.ceil(tonumber(ARGV[1]) / 1000) + 1000
+    redis.call("SET", KEYS[1], string.format("%.0f", deferred_slot), "PX", expiry_in_millis)
+end
+return 0
+"#;
+
+/// Token bucket refilled at the rate limit of the connector, holding up to the burst size.
+///
+/// The bucket is tracked as the time of the next free slot (the generic cell rate algorithm):
+/// every request reserves a slot one emission interval after the previous one, and is sent once
+/// its slot is within the burst tolerance. Requests are thereby admitted in the order they arrive
+/// and evenly spaced, instead of in bursts at the boundaries of fixed windows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TokenBucket {
+    emission_interval_in_micros: i64,
+    burst_tolerance_in_micros: i64,
+}
+
+impl TokenBucket {
+    /// The bucket of a request lane. Batch traffic is evenly spaced and never sent in bursts.
+    fn new(config: &ConnectorRateLimitConfig, lane: RequestLane) -> Self {
+        let requests_per_second = get_lane_requests_per_second(config, lane);
+        let emission_interval_in_micros = 1_000_000 / i64::from(requests_per_second.max(1));
+        let burst_size = match lane {
+            RequestLane::Live => config.burst_size.unwrap_or(1).max(1),
+            RequestLane::Batch => 1,
+        };
+
+        Self {
+            emission_interval_in_micros,
+            burst_tolerance_in_micros: emission_interval_in_micros
+                .saturating_mul(i64::from(burst_size - 1)),
+        }
+    }
+
+    /// Time to wait before sending the request which reserved the slot
+    fn get_wait_in_micros(&self, slot: i64, now: i64) -> i64 {
+        slot.saturating_sub(self.burst_tolerance_in_micros)
+            .saturating_sub(now)
+            .max(0)
+    }
+}
+
+/// Share of the rate limit of the connector available to the request lane. Each lane is shaped by
+/// its own bucket, so that batch traffic can't hold back live traffic.
+fn get_lane_requests_per_second(config: &ConnectorRateLimitConfig, lane: RequestLane) -> u32 {
+    let batch_requests_per_second = config
+        .batch_requests_per_second
+        .unwrap_or(config.requests_per_second / 10)
+        .max(1);
+
+    match lane {
+        RequestLane::Live => config
+            .requests_per_second
+            .saturating_sub(batch_requests_per_second)
+            .max(1),
+        RequestLane::Batch => batch_requests_per_second,
+    }
+}
+
+/// Rate limit of a connector for a merchant connector account, shared by all the instances of the
+/// application through redis. The rate limit is split between the request lanes, and requests are
+/// held back against the bucket of the lane they belong to.
+#[derive(Debug, Clone)]
+pub struct ConnectorRateLimiter {
+    bucket_key_prefix: String,
+    lane: RequestLane,
+    config: ConnectorRateLimitConfig,
+}
+
+impl ConnectorRateLimiter {
+    /// Returns the rate limiter of the merchant connector account, if a rate limit is configured
+    pub async fn find(
+        state: &SessionState,
+        connector_name: &str,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    ) -> Option<Self> {
+        let config_key = ConnectorRateLimitConfig::get_config_key(merchant_connector_id);
+        let config = state
+            .store
+            .find_config_by_key(&config_key)
+            .await
+            .ok()?
+            .config
+            .parse_struct::<ConnectorRateLimitConfig>("ConnectorRateLimitConfig")
+            .map_err(|error| {
+                logger::error!(?error, "Failed to parse the connector rate limit config")
+            })
+            .ok()?;
+
+        Some(Self {
+            bucket_key_prefix: format!(
+                "{}_{}_{}",
+                CONNECTOR_RATE_LIMIT_PREFIX,
+                connector_name,
+                merchant_connector_id.get_string_repr()
+            ),
+            lane: state.request_lane,
+            config,
+        })
+    }
+
+    fn get_bucket_key(&self, lane: RequestLane) -> String {
+        format!("{}_{}", self.bucket_key_prefix, lane)
+    }
+
+    /// Waits until the request can be sent to the connector without exceeding the rate limit.
+    ///
+    /// Requests held back longer than the maximum wait time are sent to the connector regardless
+    /// of the rate limit. Failures in tracking the rate limit never fail the request.
+    #[instrument(skip_all)]
+    pub async fn wait_for_capacity(&self, state: &SessionState) {
+        let bucket_key = self.get_bucket_key(self.lane);
+        let bucket = TokenBucket::new(&self.config, self.lane);
+
+        let reservation = match state.store.get_redis_conn() {
+            Ok(redis_conn) => {
+                redis_conn
+                    .evaluate_redis_script::<_, Vec<i64>>(
+                        RESERVE_SLOT_SCRIPT,
+                        vec![bucket_key.clone()],
+                        vec![bucket.emission_interval_in_micros.to_string()],
+                    )
+                    .await
+            }
+            Err(error) => Err(error),
+        };
+
+        let (slot, now) = match reservation.as_deref() {
+            Ok([slot, now]) => (*slot, *now),
+            Ok(_) => {
+                logger::error!("Unexpected reply when reserving a connector rate limit slot");
+                return;
+            }
+            Err(error) => {
+                logger::error!(?error, "Failed to track connector rate limit");
+                return;
+            }
+        };
+
+        let wait_in_micros = bucket.get_wait_in_micros(slot, now);
+        if wait_in_micros == 0 {
+            return;
+        }
+
+        let max_wait_in_micros =
+            i64::try_from(self.config.max_wait_in_millis.saturating_mul(1000)).unwrap_or(i64::MAX);
+        if wait_in_micros > max_wait_in_micros {
+            logger::warn!(
+                %bucket_key,
+                wait_in_micros,
+                "Connector rate limit wait time exceeded, sending the request"
+            );
+        } else {
+            logger::debug!(
+                %bucket_key,
+                wait_in_micros,
+                "Connector rate limit reached, holding back the request"
+            );
+        }
+
+        tokio::time::sleep(Duration::from_micros(
+            u64::try_from(wait_in_micros.min(max_wait_in_micros)).unwrap_or_default(),
+        ))
+        .await;
+    }
+
+    /// Holds back further requests of all the request lanes for the cooldown period after the
+    /// connector has rejected a request for exceeding its rate limit
+    #[instrument(skip_all)]
+    pub async fn record_rate_limit_exceeded(&self, state: &SessionState) {
+        let cooldown_in_micros =
+            i64::try_from(self.config.cooldown_in_millis.saturating_mul(1000)).unwrap_or(i64::MAX);
+
+        for lane in [RequestLane::Live, RequestLane::Batch] {
+            let bucket_key = self.get_bucket_key(lane);
+            // The first slot after the cooldown is admitted at the end of the cooldown
+            let deferral_in_micros = cooldown_in_micros
+                .saturating_add(TokenBucket::new(&self.config, lane).burst_tolerance_in_micros);
+
+            let result = match state.store.get_redis_conn() {
+                Ok(redis_conn) => {
+                    redis_conn
+                        .evaluate_redis_script::<_, i64>(
+                            DEFER_SLOTS_SCRIPT,
+                            vec![bucket_key.clone()],
+                            vec![deferral_in_micros.to_string()],
+                        )
+                        .await
+                }
+                Err(error) => Err(error),
+            };
+
+            match result {
+                Ok(_) => logger::warn!(
+                    %bucket_key,
+                    "Connector rate limit exceeded, holding back requests for {} ms",
+                    self.config.cooldown_in_millis
+                ),
+                Err(error) => {
+                    logger::error!(?error, "Failed to set connector rate limit cooldown")
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_config(
+        requests_per_second: u32,
+        batch_requests_per_second: Option<u32>,
+        burst_size: Option<u32>,
+    ) -> ConnectorRateLimitConfig {
+        ConnectorRateLimitConfig {
+            requests_per_second,
+            batch_requests_per_second,
+            burst_size,
+            max_wait_in_millis: 5000,
+            cooldown_in_millis: 1000,
+        }
+    }
+
+    /// Bucket of the live lane, with the given rate available to live traffic
+    fn get_bucket(requests_per_second: u32, burst_size: Option<u32>) -> TokenBucket {
+        TokenBucket::new(
+            &get_config(requests_per_second + 1, Some(1), burst_size),
+            RequestLane::Live,
+        )
+    }
+
+    /// Reserves a slot the way the reservation script does, returning the time the request is sent
+    fn send(bucket: &TokenBucket, next_slot: &mut i64, now: i64) -> i64 {
+        let slot = (*next_slot).max(now);
+        *next_slot = slot + bucket.emission_interval_in_micros;
+        now + bucket.get_wait_in_micros(slot, now)
+    }
+
+    #[test]
+    fn test_burst_is_admitted_at_once() {
+        let bucket = get_bucket(10, Some(3));
+        let mut next_slot = 0;
+
+        let sent_at = (0..4)
+            .map(|_| send(&bucket, &mut next_slot, 1_000_000))
+            .collect::<Vec<_>>();
+
+        assert_eq!(sent_at, vec![1_000_000, 1_000_000, 1_000_000, 1_100_000]);
+    }
+
+    #[test]
+    fn test_requests_within_rate_are_not_held_back() {
+        let bucket = get_bucket(10, None);
+        let mut next_slot = 0;
+
+        for request in 0..20 {
+            let now = 1_000_000 + request * 100_000;
+            assert_eq!(send(&bucket, &mut next_slot, now), now);
+        }
+    }
+
+    #[test]
+    fn test_no_burst_at_window_boundaries() {
+        let bucket = get_bucket(10, None);
+        let mut next_slot = 0;
+
+        // A fixed window counter would send ten of these right before and ten right after the
+        // boundary at two seconds
+        let sent_at = (0..20)
+            .map(|_| send(&bucket, &mut next_slot, 1_999_000))
+            .collect::<Vec<_>>();
+
+        for (previous, next) in sent_at.iter().zip(sent_at.iter().skip(1)) {
+            assert_eq!(next - previous, 100_000);
+        }
+        assert_eq!(
+            sent_at
+                .iter()
+                .filter(|sent_at| **sent_at < 2_999_000)
+                .count(),
+            10
+        );
+    }
+
+    #[test]
+    fn test_bucket_refills_after_inactivity() {
+        let bucket = get_bucket(10, Some(2));
+        let mut next_slot = 0;
+
+        for _ in 0..4 {
+            send(&bucket, &mut next_slot, 1_000_000);
+        }
+        assert_eq!(send(&bucket, &mut next_slot, 3_000_000), 3_000_000);
+        assert_eq!(send(&bucket, &mut next_slot, 3_000_000), 3_000_000);
+        assert_eq!(send(&bucket, &mut next_slot, 3_000_000), 3_100_000);
+    }
+
+    #[test]
+    fn test_zero_rate_is_treated_as_one_request_per_second() {
+        let config = get_config(0, None, Some(0));
+
+        for lane in [RequestLane::Live, RequestLane::Batch] {
+            let bucket = TokenBucket::new(&config, lane);
+            assert_eq!(bucket.emission_interval_in_micros, 1_000_000);
+            assert_eq!(bucket.burst_tolerance_in_micros, 0);
+        }
+    }
+
+    #[test]
+    fn test_rate_limit_is_split_between_lanes() {
+        let config = get_config(100, None, None);
+        assert_eq!(get_lane_requests_per_second(&config, RequestLane::Live), 90);
+        assert_eq!(
+            get_lane_requests_per_second(&config, RequestLane::Batch),
+            10
+        );
+
+        let config = get_config(100, Some(40), None);
+        assert_eq!(get_lane_requests_per_second(&config, RequestLane::Live), 60);
+        assert_eq!(
+            get_lane_requests_per_second(&config, RequestLane::Batch),
+            40
+        );
+
+        // Both lanes are always left with some capacity
+        let config = get_config(5, None, None);
+        assert_eq!(get_lane_requests_per_second(&config, RequestLane::Live), 4);
+        assert_eq!(get_lane_requests_per_second(&config, RequestLane::Batch), 1);
+
+        let config = get_config(10, Some(20), None);
+        assert_eq!(get_lane_requests_per_second(&config, RequestLane::Live), 1);
+        assert_eq!(
+            get_lane_requests_per_second(&config, RequestLane::Batch),
+            20
+        );
+    }
+
+    #[test]
+    fn test_batch_traffic_does_not_hold_back_live_traffic() {
+        let config = get_config(20, Some(10), Some(3));
+        let live_bucket = TokenBucket::new(&config, RequestLane::Live);
+        let batch_bucket = TokenBucket::new(&config, RequestLane::Batch);
+        let mut live_next_slot = 0;
+        let mut batch_next_slot = 0;
+
+        // A large batch is spread over its own bucket, without bursts
+        let batch_sent_at = (0..50)
+            .map(|_| send(&batch_bucket, &mut batch_next_slot, 1_000_000))
+            .collect::<Vec<_>>();
+        assert_eq!(batch_sent_at.get(1), Some(&1_100_000));
+        assert_eq!(batch_sent_at.last(), Some(&5_900_000));
+
+        // Live traffic arriving during the batch is sent right away
+        for request in 0..3 {
+            assert_eq!(
+                send(&live_bucket, &mut live_next_slot, 1_000_000 + request),
+                1_000_000 + request
+            );
+        }
+    }
+}
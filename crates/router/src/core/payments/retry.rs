@@ -10,6 +10,7 @@ use router_env::{
 
 use crate::{
     core::{
+        api_locking,
         errors::{self, RouterResult, StorageErrorExt},
         payments::{
             self,
@@ -95,6 +96,10 @@ where
     };
 
     if should_step_up {
+        let previous_attempt = super::orchestration_webhooks::OrchestrationDecisionContext::new(
+            payment_data.get_payment_attempt(),
+            &router_data,
+        );
         router_data = do_retry(
             &state.clone(),
             req_state.clone(),
@@ -112,6 +117,18 @@ where
             business_profile,
         )
         .await?;
+
+        super::orchestration_webhooks::trigger_payment_retry_webhook(
+            state,
+            merchant_account,
+            business_profile,
+            key_store,
+            payment_data.get_payment_intent(),
+            previous_attempt,
+            payment_data.get_payment_attempt(),
+            api_models::routing::OrchestrationDecisionReason::StepUp,
+        )
+        .await;
     }
     // Step up is not applicable so proceed with auto retries flow
     else {
@@ -141,6 +158,11 @@ where
                     }
 
                     let connector = super::get_connector_data(&mut connectors)?;
+                    let previous_attempt =
+                        super::orchestration_webhooks::OrchestrationDecisionContext::new(
+                            payment_data.get_payment_attempt(),
+                            &router_data,
+                        );
 
                     router_data = do_retry(
                         &state.clone(),
@@ -161,6 +183,18 @@ where
                     )
                     .await?;
 
+                    super::orchestration_webhooks::trigger_payment_retry_webhook(
+                        state,
+                        merchant_account,
+                        business_profile,
+                        key_store,
+                        payment_data.get_payment_intent(),
+                        previous_attempt,
+                        payment_data.get_payment_attempt(),
+                        api_models::routing::OrchestrationDecisionReason::AutoRetry,
+                    )
+                    .await;
+
                     retries = retries.map(|i| i - 1);
                 }
                 api_models::gsm::GsmDecision::Requeue => {
@@ -325,6 +359,8 @@ where
 {
     metrics::AUTO_RETRY_PAYMENT_COUNT.add(&metrics::CONTEXT, 1, &[]);
 
+    api_locking::validate_fenced_lock(state, &req_state.fenced_lock_status).await?;
+
     modify_trackers(
         state,
         connector.connector_name.to_string(),
@@ -415,6 +451,16 @@ where
                 .and_then(|connector_response| connector_response.additional_payment_method_data),
         )?;
 
+    let connector_fee = router_data
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.connector_fee.clone());
+    let connector_request_id = router_data.connector_request_id.clone();
+    let correlation_id = state
+        .request_id
+        .as_ref()
+        .map(|request_id| request_id.as_hyphenated().to_string());
+
     match router_data.response {
         Ok(types::PaymentsResponseData::TransactionResponse {
             resource_id,
@@ -467,6 +513,10 @@ where
                 payment_method_data: additional_payment_method_data,
                 charge_id,
                 connector_mandate_detail: None,
+                connector_fee_amount: connector_fee.as_ref().map(|fee| fee.amount),
+                connector_fee_currency: connector_fee.map(|fee| fee.currency),
+                connector_request_id,
+                correlation_id,
             };
 
             #[cfg(feature = "v1")]
@@ -516,6 +566,8 @@ where
                 connector_transaction_id: error_response.connector_transaction_id.clone(),
                 payment_method_data: additional_payment_method_data,
                 authentication_type: auth_update,
+                connector_request_id,
+                correlation_id,
             };
 
             #[cfg(feature = "v1")]
@@ -654,6 +706,7 @@ pub fn make_new_payment_attempt(
         charge_id: Default::default(),
         customer_acceptance: Default::default(),
         connector_mandate_detail: Default::default(),
+        routing_decision: Default::default(),
     }
 }
 
@@ -0,0 +1,151 @@
+use std::str::FromStr;
+
+use common_enums::{Country, CountryAlpha2};
+use common_utils::{date_time, ext_traits::ValueExt};
+use hyperswitch_domain_models::router_request_types::fraud_check::PaymentRiskSignals;
+use router_env::{instrument, logger, tracing};
+use strum::IntoEnumIterator;
+
+use super::OperationSessionGetters;
+use crate::{
+    configs::settings::RiskEnrichmentConfig,
+    routes::SessionState,
+    types::{self, domain},
+};
+
+/// Largest offset of a time zone from UTC, in minutes
+const MAX_TIME_ZONE_OFFSET_IN_MINUTES: i32 = 14 * 60;
+
+/// Derives the risk signals of the payment from its context.
+///
+/// Signals which cannot be derived are left unknown, a failure in deriving a signal never fails
+/// the payment.
+#[instrument(skip_all)]
+pub async fn get_payment_risk_signals<F, D>(
+    state: &SessionState,
+    payment_data: &D,
+) -> PaymentRiskSignals
+where
+    F: Clone,
+    D: OperationSessionGetters<F>,
+{
+    let payment_intent = payment_data.get_payment_intent();
+    let browser_info = payment_data
+        .get_payment_attempt()
+        .browser_info
+        .clone()
+        .map(|browser_info| {
+            browser_info.parse_value::<types::BrowserInformation>("BrowserInformation")
+        })
+        .transpose()
+        .map_err(|error| logger::warn!(?error, "Failed to parse the browser information"))
+        .ok()
+        .flatten();
+
+    let bin_country = payment_data
+        .get_payment_method_data()
+        .and_then(|payment_method_data| match payment_method_data {
+            domain::PaymentMethodData::Card(card) => card
+                .card_issuing_country
+                .as_deref()
+                .and_then(parse_issuing_country),
+            _ => None,
+        });
+    let ip_country = browser_info
+        .as_ref()
+        .and_then(|browser_info| browser_info.ip_country);
+
+    let is_night_time = browser_info
+        .as_ref()
+        .and_then(|browser_info| browser_info.time_zone)
+        .and_then(|time_zone| is_night_time(&state.conf.risk_enrichment, time_zone));
+
+    let customer_chargeback_count = match payment_intent.customer_id.as_ref() {
+        Some(customer_id) => state
+            .store
+            .count_disputes_by_merchant_id_customer_id(&payment_intent.merchant_id, customer_id)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to count the chargebacks of the customer")
+            })
+            .ok(),
+        None => None,
+    };
+
+    PaymentRiskSignals {
+        bin_country,
+        ip_country,
+        is_bin_ip_country_mismatch: bin_country
+            .zip(ip_country)
+            .map(|(bin_country, ip_country)| bin_country != ip_country),
+        is_night_time,
+        customer_chargeback_count,
+    }
+}
+
+/// Returns the risk signals as routing parameters, keyed by the names available to routing rules
+pub fn get_routing_parameters(risk_signals: &PaymentRiskSignals) -> Vec<(&'static str, String)> {
+    [
+        (
+            "risk_bin_country",
+            risk_signals.bin_country.map(|country| country.to_string()),
+        ),
+        (
+            "risk_ip_country",
+            risk_signals.ip_country.map(|country| country.to_string()),
+        ),
+        (
+            "risk_bin_ip_country_mismatch",
+            risk_signals
+                .is_bin_ip_country_mismatch
+                .map(|mismatch| mismatch.to_string()),
+        ),
+        (
+            "risk_night_time",
+            risk_signals
+                .is_night_time
+                .map(|is_night_time| is_night_time.to_string()),
+        ),
+        (
+            "risk_customer_chargeback_count",
+            risk_signals
+                .customer_chargeback_count
+                .map(|count| count.to_string()),
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(key, value)| value.map(|value| (key, value)))
+    .collect()
+}
+
+/// Card issuing countries are stored either as alpha-2 codes or as country names, depending on
+/// the source of the card information
+fn parse_issuing_country(country: &str) -> Option<CountryAlpha2> {
+    CountryAlpha2::from_str(country.trim()).ok().or_else(|| {
+        let country_name = country
+            .chars()
+            .filter(char::is_ascii_alphabetic)
+            .collect::<String>();
+        Country::iter()
+            .find(|country| country.to_string().eq_ignore_ascii_case(&country_name))
+            .map(|country| country.to_alpha2())
+    })
+}
+
+/// Whether it is night for the customer, the time zone being the offset of UTC from the local
+/// time of the customer in minutes, as reported by the browser
+fn is_night_time(config: &RiskEnrichmentConfig, time_zone: i32) -> Option<bool> {
+    if time_zone.abs() > MAX_TIME_ZONE_OFFSET_IN_MINUTES {
+        return None;
+    }
+
+    let local_hour = (date_time::now() - time::Duration::minutes(i64::from(time_zone))).hour();
+    let (night_start_hour, night_end_hour) = (config.night_start_hour, config.night_end_hour);
+
+    // The night may span midnight, in which case the start hour is later than the end hour
+    Some(if night_start_hour <= night_end_hour {
+        (night_start_hour..night_end_hour).contains(&local_hour)
+    } else {
+        local_hour >= night_start_hour || local_hour < night_end_hour
+    })
+}
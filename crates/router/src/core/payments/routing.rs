@@ -277,6 +277,19 @@ pub fn make_dsl_input(
         .attach_printable("Unable to parse routing_parameters from metadata of payment_intent")
         .unwrap_or(None);
 
+    // Risk signals of the payment are available to routing rules as routing parameters, the
+    // routing parameters provided by the merchant take precedence over them
+    let metadata = match payments_dsl_input.risk_signals {
+        Some(risk_signals) => {
+            let mut metadata: FxHashMap<String, String> = metadata.unwrap_or_default();
+            for (key, value) in super::risk_enrichment::get_routing_parameters(risk_signals) {
+                metadata.entry(key.to_string()).or_insert(value);
+            }
+            Some(metadata)
+        }
+        None => metadata,
+    };
+
     Ok(dsl_inputs::BackendInput {
         metadata,
         payment: payment_input,
@@ -291,6 +304,7 @@ pub async fn perform_static_routing_v1(
     algorithm_id: Option<&common_utils::id_type::RoutingId>,
     business_profile: &domain::Profile,
     transaction_data: &routing::TransactionData<'_>,
+    mut routing_decision: Option<&mut routing_types::RoutingDecisionTrace>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     let algorithm_id = if let Some(id) = algorithm_id {
         id
@@ -308,6 +322,10 @@ pub async fn perform_static_routing_v1(
             .get_default_fallback_list_of_connector_under_profile()
             .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
 
+        if let Some(routing_decision) = routing_decision {
+            routing_decision.routed_connectors = fallback_config.clone();
+        }
+
         return Ok(fallback_config);
     };
     let cached_algorithm = ensure_algorithm_cached_v1(
@@ -319,7 +337,17 @@ pub async fn perform_static_routing_v1(
     )
     .await?;
 
-    Ok(match cached_algorithm.as_ref() {
+    if let Some(routing_decision) = routing_decision.as_deref_mut() {
+        routing_decision.algorithm_id = Some(algorithm_id.clone());
+        routing_decision.algorithm_kind = Some(match cached_algorithm.as_ref() {
+            CachedAlgorithm::Single(_) => routing_types::RoutingAlgorithmKind::Single,
+            CachedAlgorithm::Priority(_) => routing_types::RoutingAlgorithmKind::Priority,
+            CachedAlgorithm::VolumeSplit(_) => routing_types::RoutingAlgorithmKind::VolumeSplit,
+            CachedAlgorithm::Advanced(_) => routing_types::RoutingAlgorithmKind::Advanced,
+        });
+    }
+
+    let connectors = match cached_algorithm.as_ref() {
         CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
 
         CachedAlgorithm::Priority(plist) => plist.clone(),
@@ -336,9 +364,19 @@ pub async fn perform_static_routing_v1(
                 }
             };
 
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?
+            execute_dsl_and_get_connector_v1(
+                backend_input,
+                interpreter,
+                routing_decision.as_deref_mut(),
+            )?
         }
-    })
+    };
+
+    if let Some(routing_decision) = routing_decision {
+        routing_decision.routed_connectors = connectors.clone();
+    }
+
+    Ok(connectors)
 }
 
 async fn ensure_algorithm_cached_v1(
@@ -423,12 +461,19 @@ pub fn perform_routing_for_single_straight_through_algorithm(
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+    routing_decision: Option<&mut routing_types::RoutingDecisionTrace>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::RoutingAlgorithm = interpreter
+    let backend_output = interpreter
         .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
         .change_context(errors::RoutingError::DslExecutionError)?;
 
+    if let Some(routing_decision) = routing_decision {
+        routing_decision.matched_rule = backend_output.rule_name;
+    }
+
+    let routing_output: routing_types::RoutingAlgorithm =
+        backend_output.connector_selection.foreign_into();
+
     Ok(match routing_output {
         routing_types::RoutingAlgorithm::Priority(plist) => plist,
 
@@ -792,6 +837,7 @@ pub async fn perform_eligibility_analysis_with_fallback(
     transaction_data: &routing::TransactionData<'_>,
     eligible_connectors: Option<Vec<api_enums::RoutableConnectors>>,
     business_profile: &domain::Profile,
+    routing_decision: Option<&mut routing_types::RoutingDecisionTrace>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     let mut final_selection = perform_eligibility_analysis(
         state,
@@ -810,11 +856,16 @@ pub async fn perform_eligibility_analysis_with_fallback(
         eligible_connectors.as_ref(),
         business_profile,
     )
-    .await;
+    .await
+    .unwrap_or_default();
+
+    if let Some(routing_decision) = routing_decision {
+        routing_decision.eligible_connectors = final_selection.clone();
+        routing_decision.fallback_connectors = fallback_selection.clone();
+    }
 
     final_selection.append(
         &mut fallback_selection
-            .unwrap_or_default()
             .iter()
             .filter(|&routable_connector_choice| {
                 !final_selection.contains(routable_connector_choice)
@@ -1043,6 +1094,7 @@ async fn perform_session_routing_for_pm_type(
             CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
                 session_pm_input.backend_input.clone(),
                 interpreter,
+                None,
             )?,
         }
     } else {
@@ -1090,7 +1142,15 @@ async fn perform_session_routing_for_pm_type(
     if final_selection.is_empty() {
         Ok(None)
     } else {
-        Ok(Some(final_selection))
+        // The connectors are only allowed by name, the unhealthy accounts among them are excluded
+        // from the routed list so that the session is not routed to them
+        Ok(Some(
+            super::connector_health::exclude_unhealthy_connectors(
+                session_pm_input.state,
+                final_selection,
+            )
+            .await,
+        ))
     }
 }
 
@@ -1125,6 +1185,7 @@ async fn perform_session_routing_for_pm_type(
             CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
                 session_pm_input.backend_input.clone(),
                 interpreter,
+                None,
             )?,
         }
     } else {
@@ -1164,7 +1225,15 @@ async fn perform_session_routing_for_pm_type(
     if final_selection.is_empty() {
         Ok(None)
     } else {
-        Ok(Some(final_selection))
+        // The connectors are only allowed by name, the unhealthy accounts among them are excluded
+        // from the routed list so that the session is not routed to them
+        Ok(Some(
+            super::connector_health::exclude_unhealthy_connectors(
+                session_pm_input.state,
+                final_selection,
+            )
+            .await,
+        ))
     }
 }
 
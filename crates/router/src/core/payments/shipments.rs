@@ -0,0 +1,631 @@
+use std::collections::HashMap;
+
+use api_models::{payments as payment_types, webhooks};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, ValueExt},
+    fp_utils, id_type,
+    types::MinorUnit,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::{CallConnectorAction, PaymentCapture, PaymentData};
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+const SHIPMENT_REFERENCE_MAX_LENGTH: usize = 64;
+
+/// A shipment of the order of a payment, along with the capture made for its amount. The status
+/// of a shipment is that of its capture.
+#[derive(Debug, Clone)]
+pub struct PaymentShipment {
+    pub shipment_reference: String,
+    pub capture_id: String,
+    pub amount: MinorUnit,
+    pub line_items: Vec<payment_types::ShipmentLineItem>,
+    pub created_at: time::PrimitiveDateTime,
+}
+
+impl TryFrom<storage::PaymentShipment> for PaymentShipment {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(shipment: storage::PaymentShipment) -> Result<Self, Self::Error> {
+        Ok(Self {
+            line_items: shipment
+                .line_items
+                .parse_value("Vec<ShipmentLineItem>")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the line items of the shipment")?,
+            shipment_reference: shipment.shipment_reference,
+            capture_id: shipment.capture_id,
+            amount: shipment.amount,
+            created_at: shipment.created_at,
+        })
+    }
+}
+
+async fn get_payment_shipments(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<Vec<PaymentShipment>> {
+    db.find_payment_shipments_by_merchant_id_payment_id(merchant_id, payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the shipments of the payment")?
+        .into_iter()
+        .map(PaymentShipment::try_from)
+        .collect()
+}
+
+async fn insert_payment_shipment(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    shipment: &PaymentShipment,
+) -> RouterResult<()> {
+    let line_items = shipment
+        .line_items
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the line items of the shipment")?;
+
+    db.insert_payment_shipment(storage::PaymentShipmentNew {
+        payment_id: payment_id.clone(),
+        shipment_reference: shipment.shipment_reference.clone(),
+        merchant_id: merchant_id.clone(),
+        capture_id: shipment.capture_id.clone(),
+        amount: shipment.amount,
+        line_items,
+        created_at: shipment.created_at,
+    })
+    .await
+    .to_duplicate_response(errors::ApiErrorResponse::PreconditionFailed {
+        message: format!(
+            "The shipment '{}' is already captured",
+            shipment.shipment_reference
+        ),
+    })?;
+
+    Ok(())
+}
+
+async fn find_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<(storage::PaymentIntent, storage::PaymentAttempt)> {
+    let db = state.store.as_ref();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            merchant_account.get_id(),
+            payment_intent.active_attempt.get_id().as_str(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok((payment_intent, payment_attempt))
+}
+
+/// The captures made on the attempt of the payment, by their identifiers
+async fn get_attempt_captures(
+    db: &dyn StorageInterface,
+    payment_attempt: &storage::PaymentAttempt,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<HashMap<String, storage::Capture>> {
+    let captures = db
+        .find_all_captures_by_merchant_id_payment_id_authorized_attempt_id(
+            &payment_attempt.merchant_id,
+            &payment_attempt.payment_id,
+            &payment_attempt.attempt_id,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the captures of the payment")?;
+
+    Ok(captures
+        .into_iter()
+        .map(|capture| (capture.capture_id.clone(), capture))
+        .collect())
+}
+
+/// The quantities of the products in the order of the payment, by their product identifiers
+fn get_ordered_quantities(
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<HashMap<String, u32>> {
+    let mut ordered_quantities = HashMap::new();
+    for order_detail in payment_intent.order_details.iter().flatten() {
+        let order_detail: payment_types::OrderDetailsWithAmount = order_detail
+            .to_owned()
+            .parse_value("OrderDetailsWithAmount")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the order details of the payment")?;
+        if let Some(product_id) = order_detail.product_id {
+            *ordered_quantities.entry(product_id).or_insert(0) += u32::from(order_detail.quantity);
+        }
+    }
+    Ok(ordered_quantities)
+}
+
+/// Validates the lines of the shipment against the order of the payment, so that no more than
+/// the ordered quantity of a product is shipped across the shipments of the payment. The lines of
+/// shipments whose capture failed are not considered to be shipped.
+fn validate_shipment_line_items(
+    line_items: &[payment_types::ShipmentLineItem],
+    ordered_quantities: &HashMap<String, u32>,
+    shipments: &[PaymentShipment],
+    captures: &HashMap<String, storage::Capture>,
+) -> RouterResult<()> {
+    fp_utils::when(line_items.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "A shipment must contain at least one line item".to_string(),
+        }))
+    })?;
+
+    let mut shipped_quantities = HashMap::<&str, u32>::new();
+    for shipment in shipments {
+        let is_failed = captures
+            .get(&shipment.capture_id)
+            .is_some_and(|capture| capture.status == enums::CaptureStatus::Failed);
+        if is_failed {
+            continue;
+        }
+        for line_item in &shipment.line_items {
+            *shipped_quantities
+                .entry(line_item.product_id.as_str())
+                .or_insert(0) += u32::from(line_item.quantity);
+        }
+    }
+
+    for line_item in line_items {
+        fp_utils::when(line_item.quantity == 0, || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The quantity of the product '{}' must be greater than zero",
+                    line_item.product_id
+                ),
+            }))
+        })?;
+
+        let ordered_quantity = ordered_quantities
+            .get(&line_item.product_id)
+            .copied()
+            .ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "The product '{}' is not part of the order of the payment",
+                        line_item.product_id
+                    ),
+                })
+            })?;
+        let shipped_quantity = shipped_quantities
+            .entry(line_item.product_id.as_str())
+            .or_insert(0);
+        let remaining_quantity = ordered_quantity.saturating_sub(*shipped_quantity);
+
+        fp_utils::when(u32::from(line_item.quantity) > remaining_quantity, || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Only {remaining_quantity} of the product '{}' remain to be shipped",
+                    line_item.product_id
+                ),
+            }))
+        })?;
+        *shipped_quantity += u32::from(line_item.quantity);
+    }
+
+    Ok(())
+}
+
+fn to_shipment_response(
+    payment_id: &id_type::PaymentId,
+    shipment: PaymentShipment,
+    capture: Option<&storage::Capture>,
+) -> payment_types::PaymentShipmentResponse {
+    payment_types::PaymentShipmentResponse {
+        payment_id: payment_id.clone(),
+        shipment_reference: shipment.shipment_reference,
+        capture_id: shipment.capture_id,
+        status: capture
+            .map(|capture| capture.status)
+            .unwrap_or(enums::CaptureStatus::Started),
+        amount: shipment.amount,
+        currency: capture.and_then(|capture| capture.currency),
+        line_items: shipment.line_items,
+        error_message: capture.and_then(|capture| capture.error_message.clone()),
+        created_at: shipment.created_at,
+    }
+}
+
+/// Captures the amount of a shipment of the order of a payment authorized with the
+/// `manual_multiple` capture method. The shipment is recorded along with the capture made for it,
+/// and the merchant is notified of the outcome of the capture.
+#[instrument(skip_all)]
+pub async fn capture_shipment(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: payment_types::PaymentsShipmentCaptureRequest,
+) -> RouterResponse<payment_types::PaymentShipmentResponse> {
+    let db = state.store.as_ref();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    fp_utils::when(
+        req.shipment_reference.is_empty()
+            || req.shipment_reference.len() > SHIPMENT_REFERENCE_MAX_LENGTH,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "shipment_reference must be between 1 and {SHIPMENT_REFERENCE_MAX_LENGTH} characters long"
+                ),
+            }))
+        },
+    )?;
+
+    let (payment_intent, payment_attempt) =
+        find_payment(&state, &merchant_account, &key_store, &req.payment_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id.clone(), &payment_intent)?;
+
+    fp_utils::when(
+        payment_attempt.capture_method != Some(enums::CaptureMethod::ManualMultiple),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Shipments can only be captured on payments authorized with the manual_multiple capture method".to_string(),
+            }))
+        },
+    )?;
+    fp_utils::when(
+        req.amount_to_capture <= MinorUnit::new(0)
+            || req.amount_to_capture > payment_attempt.amount_capturable,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "amount_to_capture must be greater than zero and not exceed the remaining authorization of {}",
+                    payment_attempt.amount_capturable
+                ),
+            }))
+        },
+    )?;
+
+    let shipments =
+        get_payment_shipments(db, merchant_account.get_id(), &payment_intent.payment_id).await?;
+    fp_utils::when(
+        shipments
+            .iter()
+            .any(|shipment| shipment.shipment_reference == req.shipment_reference),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "The shipment '{}' is already captured",
+                    req.shipment_reference
+                ),
+            }))
+        },
+    )?;
+
+    let captures = get_attempt_captures(db, &payment_attempt, storage_scheme).await?;
+    validate_shipment_line_items(
+        &req.line_items,
+        &get_ordered_quantities(&payment_intent)?,
+        &shipments,
+        &captures,
+    )?;
+
+    let (payment_data, _req, _customer, _connector_http_status_code, _external_latency) =
+        Box::pin(super::payments_operation_core::<
+            api::Capture,
+            _,
+            _,
+            _,
+            PaymentData<api::Capture>,
+        >(
+            &state,
+            req_state,
+            merchant_account.clone(),
+            profile_id,
+            key_store.clone(),
+            PaymentCapture,
+            payment_types::PaymentsCaptureRequest {
+                payment_id: payment_intent.payment_id.clone(),
+                amount_to_capture: Some(req.amount_to_capture),
+                ..Default::default()
+            },
+            CallConnectorAction::Trigger,
+            services::AuthFlow::Merchant,
+            None,
+            hyperswitch_domain_models::payments::HeaderPayload::default(),
+        ))
+        .await?;
+
+    // The capture made for the shipment is the capture created by the capture request, as updated
+    // with the response of the connector
+    let capture = payment_data
+        .multiple_capture_data
+        .as_ref()
+        .and_then(|multiple_capture_data| {
+            multiple_capture_data.get_capture_by_capture_id(
+                multiple_capture_data
+                    .get_latest_capture()
+                    .capture_id
+                    .clone(),
+            )
+        })
+        .cloned()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Capture of the shipment not found")?;
+
+    let shipment = PaymentShipment {
+        shipment_reference: req.shipment_reference,
+        capture_id: capture.capture_id.clone(),
+        amount: req.amount_to_capture,
+        line_items: req.line_items,
+        created_at: date_time::now(),
+    };
+    insert_payment_shipment(
+        db,
+        merchant_account.get_id(),
+        &payment_intent.payment_id,
+        &shipment,
+    )
+    .await?;
+
+    let response = to_shipment_response(&payment_intent.payment_id, shipment, Some(&capture));
+    trigger_shipment_webhook(
+        &state,
+        &merchant_account,
+        &key_store,
+        &payment_intent,
+        &response,
+    )
+    .await;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Lists the shipments captured on a payment, along with the current status of their captures
+#[instrument(skip_all)]
+pub async fn list_shipments(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<payment_types::PaymentShipmentListResponse> {
+    let db = state.store.as_ref();
+    let (payment_intent, payment_attempt) =
+        find_payment(&state, &merchant_account, &key_store, &payment_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    let shipments =
+        get_payment_shipments(db, merchant_account.get_id(), &payment_intent.payment_id).await?;
+    let captures =
+        get_attempt_captures(db, &payment_attempt, merchant_account.storage_scheme).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payment_types::PaymentShipmentListResponse {
+            shipments: shipments
+                .into_iter()
+                .map(|shipment| {
+                    let capture = captures.get(&shipment.capture_id);
+                    to_shipment_response(&payment_intent.payment_id, shipment, capture)
+                })
+                .collect(),
+            payment_id: payment_intent.payment_id,
+            amount_capturable: payment_attempt.amount_capturable,
+        },
+    ))
+}
+
+/// Notifies the merchant of the outcome of the capture of a shipment. Captures which are still
+/// pending with the connector are not notified, their outcome is reflected in the payment.
+async fn trigger_shipment_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    shipment_response: &payment_types::PaymentShipmentResponse,
+) {
+    let event_type = match shipment_response.status {
+        enums::CaptureStatus::Charged => enums::EventType::ShipmentCaptured,
+        enums::CaptureStatus::Failed => enums::EventType::ShipmentCaptureFailed,
+        enums::CaptureStatus::Started | enums::CaptureStatus::Pending => return,
+    };
+    let Some(profile_id) = payment_intent.profile_id.as_ref() else {
+        return;
+    };
+
+    let result = async {
+        let business_profile = state
+            .store
+            .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?;
+
+        Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            business_profile,
+            key_store,
+            event_type,
+            enums::EventClass::Shipments,
+            shipment_response.capture_id.clone(),
+            enums::EventObjectType::ShipmentDetails,
+            webhooks::OutgoingWebhookContent::ShipmentDetails(Box::new(shipment_response.clone())),
+            Some(shipment_response.created_at),
+        ))
+        .await
+    }
+    .await;
+
+    if let Err(error) = result {
+        logger::error!(?error, %event_type, "Failed to trigger the shipment webhook");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_item(product_id: &str, quantity: u16) -> payment_types::ShipmentLineItem {
+        payment_types::ShipmentLineItem {
+            product_id: product_id.to_string(),
+            quantity,
+        }
+    }
+
+    fn shipment(
+        capture_id: &str,
+        line_items: Vec<payment_types::ShipmentLineItem>,
+    ) -> PaymentShipment {
+        PaymentShipment {
+            shipment_reference: format!("shipment_{capture_id}"),
+            capture_id: capture_id.to_string(),
+            amount: MinorUnit::new(1000),
+            line_items,
+            created_at: date_time::now(),
+        }
+    }
+
+    fn capture(capture_id: &str, status: enums::CaptureStatus) -> storage::Capture {
+        let now = date_time::now();
+        storage::Capture {
+            capture_id: capture_id.to_string(),
+            payment_id: id_type::PaymentId::default(),
+            merchant_id: id_type::MerchantId::default(),
+            status,
+            amount: MinorUnit::new(1000),
+            currency: None,
+            connector: "checkout".to_string(),
+            error_message: None,
+            error_code: None,
+            error_reason: None,
+            tax_amount: None,
+            created_at: now,
+            modified_at: now,
+            authorized_attempt_id: "pay_attempt".to_string(),
+            connector_capture_id: None,
+            capture_sequence: 1,
+            connector_response_reference_id: None,
+            connector_capture_data: None,
+            settlement_currency: None,
+            settlement_amount: None,
+        }
+    }
+
+    fn ordered_quantities() -> HashMap<String, u32> {
+        HashMap::from([("shirt".to_string(), 2), ("shoes".to_string(), 1)])
+    }
+
+    #[test]
+    fn test_shipment_within_ordered_quantities() {
+        let shipments = vec![shipment("capture_1", vec![line_item("shirt", 1)])];
+        let captures = HashMap::from([(
+            "capture_1".to_string(),
+            capture("capture_1", enums::CaptureStatus::Charged),
+        )]);
+
+        assert!(validate_shipment_line_items(
+            &[line_item("shirt", 1), line_item("shoes", 1)],
+            &ordered_quantities(),
+            &shipments,
+            &captures,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_shipment_exceeding_remaining_quantity() {
+        let shipments = vec![shipment("capture_1", vec![line_item("shirt", 2)])];
+        let captures = HashMap::from([(
+            "capture_1".to_string(),
+            capture("capture_1", enums::CaptureStatus::Pending),
+        )]);
+
+        assert!(validate_shipment_line_items(
+            &[line_item("shirt", 1)],
+            &ordered_quantities(),
+            &shipments,
+            &captures,
+        )
+        .is_err());
+        // The lines of the shipment are validated together
+        assert!(validate_shipment_line_items(
+            &[line_item("shoes", 1), line_item("shoes", 1)],
+            &ordered_quantities(),
+            &[],
+            &HashMap::new(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_lines_of_failed_captures_are_not_shipped() {
+        let shipments = vec![shipment("capture_1", vec![line_item("shirt", 2)])];
+        let captures = HashMap::from([(
+            "capture_1".to_string(),
+            capture("capture_1", enums::CaptureStatus::Failed),
+        )]);
+
+        assert!(validate_shipment_line_items(
+            &[line_item("shirt", 2)],
+            &ordered_quantities(),
+            &shipments,
+            &captures,
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_invalid_shipment_lines() {
+        let ordered_quantities = ordered_quantities();
+
+        assert!(
+            validate_shipment_line_items(&[], &ordered_quantities, &[], &HashMap::new()).is_err()
+        );
+        assert!(validate_shipment_line_items(
+            &[line_item("shirt", 0)],
+            &ordered_quantities,
+            &[],
+            &HashMap::new(),
+        )
+        .is_err());
+        assert!(validate_shipment_line_items(
+            &[line_item("hat", 1)],
+            &ordered_quantities,
+            &[],
+            &HashMap::new(),
+        )
+        .is_err());
+    }
+}
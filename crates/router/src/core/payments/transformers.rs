@@ -30,6 +30,7 @@ use crate::{
     connector::{Helcim, Nexinets},
     core::{
         errors::{self, RouterResponse, RouterResult},
+        localization,
         payments::{self, helpers},
         utils as core_utils,
     },
@@ -158,6 +159,7 @@ where
         payment_method_balance: None,
         connector_api_version: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -165,6 +167,7 @@ where
         dispute_id: None,
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id,
@@ -288,6 +291,8 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         integrity_object: None,
         shipping_cost: payment_data.payment_intent.amount_details.shipping_cost,
         additional_payment_method_data: None,
+        connector_passthrough_data: None,
+        merchant_category_code: None,
     };
     let connector_mandate_request_reference_id = payment_data
         .payment_attempt
@@ -360,6 +365,7 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         payment_method_balance: None,
         connector_api_version: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -367,6 +373,7 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         dispute_id: None,
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload,
         connector_mandate_request_reference_id,
@@ -492,6 +499,7 @@ pub async fn construct_router_data_for_psync<'a>(
         payment_method_balance: None,
         connector_api_version: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -499,6 +507,7 @@ pub async fn construct_router_data_for_psync<'a>(
         dispute_id: None,
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload,
         connector_mandate_request_reference_id: None,
@@ -654,6 +663,17 @@ where
         .as_ref()
         .and_then(|detail| detail.get_connector_mandate_request_reference_id());
 
+    let connector_options = match merchant_connector_account.get_mca_id() {
+        Some(merchant_connector_id) => {
+            crate::core::connector_options::find_connector_options(
+                state.store.as_ref(),
+                &merchant_connector_id,
+            )
+            .await?
+        }
+        None => None,
+    };
+
     crate::logger::debug!("unified address details {:?}", unified_address);
 
     router_data = types::RouterData {
@@ -709,6 +729,7 @@ where
         payment_method_balance: None,
         connector_api_version,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow,
         frm_metadata: None,
@@ -716,6 +737,7 @@ where
         dispute_id: None,
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options,
         additional_merchant_data: merchant_recipient_data.map(|data| {
             api_models::admin::AdditionalMerchantData::foreign_from(
                 types::AdditionalMerchantData::OpenBankingRecipientData(data),
@@ -761,6 +783,16 @@ where
         is_latency_header_enabled: Option<bool>,
         merchant_account: &domain::MerchantAccount,
     ) -> RouterResponse<Self>;
+
+    /// Localizes the customer facing content of the response
+    fn localize(response: Self, _localizer: &localization::Localizer) -> Self {
+        response
+    }
+
+    /// Attaches the signed receipt download urls to the response
+    fn attach_receipt_urls(response: Self, _state: &SessionState) -> Self {
+        response
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -811,6 +843,35 @@ where
             is_latency_header_enabled,
         )
     }
+
+    fn localize(mut response: Self, localizer: &localization::Localizer) -> Self {
+        if let Some(api_models::payments::NextActionData::DisplayBankTransferInformation {
+            bank_transfer_steps_and_charges_details,
+        }) = response.next_action.as_mut()
+        {
+            bank_transfer_steps_and_charges_details.instructions =
+                Some(localizer.get_bank_transfer_instructions(
+                    &bank_transfer_steps_and_charges_details.bank_transfer_instructions,
+                ));
+        }
+        if response.error_code.is_some() || response.error_message.is_some() {
+            response.display_message = Some(localizer.get_error_display_message(
+                response.error_code.as_deref(),
+                response.error_message.as_deref(),
+            ));
+        }
+        response
+    }
+
+    fn attach_receipt_urls(mut response: Self, state: &SessionState) -> Self {
+        response.receipt = crate::core::receipts::get_receipt_urls_or_log(
+            state,
+            &response.merchant_id,
+            &response.payment_id,
+            response.status,
+        );
+        response
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -1552,6 +1613,7 @@ where
             payment_method_data: payment_data.get_payment_method_data().cloned(),
             amount,
             currency: currency.to_string(),
+            branding: None,
         }))
     } else {
         let mut next_action_response = None;
@@ -1837,6 +1899,7 @@ where
                 .or(payment_attempt.error_message),
             unified_code: payment_attempt.unified_code,
             unified_message: payment_attempt.unified_message,
+            display_message: None,
             payment_experience: payment_attempt.payment_experience,
             payment_method_type: payment_attempt.payment_method_type,
             connector_label,
@@ -1885,6 +1948,11 @@ where
             order_tax_amount,
             connector_mandate_id,
             shipping_cost: payment_intent.shipping_cost,
+            receipt: None,
+            connector_fee_amount: payment_attempt.connector_fee_amount,
+            connector_fee_currency: payment_attempt.connector_fee_currency,
+            connector_request_id: payment_attempt.connector_request_id.clone(),
+            correlation_id: payment_attempt.correlation_id.clone(),
         };
 
         services::ApplicationResponse::JsonWithHeaders((payments_response, headers))
@@ -2114,6 +2182,7 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             error_message: None,
             unified_code: None,
             unified_message: None,
+            display_message: None,
             payment_experience: None,
             connector_label: None,
             allowed_payment_method_types: None,
@@ -2141,7 +2210,12 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             frm_metadata: None,
             order_tax_amount: None,
             connector_mandate_id:None,
+            receipt: None,
             shipping_cost: None,
+            connector_fee_amount: pa.connector_fee_amount,
+            connector_fee_currency: pa.connector_fee_currency,
+            connector_request_id: pa.connector_request_id,
+            correlation_id: pa.correlation_id,
         }
     }
 }
@@ -2326,7 +2400,7 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                 field_name: "browser_info",
             })?;
 
-        let order_category = additional_data
+        let connector_metadata = additional_data
             .payment_data
             .payment_intent
             .connector_metadata
@@ -2335,8 +2409,20 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("Failed parsing ConnectorMetadata")
             })
-            .transpose()?
-            .and_then(|cm| cm.noon.and_then(|noon| noon.order_category));
+            .transpose()?;
+
+        let order_category = connector_metadata
+            .as_ref()
+            .and_then(|cm| cm.noon.as_ref())
+            .and_then(|noon| noon.order_category.clone());
+
+        let connector_passthrough_data = connector_metadata.as_ref().and_then(|cm| {
+            connector_name
+                .parse::<api_models::enums::Connector>()
+                .ok()
+                .and_then(|connector| cm.get_passthrough_data(connector))
+                .cloned()
+        });
 
         let order_details = additional_data
             .payment_data
@@ -2357,23 +2443,29 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             })
             .transpose()?;
 
-        let complete_authorize_url = Some(helpers::create_complete_authorize_url(
-            router_base_url,
-            attempt,
-            connector_name,
-        ));
+        let complete_authorize_url = Some(helpers::sign_callback_url(
+            additional_data.state,
+            helpers::create_complete_authorize_url(router_base_url, attempt, connector_name),
+            &attempt.merchant_id,
+            &attempt.payment_id,
+        )?);
 
         let webhook_url = Some(helpers::create_webhook_url(
             router_base_url,
             &attempt.merchant_id,
             connector_name,
         ));
-        let router_return_url = Some(helpers::create_redirect_url(
-            router_base_url,
-            attempt,
-            connector_name,
-            payment_data.creds_identifier.as_deref(),
-        ));
+        let router_return_url = Some(helpers::sign_callback_url(
+            additional_data.state,
+            helpers::create_redirect_url(
+                router_base_url,
+                attempt,
+                connector_name,
+                payment_data.creds_identifier.as_deref(),
+            ),
+            &attempt.merchant_id,
+            &attempt.payment_id,
+        )?);
 
         let additional_payment_method_data = if payment_data.mandate_id.is_some() {
             let parsed_additional_payment_data: Option<api_models::payments::AdditionalPaymentData> =
@@ -2474,6 +2566,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             integrity_object: None,
             additional_payment_method_data,
             shipping_cost,
+            connector_passthrough_data,
+            merchant_category_code: None,
         })
     }
 }
@@ -2753,6 +2847,11 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
                 .connector
                 .connector_transaction_id(payment_data.payment_attempt.clone())?
                 .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)?,
+            cancellation_reason_code: payment_data
+                .payment_attempt
+                .cancellation_reason
+                .as_deref()
+                .and_then(|reason| reason.parse::<enums::CancellationReason>().ok()),
             cancellation_reason: payment_data.payment_attempt.cancellation_reason,
             connector_meta: payment_data.payment_attempt.connector_metadata,
             browser_info,
@@ -2855,12 +2954,17 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsPostSess
         let router_base_url = &additional_data.router_base_url;
         let connector_name = &additional_data.connector_name;
         let attempt = &payment_data.payment_attempt;
-        let router_return_url = Some(helpers::create_redirect_url(
-            router_base_url,
-            attempt,
-            connector_name,
-            payment_data.creds_identifier.as_deref(),
-        ));
+        let router_return_url = Some(helpers::sign_callback_url(
+            additional_data.state,
+            helpers::create_redirect_url(
+                router_base_url,
+                attempt,
+                connector_name,
+                payment_data.creds_identifier.as_deref(),
+            ),
+            &attempt.merchant_id,
+            &attempt.payment_id,
+        )?);
         Ok(Self {
             amount, //need to change after we move to connector module
             order_amount: payment_data.payment_intent.amount,
@@ -3011,12 +3115,17 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::SetupMandateRequ
         let router_base_url = &additional_data.router_base_url;
         let connector_name = &additional_data.connector_name;
         let attempt = &payment_data.payment_attempt;
-        let router_return_url = Some(helpers::create_redirect_url(
-            router_base_url,
-            attempt,
-            connector_name,
-            payment_data.creds_identifier.as_deref(),
-        ));
+        let router_return_url = Some(helpers::sign_callback_url(
+            additional_data.state,
+            helpers::create_redirect_url(
+                router_base_url,
+                attempt,
+                connector_name,
+                payment_data.creds_identifier.as_deref(),
+            ),
+            &attempt.merchant_id,
+            &attempt.payment_id,
+        )?);
         let browser_info: Option<types::BrowserInformation> = attempt
             .browser_info
             .clone()
@@ -3089,6 +3198,7 @@ impl ForeignTryFrom<types::CaptureSyncResponse> for storage::CaptureUpdate {
                 resource_id,
                 status,
                 connector_response_reference_id,
+                settlement_details,
                 ..
             } => {
                 let (connector_capture_id, connector_capture_data) = match resource_id {
@@ -3106,6 +3216,10 @@ impl ForeignTryFrom<types::CaptureSyncResponse> for storage::CaptureUpdate {
                     connector_capture_id,
                     connector_response_reference_id,
                     connector_capture_data,
+                    settlement_currency: settlement_details
+                        .as_ref()
+                        .map(|details| details.settlement_currency),
+                    settlement_amount: settlement_details.map(|details| details.settlement_amount),
                 })
             }
             types::CaptureSyncResponse::Error {
@@ -3153,11 +3267,12 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::CompleteAuthoriz
             }
         });
         let amount = payment_data.payment_attempt.get_total_amount();
-        let complete_authorize_url = Some(helpers::create_complete_authorize_url(
-            router_base_url,
-            attempt,
-            connector_name,
-        ));
+        let complete_authorize_url = Some(helpers::sign_callback_url(
+            additional_data.state,
+            helpers::create_complete_authorize_url(router_base_url, attempt, connector_name),
+            &attempt.merchant_id,
+            &attempt.payment_id,
+        )?);
         Ok(Self {
             setup_future_usage: payment_data.payment_intent.setup_future_usage,
             mandate_id: payment_data.mandate_id.clone(),
@@ -3237,17 +3352,23 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsPreProce
             &attempt.merchant_id,
             connector_name,
         ));
-        let router_return_url = Some(helpers::create_redirect_url(
-            router_base_url,
-            attempt,
-            connector_name,
-            payment_data.creds_identifier.as_deref(),
-        ));
-        let complete_authorize_url = Some(helpers::create_complete_authorize_url(
-            router_base_url,
-            attempt,
-            connector_name,
-        ));
+        let router_return_url = Some(helpers::sign_callback_url(
+            additional_data.state,
+            helpers::create_redirect_url(
+                router_base_url,
+                attempt,
+                connector_name,
+                payment_data.creds_identifier.as_deref(),
+            ),
+            &attempt.merchant_id,
+            &attempt.payment_id,
+        )?);
+        let complete_authorize_url = Some(helpers::sign_callback_url(
+            additional_data.state,
+            helpers::create_complete_authorize_url(router_base_url, attempt, connector_name),
+            &attempt.merchant_id,
+            &attempt.payment_id,
+        )?);
         let browser_info: Option<types::BrowserInformation> = payment_data
             .payment_attempt
             .browser_info
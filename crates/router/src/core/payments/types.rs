@@ -138,10 +138,16 @@ impl MultipleCaptureData {
             return storage_enums::AttemptStatus::Charged;
         }
         let status_count_map = self.get_status_count();
+        let is_capture_in_progress = status_count_map.get(&storage_enums::CaptureStatus::Pending)
+            > Some(&0)
+            || status_count_map.get(&storage_enums::CaptureStatus::Started) > Some(&0);
         if status_count_map.get(&storage_enums::CaptureStatus::Charged) > Some(&0) {
             storage_enums::AttemptStatus::PartialChargedAndChargeable
-        } else {
+        } else if is_capture_in_progress {
             storage_enums::AttemptStatus::CaptureInitiated
+        } else {
+            // All the captures failed, so the whole authorized amount is still capturable
+            storage_enums::AttemptStatus::Authorized
         }
     }
     pub fn get_pending_captures(&self) -> Vec<&storage::Capture> {
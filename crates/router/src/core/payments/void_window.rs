@@ -0,0 +1,279 @@
+use api_models::{payments as payment_types, refunds as refund_types};
+use common_utils::id_type;
+use router_env::{instrument, logger, tracing};
+
+use super::{CallConnectorAction, PaymentCancel, PaymentData, PaymentStatus};
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        refunds,
+    },
+    db::StorageInterface,
+    routes::{app::ReqState, SessionState},
+    services::{self, VoidWindow},
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+const CAPTURED_AT_PREFIX: &str = "PAYMENT_ATTEMPT_CAPTURED_AT";
+/// The capture time of attempts is kept for longer than the void windows of the connectors
+const CAPTURED_AT_TTL_SECS: i64 = 7 * 24 * 60 * 60;
+
+fn get_captured_at_key(payment_attempt: &storage::PaymentAttempt) -> String {
+    format!(
+        "{CAPTURED_AT_PREFIX}_{}_{}",
+        payment_attempt.merchant_id.get_string_repr(),
+        payment_attempt.attempt_id
+    )
+}
+
+/// Records the time at which the attempt was first captured, from which its void window is
+/// measured
+#[instrument(skip_all)]
+pub async fn record_capture_time(state: SessionState, payment_attempt: storage::PaymentAttempt) {
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .set_key_if_not_exists_with_expiry(
+                &get_captured_at_key(&payment_attempt),
+                common_utils::date_time::now().assume_utc().unix_timestamp(),
+                Some(CAPTURED_AT_TTL_SECS),
+            )
+            .await
+            .map(|_| ()),
+        Err(error) => Err(error),
+    };
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            "Failed to record the capture time of the payment attempt"
+        );
+    }
+}
+
+/// The time at which the attempt was captured. The creation time of the attempt, which is never
+/// later than its capture, is used when the capture time is not known, so that a payment is never
+/// voided after it has been settled.
+async fn get_capture_time(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> time::PrimitiveDateTime {
+    let captured_at = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .get_key::<Option<i64>>(&get_captured_at_key(payment_attempt))
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch the capture time of the payment attempt"
+                )
+            })
+            .ok()
+            .flatten(),
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            None
+        }
+    };
+
+    captured_at
+        .and_then(|captured_at| time::OffsetDateTime::from_unix_timestamp(captured_at).ok())
+        .map(|captured_at| time::PrimitiveDateTime::new(captured_at.date(), captured_at.time()))
+        .unwrap_or(payment_attempt.created_at)
+}
+
+/// The void window of the connector through which the payment was made, `None` when the connector
+/// doesn't allow captured payments to be voided
+pub fn get_connector_void_window(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<VoidWindow>> {
+    let Some(connector_name) = payment_attempt.connector.as_ref() else {
+        return Ok(None);
+    };
+    let connector = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        connector_name,
+        api::GetToken::Connector,
+        payment_attempt.merchant_connector_id.clone(),
+    )?;
+
+    Ok(connector.connector.get_void_window())
+}
+
+/// Whether the captured payment can still be voided, the window being counted from its capture
+pub async fn is_void_window_open(
+    state: &SessionState,
+    void_window: VoidWindow,
+    payment_attempt: &storage::PaymentAttempt,
+) -> bool {
+    void_window.is_open(
+        get_capture_time(state, payment_attempt).await,
+        common_utils::date_time::now(),
+    )
+}
+
+/// Whether the profile converts the cancellation of captured payments, whose void window has
+/// passed, into refunds
+pub async fn is_void_to_refund_conversion_enabled(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> bool {
+    let config = db
+        .find_config_by_key_unwrap_or(
+            &profile_id.get_void_to_refund_conversion_enabled_key(),
+            Some("false".to_string()),
+        )
+        .await;
+    match config {
+        Ok(conf) => conf.config == "true",
+        Err(error) => {
+            logger::error!(?error);
+            false
+        }
+    }
+}
+
+/// Cancels a payment. Captured payments whose void window has passed, or made through connectors
+/// which don't allow captured payments to be voided, are refunded in full instead, when enabled for
+/// the profile of the payment.
+#[instrument(skip_all)]
+pub async fn payments_cancel_core(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: payment_types::PaymentsCancelRequest,
+) -> RouterResponse<payment_types::PaymentsResponse> {
+    let db = state.store.as_ref();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &req.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.status != enums::IntentStatus::Succeeded {
+        return cancel_payment(
+            state,
+            req_state,
+            merchant_account,
+            profile_id,
+            key_store,
+            req,
+        )
+        .await;
+    }
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            merchant_account.get_id(),
+            payment_intent.active_attempt.get_id().as_str(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let is_void_unavailable = match get_connector_void_window(&state, &payment_attempt)? {
+        Some(void_window) => !is_void_window_open(&state, void_window, &payment_attempt).await,
+        None => true,
+    };
+    let is_conversion_enabled = match payment_intent.profile_id.as_ref() {
+        Some(profile_id) => is_void_to_refund_conversion_enabled(db, profile_id).await,
+        None => false,
+    };
+
+    if !(is_void_unavailable && is_conversion_enabled) {
+        return cancel_payment(
+            state,
+            req_state,
+            merchant_account,
+            profile_id,
+            key_store,
+            req,
+        )
+        .await;
+    }
+
+    logger::info!(
+        payment_id = %payment_intent.payment_id.get_string_repr(),
+        "Void window has passed, refunding the payment instead"
+    );
+    Box::pin(refunds::refund_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        profile_id.clone(),
+        key_store.clone(),
+        refund_types::RefundRequest {
+            payment_id: payment_intent.payment_id.clone(),
+            reason: req.cancellation_reason,
+            refund_type: Some(refund_types::RefundType::Instant),
+            merchant_connector_details: req.merchant_connector_details,
+            ..Default::default()
+        },
+    ))
+    .await?;
+
+    Box::pin(super::payments_core::<
+        api::PSync,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        PaymentData<api::PSync>,
+    >(
+        state,
+        req_state,
+        merchant_account,
+        profile_id,
+        key_store,
+        PaymentStatus,
+        payment_types::PaymentsRetrieveRequest {
+            resource_id: api::PaymentIdType::PaymentIntentId(payment_intent.payment_id),
+            ..Default::default()
+        },
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Avoid,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await
+}
+
+async fn cancel_payment(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: payment_types::PaymentsCancelRequest,
+) -> RouterResponse<payment_types::PaymentsResponse> {
+    Box::pin(super::payments_core::<
+        api::Void,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        PaymentData<api::Void>,
+    >(
+        state,
+        req_state,
+        merchant_account,
+        profile_id,
+        key_store,
+        PaymentCancel,
+        req,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+    ))
+    .await
+}
@@ -107,6 +107,9 @@ pub async fn get_connector_choice(
                 .attach_printable("Invalid connector choice - SessionMultiple")?
         }
 
+        api::ConnectorChoice::Override(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid connector choice - Override")?,
+
         api::ConnectorChoice::StraightThrough(straight_through) => {
             let request_straight_through: api::routing::StraightThroughAlgorithm = straight_through
                 .clone()
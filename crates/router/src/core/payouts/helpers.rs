@@ -821,6 +821,7 @@ pub async fn decide_payout_connector(
                 &TransactionData::Payout(payout_data),
                 eligible_connectors,
                 &business_profile,
+                None,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -869,6 +870,7 @@ pub async fn decide_payout_connector(
                 &TransactionData::Payout(payout_data),
                 eligible_connectors,
                 &business_profile,
+                None,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
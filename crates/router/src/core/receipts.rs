@@ -0,0 +1,673 @@
+mod pdf;
+
+use actix_web::http::header;
+use api_models::{
+    payments::AdditionalPaymentData,
+    receipts::{PaymentReceiptUrls, ReceiptDownloadRequest, ReceiptFormat},
+};
+use common_utils::{
+    consts::DEFAULT_BACKGROUND_COLOR,
+    crypto::{self, SignMessage},
+    ext_traits::ValueExt,
+    id_type,
+    types::{AmountConvertor, MinorUnit, StringMajorUnitForCore},
+};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        localization::{self, Localizer},
+        payments::helpers,
+    },
+    headers::ACCEPT_LANGUAGE,
+    routes::SessionState,
+    services::{authentication::get_header_value_by_key, ApplicationResponse},
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+/// Message used to derive the key signing the receipt download urls from the master key
+const URL_SIGNING_KEY_CONTEXT: &[u8] = b"payment_receipts";
+
+/// Query parameter carrying the unix timestamp after which a receipt download url expires
+const EXPIRES_AT_PARAM: &str = "expires_at";
+
+/// Prefix of the keys marking the rendered receipts in the file storage which are still fresh
+const RECEIPT_CACHE_PREFIX: &str = "RECEIPT_RENDERED";
+
+/// Receipts are only available for payments in which money has moved
+pub fn is_receipt_available(status: storage_enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        storage_enums::IntentStatus::Succeeded | storage_enums::IntentStatus::PartiallyCaptured
+    )
+}
+
+fn get_url_signing_key(state: &SessionState) -> RouterResult<String> {
+    crypto::HmacSha256
+        .sign_message(state.store.get_master_key(), URL_SIGNING_KEY_CONTEXT)
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to derive the receipt url signing key")
+}
+
+/// Signed urls bind both the merchant and the payment, as only the query of a url is signed
+fn get_signed_resource_id(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> String {
+    format!(
+        "{}/{}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+/// Builds the signed urls to download the receipt of a payment, if receipts are enabled and the
+/// payment has succeeded
+pub fn get_receipt_urls(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    status: storage_enums::IntentStatus,
+) -> RouterResult<Option<PaymentReceiptUrls>> {
+    if !state.conf.receipts.enabled || !is_receipt_available(status) {
+        return Ok(None);
+    }
+
+    let expires_at = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+        state.conf.receipts.download_url_expiry_in_secs,
+    ));
+    let signing_key = get_url_signing_key(state)?;
+    let resource_id = get_signed_resource_id(merchant_id, payment_id);
+    let get_url = |format: ReceiptFormat| {
+        helpers::sign_url(
+            &format!(
+                "{}/receipts/{}/{}/{format}?{EXPIRES_AT_PARAM}={}",
+                state.base_url,
+                merchant_id.get_string_repr(),
+                payment_id.get_string_repr(),
+                expires_at.assume_utc().unix_timestamp()
+            ),
+            &resource_id,
+            &signing_key,
+        )
+    };
+
+    Ok(Some(PaymentReceiptUrls {
+        html_url: get_url(ReceiptFormat::Html)?,
+        pdf_url: get_url(ReceiptFormat::Pdf)?,
+        expires_at,
+    }))
+}
+
+/// Same as `get_receipt_urls`, logging failures instead of failing the payment response
+pub fn get_receipt_urls_or_log(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    status: storage_enums::IntentStatus,
+) -> Option<PaymentReceiptUrls> {
+    get_receipt_urls(state, merchant_id, payment_id, status)
+        .map_err(|error| logger::error!(?error, "Failed to build the receipt download urls"))
+        .ok()
+        .flatten()
+}
+
+fn verify_download_url(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    query_string: &str,
+) -> RouterResult<()> {
+    helpers::verify_url_signature(
+        query_string,
+        &get_signed_resource_id(merchant_id, payment_id),
+        &get_url_signing_key(state)?,
+    )?;
+
+    let expires_at = url::form_urlencoded::parse(query_string.as_bytes())
+        .find(|(param, _)| param == EXPIRES_AT_PARAM)
+        .and_then(|(_, value)| value.parse::<i64>().ok())
+        .ok_or(errors::ApiErrorResponse::GenericUnauthorized {
+            message: "Expiry of the receipt url is missing".to_string(),
+        })?;
+
+    if expires_at < common_utils::date_time::now().assume_utc().unix_timestamp() {
+        return Err(errors::ApiErrorResponse::GenericUnauthorized {
+            message: "Receipt url has expired".to_string(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Details of a payment shown on its receipt, before localization
+#[derive(Debug, Clone)]
+struct ReceiptData {
+    merchant_name: String,
+    merchant_logo: Option<String>,
+    theme: String,
+    payment_id: String,
+    date: String,
+    payment_method: Option<String>,
+    currency: storage_enums::Currency,
+    /// Message keys of the components of the amount, with the amount of each component
+    amount_breakdown: Vec<(&'static str, MinorUnit)>,
+    total: MinorUnit,
+}
+
+/// Localized content of a receipt, as pairs of labels and values
+#[derive(Debug, Clone)]
+struct ReceiptContent {
+    title: String,
+    merchant_name: String,
+    merchant_logo: Option<String>,
+    theme: String,
+    details: Vec<(String, String)>,
+    amount_breakdown: Vec<(String, String)>,
+    total: (String, String),
+    thank_you: String,
+}
+
+impl ReceiptData {
+    fn localize(&self, localizer: &Localizer) -> RouterResult<ReceiptContent> {
+        let format_amount = |amount: MinorUnit| -> RouterResult<String> {
+            StringMajorUnitForCore
+                .convert(amount, self.currency)
+                .change_context(errors::ApiErrorResponse::AmountConversionFailed {
+                    amount_type: "StringMajorUnit",
+                })
+                .map(|amount| format!("{} {}", amount.get_amount_as_string(), self.currency))
+        };
+
+        let details = [
+            Some(("receipt.payment_id", self.payment_id.clone())),
+            Some(("receipt.date", self.date.clone())),
+            self.payment_method
+                .clone()
+                .map(|payment_method| ("receipt.payment_method", payment_method)),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|(key, value)| (localizer.translate(key), value))
+        .collect();
+
+        let amount_breakdown = self
+            .amount_breakdown
+            .iter()
+            .map(|(key, amount)| Ok((localizer.translate(key), format_amount(*amount)?)))
+            .collect::<RouterResult<Vec<_>>>()?;
+
+        Ok(ReceiptContent {
+            title: localizer.translate("receipt.title"),
+            merchant_name: self.merchant_name.clone(),
+            merchant_logo: self.merchant_logo.clone(),
+            theme: self.theme.clone(),
+            details,
+            amount_breakdown,
+            total: (
+                localizer.translate("receipt.total"),
+                format_amount(self.total)?,
+            ),
+            thank_you: localizer.translate("receipt.thank_you"),
+        })
+    }
+}
+
+impl ReceiptContent {
+    fn labels(&self) -> impl Iterator<Item = &String> {
+        [&self.title, &self.total.0, &self.thank_you]
+            .into_iter()
+            .chain(self.details.iter().map(|(label, _)| label))
+            .chain(self.amount_breakdown.iter().map(|(label, _)| label))
+    }
+
+    fn render_html(&self) -> Vec<u8> {
+        maud::html! {
+            (maud::DOCTYPE)
+            html {
+                head {
+                    meta charset="utf-8";
+                    meta name="viewport" content="width=device-width, initial-scale=1";
+                    title { (self.title) " - " (self.merchant_name) }
+                }
+                body style="margin: 0; padding: 32px 16px; background-color: #f5f5f5; font-family: Arial, Helvetica, Sans-Serif; color: #1a1a1a;" {
+                    div style="max-width: 480px; margin: 0 auto; background-color: #ffffff; border-radius: 8px; overflow: hidden;" {
+                        div style={ "padding: 24px; color: #ffffff; background-color: " (self.theme) ";" } {
+                            @if let Some(logo) = &self.merchant_logo {
+                                img src=(logo) alt=(self.merchant_name) style="max-height: 48px; display: block; margin-bottom: 12px;";
+                            }
+                            div style="font-size: 20px; font-weight: bold;" { (self.merchant_name) }
+                            div style="font-size: 14px; opacity: 0.8;" { (self.title) }
+                        }
+                        table style="width: 100%; padding: 16px 24px; border-collapse: collapse; font-size: 14px;" {
+                            @for (label, value) in &self.details {
+                                tr {
+                                    td style="padding: 4px 0; color: #666666;" { (label) }
+                                    td style="padding: 4px 0; text-align: right;" { (value) }
+                                }
+                            }
+                            tr { td colspan="2" style="border-bottom: 1px solid #e0e0e0; padding: 8px 0;" {} }
+                            @for (label, value) in &self.amount_breakdown {
+                                tr {
+                                    td style="padding: 4px 0; color: #666666;" { (label) }
+                                    td style="padding: 4px 0; text-align: right;" { (value) }
+                                }
+                            }
+                            tr {
+                                td style="padding: 12px 0 4px; font-weight: bold;" { (self.total.0) }
+                                td style="padding: 12px 0 4px; font-weight: bold; text-align: right;" { (self.total.1) }
+                            }
+                        }
+                        p style="padding: 0 24px 24px; margin: 0; font-size: 14px; color: #666666;" { (self.thank_you) }
+                    }
+                }
+            }
+        }
+        .into_string()
+        .into_bytes()
+    }
+
+    fn render_pdf(&self) -> Vec<u8> {
+        const MARGIN: u32 = 50;
+        const VALUE_COLUMN: u32 = 320;
+        const LINE_HEIGHT: u32 = 20;
+
+        let mut page = pdf::PdfPage::default();
+        let mut y = pdf::PAGE_HEIGHT - 70;
+        page.text(pdf::Font::Bold, 20, MARGIN, y, &self.merchant_name);
+        y = y.saturating_sub(24);
+        page.text(pdf::Font::Regular, 14, MARGIN, y, &self.title);
+        y = y.saturating_sub(16);
+        page.horizontal_rule(MARGIN, pdf::PAGE_WIDTH - MARGIN, y);
+        y = y.saturating_sub(28);
+
+        for (label, value) in &self.details {
+            page.text(pdf::Font::Regular, 11, MARGIN, y, label);
+            page.text(pdf::Font::Regular, 11, VALUE_COLUMN, y, value);
+            y = y.saturating_sub(LINE_HEIGHT);
+        }
+        page.horizontal_rule(MARGIN, pdf::PAGE_WIDTH - MARGIN, y + 8);
+        y = y.saturating_sub(LINE_HEIGHT);
+
+        for (label, value) in &self.amount_breakdown {
+            page.text(pdf::Font::Regular, 11, MARGIN, y, label);
+            page.text(pdf::Font::Regular, 11, VALUE_COLUMN, y, value);
+            y = y.saturating_sub(LINE_HEIGHT);
+        }
+        page.text(pdf::Font::Bold, 12, MARGIN, y, &self.total.0);
+        page.text(pdf::Font::Bold, 12, VALUE_COLUMN, y, &self.total.1);
+        y = y.saturating_sub(2 * LINE_HEIGHT);
+
+        page.text(pdf::Font::Regular, 11, MARGIN, y, &self.thank_you);
+        page.render()
+    }
+}
+
+fn get_payment_method_description(payment_attempt: &storage::PaymentAttempt) -> Option<String> {
+    let card_description = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|data| {
+            data.parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|data| match data {
+            AdditionalPaymentData::Card(card) => card.last4.map(|last4| {
+                let network = card
+                    .card_network
+                    .map(|network| network.to_string())
+                    .unwrap_or_else(|| "Card".to_string());
+                format!("{network} •••• {last4}")
+            }),
+            _ => None,
+        });
+
+    card_description.or_else(|| {
+        payment_attempt
+            .payment_method_type
+            .map(|payment_method_type| payment_method_type.to_string())
+            .or_else(|| {
+                payment_attempt
+                    .payment_method
+                    .map(|payment_method| payment_method.to_string())
+            })
+    })
+}
+
+fn format_date(date_time: time::PrimitiveDateTime) -> String {
+    format!(
+        "{} {:02}:{:02} UTC",
+        date_time.date(),
+        date_time.hour(),
+        date_time.minute()
+    )
+}
+
+/// Accepts only hex colors as the theme, as it is interpolated into the styles of the receipt
+fn sanitize_theme(theme: Option<String>) -> String {
+    theme
+        .filter(|theme| {
+            theme.strip_prefix('#').is_some_and(|hex| {
+                matches!(hex.len(), 3 | 6) && hex.chars().all(|c| c.is_ascii_hexdigit())
+            })
+        })
+        .unwrap_or_else(|| DEFAULT_BACKGROUND_COLOR.to_string())
+}
+
+async fn get_receipt_data(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<ReceiptData> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_account.get_id();
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            payment_id,
+            merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if !is_receipt_available(payment_intent.status) {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Receipts are only available for successful payments".to_string(),
+        }
+        .into());
+    }
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            payment_id,
+            merchant_id,
+            &payment_intent.active_attempt.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let branding = match payment_intent.profile_id.as_ref() {
+        Some(profile_id) => db
+            .find_business_profile_by_profile_id(key_manager_state, key_store, profile_id)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to fetch the business profile"))
+            .ok()
+            .and_then(|business_profile| business_profile.payment_link_config)
+            .and_then(|payment_link_config| payment_link_config.default_config),
+        None => None,
+    };
+
+    let merchant_name = branding
+        .as_ref()
+        .and_then(|branding| branding.seller_name.clone())
+        .or_else(|| {
+            merchant_account
+                .merchant_name
+                .clone()
+                .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+        })
+        .unwrap_or_else(|| merchant_id.get_string_repr().to_owned());
+
+    let net_amount = &payment_attempt.net_amount;
+    let amount_breakdown = [
+        Some(("receipt.amount", net_amount.get_order_amount())),
+        net_amount
+            .get_shipping_cost()
+            .map(|amount| ("receipt.shipping", amount)),
+        net_amount
+            .get_order_tax_amount()
+            .map(|amount| ("receipt.tax", amount)),
+        net_amount
+            .get_total_surcharge_amount()
+            .map(|amount| ("receipt.surcharge", amount)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    Ok(ReceiptData {
+        merchant_name,
+        merchant_logo: branding.as_ref().and_then(|branding| branding.logo.clone()),
+        theme: sanitize_theme(branding.and_then(|branding| branding.theme)),
+        payment_id: payment_id.get_string_repr().to_owned(),
+        date: format_date(payment_attempt.modified_at),
+        payment_method: get_payment_method_description(&payment_attempt),
+        currency: payment_intent
+            .currency
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Currency missing in payment intent")?,
+        total: payment_intent
+            .amount_captured
+            .unwrap_or_else(|| net_amount.get_total_amount()),
+        amount_breakdown,
+    })
+}
+
+fn get_file_key(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    locale: &str,
+    format: ReceiptFormat,
+) -> String {
+    let locale = locale
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '-')
+        .collect::<String>();
+    format!(
+        "{}/receipts/{}_{locale}.{format}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+fn get_cache_key(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    locale: &str,
+    format: ReceiptFormat,
+) -> String {
+    format!(
+        "{RECEIPT_CACHE_PREFIX}_{}_{}_{locale}_{format}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+/// Checks whether the receipt stored in the file storage was rendered within the cache TTL.
+/// Receipts are considered stale if this cannot be determined, so that they are rendered again.
+async fn is_stored_receipt_fresh(state: &SessionState, cache_key: &str) -> bool {
+    match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .exists::<()>(cache_key)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to check the rendered receipt"))
+            .unwrap_or(false),
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection for receipts");
+            false
+        }
+    }
+}
+
+async fn mark_stored_receipt_fresh(state: &SessionState, cache_key: &str, file_key: String) {
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .set_key_with_expiry(cache_key, file_key, state.conf.receipts.cache_ttl_in_secs)
+                .await
+        }
+        Err(error) => Err(error),
+    };
+
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to mark the rendered receipt as fresh");
+    }
+}
+
+/// Serves the receipt of a payment through a signed url. Receipts are rendered in the preferred
+/// locale of the customer among the supported ones, and stored in the file storage from where
+/// they are served until the cache TTL elapses, after which they are rendered again.
+#[instrument(skip_all)]
+pub async fn download_receipt(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: ReceiptDownloadRequest,
+    request_headers: &header::HeaderMap,
+    query_string: &str,
+) -> RouterResponse<serde_json::Value> {
+    if !state.conf.receipts.enabled {
+        return Err(errors::ApiErrorResponse::NotSupported {
+            message: "Receipts".to_string(),
+        }
+        .into());
+    }
+    verify_download_url(
+        &state,
+        &request.merchant_id,
+        &request.payment_id,
+        query_string,
+    )?;
+
+    let locale = localization::get_preferred_locale(get_header_value_by_key(
+        ACCEPT_LANGUAGE.into(),
+        request_headers,
+    )?);
+    let file_key = get_file_key(
+        merchant_account.get_id(),
+        &request.payment_id,
+        &locale,
+        request.format,
+    );
+    let cache_key = get_cache_key(
+        merchant_account.get_id(),
+        &request.payment_id,
+        &locale,
+        request.format,
+    );
+    let content_type = match request.format {
+        ReceiptFormat::Html => mime::TEXT_HTML_UTF_8,
+        ReceiptFormat::Pdf => mime::APPLICATION_PDF,
+    };
+
+    if is_stored_receipt_fresh(&state, &cache_key).await {
+        match state.file_storage_client.retrieve_file(&file_key).await {
+            Ok(receipt) => return Ok(ApplicationResponse::FileData((receipt, content_type))),
+            Err(error) => logger::warn!(?error, "Receipt not found in file storage, rendering it"),
+        }
+    }
+
+    let localizer =
+        localization::get_localizer(&state, merchant_account.get_id(), Some(&locale)).await;
+    let receipt_data =
+        get_receipt_data(&state, &merchant_account, &key_store, &request.payment_id).await?;
+    let receipt = match request.format {
+        ReceiptFormat::Html => receipt_data.localize(&localizer)?.render_html(),
+        ReceiptFormat::Pdf => {
+            let content = receipt_data.localize(&localizer)?;
+            // The standard PDF fonts cannot render every script, such labels fall back to the
+            // default locale
+            if content.labels().all(|label| pdf::is_encodable(label)) {
+                content.render_pdf()
+            } else {
+                receipt_data.localize(&Localizer::new(None))?.render_pdf()
+            }
+        }
+    };
+
+    match state
+        .file_storage_client
+        .upload_file(&file_key, receipt.clone())
+        .await
+    {
+        Ok(()) => mark_stored_receipt_fresh(&state, &cache_key, file_key).await,
+        Err(error) => logger::error!(?error, "Failed to store the rendered receipt"),
+    }
+
+    Ok(ApplicationResponse::FileData((receipt, content_type)))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_test_receipt_data() -> ReceiptData {
+        ReceiptData {
+            merchant_name: "Juspay <Store>".to_string(),
+            merchant_logo: None,
+            theme: sanitize_theme(Some("#4E6ADD".to_string())),
+            payment_id: "pay_123".to_string(),
+            date: "2024-01-02 10:11 UTC".to_string(),
+            payment_method: Some("Visa •••• 4242".to_string()),
+            currency: storage_enums::Currency::USD,
+            amount_breakdown: vec![
+                ("receipt.amount", MinorUnit::new(1000)),
+                ("receipt.tax", MinorUnit::new(150)),
+            ],
+            total: MinorUnit::new(1150),
+        }
+    }
+
+    #[test]
+    fn test_sanitize_theme() {
+        assert_eq!(sanitize_theme(Some("#fff".to_string())), "#fff");
+        assert_eq!(
+            sanitize_theme(Some("red; background: url(x)".to_string())),
+            DEFAULT_BACKGROUND_COLOR
+        );
+        assert_eq!(sanitize_theme(None), DEFAULT_BACKGROUND_COLOR);
+    }
+
+    #[test]
+    fn test_get_file_key_strips_path_characters() {
+        let merchant_id =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1")).unwrap();
+        let payment_id = id_type::PaymentId::try_from(std::borrow::Cow::from("pay_123")).unwrap();
+        assert_eq!(
+            get_file_key(&merchant_id, &payment_id, "../en-GB", ReceiptFormat::Pdf),
+            "merchant_1/receipts/pay_123_en-GB.pdf"
+        );
+    }
+
+    #[test]
+    fn test_render_html_receipt() {
+        let content = get_test_receipt_data()
+            .localize(&Localizer::new(Some("en")))
+            .unwrap();
+        let html = String::from_utf8(content.render_html()).unwrap();
+
+        assert!(html.contains("Juspay &lt;Store&gt;"));
+        assert!(html.contains("Visa •••• 4242"));
+        assert!(html.contains("10.00 USD"));
+        assert!(html.contains("11.50 USD"));
+        assert!(html.contains("background-color: #4E6ADD;"));
+    }
+
+    #[test]
+    fn test_pdf_receipt_falls_back_for_unsupported_scripts() {
+        let content = get_test_receipt_data()
+            .localize(&Localizer::new(Some("ja")))
+            .unwrap();
+        assert!(!content.labels().all(|label| pdf::is_encodable(label)));
+
+        let content = get_test_receipt_data()
+            .localize(&Localizer::new(Some("de")))
+            .unwrap();
+        assert!(content.labels().all(|label| pdf::is_encodable(label)));
+        assert!(content.render_pdf().starts_with(b"%PDF-1.4"));
+    }
+}
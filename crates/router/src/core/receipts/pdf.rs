@@ -0,0 +1,167 @@
+//! Minimal writer for single page PDF documents, using the standard Helvetica fonts so that no
+//! font needs to be embedded. Text is encoded with the WinAnsi encoding, characters outside of it
+//! are replaced with `?`.
+
+/// Width of an A4 page in points
+pub const PAGE_WIDTH: u32 = 595;
+/// Height of an A4 page in points
+pub const PAGE_HEIGHT: u32 = 842;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Font {
+    Regular,
+    Bold,
+}
+
+impl Font {
+    fn resource_name(self) -> &'static str {
+        match self {
+            Self::Regular => "F1",
+            Self::Bold => "F2",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct PdfPage {
+    content: Vec<u8>,
+}
+
+impl PdfPage {
+    /// Writes a line of text with its baseline starting at the given position
+    pub fn text(&mut self, font: Font, size: u32, x: u32, y: u32, text: &str) {
+        self.content.extend_from_slice(
+            format!("BT /{} {size} Tf {x} {y} Td (", font.resource_name()).as_bytes(),
+        );
+        self.content.extend(encode_text(text));
+        self.content.extend_from_slice(b") Tj ET\n");
+    }
+
+    /// Draws a light horizontal rule
+    pub fn horizontal_rule(&mut self, x_start: u32, x_end: u32, y: u32) {
+        self.content
+            .extend_from_slice(format!("0.8 G {x_start} {y} m {x_end} {y} l S\n").as_bytes());
+    }
+
+    pub fn render(self) -> Vec<u8> {
+        let objects = [
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            b"<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_vec(),
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                 /Resources << /Font << /F1 4 0 R /F2 5 0 R >> >> /Contents 6 0 R >>"
+            )
+            .into_bytes(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>"
+                .to_vec(),
+            [
+                format!("<< /Length {} >>\nstream\n", self.content.len()).into_bytes(),
+                self.content,
+                b"\nendstream".to_vec(),
+            ]
+            .concat(),
+        ];
+
+        let mut document = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(document.len());
+            document.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+            document.extend_from_slice(object);
+            document.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref_offset = document.len();
+        document.extend_from_slice(format!("xref\n0 {}\n", objects.len() + 1).as_bytes());
+        document.extend_from_slice(b"0000000000 65535 f \n");
+        for offset in offsets {
+            document.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+        }
+        document.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+                objects.len() + 1
+            )
+            .as_bytes(),
+        );
+        document
+    }
+}
+
+/// Checks whether the text can be written without any of its characters being replaced
+pub fn is_encodable(text: &str) -> bool {
+    text.chars()
+        .all(|character| encode_char(character).is_some())
+}
+
+fn encode_char(character: char) -> Option<u8> {
+    match character {
+        ' '..='~' | '\u{a0}'..='\u{ff}' => u8::try_from(character).ok(),
+        '€' => Some(0x80),
+        '…' => Some(0x85),
+        '‘' => Some(0x91),
+        '’' => Some(0x92),
+        '“' => Some(0x93),
+        '”' => Some(0x94),
+        '•' => Some(0x95),
+        '–' => Some(0x96),
+        '—' => Some(0x97),
+        _ => None,
+    }
+}
+
+fn encode_text(text: &str) -> Vec<u8> {
+    let mut encoded = Vec::with_capacity(text.len());
+    for character in text.chars() {
+        if matches!(character, '\\' | '(' | ')') {
+            encoded.push(b'\\');
+        }
+        encoded.push(encode_char(character).unwrap_or(b'?'));
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::indexing_slicing)]
+    use super::*;
+
+    #[test]
+    fn test_encode_text() {
+        assert_eq!(encode_text("Total (EUR)"), b"Total \\(EUR\\)".to_vec());
+        assert_eq!(encode_text("Reçu • 10 €"), b"Re\xE7u \x95 10 \x80".to_vec());
+        assert_eq!(encode_text("領収書"), b"???".to_vec());
+        assert!(is_encodable("Zahlungsbeleg für Ihre Zahlung"));
+        assert!(!is_encodable("Квитанция"));
+    }
+
+    #[test]
+    fn test_render_has_valid_cross_reference_table() {
+        let mut page = PdfPage::default();
+        page.text(Font::Bold, 18, 50, 780, "Payment receipt");
+        page.horizontal_rule(50, 545, 770);
+        let document = page.render();
+
+        assert!(document.starts_with(b"%PDF-1.4"));
+        assert!(document.ends_with(b"%%EOF\n"));
+
+        let document_text = String::from_utf8_lossy(&document);
+        let xref_offset: usize = document_text
+            .rsplit("startxref\n")
+            .next()
+            .and_then(|tail| tail.lines().next())
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(document[xref_offset..].starts_with(b"xref\n0 7\n"));
+
+        let entries = &document[xref_offset + b"xref\n0 7\n".len()..];
+        for object_number in 1..=6 {
+            let entry = &entries[object_number * 20..object_number * 20 + 10];
+            let offset: usize = std::str::from_utf8(entry).unwrap().parse().unwrap();
+            assert!(document[offset..].starts_with(format!("{object_number} 0 obj").as_bytes()));
+        }
+    }
+}
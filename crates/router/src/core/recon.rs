@@ -95,6 +95,7 @@ pub async fn generate_recon_token(
         user.org_id.clone(),
         user.profile_id.clone(),
         user.tenant_id,
+        user.two_factor_auth_verified,
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
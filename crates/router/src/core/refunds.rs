@@ -1,3 +1,6 @@
+pub mod settlement_estimation;
+#[cfg(feature = "payouts")]
+pub mod tender_override;
 pub mod transformers;
 pub mod validator;
 
@@ -82,6 +85,22 @@ pub async fn refund_create_core(
         },
     )?;
 
+    let disputes = db
+        .find_disputes_by_merchant_id_payment_id(merchant_id, &req.payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the disputes of the payment")?;
+    utils::when(
+        disputes
+            .iter()
+            .any(|dispute| dispute.dispute_status == enums::DisputeStatus::DisputeLost),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Refunds are not allowed for payments whose dispute was lost".to_string(),
+            }))
+        },
+    )?;
+
     // Amount is not passed in request refer from payment intent.
     amount = req
         .amount
@@ -107,6 +126,18 @@ pub async fn refund_create_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
 
+    utils::when(
+        payments::external_payments::is_external_payment_attempt(
+            payment_attempt.connector.as_deref(),
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Refunds are not supported for payments processed outside of hyperswitch"
+                    .to_string(),
+            }))
+        },
+    )?;
+
     let creds_identifier = req
         .merchant_connector_details
         .as_ref()
@@ -282,6 +313,16 @@ pub async fn trigger_refund_to_gateway(
         router_data
     };
 
+    let settlement_details = router_data_res
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.settlement_details.clone());
+    let expected_settlement_at = match &router_data_res.response {
+        Ok(response) if response.refund_status == enums::RefundStatus::Pending => {
+            settlement_estimation::get_expected_settlement_at(state, refund).await
+        }
+        _ => None,
+    };
     let refund_update = match router_data_res.response {
         Err(err) => storage::RefundUpdate::ErrorUpdate {
             refund_status: Some(enums::RefundStatus::Failure),
@@ -342,6 +383,12 @@ pub async fn trigger_refund_to_gateway(
                         refund_arn: "".to_string(),
                         updated_by: storage_scheme.to_string(),
                         connector_refund_data,
+                        settlement_currency: settlement_details
+                            .as_ref()
+                            .map(|details| details.settlement_currency),
+                        settlement_amount: settlement_details
+                            .map(|details| details.settlement_amount),
+                        expected_settlement_at,
                     }
                 }
             }
@@ -363,6 +410,7 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+    settlement_estimation::record_refund_settlement(state, refund, &response).await;
     Ok(response)
 }
 
@@ -590,6 +638,16 @@ pub async fn sync_refund_with_gateway(
         router_data
     };
 
+    let settlement_details = router_data_res
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.settlement_details.clone());
+    let expected_settlement_at = match &router_data_res.response {
+        Ok(response) if response.refund_status == enums::RefundStatus::Pending => {
+            settlement_estimation::get_expected_settlement_at(state, refund).await
+        }
+        _ => None,
+    };
     let refund_update = match router_data_res.response {
         Err(error_message) => {
             let refund_status = match error_message.status_code {
@@ -649,6 +707,11 @@ pub async fn sync_refund_with_gateway(
                     refund_arn: "".to_string(),
                     updated_by: storage_scheme.to_string(),
                     connector_refund_data,
+                    settlement_currency: settlement_details
+                        .as_ref()
+                        .map(|details| details.settlement_currency),
+                    settlement_amount: settlement_details.map(|details| details.settlement_amount),
+                    expected_settlement_at,
                 }
             }
         },
@@ -669,6 +732,7 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+    settlement_estimation::record_refund_settlement(state, refund, &response).await;
     Ok(response)
 }
 
@@ -806,6 +870,15 @@ pub async fn validate_and_create_refund(
     let total_amount_captured = payment_intent
         .amount_captured
         .unwrap_or(payment_attempt.get_total_amount());
+    // Amounts refunded to bank accounts are no longer refundable to the payment method
+    #[cfg(feature = "payouts")]
+    let total_amount_captured = total_amount_captured
+        - tender_override::get_overridden_amount(
+            state,
+            merchant_account,
+            &payment_intent.payment_id,
+        )
+        .await?;
 
     validator::validate_refund_amount(
         total_amount_captured.get_amount_as_i64(),
@@ -1187,6 +1260,11 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             connector: refund.connector,
             merchant_connector_id: refund.merchant_connector_id,
             charges: refund.charges,
+            settlement_currency: refund.settlement_currency,
+            settlement_amount: refund.settlement_amount,
+            expected_settlement_at: refund
+                .expected_settlement_at
+                .filter(|_| refund.refund_status == enums::RefundStatus::Pending),
         }
     }
 }
@@ -0,0 +1,114 @@
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+const REFUND_SETTLEMENT_TIMES_PREFIX: &str = "REFUND_SETTLEMENT_TIMES";
+
+fn get_settlement_times_key(connector: &str) -> String {
+    format!("{REFUND_SETTLEMENT_TIMES_PREFIX}_{connector}")
+}
+
+/// Records the time the connector took to settle the refund, if the update settled the refund,
+/// discarding the oldest settlement times once the maximum number of samples is exceeded
+#[instrument(skip_all)]
+pub async fn record_refund_settlement(
+    state: &SessionState,
+    refund: &storage::Refund,
+    updated_refund: &storage::Refund,
+) {
+    if !state.conf.refund_settlement_estimation.enabled
+        || refund.refund_status == enums::RefundStatus::Success
+        || updated_refund.refund_status != enums::RefundStatus::Success
+    {
+        return;
+    }
+
+    let settlement_time = (updated_refund.modified_at - updated_refund.created_at).whole_seconds();
+    if let Err(error) =
+        append_settlement_time(state, &updated_refund.connector, settlement_time).await
+    {
+        logger::error!(?error, "Failed to record the refund settlement time");
+    }
+}
+
+async fn append_settlement_time(
+    state: &SessionState,
+    connector: &str,
+    settlement_time: i64,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = get_settlement_times_key(connector);
+
+    redis_conn
+        .append_elements_to_list(&key, settlement_time.to_string())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    // Keeps only the most recent samples, whichever number of concurrent appends were made
+    let max_samples = i64::try_from(state.conf.refund_settlement_estimation.max_samples)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid maximum number of refund settlement samples")?;
+    redis_conn
+        .trim_list(&key, -max_samples, -1)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+}
+
+/// Estimates the time by which the pending refund is settled from the configured percentile of the
+/// times the connector took to settle its recent refunds. No estimate is made until enough
+/// settlement times are tracked for the connector.
+#[instrument(skip_all)]
+pub async fn get_expected_settlement_at(
+    state: &SessionState,
+    refund: &storage::Refund,
+) -> Option<PrimitiveDateTime> {
+    let config = &state.conf.refund_settlement_estimation;
+    if !config.enabled {
+        return None;
+    }
+
+    let settlement_times = get_settlement_times(state, &refund.connector)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to fetch the refund settlement times"))
+        .ok()?;
+    if settlement_times.len() < config.min_samples {
+        return None;
+    }
+
+    let percentile_index = settlement_times
+        .len()
+        .saturating_mul(usize::from(config.percentile))
+        .div_ceil(100)
+        .saturating_sub(1);
+    let settlement_time = settlement_times.get(percentile_index)?;
+    Some(refund.created_at + time::Duration::seconds(*settlement_time))
+}
+
+/// The tracked settlement times of the connector, in seconds and in ascending order
+async fn get_settlement_times(state: &SessionState, connector: &str) -> RouterResult<Vec<i64>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let mut settlement_times = redis_conn
+        .get_list_elements(&get_settlement_times_key(connector), 0, -1)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .into_iter()
+        .filter_map(|settlement_time| settlement_time.parse::<i64>().ok())
+        .collect::<Vec<_>>();
+    settlement_times.sort_unstable();
+    Ok(settlement_times)
+}
@@ -0,0 +1,503 @@
+use api_models::{enums as api_enums, payouts as payout_types, refunds as refund_types};
+use common_utils::{consts::DEFAULT_LOCALE, date_time, id_type, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::validator;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payouts::{self, helpers as payout_helpers},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, SessionState},
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+const REASON_MAX_LENGTH: usize = 255;
+
+async fn get_tender_overrides(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<Vec<storage::RefundTenderOverride>> {
+    db.find_refund_tender_overrides_by_merchant_id_payment_id(merchant_id, payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refund overrides of the payment")
+}
+
+async fn get_payout_status(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payout_id: Option<&String>,
+) -> RouterResult<Option<enums::PayoutStatus>> {
+    let Some(payout_id) = payout_id else {
+        return Ok(None);
+    };
+    db.find_payout_by_merchant_id_payout_id(
+        merchant_account.get_id(),
+        payout_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)
+    .map(|payout| Some(payout.status))
+}
+
+/// Whether the amount of the override is no longer refundable to the payment method. The amount
+/// is reserved while the payout is being created, and released once the payout could not be
+/// created, failed or was cancelled.
+fn is_amount_overridden(
+    status: enums::RefundTenderOverrideStatus,
+    payout_status: Option<enums::PayoutStatus>,
+) -> bool {
+    match status {
+        enums::RefundTenderOverrideStatus::Pending => true,
+        enums::RefundTenderOverrideStatus::PayoutCreated => payout_status
+            .map_or(true, |payout_status| {
+                !payout_helpers::is_payout_err_state(payout_status)
+            }),
+        enums::RefundTenderOverrideStatus::Failed => false,
+    }
+}
+
+fn sum_overridden_amount(
+    tender_overrides: &[(storage::RefundTenderOverride, Option<enums::PayoutStatus>)],
+) -> MinorUnit {
+    tender_overrides
+        .iter()
+        .filter(|(tender_override, payout_status)| {
+            is_amount_overridden(tender_override.status, *payout_status)
+        })
+        .fold(
+            MinorUnit::new(0),
+            |overridden_amount, (tender_override, _)| overridden_amount + tender_override.amount,
+        )
+}
+
+async fn get_tender_overrides_with_payout_status(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<Vec<(storage::RefundTenderOverride, Option<enums::PayoutStatus>)>> {
+    let mut tender_overrides = Vec::new();
+    for tender_override in get_tender_overrides(db, merchant_account.get_id(), payment_id).await? {
+        let payout_status =
+            get_payout_status(db, merchant_account, tender_override.payout_id.as_ref()).await?;
+        tender_overrides.push((tender_override, payout_status));
+    }
+    Ok(tender_overrides)
+}
+
+/// The amount of the payment refunded to bank accounts, including the refunds whose payouts are
+/// being created, and excluding the payouts which failed or were cancelled
+#[instrument(skip_all)]
+pub async fn get_overridden_amount(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<MinorUnit> {
+    get_tender_overrides_with_payout_status(state.store.as_ref(), merchant_account, payment_id)
+        .await
+        .map(|tender_overrides| sum_overridden_amount(&tender_overrides))
+}
+
+/// Validates the requested override, returning the trimmed reason for the audit trail
+fn validate_tender_override_request(reason: &str, amount: MinorUnit) -> RouterResult<String> {
+    let reason = reason.trim().to_string();
+    utils::when(
+        reason.is_empty() || reason.len() > REASON_MAX_LENGTH,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "reason must be between 1 and {REASON_MAX_LENGTH} characters long"
+                ),
+            }))
+        },
+    )?;
+    utils::when(amount <= MinorUnit::new(0), || {
+        Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "amount".to_string(),
+            expected_format: "positive integer".to_string(),
+        }))
+    })?;
+    Ok(reason)
+}
+
+fn to_tender_override_response(
+    tender_override: storage::RefundTenderOverride,
+    payout_status: Option<enums::PayoutStatus>,
+) -> refund_types::RefundTenderOverrideResponse {
+    refund_types::RefundTenderOverrideResponse {
+        override_id: tender_override.override_id,
+        payment_id: tender_override.payment_id,
+        status: tender_override.status,
+        payout_id: tender_override.payout_id,
+        payout_status,
+        amount: tender_override.amount,
+        currency: tender_override.currency,
+        reason: tender_override.reason,
+        created_by: tender_override.created_by,
+        created_at: tender_override.created_at,
+    }
+}
+
+/// Refunds a payment to a bank account through a payout, instead of to the payment method the
+/// payment was made with. Refunds are made to the payment method by default, so the override
+/// requires a reason and is recorded along with the user who made it, in the audit trail of the
+/// payment and as an audit event. The refunded amount is deducted from the amount of the payment
+/// that remains refundable. The override is recorded before its payout is created, so that the
+/// amount is reserved even if the payout outlives a failure to record it.
+#[instrument(skip_all)]
+pub async fn create_tender_override(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    user_id: String,
+    req: refund_types::RefundTenderOverrideRequest,
+) -> RouterResponse<refund_types::RefundTenderOverrideResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    let reason = validate_tender_override_request(&req.reason, req.amount)?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &req.payment_id,
+            merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    utils::when(
+        !(payment_intent.status == enums::IntentStatus::Succeeded
+            || payment_intent.status == enums::IntentStatus::PartiallyCaptured),
+        || {
+            Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+                current_flow: "refund".into(),
+                field_name: "status".into(),
+                current_value: payment_intent.status.to_string(),
+                states: "succeeded, partially_captured".to_string()
+            }))
+        },
+    )?;
+    let customer_id = payment_intent.customer_id.clone().ok_or_else(|| {
+        report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Only payments made by a customer can be refunded to a bank account"
+                .to_string(),
+        })
+    })?;
+
+    let payment_attempt = db
+        .find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_merchant_id(
+            &payment_intent.payment_id,
+            merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
+    let currency = payment_attempt
+        .currency
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Currency not found in the payment attempt")?;
+
+    let all_refunds = db
+        .find_refund_by_payment_id_merchant_id(
+            &payment_intent.payment_id,
+            merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the refunds of the payment")?;
+    let refundable_amount = payment_intent
+        .amount_captured
+        .unwrap_or(payment_attempt.get_total_amount())
+        - get_overridden_amount(&state, &merchant_account, &payment_intent.payment_id).await?;
+    validator::validate_refund_amount(
+        refundable_amount.get_amount_as_i64(),
+        &all_refunds,
+        req.amount.get_amount_as_i64(),
+    )
+    .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
+
+    let tender_override = db
+        .insert_refund_tender_override(storage::RefundTenderOverrideNew {
+            override_id: utils::generate_id(consts::ID_LENGTH, "rto"),
+            merchant_id: merchant_id.clone(),
+            payment_id: payment_intent.payment_id.clone(),
+            payout_id: None,
+            status: enums::RefundTenderOverrideStatus::Pending,
+            amount: req.amount,
+            currency,
+            reason,
+            created_by: user_id,
+            created_at: date_time::now(),
+            modified_at: date_time::now(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the refund override")?;
+
+    let payout_request = payout_types::PayoutCreateRequest {
+        amount: Some(req.amount.into()),
+        currency: Some(currency),
+        connector: req.connector,
+        confirm: Some(true),
+        auto_fulfill: Some(true),
+        payout_type: Some(api_enums::PayoutType::Bank),
+        payout_method_data: Some(payout_types::PayoutMethodData::Bank(req.bank)),
+        billing: req.billing,
+        customer_id: Some(customer_id),
+        profile_id: payment_intent.profile_id.clone(),
+        description: Some(format!(
+            "Refund of the payment {}",
+            payment_intent.payment_id.get_string_repr()
+        )),
+        ..Default::default()
+    };
+    let payout_result = match Box::pin(payouts::payouts_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store,
+        payout_request,
+        DEFAULT_LOCALE,
+    ))
+    .await
+    {
+        Ok(ApplicationResponse::Json(payout))
+        | Ok(ApplicationResponse::JsonWithHeaders((payout, _))) => Ok(payout),
+        Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response from the payout create flow"),
+        Err(error) => Err(error),
+    };
+    let payout = match payout_result {
+        Ok(payout) => payout,
+        Err(error) => {
+            // No payout was created, so the reserved amount is released
+            db.update_refund_tender_override(
+                merchant_id,
+                &tender_override.override_id,
+                storage::RefundTenderOverrideUpdate::Failed,
+            )
+            .await
+            .map_err(|update_error| {
+                logger::error!(
+                    ?update_error,
+                    override_id = %tender_override.override_id,
+                    "Failed to mark the refund override as failed"
+                )
+            })
+            .ok();
+            return Err(error);
+        }
+    };
+
+    // The payout has been created, a failure to record it keeps the amount reserved
+    let tender_override = db
+        .update_refund_tender_override(
+            merchant_id,
+            &tender_override.override_id,
+            storage::RefundTenderOverrideUpdate::PayoutCreated {
+                payout_id: payout.payout_id.clone(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed to record the payout {} of the refund override {}",
+                payout.payout_id, tender_override.override_id
+            )
+        })?;
+
+    logger::info!(
+        payment_id = %payment_intent.payment_id.get_string_repr(),
+        override_id = %tender_override.override_id,
+        payout_id = %payout.payout_id,
+        "Payment refunded to a bank account"
+    );
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::RefundTenderOverridden {
+            payment_id: payment_intent.payment_id.clone(),
+            override_id: tender_override.override_id.clone(),
+            payout_id: payout.payout_id.clone(),
+            amount: tender_override.amount,
+            reason: tender_override.reason.clone(),
+            user_id: tender_override.created_by.clone(),
+        }))
+        .emit();
+
+    Ok(ApplicationResponse::Json(to_tender_override_response(
+        tender_override,
+        Some(payout.status),
+    )))
+}
+
+/// Lists the refunds of a payment made to bank accounts, along with the current status of their
+/// payouts
+#[instrument(skip_all)]
+pub async fn list_tender_overrides(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<refund_types::RefundTenderOverrideListResponse> {
+    let db = state.store.as_ref();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    let overrides =
+        get_tender_overrides_with_payout_status(db, &merchant_account, &payment_intent.payment_id)
+            .await?
+            .into_iter()
+            .map(|(tender_override, payout_status)| {
+                to_tender_override_response(tender_override, payout_status)
+            })
+            .collect();
+
+    Ok(ApplicationResponse::Json(
+        refund_types::RefundTenderOverrideListResponse {
+            payment_id: payment_intent.payment_id,
+            overrides,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tender_override(
+        amount: i64,
+        status: enums::RefundTenderOverrideStatus,
+    ) -> storage::RefundTenderOverride {
+        storage::RefundTenderOverride {
+            override_id: "rto_123".to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            payment_id: id_type::PaymentId::default(),
+            payout_id: None,
+            status,
+            amount: MinorUnit::new(amount),
+            currency: enums::Currency::USD,
+            reason: "The card of the customer was closed".to_string(),
+            created_by: "user_123".to_string(),
+            created_at: date_time::now(),
+            modified_at: date_time::now(),
+        }
+    }
+
+    #[test]
+    fn test_pending_overrides_reserve_their_amount() {
+        assert!(is_amount_overridden(
+            enums::RefundTenderOverrideStatus::Pending,
+            None
+        ));
+        assert!(!is_amount_overridden(
+            enums::RefundTenderOverrideStatus::Failed,
+            None
+        ));
+    }
+
+    #[test]
+    fn test_failed_and_cancelled_payouts_release_their_amount() {
+        let status = enums::RefundTenderOverrideStatus::PayoutCreated;
+        assert!(is_amount_overridden(
+            status,
+            Some(enums::PayoutStatus::Success)
+        ));
+        assert!(is_amount_overridden(
+            status,
+            Some(enums::PayoutStatus::Pending)
+        ));
+        assert!(!is_amount_overridden(
+            status,
+            Some(enums::PayoutStatus::Failed)
+        ));
+        assert!(!is_amount_overridden(
+            status,
+            Some(enums::PayoutStatus::Cancelled)
+        ));
+        // The amount stays reserved while the status of the payout is not known
+        assert!(is_amount_overridden(status, None));
+    }
+
+    #[test]
+    fn test_tender_override_request_validation() {
+        assert_eq!(
+            validate_tender_override_request("  The card was closed  ", MinorUnit::new(100)).ok(),
+            Some("The card was closed".to_string())
+        );
+        assert!(validate_tender_override_request("   ", MinorUnit::new(100)).is_err());
+        assert!(validate_tender_override_request(
+            &"a".repeat(REASON_MAX_LENGTH),
+            MinorUnit::new(100)
+        )
+        .is_ok());
+        assert!(validate_tender_override_request(
+            &"a".repeat(REASON_MAX_LENGTH + 1),
+            MinorUnit::new(100)
+        )
+        .is_err());
+        assert!(
+            validate_tender_override_request("The card was closed", MinorUnit::new(0)).is_err()
+        );
+        assert!(
+            validate_tender_override_request("The card was closed", MinorUnit::new(-100)).is_err()
+        );
+    }
+
+    #[test]
+    fn test_overridden_amount_sums_the_reserved_and_refunded_amounts() {
+        let tender_overrides = [
+            (
+                tender_override(1000, enums::RefundTenderOverrideStatus::Pending),
+                None,
+            ),
+            (
+                tender_override(200, enums::RefundTenderOverrideStatus::PayoutCreated),
+                Some(enums::PayoutStatus::Success),
+            ),
+            (
+                tender_override(30, enums::RefundTenderOverrideStatus::PayoutCreated),
+                Some(enums::PayoutStatus::Failed),
+            ),
+            (
+                tender_override(4, enums::RefundTenderOverrideStatus::Failed),
+                None,
+            ),
+        ];
+
+        assert_eq!(
+            sum_overridden_amount(&tender_overrides),
+            MinorUnit::new(1200)
+        );
+        assert_eq!(sum_overridden_amount(&[]), MinorUnit::new(0));
+    }
+}
@@ -9,7 +9,9 @@ use api_models::{
 use async_trait::async_trait;
 use diesel_models::routing_algorithm::RoutingAlgorithm;
 use error_stack::ResultExt;
-use hyperswitch_domain_models::{mandates, payment_address};
+use hyperswitch_domain_models::{
+    mandates, payment_address, router_request_types::fraud_check::PaymentRiskSignals,
+};
 #[cfg(feature = "v1")]
 use router_env::logger;
 use router_env::metrics::add_attributes;
@@ -61,6 +63,7 @@ pub struct PaymentsDslInput<'a> {
     pub address: &'a payment_address::PaymentAddress,
     pub recurring_details: Option<&'a mandates_api::RecurringDetails>,
     pub currency: storage_enums::Currency,
+    pub risk_signals: Option<&'a PaymentRiskSignals>,
 }
 
 impl<'a> PaymentsDslInput<'a> {
@@ -72,6 +75,7 @@ impl<'a> PaymentsDslInput<'a> {
         address: &'a payment_address::PaymentAddress,
         recurring_details: Option<&'a mandates_api::RecurringDetails>,
         currency: storage_enums::Currency,
+        risk_signals: Option<&'a PaymentRiskSignals>,
     ) -> Self {
         Self {
             setup_mandate,
@@ -81,6 +85,7 @@ impl<'a> PaymentsDslInput<'a> {
             address,
             recurring_details,
             currency,
+            risk_signals,
         }
     }
 }
@@ -1547,6 +1552,7 @@ impl RoutableConnectors {
             payment_data.get_address(),
             payment_data.get_recurring_details(),
             payment_data.get_currency(),
+            payment_data.get_risk_signals(),
         );
 
         let routable_connector_choice = self.0.clone();
@@ -1585,3 +1591,55 @@ impl RoutableConnectors {
         Ok(connector_data)
     }
 }
+
+#[cfg(feature = "v1")]
+pub async fn retrieve_payment_routing_decision(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    payment_id: common_utils::id_type::PaymentId,
+) -> RouterResponse<routing_types::PaymentRoutingDecisionResponse> {
+    let mut payment_attempts = state
+        .store
+        .find_attempts_by_merchant_id_payment_id(
+            merchant_account.get_id(),
+            &payment_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_attempts.is_empty() {
+        return Err(errors::ApiErrorResponse::PaymentNotFound.into());
+    }
+
+    payment_attempts.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+    let attempts = payment_attempts
+        .into_iter()
+        .map(|payment_attempt| {
+            let routing_decision = payment_attempt
+                .routing_decision
+                .map(|routing_decision| {
+                    routing_decision
+                        .parse_value::<routing_types::RoutingDecisionTrace>("RoutingDecisionTrace")
+                })
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deserialize the routing decision of the attempt")?;
+
+            Ok(routing_types::PaymentAttemptRoutingDecision {
+                attempt_id: payment_attempt.attempt_id,
+                connector: payment_attempt.connector,
+                routing_decision,
+                created_at: payment_attempt.created_at,
+            })
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::PaymentRoutingDecisionResponse {
+            payment_id,
+            attempts,
+        },
+    ))
+}
@@ -0,0 +1,438 @@
+use std::collections::HashMap;
+
+use api_models::sandbox_data as sandbox_data_types;
+use common_enums::{MerchantStorageScheme, SandboxDataResetStatus, SandboxDataResource};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt},
+    fp_utils, generate_id, id_type,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing, Env};
+use strum::IntoEnumIterator;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payment_methods::{cards, network_tokenization},
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage, storage::enums},
+};
+
+/// Number of records deleted by a single statement, so that a reset never holds the locks on a
+/// table for long
+const SANDBOX_DATA_RESET_BATCH_SIZE: u16 = 500;
+
+const SANDBOX_DATA_RESET_NAME: &str = "SANDBOX_DATA_RESET";
+const SANDBOX_DATA_RESET_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::SandboxDataResetWorkflow;
+const SANDBOX_DATA_RESET_TAG: &str = "SANDBOX_DATA";
+
+/// Latest data reset of a sandbox merchant account, stored in the configs table
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SandboxDataReset {
+    pub reset_id: String,
+    pub status: SandboxDataResetStatus,
+    pub current_resource: Option<SandboxDataResource>,
+    pub deleted_records: HashMap<SandboxDataResource, u64>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+impl SandboxDataReset {
+    fn get_config_key(merchant_id: &id_type::MerchantId) -> String {
+        format!("sandbox_data_reset_{}", merchant_id.get_string_repr())
+    }
+
+    fn is_running(&self) -> bool {
+        matches!(
+            self.status,
+            SandboxDataResetStatus::Pending | SandboxDataResetStatus::InProgress
+        )
+    }
+
+    fn to_response(&self) -> sandbox_data_types::SandboxDataResetResponse {
+        sandbox_data_types::SandboxDataResetResponse {
+            reset_id: self.reset_id.clone(),
+            status: self.status,
+            current_resource: self.current_resource,
+            progress: SandboxDataResource::iter()
+                .map(|resource| sandbox_data_types::SandboxDataResetProgress {
+                    resource,
+                    deleted_records: self
+                        .deleted_records
+                        .get(&resource)
+                        .copied()
+                        .unwrap_or_default(),
+                })
+                .collect(),
+            error_message: self.error_message.clone(),
+            created_at: self.created_at,
+            completed_at: self.completed_at,
+        }
+    }
+}
+
+/// Tracking data of the task executing a data reset
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SandboxDataResetTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    pub reset_id: String,
+}
+
+fn validate_sandbox_environment() -> RouterResult<()> {
+    match router_env::which() {
+        Env::Production => Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Data resets are only available in the sandbox environment".to_string(),
+        })),
+        Env::Development | Env::Sandbox => Ok(()),
+    }
+}
+
+async fn find_sandbox_data_reset(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<SandboxDataReset>> {
+    // Read from the database, as the progress of the reset is updated after every batch
+    match db
+        .find_config_by_key_from_db(&SandboxDataReset::get_config_key(merchant_id))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("SandboxDataReset")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the sandbox data reset")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the sandbox data reset")),
+    }
+}
+
+async fn store_sandbox_data_reset(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    reset: &SandboxDataReset,
+    is_new_merchant_record: bool,
+) -> RouterResult<()> {
+    let key = SandboxDataReset::get_config_key(merchant_id);
+    let config = reset
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the sandbox data reset")?;
+
+    if is_new_merchant_record {
+        db.insert_config(storage::ConfigNew { key, config })
+            .await
+            .map(|_| ())
+    } else {
+        db.update_config_in_database(
+            &key,
+            storage::ConfigUpdate::Update {
+                config: Some(config),
+            },
+        )
+        .await
+        .map(|_| ())
+    }
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store the sandbox data reset")
+}
+
+/// Schedules the deletion of the payments, customers and payment methods of a sandbox merchant
+/// account, along with the cards saved in the locker. The configuration of the merchant account,
+/// such as its profiles, connectors and api keys, is retained.
+#[instrument(skip_all)]
+pub async fn reset_sandbox_data(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<sandbox_data_types::SandboxDataResetResponse> {
+    validate_sandbox_environment()?;
+    // The records held in redis are not reached by the deletes on the database
+    fp_utils::when(
+        merchant_account.storage_scheme == MerchantStorageScheme::RedisKv,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Data can not be reset for merchant accounts using the redis_kv storage \
+                          scheme"
+                    .to_string(),
+            }))
+        },
+    )?;
+
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let latest_reset = find_sandbox_data_reset(db, merchant_id).await?;
+    fp_utils::when(
+        latest_reset
+            .as_ref()
+            .is_some_and(|latest_reset| latest_reset.is_running()),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A data reset is already in progress for the merchant account".to_string(),
+            }))
+        },
+    )?;
+
+    let reset = SandboxDataReset {
+        reset_id: generate_id(consts::ID_LENGTH, "reset"),
+        status: SandboxDataResetStatus::Pending,
+        current_resource: None,
+        deleted_records: HashMap::new(),
+        error_message: None,
+        created_at: date_time::now(),
+        completed_at: None,
+    };
+    store_sandbox_data_reset(db, merchant_id, &reset, latest_reset.is_none()).await?;
+    add_sandbox_data_reset_task(db, merchant_id, &reset.reset_id).await?;
+
+    logger::info!(
+        merchant_id = %merchant_id.get_string_repr(),
+        reset_id = %reset.reset_id,
+        "Sandbox data reset scheduled"
+    );
+
+    Ok(ApplicationResponse::JsonAccepted(reset.to_response()))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_sandbox_data_reset(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<sandbox_data_types::SandboxDataResetResponse> {
+    let reset = find_sandbox_data_reset(state.store.as_ref(), merchant_account.get_id())
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No data reset has been requested for the merchant account".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(reset.to_response()))
+}
+
+async fn add_sandbox_data_reset_task(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    reset_id: &str,
+) -> RouterResult<()> {
+    let process_tracker_id =
+        format!("{SANDBOX_DATA_RESET_RUNNER}_{SANDBOX_DATA_RESET_NAME}_{reset_id}");
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        SANDBOX_DATA_RESET_NAME,
+        SANDBOX_DATA_RESET_RUNNER,
+        [SANDBOX_DATA_RESET_TAG],
+        SandboxDataResetTrackingData {
+            merchant_id: merchant_id.clone(),
+            reset_id: reset_id.to_owned(),
+        },
+        date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the sandbox data reset task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to schedule the sandbox data reset")?;
+
+    Ok(())
+}
+
+/// Deletes the data of the merchant account one batch at a time, recording the progress of the
+/// reset after every batch. Returns the status the reset ended with, or `None` when the reset has
+/// been superseded by a later one.
+pub async fn execute_sandbox_data_reset(
+    state: &SessionState,
+    tracking_data: &SandboxDataResetTrackingData,
+) -> RouterResult<Option<SandboxDataResetStatus>> {
+    let db = state.store.as_ref();
+    let merchant_id = &tracking_data.merchant_id;
+    let Some(mut reset) = find_sandbox_data_reset(db, merchant_id)
+        .await?
+        .filter(|reset| reset.reset_id == tracking_data.reset_id)
+    else {
+        logger::warn!(
+            reset_id = %tracking_data.reset_id,
+            "Sandbox data reset not found, it may have been superseded"
+        );
+        return Ok(None);
+    };
+
+    reset.status = SandboxDataResetStatus::InProgress;
+    store_sandbox_data_reset(db, merchant_id, &reset, false).await?;
+    let result = delete_sandbox_data(state, merchant_id, &mut reset).await;
+
+    reset.current_resource = None;
+    reset.completed_at = Some(date_time::now());
+    match result {
+        Ok(()) => reset.status = SandboxDataResetStatus::Completed,
+        Err(error) => {
+            logger::error!(?error, reset_id = %reset.reset_id, "Sandbox data reset failed");
+            reset.status = SandboxDataResetStatus::Failed;
+            reset.error_message = Some(error.current_context().to_string());
+        }
+    }
+    store_sandbox_data_reset(db, merchant_id, &reset, false).await?;
+    logger::info!(
+        merchant_id = %merchant_id.get_string_repr(),
+        reset_id = %reset.reset_id,
+        status = %reset.status,
+        "Sandbox data reset finished"
+    );
+
+    Ok(Some(reset.status))
+}
+
+/// Deletes a batch of the payment methods of the merchant, returning the number of records
+/// deleted. The cards and network tokens of the payment methods are deleted from the locker before
+/// the records referencing them.
+async fn delete_payment_methods_batch(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<usize> {
+    let db = state.store.as_ref();
+    let payment_methods = db
+        .find_sandbox_payment_method_locker_references(
+            merchant_id,
+            i64::from(SANDBOX_DATA_RESET_BATCH_SIZE),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment methods")?;
+
+    for payment_method in payment_methods
+        .iter()
+        .filter(|payment_method| payment_method.payment_method == Some(enums::PaymentMethod::Card))
+    {
+        cards::delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            merchant_id,
+            payment_method
+                .locker_id
+                .as_ref()
+                .unwrap_or(&payment_method.payment_method_id),
+        )
+        .await?;
+
+        if let Some(network_token_requestor_reference_id) =
+            payment_method.network_token_requestor_reference_id.clone()
+        {
+            network_tokenization::delete_network_token_from_locker_and_token_service(
+                state,
+                &payment_method.customer_id,
+                merchant_id,
+                payment_method.payment_method_id.clone(),
+                payment_method.network_token_locker_id.clone(),
+                network_token_requestor_reference_id,
+            )
+            .await?;
+        }
+    }
+
+    db.delete_sandbox_payment_methods(
+        merchant_id,
+        payment_methods
+            .into_iter()
+            .map(|payment_method| payment_method.payment_method_id)
+            .collect(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to delete the payment methods")
+}
+
+async fn delete_sandbox_data(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    reset: &mut SandboxDataReset,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    for resource in SandboxDataResource::iter() {
+        reset.current_resource = Some(resource);
+        loop {
+            let deleted_records = match resource {
+                SandboxDataResource::PaymentMethods => {
+                    delete_payment_methods_batch(state, merchant_id).await?
+                }
+                _ => db
+                    .delete_sandbox_data_batch(
+                        merchant_id,
+                        resource,
+                        i64::from(SANDBOX_DATA_RESET_BATCH_SIZE),
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable_lazy(|| format!("Failed to delete the {resource}"))?,
+            };
+
+            let total_deleted_records = reset.deleted_records.entry(resource).or_default();
+            *total_deleted_records = total_deleted_records
+                .saturating_add(u64::try_from(deleted_records).unwrap_or(u64::MAX));
+            store_sandbox_data_reset(db, merchant_id, reset, false).await?;
+
+            // A partial batch means no records of the resource are left
+            if deleted_records < usize::from(SANDBOX_DATA_RESET_BATCH_SIZE) {
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_reset_progress_covers_every_resource_in_order() {
+        let reset = SandboxDataReset {
+            reset_id: "reset_123".to_string(),
+            status: SandboxDataResetStatus::InProgress,
+            current_resource: Some(SandboxDataResource::Payments),
+            deleted_records: HashMap::from([
+                (SandboxDataResource::Refunds, 3),
+                (SandboxDataResource::PaymentAttempts, 1200),
+            ]),
+            error_message: None,
+            created_at: date_time::now(),
+            completed_at: None,
+        };
+
+        let parsed: SandboxDataReset = reset
+            .encode_to_string_of_json()
+            .unwrap()
+            .parse_struct("SandboxDataReset")
+            .unwrap();
+        let response = parsed.to_response();
+
+        assert_eq!(response.progress.len(), SandboxDataResource::iter().count());
+        assert_eq!(
+            response.progress.first().map(|progress| progress.resource),
+            Some(SandboxDataResource::LedgerEntries)
+        );
+        assert_eq!(
+            response
+                .progress
+                .iter()
+                .map(|progress| progress.deleted_records)
+                .sum::<u64>(),
+            1203
+        );
+        assert_eq!(
+            response.current_resource,
+            Some(SandboxDataResource::Payments)
+        );
+    }
+}
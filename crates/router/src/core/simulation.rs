@@ -0,0 +1,715 @@
+use std::{collections::HashMap, str::FromStr};
+
+use api_models::{
+    api_logs as api_log_types, enums, routing as api_routing, simulation as simulation_types,
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    generate_id,
+    types::MinorUnit,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::HeaderPayload;
+use redis_interface::{errors::RedisError, SetnxReply};
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+    Flow,
+};
+use storage_impl::redis::cache::{self, CacheKind, ROUTING_CACHE};
+
+use crate::{
+    consts,
+    core::{
+        api_logs,
+        errors::{self, utils::StorageErrorExt, RouterResponse, RouterResult},
+        payments,
+    },
+    routes::SessionState,
+    services::{self, ApplicationResponse},
+    types::{api, domain},
+};
+
+const LOAD_TEST_PREFIX: &str = "LOAD_TEST";
+const LOAD_TEST_COUNTS_PREFIX: &str = "LOAD_TEST_COUNTS";
+const ACTIVE_LOAD_TEST_PREFIX: &str = "ACTIVE_LOAD_TEST";
+/// Load tests are retained for a day after they are started
+const LOAD_TEST_RETENTION_IN_SECS: i64 = 24 * 60 * 60;
+/// Card which is always authorized successfully by the dummy connectors
+const LOAD_TEST_CARD_NUMBER: &str = "4242424242424242";
+
+/// Simulation mode of a merchant, stored in the configs table
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+struct SimulationMode {
+    enabled: bool,
+    connector: Option<enums::RoutableConnectors>,
+    merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+}
+
+impl SimulationMode {
+    fn get_config_key(merchant_id: &common_utils::id_type::MerchantId) -> String {
+        format!("simulation_mode_{}", merchant_id.get_string_repr())
+    }
+
+    /// Key of the parsed simulation mode in the routing cache
+    fn get_cache_key(merchant_id: &common_utils::id_type::MerchantId) -> String {
+        format!("simulation_mode_routing_{}", merchant_id.get_string_repr())
+    }
+
+    fn to_response(&self) -> simulation_types::SimulationModeResponse {
+        simulation_types::SimulationModeResponse {
+            enabled: self.enabled,
+            merchant_connector_id: self.merchant_connector_id.clone(),
+            connector_name: self.connector.map(|connector| connector.to_string()),
+        }
+    }
+}
+
+fn get_load_test_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    load_test_id: &str,
+) -> String {
+    format!(
+        "{LOAD_TEST_PREFIX}_{}_{load_test_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_load_test_counts_key(
+    merchant_id: &common_utils::id_type::MerchantId,
+    load_test_id: &str,
+) -> String {
+    format!(
+        "{LOAD_TEST_COUNTS_PREFIX}_{}_{load_test_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_active_load_test_key(merchant_id: &common_utils::id_type::MerchantId) -> String {
+    format!(
+        "{ACTIVE_LOAD_TEST_PREFIX}_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn is_dummy_connector(connector: enums::RoutableConnectors) -> bool {
+    matches!(
+        connector,
+        enums::RoutableConnectors::DummyConnector1
+            | enums::RoutableConnectors::DummyConnector2
+            | enums::RoutableConnectors::DummyConnector3
+            | enums::RoutableConnectors::DummyConnector4
+            | enums::RoutableConnectors::DummyConnector5
+            | enums::RoutableConnectors::DummyConnector6
+            | enums::RoutableConnectors::DummyConnector7
+    )
+}
+
+async fn find_simulation_mode(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> RouterResult<SimulationMode> {
+    let db = &*state.store;
+    let find_simulation_mode_from_db = || async {
+        match db
+            .find_config_by_key_from_db(&SimulationMode::get_config_key(merchant_id))
+            .await
+        {
+            Ok(config) => config
+                .config
+                .parse_struct("SimulationMode")
+                .change_context(errors::StorageError::DeserializationFailed)
+                .attach_printable("Failed to parse the simulation mode"),
+            // The simulation mode is disabled for merchants without the config
+            Err(error) if error.current_context().is_db_not_found() => {
+                Ok(SimulationMode::default())
+            }
+            Err(error) => Err(error),
+        }
+    };
+
+    // The simulation mode is looked up on every routing decision, so the parsed mode is cached and
+    // redacted whenever it is updated
+    cache::get_or_populate_in_memory(
+        db.get_cache_store().as_ref(),
+        &SimulationMode::get_cache_key(merchant_id),
+        find_simulation_mode_from_db,
+        &ROUTING_CACHE,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the simulation mode")
+}
+
+/// Returns the synthetic connector which the payments of the merchant are to be routed to, if the
+/// simulation mode is enabled for the merchant
+#[instrument(skip_all)]
+pub async fn get_simulation_connector(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> Option<api_routing::RoutableConnectorChoice> {
+    let simulation_mode = find_simulation_mode(state, merchant_id)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to find the simulation mode"))
+        .ok()?;
+
+    match simulation_mode {
+        SimulationMode {
+            enabled: true,
+            connector: Some(connector),
+            merchant_connector_id,
+        } => Some(api_routing::RoutableConnectorChoice {
+            choice_kind: api_routing::RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id,
+        }),
+        _ => None,
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_simulation_mode(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<simulation_types::SimulationModeResponse> {
+    let simulation_mode = find_simulation_mode(&state, merchant_account.get_id()).await?;
+
+    Ok(ApplicationResponse::Json(simulation_mode.to_response()))
+}
+
+/// Enables or disables the simulation mode of the merchant. While the simulation mode is enabled,
+/// all the payments of the merchant are routed to the configured dummy connector.
+#[instrument(skip_all)]
+pub async fn update_simulation_mode(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: simulation_types::SimulationModeUpdateRequest,
+) -> RouterResponse<simulation_types::SimulationModeResponse> {
+    let merchant_id = merchant_account.get_id();
+    let simulation_mode = if request.enabled {
+        let merchant_connector_id = request.merchant_connector_id.ok_or(
+            errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "merchant_connector_id",
+            },
+        )?;
+        let merchant_connector_account = state
+            .store
+            .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+                &(&state).into(),
+                merchant_id,
+                &merchant_connector_id,
+                &key_store,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                id: merchant_connector_id.get_string_repr().to_string(),
+            })?;
+
+        let connector =
+            enums::RoutableConnectors::from_str(&merchant_connector_account.connector_name)
+                .ok()
+                .filter(|connector| is_dummy_connector(*connector))
+                .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "The merchant connector account must be of a dummy connector"
+                        .to_string(),
+                })?;
+
+        SimulationMode {
+            enabled: true,
+            connector: Some(connector),
+            merchant_connector_id: Some(merchant_connector_id),
+        }
+    } else {
+        SimulationMode::default()
+    };
+
+    let key = SimulationMode::get_config_key(merchant_id);
+    let config = simulation_mode
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the simulation mode")?;
+
+    match state.store.find_config_by_key_from_db(&key).await {
+        Ok(_) => state
+            .store
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the simulation mode")?,
+        Err(error) if error.current_context().is_db_not_found() => state
+            .store
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the simulation mode")?,
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the simulation mode"))
+        }
+    };
+
+    cache::publish_into_redact_channel(
+        state.store.get_cache_store().as_ref(),
+        [CacheKind::Routing(
+            SimulationMode::get_cache_key(merchant_id).into(),
+        )],
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to redact the cached simulation mode")?;
+
+    logger::info!(
+        enabled = simulation_mode.enabled,
+        connector = ?simulation_mode.connector,
+        "Updated the simulation mode of the merchant"
+    );
+
+    Ok(ApplicationResponse::Json(simulation_mode.to_response()))
+}
+
+/// Builds the stages of a load test from the payments created by the merchant, as recorded in the
+/// API logs of the merchant. Consecutive seconds with the same rate of payments are merged into a
+/// single stage.
+async fn get_recorded_stages(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    created_after: Option<time::PrimitiveDateTime>,
+    created_before: Option<time::PrimitiveDateTime>,
+    rate_multiplier: u32,
+) -> RouterResult<Vec<simulation_types::LoadTestStage>> {
+    let api_logs = api_logs::find_api_logs(
+        state,
+        merchant_id,
+        api_log_types::ApiLogsListConstraints {
+            url_path: Some("/payments".to_string()),
+            created_after,
+            created_before,
+            limit: Some(u16::MAX),
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    // The API logs are ordered with the most recent request first
+    let timestamps = api_logs
+        .iter()
+        .rev()
+        .filter(|api_log| api_log.api_flow == Flow::PaymentsCreate.to_string())
+        .map(|api_log| api_log.created_at.assume_utc().unix_timestamp())
+        .collect::<Vec<_>>();
+
+    let (Some(first), Some(last)) = (timestamps.first(), timestamps.last()) else {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "No payments were recorded for the merchant in the given time range"
+                .to_string(),
+        }));
+    };
+
+    let recorded_duration = usize::try_from(last - first + 1)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to compute the duration of the recorded payments")?;
+    let mut requests_per_second = vec![0_u32; recorded_duration];
+    for timestamp in &timestamps {
+        if let Some(requests) = usize::try_from(timestamp - first)
+            .ok()
+            .and_then(|second| requests_per_second.get_mut(second))
+        {
+            *requests += 1;
+        }
+    }
+
+    let mut stages: Vec<simulation_types::LoadTestStage> = Vec::new();
+    for requests in requests_per_second {
+        let requests = requests.saturating_mul(rate_multiplier);
+        match stages.last_mut() {
+            Some(stage) if stage.requests_per_second == requests => stage.duration_in_secs += 1,
+            _ => stages.push(simulation_types::LoadTestStage {
+                duration_in_secs: 1,
+                requests_per_second: requests,
+            }),
+        }
+    }
+
+    Ok(stages)
+}
+
+fn validate_load_test_stages(
+    state: &SessionState,
+    stages: &[simulation_types::LoadTestStage],
+) -> RouterResult<u64> {
+    let config = &state.conf.simulation;
+
+    if let Some(stage) = stages
+        .iter()
+        .find(|stage| stage.requests_per_second > config.max_requests_per_second)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "`requests_per_second` of {} exceeds the maximum of {}",
+                stage.requests_per_second, config.max_requests_per_second
+            ),
+        }));
+    }
+
+    let planned_requests = stages
+        .iter()
+        .map(|stage| u64::from(stage.duration_in_secs) * u64::from(stage.requests_per_second))
+        .sum::<u64>();
+
+    if planned_requests == 0 || planned_requests > config.max_load_test_requests {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "A load test must create between 1 and {} payments",
+                config.max_load_test_requests
+            ),
+        }));
+    }
+
+    Ok(planned_requests)
+}
+
+/// Starts a load test, which creates payments for the merchant in the background following the
+/// traffic shape. The payments are created through the payments core on behalf of the merchant, so
+/// that no credentials of the merchant are held by the background task.
+#[instrument(skip_all)]
+pub async fn create_load_test(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: simulation_types::LoadTestCreateRequest,
+) -> RouterResponse<simulation_types::LoadTestResponse> {
+    let merchant_id = merchant_account.get_id();
+
+    // Load tests are only run against the synthetic connector, so that no real payments are made
+    if !find_simulation_mode(&state, merchant_id).await?.enabled {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The simulation mode must be enabled to run load tests".to_string(),
+        }));
+    }
+
+    let stages = match request.shape {
+        simulation_types::LoadTestTrafficShape::Stages { stages } => stages,
+        simulation_types::LoadTestTrafficShape::Recorded {
+            created_after,
+            created_before,
+            rate_multiplier,
+        } => {
+            get_recorded_stages(
+                &state,
+                merchant_id,
+                created_after,
+                created_before,
+                rate_multiplier.unwrap_or(1),
+            )
+            .await?
+        }
+    };
+    let planned_requests = validate_load_test_stages(&state, &stages)?;
+
+    let load_test_id = generate_id(consts::ID_LENGTH, "load_test");
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    // Only a single load test is run for a merchant at a time, the lock expires once the load test
+    // would have completed even if the load test is interrupted
+    let total_duration_in_secs = stages
+        .iter()
+        .map(|stage| i64::from(stage.duration_in_secs))
+        .sum::<i64>();
+    let request_timeout_in_secs = i64::try_from(state.conf.simulation.request_timeout_in_secs)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid request timeout for load tests")?;
+    let reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &get_active_load_test_key(merchant_id),
+            load_test_id.clone(),
+            Some(total_duration_in_secs + request_timeout_in_secs),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to acquire the load test lock")?;
+    if reply != SetnxReply::KeySet {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A load test is already running for the merchant".to_string(),
+        }));
+    }
+
+    let load_test = simulation_types::LoadTestResponse {
+        load_test_id: load_test_id.clone(),
+        status: simulation_types::LoadTestStatus::Running,
+        stages,
+        planned_requests,
+        sent_requests: 0,
+        succeeded_requests: 0,
+        failed_requests: 0,
+        created_at: common_utils::date_time::now(),
+        completed_at: None,
+    };
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_load_test_key(merchant_id, &load_test_id),
+            &load_test,
+            LOAD_TEST_RETENTION_IN_SECS,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to store the load test")?;
+
+    let counts_key = get_load_test_counts_key(merchant_id, &load_test_id);
+    redis_conn
+        .increment_fields_in_hash(
+            &counts_key,
+            &[
+                ("sent_requests", 0),
+                ("succeeded_requests", 0),
+                ("failed_requests", 0),
+            ],
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to initialize the load test counts")?;
+    redis_conn
+        .set_expiry(&counts_key, LOAD_TEST_RETENTION_IN_SECS)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to set expiry for the load test counts")?;
+
+    logger::info!(%load_test_id, planned_requests, "Starting the load test");
+    tokio::spawn(
+        run_load_test(
+            state.clone(),
+            merchant_account.clone(),
+            key_store,
+            load_test.clone(),
+            request.amount,
+            request.currency,
+        )
+        .in_current_span(),
+    );
+
+    Ok(ApplicationResponse::Json(load_test))
+}
+
+async fn run_load_test(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    mut load_test: simulation_types::LoadTestResponse,
+    amount: MinorUnit,
+    currency: enums::Currency,
+) {
+    let merchant_id = merchant_account.get_id().clone();
+    let counts_key = get_load_test_counts_key(&merchant_id, &load_test.load_test_id);
+    let mut payments = Vec::new();
+    let mut interval = tokio::time::interval(std::time::Duration::from_secs(1));
+
+    for stage in &load_test.stages {
+        for _ in 0..stage.duration_in_secs {
+            interval.tick().await;
+            for _ in 0..stage.requests_per_second {
+                payments.push(tokio::spawn(
+                    send_load_test_payment(
+                        state.clone(),
+                        merchant_account.clone(),
+                        key_store.clone(),
+                        counts_key.clone(),
+                        load_test.load_test_id.clone(),
+                        amount,
+                        currency,
+                    )
+                    .in_current_span(),
+                ));
+            }
+        }
+    }
+    futures::future::join_all(payments).await;
+
+    load_test.status = simulation_types::LoadTestStatus::Completed;
+    load_test.completed_at = Some(common_utils::date_time::now());
+    logger::info!(load_test_id = %load_test.load_test_id, "Completed the load test");
+
+    let result = async {
+        let redis_conn = state.store.get_redis_conn()?;
+        redis_conn
+            .serialize_and_set_key_with_expiry(
+                &get_load_test_key(&merchant_id, &load_test.load_test_id),
+                &load_test,
+                LOAD_TEST_RETENTION_IN_SECS,
+            )
+            .await?;
+        redis_conn
+            .delete_key(&get_active_load_test_key(&merchant_id))
+            .await
+            .map(|_| ())
+    }
+    .await;
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to mark the load test as completed");
+    }
+}
+
+async fn send_load_test_payment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    counts_key: String,
+    load_test_id: String,
+    amount: MinorUnit,
+    currency: enums::Currency,
+) {
+    let payment_request = serde_json::json!({
+        "amount": amount,
+        "currency": currency,
+        "confirm": true,
+        "capture_method": "automatic",
+        "payment_method": "card",
+        "payment_method_type": "credit",
+        "payment_method_data": {
+            "card": {
+                "card_number": LOAD_TEST_CARD_NUMBER,
+                "card_exp_month": "12",
+                "card_exp_year": "2050",
+                "card_holder_name": "Load Test",
+                "card_cvc": "123"
+            }
+        },
+        "metadata": {
+            "load_test_id": load_test_id
+        }
+    });
+    let mut payment_request = match serde_json::from_value::<api::PaymentsRequest>(payment_request)
+    {
+        Ok(payment_request) => payment_request,
+        Err(error) => {
+            logger::error!(?error, "Failed to build the load test payment");
+            return;
+        }
+    };
+    payment_request.payment_id = Some(api::PaymentIdType::PaymentIntentId(
+        common_utils::id_type::PaymentId::default(),
+    ));
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection for the load test");
+            return;
+        }
+    };
+    if let Err(error) = redis_conn
+        .increment_fields_in_hash(&counts_key, &[("sent_requests", 1)])
+        .await
+    {
+        logger::error!(?error, "Failed to count the sent load test payment");
+    }
+
+    let req_state = state.get_req_state();
+    let payment = payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account,
+        None,
+        key_store,
+        payments::operations::PaymentCreate,
+        payment_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        HeaderPayload::default(),
+    );
+    let succeeded = match tokio::time::timeout(
+        std::time::Duration::from_secs(state.conf.simulation.request_timeout_in_secs),
+        Box::pin(payment),
+    )
+    .await
+    {
+        Ok(Ok(_)) => true,
+        Ok(Err(error)) => {
+            logger::error!(?error, "Failed to create the load test payment");
+            false
+        }
+        Err(_) => {
+            logger::error!("Timed out creating the load test payment");
+            false
+        }
+    };
+    let outcome = if succeeded {
+        "succeeded_requests"
+    } else {
+        "failed_requests"
+    };
+    if let Err(error) = redis_conn
+        .increment_fields_in_hash(&counts_key, &[(outcome, 1)])
+        .await
+    {
+        logger::error!(
+            ?error,
+            "Failed to count the outcome of the load test payment"
+        );
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_load_test(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    load_test_id: String,
+) -> RouterResponse<simulation_types::LoadTestResponse> {
+    let merchant_id = merchant_account.get_id();
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let mut load_test = redis_conn
+        .get_and_deserialize_key::<simulation_types::LoadTestResponse>(
+            &get_load_test_key(merchant_id, &load_test_id),
+            "LoadTestResponse",
+        )
+        .await
+        .map_err(|error| match error.current_context() {
+            RedisError::NotFound => {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: format!("Load test with the given id '{load_test_id}' does not exist"),
+                })
+            }
+            _ => error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the load test"),
+        })?;
+
+    let counts = redis_conn
+        .get_hash_fields::<HashMap<String, u64>>(&get_load_test_counts_key(
+            merchant_id,
+            &load_test_id,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the load test counts")?;
+    let get_count = |field: &str| counts.get(field).copied().unwrap_or_default();
+    load_test.sent_requests = get_count("sent_requests");
+    load_test.succeeded_requests = get_count("succeeded_requests");
+    load_test.failed_requests = get_count("failed_requests");
+
+    Ok(ApplicationResponse::Json(load_test))
+}
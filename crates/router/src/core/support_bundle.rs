@@ -0,0 +1,411 @@
+use api_models::{
+    analytics::connector_events::ConnectorEventsRequest, support_bundle as support_bundle_types,
+    webhook_events::OutgoingWebhookResponseContent,
+};
+use common_utils::{date_time, ext_traits::StringExt, id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use masking::{Maskable, PeekInterface};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    core::{
+        connector_debug_capture,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    db::StorageInterface,
+    headers,
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage, storage::enums},
+};
+
+/// Scheduler tasks created for every attempt of a payment
+const PAYMENT_ATTEMPT_TASKS: [(storage::ProcessTrackerRunner, &str); 2] = [
+    (
+        storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+        "PAYMENTS_SYNC",
+    ),
+    (
+        storage::ProcessTrackerRunner::PaymentsConfirmWorkflow,
+        "PAYMENTS_CONFIRM",
+    ),
+];
+
+/// Scheduler tasks created for every refund of a payment
+const REFUND_TASKS: [&str; 2] = ["EXECUTE_REFUND", "SYNC_REFUND"];
+
+/// Assembles the diagnostic data of a payment into a single document, to be attached to
+/// escalations. The sections other than the payment and its attempts are collected on a best
+/// effort basis, the sections which could not be collected are listed in the warnings.
+#[instrument(skip_all)]
+pub async fn retrieve_support_bundle(
+    state: SessionState,
+    request: support_bundle_types::SupportBundleRequest,
+) -> RouterResponse<support_bundle_types::SupportBundle> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = &request.merchant_id;
+    let payment_id = &request.payment_id;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            payment_id,
+            merchant_id,
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempts = db
+        .find_attempts_by_merchant_id_payment_id(merchant_id, payment_id, storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the attempts of the payment")?;
+
+    let mut warnings = Vec::new();
+    let refunds = collect_section(
+        &mut warnings,
+        "refunds",
+        db.find_refund_by_payment_id_merchant_id(payment_id, merchant_id, storage_scheme)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+    );
+    let disputes = collect_section(
+        &mut warnings,
+        "disputes",
+        db.find_disputes_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+    );
+    let connector_events = collect_section(
+        &mut warnings,
+        "connector_events",
+        find_connector_events(&state, merchant_id, payment_id).await,
+    );
+    let failed_connector_calls = collect_section(
+        &mut warnings,
+        "failed_connector_calls",
+        connector_debug_capture::find_connector_debug_captures(&state, merchant_id, payment_id)
+            .await,
+    );
+    let webhook_deliveries = collect_section(
+        &mut warnings,
+        "webhook_deliveries",
+        find_webhook_deliveries(&state, merchant_id, payment_id, &key_store).await,
+    );
+
+    let mut task_ids = payment_attempts
+        .iter()
+        .flat_map(|payment_attempt| {
+            PAYMENT_ATTEMPT_TASKS.iter().map(|(runner, task)| {
+                pt_utils::get_process_tracker_id(
+                    *runner,
+                    task,
+                    payment_attempt.get_id(),
+                    merchant_id,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    task_ids.extend(refunds.iter().flat_map(|refund| {
+        REFUND_TASKS.iter().map(|task| {
+            format!(
+                "{}_{task}_{}",
+                storage::ProcessTrackerRunner::RefundWorkflowRouter,
+                refund.internal_reference_id
+            )
+        })
+    }));
+    task_ids.extend(
+        webhook_deliveries
+            .iter()
+            .filter(|delivery| {
+                delivery.delivery_attempt == Some(enums::WebhookDeliveryAttempt::InitialAttempt)
+            })
+            .map(|delivery| {
+                pt_utils::get_process_tracker_id(
+                    storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+                    "OUTGOING_WEBHOOK_RETRY",
+                    &delivery.event_information.event_id,
+                    merchant_id,
+                )
+            }),
+    );
+    let scheduler_tasks = collect_section(
+        &mut warnings,
+        "scheduler_tasks",
+        find_scheduler_tasks(db, &task_ids).await,
+    );
+
+    let routing_trace = payment_attempts
+        .iter()
+        .map(
+            |payment_attempt| support_bundle_types::SupportBundleRoutingTrace {
+                attempt_id: payment_attempt.get_id().to_owned(),
+                connector: payment_attempt.connector.clone(),
+                merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+                straight_through_algorithm: payment_attempt.straight_through_algorithm.clone(),
+                routing_decision: payment_attempt.routing_decision.clone(),
+            },
+        )
+        .collect();
+    let timeline = build_timeline(&payment_intent, &payment_attempts, &refunds, &disputes);
+
+    let support_bundle = support_bundle_types::SupportBundle {
+        payment_id: payment_id.clone(),
+        merchant_id: merchant_id.clone(),
+        generated_at: date_time::now(),
+        timeline,
+        routing_trace,
+        connector_events,
+        failed_connector_calls,
+        webhook_deliveries,
+        scheduler_tasks,
+        warnings,
+    };
+    let content_disposition = format!(
+        "attachment; filename=\"support_bundle_{}.json\"",
+        payment_id.get_string_repr()
+    );
+
+    Ok(ApplicationResponse::JsonWithHeaders((
+        support_bundle,
+        vec![(
+            headers::CONTENT_DISPOSITION.to_string(),
+            Maskable::new_normal(content_disposition),
+        )],
+    )))
+}
+
+fn collect_section<T>(
+    warnings: &mut Vec<String>,
+    section: &str,
+    result: RouterResult<Vec<T>>,
+) -> Vec<T> {
+    result.unwrap_or_else(|error| {
+        logger::error!(
+            ?error,
+            section,
+            "Failed to collect the support bundle section"
+        );
+        warnings.push(format!("Failed to collect the {section}"));
+        Vec::new()
+    })
+}
+
+async fn find_connector_events(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<Vec<serde_json::Value>> {
+    let request = ConnectorEventsRequest {
+        payment_id: payment_id.clone(),
+        refund_id: None,
+        dispute_id: None,
+    };
+
+    analytics::connector_events::connector_events_core(&state.pool, request, merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the connector events of the payment")?
+        .into_iter()
+        .map(|connector_event| {
+            serde_json::to_value(connector_event)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the connector event")
+        })
+        .collect()
+}
+
+/// Lists every delivery attempt of the webhooks sent for the payment, leaving out the bodies and
+/// headers of the requests and responses
+async fn find_webhook_deliveries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Vec<support_bundle_types::SupportBundleWebhookDelivery>> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let initial_events = db
+        .list_initial_events_by_merchant_id_primary_object_id(
+            key_manager_state,
+            merchant_id,
+            payment_id.get_string_repr(),
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the webhook events of the payment")?;
+
+    let mut deliveries = Vec::new();
+    for initial_event in initial_events {
+        let events = db
+            .list_events_by_merchant_id_initial_attempt_id(
+                key_manager_state,
+                merchant_id,
+                &initial_event.event_id,
+                key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list the delivery attempts of the webhook event")?;
+
+        for event in events {
+            let response = event.response.as_ref().and_then(|response| {
+                response
+                    .peek()
+                    .parse_struct::<OutgoingWebhookResponseContent>(
+                        "OutgoingWebhookResponseContent",
+                    )
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to parse the webhook event response")
+                    })
+                    .ok()
+            });
+            let delivery_attempt = event.delivery_attempt;
+
+            deliveries.push(support_bundle_types::SupportBundleWebhookDelivery {
+                event_information: event.try_into()?,
+                delivery_attempt,
+                status_code: response.as_ref().and_then(|response| response.status_code),
+                error_message: response.and_then(|response| response.error_message),
+            });
+        }
+    }
+
+    Ok(deliveries)
+}
+
+async fn find_scheduler_tasks(
+    db: &dyn StorageInterface,
+    task_ids: &[String],
+) -> RouterResult<Vec<support_bundle_types::SupportBundleSchedulerTask>> {
+    let mut tasks = Vec::new();
+    for task_id in task_ids {
+        let Some(task) = db
+            .find_process_by_id(task_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the scheduler task")?
+        else {
+            continue;
+        };
+
+        tasks.push(support_bundle_types::SupportBundleSchedulerTask {
+            id: task.id,
+            name: task.name,
+            runner: task.runner,
+            status: task.status.to_string(),
+            business_status: task.business_status,
+            retry_count: task.retry_count,
+            schedule_time: task.schedule_time,
+            created_at: task.created_at,
+            updated_at: task.updated_at,
+        });
+    }
+
+    Ok(tasks)
+}
+
+fn build_timeline(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempts: &[storage::PaymentAttempt],
+    refunds: &[storage::Refund],
+    disputes: &[storage::Dispute],
+) -> Vec<support_bundle_types::SupportBundleTimelineEntry> {
+    let payment = support_bundle_types::SupportBundleTimelineEntry {
+        object: support_bundle_types::SupportBundleObject::Payment,
+        object_id: payment_intent.get_id().get_string_repr().to_owned(),
+        status: payment_intent.status.to_string(),
+        connector: None,
+        connector_reference_id: None,
+        connector_request_id: None,
+        correlation_id: None,
+        amount: Some(payment_intent.amount),
+        currency: payment_intent.currency,
+        error_code: None,
+        error_message: None,
+        created_at: payment_intent.created_at,
+        modified_at: payment_intent.modified_at,
+    };
+    let attempts = payment_attempts.iter().map(|payment_attempt| {
+        support_bundle_types::SupportBundleTimelineEntry {
+            object: support_bundle_types::SupportBundleObject::PaymentAttempt,
+            object_id: payment_attempt.get_id().to_owned(),
+            status: payment_attempt.status.to_string(),
+            connector: payment_attempt.connector.clone(),
+            connector_reference_id: payment_attempt.connector_transaction_id.clone(),
+            connector_request_id: payment_attempt.connector_request_id.clone(),
+            correlation_id: payment_attempt.correlation_id.clone(),
+            amount: Some(payment_attempt.net_amount.get_total_amount()),
+            currency: payment_attempt.currency,
+            error_code: payment_attempt.error_code.clone(),
+            error_message: payment_attempt.error_message.clone(),
+            created_at: payment_attempt.created_at,
+            modified_at: payment_attempt.modified_at,
+        }
+    });
+    let refunds = refunds
+        .iter()
+        .map(|refund| support_bundle_types::SupportBundleTimelineEntry {
+            object: support_bundle_types::SupportBundleObject::Refund,
+            object_id: refund.refund_id.clone(),
+            status: refund.refund_status.to_string(),
+            connector: Some(refund.connector.clone()),
+            connector_reference_id: refund
+                .connector_refund_id
+                .as_ref()
+                .map(|connector_refund_id| connector_refund_id.get_id().to_owned()),
+            connector_request_id: None,
+            correlation_id: None,
+            amount: Some(refund.refund_amount),
+            currency: Some(refund.currency),
+            error_code: refund.refund_error_code.clone(),
+            error_message: refund.refund_error_message.clone(),
+            created_at: refund.created_at,
+            modified_at: refund.modified_at,
+        });
+    let disputes =
+        disputes
+            .iter()
+            .map(|dispute| support_bundle_types::SupportBundleTimelineEntry {
+                object: support_bundle_types::SupportBundleObject::Dispute,
+                object_id: dispute.dispute_id.clone(),
+                status: dispute.dispute_status.to_string(),
+                connector: Some(dispute.connector.clone()),
+                connector_reference_id: Some(dispute.connector_dispute_id.clone()),
+                connector_request_id: None,
+                correlation_id: None,
+                amount: Some(MinorUnit::new(dispute.dispute_amount)),
+                currency: dispute.currency.parse().ok(),
+                error_code: dispute.connector_reason_code.clone(),
+                error_message: dispute.connector_reason.clone(),
+                created_at: dispute.created_at,
+                modified_at: dispute.modified_at,
+            });
+
+    let mut timeline = std::iter::once(payment)
+        .chain(attempts)
+        .chain(refunds)
+        .chain(disputes)
+        .collect::<Vec<_>>();
+    timeline.sort_by_key(|entry| entry.created_at);
+    timeline
+}
@@ -0,0 +1,259 @@
+use api_models::test_clocks as test_clock_types;
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    generate_id,
+};
+use diesel_models::{configs, enums as storage_enums};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing, Env};
+
+use crate::{
+    consts,
+    core::errors::{self, utils::StorageErrorExt, RouterResponse, RouterResult},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{domain, storage},
+};
+
+/// Number of scheduled tasks fetched at a time while advancing a test clock
+const TASKS_PAGE_SIZE: i64 = 100;
+
+/// Test clock of a merchant, stored in the configs table.
+///
+/// Advancing a test clock triggers the scheduled tasks of the attached resources which would have
+/// become due, had the time passed by as much as the clock has been advanced since it started.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+struct TestClock {
+    clock_id: String,
+    name: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    start_time: time::PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    frozen_time: time::PrimitiveDateTime,
+    resource_ids: Vec<String>,
+}
+
+impl TestClock {
+    fn get_config_key(merchant_id: &common_utils::id_type::MerchantId, clock_id: &str) -> String {
+        format!("test_clock_{}_{clock_id}", merchant_id.get_string_repr())
+    }
+
+    fn to_response(&self, triggered_tasks: usize) -> test_clock_types::TestClockResponse {
+        test_clock_types::TestClockResponse {
+            clock_id: self.clock_id.clone(),
+            name: self.name.clone(),
+            start_time: self.start_time,
+            frozen_time: self.frozen_time,
+            resource_ids: self.resource_ids.clone(),
+            triggered_tasks,
+        }
+    }
+}
+
+fn validate_sandbox_environment() -> RouterResult<()> {
+    match router_env::which() {
+        Env::Production => Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Test clocks are only available in the sandbox environment".to_string(),
+        })),
+        Env::Development | Env::Sandbox => Ok(()),
+    }
+}
+
+async fn find_test_clock(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    clock_id: &str,
+) -> RouterResult<TestClock> {
+    state
+        .store
+        .find_config_by_key(&TestClock::get_config_key(merchant_id, clock_id))
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Test clock with the given id '{clock_id}' does not exist"),
+        })?
+        .config
+        .parse_struct("TestClock")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the test clock")
+}
+
+async fn update_test_clock(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    test_clock: &TestClock,
+) -> RouterResult<()> {
+    let config = test_clock
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the test clock")?;
+
+    state
+        .store
+        .update_config_by_key(
+            &TestClock::get_config_key(merchant_id, &test_clock.clock_id),
+            configs::ConfigUpdate::Update {
+                config: Some(config),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the test clock")?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn create_test_clock(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: test_clock_types::TestClockCreateRequest,
+) -> RouterResponse<test_clock_types::TestClockResponse> {
+    validate_sandbox_environment()?;
+
+    let frozen_time = request
+        .frozen_time
+        .unwrap_or_else(common_utils::date_time::now);
+    let test_clock = TestClock {
+        clock_id: generate_id(consts::ID_LENGTH, "clock"),
+        name: request.name,
+        start_time: frozen_time,
+        frozen_time,
+        resource_ids: Vec::new(),
+    };
+    let config = test_clock
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the test clock")?;
+
+    state
+        .store
+        .insert_config(configs::ConfigNew {
+            key: TestClock::get_config_key(merchant_account.get_id(), &test_clock.clock_id),
+            config,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the test clock")?;
+
+    Ok(ApplicationResponse::Json(test_clock.to_response(0)))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_test_clock(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    clock_id: String,
+) -> RouterResponse<test_clock_types::TestClockResponse> {
+    validate_sandbox_environment()?;
+
+    let test_clock = find_test_clock(&state, merchant_account.get_id(), &clock_id).await?;
+
+    Ok(ApplicationResponse::Json(test_clock.to_response(0)))
+}
+
+#[instrument(skip_all)]
+pub async fn attach_resources_to_test_clock(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    clock_id: String,
+    request: test_clock_types::TestClockAttachRequest,
+) -> RouterResponse<test_clock_types::TestClockResponse> {
+    validate_sandbox_environment()?;
+
+    let mut test_clock = find_test_clock(&state, merchant_account.get_id(), &clock_id).await?;
+    for resource_id in request.resource_ids {
+        if !test_clock.resource_ids.contains(&resource_id) {
+            test_clock.resource_ids.push(resource_id);
+        }
+    }
+    update_test_clock(&state, merchant_account.get_id(), &test_clock).await?;
+
+    Ok(ApplicationResponse::Json(test_clock.to_response(0)))
+}
+
+/// Advances the test clock, and triggers the scheduled tasks of the attached resources which have
+/// become due. The tasks are scheduled to be picked up by the scheduler immediately.
+#[instrument(skip_all)]
+pub async fn advance_test_clock(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    clock_id: String,
+    request: test_clock_types::TestClockAdvanceRequest,
+) -> RouterResponse<test_clock_types::TestClockResponse> {
+    validate_sandbox_environment()?;
+
+    let merchant_id = merchant_account.get_id();
+    let mut test_clock = find_test_clock(&state, merchant_id, &clock_id).await?;
+    if request.frozen_time <= test_clock.frozen_time {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "`frozen_time` must be later than the current time of the test clock"
+                .to_string(),
+        }));
+    }
+    test_clock.frozen_time = request.frozen_time;
+
+    let now = common_utils::date_time::now();
+    let due_before = now + (test_clock.frozen_time - test_clock.start_time);
+    let db = state.store.as_scheduler();
+    let mut triggered_tasks = 0;
+
+    // Process tracker ids end with the identifiers of the resource and of the merchant
+    for resource_id in &test_clock.resource_ids {
+        let id_suffix = format!("_{resource_id}_{}", merchant_id.get_string_repr());
+        for status in [
+            storage_enums::ProcessTrackerStatus::New,
+            storage_enums::ProcessTrackerStatus::Pending,
+        ] {
+            let mut after_id = None;
+            loop {
+                let processes = db
+                    .find_processes_by_id_suffix_time_status(
+                        &id_suffix,
+                        now,
+                        due_before,
+                        status,
+                        after_id.take(),
+                        TASKS_PAGE_SIZE,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to find the scheduled tasks")?;
+                let is_last_page = processes.len() < usize::try_from(TASKS_PAGE_SIZE).unwrap_or(0);
+                after_id = processes.last().map(|process| process.id.clone());
+
+                for process in processes {
+                    let process_id = process.id.clone();
+                    let retry_count = process.retry_count;
+                    db.update_process(
+                        process,
+                        storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                            status,
+                            retry_count,
+                            schedule_time: now,
+                        },
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to trigger the scheduled task")?;
+
+                    logger::info!(
+                        %process_id,
+                        %clock_id,
+                        "Triggered the scheduled task by the test clock"
+                    );
+                    triggered_tasks += 1;
+                }
+
+                if is_last_page {
+                    break;
+                }
+            }
+        }
+    }
+
+    update_test_clock(&state, merchant_id, &test_clock).await?;
+
+    Ok(ApplicationResponse::Json(
+        test_clock.to_response(triggered_tasks),
+    ))
+}
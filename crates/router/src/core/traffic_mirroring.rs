@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use actix_web::HttpRequest;
+use common_utils::{
+    id_type,
+    request::{Method, RequestBuilder, RequestContent},
+};
+use error_stack::report;
+use masking::Mask;
+use rand::Rng;
+use router_env::{
+    instrument, logger,
+    tracing::{self, Instrument},
+};
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+use crate::core::simulation;
+use crate::{
+    core::errors::{self, RouterResult},
+    headers,
+    routes::SessionState,
+};
+
+fn is_mirrored_request(request: &HttpRequest) -> bool {
+    request
+        .headers()
+        .contains_key(headers::X_MIRRORED_REQUEST_ID)
+}
+
+fn to_request_method(method: &actix_web::http::Method) -> Option<Method> {
+    match *method {
+        actix_web::http::Method::GET => Some(Method::Get),
+        actix_web::http::Method::POST => Some(Method::Post),
+        actix_web::http::Method::PUT => Some(Method::Put),
+        actix_web::http::Method::DELETE => Some(Method::Delete),
+        actix_web::http::Method::PATCH => Some(Method::Patch),
+        _ => None,
+    }
+}
+
+/// Replaces the fields of the masked request which have a placeholder configured, so that the
+/// mirrored request passes the validations of the staging deployment
+fn replace_masked_fields(value: &mut serde_json::Value, placeholders: &HashMap<String, String>) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (key, field) in fields.iter_mut() {
+                match placeholders.get(key) {
+                    Some(placeholder) if field.is_string() => {
+                        *field = serde_json::Value::String(placeholder.clone());
+                    }
+                    _ => replace_masked_fields(field, placeholders),
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| replace_masked_fields(item, placeholders)),
+        _ => (),
+    }
+}
+
+/// Whether the request of the flow is to be mirrored to the staging deployment. Mirrored requests
+/// are never mirrored again.
+pub fn should_mirror_request(state: &SessionState, flow: &str, request: &HttpRequest) -> bool {
+    let config = &state.conf.traffic_mirroring;
+    config.enabled
+        && config.flows.contains(flow)
+        && !is_mirrored_request(request)
+        && rand::thread_rng().gen_range(0..100u8) < config.sampling_percentage
+}
+
+/// Replays a sanitized copy of the request on the staging deployment in the background. The body
+/// of the copy is the masked request, so that no sensitive data leaves the deployment, with the
+/// configured placeholders in place of the masked fields.
+#[instrument(skip_all)]
+pub fn mirror_request(
+    state: &SessionState,
+    request_id: String,
+    request: &HttpRequest,
+    mut masked_request: serde_json::Value,
+) {
+    let Some(method) = to_request_method(request.method()) else {
+        return;
+    };
+    let config = &state.conf.traffic_mirroring;
+    let path_and_query = request
+        .uri()
+        .path_and_query()
+        .map(|path_and_query| path_and_query.as_str())
+        .unwrap_or(request.path());
+
+    let mut request_builder = RequestBuilder::new()
+        .method(method)
+        .url(&format!("{}{path_and_query}", config.target_base_url))
+        .attach_default_headers()
+        .headers(vec![
+            (
+                headers::API_KEY.to_string(),
+                config.api_key.clone().into_masked(),
+            ),
+            (
+                headers::X_MIRRORED_REQUEST_ID.to_string(),
+                request_id.into(),
+            ),
+        ]);
+    if !masked_request.is_null() {
+        replace_masked_fields(&mut masked_request, &config.placeholders);
+        request_builder = request_builder
+            .header(headers::CONTENT_TYPE, "application/json")
+            .set_body(RequestContent::Json(Box::new(masked_request)));
+    }
+    let mirrored_request = request_builder.build();
+
+    let mirror_state = state.clone();
+    tokio::spawn(
+        async move {
+            match mirror_state
+                .api_client
+                .send_request(
+                    &mirror_state,
+                    mirrored_request,
+                    Some(mirror_state.conf.traffic_mirroring.request_timeout_in_secs),
+                    false,
+                )
+                .await
+            {
+                Ok(response) => logger::debug!(
+                    status_code = response.status().as_u16(),
+                    "Mirrored the request to the staging deployment"
+                ),
+                Err(error) => logger::warn!(
+                    ?error,
+                    "Failed to mirror the request to the staging deployment"
+                ),
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+/// Rejects mirrored requests unless this deployment accepts them and the merchant account is in
+/// simulation mode, so that mirrored requests never reach the real connectors
+pub async fn validate_mirrored_request(
+    state: &SessionState,
+    request: &HttpRequest,
+    merchant_id: Option<&id_type::MerchantId>,
+) -> RouterResult<()> {
+    if !is_mirrored_request(request) {
+        return Ok(());
+    }
+    if !state.conf.traffic_mirroring.accept_mirrored_requests {
+        return Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Mirrored requests are not accepted by this deployment".to_string(),
+        }));
+    }
+
+    #[cfg(all(feature = "dummy_connector", feature = "v1"))]
+    if let Some(merchant_id) = merchant_id {
+        if simulation::get_simulation_connector(state, merchant_id)
+            .await
+            .is_some()
+        {
+            return Ok(());
+        }
+    }
+    #[cfg(not(all(feature = "dummy_connector", feature = "v1")))]
+    let _ = merchant_id;
+
+    Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+        message: "Mirrored requests are only accepted for merchant accounts in simulation mode"
+            .to_string(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_masked_fields_are_replaced_with_placeholders() {
+        let placeholders = HashMap::from([
+            ("card_number".to_string(), "4242424242424242".to_string()),
+            ("email".to_string(), "guest@example.com".to_string()),
+        ]);
+        let mut masked_request = json!({
+            "amount": 1000,
+            "email": "*** alloc::string::String ***",
+            "payment_method_data": {
+                "card": {
+                    "card_number": "*** alloc::string::String ***",
+                    "card_exp_month": "*** alloc::string::String ***",
+                }
+            },
+            "order_details": [
+                { "product_name": "shirt", "email": "*** alloc::string::String ***" },
+                { "product_name": "shoes", "email": null }
+            ],
+            "metadata": { "card_number": { "last4": "4242" } }
+        });
+
+        replace_masked_fields(&mut masked_request, &placeholders);
+
+        assert_eq!(
+            masked_request,
+            json!({
+                "amount": 1000,
+                "email": "guest@example.com",
+                "payment_method_data": {
+                    "card": {
+                        "card_number": "4242424242424242",
+                        "card_exp_month": "*** alloc::string::String ***",
+                    }
+                },
+                "order_details": [
+                    { "product_name": "shirt", "email": "guest@example.com" },
+                    { "product_name": "shoes", "email": null }
+                ],
+                "metadata": { "card_number": { "last4": "4242" } }
+            })
+        );
+    }
+
+    #[test]
+    fn test_mirrored_requests_are_recognized() {
+        let request = actix_web::test::TestRequest::default()
+            .insert_header((headers::X_MIRRORED_REQUEST_ID, "req_123"))
+            .to_http_request();
+        assert!(is_mirrored_request(&request));
+
+        let request = actix_web::test::TestRequest::default().to_http_request();
+        assert!(!is_mirrored_request(&request));
+    }
+
+    #[test]
+    fn test_only_api_methods_are_mirrored() {
+        assert_eq!(
+            to_request_method(&actix_web::http::Method::POST),
+            Some(Method::Post)
+        );
+        assert_eq!(
+            to_request_method(&actix_web::http::Method::DELETE),
+            Some(Method::Delete)
+        );
+        assert_eq!(to_request_method(&actix_web::http::Method::OPTIONS), None);
+    }
+}
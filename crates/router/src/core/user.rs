@@ -8,7 +8,7 @@ use api_models::{
     user::{self as user_api, InviteMultipleUserResponse, NameIdUnit},
 };
 use common_enums::EntityType;
-use common_utils::{type_name, types::keymanager::Identifier};
+use common_utils::{id_type, type_name, types::keymanager::Identifier};
 #[cfg(feature = "email")]
 use diesel_models::user_role::UserRoleUpdate;
 use diesel_models::{
@@ -36,7 +36,9 @@ use crate::{
         user_role::ListUserRolesByUserIdPayload,
     },
     routes::{app::ReqState, SessionState},
-    services::{authentication as auth, authorization::roles, openidconnect, ApplicationResponse},
+    services::{
+        authentication as auth, authorization::roles, openidconnect, saml, ApplicationResponse,
+    },
     types::{domain, transformers::ForeignInto},
     utils::{self, user::two_factor_auth as tfa_utils},
 };
@@ -536,10 +538,28 @@ async fn handle_invitation(
         .into());
     }
 
+    let requestor_role_info = roles::RoleInfo::from_role_id_in_merchant_scope(
+        state,
+        &user_from_token.role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)?;
+
+    let (merchant_id, profile_id) = utils::user_role::get_invitation_lineage(
+        state,
+        user_from_token,
+        requestor_role_info.get_entity_type(),
+        request.merchant_id.as_ref(),
+        request.profile_id.as_ref(),
+    )
+    .await?;
+
     let role_info = roles::RoleInfo::from_role_id_in_merchant_scope(
         state,
         &request.role_id,
-        &user_from_token.merchant_id,
+        &merchant_id,
         &user_from_token.org_id,
     )
     .await
@@ -550,6 +570,18 @@ async fn handle_invitation(
             .attach_printable(format!("role_id = {} is not invitable", request.role_id));
     }
 
+    if role_info.get_entity_type() > requestor_role_info.get_entity_type() {
+        return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+            "Users cannot be invited to a role of a higher entity than the inviter".to_string()
+        )));
+    }
+
+    if role_info.get_entity_type() == EntityType::Profile && profile_id.is_none() {
+        return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+            "Profile is required to invite users to a profile level role".to_string()
+        )));
+    }
+
     let invitee_email = domain::UserEmail::from_pii_email(request.email.clone())?;
     let invitee_user = state
         .global_store
@@ -563,6 +595,7 @@ async fn handle_invitation(
             request,
             invitee_user.into(),
             role_info,
+            (&merchant_id, profile_id.as_ref()),
             auth_id,
         )
         .await
@@ -577,6 +610,7 @@ async fn handle_invitation(
             user_from_token,
             request,
             role_info,
+            (&merchant_id, profile_id.as_ref()),
             req_state.clone(),
             auth_id,
         )
@@ -593,6 +627,7 @@ async fn handle_existing_user_invitation(
     request: &user_api::InviteUserRequest,
     invitee_user_from_db: domain::UserFromStorage,
     role_info: roles::RoleInfo,
+    (merchant_id, profile_id): (&id_type::MerchantId, Option<&id_type::ProfileId>),
     auth_id: &Option<String>,
 ) -> UserResult<InviteMultipleUserResponse> {
     let now = common_utils::date_time::now();
@@ -602,8 +637,8 @@ async fn handle_existing_user_invitation(
         .find_user_role_by_user_id_and_lineage(
             invitee_user_from_db.get_user_id(),
             &user_from_token.org_id,
-            &user_from_token.merchant_id,
-            user_from_token.profile_id.as_ref(),
+            merchant_id,
+            profile_id,
             UserRoleVersion::V1,
         )
         .await
@@ -618,8 +653,8 @@ async fn handle_existing_user_invitation(
         .find_user_role_by_user_id_and_lineage(
             invitee_user_from_db.get_user_id(),
             &user_from_token.org_id,
-            &user_from_token.merchant_id,
-            user_from_token.profile_id.as_ref(),
+            merchant_id,
+            profile_id,
             UserRoleVersion::V2,
         )
         .await
@@ -667,16 +702,13 @@ async fn handle_existing_user_invitation(
                         .clone()
                         .unwrap_or(state.tenant.tenant_id.clone()),
                     org_id: user_from_token.org_id.clone(),
-                    merchant_id: user_from_token.merchant_id.clone(),
+                    merchant_id: merchant_id.clone(),
                 })
                 .insert_in_v2(state)
                 .await?
         }
         EntityType::Profile => {
-            let profile_id = user_from_token
-                .profile_id
-                .clone()
-                .ok_or(UserErrors::InternalServerError)?;
+            let profile_id = profile_id.cloned().ok_or(UserErrors::InternalServerError)?;
             user_role
                 .add_entity(domain::ProfileLevel {
                     tenant_id: user_from_token
@@ -684,7 +716,7 @@ async fn handle_existing_user_invitation(
                         .clone()
                         .unwrap_or(state.tenant.tenant_id.clone()),
                     org_id: user_from_token.org_id.clone(),
-                    merchant_id: user_from_token.merchant_id.clone(),
+                    merchant_id: merchant_id.clone(),
                     profile_id: profile_id.clone(),
                 })
                 .insert_in_v2(state)
@@ -702,14 +734,11 @@ async fn handle_existing_user_invitation(
                 entity_type: EntityType::Organization,
             },
             EntityType::Merchant => email_types::Entity {
-                entity_id: user_from_token.merchant_id.get_string_repr().to_owned(),
+                entity_id: merchant_id.get_string_repr().to_owned(),
                 entity_type: EntityType::Merchant,
             },
             EntityType::Profile => {
-                let profile_id = user_from_token
-                    .profile_id
-                    .clone()
-                    .ok_or(UserErrors::InternalServerError)?;
+                let profile_id = profile_id.cloned().ok_or(UserErrors::InternalServerError)?;
                 email_types::Entity {
                     entity_id: profile_id.get_string_repr().to_owned(),
                     entity_type: EntityType::Profile,
@@ -756,6 +785,7 @@ async fn handle_new_user_invitation(
     user_from_token: &auth::UserFromToken,
     request: &user_api::InviteUserRequest,
     role_info: roles::RoleInfo,
+    (merchant_id, profile_id): (&id_type::MerchantId, Option<&id_type::ProfileId>),
     req_state: ReqState,
     auth_id: &Option<String>,
 ) -> UserResult<InviteMultipleUserResponse> {
@@ -806,16 +836,13 @@ async fn handle_new_user_invitation(
                         .clone()
                         .unwrap_or(state.tenant.tenant_id.clone()),
                     org_id: user_from_token.org_id.clone(),
-                    merchant_id: user_from_token.merchant_id.clone(),
+                    merchant_id: merchant_id.clone(),
                 })
                 .insert_in_v2(state)
                 .await?
         }
         EntityType::Profile => {
-            let profile_id = user_from_token
-                .profile_id
-                .clone()
-                .ok_or(UserErrors::InternalServerError)?;
+            let profile_id = profile_id.cloned().ok_or(UserErrors::InternalServerError)?;
             user_role
                 .add_entity(domain::ProfileLevel {
                     tenant_id: user_from_token
@@ -823,7 +850,7 @@ async fn handle_new_user_invitation(
                         .clone()
                         .unwrap_or(state.tenant.tenant_id.clone()),
                     org_id: user_from_token.org_id.clone(),
-                    merchant_id: user_from_token.merchant_id.clone(),
+                    merchant_id: merchant_id.clone(),
                     profile_id: profile_id.clone(),
                 })
                 .insert_in_v2(state)
@@ -845,14 +872,11 @@ async fn handle_new_user_invitation(
                 entity_type: EntityType::Organization,
             },
             EntityType::Merchant => email_types::Entity {
-                entity_id: user_from_token.merchant_id.get_string_repr().to_owned(),
+                entity_id: merchant_id.get_string_repr().to_owned(),
                 entity_type: EntityType::Merchant,
             },
             EntityType::Profile => {
-                let profile_id = user_from_token
-                    .profile_id
-                    .clone()
-                    .ok_or(UserErrors::InternalServerError)?;
+                let profile_id = profile_id.cloned().ok_or(UserErrors::InternalServerError)?;
                 email_types::Entity {
                     entity_id: profile_id.get_string_repr().to_owned(),
                     entity_type: EntityType::Profile,
@@ -884,11 +908,12 @@ async fn handle_new_user_invitation(
 
         let invited_user_token = auth::UserFromToken {
             user_id: new_user.get_user_id(),
-            merchant_id: user_from_token.merchant_id.clone(),
+            merchant_id: merchant_id.clone(),
             org_id: user_from_token.org_id.clone(),
             role_id: request.role_id.clone(),
             profile_id: None,
             tenant_id: user_from_token.tenant_id.clone(),
+            two_factor_auth_verified: false,
         };
 
         let set_metadata_request = SetMetaDataRequest::IsChangePasswordRequired;
@@ -933,13 +958,31 @@ pub async fn resend_invite(
         })?
         .into();
 
+    let requestor_role_info = roles::RoleInfo::from_role_id_in_merchant_scope(
+        &state,
+        &user_from_token.role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)?;
+
+    let (merchant_id, profile_id) = utils::user_role::get_invitation_lineage(
+        &state,
+        &user_from_token,
+        requestor_role_info.get_entity_type(),
+        request.merchant_id.as_ref(),
+        request.profile_id.as_ref(),
+    )
+    .await?;
+
     let user_role = match state
         .global_store
         .find_user_role_by_user_id_and_lineage(
             user.get_user_id(),
             &user_from_token.org_id,
-            &user_from_token.merchant_id,
-            user_from_token.profile_id.as_ref(),
+            &merchant_id,
+            profile_id.as_ref(),
             UserRoleVersion::V2,
         )
         .await
@@ -961,8 +1004,8 @@ pub async fn resend_invite(
             .find_user_role_by_user_id_and_lineage(
                 user.get_user_id(),
                 &user_from_token.org_id,
-                &user_from_token.merchant_id,
-                user_from_token.profile_id.as_ref(),
+                &merchant_id,
+                profile_id.as_ref(),
                 UserRoleVersion::V1,
             )
             .await
@@ -980,6 +1023,26 @@ pub async fn resend_invite(
         .get_entity_id_and_type()
         .ok_or(UserErrors::InternalServerError)?;
 
+    // Resending the invitation restarts its expiry
+    let (update_v1_result, update_v2_result) = utils::user_role::update_v1_and_v2_user_roles_in_db(
+        &state,
+        user.get_user_id(),
+        &user_from_token.org_id,
+        Some(&merchant_id),
+        profile_id.as_ref(),
+        UserRoleUpdate::UpdateStatus {
+            status: UserStatus::InvitationSent,
+            modified_by: user_from_token.user_id.clone(),
+        },
+    )
+    .await;
+    if update_v1_result.is_err_and(|err| !err.current_context().is_db_not_found())
+        || update_v2_result.is_err_and(|err| !err.current_context().is_db_not_found())
+    {
+        return Err(report!(UserErrors::InternalServerError))
+            .attach_printable("Failed to refresh the invitation");
+    }
+
     let email_contents = email_types::InviteUser {
         recipient_email: invitee_email,
         user_name: domain::UserName::new(user.get_name())?,
@@ -1951,7 +2014,8 @@ pub async fn verify_recovery_code(
 pub async fn terminate_two_factor_auth(
     state: SessionState,
     user_token: auth::UserFromSinglePurposeToken,
-    skip_two_factor_auth: bool,
+    query: user_api::SkipTwoFactorAuthQueryParam,
+    remembered_device_token: Option<String>,
 ) -> UserResponse<user_api::TokenResponse> {
     let user_from_db: domain::UserFromStorage = state
         .global_store
@@ -1960,7 +2024,20 @@ pub async fn terminate_two_factor_auth(
         .change_context(UserErrors::InternalServerError)?
         .into();
 
-    if state.conf.user.force_two_factor_auth || !skip_two_factor_auth {
+    let is_remembered_device = match remembered_device_token {
+        Some(device_token) if user_from_db.get_totp_status() == TotpStatus::Set => {
+            tfa_utils::check_remembered_device_in_redis(&state, &user_token.user_id, &device_token)
+                .await?
+        }
+        _ => false,
+    };
+    let force_two_factor_auth =
+        tfa_utils::is_two_factor_auth_enforced_for_user(&state, &user_token.user_id).await?;
+    let is_two_factor_auth_required =
+        force_two_factor_auth || !query.skip_two_factor_auth.unwrap_or(false);
+
+    let mut remembered_device = None;
+    if is_two_factor_auth_required && !is_remembered_device {
         if !tfa_utils::check_totp_in_redis(&state, &user_token.user_id).await?
             && !tfa_utils::check_recovery_code_in_redis(&state, &user_token.user_id).await?
         {
@@ -1985,9 +2062,21 @@ pub async fn terminate_two_factor_auth(
                 .await
                 .change_context(UserErrors::InternalServerError)?;
         }
+
+        if query.remember_device.unwrap_or(false) {
+            remembered_device = Some(
+                tfa_utils::insert_remembered_device_in_redis(&state, &user_token.user_id).await?,
+            );
+        }
     }
 
-    let current_flow = domain::CurrentFlow::new(user_token.clone(), domain::SPTFlow::TOTP.into())?;
+    let current_flow = domain::CurrentFlow::new(
+        auth::UserFromSinglePurposeToken {
+            two_factor_auth_verified: is_two_factor_auth_required || is_remembered_device,
+            ..user_token.clone()
+        },
+        domain::SPTFlow::TOTP.into(),
+    )?;
     let next_flow = current_flow.next(user_from_db, &state).await?;
     let token = next_flow.get_token(&state).await?;
 
@@ -1998,13 +2087,19 @@ pub async fn terminate_two_factor_auth(
         .await
         .inspect_err(|error| logger::error!(?error));
 
-    auth::cookies::set_cookie_response(
-        user_api::TokenResponse {
-            token: token.clone(),
-            token_type: next_flow.get_flow().into(),
-        },
-        token,
-    )
+    let response = user_api::TokenResponse {
+        token: token.clone(),
+        token_type: next_flow.get_flow().into(),
+    };
+    match remembered_device {
+        Some(device_token) => auth::cookies::set_cookie_response_with_remembered_device(
+            response,
+            token,
+            device_token,
+            state.conf.user.remembered_device_expiry_in_secs,
+        ),
+        None => auth::cookies::set_cookie_response(response, token),
+    }
 }
 
 pub async fn check_two_factor_auth_status(
@@ -2031,7 +2126,9 @@ pub async fn check_two_factor_auth_status_with_attempts(
         .change_context(UserErrors::InternalServerError)?
         .into();
 
-    let is_skippable = state.conf.user.force_two_factor_auth.not();
+    let is_skippable = tfa_utils::is_two_factor_auth_enforced_for_user(&state, &user_token.user_id)
+        .await?
+        .not();
     if user_from_db.get_totp_status() == TotpStatus::NotSet {
         return Ok(ApplicationResponse::Json(user_api::TwoFactorStatus {
             status: None,
@@ -2058,6 +2155,34 @@ pub async fn check_two_factor_auth_status_with_attempts(
     }))
 }
 
+pub async fn get_two_factor_auth_policy(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<user_api::TwoFactorAuthPolicy> {
+    Ok(ApplicationResponse::Json(user_api::TwoFactorAuthPolicy {
+        force_two_factor_auth: tfa_utils::is_two_factor_auth_enforced(
+            &state,
+            &user_from_token.org_id,
+        )
+        .await?,
+    }))
+}
+
+pub async fn update_two_factor_auth_policy(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    req: user_api::TwoFactorAuthPolicy,
+) -> UserResponse<user_api::TwoFactorAuthPolicy> {
+    tfa_utils::set_two_factor_auth_policy(
+        &state,
+        &user_from_token.org_id,
+        req.force_two_factor_auth,
+    )
+    .await?;
+
+    get_two_factor_auth_policy(state, user_from_token).await
+}
+
 pub async fn create_user_authentication_method(
     state: SessionState,
     req: user_api::CreateUserAuthenticationMethodRequest,
@@ -2111,6 +2236,20 @@ pub async fn create_user_authentication_method(
                 let req_auth_name = public_config.name;
                 db_auth_name.is_some_and(|name| name == req_auth_name)
             }
+            user_api::AuthConfig::Saml { public_config } => {
+                let db_idp_entity_id = db_auth_method
+                    .public_config
+                    .map(|config| {
+                        utils::user::parse_value::<user_api::SamlPublicConfig>(
+                            config,
+                            "SamlPublicConfig",
+                        )
+                    })
+                    .transpose()?
+                    .map(|config| config.idp_entity_id);
+                db_idp_entity_id
+                    .is_some_and(|idp_entity_id| idp_entity_id == public_config.idp_entity_id)
+            }
             user_api::AuthConfig::Password | user_api::AuthConfig::MagicLink => true,
         };
         if is_type_same && is_extra_identifier_same {
@@ -2233,6 +2372,10 @@ pub async fn get_sso_auth_url(
         .await
         .to_not_found_response(UserErrors::InvalidUserAuthMethodOperation)?;
 
+    if user_authentication_method.auth_type == common_enums::UserAuthType::Saml {
+        return get_saml_auth_url(state, request, user_authentication_method.public_config).await;
+    }
+
     let open_id_private_config = utils::user::decrypt_oidc_private_config(
         &state,
         user_authentication_method.private_config,
@@ -2249,6 +2392,14 @@ pub async fn get_sso_auth_url(
     .change_context(UserErrors::InternalServerError)
     .attach_printable("Unable to parse OpenIdConnectPublicConfig")?;
 
+    // Sign in initiated by the identity provider has to be from the configured issuer
+    if let Some(issuer) = &request.iss {
+        if issuer.trim_end_matches('/') != open_id_private_config.base_url.trim_end_matches('/') {
+            return Err(report!(UserErrors::SSOFailed))
+                .attach_printable("Issuer does not match the configured OpenId provider");
+        }
+    }
+
     let oidc_state = Secret::new(nanoid::nanoid!());
     utils::user::set_sso_id_in_redis(&state, oidc_state.clone(), request.id).await?;
 
@@ -2261,6 +2412,7 @@ pub async fn get_sso_auth_url(
         oidc_state,
         open_id_private_config.base_url.into(),
         open_id_private_config.client_id,
+        open_id_public_config.jit_provisioning.is_some(),
     )
     .await
     .map(|url| {
@@ -2274,56 +2426,224 @@ pub async fn get_sso_auth_url(
     })
 }
 
-pub async fn sso_sign(
+/// Redirects to the identity provider with a SAML authentication request, the response of the
+/// identity provider is posted to the assertion consumer service
+async fn get_saml_auth_url(
     state: SessionState,
-    request: user_api::SsoSignInRequest,
-    user_from_single_purpose_token: Option<auth::UserFromSinglePurposeToken>,
-) -> UserResponse<user_api::TokenResponse> {
-    let authentication_method_id =
-        utils::user::get_sso_id_from_redis(&state, request.state.clone()).await?;
+    request: user_api::GetSsoAuthUrlRequest,
+    public_config: Option<serde_json::Value>,
+) -> UserResponse<()> {
+    let saml_public_config = utils::user::parse_value::<user_api::SamlPublicConfig>(
+        public_config
+            .ok_or(UserErrors::InternalServerError)
+            .attach_printable("Public config not present")?,
+        "SamlPublicConfig",
+    )?;
 
+    // Sign in initiated by the identity provider has to be from the configured issuer
+    if let Some(issuer) = &request.iss {
+        if *issuer != saml_public_config.idp_entity_id {
+            return Err(report!(UserErrors::SSOFailed))
+                .attach_printable("Issuer does not match the configured SAML identity provider");
+        }
+    }
+
+    let relay_state = Secret::new(nanoid::nanoid!());
+    // Identifiers of SAML requests must not start with a digit
+    let request_id = format!("_{}", uuid::Uuid::new_v4().simple());
+    let acs_url = utils::user::get_saml_acs_url(&state, &request.id);
+    utils::user::set_sso_id_in_redis(&state, relay_state.clone(), request.id).await?;
+    saml::set_authentication_request_id_in_redis(&state, &relay_state, &request_id).await?;
+
+    let url = saml::get_authentication_request_url(
+        &saml_public_config,
+        &acs_url,
+        &request_id,
+        &relay_state,
+        common_utils::date_time::now(),
+    )?;
+
+    Ok(ApplicationResponse::JsonForRedirection(
+        RedirectionResponse {
+            headers: Vec::with_capacity(0),
+            return_url: String::new(),
+            http_method: String::new(),
+            params: Vec::with_capacity(0),
+            return_url_with_query_params: url.to_string(),
+        },
+    ))
+}
+
+/// Assertion consumer service of a SAML authentication method. The response of the identity
+/// provider is verified and the dashboard is redirected to complete the sign in with a one time
+/// code. Responses without a relay state are sign ins initiated by the identity provider.
+pub async fn saml_acs(
+    state: SessionState,
+    auth_method_id: String,
+    request: user_api::SamlAcsRequest,
+) -> UserResponse<()> {
     let user_authentication_method = state
         .store
-        .get_user_authentication_method_by_id(&authentication_method_id)
+        .get_user_authentication_method_by_id(&auth_method_id)
         .await
-        .change_context(UserErrors::InternalServerError)?;
+        .to_not_found_response(UserErrors::InvalidUserAuthMethodOperation)?;
 
-    let open_id_private_config = utils::user::decrypt_oidc_private_config(
-        &state,
-        user_authentication_method.private_config,
-        authentication_method_id,
-    )
-    .await?;
+    if user_authentication_method.auth_type != common_enums::UserAuthType::Saml {
+        return Err(report!(UserErrors::InvalidUserAuthMethodOperation))
+            .attach_printable("Authentication method is not a SAML identity provider");
+    }
 
-    let open_id_public_config = serde_json::from_value::<user_api::OpenIdConnectPublicConfig>(
+    let saml_public_config = utils::user::parse_value::<user_api::SamlPublicConfig>(
         user_authentication_method
             .public_config
             .ok_or(UserErrors::InternalServerError)
             .attach_printable("Public config not present")?,
-    )
-    .change_context(UserErrors::InternalServerError)
-    .attach_printable("Unable to parse OpenIdConnectPublicConfig")?;
+        "SamlPublicConfig",
+    )?;
 
-    let redirect_url =
-        utils::user::get_oidc_sso_redirect_url(&state, &open_id_public_config.name.to_string());
-    let email = openidconnect::get_user_email_from_oidc_provider(
+    let request_id = match &request.relay_state {
+        Some(relay_state) => {
+            let sso_id = utils::user::get_sso_id_from_redis(&state, relay_state.clone()).await?;
+            if sso_id != auth_method_id {
+                return Err(report!(UserErrors::SSOFailed))
+                    .attach_printable("Relay state belongs to another authentication method");
+            }
+            Some(saml::get_authentication_request_id_from_redis(&state, relay_state).await?)
+        }
+        None if saml_public_config.allow_idp_initiated_sign_in => None,
+        None => {
+            return Err(report!(UserErrors::SSOFailed))
+                .attach_printable("Sign in initiated by the identity provider is not allowed")
+        }
+    };
+
+    let acs_url = utils::user::get_saml_acs_url(&state, &auth_method_id);
+    let user_details = saml::get_user_details_from_saml_response(
         &state,
-        redirect_url,
-        request.state,
-        open_id_private_config.base_url.into(),
-        open_id_private_config.client_id,
-        request.code,
-        open_id_private_config.client_secret,
+        request.saml_response,
+        &saml_public_config,
+        &acs_url,
+        request_id.as_deref(),
     )
     .await?;
 
-    // TODO: Use config to handle not found error
-    let user_from_db = state
+    let sign_in_state = match request.relay_state {
+        Some(relay_state) => relay_state,
+        None => {
+            let sign_in_state = Secret::new(nanoid::nanoid!());
+            utils::user::set_sso_id_in_redis(&state, sign_in_state.clone(), auth_method_id).await?;
+            sign_in_state
+        }
+    };
+    let code = Secret::new(nanoid::nanoid!());
+    saml::set_user_details_in_redis(&state, &sign_in_state, code.clone(), user_details).await?;
+
+    let redirect_url = utils::user::get_saml_sso_redirect_url(&state, &sign_in_state, &code)?;
+    Ok(ApplicationResponse::JsonForRedirection(
+        RedirectionResponse {
+            headers: Vec::with_capacity(0),
+            return_url: String::new(),
+            http_method: String::new(),
+            params: Vec::with_capacity(0),
+            return_url_with_query_params: redirect_url.to_string(),
+        },
+    ))
+}
+
+/// Completes the sign in with an OpenID Connect or SAML identity provider
+pub async fn sso_sign(
+    state: SessionState,
+    request: user_api::SsoSignInRequest,
+    user_from_single_purpose_token: Option<auth::UserFromSinglePurposeToken>,
+) -> UserResponse<user_api::TokenResponse> {
+    let authentication_method_id =
+        utils::user::get_sso_id_from_redis(&state, request.state.clone()).await?;
+
+    let user_authentication_method = state
+        .store
+        .get_user_authentication_method_by_id(&authentication_method_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    let (email, groups, jit_provisioning) = match user_authentication_method.auth_type {
+        common_enums::UserAuthType::Saml => {
+            let saml_public_config = utils::user::parse_value::<user_api::SamlPublicConfig>(
+                user_authentication_method
+                    .public_config
+                    .clone()
+                    .ok_or(UserErrors::InternalServerError)
+                    .attach_printable("Public config not present")?,
+                "SamlPublicConfig",
+            )?;
+            let user_details =
+                saml::get_user_details_from_redis(&state, &request.state, &request.code).await?;
+            (
+                user_details.get_email()?,
+                user_details.groups,
+                saml_public_config.jit_provisioning,
+            )
+        }
+        _ => {
+            let open_id_private_config = utils::user::decrypt_oidc_private_config(
+                &state,
+                user_authentication_method.private_config,
+                authentication_method_id,
+            )
+            .await?;
+
+            let open_id_public_config =
+                serde_json::from_value::<user_api::OpenIdConnectPublicConfig>(
+                    user_authentication_method
+                        .public_config
+                        .ok_or(UserErrors::InternalServerError)
+                        .attach_printable("Public config not present")?,
+                )
+                .change_context(UserErrors::InternalServerError)
+                .attach_printable("Unable to parse OpenIdConnectPublicConfig")?;
+
+            let redirect_url = utils::user::get_oidc_sso_redirect_url(
+                &state,
+                &open_id_public_config.name.to_string(),
+            );
+            let user_details = openidconnect::get_user_details_from_oidc_provider(
+                &state,
+                redirect_url,
+                request.state,
+                open_id_private_config.base_url.into(),
+                open_id_private_config.client_id,
+                request.code,
+                open_id_private_config.client_secret,
+            )
+            .await?;
+            (
+                user_details.email,
+                user_details.groups,
+                open_id_public_config.jit_provisioning,
+            )
+        }
+    };
+
+    let user_from_db: domain::UserFromStorage = match state
         .global_store
-        .find_user_by_email(&email.into_inner())
+        .find_user_by_email(&email.clone().into_inner())
         .await
-        .map(Into::into)
-        .to_not_found_response(UserErrors::UserNotFound)?;
+    {
+        Ok(user) => user.into(),
+        Err(error) if error.current_context().is_db_not_found() => match &jit_provisioning {
+            Some(jit_provisioning_config)
+                if user_authentication_method.allow_signup
+                    && user_authentication_method.owner_type
+                        == common_enums::Owner::Organization =>
+            {
+                let org_id =
+                    id_type::OrganizationId::wrap(user_authentication_method.owner_id.clone())
+                        .change_context(UserErrors::InternalServerError)?;
+                provision_sso_user(&state, &org_id, jit_provisioning_config, email, &groups).await?
+            }
+            _ => return Err(error.change_context(UserErrors::UserNotFound)),
+        },
+        Err(error) => return Err(error.change_context(UserErrors::InternalServerError)),
+    };
 
     let next_flow = if let Some(user_from_single_purpose_token) = user_from_single_purpose_token {
         let current_flow =
@@ -2342,6 +2662,92 @@ pub async fn sso_sign(
     auth::cookies::set_cookie_response(response, token)
 }
 
+/// Creates the user signing in with SSO for the first time, with the role mapped from the groups of
+/// the user at the identity provider
+async fn provision_sso_user(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    jit_provisioning_config: &user_api::SsoJitProvisioningConfig,
+    email: domain::UserEmail,
+    groups: &[String],
+) -> UserResult<domain::UserFromStorage> {
+    let role_id = jit_provisioning_config
+        .group_role_mappings
+        .iter()
+        .find(|mapping| groups.contains(&mapping.group))
+        .map(|mapping| mapping.role_id.clone())
+        .or_else(|| jit_provisioning_config.default_role_id.clone())
+        .ok_or(UserErrors::UserNotFound)
+        .attach_printable("No role is mapped for the groups of the user")?;
+
+    let merchant_id = &jit_provisioning_config.merchant_id;
+    let is_merchant_in_org = state
+        .store
+        .list_merchant_accounts_by_organization_id(&state.into(), org_id)
+        .await
+        .change_context(UserErrors::InternalServerError)?
+        .iter()
+        .any(|merchant_account| merchant_account.get_id() == merchant_id);
+    if !is_merchant_in_org {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("Provisioning merchant does not belong to the organization");
+    }
+
+    let role_info =
+        roles::RoleInfo::from_role_id_in_merchant_scope(state, &role_id, merchant_id, org_id)
+            .await
+            .to_not_found_response(UserErrors::InvalidRoleId)?;
+    if !role_info.is_invitable() || role_info.is_internal() {
+        return Err(report!(UserErrors::InvalidRoleId))
+            .attach_printable(format!("Role {role_id} cannot be assigned to users"));
+    }
+
+    let new_user = domain::NewUser::try_from((email, org_id.clone(), merchant_id.clone()))?;
+    let user_from_db = new_user
+        .insert_user_in_db(state.global_store.as_ref())
+        .await?;
+
+    let now = common_utils::date_time::now();
+    let user_role = domain::NewUserRole {
+        user_id: user_from_db.get_user_id().to_owned(),
+        role_id,
+        status: UserStatus::Active,
+        created_by: user_from_db.get_user_id().to_owned(),
+        last_modified_by: user_from_db.get_user_id().to_owned(),
+        created_at: now,
+        last_modified: now,
+        entity: domain::NoLevel,
+    };
+
+    match role_info.get_entity_type() {
+        EntityType::Organization => {
+            user_role
+                .add_entity(domain::OrganizationLevel {
+                    tenant_id: state.tenant.tenant_id.clone(),
+                    org_id: org_id.clone(),
+                })
+                .insert_in_v2(state)
+                .await?
+        }
+        EntityType::Merchant => {
+            user_role
+                .add_entity(domain::MerchantLevel {
+                    tenant_id: state.tenant.tenant_id.clone(),
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                })
+                .insert_in_v2(state)
+                .await?
+        }
+        EntityType::Profile => {
+            return Err(report!(UserErrors::InvalidRoleId))
+                .attach_printable("Profile level roles cannot be assigned on provisioning")
+        }
+    };
+
+    Ok(user_from_db)
+}
+
 pub async fn terminate_auth_select(
     state: SessionState,
     user_token: auth::UserFromSinglePurposeToken,
@@ -2655,6 +3061,7 @@ pub async fn switch_org_for_user(
         user_role.role_id.clone(),
         profile_id.clone(),
         user_from_token.tenant_id,
+        user_from_token.two_factor_auth_verified,
     )
     .await?;
 
@@ -2840,6 +3247,7 @@ pub async fn switch_merchant_for_user_in_org(
         role_id.clone(),
         profile_id,
         user_from_token.tenant_id,
+        user_from_token.two_factor_auth_verified,
     )
     .await?;
 
@@ -2946,6 +3354,7 @@ pub async fn switch_profile_for_user_in_org_and_merchant(
         role_id.clone(),
         profile_id,
         user_from_token.tenant_id,
+        user_from_token.two_factor_auth_verified,
     )
     .await?;
 
@@ -26,7 +26,7 @@ use crate::{
         },
         ApplicationResponse,
     },
-    types::domain,
+    types::{domain, transformers::ForeignInto},
     utils,
 };
 pub mod role;
@@ -627,6 +627,108 @@ pub async fn delete_user_role(
     Ok(ApplicationResponse::StatusOk)
 }
 
+pub async fn revoke_invitation(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    request: user_role_api::RevokeInvitationRequest,
+) -> UserResponse<()> {
+    let user_from_db: domain::UserFromStorage = state
+        .global_store
+        .find_user_by_email(&domain::UserEmail::from_pii_email(request.email)?.into_inner())
+        .await
+        .map_err(|e| {
+            if e.current_context().is_db_not_found() {
+                e.change_context(UserErrors::InvalidRoleOperation)
+                    .attach_printable("User not found in our records")
+            } else {
+                e.change_context(UserErrors::InternalServerError)
+            }
+        })?
+        .into();
+
+    let requestor_role_info = roles::RoleInfo::from_role_id_in_merchant_scope(
+        &state,
+        &user_from_token.role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+    )
+    .await
+    .change_context(UserErrors::InternalServerError)?;
+
+    let (merchant_id, profile_id) = utils::user_role::get_invitation_lineage(
+        &state,
+        &user_from_token,
+        requestor_role_info.get_entity_type(),
+        request.merchant_id.as_ref(),
+        request.profile_id.as_ref(),
+    )
+    .await?;
+
+    let mut is_invitation_revoked = false;
+
+    for version in [UserRoleVersion::V2, UserRoleVersion::V1] {
+        let user_role = match state
+            .global_store
+            .find_user_role_by_user_id_and_lineage(
+                user_from_db.get_user_id(),
+                &user_from_token.org_id,
+                &merchant_id,
+                profile_id.as_ref(),
+                version,
+            )
+            .await
+        {
+            Ok(user_role) => user_role,
+            Err(e) if e.current_context().is_db_not_found() => continue,
+            Err(e) => return Err(e.change_context(UserErrors::InternalServerError)),
+        };
+
+        if user_role.status != UserStatus::InvitationSent {
+            return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+                "User has already accepted the invitation".to_string()
+            )));
+        }
+
+        let target_role_info = roles::RoleInfo::from_role_id_in_merchant_scope(
+            &state,
+            &user_role.role_id,
+            &merchant_id,
+            &user_from_token.org_id,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+        if requestor_role_info.get_entity_type() < target_role_info.get_entity_type() {
+            return Err(report!(UserErrors::InvalidRoleOperation)).attach_printable(format!(
+                "Invalid operation, requestor = {} cannot revoke invitation of target = {}",
+                requestor_role_info.get_entity_type(),
+                target_role_info.get_entity_type()
+            ));
+        }
+
+        state
+            .global_store
+            .delete_user_role_by_user_id_and_lineage(
+                user_from_db.get_user_id(),
+                &user_from_token.org_id,
+                &merchant_id,
+                profile_id.as_ref(),
+                version,
+            )
+            .await
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Error while deleting invited user role")?;
+        is_invitation_revoked = true;
+    }
+
+    if !is_invitation_revoked {
+        return Err(report!(UserErrors::InvalidRoleOperation))
+            .attach_printable("User has no pending invitation for the entity");
+    }
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
 pub async fn list_users_in_lineage(
     state: SessionState,
     user_from_token: auth::UserFromToken,
@@ -728,15 +830,7 @@ pub async fn list_users_in_lineage(
                 &user_from_token.org_id,
             )
             .await
-            .map(|role_info| {
-                (
-                    user_role.role_id.clone(),
-                    user_role_api::role::MinimalRoleInfo {
-                        role_id: user_role.role_id.clone(),
-                        role_name: role_info.get_role_name().to_string(),
-                    },
-                )
-            })
+            .map(|role_info| (user_role.role_id.clone(), role_info))
         }))
         .await
         .change_context(UserErrors::InternalServerError)?
@@ -746,29 +840,54 @@ pub async fn list_users_in_lineage(
     let user_role_map = user_roles_set
         .into_iter()
         .fold(HashMap::new(), |mut map, user_role| {
-            map.entry(user_role.user_id)
+            map.entry(user_role.user_id.clone())
                 .or_insert(Vec::with_capacity(1))
-                .push(user_role.role_id);
+                .push(user_role);
             map
         });
 
     Ok(ApplicationResponse::Json(
         user_role_map
             .into_iter()
-            .map(|(user_id, role_id_vec)| {
+            .map(|(user_id, user_roles)| {
+                let mut roles = Vec::with_capacity(user_roles.len());
+                let mut permission_groups = HashSet::new();
+                for user_role in user_roles.iter() {
+                    let role_info = role_info_map
+                        .get(&user_role.role_id)
+                        .ok_or(UserErrors::InternalServerError)?;
+                    roles.push(user_role_api::role::MinimalRoleInfo {
+                        role_id: user_role.role_id.clone(),
+                        role_name: role_info.get_role_name().to_string(),
+                    });
+                    // Pending invitations do not grant any permissions until they are accepted
+                    if user_role.status == UserStatus::Active {
+                        permission_groups.extend(role_info.get_permission_groups());
+                    }
+                }
+
+                let status = if user_roles
+                    .iter()
+                    .any(|user_role| user_role.status == UserStatus::Active)
+                {
+                    UserStatus::Active
+                } else {
+                    UserStatus::InvitationSent
+                };
+
                 Ok::<_, error_stack::Report<UserErrors>>(user_role_api::ListUsersInEntityResponse {
                     email: email_map
                         .remove(&user_id)
                         .ok_or(UserErrors::InternalServerError)?,
-                    roles: role_id_vec
-                        .into_iter()
-                        .map(|role_id| {
-                            role_info_map
-                                .get(&role_id)
-                                .cloned()
-                                .ok_or(UserErrors::InternalServerError)
+                    roles,
+                    status: status.foreign_into(),
+                    invitation_expires_at: user_roles
+                        .iter()
+                        .filter_map(|user_role| {
+                            utils::user_role::get_invitation_expiry(&state, user_role)
                         })
-                        .collect::<Result<Vec<_>, _>>()?,
+                        .min(),
+                    permission_groups: permission_groups.into_iter().collect(),
                 })
             })
             .collect::<Result<Vec<_>, _>>()?,
@@ -795,6 +914,7 @@ pub async fn list_invitations_for_user(
         .change_context(UserErrors::InternalServerError)
         .attach_printable("Failed to list user roles by user id and invitation sent")?
         .into_iter()
+        .filter(|user_role| !utils::user_role::is_invitation_expired(&state, user_role))
         .collect::<HashSet<_>>();
 
     let (org_ids, merchant_ids, profile_ids_with_merchant_ids) = user_roles.iter().try_fold(
@@ -205,6 +205,7 @@ pub async fn construct_payout_router_data<'a, F>(
         payment_method_balance: None,
         connector_api_version: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -212,6 +213,7 @@ pub async fn construct_payout_router_data<'a, F>(
         dispute_id: None,
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -384,6 +386,7 @@ pub async fn construct_refund_router_data<'a, F>(
         payment_method_balance: None,
         connector_api_version,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -391,6 +394,7 @@ pub async fn construct_refund_router_data<'a, F>(
         dispute_id: None,
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -695,6 +699,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         payment_method_balance: None,
         connector_api_version: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -702,6 +707,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         refund_id: None,
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -791,6 +797,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         test_mode,
         connector_api_version: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -798,6 +805,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         dispute_id: Some(dispute.dispute_id.clone()),
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -893,6 +901,7 @@ pub async fn construct_upload_file_router_data<'a>(
         test_mode,
         connector_api_version: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -900,6 +909,7 @@ pub async fn construct_upload_file_router_data<'a>(
         dispute_id: None,
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -1013,6 +1023,7 @@ pub async fn construct_payments_dynamic_tax_calculation_router_data<'a, F: Clone
         quote_id: None,
         test_mode,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -1022,6 +1033,7 @@ pub async fn construct_payments_dynamic_tax_calculation_router_data<'a, F: Clone
         payment_method_status: None,
         minor_amount_captured: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -1114,6 +1126,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         test_mode,
         connector_api_version: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -1121,6 +1134,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         dispute_id: Some(dispute.dispute_id.clone()),
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -1207,6 +1221,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         test_mode,
         connector_api_version: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -1214,6 +1229,7 @@ pub async fn construct_retrieve_file_router_data<'a>(
         dispute_id: None,
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
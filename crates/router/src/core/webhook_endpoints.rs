@@ -0,0 +1,601 @@
+use std::collections::HashMap;
+
+use api_models::webhook_endpoints as webhook_endpoint_types;
+use common_utils::{
+    encryption::Encryption,
+    ext_traits::{Encode, StringExt},
+    generate_id, id_type, type_name,
+    types::keymanager::Identifier,
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::type_encryption::{crypto_operation, CryptoOperation};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+const WEBHOOK_ENDPOINT_DELIVERY_STATS_PREFIX: &str = "WEBHOOK_ENDPOINT_DELIVERY_STATS";
+const WEBHOOK_ENDPOINT_DELIVERY_ATTEMPTS_PREFIX: &str = "WEBHOOK_ENDPOINT_DELIVERY_ATTEMPTS";
+const DEFAULT_DELIVERY_ATTEMPTS_LIST_LIMIT: u16 = 20;
+const LATENCY_PERCENTILE: usize = 95;
+const SIGNING_SECRET_LENGTH: usize = 64;
+
+const SUCCEEDED_DELIVERIES_FIELD: &str = "succeeded";
+const FAILED_DELIVERIES_FIELD: &str = "failed";
+const CONSECUTIVE_FAILURES_FIELD: &str = "consecutive_failures";
+const LAST_DELIVERED_AT_FIELD: &str = "last_delivered_at";
+
+fn get_delivery_stats_key(endpoint_id: &str) -> String {
+    format!("{WEBHOOK_ENDPOINT_DELIVERY_STATS_PREFIX}_{endpoint_id}")
+}
+
+fn get_delivery_attempts_key(endpoint_id: &str) -> String {
+    format!("{WEBHOOK_ENDPOINT_DELIVERY_ATTEMPTS_PREFIX}_{endpoint_id}")
+}
+
+fn webhook_endpoint_not_found(endpoint_id: &str) -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Webhook endpoint with the given id '{endpoint_id}' does not exist"),
+    }
+}
+
+async fn validate_business_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<domain::Profile> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+}
+
+/// Generates a new signing secret, encrypted with the key of the merchant
+async fn generate_signing_secret(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Encryption> {
+    let signing_secret = Secret::new(
+        common_utils::crypto::generate_cryptographically_secure_random_string(
+            SIGNING_SECRET_LENGTH,
+        ),
+    );
+
+    crypto_operation(
+        &state.into(),
+        type_name!(storage::WebhookEndpoint),
+        CryptoOperation::Encrypt(signing_secret),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(Into::into)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the webhook endpoint signing secret")
+}
+
+pub(crate) async fn decrypt_signing_secret(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    signing_secret: Encryption,
+) -> RouterResult<Secret<String>> {
+    crypto_operation::<String, masking::WithType>(
+        &state.into(),
+        type_name!(storage::WebhookEndpoint),
+        CryptoOperation::Decrypt(signing_secret),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .map(|signing_secret| signing_secret.into_inner())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the webhook endpoint signing secret")
+}
+
+fn to_enabled_events(
+    enabled_events: Option<Vec<storage_enums::EventType>>,
+) -> Option<storage::WebhookEndpointEnabledEvents> {
+    enabled_events.map(storage::WebhookEndpointEnabledEvents)
+}
+
+#[instrument(skip_all)]
+pub async fn create_webhook_endpoint(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: webhook_endpoint_types::WebhookEndpointCreateRequest,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    let now = common_utils::date_time::now();
+    let webhook_endpoint = storage::WebhookEndpointNew {
+        endpoint_id: generate_id(consts::ID_LENGTH, "whe"),
+        merchant_id: merchant_account.get_id().to_owned(),
+        profile_id,
+        url: request.url.to_string(),
+        description: request.description,
+        signing_secret: generate_signing_secret(&state, &key_store).await?,
+        enabled_events: to_enabled_events(request.enabled_events),
+        is_active: true,
+        created_at: now,
+        modified_at: now,
+    };
+
+    let webhook_endpoint = state
+        .store
+        .insert_webhook_endpoint(webhook_endpoint)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the webhook endpoint")?;
+
+    Ok(ApplicationResponse::Json(
+        get_webhook_endpoint_response(&state, &key_store, webhook_endpoint).await?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_webhook_endpoints(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointListResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    let webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_profile_id(&profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the webhook endpoints")?;
+
+    let mut data = Vec::with_capacity(webhook_endpoints.len());
+    for webhook_endpoint in webhook_endpoints {
+        data.push(get_webhook_endpoint_response(&state, &key_store, webhook_endpoint).await?);
+    }
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_types::WebhookEndpointListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_webhook_endpoint(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_profile_id_endpoint_id(&profile_id, &endpoint_id)
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(&endpoint_id))?;
+
+    Ok(ApplicationResponse::Json(
+        get_webhook_endpoint_response(&state, &key_store, webhook_endpoint).await?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_webhook_endpoint(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+    request: webhook_endpoint_types::WebhookEndpointUpdateRequest,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    let webhook_endpoint = state
+        .store
+        .update_webhook_endpoint_by_profile_id_endpoint_id(
+            &profile_id,
+            &endpoint_id,
+            storage::WebhookEndpointUpdate::Update {
+                url: request.url.map(|url| url.to_string()),
+                description: request.description,
+                enabled_events: to_enabled_events(request.enabled_events),
+                is_active: request.is_active,
+            },
+        )
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(&endpoint_id))?;
+
+    Ok(ApplicationResponse::Json(
+        get_webhook_endpoint_response(&state, &key_store, webhook_endpoint).await?,
+    ))
+}
+
+/// Replaces the signing secret of the webhook endpoint. Webhooks posted to the endpoint are signed
+/// with the new secret from then on.
+#[instrument(skip_all)]
+pub async fn rotate_webhook_endpoint_signing_secret(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    let webhook_endpoint = state
+        .store
+        .update_webhook_endpoint_by_profile_id_endpoint_id(
+            &profile_id,
+            &endpoint_id,
+            storage::WebhookEndpointUpdate::SigningSecretUpdate {
+                signing_secret: generate_signing_secret(&state, &key_store).await?,
+            },
+        )
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(&endpoint_id))?;
+
+    Ok(ApplicationResponse::Json(
+        get_webhook_endpoint_response(&state, &key_store, webhook_endpoint).await?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_webhook_endpoint(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    let webhook_endpoint = state
+        .store
+        .delete_webhook_endpoint_by_profile_id_endpoint_id(&profile_id, &endpoint_id)
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(&endpoint_id))?;
+    let response = get_webhook_endpoint_response(&state, &key_store, webhook_endpoint).await?;
+
+    if let Err(error) = delete_delivery_stats(&state, &endpoint_id).await {
+        logger::error!(
+            ?error,
+            "Failed to delete the webhook endpoint delivery stats"
+        );
+    }
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+/// Computes the success rate and latency of the webhook endpoint from its recorded delivery
+/// attempts
+#[instrument(skip_all)]
+pub async fn retrieve_webhook_endpoint_metrics(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointMetricsResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+    state
+        .store
+        .find_webhook_endpoint_by_profile_id_endpoint_id(&profile_id, &endpoint_id)
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(&endpoint_id))?;
+
+    let delivery_stats = get_delivery_stats(&state, &endpoint_id).await?;
+    let delivery_attempts = get_delivery_attempts(&state, &endpoint_id).await?;
+
+    let attempts = u32::try_from(delivery_attempts.len()).unwrap_or(u32::MAX);
+    let delivered = u32::try_from(
+        delivery_attempts
+            .iter()
+            .filter(|delivery_attempt| delivery_attempt.is_delivered)
+            .count(),
+    )
+    .unwrap_or(u32::MAX);
+    let success_rate = (attempts > 0).then(|| f64::from(delivered) * 100.0 / f64::from(attempts));
+
+    let mut latencies = delivery_attempts
+        .iter()
+        .filter(|delivery_attempt| delivery_attempt.status_code.is_some())
+        .map(|delivery_attempt| delivery_attempt.latency_in_ms)
+        .collect::<Vec<_>>();
+    latencies.sort_unstable();
+    let percentile_index = latencies
+        .len()
+        .saturating_mul(LATENCY_PERCENTILE)
+        .div_ceil(100)
+        .saturating_sub(1);
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_types::WebhookEndpointMetricsResponse {
+            endpoint_id,
+            delivery_attempts: delivery_attempts.len(),
+            success_rate,
+            p95_latency_in_ms: latencies.get(percentile_index).copied(),
+            consecutive_failures: delivery_stats.consecutive_failures,
+            last_delivered_at: delivery_stats.last_delivered_at,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_webhook_endpoint_delivery_attempts(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    endpoint_id: String,
+    constraints: webhook_endpoint_types::WebhookEndpointDeliveryAttemptsListConstraints,
+) -> RouterResponse<webhook_endpoint_types::WebhookEndpointDeliveryAttemptsListResponse> {
+    validate_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+    state
+        .store
+        .find_webhook_endpoint_by_profile_id_endpoint_id(&profile_id, &endpoint_id)
+        .await
+        .to_not_found_response(webhook_endpoint_not_found(&endpoint_id))?;
+
+    let delivery_attempts = get_delivery_attempts(&state, &endpoint_id).await?;
+    let total_count = delivery_attempts.len();
+    let data = delivery_attempts
+        .into_iter()
+        .skip(usize::from(constraints.offset.unwrap_or_default()))
+        .take(usize::from(
+            constraints
+                .limit
+                .unwrap_or(DEFAULT_DELIVERY_ATTEMPTS_LIST_LIMIT),
+        ))
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        webhook_endpoint_types::WebhookEndpointDeliveryAttemptsListResponse {
+            count: data.len(),
+            total_count,
+            data,
+        },
+    ))
+}
+
+async fn get_webhook_endpoint_response(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    webhook_endpoint: storage::WebhookEndpoint,
+) -> RouterResult<webhook_endpoint_types::WebhookEndpointResponse> {
+    let signing_secret =
+        decrypt_signing_secret(state, key_store, webhook_endpoint.signing_secret).await?;
+    let delivery_stats = get_delivery_stats(state, &webhook_endpoint.endpoint_id)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to fetch the webhook endpoint delivery stats"
+            )
+        })
+        .unwrap_or_default();
+
+    Ok(webhook_endpoint_types::WebhookEndpointResponse {
+        endpoint_id: webhook_endpoint.endpoint_id,
+        profile_id: webhook_endpoint.profile_id,
+        url: webhook_endpoint.url,
+        description: webhook_endpoint.description,
+        enabled_events: webhook_endpoint
+            .enabled_events
+            .map(|enabled_events| enabled_events.0),
+        is_active: webhook_endpoint.is_active,
+        signing_secret,
+        delivery_stats,
+        created_at: webhook_endpoint.created_at,
+        modified_at: webhook_endpoint.modified_at,
+    })
+}
+
+async fn get_delivery_stats(
+    state: &SessionState,
+    endpoint_id: &str,
+) -> RouterResult<webhook_endpoint_types::WebhookEndpointDeliveryStats> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let stats = redis_conn
+        .get_hash_fields::<HashMap<String, i64>>(&get_delivery_stats_key(endpoint_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let get_count = |field: &str| {
+        stats
+            .get(field)
+            .and_then(|count| u64::try_from(*count).ok())
+            .unwrap_or_default()
+    };
+
+    Ok(webhook_endpoint_types::WebhookEndpointDeliveryStats {
+        succeeded: get_count(SUCCEEDED_DELIVERIES_FIELD),
+        failed: get_count(FAILED_DELIVERIES_FIELD),
+        consecutive_failures: get_count(CONSECUTIVE_FAILURES_FIELD),
+        last_delivered_at: stats
+            .get(LAST_DELIVERED_AT_FIELD)
+            .and_then(|timestamp| time::OffsetDateTime::from_unix_timestamp(*timestamp).ok())
+            .map(|last_delivered_at| {
+                time::PrimitiveDateTime::new(last_delivered_at.date(), last_delivered_at.time())
+            }),
+    })
+}
+
+/// Records the delivery attempt in the stats and the delivery attempts of the webhook endpoint,
+/// discarding the oldest attempts once the maximum number of attempts is exceeded
+pub(crate) async fn record_delivery_attempt(
+    state: &SessionState,
+    endpoint_id: &str,
+    delivery_attempt: webhook_endpoint_types::WebhookEndpointDeliveryAttempt,
+) {
+    if let Err(error) =
+        increment_delivery_stats(state, endpoint_id, delivery_attempt.is_delivered).await
+    {
+        logger::error!(
+            ?error,
+            "Failed to record the webhook endpoint delivery stats"
+        );
+    }
+    if let Err(error) = append_delivery_attempt(state, endpoint_id, delivery_attempt).await {
+        logger::error!(
+            ?error,
+            "Failed to record the webhook endpoint delivery attempt"
+        );
+    }
+}
+
+async fn increment_delivery_stats(
+    state: &SessionState,
+    endpoint_id: &str,
+    is_delivered: bool,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = get_delivery_stats_key(endpoint_id);
+
+    if is_delivered {
+        redis_conn
+            .increment_fields_in_hash(&key, &[(SUCCEEDED_DELIVERIES_FIELD, 1)])
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        let now = common_utils::date_time::now().assume_utc().unix_timestamp();
+        redis_conn
+            .set_hash_fields(
+                &key,
+                vec![
+                    (LAST_DELIVERED_AT_FIELD.to_string(), now.to_string()),
+                    (CONSECUTIVE_FAILURES_FIELD.to_string(), 0.to_string()),
+                ],
+                None,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    } else {
+        redis_conn
+            .increment_fields_in_hash(
+                &key,
+                &[
+                    (FAILED_DELIVERIES_FIELD, 1),
+                    (CONSECUTIVE_FAILURES_FIELD, 1),
+                ],
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    }
+    Ok(())
+}
+
+async fn append_delivery_attempt(
+    state: &SessionState,
+    endpoint_id: &str,
+    delivery_attempt: webhook_endpoint_types::WebhookEndpointDeliveryAttempt,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let key = get_delivery_attempts_key(endpoint_id);
+    let delivery_attempt = delivery_attempt
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the webhook endpoint delivery attempt")?;
+
+    redis_conn
+        .append_elements_to_list(&key, delivery_attempt)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    // Keeps only the latest attempts, which are at the end of the list
+    let max_delivery_attempts =
+        i64::try_from(state.conf.webhook_endpoint_analytics.max_delivery_attempts)
+            .unwrap_or(i64::MAX);
+    redis_conn
+        .trim_list(&key, max_delivery_attempts.saturating_neg(), -1)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    Ok(())
+}
+
+/// The recorded delivery attempts of the webhook endpoint, with the most recent attempt first
+async fn get_delivery_attempts(
+    state: &SessionState,
+    endpoint_id: &str,
+) -> RouterResult<Vec<webhook_endpoint_types::WebhookEndpointDeliveryAttempt>> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let delivery_attempts = redis_conn
+        .get_list_elements(&get_delivery_attempts_key(endpoint_id), 0, -1)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the webhook endpoint delivery attempts")?;
+
+    Ok(delivery_attempts
+        .into_iter()
+        .rev()
+        .filter_map(|delivery_attempt| {
+            delivery_attempt
+                .parse_struct::<webhook_endpoint_types::WebhookEndpointDeliveryAttempt>(
+                    "WebhookEndpointDeliveryAttempt",
+                )
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to parse the webhook endpoint delivery attempt"
+                    )
+                })
+                .ok()
+        })
+        .collect())
+}
+
+async fn delete_delivery_stats(state: &SessionState, endpoint_id: &str) -> RouterResult<()> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .delete_multiple_keys(vec![
+            get_delivery_stats_key(endpoint_id),
+            get_delivery_attempts_key(endpoint_id),
+        ])
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    Ok(())
+}
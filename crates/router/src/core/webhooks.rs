@@ -9,7 +9,7 @@ pub(crate) use self::{
     incoming::incoming_webhooks_wrapper,
     outgoing::{
         create_event_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
-        trigger_webhook_and_raise_event,
+        get_webhook_schema_version, trigger_webhook_and_raise_event,
     },
 };
 
@@ -4,7 +4,11 @@ use actix_web::FromRequest;
 #[cfg(feature = "payouts")]
 use api_models::payouts as payout_models;
 use api_models::webhooks::{self, WebhookResponseTracker};
-use common_utils::{errors::ReportSwitchExt, events::ApiEventsType, ext_traits::ValueExt};
+use common_utils::{
+    errors::ReportSwitchExt,
+    events::ApiEventsType,
+    ext_traits::{Encode, ValueExt},
+};
 use diesel_models::ConnectorMandateReferenceId;
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::{
@@ -20,9 +24,9 @@ use super::{types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
-        metrics, payments,
+        metrics, payment_methods, payments,
         payments::tokenization,
         refunds, utils as core_utils,
         webhooks::utils::construct_webhook_router_data,
@@ -358,7 +362,21 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 id: profile_id.get_string_repr().to_owned(),
             })?;
 
-        match flow_type {
+        // The timestamp and nonce can only be trusted once the signature is verified
+        let webhook_nonce_key = if source_verified {
+            validate_webhook_freshness(
+                &state,
+                merchant_account.get_id(),
+                &connector_name,
+                &connector,
+                &request_details,
+            )
+            .await?
+        } else {
+            None
+        };
+
+        let flow_result = match flow_type {
             api::WebhookFlow::Payment => Box::pin(payments_incoming_webhook_flow(
                 state.clone(),
                 req_state,
@@ -372,7 +390,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 event_type,
             ))
             .await
-            .attach_printable("Incoming webhook flow for payments failed")?,
+            .attach_printable("Incoming webhook flow for payments failed"),
 
             api::WebhookFlow::Refund => Box::pin(refunds_incoming_webhook_flow(
                 state.clone(),
@@ -385,7 +403,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 event_type,
             ))
             .await
-            .attach_printable("Incoming webhook flow for refunds failed")?,
+            .attach_printable("Incoming webhook flow for refunds failed"),
 
             api::WebhookFlow::Dispute => Box::pin(disputes_incoming_webhook_flow(
                 state.clone(),
@@ -399,7 +417,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 event_type,
             ))
             .await
-            .attach_printable("Incoming webhook flow for disputes failed")?,
+            .attach_printable("Incoming webhook flow for disputes failed"),
 
             api::WebhookFlow::BankTransfer => Box::pin(bank_transfer_webhook_flow(
                 state.clone(),
@@ -411,9 +429,9 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 source_verified,
             ))
             .await
-            .attach_printable("Incoming bank-transfer webhook flow failed")?,
+            .attach_printable("Incoming bank-transfer webhook flow failed"),
 
-            api::WebhookFlow::ReturnResponse => WebhookResponseTracker::NoEffect,
+            api::WebhookFlow::ReturnResponse => Ok(WebhookResponseTracker::NoEffect),
 
             api::WebhookFlow::Mandate => Box::pin(mandates_incoming_webhook_flow(
                 state.clone(),
@@ -425,7 +443,21 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 event_type,
             ))
             .await
-            .attach_printable("Incoming webhook flow for mandates failed")?,
+            .attach_printable("Incoming webhook flow for mandates failed"),
+
+            api::WebhookFlow::WalletToken => Box::pin(wallet_tokens_incoming_webhook_flow(
+                state.clone(),
+                merchant_account,
+                key_store,
+                webhook_details,
+                source_verified,
+                &connector,
+                &request_details,
+                &merchant_connector_account,
+                event_type,
+            ))
+            .await
+            .attach_printable("Incoming webhook flow for wallet tokens failed"),
 
             api::WebhookFlow::ExternalAuthentication => {
                 Box::pin(external_authentication_incoming_webhook_flow(
@@ -442,7 +474,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                     merchant_connector_account,
                 ))
                 .await
-                .attach_printable("Incoming webhook flow for external authentication failed")?
+                .attach_printable("Incoming webhook flow for external authentication failed")
             }
             api::WebhookFlow::FraudCheck => Box::pin(frm_incoming_webhook_flow(
                 state.clone(),
@@ -455,7 +487,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 business_profile,
             ))
             .await
-            .attach_printable("Incoming webhook flow for fraud check failed")?,
+            .attach_printable("Incoming webhook flow for fraud check failed"),
 
             #[cfg(feature = "payouts")]
             api::WebhookFlow::Payout => Box::pin(payouts_incoming_webhook_flow(
@@ -468,11 +500,19 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                 source_verified,
             ))
             .await
-            .attach_printable("Incoming webhook flow for payouts failed")?,
+            .attach_printable("Incoming webhook flow for payouts failed"),
 
-            _ => Err(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Unsupported Flow Type received in incoming webhooks")?,
+            _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Unsupported Flow Type received in incoming webhooks"),
+        };
+
+        // A webhook which failed to be processed can be delivered again by the connector, its
+        // nonce is released so that the retry is not rejected as a replay
+        if let (Err(_), Some(nonce_key)) = (&flow_result, &webhook_nonce_key) {
+            release_webhook_nonce(&state, nonce_key).await;
         }
+
+        flow_result?
     } else {
         metrics::WEBHOOK_INCOMING_FILTERED_COUNT.add(
             &metrics::CONTEXT,
@@ -835,14 +875,20 @@ async fn refunds_incoming_webhook_flow(
             updated_by: merchant_account.storage_scheme.to_string(),
             connector_refund_data: None,
         };
-        db.update_refund(
-            refund.to_owned(),
-            refund_update,
-            merchant_account.storage_scheme,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
-        .attach_printable_lazy(|| format!("Failed while updating refund: refund_id: {refund_id}"))?
+        let updated_refund = db
+            .update_refund(
+                refund.to_owned(),
+                refund_update,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable_lazy(|| {
+                format!("Failed while updating refund: refund_id: {refund_id}")
+            })?;
+        refunds::settlement_estimation::record_refund_settlement(&state, &refund, &updated_refund)
+            .await;
+        updated_refund
     } else {
         Box::pin(refunds::refund_retrieve_core_with_refund_id(
             state.clone(),
@@ -1263,6 +1309,143 @@ async fn mandates_incoming_webhook_flow(
     }
 }
 
+/// Updates the status of the reusable wallet tokens, such as the merchant tokens issued for
+/// recurring Apple Pay payments, stored as connector mandates of the payment methods
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+async fn wallet_tokens_incoming_webhook_flow(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    webhook_details: api::IncomingWebhookDetails,
+    source_verified: bool,
+    connector: &ConnectorEnum,
+    request_details: &IncomingWebhookRequestDetails<'_>,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    event_type: webhooks::IncomingWebhookEvent,
+) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
+    if source_verified {
+        let db = &*state.store;
+        let key_manager_state = &(&state).into();
+        let connector_mandate_id = match webhook_details.object_reference_id {
+            webhooks::ObjectReferenceId::MandateId(
+                webhooks::MandateIdType::ConnectorMandateId(connector_mandate_id),
+            ) => connector_mandate_id,
+            _ => Err(errors::ApiErrorResponse::WebhookProcessingFailure)
+                .attach_printable("received a non-wallet token id for retrieving payment method")?,
+        };
+        let connector_mandate_status =
+            common_enums::ConnectorMandateStatus::foreign_try_from(event_type)
+                .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+                .attach_printable("event type to connector mandate status mapping failed")?;
+
+        // The connector may issue a new token in place of the one that was updated
+        let updated_connector_mandate_id = match connector_mandate_status {
+            common_enums::ConnectorMandateStatus::Active => connector
+                .get_mandate_details(request_details)
+                .switch()
+                .attach_printable("Could not find wallet token details in incoming webhook body")?
+                .map(|mandate_details| mandate_details.connector_mandate_id.peek().to_string()),
+            common_enums::ConnectorMandateStatus::Inactive => None,
+        };
+
+        let merchant_connector_id = merchant_connector_account.get_id();
+        let payment_methods = db
+            .find_payment_method_by_merchant_id_connector_mandate_id(
+                key_manager_state,
+                &key_store,
+                merchant_account.get_id(),
+                &merchant_connector_id,
+                &connector_mandate_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payment methods of the wallet token")?;
+
+        let mut webhook_response_tracker = WebhookResponseTracker::NoEffect;
+        for payment_method in payment_methods {
+            let mut mandate_details = payment_method
+                .connector_mandate_details
+                .clone()
+                .get_required_value("connector_mandate_details")?
+                .parse_value::<diesel_models::PaymentsMandateReference>("PaymentsMandateReference")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to deserialize to Payment Mandate Reference")?;
+
+            if let Some(mandate_reference_record) = mandate_details.get_mut(&merchant_connector_id)
+            {
+                mandate_reference_record.connector_mandate_status = Some(connector_mandate_status);
+                if let Some(updated_connector_mandate_id) = updated_connector_mandate_id.clone() {
+                    mandate_reference_record.connector_mandate_id = updated_connector_mandate_id;
+                }
+            }
+            // The payment method can be used as long as any of its wallet tokens is usable
+            let payment_method_status = if mandate_details.values().any(|record| record.is_usable())
+            {
+                enums::PaymentMethodStatus::Active
+            } else if event_type == webhooks::IncomingWebhookEvent::WalletTokenSuspended {
+                enums::PaymentMethodStatus::Suspended
+            } else {
+                enums::PaymentMethodStatus::Inactive
+            };
+
+            let connector_mandate_details = mandate_details
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize Payment Mandate Reference")?;
+            let pm_update = diesel_models::PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
+                connector_mandate_details: Some(connector_mandate_details),
+            };
+            let updated_payment_method = db
+                .update_payment_method(
+                    key_manager_state,
+                    &key_store,
+                    payment_method,
+                    pm_update,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update payment method in db")?;
+
+            logger::info!(
+                payment_method_id = %updated_payment_method.get_id(),
+                %connector_mandate_status,
+                "Wallet token of the payment method updated"
+            );
+
+            // Payment methods which are yet to be activated or are already deactivated aren't
+            // revived by the wallet token
+            let updated_payment_method = if matches!(
+                updated_payment_method.status,
+                enums::PaymentMethodStatus::Active | enums::PaymentMethodStatus::Suspended
+            ) {
+                payment_methods::token_lifecycle::update_payment_method_token_status(
+                    &state,
+                    &merchant_account,
+                    &key_store,
+                    updated_payment_method,
+                    payment_method_status,
+                )
+                .await?
+            } else {
+                updated_payment_method
+            };
+            webhook_response_tracker = WebhookResponseTracker::PaymentMethod {
+                payment_method_id: updated_payment_method.get_id().to_owned(),
+                status: connector_mandate_status,
+            };
+        }
+
+        Ok(webhook_response_tracker)
+    } else {
+        logger::error!("Webhook source verification failed for wallet tokens webhook flow");
+        Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ))
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 async fn frm_incoming_webhook_flow(
@@ -1417,7 +1600,25 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
-        let disputes_response = Box::new(dispute_object.clone().foreign_into());
+        let financial_impact = if dispute_object.dispute_status == enums::DisputeStatus::DisputeLost
+        {
+            Some(
+                disputes::chargebacks::record_lost_dispute(
+                    &state,
+                    &merchant_account,
+                    &business_profile,
+                    &key_store,
+                    &dispute_object,
+                )
+                .await?,
+            )
+        } else {
+            None
+        };
+        let disputes_response = Box::new(api_models::disputes::DisputeResponse {
+            financial_impact,
+            ..dispute_object.clone().foreign_into()
+        });
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
         Box::pin(super::create_event_and_trigger_outgoing_webhook(
@@ -1569,6 +1770,97 @@ async fn get_payment_id(
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
 }
 
+/// Rejects replayed webhooks, whose signed timestamp is outside the configured tolerance or whose
+/// signed nonce has already been seen. Returns the key of the nonce claimed by the webhook.
+async fn validate_webhook_freshness(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    connector_name: &str,
+    connector: &ConnectorEnum,
+    request_details: &IncomingWebhookRequestDetails<'_>,
+) -> errors::RouterResult<Option<String>> {
+    let replay_protection = &state.conf.webhooks.replay_protection;
+    if !replay_protection.enabled {
+        return Ok(None);
+    }
+
+    let reject_replay = |reason: &'static str| -> errors::RouterResult<Option<String>> {
+        metrics::WEBHOOK_REPLAY_REJECTED_COUNT.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([
+                (MERCHANT_ID, merchant_id.get_string_repr().to_owned()),
+                ("connector", connector_name.to_owned()),
+                ("reason", reason.to_owned()),
+            ]),
+        );
+        Err(report!(errors::ApiErrorResponse::WebhookReplayDetected))
+            .attach_printable_lazy(|| format!("Incoming webhook rejected as replay: {reason}"))
+    };
+
+    if let Some(timestamp) = connector
+        .get_webhook_timestamp(request_details)
+        .switch()
+        .attach_printable("Could not get the timestamp of the incoming webhook")?
+    {
+        if !common_utils::crypto::is_timestamp_within_tolerance(
+            timestamp,
+            replay_protection.timestamp_tolerance_in_secs,
+        ) {
+            return reject_replay("stale_timestamp");
+        }
+    }
+
+    if let Some(nonce) = connector
+        .get_webhook_nonce(request_details)
+        .switch()
+        .attach_printable("Could not get the nonce of the incoming webhook")?
+    {
+        let nonce_key = format!(
+            "webhook_nonce_{}_{connector_name}_{nonce}",
+            merchant_id.get_string_repr()
+        );
+        let reply = state
+            .store
+            .get_redis_conn()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to get redis connection")?
+            .set_key_if_not_exists_with_expiry(
+                &nonce_key,
+                "true",
+                Some(replay_protection.nonce_expiry_in_secs),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to record the nonce of the incoming webhook")?;
+
+        if reply == redis_interface::SetnxReply::KeyNotSet {
+            return reject_replay("reused_nonce");
+        }
+        return Ok(Some(nonce_key));
+    }
+
+    Ok(None)
+}
+
+async fn release_webhook_nonce(state: &SessionState, nonce_key: &str) {
+    match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            redis_conn
+                .delete_key(nonce_key)
+                .await
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to release the nonce of the incoming webhook"
+                    )
+                })
+                .ok();
+        }
+        Err(error) => logger::error!(?error, "Failed to get redis connection"),
+    }
+}
+
 #[inline]
 async fn verify_webhook_source_verification_call(
     connector: ConnectorEnum,
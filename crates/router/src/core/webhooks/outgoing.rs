@@ -25,9 +25,10 @@ use super::{types, utils, MERCHANT_ID};
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
+    compatibility::adyen::webhooks as adyen_webhooks,
     core::{
         errors::{self, CustomResult},
-        metrics,
+        metrics, receipts, webhook_endpoints,
     },
     db::StorageInterface,
     events::outgoing_webhook_logs::{
@@ -48,6 +49,26 @@ use crate::{
 
 const OUTGOING_WEBHOOK_TIMEOUT_SECS: u64 = 5;
 
+/// Where the outgoing webhook of an event is delivered
+enum WebhookDeliveryTarget {
+    /// The webhook URL of the business profile, signed with its payment response hash key
+    BusinessProfile,
+    /// A webhook endpoint of the business profile, signed with the signing secret of the endpoint
+    WebhookEndpoint {
+        endpoint_id: String,
+        signing_secret: Secret<String>,
+    },
+}
+
+impl WebhookDeliveryTarget {
+    fn get_webhook_endpoint_id(&self) -> Option<&str> {
+        match self {
+            Self::BusinessProfile => None,
+            Self::WebhookEndpoint { endpoint_id, .. } => Some(endpoint_id),
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
 pub(crate) async fn create_event_and_trigger_outgoing_webhook(
@@ -62,24 +83,142 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     content: api::OutgoingWebhookContent,
     primary_object_created_at: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
-    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
-    let idempotent_event_id =
-        utils::get_idempotent_event_id(&primary_object_id, event_type, delivery_attempt);
-    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
-
-    if !state.conf.webhooks.outgoing_enabled
-        || webhook_url_result.is_err()
-        || webhook_url_result.as_ref().is_ok_and(String::is_empty)
-    {
+    if !state.conf.webhooks.outgoing_enabled {
         logger::debug!(
             business_profile_id=?business_profile.get_id(),
-            %idempotent_event_id,
-            "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
-             could not be obtained; skipping outgoing webhooks for event"
+            %primary_object_id,
+            %event_type,
+            "Outgoing webhooks are disabled in application configuration; skipping outgoing \
+             webhooks for event"
         );
         return Ok(());
     }
 
+    let content = match content {
+        api::OutgoingWebhookContent::PaymentDetails(mut payments_response) => {
+            payments_response.receipt = receipts::get_receipt_urls_or_log(
+                &state,
+                &payments_response.merchant_id,
+                &payments_response.payment_id,
+                payments_response.status,
+            );
+            api::OutgoingWebhookContent::PaymentDetails(payments_response)
+        }
+        content => content,
+    };
+
+    let mut delivery_targets = Vec::new();
+    let webhook_url_result = get_webhook_url_from_business_profile(&business_profile);
+    if webhook_url_result.is_err() || webhook_url_result.as_ref().is_ok_and(String::is_empty) {
+        logger::debug!(
+            business_profile_id=?business_profile.get_id(),
+            %primary_object_id,
+            "Merchant webhook URL could not be obtained; skipping outgoing webhook to the webhook \
+             URL of the business profile for event"
+        );
+    } else if !is_event_type_enabled(&business_profile, event_type) {
+        logger::debug!(
+            business_profile_id=?business_profile.get_id(),
+            %primary_object_id,
+            %event_type,
+            "Event type is not enabled for the business profile; skipping outgoing webhook to the \
+             webhook URL of the business profile for event"
+        );
+    } else {
+        delivery_targets.push(WebhookDeliveryTarget::BusinessProfile);
+    }
+
+    let webhook_endpoints = state
+        .store
+        .list_webhook_endpoints_by_profile_id(business_profile.get_id())
+        .await
+        .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+        .attach_printable("Failed to list the webhook endpoints of the business profile")?;
+    for webhook_endpoint in webhook_endpoints.into_iter().filter(|webhook_endpoint| {
+        webhook_endpoint.is_active
+            && webhook_endpoint
+                .enabled_events
+                .as_ref()
+                .map_or(true, |enabled_events| {
+                    enabled_events.0.contains(&event_type)
+                })
+    }) {
+        let signing_secret = match webhook_endpoints::decrypt_signing_secret(
+            &state,
+            merchant_key_store,
+            webhook_endpoint.signing_secret,
+        )
+        .await
+        {
+            Ok(signing_secret) => signing_secret,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    endpoint_id = %webhook_endpoint.endpoint_id,
+                    "Failed to decrypt the signing secret of the webhook endpoint; skipping \
+                     outgoing webhook to the webhook endpoint for event"
+                );
+                continue;
+            }
+        };
+        delivery_targets.push(WebhookDeliveryTarget::WebhookEndpoint {
+            endpoint_id: webhook_endpoint.endpoint_id,
+            signing_secret,
+        });
+    }
+
+    for delivery_target in delivery_targets {
+        create_event_and_trigger_webhook_delivery(
+            &state,
+            &merchant_account,
+            &business_profile,
+            merchant_key_store,
+            WebhookEventDetails {
+                event_type,
+                event_class,
+                primary_object_id: &primary_object_id,
+                primary_object_type,
+                primary_object_created_at,
+                content: &content,
+            },
+            delivery_target,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// The event, and the object it is about, of an outgoing webhook
+struct WebhookEventDetails<'a> {
+    event_type: enums::EventType,
+    event_class: enums::EventClass,
+    primary_object_id: &'a str,
+    primary_object_type: enums::EventObjectType,
+    primary_object_created_at: Option<time::PrimitiveDateTime>,
+    content: &'a api::OutgoingWebhookContent,
+}
+
+/// Records the event of the delivery of the outgoing webhook to the delivery target, along with
+/// a task to retry the delivery, and triggers the delivery. Nothing is delivered if the event was
+/// already delivered to the target.
+async fn create_event_and_trigger_webhook_delivery(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    business_profile: &domain::Profile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    event_details: WebhookEventDetails<'_>,
+    delivery_target: WebhookDeliveryTarget,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let delivery_attempt = enums::WebhookDeliveryAttempt::InitialAttempt;
+    let webhook_endpoint_id = delivery_target.get_webhook_endpoint_id().map(str::to_owned);
+    let idempotent_event_id = utils::get_idempotent_event_id(
+        event_details.primary_object_id,
+        event_details.event_type,
+        delivery_attempt,
+        webhook_endpoint_id.as_deref(),
+    );
+
     let event_id = utils::generate_event_id();
     let merchant_id = business_profile.merchant_id.clone();
     let now = common_utils::date_time::now();
@@ -87,29 +226,41 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     let outgoing_webhook = api::OutgoingWebhook {
         merchant_id: merchant_id.clone(),
         event_id: event_id.clone(),
-        event_type,
-        content: content.clone(),
+        event_type: event_details.event_type,
+        schema_version: get_webhook_schema_version(business_profile),
+        content: event_details.content.clone(),
         timestamp: now,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&merchant_account, outgoing_webhook, &business_profile)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+    let request_content = match delivery_target {
+        WebhookDeliveryTarget::BusinessProfile => {
+            get_outgoing_webhook_request(merchant_account, outgoing_webhook, business_profile)
+        }
+        WebhookDeliveryTarget::WebhookEndpoint { signing_secret, .. } => {
+            get_outgoing_webhook_request_with_signing_key(
+                merchant_account,
+                outgoing_webhook,
+                business_profile,
+                Some(signing_secret.expose()),
+            )
+        }
+    }
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
 
-    let event_metadata = storage::EventMetadata::foreign_from(&content);
-    let key_manager_state = &(&state).into();
+    let event_metadata = storage::EventMetadata::foreign_from(event_details.content);
+    let key_manager_state = &state.into();
     let new_event = domain::Event {
         event_id: event_id.clone(),
-        event_type,
-        event_class,
+        event_type: event_details.event_type,
+        event_class: event_details.event_class,
         is_webhook_notified: false,
-        primary_object_id,
-        primary_object_type,
+        primary_object_id: event_details.primary_object_id.to_owned(),
+        primary_object_type: event_details.primary_object_type,
         created_at: now,
         merchant_id: Some(business_profile.merchant_id.clone()),
         business_profile_id: Some(business_profile.get_id().to_owned()),
-        primary_object_created_at,
+        primary_object_created_at: event_details.primary_object_created_at,
         idempotent_event_id: Some(idempotent_event_id.clone()),
         initial_attempt_id: Some(event_id.clone()),
         request: Some(
@@ -134,6 +285,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         response: None,
         delivery_attempt: Some(delivery_attempt),
         metadata: Some(event_metadata),
+        webhook_endpoint_id,
     };
 
     let event_insert_result = state
@@ -156,21 +308,21 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         }
     }?;
 
-    let process_tracker = add_outgoing_webhook_retry_task_to_process_tracker(
-        &*state.store,
-        &business_profile,
-        &event,
-    )
-    .await
-    .inspect_err(|error| {
-        logger::error!(
-            ?error,
-            "Failed to add outgoing webhook retry task to process tracker"
-        );
-    })
-    .ok();
+    let process_tracker =
+        add_outgoing_webhook_retry_task_to_process_tracker(&*state.store, business_profile, &event)
+            .await
+            .inspect_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to add outgoing webhook retry task to process tracker"
+                );
+            })
+            .ok();
 
+    let state = state.clone();
+    let business_profile = business_profile.clone();
     let cloned_key_store = merchant_key_store.clone();
+    let content = event_details.content.clone();
     // Using a tokio spawn here and not arbiter because not all caller of this function
     // may have an actix arbiter
     tokio::spawn(
@@ -213,6 +365,7 @@ pub(crate) async fn trigger_webhook_and_raise_event(
     );
 
     let merchant_id = business_profile.merchant_id.clone();
+    let started_at = std::time::Instant::now();
     let trigger_webhook_result = trigger_webhook_to_merchant(
         state.clone(),
         business_profile,
@@ -223,6 +376,7 @@ pub(crate) async fn trigger_webhook_and_raise_event(
         process_tracker,
     )
     .await;
+    let latency_in_ms = u64::try_from(started_at.elapsed().as_millis()).unwrap_or(u64::MAX);
 
     let _ = raise_webhooks_analytics_event(
         state,
@@ -231,6 +385,7 @@ pub(crate) async fn trigger_webhook_and_raise_event(
         merchant_id,
         event,
         merchant_key_store,
+        latency_in_ms,
     )
     .await;
 }
@@ -245,7 +400,12 @@ async fn trigger_webhook_to_merchant(
     process_tracker: Option<storage::ProcessTracker>,
 ) -> CustomResult<(), errors::WebhooksFlowError> {
     let webhook_url = match (
-        get_webhook_url_from_business_profile(&business_profile),
+        get_webhook_url(
+            &state,
+            &business_profile,
+            event.webhook_endpoint_id.as_deref(),
+        )
+        .await,
         process_tracker.clone(),
     ) {
         (Ok(webhook_url), _) => Ok(webhook_url),
@@ -449,9 +609,14 @@ async fn raise_webhooks_analytics_event(
     merchant_id: common_utils::id_type::MerchantId,
     event: domain::Event,
     merchant_key_store: &domain::MerchantKeyStore,
+    latency_in_ms: u64,
 ) {
     let key_manager_state: &KeyManagerState = &(&state).into();
     let event_id = event.event_id;
+    let trigger_error_message = trigger_webhook_result
+        .as_ref()
+        .err()
+        .map(|error| error.current_context().to_string());
 
     let error = if let Err(error) = trigger_webhook_result {
         logger::error!(?error, "Failed to send webhook to merchant");
@@ -488,21 +653,50 @@ async fn raise_webhooks_analytics_event(
         })
         .ok();
 
-    // Get status_code from webhook response
-    let status_code = updated_event.and_then(|updated_event| {
-        let webhook_response: Option<OutgoingWebhookResponseContent> =
-            updated_event.response.and_then(|res| {
-                res.peek()
-                    .parse_struct("OutgoingWebhookResponseContent")
-                    .map_err(|error| {
-                        logger::error!(?error, "Error deserializing webhook response");
-                        error
-                    })
-                    .ok()
-            });
-        webhook_response.and_then(|res| res.status_code)
+    let is_webhook_notified = updated_event
+        .as_ref()
+        .is_some_and(|updated_event| updated_event.is_webhook_notified);
+    let webhook_response = updated_event.and_then(|updated_event| {
+        updated_event.response.and_then(|res| {
+            res.peek()
+                .parse_struct::<OutgoingWebhookResponseContent>("OutgoingWebhookResponseContent")
+                .map_err(|error| {
+                    logger::error!(?error, "Error deserializing webhook response");
+                    error
+                })
+                .ok()
+        })
     });
 
+    // Get status_code from webhook response
+    let status_code = webhook_response
+        .as_ref()
+        .and_then(|webhook_response| webhook_response.status_code);
+
+    if let Some(webhook_endpoint_id) = &event.webhook_endpoint_id {
+        let max_response_body_length = state
+            .conf
+            .webhook_endpoint_analytics
+            .max_response_body_length;
+        let delivery_attempt = api_models::webhook_endpoints::WebhookEndpointDeliveryAttempt {
+            event_id: event_id.clone(),
+            event_type: event.event_type,
+            is_delivered: is_webhook_notified,
+            status_code,
+            response_body: webhook_response
+                .as_ref()
+                .and_then(|webhook_response| webhook_response.body.as_ref())
+                .map(|body| body.peek().chars().take(max_response_body_length).collect()),
+            error_message: webhook_response
+                .and_then(|webhook_response| webhook_response.error_message)
+                .or(trigger_error_message),
+            latency_in_ms,
+            created_at: event.created_at,
+        };
+        webhook_endpoints::record_delivery_attempt(&state, webhook_endpoint_id, delivery_attempt)
+            .await;
+    }
+
     let webhook_event = OutgoingWebhookEvent::new(
         merchant_id,
         event_id,
@@ -581,6 +775,40 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
     }
 }
 
+/// The URL the outgoing webhook of the event is posted to, which is the URL of the webhook endpoint
+/// if the event is delivered to one, or else the webhook URL of the business profile
+async fn get_webhook_url(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    webhook_endpoint_id: Option<&str>,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let Some(webhook_endpoint_id) = webhook_endpoint_id else {
+        return get_webhook_url_from_business_profile(business_profile);
+    };
+
+    let webhook_endpoint = state
+        .store
+        .find_webhook_endpoint_by_profile_id_endpoint_id(
+            business_profile.get_id(),
+            webhook_endpoint_id,
+        )
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::WebhooksFlowError::WebhookEndpointNotFound)
+            } else {
+                error.change_context(errors::WebhooksFlowError::WebhookEndpointFetchFailed)
+            }
+        })?;
+
+    if !webhook_endpoint.is_active {
+        return Err(report!(errors::WebhooksFlowError::WebhookEndpointNotFound))
+            .attach_printable("The webhook endpoint is inactive");
+    }
+
+    Ok(webhook_endpoint.url)
+}
+
 fn get_webhook_url_from_business_profile(
     business_profile: &domain::Profile,
 ) -> CustomResult<String, errors::WebhooksFlowError> {
@@ -597,15 +825,53 @@ fn get_webhook_url_from_business_profile(
         .map(ExposeInterface::expose)
 }
 
+/// Whether webhooks of the event type are posted to the webhook endpoint of the business profile,
+/// which are the ones of all event types unless the profile selects the event types it is sent
+fn is_event_type_enabled(business_profile: &domain::Profile, event_type: enums::EventType) -> bool {
+    business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.enabled_events.as_ref())
+        .map_or(true, |enabled_events| enabled_events.contains(&event_type))
+}
+
+/// The version of the payload schema pinned by the business profile, or the latest one
+pub(crate) fn get_webhook_schema_version(
+    business_profile: &domain::Profile,
+) -> enums::OutgoingWebhookSchemaVersion {
+    business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.schema_version)
+        .unwrap_or_default()
+}
+
 pub(crate) fn get_outgoing_webhook_request(
     merchant_account: &domain::MerchantAccount,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::Profile,
+) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
+    get_outgoing_webhook_request_with_signing_key(
+        merchant_account,
+        outgoing_webhook,
+        business_profile,
+        business_profile.payment_response_hash_key.clone(),
+    )
+}
+
+/// Builds the outgoing webhook request signed with the specified key instead of the payment
+/// response hash key of the business profile
+fn get_outgoing_webhook_request_with_signing_key(
+    merchant_account: &domain::MerchantAccount,
+    outgoing_webhook: api::OutgoingWebhook,
+    business_profile: &domain::Profile,
+    signing_key: Option<String>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::Profile,
+        signing_key: Option<String>,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![
             (
@@ -619,7 +885,6 @@ pub(crate) fn get_outgoing_webhook_request(
         ];
 
         let transformed_outgoing_webhook = WebhookType::from(outgoing_webhook);
-        let payment_response_hash_key = business_profile.payment_response_hash_key.clone();
         let custom_headers = business_profile
             .outgoing_webhook_custom_http_headers
             .clone()
@@ -638,8 +903,8 @@ pub(crate) fn get_outgoing_webhook_request(
                     .map(|(key, value)| (key.clone(), value.clone().into_masked())),
             );
         };
-        let outgoing_webhooks_signature = transformed_outgoing_webhook
-            .get_outgoing_webhooks_signature(payment_response_hash_key)?;
+        let outgoing_webhooks_signature =
+            transformed_outgoing_webhook.get_outgoing_webhooks_signature(signing_key)?;
 
         if let Some(signature) = outgoing_webhooks_signature.signature {
             WebhookType::add_webhook_header(&mut headers, signature)
@@ -654,14 +919,33 @@ pub(crate) fn get_outgoing_webhook_request(
         })
     }
 
+    let webhook_format = business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.webhook_format)
+        .unwrap_or_default();
+
+    if webhook_format == enums::OutgoingWebhookFormat::Adyen {
+        return get_outgoing_webhook_request_inner::<adyen_webhooks::AdyenOutgoingWebhook>(
+            outgoing_webhook,
+            business_profile,
+            signing_key,
+        );
+    }
+
     match merchant_account.get_compatible_connector() {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                signing_key,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            signing_key,
         ),
     }
 }
@@ -931,10 +1215,42 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
                 payment_method_id: mandate_response.payment_method_id.clone(),
                 mandate_id: mandate_response.mandate_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::MandateDebitDetails(mandate_debit_response) => {
+                Self::Mandate {
+                    payment_method_id: mandate_debit_response.payment_method_id.clone(),
+                    mandate_id: mandate_debit_response.mandate_id.clone(),
+                }
+            }
             #[cfg(feature = "payouts")]
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::InvoiceDetails(invoice_response) => Self::Invoice {
+                invoice_id: invoice_response.invoice_id.clone(),
+            },
+            webhooks::OutgoingWebhookContent::PaymentMethodCollectLinkDetails(
+                pm_collect_link_response,
+            ) => Self::PaymentMethodCollectLink {
+                pm_collect_link_id: pm_collect_link_response.pm_collect_link_id.clone(),
+            },
+            webhooks::OutgoingWebhookContent::PaymentMethodTokenDetails(
+                payment_method_response,
+            ) => Self::PaymentMethod {
+                payment_method_id: payment_method_response.payment_method_id.clone(),
+            },
+            webhooks::OutgoingWebhookContent::OrchestrationDetails(orchestration_response) => {
+                Self::Payment {
+                    payment_id: orchestration_response.payment_id.clone(),
+                }
+            }
+            webhooks::OutgoingWebhookContent::ConnectorCredentialHealthDetails(
+                credential_health_response,
+            ) => Self::MerchantConnector {
+                merchant_connector_id: credential_health_response.merchant_connector_id.clone(),
+            },
+            webhooks::OutgoingWebhookContent::ShipmentDetails(shipment_response) => Self::Payment {
+                payment_id: shipment_response.payment_id.clone(),
+            },
         }
     }
 }
@@ -979,5 +1295,29 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::Invoice { invoice_id } => {
+            OutgoingWebhookEventContent::Invoice {
+                invoice_id,
+                content: serde_json::Value::Null,
+            }
+        }
+        diesel_models::EventMetadata::PaymentMethodCollectLink { pm_collect_link_id } => {
+            OutgoingWebhookEventContent::PaymentMethodCollectLink {
+                pm_collect_link_id,
+                content: serde_json::Value::Null,
+            }
+        }
+        diesel_models::EventMetadata::PaymentMethod { payment_method_id } => {
+            OutgoingWebhookEventContent::PaymentMethod {
+                payment_method_id,
+                content: serde_json::Value::Null,
+            }
+        }
+        diesel_models::EventMetadata::MerchantConnector {
+            merchant_connector_id,
+        } => OutgoingWebhookEventContent::MerchantConnector {
+            merchant_connector_id,
+            content: serde_json::Value::Null,
+        },
     })
 }
@@ -113,6 +113,7 @@ pub async fn construct_webhook_router_data<'a>(
         payment_method_status: None,
         connector_api_version: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -120,6 +121,7 @@ pub async fn construct_webhook_router_data<'a>(
         dispute_id: None,
         connector_response: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -127,17 +129,31 @@ pub async fn construct_webhook_router_data<'a>(
     Ok(router_data)
 }
 
+/// The ID which identifies the delivery of the event of the object, to the webhook endpoint if
+/// specified or else to the webhook URL of the business profile
 #[inline]
 pub(crate) fn get_idempotent_event_id(
     primary_object_id: &str,
     event_type: types::storage::enums::EventType,
     delivery_attempt: types::storage::enums::WebhookDeliveryAttempt,
+    webhook_endpoint_id: Option<&str>,
 ) -> String {
     use crate::types::storage::enums::WebhookDeliveryAttempt;
 
     const EVENT_ID_SUFFIX_LENGTH: usize = 8;
 
-    let common_prefix = format!("{primary_object_id}_{event_type}");
+    let common_prefix = match event_type {
+        // Reminders are sent more than once for the same invoice, but at most once a day
+        types::storage::enums::EventType::InvoiceReminder => format!(
+            "{primary_object_id}_{event_type}_{}",
+            common_utils::date_time::now().date()
+        ),
+        _ => format!("{primary_object_id}_{event_type}"),
+    };
+    let common_prefix = match webhook_endpoint_id {
+        Some(webhook_endpoint_id) => format!("{common_prefix}_{webhook_endpoint_id}"),
+        None => common_prefix,
+    };
     match delivery_attempt {
         WebhookDeliveryAttempt::InitialAttempt => common_prefix,
         WebhookDeliveryAttempt::AutomaticRetry | WebhookDeliveryAttempt::ManualRetry => {
@@ -11,15 +11,19 @@ pub mod cards_info;
 pub mod configs;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_export;
 pub mod dispute;
 pub mod ephemeral_key;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
+pub mod graphql;
 pub mod gsm;
 pub mod health_check;
+pub mod invoice;
 pub mod kafka_store;
+pub mod ledger_entry;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -28,15 +32,21 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_order_reference;
+pub mod payment_shipment;
 pub mod refund;
+pub mod refund_tender_override;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod routing_experiment_assignment;
+pub mod sandbox_data;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod webhook_endpoint;
 use common_utils::id_type;
 use diesel_models::{
     fraud_check::{FraudCheck, FraudCheckUpdate},
@@ -111,6 +121,7 @@ pub trait StorageInterface:
     + PayoutAttemptInterface
     + PayoutsInterface
     + refund::RefundInterface
+    + refund_tender_override::RefundTenderOverrideInterface
     + reverse_lookup::ReverseLookupInterface
     + cards_info::CardsInfoInterface
     + merchant_key_store::MerchantKeyStoreInterface
@@ -121,6 +132,7 @@ pub trait StorageInterface:
     + business_profile::ProfileInterface
     + OrganizationInterface
     + routing_algorithm::RoutingAlgorithmInterface
+    + routing_experiment_assignment::RoutingExperimentAssignmentInterface
     + gsm::GsmInterface
     + unified_translations::UnifiedTranslationsInterface
     + authorization::AuthorizationInterface
@@ -130,6 +142,14 @@ pub trait StorageInterface:
     + user_authentication_method::UserAuthenticationMethodInterface
     + authentication::AuthenticationInterface
     + generic_link::GenericLinkInterface
+    + invoice::InvoiceInterface
+    + ledger_entry::LedgerEntryInterface
+    + payment_order_reference::PaymentOrderReferenceInterface
+    + payment_shipment::PaymentShipmentInterface
+    + sandbox_data::SandboxDataInterface
+    + data_export::DataExportInterface
+    + graphql::GraphqlInterface
+    + webhook_endpoint::WebhookEndpointInterface
     + 'static
 {
     fn get_scheduler_db(&self) -> Box<dyn scheduler::SchedulerInterface>;
@@ -41,6 +41,24 @@ pub trait BlocklistInterface {
         limit: i64,
         offset: i64,
     ) -> CustomResult<Vec<storage::Blocklist>, errors::StorageError>;
+
+    async fn apply_blocklist_sync(
+        &self,
+        blocklist_sync: storage::BlocklistSync,
+        max_retained_versions: i32,
+    ) -> CustomResult<storage::BlocklistSyncVersion, errors::StorageError>;
+
+    async fn rollback_blocklist_sync_versions(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        versions: Vec<storage::BlocklistSyncVersion>,
+        rolled_back_at: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn list_blocklist_sync_versions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::BlocklistSyncVersion>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -111,6 +129,42 @@ impl BlocklistInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn apply_blocklist_sync(
+        &self,
+        blocklist_sync: storage::BlocklistSync,
+        max_retained_versions: i32,
+    ) -> CustomResult<storage::BlocklistSyncVersion, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::BlocklistSyncVersion::apply(&conn, blocklist_sync, max_retained_versions)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn rollback_blocklist_sync_versions(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        versions: Vec<storage::BlocklistSyncVersion>,
+        rolled_back_at: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::BlocklistSyncVersion::rollback(&conn, merchant_id, versions, rolled_back_at)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_blocklist_sync_versions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::BlocklistSyncVersion>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::BlocklistSyncVersion::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -155,6 +209,30 @@ impl BlocklistInterface for MockDb {
     ) -> CustomResult<storage::Blocklist, errors::StorageError> {
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn apply_blocklist_sync(
+        &self,
+        _blocklist_sync: storage::BlocklistSync,
+        _max_retained_versions: i32,
+    ) -> CustomResult<storage::BlocklistSyncVersion, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn rollback_blocklist_sync_versions(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _versions: Vec<storage::BlocklistSyncVersion>,
+        _rolled_back_at: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_blocklist_sync_versions_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::BlocklistSyncVersion>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
 
 #[async_trait::async_trait]
@@ -211,4 +289,37 @@ impl BlocklistInterface for KafkaStore {
             .list_blocklist_entries_by_merchant_id(merchant_id)
             .await
     }
+
+    #[instrument(skip_all)]
+    async fn apply_blocklist_sync(
+        &self,
+        blocklist_sync: storage::BlocklistSync,
+        max_retained_versions: i32,
+    ) -> CustomResult<storage::BlocklistSyncVersion, errors::StorageError> {
+        self.diesel_store
+            .apply_blocklist_sync(blocklist_sync, max_retained_versions)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn rollback_blocklist_sync_versions(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        versions: Vec<storage::BlocklistSyncVersion>,
+        rolled_back_at: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .rollback_blocklist_sync_versions(merchant_id, versions, rolled_back_at)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn list_blocklist_sync_versions_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::BlocklistSyncVersion>, errors::StorageError> {
+        self.diesel_store
+            .list_blocklist_sync_versions_by_merchant_id(merchant_id)
+            .await
+    }
 }
@@ -199,6 +199,8 @@ impl CaptureInterface for MockDb {
             connector_capture_id: capture.connector_capture_id,
             connector_response_reference_id: capture.connector_response_reference_id,
             connector_capture_data: capture.connector_capture_data,
+            settlement_currency: capture.settlement_currency,
+            settlement_amount: capture.settlement_amount,
         };
         captures.push(capture.clone());
         Ok(capture)
@@ -0,0 +1,92 @@
+use diesel_models::query::data_export::ExportConstraints;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait DataExportInterface {
+    /// Finds the next batch of the payments of the merchant to be exported
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_export_batch(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &ExportConstraints,
+        status: Option<&[enums::IntentStatus]>,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError>;
+
+    /// Finds the next batch of the refunds of the merchant to be exported
+    async fn find_refunds_export_batch(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &ExportConstraints,
+        refund_status: Option<&[enums::RefundStatus]>,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DataExportInterface for Store {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_intents_export_batch(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &ExportConstraints,
+        status: Option<&[enums::IntentStatus]>,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::query::data_export::find_payment_intents_batch(
+            &conn,
+            merchant_id,
+            constraints,
+            status,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_refunds_export_batch(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        constraints: &ExportConstraints,
+        refund_status: Option<&[enums::RefundStatus]>,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::query::data_export::find_refunds_batch(
+            &conn,
+            merchant_id,
+            constraints,
+            refund_status,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl DataExportInterface for MockDb {
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_export_batch(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _constraints: &ExportConstraints,
+        _status: Option<&[enums::IntentStatus]>,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_refunds_export_batch(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _constraints: &ExportConstraints,
+        _refund_status: Option<&[enums::RefundStatus]>,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -55,6 +55,13 @@ pub trait DisputeInterface {
         profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
         time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::enums::DisputeStatus, i64)>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn count_disputes_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<i64, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -154,6 +161,19 @@ impl DisputeInterface for Store {
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn count_disputes_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Dispute::count_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -199,6 +219,8 @@ impl DisputeInterface for MockDb {
             merchant_connector_id: dispute.merchant_connector_id,
             dispute_amount: dispute.dispute_amount,
             organization_id: dispute.organization_id,
+            submitted_evidence_types: None,
+            evidence_submitted_at: None,
         };
 
         locked_disputes.push(new_dispute.clone());
@@ -401,6 +423,18 @@ impl DisputeInterface for MockDb {
             storage::DisputeUpdate::EvidenceUpdate { evidence } => {
                 dispute_to_update.evidence = evidence;
             }
+            storage::DisputeUpdate::EvidenceSubmittedUpdate {
+                dispute_status,
+                connector_status,
+                submitted_evidence_types,
+            } => {
+                if let Some(status) = connector_status {
+                    dispute_to_update.connector_status = status;
+                }
+                dispute_to_update.dispute_status = dispute_status;
+                dispute_to_update.submitted_evidence_types = Some(submitted_evidence_types);
+                dispute_to_update.evidence_submitted_at = Some(now);
+            }
         }
 
         dispute_to_update.modified_at = now;
@@ -451,6 +485,38 @@ impl DisputeInterface for MockDb {
             .into_iter()
             .collect::<Vec<(common_enums::DisputeStatus, i64)>>())
     }
+
+    #[cfg(feature = "v1")]
+    async fn count_disputes_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let customer_payment_ids = self
+            .payment_intents
+            .lock()
+            .await
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == *merchant_id
+                    && payment_intent.customer_id.as_ref() == Some(customer_id)
+            })
+            .map(|payment_intent| payment_intent.payment_id.clone())
+            .collect::<Vec<_>>();
+
+        let count = self
+            .disputes
+            .lock()
+            .await
+            .iter()
+            .filter(|dispute| {
+                dispute.merchant_id == *merchant_id
+                    && customer_payment_ids.contains(&dispute.payment_id)
+            })
+            .count();
+
+        Ok(i64::try_from(count).unwrap_or(i64::MAX))
+    }
 }
 
 #[cfg(test)]
@@ -799,6 +799,7 @@ mod tests {
                         )
                         .unwrap(),
                     }),
+                    webhook_endpoint_id: None,
                 },
                 &merchant_key_store,
             )
@@ -41,6 +41,12 @@ pub trait GenericLinkInterface {
         _payout_link: storage::GenericLinkNew,
     ) -> CustomResult<storage::PayoutLink, errors::StorageError>;
 
+    async fn update_pm_collect_link(
+        &self,
+        pm_collect_link: storage::PaymentMethodCollectLink,
+        pm_collect_link_update: storage::PaymentMethodCollectLinkUpdate,
+    ) -> CustomResult<storage::PaymentMethodCollectLink, errors::StorageError>;
+
     async fn update_payout_link(
         &self,
         payout_link: storage::PayoutLink,
@@ -119,6 +125,19 @@ impl GenericLinkInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn update_pm_collect_link(
+        &self,
+        pm_collect_link: storage::PaymentMethodCollectLink,
+        pm_collect_link_update: storage::PaymentMethodCollectLinkUpdate,
+    ) -> CustomResult<storage::PaymentMethodCollectLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        pm_collect_link
+            .update_pm_collect_link(&conn, pm_collect_link_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn update_payout_link(
         &self,
@@ -183,6 +202,15 @@ impl GenericLinkInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn update_pm_collect_link(
+        &self,
+        _pm_collect_link: storage::PaymentMethodCollectLink,
+        _pm_collect_link_update: storage::PaymentMethodCollectLinkUpdate,
+    ) -> CustomResult<storage::PaymentMethodCollectLink, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn update_payout_link(
         &self,
         _payout_link: storage::PayoutLink,
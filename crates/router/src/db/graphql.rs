@@ -0,0 +1,229 @@
+use common_utils::id_type;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait GraphqlInterface {
+    /// Finds the payments of the merchant with any of the payment ids
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_by_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError>;
+
+    /// Finds the attempts of the payments of the merchant with any of the payment ids
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempts_by_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError>;
+
+    /// Finds the refunds of the payments of the merchant with any of the payment ids
+    async fn find_refunds_by_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError>;
+
+    /// Finds the refunds of the merchant with any of the refund ids
+    async fn find_refunds_by_refund_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        refund_ids: &[String],
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError>;
+
+    /// Finds the disputes of the payments of the merchant with any of the payment ids
+    async fn find_disputes_by_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+
+    /// Finds the disputes of the merchant with any of the dispute ids
+    async fn find_disputes_by_dispute_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dispute_ids: &[String],
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+
+    /// Finds the customers of the merchant with any of the customer ids
+    #[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+    async fn find_customers_by_customer_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_ids: &[id_type::CustomerId],
+    ) -> CustomResult<Vec<diesel_models::Customer>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl GraphqlInterface for Store {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_intents_by_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::query::graphql::find_payment_intents_by_payment_ids(
+            &conn,
+            merchant_id,
+            payment_ids,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_attempts_by_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::query::graphql::find_payment_attempts_by_payment_ids(
+            &conn,
+            merchant_id,
+            payment_ids,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_refunds_by_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::query::graphql::find_refunds_by_payment_ids(&conn, merchant_id, payment_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_refunds_by_refund_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        refund_ids: &[String],
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::query::graphql::find_refunds_by_refund_ids(&conn, merchant_id, refund_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_disputes_by_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::query::graphql::find_disputes_by_payment_ids(&conn, merchant_id, payment_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_disputes_by_dispute_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dispute_ids: &[String],
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::query::graphql::find_disputes_by_dispute_ids(&conn, merchant_id, dispute_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+    #[instrument(skip_all)]
+    async fn find_customers_by_customer_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_ids: &[id_type::CustomerId],
+    ) -> CustomResult<Vec<diesel_models::Customer>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        diesel_models::query::graphql::find_customers_by_customer_ids(
+            &conn,
+            merchant_id,
+            customer_ids,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl GraphqlInterface for MockDb {
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_by_payment_ids(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempts_by_payment_ids(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_refunds_by_payment_ids(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_refunds_by_refund_ids(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _refund_ids: &[String],
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_disputes_by_payment_ids(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_disputes_by_dispute_ids(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _dispute_ids: &[String],
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+    async fn find_customers_by_customer_ids(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _customer_ids: &[id_type::CustomerId],
+    ) -> CustomResult<Vec<diesel_models::Customer>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
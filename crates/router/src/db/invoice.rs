@@ -0,0 +1,241 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait InvoiceInterface {
+    async fn insert_invoice(
+        &self,
+        invoice: storage::InvoiceNew,
+    ) -> CustomResult<storage::Invoice, errors::StorageError>;
+
+    async fn find_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::Invoice, errors::StorageError>;
+
+    async fn find_invoices_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError>;
+
+    async fn list_invoices_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<enums::InvoiceStatus>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError>;
+
+    async fn update_invoice(
+        &self,
+        this: storage::Invoice,
+        invoice: storage::InvoiceUpdate,
+    ) -> CustomResult<storage::Invoice, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl InvoiceInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_invoice(
+        &self,
+        invoice: storage::InvoiceNew,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        invoice
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Invoice::find_by_merchant_id_invoice_id(&conn, merchant_id, invoice_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_invoices_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Invoice::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_invoices_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<enums::InvoiceStatus>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Invoice::list_by_merchant_id(&conn, merchant_id, status, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_invoice(
+        &self,
+        this: storage::Invoice,
+        invoice: storage::InvoiceUpdate,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, invoice)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl InvoiceInterface for MockDb {
+    async fn insert_invoice(
+        &self,
+        invoice: storage::InvoiceNew,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let mut locked_invoices = self.invoices.lock().await;
+
+        if locked_invoices
+            .iter()
+            .any(|existing| existing.invoice_id == invoice.invoice_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "invoice_id",
+                key: Some(invoice.invoice_id.clone()),
+            })?;
+        }
+
+        let new_invoice = storage::Invoice {
+            invoice_id: invoice.invoice_id,
+            merchant_id: invoice.merchant_id,
+            profile_id: invoice.profile_id,
+            customer_id: invoice.customer_id,
+            status: invoice.status,
+            currency: invoice.currency,
+            amount: invoice.amount,
+            amount_paid: invoice.amount_paid,
+            line_items: invoice.line_items,
+            description: invoice.description,
+            metadata: invoice.metadata,
+            payment_ids: invoice.payment_ids,
+            due_date: invoice.due_date,
+            created_at: invoice.created_at,
+            modified_at: invoice.modified_at,
+        };
+        locked_invoices.push(new_invoice.clone());
+
+        Ok(new_invoice)
+    }
+
+    async fn find_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        self.invoices
+            .lock()
+            .await
+            .iter()
+            .find(|invoice| invoice.merchant_id == *merchant_id && invoice.invoice_id == invoice_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No invoice available for merchant_id = {merchant_id:?} and invoice_id = {invoice_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn find_invoices_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError> {
+        Ok(self
+            .invoices
+            .lock()
+            .await
+            .iter()
+            .filter(|invoice| {
+                invoice.merchant_id == *merchant_id
+                    && invoice
+                        .payment_ids
+                        .iter()
+                        .any(|linked_payment_id| linked_payment_id == payment_id.get_string_repr())
+            })
+            .cloned()
+            .collect())
+    }
+
+    async fn list_invoices_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: Option<enums::InvoiceStatus>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError> {
+        let mut invoices = self
+            .invoices
+            .lock()
+            .await
+            .iter()
+            .filter(|invoice| {
+                invoice.merchant_id == *merchant_id
+                    && status.map_or(true, |status| invoice.status == status)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        invoices.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(invoices
+            .into_iter()
+            .skip(
+                offset
+                    .and_then(|offset| usize::try_from(offset).ok())
+                    .unwrap_or_default(),
+            )
+            .take(usize::try_from(limit).unwrap_or_default())
+            .collect())
+    }
+
+    async fn update_invoice(
+        &self,
+        this: storage::Invoice,
+        invoice: storage::InvoiceUpdate,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        let mut locked_invoices = self.invoices.lock().await;
+
+        let invoice_to_update = locked_invoices
+            .iter_mut()
+            .find(|existing| existing.invoice_id == this.invoice_id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No invoice available for invoice_id = {}",
+                this.invoice_id
+            )))?;
+
+        *invoice_to_update = storage::InvoiceUpdateInternal::from(invoice)
+            .apply_changeset(invoice_to_update.clone());
+
+        Ok(invoice_to_update.clone())
+    }
+}
@@ -57,13 +57,17 @@ use crate::{
         cards_info::CardsInfoInterface,
         configs::ConfigInterface,
         customers::CustomerInterface,
+        data_export::DataExportInterface,
         dispute::DisputeInterface,
         ephemeral_key::EphemeralKeyInterface,
         events::EventInterface,
         file::FileMetadataInterface,
         generic_link::GenericLinkInterface,
+        graphql::GraphqlInterface,
         gsm::GsmInterface,
         health_check::HealthCheckDbInterface,
+        invoice::InvoiceInterface,
+        ledger_entry::LedgerEntryInterface,
         locker_mock_up::LockerMockUpInterface,
         mandate::MandateInterface,
         merchant_account::MerchantAccountInterface,
@@ -71,10 +75,16 @@ use crate::{
         merchant_key_store::MerchantKeyStoreInterface,
         payment_link::PaymentLinkInterface,
         payment_method::PaymentMethodInterface,
+        payment_order_reference::PaymentOrderReferenceInterface,
+        payment_shipment::PaymentShipmentInterface,
         refund::RefundInterface,
+        refund_tender_override::RefundTenderOverrideInterface,
         reverse_lookup::ReverseLookupInterface,
         routing_algorithm::RoutingAlgorithmInterface,
+        routing_experiment_assignment::RoutingExperimentAssignmentInterface,
+        sandbox_data::SandboxDataInterface,
         unified_translations::UnifiedTranslationsInterface,
+        webhook_endpoint::WebhookEndpointInterface,
         CommonStorageInterface, GlobalStorageInterface, MasterKeyInterface, StorageInterface,
     },
     services::{kafka::KafkaProducer, Store},
@@ -642,6 +652,17 @@ impl DisputeInterface for KafkaStore {
             .get_dispute_status_with_count(merchant_id, profile_id_list, time_range)
             .await
     }
+
+    #[cfg(feature = "v1")]
+    async fn count_disputes_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_id: &id_type::CustomerId,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .count_disputes_by_merchant_id_customer_id(merchant_id, customer_id)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -919,6 +940,429 @@ impl MandateInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl LedgerEntryInterface for KafkaStore {
+    async fn insert_ledger_entries_with_refund_reversals(
+        &self,
+        ledger_entries: Vec<storage::LedgerEntryNew>,
+        refund_reversals: Vec<storage::RefundReversal>,
+    ) -> CustomResult<(Vec<storage::LedgerEntry>, Vec<storage::Refund>), errors::StorageError> {
+        let original_refunds = refund_reversals
+            .iter()
+            .map(|refund_reversal| refund_reversal.refund.clone())
+            .collect::<Vec<_>>();
+        let (ledger_entries, reversed_refunds) = self
+            .diesel_store
+            .insert_ledger_entries_with_refund_reversals(ledger_entries, refund_reversals)
+            .await?;
+
+        for reversed_refund in &reversed_refunds {
+            let original_refund = original_refunds
+                .iter()
+                .find(|refund| refund.refund_id == reversed_refund.refund_id)
+                .cloned();
+            if let Err(er) = self
+                .kafka_producer
+                .log_refund(reversed_refund, original_refund, self.tenant_id.clone())
+                .await
+            {
+                logger::error!(message="Failed to insert analytics event for Refund Update {reversed_refund?}", error_message=?er);
+            }
+        }
+
+        Ok((ledger_entries, reversed_refunds))
+    }
+
+    async fn find_ledger_entries_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        self.diesel_store
+            .find_ledger_entries_by_merchant_id_dispute_id(merchant_id, dispute_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentOrderReferenceInterface for KafkaStore {
+    async fn insert_payment_order_reference(
+        &self,
+        payment_order_reference: storage::PaymentOrderReferenceNew,
+    ) -> CustomResult<storage::PaymentOrderReference, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_order_reference(payment_order_reference)
+            .await
+    }
+
+    async fn find_payment_order_reference_by_profile_id_merchant_order_reference_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+        merchant_order_reference_id: &str,
+    ) -> CustomResult<storage::PaymentOrderReference, errors::StorageError> {
+        self.diesel_store
+            .find_payment_order_reference_by_profile_id_merchant_order_reference_id(
+                profile_id,
+                merchant_order_reference_id,
+            )
+            .await
+    }
+
+    async fn delete_payment_order_reference_by_profile_id_merchant_order_reference_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+        merchant_order_reference_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_payment_order_reference_by_profile_id_merchant_order_reference_id(
+                profile_id,
+                merchant_order_reference_id,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentShipmentInterface for KafkaStore {
+    async fn insert_payment_shipment(
+        &self,
+        payment_shipment: storage::PaymentShipmentNew,
+    ) -> CustomResult<storage::PaymentShipment, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_shipment(payment_shipment)
+            .await
+    }
+
+    async fn find_payment_shipments_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentShipment>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_shipments_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl DataExportInterface for KafkaStore {
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_export_batch(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        constraints: &diesel_models::query::data_export::ExportConstraints,
+        status: Option<&[common_enums::IntentStatus]>,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_intents_export_batch(merchant_id, constraints, status)
+            .await
+    }
+
+    async fn find_refunds_export_batch(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        constraints: &diesel_models::query::data_export::ExportConstraints,
+        refund_status: Option<&[common_enums::RefundStatus]>,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        self.diesel_store
+            .find_refunds_export_batch(merchant_id, constraints, refund_status)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl GraphqlInterface for KafkaStore {
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_by_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_intents_by_payment_ids(merchant_id, payment_ids)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempts_by_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<diesel_models::PaymentAttempt>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_attempts_by_payment_ids(merchant_id, payment_ids)
+            .await
+    }
+
+    async fn find_refunds_by_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        self.diesel_store
+            .find_refunds_by_payment_ids(merchant_id, payment_ids)
+            .await
+    }
+
+    async fn find_refunds_by_refund_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        refund_ids: &[String],
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        self.diesel_store
+            .find_refunds_by_refund_ids(merchant_id, refund_ids)
+            .await
+    }
+
+    async fn find_disputes_by_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: &[id_type::PaymentId],
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        self.diesel_store
+            .find_disputes_by_payment_ids(merchant_id, payment_ids)
+            .await
+    }
+
+    async fn find_disputes_by_dispute_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        dispute_ids: &[String],
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        self.diesel_store
+            .find_disputes_by_dispute_ids(merchant_id, dispute_ids)
+            .await
+    }
+
+    #[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+    async fn find_customers_by_customer_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        customer_ids: &[id_type::CustomerId],
+    ) -> CustomResult<Vec<diesel_models::Customer>, errors::StorageError> {
+        self.diesel_store
+            .find_customers_by_customer_ids(merchant_id, customer_ids)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl SandboxDataInterface for KafkaStore {
+    #[cfg(feature = "v1")]
+    async fn delete_sandbox_data_batch(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        resource: common_enums::SandboxDataResource,
+        batch_size: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .delete_sandbox_data_batch(merchant_id, resource, batch_size)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_sandbox_payment_method_locker_references(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        batch_size: i64,
+    ) -> CustomResult<
+        Vec<diesel_models::query::sandbox_data::PaymentMethodLockerReference>,
+        errors::StorageError,
+    > {
+        self.diesel_store
+            .find_sandbox_payment_method_locker_references(merchant_id, batch_size)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn delete_sandbox_payment_methods(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_method_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .delete_sandbox_payment_methods(merchant_id, payment_method_ids)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl RoutingExperimentAssignmentInterface for KafkaStore {
+    async fn insert_routing_experiment_assignment_if_not_exists(
+        &self,
+        assignment: storage::RoutingExperimentAssignmentNew,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .insert_routing_experiment_assignment_if_not_exists(assignment)
+            .await
+    }
+
+    async fn update_routing_experiment_assignment_outcome(
+        &self,
+        experiment_id: &str,
+        payment_id: &id_type::PaymentId,
+        succeeded: bool,
+        completed_at: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .update_routing_experiment_assignment_outcome(
+                experiment_id,
+                payment_id,
+                succeeded,
+                completed_at,
+            )
+            .await
+    }
+
+    async fn count_routing_experiment_assignments(
+        &self,
+        experiment_id: &str,
+    ) -> CustomResult<Vec<storage::RoutingExperimentArmCount>, errors::StorageError> {
+        self.diesel_store
+            .count_routing_experiment_assignments(experiment_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl RefundTenderOverrideInterface for KafkaStore {
+    async fn insert_refund_tender_override(
+        &self,
+        tender_override: storage::RefundTenderOverrideNew,
+    ) -> CustomResult<storage::RefundTenderOverride, errors::StorageError> {
+        self.diesel_store
+            .insert_refund_tender_override(tender_override)
+            .await
+    }
+
+    async fn update_refund_tender_override(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        override_id: &str,
+        tender_override_update: storage::RefundTenderOverrideUpdate,
+    ) -> CustomResult<storage::RefundTenderOverride, errors::StorageError> {
+        self.diesel_store
+            .update_refund_tender_override(merchant_id, override_id, tender_override_update)
+            .await
+    }
+
+    async fn find_refund_tender_overrides_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::RefundTenderOverride>, errors::StorageError> {
+        self.diesel_store
+            .find_refund_tender_overrides_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for KafkaStore {
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .insert_webhook_endpoint(webhook_endpoint)
+            .await
+    }
+
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .find_webhook_endpoint_by_profile_id_endpoint_id(profile_id, endpoint_id)
+            .await
+    }
+
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        self.diesel_store
+            .list_webhook_endpoints_by_profile_id(profile_id)
+            .await
+    }
+
+    async fn update_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .update_webhook_endpoint_by_profile_id_endpoint_id(
+                profile_id,
+                endpoint_id,
+                webhook_endpoint_update,
+            )
+            .await
+    }
+
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.diesel_store
+            .delete_webhook_endpoint_by_profile_id_endpoint_id(profile_id, endpoint_id)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl InvoiceInterface for KafkaStore {
+    async fn insert_invoice(
+        &self,
+        invoice: storage::InvoiceNew,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        self.diesel_store.insert_invoice(invoice).await
+    }
+
+    async fn find_invoice_by_merchant_id_invoice_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        invoice_id: &str,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        self.diesel_store
+            .find_invoice_by_merchant_id_invoice_id(merchant_id, invoice_id)
+            .await
+    }
+
+    async fn find_invoices_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_id: &id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError> {
+        self.diesel_store
+            .find_invoices_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+
+    async fn list_invoices_by_merchant_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        status: Option<enums::InvoiceStatus>,
+        limit: i64,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::Invoice>, errors::StorageError> {
+        self.diesel_store
+            .list_invoices_by_merchant_id(merchant_id, status, limit, offset)
+            .await
+    }
+
+    async fn update_invoice(
+        &self,
+        this: storage::Invoice,
+        invoice: storage::InvoiceUpdate,
+    ) -> CustomResult<storage::Invoice, errors::StorageError> {
+        self.diesel_store.update_invoice(this, invoice).await
+    }
+}
+
 #[async_trait::async_trait]
 impl PaymentLinkInterface for KafkaStore {
     async fn find_payment_link_by_payment_link_id(
@@ -1328,6 +1772,27 @@ impl QueueInterface for KafkaStore {
         self.diesel_store.release_pt_lock(tag, lock_key).await
     }
 
+    async fn acquire_or_renew_leader_lease(
+        &self,
+        lease_key: &str,
+        instance_id: &str,
+        ttl: i64,
+    ) -> CustomResult<bool, RedisError> {
+        self.diesel_store
+            .acquire_or_renew_leader_lease(lease_key, instance_id, ttl)
+            .await
+    }
+
+    async fn release_leader_lease(
+        &self,
+        lease_key: &str,
+        instance_id: &str,
+    ) -> CustomResult<(), RedisError> {
+        self.diesel_store
+            .release_leader_lease(lease_key, instance_id)
+            .await
+    }
+
     async fn stream_append_entry(
         &self,
         stream: &str,
@@ -1930,6 +2395,29 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_by_merchant_id_connector_mandate_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        connector_mandate_id: &str,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_by_merchant_id_connector_mandate_id(
+                state,
+                key_store,
+                merchant_id,
+                merchant_connector_id,
+                connector_mandate_id,
+            )
+            .await
+    }
+
     #[cfg(all(
         any(feature = "v1", feature = "v2"),
         not(feature = "payment_methods_v2")
@@ -2347,6 +2835,27 @@ impl ProcessTrackerInterface for KafkaStore {
             .find_processes_by_time_status(time_lower_limit, time_upper_limit, status, limit)
             .await
     }
+
+    async fn find_processes_by_id_suffix_time_status(
+        &self,
+        id_suffix: &str,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: ProcessTrackerStatus,
+        after_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .find_processes_by_id_suffix_time_status(
+                id_suffix,
+                time_lower_limit,
+                time_upper_limit,
+                status,
+                after_id,
+                limit,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3591,6 +4100,16 @@ impl GenericLinkInterface for KafkaStore {
         self.diesel_store.insert_payout_link(pm_collect_link).await
     }
 
+    async fn update_pm_collect_link(
+        &self,
+        pm_collect_link: storage::PaymentMethodCollectLink,
+        pm_collect_link_update: storage::PaymentMethodCollectLinkUpdate,
+    ) -> CustomResult<storage::PaymentMethodCollectLink, errors::StorageError> {
+        self.diesel_store
+            .update_pm_collect_link(pm_collect_link, pm_collect_link_update)
+            .await
+    }
+
     async fn update_payout_link(
         &self,
         payout_link: storage::PayoutLink,
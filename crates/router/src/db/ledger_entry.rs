@@ -0,0 +1,125 @@
+use diesel_models::refund::RefundUpdateInternal;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait LedgerEntryInterface {
+    async fn insert_ledger_entries_with_refund_reversals(
+        &self,
+        ledger_entries: Vec<storage::LedgerEntryNew>,
+        refund_reversals: Vec<storage::RefundReversal>,
+    ) -> CustomResult<(Vec<storage::LedgerEntry>, Vec<storage::Refund>), errors::StorageError>;
+
+    async fn find_ledger_entries_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl LedgerEntryInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_ledger_entries_with_refund_reversals(
+        &self,
+        ledger_entries: Vec<storage::LedgerEntryNew>,
+        refund_reversals: Vec<storage::RefundReversal>,
+    ) -> CustomResult<(Vec<storage::LedgerEntry>, Vec<storage::Refund>), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::LedgerEntry::insert_with_refund_reversals(&conn, ledger_entries, refund_reversals)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_ledger_entries_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LedgerEntry::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl LedgerEntryInterface for MockDb {
+    async fn insert_ledger_entries_with_refund_reversals(
+        &self,
+        mut ledger_entries: Vec<storage::LedgerEntryNew>,
+        refund_reversals: Vec<storage::RefundReversal>,
+    ) -> CustomResult<(Vec<storage::LedgerEntry>, Vec<storage::Refund>), errors::StorageError> {
+        let mut locked_ledger_entries = self.ledger_entries.lock().await;
+        let mut locked_refunds = self.refunds.lock().await;
+
+        let mut reversed_refunds = Vec::new();
+        let mut updated_refunds = locked_refunds.clone();
+        for refund_reversal in refund_reversals {
+            if let Some(refund) = updated_refunds.iter_mut().find(|refund| {
+                refund.merchant_id == refund_reversal.refund.merchant_id
+                    && refund.refund_id == refund_reversal.refund.refund_id
+                    && refund.refund_status == enums::RefundStatus::Pending
+                    && !refund.sent_to_gateway
+            }) {
+                *refund = RefundUpdateInternal::from(refund_reversal.refund_update)
+                    .create_refund(refund.clone());
+                reversed_refunds.push(refund.clone());
+                ledger_entries.push(refund_reversal.ledger_entry);
+            }
+        }
+
+        // Mirrors the unique indexes of the ledger entries of a dispute
+        let is_duplicate = |existing: &storage::LedgerEntry, new: &storage::LedgerEntryNew| {
+            existing.dispute_id.is_some()
+                && existing.dispute_id == new.dispute_id
+                && existing.entry_type == new.entry_type
+                && existing.refund_id == new.refund_id
+        };
+        if let Some(duplicate) = ledger_entries.iter().find(|ledger_entry| {
+            locked_ledger_entries
+                .iter()
+                .any(|existing| is_duplicate(existing, ledger_entry))
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "ledger_entry",
+                key: duplicate.dispute_id.clone(),
+            })?;
+        }
+
+        let recorded_ledger_entries = ledger_entries
+            .into_iter()
+            .map(storage::LedgerEntry::from)
+            .collect::<Vec<_>>();
+        locked_ledger_entries.extend(recorded_ledger_entries.iter().cloned());
+        *locked_refunds = updated_refunds;
+
+        Ok((recorded_ledger_entries, reversed_refunds))
+    }
+
+    async fn find_ledger_entries_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        Ok(self
+            .ledger_entries
+            .lock()
+            .await
+            .iter()
+            .filter(|ledger_entry| {
+                ledger_entry.merchant_id == *merchant_id
+                    && ledger_entry.dispute_id.as_deref() == Some(dispute_id)
+            })
+            .cloned()
+            .collect())
+    }
+}
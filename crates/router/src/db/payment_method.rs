@@ -93,6 +93,19 @@ pub trait PaymentMethodInterface {
         status: common_enums::PaymentMethodStatus,
     ) -> CustomResult<i64, errors::StorageError>;
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_by_merchant_id_connector_mandate_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        connector_mandate_id: &str,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError>;
+
     async fn insert_payment_method(
         &self,
         state: &KeyManagerState,
@@ -399,6 +412,47 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        // not supported in kv
+        #[cfg(all(
+            any(feature = "v1", feature = "v2"),
+            not(feature = "payment_methods_v2")
+        ))]
+        #[instrument(skip_all)]
+        async fn find_payment_method_by_merchant_id_connector_mandate_id(
+            &self,
+            state: &KeyManagerState,
+            key_store: &domain::MerchantKeyStore,
+            merchant_id: &id_type::MerchantId,
+            merchant_connector_id: &id_type::MerchantConnectorAccountId,
+            connector_mandate_id: &str,
+        ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let payment_methods =
+                storage_types::PaymentMethod::find_by_merchant_id_connector_mandate_id(
+                    &conn,
+                    merchant_id,
+                    merchant_connector_id,
+                    connector_mandate_id,
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+            let pm_futures = payment_methods
+                .into_iter()
+                .map(|pm| async {
+                    pm.convert(
+                        state,
+                        key_store.key.get_inner(),
+                        key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)
+                })
+                .collect::<Vec<_>>();
+
+            futures::future::try_join_all(pm_futures).await
+        }
+
         #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
         #[instrument(skip_all)]
         async fn insert_payment_method(
@@ -977,6 +1031,46 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[cfg(all(
+            any(feature = "v1", feature = "v2"),
+            not(feature = "payment_methods_v2")
+        ))]
+        #[instrument(skip_all)]
+        async fn find_payment_method_by_merchant_id_connector_mandate_id(
+            &self,
+            state: &KeyManagerState,
+            key_store: &domain::MerchantKeyStore,
+            merchant_id: &id_type::MerchantId,
+            merchant_connector_id: &id_type::MerchantConnectorAccountId,
+            connector_mandate_id: &str,
+        ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            let payment_methods =
+                storage_types::PaymentMethod::find_by_merchant_id_connector_mandate_id(
+                    &conn,
+                    merchant_id,
+                    merchant_connector_id,
+                    connector_mandate_id,
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?;
+
+            let pm_futures = payment_methods
+                .into_iter()
+                .map(|pm| async {
+                    pm.convert(
+                        state,
+                        key_store.key.get_inner(),
+                        key_store.merchant_id.clone().into(),
+                    )
+                    .await
+                    .change_context(errors::StorageError::DecryptionError)
+                })
+                .collect::<Vec<_>>();
+
+            futures::future::try_join_all(pm_futures).await
+        }
+
         #[instrument(skip_all)]
         async fn insert_payment_method(
             &self,
@@ -1363,6 +1457,50 @@ impl PaymentMethodInterface for MockDb {
         i64::try_from(count).change_context(errors::StorageError::MockDbError)
     }
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn find_payment_method_by_merchant_id_connector_mandate_id(
+        &self,
+        state: &KeyManagerState,
+        key_store: &domain::MerchantKeyStore,
+        merchant_id: &id_type::MerchantId,
+        merchant_connector_id: &id_type::MerchantConnectorAccountId,
+        connector_mandate_id: &str,
+    ) -> CustomResult<Vec<domain::PaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let payment_methods_found: Vec<storage_types::PaymentMethod> = payment_methods
+            .iter()
+            .filter(|pm| {
+                pm.merchant_id == *merchant_id
+                    && pm
+                        .connector_mandate_details
+                        .as_ref()
+                        .and_then(|details| details.get(merchant_connector_id.get_string_repr()))
+                        .and_then(|record| record.get("connector_mandate_id"))
+                        .and_then(|id| id.as_str())
+                        == Some(connector_mandate_id)
+            })
+            .cloned()
+            .collect();
+
+        let pm_futures = payment_methods_found
+            .into_iter()
+            .map(|pm| async {
+                pm.convert(
+                    state,
+                    key_store.key.get_inner(),
+                    key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)
+            })
+            .collect::<Vec<_>>();
+
+        futures::future::try_join_all(pm_futures).await
+    }
+
     async fn insert_payment_method(
         &self,
         _state: &KeyManagerState,
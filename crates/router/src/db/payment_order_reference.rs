@@ -0,0 +1,142 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentOrderReferenceInterface {
+    async fn insert_payment_order_reference(
+        &self,
+        payment_order_reference: storage::PaymentOrderReferenceNew,
+    ) -> CustomResult<storage::PaymentOrderReference, errors::StorageError>;
+
+    async fn find_payment_order_reference_by_profile_id_merchant_order_reference_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_order_reference_id: &str,
+    ) -> CustomResult<storage::PaymentOrderReference, errors::StorageError>;
+
+    async fn delete_payment_order_reference_by_profile_id_merchant_order_reference_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_order_reference_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentOrderReferenceInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_order_reference(
+        &self,
+        payment_order_reference: storage::PaymentOrderReferenceNew,
+    ) -> CustomResult<storage::PaymentOrderReference, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_order_reference
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_order_reference_by_profile_id_merchant_order_reference_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_order_reference_id: &str,
+    ) -> CustomResult<storage::PaymentOrderReference, errors::StorageError> {
+        // Read from the primary, since the reference is looked up right after a conflicting insert
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentOrderReference::find_by_profile_id_merchant_order_reference_id(
+            &conn,
+            profile_id,
+            merchant_order_reference_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_payment_order_reference_by_profile_id_merchant_order_reference_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_order_reference_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentOrderReference::delete_by_profile_id_merchant_order_reference_id(
+            &conn,
+            profile_id,
+            merchant_order_reference_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentOrderReferenceInterface for MockDb {
+    async fn insert_payment_order_reference(
+        &self,
+        payment_order_reference: storage::PaymentOrderReferenceNew,
+    ) -> CustomResult<storage::PaymentOrderReference, errors::StorageError> {
+        let mut locked_payment_order_references = self.payment_order_references.lock().await;
+
+        if locked_payment_order_references.iter().any(|existing| {
+            existing.profile_id == payment_order_reference.profile_id
+                && existing.merchant_order_reference_id
+                    == payment_order_reference.merchant_order_reference_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "merchant_order_reference_id",
+                key: Some(payment_order_reference.merchant_order_reference_id.clone()),
+            })?;
+        }
+
+        let payment_order_reference = storage::PaymentOrderReference::from(payment_order_reference);
+        locked_payment_order_references.push(payment_order_reference.clone());
+
+        Ok(payment_order_reference)
+    }
+
+    async fn find_payment_order_reference_by_profile_id_merchant_order_reference_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_order_reference_id: &str,
+    ) -> CustomResult<storage::PaymentOrderReference, errors::StorageError> {
+        self.payment_order_references
+            .lock()
+            .await
+            .iter()
+            .find(|payment_order_reference| {
+                payment_order_reference.profile_id == *profile_id
+                    && payment_order_reference.merchant_order_reference_id
+                        == merchant_order_reference_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No payment available for merchant_order_reference_id = {merchant_order_reference_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn delete_payment_order_reference_by_profile_id_merchant_order_reference_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        merchant_order_reference_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let mut locked_payment_order_references = self.payment_order_references.lock().await;
+        let initial_len = locked_payment_order_references.len();
+        locked_payment_order_references.retain(|payment_order_reference| {
+            payment_order_reference.profile_id != *profile_id
+                || payment_order_reference.merchant_order_reference_id
+                    != merchant_order_reference_id
+        });
+
+        Ok(locked_payment_order_references.len() != initial_len)
+    }
+}
@@ -0,0 +1,68 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentShipmentInterface {
+    async fn insert_payment_shipment(
+        &self,
+        payment_shipment: storage::PaymentShipmentNew,
+    ) -> CustomResult<storage::PaymentShipment, errors::StorageError>;
+
+    async fn find_payment_shipments_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentShipment>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentShipmentInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_shipment(
+        &self,
+        payment_shipment: storage::PaymentShipmentNew,
+    ) -> CustomResult<storage::PaymentShipment, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_shipment
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_shipments_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentShipment>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentShipment::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentShipmentInterface for MockDb {
+    async fn insert_payment_shipment(
+        &self,
+        _payment_shipment: storage::PaymentShipmentNew,
+    ) -> CustomResult<storage::PaymentShipment, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_shipments_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentShipment>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -436,6 +436,9 @@ mod storage {
                         organization_id: new.organization_id.clone(),
                         connector_refund_data: new.connector_refund_data.clone(),
                         connector_transaction_data: new.connector_transaction_data.clone(),
+                        settlement_currency: None,
+                        settlement_amount: None,
+                        expected_settlement_at: None,
                     };
 
                     let field = format!(
@@ -930,6 +933,9 @@ impl RefundInterface for MockDb {
             organization_id: new.organization_id,
             connector_refund_data: new.connector_refund_data,
             connector_transaction_data: new.connector_transaction_data,
+            settlement_currency: None,
+            settlement_amount: None,
+            expected_settlement_at: None,
         };
         refunds.push(refund.clone());
         Ok(refund)
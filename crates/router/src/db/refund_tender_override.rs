@@ -0,0 +1,108 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait RefundTenderOverrideInterface {
+    async fn insert_refund_tender_override(
+        &self,
+        tender_override: storage::RefundTenderOverrideNew,
+    ) -> CustomResult<storage::RefundTenderOverride, errors::StorageError>;
+
+    async fn update_refund_tender_override(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        override_id: &str,
+        tender_override_update: storage::RefundTenderOverrideUpdate,
+    ) -> CustomResult<storage::RefundTenderOverride, errors::StorageError>;
+
+    async fn find_refund_tender_overrides_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::RefundTenderOverride>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RefundTenderOverrideInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_refund_tender_override(
+        &self,
+        tender_override: storage::RefundTenderOverrideNew,
+    ) -> CustomResult<storage::RefundTenderOverride, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        tender_override
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_refund_tender_override(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        override_id: &str,
+        tender_override_update: storage::RefundTenderOverrideUpdate,
+    ) -> CustomResult<storage::RefundTenderOverride, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RefundTenderOverride::update_by_merchant_id_override_id(
+            &conn,
+            merchant_id,
+            override_id,
+            tender_override_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_refund_tender_overrides_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::RefundTenderOverride>, errors::StorageError> {
+        // Read from the primary, as the refundable amount of the payment is derived from the
+        // overrides recorded by the previous refunds
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RefundTenderOverride::find_by_merchant_id_payment_id(
+            &conn,
+            merchant_id,
+            payment_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl RefundTenderOverrideInterface for MockDb {
+    async fn insert_refund_tender_override(
+        &self,
+        _tender_override: storage::RefundTenderOverrideNew,
+    ) -> CustomResult<storage::RefundTenderOverride, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_refund_tender_override(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _override_id: &str,
+        _tender_override_update: storage::RefundTenderOverrideUpdate,
+    ) -> CustomResult<storage::RefundTenderOverride, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_refund_tender_overrides_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::RefundTenderOverride>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
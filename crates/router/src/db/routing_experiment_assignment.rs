@@ -0,0 +1,103 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait RoutingExperimentAssignmentInterface {
+    async fn insert_routing_experiment_assignment_if_not_exists(
+        &self,
+        assignment: storage::RoutingExperimentAssignmentNew,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn update_routing_experiment_assignment_outcome(
+        &self,
+        experiment_id: &str,
+        payment_id: &common_utils::id_type::PaymentId,
+        succeeded: bool,
+        completed_at: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn count_routing_experiment_assignments(
+        &self,
+        experiment_id: &str,
+    ) -> CustomResult<Vec<storage::RoutingExperimentArmCount>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl RoutingExperimentAssignmentInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_routing_experiment_assignment_if_not_exists(
+        &self,
+        assignment: storage::RoutingExperimentAssignmentNew,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        assignment
+            .insert_if_not_exists(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_routing_experiment_assignment_outcome(
+        &self,
+        experiment_id: &str,
+        payment_id: &common_utils::id_type::PaymentId,
+        succeeded: bool,
+        completed_at: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::RoutingExperimentAssignment::update_outcome(
+            &conn,
+            experiment_id,
+            payment_id,
+            succeeded,
+            completed_at,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn count_routing_experiment_assignments(
+        &self,
+        experiment_id: &str,
+    ) -> CustomResult<Vec<storage::RoutingExperimentArmCount>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::RoutingExperimentAssignment::count_by_experiment_id(&conn, experiment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl RoutingExperimentAssignmentInterface for MockDb {
+    async fn insert_routing_experiment_assignment_if_not_exists(
+        &self,
+        _assignment: storage::RoutingExperimentAssignmentNew,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_routing_experiment_assignment_outcome(
+        &self,
+        _experiment_id: &str,
+        _payment_id: &common_utils::id_type::PaymentId,
+        _succeeded: bool,
+        _completed_at: time::PrimitiveDateTime,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn count_routing_experiment_assignments(
+        &self,
+        _experiment_id: &str,
+    ) -> CustomResult<Vec<storage::RoutingExperimentArmCount>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
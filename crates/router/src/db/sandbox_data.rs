@@ -0,0 +1,123 @@
+use common_enums::SandboxDataResource;
+#[cfg(feature = "v1")]
+use diesel_models::query::sandbox_data::PaymentMethodLockerReference;
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+};
+
+#[async_trait::async_trait]
+pub trait SandboxDataInterface {
+    /// Deletes a batch of the records of a resource of the merchant, returning the number of
+    /// records deleted
+    #[cfg(feature = "v1")]
+    async fn delete_sandbox_data_batch(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        resource: SandboxDataResource,
+        batch_size: i64,
+    ) -> CustomResult<usize, errors::StorageError>;
+
+    /// Finds the locker references of a batch of the payment methods of the merchant
+    #[cfg(feature = "v1")]
+    async fn find_sandbox_payment_method_locker_references(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_size: i64,
+    ) -> CustomResult<Vec<PaymentMethodLockerReference>, errors::StorageError>;
+
+    /// Deletes the given payment methods of the merchant, returning the number of records deleted
+    #[cfg(feature = "v1")]
+    async fn delete_sandbox_payment_methods(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SandboxDataInterface for Store {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn delete_sandbox_data_batch(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        resource: SandboxDataResource,
+        batch_size: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        diesel_models::query::sandbox_data::delete_batch(&conn, merchant_id, resource, batch_size)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_sandbox_payment_method_locker_references(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_size: i64,
+    ) -> CustomResult<Vec<PaymentMethodLockerReference>, errors::StorageError> {
+        // Read from the primary, as the payment methods of the previous batch were just deleted
+        let conn = connection::pg_connection_write(self).await?;
+        diesel_models::query::sandbox_data::find_payment_method_locker_references(
+            &conn,
+            merchant_id,
+            batch_size,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn delete_sandbox_payment_methods(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        diesel_models::query::sandbox_data::delete_payment_methods(
+            &conn,
+            merchant_id,
+            payment_method_ids,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl SandboxDataInterface for MockDb {
+    #[cfg(feature = "v1")]
+    async fn delete_sandbox_data_batch(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _resource: SandboxDataResource,
+        _batch_size: i64,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_sandbox_payment_method_locker_references(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_size: i64,
+    ) -> CustomResult<Vec<PaymentMethodLockerReference>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn delete_sandbox_payment_methods(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_method_ids: Vec<String>,
+    ) -> CustomResult<usize, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
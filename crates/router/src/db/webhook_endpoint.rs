@@ -0,0 +1,212 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait WebhookEndpointInterface {
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError>;
+
+    async fn update_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        webhook_endpoint
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::find_by_profile_id_endpoint_id(&conn, profile_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::WebhookEndpoint::list_by_profile_id(&conn, profile_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::update_by_profile_id_endpoint_id(
+            &conn,
+            profile_id,
+            endpoint_id,
+            webhook_endpoint_update,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::WebhookEndpoint::delete_by_profile_id_endpoint_id(&conn, profile_id, endpoint_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl WebhookEndpointInterface for MockDb {
+    async fn insert_webhook_endpoint(
+        &self,
+        webhook_endpoint: storage::WebhookEndpointNew,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let mut locked_webhook_endpoints = self.webhook_endpoints.lock().await;
+
+        if locked_webhook_endpoints
+            .iter()
+            .any(|existing| existing.endpoint_id == webhook_endpoint.endpoint_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "endpoint_id",
+                key: Some(webhook_endpoint.endpoint_id.clone()),
+            })?;
+        }
+
+        let webhook_endpoint = storage::WebhookEndpoint::from(webhook_endpoint);
+        locked_webhook_endpoints.push(webhook_endpoint.clone());
+
+        Ok(webhook_endpoint)
+    }
+
+    async fn find_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        self.webhook_endpoints
+            .lock()
+            .await
+            .iter()
+            .find(|webhook_endpoint| {
+                webhook_endpoint.profile_id == *profile_id
+                    && webhook_endpoint.endpoint_id == endpoint_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "No webhook endpoint available for endpoint_id = {endpoint_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_webhook_endpoints_by_profile_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+    ) -> CustomResult<Vec<storage::WebhookEndpoint>, errors::StorageError> {
+        Ok(self
+            .webhook_endpoints
+            .lock()
+            .await
+            .iter()
+            .filter(|webhook_endpoint| webhook_endpoint.profile_id == *profile_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+        webhook_endpoint_update: storage::WebhookEndpointUpdate,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let mut locked_webhook_endpoints = self.webhook_endpoints.lock().await;
+        let webhook_endpoint = locked_webhook_endpoints
+            .iter_mut()
+            .find(|webhook_endpoint| {
+                webhook_endpoint.profile_id == *profile_id
+                    && webhook_endpoint.endpoint_id == endpoint_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook endpoint available for endpoint_id = {endpoint_id}"
+            )))?;
+
+        *webhook_endpoint = storage::WebhookEndpointUpdateInternal::from(webhook_endpoint_update)
+            .apply_changeset(webhook_endpoint.clone());
+
+        Ok(webhook_endpoint.clone())
+    }
+
+    async fn delete_webhook_endpoint_by_profile_id_endpoint_id(
+        &self,
+        profile_id: &common_utils::id_type::ProfileId,
+        endpoint_id: &str,
+    ) -> CustomResult<storage::WebhookEndpoint, errors::StorageError> {
+        let mut locked_webhook_endpoints = self.webhook_endpoints.lock().await;
+        let position = locked_webhook_endpoints
+            .iter()
+            .position(|webhook_endpoint| {
+                webhook_endpoint.profile_id == *profile_id
+                    && webhook_endpoint.endpoint_id == endpoint_id
+            })
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No webhook endpoint available for endpoint_id = {endpoint_id}"
+            )))?;
+
+        Ok(locked_webhook_endpoints.remove(position))
+    }
+}
@@ -1,5 +1,5 @@
-use api_models::payments::Amount;
-use common_utils::types::MinorUnit;
+use api_models::{enums::MerchantAccountStatus, payments::Amount};
+use common_utils::{id_type, types::MinorUnit};
 use diesel_models::fraud_check::FraudCheck;
 use events::{Event, EventInfo};
 use serde::Serialize;
@@ -37,6 +37,32 @@ pub enum AuditEventType {
         error_code: Option<String>,
         error_message: Option<String>,
     },
+    MerchantAccountStatusUpdate {
+        merchant_id: id_type::MerchantId,
+        previous_status: MerchantAccountStatus,
+        status: MerchantAccountStatus,
+        reason: Option<String>,
+    },
+    MerchantKeyDestroyed {
+        merchant_id: id_type::MerchantId,
+        reason: Option<String>,
+    },
+    RefundTenderOverridden {
+        payment_id: id_type::PaymentId,
+        override_id: String,
+        payout_id: String,
+        amount: MinorUnit,
+        reason: String,
+        user_id: String,
+    },
+    ChallengePageServed,
+    AcsCallbackReceived {
+        merchant_id: id_type::MerchantId,
+        payment_id: id_type::PaymentId,
+        connector: String,
+        /// Time from the challenge page being served to the callback of the ACS
+        challenge_duration_in_secs: i64,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -79,6 +105,11 @@ impl Event for AuditEvent {
             AuditEventType::PaymentApprove { .. } => "payment_approve",
             AuditEventType::PaymentCreate { .. } => "payment_create",
             AuditEventType::PaymentReject { .. } => "payment_rejected",
+            AuditEventType::MerchantAccountStatusUpdate { .. } => "merchant_account_status_update",
+            AuditEventType::MerchantKeyDestroyed { .. } => "merchant_key_destroyed",
+            AuditEventType::RefundTenderOverridden { .. } => "refund_tender_overridden",
+            AuditEventType::ChallengePageServed => "challenge_page_served",
+            AuditEventType::AcsCallbackReceived { .. } => "acs_callback_received",
         };
         format!(
             "{event_type}-{}",
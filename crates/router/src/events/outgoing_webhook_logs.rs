@@ -57,6 +57,22 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    Invoice {
+        invoice_id: String,
+        content: Value,
+    },
+    PaymentMethodCollectLink {
+        pm_collect_link_id: String,
+        content: Value,
+    },
+    PaymentMethod {
+        payment_method_id: String,
+        content: Value,
+    },
+    MerchantConnector {
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -88,12 +104,58 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&mandate_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::MandateDebitDetails(mandate_debit_payload) => {
+                Some(OutgoingWebhookEventContent::Mandate {
+                    payment_method_id: mandate_debit_payload.payment_method_id.clone(),
+                    mandate_id: mandate_debit_payload.mandate_id.clone(),
+                    content: masking::masked_serialize(&mandate_debit_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
             #[cfg(feature = "payouts")]
             Self::PayoutDetails(payout_payload) => Some(OutgoingWebhookEventContent::Payout {
                 payout_id: payout_payload.payout_id.clone(),
                 content: masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::InvoiceDetails(invoice_payload) => Some(OutgoingWebhookEventContent::Invoice {
+                invoice_id: invoice_payload.invoice_id.clone(),
+                content: masking::masked_serialize(&invoice_payload)
+                    .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+            }),
+            Self::PaymentMethodCollectLinkDetails(pm_collect_link_payload) => {
+                Some(OutgoingWebhookEventContent::PaymentMethodCollectLink {
+                    pm_collect_link_id: pm_collect_link_payload.pm_collect_link_id.clone(),
+                    content: masking::masked_serialize(&pm_collect_link_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::PaymentMethodTokenDetails(payment_method_payload) => {
+                Some(OutgoingWebhookEventContent::PaymentMethod {
+                    payment_method_id: payment_method_payload.payment_method_id.clone(),
+                    content: masking::masked_serialize(&payment_method_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::OrchestrationDetails(orchestration_payload) => {
+                Some(OutgoingWebhookEventContent::Payment {
+                    payment_id: orchestration_payload.payment_id.clone(),
+                    content: masking::masked_serialize(&orchestration_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::ConnectorCredentialHealthDetails(credential_health_payload) => {
+                Some(OutgoingWebhookEventContent::MerchantConnector {
+                    merchant_connector_id: credential_health_payload.merchant_connector_id.clone(),
+                    content: masking::masked_serialize(&credential_health_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::ShipmentDetails(shipment_payload) => Some(OutgoingWebhookEventContent::Payment {
+                payment_id: shipment_payload.payment_id.clone(),
+                content: masking::masked_serialize(&shipment_payload)
+                    .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+            }),
         }
     }
 }
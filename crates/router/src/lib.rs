@@ -55,6 +55,8 @@ pub mod headers {
     pub const X_CC_API_KEY: &str = "X-CC-Api-Key";
     pub const API_TOKEN: &str = "Api-Token";
     pub const AUTHORIZATION: &str = "Authorization";
+    pub const CF_IP_COUNTRY: &str = "CF-IPCountry";
+    pub const CLOUDFRONT_VIEWER_COUNTRY: &str = "CloudFront-Viewer-Country";
     pub const CONTENT_TYPE: &str = "Content-Type";
     pub const DATE: &str = "Date";
     pub const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
@@ -66,6 +68,7 @@ pub mod headers {
     pub const X_API_VERSION: &str = "X-ApiVersion";
     pub const X_FORWARDED_FOR: &str = "X-Forwarded-For";
     pub const X_MERCHANT_ID: &str = "X-Merchant-Id";
+    pub const X_MIRRORED_REQUEST_ID: &str = "X-Mirrored-Request-Id";
     pub const X_ORGANIZATION_ID: &str = "X-Organization-Id";
     pub const X_LOGIN: &str = "X-Login";
     pub const X_TRANS_KEY: &str = "X-Trans-Key";
@@ -75,6 +78,7 @@ pub mod headers {
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
+    pub const X_REQUEST_LANE: &str = "X-Request-Lane";
     pub const X_PROFILE_ID: &str = "X-Profile-Id";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
     pub const STRIPE_COMPATIBLE_CONNECT_ACCOUNT: &str = "Stripe-Account";
@@ -82,6 +86,7 @@ pub mod headers {
     pub const X_CLIENT_SOURCE: &str = "X-Client-Source";
     pub const X_PAYMENT_CONFIRM_SOURCE: &str = "X-Payment-Confirm-Source";
     pub const CONTENT_LENGTH: &str = "Content-Length";
+    pub const CONTENT_DISPOSITION: &str = "Content-Disposition";
     pub const BROWSER_NAME: &str = "x-browser-name";
     pub const X_CLIENT_PLATFORM: &str = "x-client-platform";
     pub const X_MERCHANT_DOMAIN: &str = "x-merchant-domain";
@@ -117,6 +122,11 @@ pub fn mk_app(
     {
         use routes::DummyConnector;
         server_app = server_app.service(DummyConnector::server(state.clone()));
+
+        #[cfg(feature = "v1")]
+        {
+            server_app = server_app.service(routes::Simulation::server(state.clone()));
+        }
     }
 
     #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -144,10 +154,19 @@ pub fn mk_app(
         {
             server_app = server_app
                 .service(routes::Refunds::server(state.clone()))
-                .service(routes::Mandates::server(state.clone()));
+                .service(routes::Mandates::server(state.clone()))
+                .service(routes::Receipts::server(state.clone()))
+                .service(routes::Invoices::server(state.clone()))
+                .service(routes::TestClocks::server(state.clone()))
+                .service(routes::SandboxData::server(state.clone()));
         }
     }
 
+    #[cfg(all(feature = "graphql", feature = "v1", not(feature = "customer_v2")))]
+    {
+        server_app = server_app.service(routes::GraphQL::server(state.clone()));
+    }
+
     #[cfg(all(
         feature = "oltp",
         any(feature = "v1", feature = "v2"),
@@ -182,6 +201,15 @@ pub fn mk_app(
                 .service(routes::ConnectorOnboarding::server(state.clone()))
                 .service(routes::Verify::server(state.clone()))
                 .service(routes::Analytics::server(state.clone()))
+                .service(routes::ConnectorBalances::server(state.clone()))
+                .service(routes::ConnectorDebugCaptures::server(state.clone()))
+                .service(routes::SupportBundles::server(state.clone()))
+                .service(routes::DeclarativeConfig::server(state.clone()))
+                .service(routes::PaymentMetadataSchema::server(state.clone()))
+                .service(routes::MessageBundles::server(state.clone()))
+                // This is a more specific route as compared to `WebhookEvents`
+                // so it is registered before `WebhookEvents`.
+                .service(routes::ApiLogs::server(state.clone()))
                 .service(routes::WebhookEvents::server(state.clone()));
         }
     }
@@ -210,6 +238,9 @@ pub fn mk_app(
     }
 
     server_app = server_app.service(routes::Cache::server(state.clone()));
+    server_app = server_app.service(routes::LogConfig::server(state.clone()));
+    server_app = server_app.service(routes::ConnectorOutages::server(state.clone()));
+    server_app = server_app.service(routes::ConnectorCapabilities::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state.clone()));
 
     server_app
@@ -231,6 +262,7 @@ pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> Applicatio
             services::proxy_bypass_urls(
                 conf.key_manager.get_inner(),
                 &conf.locker,
+                &conf.data_residency,
                 &conf.proxy.bypass_proxy_urls,
             ),
         )
@@ -365,7 +397,10 @@ pub fn get_application_builder(
         .wrap(cors::cors(cors))
         // this middleware works only for Http1.1 requests
         .wrap(middleware::Http400RequestDetailsLogger)
+        .wrap(middleware::SparseFieldset)
         .wrap(middleware::AddAcceptLanguageHeader)
+        // capture the risk context before the request headers are modified by the middlewares above
+        .wrap(middleware::CaptureRequestRiskContext)
         .wrap(middleware::LogSpanInitializer)
         .wrap(router_env::tracing_actix_web::TracingLogger::default())
 }
@@ -1,3 +1,4 @@
+use actix_web::HttpMessage;
 use common_utils::consts::TENANT_HEADER;
 use futures::StreamExt;
 use router_env::{
@@ -396,3 +397,185 @@ where
         })
     }
 }
+
+/// Risk relevant context of a request, as sent by the client before any of the request headers
+/// are modified by the other middlewares
+#[derive(Debug, Clone, Default)]
+pub struct RequestRiskContext {
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub accept_language: Option<String>,
+    pub accept_header: Option<String>,
+    pub origin: Option<String>,
+    /// Country of the client, as resolved from its ip address by the CDN in front of the server
+    pub ip_country: Option<String>,
+}
+
+impl RequestRiskContext {
+    pub fn new(
+        headers: &http::HeaderMap,
+        connection_info: &actix_web::dev::ConnectionInfo,
+    ) -> Self {
+        let get_header = |name: http::header::HeaderName| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(ToOwned::to_owned)
+        };
+
+        Self {
+            ip_address: connection_info.realip_remote_addr().map(ToOwned::to_owned),
+            user_agent: get_header(http::header::USER_AGENT),
+            accept_language: get_header(http::header::ACCEPT_LANGUAGE),
+            accept_header: get_header(http::header::ACCEPT),
+            origin: get_header(http::header::ORIGIN),
+            ip_country: [headers::CF_IP_COUNTRY, headers::CLOUDFRONT_VIEWER_COUNTRY]
+                .into_iter()
+                .find_map(|name| headers.get(name).and_then(|value| value.to_str().ok()))
+                .map(ToOwned::to_owned),
+        }
+    }
+
+    /// Returns the preferred language of the client from the `Accept-Language` header
+    pub fn get_preferred_language(&self) -> Option<String> {
+        self.accept_language
+            .as_deref()
+            .and_then(|accept_language| accept_language.split(',').next())
+            .and_then(|language| language.split(';').next())
+            .map(str::trim)
+            .filter(|language| !language.is_empty() && *language != "*")
+            .map(ToOwned::to_owned)
+    }
+}
+
+/// Middleware for capturing the risk relevant context of a request into the request extensions
+pub struct CaptureRequestRiskContext;
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest>
+    for CaptureRequestRiskContext
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = CaptureRequestRiskContextMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(CaptureRequestRiskContextMiddleware { service }))
+    }
+}
+
+pub struct CaptureRequestRiskContextMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest>
+    for CaptureRequestRiskContextMiddleware<S>
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        let risk_context = RequestRiskContext::new(req.headers(), &req.connection_info());
+        req.extensions_mut().insert(risk_context);
+
+        Box::pin(self.service.call(req))
+    }
+}
+
+/// Middleware for extracting the sparse fieldset of a request into the request extensions.
+///
+/// The `fields` query parameter is removed from the request uri before the request is routed, so
+/// that it is accepted by every endpoint irrespective of the query parameters the endpoint expects.
+pub struct SparseFieldset;
+
+impl<S, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for SparseFieldset
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = SparseFieldsetMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(SparseFieldsetMiddleware { service }))
+    }
+}
+
+pub struct SparseFieldsetMiddleware<S> {
+    service: S,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for SparseFieldsetMiddleware<S>
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, mut req: actix_web::dev::ServiceRequest) -> Self::Future {
+        if let Some((query_string, field_selection)) =
+            crate::services::api::sparse_fieldset::strip_from_query_string(req.query_string())
+        {
+            let path_and_query = if query_string.is_empty() {
+                req.path().to_owned()
+            } else {
+                format!("{}?{query_string}", req.path())
+            };
+            let mut uri_parts = req.uri().clone().into_parts();
+            uri_parts.path_and_query = path_and_query.parse().ok();
+
+            match actix_web::http::Uri::from_parts(uri_parts) {
+                Ok(uri) => {
+                    req.match_info_mut().get_mut().update(&uri);
+                    req.head_mut().uri = uri;
+                }
+                Err(error) => {
+                    logger::warn!(?error, "Failed to remove the fields query parameter");
+                }
+            }
+
+            if let Some(field_selection) = field_selection {
+                req.extensions_mut().insert(field_selection);
+            }
+        }
+
+        Box::pin(self.service.call(req))
+    }
+}
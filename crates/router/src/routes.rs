@@ -1,5 +1,7 @@
 pub mod admin;
 pub mod api_keys;
+#[cfg(feature = "olap")]
+pub mod api_logs;
 pub mod app;
 #[cfg(feature = "v1")]
 pub mod apple_pay_certificates_migration;
@@ -8,25 +10,46 @@ pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
 pub mod configs;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod connector_balance;
+pub mod connector_capabilities;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod connector_debug_capture;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
+pub mod connector_outage;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod declarative_config;
 pub mod disputes;
 #[cfg(feature = "dummy_connector")]
 pub mod dummy_connector;
 pub mod ephemeral_key;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod experiments;
 pub mod files;
 #[cfg(feature = "frm")]
 pub mod fraud_check;
+#[cfg(all(feature = "graphql", feature = "v1", not(feature = "customer_v2")))]
+pub mod graphql;
 pub mod gsm;
 pub mod health;
+#[cfg(feature = "v1")]
+pub mod invoices;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod localization;
 pub mod lock_utils;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
+pub mod log_config;
 pub mod mandates;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod metadata_schema;
 pub mod metrics;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod payment_descriptors;
 #[cfg(feature = "v1")]
 pub mod payment_link;
 pub mod payment_methods;
@@ -40,12 +63,22 @@ pub mod pm_auth;
 pub mod poll;
 #[cfg(feature = "olap")]
 pub mod profiles;
+#[cfg(feature = "v1")]
+pub mod receipts;
 #[cfg(feature = "recon")]
 pub mod recon;
 #[cfg(feature = "v1")]
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod sandbox_data;
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub mod simulation;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod support_bundle;
+#[cfg(feature = "v1")]
+pub mod test_clocks;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
@@ -55,6 +88,8 @@ pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_endpoints;
+#[cfg(all(feature = "olap", feature = "v1"))]
 pub mod webhook_events;
 #[cfg(feature = "v1")]
 pub mod webhooks;
@@ -63,14 +98,22 @@ pub mod webhooks;
 pub use self::app::DummyConnector;
 #[cfg(all(feature = "olap", feature = "recon", feature = "v1"))]
 pub use self::app::Recon;
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub use self::app::Simulation;
 pub use self::app::{
-    ApiKeys, AppState, ApplePayCertificatesMigration, Cache, Cards, Configs, ConnectorOnboarding,
-    Customers, Disputes, EphemeralKey, Files, Forex, Gsm, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments, Poll, Profile, ProfileNew,
-    Refunds, SessionState, User, Webhooks,
+    ApiKeys, AppState, ApplePayCertificatesMigration, Cache, Cards, Configs, ConnectorCapabilities,
+    ConnectorOnboarding, ConnectorOutages, Customers, Disputes, EphemeralKey, Files, Forex,
+    GraphQL, Gsm, Health, Invoices, LogConfig, Mandates, MerchantAccount, MerchantConnectorAccount,
+    PaymentLink, PaymentMethods, Payments, Poll, Profile, ProfileNew, Receipts, Refunds,
+    SandboxData, SessionState, TestClocks, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
+pub use self::app::{ApiLogs, Blocklist, Organization, Routing, Verify, WebhookEvents};
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub use self::app::{
+    ConnectorBalances, ConnectorDebugCaptures, DeclarativeConfig, MessageBundles,
+    PaymentMetadataSchema, SupportBundles,
+};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
 #[cfg(all(
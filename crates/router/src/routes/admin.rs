@@ -2,8 +2,13 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(feature = "v1")]
+use crate::core::connector_options;
 use crate::{
-    core::{admin::*, api_locking},
+    core::{
+        admin::*, api_locking, merchant_account_lifecycle, merchant_key_lifecycle,
+        payment_methods::external_vault,
+    },
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::admin,
 };
@@ -300,6 +305,198 @@ pub async fn delete_merchant_account(
     .await
 }
 
+/// Merchant Account - Status Retrieve
+///
+/// Retrieve the lifecycle status of a merchant account
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsAccountStatusRetrieve))]
+pub async fn merchant_account_status_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantsAccountStatusRetrieve;
+    let payload = admin::MerchantId {
+        merchant_id: path.into_inner(),
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| {
+            merchant_account_lifecycle::retrieve_merchant_account_status(state, req.merchant_id)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - Status Update
+///
+/// Suspend, reactivate or close a merchant account. Suspended merchant accounts can not create new
+/// payments or payouts, closed merchant accounts are read-only and are purged after their
+/// retention period.
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsAccountStatusUpdate))]
+pub async fn merchant_account_status_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::admin::MerchantAccountStatusUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantsAccountStatusUpdate;
+    let payload = api_models::admin::MerchantAccountStatusUpdateRequestWithId {
+        merchant_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, req_state| {
+            merchant_account_lifecycle::update_merchant_account_status(
+                state,
+                req_state,
+                req.merchant_id,
+                req.request,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - External Vault Retrieve
+///
+/// Retrieve the external tokenization service in which the card numbers of a merchant account are
+/// kept
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsExternalVaultRetrieve))]
+pub async fn merchant_external_vault_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantsExternalVaultRetrieve;
+    let payload = admin::MerchantId {
+        merchant_id: path.into_inner(),
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| external_vault::retrieve_merchant_external_vault(state, req.merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - External Vault Update
+///
+/// Set the external tokenization service in which the card numbers of a merchant account are kept
+/// in place of the card vault. Card numbers are exchanged for aliases of the service when cards
+/// are saved, and the connector requests are sent through the forward proxy of the service.
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsExternalVaultUpdate))]
+pub async fn merchant_external_vault_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::admin::MerchantExternalVaultRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantsExternalVaultUpdate;
+    let payload = api_models::admin::MerchantExternalVaultRequestWithId {
+        merchant_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| {
+            external_vault::update_merchant_external_vault(state, req.merchant_id, req.request)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Key Retrieve
+///
+/// Retrieve the status of the data encryption key of a merchant account
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsKeyRetrieve))]
+pub async fn merchant_key_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantsKeyRetrieve;
+    let payload = admin::MerchantId {
+        merchant_id: path.into_inner(),
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| merchant_key_lifecycle::retrieve_merchant_key(state, req.merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - Key Destroy
+///
+/// Destroy the data encryption key of a closed merchant account. The encrypted data of the
+/// merchant account can not be decrypted once its key is destroyed, this can not be undone.
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsKeyDestroy))]
+pub async fn merchant_key_destroy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::admin::MerchantKeyDestroyRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantsKeyDestroy;
+    let payload = api_models::admin::MerchantKeyDestroyRequestWithId {
+        merchant_id: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, req_state| {
+            merchant_key_lifecycle::destroy_merchant_key(
+                state,
+                req_state,
+                req.merchant_id,
+                req.request,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Merchant Connector - Create
 ///
 /// Create a new Merchant Connector for the merchant account. The connector could be a payment processor / facilitator / acquirer or specialized services like Fraud / Accounting etc."
@@ -774,6 +971,190 @@ pub async fn connector_delete(
     ))
     .await
 }
+
+/// Merchant Connector - Update Options
+///
+/// Sets the connector specific options of a Merchant Connector, which tune the requests sent to
+/// the connector
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/{connector_id}/options",
+    request_body = ConnectorOptions,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Connector options updated", body = ConnectorOptionsResponse),
+        (status = 400, description = "Options not supported by the connector"),
+        (status = 404, description = "Merchant Connector does not exist in records")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Update the Options of a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorOptionsUpdate))]
+pub async fn connector_options_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+    json_payload: web::Json<api_models::connector_options::ConnectorOptions>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorOptionsUpdate;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            connector_options::update_connector_options(
+                state,
+                merchant_id.clone(),
+                auth.profile_id,
+                merchant_connector_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::ProfileConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - Retrieve Options
+///
+/// Retrieves the connector specific options of a Merchant Connector
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/{connector_id}/options",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Connector options retrieved", body = ConnectorOptionsResponse),
+        (status = 404, description = "No options set for the Merchant Connector")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Retrieve the Options of a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorOptionsRetrieve))]
+pub async fn connector_options_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorOptionsRetrieve;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            connector_options::retrieve_connector_options(
+                state,
+                req.merchant_id,
+                auth.profile_id,
+                req.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::ProfileConnectorRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Connector - Delete Options
+///
+/// Removes the connector specific options of a Merchant Connector
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    delete,
+    path = "/accounts/{account_id}/connectors/{connector_id}/options",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Connector options deleted"),
+        (status = 404, description = "No options set for the Merchant Connector")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Delete the Options of a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorOptionsDelete))]
+pub async fn connector_options_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorOptionsDelete;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            connector_options::delete_connector_options(
+                state,
+                req.merchant_id,
+                auth.profile_id,
+                req.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::ProfileConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Merchant Connector - Delete
 ///
 /// Delete or Detach a Merchant Connector from Merchant Account
@@ -0,0 +1,58 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::api_logs as api_log_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, api_logs},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// API Logs - List
+///
+/// Lists the summaries of the recent API requests made by the merchant, to debug their integration
+#[utoipa::path(
+    get,
+    path = "/events/api_logs",
+    params(
+        ("url_path" = Option<String>, Query, description = "Only include requests whose path starts with this prefix"),
+        ("status_code" = Option<i64>, Query, description = "Only include requests responded to with this status code"),
+        ("error_code" = Option<String>, Query, description = "Only include requests which failed with this error code"),
+        ("api_key_id" = Option<String>, Query, description = "Only include requests authenticated with this API key"),
+        ("created_after" = Option<PrimitiveDateTime>, Query, description = "Only include requests received after this time"),
+        ("created_before" = Option<PrimitiveDateTime>, Query, description = "Only include requests received before this time"),
+        ("limit" = Option<u16>, Query, description = "The maximum number of requests to include, defaults to 100"),
+    ),
+    responses(
+        (status = 200, description = "API logs retrieved", body = ApiLogsListResponse),
+    ),
+    tag = "Event",
+    operation_id = "List API Logs",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ApiLogsList))]
+pub async fn list_api_logs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<api_log_types::ApiLogsListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ApiLogsList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            api_logs::list_api_logs(state, auth.merchant_account, constraints)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -20,8 +20,12 @@ use storage_impl::{config::TenantConfig, redis::RedisStore, MockDb};
 use tokio::sync::oneshot;
 
 use self::settings::Tenant;
+#[cfg(feature = "olap")]
+use super::api_logs;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::currency;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::declarative_config;
 #[cfg(feature = "dummy_connector")]
 use super::dummy_connector::*;
 #[cfg(all(
@@ -30,6 +34,12 @@ use super::dummy_connector::*;
     feature = "oltp"
 ))]
 use super::ephemeral_key::*;
+#[cfg(all(feature = "graphql", feature = "v1", not(feature = "customer_v2")))]
+use super::graphql;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::localization;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::metadata_schema;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::payment_methods::*;
 #[cfg(feature = "payouts")]
@@ -46,18 +56,28 @@ use super::pm_auth;
 use super::poll;
 #[cfg(feature = "olap")]
 use super::routing;
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+use super::simulation;
 #[cfg(all(feature = "olap", feature = "v1"))]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(all(feature = "oltp", feature = "v1"))]
 use super::webhooks::*;
 use super::{
-    admin, api_keys, cache::*, connector_onboarding, disputes, files, gsm, health::*, profiles,
-    user, user_role,
+    admin, api_keys, cache::*, connector_capabilities, connector_onboarding, connector_outage,
+    disputes, files, gsm, health::*, log_config, profiles, user, user_role,
 };
 #[cfg(feature = "v1")]
-use super::{apple_pay_certificates_migration, blocklist, payment_link, webhook_events};
+use super::{
+    apple_pay_certificates_migration, blocklist, invoices, payment_link, receipts, sandbox_data,
+    test_clocks, webhook_events,
+};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers::*, payments};
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::{
+    connector_balance, connector_debug_capture, experiments, payment_descriptors, support_bundle,
+    webhook_endpoints,
+};
 #[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
 use super::{mandates::*, refunds::*};
 #[cfg(feature = "olap")]
@@ -80,12 +100,16 @@ pub use crate::{
 };
 use crate::{
     configs::{secrets_transformers, Settings},
+    core::api_locking,
     db::kafka_store::{KafkaStore, TenantID},
+    services::api::request_lane::{ConnectorCallLimiter, RequestLane},
 };
 
 #[derive(Clone)]
 pub struct ReqState {
     pub event_context: events::EventContext<crate::events::EventType, EventsHandler>,
+    /// Status of the fenced lock of the request, if the request is guarded by one
+    pub fenced_lock_status: Option<api_locking::FencedLockStatus>,
 }
 
 #[derive(Clone)]
@@ -107,6 +131,8 @@ pub struct SessionState {
     #[cfg(feature = "olap")]
     pub opensearch_client: Arc<OpenSearchClient>,
     pub grpc_client: Arc<GrpcClients>,
+    pub request_lane: RequestLane,
+    pub connector_call_limiter: ConnectorCallLimiter,
 }
 impl scheduler::SchedulerSessionState for SessionState {
     fn get_db(&self) -> Box<dyn SchedulerInterface> {
@@ -117,6 +143,7 @@ impl SessionState {
     pub fn get_req_state(&self) -> ReqState {
         ReqState {
             event_context: events::EventContext::new(self.event_handler.clone()),
+            fenced_lock_status: None,
         }
     }
 }
@@ -205,6 +232,7 @@ pub struct AppState {
     pub file_storage_client: Arc<dyn FileStorageInterface>,
     pub encryption_client: Arc<dyn EncryptionManagementInterface>,
     pub grpc_client: Arc<GrpcClients>,
+    pub connector_call_limiter: ConnectorCallLimiter,
 }
 impl scheduler::SchedulerAppState for AppState {
     fn get_tenants(&self) -> Vec<String> {
@@ -288,6 +316,7 @@ impl AppState {
             &*secret_management_client,
         ))
         .await;
+        crate::connector::Declarative::register(&conf.connectors.declarative);
 
         #[allow(clippy::expect_used)]
         let encryption_client = conf
@@ -356,6 +385,8 @@ impl AppState {
 
             let grpc_client = conf.grpc_client.get_grpc_client_interface().await;
 
+            let connector_call_limiter = ConnectorCallLimiter::new(&conf.request_lanes);
+
             Self {
                 flow_name: String::from("default"),
                 stores,
@@ -373,6 +404,7 @@ impl AppState {
                 file_storage_client,
                 encryption_client,
                 grpc_client,
+                connector_call_limiter,
             }
         })
         .await
@@ -454,6 +486,8 @@ impl AppState {
             #[cfg(feature = "olap")]
             opensearch_client: Arc::clone(&self.opensearch_client),
             grpc_client: Arc::clone(&self.grpc_client),
+            request_lane: RequestLane::default(),
+            connector_call_limiter: self.connector_call_limiter.clone(),
         })
     }
 }
@@ -508,6 +542,29 @@ impl DummyConnector {
     }
 }
 
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub struct Simulation;
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+impl Simulation {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/simulation")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/mode")
+                    .route(web::get().to(simulation::retrieve_simulation_mode))
+                    .route(web::post().to(simulation::update_simulation_mode)),
+            )
+            .service(
+                web::resource("/load_tests").route(web::post().to(simulation::create_load_test)),
+            )
+            .service(
+                web::resource("/load_tests/{load_test_id}")
+                    .route(web::get().to(simulation::retrieve_load_test)),
+            )
+    }
+}
+
 pub struct Payments;
 
 #[cfg(all(
@@ -565,6 +622,7 @@ impl Payments {
                         .route(web::get().to(payments::profile_payments_list))
                         .route(web::post().to(payments::profile_payments_list_by_filter)),
                 )
+                .service(web::resource("/export").route(web::post().to(payments::payments_export)))
                 .service(
                     web::resource("/filter")
                         .route(web::post().to(payments::get_filters_for_payments)),
@@ -580,14 +638,25 @@ impl Payments {
                     web::resource("/profile/aggregate")
                         .route(web::get().to(payments::get_payments_aggregates_profile)),
                 )
+                .service(
+                    web::resource("/purchase").route(web::get().to(payments::purchase_retrieve)),
+                )
                 .service(
                     web::resource("/v2/profile/filter")
                         .route(web::get().to(payments::get_payment_filters_profile)),
                 )
+                .service(
+                    web::resource("/external/import")
+                        .route(web::post().to(payments::import_external_payments)),
+                )
                 .service(
                     web::resource("/{payment_id}/manual-update")
                         .route(web::put().to(payments::payments_manual_update)),
                 )
+                .service(
+                    web::resource("/{payment_id}/routing_decision")
+                        .route(web::get().to(payments::retrieve_payment_routing_decision)),
+                )
         }
         #[cfg(feature = "oltp")]
         {
@@ -609,6 +678,15 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/post_session_tokens").route(web::post().to(payments::payments_post_session_tokens)),
                 )
+                .service(
+                    web::resource("/{payment_id}/client_tokens").route(web::post().to(payments::payments_create_client_token)),
+                )
+                .service(
+                    web::resource("/{payment_id}/client_tokens/rotate").route(web::post().to(payments::payments_rotate_client_tokens)),
+                )
+                .service(
+                    web::resource("/{payment_id}/gift_card/balance").route(web::post().to(payments::payments_check_gift_card_balance)),
+                )
                 .service(
                     web::resource("/{payment_id}/confirm").route(web::post().to(payments::payments_confirm)),
                 )
@@ -618,6 +696,11 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments::payments_capture)),
                 )
+                .service(
+                    web::resource("/{payment_id}/shipments")
+                        .route(web::post().to(payments::payments_shipment_capture))
+                        .route(web::get().to(payments::payments_shipment_list)),
+                )
                 .service(
                     web::resource("/{payment_id}/approve")
                         .route(web::post().to(payments::payments_approve)),
@@ -1025,6 +1108,7 @@ impl Refunds {
             route = route
                 .service(web::resource("/list").route(web::post().to(refunds_list)))
                 .service(web::resource("/profile/list").route(web::post().to(refunds_list_profile)))
+                .service(web::resource("/export").route(web::post().to(refunds_export)))
                 .service(web::resource("/filter").route(web::post().to(refunds_filter_list)))
                 .service(web::resource("/v2/filter").route(web::get().to(get_refunds_filters)))
                 .service(web::resource("/aggregate").route(web::get().to(get_refunds_aggregates)))
@@ -1040,6 +1124,14 @@ impl Refunds {
                     web::resource("/{id}/manual-update")
                         .route(web::put().to(refunds_manual_update)),
                 );
+            #[cfg(feature = "payouts")]
+            {
+                route = route.service(
+                    web::resource("/tender_overrides/{payment_id}")
+                        .route(web::post().to(refunds_tender_override_create))
+                        .route(web::get().to(refunds_tender_override_list)),
+                );
+            }
         }
         #[cfg(feature = "oltp")]
         {
@@ -1158,10 +1250,22 @@ impl PaymentMethods {
                 .service(
                     web::resource("/collect").route(web::post().to(initiate_pm_collect_link_flow)),
                 )
+                .service(
+                    web::resource("/account_updater/schedule")
+                        .route(web::post().to(schedule_account_updater_api)),
+                )
+                .service(
+                    web::resource("/collect/{collect_id}")
+                        .route(web::get().to(retrieve_pm_collect_link_api)),
+                )
                 .service(
                     web::resource("/collect/{merchant_id}/{collect_id}")
                         .route(web::get().to(render_pm_collect_link)),
                 )
+                .service(
+                    web::resource("/collect/{merchant_id}/{collect_id}/submit")
+                        .route(web::post().to(submit_pm_collect_link_api)),
+                )
                 .service(
                     web::resource("/{payment_method_id}")
                         .route(web::get().to(payment_method_retrieve_api))
@@ -1175,6 +1279,14 @@ impl PaymentMethods {
                     web::resource("/{payment_method_id}/save")
                         .route(web::post().to(save_payment_method_api)),
                 )
+                .service(
+                    web::resource("/{payment_method_id}/network_token")
+                        .route(web::post().to(payment_method_network_token_provision_api)),
+                )
+                .service(
+                    web::resource("/{payment_method_id}/account_update")
+                        .route(web::post().to(payment_method_account_update_api)),
+                )
                 .service(
                     web::resource("/auth/link").route(web::post().to(pm_auth::link_token_create)),
                 )
@@ -1223,6 +1335,22 @@ impl Blocklist {
             .service(
                 web::resource("/toggle").route(web::post().to(blocklist::toggle_blocklist_guard)),
             )
+            .service(
+                web::scope("/sync")
+                    .service(
+                        web::resource("")
+                            .route(web::post().to(blocklist::sync_blocklist))
+                            .route(web::get().to(blocklist::retrieve_blocklist_sync_status)),
+                    )
+                    .service(
+                        web::resource("/rollback")
+                            .route(web::post().to(blocklist::rollback_blocklist_sync)),
+                    )
+                    .service(
+                        web::resource("/source")
+                            .route(web::post().to(blocklist::update_blocklist_sync_source)),
+                    ),
+            )
     }
 }
 
@@ -1305,6 +1433,21 @@ impl MerchantAccount {
             .service(
                 web::resource("/kv").route(web::post().to(admin::merchant_account_toggle_all_kv)),
             )
+            .service(
+                web::resource("/{id}/status")
+                    .route(web::get().to(admin::merchant_account_status_retrieve))
+                    .route(web::post().to(admin::merchant_account_status_update)),
+            )
+            .service(
+                web::resource("/{id}/external_vault")
+                    .route(web::get().to(admin::merchant_external_vault_retrieve))
+                    .route(web::post().to(admin::merchant_external_vault_update)),
+            )
+            .service(web::resource("/{id}/key").route(web::get().to(admin::merchant_key_retrieve)))
+            .service(
+                web::resource("/{id}/key/destroy")
+                    .route(web::post().to(admin::merchant_key_destroy)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(admin::retrieve_merchant_account))
@@ -1362,6 +1505,12 @@ impl MerchantConnectorAccount {
                         .route(web::get().to(connector_retrieve))
                         .route(web::post().to(connector_update))
                         .route(web::delete().to(connector_delete)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/connectors/{merchant_connector_id}/options")
+                        .route(web::get().to(connector_options_retrieve))
+                        .route(web::post().to(connector_options_update))
+                        .route(web::delete().to(connector_options_delete)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -1407,6 +1556,9 @@ impl Mandates {
         {
             route =
                 route.service(web::resource("/revoke/{id}").route(web::post().to(revoke_mandate)));
+            route = route.service(
+                web::resource("/{id}/debits").route(web::post().to(schedule_mandate_debit)),
+            );
         }
         route
     }
@@ -1433,6 +1585,14 @@ impl Webhooks {
                     ),
             );
 
+        #[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+        {
+            route = route.service(
+                web::resource("/{merchant_id}/network_token/lifecycle")
+                    .route(web::post().to(receive_network_token_lifecycle_webhook)),
+            );
+        }
+
         #[cfg(feature = "frm")]
         {
             route = route.service(
@@ -1488,6 +1648,91 @@ impl Poll {
     }
 }
 
+pub struct Receipts;
+
+#[cfg(feature = "v1")]
+impl Receipts {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/receipts")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{merchant_id}/{payment_id}/{format}")
+                    .route(web::get().to(receipts::download_receipt)),
+            )
+    }
+}
+
+pub struct Invoices;
+
+#[cfg(feature = "v1")]
+impl Invoices {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/invoices")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(invoices::create_invoice)))
+            .service(web::resource("/list").route(web::get().to(invoices::list_invoices)))
+            .service(
+                web::resource("/{invoice_id}").route(web::get().to(invoices::retrieve_invoice)),
+            )
+            .service(
+                web::resource("/{invoice_id}/link").route(web::post().to(invoices::link_payments)),
+            )
+    }
+}
+
+pub struct TestClocks;
+
+#[cfg(feature = "v1")]
+impl TestClocks {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/test_clocks")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(test_clocks::create_test_clock)))
+            .service(
+                web::resource("/{clock_id}").route(web::get().to(test_clocks::retrieve_test_clock)),
+            )
+            .service(
+                web::resource("/{clock_id}/attach")
+                    .route(web::post().to(test_clocks::attach_resources_to_test_clock)),
+            )
+            .service(
+                web::resource("/{clock_id}/advance")
+                    .route(web::post().to(test_clocks::advance_test_clock)),
+            )
+    }
+}
+
+pub struct SandboxData;
+
+#[cfg(feature = "v1")]
+impl SandboxData {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/sandbox")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/data_reset")
+                    .route(web::post().to(sandbox_data::reset_sandbox_data))
+                    .route(web::get().to(sandbox_data::retrieve_sandbox_data_reset)),
+            )
+            .service(
+                web::resource("/demo_connectors")
+                    .route(web::post().to(sandbox_data::opt_into_demo_connectors))
+                    .route(web::get().to(sandbox_data::retrieve_demo_connectors)),
+            )
+    }
+}
+
+pub struct GraphQL;
+
+#[cfg(all(feature = "graphql", feature = "v1", not(feature = "customer_v2")))]
+impl GraphQL {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/graphql")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(graphql::graphql_query)))
+    }
+}
+
 pub struct ApiKeys;
 
 #[cfg(all(feature = "olap", feature = "v2"))]
@@ -1550,6 +1795,14 @@ impl Disputes {
                 web::resource("/profile/aggregate")
                     .route(web::get().to(disputes::get_disputes_aggregate_profile)),
             )
+            .service(
+                web::resource("/evidence_effectiveness")
+                    .route(web::get().to(disputes::get_disputes_evidence_effectiveness)),
+            )
+            .service(
+                web::resource("/profile/evidence_effectiveness")
+                    .route(web::get().to(disputes::get_disputes_evidence_effectiveness_profile)),
+            )
             .service(
                 web::resource("/evidence")
                     .route(web::post().to(disputes::submit_dispute_evidence))
@@ -1603,6 +1856,47 @@ impl Cache {
     }
 }
 
+pub struct ConnectorOutages;
+
+impl ConnectorOutages {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connector_outages")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{connector}")
+                    .route(web::get().to(connector_outage::retrieve_connector_outage))
+                    .route(web::post().to(connector_outage::update_connector_outage)),
+            )
+    }
+}
+
+pub struct ConnectorCapabilities;
+
+impl ConnectorCapabilities {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/connector_capabilities")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{connector}")
+                    .route(web::get().to(connector_capabilities::retrieve_connector_capabilities)),
+            )
+    }
+}
+
+pub struct LogConfig;
+
+impl LogConfig {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/log_config")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::get().to(log_config::retrieve_log_config))
+                    .route(web::post().to(log_config::update_log_config)),
+            )
+    }
+}
+
 pub struct PaymentLink;
 
 #[cfg(all(feature = "olap", feature = "v1"))]
@@ -1627,6 +1921,10 @@ impl PaymentLink {
                 web::resource("status/{merchant_id}/{payment_id}")
                     .route(web::get().to(payment_link::payment_link_status)),
             )
+            .service(
+                web::resource("preview/{merchant_id}/{payment_id}")
+                    .route(web::get().to(payment_link::preview_payment_link)),
+            )
     }
 }
 
@@ -1748,6 +2046,79 @@ impl Profile {
                     .service(
                         web::resource("/toggle_connector_agnostic_mit")
                             .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                    )
+                    .service(
+                        web::scope("/experiments")
+                            .service(
+                                web::resource("")
+                                    .route(web::post().to(experiments::experiment_create))
+                                    .route(web::get().to(experiments::experiment_list)),
+                            )
+                            .service(
+                                web::resource("/{experiment_id}")
+                                    .route(web::get().to(experiments::experiment_retrieve)),
+                            )
+                            .service(
+                                web::resource("/{experiment_id}/stop")
+                                    .route(web::post().to(experiments::experiment_stop)),
+                            )
+                            .service(
+                                web::resource("/{experiment_id}/promote")
+                                    .route(web::post().to(experiments::experiment_promote)),
+                            ),
+                    )
+                    .service(
+                        web::resource("/payment_descriptors")
+                            .route(
+                                web::get()
+                                    .to(payment_descriptors::payment_descriptor_config_retrieve),
+                            )
+                            .route(
+                                web::post()
+                                    .to(payment_descriptors::payment_descriptor_config_update),
+                            ),
+                    )
+                    .service(
+                        web::resource("/payment_descriptors/preview")
+                            .route(web::post().to(payment_descriptors::soft_descriptor_preview)),
+                    )
+                    .service(
+                        web::scope("/webhook_endpoints")
+                            .service(
+                                web::resource("")
+                                    .route(
+                                        web::post().to(webhook_endpoints::webhook_endpoint_create),
+                                    )
+                                    .route(
+                                        web::get().to(webhook_endpoints::webhook_endpoints_list),
+                                    ),
+                            )
+                            .service(
+                                web::resource("/{endpoint_id}")
+                                    .route(
+                                        web::get().to(webhook_endpoints::webhook_endpoint_retrieve),
+                                    )
+                                    .route(
+                                        web::post().to(webhook_endpoints::webhook_endpoint_update),
+                                    )
+                                    .route(
+                                        web::delete()
+                                            .to(webhook_endpoints::webhook_endpoint_delete),
+                                    ),
+                            )
+                            .service(web::resource("/{endpoint_id}/rotate_secret").route(
+                                web::post().to(webhook_endpoints::webhook_endpoint_secret_rotate),
+                            ))
+                            .service(web::resource("/{endpoint_id}/metrics").route(
+                                web::get().to(webhook_endpoints::webhook_endpoint_metrics_retrieve),
+                            ))
+                            .service(
+                                web::resource("/{endpoint_id}/delivery_attempts").route(
+                                    web::get().to(
+                                        webhook_endpoints::webhook_endpoint_delivery_attempts_list,
+                                    ),
+                                ),
+                            ),
                     ),
             )
     }
@@ -1820,6 +2191,10 @@ impl User {
             .service(web::resource("/v2/signin").route(web::post().to(user::user_signin)))
             // signin/signup with sso using openidconnect
             .service(web::resource("/oidc").route(web::post().to(user::sso_sign)))
+            // assertion consumer service of the SAML identity providers
+            .service(
+                web::resource("/saml/acs/{auth_method_id}").route(web::post().to(user::saml_acs)),
+            )
             .service(web::resource("/signout").route(web::post().to(user::signout)))
             .service(web::resource("/rotate_password").route(web::post().to(user::rotate_password)))
             .service(web::resource("/change_password").route(web::post().to(user::change_password)))
@@ -1920,6 +2295,11 @@ impl User {
                 .service(
                     web::resource("/terminate")
                         .route(web::get().to(user::terminate_two_factor_auth)),
+                )
+                .service(
+                    web::resource("/policy")
+                        .route(web::get().to(user::get_two_factor_auth_policy))
+                        .route(web::post().to(user::update_two_factor_auth_policy)),
                 ),
         );
 
@@ -2026,6 +2406,10 @@ impl User {
                 )
                 .service(
                     web::resource("/delete").route(web::delete().to(user_role::delete_user_role)),
+                )
+                .service(
+                    web::resource("/invite/revoke")
+                        .route(web::post().to(user_role::revoke_invitation)),
                 ),
         );
 
@@ -2104,7 +2488,115 @@ impl ConnectorOnboarding {
     }
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct ConnectorBalances;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl ConnectorBalances {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/connectors")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("/{merchant_connector_id}/balance")
+                    .route(web::get().to(connector_balance::retrieve_connector_balance)),
+            )
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct ConnectorDebugCaptures;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl ConnectorDebugCaptures {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/connector_debug_captures")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("/{merchant_id}/{payment_id}")
+                    .route(web::get().to(connector_debug_capture::list_connector_debug_captures)),
+            )
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct SupportBundles;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl SupportBundles {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/support_bundles")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("/{merchant_id}/{payment_id}")
+                    .route(web::get().to(support_bundle::retrieve_support_bundle)),
+            )
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct DeclarativeConfig;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl DeclarativeConfig {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/config")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("/apply")
+                    .route(web::post().to(declarative_config::apply_declarative_config)),
+            )
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct PaymentMetadataSchema;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl PaymentMetadataSchema {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/metadata_schema")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("")
+                    .route(web::get().to(metadata_schema::retrieve_payment_metadata_schema))
+                    .route(web::post().to(metadata_schema::upsert_payment_metadata_schema))
+                    .route(web::delete().to(metadata_schema::delete_payment_metadata_schema)),
+            )
+    }
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct MessageBundles;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl MessageBundles {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/message_bundles")
+            .app_data(web::Data::new(config))
+            .service(
+                web::resource("")
+                    .route(web::get().to(localization::list_message_bundles))
+                    .route(web::post().to(localization::upsert_message_bundle)),
+            )
+            .service(
+                web::resource("/{locale}")
+                    .route(web::delete().to(localization::delete_message_bundle)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct ApiLogs;
+
 #[cfg(feature = "olap")]
+impl ApiLogs {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/events/api_logs")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(api_logs::list_api_logs)))
+    }
+}
+
 pub struct WebhookEvents;
 
 #[cfg(all(feature = "olap", feature = "v1"))]
@@ -163,3 +163,153 @@ pub async fn toggle_blocklist_guard(
     ))
     .await
 }
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/sync",
+    request_body = BlocklistSyncRequest,
+    responses(
+        (status = 200, description = "Blocklist synchronized", body = BlocklistSyncVersionResponse),
+        (status = 400, description = "Invalid Data"),
+        (status = 412, description = "Blocklist synchronized since the base version")
+    ),
+    tag = "Blocklist",
+    operation_id = "Synchronize the blocklist",
+    security(("api_key" = []))
+)]
+pub async fn sync_blocklist(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_blocklist::BlocklistSyncRequest>,
+) -> HttpResponse {
+    let flow = Flow::BlocklistSync;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, body, _| {
+            blocklist::sync::sync_blocklist(state, auth.merchant_account, body)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[utoipa::path(
+    get,
+    path = "/blocklist/sync",
+    responses(
+        (status = 200, description = "Blocklist synchronization status", body = BlocklistSyncStatusResponse)
+    ),
+    tag = "Blocklist",
+    operation_id = "Retrieve the synchronization status of the blocklist",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_blocklist_sync_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::BlocklistSyncStatus;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            blocklist::sync::retrieve_blocklist_sync_status(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/sync/rollback",
+    request_body = BlocklistSyncRollbackRequest,
+    responses(
+        (status = 200, description = "Blocklist rolled back", body = BlocklistSyncStatusResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Roll the blocklist back to a previous version",
+    security(("api_key" = []))
+)]
+pub async fn rollback_blocklist_sync(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_blocklist::BlocklistSyncRollbackRequest>,
+) -> HttpResponse {
+    let flow = Flow::BlocklistSyncRollback;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, body, _| {
+            blocklist::sync::rollback_blocklist(state, auth.merchant_account, body)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[utoipa::path(
+    post,
+    path = "/blocklist/sync/source",
+    request_body = BlocklistSyncSourceRequest,
+    responses(
+        (status = 200, description = "Blocklist source configured", body = BlocklistSyncSourceResponse),
+        (status = 400, description = "Invalid Data")
+    ),
+    tag = "Blocklist",
+    operation_id = "Configure the source of the blocklist",
+    security(("api_key" = []))
+)]
+pub async fn update_blocklist_sync_source(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_blocklist::BlocklistSyncSourceRequest>,
+) -> HttpResponse {
+    let flow = Flow::BlocklistSyncSourceUpdate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, body, _| {
+            blocklist::sync::update_blocklist_sync_source(state, auth.merchant_account, body)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
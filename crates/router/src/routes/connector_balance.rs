@@ -0,0 +1,67 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::connector_balance as balance_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, connector_balance},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Connector Balance - Retrieve
+///
+/// Retrieves the available and pending balances per currency of the account at the connector.
+/// Supported for Stripe, Adyen balance accounts and Rapyd wallets. Balances are cached for a
+/// minute.
+#[utoipa::path(
+    get,
+    path = "/connectors/{merchant_connector_id}/balance",
+    params(
+        ("merchant_connector_id" = String, Path, description = "The identifier for the merchant connector account"),
+        ("account_id" = Option<String>, Query, description = "The account at the connector whose balance is to be retrieved. Required for Adyen and Rapyd")
+    ),
+    responses(
+        (status = 200, description = "Connector balance retrieved", body = ConnectorBalanceResponse),
+        (status = 400, description = "The connector does not support retrieving balances"),
+        (status = 404, description = "Merchant connector account not found")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Retrieve the balance of a Merchant Connector",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorBalanceRetrieve))]
+pub async fn retrieve_connector_balance(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantConnectorAccountId>,
+    query: web::Query<balance_types::ConnectorBalanceQuery>,
+) -> impl Responder {
+    let flow = Flow::ConnectorBalanceRetrieve;
+    let payload = balance_types::ConnectorBalanceRequest {
+        merchant_connector_id: path.into_inner(),
+        account_id: query.into_inner().account_id,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            connector_balance::retrieve_connector_balance(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantConnectorRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,48 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::{connector_capabilities as capabilities_types, enums};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, connector_capabilities},
+    services::{api, authentication as auth},
+};
+
+/// Connector Capabilities - Retrieve
+///
+/// Retrieves the capabilities of the connector, the countries and currencies it supports per
+/// payment method type, and the timeout and retry policy applied to the requests sent to it
+#[utoipa::path(
+    get,
+    path = "/connector_capabilities/{connector}",
+    params(("connector" = Connector, Path, description = "The connector")),
+    responses(
+        (status = 200, description = "Connector capabilities retrieved", body = ConnectorCapabilitiesResponse),
+    ),
+    tag = "Connector Capabilities",
+    operation_id = "Retrieve Connector Capabilities",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorCapabilitiesRetrieve))]
+pub async fn retrieve_connector_capabilities(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<enums::Connector>,
+) -> impl Responder {
+    let flow = Flow::ConnectorCapabilitiesRetrieve;
+    let payload = capabilities_types::ConnectorCapabilitiesRetrieveRequest {
+        connector: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, request, _| {
+            connector_capabilities::retrieve_connector_capabilities(state, request)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,56 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::connector_debug_capture as debug_capture_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, connector_debug_capture},
+    services::{api, authentication as auth},
+};
+
+/// Connector Debug Captures - List
+///
+/// Lists the failed connector calls of a payment, captured with the sensitive fields of the
+/// request and response masked, for profiles which have the connector debug capture enabled
+#[utoipa::path(
+    get,
+    path = "/connector_debug_captures/{merchant_id}/{payment_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant"),
+        ("payment_id" = String, Path, description = "The identifier for the payment")
+    ),
+    responses(
+        (status = 200, description = "Captured connector calls retrieved", body = ConnectorDebugCaptureListResponse),
+    ),
+    tag = "Connector Debug Captures",
+    operation_id = "List Connector Debug Captures",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorDebugCaptureList))]
+pub async fn list_connector_debug_captures(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::PaymentId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::ConnectorDebugCaptureList;
+    let (merchant_id, payment_id) = path.into_inner();
+    let payload = debug_capture_types::ConnectorDebugCaptureListRequest {
+        merchant_id,
+        payment_id,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, request, _| {
+            connector_debug_capture::list_connector_debug_captures(state, request)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,93 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::{connector_outage as outage_types, enums};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, connector_outage},
+    services::{api, authentication as auth},
+};
+
+/// Connector Outages - Retrieve
+///
+/// Retrieves whether the connector is currently degraded due to a probable outage, either at the
+/// host or across all the hosts of the connector
+#[utoipa::path(
+    get,
+    path = "/connector_outages/{connector}",
+    params(
+        ("connector" = Connector, Path, description = "The connector"),
+        ("region" = Option<String>, Query, description = "The host of the connector. Defaults to the host of the base URL of the connector")
+    ),
+    responses(
+        (status = 200, description = "Connector outage status retrieved", body = ConnectorOutageResponse),
+    ),
+    tag = "Connector Outages",
+    operation_id = "Retrieve Connector Outage",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorOutageRetrieve))]
+pub async fn retrieve_connector_outage(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<enums::Connector>,
+    query: web::Query<outage_types::ConnectorOutageQuery>,
+) -> impl Responder {
+    let flow = Flow::ConnectorOutageRetrieve;
+    let payload = outage_types::ConnectorOutageRetrieveRequest {
+        connector: path.into_inner(),
+        region: query.into_inner().region,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, request, _| connector_outage::retrieve_connector_outage(state, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Connector Outages - Update
+///
+/// Manually marks the connector as degraded, or as healthy which also suppresses the automatic
+/// outage detection for the connector for the duration. The status is overridden at the provided
+/// host of the connector, or across all its hosts
+#[utoipa::path(
+    post,
+    path = "/connector_outages/{connector}",
+    params(("connector" = Connector, Path, description = "The connector")),
+    request_body = ConnectorOutageUpdateRequest,
+    responses(
+        (status = 200, description = "Connector outage status updated", body = ConnectorOutageResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Connector Outages",
+    operation_id = "Update Connector Outage",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorOutageUpdate))]
+pub async fn update_connector_outage(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<enums::Connector>,
+    json_payload: web::Json<outage_types::ConnectorOutageUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::ConnectorOutageUpdate;
+    let payload = outage_types::ConnectorOutageUpdateWrapper {
+        connector: path.into_inner(),
+        request: json_payload.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, wrapper, _| connector_outage::update_connector_outage(state, wrapper),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,45 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::declarative_config as config_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, declarative_config},
+    services::{api, authentication as auth},
+};
+
+/// Declarative Config - Apply
+///
+/// Computes the creates, updates and deletes needed for the merchant account, its profiles, their
+/// connectors and their routing algorithms to match the desired configuration, and applies them
+/// unless it is a dry run. The changes applied are undone if any of the changes fails.
+#[utoipa::path(
+    post,
+    path = "/config/apply",
+    request_body = DeclarativeConfigRequest,
+    responses(
+        (status = 200, description = "Plan computed and applied unless it is a dry run", body = DeclarativeConfigResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Declarative Config",
+    operation_id = "Apply Declarative Config",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DeclarativeConfigApply))]
+pub async fn apply_declarative_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<config_types::DeclarativeConfigRequest>,
+) -> impl Responder {
+    let flow = Flow::DeclarativeConfigApply;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, _| declarative_config::apply_declarative_config(state, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
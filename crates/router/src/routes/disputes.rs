@@ -548,3 +548,72 @@ pub async fn get_disputes_aggregate_profile(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesEvidenceEffectiveness))]
+pub async fn get_disputes_evidence_effectiveness(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_param: web::Query<common_utils::types::TimeRange>,
+) -> HttpResponse {
+    let flow = Flow::DisputesEvidenceEffectiveness;
+    let query_param = query_param.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_param,
+        |state, auth: auth::AuthenticationData, req, _| {
+            disputes::get_evidence_effectiveness_for_disputes(
+                state,
+                auth.merchant_account,
+                None,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantDisputeRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesEvidenceEffectiveness))]
+pub async fn get_disputes_evidence_effectiveness_profile(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_param: web::Query<common_utils::types::TimeRange>,
+) -> HttpResponse {
+    let flow = Flow::DisputesEvidenceEffectiveness;
+    let query_param = query_param.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_param,
+        |state, auth: auth::AuthenticationData, req, _| {
+            disputes::get_evidence_effectiveness_for_disputes(
+                state,
+                auth.merchant_account,
+                auth.profile_id.map(|profile_id| vec![profile_id]),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,197 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::experiments as experiment_types;
+use common_utils::id_type;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, experiments},
+    services::{api, authentication as auth, authorization::permissions},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ExperimentCreate))]
+pub async fn experiment_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId)>,
+    json_payload: web::Json<experiment_types::ExperimentCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::ExperimentCreate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            experiments::create_experiment(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileRoutingWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ExperimentList))]
+pub async fn experiment_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId)>,
+) -> HttpResponse {
+    let flow = Flow::ExperimentList;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth_data, _, _| {
+            experiments::list_experiments(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ExperimentRetrieve))]
+pub async fn experiment_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId, String)>,
+) -> HttpResponse {
+    let flow = Flow::ExperimentRetrieve;
+    let (merchant_id, profile_id, experiment_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        experiment_id,
+        |state, auth_data, experiment_id, _| {
+            experiments::retrieve_experiment(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                experiment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileRoutingRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ExperimentStop))]
+pub async fn experiment_stop(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId, String)>,
+) -> HttpResponse {
+    let flow = Flow::ExperimentStop;
+    let (merchant_id, profile_id, experiment_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        experiment_id,
+        |state, auth_data, experiment_id, _| {
+            experiments::stop_experiment(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                experiment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileRoutingWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ExperimentPromote))]
+pub async fn experiment_promote(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId, String)>,
+    json_payload: web::Json<experiment_types::ExperimentPromoteRequest>,
+) -> HttpResponse {
+    let flow = Flow::ExperimentPromote;
+    let (merchant_id, profile_id, experiment_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            experiments::promote_experiment(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                experiment_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileRoutingWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,49 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::graphql as graphql_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, graphql},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// GraphQL - Query
+///
+/// Executes a read only GraphQL query over the payments, payment attempts, refunds, disputes and
+/// customers of the merchant. Fields which the user is not permitted to read resolve to `null`,
+/// with an error reported for each of them.
+#[instrument(skip_all, fields(flow = ?Flow::GraphQLQuery))]
+pub async fn graphql_query(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<graphql_types::GraphQLRequest>,
+) -> HttpResponse {
+    let flow = Flow::GraphQLQuery;
+    let request_headers = req.headers();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            graphql::execute_query(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                request_headers,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
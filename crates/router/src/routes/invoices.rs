@@ -0,0 +1,198 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::invoices as invoice_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, invoices},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Invoices - Create
+///
+/// Creates an invoice with line items and a due date, to which payments can be linked
+#[utoipa::path(
+    post,
+    path = "/invoices",
+    request_body = InvoiceCreateRequest,
+    responses(
+        (status = 200, description = "Invoice created", body = InvoiceResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Invoices",
+    operation_id = "Create an Invoice",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceCreate))]
+pub async fn create_invoice(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<invoice_types::InvoiceCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::InvoiceCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            invoices::create_invoice(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - Retrieve
+///
+/// Retrieves an invoice, with its status refreshed from the linked payments
+#[utoipa::path(
+    get,
+    path = "/invoices/{invoice_id}",
+    params(
+        ("invoice_id" = String, Path, description = "The identifier for the invoice")
+    ),
+    responses(
+        (status = 200, description = "Invoice retrieved", body = InvoiceResponse),
+        (status = 404, description = "Invoice not found")
+    ),
+    tag = "Invoices",
+    operation_id = "Retrieve an Invoice",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceRetrieve))]
+pub async fn retrieve_invoice(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::InvoiceRetrieve;
+    let payload = invoice_types::InvoiceId {
+        invoice_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            invoices::retrieve_invoice(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - List
+///
+/// Lists the invoices of the merchant, most recently created first
+#[utoipa::path(
+    get,
+    path = "/invoices/list",
+    params(
+        ("status" = Option<InvoiceStatus>, Query, description = "Only list invoices with this status"),
+        ("limit" = Option<u32>, Query, description = "Number of invoices to list"),
+        ("offset" = Option<u32>, Query, description = "Number of invoices to skip")
+    ),
+    responses(
+        (status = 200, description = "Invoices listed", body = InvoiceListResponse)
+    ),
+    tag = "Invoices",
+    operation_id = "List Invoices",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceList))]
+pub async fn list_invoices(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<invoice_types::InvoiceListConstraints>,
+) -> impl Responder {
+    let flow = Flow::InvoiceList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            invoices::list_invoices(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Invoices - Link Payments
+///
+/// Links payments to an invoice, directly or through payment links. The amount captured by the
+/// linked payments counts towards the invoice amount.
+#[utoipa::path(
+    post,
+    path = "/invoices/{invoice_id}/link",
+    params(
+        ("invoice_id" = String, Path, description = "The identifier for the invoice")
+    ),
+    request_body = InvoiceLinkPaymentsRequest,
+    responses(
+        (status = 200, description = "Payments linked to the invoice", body = InvoiceResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Invoice, payment or payment link not found")
+    ),
+    tag = "Invoices",
+    operation_id = "Link Payments to an Invoice",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::InvoiceLinkPayments))]
+pub async fn link_payments(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<invoice_types::InvoiceLinkPaymentsRequest>,
+) -> impl Responder {
+    let flow = Flow::InvoiceLinkPayments;
+    let invoice_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            invoices::link_payments(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                invoice_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,135 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::localization as localization_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, localization},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Message Bundles - List
+///
+/// Lists the message bundles which override the customer facing content of the merchant
+#[utoipa::path(
+    get,
+    path = "/message_bundles",
+    responses(
+        (status = 200, description = "Message bundles retrieved", body = MessageBundlesResponse),
+    ),
+    tag = "Merchant Account",
+    operation_id = "List Message Bundles",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MessageBundlesList))]
+pub async fn list_message_bundles(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::MessageBundlesList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            localization::list_message_bundles(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Message Bundles - Upsert
+///
+/// Sets the messages overriding the customer facing content of the merchant for a locale,
+/// replacing any previous messages for the locale
+#[utoipa::path(
+    post,
+    path = "/message_bundles",
+    request_body = MessageBundle,
+    responses(
+        (status = 200, description = "Message bundle updated", body = MessageBundlesResponse),
+        (status = 400, description = "Invalid message bundle"),
+    ),
+    tag = "Merchant Account",
+    operation_id = "Upsert Message Bundle",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MessageBundleUpsert))]
+pub async fn upsert_message_bundle(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<localization_types::MessageBundle>,
+) -> impl Responder {
+    let flow = Flow::MessageBundleUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, request, _| {
+            localization::upsert_message_bundle(state, auth.merchant_account, request)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Message Bundles - Delete
+///
+/// Deletes the message bundle of the merchant for a locale
+#[utoipa::path(
+    delete,
+    path = "/message_bundles/{locale}",
+    params(
+        ("locale" = String, Path, description = "The locale of the message bundle"),
+    ),
+    responses(
+        (status = 200, description = "Message bundle deleted", body = MessageBundlesResponse),
+        (status = 404, description = "Message bundle not found"),
+    ),
+    tag = "Merchant Account",
+    operation_id = "Delete Message Bundle",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MessageBundleDelete))]
+pub async fn delete_message_bundle(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::MessageBundleDelete;
+    let request = localization_types::MessageBundleDeleteRequest {
+        locale: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request,
+        |state, auth: auth::AuthenticationData, request, _| {
+            localization::delete_message_bundle(state, auth.merchant_account, request)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
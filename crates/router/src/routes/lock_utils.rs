@@ -36,7 +36,23 @@ pub enum ApiIdentifier {
     ConnectorOnboarding,
     Recon,
     Poll,
+    ApiLogs,
+    ConnectorDebugCaptures,
+    SupportBundles,
+    DeclarativeConfig,
+    PaymentMetadataSchema,
+    MessageBundles,
+    Receipts,
+    Invoices,
+    TestClocks,
+    SandboxData,
+    GraphQL,
+    LogConfig,
+    ConnectorOutages,
+    ConnectorCapabilities,
+    Simulation,
     ApplePayCertificatesMigration,
+    WebhookEndpoints,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -46,6 +62,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountRetrieve
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
+            | Flow::MerchantsAccountStatusRetrieve
+            | Flow::MerchantsAccountStatusUpdate
+            | Flow::MerchantsExternalVaultRetrieve
+            | Flow::MerchantsExternalVaultUpdate
+            | Flow::MerchantsKeyRetrieve
+            | Flow::MerchantsKeyDestroy
             | Flow::MerchantTransferKey
             | Flow::MerchantAccountList => Self::MerchantAccount,
 
@@ -67,6 +89,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::DecisionManagerRetrieveConfig
             | Flow::ToggleDynamicRouting
             | Flow::UpdateDynamicRoutingConfigs
+            | Flow::ExperimentCreate
+            | Flow::ExperimentList
+            | Flow::ExperimentRetrieve
+            | Flow::ExperimentStop
+            | Flow::ExperimentPromote
             | Flow::DecisionManagerUpsertConfig => Self::Routing,
 
             Flow::RetrieveForexFlow => Self::Forex,
@@ -75,12 +102,20 @@ impl From<Flow> for ApiIdentifier {
             Flow::DeleteFromBlocklist => Self::Blocklist,
             Flow::ListBlocklist => Self::Blocklist,
             Flow::ToggleBlocklistGuard => Self::Blocklist,
+            Flow::BlocklistSync => Self::Blocklist,
+            Flow::BlocklistSyncStatus => Self::Blocklist,
+            Flow::BlocklistSyncRollback => Self::Blocklist,
+            Flow::BlocklistSyncSourceUpdate => Self::Blocklist,
 
             Flow::MerchantConnectorsCreate
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
             | Flow::MerchantConnectorsDelete
-            | Flow::MerchantConnectorsList => Self::MerchantConnector,
+            | Flow::MerchantConnectorsList
+            | Flow::MerchantConnectorOptionsUpdate
+            | Flow::MerchantConnectorOptionsRetrieve
+            | Flow::MerchantConnectorOptionsDelete
+            | Flow::ConnectorBalanceRetrieve => Self::MerchantConnector,
 
             Flow::ConfigKeyCreate
             | Flow::ConfigKeyFetch
@@ -98,7 +133,10 @@ impl From<Flow> for ApiIdentifier {
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
 
             Flow::DeepHealthCheck | Flow::HealthCheck => Self::Health,
-            Flow::MandatesRetrieve | Flow::MandatesRevoke | Flow::MandatesList => Self::Mandates,
+            Flow::MandatesRetrieve
+            | Flow::MandatesRevoke
+            | Flow::MandatesList
+            | Flow::MandatesScheduleDebit => Self::Mandates,
 
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsMigrate
@@ -107,7 +145,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentMethodsRetrieve
             | Flow::PaymentMethodsUpdate
             | Flow::PaymentMethodsDelete
+            | Flow::PaymentMethodsNetworkTokenProvision
+            | Flow::PaymentMethodsAccountUpdate
+            | Flow::AccountUpdaterSchedule
             | Flow::PaymentMethodCollectLink
+            | Flow::PaymentMethodCollectLinkRetrieve
+            | Flow::PaymentMethodCollectLinkSubmit
             | Flow::ValidatePaymentMethod
             | Flow::ListCountriesCurrencies
             | Flow::DefaultPaymentMethodsSet
@@ -121,14 +164,18 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsUpdate
             | Flow::PaymentsConfirm
             | Flow::PaymentsCapture
+            | Flow::PaymentsShipmentCapture
+            | Flow::PaymentsShipmentList
             | Flow::PaymentsCancel
             | Flow::PaymentsApprove
             | Flow::PaymentsReject
             | Flow::PaymentsSessionToken
             | Flow::PaymentsStart
             | Flow::PaymentsList
+            | Flow::PaymentsExport
             | Flow::PaymentsFilters
             | Flow::PaymentsAggregate
+            | Flow::PurchaseRetrieve
             | Flow::PaymentsRedirect
             | Flow::PaymentsIncrementalAuthorization
             | Flow::PaymentsExternalAuthentication
@@ -136,6 +183,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::GetExtendedCardInfo
             | Flow::PaymentsCompleteAuthorize
             | Flow::PaymentsManualUpdate
+            | Flow::ExternalPaymentsImport
+            | Flow::PaymentsRoutingDecision
+            | Flow::PaymentsClientTokenCreate
+            | Flow::PaymentsClientTokenRotate
+            | Flow::PaymentsGiftCardBalanceCheck
             | Flow::SessionUpdateTaxCalculation
             | Flow::PaymentsConfirmIntent
             | Flow::PaymentsCreateIntent
@@ -159,12 +211,16 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsRetrieveForceSync
             | Flow::RefundsUpdate
             | Flow::RefundsList
+            | Flow::RefundsExport
             | Flow::RefundsFilters
             | Flow::RefundsAggregate
-            | Flow::RefundsManualUpdate => Self::Refunds,
+            | Flow::RefundsManualUpdate
+            | Flow::RefundsTenderOverrideCreate
+            | Flow::RefundsTenderOverrideList => Self::Refunds,
 
             Flow::FrmFulfillment
             | Flow::IncomingWebhookReceive
+            | Flow::NetworkTokenLifecycleWebhook
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry => Self::Webhooks,
@@ -182,6 +238,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
             | Flow::DisputesAggregate
+            | Flow::DisputesEvidenceEffectiveness
             | Flow::DeleteDisputeEvidence => Self::Disputes,
 
             Flow::CardsInfo => Self::CardsInfo,
@@ -196,13 +253,17 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ProfileDelete
             | Flow::ProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Profile,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::PaymentDescriptorConfigRetrieve
+            | Flow::PaymentDescriptorConfigUpdate
+            | Flow::SoftDescriptorPreview => Self::Profile,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
             | Flow::PaymentSecureLinkInitiate
             | Flow::PaymentLinkList
-            | Flow::PaymentLinkStatus => Self::PaymentLink,
+            | Flow::PaymentLinkStatus
+            | Flow::PaymentLinkPreview => Self::PaymentLink,
 
             Flow::Verification => Self::Verification,
 
@@ -249,12 +310,15 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RecoveryCodesGenerate
             | Flow::TerminateTwoFactorAuth
             | Flow::TwoFactorAuthStatus
+            | Flow::GetTwoFactorAuthPolicy
+            | Flow::UpdateTwoFactorAuthPolicy
             | Flow::CreateUserAuthenticationMethod
             | Flow::UpdateUserAuthenticationMethod
             | Flow::ListUserAuthenticationMethods
             | Flow::UserTransferKey
             | Flow::GetSsoAuthUrl
             | Flow::SignInWithSso
+            | Flow::SamlAssertionConsumerService
             | Flow::ListOrgForUser
             | Flow::ListMerchantsForUserInOrg
             | Flow::ListProfileForUserInOrgAndMerchant
@@ -275,6 +339,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::AcceptInvitationsV2
             | Flow::AcceptInvitationsPreAuth
             | Flow::DeleteUserRole
+            | Flow::RevokeInvitation
             | Flow::CreateRole
             | Flow::UpdateRole
             | Flow::UserFromEmail
@@ -290,6 +355,61 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ReconVerifyToken => Self::Recon,
 
             Flow::RetrievePollStatus => Self::Poll,
+
+            Flow::ApiLogsList => Self::ApiLogs,
+
+            Flow::ConnectorDebugCaptureList => Self::ConnectorDebugCaptures,
+
+            Flow::PaymentSupportBundle => Self::SupportBundles,
+
+            Flow::DeclarativeConfigApply => Self::DeclarativeConfig,
+
+            Flow::PaymentMetadataSchemaRetrieve
+            | Flow::PaymentMetadataSchemaUpdate
+            | Flow::PaymentMetadataSchemaDelete => Self::PaymentMetadataSchema,
+
+            Flow::MessageBundlesList | Flow::MessageBundleUpsert | Flow::MessageBundleDelete => {
+                Self::MessageBundles
+            }
+
+            Flow::PaymentReceiptDownload => Self::Receipts,
+
+            Flow::InvoiceCreate
+            | Flow::InvoiceRetrieve
+            | Flow::InvoiceList
+            | Flow::InvoiceLinkPayments => Self::Invoices,
+
+            Flow::TestClockCreate
+            | Flow::TestClockRetrieve
+            | Flow::TestClockAttach
+            | Flow::TestClockAdvance => Self::TestClocks,
+
+            Flow::SandboxDataReset
+            | Flow::SandboxDataResetRetrieve
+            | Flow::DemoConnectorsOptIn
+            | Flow::DemoConnectorsRetrieve => Self::SandboxData,
+
+            Flow::GraphQLQuery => Self::GraphQL,
+
+            Flow::LogConfigRetrieve | Flow::LogConfigUpdate => Self::LogConfig,
+
+            Flow::ConnectorOutageRetrieve | Flow::ConnectorOutageUpdate => Self::ConnectorOutages,
+
+            Flow::ConnectorCapabilitiesRetrieve => Self::ConnectorCapabilities,
+
+            Flow::SimulationModeRetrieve
+            | Flow::SimulationModeUpdate
+            | Flow::LoadTestCreate
+            | Flow::LoadTestRetrieve => Self::Simulation,
+
+            Flow::WebhookEndpointCreate
+            | Flow::WebhookEndpointList
+            | Flow::WebhookEndpointRetrieve
+            | Flow::WebhookEndpointUpdate
+            | Flow::WebhookEndpointSecretRotate
+            | Flow::WebhookEndpointDelete
+            | Flow::WebhookEndpointMetricsRetrieve
+            | Flow::WebhookEndpointDeliveryAttemptsList => Self::WebhookEndpoints,
         }
     }
 }
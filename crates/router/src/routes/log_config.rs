@@ -0,0 +1,73 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::log_config as log_config_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, log_config},
+    services::{api, authentication as auth},
+};
+
+/// Log Config - Retrieve
+///
+/// Retrieves the log level overrides and the sampling rate of info logs currently in effect
+#[utoipa::path(
+    get,
+    path = "/log_config",
+    responses(
+        (status = 200, description = "Log config retrieved", body = LogConfigResponse),
+    ),
+    tag = "Log Config",
+    operation_id = "Retrieve Log Config",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::LogConfigRetrieve))]
+pub async fn retrieve_log_config(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::LogConfigRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| log_config::retrieve_log_config(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Log Config - Update
+///
+/// Updates the log level of modules or merchants and the sampling rate of info logs on all the
+/// instances, without requiring a restart. Setting the log level of a module or merchant to
+/// `null` removes its override.
+#[utoipa::path(
+    post,
+    path = "/log_config",
+    request_body = LogConfigUpdateRequest,
+    responses(
+        (status = 200, description = "Log config updated", body = LogConfigResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Log Config",
+    operation_id = "Update Log Config",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::LogConfigUpdate))]
+pub async fn update_log_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<log_config_types::LogConfigUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::LogConfigUpdate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, request, _| log_config::update_log_config(state, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -74,6 +74,36 @@ pub async fn revoke_mandate(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::MandatesScheduleDebit))]
+pub async fn schedule_mandate_debit(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<mandates::MandateDebitScheduleRequest>,
+) -> HttpResponse {
+    let flow = Flow::MandatesScheduleDebit;
+    let mandate_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            mandate::debits::schedule_mandate_debit(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                mandate_id.clone(),
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Mandates - List Mandates
 #[utoipa::path(
     get,
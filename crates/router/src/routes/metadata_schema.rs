@@ -0,0 +1,133 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::metadata_schema as schema_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, metadata_schema},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+/// Payment Metadata Schema - Retrieve
+///
+/// Retrieves the schema which the metadata of the merchant's payments must conform to
+#[utoipa::path(
+    get,
+    path = "/metadata_schema",
+    responses(
+        (status = 200, description = "Payment metadata schema retrieved", body = PaymentMetadataSchemaResponse),
+        (status = 404, description = "Payment metadata schema not registered"),
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve Payment Metadata Schema",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMetadataSchemaRetrieve))]
+pub async fn retrieve_payment_metadata_schema(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::PaymentMetadataSchemaRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            metadata_schema::retrieve_payment_metadata_schema(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Metadata Schema - Register
+///
+/// Registers the schema which the metadata of the merchant's payments must conform to, replacing
+/// any previously registered schema
+#[utoipa::path(
+    post,
+    path = "/metadata_schema",
+    request_body = PaymentMetadataSchemaRequest,
+    responses(
+        (status = 200, description = "Payment metadata schema registered", body = PaymentMetadataSchemaResponse),
+        (status = 400, description = "Invalid payment metadata schema"),
+    ),
+    tag = "Merchant Account",
+    operation_id = "Register Payment Metadata Schema",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMetadataSchemaUpdate))]
+pub async fn upsert_payment_metadata_schema(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<schema_types::PaymentMetadataSchemaRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentMetadataSchemaUpdate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, request, _| {
+            metadata_schema::upsert_payment_metadata_schema(state, auth.merchant_account, request)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payment Metadata Schema - Delete
+///
+/// Deletes the payment metadata schema of the merchant, after which payment metadata is no longer
+/// validated
+#[utoipa::path(
+    delete,
+    path = "/metadata_schema",
+    responses(
+        (status = 200, description = "Payment metadata schema deleted"),
+        (status = 404, description = "Payment metadata schema not registered"),
+    ),
+    tag = "Merchant Account",
+    operation_id = "Delete Payment Metadata Schema",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMetadataSchemaDelete))]
+pub async fn delete_payment_metadata_schema(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::PaymentMetadataSchemaDelete;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            metadata_schema::delete_payment_metadata_schema(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -69,6 +69,9 @@ counter_metric!(THREE_DS_DOWNGRADE_COUNT, GLOBAL_METER);
 counter_metric!(RESPONSE_DESERIALIZATION_FAILURE, GLOBAL_METER);
 counter_metric!(CONNECTOR_ERROR_RESPONSE_COUNT, GLOBAL_METER);
 counter_metric!(REQUEST_TIMEOUT_COUNT, GLOBAL_METER);
+counter_metric!(CONNECTOR_OUTAGE_DETECTED, GLOBAL_METER);
+counter_metric!(PROCESSING_LIMIT_EXCEEDED, GLOBAL_METER);
+counter_metric!(PROCESSING_LIMIT_ALERT, GLOBAL_METER);
 
 counter_metric!(EXECUTE_PRETASK_COUNT, GLOBAL_METER);
 counter_metric!(CONNECTOR_PAYMENT_METHOD_TOKENIZATION, GLOBAL_METER);
@@ -0,0 +1,122 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::payment_descriptors as descriptor_types;
+use common_utils::id_type;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, payment_descriptors},
+    services::{api, authentication as auth, authorization::permissions},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentDescriptorConfigRetrieve))]
+pub async fn payment_descriptor_config_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId)>,
+) -> HttpResponse {
+    let flow = Flow::PaymentDescriptorConfigRetrieve;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth_data, _, _| {
+            payment_descriptors::retrieve_payment_descriptor_config(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentDescriptorConfigUpdate))]
+pub async fn payment_descriptor_config_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId)>,
+    json_payload: web::Json<descriptor_types::PaymentDescriptorConfigRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentDescriptorConfigUpdate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            payment_descriptors::update_payment_descriptor_config(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SoftDescriptorPreview))]
+pub async fn soft_descriptor_preview(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId)>,
+    json_payload: web::Json<descriptor_types::SoftDescriptorPreviewRequest>,
+) -> HttpResponse {
+    let flow = Flow::SoftDescriptorPreview;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            payment_descriptors::preview_soft_descriptor(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
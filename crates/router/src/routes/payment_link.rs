@@ -80,6 +80,48 @@ pub async fn initiate_payment_link(
                 payload.merchant_id.clone(),
                 payload.payment_id.clone(),
                 headers,
+                req.query_string(),
+            )
+        },
+        &crate::services::authentication::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Renders the payment link in the sandbox along with the test cards of the connectors of the
+/// payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentLinkPreview))]
+pub async fn preview_payment_link(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::PaymentId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::PaymentLinkPreview;
+    let (merchant_id, payment_id) = path.into_inner();
+
+    let payload = api_models::payments::PaymentLinkInitiateRequest {
+        payment_id,
+        merchant_id: merchant_id.clone(),
+    };
+    let headers = req.headers();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.clone(),
+        |state, auth: auth::AuthenticationData, _, _| {
+            initiate_payment_link_preview_flow(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload.merchant_id.clone(),
+                payload.payment_id.clone(),
+                headers,
+                req.query_string(),
             )
         },
         &crate::services::authentication::MerchantIdAuth(merchant_id),
@@ -116,6 +158,7 @@ pub async fn initiate_secure_payment_link(
                 payload.merchant_id.clone(),
                 payload.payment_id.clone(),
                 headers,
+                req.query_string(),
             )
         },
         &crate::services::authentication::MerchantIdAuth(merchant_id),
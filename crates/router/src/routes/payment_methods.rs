@@ -761,6 +761,67 @@ pub async fn render_pm_collect_link(
     .await
 }
 
+/// Retrieve a payment method collect link, along with the payment method saved through it
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodCollectLinkRetrieve))]
+pub async fn retrieve_pm_collect_link_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodCollectLinkRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, pm_collect_link_id, _| {
+            payment_methods_routes::retrieve_pm_collect_link(
+                state,
+                auth.merchant_account,
+                pm_collect_link_id,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+/// Submit the payment method details collected through the form of a payment method collect link
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodCollectLinkSubmit))]
+pub async fn submit_pm_collect_link_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String)>,
+    json_payload: web::Json<payment_methods::PaymentMethodCollectLinkSubmitRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodCollectLinkSubmit;
+    let (merchant_id, pm_collect_link_id) = path.into_inner();
+    let mut payload = json_payload.into_inner();
+    payload.pm_collect_link_id = pm_collect_link_id;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payment_methods_routes::submit_pm_collect_link(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -831,6 +892,102 @@ pub async fn payment_method_update_api(
     .await
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsNetworkTokenProvision))]
+pub async fn payment_method_network_token_provision_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsNetworkTokenProvision;
+    let pm = PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        pm,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payment_methods_routes::network_tokenization::provision_network_token(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req.payment_method_id,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsAccountUpdate))]
+pub async fn payment_method_account_update_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsAccountUpdate;
+    let pm = PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        pm,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payment_methods_routes::account_updater::update_payment_method_account(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req.payment_method_id,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::AccountUpdaterSchedule))]
+pub async fn schedule_account_updater_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::AccountUpdaterSchedule;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            payment_methods_routes::account_updater::schedule_account_updater(
+                state,
+                auth.merchant_account,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -75,8 +75,7 @@ pub async fn payments_create(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, req, req_state| {
-            authorize_verify_select::<_>(
-                payments::PaymentCreate,
+            create_payment_with_order_reference(
                 state,
                 req_state,
                 auth.merchant_account,
@@ -84,7 +83,6 @@ pub async fn payments_create(
                 auth.key_store,
                 header_payload.clone(),
                 req,
-                api::AuthFlow::Merchant,
             )
         },
         match env::which() {
@@ -102,6 +100,91 @@ pub async fn payments_create(
     .await
 }
 
+/// Creates the payment, enforcing the uniqueness of its order reference if the profile requires it.
+/// When the order reference is already used and the profile is configured to return the existing
+/// payment, the existing payment is retrieved instead of creating a new one.
+#[cfg(feature = "v1")]
+async fn create_payment_with_order_reference(
+    state: app::SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    header_payload: HeaderPayload,
+    req: payment_types::PaymentsRequest,
+) -> errors::RouterResponse<payment_types::PaymentsResponse> {
+    let reservation = payments::order_reference::reserve_order_reference(
+        &state,
+        &merchant_account,
+        &key_store,
+        profile_id.as_ref(),
+        &req,
+    )
+    .await?;
+
+    match reservation {
+        payments::order_reference::OrderReferenceReservation::Existing(payment_id) => {
+            let retrieve_request = payment_types::PaymentsRetrieveRequest {
+                resource_id: payment_types::PaymentIdType::PaymentIntentId(payment_id),
+                merchant_id: Some(merchant_account.get_id().clone()),
+                force_sync: false,
+                ..Default::default()
+            };
+            payments::payments_core::<
+                api_types::PSync,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::PSync>,
+            >(
+                state,
+                req_state,
+                merchant_account,
+                profile_id,
+                key_store,
+                payments::PaymentStatus,
+                retrieve_request,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                header_payload,
+            )
+            .await
+        }
+        reservation => {
+            let payment_id = req
+                .payment_id
+                .as_ref()
+                .and_then(|payment_id| payment_id.get_payment_intent_id().ok());
+            let response = authorize_verify_select::<_>(
+                payments::PaymentCreate,
+                state.clone(),
+                req_state,
+                merchant_account.clone(),
+                profile_id,
+                key_store.clone(),
+                header_payload,
+                req,
+                api::AuthFlow::Merchant,
+            )
+            .await;
+
+            if let (Err(_), Some(payment_id)) = (&response, payment_id) {
+                payments::order_reference::release_order_reference(
+                    &state,
+                    &merchant_account,
+                    &key_store,
+                    &payment_id,
+                    &reservation,
+                )
+                .await;
+            }
+            response
+        }
+    }
+}
+
 #[cfg(feature = "v2")]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsCreateIntent, payment_id))]
 pub async fn payments_create_intent(
@@ -531,10 +614,6 @@ pub async fn payments_confirm(
         return http_not_implemented();
     };
 
-    if let Err(err) = helpers::populate_ip_into_browser_info(&req, &mut payload) {
-        return api::log_and_return_error_response(err);
-    }
-
     let payment_id = path.into_inner();
     tracing::Span::current().record("payment_id", payment_id.get_string_repr());
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
@@ -552,8 +631,39 @@ pub async fn payments_confirm(
             Err(e) => return api::log_and_return_error_response(e),
         };
 
+    if let Err(err) = helpers::populate_browser_info_from_request(
+        &req,
+        &mut payload,
+        auth_flow,
+        state.conf.risk_enrichment.trust_cdn_country_headers,
+    ) {
+        return api::log_and_return_error_response(err);
+    }
+
     let locking_action = payload.get_locking_input(flow.clone());
 
+    if payload.confirm_async == Some(true) {
+        return Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: auth::AuthenticationData, req, req_state| {
+                payments::payments_confirm_async(
+                    state,
+                    req_state,
+                    auth.merchant_account,
+                    auth.profile_id,
+                    auth.key_store,
+                    req,
+                )
+            },
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            locking_action,
+        ))
+        .await;
+    }
+
     Box::pin(api::server_wrap(
         flow,
         state,
@@ -630,6 +740,83 @@ pub async fn payments_capture(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsShipmentCapture, payment_id))]
+pub async fn payments_shipment_capture(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsShipmentCaptureRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    let flow = Flow::PaymentsShipmentCapture;
+    let payload = payment_types::PaymentsShipmentCaptureRequest {
+        payment_id,
+        ..json_payload.into_inner()
+    };
+
+    let locking_action = payload.get_locking_input(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, req_state| {
+            payments::shipments::capture_shipment(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payload,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        locking_action,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsShipmentList, payment_id))]
+pub async fn payments_shipment_list(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    let flow = Flow::PaymentsShipmentList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            payments::shipments::list_shipments(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all, fields(flow = ?Flow::SessionUpdateTaxCalculation, payment_id))]
 pub async fn payments_dynamic_tax_calculation(
@@ -995,25 +1182,13 @@ pub async fn payments_cancel(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, req, req_state| {
-            payments::payments_core::<
-                api_types::Void,
-                payment_types::PaymentsResponse,
-                _,
-                _,
-                _,
-                payments::PaymentData<api_types::Void>,
-            >(
+            payments::void_window::payments_cancel_core(
                 state,
                 req_state,
                 auth.merchant_account,
                 auth.profile_id,
                 auth.key_store,
-                payments::PaymentCancel,
                 req,
-                api::AuthFlow::Merchant,
-                payments::CallConnectorAction::Trigger,
-                None,
-                HeaderPayload::default(),
             )
         },
         &auth::HeaderAuth(auth::ApiKeyAuth),
@@ -1051,6 +1226,34 @@ pub async fn payments_list(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsExport))]
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn payments_export(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Json<api_models::data_export::PaymentsExportRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsExport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            crate::core::data_export::export_payments(state, auth.merchant_account, None, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsList))]
 #[cfg(all(feature = "olap", feature = "v1"))]
 pub async fn profile_payments_list(
@@ -1248,6 +1451,40 @@ pub async fn get_payments_aggregates(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::PurchaseRetrieve))]
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn purchase_retrieve(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<payment_types::PurchaseRetrieveRequest>,
+) -> impl Responder {
+    let flow = Flow::PurchaseRetrieve;
+    let payload = payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::purchases::retrieve_purchase(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(feature = "oltp", feature = "v1"))]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsApprove, payment_id))]
 pub async fn payments_approve(
@@ -1377,7 +1614,7 @@ pub async fn payments_reject(
 
 #[cfg(feature = "v1")]
 #[allow(clippy::too_many_arguments)]
-async fn authorize_verify_select<Op>(
+pub(crate) async fn authorize_verify_select<Op>(
     operation: Op,
     state: app::SessionState,
     req_state: ReqState,
@@ -1402,6 +1639,43 @@ where
             Data = payments::PaymentData<api_types::SetupMandate>,
         >,
 {
+    // A duplicate of a confirm which completed while the duplicate waited for its lock receives the
+    // stored state of the payment, instead of the payment being confirmed again
+    if let (
+        Some(api_locking::FencedLockStatus::Replay),
+        Some(payment_types::PaymentIdType::PaymentIntentId(payment_id)),
+    ) = (&req_state.fenced_lock_status, &req.payment_id)
+    {
+        logger::info!("Replaying the outcome of the duplicate confirm of the payment");
+        let retrieve_request = payment_types::PaymentsRetrieveRequest {
+            resource_id: payment_types::PaymentIdType::PaymentIntentId(payment_id.clone()),
+            merchant_id: Some(merchant_account.get_id().clone()),
+            force_sync: false,
+            ..Default::default()
+        };
+        return payments::payments_core::<
+            api_types::PSync,
+            payment_types::PaymentsResponse,
+            _,
+            _,
+            _,
+            payments::PaymentData<api_types::PSync>,
+        >(
+            state,
+            req_state,
+            merchant_account,
+            profile_id,
+            key_store,
+            payments::PaymentStatus,
+            retrieve_request,
+            auth_flow,
+            payments::CallConnectorAction::Trigger,
+            None,
+            header_payload,
+        )
+        .await;
+    }
+
     // TODO: Change for making it possible for the flow to be inferred internally or through validation layer
     // This is a temporary fix.
     // After analyzing the code structure,
@@ -1656,6 +1930,40 @@ pub async fn payments_manual_update(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::ExternalPaymentsImport))]
+pub async fn import_external_payments(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::ExternalPaymentsImportRequest>,
+) -> impl Responder {
+    let flow = Flow::ExternalPaymentsImport;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _req_state| {
+            payments::external_payments::import_external_payments(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 /// Retrieve endpoint for merchant to fetch the encrypted customer payment method data
 #[instrument(skip_all, fields(flow = ?Flow::GetExtendedCardInfo, payment_id))]
@@ -1685,6 +1993,158 @@ pub async fn retrieve_extended_card_info(
     .await
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+/// Retrieve endpoint for merchant to fetch the routing decisions made for the attempts of a payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsRoutingDecision, payment_id))]
+pub async fn retrieve_payment_routing_decision(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsRoutingDecision;
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            crate::core::routing::retrieve_payment_routing_decision(
+                state,
+                auth.merchant_account,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantPaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Create a client token for the payment, which is limited to the requested scopes and expires
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsClientTokenCreate, payment_id))]
+pub async fn payments_create_client_token(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsClientTokenRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsClientTokenCreate;
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    let payload = payment_types::PaymentsClientTokenRequest {
+        payment_id,
+        ..json_payload.into_inner()
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            payments::client_token::create_client_token(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Revoke all the client tokens of the payment and issue a new one
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsClientTokenRotate, payment_id))]
+pub async fn payments_rotate_client_tokens(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsClientTokenRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsClientTokenRotate;
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    let payload = payment_types::PaymentsClientTokenRequest {
+        payment_id,
+        ..json_payload.into_inner()
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            payments::client_token::rotate_client_tokens(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Check the balance remaining on a gift card, so that the client can plan splitting the payment
+/// before confirming it
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsGiftCardBalanceCheck, payment_id))]
+pub async fn payments_check_gift_card_balance(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsGiftCardBalanceCheckRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsGiftCardBalanceCheck;
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    let payload = payment_types::PaymentsGiftCardBalanceCheckRequest {
+        payment_id,
+        ..json_payload.into_inner()
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            payments::gift_card_balance::check_gift_card_balance(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+            )
+        },
+        &auth::PublishableKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 pub fn get_or_generate_payment_id(
     payload: &mut payment_types::PaymentsRequest,
@@ -1716,6 +2176,19 @@ impl GetLockingInput for payment_types::PaymentsRequest {
         lock_utils::ApiIdentifier: From<F>,
     {
         match self.payment_id {
+            // Duplicate confirms wait for the lock to be released and then replay the stored
+            // state of the payment, writes are guarded by the fencing token of the lock
+            Some(payment_types::PaymentIdType::PaymentIntentId(ref id))
+                if self.confirm == Some(true) =>
+            {
+                api_locking::LockAction::Fence {
+                    input: api_locking::LockingInput {
+                        unique_locking_key: id.get_string_repr().to_owned(),
+                        api_identifier: lock_utils::ApiIdentifier::from(flow),
+                        override_lock_retries: None,
+                    },
+                }
+            }
             Some(payment_types::PaymentIdType::PaymentIntentId(ref id)) => {
                 api_locking::LockAction::Hold {
                     input: api_locking::LockingInput {
@@ -1876,6 +2349,23 @@ impl GetLockingInput for payment_types::PaymentsCancelRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl GetLockingInput for payment_types::PaymentsShipmentCaptureRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
 #[cfg(feature = "v1")]
 impl GetLockingInput for payment_types::PaymentsCaptureRequest {
     fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
@@ -2120,7 +2610,7 @@ pub async fn payment_confirm_intent(
     let flow = Flow::PaymentsConfirmIntent;
 
     // TODO: Populate browser information into the payload
-    // if let Err(err) = helpers::populate_ip_into_browser_info(&req, &mut payload) {
+    // if let Err(err) = helpers::populate_browser_info_from_request(&req, &mut payload, auth_flow) {
     //     return api::log_and_return_error_response(err);
     // }
 
@@ -3,14 +3,22 @@ use error_stack::ResultExt;
 use crate::{
     core::errors::{self, RouterResult},
     logger,
+    middleware::RequestRiskContext,
+    services,
     types::{self, api},
     utils::{Encode, ValueExt},
 };
 
 #[cfg(feature = "v1")]
-pub fn populate_ip_into_browser_info(
+/// Populates the browser information of the payment with the risk context captured from the
+/// request, the details provided in the request take precedence over the captured ones. Apart
+/// from the ip address, the context is only used for requests made by the client, as the
+/// headers of server to server requests do not describe the browser of the customer.
+pub fn populate_browser_info_from_request(
     req: &actix_web::HttpRequest,
     payload: &mut api::PaymentsRequest,
+    auth_flow: services::AuthFlow,
+    trust_cdn_country_headers: bool,
 ) -> RouterResult<()> {
     let mut browser_info: types::BrowserInformation = payload
         .browser_info
@@ -20,23 +28,15 @@ pub fn populate_ip_into_browser_info(
         .change_context_lazy(|| errors::ApiErrorResponse::InvalidRequestData {
             message: "invalid format for 'browser_info' provided".to_string(),
         })?
-        .unwrap_or(types::BrowserInformation {
-            color_depth: None,
-            java_enabled: None,
-            java_script_enabled: None,
-            language: None,
-            screen_height: None,
-            screen_width: None,
-            time_zone: None,
-            accept_header: None,
-            user_agent: None,
-            ip_address: None,
-        });
+        .unwrap_or_default();
 
-    let ip_address = req
-        .connection_info()
-        .realip_remote_addr()
-        .map(ToOwned::to_owned);
+    let risk_context = req
+        .extensions()
+        .get::<RequestRiskContext>()
+        .cloned()
+        .unwrap_or_else(|| RequestRiskContext::new(req.headers(), &req.connection_info()));
+
+    let ip_address = risk_context.ip_address.clone();
 
     if ip_address.is_some() {
         logger::debug!("Extracted ip address from request");
@@ -56,6 +56,23 @@ pub fn populate_ip_into_browser_info(
             })
     });
 
+    // The country of the client is never taken from the request, as the client could set it to
+    // any value, it is only resolved from the headers set by the CDN in front of the server
+    browser_info.ip_country = risk_context
+        .ip_country
+        .as_deref()
+        .filter(|_| trust_cdn_country_headers)
+        .and_then(|ip_country| ip_country.parse().ok());
+
+    if matches!(auth_flow, services::AuthFlow::Client) {
+        browser_info.language = browser_info
+            .language
+            .or_else(|| risk_context.get_preferred_language());
+        browser_info.user_agent = browser_info.user_agent.or(risk_context.user_agent);
+        browser_info.accept_header = browser_info.accept_header.or(risk_context.accept_header);
+        browser_info.origin = browser_info.origin.or(risk_context.origin);
+    }
+
     if let Some(api::MandateData {
         customer_acceptance:
             Some(api::CustomerAcceptance {
@@ -77,7 +94,7 @@ pub fn populate_ip_into_browser_info(
         .encode_to_value()
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable(
-            "failed to re-encode browser information to json after setting ip address",
+            "failed to re-encode browser information to json after populating it from the request",
         )?;
 
     payload.browser_info = Some(encoded);
@@ -0,0 +1,68 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::receipts::{ReceiptDownloadRequest, ReceiptFormat};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, receipts},
+    services::{api, authentication as auth},
+};
+
+/// Receipts - Download
+///
+/// Downloads the receipt of a successful payment as an HTML page or a PDF document, using the
+/// signed url present in the payment response
+#[utoipa::path(
+    get,
+    path = "/receipts/{merchant_id}/{payment_id}/{format}",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant"),
+        ("payment_id" = String, Path, description = "The identifier for the payment"),
+        ("format" = ReceiptFormat, Path, description = "The format of the receipt"),
+    ),
+    responses(
+        (status = 200, description = "Receipt of the payment"),
+        (status = 401, description = "Signature of the url is invalid or the url has expired"),
+        (status = 404, description = "Payment not found")
+    ),
+    tag = "Payments",
+    operation_id = "Download the Receipt of a Payment",
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentReceiptDownload))]
+pub async fn download_receipt(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::PaymentId,
+        ReceiptFormat,
+    )>,
+) -> impl Responder {
+    let flow = Flow::PaymentReceiptDownload;
+    let (merchant_id, payment_id, format) = path.into_inner();
+    let payload = ReceiptDownloadRequest {
+        merchant_id: merchant_id.clone(),
+        payment_id,
+        format,
+    };
+    let headers = req.headers();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, payload, _| {
+            receipts::download_receipt(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+                headers,
+                req.query_string(),
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
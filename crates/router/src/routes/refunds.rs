@@ -1,13 +1,28 @@
 use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
-use super::app::AppState;
+use super::{app::AppState, lock_utils};
 use crate::{
     core::{api_locking, refunds::*},
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::refunds,
 };
 
+/// Refunds of a payment, including the ones made to bank accounts, are created one at a time, so
+/// that each of them is validated against the amount left refundable by the others
+fn get_payment_refund_lock_action(
+    payment_id: &common_utils::id_type::PaymentId,
+    flow: Flow,
+) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payment_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow),
+            override_lock_retries: None,
+        },
+    }
+}
+
 /// Refunds - Create
 ///
 /// To create a refund against an already processed payment
@@ -31,11 +46,13 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    let payload = json_payload.into_inner();
+    let lock_action = get_payment_refund_lock_action(&payload.payment_id, flow.clone());
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
+        payload,
         |state, auth: auth::AuthenticationData, req, _| {
             refund_create_core(
                 state,
@@ -52,7 +69,7 @@ pub async fn refunds_create(
             },
             req.headers(),
         ),
-        api_locking::LockAction::NotApplicable,
+        lock_action,
     ))
     .await
 }
@@ -251,6 +268,34 @@ pub async fn refunds_list(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::RefundsExport))]
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub async fn refunds_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<api_models::data_export::RefundsExportRequest>,
+) -> HttpResponse {
+    let flow = Flow::RefundsExport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            crate::core::data_export::export_refunds(state, auth.merchant_account, None, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantRefundRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Refunds - List at profile level
 ///
 /// To list the refunds associated with a payment_id or with the merchant, if payment_id is not provided
@@ -450,6 +495,80 @@ pub async fn get_refunds_aggregates(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::RefundsTenderOverrideCreate))]
+#[cfg(all(feature = "olap", feature = "payouts"))]
+pub async fn refunds_tender_override_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::refunds::RefundTenderOverrideRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> HttpResponse {
+    let flow = Flow::RefundsTenderOverrideCreate;
+    let payload = api_models::refunds::RefundTenderOverrideRequest {
+        payment_id: path.into_inner(),
+        ..json_payload.into_inner()
+    };
+    let lock_action = get_payment_refund_lock_action(&payload.payment_id, flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, req, req_state| {
+            tender_override::create_tender_override(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                user_id,
+                req,
+            )
+        },
+        // Refunds are made to the payment method of the payment by default, overriding it is
+        // restricted to users who can manage the merchant account
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountWrite,
+        },
+        lock_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::RefundsTenderOverrideList))]
+#[cfg(all(feature = "olap", feature = "payouts"))]
+pub async fn refunds_tender_override_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> HttpResponse {
+    let flow = Flow::RefundsTenderOverrideList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            tender_override::list_tender_overrides(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantRefundRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::RefundsManualUpdate))]
 #[cfg(feature = "olap")]
 pub async fn refunds_manual_update(
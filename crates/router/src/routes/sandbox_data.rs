@@ -0,0 +1,144 @@
+use actix_web::{web, HttpRequest, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, demo_connectors, sandbox_data},
+    services::{api, authentication as auth},
+};
+
+/// Sandbox Data - Reset
+///
+/// Schedules the deletion of the payments, customers and payment methods of the merchant account,
+/// keeping its configuration. Only available in the sandbox environment.
+#[utoipa::path(
+    post,
+    path = "/sandbox/data_reset",
+    responses(
+        (status = 202, description = "Data reset scheduled", body = SandboxDataResetResponse),
+        (status = 412, description = "A data reset is already in progress")
+    ),
+    tag = "Sandbox Data",
+    operation_id = "Reset Sandbox Data",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SandboxDataReset))]
+pub async fn reset_sandbox_data(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::SandboxDataReset;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth, _, _| sandbox_data::reset_sandbox_data(state, auth.merchant_account),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Sandbox Data - Retrieve Reset
+///
+/// Retrieves the progress of the latest data reset of the merchant account
+#[utoipa::path(
+    get,
+    path = "/sandbox/data_reset",
+    responses(
+        (status = 200, description = "Data reset retrieved", body = SandboxDataResetResponse),
+        (status = 404, description = "No data reset has been requested")
+    ),
+    tag = "Sandbox Data",
+    operation_id = "Retrieve Sandbox Data Reset",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SandboxDataResetRetrieve))]
+pub async fn retrieve_sandbox_data_reset(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::SandboxDataResetRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth, _, _| sandbox_data::retrieve_sandbox_data_reset(state, auth.merchant_account),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Sandbox Data - Opt into Demo Connectors
+///
+/// Opts the merchant account into the demo connectors of the platform, so that test payments can
+/// be made before configuring connector accounts of its own. Payments through the demo connectors
+/// are subject to a daily quota. Only available in the sandbox environment.
+#[utoipa::path(
+    post,
+    path = "/sandbox/demo_connectors",
+    request_body = DemoConnectorsRequest,
+    responses(
+        (status = 200, description = "Demo connectors created", body = DemoConnectorsResponse),
+        (status = 412, description = "Demo connectors are not enabled")
+    ),
+    tag = "Sandbox Data",
+    operation_id = "Opt into Demo Connectors",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DemoConnectorsOptIn))]
+pub async fn opt_into_demo_connectors(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::sandbox_data::DemoConnectorsRequest>,
+) -> impl Responder {
+    let flow = Flow::DemoConnectorsOptIn;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            demo_connectors::opt_into_demo_connectors(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Sandbox Data - Retrieve Demo Connectors
+///
+/// Retrieves the demo connectors of the merchant account, along with the usage of the daily quota
+#[utoipa::path(
+    get,
+    path = "/sandbox/demo_connectors",
+    responses(
+        (status = 200, description = "Demo connectors retrieved", body = DemoConnectorsResponse)
+    ),
+    tag = "Sandbox Data",
+    operation_id = "Retrieve Demo Connectors",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DemoConnectorsRetrieve))]
+pub async fn retrieve_demo_connectors(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::DemoConnectorsRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth, _, _| demo_connectors::retrieve_demo_connectors(state, auth.merchant_account),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,155 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::simulation as simulation_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, simulation},
+    services::{api, authentication as auth},
+};
+
+/// Simulation - Retrieve Mode
+///
+/// Retrieves whether the payments of the merchant are routed to a synthetic connector
+#[utoipa::path(
+    get,
+    path = "/simulation/mode",
+    responses(
+        (status = 200, description = "Simulation mode retrieved", body = SimulationModeResponse),
+    ),
+    tag = "Simulation",
+    operation_id = "Retrieve the Simulation Mode",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SimulationModeRetrieve))]
+pub async fn retrieve_simulation_mode(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::SimulationModeRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth, _, _| simulation::retrieve_simulation_mode(state, auth.merchant_account),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Simulation - Update Mode
+///
+/// Enables or disables the simulation mode, which routes all the payments of the merchant to the
+/// given dummy connector
+#[utoipa::path(
+    post,
+    path = "/simulation/mode",
+    request_body = SimulationModeUpdateRequest,
+    responses(
+        (status = 200, description = "Simulation mode updated", body = SimulationModeResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Simulation",
+    operation_id = "Update the Simulation Mode",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::SimulationModeUpdate))]
+pub async fn update_simulation_mode(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<simulation_types::SimulationModeUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::SimulationModeUpdate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            simulation::update_simulation_mode(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Simulation - Create Load Test
+///
+/// Starts a load test, which creates payments for the merchant following the given traffic shape.
+/// The simulation mode must be enabled to run load tests.
+#[utoipa::path(
+    post,
+    path = "/simulation/load_tests",
+    request_body = LoadTestCreateRequest,
+    responses(
+        (status = 200, description = "Load test started", body = LoadTestResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Simulation",
+    operation_id = "Create a Load Test",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::LoadTestCreate))]
+pub async fn create_load_test(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<simulation_types::LoadTestCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::LoadTestCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            simulation::create_load_test(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Simulation - Retrieve Load Test
+///
+/// Retrieves the progress of a load test
+#[utoipa::path(
+    get,
+    path = "/simulation/load_tests/{load_test_id}",
+    params(
+        ("load_test_id" = String, Path, description = "The identifier for the load test")
+    ),
+    responses(
+        (status = 200, description = "Load test retrieved", body = LoadTestResponse),
+        (status = 404, description = "Load test not found")
+    ),
+    tag = "Simulation",
+    operation_id = "Retrieve a Load Test",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::LoadTestRetrieve))]
+pub async fn retrieve_load_test(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::LoadTestRetrieve;
+    let payload = simulation_types::LoadTestId {
+        load_test_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            simulation::retrieve_load_test(state, auth.merchant_account, req.load_test_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,55 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::support_bundle as support_bundle_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, support_bundle},
+    services::{api, authentication as auth},
+};
+
+/// Support Bundles - Retrieve
+///
+/// Exports the timeline, routing trace, connector events, webhook deliveries and scheduler tasks
+/// of a payment as a single JSON document, with customer and payment method details left out
+#[utoipa::path(
+    get,
+    path = "/support_bundles/{merchant_id}/{payment_id}",
+    params(
+        ("merchant_id" = String, Path, description = "The identifier for the merchant"),
+        ("payment_id" = String, Path, description = "The identifier for the payment")
+    ),
+    responses(
+        (status = 200, description = "Support bundle generated", body = SupportBundle),
+        (status = 404, description = "Payment not found")
+    ),
+    tag = "Support Bundles",
+    operation_id = "Retrieve Payment Support Bundle",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentSupportBundle))]
+pub async fn retrieve_support_bundle(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::PaymentId,
+    )>,
+) -> impl Responder {
+    let flow = Flow::PaymentSupportBundle;
+    let (merchant_id, payment_id) = path.into_inner();
+    let payload = support_bundle_types::SupportBundleRequest {
+        merchant_id,
+        payment_id,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, request, _| support_bundle::retrieve_support_bundle(state, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,175 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::test_clocks as test_clock_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, test_clocks},
+    services::{api, authentication as auth},
+};
+
+/// Test Clocks - Create
+///
+/// Creates a test clock, to drive time dependent flows of resources deterministically in the
+/// sandbox environment
+#[utoipa::path(
+    post,
+    path = "/test_clocks",
+    request_body = TestClockCreateRequest,
+    responses(
+        (status = 200, description = "Test clock created", body = TestClockResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Test Clocks",
+    operation_id = "Create a Test Clock",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::TestClockCreate))]
+pub async fn create_test_clock(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<test_clock_types::TestClockCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::TestClockCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| test_clocks::create_test_clock(state, auth.merchant_account, req),
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Test Clocks - Retrieve
+///
+/// Retrieves a test clock
+#[utoipa::path(
+    get,
+    path = "/test_clocks/{clock_id}",
+    params(
+        ("clock_id" = String, Path, description = "The identifier for the test clock")
+    ),
+    responses(
+        (status = 200, description = "Test clock retrieved", body = TestClockResponse),
+        (status = 404, description = "Test clock not found")
+    ),
+    tag = "Test Clocks",
+    operation_id = "Retrieve a Test Clock",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::TestClockRetrieve))]
+pub async fn retrieve_test_clock(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::TestClockRetrieve;
+    let payload = test_clock_types::TestClockId {
+        clock_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            test_clocks::retrieve_test_clock(state, auth.merchant_account, req.clock_id)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Test Clocks - Attach
+///
+/// Attaches payments, mandates or other resources to a test clock, so that their scheduled tasks
+/// are triggered when the test clock is advanced
+#[utoipa::path(
+    post,
+    path = "/test_clocks/{clock_id}/attach",
+    params(
+        ("clock_id" = String, Path, description = "The identifier for the test clock")
+    ),
+    request_body = TestClockAttachRequest,
+    responses(
+        (status = 200, description = "Resources attached to the test clock", body = TestClockResponse),
+        (status = 404, description = "Test clock not found")
+    ),
+    tag = "Test Clocks",
+    operation_id = "Attach Resources to a Test Clock",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::TestClockAttach))]
+pub async fn attach_resources_to_test_clock(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<test_clock_types::TestClockAttachRequest>,
+) -> impl Responder {
+    let flow = Flow::TestClockAttach;
+    let clock_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            test_clocks::attach_resources_to_test_clock(
+                state,
+                auth.merchant_account,
+                clock_id.clone(),
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Test Clocks - Advance
+///
+/// Advances a test clock, triggering the scheduled tasks of the attached resources, such as
+/// expiries, scheduled captures and retries, which have become due
+#[utoipa::path(
+    post,
+    path = "/test_clocks/{clock_id}/advance",
+    params(
+        ("clock_id" = String, Path, description = "The identifier for the test clock")
+    ),
+    request_body = TestClockAdvanceRequest,
+    responses(
+        (status = 200, description = "Test clock advanced", body = TestClockResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Test clock not found")
+    ),
+    tag = "Test Clocks",
+    operation_id = "Advance a Test Clock",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::TestClockAdvance))]
+pub async fn advance_test_clock(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<test_clock_types::TestClockAdvanceRequest>,
+) -> impl Responder {
+    let flow = Flow::TestClockAdvance;
+    let clock_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            test_clocks::advance_test_clock(state, auth.merchant_account, clock_id.clone(), req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -643,20 +643,70 @@ pub async fn terminate_two_factor_auth(
     query: web::Query<user_api::SkipTwoFactorAuthQueryParam>,
 ) -> HttpResponse {
     let flow = Flow::TerminateTwoFactorAuth;
-    let skip_two_factor_auth = query.into_inner().skip_two_factor_auth.unwrap_or(false);
+    let remembered_device_token = req
+        .headers()
+        .get(auth::cookies::get_cookie_header())
+        .and_then(|cookies| cookies.to_str().ok())
+        .and_then(auth::cookies::parse_remembered_device_cookie);
 
     Box::pin(api::server_wrap(
         flow,
         state.clone(),
         &req,
-        (),
-        |state, user, _, _| user_core::terminate_two_factor_auth(state, user, skip_two_factor_auth),
+        query.into_inner(),
+        |state, user, query, _| {
+            user_core::terminate_two_factor_auth(
+                state,
+                user,
+                query,
+                remembered_device_token.clone(),
+            )
+        },
         &auth::SinglePurposeJWTAuth(TokenPurpose::TOTP),
         api_locking::LockAction::NotApplicable,
     ))
     .await
 }
 
+pub async fn get_two_factor_auth_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::GetTwoFactorAuthPolicy;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        (),
+        |state, user, _, _| user_core::get_two_factor_auth_policy(state, user),
+        &auth::JWTAuth {
+            permission: Permission::OrganizationAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn update_two_factor_auth_policy(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<user_api::TwoFactorAuthPolicy>,
+) -> HttpResponse {
+    let flow = Flow::UpdateTwoFactorAuthPolicy;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        json_payload.into_inner(),
+        |state, user, req_body, _| user_core::update_two_factor_auth_policy(state, user, req_body),
+        &auth::JWTAuth {
+            permission: Permission::OrganizationAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn check_two_factor_auth_status(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -735,6 +785,27 @@ pub async fn sso_sign(
     .await
 }
 
+pub async fn saml_acs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    form_payload: web::Form<user_api::SamlAcsRequest>,
+) -> HttpResponse {
+    let flow = Flow::SamlAssertionConsumerService;
+    let auth_method_id = path.into_inner();
+    let payload = form_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload,
+        |state, _: (), req, _| user_core::saml_acs(state, auth_method_id.clone(), req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn create_user_authentication_method(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -249,6 +249,26 @@ pub async fn delete_user_role(
     .await
 }
 
+pub async fn revoke_invitation(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<user_role_api::RevokeInvitationRequest>,
+) -> HttpResponse {
+    let flow = Flow::RevokeInvitation;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &req,
+        payload.into_inner(),
+        |state, user, req_body, _| user_role_core::revoke_invitation(state, user, req_body),
+        &auth::JWTAuth {
+            permission: Permission::ProfileUserWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn get_role_information(
     state: web::Data<AppState>,
     http_req: HttpRequest,
@@ -0,0 +1,310 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::webhook_endpoints as webhook_endpoint_types;
+use common_utils::id_type;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, webhook_endpoints},
+    services::{api, authentication as auth, authorization::permissions},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointCreate))]
+pub async fn webhook_endpoint_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId)>,
+    json_payload: web::Json<webhook_endpoint_types::WebhookEndpointCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointCreate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            webhook_endpoints::create_webhook_endpoint(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointList))]
+pub async fn webhook_endpoints_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId)>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointList;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth_data, _, _| {
+            webhook_endpoints::list_webhook_endpoints(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointRetrieve))]
+pub async fn webhook_endpoint_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId, String)>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointRetrieve;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth_data, _, _| {
+            webhook_endpoints::retrieve_webhook_endpoint(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                endpoint_id.clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointUpdate))]
+pub async fn webhook_endpoint_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId, String)>,
+    json_payload: web::Json<webhook_endpoint_types::WebhookEndpointUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointUpdate;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            webhook_endpoints::update_webhook_endpoint(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                endpoint_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointSecretRotate))]
+pub async fn webhook_endpoint_secret_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId, String)>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointSecretRotate;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth_data, _, _| {
+            webhook_endpoints::rotate_webhook_endpoint_signing_secret(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                endpoint_id.clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointDelete))]
+pub async fn webhook_endpoint_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId, String)>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointDelete;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth_data, _, _| {
+            webhook_endpoints::delete_webhook_endpoint(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                endpoint_id.clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointMetricsRetrieve))]
+pub async fn webhook_endpoint_metrics_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId, String)>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointMetricsRetrieve;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth_data, _, _| {
+            webhook_endpoints::retrieve_webhook_endpoint_metrics(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                endpoint_id.clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointDeliveryAttemptsList))]
+pub async fn webhook_endpoint_delivery_attempts_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, id_type::ProfileId, String)>,
+    query: web::Query<webhook_endpoint_types::WebhookEndpointDeliveryAttemptsListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::WebhookEndpointDeliveryAttemptsList;
+    let (merchant_id, profile_id, endpoint_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth_data, constraints, _| {
+            webhook_endpoints::list_webhook_endpoint_delivery_attempts(
+                state,
+                auth_data.merchant_account,
+                auth_data.key_store,
+                profile_id.clone(),
+                endpoint_id.clone(),
+                constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantAndProfileFromRoute {
+                merchant_id: merchant_id.clone(),
+                profile_id: profile_id.clone(),
+                required_permission: permissions::Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -2,6 +2,8 @@ use actix_web::{web, HttpRequest, Responder};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+use crate::core::payment_methods::token_lifecycle;
 use crate::{
     core::{
         api_locking,
@@ -42,3 +44,33 @@ pub async fn receive_incoming_webhook<W: types::OutgoingWebhookType>(
     ))
     .await
 }
+
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::NetworkTokenLifecycleWebhook))]
+pub async fn receive_network_token_lifecycle_webhook(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::payment_methods::NetworkTokenLifecycleEvent>,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> impl Responder {
+    let flow = Flow::NetworkTokenLifecycleWebhook;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            token_lifecycle::handle_network_token_lifecycle_event(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::NetworkTokenServiceAuthWithMerchantIdFromRoute(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
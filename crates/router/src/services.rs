@@ -14,6 +14,8 @@ pub mod pm_auth;
 
 #[cfg(feature = "olap")]
 pub mod openidconnect;
+#[cfg(feature = "olap")]
+pub mod saml;
 
 use std::sync::Arc;
 
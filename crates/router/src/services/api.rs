@@ -1,6 +1,8 @@
 pub mod client;
 pub mod generic_link_response;
 pub mod request;
+pub mod request_lane;
+pub mod sparse_fieldset;
 use std::{
     collections::{HashMap, HashSet},
     error::Error,
@@ -29,40 +31,49 @@ use error_stack::{report, Report, ResultExt};
 use hyperswitch_domain_models::router_data_v2::flow_common_types as common_types;
 pub use hyperswitch_domain_models::{
     api::{
-        ApplicationResponse, GenericExpiredLinkData, GenericLinkFormData, GenericLinkStatusData,
-        GenericLinks, PaymentLinkAction, PaymentLinkFormData, PaymentLinkStatusData,
-        RedirectionFormData,
+        ApplicationResponse, FileStream, GenericExpiredLinkData, GenericLinkFormData,
+        GenericLinkStatusData, GenericLinks, PaymentLinkAction, PaymentLinkFormData,
+        PaymentLinkStatusData, RedirectionFormBranding, RedirectionFormData,
     },
     payment_method_data::PaymentMethodData,
     router_response_types::RedirectForm,
 };
+use hyperswitch_interfaces::{api::ConnectorCommon, configs::ConnectorFlowRequestPolicy};
 pub use hyperswitch_interfaces::{
     api::{
         BoxedConnectorIntegration, CaptureSyncMethod, ConnectorIntegration,
-        ConnectorIntegrationAny, ConnectorRedirectResponse, ConnectorValidation,
+        ConnectorIntegrationAny, ConnectorRedirectResponse, ConnectorValidation, VoidWindow,
     },
     connector_integration_v2::{
         BoxedConnectorIntegrationV2, ConnectorIntegrationAnyV2, ConnectorIntegrationV2,
     },
 };
 use masking::{Maskable, PeekInterface};
-use router_env::{instrument, metrics::add_attributes, tracing, tracing_actix_web::RequestId, Tag};
+use router_env::{
+    instrument,
+    metrics::add_attributes,
+    tracing::{self, Instrument},
+    tracing_actix_web::RequestId,
+    Tag,
+};
 use serde::Serialize;
 use serde_json::json;
 use tera::{Context, Error as TeraError, Tera};
 
 use self::request::{HeaderExt, RequestBuilderExt};
 use super::{
-    authentication::AuthenticateAndFetch,
+    authentication::{AuthenticateAndFetch, AuthenticationType},
     connector_integration_interface::BoxedConnectorIntegrationInterface,
 };
 use crate::{
     configs::Settings,
     consts,
     core::{
-        api_locking,
+        api_locking, api_logs, connector_debug_capture, connector_outage, data_residency,
         errors::{self, CustomResult},
-        payments,
+        localization, merchant_account_lifecycle,
+        payment_methods::external_vault,
+        payments, traffic_mirroring,
     },
     events::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
@@ -102,6 +113,10 @@ pub type BoxedAccessTokenConnectorIntegrationInterface<T, Req, Resp> =
     BoxedConnectorIntegrationInterface<T, common_types::AccessTokenFlowData, Req, Resp>;
 pub type BoxedFilesConnectorIntegrationInterface<T, Req, Resp> =
     BoxedConnectorIntegrationInterface<T, common_types::FilesFlowData, Req, Resp>;
+pub type BoxedAccountBalanceConnectorIntegrationInterface<T, Req, Resp> =
+    BoxedConnectorIntegrationInterface<T, common_types::AccountBalanceFlowData, Req, Resp>;
+pub type BoxedAccountUpdateConnectorIntegrationInterface<T, Req, Resp> =
+    BoxedConnectorIntegrationInterface<T, common_types::AccountUpdateFlowData, Req, Resp>;
 
 /// Handle the flow by interacting with connector module
 /// `connector_request` is applicable only in case if the `CallConnectorAction` is `Trigger`
@@ -196,7 +211,10 @@ where
                             )
                         }
                     })?,
-            };
+            }
+            // Requests prebuilt by the flows carry the idempotency key as well
+            .map(|request| attach_idempotency_header(&connector_integration, req, request))
+            .transpose()?;
 
             match connector_request {
                 Some(request) => {
@@ -214,12 +232,20 @@ where
                     };
                     let request_url = request.url.clone();
                     let request_method = request.method;
+                    let request_policy =
+                        get_connector_flow_request_policy::<T>(state, &req.connector);
                     let current_time = Instant::now();
-                    let response =
-                        call_connector_api(state, request, "execute_connector_processing_step")
-                            .await;
+                    let response = call_connector_api_with_policy(
+                        state,
+                        &connector_integration,
+                        req,
+                        request,
+                        &request_policy,
+                    )
+                    .await;
                     let external_latency = current_time.elapsed().as_millis();
                     logger::info!(raw_connector_request=?masked_request_body);
+                    record_connector_call_outcome(state, req, &request_url, &response);
                     let status_code = response
                         .as_ref()
                         .map(|i| {
@@ -227,6 +253,12 @@ where
                                 .map_or_else(|value| value.status_code, |value| value.status_code)
                         })
                         .unwrap_or_default();
+                    let connector_request_id = response.as_ref().ok().and_then(|body| {
+                        get_connector_request_id(
+                            connector_integration.get_request_id_headers(),
+                            body.as_ref().unwrap_or_else(|body| body),
+                        )
+                    });
                     let mut connector_event = ConnectorEvent::new(
                         req.connector.clone(),
                         std::any::type_name::<T>(),
@@ -241,6 +273,7 @@ where
                         req.dispute_id.clone(),
                         status_code,
                     );
+                    connector_event.set_connector_request_id(connector_request_id.clone());
 
                     match response {
                         Ok(body) => {
@@ -268,6 +301,7 @@ where
                                             state.event_handler().log_event(&connector_event);
                                             data.connector_http_status_code =
                                                 connector_http_status_code;
+                                            data.connector_request_id = connector_request_id;
                                             // Add up multiple external latencies in case of multiple external calls within the same request.
                                             data.external_latency = Some(
                                                 data.external_latency
@@ -282,12 +316,18 @@ where
                                                 .set_error(json!({"error": err.to_string()}));
 
                                             state.event_handler().log_event(&connector_event);
+                                            capture_failed_connector_call(
+                                                state,
+                                                req,
+                                                &connector_event,
+                                            );
                                             Err(err)
                                         }
                                     }?
                                 }
                                 Err(body) => {
                                     router_data.connector_http_status_code = Some(body.status_code);
+                                    router_data.connector_request_id = connector_request_id;
                                     router_data.external_latency = Some(
                                         router_data
                                             .external_latency
@@ -307,6 +347,11 @@ where
                                                     Some(&mut connector_event),
                                                 )?;
                                             state.event_handler().log_event(&connector_event);
+                                            capture_failed_connector_call(
+                                                state,
+                                                req,
+                                                &connector_event,
+                                            );
                                             error_res
                                         }
                                         _ => {
@@ -319,6 +364,11 @@ where
                                                 router_data.status = status;
                                             };
                                             state.event_handler().log_event(&connector_event);
+                                            capture_failed_connector_call(
+                                                state,
+                                                req,
+                                                &connector_event,
+                                            );
                                             error_res
                                         }
                                     };
@@ -333,6 +383,7 @@ where
                         Err(error) => {
                             connector_event.set_error(json!({"error": error.to_string()}));
                             state.event_handler().log_event(&connector_event);
+                            capture_failed_connector_call(state, req, &connector_event);
                             if error.current_context().is_upstream_timeout() {
                                 let error_response = ErrorResponse {
                                     code: consts::REQUEST_TIMEOUT_ERROR_CODE.to_string(),
@@ -364,18 +415,254 @@ where
     }
 }
 
+/// Attaches the idempotency key of the flow to the connector request, if the connector supports
+/// one. The key is derived from the router data, so the first request and every rebuild of the
+/// request for a retry carry the same key and the connector does not process the request twice.
+pub fn attach_idempotency_header<T, ResourceCommonData, Req, Resp>(
+    connector_integration: &BoxedConnectorIntegrationInterface<T, ResourceCommonData, Req, Resp>,
+    req: &types::RouterData<T, Req, Resp>,
+    mut request: Request,
+) -> CustomResult<Request, errors::ConnectorError>
+where
+    T: Clone + Debug + 'static,
+    ResourceCommonData: Clone + RouterDataConversion<T, Req, Resp> + 'static,
+    Req: Debug + Clone + 'static,
+    Resp: Debug + Clone + 'static,
+{
+    if let Some((header, idempotency_key)) = connector_integration.get_idempotency_header(req)? {
+        request.add_header(&header, idempotency_key);
+    }
+    Ok(request)
+}
+
+/// Status codes with which connectors reject requests that can be safely retried
+pub const RETRYABLE_CONNECTOR_STATUS_CODES: [u16; 3] = [429, 502, 503];
+
+/// Flows whose requests only read the state of a resource at the connector
+const IDEMPOTENT_CONNECTOR_FLOWS: [&str; 3] = ["PSync", "RSync", "PoSync"];
+
+/// Whether the request of the flow can be sent to the connector again without the risk of it being
+/// processed twice, which is the case for flows that only read the state of a resource and for
+/// connectors which deduplicate requests by the idempotency key of the flow
+pub fn is_connector_request_retryable<T, ResourceCommonData, Req, Resp>(
+    connector_integration: &BoxedConnectorIntegrationInterface<T, ResourceCommonData, Req, Resp>,
+    req: &types::RouterData<T, Req, Resp>,
+) -> bool
+where
+    T: Clone + Debug + 'static,
+    ResourceCommonData: Clone + RouterDataConversion<T, Req, Resp> + 'static,
+    Req: Debug + Clone + 'static,
+    Resp: Debug + Clone + 'static,
+{
+    let flow = std::any::type_name::<T>()
+        .split("::")
+        .last()
+        .unwrap_or_default();
+    IDEMPOTENT_CONNECTOR_FLOWS.contains(&flow)
+        || connector_integration
+            .get_idempotency_header(req)
+            .is_ok_and(|idempotency_header| idempotency_header.is_some())
+}
+
+/// The timeout and retry policy configured for the requests of the flow sent to the connector
+pub fn get_connector_flow_request_policy<T>(
+    state: &SessionState,
+    connector: &str,
+) -> ConnectorFlowRequestPolicy {
+    state.conf.connectors.request_policies.get_flow_policy(
+        connector,
+        std::any::type_name::<T>()
+            .split("::")
+            .last()
+            .unwrap_or_default(),
+    )
+}
+
+/// Sends the request to the connector with the timeout configured for the flow, and retries it as
+/// per the retry policy of the flow while the connector rejects it with a retryable status code.
+/// The request is rebuilt for every retry, and only retried if it cannot be processed twice by the
+/// connector.
+async fn call_connector_api_with_policy<T, ResourceCommonData, Req, Resp>(
+    state: &SessionState,
+    connector_integration: &BoxedConnectorIntegrationInterface<T, ResourceCommonData, Req, Resp>,
+    req: &types::RouterData<T, Req, Resp>,
+    request: Request,
+    request_policy: &ConnectorFlowRequestPolicy,
+) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>
+where
+    T: Clone + Debug + 'static,
+    ResourceCommonData: Clone + RouterDataConversion<T, Req, Resp> + 'static,
+    Req: Debug + Clone + 'static,
+    Resp: Debug + Clone + 'static,
+{
+    // Card numbers of merchant accounts using an external vault are sent as aliases, which the
+    // forward proxy of the vault exchanges for the card numbers
+    let vault_proxy = external_vault::get_request_proxy_for_merchant(state, &req.merchant_id)
+        .await
+        .change_context(errors::ApiClientError::InvalidProxyConfiguration)?;
+    let mut request = request;
+    request.add_proxy(vault_proxy.clone());
+    let mut retries = 0;
+
+    loop {
+        let response = call_connector_api_with_timeout(
+            state,
+            request,
+            "execute_connector_processing_step",
+            request_policy.timeout_secs,
+        )
+        .await;
+
+        let status_code = match &response {
+            Ok(Err(body)) if RETRYABLE_CONNECTOR_STATUS_CODES.contains(&body.status_code) => {
+                body.status_code
+            }
+            _ => return response,
+        };
+        let retry_policy = match request_policy.retry.as_ref() {
+            Some(retry_policy)
+                if retries < retry_policy.max_retries
+                    && is_connector_request_retryable(connector_integration, req) =>
+            {
+                retry_policy
+            }
+            _ => return response,
+        };
+        let rebuilt_request = connector_integration
+            .build_request(req, &state.conf.connectors)
+            .and_then(|request| {
+                request
+                    .map(|request| attach_idempotency_header(connector_integration, req, request))
+                    .transpose()
+            });
+        request = match rebuilt_request {
+            Ok(Some(mut request)) => {
+                request.add_proxy(vault_proxy.clone());
+                request
+            }
+            Ok(None) => return response,
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Failed to rebuild the connector request for the retry"
+                );
+                return response;
+            }
+        };
+
+        let delay = retry_policy.get_delay(retries);
+        retries += 1;
+        logger::info!(
+            connector = %req.connector,
+            status_code,
+            retries,
+            delay_in_millis = delay.as_millis(),
+            "Retrying the connector request"
+        );
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Captures the failed connector call in the background, so that it is retained if the connector
+/// debug capture is enabled for the profile of the payment
+fn capture_failed_connector_call<T, Req, Resp>(
+    state: &SessionState,
+    req: &types::RouterData<T, Req, Resp>,
+    connector_event: &ConnectorEvent,
+) {
+    let connector_call = match serde_json::to_value(connector_event) {
+        Ok(connector_call) => connector_call,
+        Err(error) => {
+            logger::error!(?error, "Failed to serialize the connector call");
+            return;
+        }
+    };
+
+    tokio::spawn(
+        connector_debug_capture::capture_failed_connector_call(
+            state.clone(),
+            req.merchant_id.clone(),
+            req.payment_id.clone(),
+            req.attempt_id.clone(),
+            connector_call,
+        )
+        .in_current_span(),
+    );
+}
+
+/// Records the outcome of the connector call against the host it was made to, for detecting
+/// connector outages
+fn record_connector_call_outcome<T, Req, Resp>(
+    state: &SessionState,
+    req: &types::RouterData<T, Req, Resp>,
+    request_url: &str,
+    response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+) {
+    if !state.conf.connector_outage_detection.enabled {
+        return;
+    }
+
+    let outcome = match response {
+        Ok(Err(body)) if body.status_code >= 500 => connector_outage::ConnectorCallOutcome::Failed,
+        Ok(_) => connector_outage::ConnectorCallOutcome::Responded,
+        Err(error) if error.current_context().is_upstream_timeout() => {
+            connector_outage::ConnectorCallOutcome::TimedOut
+        }
+        Err(_) => connector_outage::ConnectorCallOutcome::Failed,
+    };
+    let Some(region) = url::Url::parse(request_url)
+        .ok()
+        .and_then(|url| url.host_str().map(ToString::to_string))
+    else {
+        return;
+    };
+
+    connector_outage::record_connector_call_outcome(state, &req.connector, &region, outcome);
+}
+
+/// Extracts the identifier assigned by the connector to the request from the first of the given
+/// headers present in its response
+fn get_connector_request_id(
+    request_id_headers: &[&str],
+    response: &types::Response,
+) -> Option<String> {
+    let headers = response.headers.as_ref()?;
+    request_id_headers.iter().find_map(|header_name| {
+        headers
+            .get(*header_name)
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string)
+    })
+}
+
 #[instrument(skip_all)]
 pub async fn call_connector_api(
     state: &SessionState,
     request: Request,
     flow_name: &str,
+) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
+    call_connector_api_with_timeout(state, request, flow_name, None).await
+}
+
+/// Sends the request to the connector, timing out after `option_timeout_secs` instead of the
+/// default request timeout if set
+#[instrument(skip_all)]
+pub async fn call_connector_api_with_timeout(
+    state: &SessionState,
+    request: Request,
+    flow_name: &str,
+    option_timeout_secs: Option<u64>,
 ) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
     let current_time = Instant::now();
     let headers = request.headers.clone();
     let url = request.url.clone();
+    let _connector_call_permit = state
+        .connector_call_limiter
+        .acquire(state.request_lane)
+        .await;
     let response = state
         .api_client
-        .send_request(state, request, None, true)
+        .send_request(state, request, option_timeout_secs, true)
         .await;
 
     match response.as_ref() {
@@ -387,6 +674,7 @@ pub async fn call_connector_api(
                 url,
                 status_code,
                 flow=?flow_name,
+                request_lane=%state.request_lane,
                 ?elapsed_time
             );
         }
@@ -418,6 +706,7 @@ pub async fn send_request(
         || proxy_bypass_urls(
             state.conf.key_manager.get_inner(),
             &state.conf.locker,
+            &state.conf.data_residency,
             &state.conf.proxy.bypass_proxy_urls,
         )
         .contains(&url.to_string());
@@ -425,15 +714,25 @@ pub async fn send_request(
     let should_bypass_proxy = proxy_bypass_urls(
         &state.conf.key_manager.get_inner(),
         &state.conf.locker,
+        &state.conf.data_residency,
         &state.conf.proxy.bypass_proxy_urls,
     )
     .contains(&url.to_string());
-    let client = client::create_client(
-        &state.conf.proxy,
-        should_bypass_proxy,
-        request.certificate,
-        request.certificate_key,
-    )?;
+    // Requests through the forward proxy of an external vault skip the configured outbound proxy
+    let client = match request.proxy.as_ref() {
+        Some(vault_proxy) => client::get_vault_proxy_client(
+            &state.conf.proxy,
+            vault_proxy,
+            request.certificate,
+            request.certificate_key,
+        )?,
+        None => client::create_client(
+            &state.conf.proxy,
+            should_bypass_proxy,
+            request.certificate,
+            request.certificate_key,
+        )?,
+    };
 
     let headers = request.headers.construct_header_map()?;
     let metrics_tag = router_env::opentelemetry::KeyValue {
@@ -683,6 +982,17 @@ pub enum AuthFlow {
     Merchant,
 }
 
+/// Extracts the error code from an error serialized as an `ApiErrorResponse`
+fn get_error_code(error: &serde_json::Value) -> Option<String> {
+    let error = error.get("value")?;
+
+    Some(format!(
+        "{}_{:02}",
+        error.get("sub_code")?.as_str()?,
+        error.get("error_identifier")?.as_u64()?
+    ))
+}
+
 #[allow(clippy::too_many_arguments)]
 #[instrument(
     skip(request, payload, state, func, api_auth, incoming_request_header),
@@ -750,6 +1060,7 @@ where
             .switch()
         })?;
     session_state.add_request_id(request_id);
+    session_state.request_lane = request_lane::RequestLane::from_headers(request.headers());
     let mut request_state = session_state.get_req_state();
 
     request_state.event_context.record_info(request_id);
@@ -769,6 +1080,33 @@ where
 
     request_state.event_context.record_info(auth_type.clone());
 
+    // Admin requests are exempt, as they are used to manage the merchant account status itself
+    if let Some(merchant_id) = auth_type.get_merchant_id().filter(|_| {
+        !matches!(
+            auth_type,
+            AuthenticationType::AdminApiAuthWithMerchantId { .. }
+        )
+    }) {
+        merchant_account_lifecycle::validate_request_for_merchant_account_status(
+            &session_state,
+            merchant_id,
+            &flow.to_string(),
+            request.method(),
+        )
+        .await
+        .switch()?;
+        data_residency::validate_request_for_data_region(&session_state, merchant_id)
+            .await
+            .switch()?;
+    }
+    traffic_mirroring::validate_mirrored_request(
+        &session_state,
+        request,
+        auth_type.get_merchant_id(),
+    )
+    .await
+    .switch()?;
+
     let merchant_id = auth_type
         .get_merchant_id()
         .cloned()
@@ -779,18 +1117,26 @@ where
     tracing::Span::current().record("merchant_id", merchant_id.get_string_repr().to_owned());
 
     let output = {
-        lock_action
+        let fenced_lock_status = lock_action
             .clone()
             .perform_locking_action(&session_state, merchant_id.to_owned())
             .await
             .switch()?;
+        // A request replaying the outcome of a duplicate request does not hold the lock
+        let should_free_lock = !matches!(
+            fenced_lock_status,
+            Some(api_locking::FencedLockStatus::Replay)
+        );
+        request_state.fenced_lock_status = fenced_lock_status;
         let res = func(session_state.clone(), auth_out, payload, request_state)
             .await
             .switch();
-        lock_action
-            .free_lock_action(&session_state, merchant_id.to_owned())
-            .await
-            .switch()?;
+        if should_free_lock {
+            lock_action
+                .free_lock_action(&session_state, merchant_id.to_owned())
+                .await
+                .switch()?;
+        }
         res
     };
     let request_duration = Instant::now()
@@ -838,6 +1184,41 @@ where
         }
     };
 
+    if session_state.conf.api_logs.enabled {
+        if let Some(log_merchant_id) = auth_type.get_merchant_id().cloned() {
+            let api_log = api_models::api_logs::ApiLogResponse {
+                request_id: request_id.as_hyphenated().to_string(),
+                api_flow: flow.to_string(),
+                http_method: request.method().to_string(),
+                url_path: request.path().to_string(),
+                status_code,
+                latency: request_duration,
+                error_code: error.as_ref().and_then(get_error_code),
+                api_key_id: match &auth_type {
+                    AuthenticationType::ApiKey { key_id, .. } => Some(key_id.clone()),
+                    _ => None,
+                },
+                created_at: common_utils::date_time::now(),
+            };
+            let log_state = session_state.clone();
+            tokio::spawn(
+                async move {
+                    api_logs::record_api_log(&log_state, &log_merchant_id, api_log).await;
+                }
+                .in_current_span(),
+            );
+        }
+    }
+
+    if traffic_mirroring::should_mirror_request(&session_state, &flow.to_string(), request) {
+        traffic_mirroring::mirror_request(
+            &session_state,
+            request_id.as_hyphenated().to_string(),
+            request,
+            serialized_request.clone(),
+        );
+    }
+
     let api_event = ApiEvent::new(
         Some(merchant_id.clone()),
         flow,
@@ -910,6 +1291,8 @@ where
 
     let start_instant = Instant::now();
 
+    let field_selection = sparse_fieldset::FieldSelection::from_request(request);
+
     logger::info!(
         tag = ?Tag::BeginRequest, payload = ?payload,
     headers = ?incoming_header_to_log);
@@ -934,21 +1317,36 @@ where
     });
 
     let res = match server_wrap_util_res {
-        Ok(ApplicationResponse::Json(response)) => match serde_json::to_string(&response) {
-            Ok(res) => http_response_json(res),
-            Err(_) => http_response_err(
-                r#"{
+        Ok(ApplicationResponse::Json(response)) => {
+            match sparse_fieldset::serialize_response(&response, field_selection.as_ref()) {
+                Ok(res) => http_response_json(res),
+                Err(_) => http_response_err(
+                    r#"{
                     "error": {
                         "message": "Error serializing response from connector"
                     }
                 }"#,
-            ),
-        },
+                ),
+            }
+        }
+        Ok(ApplicationResponse::JsonAccepted(response)) => {
+            match sparse_fieldset::serialize_response(&response, field_selection.as_ref()) {
+                Ok(res) => http_response_json_accepted(res),
+                Err(_) => http_response_err(
+                    r#"{
+                    "error": {
+                        "message": "Error serializing response from connector"
+                    }
+                }"#,
+                ),
+            }
+        }
         Ok(ApplicationResponse::StatusOk) => http_response_ok(),
         Ok(ApplicationResponse::TextPlain(text)) => http_response_plaintext(text),
         Ok(ApplicationResponse::FileData((file_data, content_type))) => {
             http_response_file_data(file_data, content_type)
         }
+        Ok(ApplicationResponse::FileStream(file_stream)) => http_response_file_stream(file_stream),
         Ok(ApplicationResponse::JsonForRedirection(response)) => {
             match serde_json::to_string(&response) {
                 Ok(res) => http_redirect_response(res, response),
@@ -963,12 +1361,20 @@ where
         }
         Ok(ApplicationResponse::Form(redirection_data)) => {
             let config = state.conf();
+            let localizer = localization::Localizer::new(
+                request
+                    .headers()
+                    .get(crate::headers::ACCEPT_LANGUAGE)
+                    .and_then(|locale| locale.to_str().ok()),
+            );
             build_redirection_form(
                 &redirection_data.redirect_form,
                 redirection_data.payment_method_data,
                 redirection_data.amount,
                 redirection_data.currency,
+                redirection_data.branding,
                 config,
+                &localizer,
             )
             .respond_to(request)
             .map_into_boxed_body()
@@ -1037,7 +1443,7 @@ where
                     None
                 }
             });
-            match serde_json::to_string(&response) {
+            match sparse_fieldset::serialize_response(&response, field_selection.as_ref()) {
                 Ok(res) => http_response_json_with_headers(res, headers, request_elapsed_time),
                 Err(_) => http_response_err(
                     r#"{
@@ -1118,6 +1524,12 @@ pub fn http_response_json<T: body::MessageBody + 'static>(response: T) -> HttpRe
         .body(response)
 }
 
+pub fn http_response_json_accepted<T: body::MessageBody + 'static>(response: T) -> HttpResponse {
+    HttpResponse::Accepted()
+        .content_type(mime::APPLICATION_JSON)
+        .body(response)
+}
+
 pub fn http_server_error_json_response<T: body::MessageBody + 'static>(
     response: T,
 ) -> HttpResponse {
@@ -1173,6 +1585,12 @@ pub fn http_response_file_data<T: body::MessageBody + 'static>(
     HttpResponse::Ok().content_type(content_type).body(res)
 }
 
+pub fn http_response_file_stream(file_stream: FileStream) -> HttpResponse {
+    HttpResponse::Ok()
+        .content_type(file_stream.content_type)
+        .streaming(file_stream.body)
+}
+
 pub fn http_response_html_data<T: body::MessageBody + 'static>(
     res: T,
     optional_headers: Option<HashSet<(&'static str, String)>>,
@@ -1267,11 +1685,22 @@ pub fn build_redirection_form(
     payment_method_data: Option<PaymentMethodData>,
     amount: String,
     currency: String,
+    branding: Option<RedirectionFormBranding>,
     config: Settings,
+    localizer: &localization::Localizer,
 ) -> maud::Markup {
     use maud::PreEscaped;
     let logging_template =
         include_str!("redirection/assets/redirect_error_logs_push.js").to_string();
+    let branding = branding.unwrap_or_default();
+    let processing_message = branding
+        .processing_message
+        .clone()
+        .unwrap_or_else(|| localizer.translate("redirection.processing_payment"));
+    let theme = branding
+        .theme
+        .clone()
+        .unwrap_or_else(|| "#000000".to_string());
     match form {
         RedirectForm::Form {
             endpoint,
@@ -1303,6 +1732,17 @@ pub fn build_redirection_form(
 
             body style="background-color: #ffffff; padding: 20px; font-family: Arial, Helvetica, Sans-Serif;" {
 
+                @if branding.logo.is_some() || branding.seller_name.is_some() {
+                    div style="display: flex; align-items: center; justify-content: center; gap: 12px; margin-bottom: 20px;" {
+                        @if let Some(logo) = &branding.logo {
+                            img src=(logo) alt="" style="max-height: 48px; max-width: 160px;";
+                        }
+                        @if let Some(seller_name) = &branding.seller_name {
+                            span style=(format!("font-size: 18px; font-weight: 600; color: {theme};")) { (seller_name) }
+                        }
+                    }
+                }
+
                 div id="loader1" class="lottie" style="height: 150px; display: block; position: relative; margin-left: auto; margin-right: auto;" { "" }
 
                 (PreEscaped(r#"<script src="https://cdnjs.cloudflare.com/ajax/libs/bodymovin/5.7.4/lottie.min.js"></script>"#))
@@ -1320,7 +1760,7 @@ pub fn build_redirection_form(
                 </script>
                 "#))
 
-                h3 style="text-align: center;" { "Please wait while we process your payment..." }
+                h3 style=(format!("text-align: center; color: {theme};")) { (processing_message) }
                     form action=(PreEscaped(endpoint)) method=(method.to_string()) #payment_form {
                         @for (field, value) in form_fields {
                         input type="hidden" name=(field) value=(value);
@@ -1390,7 +1830,7 @@ pub fn build_redirection_form(
                         </script>
                         "#))
 
-                        h3 style="text-align: center;" { "Please wait while we process your payment..." }
+                        h3 style="text-align: center;" { (processing_message) }
                     }
 
                 (PreEscaped(format!("<script>
@@ -1445,7 +1885,7 @@ pub fn build_redirection_form(
                             </script>
                             "#))
 
-                        h3 style="text-align: center;" { "Please wait while we process your payment..." }
+                        h3 style="text-align: center;" { (processing_message) }
                     }
 
                 (PreEscaped(r#"<iframe id="cardinal_collection_iframe" name="collectionIframe" height="10" width="10" style="display: none;"></iframe>"#))
@@ -1497,7 +1937,7 @@ pub fn build_redirection_form(
                             </script>
                             "#))
 
-                        h3 style="text-align: center;" { "Please wait while we process your payment..." }
+                        h3 style="text-align: center;" { (processing_message) }
                     }
 
                 // This is the iframe recommended by cybersource but the redirection happens inside this iframe once otp
@@ -1575,7 +2015,7 @@ pub fn build_redirection_form(
                             </script>
                             "#))
 
-                        h3 style="text-align: center;" { "Please wait while we process your payment..." }
+                        h3 style="text-align: center;" { (processing_message) }
                     }
 
                 (PreEscaped(format!("<script>
@@ -1671,7 +2111,7 @@ pub fn build_redirection_form(
                             </script>
                             "#))
 
-                        h3 style="text-align: center;" { "Please wait while we process your payment..." }
+                        h3 style="text-align: center;" { (processing_message) }
                         }
 
                         div id="threeds-wrapper" style="display: flex; width: 100%; height: 100vh; align-items: center; justify-content: center;" {""}
@@ -1843,7 +2283,7 @@ pub fn build_redirection_form(
                             })
                         </script>
                     "#))
-                    h3 style="text-align: center;" { "Please wait while we process your payment..." }
+                    h3 style="text-align: center;" { (processing_message) }
 
                     script {
                         (PreEscaped(format!(
@@ -1,6 +1,7 @@
-use std::time::Duration;
+use std::{collections::HashMap, sync::RwLock, time::Duration};
 
 use base64::Engine;
+use common_utils::request::RequestProxy;
 use error_stack::ResultExt;
 use http::{HeaderValue, Method};
 use masking::{ExposeInterface, PeekInterface};
@@ -10,7 +11,7 @@ use router_env::tracing_actix_web::RequestId;
 
 use super::{request::Maskable, Request};
 use crate::{
-    configs::settings::{Locker, Proxy},
+    configs::settings::{DataResidency, Locker, Proxy},
     consts::{BASE64_ENGINE, LOCKER_HEALTH_CALL_PATH},
     core::errors::{ApiClientError, CustomResult},
     routes::{app::settings::KeyManagerConfig, SessionState},
@@ -18,6 +19,7 @@ use crate::{
 
 static NON_PROXIED_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
 static PROXIED_CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
+static VAULT_PROXY_CLIENTS: OnceCell<RwLock<HashMap<String, reqwest::Client>>> = OnceCell::new();
 
 fn get_client_builder(
     proxy_config: &Proxy,
@@ -109,6 +111,89 @@ pub fn create_client(
     }
 }
 
+fn get_vault_proxy_client_builder(
+    proxy_config: &Proxy,
+    vault_proxy: &RequestProxy,
+) -> CustomResult<reqwest::ClientBuilder, ApiClientError> {
+    let client_builder = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .pool_idle_timeout(Duration::from_secs(
+            proxy_config
+                .idle_pool_connection_timeout
+                .unwrap_or_default(),
+        ))
+        .proxy(
+            reqwest::Proxy::all(vault_proxy.url.peek())
+                .change_context(ApiClientError::InvalidProxyConfiguration)
+                .attach_printable("External vault proxy configuration error")?,
+        );
+    let certificate_list = vault_proxy
+        .ca_certificate
+        .clone()
+        .map(create_certificate)
+        .transpose()?
+        .unwrap_or_default();
+    Ok(certificate_list
+        .into_iter()
+        .fold(client_builder, |client_builder, certificate| {
+            client_builder.add_root_certificate(certificate)
+        }))
+}
+
+/// Client sending the requests through the forward proxy of an external vault. The clients
+/// without a client certificate are reused across requests as the proxies are part of the
+/// configuration, the clients presenting the certificate of a connector account are built per
+/// request like those of `create_client`.
+pub fn get_vault_proxy_client(
+    proxy_config: &Proxy,
+    vault_proxy: &RequestProxy,
+    client_certificate: Option<masking::Secret<String>>,
+    client_certificate_key: Option<masking::Secret<String>>,
+) -> CustomResult<reqwest::Client, ApiClientError> {
+    if let (Some(encoded_certificate), Some(encoded_certificate_key)) =
+        (client_certificate, client_certificate_key)
+    {
+        let identity = create_identity_from_certificate_and_key(
+            encoded_certificate.clone(),
+            encoded_certificate_key,
+        )?;
+        let certificate_list = create_certificate(encoded_certificate)?;
+        return certificate_list
+            .into_iter()
+            .fold(
+                get_vault_proxy_client_builder(proxy_config, vault_proxy)?,
+                |client_builder, certificate| client_builder.add_root_certificate(certificate),
+            )
+            .identity(identity)
+            .use_rustls_tls()
+            .build()
+            .change_context(ApiClientError::ClientConstructionFailed)
+            .attach_printable(
+                "Failed to construct external vault proxy client with certificate and certificate key",
+            );
+    }
+
+    let clients = VAULT_PROXY_CLIENTS.get_or_init(|| RwLock::new(HashMap::new()));
+    let proxy_url = vault_proxy.url.peek();
+    if let Some(client) = clients
+        .read()
+        .ok()
+        .and_then(|clients| clients.get(proxy_url).cloned())
+    {
+        return Ok(client);
+    }
+
+    let client = get_vault_proxy_client_builder(proxy_config, vault_proxy)?
+        .build()
+        .change_context(ApiClientError::ClientConstructionFailed)
+        .attach_printable("Failed to construct external vault proxy client")?;
+
+    if let Ok(mut clients) = clients.write() {
+        clients.insert(proxy_url.clone(), client.clone());
+    }
+    Ok(client)
+}
+
 pub fn create_identity_from_certificate_and_key(
     encoded_certificate: masking::Secret<String>,
     encoded_certificate_key: masking::Secret<String>,
@@ -148,30 +233,44 @@ pub fn create_certificate(
 pub fn proxy_bypass_urls(
     key_manager: &KeyManagerConfig,
     locker: &Locker,
+    data_residency: &DataResidency,
     config_whitelist: &[String],
 ) -> Vec<String> {
     let key_manager_host = key_manager.url.to_owned();
-    let locker_host = locker.host.to_owned();
-    let locker_host_rs = locker.host_rs.to_owned();
-
-    let proxy_list = [
-        format!("{locker_host}/cards/add"),
-        format!("{locker_host}/cards/fingerprint"),
-        format!("{locker_host}/cards/retrieve"),
-        format!("{locker_host}/cards/delete"),
-        format!("{locker_host_rs}/cards/add"),
-        format!("{locker_host_rs}/cards/retrieve"),
-        format!("{locker_host_rs}/cards/delete"),
-        format!("{locker_host_rs}{}", LOCKER_HEALTH_CALL_PATH),
-        format!("{locker_host}/card/addCard"),
-        format!("{locker_host}/card/getCard"),
-        format!("{locker_host}/card/deleteCard"),
+    // The card vaults of all the data regions are called directly, like the default card vault
+    let locker_hosts = std::iter::once((locker.host.as_str(), locker.host_rs.as_str())).chain(
+        data_residency
+            .regions
+            .values()
+            .filter(|_| data_residency.enabled)
+            .map(|region| (region.locker_host.as_str(), region.locker_host_rs.as_str())),
+    );
+
+    let locker_list = locker_hosts.flat_map(|(locker_host, locker_host_rs)| {
+        [
+            format!("{locker_host}/cards/add"),
+            format!("{locker_host}/cards/fingerprint"),
+            format!("{locker_host}/cards/retrieve"),
+            format!("{locker_host}/cards/delete"),
+            format!("{locker_host_rs}/cards/add"),
+            format!("{locker_host_rs}/cards/retrieve"),
+            format!("{locker_host_rs}/cards/delete"),
+            format!("{locker_host_rs}{}", LOCKER_HEALTH_CALL_PATH),
+            format!("{locker_host}/card/addCard"),
+            format!("{locker_host}/card/getCard"),
+            format!("{locker_host}/card/deleteCard"),
+        ]
+    });
+    let key_manager_list = [
         format!("{key_manager_host}/data/encrypt"),
         format!("{key_manager_host}/data/decrypt"),
         format!("{key_manager_host}/key/create"),
         format!("{key_manager_host}/key/rotate"),
     ];
-    [&proxy_list, config_whitelist].concat().to_vec()
+    locker_list
+        .chain(key_manager_list)
+        .chain(config_whitelist.iter().cloned())
+        .collect()
 }
 
 pub trait RequestBuilder: Send + Sync {
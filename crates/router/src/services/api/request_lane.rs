@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use actix_web::http::header::HeaderMap;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::{configs::settings::RequestLanes, headers};
+
+/// The class of traffic a request belongs to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, strum::Display, strum::EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum RequestLane {
+    /// Customer facing traffic, such as checkout
+    #[default]
+    Live,
+    /// Background traffic, such as scheduled syncs, retries and replays
+    Batch,
+}
+
+impl RequestLane {
+    /// Classifies the request based on the `X-Request-Lane` header, requests are considered to be
+    /// live traffic unless explicitly marked as batch traffic
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        headers
+            .get(headers::X_REQUEST_LANE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse().ok())
+            .unwrap_or_default()
+    }
+}
+
+/// Bounds the number of connector calls in progress for each request lane, so that batch traffic
+/// cannot use up the connector capacity needed by live traffic. The rate of connector calls is
+/// split between the lanes by the connector rate limiter of each merchant connector account.
+///
+/// The lanes are not served by separate worker pools: the http workers, the database and redis
+/// connection pools are shared by both lanes, and a batch request only waits for capacity once it
+/// reaches the connector call. Batch traffic should be run on separate instances of the
+/// application where it must not compete with live traffic for these.
+#[derive(Debug, Clone)]
+pub struct ConnectorCallLimiter {
+    live: Option<Arc<Semaphore>>,
+    batch: Arc<Semaphore>,
+}
+
+impl ConnectorCallLimiter {
+    pub fn new(config: &RequestLanes) -> Self {
+        Self {
+            live: config
+                .live_max_concurrent_connector_calls
+                .map(|permits| Arc::new(Semaphore::new(permits))),
+            batch: Arc::new(Semaphore::new(config.batch_max_concurrent_connector_calls)),
+        }
+    }
+
+    /// Waits until a connector call can be made in the request lane. The returned permit must be
+    /// held until the connector call is complete.
+    pub async fn acquire(&self, lane: RequestLane) -> Option<OwnedSemaphorePermit> {
+        let semaphore = match lane {
+            RequestLane::Live => self.live.as_ref()?,
+            RequestLane::Batch => &self.batch,
+        };
+
+        // The semaphores are never closed, so acquiring a permit cannot fail
+        Arc::clone(semaphore).acquire_owned().await.ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use actix_web::http::header::{HeaderName, HeaderValue};
+
+    use super::*;
+
+    #[test]
+    fn test_request_lane_from_headers() {
+        let mut header_map = HeaderMap::new();
+        assert_eq!(RequestLane::from_headers(&header_map), RequestLane::Live);
+
+        header_map.insert(
+            HeaderName::from_static("x-request-lane"),
+            HeaderValue::from_static("batch"),
+        );
+        assert_eq!(RequestLane::from_headers(&header_map), RequestLane::Batch);
+
+        header_map.insert(
+            HeaderName::from_static("x-request-lane"),
+            HeaderValue::from_static("unknown"),
+        );
+        assert_eq!(RequestLane::from_headers(&header_map), RequestLane::Live);
+    }
+
+    #[tokio::test]
+    async fn test_batch_lane_is_bounded() {
+        let limiter = ConnectorCallLimiter::new(&RequestLanes {
+            live_max_concurrent_connector_calls: None,
+            batch_max_concurrent_connector_calls: 1,
+        });
+
+        let permit = limiter.acquire(RequestLane::Batch).await;
+        assert!(permit.is_some());
+        assert_eq!(limiter.batch.available_permits(), 0);
+        assert!(limiter.acquire(RequestLane::Live).await.is_none());
+
+        drop(permit);
+        assert_eq!(limiter.batch.available_permits(), 1);
+    }
+}
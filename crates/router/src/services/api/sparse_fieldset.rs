@@ -0,0 +1,215 @@
+use std::collections::BTreeMap;
+
+use actix_web::{HttpMessage, HttpRequest};
+use serde::Serialize;
+
+/// Query parameter used by clients to request a subset of the response fields
+pub const FIELDS_QUERY_PARAM: &str = "fields";
+
+/// A tree of fields requested through the `fields` query parameter.
+///
+/// The parameter accepts a comma separated list of field paths, where nested fields are
+/// separated by a `.`, for example `?fields=payment_id,status,customer.email`.
+/// Selections are applied to every element of an array, so items of a list response can be
+/// filtered with paths like `data.payment_id`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldSelection {
+    /// The complete value is included in the response
+    All,
+    /// Only the listed keys of the object are included in the response
+    Fields(BTreeMap<String, FieldSelection>),
+}
+
+impl FieldSelection {
+    /// Parses the value of the `fields` query parameter.
+    ///
+    /// Returns `None` if no field paths were provided, in which case the response is not filtered.
+    pub fn parse(fields: &str) -> Option<Self> {
+        let mut selection = Self::Fields(BTreeMap::new());
+        let mut has_fields = false;
+
+        for path in fields
+            .split(',')
+            .map(str::trim)
+            .filter(|path| !path.is_empty())
+        {
+            let segments = path
+                .split('.')
+                .map(str::trim)
+                .filter(|segment| !segment.is_empty())
+                .collect::<Vec<_>>();
+
+            if !segments.is_empty() {
+                selection.insert(&segments);
+                has_fields = true;
+            }
+        }
+
+        has_fields.then_some(selection)
+    }
+
+    /// The field selection of the incoming request, extracted from its query string by the
+    /// `SparseFieldset` middleware
+    pub fn from_request(request: &HttpRequest) -> Option<Self> {
+        request.extensions().get::<Self>().cloned()
+    }
+
+    fn insert(&mut self, path: &[&str]) {
+        match self {
+            // The complete value is already selected, a nested path cannot narrow it down
+            Self::All => {}
+            Self::Fields(fields) => match path.split_first() {
+                None => *self = Self::All,
+                Some((field, rest)) => fields
+                    .entry((*field).to_string())
+                    .or_insert_with(|| Self::Fields(BTreeMap::new()))
+                    .insert(rest),
+            },
+        }
+    }
+
+    /// Retains only the selected fields of the provided value
+    pub fn apply(&self, value: serde_json::Value) -> serde_json::Value {
+        match (self, value) {
+            (Self::All, value) => value,
+            (Self::Fields(fields), serde_json::Value::Object(object)) => object
+                .into_iter()
+                .filter_map(|(key, value)| {
+                    fields
+                        .get(&key)
+                        .map(|selection| (key, selection.apply(value)))
+                })
+                .collect::<serde_json::Map<_, _>>()
+                .into(),
+            (Self::Fields(_), serde_json::Value::Array(items)) => items
+                .into_iter()
+                .map(|item| self.apply(item))
+                .collect::<Vec<_>>()
+                .into(),
+            (Self::Fields(_), value) => value,
+        }
+    }
+}
+
+/// Removes the `fields` query parameter from the query string, so that the query parameters of
+/// every endpoint are deserialized without it, and returns the field selection it carried.
+///
+/// Returns `None` if the query string does not contain the `fields` query parameter, the other
+/// query parameters are retained as they were encoded by the client.
+pub fn strip_from_query_string(query_string: &str) -> Option<(String, Option<FieldSelection>)> {
+    let mut has_fields = false;
+    let mut field_selection = None;
+    let mut remaining_pairs = Vec::new();
+
+    for pair in query_string.split('&').filter(|pair| !pair.is_empty()) {
+        match url::form_urlencoded::parse(pair.as_bytes()).next() {
+            Some((key, value)) if key == FIELDS_QUERY_PARAM => {
+                has_fields = true;
+                field_selection = field_selection.or_else(|| FieldSelection::parse(&value));
+            }
+            _ => remaining_pairs.push(pair),
+        }
+    }
+
+    has_fields.then(|| (remaining_pairs.join("&"), field_selection))
+}
+
+/// Serializes the response, retaining only the selected fields if a selection was provided
+pub fn serialize_response<T: Serialize>(
+    response: &T,
+    field_selection: Option<&FieldSelection>,
+) -> Result<String, serde_json::Error> {
+    match field_selection {
+        Some(selection) => serde_json::to_value(response)
+            .and_then(|value| serde_json::to_string(&selection.apply(value))),
+        None => serde_json::to_string(response),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_empty_fields_are_ignored() {
+        assert_eq!(FieldSelection::parse(""), None);
+        assert_eq!(FieldSelection::parse(" , ,."), None);
+    }
+
+    #[test]
+    fn test_nested_field_selection() {
+        let selection = FieldSelection::parse("payment_id, customer.email,data.status").unwrap();
+        let response = json!({
+            "payment_id": "pay_123",
+            "amount": 6540,
+            "customer": { "id": "cus_123", "email": "guest@example.com" },
+            "data": [{ "status": "succeeded", "amount": 100 }, { "status": "failed" }]
+        });
+
+        assert_eq!(
+            selection.apply(response),
+            json!({
+                "payment_id": "pay_123",
+                "customer": { "email": "guest@example.com" },
+                "data": [{ "status": "succeeded" }, { "status": "failed" }]
+            })
+        );
+    }
+
+    #[test]
+    fn test_fields_are_stripped_from_query_string() {
+        assert_eq!(strip_from_query_string("limit=10&offset=5"), None);
+        assert_eq!(
+            strip_from_query_string("limit=10&fields=data.payment_id%2Cdata.status&offset=5"),
+            Some((
+                "limit=10&offset=5".to_string(),
+                FieldSelection::parse("data.payment_id,data.status")
+            ))
+        );
+        assert_eq!(
+            strip_from_query_string("fields=&created%5Blt%5D=2024-01-01"),
+            Some(("created%5Blt%5D=2024-01-01".to_string(), None))
+        );
+    }
+
+    #[test]
+    fn test_list_constraints_deserialize_without_fields() {
+        let (query_string, field_selection) =
+            strip_from_query_string("limit=10&fields=data.dispute_id").unwrap();
+        assert!(field_selection.is_some());
+
+        let dispute_constraints = serde_urlencoded::from_str::<
+            api_models::disputes::DisputeListGetConstraints,
+        >(&query_string);
+        assert!(dispute_constraints.is_ok());
+
+        let mandate_constraints = serde_urlencoded::from_str::<
+            api_models::mandates::MandateListConstraints,
+        >(&query_string);
+        assert!(mandate_constraints.is_ok());
+
+        #[cfg(feature = "payouts")]
+        assert!(
+            serde_urlencoded::from_str::<api_models::payouts::PayoutListConstraints>(&query_string)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_parent_field_selects_complete_value() {
+        let selection = FieldSelection::parse("customer.email,customer").unwrap();
+        let response = json!({
+            "customer": { "id": "cus_123", "email": "guest@example.com" },
+            "status": "succeeded"
+        });
+
+        assert_eq!(
+            selection.apply(response),
+            json!({ "customer": { "id": "cus_123", "email": "guest@example.com" } })
+        );
+    }
+}
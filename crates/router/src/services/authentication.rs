@@ -186,6 +186,7 @@ pub struct UserFromSinglePurposeToken {
     pub origin: domain::Origin,
     pub path: Vec<TokenPurpose>,
     pub tenant_id: Option<String>,
+    pub two_factor_auth_verified: bool,
 }
 
 #[cfg(feature = "olap")]
@@ -197,6 +198,8 @@ pub struct SinglePurposeToken {
     pub path: Vec<TokenPurpose>,
     pub exp: u64,
     pub tenant_id: Option<String>,
+    #[serde(default)]
+    pub two_factor_auth_verified: bool,
 }
 
 #[cfg(feature = "olap")]
@@ -208,6 +211,7 @@ impl SinglePurposeToken {
         settings: &Settings,
         path: Vec<TokenPurpose>,
         tenant_id: Option<String>,
+        two_factor_auth_verified: bool,
     ) -> UserResult<String> {
         let exp_duration =
             std::time::Duration::from_secs(consts::SINGLE_PURPOSE_TOKEN_TIME_IN_SECS);
@@ -219,6 +223,7 @@ impl SinglePurposeToken {
             exp,
             path,
             tenant_id,
+            two_factor_auth_verified,
         };
         jwt::generate_jwt(&token_payload, settings).await
     }
@@ -233,10 +238,14 @@ pub struct AuthToken {
     pub org_id: id_type::OrganizationId,
     pub profile_id: Option<id_type::ProfileId>,
     pub tenant_id: Option<String>,
+    /// Whether the user completed two factor authentication while signing in for this session
+    #[serde(default)]
+    pub two_factor_auth_verified: bool,
 }
 
 #[cfg(feature = "olap")]
 impl AuthToken {
+    #[allow(clippy::too_many_arguments)]
     pub async fn new_token(
         user_id: String,
         merchant_id: id_type::MerchantId,
@@ -245,6 +254,7 @@ impl AuthToken {
         org_id: id_type::OrganizationId,
         profile_id: Option<id_type::ProfileId>,
         tenant_id: Option<String>,
+        two_factor_auth_verified: bool,
     ) -> UserResult<String> {
         let exp_duration = std::time::Duration::from_secs(consts::JWT_TOKEN_TIME_IN_SECS);
         let exp = jwt::generate_exp(exp_duration)?.as_secs();
@@ -256,6 +266,7 @@ impl AuthToken {
             org_id,
             profile_id,
             tenant_id,
+            two_factor_auth_verified,
         };
         jwt::generate_jwt(&token_payload, settings).await
     }
@@ -269,6 +280,7 @@ pub struct UserFromToken {
     pub org_id: id_type::OrganizationId,
     pub profile_id: Option<id_type::ProfileId>,
     pub tenant_id: Option<String>,
+    pub two_factor_auth_verified: bool,
 }
 
 pub struct UserIdFromAuth {
@@ -773,6 +785,7 @@ where
                 origin: payload.origin.clone(),
                 path: payload.path,
                 tenant_id: payload.tenant_id,
+                two_factor_auth_verified: payload.two_factor_auth_verified,
             },
             AuthenticationType::SinglePurposeJwt {
                 user_id: payload.user_id,
@@ -812,6 +825,7 @@ where
                 origin: payload.origin.clone(),
                 path: payload.path,
                 tenant_id: payload.tenant_id,
+                two_factor_auth_verified: payload.two_factor_auth_verified,
             }),
             AuthenticationType::SinglePurposeJwt {
                 user_id: payload.user_id,
@@ -1007,6 +1021,75 @@ where
     }
 }
 
+/// Authenticates the callbacks of the network tokenization service, which present the api key
+/// shared with the service, for the merchant in the route
+#[cfg(feature = "v1")]
+#[derive(Debug)]
+pub struct NetworkTokenServiceAuthWithMerchantIdFromRoute(pub id_type::MerchantId);
+
+#[cfg(feature = "v1")]
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A>
+    for NetworkTokenServiceAuthWithMerchantIdFromRoute
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        let request_api_key =
+            get_header_value_by_key(headers::AUTHORIZATION.to_lowercase(), request_headers)?
+                .get_required_value("authorization")
+                .change_context(errors::ApiErrorResponse::Unauthorized)?;
+        let conf = state.conf();
+        let network_tokenization_service = conf
+            .network_tokenization_service
+            .as_ref()
+            .ok_or(errors::ApiErrorResponse::Unauthorized)
+            .attach_printable("Network tokenization service is not configured")?;
+
+        if !common_utils::crypto::constant_time_eq(
+            request_api_key.as_bytes(),
+            network_tokenization_service
+                .get_inner()
+                .token_service_api_key
+                .peek()
+                .as_bytes(),
+        ) {
+            Err(report!(errors::ApiErrorResponse::Unauthorized)
+                .attach_printable("Network Tokenization Service Authentication Failure"))?;
+        }
+
+        let merchant_id = self.0.clone();
+        let key_manager_state = &(&state.session_state()).into();
+        let key_store = state
+            .store()
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &state.store().get_master_key().to_vec().into(),
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+
+        let merchant = state
+            .store()
+            .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+
+        let auth = AuthenticationData {
+            merchant_account: merchant,
+            key_store,
+            profile_id: None,
+        };
+
+        Ok((auth, AuthenticationType::WebhookAuth { merchant_id }))
+    }
+}
+
 #[cfg(feature = "v2")]
 #[async_trait]
 impl<A> AuthenticateAndFetch<AuthenticationDataWithoutProfile, A>
@@ -1562,6 +1645,7 @@ where
         state: &A,
     ) -> RouterResult<((), AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -1596,6 +1680,7 @@ where
         state: &A,
     ) -> RouterResult<(UserFromToken, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -1615,6 +1700,7 @@ where
                 role_id: payload.role_id,
                 profile_id: payload.profile_id,
                 tenant_id: payload.tenant_id,
+                two_factor_auth_verified: payload.two_factor_auth_verified,
             },
             AuthenticationType::MerchantJwt {
                 merchant_id: payload.merchant_id,
@@ -1636,6 +1722,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationDataWithMultipleProfiles, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -1699,6 +1786,7 @@ where
         state: &A,
     ) -> RouterResult<((), AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -1744,6 +1832,7 @@ where
         state: &A,
     ) -> RouterResult<((), AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -1784,6 +1873,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -1854,6 +1944,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -1934,6 +2025,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationDataWithoutProfile, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -1999,6 +2091,7 @@ where
         state: &A,
     ) -> RouterResult<((), AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -2036,6 +2129,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -2101,6 +2195,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         let profile_id =
             get_id_type_by_key_from_headers(headers::X_PROFILE_ID.to_string(), request_headers)?
                 .get_required_value(headers::X_PROFILE_ID)?;
@@ -2174,6 +2269,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationDataWithoutProfile, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
 
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
@@ -2241,6 +2337,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -2320,6 +2417,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -2402,6 +2500,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -2490,6 +2589,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -2551,6 +2651,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -2627,6 +2728,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationDataWithUserId, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -2689,6 +2791,7 @@ where
         state: &A,
     ) -> RouterResult<(UserFromToken, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -2706,6 +2809,7 @@ where
                 role_id: payload.role_id,
                 profile_id: payload.profile_id,
                 tenant_id: payload.tenant_id,
+                two_factor_auth_verified: payload.two_factor_auth_verified,
             },
             AuthenticationType::MerchantJwt {
                 merchant_id: payload.merchant_id,
@@ -2727,6 +2831,7 @@ where
         state: &A,
     ) -> RouterResult<((), AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -2751,6 +2856,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         authorization::check_tenant(
             payload.tenant_id.clone(),
             &state.session_state().tenant.tenant_id,
@@ -3121,6 +3227,7 @@ where
         state: &A,
     ) -> RouterResult<(AuthenticationDataWithUser, AuthenticationType)> {
         let payload = parse_jwt_payload::<A, AuthToken>(request_headers, state).await?;
+        authorization::check_two_factor_auth(state, &payload).await?;
         if payload.check_in_blacklist(state).await? {
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
@@ -16,7 +16,7 @@ use crate::{
 };
 #[cfg(feature = "olap")]
 use crate::{
-    consts::JWT_TOKEN_TIME_IN_SECS,
+    consts::{user::REMEMBERED_DEVICE_COOKIE_NAME, JWT_TOKEN_TIME_IN_SECS},
     core::errors::{UserErrors, UserResponse},
     services::ApplicationResponse,
 };
@@ -37,6 +37,44 @@ pub fn set_cookie_response<R>(response: R, token: Secret<String>) -> UserRespons
     Ok(ApplicationResponse::JsonWithHeaders((response, header)))
 }
 
+#[cfg(feature = "olap")]
+pub fn set_cookie_response_with_remembered_device<R>(
+    response: R,
+    token: Secret<String>,
+    device_token: Secret<String>,
+    device_expiry_in_seconds: i64,
+) -> UserResponse<R> {
+    let jwt_expiry_in_seconds = JWT_TOKEN_TIME_IN_SECS
+        .try_into()
+        .map_err(|_| UserErrors::InternalServerError)?;
+    let (expiry, max_age) = get_expiry_and_max_age_from_seconds(jwt_expiry_in_seconds);
+    let (device_expiry, device_max_age) =
+        get_expiry_and_max_age_from_seconds(device_expiry_in_seconds);
+
+    let header_key = get_set_cookie_header();
+    let header = vec![
+        (
+            header_key.clone(),
+            create_cookie(token, expiry, max_age)
+                .to_string()
+                .into_masked(),
+        ),
+        (
+            header_key,
+            build_cookie(
+                REMEMBERED_DEVICE_COOKIE_NAME,
+                device_token,
+                device_expiry,
+                device_max_age,
+            )
+            .to_string()
+            .into_masked(),
+        ),
+    ];
+
+    Ok(ApplicationResponse::JsonWithHeaders((response, header)))
+}
+
 #[cfg(feature = "olap")]
 pub fn remove_cookie_response() -> UserResponse<()> {
     let (expiry, max_age) = get_expiry_and_max_age_from_seconds(0);
@@ -49,6 +87,16 @@ pub fn remove_cookie_response() -> UserResponse<()> {
     Ok(ApplicationResponse::JsonWithHeaders(((), header)))
 }
 
+#[cfg(feature = "olap")]
+pub fn parse_remembered_device_cookie(cookies: &str) -> Option<String> {
+    Cookie::split_parse(cookies).find_map(|cookie| {
+        cookie
+            .ok()
+            .filter(|parsed_cookie| parsed_cookie.name() == REMEMBERED_DEVICE_COOKIE_NAME)
+            .map(|parsed_cookie| parsed_cookie.value().to_owned())
+    })
+}
+
 pub fn parse_cookie(cookies: &str) -> RouterResult<String> {
     Cookie::split_parse(cookies)
         .find_map(|cookie| {
@@ -67,7 +115,17 @@ fn create_cookie<'c>(
     expires: OffsetDateTime,
     max_age: Duration,
 ) -> Cookie<'c> {
-    Cookie::build((JWT_TOKEN_COOKIE_NAME, token.expose()))
+    build_cookie(JWT_TOKEN_COOKIE_NAME, token, expires, max_age)
+}
+
+#[cfg(feature = "olap")]
+fn build_cookie<'c>(
+    name: &'static str,
+    value: Secret<String>,
+    expires: OffsetDateTime,
+    max_age: Duration,
+) -> Cookie<'c> {
+    Cookie::build((name, value.expose()))
         .http_only(true)
         .secure(true)
         .same_site(SameSite::Strict)
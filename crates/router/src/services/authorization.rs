@@ -124,6 +124,38 @@ pub fn check_tenant(token_tenant_id: Option<String>, header_tenant_id: &str) ->
     Ok(())
 }
 
+/// Rejects the token if two factor authentication is enforced for the organization of the token
+/// and the user did not complete it while signing in for the session of the token. The policy is
+/// checked on every request, so that enforcing it also ends the sessions signed in without it.
+#[cfg(feature = "olap")]
+pub async fn check_two_factor_auth<A>(state: &A, token: &AuthToken) -> RouterResult<()>
+where
+    A: SessionStateInfo + Sync,
+{
+    use crate::utils::user::two_factor_auth as tfa_utils;
+
+    if token.two_factor_auth_verified {
+        return Ok(());
+    }
+
+    if tfa_utils::is_two_factor_auth_enforced(&state.session_state(), &token.org_id)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)?
+    {
+        return Err(ApiErrorResponse::InvalidJwtToken)
+            .attach_printable("Two factor authentication is enforced for the organization");
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "olap"))]
+pub async fn check_two_factor_auth<A>(_state: &A, _token: &AuthToken) -> RouterResult<()>
+where
+    A: SessionStateInfo + Sync,
+{
+    Ok(())
+}
+
 fn get_redis_connection<A: SessionStateInfo>(state: &A) -> RouterResult<Arc<RedisConnectionPool>> {
     state
         .store()
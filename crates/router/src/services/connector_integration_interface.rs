@@ -319,6 +319,26 @@ impl api::IncomingWebhook for ConnectorEnum {
             Self::New(connector) => connector.get_mandate_details(request),
         }
     }
+
+    fn get_webhook_timestamp(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<i64>, errors::ConnectorError> {
+        match self {
+            Self::Old(connector) => connector.get_webhook_timestamp(request),
+            Self::New(connector) => connector.get_webhook_timestamp(request),
+        }
+    }
+
+    fn get_webhook_nonce(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<String>, errors::ConnectorError> {
+        match self {
+            Self::Old(connector) => connector.get_webhook_nonce(request),
+            Self::New(connector) => connector.get_webhook_nonce(request),
+        }
+    }
 }
 
 impl api::ConnectorTransactionId for ConnectorEnum {
@@ -464,6 +484,10 @@ pub trait ConnectorIntegrationInterface<F, ResourceCommonData, Req, Resp>: Send
         req: &RouterData<F, Req, Resp>,
         _connectors: &Connectors,
     ) -> CustomResult<Option<Request>, errors::ConnectorError>;
+    fn get_idempotency_header(
+        &self,
+        req: &RouterData<F, Req, Resp>,
+    ) -> CustomResult<Option<(String, masking::Maskable<String>)>, errors::ConnectorError>;
     fn handle_response(
         &self,
         data: &RouterData<F, Req, Resp>,
@@ -522,6 +546,20 @@ where
             }
         }
     }
+    fn get_idempotency_header(
+        &self,
+        req: &RouterData<T, Req, Resp>,
+    ) -> CustomResult<Option<(String, masking::Maskable<String>)>, errors::ConnectorError> {
+        match self {
+            ConnectorIntegrationEnum::Old(old_integration) => {
+                old_integration.get_idempotency_header(req)
+            }
+            ConnectorIntegrationEnum::New(new_integration) => {
+                let new_router_data = ResourceCommonData::from_old_router_data(req)?;
+                new_integration.get_idempotency_header(&new_router_data)
+            }
+        }
+    }
     fn handle_response(
         &self,
         data: &RouterData<T, Req, Resp>,
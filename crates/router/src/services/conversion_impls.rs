@@ -7,8 +7,9 @@ use hyperswitch_domain_models::{
     router_data::{self, RouterData},
     router_data_v2::{
         flow_common_types::{
-            AccessTokenFlowData, DisputesFlowData, ExternalAuthenticationFlowData, FilesFlowData,
-            MandateRevokeFlowData, PaymentFlowData, RefundFlowData, WebhookSourceVerifyData,
+            AccessTokenFlowData, AccountBalanceFlowData, AccountUpdateFlowData, DisputesFlowData,
+            ExternalAuthenticationFlowData, FilesFlowData, MandateRevokeFlowData, PaymentFlowData,
+            RefundFlowData, WebhookSourceVerifyData,
         },
         RouterDataV2,
     },
@@ -65,6 +66,7 @@ fn get_default_router_data<F, Req, Resp>(
         quote_id: None,
         test_mode: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         external_latency: None,
         apple_pay_flow: None,
         frm_metadata: None,
@@ -74,6 +76,7 @@ fn get_default_router_data<F, Req, Resp>(
         payment_method_status: None,
         minor_amount_captured: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -526,6 +529,78 @@ impl<T, Req: Clone, Resp: Clone> RouterDataConversion<T, Req, Resp> for WebhookS
     }
 }
 
+impl<T, Req: Clone, Resp: Clone> RouterDataConversion<T, Req, Resp> for AccountBalanceFlowData {
+    fn from_old_router_data(
+        old_router_data: &RouterData<T, Req, Resp>,
+    ) -> errors::CustomResult<RouterDataV2<T, Self, Req, Resp>, errors::ConnectorError>
+    where
+        Self: Sized,
+    {
+        let resource_common_data = Self {
+            merchant_id: old_router_data.merchant_id.clone(),
+        };
+        Ok(RouterDataV2 {
+            flow: std::marker::PhantomData,
+            resource_common_data,
+            connector_auth_type: old_router_data.connector_auth_type.clone(),
+            request: old_router_data.request.clone(),
+            response: old_router_data.response.clone(),
+        })
+    }
+
+    fn to_old_router_data(
+        new_router_data: RouterDataV2<T, Self, Req, Resp>,
+    ) -> errors::CustomResult<RouterData<T, Req, Resp>, errors::ConnectorError>
+    where
+        Self: Sized,
+    {
+        let Self { merchant_id } = new_router_data.resource_common_data;
+        let mut router_data = get_default_router_data(
+            "account balance",
+            new_router_data.request,
+            new_router_data.response,
+        );
+        router_data.merchant_id = merchant_id;
+        Ok(router_data)
+    }
+}
+
+impl<T, Req: Clone, Resp: Clone> RouterDataConversion<T, Req, Resp> for AccountUpdateFlowData {
+    fn from_old_router_data(
+        old_router_data: &RouterData<T, Req, Resp>,
+    ) -> errors::CustomResult<RouterDataV2<T, Self, Req, Resp>, errors::ConnectorError>
+    where
+        Self: Sized,
+    {
+        let resource_common_data = Self {
+            merchant_id: old_router_data.merchant_id.clone(),
+        };
+        Ok(RouterDataV2 {
+            flow: std::marker::PhantomData,
+            resource_common_data,
+            connector_auth_type: old_router_data.connector_auth_type.clone(),
+            request: old_router_data.request.clone(),
+            response: old_router_data.response.clone(),
+        })
+    }
+
+    fn to_old_router_data(
+        new_router_data: RouterDataV2<T, Self, Req, Resp>,
+    ) -> errors::CustomResult<RouterData<T, Req, Resp>, errors::ConnectorError>
+    where
+        Self: Sized,
+    {
+        let Self { merchant_id } = new_router_data.resource_common_data;
+        let mut router_data = get_default_router_data(
+            "account update",
+            new_router_data.request,
+            new_router_data.response,
+        );
+        router_data.merchant_id = merchant_id;
+        Ok(router_data)
+    }
+}
+
 impl<T, Req: Clone, Resp: Clone> RouterDataConversion<T, Req, Resp> for MandateRevokeFlowData {
     fn from_old_router_data(
         old_router_data: &RouterData<T, Req, Resp>,
@@ -26,6 +26,7 @@ mod payment_attempt;
 mod payment_attempt_event;
 mod payment_intent;
 mod payment_intent_event;
+mod pseudonymization;
 mod refund;
 mod refund_event;
 use diesel_models::{authentication::Authentication, refund::Refund};
@@ -39,8 +40,8 @@ use self::{
     authentication::KafkaAuthentication, authentication_event::KafkaAuthenticationEvent,
     dispute::KafkaDispute, dispute_event::KafkaDisputeEvent, payment_attempt::KafkaPaymentAttempt,
     payment_attempt_event::KafkaPaymentAttemptEvent, payment_intent::KafkaPaymentIntent,
-    payment_intent_event::KafkaPaymentIntentEvent, refund::KafkaRefund,
-    refund_event::KafkaRefundEvent,
+    payment_intent_event::KafkaPaymentIntentEvent, pseudonymization::Pseudonymizer,
+    refund::KafkaRefund, refund_event::KafkaRefundEvent,
 };
 use crate::{services::kafka::fraud_check::KafkaFraudCheck, types::storage::Dispute};
 
@@ -161,6 +162,7 @@ pub struct KafkaSettings {
     payout_analytics_topic: String,
     consolidated_events_topic: String,
     authentication_analytics_topic: String,
+    pseudonymization: pseudonymization::PseudonymizationConfig,
 }
 
 impl KafkaSettings {
@@ -248,6 +250,15 @@ impl KafkaSettings {
             },
         )?;
 
+        common_utils::fp_utils::when(
+            self.pseudonymization.enabled && self.pseudonymization.secret.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "Kafka pseudonymization secret must not be empty".into(),
+                ))
+            },
+        )?;
+
         Ok(())
     }
 }
@@ -268,6 +279,7 @@ pub struct KafkaProducer {
     payout_analytics_topic: String,
     consolidated_events_topic: String,
     authentication_analytics_topic: String,
+    pseudonymizer: Option<Pseudonymizer>,
     ckh_database_name: Option<String>,
 }
 
@@ -317,6 +329,7 @@ impl KafkaProducer {
             payout_analytics_topic: conf.payout_analytics_topic.clone(),
             consolidated_events_topic: conf.consolidated_events_topic.clone(),
             authentication_analytics_topic: conf.authentication_analytics_topic.clone(),
+            pseudonymizer: Pseudonymizer::new(&conf.pseudonymization)?,
             ckh_database_name: None,
         })
     }
@@ -467,7 +480,7 @@ impl KafkaProducer {
     ) -> MQResult<()> {
         if let Some(negative_event) = old_intent {
             self.log_event(&KafkaEvent::old(
-                &KafkaPaymentIntent::from_storage(&negative_event),
+                &KafkaPaymentIntent::from_storage(&negative_event, self.pseudonymizer.as_ref()),
                 tenant_id.clone(),
                 self.ckh_database_name.clone(),
             ))
@@ -477,14 +490,14 @@ impl KafkaProducer {
         };
 
         self.log_event(&KafkaEvent::new(
-            &KafkaPaymentIntent::from_storage(intent),
+            &KafkaPaymentIntent::from_storage(intent, self.pseudonymizer.as_ref()),
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
         .attach_printable_lazy(|| format!("Failed to add positive intent event {intent:?}"))?;
 
         self.log_event(&KafkaConsolidatedEvent::new(
-            &KafkaPaymentIntentEvent::from_storage(intent),
+            &KafkaPaymentIntentEvent::from_storage(intent, self.pseudonymizer.as_ref()),
             tenant_id.clone(),
         ))
         .attach_printable_lazy(|| format!("Failed to add consolidated intent event {intent:?}"))
@@ -496,7 +509,7 @@ impl KafkaProducer {
         tenant_id: TenantID,
     ) -> MQResult<()> {
         self.log_event(&KafkaEvent::old(
-            &KafkaPaymentIntent::from_storage(delete_old_intent),
+            &KafkaPaymentIntent::from_storage(delete_old_intent, self.pseudonymizer.as_ref()),
             tenant_id.clone(),
             self.ckh_database_name.clone(),
         ))
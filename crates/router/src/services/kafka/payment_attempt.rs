@@ -57,6 +57,10 @@ pub struct KafkaPaymentAttempt<'a> {
     pub profile_id: &'a id_type::ProfileId,
     pub organization_id: &'a id_type::OrganizationId,
     pub card_network: Option<String>,
+    pub connector_fee_amount: Option<MinorUnit>,
+    pub connector_fee_currency: Option<storage_enums::Currency>,
+    pub connector_request_id: Option<&'a String>,
+    pub correlation_id: Option<&'a String>,
 }
 
 #[cfg(feature = "v1")]
@@ -115,6 +119,10 @@ impl<'a> KafkaPaymentAttempt<'a> {
                 .and_then(|card| card.get("card_network"))
                 .and_then(|network| network.as_str())
                 .map(|network| network.to_string()),
+            connector_fee_amount: attempt.connector_fee_amount,
+            connector_fee_currency: attempt.connector_fee_currency,
+            connector_request_id: attempt.connector_request_id.as_ref(),
+            correlation_id: attempt.correlation_id.as_ref(),
         }
     }
 }
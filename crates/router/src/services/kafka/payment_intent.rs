@@ -1,10 +1,14 @@
-use common_utils::{crypto::Encryptable, hashing::HashedString, id_type, pii, types::MinorUnit};
+use common_utils::{crypto::Encryptable, id_type, types::MinorUnit};
 use diesel_models::enums as storage_enums;
 use hyperswitch_domain_models::payments::PaymentIntent;
-use masking::{PeekInterface, Secret};
+use masking::Secret;
 use serde_json::Value;
 use time::OffsetDateTime;
 
+#[cfg(feature = "v1")]
+use super::pseudonymization::hash_customer_identifier;
+use super::pseudonymization::Pseudonymizer;
+
 #[cfg(feature = "v1")]
 #[derive(serde::Serialize, Debug)]
 pub struct KafkaPaymentIntent<'a> {
@@ -38,7 +42,8 @@ pub struct KafkaPaymentIntent<'a> {
     pub payment_confirm_source: Option<storage_enums::PaymentSource>,
     pub billing_details: Option<Encryptable<Secret<Value>>>,
     pub shipping_details: Option<Encryptable<Secret<Value>>>,
-    pub customer_email: Option<HashedString<pii::EmailStrategy>>,
+    pub customer_email: Option<String>,
+    pub customer_phone: Option<String>,
     pub feature_metadata: Option<&'a Value>,
     pub merchant_order_reference_id: Option<&'a String>,
     pub organization_id: &'a id_type::OrganizationId,
@@ -73,7 +78,8 @@ pub struct KafkaPaymentIntent<'a> {
     pub payment_confirm_source: Option<storage_enums::PaymentSource>,
     pub billing_details: Option<Encryptable<Secret<Value>>>,
     pub shipping_details: Option<Encryptable<Secret<Value>>>,
-    pub customer_email: Option<HashedString<pii::EmailStrategy>>,
+    pub customer_email: Option<String>,
+    pub customer_phone: Option<String>,
     pub feature_metadata: Option<&'a Value>,
     pub merchant_order_reference_id: Option<&'a String>,
     pub organization_id: &'a id_type::OrganizationId,
@@ -81,7 +87,7 @@ pub struct KafkaPaymentIntent<'a> {
 
 #[cfg(feature = "v1")]
 impl<'a> KafkaPaymentIntent<'a> {
-    pub fn from_storage(intent: &'a PaymentIntent) -> Self {
+    pub fn from_storage(intent: &'a PaymentIntent, pseudonymizer: Option<&Pseudonymizer>) -> Self {
         Self {
             payment_id: &intent.payment_id,
             merchant_id: &intent.merchant_id,
@@ -111,13 +117,18 @@ impl<'a> KafkaPaymentIntent<'a> {
             // TODO: use typed information here to avoid PII logging
             billing_details: None,
             shipping_details: None,
-            customer_email: intent
-                .customer_details
-                .as_ref()
-                .and_then(|value| value.get_inner().peek().as_object())
-                .and_then(|obj| obj.get("email"))
-                .and_then(|email| email.as_str())
-                .map(|email| HashedString::from(Secret::new(email.to_string()))),
+            customer_email: hash_customer_identifier(
+                intent.customer_details.as_ref(),
+                "email",
+                intent.created_at,
+                pseudonymizer,
+            ),
+            customer_phone: hash_customer_identifier(
+                intent.customer_details.as_ref(),
+                "phone",
+                intent.created_at,
+                pseudonymizer,
+            ),
             feature_metadata: intent.feature_metadata.as_ref(),
             merchant_order_reference_id: intent.merchant_order_reference_id.as_ref(),
             organization_id: &intent.organization_id,
@@ -127,7 +138,7 @@ impl<'a> KafkaPaymentIntent<'a> {
 
 #[cfg(feature = "v2")]
 impl<'a> KafkaPaymentIntent<'a> {
-    pub fn from_storage(intent: &'a PaymentIntent) -> Self {
+    pub fn from_storage(intent: &'a PaymentIntent, _pseudonymizer: Option<&Pseudonymizer>) -> Self {
         // Self {
         //     id: &intent.id,
         //     merchant_id: &intent.merchant_id,
@@ -153,13 +164,18 @@ impl<'a> KafkaPaymentIntent<'a> {
         //     // TODO: use typed information here to avoid PII logging
         //     billing_details: None,
         //     shipping_details: None,
-        //     customer_email: intent
-        //         .customer_details
-        //         .as_ref()
-        //         .and_then(|value| value.get_inner().peek().as_object())
-        //         .and_then(|obj| obj.get("email"))
-        //         .and_then(|email| email.as_str())
-        //         .map(|email| HashedString::from(Secret::new(email.to_string()))),
+        //     customer_email: hash_customer_identifier(
+        //         intent.customer_details.as_ref(),
+        //         "email",
+        //         intent.created_at,
+        //         pseudonymizer,
+        //     ),
+        //     customer_phone: hash_customer_identifier(
+        //         intent.customer_details.as_ref(),
+        //         "phone",
+        //         intent.created_at,
+        //         pseudonymizer,
+        //     ),
         //     feature_metadata: intent.feature_metadata.as_ref(),
         //     merchant_order_reference_id: intent.merchant_order_reference_id.as_ref(),
         //     organization_id: &intent.organization_id,
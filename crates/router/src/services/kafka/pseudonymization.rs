@@ -0,0 +1,103 @@
+use common_utils::{
+    crypto::{Encryptable, HmacSha256, SignMessage},
+    ext_traits::ConfigExt,
+};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use serde_json::Value;
+use time::PrimitiveDateTime;
+
+use super::{KafkaError, MQResult};
+
+/// Pseudonymization of the customer identifiers in analytics events
+#[derive(Debug, serde::Deserialize, Clone)]
+#[serde(default)]
+pub struct PseudonymizationConfig {
+    pub enabled: bool,
+    /// Secret from which the salt of every rotation period is derived
+    pub secret: Secret<String>,
+    /// Number of days for which the same salt is used
+    pub salt_rotation_period_in_days: u16,
+}
+
+impl Default for PseudonymizationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            secret: Secret::new(String::new()),
+            salt_rotation_period_in_days: 30,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Pseudonymizer {
+    secret: Secret<String>,
+    rotation_period_in_secs: i64,
+}
+
+impl Pseudonymizer {
+    /// Fails if pseudonymization is enabled without a secret, as the salts would then be derivable
+    /// by anyone and the pseudonyms could be reversed by hashing the known identifiers.
+    pub fn new(config: &PseudonymizationConfig) -> MQResult<Option<Self>> {
+        if !config.enabled {
+            return Ok(None);
+        }
+
+        if config.secret.is_default_or_empty() {
+            return Err(report!(KafkaError::InitializationError))
+                .attach_printable("Kafka pseudonymization secret must not be empty");
+        }
+
+        Ok(Some(Self {
+            secret: config.secret.clone(),
+            rotation_period_in_secs: time::Duration::days(i64::from(
+                config.salt_rotation_period_in_days.max(1),
+            ))
+            .whole_seconds(),
+        }))
+    }
+
+    /// The salt of the rotation period the time falls in
+    fn get_salt(&self, time: PrimitiveDateTime) -> Option<Vec<u8>> {
+        let period = time
+            .assume_utc()
+            .unix_timestamp()
+            .div_euclid(self.rotation_period_in_secs);
+
+        HmacSha256
+            .sign_message(self.secret.peek().as_bytes(), period.to_string().as_bytes())
+            .ok()
+    }
+
+    /// The pseudonym of the identifier, which is the same for all the records created within the
+    /// same rotation period. Pseudonyms of different periods can not be joined.
+    fn pseudonymize(&self, identifier: &str, created_at: PrimitiveDateTime) -> Option<String> {
+        let salt = self.get_salt(created_at)?;
+
+        HmacSha256
+            .sign_message(&salt, identifier.as_bytes())
+            .ok()
+            .map(hex::encode)
+    }
+}
+
+/// Hashes the customer identifier stored under the key of the customer details, such as the email
+/// or the phone number. The identifier is pseudonymized with the salt of the rotation period of the
+/// record if pseudonymization is enabled, and hashed without a salt otherwise.
+pub fn hash_customer_identifier(
+    customer_details: Option<&Encryptable<Secret<Value>>>,
+    key: &str,
+    created_at: PrimitiveDateTime,
+    pseudonymizer: Option<&Pseudonymizer>,
+) -> Option<String> {
+    let identifier = customer_details
+        .and_then(|value| value.get_inner().peek().as_object())
+        .and_then(|obj| obj.get(key))
+        .and_then(|identifier| identifier.as_str())?;
+
+    match pseudonymizer {
+        Some(pseudonymizer) => pseudonymizer.pseudonymize(identifier, created_at),
+        None => Some(blake3::hash(identifier.as_bytes()).to_hex().to_string()),
+    }
+}
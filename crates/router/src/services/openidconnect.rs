@@ -1,3 +1,4 @@
+use base64::Engine;
 use error_stack::ResultExt;
 use masking::{ExposeInterface, Secret};
 use oidc::TokenResponse;
@@ -13,24 +14,41 @@ use crate::{
     types::domain::user::UserEmail,
 };
 
+/// Details of the user as provided by the OpenId provider in the verified id token
+pub struct OidcUserDetails {
+    pub email: UserEmail,
+    pub groups: Vec<String>,
+}
+
+#[derive(serde::Deserialize)]
+struct IdTokenGroupsClaim {
+    #[serde(default)]
+    groups: Vec<String>,
+}
+
 pub async fn get_authorization_url(
     state: SessionState,
     redirect_url: String,
     redirect_state: Secret<String>,
     base_url: Secret<String>,
     client_id: Secret<String>,
+    request_groups: bool,
 ) -> UserResult<url::Url> {
     let discovery_document = get_discovery_document(base_url, &state).await?;
 
-    let (auth_url, csrf_token, nonce) =
+    let mut authorization_request =
         get_oidc_core_client(discovery_document, client_id, None, redirect_url)?
             .authorize_url(
                 oidc_core::CoreAuthenticationFlow::AuthorizationCode,
                 || oidc::CsrfToken::new(redirect_state.expose()),
                 oidc::Nonce::new_random,
             )
-            .add_scope(oidc::Scope::new("email".to_string()))
-            .url();
+            .add_scope(oidc::Scope::new("email".to_string()));
+    if request_groups {
+        authorization_request =
+            authorization_request.add_scope(oidc::Scope::new("groups".to_string()));
+    }
+    let (auth_url, csrf_token, nonce) = authorization_request.url();
 
     // Save csrf & nonce as key value respectively
     let key = get_oidc_redis_key(csrf_token.secret());
@@ -43,7 +61,7 @@ pub async fn get_authorization_url(
     Ok(auth_url)
 }
 
-pub async fn get_user_email_from_oidc_provider(
+pub async fn get_user_details_from_oidc_provider(
     state: &SessionState,
     redirect_url: String,
     redirect_state: Secret<String>,
@@ -51,7 +69,7 @@ pub async fn get_user_email_from_oidc_provider(
     client_id: Secret<String>,
     authorization_code: Secret<String>,
     client_secret: Secret<String>,
-) -> UserResult<UserEmail> {
+) -> UserResult<OidcUserDetails> {
     let nonce = get_nonce_from_redis(state, &redirect_state).await?;
     let discovery_document = get_discovery_document(base_url, state).await?;
     let client = get_oidc_core_client(
@@ -97,9 +115,33 @@ pub async fn get_user_email_from_oidc_provider(
         .ok_or(UserErrors::InternalServerError)
         .attach_printable("OpenID Provider Didnt provide email")?;
 
-    UserEmail::new(Secret::new(email_from_token))
+    let email = UserEmail::new(Secret::new(email_from_token))
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to create email type")?;
+
+    Ok(OidcUserDetails {
+        email,
+        groups: get_groups_from_id_token(&id_token.to_string())?,
+    })
+}
+
+/// Groups are not a standard claim, so they are read from the payload of the id token, which has
+/// to be verified before
+fn get_groups_from_id_token(id_token: &str) -> UserResult<Vec<String>> {
+    let payload = id_token
+        .split('.')
+        .nth(1)
+        .ok_or(UserErrors::InternalServerError)
+        .attach_printable("Id token is not a valid jwt")?;
+    let decoded_payload = base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(payload)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to decode id token payload")?;
+
+    serde_json::from_slice::<IdTokenGroupsClaim>(&decoded_payload)
         .change_context(UserErrors::InternalServerError)
-        .attach_printable("Failed to create email type")
+        .attach_printable("Failed to parse groups from id token payload")
+        .map(|claim| claim.groups)
 }
 
 // TODO: Cache Discovery Document
@@ -0,0 +1,1345 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io::Write,
+};
+
+use api_models::user as user_api;
+use base64::Engine;
+use common_utils::pii;
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use openssl::{hash::MessageDigest, sign::Verifier, x509::X509};
+use quick_xml::{events::Event, name::PrefixDeclaration};
+use redis_interface::{RedisConnectionPool, SetnxReply};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime, PrimitiveDateTime};
+
+use crate::{
+    consts,
+    core::errors::{UserErrors, UserResult},
+    routes::SessionState,
+    types::domain::user::UserEmail,
+};
+
+const PROTOCOL_NAMESPACE: &str = "urn:oasis:names:tc:SAML:2.0:protocol";
+const ASSERTION_NAMESPACE: &str = "urn:oasis:names:tc:SAML:2.0:assertion";
+const XML_DSIG_NAMESPACE: &str = "http://www.w3.org/2000/09/xmldsig#";
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+const SUCCESS_STATUS: &str = "urn:oasis:names:tc:SAML:2.0:status:Success";
+const BEARER_CONFIRMATION_METHOD: &str = "urn:oasis:names:tc:SAML:2.0:cm:bearer";
+const HTTP_POST_BINDING: &str = "urn:oasis:names:tc:SAML:2.0:bindings:HTTP-POST";
+const EMAIL_NAME_ID_FORMAT: &str = "urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress";
+const EXCLUSIVE_C14N_ALGORITHM: &str = "http://www.w3.org/2001/10/xml-exc-c14n#";
+const ENVELOPED_SIGNATURE_ALGORITHM: &str = "http://www.w3.org/2000/09/xmldsig#enveloped-signature";
+const RSA_SHA256_ALGORITHM: &str = "http://www.w3.org/2001/04/xmldsig-more#rsa-sha256";
+const SHA256_ALGORITHM: &str = "http://www.w3.org/2001/04/xmlenc#sha256";
+const DEFAULT_GROUPS_ATTRIBUTE: &str = "groups";
+/// Allowed difference between the clocks of the identity provider and the application
+const CLOCK_SKEW_IN_SECS: i64 = 60;
+
+/// Details of the user as asserted by the identity provider in the verified SAML response
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct SamlUserDetails {
+    pub email: pii::Email,
+    pub groups: Vec<String>,
+}
+
+impl SamlUserDetails {
+    pub fn get_email(&self) -> UserResult<UserEmail> {
+        UserEmail::from_pii_email(self.email.clone())
+    }
+}
+
+/// User details stored until the sign in is completed with the one time code
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SamlSignInDetails {
+    code: Secret<String>,
+    user_details: SamlUserDetails,
+}
+
+/// Assertion of a SAML response whose signature and conditions have been verified
+#[derive(Debug)]
+struct VerifiedAssertion {
+    id: String,
+    not_on_or_after: PrimitiveDateTime,
+    user_details: SamlUserDetails,
+}
+
+/// Url of the identity provider with the authentication request, sent with the HTTP-Redirect
+/// binding. The identity provider posts its response to the assertion consumer service url.
+pub fn get_authentication_request_url(
+    config: &user_api::SamlPublicConfig,
+    acs_url: &str,
+    request_id: &str,
+    relay_state: &Secret<String>,
+    now: PrimitiveDateTime,
+) -> UserResult<url::Url> {
+    let issue_instant = now
+        .assume_utc()
+        .format(&Rfc3339)
+        .change_context(UserErrors::InternalServerError)?;
+    // Identity providers asserting the email in an attribute may use any name id format
+    let name_id_policy = match config.email_attribute {
+        Some(_) => r#"<samlp:NameIDPolicy AllowCreate="true"/>"#.to_string(),
+        None => {
+            format!(r#"<samlp:NameIDPolicy Format="{EMAIL_NAME_ID_FORMAT}" AllowCreate="true"/>"#)
+        }
+    };
+    let authentication_request = format!(
+        r#"<samlp:AuthnRequest xmlns:samlp="{PROTOCOL_NAMESPACE}" xmlns:saml="{ASSERTION_NAMESPACE}" ID="{}" Version="2.0" IssueInstant="{}" Destination="{}" AssertionConsumerServiceURL="{}" ProtocolBinding="{HTTP_POST_BINDING}"><saml:Issuer>{}</saml:Issuer>{name_id_policy}</samlp:AuthnRequest>"#,
+        escape_attribute_value(request_id),
+        issue_instant,
+        escape_attribute_value(&config.idp_sso_url),
+        escape_attribute_value(acs_url),
+        escape_text(&config.sp_entity_id),
+    );
+
+    let mut encoder =
+        flate2::write::DeflateEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(authentication_request.as_bytes())
+        .change_context(UserErrors::InternalServerError)?;
+    let deflated_request = encoder
+        .finish()
+        .change_context(UserErrors::InternalServerError)?;
+
+    let mut url = url::Url::parse(&config.idp_sso_url)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Invalid single sign on url of the SAML identity provider")?;
+    url.query_pairs_mut()
+        .append_pair(
+            "SAMLRequest",
+            &consts::BASE64_ENGINE.encode(deflated_request),
+        )
+        .append_pair("RelayState", relay_state.peek());
+
+    Ok(url)
+}
+
+/// Verifies the SAML response posted by the identity provider and returns the details of the
+/// user. Each assertion is accepted only once.
+pub async fn get_user_details_from_saml_response(
+    state: &SessionState,
+    saml_response: Secret<String>,
+    config: &user_api::SamlPublicConfig,
+    acs_url: &str,
+    request_id: Option<&str>,
+) -> UserResult<SamlUserDetails> {
+    let decoded_response = consts::BASE64_ENGINE
+        .decode(saml_response.expose().trim())
+        .change_context(UserErrors::SSOFailed)
+        .attach_printable("Failed to decode SAML response")?;
+    let response = String::from_utf8(decoded_response)
+        .change_context(UserErrors::SSOFailed)
+        .attach_printable("SAML response is not valid UTF-8")?;
+
+    let now = common_utils::date_time::now();
+    let assertion = verify_saml_response(&response, config, acs_url, request_id, now)?;
+
+    // The assertion is remembered until it expires, so that it can't be replayed
+    let expiry_in_secs = (assertion.not_on_or_after - now).whole_seconds() + CLOCK_SKEW_IN_SECS;
+    let reply = get_redis_connection(state)?
+        .set_key_if_not_exists_with_expiry(
+            &get_saml_redis_key("ASSERTION", &assertion.id),
+            true,
+            Some(expiry_in_secs.max(1)),
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to save SAML assertion id in redis")?;
+    if reply != SetnxReply::KeySet {
+        return Err(report!(UserErrors::SSOFailed)).attach_printable("SAML assertion was replayed");
+    }
+
+    Ok(assertion.user_details)
+}
+
+pub async fn set_authentication_request_id_in_redis(
+    state: &SessionState,
+    relay_state: &Secret<String>,
+    request_id: &str,
+) -> UserResult<()> {
+    get_redis_connection(state)?
+        .set_key_with_expiry(
+            &get_saml_redis_key("REQUEST", relay_state.peek()),
+            request_id,
+            consts::user::REDIS_SSO_TTL,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to save SAML request id in redis")
+}
+
+pub async fn get_authentication_request_id_from_redis(
+    state: &SessionState,
+    relay_state: &Secret<String>,
+) -> UserResult<String> {
+    get_redis_connection(state)?
+        .get_and_delete_key::<Option<String>>(&get_saml_redis_key("REQUEST", relay_state.peek()))
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Error fetching SAML request id from redis")?
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Cannot find SAML request id in redis. Relay state invalid or expired")
+}
+
+/// Keeps the verified user details until the dashboard completes the sign in with the code
+pub async fn set_user_details_in_redis(
+    state: &SessionState,
+    sign_in_state: &Secret<String>,
+    code: Secret<String>,
+    user_details: SamlUserDetails,
+) -> UserResult<()> {
+    get_redis_connection(state)?
+        .serialize_and_set_key_with_expiry(
+            &get_saml_redis_key("USER", sign_in_state.peek()),
+            SamlSignInDetails { code, user_details },
+            consts::user::REDIS_SSO_TTL,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to save SAML user details in redis")
+}
+
+pub async fn get_user_details_from_redis(
+    state: &SessionState,
+    sign_in_state: &Secret<String>,
+    code: &Secret<String>,
+) -> UserResult<SamlUserDetails> {
+    let sign_in_details = get_redis_connection(state)?
+        .get_and_delete_key::<Option<String>>(&get_saml_redis_key("USER", sign_in_state.peek()))
+        .await
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Error fetching SAML user details from redis")?
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Cannot find SAML user details in redis. State invalid or expired")?;
+    let sign_in_details = serde_json::from_str::<SamlSignInDetails>(&sign_in_details)
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to parse SAML user details")?;
+
+    if sign_in_details.code.peek() != code.peek() {
+        return Err(report!(UserErrors::SSOFailed)).attach_printable("Invalid SAML sign in code");
+    }
+
+    Ok(sign_in_details.user_details)
+}
+
+fn verify_saml_response(
+    response: &str,
+    config: &user_api::SamlPublicConfig,
+    acs_url: &str,
+    request_id: Option<&str>,
+    now: PrimitiveDateTime,
+) -> UserResult<VerifiedAssertion> {
+    let response = parse_xml(response)?;
+    ensure(
+        response.is(PROTOCOL_NAMESPACE, "Response"),
+        "Document is not a SAML response",
+    )?;
+
+    // Only the assertion at its expected location is read, so that an unsigned assertion can't be
+    // smuggled into the response alongside the signed one
+    ensure(
+        response.count_descendants(ASSERTION_NAMESPACE, "EncryptedAssertion") == 0,
+        "Encrypted SAML assertions are not supported",
+    )?;
+    ensure(
+        response.count_descendants(ASSERTION_NAMESPACE, "Assertion") == 1,
+        "SAML response must contain exactly one assertion",
+    )?;
+    let assertion = response
+        .find_child(ASSERTION_NAMESPACE, "Assertion")
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("SAML assertion is not a child of the response")?;
+
+    let certificate = X509::from_pem(config.idp_certificate.as_bytes())
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Invalid certificate of the SAML identity provider")?;
+    let response_signature = response.find_child(XML_DSIG_NAMESPACE, "Signature");
+    let assertion_signature = assertion.find_child(XML_DSIG_NAMESPACE, "Signature");
+    ensure(
+        response_signature.is_some() || assertion_signature.is_some(),
+        "SAML response is not signed",
+    )?;
+    if let Some(signature) = response_signature {
+        verify_signature(&response, signature, &certificate)?;
+    }
+    if let Some(signature) = assertion_signature {
+        verify_signature(assertion, signature, &certificate)?;
+    }
+
+    let status_code = response
+        .find_child(PROTOCOL_NAMESPACE, "Status")
+        .and_then(|status| status.find_child(PROTOCOL_NAMESPACE, "StatusCode"))
+        .and_then(|status_code| status_code.get_attribute("Value"));
+    ensure(
+        status_code == Some(SUCCESS_STATUS),
+        "SAML response status is not success",
+    )?;
+    ensure(
+        response
+            .get_attribute("Destination")
+            .map_or(true, |destination| destination == acs_url),
+        "SAML response is not for this service provider",
+    )?;
+    ensure(
+        response.get_attribute("InResponseTo") == request_id,
+        "SAML response is not for the authentication request",
+    )?;
+    ensure(
+        response
+            .find_child(ASSERTION_NAMESPACE, "Issuer")
+            .map_or(true, |issuer| {
+                issuer.get_text().trim() == config.idp_entity_id
+            }),
+        "SAML response is not issued by the identity provider",
+    )?;
+    ensure(
+        assertion
+            .find_child(ASSERTION_NAMESPACE, "Issuer")
+            .is_some_and(|issuer| issuer.get_text().trim() == config.idp_entity_id),
+        "SAML assertion is not issued by the identity provider",
+    )?;
+
+    let clock_skew = time::Duration::seconds(CLOCK_SKEW_IN_SECS);
+    let subject = assertion
+        .find_child(ASSERTION_NAMESPACE, "Subject")
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("SAML assertion has no subject")?;
+    let not_on_or_after = subject
+        .find_children(ASSERTION_NAMESPACE, "SubjectConfirmation")
+        .filter(|confirmation| {
+            confirmation.get_attribute("Method") == Some(BEARER_CONFIRMATION_METHOD)
+        })
+        .filter_map(|confirmation| {
+            confirmation.find_child(ASSERTION_NAMESPACE, "SubjectConfirmationData")
+        })
+        .filter(|data| {
+            data.get_attribute("Recipient") == Some(acs_url)
+                && data.get_attribute("InResponseTo") == request_id
+        })
+        .filter_map(|data| data.get_attribute("NotOnOrAfter"))
+        .map(parse_instant)
+        .collect::<UserResult<Vec<_>>>()?
+        .into_iter()
+        .find(|not_on_or_after| *not_on_or_after + clock_skew > now)
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("SAML assertion has no valid bearer subject confirmation")?;
+
+    let conditions = assertion
+        .find_child(ASSERTION_NAMESPACE, "Conditions")
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("SAML assertion has no conditions")?;
+    if let Some(not_before) = conditions.get_attribute("NotBefore") {
+        ensure(
+            parse_instant(not_before)? - clock_skew <= now,
+            "SAML assertion is not yet valid",
+        )?;
+    }
+    if let Some(conditions_not_on_or_after) = conditions.get_attribute("NotOnOrAfter") {
+        ensure(
+            parse_instant(conditions_not_on_or_after)? + clock_skew > now,
+            "SAML assertion has expired",
+        )?;
+    }
+    let mut audience_restrictions = conditions
+        .find_children(ASSERTION_NAMESPACE, "AudienceRestriction")
+        .peekable();
+    ensure(
+        audience_restrictions.peek().is_some(),
+        "SAML assertion has no audience restriction",
+    )?;
+    for audience_restriction in audience_restrictions {
+        ensure(
+            audience_restriction
+                .find_children(ASSERTION_NAMESPACE, "Audience")
+                .any(|audience| audience.get_text().trim() == config.sp_entity_id),
+            "SAML assertion is not for this service provider",
+        )?;
+    }
+
+    let attributes = assertion
+        .find_children(ASSERTION_NAMESPACE, "AttributeStatement")
+        .flat_map(|statement| statement.find_children(ASSERTION_NAMESPACE, "Attribute"))
+        .collect::<Vec<_>>();
+    let get_attribute_values = |name: &str| {
+        attributes
+            .iter()
+            .filter(|attribute| attribute.get_attribute("Name") == Some(name))
+            .flat_map(|attribute| attribute.find_children(ASSERTION_NAMESPACE, "AttributeValue"))
+            .map(|value| value.get_text().trim().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    let email = match &config.email_attribute {
+        Some(email_attribute) => get_attribute_values(email_attribute).into_iter().next(),
+        None => subject
+            .find_child(ASSERTION_NAMESPACE, "NameID")
+            .map(|name_id| name_id.get_text().trim().to_string()),
+    }
+    .ok_or(UserErrors::SSOFailed)
+    .attach_printable("SAML assertion has no email of the user")?;
+    let email = UserEmail::new(Secret::new(email))
+        .change_context(UserErrors::SSOFailed)
+        .attach_printable("Invalid email in SAML assertion")?;
+    let groups = get_attribute_values(
+        config
+            .groups_attribute
+            .as_deref()
+            .unwrap_or(DEFAULT_GROUPS_ATTRIBUTE),
+    );
+
+    Ok(VerifiedAssertion {
+        id: assertion
+            .get_attribute("ID")
+            .ok_or(UserErrors::SSOFailed)
+            .attach_printable("SAML assertion has no id")?
+            .to_string(),
+        not_on_or_after,
+        user_details: SamlUserDetails {
+            email: email.into_inner(),
+            groups,
+        },
+    })
+}
+
+/// Verifies the enveloped signature of the element with the certificate of the identity provider.
+/// Only exclusive canonicalization with RSA-SHA256 signatures and SHA256 digests are supported.
+fn verify_signature(
+    element: &XmlElement,
+    signature: &XmlElement,
+    certificate: &X509,
+) -> UserResult<()> {
+    let signed_info = signature
+        .find_child(XML_DSIG_NAMESPACE, "SignedInfo")
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Signature has no signed info")?;
+    let canonicalization_method = signed_info
+        .find_child(XML_DSIG_NAMESPACE, "CanonicalizationMethod")
+        .filter(|method| method.get_attribute("Algorithm") == Some(EXCLUSIVE_C14N_ALGORITHM))
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Unsupported canonicalization method of the signature")?;
+    ensure(
+        signed_info
+            .find_child(XML_DSIG_NAMESPACE, "SignatureMethod")
+            .and_then(|method| method.get_attribute("Algorithm"))
+            == Some(RSA_SHA256_ALGORITHM),
+        "Unsupported signature method",
+    )?;
+
+    let mut references = signed_info.find_children(XML_DSIG_NAMESPACE, "Reference");
+    let reference = references
+        .next()
+        .filter(|_| references.next().is_none())
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Signature must have exactly one reference")?;
+    let element_id = element
+        .get_attribute("ID")
+        .filter(|id| !id.is_empty())
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable("Signed element has no id")?;
+    ensure(
+        reference.get_attribute("URI") == Some(format!("#{element_id}").as_str()),
+        "Signature does not reference the signed element",
+    )?;
+
+    let mut inclusive_prefixes = Vec::new();
+    for transform in reference
+        .find_child(XML_DSIG_NAMESPACE, "Transforms")
+        .into_iter()
+        .flat_map(|transforms| transforms.find_children(XML_DSIG_NAMESPACE, "Transform"))
+    {
+        match transform.get_attribute("Algorithm") {
+            Some(ENVELOPED_SIGNATURE_ALGORITHM) => {}
+            Some(EXCLUSIVE_C14N_ALGORITHM) => {
+                inclusive_prefixes = get_inclusive_prefixes(transform);
+            }
+            _ => {
+                return Err(report!(UserErrors::SSOFailed))
+                    .attach_printable("Unsupported transform of the signed element")
+            }
+        }
+    }
+    ensure(
+        reference
+            .find_child(XML_DSIG_NAMESPACE, "DigestMethod")
+            .and_then(|method| method.get_attribute("Algorithm"))
+            == Some(SHA256_ALGORITHM),
+        "Unsupported digest method",
+    )?;
+
+    let expected_digest = decode_base64_text(reference, "DigestValue")?;
+    let digest = openssl::sha::sha256(
+        canonicalize(element, &inclusive_prefixes, Some(signature)).as_bytes(),
+    );
+    ensure(
+        digest.as_slice() == expected_digest.as_slice(),
+        "Digest of the signed element does not match",
+    )?;
+
+    let signature_value = decode_base64_text(signature, "SignatureValue")?;
+    let public_key = certificate
+        .public_key()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Invalid public key in the certificate of the identity provider")?;
+    let mut verifier = Verifier::new(MessageDigest::sha256(), &public_key)
+        .change_context(UserErrors::InternalServerError)?;
+    verifier
+        .update(
+            canonicalize(
+                signed_info,
+                &get_inclusive_prefixes(canonicalization_method),
+                None,
+            )
+            .as_bytes(),
+        )
+        .change_context(UserErrors::InternalServerError)?;
+    let is_valid = verifier
+        .verify(&signature_value)
+        .change_context(UserErrors::SSOFailed)
+        .attach_printable("Failed to verify signature")?;
+
+    ensure(is_valid, "Invalid signature")
+}
+
+/// Prefixes of the namespaces treated as in the inclusive canonicalization, `None` being the
+/// default namespace
+fn get_inclusive_prefixes(method: &XmlElement) -> Vec<Option<String>> {
+    method
+        .find_child(EXCLUSIVE_C14N_ALGORITHM, "InclusiveNamespaces")
+        .and_then(|namespaces| namespaces.get_attribute("PrefixList"))
+        .map(|prefix_list| {
+            prefix_list
+                .split_whitespace()
+                .map(|prefix| (prefix != "#default").then(|| prefix.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn decode_base64_text(element: &XmlElement, local_name: &str) -> UserResult<Vec<u8>> {
+    let text = element
+        .find_child(XML_DSIG_NAMESPACE, local_name)
+        .map(|child| {
+            child
+                .get_text()
+                .chars()
+                .filter(|character| !character.is_whitespace())
+                .collect::<String>()
+        })
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable_lazy(|| format!("Signature has no {local_name}"))?;
+
+    consts::BASE64_ENGINE
+        .decode(text)
+        .change_context(UserErrors::SSOFailed)
+        .attach_printable_lazy(|| format!("Failed to decode {local_name}"))
+}
+
+fn parse_instant(value: &str) -> UserResult<PrimitiveDateTime> {
+    let instant = OffsetDateTime::parse(value, &Rfc3339)
+        .change_context(UserErrors::SSOFailed)
+        .attach_printable("Invalid instant in SAML response")?
+        .to_offset(time::UtcOffset::UTC);
+
+    Ok(PrimitiveDateTime::new(instant.date(), instant.time()))
+}
+
+fn ensure(condition: bool, message: &'static str) -> UserResult<()> {
+    if condition {
+        Ok(())
+    } else {
+        Err(report!(UserErrors::SSOFailed)).attach_printable(message)
+    }
+}
+
+fn get_saml_redis_key(kind: &str, id: &str) -> String {
+    format!("{}SAML_{kind}_{id}", consts::user::REDIS_SSO_PREFIX)
+}
+
+fn get_redis_connection(state: &SessionState) -> UserResult<std::sync::Arc<RedisConnectionPool>> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")
+}
+
+/// Element of a parsed XML document, keeping the prefixes and namespace declarations needed for
+/// the canonicalization of signed elements
+#[derive(Debug)]
+struct XmlElement {
+    prefix: Option<String>,
+    local_name: String,
+    namespace: Option<String>,
+    /// Namespaces in scope of the element by prefix, `None` being the default namespace
+    namespaces_in_scope: BTreeMap<Option<String>, String>,
+    attributes: Vec<XmlAttribute>,
+    children: Vec<XmlNode>,
+}
+
+#[derive(Debug)]
+struct XmlAttribute {
+    prefix: Option<String>,
+    local_name: String,
+    namespace: Option<String>,
+    value: String,
+}
+
+#[derive(Debug)]
+enum XmlNode {
+    Element(XmlElement),
+    Text(String),
+    ProcessingInstruction(String),
+}
+
+impl XmlElement {
+    fn is(&self, namespace: &str, local_name: &str) -> bool {
+        self.namespace.as_deref() == Some(namespace) && self.local_name == local_name
+    }
+
+    fn find_children<'a>(
+        &'a self,
+        namespace: &'a str,
+        local_name: &'a str,
+    ) -> impl Iterator<Item = &'a Self> {
+        self.children.iter().filter_map(move |child| match child {
+            XmlNode::Element(element) if element.is(namespace, local_name) => Some(element),
+            XmlNode::Element(_) | XmlNode::Text(_) | XmlNode::ProcessingInstruction(_) => None,
+        })
+    }
+
+    fn find_child<'a>(&'a self, namespace: &'a str, local_name: &'a str) -> Option<&'a Self> {
+        self.find_children(namespace, local_name).next()
+    }
+
+    fn count_descendants(&self, namespace: &str, local_name: &str) -> usize {
+        self.children
+            .iter()
+            .map(|child| match child {
+                XmlNode::Element(element) => {
+                    usize::from(element.is(namespace, local_name))
+                        + element.count_descendants(namespace, local_name)
+                }
+                XmlNode::Text(_) | XmlNode::ProcessingInstruction(_) => 0,
+            })
+            .sum()
+    }
+
+    /// Value of an attribute without a namespace
+    fn get_attribute(&self, local_name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.namespace.is_none() && attribute.local_name == local_name)
+            .map(|attribute| attribute.value.as_str())
+    }
+
+    fn get_text(&self) -> String {
+        self.children
+            .iter()
+            .filter_map(|child| match child {
+                XmlNode::Text(text) => Some(text.as_str()),
+                XmlNode::Element(_) | XmlNode::ProcessingInstruction(_) => None,
+            })
+            .collect()
+    }
+}
+
+fn parse_xml(xml: &str) -> UserResult<XmlElement> {
+    // Line breaks are normalized before parsing, as required by the XML specification
+    let xml = xml.replace("\r\n", "\n").replace('\r', "\n");
+    let mut reader = quick_xml::Reader::from_str(&xml);
+    let mut open_elements: Vec<XmlElement> = Vec::new();
+    let mut root = None;
+
+    loop {
+        let event = reader
+            .read_event()
+            .change_context(UserErrors::SSOFailed)
+            .attach_printable("Failed to parse SAML response")?;
+        match event {
+            Event::Start(start) => {
+                let element = new_xml_element(&start, open_elements.last())?;
+                open_elements.push(element);
+            }
+            Event::Empty(start) => {
+                let element = new_xml_element(&start, open_elements.last())?;
+                append_xml_element(&mut open_elements, &mut root, element)?;
+            }
+            Event::End(_) => {
+                let element = open_elements
+                    .pop()
+                    .ok_or(UserErrors::SSOFailed)
+                    .attach_printable("Unexpected end tag in SAML response")?;
+                append_xml_element(&mut open_elements, &mut root, element)?;
+            }
+            Event::Text(text) => {
+                if let Some(parent) = open_elements.last_mut() {
+                    let text = text
+                        .unescape()
+                        .change_context(UserErrors::SSOFailed)
+                        .attach_printable("Invalid text in SAML response")?;
+                    parent.children.push(XmlNode::Text(text.into_owned()));
+                }
+            }
+            Event::CData(data) => {
+                if let Some(parent) = open_elements.last_mut() {
+                    let text = String::from_utf8(data.into_inner().into_owned())
+                        .change_context(UserErrors::SSOFailed)?;
+                    parent.children.push(XmlNode::Text(text));
+                }
+            }
+            Event::PI(instruction) => {
+                if let Some(parent) = open_elements.last_mut() {
+                    let instruction = String::from_utf8(instruction.into_inner().into_owned())
+                        .change_context(UserErrors::SSOFailed)?;
+                    parent
+                        .children
+                        .push(XmlNode::ProcessingInstruction(instruction));
+                }
+            }
+            Event::DocType(_) => {
+                return Err(report!(UserErrors::SSOFailed))
+                    .attach_printable("Document type declarations are not allowed");
+            }
+            Event::Comment(_) | Event::Decl(_) => {}
+            Event::Eof => break,
+        }
+    }
+
+    ensure(open_elements.is_empty(), "SAML response is incomplete")?;
+    root.ok_or(UserErrors::SSOFailed)
+        .attach_printable("SAML response has no root element")
+}
+
+fn append_xml_element(
+    open_elements: &mut [XmlElement],
+    root: &mut Option<XmlElement>,
+    element: XmlElement,
+) -> UserResult<()> {
+    match open_elements.last_mut() {
+        Some(parent) => parent.children.push(XmlNode::Element(element)),
+        None => {
+            ensure(
+                root.is_none(),
+                "SAML response has more than one root element",
+            )?;
+            *root = Some(element);
+        }
+    }
+    Ok(())
+}
+
+fn new_xml_element(
+    start: &quick_xml::events::BytesStart<'_>,
+    parent: Option<&XmlElement>,
+) -> UserResult<XmlElement> {
+    let mut namespaces_in_scope = parent
+        .map(|parent| parent.namespaces_in_scope.clone())
+        .unwrap_or_default();
+    let mut raw_attributes = Vec::new();
+
+    for attribute in start.attributes() {
+        let attribute = attribute
+            .change_context(UserErrors::SSOFailed)
+            .attach_printable("Invalid attribute in SAML response")?;
+        // Whitespace characters in attribute values are normalized to spaces
+        let raw_value = std::str::from_utf8(&attribute.value)
+            .change_context(UserErrors::SSOFailed)?
+            .replace(['\t', '\n'], " ");
+        let value = quick_xml::escape::unescape(&raw_value)
+            .change_context(UserErrors::SSOFailed)
+            .attach_printable("Invalid attribute value in SAML response")?
+            .into_owned();
+
+        match attribute.key.as_namespace_binding() {
+            Some(PrefixDeclaration::Default) if value.is_empty() => {
+                namespaces_in_scope.remove(&None);
+            }
+            Some(PrefixDeclaration::Default) => {
+                namespaces_in_scope.insert(None, value);
+            }
+            Some(PrefixDeclaration::Named(prefix)) => {
+                let prefix = std::str::from_utf8(prefix).change_context(UserErrors::SSOFailed)?;
+                namespaces_in_scope.insert(Some(prefix.to_string()), value);
+            }
+            None => {
+                let name = std::str::from_utf8(attribute.key.as_ref())
+                    .change_context(UserErrors::SSOFailed)?;
+                raw_attributes.push((split_qualified_name(name), value));
+            }
+        }
+    }
+
+    let attributes = raw_attributes
+        .into_iter()
+        .map(|((prefix, local_name), value)| {
+            Ok(XmlAttribute {
+                // Attributes without a prefix are not in any namespace
+                namespace: prefix
+                    .as_ref()
+                    .map(|prefix| get_namespace(&namespaces_in_scope, prefix))
+                    .transpose()?,
+                prefix,
+                local_name,
+                value,
+            })
+        })
+        .collect::<UserResult<Vec<_>>>()?;
+
+    let name = std::str::from_utf8(start.name().as_ref())
+        .change_context(UserErrors::SSOFailed)?
+        .to_string();
+    let (prefix, local_name) = split_qualified_name(&name);
+    let namespace = match &prefix {
+        Some(prefix) => Some(get_namespace(&namespaces_in_scope, prefix)?),
+        None => namespaces_in_scope.get(&None).cloned(),
+    };
+
+    Ok(XmlElement {
+        prefix,
+        local_name,
+        namespace,
+        namespaces_in_scope,
+        attributes,
+        children: Vec::new(),
+    })
+}
+
+fn split_qualified_name(name: &str) -> (Option<String>, String) {
+    match name.split_once(':') {
+        Some((prefix, local_name)) => (Some(prefix.to_string()), local_name.to_string()),
+        None => (None, name.to_string()),
+    }
+}
+
+fn get_namespace(
+    namespaces_in_scope: &BTreeMap<Option<String>, String>,
+    prefix: &str,
+) -> UserResult<String> {
+    if prefix == "xml" {
+        return Ok(XML_NAMESPACE.to_string());
+    }
+    namespaces_in_scope
+        .get(&Some(prefix.to_string()))
+        .cloned()
+        .ok_or(UserErrors::SSOFailed)
+        .attach_printable_lazy(|| format!("Namespace prefix {prefix} is not declared"))
+}
+
+/// Serializes the element with the exclusive XML canonicalization without comments, leaving out
+/// the excluded element, which is the enveloped signature
+fn canonicalize(
+    element: &XmlElement,
+    inclusive_prefixes: &[Option<String>],
+    excluded_element: Option<&XmlElement>,
+) -> String {
+    let mut output = String::new();
+    write_canonical_element(
+        element,
+        &BTreeMap::new(),
+        inclusive_prefixes,
+        excluded_element,
+        &mut output,
+    );
+    output
+}
+
+fn write_canonical_element(
+    element: &XmlElement,
+    rendered_namespaces: &BTreeMap<Option<String>, String>,
+    inclusive_prefixes: &[Option<String>],
+    excluded_element: Option<&XmlElement>,
+    output: &mut String,
+) {
+    // Only the namespaces visibly utilized by the element and its attributes are rendered, along
+    // with the namespaces listed to be treated as in the inclusive canonicalization
+    let utilized_prefixes = std::iter::once(&element.prefix)
+        .chain(
+            element
+                .attributes
+                .iter()
+                .map(|attribute| &attribute.prefix)
+                .filter(|prefix| prefix.is_some()),
+        )
+        .chain(
+            inclusive_prefixes
+                .iter()
+                .filter(|prefix| element.namespaces_in_scope.contains_key(*prefix)),
+        )
+        .filter(|prefix| prefix.as_deref() != Some("xml"))
+        .collect::<BTreeSet<_>>();
+
+    let mut namespaces_to_render = BTreeMap::new();
+    let mut child_rendered_namespaces = rendered_namespaces.clone();
+    for prefix in utilized_prefixes {
+        // An undeclared default namespace is rendered as empty
+        let namespace = element
+            .namespaces_in_scope
+            .get(prefix)
+            .map(String::as_str)
+            .unwrap_or_default();
+        let rendered_namespace = rendered_namespaces
+            .get(prefix)
+            .map(String::as_str)
+            .unwrap_or_default();
+        if namespace != rendered_namespace {
+            namespaces_to_render.insert(prefix, namespace);
+            child_rendered_namespaces.insert(prefix.clone(), namespace.to_string());
+        }
+    }
+
+    let mut attributes = element.attributes.iter().collect::<Vec<_>>();
+    attributes.sort_by_key(|attribute| {
+        (
+            attribute.namespace.as_deref().unwrap_or_default(),
+            attribute.local_name.as_str(),
+        )
+    });
+
+    let name = get_qualified_name(&element.prefix, &element.local_name);
+    output.push('<');
+    output.push_str(&name);
+    for (prefix, namespace) in namespaces_to_render {
+        match prefix {
+            Some(prefix) => output.push_str(&format!(" xmlns:{prefix}=\"")),
+            None => output.push_str(" xmlns=\""),
+        }
+        output.push_str(&escape_attribute_value(namespace));
+        output.push('"');
+    }
+    for attribute in attributes {
+        output.push(' ');
+        output.push_str(&get_qualified_name(
+            &attribute.prefix,
+            &attribute.local_name,
+        ));
+        output.push_str("=\"");
+        output.push_str(&escape_attribute_value(&attribute.value));
+        output.push('"');
+    }
+    output.push('>');
+
+    for child in &element.children {
+        match child {
+            XmlNode::Element(child)
+                if excluded_element.is_some_and(|excluded| std::ptr::eq(excluded, child)) => {}
+            XmlNode::Element(child) => write_canonical_element(
+                child,
+                &child_rendered_namespaces,
+                inclusive_prefixes,
+                excluded_element,
+                output,
+            ),
+            XmlNode::Text(text) => output.push_str(&escape_text(text)),
+            XmlNode::ProcessingInstruction(instruction) => {
+                output.push_str("<?");
+                output.push_str(instruction);
+                output.push_str("?>");
+            }
+        }
+    }
+
+    output.push_str("</");
+    output.push_str(&name);
+    output.push('>');
+}
+
+fn get_qualified_name(prefix: &Option<String>, local_name: &str) -> String {
+    match prefix {
+        Some(prefix) => format!("{prefix}:{local_name}"),
+        None => local_name.to_string(),
+    }
+}
+
+fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('\r', "&#xD;")
+}
+
+fn escape_attribute_value(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('"', "&quot;")
+        .replace('\t', "&#x9;")
+        .replace('\n', "&#xA;")
+        .replace('\r', "&#xD;")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::io::Read;
+
+    use openssl::{
+        asn1::Asn1Time,
+        pkey::{PKey, Private},
+        rsa::Rsa,
+        sign::Signer,
+        x509::X509Name,
+    };
+    use time::macros::datetime;
+
+    use super::*;
+
+    const IDP_ENTITY_ID: &str = "https://idp.example.com/metadata";
+    const SP_ENTITY_ID: &str = "https://app.hyperswitch.io/saml";
+    const ACS_URL: &str = "https://sandbox.hyperswitch.io/user/saml/acs/auth_method_1";
+    const REQUEST_ID: &str = "_request_1";
+    const NOW: PrimitiveDateTime = datetime!(2024-11-10 10:01:00);
+
+    fn get_key_and_certificate() -> (PKey<Private>, String) {
+        let key = PKey::from_rsa(Rsa::generate(2048).unwrap()).unwrap();
+        let mut name = X509Name::builder().unwrap();
+        name.append_entry_by_text("CN", "idp.example.com").unwrap();
+        let name = name.build();
+
+        let mut certificate = X509::builder().unwrap();
+        certificate.set_version(2).unwrap();
+        certificate.set_subject_name(&name).unwrap();
+        certificate.set_issuer_name(&name).unwrap();
+        certificate.set_pubkey(&key).unwrap();
+        certificate
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        certificate
+            .set_not_after(&Asn1Time::days_from_now(1).unwrap())
+            .unwrap();
+        certificate.sign(&key, MessageDigest::sha256()).unwrap();
+        let certificate = String::from_utf8(certificate.build().to_pem().unwrap()).unwrap();
+
+        (key, certificate)
+    }
+
+    fn get_config(idp_certificate: String) -> user_api::SamlPublicConfig {
+        user_api::SamlPublicConfig {
+            idp_entity_id: IDP_ENTITY_ID.to_string(),
+            idp_sso_url: "https://idp.example.com/sso?tenant=1".to_string(),
+            idp_certificate,
+            sp_entity_id: SP_ENTITY_ID.to_string(),
+            email_attribute: None,
+            groups_attribute: None,
+            allow_idp_initiated_sign_in: true,
+            jit_provisioning: None,
+        }
+    }
+
+    fn get_response(request_id: Option<&str>, audience: &str, signature: &str) -> String {
+        let in_response_to = request_id
+            .map(|request_id| format!(r#" InResponseTo="{request_id}""#))
+            .unwrap_or_default();
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<samlp:Response xmlns:samlp="urn:oasis:names:tc:SAML:2.0:protocol" xmlns:saml="urn:oasis:names:tc:SAML:2.0:assertion" ID="_response_1" Version="2.0" IssueInstant="2024-11-10T10:00:00Z" Destination="{ACS_URL}"{in_response_to}>
+  <saml:Issuer>{IDP_ENTITY_ID}</saml:Issuer>
+  <samlp:Status><samlp:StatusCode Value="urn:oasis:names:tc:SAML:2.0:status:Success"/></samlp:Status>
+  <saml:Assertion ID="_assertion_1" Version="2.0" IssueInstant="2024-11-10T10:00:00Z">
+    <saml:Issuer>{IDP_ENTITY_ID}</saml:Issuer>{signature}
+    <saml:Subject>
+      <saml:NameID Format="urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress">user@example.com</saml:NameID>
+      <saml:SubjectConfirmation Method="urn:oasis:names:tc:SAML:2.0:cm:bearer">
+        <saml:SubjectConfirmationData Recipient="{ACS_URL}" NotOnOrAfter="2024-11-10T10:05:00Z"{in_response_to}/>
+      </saml:SubjectConfirmation>
+    </saml:Subject>
+    <saml:Conditions NotBefore="2024-11-10T09:59:00Z" NotOnOrAfter="2024-11-10T10:05:00Z">
+      <saml:AudienceRestriction><saml:Audience>{audience}</saml:Audience></saml:AudienceRestriction>
+    </saml:Conditions>
+    <saml:AttributeStatement>
+      <saml:Attribute Name="groups"><saml:AttributeValue>admins</saml:AttributeValue><saml:AttributeValue>developers</saml:AttributeValue></saml:Attribute>
+    </saml:AttributeStatement>
+  </saml:Assertion>
+</samlp:Response>"#
+        )
+    }
+
+    fn get_signature(digest: &str, signature_value: &str) -> String {
+        format!(
+            r##"<ds:Signature xmlns:ds="http://www.w3.org/2000/09/xmldsig#"><ds:SignedInfo><ds:CanonicalizationMethod Algorithm="http://www.w3.org/2001/10/xml-exc-c14n#"/><ds:SignatureMethod Algorithm="http://www.w3.org/2001/04/xmldsig-more#rsa-sha256"/><ds:Reference URI="#_assertion_1"><ds:Transforms><ds:Transform Algorithm="http://www.w3.org/2000/09/xmldsig#enveloped-signature"/><ds:Transform Algorithm="http://www.w3.org/2001/10/xml-exc-c14n#"><ec:InclusiveNamespaces xmlns:ec="http://www.w3.org/2001/10/xml-exc-c14n#" PrefixList="xs"/></ds:Transform></ds:Transforms><ds:DigestMethod Algorithm="http://www.w3.org/2001/04/xmlenc#sha256"/><ds:DigestValue>{digest}</ds:DigestValue></ds:Reference></ds:SignedInfo><ds:SignatureValue>{signature_value}</ds:SignatureValue></ds:Signature>"##
+        )
+    }
+
+    /// Signs the assertion of the response the way an identity provider does
+    fn get_signed_response(
+        request_id: Option<&str>,
+        audience: &str,
+        key: &PKey<Private>,
+    ) -> String {
+        let unsigned_response = parse_xml(&get_response(request_id, audience, "")).unwrap();
+        let assertion = unsigned_response
+            .find_child(ASSERTION_NAMESPACE, "Assertion")
+            .unwrap();
+        let digest = consts::BASE64_ENGINE.encode(openssl::sha::sha256(
+            canonicalize(assertion, &[Some("xs".to_string())], None).as_bytes(),
+        ));
+
+        let response_with_digest = parse_xml(&get_response(
+            request_id,
+            audience,
+            &get_signature(&digest, ""),
+        ))
+        .unwrap();
+        let signed_info = response_with_digest
+            .find_child(ASSERTION_NAMESPACE, "Assertion")
+            .and_then(|assertion| assertion.find_child(XML_DSIG_NAMESPACE, "Signature"))
+            .and_then(|signature| signature.find_child(XML_DSIG_NAMESPACE, "SignedInfo"))
+            .unwrap();
+        let mut signer = Signer::new(MessageDigest::sha256(), key).unwrap();
+        signer
+            .update(canonicalize(signed_info, &[], None).as_bytes())
+            .unwrap();
+        let signature_value = consts::BASE64_ENGINE.encode(signer.sign_to_vec().unwrap());
+
+        get_response(
+            request_id,
+            audience,
+            &get_signature(&digest, &signature_value),
+        )
+    }
+
+    #[test]
+    fn test_exclusive_canonicalization() {
+        let document = parse_xml(
+            "<root xmlns=\"urn:default\" xmlns:a=\"urn:a\" xmlns:unused=\"urn:unused\">\r\n\
+             <a:child b=\"&quot;2&#9;\" a:attr=\"line\nbreak\">text &amp; &lt;more&gt;<![CDATA[ & ]]></a:child>\
+             <plain xmlns=\"\" z=\"1\"><?instruction data?></plain><!-- comment --></root>",
+        )
+        .unwrap();
+
+        assert_eq!(
+            canonicalize(&document, &[], None),
+            "<root xmlns=\"urn:default\">\n\
+             <a:child xmlns:a=\"urn:a\" b=\"&quot;2&#x9;\" a:attr=\"line break\">text &amp; &lt;more&gt; &amp; </a:child>\
+             <plain xmlns=\"\" z=\"1\"><?instruction data?></plain></root>"
+        );
+
+        // Namespaces of the ancestors are rendered on the apex element only when utilized
+        let child = document.find_child("urn:a", "child").unwrap();
+        assert_eq!(
+            canonicalize(child, &[None, Some("unused".to_string())], None),
+            "<a:child xmlns=\"urn:default\" xmlns:a=\"urn:a\" xmlns:unused=\"urn:unused\" \
+             b=\"&quot;2&#x9;\" a:attr=\"line break\">text &amp; &lt;more&gt; &amp; </a:child>"
+        );
+    }
+
+    #[test]
+    fn test_signed_response_is_verified() {
+        let (key, certificate) = get_key_and_certificate();
+        let config = get_config(certificate);
+
+        let response = get_signed_response(Some(REQUEST_ID), SP_ENTITY_ID, &key);
+        let assertion =
+            verify_saml_response(&response, &config, ACS_URL, Some(REQUEST_ID), NOW).unwrap();
+        assert_eq!(assertion.id, "_assertion_1");
+        assert_eq!(assertion.not_on_or_after, datetime!(2024-11-10 10:05:00));
+        assert_eq!(assertion.user_details.email.peek(), "user@example.com");
+        assert_eq!(assertion.user_details.groups, vec!["admins", "developers"]);
+
+        // Responses to sign ins initiated by the identity provider are not for any request
+        let response = get_signed_response(None, SP_ENTITY_ID, &key);
+        assert!(verify_saml_response(&response, &config, ACS_URL, None, NOW).is_ok());
+        assert!(verify_saml_response(&response, &config, ACS_URL, Some(REQUEST_ID), NOW).is_err());
+    }
+
+    /// Response signed with the XML signature implementation of the Java standard library
+    const EXTERNALLY_SIGNED_RESPONSE: &str = r##"<?xml version="1.0" encoding="UTF-8" standalone="no"?><samlp:Response xmlns:samlp="urn:oasis:names:tc:SAML:2.0:protocol" xmlns:saml="urn:oasis:names:tc:SAML:2.0:assertion" xmlns:unused="urn:unused" xmlns:xs="http://www.w3.org/2001/XMLSchema" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" Destination="https://sandbox.hyperswitch.io/user/saml/acs/auth_method_1" ID="_response_1" IssueInstant="2024-11-10T10:00:00Z" Version="2.0">
+  <saml:Issuer>https://idp.example.com/metadata</saml:Issuer>
+  <samlp:Status><samlp:StatusCode Value="urn:oasis:names:tc:SAML:2.0:status:Success"/></samlp:Status>
+  <saml:Assertion ID="_assertion_1" IssueInstant="2024-11-10T10:00:00Z" Version="2.0">
+    <saml:Issuer>https://idp.example.com/metadata</saml:Issuer>
+    <ds:Signature xmlns:ds="http://www.w3.org/2000/09/xmldsig#"><ds:SignedInfo><ds:CanonicalizationMethod Algorithm="http://www.w3.org/2001/10/xml-exc-c14n#"/><ds:SignatureMethod Algorithm="http://www.w3.org/2001/04/xmldsig-more#rsa-sha256"/><ds:Reference URI="#_assertion_1"><ds:Transforms><ds:Transform Algorithm="http://www.w3.org/2000/09/xmldsig#enveloped-signature"/><ds:Transform Algorithm="http://www.w3.org/2001/10/xml-exc-c14n#"><ds:InclusiveNamespaces xmlns:ds="http://www.w3.org/2001/10/xml-exc-c14n#" PrefixList="xs"/></ds:Transform></ds:Transforms><ds:DigestMethod Algorithm="http://www.w3.org/2001/04/xmlenc#sha256"/><ds:DigestValue>kaWCyh82br42Cpa3udBqspw/LrJin9+7GHcVxsfT+iw=</ds:DigestValue></ds:Reference></ds:SignedInfo><ds:SignatureValue>eLL4x8jgB21kxwSdL69uKhGLPGJDxfHvc9n4albiI0R2P4B2Hl9FfMoMxZqzCalK8VDMvW9EZjL8&#13;
+953FZfiUXfly+NWs5f7ku3HnyFNvTebqHBvIgsur4Hn14QGMON1m8KWK8Oa2XsoE+ZSal09hK+HP&#13;
+RDfLo44aUsQX48vfmjabxgFM5QUe4/4gj1vXQy/lrFhBuOB7nfLKYuyk3XG+1D1Eut9JABVvTWTH&#13;
+QzHuzVQNehKV/eghZ6Ioc1YsutFEkuGIcCM6cuFm2/DBEot7/AG5Erjf92dUj5Uu2NvzOiVwlk8Q&#13;
+bJC8gkbhbzoV/0A600dDJVs5TnCVan7FgW669Q==</ds:SignatureValue></ds:Signature><saml:Subject>
+      <saml:NameID Format="urn:oasis:names:tc:SAML:1.1:nameid-format:emailAddress">user@example.com</saml:NameID>
+      <saml:SubjectConfirmation Method="urn:oasis:names:tc:SAML:2.0:cm:bearer">
+        <saml:SubjectConfirmationData NotOnOrAfter="2024-11-10T10:05:00Z" Recipient="https://sandbox.hyperswitch.io/user/saml/acs/auth_method_1"/>
+      </saml:SubjectConfirmation>
+    </saml:Subject>
+    <saml:Conditions NotBefore="2024-11-10T09:59:00Z" NotOnOrAfter="2024-11-10T10:05:00Z">
+      <saml:AudienceRestriction><saml:Audience>https://app.hyperswitch.io/saml</saml:Audience></saml:AudienceRestriction>
+    </saml:Conditions>
+    <saml:AttributeStatement>
+      <saml:Attribute Name="groups" NameFormat="urn:oasis:names:tc:SAML:2.0:attrname-format:basic"><saml:AttributeValue xsi:type="xs:string">admins &amp; &lt;owners&gt; "q"</saml:AttributeValue><saml:AttributeValue xsi:type="xs:string"><![CDATA[dev & ops]]></saml:AttributeValue></saml:Attribute>
+      <Extra xmlns="urn:extra" a="1" b="2" xml:lang="en">text<Inner xmlns="">x</Inner></Extra>
+    </saml:AttributeStatement>
+  </saml:Assertion>
+</samlp:Response>"##;
+    const EXTERNAL_SIGNING_CERTIFICATE: &str = "-----BEGIN CERTIFICATE-----
+MIIC/TCCAeWgAwIBAgIUAuFYze7QMS0uwG2LzisK5rrgbXEwDQYJKoZIhvcNAQEL
+BQAwDjEMMAoGA1UEAwwDaWRwMB4XDTI2MTAxODA0MzMyNloXDTI2MTAxOTA0MzMy
+NlowDjEMMAoGA1UEAwwDaWRwMIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKC
+AQEAxnMOC3R3n44lYl+eYMfsUx/DUpxTtRn1eqNhQx7suSXuHSgYs0ESCmaLkI4q
+KWfGy+0d/U4J/WHIDbMA0vgHw5m3uxe7zT9HWID5FRb9CCGyhgjb8oR/+gd3rKks
+trn2SiQvG+A9XBFF45b2EF+xvM51lui1Wr5UFhm3l9tCOTFFUI/nV/CmFjslCQjT
+tgQCVFx2rhDzpA0l02FpYSrv/mhN0L0hW2CGWOiNgmcqxzOzMIcmr2NUapFKZMgQ
+s1GzOHB/q09CsobK+TPP9ni7YZdKC0KXv6gbkKuBT+zKsJWSwzKDYgEzE//YkgM3
+4ayAhtpUFWdlm6w/SF6NWNCC6QIDAQABo1MwUTAdBgNVHQ4EFgQUn9oMmJVLfr4K
+/1rKc3pxfGJcau8wHwYDVR0jBBgwFoAUn9oMmJVLfr4K/1rKc3pxfGJcau8wDwYD
+VR0TAQH/BAUwAwEB/zANBgkqhkiG9w0BAQsFAAOCAQEArbuqjCHLsSH0g6s/XHpP
+lFdlSJakRZGoy8jrYoS4MkTMu54VReelktQ5NZFa39wTerzSH0Cyd/h4O3NE+495
+in9bQt4+RfYC/qcMUCUf9+4p2c6kXuzPUhdT/H5hfillM6UeaPlDxg9S7K8y6U8a
+udLfHHiTo9/507XH0+6nyDuClKLevRObmifHSjBDuWUXBlWxFGWUyOTdNFV3Oh9Q
+0Mu2oY7yHwUE7qE2JocJEI4/kV2DXqhz/MPg0flvE7GEYI+d+bp52AQ26vHQ0Bwy
+yuh8KQIKLofTHKSBpsmmddfyPVheBVPFQjJZZ4D9w2XDrFVnLYVWUPcrWIPMpHoR
+/w==
+-----END CERTIFICATE-----";
+
+    #[test]
+    fn test_externally_signed_response_is_verified() {
+        let config = get_config(EXTERNAL_SIGNING_CERTIFICATE.to_string());
+
+        let assertion =
+            verify_saml_response(EXTERNALLY_SIGNED_RESPONSE, &config, ACS_URL, None, NOW).unwrap();
+        assert_eq!(assertion.user_details.email.peek(), "user@example.com");
+        assert_eq!(
+            assertion.user_details.groups,
+            vec!["admins & <owners> \"q\"", "dev & ops"]
+        );
+
+        let tampered_response =
+            EXTERNALLY_SIGNED_RESPONSE.replace("<![CDATA[dev & ops]]>", "<![CDATA[dev & qa]]>");
+        assert!(verify_saml_response(&tampered_response, &config, ACS_URL, None, NOW).is_err());
+    }
+
+    #[test]
+    fn test_tampered_response_is_rejected() {
+        let (key, certificate) = get_key_and_certificate();
+        let config = get_config(certificate);
+        let response = get_signed_response(Some(REQUEST_ID), SP_ENTITY_ID, &key);
+
+        let tampered_response = response.replace("user@example.com", "admin@example.com");
+        assert!(
+            verify_saml_response(&tampered_response, &config, ACS_URL, Some(REQUEST_ID), NOW)
+                .is_err()
+        );
+
+        // A second, unsigned assertion can't be slipped in next to the signed one
+        let wrapped_response = response.replace(
+            "<samlp:Status>",
+            &format!(
+                "<samlp:Extensions>{}</samlp:Extensions><samlp:Status>",
+                tampered_response
+                    .split_once("<saml:Assertion")
+                    .and_then(|(_, assertion)| assertion.split_once("</saml:Assertion>"))
+                    .map(|(assertion, _)| format!("<saml:Assertion{assertion}</saml:Assertion>"))
+                    .unwrap()
+            ),
+        );
+        assert!(
+            verify_saml_response(&wrapped_response, &config, ACS_URL, Some(REQUEST_ID), NOW)
+                .is_err()
+        );
+
+        let unsigned_response = get_response(Some(REQUEST_ID), SP_ENTITY_ID, "");
+        assert!(
+            verify_saml_response(&unsigned_response, &config, ACS_URL, Some(REQUEST_ID), NOW)
+                .is_err()
+        );
+
+        let (other_key, _) = get_key_and_certificate();
+        let response = get_signed_response(Some(REQUEST_ID), SP_ENTITY_ID, &other_key);
+        assert!(verify_saml_response(&response, &config, ACS_URL, Some(REQUEST_ID), NOW).is_err());
+    }
+
+    #[test]
+    fn test_conditions_of_the_assertion_are_enforced() {
+        let (key, certificate) = get_key_and_certificate();
+        let config = get_config(certificate);
+
+        let response = get_signed_response(Some(REQUEST_ID), "https://other.example.com", &key);
+        assert!(verify_saml_response(&response, &config, ACS_URL, Some(REQUEST_ID), NOW).is_err());
+
+        let response = get_signed_response(Some(REQUEST_ID), SP_ENTITY_ID, &key);
+        assert!(
+            verify_saml_response(&response, &config, ACS_URL, Some("_request_2"), NOW).is_err()
+        );
+        assert!(verify_saml_response(
+            &response,
+            &config,
+            "https://sandbox.hyperswitch.io/user/saml/acs/auth_method_2",
+            Some(REQUEST_ID),
+            NOW
+        )
+        .is_err());
+        assert!(verify_saml_response(
+            &response,
+            &config,
+            ACS_URL,
+            Some(REQUEST_ID),
+            datetime!(2024-11-10 10:07:00)
+        )
+        .is_err());
+        assert!(verify_saml_response(
+            &response,
+            &config,
+            ACS_URL,
+            Some(REQUEST_ID),
+            datetime!(2024-11-10 09:57:00)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_document_type_declarations_are_rejected() {
+        let (key, certificate) = get_key_and_certificate();
+        let config = get_config(certificate);
+        let response = get_signed_response(Some(REQUEST_ID), SP_ENTITY_ID, &key).replace(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>",
+            "<!DOCTYPE samlp:Response [<!ENTITY user \"user@example.com\">]>",
+        );
+
+        assert!(verify_saml_response(&response, &config, ACS_URL, Some(REQUEST_ID), NOW).is_err());
+    }
+
+    #[test]
+    fn test_authentication_request_url() {
+        let (_, certificate) = get_key_and_certificate();
+        let config = get_config(certificate);
+        let url = get_authentication_request_url(
+            &config,
+            ACS_URL,
+            REQUEST_ID,
+            &Secret::new("relay_state_1".to_string()),
+            NOW,
+        )
+        .unwrap();
+
+        let query = url
+            .query_pairs()
+            .collect::<std::collections::HashMap<_, _>>();
+        assert_eq!(query.get("tenant").map(|value| value.as_ref()), Some("1"));
+        assert_eq!(
+            query.get("RelayState").map(|value| value.as_ref()),
+            Some("relay_state_1")
+        );
+
+        let deflated_request = consts::BASE64_ENGINE
+            .decode(query.get("SAMLRequest").unwrap().as_bytes())
+            .unwrap();
+        let mut request = String::new();
+        flate2::read::DeflateDecoder::new(deflated_request.as_slice())
+            .read_to_string(&mut request)
+            .unwrap();
+        let request = parse_xml(&request).unwrap();
+        assert!(request.is(PROTOCOL_NAMESPACE, "AuthnRequest"));
+        assert_eq!(request.get_attribute("ID"), Some(REQUEST_ID));
+        assert_eq!(
+            request.get_attribute("IssueInstant"),
+            Some("2024-11-10T10:01:00Z")
+        );
+        assert_eq!(
+            request.get_attribute("AssertionConsumerServiceURL"),
+            Some(ACS_URL)
+        );
+        assert_eq!(
+            request
+                .find_child(ASSERTION_NAMESPACE, "Issuer")
+                .map(|issuer| issuer.get_text()),
+            Some(SP_ENTITY_ID.to_string())
+        );
+    }
+}
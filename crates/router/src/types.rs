@@ -27,6 +27,8 @@ pub use hyperswitch_domain_models::router_data_v2::FrmFlowData;
 use hyperswitch_domain_models::router_flow_types::{
     self,
     access_token_auth::AccessTokenAuth,
+    account_balance::AccountBalance,
+    account_update::AccountUpdate,
     dispute::{Accept, Defend, Evidence},
     files::{Retrieve, Upload},
     mandate_revoke::MandateRevoke,
@@ -43,19 +45,21 @@ pub use hyperswitch_domain_models::{
     payment_address::PaymentAddress,
     router_data::{
         AccessToken, AdditionalPaymentMethodConnectorResponse, ApplePayCryptogramData,
-        ApplePayPredecryptData, ConnectorAuthType, ConnectorResponseData, ErrorResponse,
-        PaymentMethodBalance, PaymentMethodToken, RecurringMandatePaymentData, RouterData,
+        ApplePayPredecryptData, ConnectorAuthType, ConnectorFee, ConnectorResponseData,
+        ErrorResponse, PaymentMethodBalance, PaymentMethodToken, RecurringMandatePaymentData,
+        RouterData, SettlementDetails,
     },
     router_data_v2::{
-        AccessTokenFlowData, DisputesFlowData, ExternalAuthenticationFlowData, FilesFlowData,
-        MandateRevokeFlowData, PaymentFlowData, RefundFlowData, RouterDataV2,
-        WebhookSourceVerifyData,
+        AccessTokenFlowData, AccountBalanceFlowData, AccountUpdateFlowData, DisputesFlowData,
+        ExternalAuthenticationFlowData, FilesFlowData, MandateRevokeFlowData, PaymentFlowData,
+        RefundFlowData, RouterDataV2, WebhookSourceVerifyData,
     },
     router_request_types::{
-        AcceptDisputeRequestData, AccessTokenRequestData, AuthorizeSessionTokenData,
-        BrowserInformation, ChargeRefunds, ChargeRefundsOptions, CompleteAuthorizeData,
-        CompleteAuthorizeRedirectResponse, ConnectorCustomerData, DefendDisputeRequestData,
-        DestinationChargeRefund, DirectChargeRefund, MandateRevokeRequestData,
+        AcceptDisputeRequestData, AccessTokenRequestData, AccountBalanceRequestData,
+        AccountUpdateRequestData, AuthorizeSessionTokenData, BrowserInformation, ChargeRefunds,
+        ChargeRefundsOptions, CompleteAuthorizeData, CompleteAuthorizeRedirectResponse,
+        ConnectorCustomerData, DefendDisputeRequestData, DestinationChargeRefund,
+        DirectChargeRefund, GiftCardBalanceCheckRequestData, MandateRevokeRequestData,
         MultipleCaptureRequestData, PaymentMethodTokenizationData, PaymentsApproveData,
         PaymentsAuthorizeData, PaymentsCancelData, PaymentsCaptureData,
         PaymentsIncrementalAuthorizationData, PaymentsPostProcessingData,
@@ -66,11 +70,12 @@ pub use hyperswitch_domain_models::{
         VerifyWebhookSourceRequestData,
     },
     router_response_types::{
-        AcceptDisputeResponse, CaptureSyncResponse, DefendDisputeResponse, MandateReference,
-        MandateRevokeResponseData, PaymentsResponseData, PreprocessingResponseId,
-        RefundsResponseData, RetrieveFileResponse, SubmitEvidenceResponse,
-        TaxCalculationResponseData, UploadFileResponse, VerifyWebhookSourceResponseData,
-        VerifyWebhookStatus,
+        AcceptDisputeResponse, AccountBalanceResponseData, AccountUpdateResponseData,
+        AccountUpdateStatus, CaptureSyncResponse, CurrencyBalance, DefendDisputeResponse,
+        GiftCardBalanceCheckResponseData, MandateReference, MandateRevokeResponseData,
+        PaymentsResponseData, PreprocessingResponseId, RefundsResponseData, RetrieveFileResponse,
+        SubmitEvidenceResponse, TaxCalculationResponseData, UploadFileResponse,
+        VerifyWebhookSourceResponseData, VerifyWebhookStatus,
     },
 };
 #[cfg(feature = "payouts")]
@@ -79,13 +84,14 @@ pub use hyperswitch_domain_models::{
     router_response_types::PayoutsResponseData,
 };
 pub use hyperswitch_interfaces::types::{
-    AcceptDisputeType, ConnectorCustomerType, DefendDisputeType, IncrementalAuthorizationType,
-    MandateRevokeType, PaymentsAuthorizeType, PaymentsBalanceType, PaymentsCaptureType,
-    PaymentsCompleteAuthorizeType, PaymentsInitType, PaymentsPostProcessingType,
-    PaymentsPostSessionTokensType, PaymentsPreAuthorizeType, PaymentsPreProcessingType,
-    PaymentsSessionType, PaymentsSyncType, PaymentsVoidType, RefreshTokenType, RefundExecuteType,
-    RefundSyncType, Response, RetrieveFileType, SdkSessionUpdateType, SetupMandateType,
-    SubmitEvidenceType, TokenizationType, UploadFileType, VerifyWebhookSourceType,
+    AcceptDisputeType, AccountBalanceType, AccountUpdateType, ConnectorCustomerType,
+    DefendDisputeType, IncrementalAuthorizationType, MandateRevokeType, PaymentsAuthorizeType,
+    PaymentsBalanceType, PaymentsCaptureType, PaymentsCompleteAuthorizeType, PaymentsInitType,
+    PaymentsPostProcessingType, PaymentsPostSessionTokensType, PaymentsPreAuthorizeType,
+    PaymentsPreProcessingType, PaymentsSessionType, PaymentsSyncType, PaymentsVoidType,
+    RefreshTokenType, RefundExecuteType, RefundSyncType, Response, RetrieveFileType,
+    SdkSessionUpdateType, SetupMandateType, SubmitEvidenceType, TokenizationType, UploadFileType,
+    VerifyWebhookSourceType,
 };
 #[cfg(feature = "payouts")]
 pub use hyperswitch_interfaces::types::{
@@ -122,7 +128,7 @@ pub type PaymentsCompleteAuthorizeRouterData =
 pub type PaymentsInitRouterData =
     RouterData<InitPayment, PaymentsAuthorizeData, PaymentsResponseData>;
 pub type PaymentsBalanceRouterData =
-    RouterData<Balance, PaymentsAuthorizeData, PaymentsResponseData>;
+    RouterData<Balance, GiftCardBalanceCheckRequestData, GiftCardBalanceCheckResponseData>;
 pub type PaymentsSyncRouterData = RouterData<PSync, PaymentsSyncData, PaymentsResponseData>;
 pub type PaymentsCaptureRouterData = RouterData<Capture, PaymentsCaptureData, PaymentsResponseData>;
 pub type PaymentsIncrementalAuthorizationRouterData = RouterData<
@@ -160,8 +166,12 @@ pub type PaymentsResponseRouterData<R> =
     ResponseRouterData<Authorize, R, PaymentsAuthorizeData, PaymentsResponseData>;
 pub type PaymentsCancelResponseRouterData<R> =
     ResponseRouterData<Void, R, PaymentsCancelData, PaymentsResponseData>;
-pub type PaymentsBalanceResponseRouterData<R> =
-    ResponseRouterData<Balance, R, PaymentsAuthorizeData, PaymentsResponseData>;
+pub type PaymentsBalanceResponseRouterData<R> = ResponseRouterData<
+    Balance,
+    R,
+    GiftCardBalanceCheckRequestData,
+    GiftCardBalanceCheckResponseData,
+>;
 pub type PaymentsSyncResponseRouterData<R> =
     ResponseRouterData<PSync, R, PaymentsSyncData, PaymentsResponseData>;
 pub type PaymentsSessionResponseRouterData<R> =
@@ -172,6 +182,12 @@ pub type SdkSessionUpdateResponseRouterData<R> =
     ResponseRouterData<SdkSessionUpdate, R, SdkPaymentsSessionUpdateData, PaymentsResponseData>;
 pub type PaymentsCaptureResponseRouterData<R> =
     ResponseRouterData<Capture, R, PaymentsCaptureData, PaymentsResponseData>;
+pub type PaymentsIncrementalAuthorizationResponseRouterData<R> = ResponseRouterData<
+    IncrementalAuthorization,
+    R,
+    PaymentsIncrementalAuthorizationData,
+    PaymentsResponseData,
+>;
 pub type PaymentsPreprocessingResponseRouterData<R> =
     ResponseRouterData<PreProcessing, R, PaymentsPreProcessingData, PaymentsResponseData>;
 pub type TokenizationResponseRouterData<R> =
@@ -194,6 +210,12 @@ pub type VerifyWebhookSourceRouterData = RouterData<
     VerifyWebhookSourceResponseData,
 >;
 
+pub type AccountBalanceRouterData =
+    RouterData<AccountBalance, AccountBalanceRequestData, AccountBalanceResponseData>;
+
+pub type AccountUpdateRouterData =
+    RouterData<AccountUpdate, AccountUpdateRequestData, AccountUpdateResponseData>;
+
 pub type SubmitEvidenceRouterData =
     RouterData<Evidence, SubmitEvidenceRequestData, SubmitEvidenceResponse>;
 
@@ -520,6 +542,206 @@ impl Default for PollConfig {
     }
 }
 
+/// Maximum time to wait for the connector response in the flows of a payment, configured per
+/// profile. If the connector does not respond within the budget of a payment confirmation, the
+/// payment is moved to a pending state and its final status is obtained through webhooks or
+/// payment sync. Retries of idempotent connector calls are only made within the budget.
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorTimeBudgetConfig {
+    /// Time budget for the authorize call, in milliseconds
+    pub authorize_in_millis: Option<u64>,
+    /// Time budget for the complete authorize call, in milliseconds
+    pub complete_authorize_in_millis: Option<u64>,
+    /// Time budget for the capture call including its retries, in milliseconds
+    pub capture_in_millis: Option<u64>,
+    /// Time budget for the payment sync call including its retries, in milliseconds
+    pub psync_in_millis: Option<u64>,
+}
+
+impl ConnectorTimeBudgetConfig {
+    pub fn get_config_key(profile_id: &common_utils::id_type::ProfileId) -> String {
+        format!("connector_time_budget_{}", profile_id.get_string_repr())
+    }
+}
+
+/// Retries of idempotent connector calls within a single payment attempt, configured per
+/// connector. Calls rejected with HTTP 429, 502 or 503 are retried with exponential backoff and
+/// jitter, as long as the retry completes within the time budget of the flow configured for the
+/// profile in [`ConnectorTimeBudgetConfig`].
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorRequestRetryConfig {
+    /// Maximum number of retries of a connector call, retries are disabled if zero
+    pub max_retries: u8,
+    /// Delay before the first retry, doubled for every subsequent retry
+    pub base_delay_in_millis: u64,
+    /// Upper bound of the delay between retries
+    pub max_delay_in_millis: u64,
+    /// Whether captures are retried, only enabled for connectors supporting idempotency keys
+    #[serde(default)]
+    pub retry_capture: bool,
+}
+
+impl ConnectorRequestRetryConfig {
+    pub fn get_config_key(connector_name: &str) -> String {
+        format!("connector_request_retry_{connector_name}")
+    }
+}
+
+/// Reconciliation of payments awaiting completion, configured per merchant and disabled by
+/// default. Payments which are still awaiting customer action or pending at the connector after
+/// confirmation are synced with the connector by the payment sync workflow, starting after the
+/// threshold and retrying as configured for the workflow.
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(default)]
+pub struct PendingCompletionConfig {
+    pub enabled: bool,
+    /// Time after the confirmation at which the first sync happens, in seconds
+    pub threshold_in_secs: i64,
+}
+
+impl PendingCompletionConfig {
+    pub fn get_config_key(merchant_id: &common_utils::id_type::MerchantId) -> String {
+        format!("pending_completion_{}", merchant_id.get_string_repr())
+    }
+}
+
+/// Rate limit imposed by the connector on a merchant connector account. Requests exceeding the
+/// rate limit are held back instead of being rejected by the connector.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorRateLimitConfig {
+    /// Number of requests the connector accepts per second
+    pub requests_per_second: u32,
+    /// Number of requests per second reserved for batch traffic, defaults to a tenth of the rate
+    /// limit. Live traffic is held back against the rest of the rate limit, so batch traffic can
+    /// never use up the rate available to live traffic.
+    #[serde(default)]
+    pub batch_requests_per_second: Option<u32>,
+    /// Number of requests which can be sent at once after a period of inactivity, defaults to one
+    #[serde(default)]
+    pub burst_size: Option<u32>,
+    /// Maximum time a request is held back, after which it is sent to the connector regardless
+    pub max_wait_in_millis: u64,
+    /// Time for which requests are held back after the connector responds with HTTP 429
+    pub cooldown_in_millis: u64,
+}
+
+impl ConnectorRateLimitConfig {
+    pub fn get_config_key(
+        merchant_connector_id: &common_utils::id_type::MerchantConnectorAccountId,
+    ) -> String {
+        format!(
+            "connector_rate_limit_{}",
+            merchant_connector_id.get_string_repr()
+        )
+    }
+}
+
+/// Payment descriptors of a profile, sent to the connectors accepting descriptor overrides
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+pub struct PaymentDescriptorConfig {
+    /// Merchant category code (MCC) of the profile
+    pub merchant_category_code: Option<String>,
+    /// Template of the soft descriptor, rendered with the values of each payment
+    pub soft_descriptor_template: Option<String>,
+}
+
+impl PaymentDescriptorConfig {
+    pub fn get_config_key(profile_id: &common_utils::id_type::ProfileId) -> String {
+        format!("payment_descriptors_{}", profile_id.get_string_repr())
+    }
+}
+
+/// CVC re-collection policy of a profile. Customer initiated payments with a saved card, at or
+/// above the threshold amount, require the CVC to be provided again.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SavedCardCvcPolicyConfig {
+    /// Amount in the minor unit of the payment currency, at or above which the CVC is required
+    pub amount_threshold: common_utils::types::MinorUnit,
+}
+
+impl SavedCardCvcPolicyConfig {
+    pub fn get_config_key(profile_id: &common_utils::id_type::ProfileId) -> String {
+        format!("saved_card_cvc_policy_{}", profile_id.get_string_repr())
+    }
+
+    pub fn is_cvc_required(&self, amount: common_utils::types::MinorUnit) -> bool {
+        amount >= self.amount_threshold
+    }
+}
+
+/// Processing limits of a profile, enforced when payments are confirmed. Amounts are in the minor
+/// unit of the reference currency, payments in other currencies are converted using forex rates.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ProcessingLimitsConfig {
+    pub reference_currency: storage::enums::Currency,
+    /// Maximum amount of a single payment
+    pub max_transaction_amount: Option<common_utils::types::MinorUnit>,
+    /// Maximum volume of the payments which succeeded in a UTC day
+    pub daily_volume_limit: Option<common_utils::types::MinorUnit>,
+    /// Maximum volume of the payments which succeeded in a UTC month
+    pub monthly_volume_limit: Option<common_utils::types::MinorUnit>,
+    /// Percentage of a volume limit, reaching which raises an alert
+    #[serde(default = "ProcessingLimitsConfig::default_alert_threshold_percentage")]
+    pub alert_threshold_percentage: u8,
+}
+
+impl ProcessingLimitsConfig {
+    pub fn get_config_key(profile_id: &common_utils::id_type::ProfileId) -> String {
+        format!("processing_limits_{}", profile_id.get_string_repr())
+    }
+
+    fn default_alert_threshold_percentage() -> u8 {
+        80
+    }
+
+    /// Volume at which an alert is raised for the given volume limit
+    pub fn get_alert_threshold(&self, volume_limit: common_utils::types::MinorUnit) -> i64 {
+        volume_limit
+            .get_amount_as_i64()
+            .saturating_mul(i64::from(self.alert_threshold_percentage.min(100)))
+            / 100
+    }
+}
+
+/// Connector debug capture of a profile. The redacted requests and responses of the connector calls
+/// which fail are retained for the configured number of days, to investigate integration issues.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorDebugCaptureConfig {
+    /// Number of days for which the captured connector calls are retained
+    pub retention_in_days: u16,
+}
+
+impl ConnectorDebugCaptureConfig {
+    pub fn get_config_key(profile_id: &common_utils::id_type::ProfileId) -> String {
+        format!("connector_debug_capture_{}", profile_id.get_string_repr())
+    }
+}
+
+/// Uniqueness of the merchant order references of the payments of a profile. A payment created with
+/// an order reference already used by another payment of the profile is handled as configured.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct OrderReferenceUniquenessConfig {
+    pub on_duplicate: OrderReferenceDuplicateAction,
+}
+
+impl OrderReferenceUniquenessConfig {
+    pub fn get_config_key(profile_id: &common_utils::id_type::ProfileId) -> String {
+        format!(
+            "order_reference_uniqueness_{}",
+            profile_id.get_string_repr()
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderReferenceDuplicateAction {
+    /// Reject the payment with a duplicate error
+    Reject,
+    /// Return the payment which already uses the order reference
+    ReturnExisting,
+}
+
 #[derive(Clone, Debug)]
 pub struct RedirectPaymentFlowResponse {
     pub payments_response: api_models::payments::PaymentsResponse,
@@ -881,6 +1103,8 @@ impl ForeignFrom<&SetupMandateRouterData> for PaymentsAuthorizeData {
             integrity_object: None,
             additional_payment_method_data: None,
             shipping_cost: data.request.shipping_cost,
+            connector_passthrough_data: None,
+            merchant_category_code: None,
         }
     }
 }
@@ -928,6 +1152,7 @@ impl<F1, F2, T1, T2> ForeignFrom<(&RouterData<F1, T1, PaymentsResponseData>, T2)
             payment_method_balance: data.payment_method_balance.clone(),
             connector_api_version: data.connector_api_version.clone(),
             connector_http_status_code: data.connector_http_status_code,
+            connector_request_id: data.connector_request_id.clone(),
             external_latency: data.external_latency,
             apple_pay_flow: data.apple_pay_flow.clone(),
             frm_metadata: data.frm_metadata.clone(),
@@ -935,6 +1160,7 @@ impl<F1, F2, T1, T2> ForeignFrom<(&RouterData<F1, T1, PaymentsResponseData>, T2)
             refund_id: data.refund_id.clone(),
             connector_response: data.connector_response.clone(),
             integrity_check: Ok(()),
+            connector_options: data.connector_options.clone(),
             additional_merchant_data: data.additional_merchant_data.clone(),
             header_payload: data.header_payload.clone(),
             connector_mandate_request_reference_id: data
@@ -995,6 +1221,7 @@ impl<F1, F2>
             payment_method_status: None,
             connector_api_version: None,
             connector_http_status_code: data.connector_http_status_code,
+            connector_request_id: data.connector_request_id.clone(),
             external_latency: data.external_latency,
             apple_pay_flow: None,
             frm_metadata: None,
@@ -1002,6 +1229,7 @@ impl<F1, F2>
             dispute_id: None,
             connector_response: data.connector_response.clone(),
             integrity_check: Ok(()),
+            connector_options: data.connector_options.clone(),
             additional_merchant_data: data.additional_merchant_data.clone(),
             header_payload: data.header_payload.clone(),
             connector_mandate_request_reference_id: None,
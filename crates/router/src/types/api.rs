@@ -42,13 +42,14 @@ use api_models::routing::{self as api_routing, RoutableConnectorChoice};
 use common_enums::RoutableConnectors;
 use error_stack::{report, ResultExt};
 pub use hyperswitch_domain_models::router_flow_types::{
-    access_token_auth::AccessTokenAuth, mandate_revoke::MandateRevoke,
-    webhooks::VerifyWebhookSource,
+    access_token_auth::AccessTokenAuth, account_balance::AccountBalance,
+    account_update::AccountUpdate, mandate_revoke::MandateRevoke, webhooks::VerifyWebhookSource,
 };
 pub use hyperswitch_interfaces::api::{
-    ConnectorAccessToken, ConnectorAccessTokenV2, ConnectorCommon, ConnectorCommonExt,
-    ConnectorMandateRevoke, ConnectorMandateRevokeV2, ConnectorVerifyWebhookSource,
-    ConnectorVerifyWebhookSourceV2, CurrencyUnit,
+    ConnectorAccessToken, ConnectorAccessTokenV2, ConnectorAccountBalance,
+    ConnectorAccountBalanceV2, ConnectorAccountUpdate, ConnectorAccountUpdateV2, ConnectorCommon,
+    ConnectorCommonExt, ConnectorMandateRevoke, ConnectorMandateRevokeV2,
+    ConnectorVerifyWebhookSource, ConnectorVerifyWebhookSourceV2, CurrencyUnit,
 };
 
 #[cfg(feature = "frm")]
@@ -106,6 +107,8 @@ pub trait Connector:
     + ConnectorMandateRevoke
     + ExternalAuthentication
     + TaxCalculation
+    + ConnectorAccountBalance
+    + ConnectorAccountUpdate
 {
 }
 
@@ -124,7 +127,9 @@ impl<
             + FraudCheck
             + ConnectorMandateRevoke
             + ExternalAuthentication
-            + TaxCalculation,
+            + TaxCalculation
+            + ConnectorAccountBalance
+            + ConnectorAccountUpdate,
     > Connector for T
 {
 }
@@ -144,6 +149,8 @@ pub trait ConnectorV2:
     + FraudCheckV2
     + ConnectorMandateRevokeV2
     + ExternalAuthenticationV2
+    + ConnectorAccountBalanceV2
+    + ConnectorAccountUpdateV2
 {
 }
 impl<
@@ -160,7 +167,9 @@ impl<
             + ConnectorVerifyWebhookSourceV2
             + FraudCheckV2
             + ConnectorMandateRevokeV2
-            + ExternalAuthenticationV2,
+            + ExternalAuthenticationV2
+            + ConnectorAccountBalanceV2
+            + ConnectorAccountUpdateV2,
     > ConnectorV2 for T
 {
 }
@@ -268,6 +277,8 @@ impl SessionSurchargeDetails {
 pub enum ConnectorChoice {
     SessionMultiple(Vec<SessionConnectorData>),
     StraightThrough(serde_json::Value),
+    /// The connector the attempt is forced to, bypassing the routing algorithm of the profile
+    Override(api_models::routing::RoutableConnectorChoice),
     Decide,
 }
 
@@ -372,6 +383,9 @@ impl ConnectorData {
                 enums::Connector::Datatrans => {
                     Ok(ConnectorEnum::Old(Box::new(connector::Datatrans::new())))
                 }
+                enums::Connector::Declarative => {
+                    Ok(ConnectorEnum::Old(Box::new(connector::Declarative::new())))
+                }
                 enums::Connector::Deutschebank => {
                     Ok(ConnectorEnum::Old(Box::new(connector::Deutschebank::new())))
                 }
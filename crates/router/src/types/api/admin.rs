@@ -82,6 +82,7 @@ impl ForeignTryFrom<domain::MerchantAccount> for MerchantAccountResponse {
             default_profile: item.default_profile,
             recon_status: item.recon_status,
             pm_collect_link_config,
+            data_region: item.data_region,
         })
     }
 }
@@ -27,6 +27,7 @@ impl ForeignTryFrom<FileUploadProvider> for types::Connector {
         match item {
             FileUploadProvider::Stripe => Ok(Self::Stripe),
             FileUploadProvider::Checkout => Ok(Self::Checkout),
+            FileUploadProvider::Rapyd => Ok(Self::Rapyd),
             FileUploadProvider::Router => Err(errors::ApiErrorResponse::NotSupported {
                 message: "File upload provider is not a connector".to_owned(),
             }
@@ -41,6 +42,7 @@ impl ForeignTryFrom<&types::Connector> for FileUploadProvider {
         match *item {
             types::Connector::Stripe => Ok(Self::Stripe),
             types::Connector::Checkout => Ok(Self::Checkout),
+            types::Connector::Rapyd => Ok(Self::Rapyd),
             _ => Err(errors::ApiErrorResponse::NotSupported {
                 message: "Connector not supported as file provider".to_owned(),
             }
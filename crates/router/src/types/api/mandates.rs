@@ -1,5 +1,8 @@
 use api_models::mandates;
-pub use api_models::mandates::{MandateId, MandateResponse, MandateRevokedResponse};
+pub use api_models::mandates::{
+    MandateDebitResponse, MandateDebitScheduleRequest, MandateId, MandateResponse,
+    MandateRevokedResponse,
+};
 use common_utils::ext_traits::OptionExt;
 use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
@@ -2,14 +2,14 @@
 pub use api_models::payments::PaymentsRequest;
 pub use api_models::payments::{
     AcceptanceType, Address, AddressDetails, Amount, AuthenticationForStartResponse, Card,
-    CryptoData, CustomerAcceptance, CustomerDetailsResponse, MandateAmountData, MandateData,
-    MandateTransactionType, MandateType, MandateValidationFields, NextActionType, OnlineMandate,
-    OpenBankingSessionToken, PayLaterData, PaymentIdType, PaymentListConstraints,
-    PaymentListFilterConstraints, PaymentListFilters, PaymentListFiltersV2, PaymentListResponse,
-    PaymentListResponseV2, PaymentMethodData, PaymentMethodDataRequest, PaymentMethodDataResponse,
-    PaymentOp, PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials, PaymentsAggregateResponse,
-    PaymentsApproveRequest, PaymentsCancelRequest, PaymentsCaptureRequest,
-    PaymentsCompleteAuthorizeRequest, PaymentsDynamicTaxCalculationRequest,
+    CryptoData, CustomerAcceptance, CustomerDetailsResponse, ExternalPaymentsImportRequest,
+    MandateAmountData, MandateData, MandateTransactionType, MandateType, MandateValidationFields,
+    NextActionType, OnlineMandate, OpenBankingSessionToken, PayLaterData, PaymentIdType,
+    PaymentListConstraints, PaymentListFilterConstraints, PaymentListFilters, PaymentListFiltersV2,
+    PaymentListResponse, PaymentListResponseV2, PaymentMethodData, PaymentMethodDataRequest,
+    PaymentMethodDataResponse, PaymentOp, PaymentRetrieveBody, PaymentRetrieveBodyWithCredentials,
+    PaymentsAggregateResponse, PaymentsApproveRequest, PaymentsCancelRequest,
+    PaymentsCaptureRequest, PaymentsCompleteAuthorizeRequest, PaymentsDynamicTaxCalculationRequest,
     PaymentsDynamicTaxCalculationResponse, PaymentsExternalAuthenticationRequest,
     PaymentsIncrementalAuthorizationRequest, PaymentsManualUpdateRequest,
     PaymentsPostSessionTokensRequest, PaymentsPostSessionTokensResponse, PaymentsRedirectRequest,
@@ -29,18 +29,19 @@ pub use hyperswitch_domain_models::router_flow_types::payments::{
 };
 pub use hyperswitch_interfaces::api::payments::{
     ConnectorCustomer, MandateSetup, Payment, PaymentApprove, PaymentAuthorize,
-    PaymentAuthorizeSessionToken, PaymentCapture, PaymentIncrementalAuthorization,
-    PaymentPostSessionTokens, PaymentReject, PaymentSession, PaymentSessionUpdate, PaymentSync,
-    PaymentToken, PaymentVoid, PaymentsCompleteAuthorize, PaymentsPostProcessing,
-    PaymentsPreProcessing, TaxCalculation,
+    PaymentAuthorizeSessionToken, PaymentCapture, PaymentGiftCardBalanceCheck,
+    PaymentIncrementalAuthorization, PaymentPostSessionTokens, PaymentReject, PaymentSession,
+    PaymentSessionUpdate, PaymentSync, PaymentToken, PaymentVoid, PaymentsCompleteAuthorize,
+    PaymentsPostProcessing, PaymentsPreProcessing, TaxCalculation,
 };
 
 pub use super::payments_v2::{
     ConnectorCustomerV2, MandateSetupV2, PaymentApproveV2, PaymentAuthorizeSessionTokenV2,
-    PaymentAuthorizeV2, PaymentCaptureV2, PaymentIncrementalAuthorizationV2,
-    PaymentPostSessionTokensV2, PaymentRejectV2, PaymentSessionUpdateV2, PaymentSessionV2,
-    PaymentSyncV2, PaymentTokenV2, PaymentV2, PaymentVoidV2, PaymentsCompleteAuthorizeV2,
-    PaymentsPostProcessingV2, PaymentsPreProcessingV2, TaxCalculationV2,
+    PaymentAuthorizeV2, PaymentCaptureV2, PaymentGiftCardBalanceCheckV2,
+    PaymentIncrementalAuthorizationV2, PaymentPostSessionTokensV2, PaymentRejectV2,
+    PaymentSessionUpdateV2, PaymentSessionV2, PaymentSyncV2, PaymentTokenV2, PaymentV2,
+    PaymentVoidV2, PaymentsCompleteAuthorizeV2, PaymentsPostProcessingV2, PaymentsPreProcessingV2,
+    TaxCalculationV2,
 };
 use crate::core::errors;
 
@@ -58,6 +58,8 @@ impl VerifyConnectorData {
             merchant_order_reference_id: None,
             integrity_object: None,
             additional_payment_method_data: None,
+            connector_passthrough_data: None,
+            merchant_category_code: None,
             shipping_cost: None,
         }
     }
@@ -108,6 +110,7 @@ impl VerifyConnectorData {
             quote_id: None,
             payment_method_balance: None,
             connector_http_status_code: None,
+            connector_request_id: None,
             external_latency: None,
             apple_pay_flow: None,
             frm_metadata: None,
@@ -115,6 +118,7 @@ impl VerifyConnectorData {
             dispute_id: None,
             connector_response: None,
             integrity_check: Ok(()),
+            connector_options: None,
             additional_merchant_data: None,
             header_payload: None,
             connector_mandate_request_reference_id: None,
@@ -37,6 +37,9 @@ pub struct Event {
     pub response: Option<Encryptable<Secret<String>>>,
     pub delivery_attempt: Option<WebhookDeliveryAttempt>,
     pub metadata: Option<EventMetadata>,
+    /// The webhook endpoint of the business profile the event is delivered to, if it is not
+    /// delivered to the webhook url of the profile
+    pub webhook_endpoint_id: Option<String>,
 }
 
 #[derive(Debug)]
@@ -84,6 +87,7 @@ impl super::behaviour::Conversion for Event {
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 
@@ -133,6 +137,7 @@ impl super::behaviour::Conversion for Event {
             response: encryptable_event.response,
             delivery_attempt: item.delivery_attempt,
             metadata: item.metadata,
+            webhook_endpoint_id: item.webhook_endpoint_id,
         })
     }
 
@@ -154,6 +159,7 @@ impl super::behaviour::Conversion for Event {
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
             metadata: self.metadata,
+            webhook_endpoint_id: self.webhook_endpoint_id,
         })
     }
 }
@@ -321,6 +321,18 @@ impl From<InviteeUserRequestWithInvitedUserToken> for NewUserOrganization {
     }
 }
 
+type SsoProvisionedUserRequest = (UserEmail, id_type::OrganizationId, id_type::MerchantId);
+impl From<SsoProvisionedUserRequest> for NewUserOrganization {
+    fn from((_email, org_id, _merchant_id): SsoProvisionedUserRequest) -> Self {
+        let new_organization = api_org::OrganizationNew {
+            org_id,
+            org_name: None,
+        };
+        let db_organization = ForeignFrom::foreign_from(new_organization);
+        Self(db_organization)
+    }
+}
+
 #[derive(Clone)]
 pub struct MerchantId(String);
 
@@ -442,6 +454,7 @@ impl NewUserMerchant {
             enable_payment_response_hash: None,
             redirect_to_merchant_with_http_post: None,
             pm_collect_link_config: None,
+            data_region: None,
         })
     }
 
@@ -544,6 +557,18 @@ impl TryFrom<InviteeUserRequestWithInvitedUserToken> for NewUserMerchant {
     }
 }
 
+impl From<SsoProvisionedUserRequest> for NewUserMerchant {
+    fn from(value: SsoProvisionedUserRequest) -> Self {
+        let merchant_id = value.2.clone();
+        let new_organization = NewUserOrganization::from(value);
+        Self {
+            company_name: None,
+            merchant_id,
+            new_organization,
+        }
+    }
+}
+
 type UserMerchantCreateRequestWithToken =
     (UserFromStorage, user_api::UserMerchantCreate, UserFromToken);
 
@@ -865,6 +890,24 @@ impl TryFrom<InviteeUserRequestWithInvitedUserToken> for NewUser {
     }
 }
 
+impl TryFrom<SsoProvisionedUserRequest> for NewUser {
+    type Error = error_stack::Report<UserErrors>;
+    fn try_from(value: SsoProvisionedUserRequest) -> UserResult<Self> {
+        let user_id = uuid::Uuid::new_v4().to_string();
+        let email = value.0.clone();
+        let name = UserName::try_from(email.clone().into_inner())?;
+        let new_merchant = NewUserMerchant::from(value);
+
+        Ok(Self {
+            user_id,
+            name,
+            email,
+            password: None,
+            new_merchant,
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct UserFromStorage(pub storage_user::User);
 
@@ -94,6 +94,7 @@ impl SPTFlow {
             &state.conf,
             next_flow.path.to_vec(),
             Some(state.tenant.tenant_id.clone()),
+            next_flow.two_factor_auth_verified,
         )
         .await
         .map(|token| token.into())
@@ -120,6 +121,21 @@ impl JWTFlow {
         next_flow: &NextFlow,
         user_role: &UserRole,
     ) -> UserResult<Secret<String>> {
+        // Two factor authentication is left to the identity provider for users signing in with SSO
+        let two_factor_auth_verified =
+            next_flow.two_factor_auth_verified || next_flow.path.contains(&TokenPurpose::SSO);
+        let org_id = user_role
+            .org_id
+            .clone()
+            .ok_or(report!(UserErrors::InternalServerError))
+            .attach_printable("org_id not found")?;
+        utils::user::two_factor_auth::check_two_factor_auth_policy(
+            state,
+            &org_id,
+            two_factor_auth_verified,
+        )
+        .await?;
+
         let (merchant_id, profile_id) =
             utils::user_role::get_single_merchant_id_and_profile_id(state, user_role).await?;
         auth::AuthToken::new_token(
@@ -127,13 +143,10 @@ impl JWTFlow {
             merchant_id,
             user_role.role_id.clone(),
             &state.conf,
-            user_role
-                .org_id
-                .clone()
-                .ok_or(report!(UserErrors::InternalServerError))
-                .attach_printable("org_id not found")?,
+            org_id,
             Some(profile_id),
             Some(user_role.tenant_id.clone()),
+            two_factor_auth_verified,
         )
         .await
         .map(|token| token.into())
@@ -220,6 +233,7 @@ pub struct CurrentFlow {
     origin: Origin,
     current_flow_index: usize,
     path: Vec<TokenPurpose>,
+    two_factor_auth_verified: bool,
 }
 
 impl CurrentFlow {
@@ -239,6 +253,7 @@ impl CurrentFlow {
             origin: token.origin,
             current_flow_index: index,
             path,
+            two_factor_auth_verified: token.two_factor_auth_verified,
         })
     }
 
@@ -253,6 +268,7 @@ impl CurrentFlow {
                     next_flow: *flow,
                     user,
                     path: self.path,
+                    two_factor_auth_verified: self.two_factor_auth_verified,
                 });
             }
         }
@@ -265,6 +281,7 @@ pub struct NextFlow {
     next_flow: UserFlow,
     user: UserFromStorage,
     path: Vec<TokenPurpose>,
+    two_factor_auth_verified: bool,
 }
 
 impl NextFlow {
@@ -282,6 +299,7 @@ impl NextFlow {
                     next_flow: *flow,
                     user,
                     path,
+                    two_factor_auth_verified: false,
                 });
             }
         }
@@ -358,6 +376,7 @@ impl NextFlow {
                     next_flow: *flow,
                     user,
                     path: self.path,
+                    two_factor_auth_verified: self.two_factor_auth_verified,
                 });
             }
         }
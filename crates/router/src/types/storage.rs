@@ -19,8 +19,10 @@ pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod invoice;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod ledger_entry;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -29,16 +31,21 @@ pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_order_reference;
+pub mod payment_shipment;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod refund;
+pub mod refund_tender_override;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod routing_experiment_assignment;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_role;
+pub mod webhook_endpoint;
 
 use std::collections::HashMap;
 
@@ -64,11 +71,13 @@ pub use self::{
     address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
     configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, generic_link::*, gsm::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
-    user_role::*,
+    file::*, fraud_check::*, generic_link::*, gsm::*, invoice::*, ledger_entry::*,
+    locker_mock_up::*, mandate::*, merchant_account::*, merchant_connector_account::*,
+    merchant_key_store::*, payment_link::*, payment_method::*, payment_order_reference::*,
+    payment_shipment::*, process_tracker::*, refund::*, refund_tender_override::*,
+    reverse_lookup::*, role::*, routing_algorithm::*, routing_experiment_assignment::*,
+    unified_translations::*, user::*, user_authentication_method::*, user_role::*,
+    webhook_endpoint::*,
 };
 use crate::types::api::routing;
 
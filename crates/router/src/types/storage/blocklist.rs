@@ -1 +1,4 @@
-pub use diesel_models::blocklist::{Blocklist, BlocklistNew};
+pub use diesel_models::{
+    blocklist::{Blocklist, BlocklistNew},
+    blocklist_sync_version::{BlocklistSync, BlocklistSyncEntries, BlocklistSyncVersion},
+};
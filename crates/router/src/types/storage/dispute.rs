@@ -2,6 +2,8 @@ use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
 pub use diesel_models::dispute::{Dispute, DisputeNew, DisputeUpdate};
+#[cfg(feature = "v1")]
+use diesel_models::schema::payment_intent::dsl as pi_dsl;
 use diesel_models::{errors, query::generics::db_metrics, schema::dispute::dsl};
 use error_stack::ResultExt;
 use hyperswitch_domain_models::disputes;
@@ -22,6 +24,13 @@ pub trait DisputeDbExt: Sized {
         profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
         time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::enums::DisputeStatus, i64)>, errors::DatabaseError>;
+
+    #[cfg(feature = "v1")]
+    async fn count_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<i64, errors::DatabaseError>;
 }
 
 #[async_trait::async_trait]
@@ -146,4 +155,31 @@ impl DisputeDbExt for Dispute {
         .change_context(errors::DatabaseError::NotFound)
         .attach_printable_lazy(|| "Error filtering records by predicate")
     }
+
+    #[cfg(feature = "v1")]
+    async fn count_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<i64, errors::DatabaseError> {
+        let customer_payment_ids = pi_dsl::payment_intent
+            .select(pi_dsl::payment_id)
+            .filter(pi_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(pi_dsl::customer_id.eq(customer_id.to_owned()));
+
+        let query = <Self as HasTable>::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::payment_id.eq_any(customer_payment_ids))
+            .count();
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_result_async::<i64>(conn),
+            db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error counting the disputes of the customer")
+    }
 }
@@ -64,6 +64,8 @@ impl PaymentAttemptExt for PaymentAttempt {
             connector_capture_id: None,
             connector_response_reference_id: None,
             connector_capture_data: None,
+            settlement_currency: None,
+            settlement_amount: None,
         })
     }
 
@@ -217,6 +219,7 @@ mod tests {
             profile_id: common_utils::generate_profile_id_of_default_length(),
             organization_id: Default::default(),
             connector_mandate_detail: Default::default(),
+            routing_decision: Default::default(),
         };
 
         let store = state
@@ -301,6 +304,7 @@ mod tests {
             profile_id: common_utils::generate_profile_id_of_default_length(),
             organization_id: Default::default(),
             connector_mandate_detail: Default::default(),
+            routing_decision: Default::default(),
         };
         let store = state
             .stores
@@ -398,6 +402,7 @@ mod tests {
             profile_id: common_utils::generate_profile_id_of_default_length(),
             organization_id: Default::default(),
             connector_mandate_detail: Default::default(),
+            routing_decision: Default::default(),
         };
         let store = state
             .stores
@@ -0,0 +1,3 @@
+pub use diesel_models::refund_tender_override::{
+    RefundTenderOverride, RefundTenderOverrideNew, RefundTenderOverrideUpdate,
+};
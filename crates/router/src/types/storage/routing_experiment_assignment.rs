@@ -0,0 +1,3 @@
+pub use diesel_models::routing_experiment_assignment::{
+    RoutingExperimentArmCount, RoutingExperimentAssignment, RoutingExperimentAssignmentNew,
+};
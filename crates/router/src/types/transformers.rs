@@ -230,6 +230,7 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
             api_enums::Connector::Cryptopay => Self::Cryptopay,
             api_enums::Connector::Cybersource => Self::Cybersource,
             api_enums::Connector::Datatrans => Self::Datatrans,
+            api_enums::Connector::Declarative => Self::Declarative,
             api_enums::Connector::Deutschebank => Self::Deutschebank,
             api_enums::Connector::Digitalvirgo => Self::Digitalvirgo,
             api_enums::Connector::Dlocal => Self::Dlocal,
@@ -625,6 +626,19 @@ impl ForeignFrom<storage_enums::DisputeStatus> for storage_enums::EventType {
     }
 }
 
+impl ForeignFrom<storage_enums::InvoiceStatus> for Option<storage_enums::EventType> {
+    fn foreign_from(value: storage_enums::InvoiceStatus) -> Self {
+        match value {
+            storage_enums::InvoiceStatus::Paid => Some(storage_enums::EventType::InvoicePaid),
+            storage_enums::InvoiceStatus::PartiallyPaid => {
+                Some(storage_enums::EventType::InvoicePartiallyPaid)
+            }
+            storage_enums::InvoiceStatus::Overdue => Some(storage_enums::EventType::InvoiceOverdue),
+            storage_enums::InvoiceStatus::Open => None,
+        }
+    }
+}
+
 impl ForeignFrom<storage_enums::MandateStatus> for Option<storage_enums::EventType> {
     fn foreign_from(value: storage_enums::MandateStatus) -> Self {
         match value {
@@ -635,6 +649,24 @@ impl ForeignFrom<storage_enums::MandateStatus> for Option<storage_enums::EventTy
     }
 }
 
+impl ForeignFrom<storage_enums::PaymentMethodStatus> for Option<storage_enums::EventType> {
+    fn foreign_from(value: storage_enums::PaymentMethodStatus) -> Self {
+        match value {
+            storage_enums::PaymentMethodStatus::Suspended => {
+                Some(storage_enums::EventType::PaymentMethodTokenSuspended)
+            }
+            storage_enums::PaymentMethodStatus::Active => {
+                Some(storage_enums::EventType::PaymentMethodTokenResumed)
+            }
+            storage_enums::PaymentMethodStatus::Inactive => {
+                Some(storage_enums::EventType::PaymentMethodTokenDeleted)
+            }
+            storage_enums::PaymentMethodStatus::Processing
+            | storage_enums::PaymentMethodStatus::AwaitingData => None,
+        }
+    }
+}
+
 impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enums::RefundStatus {
     type Error = errors::ValidationError;
 
@@ -689,6 +721,28 @@ impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent> for storage_enum
     }
 }
 
+impl ForeignTryFrom<api_models::webhooks::IncomingWebhookEvent>
+    for storage_enums::ConnectorMandateStatus
+{
+    type Error = errors::ValidationError;
+
+    fn foreign_try_from(
+        value: api_models::webhooks::IncomingWebhookEvent,
+    ) -> Result<Self, Self::Error> {
+        match value {
+            api_models::webhooks::IncomingWebhookEvent::WalletTokenUpdated => Ok(Self::Active),
+            api_models::webhooks::IncomingWebhookEvent::WalletTokenExpired
+            | api_models::webhooks::IncomingWebhookEvent::WalletTokenSuspended => {
+                Ok(Self::Inactive)
+            }
+            api_models::webhooks::IncomingWebhookEvent::WalletTokenResumed => Ok(Self::Active),
+            _ => Err(errors::ValidationError::IncorrectValueProvided {
+                field_name: "incoming_webhook_event_type",
+            }),
+        }
+    }
+}
+
 impl ForeignFrom<storage::Config> for api_types::Config {
     fn foreign_from(config: storage::Config) -> Self {
         Self {
@@ -900,6 +954,7 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponse {
             created_at: dispute.created_at,
             profile_id: dispute.profile_id,
             merchant_connector_id: dispute.merchant_connector_id,
+            financial_impact: None,
         }
     }
 }
@@ -1295,6 +1350,10 @@ impl ForeignFrom<storage::PaymentAttempt> for payments::PaymentAttemptResponse {
             unified_message: payment_attempt.unified_message,
             client_source: payment_attempt.client_source,
             client_version: payment_attempt.client_version,
+            connector_fee_amount: payment_attempt.connector_fee_amount,
+            connector_fee_currency: payment_attempt.connector_fee_currency,
+            connector_request_id: payment_attempt.connector_request_id,
+            correlation_id: payment_attempt.correlation_id,
         }
     }
 }
@@ -1315,6 +1374,8 @@ impl ForeignFrom<storage::Capture> for payments::CaptureResponse {
             error_code: capture.error_code,
             error_reason: capture.error_reason,
             reference_id: capture.connector_response_reference_id,
+            settlement_currency: capture.settlement_currency,
+            settlement_amount: capture.settlement_amount,
         }
     }
 }
@@ -1922,6 +1983,9 @@ impl ForeignFrom<api_models::admin::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            webhook_format: item.webhook_format,
+            enabled_events: item.enabled_events,
+            schema_version: item.schema_version,
         }
     }
 }
@@ -1938,6 +2002,9 @@ impl ForeignFrom<diesel_models::business_profile::WebhookDetails>
             payment_created_enabled: item.payment_created_enabled,
             payment_succeeded_enabled: item.payment_succeeded_enabled,
             payment_failed_enabled: item.payment_failed_enabled,
+            webhook_format: item.webhook_format,
+            enabled_events: item.enabled_events,
+            schema_version: item.schema_version,
         }
     }
 }
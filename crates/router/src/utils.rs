@@ -1195,6 +1195,31 @@ where
                 .collect()
         });
 
+    if matches!(
+        status,
+        enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured
+    ) {
+        let cloned_state = state.clone();
+        let cloned_merchant_account = merchant_account.clone();
+        let cloned_key_store = key_store.clone();
+        let payment_id = payment_id.clone();
+        tokio::spawn(
+            async move {
+                crate::core::invoices::refresh_invoices_of_payment(
+                    &cloned_state,
+                    &cloned_merchant_account,
+                    &cloned_key_store,
+                    &payment_id,
+                )
+                .await
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to refresh the invoices of the payment")
+                })
+            }
+            .in_current_span(),
+        );
+    }
+
     if matches!(
         status,
         enums::IntentStatus::Succeeded
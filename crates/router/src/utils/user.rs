@@ -6,7 +6,7 @@ use common_utils::{
     encryption::Encryption, errors::CustomResult, id_type, type_name, types::keymanager::Identifier,
 };
 use error_stack::ResultExt;
-use masking::{ExposeInterface, Secret};
+use masking::{ExposeInterface, PeekInterface, Secret};
 use redis_interface::RedisConnectionPool;
 
 use crate::{
@@ -85,6 +85,7 @@ impl UserFromToken {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn generate_jwt_auth_token_with_attributes(
     state: &SessionState,
     user_id: String,
@@ -93,7 +94,10 @@ pub async fn generate_jwt_auth_token_with_attributes(
     role_id: String,
     profile_id: id_type::ProfileId,
     tenant_id: Option<String>,
+    two_factor_auth_verified: bool,
 ) -> UserResult<Secret<String>> {
+    two_factor_auth::check_two_factor_auth_policy(state, &org_id, two_factor_auth_verified).await?;
+
     let token = AuthToken::new_token(
         user_id,
         merchant_id,
@@ -102,6 +106,7 @@ pub async fn generate_jwt_auth_token_with_attributes(
         org_id,
         Some(profile_id),
         tenant_id,
+        two_factor_auth_verified,
     )
     .await?;
     Ok(Secret::new(token))
@@ -141,6 +146,7 @@ impl ForeignFrom<&user_api::AuthConfig> for UserAuthType {
     fn foreign_from(from: &user_api::AuthConfig) -> Self {
         match *from {
             user_api::AuthConfig::OpenIdConnect { .. } => Self::OpenIdConnect,
+            user_api::AuthConfig::Saml { .. } => Self::Saml,
             user_api::AuthConfig::Password => Self::Password,
             user_api::AuthConfig::MagicLink => Self::MagicLink,
         }
@@ -184,6 +190,15 @@ pub async fn construct_public_and_private_db_configs(
                 ),
             ))
         }
+        // The configuration of a SAML identity provider holds no secrets
+        user_api::AuthConfig::Saml { public_config } => Ok((
+            None,
+            Some(
+                serde_json::to_value(public_config.clone())
+                    .change_context(UserErrors::InternalServerError)
+                    .attach_printable("Failed to convert auth config to json")?,
+            ),
+        )),
         user_api::AuthConfig::Password | user_api::AuthConfig::MagicLink => Ok((None, None)),
     }
 }
@@ -272,9 +287,29 @@ pub fn get_oidc_sso_redirect_url(state: &SessionState, provider: &str) -> String
     format!("{}/redirect/oidc/{}", state.conf.user.base_url, provider)
 }
 
+/// Assertion consumer service the SAML identity provider of the authentication method posts its
+/// responses to
+pub fn get_saml_acs_url(state: &SessionState, auth_method_id: &str) -> String {
+    format!("{}/user/saml/acs/{}", state.base_url, auth_method_id)
+}
+
+pub fn get_saml_sso_redirect_url(
+    state: &SessionState,
+    sign_in_state: &Secret<String>,
+    code: &Secret<String>,
+) -> UserResult<url::Url> {
+    let mut url = url::Url::parse(&format!("{}/redirect/saml", state.conf.user.base_url))
+        .change_context(UserErrors::InternalServerError)
+        .attach_printable("Invalid base url of the dashboard")?;
+    url.query_pairs_mut()
+        .append_pair("state", sign_in_state.peek())
+        .append_pair("code", code.peek());
+    Ok(url)
+}
+
 pub fn is_sso_auth_type(auth_type: &UserAuthType) -> bool {
     match auth_type {
-        UserAuthType::OpenIdConnect => true,
+        UserAuthType::OpenIdConnect | UserAuthType::Saml => true,
         UserAuthType::Password | UserAuthType::MagicLink => false,
     }
 }
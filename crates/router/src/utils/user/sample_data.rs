@@ -274,6 +274,7 @@ pub async fn generate_sample_data(
             shipping_cost: None,
             tax_details: None,
             skip_external_tax_calculation: None,
+            row_version: common_utils::consts::INITIAL_ROW_VERSION,
         };
         let (connector_transaction_id, connector_transaction_data) =
             ConnectorTransactionId::form_id_and_data(attempt_id.clone());
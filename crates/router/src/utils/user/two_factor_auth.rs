@@ -1,11 +1,16 @@
-use common_utils::pii;
+use common_utils::{
+    crypto::{GenerateDigest, Sha256},
+    id_type, pii,
+};
+use diesel_models::{configs, enums::UserStatus};
 use error_stack::ResultExt;
-use masking::{ExposeInterface, PeekInterface};
+use masking::{ExposeInterface, PeekInterface, Secret};
 use totp_rs::{Algorithm, TOTP};
 
 use crate::{
     consts,
     core::errors::{UserErrors, UserResult},
+    db::user_role::ListUserRolesByUserIdPayload,
     routes::SessionState,
 };
 
@@ -226,3 +231,169 @@ pub async fn delete_recovery_code_attempts_from_redis(
         .change_context(UserErrors::InternalServerError)
         .map(|_| ())
 }
+
+fn get_remembered_device_key(user_id: &str, device_token: &str) -> UserResult<String> {
+    // Only the digest of the device token is stored, the token itself is known to the device only
+    let device_token_digest = Sha256
+        .generate_digest(device_token.as_bytes())
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(format!(
+        "{}{}_{}",
+        consts::user::REDIS_REMEMBERED_DEVICE_PREFIX,
+        user_id,
+        hex::encode(device_token_digest)
+    ))
+}
+
+/// Remembers the device of the user, so that two factor authentication can be skipped on it until
+/// the remembered device expires. Returns the token identifying the device.
+pub async fn insert_remembered_device_in_redis(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<Secret<String>> {
+    let redis_conn = super::get_redis_connection(state)?;
+    let device_token = common_utils::generate_id_with_len(64);
+    redis_conn
+        .set_key_with_expiry(
+            &get_remembered_device_key(user_id, &device_token)?,
+            common_utils::date_time::now_unix_timestamp(),
+            state.conf.user.remembered_device_expiry_in_secs,
+        )
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    Ok(device_token.into())
+}
+
+pub async fn check_remembered_device_in_redis(
+    state: &SessionState,
+    user_id: &str,
+    device_token: &str,
+) -> UserResult<bool> {
+    let redis_conn = super::get_redis_connection(state)?;
+    redis_conn
+        .exists::<()>(&get_remembered_device_key(user_id, device_token)?)
+        .await
+        .change_context(UserErrors::InternalServerError)
+}
+
+fn get_two_factor_auth_policy_key(org_id: &id_type::OrganizationId) -> String {
+    format!(
+        "{}{}",
+        consts::user::TWO_FACTOR_AUTH_POLICY_CONFIG_PREFIX,
+        org_id.get_string_repr()
+    )
+}
+
+/// Returns whether two factor authentication is enforced for the users of the organization,
+/// either by the organization policy or for all the users
+pub async fn is_two_factor_auth_enforced(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+) -> UserResult<bool> {
+    if state.conf.user.force_two_factor_auth {
+        return Ok(true);
+    }
+
+    match state
+        .store
+        .find_config_by_key(&get_two_factor_auth_policy_key(org_id))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse::<bool>()
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to parse the two factor auth policy"),
+        Err(error) if error.current_context().is_db_not_found() => Ok(false),
+        Err(error) => Err(error
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to fetch the two factor auth policy")),
+    }
+}
+
+/// Returns whether two factor authentication is enforced for the user by any of the
+/// organizations the user is part of
+pub async fn is_two_factor_auth_enforced_for_user(
+    state: &SessionState,
+    user_id: &str,
+) -> UserResult<bool> {
+    if state.conf.user.force_two_factor_auth {
+        return Ok(true);
+    }
+
+    let user_roles = state
+        .global_store
+        .list_user_roles_by_user_id(ListUserRolesByUserIdPayload {
+            user_id,
+            org_id: None,
+            merchant_id: None,
+            profile_id: None,
+            entity_id: None,
+            version: None,
+            status: Some(UserStatus::Active),
+            limit: None,
+        })
+        .await
+        .change_context(UserErrors::InternalServerError)?;
+
+    for org_id in user_roles
+        .iter()
+        .filter_map(|user_role| user_role.org_id.as_ref())
+        .collect::<std::collections::HashSet<_>>()
+    {
+        if is_two_factor_auth_enforced(state, org_id).await? {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Rejects issuing a dashboard token for an organization which enforces two factor
+/// authentication, unless the user completed it while signing in
+pub async fn check_two_factor_auth_policy(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    two_factor_auth_verified: bool,
+) -> UserResult<()> {
+    if !two_factor_auth_verified && is_two_factor_auth_enforced(state, org_id).await? {
+        return Err(UserErrors::TwoFactorAuthRequired.into());
+    }
+    Ok(())
+}
+
+pub async fn set_two_factor_auth_policy(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    force_two_factor_auth: bool,
+) -> UserResult<()> {
+    let key = get_two_factor_auth_policy_key(org_id);
+    let config = force_two_factor_auth.to_string();
+
+    match state.store.find_config_by_key_from_db(&key).await {
+        Ok(_) => state
+            .store
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .map(|_| ())
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to update the two factor auth policy"),
+        Err(error) if error.current_context().is_db_not_found() => state
+            .store
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .map(|_| ())
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to insert the two factor auth policy"),
+        Err(error) => Err(error
+            .change_context(UserErrors::InternalServerError)
+            .attach_printable("Failed to fetch the two factor auth policy")),
+    }
+}
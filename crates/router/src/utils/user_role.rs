@@ -12,10 +12,13 @@ use storage_impl::errors::StorageError;
 
 use crate::{
     consts,
-    core::errors::{UserErrors, UserResult},
+    core::errors::{StorageErrorExt, UserErrors, UserResult},
     db::user_role::{ListUserRolesByOrgIdPayload, ListUserRolesByUserIdPayload},
     routes::SessionState,
-    services::authorization::{self as authz, roles},
+    services::{
+        authentication as auth,
+        authorization::{self as authz, roles},
+    },
     types::domain,
 };
 
@@ -242,6 +245,7 @@ pub async fn get_lineage_for_user_id_and_entity_for_accepting_invite(
                 .await
                 .change_context(UserErrors::InternalServerError)?
                 .into_iter()
+                .filter(|user_role| !is_invitation_expired(state, user_role))
                 .collect::<HashSet<_>>();
 
             if user_roles.len() > 1 {
@@ -286,6 +290,7 @@ pub async fn get_lineage_for_user_id_and_entity_for_accepting_invite(
                 .await
                 .change_context(UserErrors::InternalServerError)?
                 .into_iter()
+                .filter(|user_role| !is_invitation_expired(state, user_role))
                 .collect::<HashSet<_>>();
 
             if user_roles.len() > 1 {
@@ -331,6 +336,7 @@ pub async fn get_lineage_for_user_id_and_entity_for_accepting_invite(
                 .await
                 .change_context(UserErrors::InternalServerError)?
                 .into_iter()
+                .filter(|user_role| !is_invitation_expired(state, user_role))
                 .collect::<HashSet<_>>();
 
             if user_roles.len() > 1 {
@@ -362,6 +368,108 @@ pub async fn get_lineage_for_user_id_and_entity_for_accepting_invite(
     }
 }
 
+/// Returns when the invitation of a pending user role expires, invitations can be accepted for the
+/// configured duration after they were sent or last resent
+pub fn get_invitation_expiry(
+    state: &SessionState,
+    user_role: &UserRole,
+) -> Option<time::PrimitiveDateTime> {
+    (user_role.status == UserStatus::InvitationSent).then(|| {
+        user_role
+            .last_modified
+            .saturating_add(time::Duration::seconds(
+                state.conf.user.invitation_expiry_in_secs,
+            ))
+    })
+}
+
+pub fn is_invitation_expired(state: &SessionState, user_role: &UserRole) -> bool {
+    get_invitation_expiry(state, user_role)
+        .is_some_and(|expiry| expiry < common_utils::date_time::now())
+}
+
+/// Returns the merchant account and profile of an invitation. They default to the ones of the
+/// requestor and have to be within the entity of the requestor otherwise.
+pub async fn get_invitation_lineage(
+    state: &SessionState,
+    user_from_token: &auth::UserFromToken,
+    requestor_entity_type: EntityType,
+    merchant_id: Option<&id_type::MerchantId>,
+    profile_id: Option<&id_type::ProfileId>,
+) -> UserResult<(id_type::MerchantId, Option<id_type::ProfileId>)> {
+    let merchant_id = match merchant_id {
+        Some(merchant_id) if merchant_id != &user_from_token.merchant_id => {
+            if requestor_entity_type < EntityType::Organization {
+                return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+                    "Only organization users can manage invitations of other merchant accounts"
+                        .to_string()
+                )));
+            }
+
+            let is_merchant_in_org = state
+                .store
+                .list_merchant_accounts_by_organization_id(&state.into(), &user_from_token.org_id)
+                .await
+                .change_context(UserErrors::InternalServerError)?
+                .iter()
+                .any(|merchant_account| merchant_account.get_id() == merchant_id);
+            if !is_merchant_in_org {
+                return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+                    "Merchant account does not belong to the organization".to_string()
+                )));
+            }
+
+            merchant_id.clone()
+        }
+        _ => user_from_token.merchant_id.clone(),
+    };
+    let is_requestor_merchant = merchant_id == user_from_token.merchant_id;
+
+    let profile_id = match profile_id {
+        Some(profile_id)
+            if is_requestor_merchant && Some(profile_id) == user_from_token.profile_id.as_ref() =>
+        {
+            Some(profile_id.clone())
+        }
+        Some(profile_id) => {
+            if requestor_entity_type < EntityType::Merchant {
+                return Err(report!(UserErrors::InvalidRoleOperationWithMessage(
+                    "Only merchant users can manage invitations of other profiles".to_string()
+                )));
+            }
+
+            let key_manager_state = &state.into();
+            let key_store = state
+                .store
+                .get_merchant_key_store_by_merchant_id(
+                    key_manager_state,
+                    &merchant_id,
+                    &state.store.get_master_key().to_vec().into(),
+                )
+                .await
+                .change_context(UserErrors::InternalServerError)?;
+            state
+                .store
+                .find_business_profile_by_merchant_id_profile_id(
+                    key_manager_state,
+                    &key_store,
+                    &merchant_id,
+                    profile_id,
+                )
+                .await
+                .to_not_found_response(UserErrors::InvalidRoleOperationWithMessage(
+                    "Profile does not belong to the merchant account".to_string(),
+                ))?;
+
+            Some(profile_id.clone())
+        }
+        None if is_requestor_merchant => user_from_token.profile_id.clone(),
+        None => None,
+    };
+
+    Ok((merchant_id, profile_id))
+}
+
 pub async fn get_single_merchant_id_and_profile_id(
     state: &SessionState,
     user_role: &UserRole,
@@ -1,13 +1,28 @@
+#[cfg(all(feature = "v1", not(feature = "payment_methods_v2")))]
+pub mod account_updater;
 #[cfg(feature = "email")]
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod blocklist_sync;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod connector_credential_health;
+#[cfg(feature = "v1")]
+pub mod invoice_reminder;
+#[cfg(feature = "v1")]
+pub mod mandate_debit;
+pub mod merchant_account_purge;
 #[cfg(feature = "v1")]
 pub mod outgoing_webhook_retry;
 #[cfg(feature = "v1")]
+pub mod payment_confirm;
+#[cfg(feature = "v1")]
 pub mod payment_method_status_update;
+#[cfg(feature = "v1")]
 pub mod payment_sync;
 #[cfg(feature = "v1")]
 pub mod refund_router;
 #[cfg(feature = "v1")]
+pub mod sandbox_data_reset;
+#[cfg(feature = "v1")]
 pub mod tokenized_data;
@@ -0,0 +1,90 @@
+use common_utils::{date_time, ext_traits::ValueExt};
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{consumer::workflows::ProcessTrackerWorkflow, errors as sch_errors};
+
+use crate::{
+    core::payment_methods::account_updater, db::StorageInterface, errors, routes::SessionState,
+    types::storage,
+};
+
+/// Periodically refreshes the saved cards of a merchant through the account updater of the
+/// connectors, one batch of customers per run
+pub struct AccountUpdaterWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AccountUpdaterWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: account_updater::AccountUpdaterTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AccountUpdaterTrackingData")?;
+
+        // A failed run is retried with the next pass, starting over from the same batch
+        let outcome = account_updater::run_account_updater(state, &tracking_data)
+            .await
+            .unwrap_or_else(|error| {
+                logger::error!(?error, "Failed to run the account updater");
+                account_updater::AccountUpdaterRunOutcome::NextBatch {
+                    offset: tracking_data.offset,
+                }
+            });
+
+        let (offset, schedule_time) = match outcome {
+            account_updater::AccountUpdaterRunOutcome::NextBatch { offset }
+                if offset != tracking_data.offset =>
+            {
+                (offset, date_time::now())
+            }
+            account_updater::AccountUpdaterRunOutcome::NextBatch { offset } => {
+                (offset, account_updater::get_next_pass_time(state))
+            }
+            account_updater::AccountUpdaterRunOutcome::PassCompleted => {
+                (0, account_updater::get_next_pass_time(state))
+            }
+            account_updater::AccountUpdaterRunOutcome::Stopped => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+                return Ok(());
+            }
+        };
+
+        let tracking_data = serde_json::to_value(account_updater::AccountUpdaterTrackingData {
+            offset,
+            ..tracking_data
+        })
+        .map_err(|_| sch_errors::ProcessTrackerError::SerializationFailed)?;
+        db.as_scheduler()
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: None,
+                    schedule_time: Some(schedule_time),
+                    tracking_data: Some(tracking_data),
+                    business_status: None,
+                    status: Some(storage::enums::ProcessTrackerStatus::Pending),
+                    updated_at: Some(date_time::now()),
+                },
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        scheduler::consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+            .await
+    }
+}
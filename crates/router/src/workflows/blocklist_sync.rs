@@ -0,0 +1,65 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{consumer::workflows::ProcessTrackerWorkflow, errors as sch_errors};
+
+use crate::{
+    core::blocklist::sync, db::StorageInterface, errors, routes::SessionState, types::storage,
+};
+
+/// Periodically pulls the blocklist of a merchant from the source configured by the merchant
+pub struct BlocklistSyncWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for BlocklistSyncWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: sync::BlocklistSyncTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("BlocklistSyncTrackingData")?;
+
+        // Failures to fetch or apply the document are recorded on the source and retried with the
+        // next pull
+        let next_pull_time = sync::pull_blocklist_from_source(state, &tracking_data)
+            .await
+            .unwrap_or_else(|error| {
+                logger::error!(?error, "Failed to pull the blocklist from its source");
+                Some(
+                    common_utils::date_time::now()
+                        + time::Duration::seconds(i64::from(
+                            state.conf.blocklist_sync.min_pull_interval_in_secs,
+                        )),
+                )
+            });
+
+        match next_pull_time {
+            Some(next_pull_time) => {
+                db.as_scheduler()
+                    .retry_process(process, next_pull_time)
+                    .await?
+            }
+            None => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        scheduler::consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+            .await
+    }
+}
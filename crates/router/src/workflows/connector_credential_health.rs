@@ -0,0 +1,64 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{consumer::workflows::ProcessTrackerWorkflow, errors as sch_errors};
+
+use crate::{
+    core::connector_credential_health, db::StorageInterface, errors, routes::SessionState,
+    types::storage,
+};
+
+/// Periodically verifies the credentials of a merchant connector account, excluding the connector
+/// account from routing while its credentials are invalid
+pub struct ConnectorCredentialHealthWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ConnectorCredentialHealthWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: connector_credential_health::ConnectorCredentialHealthTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("ConnectorCredentialHealthTrackingData")?;
+
+        // Errors which aren't caused by the credentials, such as the connector being unreachable,
+        // don't change the health of the credentials and are retried with the next check
+        let should_check_again =
+            connector_credential_health::perform_credential_health_check(state, &tracking_data)
+                .await
+                .unwrap_or_else(|error| {
+                    logger::error!(?error, "Failed to verify the connector credentials");
+                    true
+                });
+
+        if should_check_again {
+            db.as_scheduler()
+                .retry_process(
+                    process,
+                    connector_credential_health::get_next_check_time(state),
+                )
+                .await?;
+        } else {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        scheduler::consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+            .await
+    }
+}
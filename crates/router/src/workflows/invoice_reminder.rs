@@ -0,0 +1,109 @@
+use api_models::enums::{EventType, InvoiceStatus};
+use common_utils::ext_traits::ValueExt;
+use diesel_models::{
+    enums as storage_enums, invoice::InvoiceReminderTrackingData, process_tracker::business_status,
+};
+use router_env::logger;
+use scheduler::{consumer::workflows::ProcessTrackerWorkflow, errors as sch_errors};
+
+use crate::{core::invoices, db::StorageInterface, errors, routes::SessionState, types::storage};
+
+/// Checks an invoice on the configured days around its due date, and sends reminder webhooks until
+/// it is paid in full
+pub struct InvoiceReminderWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for InvoiceReminderWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: InvoiceReminderTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("InvoiceReminderTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        // Refreshing the status sends the overdue webhook once the due date has passed
+        let invoice =
+            invoices::find_invoice(state, &tracking_data.merchant_id, &tracking_data.invoice_id)
+                .await?;
+        let invoice =
+            invoices::refresh_invoice_status(state, &merchant_account, &key_store, invoice).await?;
+        if invoice.status == InvoiceStatus::Paid {
+            return Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?);
+        }
+
+        let reminder_index = usize::try_from(process.retry_count)
+            .map_err(|_| sch_errors::ProcessTrackerError::TypeConversionError)?;
+        let is_due_date_check = tracking_data
+            .reminder_times
+            .get(reminder_index)
+            .is_some_and(|reminder_time| *reminder_time == tracking_data.due_date);
+        if !is_due_date_check {
+            invoices::send_invoice_webhook(
+                state,
+                &merchant_account,
+                &key_store,
+                &invoice,
+                EventType::InvoiceReminder,
+            )
+            .await?;
+            logger::info!(
+                invoice_id = %invoice.invoice_id,
+                status = %invoice.status,
+                "Invoice reminder sent"
+            );
+        }
+
+        match tracking_data.reminder_times.get(reminder_index + 1) {
+            Some(next_reminder_time) => {
+                let retry_count = process.retry_count + 1;
+                db.as_scheduler()
+                    .update_process(
+                        process,
+                        storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                            status: storage_enums::ProcessTrackerStatus::New,
+                            retry_count,
+                            schedule_time: *next_reminder_time,
+                        },
+                    )
+                    .await?;
+                Ok(())
+            }
+            None => Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?),
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        scheduler::consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+            .await
+    }
+}
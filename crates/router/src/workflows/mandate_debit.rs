@@ -0,0 +1,139 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::{
+    enums as storage_enums, mandate::MandateDebitTrackingData, process_tracker::business_status,
+};
+use router_env::logger;
+use scheduler::{consumer::workflows::ProcessTrackerWorkflow, errors as sch_errors};
+
+use crate::{
+    core::mandate::debits, db::StorageInterface, errors, routes::SessionState, types::storage,
+};
+
+/// Sends the pre-debit notification of a debit scheduled on an e-mandate, and then creates the
+/// recurring payment at the scheduled time. A failed debit is retried until the end of its retry
+/// window.
+pub struct MandateDebitWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for MandateDebitWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: MandateDebitTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MandateDebitTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        // The first run notifies the customer, the debit is attempted in the runs after it
+        if process.retry_count == 0 {
+            debits::send_pre_debit_notification(
+                state,
+                &merchant_account,
+                &key_store,
+                &tracking_data,
+            )
+            .await?;
+            logger::info!(
+                mandate_debit_id = %tracking_data.mandate_debit_id,
+                "Mandate pre-debit notification sent"
+            );
+            db.as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                        status: storage_enums::ProcessTrackerStatus::New,
+                        retry_count: 1,
+                        schedule_time: tracking_data.debit_at,
+                    },
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let status = match debits::execute_mandate_debit(
+            state,
+            merchant_account,
+            key_store,
+            &tracking_data,
+            process.retry_count,
+        )
+        .await
+        {
+            Ok(status) => status,
+            Err(error) => {
+                // Errors are raised before the payment reaches the connector, such as when the
+                // mandate has been revoked, and are not resolved by retrying the debit
+                logger::error!(?error, "Failed to create the payment of the mandate debit");
+                return Ok(db
+                    .as_scheduler()
+                    .finish_process_with_business_status(process, business_status::FAILURE)
+                    .await?);
+            }
+        };
+        logger::info!(
+            mandate_debit_id = %tracking_data.mandate_debit_id,
+            attempt = process.retry_count,
+            %status,
+            "Mandate debit attempted"
+        );
+        if status != storage_enums::IntentStatus::Failed {
+            return Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?);
+        }
+
+        match debits::get_next_retry_time(
+            &state.conf.e_mandate,
+            &tracking_data,
+            common_utils::date_time::now(),
+        ) {
+            Some(next_retry_time) => {
+                let retry_count = process.retry_count + 1;
+                db.as_scheduler()
+                    .update_process(
+                        process,
+                        storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                            status: storage_enums::ProcessTrackerStatus::New,
+                            retry_count,
+                            schedule_time: next_retry_time,
+                        },
+                    )
+                    .await?;
+                Ok(())
+            }
+            None => Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                .await?),
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        scheduler::consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+            .await
+    }
+}
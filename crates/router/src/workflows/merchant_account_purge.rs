@@ -0,0 +1,78 @@
+use api_models::enums::MerchantAccountStatus;
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use router_env::logger;
+use scheduler::{consumer::workflows::ProcessTrackerWorkflow, errors as sch_errors};
+
+use crate::{
+    core::{admin, merchant_account_lifecycle, merchant_key_lifecycle},
+    db::StorageInterface,
+    errors,
+    routes::SessionState,
+    types::{api, storage},
+};
+
+/// Purges a closed merchant account and its data once its retention period is over
+pub struct MerchantAccountPurgeWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for MerchantAccountPurgeWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: api::MerchantId =
+            process.tracking_data.clone().parse_value("MerchantId")?;
+        let merchant_id = tracking_data.merchant_id;
+
+        // Closing is terminal, but the account is never purged unless it is still closed
+        let record =
+            merchant_account_lifecycle::get_merchant_account_status(db, &merchant_id).await?;
+        if record.status != MerchantAccountStatus::Closed {
+            logger::warn!(
+                merchant_id = %merchant_id.get_string_repr(),
+                status = %record.status,
+                "Merchant account is not closed, skipping the purge"
+            );
+            return Ok(db
+                .as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?);
+        }
+
+        // The merchant account can not be decrypted once its key is destroyed, so it is deleted
+        // without being fetched
+        if merchant_key_lifecycle::get_key_destruction_record(db, &merchant_id)
+            .await?
+            .is_some()
+        {
+            db.delete_merchant_account_by_merchant_id(&merchant_id)
+                .await?;
+            merchant_key_lifecycle::delete_key_destruction_record(db, &merchant_id).await?;
+        } else {
+            admin::merchant_account_delete(state.clone(), merchant_id.clone()).await?;
+        }
+        merchant_account_lifecycle::delete_merchant_account_status(db, &merchant_id).await?;
+        logger::info!(
+            merchant_id = %merchant_id.get_string_repr(),
+            "Closed merchant account purged"
+        );
+
+        Ok(db
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+            .await?)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        scheduler::consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+            .await
+    }
+}
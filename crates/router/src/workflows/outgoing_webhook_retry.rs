@@ -65,13 +65,6 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             )
             .await?;
 
-        let event_id = webhooks_core::utils::generate_event_id();
-        let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
-            &tracking_data.primary_object_id,
-            tracking_data.event_type,
-            delivery_attempt,
-        );
-
         let initial_event = match &tracking_data.initial_attempt_id {
             Some(initial_attempt_id) => {
                 db.find_event_by_merchant_id_event_id(
@@ -99,6 +92,14 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             }
         };
 
+        let event_id = webhooks_core::utils::generate_event_id();
+        let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
+            &tracking_data.primary_object_id,
+            tracking_data.event_type,
+            delivery_attempt,
+            initial_event.webhook_endpoint_id.as_deref(),
+        );
+
         let now = common_utils::date_time::now();
         let new_event = domain::Event {
             event_id,
@@ -117,6 +118,7 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             response: None,
             delivery_attempt: Some(delivery_attempt),
             metadata: initial_event.metadata,
+            webhook_endpoint_id: initial_event.webhook_endpoint_id,
         };
 
         let event = db
@@ -174,6 +176,9 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             merchant_id: tracking_data.merchant_id.clone(),
                             event_id: event.event_id.clone(),
                             event_type,
+                            schema_version: webhooks_core::get_webhook_schema_version(
+                                &business_profile,
+                            ),
                             content: content.clone(),
                             timestamp: event.created_at,
                         };
@@ -351,7 +356,9 @@ async fn get_outgoing_webhook_content_and_event_type(
     use crate::{
         core::{
             disputes::retrieve_dispute,
-            mandate::get_mandate,
+            invoices,
+            mandate::{debits as mandate_debits, get_mandate},
+            payment_methods,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core_with_refund_id,
         },
@@ -404,6 +411,7 @@ async fn get_outgoing_webhook_content_and_event_type(
             .await?
             {
                 ApplicationResponse::Json(payments_response)
+                | ApplicationResponse::JsonAccepted(payments_response)
                 | ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
                     Ok(payments_response)
                 }
@@ -413,7 +421,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                 | ApplicationResponse::Form(_)
                 | ApplicationResponse::GenericLinkForm(_)
                 | ApplicationResponse::PaymentLinkForm(_)
-                | ApplicationResponse::FileData(_) => {
+                | ApplicationResponse::FileData(_)
+                | ApplicationResponse::FileStream(_) => {
                     Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                         resource_name: tracking_data.primary_object_id.clone(),
                     })
@@ -461,6 +470,7 @@ async fn get_outgoing_webhook_content_and_event_type(
             let dispute_response =
                 match retrieve_dispute(state, merchant_account, None, request).await? {
                     ApplicationResponse::Json(dispute_response)
+                    | ApplicationResponse::JsonAccepted(dispute_response)
                     | ApplicationResponse::JsonWithHeaders((dispute_response, _)) => {
                         Ok(dispute_response)
                     }
@@ -470,7 +480,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::FileStream(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -486,6 +497,28 @@ async fn get_outgoing_webhook_content_and_event_type(
             ))
         }
 
+        diesel_models::enums::EventClass::Mandates
+            if tracking_data.event_type == EventType::MandatePreDebitNotification =>
+        {
+            let mandate_debit = mandate_debits::find_mandate_debit(
+                &state,
+                merchant_account.get_id(),
+                &tracking_data.primary_object_id,
+            )
+            .await?;
+
+            // The notification is relevant only until the customer is debited
+            let event_type = (common_utils::date_time::now() < mandate_debit.debit_at)
+                .then_some(EventType::MandatePreDebitNotification);
+
+            Ok((
+                OutgoingWebhookContent::MandateDebitDetails(Box::new(
+                    mandate_debits::to_mandate_debit_response(mandate_debit),
+                )),
+                event_type,
+            ))
+        }
+
         diesel_models::enums::EventClass::Mandates => {
             let mandate_id = tracking_data.primary_object_id.clone();
             let request = MandateId { mandate_id };
@@ -493,6 +526,7 @@ async fn get_outgoing_webhook_content_and_event_type(
             let mandate_response =
                 match get_mandate(state, merchant_account, key_store, request).await? {
                     ApplicationResponse::Json(mandate_response)
+                    | ApplicationResponse::JsonAccepted(mandate_response)
                     | ApplicationResponse::JsonWithHeaders((mandate_response, _)) => {
                         Ok(mandate_response)
                     }
@@ -502,7 +536,8 @@ async fn get_outgoing_webhook_content_and_event_type(
                     | ApplicationResponse::Form(_)
                     | ApplicationResponse::GenericLinkForm(_)
                     | ApplicationResponse::PaymentLinkForm(_)
-                    | ApplicationResponse::FileData(_) => {
+                    | ApplicationResponse::FileData(_)
+                    | ApplicationResponse::FileStream(_) => {
                         Err(errors::ProcessTrackerError::ResourceFetchingFailed {
                             resource_name: tracking_data.primary_object_id.clone(),
                         })
@@ -552,5 +587,115 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::Invoices => {
+            let invoice = invoices::find_invoice(
+                &state,
+                merchant_account.get_id(),
+                &tracking_data.primary_object_id,
+            )
+            .await?;
+            let invoice =
+                invoices::refresh_invoice_status(&state, &merchant_account, &key_store, invoice)
+                    .await?;
+
+            // Reminders remain valid as long as the invoice is not paid in full
+            let event_type = match tracking_data.event_type {
+                EventType::InvoiceReminder
+                    if invoice.status != diesel_models::enums::InvoiceStatus::Paid =>
+                {
+                    Some(EventType::InvoiceReminder)
+                }
+                _ => Option::<EventType>::foreign_from(invoice.status),
+            };
+            logger::debug!(current_resource_status=%invoice.status);
+
+            Ok((
+                OutgoingWebhookContent::InvoiceDetails(Box::new(invoices::to_invoice_response(
+                    invoice,
+                )?)),
+                event_type,
+            ))
+        }
+
+        diesel_models::enums::EventClass::PaymentMethods
+            if tracking_data.event_type != EventType::PaymentMethodCollected =>
+        {
+            let payment_method = state
+                .store
+                .find_payment_method(
+                    &(&state).into(),
+                    &key_store,
+                    &tracking_data.primary_object_id,
+                    merchant_account.storage_scheme,
+                )
+                .await?;
+
+            // Card updates don't change the status of the payment method
+            let event_type = if tracking_data.event_type == EventType::PaymentMethodUpdated {
+                Some(EventType::PaymentMethodUpdated)
+            } else {
+                Option::<EventType>::foreign_from(payment_method.status)
+            };
+            logger::debug!(current_resource_status=%payment_method.status);
+
+            Ok((
+                OutgoingWebhookContent::PaymentMethodTokenDetails(Box::new(
+                    payment_methods::token_lifecycle::to_payment_method_token_response(
+                        &payment_method,
+                    ),
+                )),
+                event_type,
+            ))
+        }
+
+        diesel_models::enums::EventClass::PaymentMethods => {
+            let pm_collect_link = payment_methods::find_pm_collect_link(
+                &state,
+                merchant_account.get_id(),
+                &tracking_data.primary_object_id,
+            )
+            .await?;
+
+            let event_type = match pm_collect_link.link_status {
+                common_utils::link_utils::PaymentMethodCollectStatus::Submitted => {
+                    Some(EventType::PaymentMethodCollected)
+                }
+                common_utils::link_utils::PaymentMethodCollectStatus::Initiated
+                | common_utils::link_utils::PaymentMethodCollectStatus::Invalidated => None,
+            };
+            logger::debug!(current_resource_status=?pm_collect_link.link_status);
+
+            Ok((
+                OutgoingWebhookContent::PaymentMethodCollectLinkDetails(Box::new(
+                    payment_methods::to_pm_collect_link_response(pm_collect_link)?,
+                )),
+                event_type,
+            ))
+        }
+
+        // Orchestration decisions can not be derived from the current state of the payment, their
+        // events are always inserted along with the request content
+        diesel_models::enums::EventClass::Orchestration => {
+            Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: tracking_data.primary_object_id.clone(),
+            })
+        }
+
+        // The health of the credentials is only known at the time of the verification, their
+        // events are always inserted along with the request content
+        diesel_models::enums::EventClass::MerchantConnectors => {
+            Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: tracking_data.primary_object_id.clone(),
+            })
+        }
+
+        // The shipment records are looked up by the payment, which the capture identifying the
+        // event does not lead to, their events are always inserted along with the request content
+        diesel_models::enums::EventClass::Shipments => {
+            Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: tracking_data.primary_object_id.clone(),
+            })
+        }
     }
 }
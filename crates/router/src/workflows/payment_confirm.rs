@@ -0,0 +1,109 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors as sch_errors,
+};
+
+use crate::{
+    core::payments,
+    db::StorageInterface,
+    errors,
+    routes::{payments as payment_routes, SessionState},
+    services,
+    types::storage,
+};
+
+/// Number of times the task is retried when it fails before the payment could be confirmed
+const MAX_CONFIRM_TASK_RETRIES: i32 = 3;
+const CONFIRM_TASK_RETRY_DELAY_IN_SECS: i64 = 30;
+
+pub struct PaymentsConfirmWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentsConfirmWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: payments::PaymentsConfirmTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentsConfirmTrackingData")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        // The outcome of the confirmation is recorded on the payment and delivered through
+        // webhooks, the confirmation is not retried as the connector may have processed it
+        let business_status = match Box::pin(payment_routes::authorize_verify_select(
+            payments::PaymentConfirm,
+            state.clone(),
+            state.get_req_state(),
+            merchant_account,
+            tracking_data.profile_id.clone(),
+            key_store,
+            hyperswitch_domain_models::payments::HeaderPayload::default(),
+            tracking_data.into(),
+            services::AuthFlow::Merchant,
+        ))
+        .await
+        {
+            Ok(_) => business_status::COMPLETED_BY_PT,
+            Err(error) => {
+                logger::error!(?error, "Failed to confirm the payment asynchronously");
+                business_status::FAILURE
+            }
+        };
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Retries the task, as the errors surfaced here occur either before the payment is confirmed
+    /// or while recording the outcome of the confirmation. A confirmation retried after the
+    /// payment was confirmed is rejected by the status validation of the payment.
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        if process.retry_count >= MAX_CONFIRM_TASK_RETRIES {
+            return consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+                .await;
+        }
+
+        logger::error!(%process.id, ?error, "Failed while executing workflow, retrying");
+        let schedule_time = common_utils::date_time::now()
+            + time::Duration::seconds(
+                CONFIRM_TASK_RETRY_DELAY_IN_SECS * i64::from(process.retry_count + 1),
+            );
+        state
+            .store
+            .as_scheduler()
+            .retry_process(process, schedule_time)
+            .await
+            .change_context(sch_errors::ProcessTrackerError::ProcessUpdateFailed)
+    }
+}
@@ -0,0 +1,49 @@
+use common_enums::SandboxDataResetStatus;
+use common_utils::ext_traits::ValueExt;
+use diesel_models::process_tracker::business_status;
+use scheduler::{consumer::workflows::ProcessTrackerWorkflow, errors as sch_errors};
+
+use crate::{
+    core::sandbox_data, db::StorageInterface, errors, routes::SessionState, types::storage,
+};
+
+/// Deletes the test data of a sandbox merchant account, as requested through the data reset api
+pub struct SandboxDataResetWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for SandboxDataResetWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), sch_errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: sandbox_data::SandboxDataResetTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SandboxDataResetTrackingData")?;
+
+        // A failed reset is recorded on the reset itself and isn't retried, the merchant can
+        // request another one
+        let business_status =
+            match sandbox_data::execute_sandbox_data_reset(state, &tracking_data).await? {
+                Some(SandboxDataResetStatus::Failed) => business_status::FAILURE,
+                Some(_) | None => business_status::COMPLETED_BY_PT,
+            };
+
+        Ok(db
+            .as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await?)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: sch_errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), sch_errors::ProcessTrackerError> {
+        scheduler::consumer::consumer_error_handler(state.store.as_scheduler(), process, error)
+            .await
+    }
+}
@@ -120,12 +120,14 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
         payment_method_balance: None,
         connector_api_version: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         external_latency: None,
         frm_metadata: None,
         refund_id: None,
         dispute_id: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -190,12 +192,14 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
         payment_method_balance: None,
         connector_api_version: None,
         connector_http_status_code: None,
+        connector_request_id: None,
         apple_pay_flow: None,
         external_latency: None,
         frm_metadata: None,
         refund_id: None,
         dispute_id: None,
         integrity_check: Ok(()),
+        connector_options: None,
         additional_merchant_data: None,
         header_payload: None,
         connector_mandate_request_reference_id: None,
@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use futures::future::OptionFuture;
 use masking::Secret;
-use router::types::{self, domain, storage::enums};
+use router::types::{self, api, domain, storage::enums};
 use serial_test::serial;
 
 use crate::{
@@ -37,6 +37,28 @@ impl utils::Connector for Rapyd {
     }
 }
 
+// Rapyd identifies card payment methods by the country of the billing address
+fn get_payment_info() -> PaymentInfo {
+    PaymentInfo {
+        address: Some(types::PaymentAddress::new(
+            None,
+            None,
+            Some(api::Address {
+                address: Some(api::AddressDetails {
+                    first_name: Some(Secret::new("John".to_string())),
+                    last_name: Some(Secret::new("Doe".to_string())),
+                    country: Some(api_models::enums::CountryAlpha2::US),
+                    ..Default::default()
+                }),
+                phone: None,
+                email: None,
+            }),
+            None,
+        )),
+        ..Default::default()
+    }
+}
+
 #[actix_web::test]
 async fn should_only_authorize_payment() {
     let response = Rapyd {}
@@ -57,7 +79,7 @@ async fn should_only_authorize_payment() {
                 capture_method: Some(diesel_models::enums::CaptureMethod::Manual),
                 ..utils::PaymentAuthorizeType::default().0
             }),
-            Some(PaymentInfo::with_default_billing_name()),
+            Some(get_payment_info()),
         )
         .await
         .unwrap();
@@ -83,7 +105,7 @@ async fn should_authorize_and_capture_payment() {
                 }),
                 ..utils::PaymentAuthorizeType::default().0
             }),
-            Some(PaymentInfo::with_default_billing_name()),
+            Some(get_payment_info()),
         )
         .await
         .unwrap();
@@ -94,7 +116,7 @@ async fn should_authorize_and_capture_payment() {
 async fn should_capture_already_authorized_payment() {
     let connector = Rapyd {};
     let authorize_response = connector
-        .authorize_payment(None, Some(PaymentInfo::with_default_billing_name()))
+        .authorize_payment(None, Some(get_payment_info()))
         .await
         .unwrap();
     assert_eq!(authorize_response.status, enums::AttemptStatus::Authorized);
@@ -116,7 +138,7 @@ async fn should_capture_already_authorized_payment() {
 async fn voiding_already_authorized_payment_fails() {
     let connector = Rapyd {};
     let authorize_response = connector
-        .authorize_payment(None, Some(PaymentInfo::with_default_billing_name()))
+        .authorize_payment(None, Some(get_payment_info()))
         .await
         .unwrap();
     assert_eq!(authorize_response.status, enums::AttemptStatus::Authorized);
@@ -137,7 +159,10 @@ async fn voiding_already_authorized_payment_fails() {
 async fn should_refund_succeeded_payment() {
     let connector = Rapyd {};
     //make a successful payment
-    let response = connector.make_payment(None, None).await.unwrap();
+    let response = connector
+        .make_payment(None, Some(get_payment_info()))
+        .await
+        .unwrap();
 
     //try refund for previous payment
     if let Some(transaction_id) = utils::get_connector_transaction_id(response.response) {
@@ -1,7 +1,11 @@
 use std::str::FromStr;
 
 use masking::Secret;
-use router::types::{self, domain, storage::enums};
+use router::{
+    configs::settings::Settings,
+    headers, services,
+    types::{self, domain, storage::enums},
+};
 
 use crate::{
     connector_auth,
@@ -54,6 +58,34 @@ async fn should_only_authorize_payment() {
     assert_eq!(response.status, enums::AttemptStatus::Authorized);
 }
 
+// The authorize flow prebuilds the connector request, which must carry the idempotency key before
+// it is first sent to the connector
+#[actix_web::test]
+async fn should_attach_idempotency_key_to_prebuilt_request() {
+    let connector = Stripe {};
+    let integration: services::BoxedPaymentConnectorIntegrationInterface<
+        types::api::Authorize,
+        types::PaymentsAuthorizeData,
+        types::PaymentsResponseData,
+    > = utils::Connector::get_data(&connector)
+        .connector
+        .get_connector_integration();
+    let request_data = connector.generate_data(get_payment_authorize_data().unwrap(), None);
+    let prebuilt_request = integration
+        .build_request(&request_data, &Settings::new().unwrap().connectors)
+        .unwrap()
+        .unwrap();
+
+    let request =
+        services::api::attach_idempotency_header(&integration, &request_data, prebuilt_request)
+            .unwrap();
+
+    assert!(request.headers.contains(&(
+        headers::IDEMPOTENCY_KEY.to_string(),
+        format!("{}_Authorize", request_data.attempt_id).into(),
+    )));
+}
+
 #[actix_web::test]
 async fn should_make_payment() {
     let response = Stripe {}
@@ -48,6 +48,8 @@ fn get_default_browser_info() -> BrowserInformation {
         accept_header: Some("*".to_string()),
         user_agent: Some("none".to_string()),
         ip_address: None,
+        origin: None,
+        ip_country: None,
     }
 }
 
@@ -537,6 +537,7 @@ pub trait ConnectorActions: Connector {
             payment_method_balance: None,
             connector_api_version: None,
             connector_http_status_code: None,
+            connector_request_id: None,
             apple_pay_flow: None,
             external_latency: None,
             frm_metadata: None,
@@ -544,6 +545,7 @@ pub trait ConnectorActions: Connector {
             dispute_id: None,
             connector_response: None,
             integrity_check: Ok(()),
+            connector_options: None,
             additional_merchant_data: None,
             header_payload: None,
             connector_mandate_request_reference_id: None,
@@ -949,6 +951,8 @@ impl Default for PaymentAuthorizeType {
             integrity_object: None,
             merchant_order_reference_id: None,
             additional_payment_method_data: None,
+            connector_passthrough_data: None,
+            merchant_category_code: None,
             shipping_cost: None,
         };
         Self(data)
@@ -990,6 +994,8 @@ impl Default for BrowserInfoType {
             java_enabled: Some(true),
             java_script_enabled: Some(true),
             ip_address: Some("127.0.0.1".parse().unwrap()),
+            origin: None,
+            ip_country: None,
         };
         Self(data)
     }
@@ -170,6 +170,7 @@ async fn payments_create_core() {
         error_message: None,
         unified_code: None,
         unified_message: None,
+        display_message: None,
         payment_experience: None,
         payment_method_type: None,
         connector_label: None,
@@ -207,7 +208,12 @@ async fn payments_create_core() {
         merchant_order_reference_id: None,
         order_tax_amount: None,
         connector_mandate_id: None,
+        receipt: None,
         shipping_cost: None,
+        connector_fee_amount: None,
+        connector_fee_currency: None,
+        connector_request_id: None,
+        correlation_id: None,
     };
 
     let expected_response =
@@ -438,6 +444,7 @@ async fn payments_create_core_adyen_no_redirect() {
             error_message: None,
             unified_code: None,
             unified_message: None,
+            display_message: None,
             payment_experience: None,
             payment_method_type: None,
             connector_label: None,
@@ -475,7 +482,12 @@ async fn payments_create_core_adyen_no_redirect() {
             merchant_order_reference_id: None,
             order_tax_amount: None,
             connector_mandate_id: None,
+            receipt: None,
             shipping_cost: None,
+            connector_fee_amount: None,
+            connector_fee_currency: None,
+            connector_request_id: None,
+            correlation_id: None,
         },
         vec![],
     ));
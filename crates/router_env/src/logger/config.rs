@@ -54,6 +54,21 @@ impl Level {
     }
 }
 
+impl From<tracing::Level> for Level {
+    fn from(level: tracing::Level) -> Self {
+        Self(level)
+    }
+}
+
+impl serde::Serialize for Level {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0.as_str().to_lowercase())
+    }
+}
+
 impl<'de> Deserialize<'de> for Level {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
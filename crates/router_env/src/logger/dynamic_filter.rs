@@ -0,0 +1,239 @@
+//!
+//! Log levels and sampling which can be adjusted at runtime.
+//!
+
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    hash::BuildHasher,
+    sync::{PoisonError, RwLock},
+};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use tracing::{span, subscriber::Interest, Metadata, Subscriber};
+use tracing_subscriber::{
+    layer::{Context, Filter},
+    registry::LookupSpan,
+};
+
+use super::{config::Level, storage::Storage};
+
+static DYNAMIC_LOG_CONFIG: Lazy<RwLock<DynamicLogConfig>> =
+    Lazy::new(|| RwLock::new(DynamicLogConfig::default()));
+
+/// Log levels and sampling which override the configured filtering directives at runtime.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DynamicLogConfig {
+    /// Log levels of modules, keyed by the module path, for example `router::core::payments`.
+    pub module_levels: HashMap<String, Level>,
+    /// Log levels of the requests of merchants, keyed by the merchant ID.
+    pub merchant_levels: HashMap<String, Level>,
+    /// Fraction of the info logs which are retained, between 0 and 1.
+    pub info_sample_rate: f64,
+}
+
+impl Default for DynamicLogConfig {
+    fn default() -> Self {
+        Self {
+            module_levels: HashMap::new(),
+            merchant_levels: HashMap::new(),
+            info_sample_rate: 1.0,
+        }
+    }
+}
+
+impl DynamicLogConfig {
+    /// Returns the current log levels and sampling.
+    pub fn get() -> Self {
+        DYNAMIC_LOG_CONFIG
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Replaces the current log levels and sampling.
+    pub fn set(config: Self) {
+        *DYNAMIC_LOG_CONFIG
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = config;
+
+        // The interest of the callsites depends on the config, the lock must be released first as
+        // the callsites are registered again with the filter
+        tracing::callsite::rebuild_interest_cache();
+    }
+
+    fn is_empty(&self) -> bool {
+        self.module_levels.is_empty() && self.merchant_levels.is_empty()
+    }
+
+    /// The level of the longest module path matching the target.
+    fn get_module_level(&self, metadata: &Metadata<'_>) -> Option<Level> {
+        self.module_levels
+            .iter()
+            .filter(|(module, _)| {
+                metadata
+                    .target()
+                    .strip_prefix(module.as_str())
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .max_by_key(|(module, _)| module.len())
+            .map(|(_, level)| *level)
+    }
+
+    /// Whether the callsite can be enabled by the level of a merchant, which depends on the span
+    /// the callsite is reached from.
+    fn is_enabled_by_merchant_level(&self, metadata: &Metadata<'_>) -> bool {
+        self.merchant_levels
+            .values()
+            .any(|level| *metadata.level() <= level.into_level())
+    }
+
+    fn is_sampled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.is_event()
+            && *metadata.level() == tracing::Level::INFO
+            && self.info_sample_rate < 1.0
+    }
+
+    /// The most verbose level overriding the configured filtering directives, out of the level of
+    /// the longest module path matching the target, and the level of the current merchant.
+    fn get_override_level<S>(&self, metadata: &Metadata<'_>, cx: &Context<'_, S>) -> Option<Level>
+    where
+        S: Subscriber + for<'a> LookupSpan<'a>,
+    {
+        let module_level = self.get_module_level(metadata);
+
+        let merchant_level = if self.merchant_levels.is_empty() {
+            None
+        } else {
+            cx.lookup_current().and_then(|span| {
+                span.scope().find_map(|span| {
+                    span.extensions()
+                        .get::<Storage<'_>>()
+                        .and_then(|storage| storage.values.get("merchant_id"))
+                        .and_then(|merchant_id| merchant_id.as_str())
+                        .and_then(|merchant_id| self.merchant_levels.get(merchant_id))
+                        .copied()
+                })
+            })
+        };
+
+        match (module_level, merchant_level) {
+            (Some(module_level), Some(merchant_level)) => {
+                Some(if module_level.into_level() > merchant_level.into_level() {
+                    module_level
+                } else {
+                    merchant_level
+                })
+            }
+            (level, None) | (None, level) => level,
+        }
+    }
+
+    fn should_sample(&self, metadata: &Metadata<'_>) -> bool {
+        !self.is_sampled(metadata) || random_fraction() < self.info_sample_rate
+    }
+}
+
+thread_local! {
+    static RANDOM_STATE: Cell<u64> = Cell::new(
+        // Seeded differently for every thread, the state must never be zero
+        std::collections::hash_map::RandomState::new().hash_one(std::thread::current().id()) | 1,
+    );
+}
+
+/// A pseudo random fraction between 0 and 1, good enough for sampling logs.
+fn random_fraction() -> f64 {
+    RANDOM_STATE.with(|state| {
+        // xorshift64
+        let mut x = state.get();
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        state.set(x);
+
+        #[allow(clippy::as_conversions)]
+        let fraction = (x >> 11) as f64 / (1u64 << 53) as f64;
+        fraction
+    })
+}
+
+/// Filter which applies the [`DynamicLogConfig`] on top of the wrapped filter.
+///
+/// Spans and events enabled by a module or merchant log level are recorded regardless of the
+/// wrapped filter, all other spans and events are filtered by the wrapped filter, and info events
+/// are sampled.
+#[derive(Debug)]
+pub struct DynamicFilter<F> {
+    inner: F,
+}
+
+impl<F> DynamicFilter<F> {
+    /// Wraps the filter.
+    pub fn new(inner: F) -> Self {
+        Self { inner }
+    }
+}
+
+impl<S, F> Filter<S> for DynamicFilter<F>
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    F: Filter<S>,
+{
+    fn enabled(&self, metadata: &Metadata<'_>, cx: &Context<'_, S>) -> bool {
+        let config = DYNAMIC_LOG_CONFIG
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if !config.is_empty() {
+            if let Some(level) = config.get_override_level(metadata, cx) {
+                return *metadata.level() <= level.into_level();
+            }
+        }
+
+        self.inner.enabled(metadata, cx) && config.should_sample(metadata)
+    }
+
+    fn callsite_enabled(&self, metadata: &'static Metadata<'static>) -> Interest {
+        let inner = self.inner.callsite_enabled(metadata);
+        let config = DYNAMIC_LOG_CONFIG
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        // Only the callsites whose filtering depends on the current merchant or on sampling are
+        // evaluated on every hit, the interest of all other callsites is cached until the config
+        // changes
+        if config.is_enabled_by_merchant_level(metadata)
+            || (config.is_sampled(metadata) && !inner.is_never())
+        {
+            return Interest::sometimes();
+        }
+
+        match config.get_module_level(metadata) {
+            Some(level) if *metadata.level() <= level.into_level() => Interest::always(),
+            Some(_) => Interest::never(),
+            None => inner,
+        }
+    }
+
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        self.inner.on_new_span(attrs, id, ctx)
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: Context<'_, S>) {
+        self.inner.on_record(id, values, ctx)
+    }
+
+    fn on_enter(&self, id: &span::Id, ctx: Context<'_, S>) {
+        self.inner.on_enter(id, ctx)
+    }
+
+    fn on_exit(&self, id: &span::Id, ctx: Context<'_, S>) {
+        self.inner.on_exit(id, ctx)
+    }
+
+    fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+        self.inner.on_close(id, ctx)
+    }
+}
@@ -21,3 +21,6 @@ pub use formatter::FormattingLayer;
 
 pub mod storage;
 pub use storage::{Storage, StorageSubscription};
+
+pub mod dynamic_filter;
+pub use dynamic_filter::{DynamicFilter, DynamicLogConfig};
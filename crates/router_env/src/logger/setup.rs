@@ -21,7 +21,7 @@ use serde_json::ser::{CompactFormatter, PrettyFormatter};
 use tracing_appender::non_blocking::WorkerGuard;
 use tracing_subscriber::{fmt, prelude::*, util::SubscriberInitExt, EnvFilter, Layer};
 
-use crate::{config, FormattingLayer, StorageSubscription};
+use crate::{config, DynamicFilter, FormattingLayer, StorageSubscription};
 
 /// Contains guards necessary for logging and metrics collection.
 #[derive(Debug)]
@@ -72,7 +72,7 @@ pub fn setup(
         );
         println!("Using file logging filter: {file_filter}");
         let layer = FormattingLayer::new(service_name, file_writer, CompactFormatter)?
-            .with_filter(file_filter);
+            .with_filter(DynamicFilter::new(file_filter));
         Some(layer)
     } else {
         None
@@ -95,6 +95,7 @@ pub fn setup(
             &crates_to_filter,
         );
         println!("Using console logging filter: {console_filter}");
+        let console_filter = DynamicFilter::new(console_filter);
 
         match config.console.log_format {
             config::LogFormat::Default => {
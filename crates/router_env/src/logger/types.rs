@@ -75,6 +75,18 @@ pub enum Flow {
     MerchantsAccountUpdate,
     /// Merchants account delete flow.
     MerchantsAccountDelete,
+    /// Merchants account status retrieve flow.
+    MerchantsAccountStatusRetrieve,
+    /// Merchants account status update flow.
+    MerchantsAccountStatusUpdate,
+    /// Merchants external vault retrieve flow.
+    MerchantsExternalVaultRetrieve,
+    /// Merchants external vault update flow.
+    MerchantsExternalVaultUpdate,
+    /// Merchants key retrieve flow.
+    MerchantsKeyRetrieve,
+    /// Merchants key destroy flow.
+    MerchantsKeyDestroy,
     /// Merchant Connectors create flow.
     MerchantConnectorsCreate,
     /// Merchant Connectors retrieve flow.
@@ -87,6 +99,12 @@ pub enum Flow {
     MerchantConnectorsDelete,
     /// Merchant Connectors list flow.
     MerchantConnectorsList,
+    /// Merchant Connector options update flow.
+    MerchantConnectorOptionsUpdate,
+    /// Merchant Connector options retrieve flow.
+    MerchantConnectorOptionsRetrieve,
+    /// Merchant Connector options delete flow.
+    MerchantConnectorOptionsDelete,
     /// Merchant Transfer Keys
     MerchantTransferKey,
     /// ConfigKey create flow.
@@ -117,6 +135,8 @@ pub enum Flow {
     MandatesRevoke,
     /// Mandates list flow.
     MandatesList,
+    /// Mandate debit schedule flow.
+    MandatesScheduleDebit,
     /// Payment methods create flow.
     PaymentMethodsCreate,
     /// Payment methods migrate flow.
@@ -133,12 +153,22 @@ pub enum Flow {
     ListCountriesCurrencies,
     /// Payment method create collect link flow.
     PaymentMethodCollectLink,
+    /// Payment method collect link retrieve flow.
+    PaymentMethodCollectLinkRetrieve,
+    /// Payment method collect link submit flow.
+    PaymentMethodCollectLinkSubmit,
     /// Payment methods retrieve flow.
     PaymentMethodsRetrieve,
     /// Payment methods update flow.
     PaymentMethodsUpdate,
     /// Payment methods delete flow.
     PaymentMethodsDelete,
+    /// Payment methods network token provision flow.
+    PaymentMethodsNetworkTokenProvision,
+    /// Payment methods account update flow.
+    PaymentMethodsAccountUpdate,
+    /// Account updater schedule flow.
+    AccountUpdaterSchedule,
     /// Default Payment method flow.
     DefaultPaymentMethodsSet,
     /// Payments create flow.
@@ -153,6 +183,10 @@ pub enum Flow {
     PaymentsConfirm,
     /// Payments capture flow.
     PaymentsCapture,
+    /// Payments shipment capture flow.
+    PaymentsShipmentCapture,
+    /// Payments shipment list flow.
+    PaymentsShipmentList,
     /// Payments cancel flow.
     PaymentsCancel,
     /// Payments approve flow.
@@ -165,10 +199,14 @@ pub enum Flow {
     PaymentsStart,
     /// Payments list flow.
     PaymentsList,
+    /// Payments export flow.
+    PaymentsExport,
     /// Payments filters flow
     PaymentsFilters,
     /// Payments aggregates flow
     PaymentsAggregate,
+    /// Purchase retrieve flow
+    PurchaseRetrieve,
     /// Payments Create Intent flow
     PaymentsCreateIntent,
     /// Payments Get Intent flow
@@ -214,6 +252,8 @@ pub enum Flow {
     RefundsUpdate,
     /// Refunds list flow.
     RefundsList,
+    /// Refunds export flow.
+    RefundsExport,
     /// Refunds filters flow
     RefundsFilters,
     /// Refunds aggregates flow
@@ -252,6 +292,16 @@ pub enum Flow {
     ToggleDynamicRouting,
     /// Update dynamic routing config
     UpdateDynamicRoutingConfigs,
+    /// Create a routing experiment on a business profile
+    ExperimentCreate,
+    /// List the routing experiments of a business profile
+    ExperimentList,
+    /// Retrieve a routing experiment along with its results
+    ExperimentRetrieve,
+    /// Stop a routing experiment
+    ExperimentStop,
+    /// Promote an arm of a routing experiment to the routing configuration of the profile
+    ExperimentPromote,
     /// Add record to blocklist
     AddToBlocklist,
     /// Delete record from blocklist
@@ -260,8 +310,18 @@ pub enum Flow {
     ListBlocklist,
     /// Toggle blocklist for merchant
     ToggleBlocklistGuard,
+    /// Synchronize the blocklist with an external risk system
+    BlocklistSync,
+    /// Retrieve the synchronization status of the blocklist
+    BlocklistSyncStatus,
+    /// Roll the blocklist back to a previous version
+    BlocklistSyncRollback,
+    /// Configure the source from which the blocklist is pulled
+    BlocklistSyncSourceUpdate,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
+    /// Network token lifecycle webhook receive flow
+    NetworkTokenLifecycleWebhook,
     /// Validate payment method flow
     ValidatePaymentMethod,
     /// API Key create flow
@@ -298,6 +358,8 @@ pub enum Flow {
     DeleteDisputeEvidence,
     /// Disputes aggregate flow
     DisputesAggregate,
+    /// Disputes evidence effectiveness flow
+    DisputesEvidenceEffectiveness,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
     /// Invalidate cache flow
@@ -312,6 +374,8 @@ pub enum Flow {
     PaymentLinkList,
     /// Payment Link Status
     PaymentLinkStatus,
+    /// Payment Link Preview flow
+    PaymentLinkPreview,
     /// Create a profile
     ProfileCreate,
     /// Update a profile
@@ -422,6 +486,8 @@ pub enum Flow {
     AcceptInviteFromEmail,
     /// Delete user role
     DeleteUserRole,
+    /// Revoke pending invitation of a user
+    RevokeInvitation,
     /// Incremental Authorization flow
     PaymentsIncrementalAuthorization,
     /// Get action URL for connector onboarding
@@ -466,6 +532,10 @@ pub enum Flow {
     TerminateTwoFactorAuth,
     /// Check 2FA status
     TwoFactorAuthStatus,
+    /// Get the 2FA policy of an organization
+    GetTwoFactorAuthPolicy,
+    /// Update the 2FA policy of an organization
+    UpdateTwoFactorAuthPolicy,
     /// Create user authentication method
     CreateUserAuthenticationMethod,
     /// Update user authentication method
@@ -476,6 +546,8 @@ pub enum Flow {
     GetSsoAuthUrl,
     /// Signin with SSO
     SignInWithSso,
+    /// Receive the response of a SAML identity provider
+    SamlAssertionConsumerService,
     /// Auth Select
     AuthSelect,
     /// List Orgs for user
@@ -496,6 +568,94 @@ pub enum Flow {
     WebhookEventDeliveryRetry,
     /// Retrieve status of the Poll
     RetrievePollStatus,
+    /// List the API logs of a merchant
+    ApiLogsList,
+    /// List the captured connector calls of a payment
+    ConnectorDebugCaptureList,
+    /// Export the support bundle of a payment
+    PaymentSupportBundle,
+    /// Apply a declarative configuration of a merchant account
+    DeclarativeConfigApply,
+    /// Retrieve the payment metadata schema of a merchant
+    PaymentMetadataSchemaRetrieve,
+    /// Register the payment metadata schema of a merchant
+    PaymentMetadataSchemaUpdate,
+    /// Delete the payment metadata schema of a merchant
+    PaymentMetadataSchemaDelete,
+    /// List the message bundles of a merchant
+    MessageBundlesList,
+    /// Create or update the message bundle of a merchant for a locale
+    MessageBundleUpsert,
+    /// Delete the message bundle of a merchant for a locale
+    MessageBundleDelete,
+    /// Download the receipt of a payment
+    PaymentReceiptDownload,
+    /// Create an invoice
+    InvoiceCreate,
+    /// Retrieve an invoice
+    InvoiceRetrieve,
+    /// List the invoices of a merchant
+    InvoiceList,
+    /// Link payments to an invoice
+    InvoiceLinkPayments,
+    /// Create a test clock
+    TestClockCreate,
+    /// Retrieve a test clock
+    TestClockRetrieve,
+    /// Attach resources to a test clock
+    TestClockAttach,
+    /// Advance a test clock
+    TestClockAdvance,
+    /// Reset the data of a sandbox merchant account
+    SandboxDataReset,
+    /// Retrieve the data reset of a sandbox merchant account
+    SandboxDataResetRetrieve,
+    /// Opt a sandbox merchant account into the demo connectors
+    DemoConnectorsOptIn,
+    /// Retrieve the demo connectors of a sandbox merchant account
+    DemoConnectorsRetrieve,
+    /// Execute a GraphQL query
+    GraphQLQuery,
+    /// Retrieve the dynamic log config
+    LogConfigRetrieve,
+    /// Update the dynamic log config
+    LogConfigUpdate,
+    /// Retrieve the outage status of a connector
+    ConnectorOutageRetrieve,
+    /// Override the outage status of a connector
+    ConnectorOutageUpdate,
+    /// Retrieve the capabilities of a connector
+    ConnectorCapabilitiesRetrieve,
+    /// Retrieve the simulation mode of a merchant
+    SimulationModeRetrieve,
+    /// Update the simulation mode of a merchant
+    SimulationModeUpdate,
+    /// Start a load test
+    LoadTestCreate,
+    /// Retrieve a load test
+    LoadTestRetrieve,
+    /// Create a webhook endpoint of a business profile
+    WebhookEndpointCreate,
+    /// List the webhook endpoints of a business profile
+    WebhookEndpointList,
+    /// Retrieve a webhook endpoint of a business profile
+    WebhookEndpointRetrieve,
+    /// Update a webhook endpoint of a business profile
+    WebhookEndpointUpdate,
+    /// Rotate the signing secret of a webhook endpoint
+    WebhookEndpointSecretRotate,
+    /// Delete a webhook endpoint of a business profile
+    WebhookEndpointDelete,
+    /// Retrieve the delivery metrics of a webhook endpoint
+    WebhookEndpointMetricsRetrieve,
+    /// List the delivery attempts of a webhook endpoint
+    WebhookEndpointDeliveryAttemptsList,
+    /// Retrieve the payment descriptors of a business profile
+    PaymentDescriptorConfigRetrieve,
+    /// Update the payment descriptors of a business profile
+    PaymentDescriptorConfigUpdate,
+    /// Preview the soft descriptor of a business profile per card network
+    SoftDescriptorPreview,
     /// Toggles the extended card info feature in profile level
     ToggleExtendedCardInfo,
     /// Toggles the extended card info feature in profile level
@@ -504,8 +664,24 @@ pub enum Flow {
     GetExtendedCardInfo,
     /// Manually update the refund details like status, error code, error message etc.
     RefundsManualUpdate,
+    /// Refund a payment to a bank account through a payout
+    RefundsTenderOverrideCreate,
+    /// List the refunds of a payment made to bank accounts
+    RefundsTenderOverrideList,
     /// Manually update the payment details like status, error code, error message etc.
     PaymentsManualUpdate,
+    /// Import payments processed outside of hyperswitch
+    ExternalPaymentsImport,
+    /// Retrieve the balance of the account at a connector
+    ConnectorBalanceRetrieve,
+    /// Retrieve the routing decisions made for the attempts of a payment
+    PaymentsRoutingDecision,
+    /// Create a scoped client token for a payment
+    PaymentsClientTokenCreate,
+    /// Revoke the client tokens of a payment and issue a new one
+    PaymentsClientTokenRotate,
+    /// Check the balance remaining on a gift card before confirming a payment
+    PaymentsGiftCardBalanceCheck,
     /// Dynamic Tax Calcultion
     SessionUpdateTaxCalculation,
     /// Payments confirm intent
@@ -19,6 +19,17 @@ impl Default for super::settings::ProducerSettings {
             lock_key: "PRODUCER_LOCKING_KEY".into(),
             lock_ttl: 160,
             batch_size: 200,
+            leader_election: super::settings::LeaderElectionSettings::default(),
+        }
+    }
+}
+
+impl Default for super::settings::LeaderElectionSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            lease_key: "PRODUCER_LEADER_KEY".into(),
+            lease_ttl: 60,
         }
     }
 }
@@ -29,6 +29,18 @@ pub struct ProducerSettings {
     pub lock_key: String,
     pub lock_ttl: i64,
     pub batch_size: usize,
+
+    pub leader_election: LeaderElectionSettings,
+}
+
+/// Election of a single producer leader among the instances run for high availability. The other
+/// instances stand by, and one of them takes over once the lease of the leader expires.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct LeaderElectionSettings {
+    pub enabled: bool,
+    pub lease_key: String,
+    pub lease_ttl: i64, // in seconds
 }
 
 #[derive(Debug, Clone, Deserialize)]
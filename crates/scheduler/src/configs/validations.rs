@@ -19,6 +19,19 @@ impl super::settings::SchedulerSettings {
 
         self.producer.validate()?;
 
+        // The leader renews its lease every loop, a shorter lease would expire in between
+        when(
+            self.producer.leader_election.enabled
+                && self.producer.leader_election.lease_ttl.saturating_mul(1000)
+                    <= i64::try_from(self.loop_interval).unwrap_or(i64::MAX),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "producer leader lease ttl must be longer than the scheduler loop interval"
+                        .into(),
+                ))
+            },
+        )?;
+
         self.server.validate()?;
 
         Ok(())
@@ -31,6 +44,30 @@ impl super::settings::ProducerSettings {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "producer lock key must not be empty".into(),
             ))
+        })?;
+
+        self.leader_election.validate()
+    }
+}
+
+impl super::settings::LeaderElectionSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if !self.enabled {
+            return Ok(());
+        }
+
+        when(self.lease_key.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "producer leader lease key must not be empty".into(),
+            ))
+        })?;
+
+        when(self.lease_ttl <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "producer leader lease ttl must be greater than 0".into(),
+            ))
         })
     }
 }
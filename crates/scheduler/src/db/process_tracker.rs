@@ -62,6 +62,16 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_processes_by_id_suffix_time_status(
+        &self,
+        id_suffix: &str,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: storage_enums::ProcessTrackerStatus,
+        after_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -106,6 +116,29 @@ impl ProcessTrackerInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    async fn find_processes_by_id_suffix_time_status(
+        &self,
+        id_suffix: &str,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: storage_enums::ProcessTrackerStatus,
+        after_id: Option<String>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_processes_by_id_suffix_time_status(
+            &conn,
+            id_suffix,
+            time_lower_limit,
+            time_upper_limit,
+            status,
+            after_id,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -230,6 +263,18 @@ impl ProcessTrackerInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_processes_by_id_suffix_time_status(
+        &self,
+        _id_suffix: &str,
+        _time_lower_limit: PrimitiveDateTime,
+        _time_upper_limit: PrimitiveDateTime,
+        _status: storage_enums::ProcessTrackerStatus,
+        _after_id: Option<String>,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -6,6 +6,28 @@ use storage_impl::{mock_db::MockDb, redis::kv_store::RedisConnInterface};
 
 use crate::{errors::ProcessTrackerError, scheduler::Store};
 
+/// Sets the lease when it is not held, or extends it when it is held by the instance, so that a
+/// lease which expired and was acquired by another instance in the meantime is never extended
+const ACQUIRE_OR_RENEW_LEADER_LEASE_SCRIPT: &str = r#"
+local leader = redis.call("GET", KEYS[1])
+if not leader then
+    redis.call("SET", KEYS[1], ARGV[1], "EX", ARGV[2])
+    return 1
+elseif leader == ARGV[1] then
+    redis.call("EXPIRE", KEYS[1], ARGV[2])
+    return 1
+end
+return 0
+"#;
+
+/// Deletes the lease only when it is held by the instance
+const RELEASE_LEADER_LEASE_SCRIPT: &str = r#"
+if redis.call("GET", KEYS[1]) == ARGV[1] then
+    return redis.call("DEL", KEYS[1])
+end
+return 0
+"#;
+
 #[async_trait::async_trait]
 pub trait QueueInterface {
     async fn fetch_consumer_tasks(
@@ -32,6 +54,22 @@ pub trait QueueInterface {
 
     async fn release_pt_lock(&self, tag: &str, lock_key: &str) -> CustomResult<bool, RedisError>;
 
+    /// Acquires the lease of the producer leader for the instance, or renews it when the instance
+    /// already holds it. Returns whether the instance is the leader.
+    async fn acquire_or_renew_leader_lease(
+        &self,
+        lease_key: &str,
+        instance_id: &str,
+        ttl: i64,
+    ) -> CustomResult<bool, RedisError>;
+
+    /// Releases the lease of the producer leader, when held by the instance
+    async fn release_leader_lease(
+        &self,
+        lease_key: &str,
+        instance_id: &str,
+    ) -> CustomResult<(), RedisError>;
+
     async fn stream_append_entry(
         &self,
         stream: &str,
@@ -118,6 +156,38 @@ impl QueueInterface for Store {
         })
     }
 
+    async fn acquire_or_renew_leader_lease(
+        &self,
+        lease_key: &str,
+        instance_id: &str,
+        ttl: i64,
+    ) -> CustomResult<bool, RedisError> {
+        let is_leader = self
+            .get_redis_conn()?
+            .evaluate_redis_script::<_, i64>(
+                ACQUIRE_OR_RENEW_LEADER_LEASE_SCRIPT,
+                vec![lease_key.to_string()],
+                vec![instance_id.to_string(), ttl.to_string()],
+            )
+            .await?;
+        Ok(is_leader == 1)
+    }
+
+    async fn release_leader_lease(
+        &self,
+        lease_key: &str,
+        instance_id: &str,
+    ) -> CustomResult<(), RedisError> {
+        self.get_redis_conn()?
+            .evaluate_redis_script::<_, i64>(
+                RELEASE_LEADER_LEASE_SCRIPT,
+                vec![lease_key.to_string()],
+                vec![instance_id.to_string()],
+            )
+            .await?;
+        Ok(())
+    }
+
     async fn stream_append_entry(
         &self,
         stream: &str,
@@ -174,6 +244,24 @@ impl QueueInterface for MockDb {
         Ok(false)
     }
 
+    async fn acquire_or_renew_leader_lease(
+        &self,
+        _lease_key: &str,
+        _instance_id: &str,
+        _ttl: i64,
+    ) -> CustomResult<bool, RedisError> {
+        // A mock store is never shared between instances
+        Ok(true)
+    }
+
+    async fn release_leader_lease(
+        &self,
+        _lease_key: &str,
+        _instance_id: &str,
+    ) -> CustomResult<(), RedisError> {
+        Ok(())
+    }
+
     async fn stream_append_entry(
         &self,
         _stream: &str,
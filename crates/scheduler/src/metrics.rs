@@ -1,4 +1,7 @@
-use router_env::{counter_metric, global_meter, histogram_metric, metrics_context};
+use router_env::{
+    counter_metric, gauge_metric, global_meter, histogram_metric, histogram_metric_i64,
+    metrics_context,
+};
 
 metrics_context!(CONTEXT);
 global_meter!(PT_METER, "PROCESS_TRACKER");
@@ -13,3 +16,5 @@ counter_metric!(TASK_CONSUMED, PT_METER); // Tasks consumed by consumer
 counter_metric!(TASK_PROCESSED, PT_METER); // Tasks completed processing
 counter_metric!(TASK_FINISHED, PT_METER); // Tasks finished
 counter_metric!(TASK_RETRIED, PT_METER); // Tasks added for retries
+gauge_metric!(PRODUCER_LEADER, PT_METER); // Whether the instance leads the producer of a tenant
+histogram_metric_i64!(PRODUCER_LAG_SECONDS, PT_METER); // Time in (s) the oldest picked task was overdue
@@ -1,10 +1,11 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use common_utils::errors::CustomResult;
 use diesel_models::enums::ProcessTrackerStatus;
 use error_stack::{report, ResultExt};
 use router_env::{
     instrument,
+    metrics::add_attributes,
     tracing::{self, Instrument},
 };
 use time::Duration;
@@ -63,6 +64,9 @@ where
     let task_handle =
         tokio::spawn(common_utils::signals::signal_handler(signal, tx).in_current_span());
 
+    let instance_id = format!("producer_{}", uuid::Uuid::new_v4());
+    let mut leadership = HashMap::new();
+
     loop {
         match rx.try_recv() {
             Err(mpsc::error::TryRecvError::Empty) => {
@@ -70,6 +74,25 @@ where
                 let tenants = state.get_tenants();
                 for tenant in tenants {
                     let session_state = app_state_to_session_state(state, tenant.as_str())?;
+                    let is_leader =
+                        elect_leader(&session_state, &scheduler_settings, &instance_id).await;
+                    if leadership.insert(tenant.clone(), is_leader) != Some(is_leader) {
+                        logger::info!(
+                            %tenant,
+                            %instance_id,
+                            is_leader,
+                            "Producer leadership updated"
+                        );
+                    }
+                    metrics::PRODUCER_LEADER.observe(
+                        &metrics::CONTEXT,
+                        u64::from(is_leader),
+                        &add_attributes([("tenant", tenant.clone())]),
+                    );
+                    if !is_leader {
+                        continue;
+                    }
+
                     match run_producer_flow(&session_state, &scheduler_settings).await {
                         Ok(_) => (),
                         Err(error) => {
@@ -84,6 +107,14 @@ where
             Ok(()) | Err(mpsc::error::TryRecvError::Disconnected) => {
                 logger::debug!("Awaiting shutdown!");
                 rx.close();
+                release_leadership(
+                    state,
+                    &scheduler_settings,
+                    &instance_id,
+                    &leadership,
+                    &app_state_to_session_state,
+                )
+                .await;
                 shutdown_interval.tick().await;
                 logger::info!("Terminating producer");
                 break;
@@ -98,6 +129,66 @@ where
     Ok(())
 }
 
+/// Whether the instance leads the production of tasks for the tenant of the session, every
+/// instance is a leader when leader election is disabled
+async fn elect_leader<T>(state: &T, settings: &SchedulerSettings, instance_id: &str) -> bool
+where
+    T: SchedulerSessionState,
+{
+    let leader_election = &settings.producer.leader_election;
+    if !leader_election.enabled {
+        return true;
+    }
+
+    state
+        .get_db()
+        .as_scheduler()
+        .acquire_or_renew_leader_lease(
+            &leader_election.lease_key,
+            instance_id,
+            leader_election.lease_ttl,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to acquire the producer leader lease"))
+        .unwrap_or(false)
+}
+
+/// Releases the leases held by the instance, so that a standby instance takes over without
+/// waiting for them to expire
+async fn release_leadership<T, U, F>(
+    state: &T,
+    settings: &SchedulerSettings,
+    instance_id: &str,
+    leadership: &HashMap<String, bool>,
+    app_state_to_session_state: &F,
+) where
+    F: Fn(&T, &str) -> CustomResult<U, errors::ProcessTrackerError>,
+    T: SchedulerAppState,
+    U: SchedulerSessionState,
+{
+    let leader_election = &settings.producer.leader_election;
+    if !leader_election.enabled {
+        return;
+    }
+
+    for (tenant, _) in leadership.iter().filter(|(_, is_leader)| **is_leader) {
+        let released = match app_state_to_session_state(state, tenant) {
+            Ok(session_state) => session_state
+                .get_db()
+                .as_scheduler()
+                .release_leader_lease(&leader_election.lease_key, instance_id)
+                .await
+                .change_context(errors::ProcessTrackerError::ERedisError(
+                    errors::RedisError::RedisConnectionError.into(),
+                )),
+            Err(error) => Err(error),
+        };
+        if let Err(error) = released {
+            error!(?error, %tenant, "Failed to release the producer leader lease");
+        }
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn run_producer_flow<T>(
     state: &T,
@@ -173,6 +264,12 @@ pub async fn fetch_producer_tasks(
 
     new_tasks.append(&mut pending_tasks);
 
+    if let Some(oldest_schedule_time) = new_tasks.iter().filter_map(|task| task.schedule_time).min()
+    {
+        let lag = (now - oldest_schedule_time).whole_seconds().max(0);
+        metrics::PRODUCER_LAG_SECONDS.record(&metrics::CONTEXT, lag, &[]);
+    }
+
     // Safety: Assuming we won't deal with more than `u64::MAX` tasks at once
     #[allow(clippy::as_conversions)]
     metrics::TASKS_PICKED_COUNT.add(&metrics::CONTEXT, new_tasks.len() as u64, &[]);
@@ -115,6 +115,12 @@ impl<T: DatabaseStore> RouterStore<T> {
             .await
             .change_context(StorageError::InitializationError)
             .attach_printable("Failed to subscribe to inmemory cache stream")?;
+        cache_store
+            .redis_conn
+            .subscribe(redis::pub_sub::DYNAMIC_LOG_CONFIG_CHANNEL)
+            .await
+            .change_context(StorageError::InitializationError)
+            .attach_printable("Failed to subscribe to dynamic log config stream")?;
 
         Ok(Self {
             db_store,
@@ -39,6 +39,10 @@ pub struct MockDb {
     pub cards_info: Arc<Mutex<Vec<store::CardInfo>>>,
     pub events: Arc<Mutex<Vec<store::Event>>>,
     pub disputes: Arc<Mutex<Vec<store::Dispute>>>,
+    pub invoices: Arc<Mutex<Vec<store::Invoice>>>,
+    pub ledger_entries: Arc<Mutex<Vec<store::ledger_entry::LedgerEntry>>>,
+    pub payment_order_references:
+        Arc<Mutex<Vec<store::payment_order_reference::PaymentOrderReference>>>,
     pub lockers: Arc<Mutex<Vec<store::LockerMockUp>>>,
     pub mandates: Arc<Mutex<Vec<store::Mandate>>>,
     pub captures: Arc<Mutex<Vec<store::capture::Capture>>>,
@@ -61,6 +65,7 @@ pub struct MockDb {
     pub user_authentication_methods:
         Arc<Mutex<Vec<store::user_authentication_method::UserAuthenticationMethod>>>,
     pub themes: Arc<Mutex<Vec<store::user::theme::Theme>>>,
+    pub webhook_endpoints: Arc<Mutex<Vec<store::webhook_endpoint::WebhookEndpoint>>>,
 }
 
 impl MockDb {
@@ -86,6 +91,9 @@ impl MockDb {
             cards_info: Default::default(),
             events: Default::default(),
             disputes: Default::default(),
+            invoices: Default::default(),
+            ledger_entries: Default::default(),
+            payment_order_references: Default::default(),
             lockers: Default::default(),
             mandates: Default::default(),
             captures: Default::default(),
@@ -107,6 +115,7 @@ impl MockDb {
             user_key_store: Default::default(),
             user_authentication_methods: Default::default(),
             themes: Default::default(),
+            webhook_endpoints: Default::default(),
         })
     }
 }
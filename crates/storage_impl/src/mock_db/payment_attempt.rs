@@ -182,6 +182,12 @@ impl PaymentAttemptInterface for MockDb {
             organization_id: payment_attempt.organization_id,
             profile_id: payment_attempt.profile_id,
             connector_mandate_detail: payment_attempt.connector_mandate_detail,
+            row_version: common_utils::consts::INITIAL_ROW_VERSION,
+            routing_decision: payment_attempt.routing_decision,
+            connector_fee_amount: None,
+            connector_fee_currency: None,
+            connector_request_id: None,
+            correlation_id: None,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -5,5 +5,9 @@ use diesel_models::{payment_attempt::PaymentAttempt, PaymentIntent};
 
 use crate::redis::kv_store::KvStorePartition;
 
+/// Number of times an update is re-applied on the latest state of a record after a concurrent
+/// modification of the record was detected
+pub(crate) const MAX_ROW_VERSION_CONFLICT_RETRIES: u8 = 3;
+
 impl KvStorePartition for PaymentIntent {}
 impl KvStorePartition for PaymentAttempt {}
@@ -104,14 +104,17 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
         let conn = pg_connection_write(self).await?;
-        this.to_storage_model()
-            .update_with_attempt_id(&conn, payment_attempt.to_storage_model())
-            .await
-            .map_err(|er| {
-                let new_err = diesel_error_to_data_error(er.current_context());
-                er.change_context(new_err)
-            })
-            .map(PaymentAttempt::from_storage_model)
+        update_payment_attempt_with_row_version_check(
+            &conn,
+            this.to_storage_model(),
+            payment_attempt.to_storage_model(),
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+        .map(PaymentAttempt::from_storage_model)
     }
 
     #[cfg(feature = "v2")]
@@ -534,6 +537,12 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     organization_id: payment_attempt.organization_id.clone(),
                     profile_id: payment_attempt.profile_id.clone(),
                     connector_mandate_detail: payment_attempt.connector_mandate_detail.clone(),
+                    row_version: common_utils::consts::INITIAL_ROW_VERSION,
+                    routing_decision: payment_attempt.routing_decision.clone(),
+                    connector_fee_amount: None,
+                    connector_fee_currency: None,
+                    connector_request_id: None,
+                    correlation_id: None,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -633,29 +642,88 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
             }
             MerchantStorageScheme::RedisKv => {
                 let key_str = key.to_string();
-                let old_connector_transaction_id = &this.get_connector_payment_id();
-                let old_preprocessing_id = &this.preprocessing_step_id;
-                let updated_attempt = PaymentAttempt::from_storage_model(
-                    payment_attempt
-                        .clone()
-                        .to_storage_model()
-                        .apply_changeset(this.clone().to_storage_model()),
-                );
-                // Check for database presence as well Maybe use a read replica here ?
-                let redis_value = serde_json::to_string(&updated_attempt)
+                let mut origin_attempt = this.clone().to_storage_model();
+                let mut retry_count = 0;
+
+                // The update is applied only if the payment attempt was not modified since it was
+                // read, and is otherwise re-applied on the latest state of the payment attempt
+                let updated_attempt = loop {
+                    let updated_attempt = PaymentAttempt::from_storage_model(
+                        payment_attempt
+                            .clone()
+                            .to_storage_model()
+                            .apply_changeset(origin_attempt.clone()),
+                    );
+                    // Check for database presence as well Maybe use a read replica here ?
+                    let redis_value = serde_json::to_string(&updated_attempt)
+                        .change_context(errors::StorageError::KVError)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: Box::new(kv::Updateable::PaymentAttemptUpdate(Box::new(
+                                kv::PaymentAttemptUpdateMems {
+                                    orig: origin_attempt.clone(),
+                                    update_data: payment_attempt.clone().to_storage_model(),
+                                },
+                            ))),
+                        },
+                    };
+
+                    let is_updated = Box::pin(kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::<DieselPaymentAttempt>::HsetIfRowVersion(
+                            (&field, redis_value),
+                            origin_attempt.row_version,
+                            redis_entry,
+                        ),
+                        key.clone(),
+                    ))
+                    .await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hsetifrowversion()
                     .change_context(errors::StorageError::KVError)?;
 
-                let redis_entry = kv::TypedSql {
-                    op: kv::DBOperation::Update {
-                        updatable: Box::new(kv::Updateable::PaymentAttemptUpdate(Box::new(
-                            kv::PaymentAttemptUpdateMems {
-                                orig: this.clone().to_storage_model(),
-                                update_data: payment_attempt.to_storage_model(),
-                            },
-                        ))),
-                    },
+                    if is_updated {
+                        break updated_attempt;
+                    }
+
+                    let latest_attempt = Box::pin(kv_wrapper::<DieselPaymentAttempt, _, _>(
+                        self,
+                        KvOperation::<DieselPaymentAttempt>::HGet(&field),
+                        key.clone(),
+                    ))
+                    .await
+                    .change_context(errors::StorageError::KVError)?
+                    .try_into_hget()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    if retry_count >= super::MAX_ROW_VERSION_CONFLICT_RETRIES
+                        || latest_attempt.status != origin_attempt.status
+                    {
+                        return Err(error_stack::report!(errors::StorageError::DatabaseError(
+                            error_stack::report!(
+                                diesel_models::errors::DatabaseError::VersionConflict
+                            )
+                        )))
+                        .attach_printable(format!(
+                            "Payment attempt was moved from status {} to {} by a concurrent update",
+                            origin_attempt.status, latest_attempt.status
+                        ));
+                    }
+
+                    router_env::logger::info!(
+                        attempt_id = %origin_attempt.attempt_id,
+                        retry_count,
+                        "Retrying payment attempt update on the latest state in redis"
+                    );
+                    retry_count += 1;
+                    origin_attempt = latest_attempt;
                 };
 
+                let origin_attempt = PaymentAttempt::from_storage_model(origin_attempt);
+                let old_connector_transaction_id = &origin_attempt.get_connector_payment_id();
+                let old_preprocessing_id = &origin_attempt.preprocessing_step_id;
+
                 match (
                     old_connector_transaction_id,
                     &updated_attempt.get_connector_payment_id(),
@@ -715,16 +783,6 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     (_, _) => {}
                 }
 
-                Box::pin(kv_wrapper::<(), _, _>(
-                    self,
-                    KvOperation::Hset::<DieselPaymentAttempt>((&field, redis_value), redis_entry),
-                    key,
-                ))
-                .await
-                .change_context(errors::StorageError::KVError)?
-                .try_into_hset()
-                .change_context(errors::StorageError::KVError)?;
-
                 Ok(updated_attempt)
             }
         }
@@ -1459,6 +1517,12 @@ impl DataModelExt for PaymentAttempt {
             shipping_cost: self.net_amount.get_shipping_cost(),
             order_tax_amount: self.net_amount.get_order_tax_amount(),
             connector_mandate_detail: self.connector_mandate_detail,
+            row_version: self.row_version,
+            routing_decision: self.routing_decision,
+            connector_fee_amount: self.connector_fee_amount,
+            connector_fee_currency: self.connector_fee_currency,
+            connector_request_id: self.connector_request_id,
+            correlation_id: self.correlation_id,
         }
     }
 
@@ -1535,6 +1599,12 @@ impl DataModelExt for PaymentAttempt {
             organization_id: storage_model.organization_id,
             profile_id: storage_model.profile_id,
             connector_mandate_detail: storage_model.connector_mandate_detail,
+            row_version: storage_model.row_version,
+            routing_decision: storage_model.routing_decision,
+            connector_fee_amount: storage_model.connector_fee_amount,
+            connector_fee_currency: storage_model.connector_fee_currency,
+            connector_request_id: storage_model.connector_request_id,
+            correlation_id: storage_model.correlation_id,
         }
     }
 }
@@ -1618,6 +1688,7 @@ impl DataModelExt for PaymentAttemptNew {
             shipping_cost: self.net_amount.get_shipping_cost(),
             order_tax_amount: self.net_amount.get_order_tax_amount(),
             connector_mandate_detail: self.connector_mandate_detail,
+            routing_decision: self.routing_decision,
         }
     }
 
@@ -1690,6 +1761,7 @@ impl DataModelExt for PaymentAttemptNew {
             organization_id: storage_model.organization_id,
             profile_id: storage_model.profile_id,
             connector_mandate_detail: storage_model.connector_mandate_detail,
+            routing_decision: storage_model.routing_decision,
         }
     }
 }
@@ -1747,3 +1819,56 @@ async fn add_preprocessing_id_to_reverse_lookup<T: DatabaseStore>(
         .insert_reverse_lookup(reverse_lookup_new, storage_scheme)
         .await
 }
+
+/// Applies the update only if the payment attempt was not modified since it was read.
+///
+/// If a concurrent modification is detected, the update is re-applied on the latest state of the
+/// payment attempt only if the concurrent update did not change its status, so that status
+/// transitions are applied at most once.
+#[cfg(feature = "v1")]
+async fn update_payment_attempt_with_row_version_check(
+    conn: &diesel_models::PgPooledConn,
+    mut payment_attempt: DieselPaymentAttempt,
+    payment_attempt_update: diesel_models::payment_attempt::PaymentAttemptUpdate,
+) -> error_stack::Result<DieselPaymentAttempt, diesel_models::errors::DatabaseError> {
+    let mut retry_count = 0;
+
+    loop {
+        let stale_status = payment_attempt.status;
+        match payment_attempt
+            .clone()
+            .update_with_attempt_id_if_row_version_matches(conn, payment_attempt_update.clone())
+            .await
+        {
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    diesel_models::errors::DatabaseError::VersionConflict
+                ) && retry_count < super::MAX_ROW_VERSION_CONFLICT_RETRIES =>
+            {
+                let latest_payment_attempt = DieselPaymentAttempt::find_by_merchant_id_attempt_id(
+                    conn,
+                    &payment_attempt.merchant_id,
+                    &payment_attempt.attempt_id,
+                )
+                .await?;
+
+                if latest_payment_attempt.status != stale_status {
+                    return Err(error).attach_printable(format!(
+                        "Payment attempt was moved from status {} to {} by a concurrent update",
+                        stale_status, latest_payment_attempt.status
+                    ));
+                }
+
+                router_env::logger::info!(
+                    attempt_id = %payment_attempt.attempt_id,
+                    retry_count,
+                    "Retrying payment attempt update on the latest state after a concurrent update"
+                );
+                retry_count += 1;
+                payment_attempt = latest_payment_attempt;
+            }
+            result => return result,
+        }
+    }
+}
@@ -9,7 +9,9 @@ use common_utils::{
     types::keymanager::KeyManagerState,
 };
 #[cfg(feature = "olap")]
-use diesel::{associations::HasTable, ExpressionMethods, JoinOnDsl, QueryDsl};
+use diesel::{
+    associations::HasTable, ExpressionMethods, JoinOnDsl, PgJsonbExpressionMethods, QueryDsl,
+};
 #[cfg(feature = "olap")]
 use diesel_models::query::generics::db_metrics;
 #[cfg(all(feature = "v1", feature = "olap"))]
@@ -205,41 +207,86 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                 let key_str = key.to_string();
 
                 let diesel_intent_update = DieselPaymentIntentUpdate::from(payment_intent_update);
-                let origin_diesel_intent = this
+                let mut origin_diesel_intent = this
                     .convert()
                     .await
                     .change_context(StorageError::EncryptionError)?;
+                let mut retry_count = 0;
+
+                // The update is applied only if the payment intent was not modified since it was
+                // read, and is otherwise re-applied on the latest state of the payment intent
+                let diesel_intent = loop {
+                    let diesel_intent = diesel_intent_update
+                        .clone()
+                        .apply_changeset(origin_diesel_intent.clone());
+                    // Check for database presence as well Maybe use a read replica here ?
+
+                    let redis_value = diesel_intent
+                        .encode_to_string_of_json()
+                        .change_context(StorageError::SerializationFailed)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: Box::new(kv::Updateable::PaymentIntentUpdate(Box::new(
+                                kv::PaymentIntentUpdateMems {
+                                    orig: origin_diesel_intent.clone(),
+                                    update_data: diesel_intent_update.clone(),
+                                },
+                            ))),
+                        },
+                    };
+
+                    let is_updated = Box::pin(kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::<DieselPaymentIntent>::HsetIfRowVersion(
+                            (&field, redis_value),
+                            origin_diesel_intent.row_version,
+                            redis_entry,
+                        ),
+                        key.clone(),
+                    ))
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hsetifrowversion()
+                    .change_context(StorageError::KVError)?;
 
-                let diesel_intent = diesel_intent_update
-                    .clone()
-                    .apply_changeset(origin_diesel_intent.clone());
-                // Check for database presence as well Maybe use a read replica here ?
+                    if is_updated {
+                        break diesel_intent;
+                    }
 
-                let redis_value = diesel_intent
-                    .encode_to_string_of_json()
-                    .change_context(StorageError::SerializationFailed)?;
+                    let latest_diesel_intent = Box::pin(kv_wrapper::<DieselPaymentIntent, _, _>(
+                        self,
+                        KvOperation::<DieselPaymentIntent>::HGet(&field),
+                        key.clone(),
+                    ))
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hget()
+                    .change_context(StorageError::KVError)?;
+
+                    if retry_count >= super::MAX_ROW_VERSION_CONFLICT_RETRIES
+                        || latest_diesel_intent.status != origin_diesel_intent.status
+                    {
+                        return Err(error_stack::report!(StorageError::DatabaseError(
+                            error_stack::report!(
+                                diesel_models::errors::DatabaseError::VersionConflict
+                            )
+                        )))
+                        .attach_printable(format!(
+                            "Payment intent was moved from status {} to {} by a concurrent update",
+                            origin_diesel_intent.status, latest_diesel_intent.status
+                        ));
+                    }
 
-                let redis_entry = kv::TypedSql {
-                    op: kv::DBOperation::Update {
-                        updatable: Box::new(kv::Updateable::PaymentIntentUpdate(Box::new(
-                            kv::PaymentIntentUpdateMems {
-                                orig: origin_diesel_intent,
-                                update_data: diesel_intent_update,
-                            },
-                        ))),
-                    },
+                    router_env::logger::info!(
+                        payment_id = ?origin_diesel_intent.payment_id,
+                        retry_count,
+                        "Retrying payment intent update on the latest state in redis"
+                    );
+                    retry_count += 1;
+                    origin_diesel_intent = latest_diesel_intent;
                 };
 
-                Box::pin(kv_wrapper::<(), _, _>(
-                    self,
-                    KvOperation::<DieselPaymentIntent>::Hset((&field, redis_value), redis_entry),
-                    key,
-                ))
-                .await
-                .map_err(|err| err.to_redis_failed_response(&key_str))?
-                .try_into_hset()
-                .change_context(StorageError::KVError)?;
-
                 let payment_intent = PaymentIntent::convert_back(
                     state,
                     diesel_intent,
@@ -511,13 +558,18 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         let diesel_payment_intent = this
             .convert()
             .await
-            .change_context(StorageError::EncryptionError)?
-            .update(&conn, diesel_payment_intent_update)
-            .await
-            .map_err(|er| {
-                let new_err = diesel_error_to_data_error(er.current_context());
-                er.change_context(new_err)
-            })?;
+            .change_context(StorageError::EncryptionError)?;
+
+        let diesel_payment_intent = update_payment_intent_with_row_version_check(
+            &conn,
+            diesel_payment_intent,
+            diesel_payment_intent_update,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })?;
 
         PaymentIntent::convert_back(
             state,
@@ -659,6 +711,17 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 if let Some(customer_id) = &params.customer_id {
                     query = query.filter(pi_dsl::customer_id.eq(customer_id.clone()));
                 }
+                if let Some(metadata) = &params.metadata {
+                    query = query.filter(pi_dsl::metadata.contains(metadata.clone()));
+                }
+                if let Some(merchant_order_reference_id) = &params.merchant_order_reference_id {
+                    query = query.filter(
+                        pi_dsl::merchant_order_reference_id.eq(merchant_order_reference_id.clone()),
+                    );
+                }
+                if let Some(fingerprint_id) = &params.fingerprint_id {
+                    query = query.filter(pi_dsl::fingerprint_id.eq_any(fingerprint_id.clone()));
+                }
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(pi_dsl::profile_id.eq_any(profile_id.clone()));
                 }
@@ -870,6 +933,17 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 if let Some(customer_id) = &params.customer_id {
                     query = query.filter(pi_dsl::customer_id.eq(customer_id.clone()));
                 }
+                if let Some(metadata) = &params.metadata {
+                    query = query.filter(pi_dsl::metadata.contains(metadata.clone()));
+                }
+                if let Some(merchant_order_reference_id) = &params.merchant_order_reference_id {
+                    query = query.filter(
+                        pi_dsl::merchant_order_reference_id.eq(merchant_order_reference_id.clone()),
+                    );
+                }
+                if let Some(fingerprint_id) = &params.fingerprint_id {
+                    query = query.filter(pi_dsl::fingerprint_id.eq_any(fingerprint_id.clone()));
+                }
 
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(pi_dsl::profile_id.eq_any(profile_id.clone()));
@@ -1041,6 +1115,17 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 if let Some(customer_id) = &params.customer_id {
                     query = query.filter(pi_dsl::customer_id.eq(customer_id.clone()));
                 }
+                if let Some(metadata) = &params.metadata {
+                    query = query.filter(pi_dsl::metadata.contains(metadata.clone()));
+                }
+                if let Some(merchant_order_reference_id) = &params.merchant_order_reference_id {
+                    query = query.filter(
+                        pi_dsl::merchant_order_reference_id.eq(merchant_order_reference_id.clone()),
+                    );
+                }
+                if let Some(fingerprint_id) = &params.fingerprint_id {
+                    query = query.filter(pi_dsl::fingerprint_id.eq_any(fingerprint_id.clone()));
+                }
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(pi_dsl::profile_id.eq_any(profile_id.clone()));
                 }
@@ -1099,3 +1184,57 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         })
     }
 }
+
+/// Applies the update only if the payment intent was not modified since it was read.
+///
+/// If a concurrent modification is detected, the update is re-applied on the latest state of the
+/// payment intent only if the concurrent update did not change its status. Status transitions are
+/// thereby applied at most once, and a duplicate request operating on a stale status receives the
+/// conflict instead of repeating or overwriting the transition.
+#[cfg(feature = "v1")]
+async fn update_payment_intent_with_row_version_check(
+    conn: &diesel_models::PgPooledConn,
+    mut payment_intent: DieselPaymentIntent,
+    payment_intent_update: DieselPaymentIntentUpdate,
+) -> error_stack::Result<DieselPaymentIntent, diesel_models::errors::DatabaseError> {
+    let mut retry_count = 0;
+
+    loop {
+        let stale_status = payment_intent.status;
+        match payment_intent
+            .clone()
+            .update_if_row_version_matches(conn, payment_intent_update.clone())
+            .await
+        {
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    diesel_models::errors::DatabaseError::VersionConflict
+                ) && retry_count < super::MAX_ROW_VERSION_CONFLICT_RETRIES =>
+            {
+                let latest_payment_intent = DieselPaymentIntent::find_by_payment_id_merchant_id(
+                    conn,
+                    &payment_intent.payment_id,
+                    &payment_intent.merchant_id,
+                )
+                .await?;
+
+                if latest_payment_intent.status != stale_status {
+                    return Err(error).attach_printable(format!(
+                        "Payment intent was moved from status {} to {} by a concurrent update",
+                        stale_status, latest_payment_intent.status
+                    ));
+                }
+
+                router_env::logger::info!(
+                    payment_id = ?payment_intent.payment_id,
+                    retry_count,
+                    "Retrying payment intent update on the latest state after a concurrent update"
+                );
+                retry_count += 1;
+                payment_intent = latest_payment_intent;
+            }
+            result => return result,
+        }
+    }
+}
@@ -10,6 +10,22 @@ use serde::de;
 
 use crate::{metrics, store::kv::TypedSql, KVRouterStore, UniqueConstraints};
 
+/// Sets the hash field only if the record it holds, if any, still has the expected `row_version`.
+/// Records stored before versioning was introduced have no `row_version`, and are treated as
+/// being at the initial version `1`, as they are when deserialized.
+const SET_HASH_FIELD_IF_ROW_VERSION_SCRIPT: &str = r#"
+local current = redis.call("HGET", KEYS[1], ARGV[1])
+if current then
+    local row_version = cjson.decode(current)["row_version"] or 1
+    if row_version ~= tonumber(ARGV[3]) then
+        return 0
+    end
+end
+redis.call("HSET", KEYS[1], ARGV[1], ARGV[2])
+redis.call("EXPIRE", KEYS[1], ARGV[4])
+return 1
+"#;
+
 pub trait KvStorePartition {
     fn partition_number(key: PartitionKey<'_>, num_partitions: u8) -> u32 {
         crc32fast::hash(key.to_string().as_bytes()) % u32::from(num_partitions)
@@ -122,6 +138,7 @@ pub trait RedisConnInterface {
 /// An enum to represent what operation to do on
 pub enum KvOperation<'a, S: serde::Serialize + Debug> {
     Hset((&'a str, String), TypedSql),
+    HsetIfRowVersion((&'a str, String), i32, TypedSql),
     SetNx(&'a S, TypedSql),
     HSetNx(&'a str, &'a S, TypedSql),
     HGet(&'a str),
@@ -135,6 +152,7 @@ pub enum KvResult<T: de::DeserializeOwned> {
     HGet(T),
     Get(T),
     Hset(()),
+    HsetIfRowVersion(bool),
     SetNx(redis_interface::SetnxReply),
     HSetNx(redis_interface::HsetnxReply),
     Scan(Vec<T>),
@@ -147,6 +165,7 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             KvOperation::Hset(_, _) => f.write_str("Hset"),
+            KvOperation::HsetIfRowVersion(_, _, _) => f.write_str("HsetIfRowVersion"),
             KvOperation::SetNx(_, _) => f.write_str("Setnx"),
             KvOperation::HSetNx(_, _, _) => f.write_str("HSetNx"),
             KvOperation::HGet(_) => f.write_str("Hget"),
@@ -191,6 +210,32 @@ where
                 Ok(KvResult::Hset(()))
             }
 
+            KvOperation::HsetIfRowVersion((field, value), row_version, sql) => {
+                logger::debug!(kv_operation= %operation, value = ?value, row_version);
+
+                let is_set = redis_conn
+                    .evaluate_redis_script::<_, i64>(
+                        SET_HASH_FIELD_IF_ROW_VERSION_SCRIPT,
+                        vec![key.clone()],
+                        vec![
+                            field.to_string(),
+                            value,
+                            row_version.to_string(),
+                            ttl.to_string(),
+                        ],
+                    )
+                    .await?
+                    == 1;
+
+                if is_set {
+                    store
+                        .push_to_drainer_stream::<S>(sql, partition_key)
+                        .await?;
+                }
+
+                Ok(KvResult::HsetIfRowVersion(is_set))
+            }
+
             KvOperation::HGet(field) => {
                 let result = redis_conn
                     .get_hash_field_and_deserialize(&key, field, type_name)
@@ -10,6 +10,9 @@ use crate::redis::cache::{
     SUCCESS_BASED_DYNAMIC_ALGORITHM_CACHE, SURCHARGE_CACHE,
 };
 
+/// Channel on which the runtime log levels and sampling are published to all the instances
+pub const DYNAMIC_LOG_CONFIG_CHANNEL: &str = "hyperswitch_dynamic_log_config";
+
 #[async_trait::async_trait]
 pub trait PubSubInterface {
     async fn subscribe(&self, channel: &str) -> error_stack::Result<(), redis_errors::RedisError>;
@@ -20,6 +23,11 @@ pub trait PubSubInterface {
         key: CacheKind<'a>,
     ) -> error_stack::Result<usize, redis_errors::RedisError>;
 
+    async fn publish_dynamic_log_config(
+        &self,
+        config: &logger::DynamicLogConfig,
+    ) -> error_stack::Result<usize, redis_errors::RedisError>;
+
     async fn on_message(&self) -> error_stack::Result<(), redis_errors::RedisError>;
 }
 
@@ -81,6 +89,20 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
             .change_context(redis_errors::RedisError::SubscribeError)
     }
 
+    #[inline]
+    async fn publish_dynamic_log_config(
+        &self,
+        config: &logger::DynamicLogConfig,
+    ) -> error_stack::Result<usize, redis_errors::RedisError> {
+        let config =
+            serde_json::to_vec(config).change_context(redis_errors::RedisError::PublishError)?;
+
+        self.publisher
+            .publish(DYNAMIC_LOG_CONFIG_CHANNEL, RedisValue::from_bytes(config))
+            .await
+            .change_context(redis_errors::RedisError::PublishError)
+    }
+
     #[inline]
     async fn on_message(&self) -> error_stack::Result<(), redis_errors::RedisError> {
         logger::debug!("Started on message");
@@ -239,6 +261,24 @@ impl PubSubInterface for std::sync::Arc<redis_interface::RedisConnectionPool> {
                         "Done invalidating {key}"
                     );
                 }
+                DYNAMIC_LOG_CONFIG_CHANNEL => {
+                    let value = RedisValue::new(message.value);
+                    match value
+                        .as_bytes()
+                        .map(serde_json::from_slice::<logger::DynamicLogConfig>)
+                    {
+                        Some(Ok(config)) => {
+                            logger::info!(?config, "Updated the dynamic log config");
+                            logger::DynamicLogConfig::set(config);
+                        }
+                        Some(Err(err)) => {
+                            logger::error!(dynamic_log_config_err=?err);
+                        }
+                        None => {
+                            logger::error!("Invalid dynamic log config received in pubsub");
+                        }
+                    }
+                }
                 _ => {
                     logger::debug!("Received message from unknown channel: {channel_name}");
                 }
@@ -0,0 +1,29 @@
+#![allow(clippy::print_stdout, clippy::print_stderr)]
+
+use std::process::exit;
+
+use anyhow::Result;
+use clap::Parser;
+use test_utils::certification;
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = certification::Args::parse();
+
+    let report = match certification::run_certification(&args).await {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("Failed to run the certification: {err:#}");
+            exit(1);
+        }
+    };
+    certification::write_report(&report, args.get_output())?;
+
+    if report.certified {
+        println!("All the certified flows passed!");
+        Ok(())
+    } else {
+        eprintln!("{} of the certified flows failed", report.failed);
+        exit(1);
+    }
+}
@@ -0,0 +1,695 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::Instant,
+};
+
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use clap::{arg, command, Parser};
+use masking::{PeekInterface, Secret};
+use reqwest::{Method, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::connector_auth::{ConnectorAuthType, ConnectorAuthenticationMap};
+
+const PAYMENT_AMOUNT: i64 = 6540;
+
+#[derive(Parser)]
+#[command(version, about = "Connector certification test suite runner", long_about = None)]
+pub struct Args {
+    /// Admin API Key of the environment
+    #[arg(short, long)]
+    admin_api_key: String,
+    /// Base URL of the Hyperswitch environment
+    #[arg(short, long)]
+    base_url: String,
+    /// Name of the connector to certify
+    #[arg(short, long)]
+    connector_name: String,
+    /// Comma separated names of the flows to run, all the flows are run by default
+    #[arg(short, long)]
+    flows: Option<String>,
+    /// Directory of the simulated webhook payloads of the connector, one JSON file per payload
+    #[arg(short, long)]
+    webhook_payloads: Option<PathBuf>,
+    /// Metadata of the merchant connector account, as a JSON string
+    #[arg(short = 'm', long)]
+    connector_metadata: Option<String>,
+    /// Card number used for the card payments
+    #[arg(long, default_value = "4242424242424242")]
+    card_number: String,
+    /// Currency of the payments
+    #[arg(long, default_value = "USD")]
+    currency: String,
+    /// File to write the certification report to, the report is printed when not provided
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+}
+
+/// A simulated webhook payload of the connector, sent to the incoming webhooks endpoint
+#[derive(Debug, Deserialize)]
+struct WebhookPayload {
+    #[serde(default)]
+    headers: HashMap<String, String>,
+    body: Value,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaseStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CaseResult {
+    pub flow: &'static str,
+    pub status: CaseStatus,
+    pub duration_in_ms: u128,
+    pub message: Option<String>,
+}
+
+/// Machine readable report of a certification run
+#[derive(Debug, Serialize)]
+pub struct CertificationReport {
+    pub connector: String,
+    pub base_url: String,
+    pub merchant_id: String,
+    pub merchant_connector_id: String,
+    /// Unix timestamp of the start of the run
+    pub started_at: i64,
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    /// Whether none of the flows run failed
+    pub certified: bool,
+    pub results: Vec<CaseResult>,
+}
+
+/// State shared by the certification cases of a run
+pub struct CertificationContext {
+    client: reqwest::Client,
+    base_url: String,
+    api_key: Secret<String>,
+    merchant_id: String,
+    merchant_connector_id: String,
+    card_number: String,
+    currency: String,
+    webhook_payloads: Option<PathBuf>,
+    /// Identifiers of the payments and refunds created by the cases run so far, by flow
+    created_ids: HashMap<&'static str, String>,
+}
+
+pub enum CaseOutcome {
+    Passed,
+    Failed(String),
+    Skipped(String),
+}
+
+/// A flow of the connector which is certified. New flows can be certified by implementing this
+/// trait and adding the case to [`get_certification_cases`].
+#[async_trait]
+pub trait CertificationCase: Send + Sync {
+    /// Name of the flow, as used in the report and the `--flows` argument
+    fn name(&self) -> &'static str;
+
+    async fn run(&self, context: &mut CertificationContext) -> Result<CaseOutcome>;
+}
+
+impl CertificationContext {
+    async fn send(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<Value>,
+    ) -> Result<(StatusCode, Value)> {
+        let mut request = self
+            .client
+            .request(method, format!("{}{path}", self.base_url))
+            .header("api-key", self.api_key.peek());
+        if let Some(body) = body {
+            request = request.json(&body);
+        }
+        let response = request
+            .send()
+            .await
+            .with_context(|| format!("request to {path} failed"))?;
+        let status = response.status();
+        let body = response.json().await.unwrap_or(Value::Null);
+        Ok((status, body))
+    }
+
+    fn get_payment_request(&self, capture_method: &str) -> Value {
+        json!({
+            "amount": PAYMENT_AMOUNT,
+            "currency": self.currency,
+            "confirm": true,
+            "capture_method": capture_method,
+            "authentication_type": "no_three_ds",
+            "payment_method": "card",
+            "payment_method_type": "credit",
+            "payment_method_data": {
+                "card": {
+                    "card_number": self.card_number,
+                    "card_exp_month": "12",
+                    "card_exp_year": "2050",
+                    "card_holder_name": "Joseph Doe",
+                    "card_cvc": "123"
+                }
+            },
+            "billing": {
+                "address": {
+                    "line1": "1467",
+                    "city": "San Fransico",
+                    "state": "California",
+                    "zip": "94122",
+                    "country": "US",
+                    "first_name": "Joseph",
+                    "last_name": "Doe"
+                }
+            },
+            "return_url": "https://hyperswitch.io"
+        })
+    }
+
+    /// Creates a card payment, returning the identifier of the payment if it has the expected
+    /// status
+    async fn create_payment(
+        &self,
+        capture_method: &str,
+        expected_status: &str,
+    ) -> Result<std::result::Result<String, String>> {
+        let (status_code, body) = self
+            .send(
+                Method::POST,
+                "/payments",
+                Some(self.get_payment_request(capture_method)),
+            )
+            .await?;
+        Ok(check_status(status_code, &body, &[expected_status])
+            .map(|_| get_str(&body, "payment_id")))
+    }
+}
+
+fn get_str(body: &Value, field: &str) -> String {
+    body.get(field)
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Checks that the request succeeded and the resource has one of the expected statuses
+fn check_status(
+    status_code: StatusCode,
+    body: &Value,
+    expected_statuses: &[&str],
+) -> std::result::Result<(), String> {
+    if !status_code.is_success() {
+        return Err(format!(
+            "request failed with status code {status_code}: {body}"
+        ));
+    }
+    let status = get_str(body, "status");
+    if expected_statuses.contains(&status.as_str()) {
+        Ok(())
+    } else {
+        Err(format!(
+            "expected status to be one of {expected_statuses:?}, found `{status}`: {}",
+            body.get("error_message").unwrap_or(&Value::Null)
+        ))
+    }
+}
+
+fn into_outcome(result: std::result::Result<(), String>) -> CaseOutcome {
+    match result {
+        Ok(()) => CaseOutcome::Passed,
+        Err(message) => CaseOutcome::Failed(message),
+    }
+}
+
+struct Authorize;
+
+#[async_trait]
+impl CertificationCase for Authorize {
+    fn name(&self) -> &'static str {
+        "authorize"
+    }
+
+    async fn run(&self, context: &mut CertificationContext) -> Result<CaseOutcome> {
+        Ok(
+            match context.create_payment("automatic", "succeeded").await? {
+                Ok(payment_id) => {
+                    context.created_ids.insert(self.name(), payment_id);
+                    CaseOutcome::Passed
+                }
+                Err(message) => CaseOutcome::Failed(message),
+            },
+        )
+    }
+}
+
+struct Capture;
+
+#[async_trait]
+impl CertificationCase for Capture {
+    fn name(&self) -> &'static str {
+        "capture"
+    }
+
+    async fn run(&self, context: &mut CertificationContext) -> Result<CaseOutcome> {
+        let payment_id = match context.create_payment("manual", "requires_capture").await? {
+            Ok(payment_id) => payment_id,
+            Err(message) => return Ok(CaseOutcome::Failed(message)),
+        };
+        let (status_code, body) = context
+            .send(
+                Method::POST,
+                &format!("/payments/{payment_id}/capture"),
+                Some(json!({ "amount_to_capture": PAYMENT_AMOUNT })),
+            )
+            .await?;
+        Ok(into_outcome(check_status(
+            status_code,
+            &body,
+            &["succeeded", "processing"],
+        )))
+    }
+}
+
+struct Void;
+
+#[async_trait]
+impl CertificationCase for Void {
+    fn name(&self) -> &'static str {
+        "void"
+    }
+
+    async fn run(&self, context: &mut CertificationContext) -> Result<CaseOutcome> {
+        let payment_id = match context.create_payment("manual", "requires_capture").await? {
+            Ok(payment_id) => payment_id,
+            Err(message) => return Ok(CaseOutcome::Failed(message)),
+        };
+        let (status_code, body) = context
+            .send(
+                Method::POST,
+                &format!("/payments/{payment_id}/cancel"),
+                Some(json!({ "cancellation_reason": "requested_by_customer" })),
+            )
+            .await?;
+        Ok(into_outcome(check_status(
+            status_code,
+            &body,
+            &["cancelled"],
+        )))
+    }
+}
+
+struct PaymentSync;
+
+#[async_trait]
+impl CertificationCase for PaymentSync {
+    fn name(&self) -> &'static str {
+        "payment_sync"
+    }
+
+    async fn run(&self, context: &mut CertificationContext) -> Result<CaseOutcome> {
+        let Some(payment_id) = context.created_ids.get(Authorize.name()) else {
+            return Ok(CaseOutcome::Skipped(
+                "requires a payment authorized by the `authorize` flow".to_string(),
+            ));
+        };
+        let (status_code, body) = context
+            .send(
+                Method::GET,
+                &format!("/payments/{payment_id}?force_sync=true"),
+                None,
+            )
+            .await?;
+        Ok(into_outcome(check_status(
+            status_code,
+            &body,
+            &["succeeded"],
+        )))
+    }
+}
+
+struct Refund;
+
+#[async_trait]
+impl CertificationCase for Refund {
+    fn name(&self) -> &'static str {
+        "refund"
+    }
+
+    async fn run(&self, context: &mut CertificationContext) -> Result<CaseOutcome> {
+        let Some(payment_id) = context.created_ids.get(Authorize.name()) else {
+            return Ok(CaseOutcome::Skipped(
+                "requires a payment authorized by the `authorize` flow".to_string(),
+            ));
+        };
+        let (status_code, body) = context
+            .send(
+                Method::POST,
+                "/refunds",
+                Some(json!({ "payment_id": payment_id, "amount": PAYMENT_AMOUNT })),
+            )
+            .await?;
+        let result = check_status(status_code, &body, &["succeeded", "pending"]);
+        if result.is_ok() {
+            context
+                .created_ids
+                .insert(self.name(), get_str(&body, "refund_id"));
+        }
+        Ok(into_outcome(result))
+    }
+}
+
+struct RefundSync;
+
+#[async_trait]
+impl CertificationCase for RefundSync {
+    fn name(&self) -> &'static str {
+        "refund_sync"
+    }
+
+    async fn run(&self, context: &mut CertificationContext) -> Result<CaseOutcome> {
+        let Some(refund_id) = context.created_ids.get(Refund.name()) else {
+            return Ok(CaseOutcome::Skipped(
+                "requires a refund created by the `refund` flow".to_string(),
+            ));
+        };
+        let (status_code, body) = context
+            .send(
+                Method::GET,
+                &format!("/refunds/{refund_id}?force_sync=true"),
+                None,
+            )
+            .await?;
+        Ok(into_outcome(check_status(
+            status_code,
+            &body,
+            &["succeeded", "pending"],
+        )))
+    }
+}
+
+struct Webhooks;
+
+#[async_trait]
+impl CertificationCase for Webhooks {
+    fn name(&self) -> &'static str {
+        "webhooks"
+    }
+
+    async fn run(&self, context: &mut CertificationContext) -> Result<CaseOutcome> {
+        let Some(directory) = context.webhook_payloads.clone() else {
+            return Ok(CaseOutcome::Skipped(
+                "no webhook payloads were provided with `--webhook-payloads`".to_string(),
+            ));
+        };
+
+        let mut paths = fs::read_dir(&directory)
+            .with_context(|| format!("failed to read {}", directory.display()))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()?;
+        paths.retain(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "json")
+        });
+        paths.sort();
+        if paths.is_empty() {
+            return Ok(CaseOutcome::Skipped(format!(
+                "no webhook payloads were found in {}",
+                directory.display()
+            )));
+        }
+
+        let url = format!(
+            "{}/webhooks/{}/{}",
+            context.base_url, context.merchant_id, context.merchant_connector_id
+        );
+        for path in paths {
+            let payload: WebhookPayload = serde_json::from_str(&fs::read_to_string(&path)?)
+                .with_context(|| format!("failed to parse {}", path.display()))?;
+            let mut request = context.client.post(&url).json(&payload.body);
+            for (name, value) in &payload.headers {
+                request = request.header(name, value);
+            }
+            let response = request.send().await?;
+            if !response.status().is_success() {
+                return Ok(CaseOutcome::Failed(format!(
+                    "webhook {} was rejected with status code {}",
+                    path.display(),
+                    response.status()
+                )));
+            }
+        }
+        Ok(CaseOutcome::Passed)
+    }
+}
+
+/// The certification cases, in the order they are run. Cases depending on the resources created
+/// by other cases are run after them.
+pub fn get_certification_cases() -> Vec<Box<dyn CertificationCase>> {
+    vec![
+        Box::new(Authorize),
+        Box::new(Capture),
+        Box::new(Void),
+        Box::new(PaymentSync),
+        Box::new(Refund),
+        Box::new(RefundSync),
+        Box::new(Webhooks),
+    ]
+}
+
+fn get_connector_account_details(auth_type: &ConnectorAuthType) -> Result<Value> {
+    Ok(match auth_type {
+        ConnectorAuthType::HeaderKey { api_key } => json!({
+            "auth_type": "HeaderKey",
+            "api_key": api_key.peek(),
+        }),
+        ConnectorAuthType::BodyKey { api_key, key1 } => json!({
+            "auth_type": "BodyKey",
+            "api_key": api_key.peek(),
+            "key1": key1.peek(),
+        }),
+        ConnectorAuthType::SignatureKey {
+            api_key,
+            key1,
+            api_secret,
+        } => json!({
+            "auth_type": "SignatureKey",
+            "api_key": api_key.peek(),
+            "key1": key1.peek(),
+            "api_secret": api_secret.peek(),
+        }),
+        ConnectorAuthType::MultiAuthKey {
+            api_key,
+            key1,
+            api_secret,
+            key2,
+        } => json!({
+            "auth_type": "MultiAuthKey",
+            "api_key": api_key.peek(),
+            "key1": key1.peek(),
+            "api_secret": api_secret.peek(),
+            "key2": key2.peek(),
+        }),
+        ConnectorAuthType::NoKey => bail!("invalid authentication type for the connector"),
+    })
+}
+
+async fn send_admin_request(
+    client: &reqwest::Client,
+    args: &Args,
+    path: &str,
+    body: Value,
+) -> Result<Value> {
+    let response = client
+        .post(format!("{}{path}", args.base_url))
+        .header("api-key", &args.admin_api_key)
+        .json(&body)
+        .send()
+        .await
+        .with_context(|| format!("request to {path} failed"))?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        bail!("request to {path} failed with status code {status}: {body}");
+    }
+    Ok(body)
+}
+
+/// Creates a merchant account, an API key and a merchant connector account with the credentials
+/// of the connector from the `CONNECTOR_AUTH_FILE_PATH` file
+async fn setup_context(args: &Args) -> Result<CertificationContext> {
+    let auth_map = ConnectorAuthenticationMap::new();
+    let auth_type = auth_map
+        .inner()
+        .get(&args.connector_name)
+        .with_context(|| format!("credentials not found for {}", args.connector_name))?;
+    let connector_account_details = get_connector_account_details(auth_type)?;
+    let connector_metadata = args
+        .connector_metadata
+        .as_deref()
+        .map(serde_json::from_str::<Value>)
+        .transpose()
+        .with_context(|| "invalid connector metadata")?;
+
+    let client = reqwest::Client::new();
+    let merchant_id = format!(
+        "certification_{}_{}",
+        args.connector_name,
+        time::OffsetDateTime::now_utc().unix_timestamp()
+    );
+
+    send_admin_request(
+        &client,
+        args,
+        "/accounts",
+        json!({
+            "merchant_id": merchant_id,
+            "merchant_name": format!("{} certification", args.connector_name),
+            "return_url": "https://hyperswitch.io",
+        }),
+    )
+    .await?;
+    let api_key = send_admin_request(
+        &client,
+        args,
+        &format!("/api_keys/{merchant_id}"),
+        json!({ "name": "certification", "expiration": "never" }),
+    )
+    .await?;
+    let merchant_connector_account = send_admin_request(
+        &client,
+        args,
+        &format!("/account/{merchant_id}/connectors"),
+        json!({
+            "connector_type": "payment_processor",
+            "connector_name": args.connector_name,
+            "connector_account_details": connector_account_details,
+            "test_mode": true,
+            "disabled": false,
+            "metadata": connector_metadata,
+            "payment_methods_enabled": [{
+                "payment_method": "card",
+                "payment_method_types": [
+                    {
+                        "payment_method_type": "credit",
+                        "minimum_amount": 1,
+                        "maximum_amount": 68607706,
+                        "recurring_enabled": true,
+                        "installment_payment_enabled": true
+                    },
+                    {
+                        "payment_method_type": "debit",
+                        "minimum_amount": 1,
+                        "maximum_amount": 68607706,
+                        "recurring_enabled": true,
+                        "installment_payment_enabled": true
+                    }
+                ]
+            }],
+        }),
+    )
+    .await?;
+
+    Ok(CertificationContext {
+        client,
+        base_url: args.base_url.clone(),
+        api_key: Secret::new(get_str(&api_key, "api_key")),
+        merchant_id,
+        merchant_connector_id: get_str(&merchant_connector_account, "merchant_connector_id"),
+        card_number: args.card_number.clone(),
+        currency: args.currency.clone(),
+        webhook_payloads: args.webhook_payloads.clone(),
+        created_ids: HashMap::new(),
+    })
+}
+
+/// Runs the certification cases of the connector and builds the report of the run
+pub async fn run_certification(args: &Args) -> Result<CertificationReport> {
+    let started_at = time::OffsetDateTime::now_utc().unix_timestamp();
+    let mut context = setup_context(args).await?;
+    let flows = args.flows.as_deref().map(|flows| {
+        flows
+            .split(',')
+            .map(|flow| flow.trim().to_string())
+            .collect::<Vec<_>>()
+    });
+
+    let mut results = Vec::new();
+    for case in get_certification_cases() {
+        if flows
+            .as_ref()
+            .is_some_and(|flows| !flows.iter().any(|flow| flow == case.name()))
+        {
+            continue;
+        }
+
+        let start = Instant::now();
+        let (status, message) = match case.run(&mut context).await {
+            Ok(CaseOutcome::Passed) => (CaseStatus::Passed, None),
+            Ok(CaseOutcome::Failed(message)) => (CaseStatus::Failed, Some(message)),
+            Ok(CaseOutcome::Skipped(message)) => (CaseStatus::Skipped, Some(message)),
+            Err(error) => (CaseStatus::Failed, Some(format!("{error:#}"))),
+        };
+        println!("{:<14} {status:?}", case.name());
+        results.push(CaseResult {
+            flow: case.name(),
+            status,
+            duration_in_ms: start.elapsed().as_millis(),
+            message,
+        });
+    }
+
+    let count = |status| {
+        results
+            .iter()
+            .filter(|result| result.status == status)
+            .count()
+    };
+    let (passed, failed, skipped) = (
+        count(CaseStatus::Passed),
+        count(CaseStatus::Failed),
+        count(CaseStatus::Skipped),
+    );
+
+    Ok(CertificationReport {
+        connector: args.connector_name.clone(),
+        base_url: args.base_url.clone(),
+        merchant_id: context.merchant_id,
+        merchant_connector_id: context.merchant_connector_id,
+        started_at,
+        passed,
+        failed,
+        skipped,
+        certified: failed == 0,
+        results,
+    })
+}
+
+/// Writes the report to the file, or prints it when no file is given
+pub fn write_report(report: &CertificationReport, output: Option<&Path>) -> Result<()> {
+    let report = serde_json::to_string_pretty(report)?;
+    match output {
+        Some(path) => fs::write(path, report)
+            .with_context(|| format!("failed to write the report to {}", path.display())),
+        None => {
+            println!("{report}");
+            Ok(())
+        }
+    }
+}
+
+impl Args {
+    /// Getter for the `output` field
+    pub fn get_output(&self) -> Option<&Path> {
+        self.output.as_deref()
+    }
+}
@@ -1,4 +1,5 @@
 #![allow(clippy::print_stdout, clippy::print_stderr)]
 
+pub mod certification;
 pub mod connector_auth;
 pub mod newman_runner;
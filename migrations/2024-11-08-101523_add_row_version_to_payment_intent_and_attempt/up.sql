@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS row_version INTEGER NOT NULL DEFAULT 1;
+
+ALTER TABLE payment_attempt ADD COLUMN IF NOT EXISTS row_version INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS payment_intent_metadata_index ON payment_intent USING GIN (metadata jsonb_path_ops);
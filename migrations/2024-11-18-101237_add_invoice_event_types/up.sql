@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_paid';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_partially_paid';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_overdue';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'invoice_reminder';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'invoice_details';
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'invoices';
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS invoice_payment_ids_index;
+DROP INDEX IF EXISTS invoice_merchant_id_created_at_index;
+DROP TABLE IF EXISTS invoice;
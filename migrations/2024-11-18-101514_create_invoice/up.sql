@@ -0,0 +1,22 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS invoice (
+    invoice_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    currency "Currency" NOT NULL,
+    amount BIGINT NOT NULL,
+    amount_paid BIGINT NOT NULL DEFAULT 0,
+    line_items JSONB NOT NULL,
+    description VARCHAR(255),
+    metadata JSONB,
+    payment_ids TEXT[] NOT NULL DEFAULT '{}',
+    due_date TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS invoice_merchant_id_created_at_index ON invoice (merchant_id, created_at);
+
+CREATE INDEX IF NOT EXISTS invoice_payment_ids_index ON invoice USING GIN (payment_ids);
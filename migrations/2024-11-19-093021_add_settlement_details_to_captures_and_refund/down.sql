@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE captures
+DROP COLUMN IF EXISTS settlement_currency,
+DROP COLUMN IF EXISTS settlement_amount;
+
+ALTER TABLE refund
+DROP COLUMN IF EXISTS settlement_currency,
+DROP COLUMN IF EXISTS settlement_amount;
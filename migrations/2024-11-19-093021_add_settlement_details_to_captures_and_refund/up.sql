@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE captures
+ADD COLUMN IF NOT EXISTS settlement_currency "Currency" DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS settlement_amount BIGINT DEFAULT NULL;
+
+ALTER TABLE refund
+ADD COLUMN IF NOT EXISTS settlement_currency "Currency" DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS settlement_amount BIGINT DEFAULT NULL;
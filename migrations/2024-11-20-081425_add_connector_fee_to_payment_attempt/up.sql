@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS connector_fee_amount BIGINT DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS connector_fee_currency "Currency" DEFAULT NULL;
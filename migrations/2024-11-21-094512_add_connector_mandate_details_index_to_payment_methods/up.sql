@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS payment_methods_connector_mandate_details_index ON payment_methods USING GIN (connector_mandate_details jsonb_path_ops);
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_collected';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payment_method_collect_link_details';
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'payment_methods';
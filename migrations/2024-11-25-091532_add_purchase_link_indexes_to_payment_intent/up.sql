@@ -0,0 +1,4 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_merchant_order_reference_id_index ON payment_intent (merchant_id, merchant_order_reference_id);
+
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_fingerprint_id_index ON payment_intent (merchant_id, fingerprint_id);
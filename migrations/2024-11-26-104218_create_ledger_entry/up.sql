@@ -0,0 +1,17 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS ledger_entry (
+    entry_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    payment_id VARCHAR(64) NOT NULL,
+    dispute_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    entry_type VARCHAR(32) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS ledger_entry_merchant_id_payment_id_index ON ledger_entry (merchant_id, payment_id);
+
+CREATE INDEX IF NOT EXISTS ledger_entry_merchant_id_dispute_id_index ON ledger_entry (merchant_id, dispute_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE dispute DROP COLUMN IF EXISTS submitted_evidence_types;
+
+ALTER TABLE dispute DROP COLUMN IF EXISTS evidence_submitted_at;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE dispute ADD COLUMN IF NOT EXISTS submitted_evidence_types TEXT[] DEFAULT NULL;
+
+ALTER TABLE dispute ADD COLUMN IF NOT EXISTS evidence_submitted_at TIMESTAMP DEFAULT NULL;
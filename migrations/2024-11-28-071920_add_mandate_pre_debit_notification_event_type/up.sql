@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'mandate_pre_debit_notification';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'mandate_debit_details';
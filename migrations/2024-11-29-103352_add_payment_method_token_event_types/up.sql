@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_token_suspended';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_token_resumed';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_token_deleted';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payment_method_token_details';
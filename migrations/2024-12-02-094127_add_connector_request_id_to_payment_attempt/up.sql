@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS connector_request_id VARCHAR(255) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS correlation_id VARCHAR(64) DEFAULT NULL;
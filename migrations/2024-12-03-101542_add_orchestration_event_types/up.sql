@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'orchestration';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_retry_triggered';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_connector_switched';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'connector_circuit_breaker_opened';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'orchestration_details';
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE refund
+ADD COLUMN IF NOT EXISTS expected_settlement_at TIMESTAMP DEFAULT NULL;
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS webhook_endpoint_profile_id_index;
+DROP TABLE IF EXISTS webhook_endpoint;
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_order_reference (
+    profile_id VARCHAR(64) NOT NULL,
+    merchant_order_reference_id VARCHAR(255) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (profile_id, merchant_order_reference_id)
+);
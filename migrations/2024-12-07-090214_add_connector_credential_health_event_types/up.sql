@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'merchant_connectors';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'connector_credentials_invalid';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'connector_credentials_restored';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'connector_credential_health_details';
@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'shipments';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'shipment_captured';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'shipment_capture_failed';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'shipment_details';
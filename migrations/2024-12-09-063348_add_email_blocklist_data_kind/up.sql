@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "BlocklistDataKind" ADD VALUE IF NOT EXISTS 'email';
@@ -0,0 +1,39 @@
+-- Your SQL goes here
+-- Schedules the credential health check of the connector accounts created before the check was
+-- introduced, spread over the interval between two checks. Only the connectors whose account
+-- balance can be retrieved support the check.
+INSERT INTO process_tracker (
+        id,
+        name,
+        tag,
+        runner,
+        retry_count,
+        schedule_time,
+        rule,
+        tracking_data,
+        business_status,
+        status,
+        event,
+        created_at,
+        updated_at
+    )
+SELECT 'CONNECTOR_CREDENTIAL_HEALTH_WORKFLOW_CONNECTOR_CREDENTIAL_HEALTH_' || merchant_connector_id,
+    'CONNECTOR_CREDENTIAL_HEALTH',
+    ARRAY ['CONNECTOR_CREDENTIAL_HEALTH'],
+    'CONNECTOR_CREDENTIAL_HEALTH_WORKFLOW',
+    0,
+    now() + random() * INTERVAL '6 hours',
+    '',
+    json_build_object(
+        'merchant_id',
+        merchant_id,
+        'merchant_connector_id',
+        merchant_connector_id
+    ),
+    'Pending',
+    'new',
+    ARRAY []::TEXT [],
+    now(),
+    now()
+FROM merchant_connector_account
+WHERE connector_name IN ('adyen', 'rapyd', 'stripe') ON CONFLICT (id) DO NOTHING;
@@ -0,0 +1,16 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS blocklist_sync_version (
+    merchant_id VARCHAR(64) NOT NULL,
+    version INTEGER NOT NULL,
+    source VARCHAR(255) NOT NULL,
+    external_version VARCHAR(255),
+    added_entries JSONB NOT NULL,
+    removed_entries JSONB NOT NULL,
+    unchanged_count INTEGER NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    rolled_back_at TIMESTAMP,
+    PRIMARY KEY (merchant_id, version)
+);
+
+-- The history of the synchronizations was previously stored in the configs table
+DELETE FROM configs WHERE key LIKE 'blocklist_sync_history_%';
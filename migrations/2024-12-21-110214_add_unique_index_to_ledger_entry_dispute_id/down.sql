@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS ledger_entry_dispute_id_entry_type_refund_id_unique_index;
+DROP INDEX IF EXISTS ledger_entry_dispute_id_entry_type_unique_index;
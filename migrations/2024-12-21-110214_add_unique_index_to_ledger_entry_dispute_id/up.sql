@@ -0,0 +1,8 @@
+-- Your SQL goes here
+-- A dispute is debited, and charged a fee, at most once
+CREATE UNIQUE INDEX IF NOT EXISTS ledger_entry_dispute_id_entry_type_unique_index ON ledger_entry (dispute_id, entry_type)
+WHERE dispute_id IS NOT NULL AND refund_id IS NULL;
+
+-- A refund is reversed at most once for a dispute
+CREATE UNIQUE INDEX IF NOT EXISTS ledger_entry_dispute_id_entry_type_refund_id_unique_index ON ledger_entry (dispute_id, entry_type, refund_id)
+WHERE dispute_id IS NOT NULL AND refund_id IS NOT NULL;
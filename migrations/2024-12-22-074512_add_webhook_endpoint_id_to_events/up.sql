@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE events
+ADD COLUMN IF NOT EXISTS webhook_endpoint_id VARCHAR(64) DEFAULT NULL;
+
+-- The idempotent event IDs of the events delivered to webhook endpoints include the endpoint ID
+ALTER TABLE events
+ALTER COLUMN idempotent_event_id TYPE VARCHAR(128);
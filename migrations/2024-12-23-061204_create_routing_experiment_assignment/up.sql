@@ -0,0 +1,11 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS routing_experiment_assignment (
+    experiment_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    arm VARCHAR(32) NOT NULL,
+    succeeded BOOLEAN,
+    assigned_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    completed_at TIMESTAMP,
+    PRIMARY KEY (experiment_id, payment_id)
+);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_intent_merchant_id_modified_at_payment_id_index;
+
+DROP INDEX IF EXISTS refund_merchant_id_modified_at_refund_id_index;
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS process_tracker_reversed_id_index ON process_tracker (reverse(id) text_pattern_ops);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS refund_tender_override_merchant_id_payment_id_index;
+
+DROP TABLE IF EXISTS refund_tender_override;
@@ -0,0 +1,11 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_shipment (
+    payment_id VARCHAR(64) NOT NULL,
+    shipment_reference VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    capture_id VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    line_items JSONB NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (payment_id, shipment_reference)
+);